@@ -0,0 +1 @@
+ALTER TABLE zandbox.projects ADD COLUMN IF NOT EXISTS proving_key BYTEA;
@@ -0,0 +1,2 @@
+ALTER TABLE zandbox.contracts ADD COLUMN IF NOT EXISTS owner_address BYTEA;
+ALTER TABLE zandbox.contracts ADD COLUMN IF NOT EXISTS storage_root TEXT;
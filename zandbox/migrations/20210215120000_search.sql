@@ -0,0 +1,12 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE zandbox.projects ADD COLUMN IF NOT EXISTS description TEXT;
+
+UPDATE zandbox.projects
+SET description = project->'manifest'->'project'->>'description'
+WHERE description IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_projects_name_search
+    ON zandbox.projects USING GIN (LOWER(name) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_projects_description_search
+    ON zandbox.projects USING GIN (LOWER(description) gin_trgm_ops);
@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS zandbox.owners (
+    name               TEXT,
+
+    address            BYTEA NOT NULL,
+
+    created_at         TIMESTAMP NOT NULL,
+    updated_at         TIMESTAMP NOT NULL,
+
+    PRIMARY KEY        (name)
+);
+
+INSERT INTO zandbox.owners (name, address, created_at, updated_at)
+SELECT DISTINCT ON (name) name, owner_address, created_at, created_at
+FROM zandbox.contracts
+WHERE owner_address IS NOT NULL
+ORDER BY name, created_at
+ON CONFLICT (name) DO NOTHING;
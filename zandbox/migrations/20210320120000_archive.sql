@@ -0,0 +1 @@
+ALTER TABLE zandbox.contracts ADD COLUMN IF NOT EXISTS archived_at TIMESTAMP;
@@ -0,0 +1 @@
+ALTER TABLE zandbox.owners ADD COLUMN IF NOT EXISTS rotations BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,78 @@
+//!
+//! The Zandbox server daemon contract delete challenge store.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+///
+/// The single-use nonces, which the contract owners sign to archive or purge their contracts.
+///
+/// Each contract has at most one challenge at a time, which is returned to every requester
+/// until it is taken or expires, so a third party cannot invalidate the owner's challenge by
+/// requesting a new one. The nonce is not a secret, since it is only valid with the owner
+/// signature. The expired challenges are dropped when a new one is issued.
+///
+#[derive(Debug)]
+pub struct ChallengeStore {
+    /// The challenge lifetime.
+    lifetime: Duration,
+    /// The nonce and the issue time for each contract account ID.
+    challenges: HashMap<u64, (String, Instant)>,
+}
+
+impl ChallengeStore {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(lifetime: Duration) -> Self {
+        Self {
+            lifetime,
+            challenges: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Issues a random nonce for the contract `account_id` at `now`, or returns the live one.
+    ///
+    /// Returns the nonce and its remaining lifetime.
+    ///
+    pub fn issue(&mut self, account_id: u64, now: Instant) -> (String, Duration) {
+        let lifetime = self.lifetime;
+        self.challenges.retain(|_account_id, (_nonce, issued)| {
+            now.saturating_duration_since(*issued) < lifetime
+        });
+
+        let (nonce, issued) = self.challenges.entry(account_id).or_insert_with(|| {
+            let nonce = format!("{:032x}", rand::random::<u128>());
+            (nonce, now)
+        });
+        (
+            nonce.to_owned(),
+            lifetime - now.saturating_duration_since(*issued),
+        )
+    }
+
+    ///
+    /// Consumes the contract `account_id` challenge at `now`.
+    ///
+    /// Returns `true` if the challenge has been issued with the `nonce` and has not expired.
+    /// The challenge is only removed if the `nonce` matches, so a request with a wrong nonce
+    /// does not burn the owner's challenge.
+    ///
+    pub fn take(&mut self, account_id: u64, nonce: &str, now: Instant) -> bool {
+        match self.challenges.get(&account_id) {
+            Some((expected, _issued)) if expected == nonce => {}
+            _ => return false,
+        }
+
+        match self.challenges.remove(&account_id) {
+            Some((_nonce, issued)) => now.saturating_duration_since(issued) < self.lifetime,
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//!
+//! The Zandbox server daemon contract delete challenge store tests.
+//!
+
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::challenge::ChallengeStore;
+
+const LIFETIME: Duration = Duration::from_secs(300);
+
+#[test]
+fn ok_taken() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (nonce, _expires_in) = store.issue(1, now);
+
+    assert!(store.take(1, nonce.as_str(), now + Duration::from_secs(10)));
+}
+
+#[test]
+fn ok_contracts_issued_separately() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (first, _expires_in) = store.issue(1, now);
+    let (second, _expires_in) = store.issue(2, now);
+
+    assert!(store.take(2, second.as_str(), now));
+    assert!(store.take(1, first.as_str(), now));
+}
+
+#[test]
+fn error_single_use() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (nonce, _expires_in) = store.issue(1, now);
+
+    assert!(store.take(1, nonce.as_str(), now));
+    assert!(!store.take(1, nonce.as_str(), now));
+}
+
+#[test]
+fn error_wrong_nonce() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (nonce, _expires_in) = store.issue(1, now);
+
+    assert!(!store.take(1, "deadbeef", now));
+    assert!(store.take(1, nonce.as_str(), now));
+}
+
+#[test]
+fn error_wrong_contract() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (nonce, _expires_in) = store.issue(1, now);
+
+    assert!(!store.take(2, nonce.as_str(), now));
+}
+
+#[test]
+fn error_expired() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (nonce, _expires_in) = store.issue(1, now);
+
+    assert!(!store.take(1, nonce.as_str(), now + LIFETIME));
+}
+
+#[test]
+fn ok_requested_by_third_party() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (owner, _expires_in) = store.issue(1, now);
+    let (third_party, expires_in) = store.issue(1, now + Duration::from_secs(100));
+
+    assert_eq!(owner, third_party);
+    assert_eq!(expires_in, LIFETIME - Duration::from_secs(100));
+    assert!(store.take(1, owner.as_str(), now + Duration::from_secs(200)));
+}
+
+#[test]
+fn ok_reissued_after_expiration() {
+    let mut store = ChallengeStore::new(LIFETIME);
+    let now = Instant::now();
+
+    let (first, _expires_in) = store.issue(1, now);
+    let (second, expires_in) = store.issue(1, now + LIFETIME);
+
+    assert_ne!(first, second);
+    assert_eq!(expires_in, LIFETIME);
+    assert!(!store.take(1, first.as_str(), now + LIFETIME));
+    assert!(store.take(1, second.as_str(), now + LIFETIME));
+}
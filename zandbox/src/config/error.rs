@@ -0,0 +1,82 @@
+//!
+//! The Zandbox server daemon configuration error.
+//!
+
+use std::fmt;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+///
+/// The Zandbox server daemon configuration error.
+///
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The configuration file cannot be read.
+    #[error("reading the configuration file `{}`: {}", .path.display(), .inner)]
+    FileReading {
+        /// The configuration file path.
+        path: PathBuf,
+        /// The inner I/O error.
+        inner: std::io::Error,
+    },
+    /// The configuration file is not a valid TOML or contains unknown fields.
+    #[error("parsing the configuration file `{}`: {}", .path.display(), .inner)]
+    FileParsing {
+        /// The configuration file path.
+        path: PathBuf,
+        /// The inner TOML error.
+        inner: toml::de::Error,
+    },
+    /// The environment variable value cannot be parsed.
+    #[error("the environment variable `{name}` value `{value}` is invalid: {reason}")]
+    Environment {
+        /// The environment variable name.
+        name: String,
+        /// The environment variable value.
+        value: String,
+        /// The parsing error description.
+        reason: String,
+    },
+    /// The merged configuration fields have failed the validation.
+    #[error("invalid configuration:{}", format_fields(.0))]
+    Invalid(Vec<InvalidField>),
+}
+
+///
+/// The configuration field which has failed the validation.
+///
+#[derive(Debug)]
+pub struct InvalidField {
+    /// The configuration file field name.
+    pub field: &'static str,
+    /// The field value, which is `None` if the field is not set.
+    pub value: Option<String>,
+    /// The layer the field value has been taken from.
+    pub origin: String,
+    /// The validation failure description.
+    pub reason: String,
+}
+
+impl fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            Some(ref value) => write!(
+                f,
+                "`{}` = `{}` from {}: {}",
+                self.field, value, self.origin, self.reason
+            ),
+            None => write!(f, "`{}` is not set: {}", self.field, self.reason),
+        }
+    }
+}
+
+///
+/// Renders the invalid `fields`, one per line.
+///
+fn format_fields(fields: &[InvalidField]) -> String {
+    fields
+        .iter()
+        .map(|field| format!("\n    {}", field))
+        .collect::<String>()
+}
@@ -0,0 +1,241 @@
+//!
+//! The Zandbox server daemon configuration layer.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::config::error::Error;
+
+///
+/// The configuration layer, where every field is optional.
+///
+/// The layers are read from the defaults, the configuration file, the environment variables,
+/// and the command line flags, and are merged in that order, so the later ones take precedence.
+///
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Layer {
+    /// The HTTP server listen address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// The HTTP server port.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// The PostgreSQL connection URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_url: Option<String>,
+    /// The zkSync network identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    /// The origins allowed to make the cross-origin requests, or `*` to allow any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors_origins: Option<Vec<String>>,
+    /// The bearer token required to upload the project keys, dump the contract storage, and
+    /// archive the contracts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys_token: Option<String>,
+    /// The bearer token required to purge the contracts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_token: Option<String>,
+    /// The maximal number of scalars in the contract method arguments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_input_values: Option<usize>,
+    /// The maximal nesting depth of the contract method arguments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_input_depth: Option<usize>,
+    /// The symbols of the tokens accepted in the contract call transfers. Any if empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<Vec<String>>,
+    /// The log output format, either `pretty` or `json`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_format: Option<String>,
+    /// The number of the contract dry runs allowed per client IP address in a minute.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run_rate_limit: Option<usize>,
+    /// The number of seconds a contract method may run on the virtual machine.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method_timeout: Option<u64>,
+}
+
+impl Layer {
+    ///
+    /// Creates the layer with the default values of the optional fields.
+    ///
+    pub fn defaults() -> Self {
+        Self {
+            host: Some(zinc_const::zandbox::HOST.to_owned()),
+            port: Some(zinc_const::zandbox::PORT),
+            database_url: None,
+            network: None,
+            cors_origins: Some(vec![zinc_const::zandbox::CORS_ANY_ORIGIN.to_owned()]),
+            keys_token: None,
+            admin_token: None,
+            max_input_values: Some(zinc_const::limit::INPUT_FLAT_VALUES),
+            max_input_depth: Some(zinc_const::limit::INPUT_DEPTH),
+            tokens: Some(vec![]),
+            log_format: Some(zinc_logger::Format::default().to_string()),
+            dry_run_rate_limit: Some(zinc_const::zandbox::DRY_RUN_RATE_LIMIT),
+            method_timeout: Some(zinc_const::zandbox::METHOD_TIMEOUT_SECONDS),
+        }
+    }
+
+    ///
+    /// Reads the layer from the TOML configuration file at `path`.
+    ///
+    pub fn try_from_file(path: &Path) -> Result<Self, Error> {
+        let data = fs::read_to_string(path).map_err(|inner| Error::FileReading {
+            path: path.to_owned(),
+            inner,
+        })?;
+
+        toml::from_str(data.as_str()).map_err(|inner| Error::FileParsing {
+            path: path.to_owned(),
+            inner,
+        })
+    }
+
+    ///
+    /// Reads the layer from the `ZANDBOX_`-prefixed environment `variables`.
+    ///
+    /// The list values are separated with commas.
+    ///
+    pub fn try_from_env(variables: &HashMap<String, String>) -> Result<Self, Error> {
+        Ok(Self {
+            host: Self::read_env(variables, "host")?,
+            port: Self::read_env(variables, "port")?,
+            database_url: Self::read_env(variables, "database_url")?,
+            network: Self::read_env(variables, "network")?,
+            cors_origins: Self::read_env_list(variables, "cors_origins"),
+            keys_token: Self::read_env(variables, "keys_token")?,
+            admin_token: Self::read_env(variables, "admin_token")?,
+            max_input_values: Self::read_env(variables, "max_input_values")?,
+            max_input_depth: Self::read_env(variables, "max_input_depth")?,
+            tokens: Self::read_env_list(variables, "tokens"),
+            log_format: Self::read_env(variables, "log_format")?,
+            dry_run_rate_limit: Self::read_env(variables, "dry_run_rate_limit")?,
+            method_timeout: Self::read_env(variables, "method_timeout")?,
+        })
+    }
+
+    ///
+    /// Overwrites the fields with the ones set in `other`, recording the origin of each
+    /// overwritten field in `origins`.
+    ///
+    /// The `origin` callback describes the layer a field is taken from, e.g. its environment
+    /// variable or command line flag.
+    ///
+    pub fn merge<F>(&mut self, other: Self, origin: F, origins: &mut HashMap<&'static str, String>)
+    where
+        F: Fn(&'static str) -> String,
+    {
+        let mut overwrite = |field: &'static str, is_set: bool| {
+            if is_set {
+                origins.insert(field, origin(field));
+            }
+        };
+
+        overwrite("host", other.host.is_some());
+        overwrite("port", other.port.is_some());
+        overwrite("database_url", other.database_url.is_some());
+        overwrite("network", other.network.is_some());
+        overwrite("cors_origins", other.cors_origins.is_some());
+        overwrite("keys_token", other.keys_token.is_some());
+        overwrite("admin_token", other.admin_token.is_some());
+        overwrite("max_input_values", other.max_input_values.is_some());
+        overwrite("max_input_depth", other.max_input_depth.is_some());
+        overwrite("tokens", other.tokens.is_some());
+        overwrite("log_format", other.log_format.is_some());
+        overwrite("dry_run_rate_limit", other.dry_run_rate_limit.is_some());
+        overwrite("method_timeout", other.method_timeout.is_some());
+
+        self.host = other.host.or_else(|| self.host.take());
+        self.port = other.port.or(self.port);
+        self.database_url = other.database_url.or_else(|| self.database_url.take());
+        self.network = other.network.or_else(|| self.network.take());
+        self.cors_origins = other.cors_origins.or_else(|| self.cors_origins.take());
+        self.keys_token = other.keys_token.or_else(|| self.keys_token.take());
+        self.admin_token = other.admin_token.or_else(|| self.admin_token.take());
+        self.max_input_values = other.max_input_values.or(self.max_input_values);
+        self.max_input_depth = other.max_input_depth.or(self.max_input_depth);
+        self.tokens = other.tokens.or_else(|| self.tokens.take());
+        self.log_format = other.log_format.or_else(|| self.log_format.take());
+        self.dry_run_rate_limit = other.dry_run_rate_limit.or(self.dry_run_rate_limit);
+        self.method_timeout = other.method_timeout.or(self.method_timeout);
+    }
+
+    ///
+    /// Returns the environment variable name of the configuration `field`.
+    ///
+    pub fn env_name(field: &str) -> String {
+        format!(
+            "{}{}",
+            zinc_const::zandbox::CONFIG_ENV_PREFIX,
+            field.to_ascii_uppercase()
+        )
+    }
+
+    ///
+    /// Returns the command line flag of the configuration `field`.
+    ///
+    pub fn flag_name(field: &str) -> &'static str {
+        match field {
+            "host" => "--host",
+            "port" => "--http-port",
+            "database_url" => "--postgresql",
+            "network" => "--network",
+            "cors_origins" => "--cors-origin",
+            "keys_token" => "--keys-token",
+            "admin_token" => "--admin-token",
+            "max_input_values" => "--max-input-values",
+            "max_input_depth" => "--max-input-depth",
+            "tokens" => "--token",
+            "log_format" => "--log-format",
+            "dry_run_rate_limit" => "--dry-run-rate-limit",
+            "method_timeout" => "--method-timeout",
+            field => panic!("Unknown configuration field `{}`", field),
+        }
+    }
+
+    ///
+    /// Reads and parses the environment variable of the configuration `field`.
+    ///
+    fn read_env<T>(variables: &HashMap<String, String>, field: &str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: ToString,
+    {
+        let name = Self::env_name(field);
+        let value = match variables.get(name.as_str()) {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        value
+            .parse::<T>()
+            .map(Some)
+            .map_err(|error| Error::Environment {
+                name,
+                value: value.to_owned(),
+                reason: error.to_string(),
+            })
+    }
+
+    ///
+    /// Reads the comma-separated list environment variable of the configuration `field`.
+    ///
+    fn read_env_list(variables: &HashMap<String, String>, field: &str) -> Option<Vec<String>> {
+        variables.get(Self::env_name(field).as_str()).map(|value| {
+            value
+                .split(',')
+                .map(|item| item.trim().to_owned())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+    }
+}
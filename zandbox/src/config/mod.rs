@@ -0,0 +1,374 @@
+//!
+//! The Zandbox server daemon configuration.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod layer;
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::Path;
+use std::str::FromStr;
+
+use self::error::Error;
+use self::error::InvalidField;
+use self::layer::Layer;
+
+///
+/// The Zandbox server daemon configuration.
+///
+/// The values are taken from the defaults, the `zandbox.toml` file, the `ZANDBOX_`-prefixed
+/// environment variables, and the command line flags, where the later ones take precedence.
+///
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The HTTP server listen address.
+    pub host: IpAddr,
+    /// The HTTP server port.
+    pub port: u16,
+    /// The PostgreSQL connection URL.
+    pub database_url: String,
+    /// The zkSync network identifier.
+    pub network: zksync::Network,
+    /// The origins allowed to make the cross-origin requests. Any if it is only `*`.
+    pub cors_origins: Vec<String>,
+    /// The bearer token required by the `keys`, `storage`, and contract `DELETE` endpoints,
+    /// which are disabled if unset.
+    pub keys_token: Option<String>,
+    /// The bearer token required to purge the contracts, which is disabled if unset.
+    pub admin_token: Option<String>,
+    /// The contract method arguments limits, which are narrowed down to each method template.
+    pub input_limits: zinc_types::ValueLimits,
+    /// The symbols of the tokens accepted in the contract call transfers. Any if empty.
+    pub tokens: Vec<String>,
+    /// The log output format.
+    pub log_format: zinc_logger::Format,
+    /// The number of the contract dry runs allowed per client IP address in a minute.
+    pub dry_run_rate_limit: usize,
+    /// The number of seconds a contract method may run on the virtual machine.
+    pub method_timeout: u64,
+}
+
+impl Config {
+    /// The placeholder printed instead of the secret values.
+    pub const REDACTED: &'static str = "<redacted>";
+
+    ///
+    /// Merges the defaults, the configuration `file`, the environment `variables`, and the
+    /// command line `flags`, and validates the result.
+    ///
+    /// All the invalid fields are reported at once, along with the layers they are taken from.
+    ///
+    pub fn load(
+        file: Option<&Path>,
+        variables: &HashMap<String, String>,
+        flags: Layer,
+    ) -> Result<Self, Error> {
+        let mut origins = HashMap::new();
+
+        let mut layer = Layer::default();
+        layer.merge(
+            Layer::defaults(),
+            |_| "the default".to_owned(),
+            &mut origins,
+        );
+        if let Some(path) = file {
+            let file = Layer::try_from_file(path)?;
+            layer.merge(
+                file,
+                |_| format!("the configuration file `{}`", path.display()),
+                &mut origins,
+            );
+        }
+        layer.merge(
+            Layer::try_from_env(variables)?,
+            |field| format!("the environment variable `{}`", Layer::env_name(field)),
+            &mut origins,
+        );
+        layer.merge(
+            flags,
+            |field| format!("the `{}` flag", Layer::flag_name(field)),
+            &mut origins,
+        );
+
+        Self::validate(layer, &origins)
+    }
+
+    ///
+    /// Checks whether the token with `symbol` is accepted in the contract call transfers.
+    ///
+    pub fn is_token_allowed(&self, symbol: &str) -> bool {
+        self.tokens.is_empty()
+            || self
+                .tokens
+                .iter()
+                .any(|token| token.eq_ignore_ascii_case(symbol))
+    }
+
+    ///
+    /// Checks whether the cross-origin requests are allowed from any origin.
+    ///
+    pub fn is_any_origin_allowed(&self) -> bool {
+        self.cors_origins
+            .iter()
+            .any(|origin| origin == zinc_const::zandbox::CORS_ANY_ORIGIN)
+    }
+
+    ///
+    /// Renders the configuration as TOML with the secrets redacted.
+    ///
+    pub fn to_redacted_toml(&self) -> String {
+        let layer = Layer {
+            host: Some(self.host.to_string()),
+            port: Some(self.port),
+            database_url: Some(Self::redact_url(self.database_url.as_str())),
+            network: Some(self.network.to_string()),
+            cors_origins: Some(self.cors_origins.clone()),
+            keys_token: self.keys_token.as_ref().map(|_| Self::REDACTED.to_owned()),
+            admin_token: self.admin_token.as_ref().map(|_| Self::REDACTED.to_owned()),
+            max_input_values: Some(self.input_limits.flat_values),
+            max_input_depth: Some(self.input_limits.depth),
+            tokens: Some(self.tokens.clone()),
+            log_format: Some(self.log_format.to_string()),
+            dry_run_rate_limit: Some(self.dry_run_rate_limit),
+            method_timeout: Some(self.method_timeout),
+        };
+
+        toml::to_string_pretty(&layer).expect(zinc_const::panic::DATA_CONVERSION)
+    }
+
+    ///
+    /// Validates the merged `layer`, reporting the invalid fields along with their `origins`.
+    ///
+    fn validate(layer: Layer, origins: &HashMap<&'static str, String>) -> Result<Self, Error> {
+        let mut invalid = Vec::new();
+        let mut fail = |field: &'static str, value: Option<String>, reason: String| {
+            invalid.push(InvalidField {
+                field,
+                value,
+                origin: origins.get(field).cloned().unwrap_or_default(),
+                reason,
+            })
+        };
+
+        let host = layer.host.unwrap_or_default();
+        let host = IpAddr::from_str(host.as_str()).map_err(|_| {
+            fail(
+                "host",
+                Some(host.clone()),
+                "must be an IPv4 or IPv6 address".to_owned(),
+            )
+        });
+
+        let port = layer.port.unwrap_or_default();
+        if port == 0 {
+            fail(
+                "port",
+                Some(port.to_string()),
+                "must be between 1 and 65535".to_owned(),
+            );
+        }
+
+        let database_url = match layer.database_url {
+            Some(database_url) => {
+                if !database_url.starts_with("postgres://")
+                    && !database_url.starts_with("postgresql://")
+                {
+                    fail(
+                        "database_url",
+                        Some(Self::redact_url(database_url.as_str())),
+                        "must be a `postgres://` or `postgresql://` connection URL".to_owned(),
+                    );
+                }
+                database_url
+            }
+            None => {
+                fail("database_url", None, Self::missing_reason("database_url"));
+                String::new()
+            }
+        };
+
+        let network = match layer.network {
+            Some(network) => zksync::Network::from_str(network.as_str()).map_err(|_| {
+                fail(
+                    "network",
+                    Some(network.clone()),
+                    "is not a known zkSync network".to_owned(),
+                )
+            }),
+            None => {
+                fail("network", None, Self::missing_reason("network"));
+                Err(())
+            }
+        };
+
+        let cors_origins = layer.cors_origins.unwrap_or_default();
+        for origin in cors_origins.iter() {
+            if origin == zinc_const::zandbox::CORS_ANY_ORIGIN {
+                if cors_origins.len() > 1 {
+                    fail(
+                        "cors_origins",
+                        Some(cors_origins.join(",")),
+                        format!(
+                            "the wildcard `{}` cannot be combined with other origins",
+                            zinc_const::zandbox::CORS_ANY_ORIGIN
+                        ),
+                    );
+                }
+                continue;
+            }
+
+            let authority = origin
+                .strip_prefix("https://")
+                .or_else(|| origin.strip_prefix("http://"));
+            match authority {
+                Some(authority) if !authority.is_empty() && !authority.contains('/') => {}
+                _ => fail(
+                    "cors_origins",
+                    Some(origin.to_owned()),
+                    "must be an `http://` or `https://` origin without a path".to_owned(),
+                ),
+            }
+        }
+
+        let keys_token = layer.keys_token;
+        if let Some(ref keys_token) = keys_token {
+            if keys_token.is_empty() || keys_token.contains(char::is_whitespace) {
+                fail(
+                    "keys_token",
+                    Some(Self::REDACTED.to_owned()),
+                    "must be non-empty and contain no whitespace".to_owned(),
+                );
+            }
+        }
+
+        let admin_token = layer.admin_token;
+        if let Some(ref admin_token) = admin_token {
+            if admin_token.is_empty() || admin_token.contains(char::is_whitespace) {
+                fail(
+                    "admin_token",
+                    Some(Self::REDACTED.to_owned()),
+                    "must be non-empty and contain no whitespace".to_owned(),
+                );
+            }
+        }
+
+        let max_input_values = layer.max_input_values.unwrap_or_default();
+        if max_input_values == 0 {
+            fail(
+                "max_input_values",
+                Some(max_input_values.to_string()),
+                "must be greater than zero".to_owned(),
+            );
+        }
+        let max_input_depth = layer.max_input_depth.unwrap_or_default();
+        if max_input_depth == 0 {
+            fail(
+                "max_input_depth",
+                Some(max_input_depth.to_string()),
+                "must be greater than zero".to_owned(),
+            );
+        }
+
+        let tokens = layer.tokens.unwrap_or_default();
+        for (index, token) in tokens.iter().enumerate() {
+            if token.is_empty() || token.contains(char::is_whitespace) {
+                fail(
+                    "tokens",
+                    Some(token.to_owned()),
+                    "must be a token symbol without whitespace".to_owned(),
+                );
+            } else if tokens[..index]
+                .iter()
+                .any(|previous| previous.eq_ignore_ascii_case(token))
+            {
+                fail(
+                    "tokens",
+                    Some(token.to_owned()),
+                    "is listed more than once".to_owned(),
+                );
+            }
+        }
+
+        let log_format = layer.log_format.unwrap_or_default();
+        let log_format = zinc_logger::Format::from_str(log_format.as_str())
+            .map_err(|reason| fail("log_format", Some(log_format.clone()), reason));
+
+        let dry_run_rate_limit = layer.dry_run_rate_limit.unwrap_or_default();
+        if dry_run_rate_limit == 0 {
+            fail(
+                "dry_run_rate_limit",
+                Some(dry_run_rate_limit.to_string()),
+                "must be greater than zero".to_owned(),
+            );
+        }
+
+        let method_timeout = layer.method_timeout.unwrap_or_default();
+        if method_timeout == 0 {
+            fail(
+                "method_timeout",
+                Some(method_timeout.to_string()),
+                "must be greater than zero".to_owned(),
+            );
+        }
+
+        match (host, network, log_format) {
+            (Ok(host), Ok(network), Ok(log_format)) if invalid.is_empty() => Ok(Self {
+                host,
+                port,
+                database_url,
+                network,
+                cors_origins,
+                keys_token,
+                admin_token,
+                input_limits: zinc_types::ValueLimits::new(max_input_values, max_input_depth),
+                tokens,
+                log_format,
+                dry_run_rate_limit,
+                method_timeout,
+            }),
+            _ => Err(Error::Invalid(invalid)),
+        }
+    }
+
+    ///
+    /// Describes the ways to set the required `field`.
+    ///
+    fn missing_reason(field: &str) -> String {
+        format!(
+            "pass `{}`, set `{}`, or add `{}` to the configuration file",
+            Layer::flag_name(field),
+            Layer::env_name(field),
+            field
+        )
+    }
+
+    ///
+    /// Replaces the password in the connection `url`, if it is there.
+    ///
+    fn redact_url(url: &str) -> String {
+        let (scheme, rest) = match url.find("://") {
+            Some(index) => url.split_at(index + 3),
+            None => return url.to_owned(),
+        };
+        let authority_end = rest.find('/').unwrap_or(rest.len());
+        let credentials_end = match rest[..authority_end].rfind('@') {
+            Some(index) => index,
+            None => return url.to_owned(),
+        };
+
+        match rest[..credentials_end].find(':') {
+            Some(index) => format!(
+                "{}{}:{}{}",
+                scheme,
+                &rest[..index],
+                Self::REDACTED,
+                &rest[credentials_end..]
+            ),
+            None => url.to_owned(),
+        }
+    }
+}
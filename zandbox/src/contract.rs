@@ -47,6 +47,8 @@ impl Contract {
     ///
     /// Loads a contract from the database.
     ///
+    /// The archived contracts are rejected, so they cannot be queried, called, or upgraded.
+    ///
     pub async fn new(
         network: zksync::Network,
         postgresql: DatabaseClient,
@@ -55,6 +57,10 @@ impl Contract {
         let contract = postgresql
             .select_contract(model::contract::select_one::Input::new(eth_address), None)
             .await?;
+        if let Some(archived_at) = contract.archived_at {
+            return Err(Error::ContractArchived { archived_at });
+        }
+
         let project = postgresql
             .select_project(
                 model::project::select_one::Input::new(
@@ -123,12 +129,15 @@ impl Contract {
     ///
     /// Runs the contract method on the virtual machine.
     ///
+    /// The method is aborted with no storage changes if it runs longer than `timeout`.
+    ///
     pub async fn run_method(
         &self,
         method_name: String,
         transaction: zinc_types::TransactionMsg,
         arguments: zinc_types::Value,
         postgresql: DatabaseClient,
+        timeout: Duration,
     ) -> Result<zinc_vm::ContractOutput, Error> {
         let contract_build = self.build.clone();
         let contract_storage_keeper =
@@ -137,17 +146,18 @@ impl Contract {
         let mut storages = HashMap::with_capacity(1);
         storages.insert(self.eth_address, self.storage.clone().into_build());
 
+        let span = tracing::info_span!("vm", method = %method_name);
         let output = tokio::task::spawn_blocking(move || {
-            zinc_vm::ContractFacade::new_with_keeper(
-                contract_build,
-                Box::new(contract_storage_keeper),
-            )
-            .run::<zinc_vm::Bn256>(zinc_vm::ContractInput::new(
-                arguments,
-                storages,
-                method_name,
-                transaction,
-            ))
+            span.in_scope(|| {
+                zinc_vm::ContractFacade::new_with_keeper(
+                    contract_build,
+                    Box::new(contract_storage_keeper),
+                )
+                .run_with_timeout::<zinc_vm::Bn256>(
+                    zinc_vm::ContractInput::new(arguments, storages, method_name, transaction),
+                    timeout,
+                )
+            })
         })
         .await
         .expect(zinc_const::panic::ASYNC_RUNTIME)
@@ -283,6 +293,8 @@ impl Contract {
                         .expect(zinc_const::panic::DATA_CONVERSION),
                     initializer.eth_address,
                     initializer.eth_private_key,
+                    None,
+                    None,
                 ),
             );
         }
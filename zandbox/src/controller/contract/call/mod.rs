@@ -2,7 +2,11 @@
 //! The contract resource POST method `call` module.
 //!
 
+#[cfg(test)]
+mod tests;
+
 use std::collections::HashMap;
+use std::time::Duration;
 
 use actix_web::http::StatusCode;
 use actix_web::web;
@@ -20,12 +24,14 @@ use crate::storage::Storage;
 /// Sequence:
 /// 1. Get the contract and its data from the database.
 /// 2. Extract the called method from its metadata and check if it is mutable.
-/// 3. Parse the method input arguments.
-/// 4. Run the method on the VM.
-/// 5. Create a transactions array from the client and contract transfers.
+/// 3. Check the method input arguments against the size limits and the method template,
+///    reporting all the violations, and parse them.
+/// 4. Run the method on the VM, supplying the transaction if the method reads `zksync::msg`.
+/// 5. Check the client transfer token against the configured list and create a transactions
+///    array from the client and contract transfers.
 /// 6. Send the transactions to zkSync and store its handles.
 /// 7. Wait for all transactions to be committed.
-/// 8. Update the contract storage state in the database.
+/// 8. Update the contract storage state and its root hash in the database.
 /// 9. Send the contract method execution result back to the client.
 ///
 pub async fn handle(
@@ -42,14 +48,15 @@ pub async fn handle(
         .expect(zinc_const::panic::SYNCHRONIZATION)
         .postgresql
         .clone();
-    let network = app_data
+    let config = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .config
+        .clone();
 
     log::info!("[{}] Calling method `{}`", log_id, query.method);
 
-    let contract = Contract::new(network, postgresql.clone(), query.address).await?;
+    let contract = Contract::new(config.network, postgresql.clone(), query.address).await?;
 
     let method = match contract.build.methods.get(query.method.as_str()).cloned() {
         Some(method) => method,
@@ -58,19 +65,43 @@ pub async fn handle(
     if !method.is_mutable {
         return Err(Error::MethodIsImmutable(query.method));
     }
+    if let Some(ref field) = method.required_sender {
+        log::info!(
+            "[{}] The method `{}` may only be called by `self.{}`",
+            log_id,
+            query.method,
+            field
+        );
+    }
 
     let eth_address_bigint =
         BigInt::from_bytes_be(num::bigint::Sign::Plus, contract.eth_address.as_bytes());
+    config
+        .input_limits
+        .narrowed(&method.input)
+        .check(&body.arguments)
+        .map_err(Error::InputLimit)?;
+    let violations = zinc_types::ValueValidator::validate(&body.arguments, &method.input);
+    if !violations.is_empty() {
+        return Err(Error::InputTemplate(violations));
+    }
     let mut arguments = zinc_types::Value::try_from_typed_json(body.arguments, method.input)
         .map_err(Error::InvalidInput)?;
     arguments.insert_contract_instance(eth_address_bigint.clone());
 
+    let transaction = &body.transaction;
+    let msg = transaction_msg(&method, || {
+        transaction
+            .try_to_msg(&contract.wallet)
+            .map_err(Error::Transaction)
+    })?;
     let output = contract
         .run_method(
             query.method,
-            (&body.transaction).try_to_msg(&contract.wallet)?,
+            msg,
             arguments,
             postgresql.clone(),
+            Duration::from_secs(config.method_timeout),
         )
         .await?;
 
@@ -81,6 +112,9 @@ pub async fn handle(
             .tokens
             .resolve(transfer.token.into())
             .ok_or_else(|| Error::TokenNotFound(transfer.token.to_string()))?;
+        if !config.is_token_allowed(token.symbol.as_str()) {
+            return Err(Error::TokenNotAllowed(token.symbol));
+        }
 
         log::info!(
             "[{}] Sending {} {} from {} to {} with total batch fee {} {}",
@@ -115,11 +149,17 @@ pub async fn handle(
 
     let mut transaction = postgresql.new_transaction().await?;
     for (address, storage) in output.storages.into_iter() {
+        let storage_root = output
+            .storage_roots
+            .get(&address)
+            .map(Storage::root_hash_to_hex)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
         let address = zinc_types::address_from_slice(address.to_bytes_be().1.as_slice());
 
-        if let Some(instance) = created_instances.remove(&address) {
+        if let Some(mut instance) = created_instances.remove(&address) {
             let account_id = instance.account_id;
             let storage = Storage::from_build(storage).into_database_insert(account_id);
+            instance.storage_root = Some(storage_root);
 
             postgresql
                 .insert_contract(instance, Some(&mut transaction))
@@ -134,11 +174,17 @@ pub async fn handle(
                     Some(&mut transaction),
                 )
                 .await?;
-            let storage = Storage::from_build(storage)
-                .into_database_update(contract.account_id as zksync_types::AccountId);
+            let account_id = contract.account_id as zksync_types::AccountId;
+            let storage = Storage::from_build(storage).into_database_update(account_id);
             postgresql
                 .update_fields(storage, Some(&mut transaction))
                 .await?;
+            postgresql
+                .update_contract_storage_root(
+                    model::contract::update_storage_root::Input::new(account_id, storage_root),
+                    Some(&mut transaction),
+                )
+                .await?;
         }
     }
     transaction.commit().await?;
@@ -150,3 +196,26 @@ pub async fn handle(
     log::info!("[{}] Call finished", log_id);
     Ok(Response::new_with_data(StatusCode::OK, response))
 }
+
+///
+/// Returns the `zksync::msg` variable value for the `method`.
+///
+/// The submitted transaction is always converted by `convert`, so it is validated for every
+/// method, but the value is only supplied if the method reads the variable, whereas the other
+/// methods are executed with the default value.
+///
+pub fn transaction_msg<F>(
+    method: &zinc_types::ContractMethod,
+    convert: F,
+) -> Result<zinc_types::TransactionMsg, Error>
+where
+    F: FnOnce() -> Result<zinc_types::TransactionMsg, Error>,
+{
+    let msg = convert()?;
+
+    Ok(if method.reads_msg {
+        msg
+    } else {
+        zinc_types::TransactionMsg::default()
+    })
+}
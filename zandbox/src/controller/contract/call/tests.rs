@@ -0,0 +1,114 @@
+//!
+//! The contract resource POST method `call` tests.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use zinc_types::ScalarType;
+use zinc_types::Type;
+
+use crate::error::Error;
+use crate::storage::tests::compile;
+use crate::storage::Storage;
+
+const CONTRACT_MSG: &str = r#"
+contract Test {
+    pub value: u64;
+
+    pub fn sender(mut self) -> u160 {
+        self.value += 1;
+        zksync::msg.sender
+    }
+}
+"#;
+
+fn method(reads_msg: bool) -> zinc_types::ContractMethod {
+    zinc_types::ContractMethod::new(
+        0,
+        "deposit".to_owned(),
+        0,
+        true,
+        reads_msg,
+        None,
+        Type::Structure(vec![]),
+        Type::Scalar(ScalarType::Boolean),
+    )
+}
+
+fn msg() -> zinc_types::TransactionMsg {
+    zinc_types::TransactionMsg::new_from_bigints(
+        BigInt::from(0x42),
+        BigInt::from(0x100),
+        BigInt::from(0),
+        BigInt::from(1_000),
+    )
+}
+
+#[test]
+fn ok_msg_supplied() {
+    let result = super::transaction_msg(&method(true), || Ok(msg()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result.sender, msg().sender);
+    assert_eq!(result, msg());
+}
+
+#[test]
+fn ok_msg_not_read() {
+    let result = super::transaction_msg(&method(false), || Ok(msg()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, zinc_types::TransactionMsg::default());
+}
+
+#[test]
+fn ok_msg_sender_read() {
+    let build = compile(CONTRACT_MSG);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x1);
+    let method = build
+        .methods
+        .get("sender")
+        .cloned()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let transaction_msg =
+        super::transaction_msg(&method, || Ok(msg())).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let storage = Storage::try_from_json(
+        build.storage.as_slice(),
+        eth_address,
+        serde_json::json!(["0x0", [], "0"]),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    let mut storages = HashMap::with_capacity(1);
+    storages.insert(eth_address, storage.into_build());
+
+    let mut arguments = zinc_types::Value::try_from_typed_json(serde_json::json!({}), method.input)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    arguments.insert_contract_instance(BigInt::from_bytes_be(
+        num::bigint::Sign::Plus,
+        eth_address.as_bytes(),
+    ));
+
+    let output = zinc_vm::ContractFacade::new(build)
+        .run::<zinc_vm::Bn256>(zinc_vm::ContractInput::new(
+            arguments,
+            storages,
+            method.name,
+            transaction_msg,
+        ))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(output.result.into_flat_values(), vec![BigInt::from(0x42)]);
+}
+
+#[test]
+fn error_msg_invalid() {
+    let result = super::transaction_msg(&method(false), || {
+        Err(Error::MethodNotFound("deposit".to_owned()))
+    });
+
+    assert!(matches!(result, Err(Error::MethodNotFound(_))));
+}
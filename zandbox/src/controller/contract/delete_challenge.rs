@@ -0,0 +1,57 @@
+//!
+//! The contracts resource GET method `delete-challenge` module.
+//!
+
+use std::time::Instant;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Check that the contract exists in the database.
+/// 2. Issue a single-use nonce for the contract, or get the live one issued before.
+/// 3. Send the nonce and its lifetime back to the client.
+///
+/// The archived contracts get the challenge as well, since they may still be purged.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    path: web::Path<zinc_types::RemoveRequestPath>,
+) -> crate::Result<zinc_types::DeleteChallengeResponseBody, Error> {
+    let path = path.into_inner();
+    let log_id = path.account_id.to_string();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    postgresql
+        .select_contract_owner(
+            model::contract::select_owner::Input::new(path.account_id as zksync_types::AccountId),
+            None,
+        )
+        .await?;
+
+    let (nonce, expires_in) = {
+        let mut app_data = app_data.write().expect(zinc_const::panic::SYNCHRONIZATION);
+        let (nonce, expires_in) = app_data
+            .delete_challenges
+            .issue(path.account_id, Instant::now());
+        (nonce, expires_in.as_secs())
+    };
+
+    log::info!("[{}] Delete challenge issued", log_id);
+
+    let response = zinc_types::DeleteChallengeResponseBody::new(nonce, expires_in);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
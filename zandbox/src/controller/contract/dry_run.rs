@@ -0,0 +1,130 @@
+//!
+//! The contract resource POST method `dry-run` module.
+//!
+
+use std::time::Duration;
+use std::time::Instant;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use actix_web::HttpRequest;
+use num::BigInt;
+
+use crate::contract::Contract;
+use crate::error::Error;
+use crate::response::Response;
+use crate::storage::Storage;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Check the client against the endpoint rate limit.
+/// 2. Get the contract and its data from the database.
+/// 3. Extract the called method from its metadata and check if it is mutable.
+/// 4. Check the method input arguments against the size limits and the method template,
+///    reporting all the violations, and parse them.
+/// 5. Run the method on the VM against a copy of the contract storage, supplying the unsigned
+///    transaction if the method reads `zksync::msg`.
+/// 6. Send the method output, transfers, storage diff, and cost back to the client.
+///
+/// Nothing is written to the database or sent to zkSync. The method failures, e.g. an unmet
+/// `require`, are reported with the `422 Unprocessable Entity` status, as in the `call` endpoint.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    request: HttpRequest,
+    query: web::Query<zinc_types::DryRunRequestQuery>,
+    body: web::Json<zinc_types::DryRunRequestBody>,
+) -> crate::Result<zinc_types::DryRunResponseBody, Error> {
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let log_id = serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    if let Some(address) = request.peer_addr() {
+        app_data
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .dry_run_rate_limit
+            .check(address.ip(), Instant::now())
+            .map_err(|retry_after| Error::RateLimited {
+                retry_after: retry_after.as_secs() + 1,
+            })?;
+    }
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let config = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .clone();
+
+    log::info!("[{}] Dry running method `{}`", log_id, query.method);
+
+    let contract = Contract::new(config.network, postgresql.clone(), query.address).await?;
+
+    let method = match contract.build.methods.get(query.method.as_str()).cloned() {
+        Some(method) => method,
+        None => return Err(Error::MethodNotFound(query.method)),
+    };
+    if !method.is_mutable {
+        return Err(Error::MethodIsImmutable(query.method));
+    }
+
+    let eth_address_bigint =
+        BigInt::from_bytes_be(num::bigint::Sign::Plus, contract.eth_address.as_bytes());
+    config
+        .input_limits
+        .narrowed(&method.input)
+        .check(&body.arguments)
+        .map_err(Error::InputLimit)?;
+    let violations = zinc_types::ValueValidator::validate(&body.arguments, &method.input);
+    if !violations.is_empty() {
+        return Err(Error::InputTemplate(violations));
+    }
+    let mut arguments = zinc_types::Value::try_from_typed_json(body.arguments, method.input)
+        .map_err(Error::InvalidInput)?;
+    arguments.insert_contract_instance(eth_address_bigint.clone());
+
+    let msg = body.msg;
+    let msg = super::call::transaction_msg(&method, || Ok(msg))?;
+    let mut output = contract
+        .run_method(
+            query.method,
+            msg,
+            arguments,
+            postgresql,
+            Duration::from_secs(config.method_timeout),
+        )
+        .await?;
+
+    let storage = output
+        .storages
+        .remove(&eth_address_bigint)
+        .map(Storage::from_build)
+        .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+    let storage = contract
+        .storage
+        .diff(&storage, contract.build.storage.as_slice());
+
+    log::info!(
+        "[{}] Dry run finished with {} transfer(s) and {} changed field(s) in {} instruction(s)",
+        log_id,
+        output.transfers.len(),
+        storage.len(),
+        output.instructions,
+    );
+
+    let response = zinc_types::DryRunResponseBody::new(
+        output.result.into_json(),
+        output.transfers,
+        storage,
+        output.instructions,
+    );
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -2,6 +2,8 @@
 //! The contract resource PUT method `fee` module.
 //!
 
+use std::time::Duration;
+
 use actix_web::http::StatusCode;
 use actix_web::web;
 use num::BigInt;
@@ -20,8 +22,9 @@ use crate::response::Response;
 /// Sequence:
 /// 1. Get the contract and its data from the database.
 /// 2. Extract the called method from its metadata and check if it is mutable.
-/// 3. Parse the method input arguments.
-/// 4. Run the method on the VM.
+/// 3. Check the method input arguments against the size limits and the method template,
+///    reporting all the violations, and parse them.
+/// 4. Run the method on the VM, supplying the transaction if the method reads `zksync::msg`.
 /// 5. Calculate the fee required for the initializers and transfers.
 /// 6. Send the calculated fee back to the client.
 ///
@@ -39,10 +42,11 @@ pub async fn handle(
         .expect(zinc_const::panic::SYNCHRONIZATION)
         .postgresql
         .clone();
-    let network = app_data
+    let config = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .config
+        .clone();
 
     log::info!(
         "[{}] Calculating the fee for method `{}`",
@@ -50,7 +54,7 @@ pub async fn handle(
         query.method,
     );
 
-    let contract = Contract::new(network, postgresql.clone(), query.address).await?;
+    let contract = Contract::new(config.network, postgresql.clone(), query.address).await?;
 
     let method = match contract.build.methods.get(query.method.as_str()).cloned() {
         Some(method) => method,
@@ -62,16 +66,32 @@ pub async fn handle(
 
     let eth_address_bigint =
         BigInt::from_bytes_be(num::bigint::Sign::Plus, contract.eth_address.as_bytes());
+    config
+        .input_limits
+        .narrowed(&method.input)
+        .check(&body.arguments)
+        .map_err(Error::InputLimit)?;
+    let violations = zinc_types::ValueValidator::validate(&body.arguments, &method.input);
+    if !violations.is_empty() {
+        return Err(Error::InputTemplate(violations));
+    }
     let mut arguments = zinc_types::Value::try_from_typed_json(body.arguments, method.input)
         .map_err(Error::InvalidInput)?;
     arguments.insert_contract_instance(eth_address_bigint.clone());
 
+    let transaction = &body.transaction;
+    let msg = super::call::transaction_msg(&method, || {
+        transaction
+            .try_to_msg(&contract.wallet)
+            .map_err(Error::Transaction)
+    })?;
     let output = contract
         .run_method(
             query.method,
-            (&body.transaction).try_to_msg(&contract.wallet)?,
+            msg,
             arguments,
             postgresql,
+            Duration::from_secs(config.method_timeout),
         )
         .await?;
 
@@ -84,6 +104,9 @@ pub async fn handle(
             .ok_or_else(|| Error::TokenNotFound(transfer.token.to_string()))?,
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
+    if !config.is_token_allowed(token.symbol.as_str()) {
+        return Err(Error::TokenNotAllowed(token.symbol));
+    }
     for initializer in output.initializers.into_iter() {
         fee += contract
             .wallet
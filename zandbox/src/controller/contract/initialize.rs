@@ -22,7 +22,9 @@ use crate::response::Response;
 /// 2. Make the initial deposit to the newly created contract.
 /// 3. Send the change-pubkey transaction for the contract.
 /// 4. Set the received contract account ID.
-/// 5. Write the contract and its storage to the persistent database.
+/// 5. Compute the contract storage root hash.
+/// 6. Record the project owner, if it is the first publish, and check it otherwise.
+/// 7. Write the contract, its owner, and its storage to the persistent database.
 ///
 pub async fn handle(
     app_data: crate::WebData,
@@ -41,6 +43,7 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
         .network;
 
     log::info!("[{}] Unlocking sequence started", log_id);
@@ -74,8 +77,8 @@ pub async fn handle(
         );
     }
 
-    let fee_token_id = match body.transaction.tx {
-        zksync_types::ZkSyncTx::Transfer(ref transfer) => transfer.token,
+    let (fee_token_id, owner_address) = match body.transaction.tx {
+        zksync_types::ZkSyncTx::Transfer(ref transfer) => (transfer.token, transfer.from),
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
 
@@ -139,9 +142,25 @@ pub async fn handle(
         ));
     }
 
+    let storage_root = contract.storage.root_hash(contract.build)?;
+
     {
         let mut transaction = postgresql.new_transaction().await?;
 
+        postgresql
+            .insert_owner(
+                model::owner::insert_one::Input::new(contract.name.clone(), contract.owner),
+                Some(&mut transaction),
+            )
+            .await?;
+        crate::controller::check_owner(
+            &postgresql,
+            contract.name.clone(),
+            contract.owner,
+            Some(&mut transaction),
+        )
+        .await?;
+
         match postgresql
             .select_project(
                 model::project::select_one::Input::new(
@@ -188,6 +207,8 @@ pub async fn handle(
                     contract.instance,
                     contract.eth_address,
                     contract.eth_private_key,
+                    Some(owner_address),
+                    Some(storage_root),
                 ),
                 Some(&mut transaction),
             )
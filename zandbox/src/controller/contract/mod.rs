@@ -4,7 +4,12 @@
 
 pub mod call;
 pub mod curve;
+pub mod delete_challenge;
+pub mod dry_run;
 pub mod fee;
 pub mod initialize;
 pub mod publish;
 pub mod query;
+pub mod remove;
+pub mod storage;
+pub mod upgrade;
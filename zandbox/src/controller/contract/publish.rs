@@ -13,11 +13,12 @@ use crate::shared_data::locked_contract::LockedContract;
 /// The HTTP request handler.
 ///
 /// Sequence:
-/// 1. Parse the contract bytecode from the request.
-/// 2. Extract the contract constructor from its metadata.
-/// 3. Parse the construtor arguments.
-/// 4. Run the construtor on the VM which must return the contract storage.
-/// 5. Generate a private key for the contract.
+/// 1. Check the owner signature of the project name, version, source, and bytecode.
+/// 2. Check that the project is owned by the signer, if its owner has been recorded.
+/// 3. Parse the contract bytecode from the request and check its storage size.
+/// 4. Generate a private key for the contract.
+/// 5. If the contract has the `new` constructor, parse its arguments and run it on the VM,
+///    which must return the contract storage. Otherwise, parse the initial storage JSON.
 /// 6. Fill the implicit contract storage fields.
 /// 7. Write the contract and its storage to the in-memory cache.
 /// 8. Return the created contract address to the client.
@@ -31,11 +32,28 @@ pub async fn handle(
     let body = body.into_inner();
     let log_id = format!("{}-{}/{}", query.name, query.version, query.instance);
 
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
         .network;
 
+    let claim = zinc_types::PublishClaim::new(
+        query.name.clone(),
+        query.version.clone(),
+        &body.project,
+        body.bytecode.as_slice(),
+    );
+    if !claim.is_signed_by(&body.signature, body.owner) {
+        return Err(Error::SignatureMismatch);
+    }
+    crate::controller::check_owner(&postgresql, query.name.clone(), body.owner, None).await?;
+
     log::info!("[{}] Initializing a locked contract", log_id);
 
     let pending = LockedContract::new(
@@ -44,9 +62,11 @@ pub async fn handle(
         query.version,
         query.instance,
         body.arguments,
+        body.storage,
         body.project,
         body.bytecode,
         body.verifying_key,
+        body.owner,
         query.change_pubkey_fee_token,
     )
     .await?;
@@ -2,6 +2,8 @@
 //! The contract resource PUT method `query` module.
 //!
 
+use std::time::Duration;
+
 use actix_web::http::StatusCode;
 use actix_web::web;
 use num::BigInt;
@@ -17,7 +19,8 @@ use crate::response::Response;
 /// 1. Get the contract and its data from the database.
 /// 2. If the method was not specified, return the contract storage to the client.
 /// 3. Extract the called method from the contract metadata and check if it is immutable.
-/// 4. Parse the method input arguments.
+/// 4. Check the method input arguments against the size limits and the method template,
+///    reporting all the violations, and parse them.
 /// 5. Run the method on the VM.
 /// 6. Send the contract method execution result back to the client.
 ///
@@ -38,7 +41,18 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
         .network;
+    let input_limits = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .input_limits;
+    let method_timeout = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .method_timeout;
 
     let contract = Contract::new(network, postgresql.clone(), query.address).await?;
 
@@ -63,6 +77,14 @@ pub async fn handle(
     if method.is_mutable {
         return Err(Error::MethodIsMutable(method_name));
     }
+    if let Some(ref field) = method.required_sender {
+        log::info!(
+            "[{}] The method `{}` may only be called by `self.{}`, whereas queries have no sender",
+            log_id,
+            method_name,
+            field
+        );
+    }
 
     let arguments = match body.arguments {
         Some(arguments) => arguments,
@@ -70,6 +92,14 @@ pub async fn handle(
     };
     let eth_address_bigint =
         BigInt::from_bytes_be(num::bigint::Sign::Plus, contract.eth_address.as_bytes());
+    input_limits
+        .narrowed(&method.input)
+        .check(&arguments)
+        .map_err(Error::InputLimit)?;
+    let violations = zinc_types::ValueValidator::validate(&arguments, &method.input);
+    if !violations.is_empty() {
+        return Err(Error::InputTemplate(violations));
+    }
     let mut arguments = zinc_types::Value::try_from_typed_json(arguments, method.input)
         .map_err(Error::InvalidInput)?;
     arguments.insert_contract_instance(eth_address_bigint.clone());
@@ -80,6 +110,7 @@ pub async fn handle(
             zinc_types::TransactionMsg::default(),
             arguments,
             postgresql,
+            Duration::from_secs(method_timeout),
         )
         .await?;
 
@@ -0,0 +1,150 @@
+//!
+//! The contracts resource DELETE method module.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::time::Instant;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use actix_web::HttpRequest;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Check the request bearer token, which is the admin one if the contract is purged.
+/// 2. Get the contract and its owner from the database.
+/// 3. Check the owner signature of the removal.
+/// 4. Consume the delete challenge issued for the contract.
+/// 5. Archive the contract, or delete it along with its storage if it is purged.
+/// 6. Send the removal result back to the client.
+///
+/// The archived contracts are hidden from the listings and reject the queries and calls, but
+/// their data is kept in the database.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    request: HttpRequest,
+    path: web::Path<zinc_types::RemoveRequestPath>,
+    query: web::Query<zinc_types::RemoveRequestQuery>,
+    body: web::Json<zinc_types::RemoveRequestBody>,
+) -> crate::Result<zinc_types::RemoveResponseBody, Error> {
+    let path = path.into_inner();
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let log_id = path.account_id.to_string();
+    let account_id = path.account_id as zksync_types::AccountId;
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let token = {
+        let app_data = app_data.read().expect(zinc_const::panic::SYNCHRONIZATION);
+        if query.purge {
+            app_data.config.admin_token.clone()
+        } else {
+            app_data.config.keys_token.clone()
+        }
+    };
+
+    crate::controller::authorize(token.as_deref(), &request)?;
+
+    let contract = postgresql
+        .select_contract_owner(model::contract::select_owner::Input::new(account_id), None)
+        .await?;
+    if let Some(archived_at) = contract.archived_at {
+        if !query.purge {
+            return Err(Error::ContractArchived { archived_at });
+        }
+    }
+
+    // the signature is checked first, so a forged request does not consume the challenge
+    let removal =
+        zinc_types::ContractRemoval::new(path.account_id, body.nonce.clone(), query.purge);
+    let owner = contract
+        .owner_address
+        .map(|address| zinc_types::address_from_slice(address.as_slice()));
+    check_signer(&removal, &body.signature, owner)?;
+
+    let is_challenge_valid = app_data
+        .write()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .delete_challenges
+        .take(path.account_id, body.nonce.as_str(), Instant::now());
+    if !is_challenge_valid {
+        return Err(Error::ChallengeNotFound);
+    }
+
+    let archived_at = if query.purge {
+        log::info!("[{}] Purging the contract", log_id);
+
+        let mut transaction = postgresql.new_transaction().await?;
+        postgresql
+            .delete_contract_fields(
+                model::field::delete::Input::new(account_id),
+                Some(&mut transaction),
+            )
+            .await?;
+        postgresql
+            .delete_contract(
+                model::contract::delete_one::Input::new(account_id),
+                Some(&mut transaction),
+            )
+            .await?;
+        transaction.commit().await?;
+
+        log::info!("[{}] Contract purged", log_id);
+        None
+    } else {
+        let archived_at = postgresql
+            .update_contract_archived(
+                model::contract::update_archived::Input::new(account_id),
+                None,
+            )
+            .await?
+            .archived_at;
+
+        log::info!("[{}] Contract archived at {}", log_id, archived_at);
+        Some(archived_at)
+    };
+
+    let response = zinc_types::RemoveResponseBody::new(path.account_id, archived_at);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Checks that the `removal` `signature` has been made by the contract `owner`.
+///
+/// The contracts without a known owner cannot be removed.
+///
+pub fn check_signer(
+    removal: &zinc_types::ContractRemoval,
+    signature: &zksync_types::tx::PackedEthSignature,
+    owner: Option<zksync_types::Address>,
+) -> Result<(), Error> {
+    let owner = owner.ok_or(Error::SignatureMismatch)?;
+    let signer = removal.signer(signature).ok_or(Error::SignatureMismatch)?;
+
+    if signer != owner {
+        return Err(Error::OwnerMismatch {
+            expected: serde_json::to_string(&owner)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .replace("\"", ""),
+            found: serde_json::to_string(&signer)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .replace("\"", ""),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+//!
+//! The contracts resource DELETE method tests.
+//!
+
+use actix_web::http::header;
+use actix_web::test::TestRequest;
+
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::H256;
+
+use crate::controller::contract::remove::check_signer;
+use crate::error::Error;
+
+fn private_key(byte: u8) -> H256 {
+    H256::from([byte; zinc_const::size::ETH_PRIVATE_KEY])
+}
+
+fn address(byte: u8) -> zksync_types::Address {
+    PackedEthSignature::address_from_private_key(&private_key(byte))
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+fn removal(purge: bool) -> zinc_types::ContractRemoval {
+    zinc_types::ContractRemoval::new(42, "00ff".to_owned(), purge)
+}
+
+#[test]
+fn ok_signed_by_owner() {
+    let removal = removal(false);
+    let signature = removal
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(check_signer(&removal, &signature, Some(address(1))).is_ok());
+}
+
+#[test]
+fn error_unauthorized() {
+    let request = TestRequest::default()
+        .header(header::AUTHORIZATION, "Bearer keys-token")
+        .to_http_request();
+
+    assert!(matches!(
+        crate::controller::authorize(Some("admin-token"), &request),
+        Err(Error::Unauthorized)
+    ));
+    assert!(matches!(
+        crate::controller::authorize(None, &request),
+        Err(Error::Unauthorized)
+    ));
+}
+
+#[test]
+fn error_signed_by_another_key() {
+    let removal = removal(false);
+    let signature = removal
+        .sign(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(
+        check_signer(&removal, &signature, Some(address(1))),
+        Err(Error::OwnerMismatch { .. })
+    ));
+}
+
+#[test]
+fn error_purge_not_signed() {
+    let signature = removal(false)
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(check_signer(&removal(true), &signature, Some(address(1))).is_err());
+}
+
+#[test]
+fn error_owner_unknown() {
+    let removal = removal(false);
+    let signature = removal
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(
+        check_signer(&removal, &signature, None),
+        Err(Error::SignatureMismatch)
+    ));
+}
@@ -0,0 +1,83 @@
+//!
+//! The contract resource GET method `storage` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use actix_web::HttpRequest;
+
+use crate::contract::Contract;
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Check the request bearer token, since the private fields are returned as well.
+/// 2. Get the contract address by its account ID from the database.
+/// 3. Get the contract and its storage from the database.
+/// 4. Send the storage page back to the client.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    request: HttpRequest,
+    query: web::Query<zinc_types::StorageRequestQuery>,
+) -> crate::Result<zinc_types::StorageResponseBody, Error> {
+    let query = query.into_inner();
+    let log_id = query.account_id.to_string();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let network = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .network;
+    let keys_token = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .keys_token
+        .clone();
+
+    crate::controller::authorize(keys_token.as_deref(), &request)?;
+
+    log::info!("[{}] Dumping the storage", log_id);
+
+    let eth_address = postgresql
+        .select_contract_address(
+            model::contract::select_address::Input::new(
+                query.account_id as zksync_types::AccountId,
+            ),
+            None,
+        )
+        .await?
+        .eth_address;
+    let eth_address = zinc_types::address_from_slice(eth_address.as_slice());
+
+    let contract = Contract::new(network, postgresql, eth_address).await?;
+
+    let offset = query.offset;
+    let limit = query.limit();
+    let fields = contract
+        .storage
+        .into_page(contract.build.storage.as_slice(), offset, limit);
+
+    let response = zinc_types::StorageResponseBody::new(
+        contract.name,
+        contract.version,
+        contract.eth_address,
+        contract.build.storage_layout,
+        fields,
+        offset,
+        limit,
+    );
+
+    log::info!("[{}] Storage page sent", log_id);
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,182 @@
+//!
+//! The contract resource POST method `upgrade` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::contract::Contract;
+use crate::database::error::Error as DatabaseError;
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the contract and its data from the database.
+/// 2. Parse the new contract version bytecode and check the contract name and storage size.
+/// 3. Compare the storage layouts and find the migration method, if the layout has changed
+///    beyond appending fields.
+/// 4. Run the migration method on the VM with the current storage, or initialize the appended
+///    fields with the default values.
+/// 5. Write the new project, contract version, storage, and its root hash to the persistent database.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    query: web::Query<zinc_types::UpgradeRequestQuery>,
+    body: web::Json<zinc_types::UpgradeRequestBody>,
+) -> crate::Result<zinc_types::UpgradeResponseBody, Error> {
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let log_id = serde_json::to_string(&query.address).expect(zinc_const::panic::DATA_CONVERSION);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let network = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .network;
+
+    log::info!("[{}] Upgrading to version {}", log_id, query.version);
+
+    let contract = Contract::new(network, postgresql.clone(), query.address).await?;
+
+    let application = zinc_types::Application::try_from_slice(body.bytecode.as_slice())
+        .map_err(Error::InvalidBytecode)?;
+    let build = match application {
+        zinc_types::Application::Circuit(_circuit) => return Err(Error::NotAContract),
+        zinc_types::Application::Contract(contract) => contract,
+        zinc_types::Application::Library(_library) => return Err(Error::NotAContract),
+    };
+    crate::controller::check_pruned(&build)?;
+    crate::controller::check_storage(&build)?;
+    if build.name != contract.name {
+        return Err(Error::ContractNameMismatch {
+            expected: contract.name,
+            found: build.name,
+        });
+    }
+
+    let migration = build.migration(&contract.build).map_err(Error::Upgrade)?;
+    let migration_name = migration.method().map(|method| method.name.clone());
+    let appended_fields: Vec<String> = migration
+        .appended_fields()
+        .iter()
+        .map(|field| field.name.to_owned())
+        .collect();
+
+    let storage = match migration {
+        zinc_types::ContractMigration::Reuse => contract.storage,
+        zinc_types::ContractMigration::Append(fields) => {
+            log::info!(
+                "[{}] Appending the storage fields {}",
+                log_id,
+                appended_fields.join(", ")
+            );
+
+            contract.storage.append(fields)
+        }
+        zinc_types::ContractMigration::Method(method) => {
+            log::info!("[{}] Migrating the storage with `{}`", log_id, method.name);
+
+            let method = method.to_owned();
+            let storage = contract.storage;
+            let new_build = build.clone();
+            let eth_address = contract.eth_address;
+            let span = tracing::info_span!("vm", method = %method.name);
+            tokio::task::spawn_blocking(move || {
+                span.in_scope(|| storage.migrate(new_build, method, eth_address))
+            })
+            .await
+            .expect(zinc_const::panic::ASYNC_RUNTIME)?
+        }
+    };
+    let storage_root = storage.root_hash(build.clone())?;
+
+    {
+        let mut transaction = postgresql.new_transaction().await?;
+
+        match postgresql
+            .select_project(
+                model::project::select_one::Input::new(
+                    contract.name.clone(),
+                    query.version.clone(),
+                ),
+                Some(&mut transaction),
+            )
+            .await
+        {
+            Ok(output) => {
+                if output.project
+                    != serde_json::to_value(&body.project)
+                        .expect(zinc_const::panic::DATA_CONVERSION)
+                {
+                    return Err(Error::ContractSourceCodeMismatch);
+                }
+            }
+            Err(DatabaseError::NotFound { .. }) => {
+                postgresql
+                    .insert_project(
+                        model::project::insert_one::Input::new(
+                            contract.name.clone(),
+                            query.version.clone(),
+                            semver::Version::parse(env!("CARGO_PKG_VERSION"))
+                                .expect(zinc_const::panic::DATA_CONVERSION),
+                            body.project,
+                            body.bytecode,
+                            body.verifying_key,
+                        ),
+                        Some(&mut transaction),
+                    )
+                    .await?;
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        postgresql
+            .update_contract_version(
+                model::contract::update_version::Input::new(contract.account_id, query.version),
+                Some(&mut transaction),
+            )
+            .await?;
+
+        postgresql
+            .update_contract_storage_root(
+                model::contract::update_storage_root::Input::new(contract.account_id, storage_root),
+                Some(&mut transaction),
+            )
+            .await?;
+
+        postgresql
+            .delete_contract_fields(
+                model::field::delete::Input::new(contract.account_id),
+                Some(&mut transaction),
+            )
+            .await?;
+
+        postgresql
+            .insert_fields(
+                storage.into_database_insert(contract.account_id),
+                Some(&mut transaction),
+            )
+            .await?;
+
+        transaction.commit().await?;
+    }
+
+    let response = zinc_types::UpgradeResponseBody::new(
+        contract.build.storage_layout,
+        build.storage_layout,
+        migration_name,
+        appended_fields,
+    );
+
+    log::info!("[{}] Upgrade finished", log_id);
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
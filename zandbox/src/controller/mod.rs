@@ -6,7 +6,14 @@ pub mod contract;
 pub mod head;
 pub mod project;
 
+use actix_web::http::header;
 use actix_web::web;
+use actix_web::HttpRequest;
+
+use crate::database::client::Client as DatabaseClient;
+use crate::database::error::Error as DatabaseError;
+use crate::database::model;
+use crate::error::Error;
 
 ///
 /// The Zandbox router.
@@ -42,10 +49,38 @@ pub fn configure(config: &mut web::ServiceConfig) {
                                 .route(web::head().to(head::handle))
                                 .route(web::post().to(contract::call::handle)),
                         )
+                        .service(
+                            web::resource("/dry-run")
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::dry_run::handle)),
+                        )
                         .service(
                             web::resource("/fee")
                                 .route(web::head().to(head::handle))
                                 .route(web::put().to(contract::fee::handle)),
+                        )
+                        .service(
+                            web::resource("/storage")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::storage::handle)),
+                        )
+                        .service(
+                            web::resource("/upgrade")
+                                .route(web::head().to(head::handle))
+                                .route(web::post().to(contract::upgrade::handle)),
+                        ),
+                )
+                .service(
+                    web::scope("/contracts")
+                        .service(
+                            web::resource("/{account_id}")
+                                .route(web::head().to(head::handle))
+                                .route(web::delete().to(contract::remove::handle)),
+                        )
+                        .service(
+                            web::resource("/{account_id}/delete-challenge")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(contract::delete_challenge::handle)),
                         ),
                 )
                 .service(
@@ -60,8 +95,169 @@ pub fn configure(config: &mut web::ServiceConfig) {
                             web::resource("/source")
                                 .route(web::head().to(head::handle))
                                 .route(web::get().to(project::source::handle)),
+                        )
+                        .service(
+                            web::resource("/owner")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(project::rotation::handle))
+                                .route(web::put().to(project::owner::handle)),
+                        )
+                        .service(
+                            web::resource("/keys")
+                                .app_data(keys_json_config())
+                                .route(web::head().to(head::handle))
+                                .route(web::put().to(project::keys::handle)),
+                        ),
+                )
+                .service(
+                    web::scope("/projects")
+                        .service(
+                            web::resource("")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(project::search::handle)),
+                        )
+                        .service(
+                            web::resource("/{name}/{version}/abi")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(project::abi::handle)),
+                        )
+                        .service(
+                            web::resource("/{name}/{version}/instances")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(project::instances::handle)),
+                        )
+                        .service(
+                            web::resource("/{name}/{version}/bytecode")
+                                .route(web::head().to(head::handle))
+                                .route(web::get().to(project::bytecode::handle)),
                         ),
                 ),
         ),
     );
 }
+
+///
+/// The JSON body extractor configuration.
+///
+/// The extractor failures are converted into the common error response envelope.
+///
+pub fn json_config() -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(zinc_const::limit::JSON_PAYLOAD)
+        .error_handler(|error, _request| Error::InvalidJson(error.to_string()).into())
+}
+
+///
+/// The JSON body extractor configuration for the project keys, which exceed the common limit.
+///
+pub fn keys_json_config() -> web::JsonConfig {
+    json_config().limit(zinc_const::limit::KEYS_JSON_PAYLOAD)
+}
+
+///
+/// The query string extractor configuration.
+///
+/// The extractor failures are converted into the common error response envelope.
+///
+pub fn query_config() -> web::QueryConfig {
+    web::QueryConfig::default()
+        .error_handler(|error, _request| Error::InvalidQuery(error.to_string()).into())
+}
+
+///
+/// Checks the request bearer token against the `expected` one.
+///
+/// The request is rejected if the token is not configured on the server.
+///
+pub fn authorize(expected: Option<&str>, request: &HttpRequest) -> Result<(), Error> {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    match (expected, token) {
+        (Some(expected), Some(found)) if is_token_valid(expected, found) => Ok(()),
+        _ => Err(Error::Unauthorized),
+    }
+}
+
+///
+/// Checks that the project `name` is owned by the `owner`.
+///
+/// The project without the recorded owner passes the check, since its owner is recorded when
+/// its first version is published.
+///
+pub async fn check_owner(
+    postgresql: &DatabaseClient,
+    name: String,
+    owner: zksync_types::Address,
+    transaction: Option<&mut sqlx::Transaction<'static, sqlx::Postgres>>,
+) -> Result<(), Error> {
+    let expected = match postgresql
+        .select_owner(model::owner::select_one::Input::new(name), transaction)
+        .await
+    {
+        Ok(output) => zinc_types::address_from_slice(output.address.as_slice()),
+        Err(DatabaseError::NotFound { .. }) => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+
+    if expected != owner {
+        return Err(Error::OwnerMismatch {
+            expected: serde_json::to_string(&expected)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .replace("\"", ""),
+            found: serde_json::to_string(&owner)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .replace("\"", ""),
+        });
+    }
+
+    Ok(())
+}
+
+///
+/// Checks the `contract` storage leaf count recorded by the compiler against the storage
+/// fields and the storage Merkle tree capacity.
+///
+pub fn check_storage(contract: &zinc_types::Contract) -> Result<(), Error> {
+    let leaves = zinc_types::Contract::storage_leaf_count(contract.storage.as_slice());
+    if leaves != contract.storage_leaves {
+        return Err(Error::InvalidBytecode(format!(
+            "the storage has {} leaves, but {} are recorded",
+            leaves, contract.storage_leaves
+        )));
+    }
+
+    if leaves > zinc_const::limit::CONTRACT_STORAGE_LEAVES {
+        return Err(Error::StorageLeafLimit {
+            leaves,
+            limit: zinc_const::limit::CONTRACT_STORAGE_LEAVES,
+        });
+    }
+
+    Ok(())
+}
+
+///
+/// Checks that the `contract` has not been pruned to a subset of its methods, since only the
+/// complete artifacts are published.
+///
+pub fn check_pruned(contract: &zinc_types::Contract) -> Result<(), Error> {
+    match contract.pruned_from {
+        Some(ref original) => Err(Error::ContractPruned(original.to_owned())),
+        None => Ok(()),
+    }
+}
+
+///
+/// Compares the tokens in constant time, so the expected token cannot be guessed by timing.
+///
+fn is_token_valid(expected: &str, found: &str) -> bool {
+    expected.len() == found.len()
+        && expected
+            .bytes()
+            .zip(found.bytes())
+            .fold(0, |result, (expected, found)| result | (expected ^ found))
+            == 0
+}
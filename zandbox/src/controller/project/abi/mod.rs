@@ -0,0 +1,55 @@
+//!
+//! The project resource GET method `abi` module.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the project from the database.
+/// 2. Extract the contract ABI from the project bytecode.
+/// 3. Return the ABI to the client.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    path: web::Path<zinc_types::AbiRequestPath>,
+) -> crate::Result<Vec<zinc_types::AbiEntry>, Error> {
+    let path = path.into_inner();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let project = postgresql
+        .select_project(
+            model::project::select_one::Input::new(path.name, path.version),
+            None,
+        )
+        .await?;
+
+    let response = self::response(project.bytecode.as_slice())?;
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Extracts the contract ABI from the project `bytecode`.
+///
+pub fn response(bytecode: &[u8]) -> Result<Vec<zinc_types::AbiEntry>, Error> {
+    match zinc_types::Application::try_from_slice(bytecode).map_err(Error::InvalidBytecode)? {
+        zinc_types::Application::Contract(contract) => Ok(contract.abi()),
+        _ => Err(Error::NotAContract),
+    }
+}
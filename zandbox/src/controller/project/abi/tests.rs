@@ -0,0 +1,90 @@
+//!
+//! The project resource GET method `abi` tests.
+//!
+
+use crate::error::Error;
+
+fn version() -> semver::Version {
+    semver::Version::new(0, 1, 0)
+}
+
+fn contract() -> Vec<u8> {
+    let method = zinc_types::ContractMethod::new(
+        0,
+        "transfer".to_owned(),
+        0,
+        true,
+        false,
+        None,
+        zinc_types::Type::Structure(vec![
+            (
+                "self".to_owned(),
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Integer(
+                    zinc_types::IntegerType::ETH_ADDRESS,
+                )),
+            ),
+            ("recipient".to_owned(), zinc_types::Type::Address),
+            ("amount".to_owned(), zinc_types::Type::Balance),
+        ]),
+        zinc_types::Type::Unit,
+    );
+
+    zinc_types::Application::new_contract(
+        "test".to_owned(),
+        vec![],
+        vec![(method.name.clone(), method)].into_iter().collect(),
+        Default::default(),
+        vec![],
+        None,
+        vec![],
+    )
+    .into_vec(&version())
+}
+
+#[test]
+fn ok_contract() {
+    let abi = super::response(contract().as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(abi.len(), 1);
+    assert_eq!(abi[0].name, "transfer");
+    assert_eq!(abi[0].r#type, zinc_types::AbiEntryType::Function);
+    assert_eq!(
+        abi[0].state_mutability,
+        zinc_types::AbiStateMutability::Nonpayable
+    );
+    assert_eq!(
+        abi[0]
+            .inputs
+            .iter()
+            .map(|input| (input.name.as_str(), input.r#type.as_str()))
+            .collect::<Vec<(&str, &str)>>(),
+        vec![("recipient", "address"), ("amount", "uint248")]
+    );
+    assert!(abi[0].outputs.is_empty());
+}
+
+#[test]
+fn error_not_a_contract() {
+    let bytecode = zinc_types::Application::new_circuit(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::empty_structure(),
+        zinc_types::Type::Unit,
+        Default::default(),
+        vec![],
+    )
+    .into_vec(&version());
+
+    assert!(matches!(
+        super::response(bytecode.as_slice()),
+        Err(Error::NotAContract)
+    ));
+}
+
+#[test]
+fn error_invalid_bytecode() {
+    assert!(matches!(
+        super::response(&[0xff; 4]),
+        Err(Error::InvalidBytecode(_))
+    ));
+}
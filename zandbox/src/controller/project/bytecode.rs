@@ -0,0 +1,41 @@
+//!
+//! The project resource GET method `bytecode` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the project from the database.
+/// 2. Return the project bytecode and compiler version to the client.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    path: web::Path<zinc_types::BytecodeRequestPath>,
+) -> crate::Result<zinc_types::BytecodeResponseBody, Error> {
+    let path = path.into_inner();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let project = postgresql
+        .select_project(
+            model::project::select_one::Input::new(path.name, path.version),
+            None,
+        )
+        .await?;
+
+    let response = zinc_types::BytecodeResponseBody::new(project.zinc_version, project.bytecode);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,105 @@
+//!
+//! The project resource GET method `instances` module.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the project from the database and extract the contract interfaces from its bytecode.
+/// 2. Get the requested page of the project instances from the database.
+/// 3. Return the instances page to the client.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    path: web::Path<zinc_types::InstancesRequestPath>,
+    query: web::Query<zinc_types::InstancesRequestQuery>,
+) -> crate::Result<zinc_types::InstancesResponseBody, Error> {
+    let path = path.into_inner();
+    let query = query.into_inner();
+    let limit = query.limit();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let project = postgresql
+        .select_project(
+            model::project::select_one::Input::new(path.name.clone(), path.version.clone()),
+            None,
+        )
+        .await?;
+    let interfaces = match zinc_types::Application::try_from_slice(project.bytecode.as_slice())
+        .map_err(Error::InvalidBytecode)?
+    {
+        zinc_types::Application::Contract(contract) => contract.interfaces,
+        _ => return Err(Error::NotAContract),
+    };
+
+    let total = postgresql
+        .count_contracts_instances(
+            model::contract::count_instances::Input::new(path.name.clone(), path.version.clone()),
+            None,
+        )
+        .await?
+        .count as usize;
+    let instances = postgresql
+        .select_contracts_instances(
+            model::contract::select_instances::Input::new(
+                path.name,
+                path.version,
+                query.offset,
+                limit,
+            ),
+            None,
+        )
+        .await?;
+
+    let response = self::response(instances, interfaces, query.offset, limit, total);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Converts the database records into the response body.
+///
+/// The `interfaces` are shared by all the instances, since they are taken from the project build.
+///
+pub fn response(
+    instances: Vec<model::contract::select_instances::Output>,
+    interfaces: Vec<String>,
+    offset: usize,
+    limit: usize,
+    total: usize,
+) -> zinc_types::InstancesResponseBody {
+    let instances = instances
+        .into_iter()
+        .map(|instance| {
+            zinc_types::InstancesResponseInstance::new(
+                instance.account_id as u64,
+                instance.instance,
+                zinc_types::address_from_slice(instance.eth_address.as_slice()),
+                instance
+                    .owner_address
+                    .map(|address| zinc_types::address_from_slice(address.as_slice())),
+                instance.published_at,
+                interfaces.clone(),
+                instance.storage_root,
+            )
+        })
+        .collect();
+
+    zinc_types::InstancesResponseBody::new(instances, offset, limit, total)
+}
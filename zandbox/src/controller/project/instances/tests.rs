@@ -0,0 +1,78 @@
+//!
+//! The project resource GET method `instances` tests.
+//!
+
+use crate::database::model::contract::select_instances::Output;
+
+fn address(byte: u8) -> zksync_types::Address {
+    zksync_types::Address::from([byte; zinc_const::size::ETH_ADDRESS])
+}
+
+fn record(account_id: i64, owner: Option<u8>) -> Output {
+    Output::new(
+        account_id,
+        format!("instance_{}", account_id),
+        address(account_id as u8).as_bytes().to_vec(),
+        owner.map(|byte| address(byte).as_bytes().to_vec()),
+        format!("2020-12-20T12:00:0{}Z", account_id),
+        Some(format!("0x{:0>64}", account_id)),
+    )
+}
+
+#[test]
+fn ok_two_instances() {
+    let interfaces = vec!["Ownable".to_owned()];
+
+    let body = super::response(
+        vec![record(1, Some(0xaa)), record(2, None)],
+        interfaces.clone(),
+        0,
+        zinc_const::zandbox::PAGE_LIMIT_DEFAULT,
+        2,
+    );
+
+    assert_eq!(body.instances.len(), 2);
+    assert!(!body.has_next_page());
+
+    let first = &body.instances[0];
+    assert_eq!(first.account_id, 1);
+    assert_eq!(first.instance, "instance_1");
+    assert_eq!(first.address, address(1));
+    assert_eq!(first.owner, Some(address(0xaa)));
+    assert_eq!(first.published_at, "2020-12-20T12:00:01Z");
+    assert_eq!(first.interfaces, interfaces);
+    assert_eq!(
+        first.storage_root.as_deref(),
+        Some(format!("0x{:0>64}", 1).as_str())
+    );
+
+    let second = &body.instances[1];
+    assert_eq!(second.account_id, 2);
+    assert_eq!(second.address, address(2));
+    assert_eq!(second.owner, None);
+    assert_eq!(second.interfaces, interfaces);
+}
+
+#[test]
+fn ok_page_with_next() {
+    let body = super::response(vec![record(2, None)], vec![], 1, 1, 3);
+
+    assert_eq!(body.offset, 1);
+    assert_eq!(body.limit, 1);
+    assert_eq!(body.total, 3);
+    assert!(body.has_next_page());
+}
+
+#[test]
+fn ok_serialization() {
+    let body = super::response(vec![record(1, None)], vec![], 0, 1, 1);
+
+    let json = serde_json::to_value(zinc_types::DataResponseBody::new(body))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(json["data"]["total"], 1);
+    assert_eq!(json["data"]["instances"][0]["instance"], "instance_1");
+    assert_eq!(
+        json["data"]["instances"][0]["owner"],
+        serde_json::Value::Null
+    );
+}
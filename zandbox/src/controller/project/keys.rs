@@ -0,0 +1,124 @@
+//!
+//! The project resource PUT method `keys` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+use actix_web::HttpRequest;
+use rustc_hex::ToHex;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Check the request bearer token.
+/// 2. Check the uploaded keys sizes.
+/// 3. Get the project bytecode from the database.
+/// 4. Check that the keys have been generated for the project bytecode.
+/// 5. Replace the project keys in the database.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    request: HttpRequest,
+    query: web::Query<zinc_types::KeysRequestQuery>,
+    body: web::Json<zinc_types::KeysRequestBody>,
+) -> crate::Result<(), Error> {
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let log_id = format!("{}-{}", query.name, query.version);
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+    let keys_token = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .config
+        .keys_token
+        .clone();
+
+    crate::controller::authorize(keys_token.as_deref(), &request)?;
+
+    check_size(
+        "verifying",
+        body.verifying_key.as_slice(),
+        zinc_const::limit::VERIFYING_KEY_SIZE,
+    )?;
+    if let Some(ref proving_key) = body.proving_key {
+        check_size(
+            "proving",
+            proving_key.as_slice(),
+            zinc_const::limit::PROVING_KEY_SIZE,
+        )?;
+    }
+
+    let project = postgresql
+        .select_project(
+            model::project::select_one::Input::new(query.name.clone(), query.version.clone()),
+            None,
+        )
+        .await?;
+
+    let circuit_hash = zinc_types::circuit_hash(project.bytecode.as_slice());
+    check_circuit_hash(
+        "verifying",
+        body.verifying_key.as_slice(),
+        circuit_hash.as_slice(),
+    )?;
+    if let Some(ref proving_key) = body.proving_key {
+        check_circuit_hash("proving", proving_key.as_slice(), circuit_hash.as_slice())?;
+    }
+
+    postgresql
+        .update_project_keys(
+            model::project::update_keys::Input::new(
+                query.name,
+                query.version,
+                body.verifying_key,
+                body.proving_key,
+            ),
+            None,
+        )
+        .await?;
+
+    log::info!("[{}] Project keys uploaded", log_id);
+
+    Ok(Response::new(StatusCode::OK))
+}
+
+///
+/// Checks that the `key` size does not exceed the `limit`.
+///
+fn check_size(kind: &'static str, key: &[u8], limit: usize) -> Result<(), Error> {
+    if key.len() > limit {
+        return Err(Error::KeyTooLarge {
+            key: kind,
+            size: key.len(),
+            limit,
+        });
+    }
+
+    Ok(())
+}
+
+///
+/// Checks that the `key` has been generated for the bytecode with the `expected` circuit hash.
+///
+fn check_circuit_hash(kind: &'static str, key: &[u8], expected: &[u8]) -> Result<(), Error> {
+    let found = zinc_types::key_circuit_hash(key).unwrap_or_default();
+    if found != expected {
+        return Err(Error::CircuitHashMismatch {
+            key: kind,
+            expected: expected.to_hex(),
+            found: found.to_hex(),
+        });
+    }
+
+    Ok(())
+}
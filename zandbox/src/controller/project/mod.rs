@@ -2,6 +2,13 @@
 //! The project resource.
 //!
 
+pub mod abi;
+pub mod bytecode;
+pub mod instances;
+pub mod keys;
 pub mod metadata;
+pub mod owner;
+pub mod rotation;
+pub mod search;
 pub mod source;
 pub mod upload;
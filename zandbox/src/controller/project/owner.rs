@@ -0,0 +1,67 @@
+//!
+//! The project resource PUT method `owner` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the current project owner and the number of the owner rotations from the database.
+/// 2. Check the current owner signature of the rotation.
+/// 3. Replace the project owner in the database, incrementing the number of the rotations.
+///
+/// The rotation is signed along with the number of the rotations made before, so the signature
+/// of a past rotation cannot be replayed to take the ownership back.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    query: web::Query<zinc_types::OwnerRequestQuery>,
+    body: web::Json<zinc_types::OwnerRequestBody>,
+) -> crate::Result<(), Error> {
+    let query = query.into_inner();
+    let body = body.into_inner();
+    let log_id = query.name.clone();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let owner = postgresql
+        .select_owner(
+            model::owner::select_one::Input::new(query.name.clone()),
+            None,
+        )
+        .await?;
+    let rotations = owner.rotations;
+    let owner = zinc_types::address_from_slice(owner.address.as_slice());
+
+    let rotation =
+        zinc_types::OwnerRotation::new(query.name.clone(), rotations as u64, owner, body.new_owner);
+    if !rotation.is_signed_by_owner(&body.signature) {
+        return Err(Error::SignatureMismatch);
+    }
+
+    postgresql
+        .update_owner(
+            model::owner::update_one::Input::new(query.name, owner, body.new_owner, rotations),
+            None,
+        )
+        .await?;
+
+    log::info!(
+        "[{}] Project owner changed to {}",
+        log_id,
+        serde_json::to_string(&body.new_owner).expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    Ok(Response::new(StatusCode::OK))
+}
@@ -0,0 +1,44 @@
+//!
+//! The project resource GET method `owner` module.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Get the current project owner from the database.
+/// 2. Send the owner and the number of the owner rotations back to the client.
+///
+/// The number of the rotations is signed along with the next rotation, so a rotation signature
+/// cannot be replayed.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    query: web::Query<zinc_types::OwnerRequestQuery>,
+) -> crate::Result<zinc_types::OwnerResponseBody, Error> {
+    let query = query.into_inner();
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let owner = postgresql
+        .select_owner(model::owner::select_one::Input::new(query.name), None)
+        .await?;
+
+    let response = zinc_types::OwnerResponseBody::new(
+        zinc_types::address_from_slice(owner.address.as_slice()),
+        owner.rotations as u64,
+    );
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
@@ -0,0 +1,87 @@
+//!
+//! The projects resource GET method `search` module.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::str::FromStr;
+
+use actix_web::http::StatusCode;
+use actix_web::web;
+
+use crate::database::model;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// The HTTP request handler.
+///
+/// Sequence:
+/// 1. Count the projects matching the query in the database.
+/// 2. Get the requested page of the matching projects from the database.
+/// 3. Return the projects page to the client.
+///
+pub async fn handle(
+    app_data: crate::WebData,
+    query: web::Query<zinc_types::SearchRequestQuery>,
+) -> crate::Result<zinc_types::SearchResponseBody, Error> {
+    let query = query.into_inner();
+    let limit = query.limit();
+    if query.query.trim().is_empty() {
+        return Err(Error::InvalidQuery(
+            "the search query must not be empty".to_owned(),
+        ));
+    }
+
+    let postgresql = app_data
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .postgresql
+        .clone();
+
+    let total = postgresql
+        .count_projects_search(
+            model::project::count_search::Input::new(query.query.as_str()),
+            None,
+        )
+        .await?
+        .count as usize;
+    let projects = postgresql
+        .select_projects_search(
+            model::project::select_search::Input::new(query.query.as_str(), query.offset, limit),
+            None,
+        )
+        .await?;
+
+    let response = self::response(projects, query.offset, limit, total);
+
+    Ok(Response::new_with_data(StatusCode::OK, response))
+}
+
+///
+/// Converts the database records into the response body.
+///
+pub fn response(
+    projects: Vec<model::project::select_search::Output>,
+    offset: usize,
+    limit: usize,
+    total: usize,
+) -> zinc_types::SearchResponseBody {
+    let projects = projects
+        .into_iter()
+        .map(|project| {
+            zinc_types::SearchResponseProject::new(
+                project.name,
+                semver::Version::from_str(project.version.as_str())
+                    .expect(zinc_const::panic::VALIDATED_DURING_DATABASE_POPULATION),
+                zinc_project::ProjectType::from_str(project.project_type.as_str())
+                    .expect(zinc_const::panic::VALIDATED_DURING_DATABASE_POPULATION),
+                project.description,
+                project.published_at,
+            )
+        })
+        .collect();
+
+    zinc_types::SearchResponseBody::new(projects, offset, limit, total)
+}
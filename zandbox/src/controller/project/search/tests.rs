@@ -0,0 +1,111 @@
+//!
+//! The projects resource GET method `search` tests.
+//!
+
+use crate::database::model::project::select_search::Input;
+use crate::database::model::project::select_search::Output;
+
+fn record(name: &str, version: &str, r#type: &str, description: Option<&str>) -> Output {
+    Output::new(
+        name.to_owned(),
+        version.to_owned(),
+        r#type.to_owned(),
+        description.map(|description| description.to_owned()),
+        "2021-02-15T12:00:00Z".to_owned(),
+    )
+}
+
+#[test]
+fn ok_projects_ranking_kept() {
+    let body = super::response(
+        vec![
+            record("token", "1.2.0", "library", Some("Fungible token")),
+            record("token-swap", "0.1.0", "contract", None),
+            record("my-token", "0.3.1", "contract", Some("A token")),
+            record("exchange", "2.0.0", "contract", Some("Token exchange")),
+        ],
+        0,
+        zinc_const::zandbox::PAGE_LIMIT_DEFAULT,
+        4,
+    );
+
+    assert!(!body.has_next_page());
+    assert_eq!(
+        body.projects
+            .iter()
+            .map(|project| project.name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["token", "token-swap", "my-token", "exchange"]
+    );
+
+    let first = &body.projects[0];
+    assert_eq!(first.version, semver::Version::new(1, 2, 0));
+    assert_eq!(first.r#type, zinc_project::ProjectType::Library);
+    assert_eq!(first.description.as_deref(), Some("Fungible token"));
+    assert_eq!(first.published_at, "2021-02-15T12:00:00Z");
+
+    assert_eq!(body.projects[1].r#type, zinc_project::ProjectType::Contract);
+    assert_eq!(body.projects[1].description, None);
+}
+
+#[test]
+fn ok_page_with_next() {
+    let body = super::response(
+        vec![record("token-swap", "0.1.0", "contract", None)],
+        1,
+        1,
+        3,
+    );
+
+    assert_eq!(body.offset, 1);
+    assert_eq!(body.limit, 1);
+    assert_eq!(body.total, 3);
+    assert!(body.has_next_page());
+}
+
+#[test]
+fn ok_last_page() {
+    let body = super::response(vec![record("exchange", "2.0.0", "contract", None)], 2, 2, 3);
+
+    assert!(!body.has_next_page());
+}
+
+#[test]
+fn ok_pattern_case_insensitive() {
+    assert_eq!(Input::pattern("  Token "), "token");
+}
+
+#[test]
+fn ok_pattern_wildcards_escaped() {
+    assert_eq!(Input::pattern("my_token%"), "my\\_token\\%");
+    assert_eq!(Input::pattern("a\\b"), "a\\\\b");
+}
+
+#[test]
+fn ok_page_limit_capped() {
+    let query = zinc_types::SearchRequestQuery::new(
+        "token".to_owned(),
+        0,
+        Some(zinc_const::zandbox::PAGE_LIMIT_MAX + 1),
+    );
+    assert_eq!(query.limit(), zinc_const::zandbox::PAGE_LIMIT_MAX);
+
+    let query = zinc_types::SearchRequestQuery::new("token".to_owned(), 0, None);
+    assert_eq!(query.limit(), zinc_const::zandbox::PAGE_LIMIT_DEFAULT);
+}
+
+#[test]
+fn ok_serialization() {
+    let body = super::response(vec![record("token", "1.2.0", "library", None)], 0, 1, 1);
+
+    let json = serde_json::to_value(zinc_types::DataResponseBody::new(body))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(json["data"]["total"], 1);
+    assert_eq!(json["data"]["projects"][0]["name"], "token");
+    assert_eq!(json["data"]["projects"][0]["version"], "1.2.0");
+    assert_eq!(json["data"]["projects"][0]["type"], "library");
+    assert_eq!(
+        json["data"]["projects"][0]["description"],
+        serde_json::Value::Null
+    );
+}
@@ -63,6 +63,7 @@ impl Client {
             project,
             bytecode,
             verifying_key,
+            description,
 
             created_at
         ) VALUES (
@@ -72,17 +73,20 @@ impl Client {
             $4,
             $5,
             $6,
+            $7,
             NOW()
         );
         "#;
 
+        let description = input.project.manifest.project.description.clone();
         let query = sqlx::query(STATEMENT)
             .bind(input.name)
             .bind(input.version.to_string())
             .bind(input.zinc_version.to_string())
             .bind(serde_json::to_value(&input.project).expect(zinc_const::panic::DATA_CONVERSION))
             .bind(input.bytecode)
-            .bind(input.verifying_key);
+            .bind(input.verifying_key)
+            .bind(description);
 
         match transaction {
             Some(transaction) => query.execute(transaction).await,
@@ -126,6 +130,41 @@ impl Client {
         .map_err(|error| (error, "project"))?)
     }
 
+    ///
+    /// Replaces the project keys in the `projects` table.
+    ///
+    /// The proving key is cleared if it is not specified, since the previous one does not match
+    /// the new verifying key.
+    ///
+    pub async fn update_project_keys(
+        &self,
+        input: model::project::update_keys::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.projects
+        SET
+            verifying_key = $3,
+            proving_key = $4
+        WHERE
+            name = $1 AND version = $2;
+        "#;
+
+        let query = sqlx::query(STATEMENT)
+            .bind(input.name)
+            .bind(input.version.to_string())
+            .bind(input.verifying_key)
+            .bind(input.proving_key);
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await,
+            None => query.execute(&self.pool).await,
+        }
+        .map_err(|error| (error, "project"))?;
+
+        Ok(())
+    }
+
     ///
     /// Selects a project source code from the `projects` table.
     ///
@@ -182,6 +221,92 @@ impl Client {
         })
     }
 
+    ///
+    /// Searches the `projects` table for the projects, whose names or descriptions contain the text.
+    ///
+    /// The latest version of each project is selected. The exact name matches go first, then
+    /// the name prefix matches, then the other name matches, and then the description matches.
+    /// The matching is backed by the trigram indexes, so the table is not scanned.
+    ///
+    pub async fn select_projects_search(
+        &self,
+        input: model::project::select_search::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<Vec<model::project::select_search::Output>> {
+        const STATEMENT: &str = r#"
+        SELECT
+            name,
+            version,
+            project_type,
+            description,
+
+            TO_CHAR(created_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS published_at
+        FROM (
+            SELECT DISTINCT ON (name)
+                name,
+                version,
+                project->'manifest'->'project'->>'type' AS project_type,
+                description,
+
+                created_at
+            FROM zandbox.projects
+            WHERE name IN (
+                SELECT name
+                FROM zandbox.projects
+                WHERE
+                    LOWER(name) LIKE '%' || $1 || '%'
+                    OR LOWER(description) LIKE '%' || $1 || '%'
+            )
+            ORDER BY name, created_at DESC
+        ) AS latest
+        ORDER BY
+            CASE
+                WHEN LOWER(name) LIKE $1 THEN 0
+                WHEN LOWER(name) LIKE $1 || '%' THEN 1
+                WHEN LOWER(name) LIKE '%' || $1 || '%' THEN 2
+                ELSE 3
+            END,
+            name
+        OFFSET $2
+        LIMIT $3;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT)
+            .bind(input.pattern)
+            .bind(input.offset as i64)
+            .bind(input.limit as i64);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_all(transaction).await?,
+            None => query.fetch_all(&self.pool).await?,
+        })
+    }
+
+    ///
+    /// Counts the projects in the `projects` table, whose names or descriptions contain the text.
+    ///
+    pub async fn count_projects_search(
+        &self,
+        input: model::project::count_search::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::project::count_search::Output> {
+        const STATEMENT: &str = r#"
+        SELECT
+            COUNT(DISTINCT name) AS count
+        FROM zandbox.projects
+        WHERE
+            LOWER(name) LIKE '%' || $1 || '%'
+            OR LOWER(description) LIKE '%' || $1 || '%';
+        "#;
+
+        let query = sqlx::query_as(STATEMENT).bind(input.pattern);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await?,
+            None => query.fetch_one(&self.pool).await?,
+        })
+    }
+
     ///
     /// Inserts a contract into the `contracts` table.
     ///
@@ -201,6 +326,9 @@ impl Client {
             eth_address,
             eth_private_key,
 
+            owner_address,
+            storage_root,
+
             created_at
         ) VALUES (
             $1,
@@ -209,6 +337,8 @@ impl Client {
             $4,
             $5,
             $6,
+            $7,
+            $8,
             NOW()
         );
         "#;
@@ -219,7 +349,13 @@ impl Client {
             .bind(input.version.to_string())
             .bind(input.instance)
             .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.eth_address).to_vec())
-            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec());
+            .bind(<[u8; zinc_const::size::ETH_PRIVATE_KEY]>::from(input.eth_private_key).to_vec())
+            .bind(
+                input
+                    .owner_address
+                    .map(|address| <[u8; zinc_const::size::ETH_ADDRESS]>::from(address).to_vec()),
+            )
+            .bind(input.storage_root);
 
         match transaction {
             Some(transaction) => query.execute(transaction).await,
@@ -247,7 +383,9 @@ impl Client {
             instance,
 
             eth_address,
-            eth_private_key
+            eth_private_key,
+
+            TO_CHAR(archived_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS archived_at
         FROM zandbox.contracts
         WHERE
             eth_address = $1;
@@ -263,6 +401,239 @@ impl Client {
         .map_err(|error| (error, "contract"))?)
     }
 
+    ///
+    /// Selects the contract ETH address by its account ID from the `contracts` table.
+    ///
+    pub async fn select_contract_address(
+        &self,
+        input: model::contract::select_address::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::contract::select_address::Output> {
+        const STATEMENT: &str = r#"
+        SELECT
+            eth_address
+        FROM zandbox.contracts
+        WHERE
+            account_id = $1;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT).bind(input.account_id as i64);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await,
+            None => query.fetch_one(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?)
+    }
+
+    ///
+    /// Selects the contract owner by the contract account ID from the `contracts` and `owners`
+    /// tables.
+    ///
+    pub async fn select_contract_owner(
+        &self,
+        input: model::contract::select_owner::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::contract::select_owner::Output> {
+        const STATEMENT: &str = r#"
+        SELECT
+            contracts.eth_address,
+            contracts.name,
+
+            COALESCE(owners.address, contracts.owner_address) AS owner_address,
+            TO_CHAR(contracts.archived_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS archived_at
+        FROM zandbox.contracts
+        LEFT JOIN zandbox.owners ON owners.name = contracts.name
+        WHERE
+            contracts.account_id = $1;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT).bind(input.account_id as i64);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await,
+            None => query.fetch_one(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?)
+    }
+
+    ///
+    /// Marks the contract as archived in the `contracts` table.
+    ///
+    pub async fn update_contract_archived(
+        &self,
+        input: model::contract::update_archived::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::contract::update_archived::Output> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            archived_at = NOW()
+        WHERE
+            account_id = $1
+        RETURNING
+            TO_CHAR(archived_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS archived_at;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT).bind(input.account_id as i64);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await,
+            None => query.fetch_one(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?)
+    }
+
+    ///
+    /// Deletes a contract from the `contracts` table.
+    ///
+    /// The contract storage fields must be deleted beforehand.
+    ///
+    pub async fn delete_contract(
+        &self,
+        input: model::contract::delete_one::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.contracts
+        WHERE
+            account_id = $1;
+        "#;
+
+        let query = sqlx::query(STATEMENT).bind(input.account_id as i64);
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await,
+            None => query.execute(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Updates the contract version in the `contracts` table.
+    ///
+    pub async fn update_contract_version(
+        &self,
+        input: model::contract::update_version::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            version = $2
+        WHERE
+            account_id = $1;
+        "#;
+
+        let query = sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.version.to_string());
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await,
+            None => query.execute(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Updates the contract storage root hash in the `contracts` table.
+    ///
+    pub async fn update_contract_storage_root(
+        &self,
+        input: model::contract::update_storage_root::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.contracts
+        SET
+            storage_root = $2
+        WHERE
+            account_id = $1;
+        "#;
+
+        let query = sqlx::query(STATEMENT)
+            .bind(input.account_id as i64)
+            .bind(input.storage_root);
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await,
+            None => query.execute(&self.pool).await,
+        }
+        .map_err(|error| (error, "contract"))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a page of the project instances from the `contracts` table.
+    ///
+    /// The instances are ordered by their publishing time.
+    ///
+    pub async fn select_contracts_instances(
+        &self,
+        input: model::contract::select_instances::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<Vec<model::contract::select_instances::Output>> {
+        const STATEMENT: &str = r#"
+        SELECT
+            account_id,
+            instance,
+
+            eth_address,
+            owner_address,
+
+            TO_CHAR(created_at, 'YYYY-MM-DD"T"HH24:MI:SS"Z"') AS published_at,
+            storage_root
+        FROM zandbox.contracts
+        WHERE
+            name = $1 AND version = $2 AND archived_at IS NULL
+        ORDER BY created_at, account_id
+        OFFSET $3
+        LIMIT $4;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT)
+            .bind(input.name)
+            .bind(input.version.to_string())
+            .bind(input.offset as i64)
+            .bind(input.limit as i64);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_all(transaction).await?,
+            None => query.fetch_all(&self.pool).await?,
+        })
+    }
+
+    ///
+    /// Counts the project instances in the `contracts` table.
+    ///
+    pub async fn count_contracts_instances(
+        &self,
+        input: model::contract::count_instances::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::contract::count_instances::Output> {
+        const STATEMENT: &str = r#"
+        SELECT
+            COUNT(*) AS count
+        FROM zandbox.contracts
+        WHERE
+            name = $1 AND version = $2 AND archived_at IS NULL;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT)
+            .bind(input.name)
+            .bind(input.version.to_string());
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await?,
+            None => query.fetch_one(&self.pool).await?,
+        })
+    }
     ///
     /// Select the Curve contracts from the `contracts` table.
     ///
@@ -279,7 +650,7 @@ impl Client {
             instance
         FROM zandbox.contracts
         WHERE
-            name = 'curve'
+            name = 'curve' AND archived_at IS NULL
         ORDER BY created_at;
         "#;
 
@@ -291,6 +662,109 @@ impl Client {
         })
     }
 
+    ///
+    /// Inserts a project owner into the `owners` table.
+    ///
+    /// The existing owner is kept, so the owner is recorded only at the first publish.
+    ///
+    pub async fn insert_owner(
+        &self,
+        input: model::owner::insert_one::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        INSERT INTO zandbox.owners (
+            name,
+
+            address,
+
+            created_at,
+            updated_at
+        ) VALUES (
+            $1,
+            $2,
+            NOW(),
+            NOW()
+        )
+        ON CONFLICT (name) DO NOTHING;
+        "#;
+
+        let query = sqlx::query(STATEMENT)
+            .bind(input.name)
+            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.address).to_vec());
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await,
+            None => query.execute(&self.pool).await,
+        }
+        .map_err(|error| (error, "owner"))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Selects a project owner from the `owners` table.
+    ///
+    pub async fn select_owner(
+        &self,
+        input: model::owner::select_one::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<model::owner::select_one::Output> {
+        const STATEMENT: &str = r#"
+        SELECT
+            address,
+            rotations
+        FROM zandbox.owners
+        WHERE
+            name = $1;
+        "#;
+
+        let query = sqlx::query_as(STATEMENT).bind(input.name);
+
+        Ok(match transaction {
+            Some(transaction) => query.fetch_one(transaction).await,
+            None => query.fetch_one(&self.pool).await,
+        }
+        .map_err(|error| (error, "owner"))?)
+    }
+
+    ///
+    /// Replaces the project owner in the `owners` table.
+    ///
+    /// The owner is only replaced if it has not been changed since it was checked, otherwise
+    /// the owner is reported as not found. The number of the owner rotations is incremented.
+    ///
+    pub async fn update_owner(
+        &self,
+        input: model::owner::update_one::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        UPDATE zandbox.owners
+        SET
+            address = $3,
+            rotations = rotations + 1,
+            updated_at = NOW()
+        WHERE
+            name = $1 AND address = $2 AND rotations = $4
+        RETURNING name;
+        "#;
+
+        let query = sqlx::query(STATEMENT)
+            .bind(input.name)
+            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.address).to_vec())
+            .bind(<[u8; zinc_const::size::ETH_ADDRESS]>::from(input.new_address).to_vec())
+            .bind(input.rotations);
+
+        match transaction {
+            Some(transaction) => query.fetch_one(transaction).await,
+            None => query.fetch_one(&self.pool).await,
+        }
+        .map_err(|error| (error, "owner"))?;
+
+        Ok(())
+    }
+
     ///
     /// Inserts contract storage fields into the `fields` table.
     ///
@@ -388,6 +862,30 @@ impl Client {
         Ok(())
     }
 
+    ///
+    /// Deletes the contract storage fields from the `fields` table.
+    ///
+    pub async fn delete_contract_fields(
+        &self,
+        input: model::field::delete::Input,
+        transaction: Option<&mut Transaction<'static, Postgres>>,
+    ) -> Result<()> {
+        const STATEMENT: &str = r#"
+        DELETE FROM zandbox.fields
+        WHERE
+            account_id = $1;
+        "#;
+
+        let query = sqlx::query(STATEMENT).bind(input.account_id);
+
+        match transaction {
+            Some(transaction) => query.execute(transaction).await?,
+            None => query.execute(&self.pool).await?,
+        };
+
+        Ok(())
+    }
+
     ///
     /// Deletes the `projects` table contents.
     ///
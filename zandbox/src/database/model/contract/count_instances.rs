@@ -0,0 +1,32 @@
+//!
+//! The database contract COUNT instances model.
+//!
+
+///
+/// The database contract COUNT instances input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version) -> Self {
+        Self { name, version }
+    }
+}
+
+///
+/// The database contract COUNT instances output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The number of the project instances.
+    pub count: i64,
+}
@@ -0,0 +1,21 @@
+//!
+//! The database contract DELETE one model.
+//!
+
+///
+/// The database contract DELETE one input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: zksync_types::AccountId,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId) -> Self {
+        Self { account_id }
+    }
+}
@@ -21,12 +21,18 @@ pub struct Input {
     pub eth_address: zksync_types::Address,
     /// The contract private key.
     pub eth_private_key: zksync_types::H256,
+
+    /// The ETH address of the account, which has made the initial deposit.
+    pub owner_address: Option<zksync_types::Address>,
+    /// The hexadecimal contract storage root hash.
+    pub storage_root: Option<String>,
 }
 
 impl Input {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         account_id: zksync_types::AccountId,
 
@@ -36,6 +42,9 @@ impl Input {
 
         eth_address: zksync_types::Address,
         eth_private_key: zksync_types::H256,
+
+        owner_address: Option<zksync_types::Address>,
+        storage_root: Option<String>,
     ) -> Self {
         Self {
             account_id,
@@ -46,6 +55,9 @@ impl Input {
 
             eth_address,
             eth_private_key,
+
+            owner_address,
+            storage_root,
         }
     }
 }
@@ -2,6 +2,14 @@
 //! The database contract model.
 //!
 
+pub mod count_instances;
+pub mod delete_one;
 pub mod insert_one;
+pub mod select_address;
 pub mod select_curve;
+pub mod select_instances;
 pub mod select_one;
+pub mod select_owner;
+pub mod update_archived;
+pub mod update_storage_root;
+pub mod update_version;
@@ -0,0 +1,30 @@
+//!
+//! The database contract SELECT address model.
+//!
+
+///
+/// The database contract SELECT address input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: zksync_types::AccountId,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId) -> Self {
+        Self { account_id }
+    }
+}
+
+///
+/// The database contract SELECT address output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The contract ETH address.
+    pub eth_address: Vec<u8>,
+}
@@ -0,0 +1,82 @@
+//!
+//! The database contract SELECT instances model.
+//!
+
+///
+/// The database contract SELECT instances input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+
+    /// The number of the instances to skip.
+    pub offset: usize,
+    /// The maximal number of the instances to select.
+    pub limit: usize,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version, offset: usize, limit: usize) -> Self {
+        Self {
+            name,
+            version,
+
+            offset,
+            limit,
+        }
+    }
+}
+
+///
+/// The database contract SELECT instances output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The contract account ID.
+    pub account_id: i64,
+    /// The contract instance name.
+    pub instance: String,
+
+    /// The contract ETH address.
+    pub eth_address: Vec<u8>,
+    /// The ETH address of the account, which has made the initial deposit.
+    pub owner_address: Option<Vec<u8>>,
+
+    /// The publishing timestamp in the RFC 3339 format.
+    pub published_at: String,
+    /// The hexadecimal contract storage root hash.
+    pub storage_root: Option<String>,
+}
+
+impl Output {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        account_id: i64,
+        instance: String,
+
+        eth_address: Vec<u8>,
+        owner_address: Option<Vec<u8>>,
+
+        published_at: String,
+        storage_root: Option<String>,
+    ) -> Self {
+        Self {
+            account_id,
+            instance,
+
+            eth_address,
+            owner_address,
+
+            published_at,
+            storage_root,
+        }
+    }
+}
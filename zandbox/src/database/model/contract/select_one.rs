@@ -39,4 +39,7 @@ pub struct Output {
     pub eth_address: Vec<u8>,
     /// The contract private key.
     pub eth_private_key: Vec<u8>,
+
+    /// The contract archival time, if the contract has been archived.
+    pub archived_at: Option<String>,
 }
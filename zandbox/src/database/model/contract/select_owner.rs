@@ -0,0 +1,37 @@
+//!
+//! The database contract SELECT owner model.
+//!
+
+///
+/// The database contract SELECT owner input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: zksync_types::AccountId,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId) -> Self {
+        Self { account_id }
+    }
+}
+
+///
+/// The database contract SELECT owner output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The contract ETH address.
+    pub eth_address: Vec<u8>,
+    /// The contract project name.
+    pub name: String,
+
+    /// The project owner ETH address, or the contract publisher one if the project has no owner.
+    pub owner_address: Option<Vec<u8>>,
+    /// The contract archival time, if the contract has been archived.
+    pub archived_at: Option<String>,
+}
@@ -0,0 +1,26 @@
+//!
+//! The database contract UPDATE storage root model.
+//!
+
+///
+/// The database contract UPDATE storage root input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: zksync_types::AccountId,
+    /// The new hexadecimal contract storage root hash.
+    pub storage_root: String,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId, storage_root: String) -> Self {
+        Self {
+            account_id,
+            storage_root,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//!
+//! The database contract UPDATE version model.
+//!
+
+///
+/// The database contract UPDATE version input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID.
+    pub account_id: zksync_types::AccountId,
+    /// The new contract version.
+    pub version: semver::Version,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId, version: semver::Version) -> Self {
+        Self {
+            account_id,
+            version,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//!
+//! The database contract storage field DELETE model.
+//!
+
+///
+/// The database contract storage field DELETE input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The contract account ID referencing `contracts.account_id`.
+    pub account_id: zksync_types::AccountId,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: zksync_types::AccountId) -> Self {
+        Self { account_id }
+    }
+}
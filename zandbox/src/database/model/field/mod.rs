@@ -2,6 +2,7 @@
 //! The database contract storage field model.
 //!
 
+pub mod delete;
 pub mod insert;
 pub mod select;
 pub mod update;
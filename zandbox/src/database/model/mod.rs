@@ -4,4 +4,5 @@
 
 pub mod contract;
 pub mod field;
+pub mod owner;
 pub mod project;
@@ -0,0 +1,23 @@
+//!
+//! The database owner INSERT one model.
+//!
+
+///
+/// The database owner INSERT one input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The project name.
+    pub name: String,
+    /// The project owner ETH address.
+    pub address: zksync_types::Address,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, address: zksync_types::Address) -> Self {
+        Self { name, address }
+    }
+}
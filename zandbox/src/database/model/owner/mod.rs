@@ -0,0 +1,7 @@
+//!
+//! The database project owner model.
+//!
+
+pub mod insert_one;
+pub mod select_one;
+pub mod update_one;
@@ -0,0 +1,32 @@
+//!
+//! The database owner SELECT one model.
+//!
+
+///
+/// The database owner SELECT one input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The project name.
+    pub name: String,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+///
+/// The database owner SELECT one output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The project owner ETH address.
+    pub address: Vec<u8>,
+    /// The number of the owner rotations.
+    pub rotations: i64,
+}
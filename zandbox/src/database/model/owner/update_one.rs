@@ -0,0 +1,37 @@
+//!
+//! The database owner UPDATE one model.
+//!
+
+///
+/// The database owner UPDATE one input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The project name.
+    pub name: String,
+    /// The current project owner ETH address.
+    pub address: zksync_types::Address,
+    /// The new project owner ETH address.
+    pub new_address: zksync_types::Address,
+    /// The number of the owner rotations the rotation has been signed with.
+    pub rotations: i64,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        address: zksync_types::Address,
+        new_address: zksync_types::Address,
+        rotations: i64,
+    ) -> Self {
+        Self {
+            name,
+            address,
+            new_address,
+            rotations,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//!
+//! The database project COUNT search model.
+//!
+
+use crate::database::model::project::select_search::Input as SelectInput;
+
+///
+/// The database project COUNT search input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The lowercase search text, escaped for the `LIKE` patterns.
+    pub pattern: String,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(query: &str) -> Self {
+        Self {
+            pattern: SelectInput::pattern(query),
+        }
+    }
+}
+
+///
+/// The database project COUNT search output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The number of the found projects.
+    pub count: i64,
+}
@@ -2,7 +2,10 @@
 //! The database project model.
 //!
 
+pub mod count_search;
 pub mod insert_one;
 pub mod select_metadata;
 pub mod select_one;
+pub mod select_search;
 pub mod select_source;
+pub mod update_keys;
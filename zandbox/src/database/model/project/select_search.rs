@@ -0,0 +1,87 @@
+//!
+//! The database project SELECT search model.
+//!
+
+///
+/// The database project SELECT search input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The lowercase search text, escaped for the `LIKE` patterns.
+    pub pattern: String,
+
+    /// The number of the projects to skip.
+    pub offset: usize,
+    /// The maximal number of the projects to select.
+    pub limit: usize,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(query: &str, offset: usize, limit: usize) -> Self {
+        Self {
+            pattern: Self::pattern(query),
+
+            offset,
+            limit,
+        }
+    }
+
+    ///
+    /// Converts the search `query` into the case-insensitive `LIKE` pattern body, where the
+    /// wildcards and the escape character are matched literally.
+    ///
+    pub fn pattern(query: &str) -> String {
+        let mut pattern = String::with_capacity(query.len());
+        for character in query.trim().to_lowercase().chars() {
+            if matches!(character, '\\' | '%' | '_') {
+                pattern.push('\\');
+            }
+            pattern.push(character);
+        }
+        pattern
+    }
+}
+
+///
+/// The database project SELECT search output model.
+///
+#[derive(Debug, sqlx::FromRow)]
+pub struct Output {
+    /// The project name.
+    pub name: String,
+    /// The latest project version.
+    pub version: String,
+    /// The project type from the manifest.
+    pub project_type: String,
+    /// The project description from the manifest.
+    pub description: Option<String>,
+
+    /// The publishing timestamp of the latest version in the RFC 3339 format.
+    pub published_at: String,
+}
+
+impl Output {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: String,
+        project_type: String,
+        description: Option<String>,
+
+        published_at: String,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            project_type,
+            description,
+
+            published_at,
+        }
+    }
+}
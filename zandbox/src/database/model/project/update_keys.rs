@@ -0,0 +1,40 @@
+//!
+//! The database project UPDATE keys model.
+//!
+
+///
+/// The database project UPDATE keys input model.
+///
+#[derive(Debug)]
+pub struct Input {
+    /// The project name.
+    pub name: String,
+    /// The project version.
+    pub version: semver::Version,
+
+    /// The project verifying key as a byte array.
+    pub verifying_key: Vec<u8>,
+    /// The project proving key as a byte array, if it is stored for server-side proving.
+    pub proving_key: Option<Vec<u8>>,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+
+        verifying_key: Vec<u8>,
+        proving_key: Option<Vec<u8>>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+
+            verifying_key,
+            proving_key,
+        }
+    }
+}
@@ -4,7 +4,9 @@
 
 use std::fmt;
 
+use actix_web::http::header;
 use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
 use actix_web::ResponseError;
 
 use crate::database::error::Error as DatabaseError;
@@ -14,17 +16,41 @@ use crate::database::error::Error as DatabaseError;
 ///
 #[derive(Debug)]
 pub enum Error {
+    /// The request JSON body cannot be parsed.
+    InvalidJson(String),
+
+    /// The request query string cannot be parsed.
+    InvalidQuery(String),
+
     /// The uploaded bytecode is malformed.
     InvalidBytecode(String),
 
     /// The uploaded application is not a contract.
     NotAContract,
+    /// The uploaded contract has been pruned to a subset of its methods.
+    ContractPruned(String),
+    /// The contract storage has more leaves than the storage Merkle tree can hold.
+    StorageLeafLimit {
+        /// The number of the contract storage leaves.
+        leaves: usize,
+        /// The storage Merkle tree capacity.
+        limit: usize,
+    },
 
     /// The contract has no constructor.
     ConstructorNotFound,
+    /// The initial storage is sent for a contract, which has a constructor.
+    InitialStorageForbidden,
 
     /// The contract with the specified address is not found in the server cache.
     ContractNotFound(String),
+    /// The contract has been archived by its owner.
+    ContractArchived {
+        /// The archival time in the RFC 3339 format.
+        archived_at: String,
+    },
+    /// The delete challenge has not been issued, has expired, or has been used.
+    ChallengeNotFound,
 
     /// The specified method does not exist in the contract.
     MethodNotFound(String),
@@ -40,12 +66,62 @@ pub enum Error {
 
     /// Invalid contract method arguments.
     InvalidInput(anyhow::Error),
+    /// The contract method arguments exceed the size or nesting depth limit.
+    InputLimit(zinc_types::ValueLimitError),
+    /// The contract method arguments do not match the method input template.
+    InputTemplate(Vec<zinc_types::ValueViolation>),
 
     /// The contract source code has changed, but the name and version are the same.
     ContractSourceCodeMismatch,
+    /// The upgraded contract name does not match the deployed one.
+    ContractNameMismatch {
+        /// The deployed contract name.
+        expected: String,
+        /// The upgraded contract name.
+        found: String,
+    },
+    /// The upgraded contract is incompatible with the deployed one.
+    Upgrade(zinc_types::ContractUpgradeError),
+
+    /// The request bearer token is missing or invalid.
+    Unauthorized,
+    /// The request signature has not been made by the specified owner over the request data.
+    SignatureMismatch,
+    /// The request is signed by another account than the project owner.
+    OwnerMismatch {
+        /// The project owner address.
+        expected: String,
+        /// The request signer address.
+        found: String,
+    },
+    /// The uploaded key exceeds the size limit.
+    KeyTooLarge {
+        /// The key kind, that is, `proving` or `verifying`.
+        key: &'static str,
+        /// The uploaded key size in bytes.
+        size: usize,
+        /// The key size limit in bytes.
+        limit: usize,
+    },
+    /// The client has exceeded the endpoint rate limit.
+    RateLimited {
+        /// The number of the seconds left until the client may retry.
+        retry_after: u64,
+    },
+    /// The uploaded key has been generated for another bytecode.
+    CircuitHashMismatch {
+        /// The key kind, that is, `proving` or `verifying`.
+        key: &'static str,
+        /// The circuit hash of the stored bytecode.
+        expected: String,
+        /// The circuit hash written in the key.
+        found: String,
+    },
 
     /// Token cannot be resolved by zkSync.
     TokenNotFound(String),
+    /// Token is not in the list of the tokens accepted by the server.
+    TokenNotAllowed(String),
 
     /// The contract method input transaction is invalid.
     Transaction(zinc_types::TransactionError),
@@ -72,6 +148,149 @@ pub enum Error {
     ZkSyncSigner(zksync_eth_signer::error::SignerError),
 }
 
+impl Error {
+    ///
+    /// Returns the machine-readable error code, which is sent to the client.
+    ///
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidJson(..) => "INVALID_JSON",
+            Self::InvalidQuery(..) => "INVALID_QUERY",
+
+            Self::InvalidBytecode(..) => "INVALID_BYTECODE",
+            Self::NotAContract => "NOT_A_CONTRACT",
+            Self::ContractPruned(..) => "CONTRACT_PRUNED",
+            Self::StorageLeafLimit { .. } => "STORAGE_LEAF_LIMIT_EXCEEDED",
+            Self::ConstructorNotFound => "CONSTRUCTOR_NOT_FOUND",
+            Self::InitialStorageForbidden => "INITIAL_STORAGE_FORBIDDEN",
+            Self::ContractNotFound(..) => "CONTRACT_NOT_FOUND",
+            Self::ContractArchived { .. } => "CONTRACT_ARCHIVED",
+            Self::ChallengeNotFound => "CHALLENGE_NOT_FOUND",
+            Self::MethodNotFound(..) => "METHOD_NOT_FOUND",
+            Self::MethodIsMutable(..) => "METHOD_IS_MUTABLE",
+            Self::MethodIsImmutable(..) => "METHOD_IS_IMMUTABLE",
+            Self::MethodArgumentsNotFound(..) => "METHOD_ARGUMENTS_NOT_FOUND",
+            Self::InvalidInput(..) => "INVALID_INPUT",
+            Self::InputLimit(inner) => match inner {
+                zinc_types::ValueLimitError::TooManyValues { .. } => "INPUT_TOO_LARGE",
+                zinc_types::ValueLimitError::TooDeep { .. } => "INPUT_TOO_DEEP",
+            },
+            Self::InputTemplate(..) => "INPUT_TEMPLATE_MISMATCH",
+            Self::ContractSourceCodeMismatch => "CONTRACT_SOURCE_CODE_MISMATCH",
+            Self::ContractNameMismatch { .. } => "CONTRACT_NAME_MISMATCH",
+            Self::Upgrade(inner) => match inner {
+                zinc_types::ContractUpgradeError::StorageLayoutMismatch { .. } => {
+                    "STORAGE_LAYOUT_MISMATCH"
+                }
+                zinc_types::ContractUpgradeError::MigrationNotFound(..) => "MIGRATION_NOT_FOUND",
+                zinc_types::ContractUpgradeError::MigrationArgumentType { .. } => {
+                    "MIGRATION_ARGUMENT_TYPE"
+                }
+            },
+            Self::Unauthorized => "UNAUTHORIZED",
+            Self::SignatureMismatch => "SIGNATURE_MISMATCH",
+            Self::OwnerMismatch { .. } => "OWNER_MISMATCH",
+            Self::KeyTooLarge { .. } => "KEY_TOO_LARGE",
+            Self::RateLimited { .. } => "RATE_LIMITED",
+            Self::CircuitHashMismatch { .. } => "CIRCUIT_HASH_MISMATCH",
+
+            Self::TokenNotFound(..) => "TOKEN_NOT_FOUND",
+            Self::TokenNotAllowed(..) => "TOKEN_NOT_ALLOWED",
+            Self::Transaction(..) => "INVALID_TRANSACTION",
+            Self::TransferFailure(..) => "TRANSFER_FAILURE",
+            Self::AccountIdNotFound => "ACCOUNT_ID_NOT_FOUND",
+            Self::ChangePubkey(..) => "CHANGE_PUBKEY_FAILURE",
+
+            Self::VirtualMachine(..) => "VIRTUAL_MACHINE",
+            Self::Database(inner) => match inner {
+                DatabaseError::NotFound { .. } => "NOT_FOUND",
+                DatabaseError::AlreadyExists { .. } => "ALREADY_EXISTS",
+                DatabaseError::Other(_) => "DATABASE",
+            },
+            Self::ZkSyncClient(..) => "ZKSYNC_CLIENT",
+            Self::ZkSyncSigner(..) => "ZKSYNC_SIGNER",
+        }
+    }
+
+    ///
+    /// Returns the optional structured error details, which are sent to the client.
+    ///
+    pub fn details(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::ContractNotFound(address) => Some(serde_json::json!({ "address": address })),
+            Self::ContractPruned(original) => Some(serde_json::json!({ "original": original })),
+            Self::ContractArchived { archived_at } => {
+                Some(serde_json::json!({ "archived_at": archived_at }))
+            }
+            Self::MethodNotFound(name)
+            | Self::MethodIsMutable(name)
+            | Self::MethodIsImmutable(name)
+            | Self::MethodArgumentsNotFound(name) => Some(serde_json::json!({ "method": name })),
+            Self::ContractNameMismatch { expected, found }
+            | Self::OwnerMismatch { expected, found } => {
+                Some(serde_json::json!({ "expected": expected, "found": found }))
+            }
+            Self::Upgrade(zinc_types::ContractUpgradeError::StorageLayoutMismatch {
+                previous,
+                current,
+                diff,
+            }) => Some(serde_json::json!({
+                "previous": previous,
+                "current": current,
+                "diff": diff.lines().collect::<Vec<&str>>(),
+            })),
+            Self::Upgrade(zinc_types::ContractUpgradeError::MigrationNotFound(name)) => {
+                Some(serde_json::json!({ "method": name }))
+            }
+            Self::Upgrade(zinc_types::ContractUpgradeError::MigrationArgumentType {
+                method,
+                expected,
+                found,
+            }) => Some(serde_json::json!({
+                "method": method,
+                "expected": expected,
+                "found": found,
+            })),
+            Self::InputLimit(zinc_types::ValueLimitError::TooManyValues { path, limit })
+            | Self::InputLimit(zinc_types::ValueLimitError::TooDeep { path, limit }) => {
+                Some(serde_json::json!({ "path": path, "limit": limit }))
+            }
+            Self::InputTemplate(violations) => {
+                Some(serde_json::json!({ "violations": violations }))
+            }
+            Self::StorageLeafLimit { leaves, limit } => Some(serde_json::json!({
+                "leaves": leaves,
+                "limit": limit,
+            })),
+            Self::KeyTooLarge { key, size, limit } => Some(serde_json::json!({
+                "key": key,
+                "size": size,
+                "limit": limit,
+            })),
+            Self::RateLimited { retry_after } => {
+                Some(serde_json::json!({ "retry_after": retry_after }))
+            }
+            Self::CircuitHashMismatch {
+                key,
+                expected,
+                found,
+            } => Some(serde_json::json!({
+                "key": key,
+                "expected": expected,
+                "found": found,
+            })),
+            Self::TokenNotFound(token) | Self::TokenNotAllowed(token) => {
+                Some(serde_json::json!({ "token": token }))
+            }
+            Self::Database(DatabaseError::NotFound { entity })
+            | Self::Database(DatabaseError::AlreadyExists { entity }) => {
+                Some(serde_json::json!({ "entity": entity }))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl From<zinc_types::TransactionError> for Error {
     fn from(inner: zinc_types::TransactionError) -> Self {
         Self::Transaction(inner)
@@ -111,18 +330,40 @@ impl From<zksync_eth_signer::error::SignerError> for Error {
 impl ResponseError for Error {
     fn status_code(&self) -> StatusCode {
         match self {
+            Self::InvalidJson(..) => StatusCode::BAD_REQUEST,
+            Self::InvalidQuery(..) => StatusCode::BAD_REQUEST,
+
             Self::InvalidBytecode(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::NotAContract => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::ContractPruned(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::StorageLeafLimit { .. } => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ConstructorNotFound => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::InitialStorageForbidden => StatusCode::UNPROCESSABLE_ENTITY,
             Self::ContractNotFound(..) => StatusCode::NOT_FOUND,
+            Self::ContractArchived { .. } => StatusCode::GONE,
+            Self::ChallengeNotFound => StatusCode::BAD_REQUEST,
             Self::MethodNotFound(..) => StatusCode::NOT_FOUND,
             Self::MethodIsMutable(..) => StatusCode::BAD_REQUEST,
             Self::MethodIsImmutable(..) => StatusCode::BAD_REQUEST,
             Self::MethodArgumentsNotFound(..) => StatusCode::BAD_REQUEST,
             Self::InvalidInput(..) => StatusCode::BAD_REQUEST,
+            Self::InputLimit(inner) => match inner {
+                zinc_types::ValueLimitError::TooManyValues { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+                zinc_types::ValueLimitError::TooDeep { .. } => StatusCode::BAD_REQUEST,
+            },
+            Self::InputTemplate(..) => StatusCode::BAD_REQUEST,
             Self::ContractSourceCodeMismatch => StatusCode::BAD_REQUEST,
+            Self::ContractNameMismatch { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Upgrade(..) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::SignatureMismatch => StatusCode::BAD_REQUEST,
+            Self::OwnerMismatch { .. } => StatusCode::FORBIDDEN,
+            Self::KeyTooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::CircuitHashMismatch { .. } => StatusCode::UNPROCESSABLE_ENTITY,
 
             Self::TokenNotFound(..) => StatusCode::NOT_FOUND,
+            Self::TokenNotAllowed(..) => StatusCode::UNPROCESSABLE_ENTITY,
             Self::TransferFailure { .. } => StatusCode::SERVICE_UNAVAILABLE,
             Self::AccountIdNotFound => StatusCode::SERVICE_UNAVAILABLE,
             Self::ChangePubkey(..) => StatusCode::SERVICE_UNAVAILABLE,
@@ -192,6 +433,14 @@ impl ResponseError for Error {
             },
         }
     }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let Self::RateLimited { retry_after } = self {
+            response.header(header::RETRY_AFTER, retry_after.to_string());
+        }
+        response.json(zinc_types::ErrorResponseBody::from(self))
+    }
 }
 
 impl serde::Serialize for Error {
@@ -199,19 +448,39 @@ impl serde::Serialize for Error {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(self.to_string().as_str())
+        serde::Serialize::serialize(&zinc_types::ErrorResponseBody::from(self), serializer)
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let error = match self {
+            Self::InvalidJson(inner) => format!("Invalid JSON body: {}", inner),
+            Self::InvalidQuery(inner) => format!("Invalid query string: {}", inner),
+
             Self::InvalidBytecode(inner) => format!("Invalid bytecode: {}", inner),
             Self::NotAContract => "Not a contract".to_owned(),
+            Self::ContractPruned(original) => format!(
+                "The contract has been pruned from the circuit {}, only the complete artifacts can be published",
+                original
+            ),
+            Self::StorageLeafLimit { leaves, limit } => format!(
+                "The contract storage has {} leaves, which exceeds the limit of {}",
+                leaves, limit
+            ),
             Self::ConstructorNotFound => "Constructor not found".to_owned(),
+            Self::InitialStorageForbidden => {
+                "Initial storage is forbidden for contracts with a constructor".to_owned()
+            }
             Self::ContractNotFound(address) => {
                 format!("Contract with address {} not found", address)
             }
+            Self::ContractArchived { archived_at } => {
+                format!("The contract has been archived at {}", archived_at)
+            }
+            Self::ChallengeNotFound => {
+                "The delete challenge is missing, expired, or already used".to_owned()
+            }
             Self::MethodNotFound(name) => format!("Method `{}` not found", name),
             Self::MethodIsMutable(name) => {
                 format!("Method `{}` is mutable: use 'call' instead", name)
@@ -223,11 +492,48 @@ impl fmt::Display for Error {
                 format!("Method `{}` arguments are not specified", name)
             }
             Self::InvalidInput(inner) => format!("Input: {}", inner),
+            Self::InputLimit(inner) => format!("Input: {}", inner),
+            Self::InputTemplate(violations) => format!(
+                "Input: {} value(s) do not match the method template",
+                violations.len()
+            ),
             Self::ContractSourceCodeMismatch => {
                 "Contract source code mismatch, consider increasing the project version".to_owned()
             }
+            Self::ContractNameMismatch { expected, found } => format!(
+                "Contract name mismatch: expected `{}`, found `{}`",
+                expected, found
+            ),
+            Self::Upgrade(inner) => format!("Upgrade: {}", inner),
+            Self::Unauthorized => "Unauthorized: a valid bearer token is required".to_owned(),
+            Self::SignatureMismatch => {
+                "The signature does not match the request data and owner".to_owned()
+            }
+            Self::OwnerMismatch { expected, found } => format!(
+                "The project is owned by {}, but the request is signed by {}",
+                expected, found
+            ),
+            Self::KeyTooLarge { key, size, limit } => format!(
+                "The {} key size {} exceeds the limit of {} bytes",
+                key, size, limit
+            ),
+            Self::RateLimited { retry_after } => format!(
+                "Too many requests: retry in {} seconds",
+                retry_after
+            ),
+            Self::CircuitHashMismatch {
+                key,
+                expected,
+                found,
+            } => format!(
+                "The {} key does not match the project bytecode: expected circuit hash `{}`, found `{}`",
+                key, expected, found
+            ),
 
             Self::TokenNotFound(token_id) => format!("Token ID {} cannot be resolved", token_id),
+            Self::TokenNotAllowed(symbol) => {
+                format!("Token {} is not accepted by the server", symbol)
+            }
             Self::Transaction(inner) => format!("Transaction: {}", inner),
             Self::TransferFailure(inner) => format!("Transfer failure: {}", inner),
             Self::AccountIdNotFound => "Could not get the contract account ID".to_owned(),
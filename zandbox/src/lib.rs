@@ -2,17 +2,26 @@
 //! The Zandbox server daemon library.
 //!
 
+pub(crate) mod challenge;
+pub(crate) mod config;
 pub(crate) mod contract;
 pub(crate) mod controller;
 pub(crate) mod database;
 pub(crate) mod error;
+pub(crate) mod rate_limit;
+pub(crate) mod request_id;
 pub(crate) mod response;
 pub(crate) mod shared_data;
 pub(crate) mod storage;
 
+pub use self::config::layer::Layer as ConfigLayer;
+pub use self::config::Config;
 pub use self::controller::configure;
+pub use self::controller::json_config;
+pub use self::controller::query_config;
 pub use self::database::client::Client as DatabaseClient;
 pub use self::error::Error;
+pub use self::request_id::Middleware as RequestIdMiddleware;
 pub use self::shared_data::SharedData;
 
 ///
@@ -0,0 +1,65 @@
+//!
+//! The Zandbox server daemon rate limit.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
+use std::time::Instant;
+
+///
+/// The fixed window rate limit, which counts the requests of each client IP address.
+///
+/// The windows are started by the first request of a client, and the expired ones are dropped
+/// when a new window is started, so the memory is bounded by the clients active in a window.
+///
+#[derive(Debug)]
+pub struct RateLimit {
+    /// The number of the requests allowed in a window.
+    limit: usize,
+    /// The window duration.
+    window: Duration,
+    /// The window start and the number of the requests made in it for each client.
+    clients: HashMap<IpAddr, (Instant, usize)>,
+}
+
+impl RateLimit {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(limit: usize, window: Duration) -> Self {
+        Self {
+            limit,
+            window,
+            clients: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Counts the request of the `client` made at `now`.
+    ///
+    /// If the limit is exceeded, returns the time left until the client window expires.
+    ///
+    pub fn check(&mut self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let window = self.window;
+        if let Some((start, count)) = self.clients.get_mut(&client) {
+            let elapsed = now.saturating_duration_since(*start);
+            if elapsed < window {
+                if *count >= self.limit {
+                    return Err(window - elapsed);
+                }
+
+                *count += 1;
+                return Ok(());
+            }
+        }
+
+        self.clients
+            .retain(|_client, (start, _count)| now.saturating_duration_since(*start) < window);
+        self.clients.insert(client, (now, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The Zandbox server daemon rate limit tests.
+//!
+
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::rate_limit::RateLimit;
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+fn client(index: u8) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(127, 0, 0, index))
+}
+
+#[test]
+fn ok_within_limit() {
+    let mut rate_limit = RateLimit::new(2, WINDOW);
+    let now = Instant::now();
+
+    assert_eq!(rate_limit.check(client(1), now), Ok(()));
+    assert_eq!(rate_limit.check(client(1), now), Ok(()));
+}
+
+#[test]
+fn ok_window_expired() {
+    let mut rate_limit = RateLimit::new(1, WINDOW);
+    let now = Instant::now();
+
+    assert_eq!(rate_limit.check(client(1), now), Ok(()));
+    assert_eq!(rate_limit.check(client(1), now + WINDOW), Ok(()));
+}
+
+#[test]
+fn ok_clients_counted_separately() {
+    let mut rate_limit = RateLimit::new(1, WINDOW);
+    let now = Instant::now();
+
+    assert_eq!(rate_limit.check(client(1), now), Ok(()));
+    assert_eq!(rate_limit.check(client(2), now), Ok(()));
+}
+
+#[test]
+fn error_limit_exceeded() {
+    let mut rate_limit = RateLimit::new(2, WINDOW);
+    let now = Instant::now();
+
+    assert_eq!(rate_limit.check(client(1), now), Ok(()));
+    assert_eq!(
+        rate_limit.check(client(1), now + Duration::from_secs(10)),
+        Ok(())
+    );
+    assert_eq!(
+        rate_limit.check(client(1), now + Duration::from_secs(15)),
+        Err(Duration::from_secs(45))
+    );
+}
@@ -0,0 +1,171 @@
+//!
+//! The Zandbox server daemon request ID middleware.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+use std::task::Context;
+use std::task::Poll;
+
+use actix_web::body::Body;
+use actix_web::body::MessageBody;
+use actix_web::body::ResponseBody;
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::http::HeaderName;
+use actix_web::http::HeaderValue;
+use actix_web::HttpMessage;
+use actix_web::HttpResponse;
+use futures::future;
+use tracing_futures::Instrument;
+
+use crate::error::Error;
+
+///
+/// The server-generated request ID, which is stored in the request extensions.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestId(uuid::Uuid);
+
+impl RequestId {
+    ///
+    /// Generates a random request ID.
+    ///
+    pub fn new() -> Self {
+        Self(uuid::Uuid::new_v4())
+    }
+}
+
+impl Default for RequestId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+///
+/// The request ID middleware factory.
+///
+/// Every request is given a random ID and handled within the `request` span carrying it, so the
+/// ID is printed with every log record of the request, including the virtual machine ones.
+/// The ID is echoed in the `X-Request-Id` response header and added to the error envelope.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Middleware;
+
+impl Middleware {
+    ///
+    /// Replaces the error envelope of the failed `response` with the one carrying the request `id`.
+    ///
+    /// The status and headers, e.g. the CORS ones set by the inner middleware, are kept.
+    ///
+    fn with_request_id<B>(response: ServiceResponse<B>, id: &RequestId) -> ServiceResponse<B> {
+        let body = match response
+            .response()
+            .error()
+            .and_then(|error| error.as_error::<Error>())
+        {
+            Some(error) => {
+                log::warn!("{}", error);
+                zinc_types::ErrorResponseBody::from(error).with_request_id(id.to_string())
+            }
+            None => return response,
+        };
+        let body = serde_json::to_string(&body).expect(zinc_const::panic::DATA_CONVERSION);
+
+        response.map_body(|_, _| ResponseBody::Other(Body::from(body)))
+    }
+}
+
+impl<S, B> Transform<S> for Middleware
+where
+    S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>
+        + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = MiddlewareService<S>;
+    type Future = future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        future::ok(MiddlewareService { service })
+    }
+}
+
+///
+/// The request ID middleware service.
+///
+pub struct MiddlewareService<S> {
+    /// The wrapped service.
+    service: S,
+}
+
+impl<S, B> Service for MiddlewareService<S>
+where
+    S: Service<Request = ServiceRequest, Response = ServiceResponse<B>, Error = actix_web::Error>
+        + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+{
+    type Request = ServiceRequest;
+    type Response = ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>>>>;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(context)
+    }
+
+    fn call(&mut self, request: ServiceRequest) -> Self::Future {
+        let id = RequestId::new();
+        request.extensions_mut().insert(id.clone());
+
+        let span = tracing::info_span!(
+            "request",
+            id = %id,
+            method = %request.method(),
+            path = %request.path(),
+        );
+        let http_request = request.request().clone();
+        let future = span.in_scope(|| self.service.call(request));
+
+        Box::pin(
+            async move {
+                let response = match future.await {
+                    Ok(response) => response,
+                    Err(error) => ServiceResponse::new(
+                        http_request,
+                        HttpResponse::from_error(error).into_body(),
+                    ),
+                };
+
+                let mut response = Middleware::with_request_id(response, &id);
+                response.headers_mut().insert(
+                    HeaderName::from_str(zinc_const::zandbox::REQUEST_ID_HEADER)
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    HeaderValue::from_str(id.to_string().as_str())
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                );
+
+                Ok(response)
+            }
+            .instrument(span),
+        )
+    }
+}
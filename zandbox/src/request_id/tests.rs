@@ -0,0 +1,90 @@
+//!
+//! The Zandbox server daemon request ID middleware tests.
+//!
+
+use actix_web::http::StatusCode;
+use actix_web::test;
+use actix_web::web;
+use actix_web::App;
+use actix_web::HttpResponse;
+
+use crate::error::Error;
+use crate::request_id::Middleware;
+
+///
+/// Returns the `X-Request-Id` header value of the `response`.
+///
+fn header<B>(response: &actix_web::dev::ServiceResponse<B>) -> String {
+    response
+        .headers()
+        .get(zinc_const::zandbox::REQUEST_ID_HEADER)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .to_str()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .to_owned()
+}
+
+#[actix_rt::test]
+async fn ok_header_echo() {
+    let mut service = test::init_service(
+        App::new()
+            .wrap(Middleware)
+            .route("/", web::get().to(|| async { HttpResponse::Ok().finish() })),
+    )
+    .await;
+
+    let first =
+        test::call_service(&mut service, test::TestRequest::get().uri("/").to_request()).await;
+    let second =
+        test::call_service(&mut service, test::TestRequest::get().uri("/").to_request()).await;
+    assert_eq!(first.status(), StatusCode::OK);
+
+    let first = header(&first);
+    assert_eq!(first.len(), 36);
+    assert_ne!(first, header(&second));
+}
+
+#[actix_rt::test]
+async fn error_envelope() {
+    let mut service = test::init_service(App::new().wrap(Middleware).route(
+        "/",
+        web::get().to(|| async { Err::<HttpResponse, Error>(Error::NotAContract) }),
+    ))
+    .await;
+
+    let response =
+        test::call_service(&mut service, test::TestRequest::get().uri("/").to_request()).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let request_id = header(&response);
+
+    let body: zinc_types::ErrorResponseBody = test::read_body_json(response).await;
+    assert_eq!(body.error.code, "NOT_A_CONTRACT");
+    assert_eq!(body.error.request_id, Some(request_id));
+}
+
+#[actix_rt::test]
+async fn error_extractor_envelope() {
+    let mut service = test::init_service(
+        App::new()
+            .wrap(Middleware)
+            .app_data(crate::query_config())
+            .route(
+                "/",
+                web::put().to(|_: web::Query<zinc_types::QueryRequestQuery>| async {
+                    HttpResponse::Ok().finish()
+                }),
+            ),
+    )
+    .await;
+
+    let request = test::TestRequest::put()
+        .uri("/?address=invalid")
+        .to_request();
+    let response = test::call_service(&mut service, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let request_id = header(&response);
+
+    let body: zinc_types::ErrorResponseBody = test::read_body_json(response).await;
+    assert_eq!(body.error.code, "INVALID_QUERY");
+    assert_eq!(body.error.request_id, Some(request_id));
+}
@@ -2,6 +2,9 @@
 //! The Zandbox server daemon response.
 //!
 
+#[cfg(test)]
+mod tests;
+
 use std::marker::PhantomData;
 
 use actix_web::http::StatusCode;
@@ -11,9 +14,14 @@ use actix_web::Responder;
 use futures::future;
 use serde::Serialize;
 
+use crate::error::Error;
+
 ///
 /// The Zandbox server daemon response.
 ///
+/// Successful responses are sent as `{ "data": ... }`. Failures are converted from the controller
+/// errors and sent as `{ "error": { "code": ..., "message": ..., "details": ... } }`.
+///
 #[derive(Debug, Serialize)]
 pub struct Response<T, E>
 where
@@ -27,6 +35,7 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     data: Option<T>,
     /// The unused error type parameter marker.
+    #[serde(skip_serializing)]
     _pd: PhantomData<E>,
 }
 
@@ -77,9 +86,14 @@ where
     type Future = future::Ready<Result<HttpResponse, E>>;
 
     fn respond_to(self, _: &HttpRequest) -> Self::Future {
-        future::ok(match self.data {
-            Some(data) => HttpResponse::build(self.code).json(data),
-            None => HttpResponse::new(self.code),
-        })
+        future::ok(
+            HttpResponse::build(self.code).json(zinc_types::DataResponseBody::new(self.data)),
+        )
+    }
+}
+
+impl From<&Error> for zinc_types::ErrorResponseBody {
+    fn from(error: &Error) -> Self {
+        Self::new(error.code().to_owned(), error.to_string(), error.details())
     }
 }
@@ -0,0 +1,437 @@
+//!
+//! The Zandbox server daemon response tests.
+//!
+
+use actix_web::http::header;
+use actix_web::http::StatusCode;
+use actix_web::test;
+use actix_web::web;
+use actix_web::App;
+use actix_web::HttpResponse;
+use actix_web::ResponseError;
+
+use crate::database::error::Error as DatabaseError;
+use crate::error::Error;
+use crate::response::Response;
+
+///
+/// Checks that the `error` converts into the common envelope with the expected code and status.
+///
+fn check(error: Error, status: StatusCode, code: &str) -> serde_json::Value {
+    assert_eq!(error.error_response().status(), status);
+
+    let body = serde_json::to_value(zinc_types::ErrorResponseBody::from(&error))
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    assert_eq!(body["error"]["code"], code);
+    assert_eq!(body["error"]["message"], error.to_string());
+    assert!(body["error"].get("request_id").is_none());
+    assert_eq!(
+        body.as_object()
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .len(),
+        1
+    );
+
+    body
+}
+
+#[test]
+fn data_envelope() {
+    let body = serde_json::to_value(zinc_types::DataResponseBody::new(
+        zinc_types::MetadataResponseBody::new(vec![]),
+    ))
+    .expect(zinc_const::panic::DATA_CONVERSION);
+
+    assert_eq!(body, serde_json::json!({ "data": { "projects": [] } }));
+}
+
+#[test]
+fn error_contract_publish() {
+    let body = check(
+        Error::NotAContract,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "NOT_A_CONTRACT",
+    );
+
+    assert!(body["error"].get("details").is_none());
+}
+
+#[test]
+fn error_contract_pruned() {
+    let body = check(
+        Error::ContractPruned("deadbeef".to_owned()),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "CONTRACT_PRUNED",
+    );
+
+    assert_eq!(body["error"]["details"]["original"], "deadbeef");
+}
+
+#[test]
+fn error_contract_curve() {
+    check(
+        Error::Database(DatabaseError::Other(sqlx::Error::PoolClosed)),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "DATABASE",
+    );
+}
+
+#[test]
+fn error_contract_initialize() {
+    let body = check(
+        Error::ContractNotFound("0x0000000000000000000000000000000000000000".to_owned()),
+        StatusCode::NOT_FOUND,
+        "CONTRACT_NOT_FOUND",
+    );
+
+    assert_eq!(
+        body["error"]["details"]["address"],
+        "0x0000000000000000000000000000000000000000"
+    );
+}
+
+#[test]
+fn error_contract_query() {
+    let body = check(
+        Error::MethodIsMutable("deposit".to_owned()),
+        StatusCode::BAD_REQUEST,
+        "METHOD_IS_MUTABLE",
+    );
+
+    assert_eq!(body["error"]["details"]["method"], "deposit");
+}
+
+#[test]
+fn error_contract_call() {
+    let body = check(
+        Error::MethodIsImmutable("get_balance".to_owned()),
+        StatusCode::BAD_REQUEST,
+        "METHOD_IS_IMMUTABLE",
+    );
+
+    assert_eq!(body["error"]["details"]["method"], "get_balance");
+}
+
+#[test]
+fn error_contract_call_input_too_large() {
+    let r#type = zinc_types::Type::Structure(vec![(
+        "recipients".to_owned(),
+        zinc_types::Type::Array(Box::new(zinc_types::Type::Address), 8),
+    )]);
+    let arguments = serde_json::json!({ "recipients": vec!["0x0"; 100_000] });
+
+    let error = zinc_types::ValueLimits::default()
+        .narrowed(&r#type)
+        .check(&arguments)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+    let body = check(
+        Error::InputLimit(error),
+        StatusCode::PAYLOAD_TOO_LARGE,
+        "INPUT_TOO_LARGE",
+    );
+
+    assert_eq!(body["error"]["details"]["path"], ".recipients");
+    assert_eq!(body["error"]["details"]["limit"], 8);
+}
+
+#[test]
+fn error_contract_query_input_too_deep() {
+    let mut arguments = serde_json::json!("0x0");
+    for _ in 0..100 {
+        arguments = serde_json::json!({ "inner": arguments });
+    }
+
+    let error = zinc_types::ValueLimits::new(1, 4)
+        .check(&arguments)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+    let body = check(
+        Error::InputLimit(error),
+        StatusCode::BAD_REQUEST,
+        "INPUT_TOO_DEEP",
+    );
+
+    assert_eq!(body["error"]["details"]["path"], ".inner.inner.inner.inner");
+    assert_eq!(body["error"]["details"]["limit"], 4);
+}
+
+#[test]
+fn error_contract_call_input_template_mismatch() {
+    let r#type = zinc_types::Type::Structure(vec![
+        (
+            "amount".to_owned(),
+            zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+        ),
+        (
+            "recipients".to_owned(),
+            zinc_types::Type::Array(Box::new(zinc_types::Type::Address), 2),
+        ),
+    ]);
+    let arguments = serde_json::json!({
+        "amount": "256",
+        "recipients": ["0x0"],
+        "memo": "hello",
+    });
+
+    let violations = zinc_types::ValueValidator::validate(&arguments, &r#type);
+    let body = check(
+        Error::InputTemplate(violations),
+        StatusCode::BAD_REQUEST,
+        "INPUT_TEMPLATE_MISMATCH",
+    );
+
+    let violations = body["error"]["details"]["violations"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(violations.len(), 3);
+    assert_eq!(
+        violations[0],
+        serde_json::json!({
+            "pointer": "/amount",
+            "expected": "u8",
+            "found": "string (\"256\")",
+        })
+    );
+    assert_eq!(violations[1]["pointer"], "/recipients");
+    assert_eq!(violations[2]["pointer"], "/memo");
+}
+
+#[test]
+fn error_contract_call_token_not_allowed() {
+    let body = check(
+        Error::TokenNotAllowed("DAI".to_owned()),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "TOKEN_NOT_ALLOWED",
+    );
+
+    assert_eq!(body["error"]["details"]["token"], "DAI");
+}
+
+#[test]
+fn error_contract_fee() {
+    let body = check(
+        Error::MethodNotFound("unknown".to_owned()),
+        StatusCode::NOT_FOUND,
+        "METHOD_NOT_FOUND",
+    );
+
+    assert_eq!(body["error"]["details"]["method"], "unknown");
+}
+
+#[test]
+fn error_contract_dry_run_require() {
+    check(
+        Error::VirtualMachine(zinc_vm::Error::RequireError(
+            "insufficient funds".to_owned(),
+        )),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "VIRTUAL_MACHINE",
+    );
+}
+
+#[test]
+fn error_contract_dry_run_rate_limited() {
+    let error = Error::RateLimited { retry_after: 42 };
+    assert_eq!(
+        error.error_response().headers().get(header::RETRY_AFTER),
+        Some(&header::HeaderValue::from_static("42"))
+    );
+
+    let body = check(error, StatusCode::TOO_MANY_REQUESTS, "RATE_LIMITED");
+
+    assert_eq!(body["error"]["details"]["retry_after"], 42);
+}
+
+#[test]
+fn error_contract_archived() {
+    let body = check(
+        Error::ContractArchived {
+            archived_at: "2021-03-20T12:00:00Z".to_owned(),
+        },
+        StatusCode::GONE,
+        "CONTRACT_ARCHIVED",
+    );
+
+    assert_eq!(
+        body["error"]["details"]["archived_at"],
+        "2021-03-20T12:00:00Z"
+    );
+}
+
+#[test]
+fn error_contract_remove_challenge() {
+    check(
+        Error::ChallengeNotFound,
+        StatusCode::BAD_REQUEST,
+        "CHALLENGE_NOT_FOUND",
+    );
+}
+
+#[test]
+fn error_contract_upgrade() {
+    let body = check(
+        Error::Upgrade(zinc_types::ContractUpgradeError::StorageLayoutMismatch {
+            previous: "aa".to_owned(),
+            current: "bb".to_owned(),
+            diff: "- value: u8\n+ value: u64".to_owned(),
+        }),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "STORAGE_LAYOUT_MISMATCH",
+    );
+
+    assert_eq!(body["error"]["details"]["previous"], "aa");
+    assert_eq!(body["error"]["details"]["current"], "bb");
+    assert_eq!(
+        body["error"]["details"]["diff"],
+        serde_json::json!(["- value: u8", "+ value: u64"])
+    );
+}
+
+#[test]
+fn error_project_metadata() {
+    check(
+        Error::Database(DatabaseError::Other(sqlx::Error::RowNotFound)),
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "DATABASE",
+    );
+}
+
+#[test]
+fn error_project_upload() {
+    let body = check(
+        Error::Database(DatabaseError::AlreadyExists {
+            entity: "Project".to_owned(),
+        }),
+        StatusCode::NOT_FOUND,
+        "ALREADY_EXISTS",
+    );
+
+    assert_eq!(body["error"]["details"]["entity"], "Project");
+}
+
+#[test]
+fn error_project_keys() {
+    let body = check(
+        Error::CircuitHashMismatch {
+            key: "verifying",
+            expected: "aa".to_owned(),
+            found: "bb".to_owned(),
+        },
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "CIRCUIT_HASH_MISMATCH",
+    );
+
+    assert_eq!(body["error"]["details"]["key"], "verifying");
+    assert_eq!(body["error"]["details"]["expected"], "aa");
+    assert_eq!(body["error"]["details"]["found"], "bb");
+
+    check(
+        Error::Unauthorized,
+        StatusCode::UNAUTHORIZED,
+        "UNAUTHORIZED",
+    );
+}
+
+#[test]
+fn error_project_source() {
+    let body = check(
+        Error::Database(DatabaseError::NotFound {
+            entity: "Project".to_owned(),
+        }),
+        StatusCode::NOT_FOUND,
+        "NOT_FOUND",
+    );
+
+    assert_eq!(body["error"]["details"]["entity"], "Project");
+}
+
+#[actix_rt::test]
+async fn error_json_extractor() {
+    let mut service =
+        test::init_service(App::new().app_data(crate::json_config()).route(
+            "/",
+            web::put().to(|_: web::Json<zinc_types::QueryRequestBody>| async {
+                HttpResponse::Ok().finish()
+            }),
+        ))
+        .await;
+
+    let request = test::TestRequest::put()
+        .uri("/")
+        .header("content-type", "application/json")
+        .set_payload("{ malformed")
+        .to_request();
+    let response = test::call_service(&mut service, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: zinc_types::ErrorResponseBody = test::read_body_json(response).await;
+    assert_eq!(body.error.code, "INVALID_JSON");
+}
+
+#[actix_rt::test]
+async fn error_query_extractor() {
+    let mut service = test::init_service(App::new().app_data(crate::query_config()).route(
+        "/",
+        web::put().to(|_: web::Query<zinc_types::QueryRequestQuery>| async {
+            HttpResponse::Ok().finish()
+        }),
+    ))
+    .await;
+
+    let request = test::TestRequest::put()
+        .uri("/?address=invalid")
+        .to_request();
+    let response = test::call_service(&mut service, request).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: zinc_types::ErrorResponseBody = test::read_body_json(response).await;
+    assert_eq!(body.error.code, "INVALID_QUERY");
+}
+
+#[actix_rt::test]
+async fn data_key_order() {
+    let mut service = test::init_service(App::new().route(
+        "/",
+        web::get().to(|| async {
+            let storage = zinc_types::Value::Contract(vec![
+                zinc_types::ContractFieldValue::new(
+                    "zeta".to_owned(),
+                    zinc_types::Value::Scalar(zinc_types::ScalarValue::Boolean(true)),
+                    true,
+                    false,
+                ),
+                zinc_types::ContractFieldValue::new(
+                    "alpha".to_owned(),
+                    zinc_types::Value::Scalar(zinc_types::ScalarValue::Boolean(false)),
+                    true,
+                    false,
+                ),
+            ]);
+
+            Response::<serde_json::Value, Error>::new_with_data(StatusCode::OK, storage.into_json())
+        }),
+    ))
+    .await;
+
+    let request = test::TestRequest::get().uri("/").to_request();
+    let body = test::read_response(&mut service, request).await;
+
+    assert_eq!(
+        body.as_ref(),
+        br#"{"data":{"zeta":true,"alpha":false}}"#.as_ref()
+    );
+}
+
+#[test]
+fn error_storage_leaf_limit() {
+    let body = check(
+        Error::StorageLeafLimit {
+            leaves: 70000,
+            limit: 65536,
+        },
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "STORAGE_LEAF_LIMIT_EXCEEDED",
+    );
+
+    assert_eq!(body["error"]["details"]["leaves"], 70000);
+    assert_eq!(body["error"]["details"]["limit"], 65536);
+}
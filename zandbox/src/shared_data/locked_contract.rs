@@ -35,6 +35,8 @@ pub struct LockedContract {
     pub bytecode: Vec<u8>,
     /// The project verifying key.
     pub verifying_key: Vec<u8>,
+    /// The project owner address, which has signed the publishing request.
+    pub owner: zksync_types::Address,
 
     /// The pre-built contract ready to be called.
     pub build: zinc_types::Contract,
@@ -61,11 +63,13 @@ impl LockedContract {
         version: semver::Version,
         instance: String,
 
-        arguments: serde_json::Value,
+        arguments: Option<serde_json::Value>,
+        storage: Option<serde_json::Value>,
 
         project: zinc_project::Project,
         bytecode: Vec<u8>,
         verifying_key: Vec<u8>,
+        owner: zksync_types::Address,
 
         change_pubkey_fee_token: String,
     ) -> Result<Self, Error> {
@@ -82,43 +86,49 @@ impl LockedContract {
             zinc_types::Application::Contract(contract) => contract,
             zinc_types::Application::Library(_library) => return Err(Error::NotAContract),
         };
-        let constructor = build
+        crate::controller::check_pruned(&build)?;
+        crate::controller::check_storage(&build)?;
+        let storage = match build
             .methods
             .get(zinc_const::contract::CONSTRUCTOR_IDENTIFIER)
             .cloned()
-            .ok_or(Error::ConstructorNotFound)?;
-        let input_value = zinc_types::Value::try_from_typed_json(arguments, constructor.input)
-            .map_err(Error::InvalidInput)?;
-
-        let mut storages = HashMap::with_capacity(1);
-        storages.insert(
-            eth_address,
-            Storage::new(build.storage.as_slice()).into_build(),
-        );
-
-        let vm_runner = zinc_vm::ContractFacade::new(build.clone());
-        let mut output = tokio::task::spawn_blocking(move || {
-            vm_runner.run::<Bn256>(ContractInput::new(
-                input_value,
-                storages,
-                zinc_const::contract::CONSTRUCTOR_IDENTIFIER.to_owned(),
-                zinc_types::TransactionMsg::default(),
-            ))
-        })
-        .await
-        .expect(zinc_const::panic::ASYNC_RUNTIME)
-        .map_err(Error::VirtualMachine)?;
-        let address = output
-            .result
-            .into_flat_values()
-            .first()
-            .cloned()
-            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
-        let storage = output
-            .storages
-            .remove(&address)
-            .map(Storage::from_build)
-            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+        {
+            Some(constructor) => {
+                if storage.is_some() {
+                    return Err(Error::InitialStorageForbidden);
+                }
+
+                let arguments = arguments.ok_or_else(|| {
+                    Error::MethodArgumentsNotFound(
+                        zinc_const::contract::CONSTRUCTOR_IDENTIFIER.to_owned(),
+                    )
+                })?;
+                let input_value =
+                    zinc_types::Value::try_from_typed_json(arguments, constructor.input)
+                        .map_err(Error::InvalidInput)?;
+
+                Self::construct(build.clone(), eth_address, input_value).await?
+            }
+            None => {
+                if arguments.is_some() {
+                    return Err(Error::ConstructorNotFound);
+                }
+
+                let storage = storage.unwrap_or_else(|| {
+                    serde_json::Value::Array(
+                        build
+                            .storage
+                            .iter()
+                            .map(|field| {
+                                zinc_types::Value::new(field.r#type.to_owned()).into_json()
+                            })
+                            .collect(),
+                    )
+                });
+                Storage::try_from_json(build.storage.as_slice(), eth_address, storage)
+                    .map_err(Error::InvalidInput)?
+            }
+        };
 
         let provider = zksync::RpcProvider::new(network);
         let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
@@ -159,6 +169,7 @@ impl LockedContract {
             project,
             bytecode,
             verifying_key,
+            owner,
 
             build,
             storage,
@@ -168,4 +179,50 @@ impl LockedContract {
             change_pubkey_fee,
         })
     }
+
+    ///
+    /// Runs the contract constructor `new` on the VM and returns the initial contract storage.
+    ///
+    /// The constructor `require` failures are returned as the VM errors.
+    ///
+    async fn construct(
+        build: zinc_types::Contract,
+        eth_address: zksync_types::Address,
+        input_value: zinc_types::Value,
+    ) -> Result<Storage, Error> {
+        let mut storages = HashMap::with_capacity(1);
+        storages.insert(
+            eth_address,
+            Storage::new(build.storage.as_slice()).into_build(),
+        );
+
+        let vm_runner = zinc_vm::ContractFacade::new(build);
+        let span = tracing::info_span!("vm", method = zinc_const::contract::CONSTRUCTOR_IDENTIFIER);
+        let mut output = tokio::task::spawn_blocking(move || {
+            span.in_scope(|| {
+                vm_runner.run::<Bn256>(ContractInput::new(
+                    input_value,
+                    storages,
+                    zinc_const::contract::CONSTRUCTOR_IDENTIFIER.to_owned(),
+                    zinc_types::TransactionMsg::default(),
+                ))
+            })
+        })
+        .await
+        .expect(zinc_const::panic::ASYNC_RUNTIME)
+        .map_err(Error::VirtualMachine)?;
+        let address = output
+            .result
+            .into_flat_values()
+            .first()
+            .cloned()
+            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+        let storage = output
+            .storages
+            .remove(&address)
+            .map(Storage::from_build)
+            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+
+        Ok(storage)
+    }
 }
@@ -6,10 +6,14 @@ pub mod locked_contract;
 
 use std::collections::HashMap;
 use std::sync::RwLock;
+use std::time::Duration;
 
 use actix_web::web::Data;
 
+use crate::challenge::ChallengeStore;
+use crate::config::Config;
 use crate::database::client::Client as DatabaseClient;
+use crate::rate_limit::RateLimit;
 
 use self::locked_contract::LockedContract;
 
@@ -19,10 +23,14 @@ use self::locked_contract::LockedContract;
 pub struct SharedData {
     /// The PostgreSQL asynchronous client.
     pub postgresql: DatabaseClient,
-    /// The zkSync network identifier.
-    pub network: zksync::Network,
+    /// The server configuration.
+    pub config: Config,
     /// The contracts waiting to be unlocked by `initialize` endpoint.
     pub locked_contracts: HashMap<zksync_types::Address, LockedContract>,
+    /// The `dry-run` endpoint rate limit.
+    pub dry_run_rate_limit: RateLimit,
+    /// The challenges issued by the `delete-challenge` endpoint.
+    pub delete_challenges: ChallengeStore,
 }
 
 impl SharedData {
@@ -31,11 +39,20 @@ impl SharedData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(postgresql: DatabaseClient, network: zksync::Network) -> Self {
+    pub fn new(postgresql: DatabaseClient, config: Config) -> Self {
+        let dry_run_rate_limit = RateLimit::new(
+            config.dry_run_rate_limit,
+            Duration::from_secs(zinc_const::zandbox::DRY_RUN_RATE_LIMIT_WINDOW_SECONDS),
+        );
+
         Self {
             postgresql,
-            network,
+            config,
             locked_contracts: HashMap::with_capacity(Self::LOCKED_CONTRACTS_INITIAL_CAPACITY),
+            dry_run_rate_limit,
+            delete_challenges: ChallengeStore::new(Duration::from_secs(
+                zinc_const::zandbox::DELETE_CHALLENGE_LIFETIME_SECONDS,
+            )),
         }
     }
 
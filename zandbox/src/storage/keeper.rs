@@ -50,12 +50,10 @@ impl zinc_vm::IContractStorageKeeper for Keeper {
             )
             .map_err(|error| match error {
                 DatabaseError::NotFound { .. } => zinc_vm::Error::ContractNotFound {
-                    address: serde_json::to_string(&eth_address)
-                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    address: zinc_types::scalar_format::bytes(eth_address.as_bytes()),
                 },
                 DatabaseError::AlreadyExists { .. } => zinc_vm::Error::ContractAlreadyExists {
-                    address: serde_json::to_string(&eth_address)
-                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    address: zinc_types::scalar_format::bytes(eth_address.as_bytes()),
                 },
                 DatabaseError::Other(other) => zinc_vm::Error::DatabaseError(other),
             })?;
@@ -67,12 +65,10 @@ impl zinc_vm::IContractStorageKeeper for Keeper {
             ))
             .map_err(|error| match error {
                 DatabaseError::NotFound { .. } => zinc_vm::Error::ContractNotFound {
-                    address: serde_json::to_string(&eth_address)
-                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    address: zinc_types::scalar_format::bytes(eth_address.as_bytes()),
                 },
                 DatabaseError::AlreadyExists { .. } => zinc_vm::Error::ContractAlreadyExists {
-                    address: serde_json::to_string(&eth_address)
-                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    address: zinc_types::scalar_format::bytes(eth_address.as_bytes()),
                 },
                 DatabaseError::Other(other) => zinc_vm::Error::DatabaseError(other),
             })?;
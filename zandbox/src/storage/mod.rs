@@ -2,9 +2,20 @@
 //! The Zandbox server daemon contract storage utils.
 //!
 
+#[cfg(test)]
+pub(crate) mod tests;
+
 pub mod keeper;
 
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use zinc_vm::Bn256;
+use zinc_vm::ContractInput;
+
 use crate::database::model;
+use crate::error::Error;
 
 ///
 /// The Zandbox contract storage wrapper.
@@ -34,6 +45,53 @@ impl Storage {
         Self { fields }
     }
 
+    ///
+    /// Populates the storage with the initial JSON data, which is the array of all the
+    /// contract storage fields, as in the `storages` section of the input template.
+    ///
+    /// The implicit fields are ignored. The `address` field is set to `address`, and the
+    /// `balances` field is left empty.
+    ///
+    pub fn try_from_json(
+        types: &[zinc_types::ContractFieldType],
+        address: zksync_types::Address,
+        value: serde_json::Value,
+    ) -> anyhow::Result<Self> {
+        let values = match value {
+            serde_json::Value::Array(values) => values,
+            value => anyhow::bail!("expected an array of storage fields, found `{}`", value),
+        };
+        if values.len() != types.len() {
+            anyhow::bail!(
+                "expected {} storage fields, found {}",
+                types.len(),
+                values.len()
+            );
+        }
+
+        let mut storage = Self::new(types);
+
+        storage.fields[zinc_const::contract::FIELD_INDEX_ADDRESS].value =
+            zinc_types::Value::try_from_typed_json(
+                serde_json::to_value(address).expect(zinc_const::panic::DATA_CONVERSION),
+                types[zinc_const::contract::FIELD_INDEX_ADDRESS]
+                    .r#type
+                    .to_owned(),
+            )
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        for (index, value) in values
+            .into_iter()
+            .enumerate()
+            .skip(zinc_const::contract::IMPLICIT_FIELDS_COUNT)
+        {
+            storage.fields[index].value =
+                zinc_types::Value::try_from_typed_json(value, types[index].r#type.to_owned())?;
+        }
+
+        Ok(storage)
+    }
+
     ///
     /// Populates the storage with the database data and data from other sources.
     ///
@@ -103,6 +161,86 @@ impl Storage {
         Ok(Self { fields })
     }
 
+    ///
+    /// Runs the `#[upgrade]` `method` of the new contract version `build` on the VM, passing
+    /// the explicit fields of the storage as the method argument.
+    ///
+    /// The implicit fields, that is, the contract address and balances, are preserved.
+    ///
+    /// The VM is run synchronously, so the caller must move the call to a blocking task.
+    ///
+    pub fn migrate(
+        self,
+        build: zinc_types::Contract,
+        method: zinc_types::ContractMethod,
+        eth_address: zksync_types::Address,
+    ) -> Result<Self, Error> {
+        let argument_name = match method.input {
+            zinc_types::Type::Structure(ref fields) => {
+                fields.first().map(|(name, _type)| name.to_owned())
+            }
+            _ => None,
+        }
+        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let mut fields = self.fields;
+        let explicit_fields = fields.split_off(zinc_const::contract::IMPLICIT_FIELDS_COUNT);
+        let implicit_fields = fields;
+
+        let previous: serde_json::Map<String, serde_json::Value> = explicit_fields
+            .into_iter()
+            .map(|field| (field.name, field.value.into_json()))
+            .collect();
+        let mut arguments = serde_json::Map::with_capacity(1);
+        arguments.insert(argument_name, serde_json::Value::Object(previous));
+        let input_value = zinc_types::Value::try_from_typed_json(
+            serde_json::Value::Object(arguments),
+            method.input,
+        )
+        .map_err(Error::InvalidInput)?;
+
+        let mut storages = HashMap::with_capacity(1);
+        storages.insert(
+            eth_address,
+            Self::new(build.storage.as_slice()).into_build(),
+        );
+
+        let mut output = zinc_vm::ContractFacade::new(build)
+            .run::<Bn256>(ContractInput::new(
+                input_value,
+                storages,
+                method.name,
+                zinc_types::TransactionMsg::default(),
+            ))
+            .map_err(Error::VirtualMachine)?;
+        let address = output
+            .result
+            .into_flat_values()
+            .first()
+            .cloned()
+            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+        let mut storage = output
+            .storages
+            .remove(&address)
+            .map(Self::from_build)
+            .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
+
+        for (index, field) in implicit_fields.into_iter().enumerate() {
+            storage.fields[index] = field;
+        }
+
+        Ok(storage)
+    }
+
+    ///
+    /// Appends the `fields` added by the new contract version, initializing them with the
+    /// default values.
+    ///
+    pub fn append(mut self, fields: &[zinc_types::ContractFieldType]) -> Self {
+        self.fields.extend(Self::new(fields).fields);
+        self
+    }
+
     ///
     /// The build type adapter.
     ///
@@ -158,6 +296,81 @@ impl Storage {
             .collect()
     }
 
+    ///
+    /// Converts the storage into the typed JSON fields of the page, which contains the map
+    /// entries from `offset` to `offset + limit`, whereas the other fields are returned in full.
+    ///
+    pub fn into_page(
+        self,
+        types: &[zinc_types::ContractFieldType],
+        offset: usize,
+        limit: usize,
+    ) -> Vec<zinc_types::StorageResponseField> {
+        self.fields
+            .into_iter()
+            .zip(types.iter())
+            .map(|(field, r#type)| match field.value {
+                zinc_types::Value::Map(entries) => {
+                    let total = entries.len();
+                    let entries = entries.into_iter().skip(offset).take(limit).collect();
+                    zinc_types::StorageResponseField::new(
+                        field.name,
+                        r#type.r#type.to_string(),
+                        zinc_types::Value::Map(entries).into_json(),
+                        Some(total),
+                    )
+                }
+                value => zinc_types::StorageResponseField::new(
+                    field.name,
+                    r#type.r#type.to_string(),
+                    value.into_json(),
+                    None,
+                ),
+            })
+            .collect()
+    }
+
+    ///
+    /// Lists the public fields, which differ in the `after` storage, in the typed JSON format.
+    ///
+    /// The private fields are skipped, since they are not exposed to the clients.
+    /// Only the inserted, removed, and changed entries are listed for the map fields.
+    ///
+    pub fn diff(
+        &self,
+        after: &Self,
+        types: &[zinc_types::ContractFieldType],
+    ) -> Vec<zinc_types::DryRunResponseChange> {
+        self.fields
+            .iter()
+            .zip(after.fields.iter())
+            .zip(types.iter())
+            .filter(|((before, _after), _type)| before.is_public)
+            .filter_map(|((before, after), r#type)| {
+                let (before, after) = match (&before.value, &after.value) {
+                    (zinc_types::Value::Map(before), zinc_types::Value::Map(after)) => (
+                        Self::map_entries_missing(before, after).into_json(),
+                        Self::map_entries_missing(after, before).into_json(),
+                    ),
+                    (before, after) => {
+                        (before.to_owned().into_json(), after.to_owned().into_json())
+                    }
+                };
+
+                if before == after {
+                    return None;
+                }
+
+                Some(zinc_types::DryRunResponseChange::new(
+                    r#type.name.to_owned(),
+                    r#type.r#type.to_string(),
+                    before,
+                    after,
+                ))
+            })
+            .collect()
+    }
+
     ///
     /// Wraps the fields with the VM value type.
     ///
@@ -165,6 +378,23 @@ impl Storage {
         zinc_types::Value::Contract(self.fields)
     }
 
+    ///
+    /// Computes the storage Merkle tree root hash of the contract `build`.
+    ///
+    pub fn root_hash(&self, build: zinc_types::Contract) -> Result<String, Error> {
+        zinc_vm::ContractFacade::new(build)
+            .storage_root_hash(self.to_owned().into_build())
+            .map(|root_hash| Self::root_hash_to_hex(&root_hash))
+            .map_err(Error::VirtualMachine)
+    }
+
+    ///
+    /// Formats the storage root hash as a zero-padded hexadecimal string.
+    ///
+    pub fn root_hash_to_hex(root_hash: &BigInt) -> String {
+        zinc_types::scalar_format::hash(root_hash)
+    }
+
     ///
     /// Wraps the fields with the VM value type, filtering out the private fields.
     ///
@@ -176,4 +406,29 @@ impl Storage {
                 .collect(),
         )
     }
+
+    ///
+    /// Collects the `entries` missing in `other` into a map value. The entry with the key present
+    /// in `other`, but with another value, is also considered missing.
+    ///
+    fn map_entries_missing(
+        entries: &[(zinc_types::Value, zinc_types::Value)],
+        other: &[(zinc_types::Value, zinc_types::Value)],
+    ) -> zinc_types::Value {
+        let other: Vec<(serde_json::Value, serde_json::Value)> = other
+            .iter()
+            .map(|(key, value)| (key.to_owned().into_json(), value.to_owned().into_json()))
+            .collect();
+
+        zinc_types::Value::Map(
+            entries
+                .iter()
+                .filter(|(key, value)| {
+                    let entry = (key.to_owned().into_json(), value.to_owned().into_json());
+                    !other.contains(&entry)
+                })
+                .cloned()
+                .collect(),
+        )
+    }
 }
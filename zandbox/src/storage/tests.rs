@@ -0,0 +1,514 @@
+//!
+//! The Zandbox server daemon contract storage tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::thread;
+
+use num::BigInt;
+
+use zinc_compiler::EntryAnalyzer;
+use zinc_compiler::IBytecodeWritable;
+use zinc_compiler::Source;
+use zinc_compiler::ZincVMState;
+
+use crate::storage::Storage;
+
+const STORAGE_V1: &str = r#"
+contract Test {
+    pub value: u64;
+    pub total: u64;
+
+    pub fn add(mut self, value: u64) {
+        self.value += value;
+        self.total += value;
+    }
+}
+"#;
+
+const STORAGE_V1_PATCHED: &str = r#"
+contract Test {
+    pub value: u64;
+    pub total: u64;
+
+    pub fn add(mut self, value: u64) {
+        self.value = value;
+        self.total += value;
+    }
+}
+"#;
+
+const STORAGE_V2_APPENDED: &str = r#"
+contract Test {
+    pub value: u64;
+    pub total: u64;
+    pub count: u8;
+}
+"#;
+
+const STORAGE_V2_REORDERED: &str = r#"
+contract Test {
+    pub total: u64;
+    pub value: u64;
+}
+"#;
+
+const STORAGE_V2_RETYPED: &str = r#"
+contract Test {
+    pub value: u64;
+    pub total: u128;
+    pub count: u8;
+}
+"#;
+
+const STORAGE_V2: &str = r#"
+struct StorageV1 {
+    value: u64,
+    total: u64,
+}
+
+contract Test {
+    pub count: u8;
+    pub total: u64;
+    pub average: u64;
+
+    #[upgrade]
+    pub fn migrate(old: StorageV1) -> Self {
+        Self {
+            count: 2,
+            total: old.total,
+            average: old.total / 2,
+        }
+    }
+}
+"#;
+
+const STORAGE_MAP: &str = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    pub values: [u8; 3];
+    entries: MTreeMap<u8, u64>;
+}
+"#;
+
+const STORAGE_NESTED: &str = r#"
+struct Order {
+    id: u8,
+    amount: u64,
+    flags: [bool; 2],
+}
+
+contract Test {
+    pub orders: [Order; 4];
+    pub count: u8;
+}
+"#;
+
+const STORAGE_DRY_RUN: &str = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    pub value: u64;
+    pub entries: MTreeMap<u8, u64>;
+    count: u8;
+
+    pub fn add(mut self, key: u8, value: u64) -> u64 {
+        require(value > 0, "The value must be positive");
+
+        self.value += value;
+        self.entries.insert(key, value);
+        self.count += 1;
+
+        self.value
+    }
+}
+"#;
+
+///
+/// Compiles the contract `code` into the bytecode application.
+///
+pub(crate) fn compile(code: &'static str) -> zinc_types::Contract {
+    thread::Builder::new()
+        .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+        .spawn(move || {
+            let source = Source::test(code, PathBuf::from("test.zn"), HashMap::new())
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+            let project = zinc_project::ManifestProject::new(
+                "test".to_owned(),
+                zinc_project::ProjectType::Contract,
+                semver::Version::new(1, 0, 0),
+            );
+            let scope = EntryAnalyzer::define(source, project, HashMap::new(), false)
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            let state = ZincVMState::new(zinc_project::Manifest::new(
+                "test",
+                zinc_project::ProjectType::Contract,
+            ))
+            .wrap();
+            zinc_compiler::Module::new(scope.borrow().get_intermediate())
+                .write_to_zinc_vm(state.clone());
+
+            match ZincVMState::unwrap_rc(state).into_application(true, None) {
+                zinc_types::Application::Contract(contract) => contract,
+                _ => panic!(zinc_const::panic::TEST_DATA_VALID),
+            }
+        })
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+}
+
+#[test]
+fn ok_upgrade_same_layout() {
+    let previous = compile(STORAGE_V1);
+    let current = compile(STORAGE_V1_PATCHED);
+
+    assert_eq!(current.storage_layout, previous.storage_layout);
+    assert!(matches!(
+        current.migration(&previous),
+        Ok(zinc_types::ContractMigration::Reuse)
+    ));
+}
+
+#[test]
+fn ok_upgrade_append() {
+    let previous = compile(STORAGE_V1);
+    let current = compile(STORAGE_V2_APPENDED);
+
+    let fields = current
+        .migration(&previous)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .appended_fields();
+
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+    let storage = Storage::try_from_json(
+        previous.storage.as_slice(),
+        eth_address,
+        serde_json::json!(["0x0", [], "5", "42"]),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID)
+    .append(fields);
+
+    let values: Vec<(String, serde_json::Value)> = storage
+        .fields
+        .iter()
+        .skip(zinc_const::contract::IMPLICIT_FIELDS_COUNT)
+        .map(|field| (field.name.to_owned(), field.value.to_owned().into_json()))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            ("value".to_owned(), serde_json::json!("5")),
+            ("total".to_owned(), serde_json::json!("42")),
+            ("count".to_owned(), serde_json::json!("0")),
+        ]
+    );
+    assert!(storage.root_hash(current).is_ok());
+}
+
+#[test]
+fn error_upgrade_reordered() {
+    let previous = compile(STORAGE_V1);
+    let current = compile(STORAGE_V2_REORDERED);
+
+    let expected = Err(zinc_types::ContractUpgradeError::StorageLayoutMismatch {
+        previous: previous.storage_layout.clone(),
+        current: current.storage_layout.clone(),
+        diff: "- value: u64\n+ total: u64\n- total: u64\n+ value: u64".to_owned(),
+    });
+
+    let result = current.migration(&previous).map(|_migration| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_retyped() {
+    let previous = compile(STORAGE_V1);
+    let current = compile(STORAGE_V2_RETYPED);
+
+    let expected = Err(zinc_types::ContractUpgradeError::StorageLayoutMismatch {
+        previous: previous.storage_layout.clone(),
+        current: current.storage_layout.clone(),
+        diff: "- total: u64\n+ total: u128\n+ count: u8".to_owned(),
+    });
+
+    let result = current.migration(&previous).map(|_migration| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_upgrade_migration() {
+    let previous = compile(STORAGE_V1);
+    let current = compile(STORAGE_V2);
+
+    let method = current
+        .migration(&previous)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .method()
+        .cloned()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+    let storage = Storage::try_from_json(
+        previous.storage.as_slice(),
+        eth_address,
+        serde_json::json!(["0x0", [], "5", "42"]),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let implicit_fields: Vec<(String, serde_json::Value)> = storage
+        .fields
+        .iter()
+        .take(zinc_const::contract::IMPLICIT_FIELDS_COUNT)
+        .map(|field| (field.name.to_owned(), field.value.to_owned().into_json()))
+        .collect();
+
+    let storage = storage
+        .migrate(current.clone(), method, eth_address)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let fields: Vec<(String, serde_json::Value)> = storage
+        .fields
+        .into_iter()
+        .map(|field| (field.name, field.value.into_json()))
+        .collect();
+
+    let mut expected = implicit_fields;
+    expected.extend(vec![
+        ("count".to_owned(), serde_json::json!("2")),
+        ("total".to_owned(), serde_json::json!("42")),
+        ("average".to_owned(), serde_json::json!("21")),
+    ]);
+
+    assert_eq!(fields, expected);
+}
+
+#[test]
+fn ok_root_hash() {
+    let build = compile(STORAGE_V1);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    let storage = |value: &str| {
+        Storage::try_from_json(
+            build.storage.as_slice(),
+            eth_address,
+            serde_json::json!(["0x0", [], value, "42"]),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+    };
+
+    let root_hash = storage("5")
+        .root_hash(build.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(root_hash.starts_with("0x"));
+    assert_eq!(root_hash.len(), 2 + zinc_const::size::SHA256_HASH * 2);
+
+    assert_eq!(
+        storage("5")
+            .root_hash(build.clone())
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        root_hash
+    );
+    assert_ne!(
+        storage("6")
+            .root_hash(build)
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        root_hash
+    );
+}
+
+#[test]
+fn ok_page() {
+    let build = compile(STORAGE_MAP);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    let entries = serde_json::json!([
+        { "key": "1", "value": "10" },
+        { "key": "2", "value": "20" },
+        { "key": "3", "value": "30" },
+    ]);
+    let storage = Storage::try_from_json(
+        build.storage.as_slice(),
+        eth_address,
+        serde_json::json!(["0x0", [], ["1", "2", "3"], entries]),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let first = storage.clone().into_page(build.storage.as_slice(), 0, 2);
+    let second = storage.into_page(build.storage.as_slice(), 2, 2);
+
+    assert_eq!(first[2].name, "values");
+    assert_eq!(first[2].r#type, "[u8; 3]");
+    assert_eq!(first[2].value, serde_json::json!(["1", "2", "3"]));
+    assert_eq!(first[2].total, None);
+    assert_eq!(second[2], first[2]);
+
+    assert_eq!(first[3].name, "entries");
+    assert_eq!(first[3].total, Some(3));
+    assert_eq!(
+        first[3].value,
+        serde_json::json!([{ "key": "1", "value": "10" }, { "key": "2", "value": "20" }])
+    );
+    assert_eq!(
+        second[3].value,
+        serde_json::json!([{ "key": "3", "value": "30" }])
+    );
+}
+
+#[test]
+fn ok_nested() {
+    let build = compile(STORAGE_NESTED);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    assert_eq!(build.storage[2].leaves.len(), 12);
+    assert_eq!(build.storage[2].leaves[4].path, "orders[1].amount");
+    assert_eq!(build.storage[3].leaves.len(), 1);
+
+    let storage = |amount: &str| {
+        let orders: Vec<serde_json::Value> = (0..4)
+            .map(|id| {
+                serde_json::json!({
+                    "id": id.to_string(),
+                    "amount": if id == 2 { amount.to_owned() } else { "0".to_owned() },
+                    "flags": [false, id == 2],
+                })
+            })
+            .collect();
+
+        Storage::try_from_json(
+            build.storage.as_slice(),
+            eth_address,
+            serde_json::json!(["0x0", [], orders, "4"]),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+    };
+
+    let root_hash = storage("42")
+        .root_hash(build.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_ne!(
+        storage("43")
+            .root_hash(build.clone())
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        root_hash
+    );
+
+    let page = storage("42").into_page(build.storage.as_slice(), 0, 10);
+    assert_eq!(page[2].name, "orders");
+    assert_eq!(
+        page[2].value[2],
+        serde_json::json!({ "id": "2", "amount": "42", "flags": [false, true] })
+    );
+}
+
+///
+/// Runs the `add` method of the `STORAGE_DRY_RUN` contract `build` against a copy of `storage`,
+/// as the `dry-run` endpoint does.
+///
+fn dry_run(
+    build: &zinc_types::Contract,
+    storage: &Storage,
+    eth_address: zksync_types::Address,
+    value: &str,
+) -> Result<zinc_vm::ContractOutput, zinc_vm::Error> {
+    let method = build
+        .methods
+        .get("add")
+        .cloned()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let mut arguments = zinc_types::Value::try_from_typed_json(
+        serde_json::json!({ "key": "2", "value": value }),
+        method.input,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    arguments.insert_contract_instance(BigInt::from_bytes_be(
+        num::bigint::Sign::Plus,
+        eth_address.as_bytes(),
+    ));
+
+    let mut storages = HashMap::with_capacity(1);
+    storages.insert(eth_address, storage.clone().into_build());
+
+    zinc_vm::ContractFacade::new(build.to_owned()).run::<zinc_vm::Bn256>(
+        zinc_vm::ContractInput::new(
+            arguments,
+            storages,
+            method.name,
+            zinc_types::TransactionMsg::default(),
+        ),
+    )
+}
+
+#[test]
+fn ok_dry_run_diff() {
+    let build = compile(STORAGE_DRY_RUN);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    let storage = Storage::try_from_json(
+        build.storage.as_slice(),
+        eth_address,
+        serde_json::json!(["0x0", [], "5", [{ "key": "1", "value": "10" }], "0"]),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    let root_hash = storage
+        .root_hash(build.clone())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let mut output =
+        dry_run(&build, &storage, eth_address, "20").expect(zinc_const::panic::TEST_DATA_VALID);
+    let after = output
+        .storages
+        .remove(&BigInt::from_bytes_be(
+            num::bigint::Sign::Plus,
+            eth_address.as_bytes(),
+        ))
+        .map(Storage::from_build)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        storage.diff(&after, build.storage.as_slice()),
+        vec![
+            zinc_types::DryRunResponseChange::new(
+                "value".to_owned(),
+                "u64".to_owned(),
+                serde_json::json!("5"),
+                serde_json::json!("25"),
+            ),
+            zinc_types::DryRunResponseChange::new(
+                "entries".to_owned(),
+                "std::collections::MTreeMap<u8, u64>".to_owned(),
+                serde_json::json!([]),
+                serde_json::json!([{ "key": "2", "value": "20" }]),
+            ),
+        ]
+    );
+    assert!(output.transfers.is_empty());
+    assert!(output.instructions > 0);
+
+    assert_eq!(
+        storage
+            .root_hash(build)
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        root_hash
+    );
+}
+
+#[test]
+fn error_dry_run_require() {
+    let build = compile(STORAGE_DRY_RUN);
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    let storage = Storage::new(build.storage.as_slice());
+
+    let message = match dry_run(&build, &storage, eth_address, "0") {
+        Err(zinc_vm::Error::RequireError(message)) => message,
+        result => panic!("Expected the require error, found `{:?}`", result),
+    };
+
+    assert_eq!(message, "The value must be positive");
+}
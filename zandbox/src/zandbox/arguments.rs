@@ -2,11 +2,15 @@
 //! The Zandbox server daemon arguments.
 //!
 
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
 ///
 /// The Zandbox server daemon arguments.
 ///
+/// The configuration flags override the configuration file and the environment variables.
+///
 #[derive(StructOpt)]
 #[structopt(
     name = zinc_const::app_name::ZANDBOX,
@@ -21,17 +25,66 @@ pub struct Arguments {
     #[structopt(short = "q", long = "quiet")]
     pub quiet: bool,
 
+    /// The configuration file path. Defaults to `zandbox.toml`, if the file exists.
+    #[structopt(short = "c", long = "config", parse(from_os_str))]
+    pub config_path: Option<PathBuf>,
+
+    /// Prints the effective configuration with the secrets redacted and exits, if set.
+    #[structopt(long = "print-config")]
+    pub print_config: bool,
+
+    /// The HTTP server listen address.
+    #[structopt(long = "host")]
+    pub host: Option<String>,
+
     /// The HTTP server port.
     #[structopt(short = "p", long = "http-port")]
     pub http_port: Option<u16>,
 
     /// The PostgreSQL connection string.
     #[structopt(short = "d", long = "postgresql")]
-    pub postgresql_uri: String,
+    pub postgresql_uri: Option<String>,
 
     /// The zkSync network identifier.
     #[structopt(short = "n", long = "network")]
-    pub network: String,
+    pub network: Option<String>,
+
+    /// The origin allowed to make the cross-origin requests, or `*` to allow any.
+    #[structopt(long = "cors-origin")]
+    pub cors_origins: Vec<String>,
+
+    /// The bearer token required to upload the project keys, dump the contract storage, and
+    /// archive the contracts. All are disabled if unset.
+    #[structopt(long = "keys-token")]
+    pub keys_token: Option<String>,
+
+    /// The bearer token required to purge the contracts. Disabled if unset.
+    #[structopt(long = "admin-token")]
+    pub admin_token: Option<String>,
+
+    /// The maximal number of scalars in the contract method arguments.
+    #[structopt(long = "max-input-values")]
+    pub max_input_values: Option<usize>,
+
+    /// The maximal nesting depth of the contract method arguments.
+    #[structopt(long = "max-input-depth")]
+    pub max_input_depth: Option<usize>,
+
+    /// The symbol of the token accepted in the contract call transfers. Any if unset.
+    #[structopt(long = "token")]
+    pub tokens: Vec<String>,
+
+    /// The log output format, either `pretty` or `json`.
+    #[structopt(long = "log-format")]
+    pub log_format: Option<String>,
+
+    /// The number of the contract dry runs allowed per client IP address in a minute.
+    #[structopt(long = "dry-run-rate-limit")]
+    pub dry_run_rate_limit: Option<usize>,
+
+    /// The number of seconds a contract method may run on the virtual machine.
+    #[structopt(long = "method-timeout")]
+    pub method_timeout: Option<u64>,
 }
 
 impl Arguments {
@@ -41,4 +94,42 @@ impl Arguments {
     pub fn new() -> Self {
         Self::from_args()
     }
+
+    ///
+    /// Returns the configuration file path, if it is passed or exists at the default location.
+    ///
+    pub fn config_path(&self) -> Option<PathBuf> {
+        match self.config_path {
+            Some(ref path) => Some(path.to_owned()),
+            None => {
+                let path = PathBuf::from(zinc_const::zandbox::CONFIG_FILE);
+                if path.exists() {
+                    Some(path)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    ///
+    /// Converts the flags into the configuration layer.
+    ///
+    pub fn into_config_layer(self) -> zandbox::ConfigLayer {
+        zandbox::ConfigLayer {
+            host: self.host,
+            port: self.http_port,
+            database_url: self.postgresql_uri,
+            network: self.network,
+            cors_origins: Some(self.cors_origins).filter(|origins| !origins.is_empty()),
+            keys_token: self.keys_token,
+            admin_token: self.admin_token,
+            max_input_values: self.max_input_values,
+            max_input_depth: self.max_input_depth,
+            tokens: Some(self.tokens).filter(|tokens| !tokens.is_empty()),
+            log_format: self.log_format,
+            dry_run_rate_limit: self.dry_run_rate_limit,
+            method_timeout: self.method_timeout,
+        }
+    }
 }
@@ -4,10 +4,9 @@
 
 pub(crate) mod arguments;
 
-use std::str::FromStr;
+use std::collections::HashMap;
 
 use actix_web::middleware;
-use actix_web::web;
 use actix_web::App;
 use actix_web::HttpServer;
 
@@ -20,35 +19,62 @@ use self::arguments::Arguments;
 async fn main() -> anyhow::Result<()> {
     let args = Arguments::new();
 
-    zinc_logger::initialize(zinc_const::app_name::ZANDBOX, args.verbosity, args.quiet);
+    let verbosity = args.verbosity;
+    let quiet = args.quiet;
+    let config_path = args.config_path();
+    let print_config = args.print_config;
+    let variables: HashMap<String, String> = std::env::vars().collect();
+    let config =
+        zandbox::Config::load(config_path.as_deref(), &variables, args.into_config_layer())?;
 
-    log::info!("Zandbox server started");
+    if print_config {
+        print!("{}", config.to_redacted_toml());
+        return Ok(());
+    }
+
+    zinc_logger::initialize_structured(verbosity, quiet, config.log_format);
 
-    let network = zksync::Network::from_str(args.network.as_str())
-        .map_err(|network| anyhow::anyhow!(format!("Invalid network `{}`", network)))?;
+    log::info!("Zandbox server started");
 
     log::info!("Initializing the PostgreSQL client");
-    let postgresql = zandbox::DatabaseClient::new(args.postgresql_uri.as_str()).await?;
+    let postgresql = zandbox::DatabaseClient::new(config.database_url.as_str()).await?;
 
-    let data = zandbox::SharedData::new(postgresql, network).wrap();
+    let address = (config.host, config.port);
+    let data = zandbox::SharedData::new(postgresql, config.clone()).wrap();
 
     HttpServer::new(move || {
         App::new()
             .wrap(middleware::Logger::default())
             .wrap(middleware::DefaultHeaders::new().content_type())
-            .wrap(actix_cors::Cors::permissive())
-            .app_data(web::JsonConfig::default().limit(zinc_const::limit::JSON_PAYLOAD))
+            .wrap(cors(&config))
+            .wrap(zandbox::RequestIdMiddleware)
+            .app_data(zandbox::json_config())
+            .app_data(zandbox::query_config())
             .app_data(data.clone())
             .configure(zandbox::configure)
     })
-    .bind(format!(
-        "{}:{}",
-        zinc_const::zandbox::HOST,
-        args.http_port.unwrap_or(zinc_const::zandbox::PORT)
-    ))?
+    .bind(address)?
     .run()
     .await?;
 
     log::info!("Zandbox server finished");
     Ok(())
 }
+
+///
+/// Creates the CORS middleware, which allows the configured origins.
+///
+fn cors(config: &zandbox::Config) -> actix_cors::Cors {
+    if config.is_any_origin_allowed() {
+        return actix_cors::Cors::permissive();
+    }
+
+    config
+        .cors_origins
+        .iter()
+        .fold(actix_cors::Cors::default(), |cors, origin| {
+            cors.allowed_origin(origin.as_str())
+        })
+        .allow_any_method()
+        .allow_any_header()
+}
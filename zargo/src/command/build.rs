@@ -3,11 +3,14 @@
 //!
 
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+use anyhow::Context;
 use structopt::StructOpt;
 
+use crate::command::table;
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
 use crate::http::downloader::Downloader;
@@ -15,6 +18,7 @@ use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
+use crate::project::target::bytecode::Bytecode as BytecodeFile;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
 use crate::project::target::Directory as TargetDirectory;
 
@@ -47,6 +51,32 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Prints the wall time of each compilation phase and the peak memory usage.
+    /// The format is either `table` (default) or `json`. Overrides the manifest `build` section.
+    #[structopt(long = "timings", possible_values = &["table", "json"])]
+    pub timings: Option<Option<zinc_project::ManifestTimings>>,
+
+    /// The maximal number of the bytecode instructions. Overrides the manifest `build` section.
+    #[structopt(long = "max-instructions")]
+    pub max_instructions: Option<usize>,
+
+    /// Prints the contract storage sizing table with the number of leaves of each field.
+    #[structopt(long = "stats")]
+    pub is_stats: bool,
+
+    /// Generates the public input layout bindings in the language, which is either `rust` or `c`.
+    #[structopt(long = "bindings", possible_values = &["rust", "c"])]
+    pub bindings: Option<zinc_types::BindingsLanguage>,
+
+    /// The directory to write the bindings to. Defaults to the target directory.
+    #[structopt(long = "out", parse(from_os_str))]
+    pub bindings_path: Option<PathBuf>,
+
+    /// Prunes the contract to the comma-separated methods and the functions they call.
+    /// The pruned artifact can be run and queried locally, but never published.
+    #[structopt(long = "only-methods", use_delimiter = true)]
+    pub only_methods: Vec<String>,
 }
 
 impl Command {
@@ -59,6 +89,9 @@ impl Command {
         manifest_path: PathBuf,
         is_release: bool,
         network: Option<String>,
+        timings: Option<Option<zinc_project::ManifestTimings>>,
+        max_instructions: Option<usize>,
+        is_stats: bool,
     ) -> Self {
         Self {
             verbosity,
@@ -67,6 +100,12 @@ impl Command {
             is_release,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            timings,
+            max_instructions,
+            is_stats,
+            bindings: None,
+            bindings_path: None,
+            only_methods: vec![],
         }
     }
 
@@ -76,6 +115,13 @@ impl Command {
     pub async fn execute(self) -> anyhow::Result<()> {
         let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
 
+        if !self.only_methods.is_empty() {
+            match manifest.project.r#type {
+                zinc_project::ProjectType::Contract => {}
+                _ => anyhow::bail!(Error::NotAContract),
+            }
+        }
+
         let mut manifest_path = self.manifest_path.clone();
         if manifest_path.is_file() {
             manifest_path.pop();
@@ -88,6 +134,7 @@ impl Command {
         }
 
         TargetDirectory::create(&manifest_path, self.is_release)?;
+        Self::keep_previous(&manifest_path, self.is_release)?;
 
         TargetDependenciesDirectory::create(&manifest_path)?;
 
@@ -113,6 +160,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.overrides(),
             )?;
         } else {
             Compiler::build_debug(
@@ -122,9 +170,219 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.overrides(),
             )?;
         }
 
+        if let zinc_project::ProjectType::Contract = manifest.project.r#type {
+            if !self.only_methods.is_empty() {
+                Self::prune(
+                    &manifest_path,
+                    self.is_release,
+                    &manifest.project.version,
+                    self.only_methods.as_slice(),
+                )?;
+            }
+
+            let contract = Self::contract(&manifest_path, self.is_release)?;
+
+            Self::write_abi(&manifest_path, manifest.project.name.as_str(), &contract)?;
+
+            if self.is_stats {
+                print!(
+                    "{}",
+                    Self::storage_stats(&contract, zinc_const::limit::CONTRACT_STORAGE_LEAVES)
+                );
+            }
+        }
+
+        if let Some(language) = self.bindings {
+            let bindings_path = match self.bindings_path {
+                Some(ref path) => path.to_owned(),
+                None => TargetDirectory::path(&manifest_path, self.is_release),
+            };
+            Self::write_bindings(&manifest_path, self.is_release, language, &bindings_path)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the manifest `build` section values overridden by the command line options.
+    ///
+    fn overrides(&self) -> zinc_project::ManifestBuild {
+        zinc_project::ManifestBuild {
+            max_instructions: self.max_instructions,
+            timings: self
+                .timings
+                .map(|format| format.unwrap_or(zinc_project::ManifestTimings::Table)),
+            ..zinc_project::ManifestBuild::default()
+        }
+    }
+
+    ///
+    /// Copies the existing bytecode file to the previous one, which `zargo diff --against
+    /// previous-build` compares the new build against.
+    ///
+    fn keep_previous(manifest_path: &PathBuf, is_release: bool) -> anyhow::Result<()> {
+        let target_path = TargetDirectory::path(manifest_path, is_release);
+
+        let mut binary_path = target_path.clone();
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+        if !binary_path.exists() {
+            return Ok(());
+        }
+
+        let mut previous_path = target_path;
+        previous_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY_PREVIOUS,
+            zinc_const::extension::BINARY
+        ));
+        fs::copy(&binary_path, &previous_path)
+            .with_context(|| previous_path.to_string_lossy().to_string())?;
+
         Ok(())
     }
+
+    ///
+    /// Reads the built contract from the target directory.
+    ///
+    fn contract(manifest_path: &PathBuf, is_release: bool) -> anyhow::Result<zinc_types::Contract> {
+        let mut binary_path = TargetDirectory::path(manifest_path, is_release);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        let bytecode = BytecodeFile::try_from_path(&binary_path, is_release)?;
+        let contract = match zinc_types::Application::try_from_slice(bytecode.inner.as_slice())
+            .map_err(anyhow::Error::msg)?
+        {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+
+        Ok(contract)
+    }
+
+    ///
+    /// Prunes the built contract to the `methods` and the functions they call, rewriting the
+    /// bytecode file in the target directory.
+    ///
+    /// The pruned contract records the circuit hash of the complete bytecode file, so
+    /// `zargo publish` and `zargo upload` reject it.
+    ///
+    fn prune(
+        manifest_path: &PathBuf,
+        is_release: bool,
+        project_version: &semver::Version,
+        methods: &[String],
+    ) -> anyhow::Result<()> {
+        let mut binary_path = TargetDirectory::path(manifest_path, is_release);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        let bytecode =
+            fs::read(&binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let contract = match zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(anyhow::Error::msg)?
+        {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+
+        let contract = contract
+            .prune(
+                methods,
+                zinc_types::circuit_hash(bytecode.as_slice()).as_slice(),
+            )
+            .map_err(Error::ContractPruning)?;
+        fs::write(
+            &binary_path,
+            zinc_types::Application::Contract(contract).into_vec(project_version),
+        )
+        .with_context(|| binary_path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the ABI of the built `contract` to `target/<name>.abi.json`.
+    ///
+    fn write_abi(
+        manifest_path: &PathBuf,
+        name: &str,
+        contract: &zinc_types::Contract,
+    ) -> anyhow::Result<()> {
+        let mut abi_path = manifest_path.to_owned();
+        abi_path.push(zinc_const::directory::TARGET);
+        abi_path.push(format!("{}.{}", name, zinc_const::extension::ABI));
+
+        let data = serde_json::to_string_pretty(&contract.abi())
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        fs::write(&abi_path, data + "\n")
+            .with_context(|| abi_path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the public input layout bindings of the built application in the `language` to
+    /// the directory at `path`.
+    ///
+    fn write_bindings(
+        manifest_path: &PathBuf,
+        is_release: bool,
+        language: zinc_types::BindingsLanguage,
+        path: &PathBuf,
+    ) -> anyhow::Result<()> {
+        let mut binary_path = TargetDirectory::path(manifest_path, is_release);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        let bytecode =
+            fs::read(&binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let (_header, application) = zinc_types::Application::try_decode(bytecode.as_slice())
+            .with_context(|| binary_path.to_string_lossy().to_string())?;
+        let bindings =
+            zinc_types::Bindings::new(&application, zinc_types::circuit_hash(bytecode.as_slice()))
+                .ok_or(Error::LibraryBindings)?;
+
+        fs::create_dir_all(path).with_context(|| path.to_string_lossy().to_string())?;
+        let mut bindings_path = path.to_owned();
+        bindings_path.push(language.file_name());
+        fs::write(&bindings_path, bindings.generate(language))
+            .with_context(|| bindings_path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Formats the `contract` storage sizing table, with the storage fields the largest first,
+    /// and the total number of leaves compared to the `limit`.
+    ///
+    fn storage_stats(contract: &zinc_types::Contract, limit: usize) -> String {
+        let mut rows = vec![vec!["FIELD".to_owned(), "LEAVES".to_owned()]];
+        for (name, count) in contract.storage_sizing().into_iter() {
+            rows.push(vec![name, count.to_string()]);
+        }
+        rows.push(vec![
+            "total".to_owned(),
+            format!("{} of {}", contract.storage_leaves, limit),
+        ]);
+
+        table::render(rows)
+    }
 }
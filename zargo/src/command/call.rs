@@ -3,9 +3,11 @@
 //!
 
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+use anyhow::Context;
 use colored::Colorize;
 use structopt::StructOpt;
 
@@ -17,8 +19,10 @@ use crate::error::Error;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::input::Input as InputFile;
+use crate::project::data::output::Output as OutputFile;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
+use crate::project::target::Directory as TargetDirectory;
 use crate::transaction::error::Error as TransactionError;
 
 ///
@@ -58,6 +62,16 @@ pub struct Command {
     /// Sets the path to the sender private key.
     #[structopt(long = "private-key", default_value = "./data/private_key")]
     pub private_key_path: PathBuf,
+
+    /// Prints the response JSON as is, instead of rendering the output with the type metadata
+    /// of the local release build.
+    #[structopt(long = "raw")]
+    pub is_raw: bool,
+
+    /// Evaluates the method against a copy of the contract storage and prints its output,
+    /// transfers, storage diff, and cost, without signing and sending the transaction.
+    #[structopt(long = "dry-run")]
+    pub is_dry_run: bool,
 }
 
 impl Command {
@@ -81,6 +95,8 @@ impl Command {
             address,
             method,
             private_key_path: PathBuf::from("./data/private_key"),
+            is_raw: false,
+            is_dry_run: false,
         }
     }
 
@@ -101,9 +117,14 @@ impl Command {
         let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
 
         if !self.quiet {
+            let action = if self.is_dry_run {
+                "Dry running"
+            } else {
+                "Calling"
+            };
             eprintln!(
                 "     {} method `{}` of the contract `{} v{}` with address {} on network `{}`",
-                "Calling".bright_green(),
+                action.bright_green(),
                 self.method,
                 manifest.project.name,
                 manifest.project.version,
@@ -144,22 +165,9 @@ impl Command {
             .get(method.as_str())
             .cloned()
             .ok_or_else(|| Error::MissingInputSection(format!("arguments.{}", method)))?;
-
-        let private_key = PrivateKeyFile::try_from(&manifest_path)?;
-
-        let signer_private_key: H256 = private_key.inner.parse()?;
-        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)?;
-
-        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-            signer_address,
-            PrivateKeySigner::new(signer_private_key),
-            network.into(),
-        )
-        .await
-        .expect(zinc_const::panic::DATA_CONVERSION);
-        let wallet =
-            zksync::Wallet::new(zksync::RpcProvider::new(network.into()), wallet_credentials)
-                .await?;
+        zinc_types::ValueLimits::default()
+            .check(&arguments)
+            .map_err(|error| Error::InputLimit(method.clone(), error))?;
 
         let msg = input
             .inner
@@ -177,6 +185,55 @@ impl Command {
                 )
             })?;
         let msg = zinc_types::TransactionMsg::try_from(&msg).map_err(TransactionError::Parsing)?;
+
+        let mut binary_path = TargetDirectory::path(&manifest_path, true);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        if self.is_dry_run {
+            let response = http_client
+                .dry_run(
+                    zinc_types::DryRunRequestQuery::new(address, method.clone()),
+                    zinc_types::DryRunRequestBody::new(arguments, msg),
+                )
+                .await?;
+            if !self.quiet {
+                if !self.is_raw && binary_path.exists() {
+                    println!(
+                        "{}",
+                        Self::dry_run_to_pretty_string(&response, &binary_path, method.as_str())?
+                    );
+                } else {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&response)
+                            .expect(zinc_const::panic::DATA_CONVERSION)
+                    );
+                }
+            }
+
+            return Ok(serde_json::to_value(response).expect(zinc_const::panic::DATA_CONVERSION));
+        }
+
+        let private_key = PrivateKeyFile::try_from(&manifest_path)?;
+
+        let signer_private_key: H256 = private_key.inner.parse()?;
+        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)?;
+
+        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            signer_address,
+            PrivateKeySigner::new(signer_private_key),
+            network.into(),
+        )
+        .await
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        let wallet =
+            zksync::Wallet::new(zksync::RpcProvider::new(network.into()), wallet_credentials)
+                .await?;
+
         let transaction = crate::transaction::try_into_zksync(msg.clone(), &wallet, None).await?;
 
         let response = http_client
@@ -195,17 +252,126 @@ impl Command {
 
         let response = http_client
             .call(
-                zinc_types::CallRequestQuery::new(address, method),
+                zinc_types::CallRequestQuery::new(address, method.clone()),
                 zinc_types::CallRequestBody::new(arguments, transaction),
             )
             .await?;
         if !self.quiet {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&response).expect(zinc_const::panic::DATA_CONVERSION)
-            );
+            match response.get("output") {
+                Some(output) if !self.is_raw && binary_path.exists() => {
+                    let output = OutputFile {
+                        inner: output.to_owned(),
+                    };
+                    println!(
+                        "{}",
+                        output.to_pretty_string(&binary_path, Some(method.as_str()))?
+                    );
+                }
+                _ => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&response)
+                        .expect(zinc_const::panic::DATA_CONVERSION)
+                ),
+            }
         }
 
         Ok(response)
     }
+
+    ///
+    /// Renders the dry run `response` with the type metadata of the contract built at
+    /// `binary_path`.
+    ///
+    /// The changed storage fields are rendered as pairs of lines prefixed with `-` for the
+    /// value before the method execution and `+` for the value after it.
+    ///
+    fn dry_run_to_pretty_string(
+        response: &zinc_types::DryRunResponseBody,
+        binary_path: &PathBuf,
+        method: &str,
+    ) -> anyhow::Result<String> {
+        let bytecode =
+            fs::read(binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let (_header, application) = zinc_types::Application::try_decode(bytecode.as_slice())
+            .with_context(|| binary_path.to_string_lossy().to_string())?;
+        let storage = match application {
+            zinc_types::Application::Contract(contract) => contract.storage,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+
+        let output = OutputFile {
+            inner: response.output.to_owned(),
+        };
+        let mut lines = vec![
+            "Output:".to_owned(),
+            Self::indent(output.to_pretty_string(binary_path, Some(method))?.as_str()),
+            String::new(),
+            "Transfers:".to_owned(),
+        ];
+
+        if response.transfers.is_empty() {
+            lines.push(Self::indent("(none)"));
+        }
+        for transfer in response.transfers.iter() {
+            lines.push(Self::indent(
+                format!(
+                    "{} of token {} to {}",
+                    transfer.amount,
+                    Self::address_to_string(&transfer.token_address),
+                    Self::address_to_string(&transfer.recipient),
+                )
+                .as_str(),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push("Storage:".to_owned());
+        if response.storage.is_empty() {
+            lines.push(Self::indent("(unchanged)"));
+        }
+        for change in response.storage.iter() {
+            let r#type = storage
+                .iter()
+                .find(|field| field.name == change.name)
+                .map(|field| field.r#type.to_owned());
+
+            let render = |value: &serde_json::Value| match r#type {
+                Some(ref r#type) => {
+                    zinc_types::Value::try_from_typed_json(value.to_owned(), r#type.to_owned())
+                        .map(|value| zinc_types::ValuePrinter::print(&value, r#type))
+                        .unwrap_or_else(|_| value.to_string())
+                }
+                None => value.to_string(),
+            };
+
+            lines.push(Self::indent(
+                format!("- {}: {}", change.name, render(&change.before)).as_str(),
+            ));
+            lines.push(Self::indent(
+                format!("+ {}: {}", change.name, render(&change.after)).as_str(),
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("Cost: {} instruction(s)", response.cost));
+
+        Ok(lines.join("\n"))
+    }
+
+    ///
+    /// Indents every line of `text` with four spaces.
+    ///
+    fn indent(text: &str) -> String {
+        text.lines()
+            .map(|line| format!("    {}", line))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    ///
+    /// Formats the `address` as a `0x`-prefixed hexadecimal string.
+    ///
+    fn address_to_string(address: &zksync_types::Address) -> String {
+        zinc_types::scalar_format::bytes(address.as_bytes())
+    }
 }
@@ -0,0 +1,153 @@
+//!
+//! The Zargo package manager `diff` subcommand.
+//!
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::project::target::Directory as TargetDirectory;
+
+///
+/// The Zargo package manager `diff` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Compares two build artifacts")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Prints the differences as JSON instead of a summary, if set.
+    #[structopt(long = "json")]
+    pub json: bool,
+
+    /// The path to the Zinc project manifest file, which is used with `--against`.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Compares the release build with `--against`.
+    #[structopt(long = "release")]
+    pub is_release: bool,
+
+    /// Compares the project build with the one it has overwritten, instead of the given paths.
+    #[structopt(long = "against", possible_values = &["previous-build"])]
+    pub against: Option<String>,
+
+    /// The path to the previous build artifact, e.g. `old/main.znb`.
+    #[structopt(
+        parse(from_os_str),
+        required_unless = "against",
+        conflicts_with = "against"
+    )]
+    pub previous: Option<PathBuf>,
+
+    /// The path to the current build artifact, e.g. `target/debug/main.znb`.
+    #[structopt(
+        parse(from_os_str),
+        required_unless = "against",
+        conflicts_with = "against"
+    )]
+    pub current: Option<PathBuf>,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let (previous, current) = match (self.previous, self.current) {
+            (Some(previous), Some(current)) => (previous, current),
+            _ => Self::previous_build(self.manifest_path, self.is_release)?,
+        };
+
+        let diff = Self::diff(previous.as_path(), current.as_path())?;
+
+        if self.quiet {
+            return Ok(());
+        }
+
+        if self.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&diff).expect(zinc_const::panic::DATA_CONVERSION)
+            );
+        } else {
+            print!("{}", diff);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Decodes and compares the build artifacts at `previous` and `current`.
+    ///
+    pub fn diff(previous: &Path, current: &Path) -> anyhow::Result<zinc_types::BuildDiff> {
+        let (previous_header, previous) = Self::decode(previous)?;
+        let (current_header, current) = Self::decode(current)?;
+
+        Ok(zinc_types::BuildDiff::new(
+            &previous_header,
+            &previous,
+            &current_header,
+            &current,
+        ))
+    }
+
+    ///
+    /// Decodes the build artifact at `path`.
+    ///
+    fn decode(
+        path: &Path,
+    ) -> anyhow::Result<(zinc_types::ApplicationHeader, zinc_types::Application)> {
+        let bytes = fs::read(path).with_context(|| path.to_string_lossy().to_string())?;
+        let artifact = zinc_types::Application::try_decode(bytes.as_slice())
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(artifact)
+    }
+
+    ///
+    /// Returns the paths to the previous and current bytecode files of the project.
+    ///
+    fn previous_build(
+        mut manifest_path: PathBuf,
+        is_release: bool,
+    ) -> anyhow::Result<(PathBuf, PathBuf)> {
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+        let target_path = TargetDirectory::path(&manifest_path, is_release);
+
+        let mut previous_path = target_path.clone();
+        previous_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY_PREVIOUS,
+            zinc_const::extension::BINARY
+        ));
+        if !previous_path.exists() {
+            anyhow::bail!(Error::PreviousBuildMissing(previous_path.into_os_string()));
+        }
+
+        let mut current_path = target_path;
+        current_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        Ok((previous_path, current_path))
+    }
+}
@@ -0,0 +1,164 @@
+//!
+//! The Zargo package manager `doc` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+
+use anyhow::Context;
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::executable::compiler::Compiler;
+use crate::http::downloader::Downloader;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+use crate::project::target::deps::Directory as TargetDependenciesDirectory;
+
+///
+/// The Zargo package manager `doc` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Builds the project documentation")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the dependencies are downloaded from.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Documents the private functions, methods, and storage fields.
+    #[structopt(long = "document-private-items")]
+    pub document_private_items: bool,
+
+    /// Opens the documentation in the default browser.
+    #[structopt(long = "open")]
+    pub open: bool,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        TargetDependenciesDirectory::create(&manifest_path)?;
+
+        if let Some(dependencies) = manifest.dependencies {
+            let network = zksync::Network::from_str(self.network.as_str())
+                .map(Network::from)
+                .map_err(Error::NetworkInvalid)?;
+            let url = network
+                .try_into_url()
+                .map_err(Error::NetworkUnimplemented)?;
+            let http_client = HttpClient::new(url);
+            let mut downloader = Downloader::new(&http_client, &manifest_path);
+            downloader.download_dependency_list(dependencies).await?;
+        }
+
+        if !self.quiet {
+            eprintln!(
+                " {} {} v{}",
+                "Documenting".bright_green(),
+                manifest.project.name,
+                manifest.project.version
+            );
+        }
+
+        let documentation =
+            Compiler::document(self.verbosity, &manifest_path, self.document_private_items)?;
+
+        let mut doc_path = manifest_path;
+        doc_path.push(zinc_const::directory::TARGET_DOC);
+        Self::write(&doc_path, &documentation)?;
+
+        let mut index_path = doc_path;
+        index_path.push(documentation.index());
+
+        if !self.quiet {
+            eprintln!(
+                "    {} {}",
+                "Finished".bright_green(),
+                index_path.to_string_lossy()
+            );
+        }
+
+        if self.open {
+            Self::open(&index_path)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the `documentation` pages to the directory at `path`, removing the previously
+    /// generated ones, so the pages of the removed items do not linger.
+    ///
+    fn write(path: &PathBuf, documentation: &zinc_types::Documentation) -> anyhow::Result<()> {
+        if path.exists() {
+            fs::remove_dir_all(path).with_context(|| path.to_string_lossy().to_string())?;
+        }
+
+        for (name, page) in documentation.pages().into_iter() {
+            let mut page_path = path.to_owned();
+            page_path.push(name);
+
+            if let Some(parent) = page_path.parent() {
+                fs::create_dir_all(parent).with_context(|| parent.to_string_lossy().to_string())?;
+            }
+            fs::write(&page_path, page).with_context(|| page_path.to_string_lossy().to_string())?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Opens the page at `path` in the default browser of the operating system.
+    ///
+    fn open(path: &PathBuf) -> anyhow::Result<()> {
+        let mut command = if cfg!(target_os = "windows") {
+            let mut command = process::Command::new("cmd");
+            command.args(&["/C", "start", ""]);
+            command
+        } else if cfg!(target_os = "macos") {
+            process::Command::new("open")
+        } else {
+            process::Command::new("xdg-open")
+        };
+
+        let status = command
+            .arg(path)
+            .status()
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+}
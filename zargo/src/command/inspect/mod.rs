@@ -0,0 +1,323 @@
+//!
+//! The Zargo package manager `inspect` subcommand.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use rustc_hex::ToHex;
+use sha2::Digest;
+use structopt::StructOpt;
+
+///
+/// The Zargo package manager `inspect` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Prints the build artifact metadata")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Prints the metadata as JSON instead of a summary, if set.
+    #[structopt(long = "json")]
+    pub json: bool,
+
+    /// Prints only the specified section of the metadata.
+    #[structopt(long = "section", possible_values = &["templates", "methods", "tests"])]
+    pub section: Option<String>,
+
+    /// The path to the build artifact, e.g. `target/debug/main.znb`.
+    #[structopt(parse(from_os_str))]
+    pub path: PathBuf,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let metadata = Self::inspect(&self.path)?;
+        let metadata = match self.section {
+            Some(ref section) => metadata[section.as_str()].to_owned(),
+            None => metadata,
+        };
+
+        if self.quiet {
+            return Ok(());
+        }
+
+        if self.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&metadata).expect(zinc_const::panic::DATA_CONVERSION)
+            );
+        } else {
+            print!("{}", Self::summary(&metadata, self.section.as_deref()));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Decodes the build artifact at `path` and collects its metadata.
+    ///
+    /// The corrupted artifact results in the `zinc_types::ApplicationDecodingError`, which
+    /// carries the failing byte offset.
+    ///
+    pub fn inspect(path: &Path) -> anyhow::Result<serde_json::Value> {
+        let bytes = fs::read(path).with_context(|| path.to_string_lossy().to_string())?;
+        let (header, application) = zinc_types::Application::try_decode(bytes.as_slice())
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        let instruction_count = application.instructions().len();
+        let (name, r#type, methods, unit_tests) = match application {
+            zinc_types::Application::Circuit(circuit) => {
+                let methods = vec![Self::method(
+                    zinc_const::source::FUNCTION_MAIN_IDENTIFIER,
+                    circuit.address,
+                    false,
+                    false,
+                    None,
+                    circuit.input,
+                    circuit.output,
+                )];
+
+                (circuit.name, "circuit", methods, circuit.unit_tests)
+            }
+            zinc_types::Application::Contract(contract) => {
+                let methods = contract
+                    .methods
+                    .into_iter()
+                    .map(|(name, method)| {
+                        Self::method(
+                            name.as_str(),
+                            method.address,
+                            method.is_mutable,
+                            method.reads_msg,
+                            method.required_sender,
+                            method.input,
+                            method.output,
+                        )
+                    })
+                    .collect();
+
+                (contract.name, "contract", methods, contract.unit_tests)
+            }
+            zinc_types::Application::Library(library) => {
+                (library.name, "library", vec![], library.unit_tests)
+            }
+        };
+
+        let templates = methods
+            .iter()
+            .map(|method| {
+                serde_json::json!({
+                    "name": method["name"],
+                    "input": method["input_digest"],
+                    "output": method["output_digest"],
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+        let methods = methods
+            .into_iter()
+            .map(|mut method| {
+                if let Some(method) = method.as_object_mut() {
+                    method.remove("input_digest");
+                    method.remove("output_digest");
+                }
+                method
+            })
+            .collect::<Vec<serde_json::Value>>();
+        let tests = unit_tests
+            .into_iter()
+            .map(|(name, unit_test)| {
+                serde_json::json!({
+                    "name": name,
+                    "address": unit_test.address,
+                    "should_panic": unit_test.should_panic,
+                    "expected_panic": unit_test.expected_panic,
+                    "is_ignored": unit_test.is_ignored,
+                    "is_proof": unit_test.is_proof,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+
+        Ok(serde_json::json!({
+            "format_version": header.format_version,
+            "std_version": header.std_version,
+            "compiler_version": header.compiler_version,
+            "project": {
+                "name": name,
+                "version": header.project_version,
+                "type": r#type,
+            },
+            "instructions": instruction_count,
+            "methods": methods,
+            "tests": tests,
+            "templates": templates,
+        }))
+    }
+
+    ///
+    /// Renders the artifact `metadata` as a human-readable summary.
+    ///
+    /// If the `section` is specified, the `metadata` is expected to contain only that section.
+    ///
+    pub fn summary(metadata: &serde_json::Value, section: Option<&str>) -> String {
+        let mut summary = String::new();
+
+        if section.is_none() {
+            let unknown = serde_json::Value::String("unknown".to_owned());
+            let or_unknown = |value: &serde_json::Value| match value {
+                serde_json::Value::Null => unknown.to_owned(),
+                value => value.to_owned(),
+            };
+
+            summary.push_str(
+                format!(
+                    "Project:      {} {} ({})\n",
+                    Self::string(&metadata["project"]["name"]),
+                    Self::string(&or_unknown(&metadata["project"]["version"])),
+                    Self::string(&metadata["project"]["type"]),
+                )
+                .as_str(),
+            );
+            summary.push_str(
+                format!(
+                    "Compiler:     {} (format version {}, std version {})\n",
+                    Self::string(&or_unknown(&metadata["compiler_version"])),
+                    metadata["format_version"],
+                    metadata["std_version"],
+                )
+                .as_str(),
+            );
+            summary.push_str(format!("Instructions: {}\n", metadata["instructions"]).as_str());
+        }
+
+        for (name, title) in [
+            ("methods", "Methods"),
+            ("tests", "Unit tests"),
+            ("templates", "Templates"),
+        ]
+        .iter()
+        {
+            let items = match section {
+                Some(section) if section == *name => metadata,
+                Some(_) => continue,
+                None => &metadata[*name],
+            };
+            let items = items.as_array().map(Vec::as_slice).unwrap_or_default();
+
+            if section.is_none() {
+                summary.push_str(format!("{}: {}\n", title, items.len()).as_str());
+            }
+            for item in items.iter() {
+                let line = match *name {
+                    "methods" => format!(
+                        "{} at {}{}{}{}",
+                        Self::string(&item["name"]),
+                        item["address"],
+                        if item["is_mutable"] == true {
+                            ", mutable"
+                        } else {
+                            ""
+                        },
+                        if item["reads_msg"] == true {
+                            ", reads zksync::msg"
+                        } else {
+                            ""
+                        },
+                        match item["required_sender"].as_str() {
+                            Some(field) => format!(", sender must be `self.{}`", field),
+                            None => String::new(),
+                        },
+                    ),
+                    "tests" => format!(
+                        "{} at {}{}{}{}",
+                        Self::string(&item["name"]),
+                        item["address"],
+                        match item["expected_panic"].as_str() {
+                            Some(expected) if item["should_panic"] == true => {
+                                format!(", should panic with \"{}\"", expected)
+                            }
+                            _ if item["should_panic"] == true => ", should panic".to_owned(),
+                            _ => String::new(),
+                        },
+                        if item["is_ignored"] == true {
+                            ", ignored"
+                        } else {
+                            ""
+                        },
+                        if item["is_proof"] == true {
+                            ", proof"
+                        } else {
+                            ""
+                        },
+                    ),
+                    _ => format!(
+                        "{} input {} output {}",
+                        Self::string(&item["name"]),
+                        Self::string(&item["input"]),
+                        Self::string(&item["output"]),
+                    ),
+                };
+                summary.push_str(format!("    {}\n", line).as_str());
+            }
+        }
+
+        summary
+    }
+
+    ///
+    /// Describes the method at `address` with the digests of its input and output templates.
+    ///
+    fn method(
+        name: &str,
+        address: usize,
+        is_mutable: bool,
+        reads_msg: bool,
+        required_sender: Option<String>,
+        input: zinc_types::Type,
+        output: zinc_types::Type,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "address": address,
+            "is_mutable": is_mutable,
+            "reads_msg": reads_msg,
+            "required_sender": required_sender,
+            "input_digest": Self::template_digest(input),
+            "output_digest": Self::template_digest(output),
+        })
+    }
+
+    ///
+    /// Computes the hexadecimal SHA-256 digest of the JSON template of `type`.
+    ///
+    fn template_digest(r#type: zinc_types::Type) -> String {
+        let template = zinc_types::Value::new(r#type).into_json();
+        let bytes = serde_json::to_vec(&template).expect(zinc_const::panic::DATA_CONVERSION);
+
+        sha2::Sha256::digest(bytes.as_slice())[..].to_hex()
+    }
+
+    ///
+    /// Unwraps the JSON string `value` without the quotes.
+    ///
+    fn string(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::String(value) => value.to_owned(),
+            value => value.to_string(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//!
+//! The Zargo package manager `inspect` subcommand tests.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::command::inspect::Command;
+
+///
+/// Writes the `application` build artifact into the temporary directory.
+///
+fn write_artifact(name: &str, application: zinc_types::Application) -> PathBuf {
+    let bytes = application.into_vec(&semver::Version::new(0, 1, 0));
+    write_file(name, bytes.as_slice())
+}
+
+///
+/// Writes the raw artifact `bytes` into the temporary directory.
+///
+fn write_file(name: &str, bytes: &[u8]) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("zargo-inspect-{}-{}.znb", name, std::process::id()));
+    fs::write(&path, bytes).expect(zinc_const::panic::TEST_DATA_VALID);
+    path
+}
+
+///
+/// Inspects the artifact at `path` and removes it.
+///
+fn inspect(path: PathBuf) -> anyhow::Result<serde_json::Value> {
+    let result = Command::inspect(path.as_path());
+    let _ = fs::remove_file(path);
+    result
+}
+
+///
+/// Creates the circuit fixture with a single unit test.
+///
+fn circuit() -> zinc_types::Application {
+    zinc_types::Application::new_circuit(
+        "circuit".to_owned(),
+        2,
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Boolean),
+        vec![(
+            "test_main".to_owned(),
+            zinc_types::UnitTest::new(1, true, None, false, false, None, None),
+        )]
+        .into_iter()
+        .collect(),
+        vec![
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+        ],
+    )
+}
+
+///
+/// Creates the contract fixture with a mutable and an immutable method.
+///
+fn contract() -> zinc_types::Application {
+    let balance = zinc_types::Type::Scalar(zinc_types::ScalarType::Integer(
+        zinc_types::IntegerType::BALANCE,
+    ));
+
+    zinc_types::Application::new_contract(
+        "contract".to_owned(),
+        vec![zinc_types::ContractFieldType::new(
+            "total".to_owned(),
+            balance.clone(),
+            true,
+            false,
+        )],
+        vec![
+            (
+                "deposit".to_owned(),
+                zinc_types::ContractMethod::new(
+                    0,
+                    "deposit".to_owned(),
+                    0,
+                    true,
+                    true,
+                    Some("owner".to_owned()),
+                    zinc_types::Type::Structure(vec![("amount".to_owned(), balance.clone())]),
+                    zinc_types::Type::Unit,
+                ),
+            ),
+            (
+                "total".to_owned(),
+                zinc_types::ContractMethod::new(
+                    0,
+                    "total".to_owned(),
+                    1,
+                    false,
+                    false,
+                    None,
+                    zinc_types::Type::Structure(vec![]),
+                    balance,
+                ),
+            ),
+        ]
+        .into_iter()
+        .collect(),
+        vec![].into_iter().collect(),
+        vec![
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+        ],
+        None,
+        vec![],
+    )
+}
+
+#[test]
+fn ok_circuit() {
+    let metadata =
+        inspect(write_artifact("circuit", circuit())).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(metadata["format_version"], 3);
+    assert_eq!(metadata["std_version"], zinc_const::version::STD);
+    assert_eq!(metadata["compiler_version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(metadata["project"]["name"], "circuit");
+    assert_eq!(metadata["project"]["version"], "0.1.0");
+    assert_eq!(metadata["project"]["type"], "circuit");
+    assert_eq!(metadata["instructions"], 3);
+    assert_eq!(metadata["methods"][0]["name"], "main");
+    assert_eq!(metadata["methods"][0]["address"], 2);
+    assert_eq!(metadata["tests"][0]["name"], "test_main");
+    assert_eq!(metadata["tests"][0]["should_panic"], true);
+    assert_ne!(
+        metadata["templates"][0]["input"],
+        metadata["templates"][0]["output"]
+    );
+
+    let summary = Command::summary(&metadata, None);
+    assert!(summary.contains("Project:      circuit 0.1.0 (circuit)"));
+    assert!(summary.contains("Instructions: 3"));
+    assert!(summary.contains("    test_main at 1, should panic"));
+}
+
+#[test]
+fn ok_contract() {
+    let metadata =
+        inspect(write_artifact("contract", contract())).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(metadata["project"]["type"], "contract");
+    assert_eq!(metadata["instructions"], 2);
+    assert_eq!(metadata["methods"][0]["name"], "deposit");
+    assert_eq!(metadata["methods"][0]["is_mutable"], true);
+    assert_eq!(metadata["methods"][0]["reads_msg"], true);
+    assert_eq!(metadata["methods"][0]["required_sender"], "owner");
+    assert_eq!(
+        metadata["methods"][1]["required_sender"],
+        serde_json::Value::Null
+    );
+    assert_eq!(metadata["methods"][1]["name"], "total");
+    assert_eq!(metadata["methods"][1]["address"], 1);
+    assert_eq!(metadata["tests"].as_array().map(Vec::len), Some(0));
+    assert_eq!(metadata["templates"].as_array().map(Vec::len), Some(2));
+    assert_eq!(
+        metadata["templates"][0]["output"].as_str().map(str::len),
+        Some(64)
+    );
+
+    let summary = Command::summary(&metadata["methods"], Some("methods"));
+    assert_eq!(
+        summary,
+        "    deposit at 0, mutable, reads zksync::msg, sender must be `self.owner`\n    total at 1\n"
+    );
+}
+
+#[test]
+fn ok_digests_stable() {
+    let first = inspect(write_artifact("stable-first", contract()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let second = inspect(write_artifact("stable-second", contract()))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(first["templates"], second["templates"]);
+}
+
+#[test]
+fn error_corrupted() {
+    let mut bytes = contract().into_vec(&semver::Version::new(0, 1, 0));
+    bytes.truncate(bytes.len() - 1);
+
+    let error = inspect(write_file("corrupted", bytes.as_slice()))
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+    let error = error
+        .downcast_ref::<zinc_types::ApplicationDecodingError>()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(
+        error,
+        zinc_types::ApplicationDecodingError::Truncated { .. }
+    ));
+    assert!(error.offset() < bytes.len());
+}
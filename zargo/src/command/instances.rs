@@ -0,0 +1,188 @@
+//!
+//! The Zargo package manager `instances` subcommand.
+//!
+
+use std::str::FromStr;
+
+use structopt::StructOpt;
+
+use crate::command::table;
+use crate::error::Error;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+
+///
+/// The Zargo package manager `instances` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Lists the published instances of a project version")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Sets the network name, where the instances reside.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Prints the instances as JSON instead of a table, if set.
+    #[structopt(long = "json")]
+    pub json: bool,
+
+    /// The project name and version in the `name@version` format.
+    pub project: ProjectReference,
+}
+
+///
+/// The project name and version, which are specified as `name@version`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectReference {
+    /// The project name.
+    pub name: String,
+    /// The project version.
+    pub version: semver::Version,
+}
+
+impl FromStr for ProjectReference {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.splitn(2, '@');
+
+        let name = match parts.next() {
+            Some(name) if !name.is_empty() => name.to_owned(),
+            _ => return Err(Error::ProjectReferenceInvalid(value.to_owned())),
+        };
+        let version = parts
+            .next()
+            .and_then(|version| semver::Version::parse(version).ok())
+            .ok_or_else(|| Error::ProjectReferenceInvalid(value.to_owned()))?;
+
+        Ok(Self { name, version })
+    }
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        network: Option<String>,
+        json: bool,
+        project: ProjectReference,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            network: network
+                .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            json,
+            project,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    /// All the pages are requested one by one, so the whole list is printed.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let mut instances = Vec::new();
+        loop {
+            let page = http_client
+                .instances(
+                    zinc_types::InstancesRequestPath::new(
+                        self.project.name.clone(),
+                        self.project.version.clone(),
+                    ),
+                    zinc_types::InstancesRequestQuery::new(instances.len(), None),
+                )
+                .await?;
+            let has_next_page = page.has_next_page() && !page.instances.is_empty();
+            instances.extend(page.instances);
+            if !has_next_page {
+                break;
+            }
+        }
+
+        if self.quiet {
+            return Ok(());
+        }
+
+        if self.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&instances).expect(zinc_const::panic::DATA_CONVERSION)
+            );
+        } else {
+            print!("{}", Self::table(instances.as_slice()));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Renders the `instances` as a table with a header row.
+    ///
+    pub fn table(instances: &[zinc_types::InstancesResponseInstance]) -> String {
+        let header = [
+            "ACCOUNT",
+            "INSTANCE",
+            "ADDRESS",
+            "OWNER",
+            "PUBLISHED",
+            "STORAGE ROOT",
+            "INTERFACES",
+        ];
+
+        let mut rows = vec![header.iter().map(|cell| (*cell).to_owned()).collect()];
+        for instance in instances.iter() {
+            rows.push(vec![
+                instance.account_id.to_string(),
+                instance.instance.to_owned(),
+                Self::address(&instance.address),
+                instance
+                    .owner
+                    .as_ref()
+                    .map(Self::address)
+                    .unwrap_or_else(|| "-".to_owned()),
+                instance.published_at.to_owned(),
+                instance
+                    .storage_root
+                    .to_owned()
+                    .unwrap_or_else(|| "-".to_owned()),
+                if instance.interfaces.is_empty() {
+                    "-".to_owned()
+                } else {
+                    instance.interfaces.join(", ")
+                },
+            ]);
+        }
+
+        table::render(rows)
+    }
+
+    ///
+    /// Formats the ETH `address` as a hexadecimal string.
+    ///
+    fn address(address: &zksync_types::Address) -> String {
+        serde_json::to_string(address)
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .replace("\"", "")
+    }
+}
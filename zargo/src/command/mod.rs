@@ -5,18 +5,30 @@
 pub mod build;
 pub mod call;
 pub mod clean;
+pub mod diff;
+pub mod doc;
 pub mod download;
 pub mod init;
+pub mod inspect;
+pub mod instances;
 pub mod new;
 pub mod proof_check;
 pub mod prove;
 pub mod publish;
 pub mod query;
+pub mod remove;
 pub mod run;
+pub mod search;
 pub mod setup;
+pub mod stdlib;
+pub mod storage;
+pub mod table;
 pub mod test;
 pub mod upload;
+pub mod upload_keys;
 pub mod verify;
+pub mod verify_source;
+pub mod version;
 
 use structopt::StructOpt;
 
@@ -25,18 +37,29 @@ use crate::error::Error;
 use self::build::Command as BuildCommand;
 use self::call::Command as CallCommand;
 use self::clean::Command as CleanCommand;
+use self::diff::Command as DiffCommand;
+use self::doc::Command as DocCommand;
 use self::download::Command as DownloadCommand;
 use self::init::Command as InitCommand;
+use self::inspect::Command as InspectCommand;
+use self::instances::Command as InstancesCommand;
 use self::new::Command as NewCommand;
 use self::proof_check::Command as ProofCheckCommand;
 use self::prove::Command as ProveCommand;
 use self::publish::Command as PublishCommand;
 use self::query::Command as QueryCommand;
+use self::remove::Command as RemoveCommand;
 use self::run::Command as RunCommand;
+use self::search::Command as SearchCommand;
 use self::setup::Command as SetupCommand;
+use self::stdlib::Command as StdCommand;
+use self::storage::Command as StorageCommand;
 use self::test::Command as TestCommand;
 use self::upload::Command as UploadCommand;
+use self::upload_keys::Command as UploadKeysCommand;
 use self::verify::Command as VerifyCommand;
+use self::verify_source::Command as VerifySourceCommand;
+use self::version::Command as VersionCommand;
 
 ///
 /// The Zargo package manager subcommand.
@@ -57,6 +80,16 @@ pub enum Command {
     Run(RunCommand),
     /// Runs the project unit tests.
     Test(TestCommand),
+    /// Lists the intrinsic and standard library functions.
+    Std(StdCommand),
+    /// Prints the build artifact metadata.
+    Inspect(InspectCommand),
+    /// Compares two build artifacts.
+    Diff(DiffCommand),
+    /// Builds the project documentation.
+    Doc(DocCommand),
+    /// Bumps the project version in the manifest.
+    Version(VersionCommand),
 
     /// Generates a pair of proving and verifying keys.
     Setup(SetupCommand),
@@ -73,11 +106,23 @@ pub enum Command {
     Query(QueryCommand),
     /// Calls a mutable smart contract method.
     Call(CallCommand),
+    /// Exports and imports the contract storage for local development.
+    Storage(StorageCommand),
+    /// Archives or purges a published contract instance.
+    Remove(RemoveCommand),
 
     /// Uploads a project to the specified network.
     Upload(UploadCommand),
+    /// Uploads the keys of an already uploaded project to the specified network.
+    UploadKeys(UploadKeysCommand),
     /// Downloads a project from the specified network.
     Download(DownloadCommand),
+    /// Lists the published instances of a project version.
+    Instances(InstancesCommand),
+    /// Searches the published projects by their names and descriptions.
+    Search(SearchCommand),
+    /// Checks that the uploaded bytecode is built from the uploaded source.
+    VerifySource(VerifySourceCommand),
 }
 
 impl Command {
@@ -93,9 +138,15 @@ impl Command {
             Self::Build(inner) => inner.execute().await?,
             Self::Run(inner) => inner.execute().await?,
             Self::Test(inner) => inner.execute().await?,
+            Self::Std(inner) => inner.execute()?,
+            Self::Inspect(inner) => inner.execute()?,
+            Self::Diff(inner) => inner.execute()?,
+            Self::Doc(inner) => inner.execute().await?,
+            Self::Version(inner) => inner.execute()?,
 
             Self::Setup(inner) => inner.execute()?,
             Self::Prove(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
+            Self::Verify(inner) if inner.is_standalone() => inner.execute()?,
             Self::Verify(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
             Self::ProofCheck(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
 
@@ -108,9 +159,15 @@ impl Command {
             Self::Call(inner) => {
                 inner.execute().await?;
             }
+            Self::Storage(inner) => inner.execute().await?,
+            Self::Remove(inner) => inner.execute().await?,
 
             Self::Upload(inner) => inner.execute().await?,
+            Self::UploadKeys(inner) => inner.execute().await?,
             Self::Download(inner) => inner.execute().await?,
+            Self::Instances(inner) => inner.execute().await?,
+            Self::Search(inner) => inner.execute().await?,
+            Self::VerifySource(inner) => inner.execute().await?,
         }
 
         Ok(())
@@ -127,6 +127,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                zinc_project::ManifestBuild::default(),
             )?;
         } else {
             Compiler::build_debug(
@@ -136,6 +137,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                zinc_project::ManifestBuild::default(),
             )?;
         }
 
@@ -148,6 +150,8 @@ impl Command {
                     &input_path,
                     &output_path,
                     method.as_str(),
+                    false,
+                    true,
                 )?;
 
                 VirtualMachine::setup_contract(
@@ -177,6 +181,8 @@ impl Command {
                     &binary_path,
                     &input_path,
                     &output_path,
+                    None,
+                    true,
                 )?;
 
                 VirtualMachine::setup_circuit(
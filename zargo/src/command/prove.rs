@@ -9,6 +9,8 @@ use structopt::StructOpt;
 
 use crate::error::Error;
 use crate::executable::virtual_machine::VirtualMachine;
+use crate::project::data::input::Input as InputFile;
+use crate::project::data::key::Key as KeyFile;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
@@ -43,6 +45,11 @@ pub struct Command {
     /// Uses the release build.
     #[structopt(long = "release")]
     pub is_release: bool,
+
+    /// Rewrites the input file with the current template if it was generated for another version
+    /// of the application arguments, keeping the values of the unchanged arguments.
+    #[structopt(long = "regenerate-input")]
+    pub regenerate_input: bool,
 }
 
 impl Command {
@@ -55,6 +62,7 @@ impl Command {
         manifest_path: PathBuf,
         method: Option<String>,
         is_release: bool,
+        regenerate_input: bool,
     ) -> Self {
         Self {
             verbosity,
@@ -62,6 +70,7 @@ impl Command {
             manifest_path,
             method,
             is_release,
+            regenerate_input,
         }
     }
 
@@ -112,6 +121,9 @@ impl Command {
         ));
         TargetDependenciesDirectory::create(&manifest_path)?;
 
+        InputFile::check_template(&input_path, &binary_path, self.regenerate_input, self.quiet)?;
+        KeyFile::check(&proving_key_path)?;
+
         match self.method {
             Some(method) => VirtualMachine::prove_contract(
                 self.verbosity,
@@ -3,9 +3,11 @@
 //!
 
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+use anyhow::Context;
 use colored::Colorize;
 use structopt::StructOpt;
 
@@ -28,6 +30,9 @@ use crate::project::target::bytecode::Bytecode as BytecodeFile;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
 use crate::project::target::Directory as TargetDirectory;
 
+use super::version::bump::Bump;
+use super::version::Command as VersionCommand;
+
 ///
 /// The Zargo package manager `publish` subcommand.
 ///
@@ -61,6 +66,28 @@ pub struct Command {
     /// Sets the change-pubkey fee token.
     #[structopt(long = "change-pubkey-fee-token", default_value = "ETH")]
     pub change_pubkey_fee_token: String,
+
+    /// Skips the trusted setup and publishes the contract without the keys.
+    /// The keys can be uploaded later with the `upload-keys` subcommand.
+    #[structopt(long = "skip-keys")]
+    pub skip_keys: bool,
+
+    /// Bumps the project version with `patch`, `minor`, or `major` before building.
+    #[structopt(long = "bump")]
+    pub bump: Option<Bump>,
+
+    /// Publishes another instance of the project version, which is already published.
+    #[structopt(long = "allow-existing")]
+    pub allow_existing: bool,
+
+    /// Skips the network check of the already published project versions.
+    #[structopt(long = "offline")]
+    pub offline: bool,
+
+    /// The path to the previous contract version binary. If passed, the storage layout
+    /// compatibility is checked before uploading.
+    #[structopt(long = "previous-build", parse(from_os_str))]
+    pub previous_build_path: Option<PathBuf>,
 }
 
 ///
@@ -96,6 +123,10 @@ impl Command {
         instance: String,
         network: Option<String>,
         change_pubkey_fee_token: Option<String>,
+        skip_keys: bool,
+        bump: Option<Bump>,
+        allow_existing: bool,
+        offline: bool,
     ) -> Self {
         Self {
             verbosity,
@@ -105,6 +136,10 @@ impl Command {
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
             change_pubkey_fee_token: change_pubkey_fee_token.unwrap_or_else(|| "ETH".to_owned()),
+            skip_keys,
+            bump,
+            allow_existing,
+            offline,
         }
     }
 
@@ -127,6 +162,25 @@ impl Command {
             _ => anyhow::bail!(Error::NotAContract),
         }
 
+        let manifest = match self.bump {
+            Some(bump) => {
+                VersionCommand::bump_manifest(&self.manifest_path, bump, self.quiet)?;
+                zinc_project::Manifest::try_from(&self.manifest_path)?
+            }
+            None => manifest,
+        };
+
+        if self.offline {
+            if !self.quiet {
+                eprintln!(
+                    "     {} the published versions check is skipped in the offline mode",
+                    "Warning".bright_yellow(),
+                );
+            }
+        } else if !self.allow_existing {
+            Self::check_version(&http_client, &manifest.project).await?;
+        }
+
         let mut manifest_path = self.manifest_path;
         if manifest_path.is_file() {
             manifest_path.pop();
@@ -168,13 +222,6 @@ impl Command {
         TargetDependenciesDirectory::create(&manifest_path)?;
 
         if let Some(dependencies) = manifest.dependencies {
-            let network = zksync::Network::from_str(self.network.as_str())
-                .map(Network::from)
-                .map_err(Error::NetworkInvalid)?;
-            let url = network
-                .try_into_url()
-                .map_err(Error::NetworkUnimplemented)?;
-            let http_client = HttpClient::new(url);
             let mut downloader = Downloader::new(&http_client, &manifest_path);
             downloader.download_dependency_list(dependencies).await?;
         }
@@ -186,38 +233,82 @@ impl Command {
             &manifest.project.version,
             &manifest_path,
             false,
+            zinc_project::ManifestBuild::default(),
         )?;
 
         let bytecode = BytecodeFile::try_from_path(&binary_path, true)?;
 
-        let input = InputFile::try_from_path(&input_path)?;
-        let arguments = input
-            .inner
-            .as_object()
-            .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
-            .get("arguments")
-            .cloned()
-            .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
-            .as_object()
-            .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
-            .get(zinc_const::contract::CONSTRUCTOR_IDENTIFIER)
-            .cloned()
-            .ok_or_else(|| {
-                Error::MissingInputSection(zinc_const::contract::CONSTRUCTOR_IDENTIFIER.to_owned())
-            })?;
-
-        if !verifying_key_path.exists() {
-            VirtualMachine::setup_contract(
-                self.verbosity,
-                self.quiet,
-                &binary_path,
-                zinc_const::contract::CONSTRUCTOR_IDENTIFIER,
-                &proving_key_path,
-                &verifying_key_path,
-            )?;
+        let contract = match zinc_types::Application::try_from_slice(bytecode.inner.as_slice())
+            .map_err(anyhow::Error::msg)?
+        {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+        if let Some(ref original) = contract.pruned_from {
+            anyhow::bail!(Error::ContractPruned(original.to_owned()));
+        }
+        if let Some(ref path) = self.previous_build_path {
+            Self::check_previous_build(path, &contract, self.quiet)
+                .with_context(|| path.to_string_lossy().to_string())?;
         }
+        let has_constructor = contract
+            .methods
+            .contains_key(zinc_const::contract::CONSTRUCTOR_IDENTIFIER);
+
+        let input = InputFile::try_from_path(&input_path)?;
+        let (arguments, storage) = if has_constructor {
+            let arguments = input
+                .inner
+                .as_object()
+                .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
+                .get("arguments")
+                .cloned()
+                .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
+                .as_object()
+                .ok_or_else(|| Error::MissingInputSection("arguments".to_owned()))?
+                .get(zinc_const::contract::CONSTRUCTOR_IDENTIFIER)
+                .cloned()
+                .ok_or_else(|| {
+                    Error::MissingInputSection(
+                        zinc_const::contract::CONSTRUCTOR_IDENTIFIER.to_owned(),
+                    )
+                })?;
+
+            (Some(arguments), None)
+        } else {
+            let storage = input
+                .inner
+                .as_object()
+                .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?
+                .get("storages")
+                .cloned()
+                .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?
+                .as_object()
+                .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?
+                .values()
+                .next()
+                .cloned()
+                .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?;
+
+            (None, Some(storage))
+        };
+
+        let verifying_key = if self.skip_keys {
+            Vec::new()
+        } else {
+            if !verifying_key_path.exists() {
+                VirtualMachine::setup_contract(
+                    self.verbosity,
+                    self.quiet,
+                    &binary_path,
+                    zinc_const::contract::CONSTRUCTOR_IDENTIFIER,
+                    &proving_key_path,
+                    &verifying_key_path,
+                )?;
+            }
 
-        let verifying_key = VerifyingKeyFile::try_from(&verifying_key_path)?;
+            VerifyingKeyFile::try_from(&verifying_key_path)?.inner
+        };
 
         if !self.quiet {
             eprintln!(
@@ -230,6 +321,18 @@ impl Command {
             );
         }
 
+        let private_key = PrivateKeyFile::try_from(&manifest_path)?;
+        let signer_private_key: H256 = private_key.inner.parse()?;
+        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)?;
+
+        let signature = zinc_types::PublishClaim::new(
+            manifest.project.name.clone(),
+            manifest.project.version.clone(),
+            &project,
+            bytecode.inner.as_slice(),
+        )
+        .sign(&signer_private_key)?;
+
         let response = http_client
             .publish(
                 zinc_types::PublishRequestQuery::new(
@@ -242,7 +345,10 @@ impl Command {
                     project,
                     bytecode.inner,
                     arguments,
-                    verifying_key.inner,
+                    storage,
+                    verifying_key,
+                    signer_address,
+                    signature,
                 ),
             )
             .await?;
@@ -256,11 +362,6 @@ impl Command {
             );
         }
 
-        let private_key = PrivateKeyFile::try_from(&manifest_path)?;
-
-        let signer_private_key: H256 = private_key.inner.parse()?;
-        let signer_address = PackedEthSignature::address_from_private_key(&signer_private_key)?;
-
         let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
             signer_address,
             PrivateKeySigner::new(signer_private_key),
@@ -293,4 +394,83 @@ impl Command {
 
         Ok(Data::new(address, response.account_id))
     }
+
+    ///
+    /// Checks that the `project` version has not been published to the network yet, so the
+    /// publishing fails before the project is built and uploaded.
+    ///
+    async fn check_version(
+        http_client: &HttpClient,
+        project: &zinc_project::ManifestProject,
+    ) -> anyhow::Result<()> {
+        let mut versions: Vec<semver::Version> = http_client
+            .metadata()
+            .await?
+            .projects
+            .into_iter()
+            .filter(|published| published.name == project.name)
+            .map(|published| published.version)
+            .collect();
+
+        if versions.contains(&project.version) {
+            versions.sort();
+            anyhow::bail!(Error::ProjectVersionExists {
+                name: project.name.to_owned(),
+                version: project.version.to_owned(),
+                existing: versions
+                    .into_iter()
+                    .map(|version| version.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks that the `contract` can replace the previous version built at `path`, that is,
+    /// the storage layout is the same, only appends fields, or is migrated by the `#[upgrade]`
+    /// method.
+    ///
+    fn check_previous_build(
+        path: &PathBuf,
+        contract: &zinc_types::Contract,
+        quiet: bool,
+    ) -> anyhow::Result<()> {
+        let bytecode = fs::read(path)?;
+        let previous = match zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(anyhow::Error::msg)?
+        {
+            zinc_types::Application::Contract(previous) => previous,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+
+        let migration = contract
+            .migration(&previous)
+            .map_err(Error::StorageUpgrade)?;
+        if quiet {
+            return Ok(());
+        }
+
+        match migration {
+            zinc_types::ContractMigration::Reuse => {}
+            zinc_types::ContractMigration::Append(fields) => eprintln!(
+                "   {} the storage fields {} initialized with the default values",
+                "Appending".bright_green(),
+                fields
+                    .iter()
+                    .map(|field| format!("`{}`", field))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ),
+            zinc_types::ContractMigration::Method(method) => eprintln!(
+                "   {} the storage with the `{}` method on upgrade",
+                "Migrating".bright_green(),
+                method.name,
+            ),
+        }
+
+        Ok(())
+    }
 }
@@ -122,6 +122,9 @@ impl Command {
                     .get(method)
                     .cloned()
                     .ok_or_else(|| Error::MissingInputSection(format!("arguments.{}", method)))?;
+                zinc_types::ValueLimits::default()
+                    .check(&arguments)
+                    .map_err(|error| Error::InputLimit(method.to_owned(), error))?;
 
                 if !self.quiet {
                     eprintln!(
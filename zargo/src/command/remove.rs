@@ -0,0 +1,123 @@
+//!
+//! The Zargo package manager `remove` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use zksync::web3::types::H256;
+
+use crate::error::Error;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
+
+///
+/// The Zargo package manager `remove` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Archives or purges a published contract instance")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file, whose private key signs the removal.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the contract resides.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Sets the zkSync account ID of the contract.
+    #[structopt(long = "contract-id")]
+    pub contract_id: u64,
+
+    /// Sets the Zandbox bearer token, which is the keys token, or the admin one if purging.
+    #[structopt(long = "token")]
+    pub token: String,
+
+    /// Deletes the contract and its storage instead of archiving it.
+    #[structopt(long = "purge")]
+    pub purge: bool,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let private_key = PrivateKeyFile::try_from(&manifest_path)?;
+        let signer_private_key: H256 = private_key.inner.parse()?;
+
+        if !self.quiet {
+            eprintln!(
+                "    {} the contract with account ID {} on network `{}`",
+                if self.purge { "Purging" } else { "Archiving" }.bright_green(),
+                self.contract_id,
+                network,
+            );
+        }
+
+        let challenge = http_client
+            .delete_challenge(zinc_types::RemoveRequestPath::new(self.contract_id))
+            .await?;
+
+        let signature =
+            zinc_types::ContractRemoval::new(self.contract_id, challenge.nonce.clone(), self.purge)
+                .sign(&signer_private_key)?;
+
+        let response = http_client
+            .remove(
+                zinc_types::RemoveRequestPath::new(self.contract_id),
+                zinc_types::RemoveRequestQuery::new(self.purge),
+                zinc_types::RemoveRequestBody::new(challenge.nonce, signature),
+                self.token.as_str(),
+            )
+            .await?;
+
+        if !self.quiet {
+            match response.archived_at {
+                Some(archived_at) => eprintln!(
+                    "    {} the contract with account ID {} at {}",
+                    "Archived".bright_green(),
+                    response.account_id,
+                    archived_at,
+                ),
+                None => eprintln!(
+                    "      {} the contract with account ID {}",
+                    "Purged".bright_green(),
+                    response.account_id,
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
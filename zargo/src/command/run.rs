@@ -14,6 +14,8 @@ use crate::executable::virtual_machine::VirtualMachine;
 use crate::http::downloader::Downloader;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
+use crate::project::data::input::Input as InputFile;
+use crate::project::data::output::Output as OutputFile;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
 use crate::project::data::Directory as DataDirectory;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
@@ -52,12 +54,41 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Writes the witness to the file, in JSON if the extension is `json`, and in the compact
+    /// binary form otherwise. Only for circuits.
+    #[structopt(long = "output-witness", parse(from_os_str))]
+    pub output_witness_path: Option<PathBuf>,
+
+    /// Runs the contract method in the constant-time audit mode and prints the instructions,
+    /// whose host-side cost depends on the witness. Only for contracts.
+    #[structopt(long = "audit")]
+    pub is_audit: bool,
+
+    /// Rewrites the input file with the current template if it was generated for another version
+    /// of the application arguments, keeping the values of the unchanged arguments.
+    #[structopt(long = "regenerate-input")]
+    pub regenerate_input: bool,
+
+    /// Prints the output JSON as is, instead of rendering it with the output type metadata.
+    #[structopt(long = "raw")]
+    pub is_raw: bool,
+
+    /// Prints the wall time of each compilation phase and the peak memory usage.
+    /// The format is either `table` (default) or `json`. Overrides the manifest `build` section.
+    #[structopt(long = "timings", possible_values = &["table", "json"])]
+    pub timings: Option<Option<zinc_project::ManifestTimings>>,
+
+    /// The maximal number of the bytecode instructions. Overrides the manifest `build` section.
+    #[structopt(long = "max-instructions")]
+    pub max_instructions: Option<usize>,
 }
 
 impl Command {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         verbosity: usize,
         quiet: bool,
@@ -65,6 +96,12 @@ impl Command {
         method: Option<String>,
         is_release: bool,
         network: Option<String>,
+        output_witness_path: Option<PathBuf>,
+        is_audit: bool,
+        regenerate_input: bool,
+        is_raw: bool,
+        timings: Option<Option<zinc_project::ManifestTimings>>,
+        max_instructions: Option<usize>,
     ) -> Self {
         Self {
             verbosity,
@@ -74,6 +111,12 @@ impl Command {
             is_release,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            output_witness_path,
+            is_audit,
+            regenerate_input,
+            is_raw,
+            timings,
+            max_instructions,
         }
     }
 
@@ -87,6 +130,12 @@ impl Command {
             zinc_project::ProjectType::Contract if self.method.is_none() => {
                 anyhow::bail!(Error::MethodMissing)
             }
+            zinc_project::ProjectType::Contract if self.output_witness_path.is_some() => {
+                anyhow::bail!(Error::NotACircuit)
+            }
+            zinc_project::ProjectType::Circuit if self.is_audit => {
+                anyhow::bail!(Error::NotAContract)
+            }
             _ => {}
         }
 
@@ -145,6 +194,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.overrides(),
             )?;
         } else {
             Compiler::build_debug(
@@ -154,17 +204,22 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.overrides(),
             )?;
         }
 
+        InputFile::check_template(&input_path, &binary_path, self.regenerate_input, self.quiet)?;
+
         match self.method {
-            Some(method) => VirtualMachine::run_contract(
+            Some(ref method) => VirtualMachine::run_contract(
                 self.verbosity,
                 self.quiet,
                 &binary_path,
                 &input_path,
                 &output_path,
                 method.as_str(),
+                self.is_audit,
+                self.is_raw,
             ),
             None => VirtualMachine::run_circuit(
                 self.verbosity,
@@ -172,9 +227,32 @@ impl Command {
                 &binary_path,
                 &input_path,
                 &output_path,
+                self.output_witness_path.as_ref(),
+                self.is_raw,
             ),
         }?;
 
+        if !self.is_raw {
+            let output = OutputFile::try_from_path(&output_path)?;
+            println!(
+                "{}",
+                output.to_pretty_string(&binary_path, self.method.as_deref())?
+            );
+        }
+
         Ok(())
     }
+
+    ///
+    /// Returns the manifest `build` section values overridden by the command line options.
+    ///
+    fn overrides(&self) -> zinc_project::ManifestBuild {
+        zinc_project::ManifestBuild {
+            max_instructions: self.max_instructions,
+            timings: self
+                .timings
+                .map(|format| format.unwrap_or(zinc_project::ManifestTimings::Table)),
+            ..zinc_project::ManifestBuild::default()
+        }
+    }
 }
@@ -0,0 +1,138 @@
+//!
+//! The Zargo package manager `search` subcommand.
+//!
+
+use std::str::FromStr;
+
+use structopt::StructOpt;
+
+use crate::command::table;
+use crate::error::Error;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+
+///
+/// The Zargo package manager `search` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Searches the published projects by their names and descriptions")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Sets the network name, where the projects are published.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Sets the maximal number of the printed projects, which is capped by the server.
+    #[structopt(long = "limit")]
+    pub limit: Option<usize>,
+
+    /// Prints the projects as JSON instead of a table, if set.
+    #[structopt(long = "json")]
+    pub json: bool,
+
+    /// The text searched for in the project names and descriptions.
+    pub text: String,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        network: Option<String>,
+        limit: Option<usize>,
+        json: bool,
+        text: String,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            network: network
+                .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            limit,
+            json,
+            text,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    /// Only the first page is requested, so the best matches are printed.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let page = http_client
+            .search(zinc_types::SearchRequestQuery::new(
+                self.text, 0, self.limit,
+            ))
+            .await?;
+
+        if self.quiet {
+            return Ok(());
+        }
+
+        if self.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&page.projects)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+            );
+        } else {
+            print!("{}", Self::table(&page));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Renders the found projects as a table with a header row.
+    ///
+    /// The number of the remaining projects is appended, if the page is not the last one.
+    ///
+    pub fn table(page: &zinc_types::SearchResponseBody) -> String {
+        let header = ["NAME", "VERSION", "TYPE", "PUBLISHED", "DESCRIPTION"];
+
+        let mut rows = vec![header.iter().map(|cell| (*cell).to_owned()).collect()];
+        for project in page.projects.iter() {
+            rows.push(vec![
+                project.name.to_owned(),
+                project.version.to_string(),
+                project.r#type.to_string(),
+                project.published_at.to_owned(),
+                project
+                    .description
+                    .to_owned()
+                    .unwrap_or_else(|| "-".to_owned()),
+            ]);
+        }
+
+        let mut table = table::render(rows);
+        if page.has_next_page() {
+            table.push_str(
+                format!(
+                    "... and {} more\n",
+                    page.total - page.offset - page.projects.len()
+                )
+                .as_str(),
+            );
+        }
+        table
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! The Zargo package manager `std` subcommand.
+//!
+
+use structopt::StructOpt;
+
+use crate::executable::compiler::Compiler;
+
+///
+/// The Zargo package manager `std` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Lists the intrinsic and standard library functions")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Prints the functions as JSON instead of signatures, if set.
+    #[structopt(long = "json")]
+    pub json: bool,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let target_info = Compiler::target_info()?;
+
+        if self.quiet {
+            return Ok(());
+        }
+
+        if self.json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&target_info)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+            );
+        } else {
+            print!("{}", Self::signatures(&target_info));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Renders the `target_info` functions as signatures followed by their descriptions.
+    ///
+    pub fn signatures(target_info: &serde_json::Value) -> String {
+        let mut result = String::new();
+
+        let functions = match target_info["functions"].as_array() {
+            Some(functions) => functions,
+            None => return result,
+        };
+        for function in functions.iter() {
+            let arguments = function["arguments"]
+                .as_array()
+                .map(|arguments| {
+                    arguments
+                        .iter()
+                        .map(|argument| {
+                            format!(
+                                "{}: {}",
+                                argument["name"].as_str().unwrap_or_default(),
+                                argument["type"].as_str().unwrap_or_default()
+                            )
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+
+            result.push_str(
+                format!(
+                    "{}({}) -> {}\n    {}\n",
+                    function["path"].as_str().unwrap_or_default(),
+                    arguments,
+                    function["return_type"].as_str().unwrap_or_default(),
+                    function["description"].as_str().unwrap_or_default(),
+                )
+                .as_str(),
+            );
+        }
+
+        result
+    }
+}
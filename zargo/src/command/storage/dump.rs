@@ -0,0 +1,99 @@
+//!
+//! The Zargo package manager `storage dump` subcommand.
+//!
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+
+use super::state::State;
+
+///
+/// The Zargo package manager `storage dump` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Dumps the full contract storage from the specified network")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Sets the network name, where the contract resides.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Sets the zkSync account ID of the contract.
+    #[structopt(long = "contract-id")]
+    pub contract_id: u64,
+
+    /// Sets the Zandbox keys token, which is required, since the private fields are dumped as well.
+    #[structopt(long = "token")]
+    pub token: String,
+
+    /// The path to the storage state file to write.
+    #[structopt(long = "output", parse(from_os_str))]
+    pub output_path: PathBuf,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let mut offset = 0;
+        let page = http_client
+            .storage(
+                zinc_types::StorageRequestQuery::new(self.contract_id, offset, None),
+                self.token.as_str(),
+            )
+            .await?;
+
+        if !self.quiet {
+            eprintln!(
+                "     {} the storage of the contract `{} v{}` with account ID {} on network `{}`",
+                "Dumping".bright_green(),
+                page.name,
+                page.version,
+                self.contract_id,
+                network,
+            );
+        }
+
+        let limit = page.limit;
+        let mut has_next_page = page.has_next_page();
+        let mut state = State::new(page);
+        while has_next_page {
+            offset += limit;
+            let page = http_client
+                .storage(
+                    zinc_types::StorageRequestQuery::new(self.contract_id, offset, Some(limit)),
+                    self.token.as_str(),
+                )
+                .await?;
+            has_next_page = page.has_next_page();
+            state.extend(page)?;
+        }
+
+        state.write_to(&self.output_path)?;
+
+        Ok(())
+    }
+}
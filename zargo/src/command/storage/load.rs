@@ -0,0 +1,148 @@
+//!
+//! The Zargo package manager `storage load` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::executable::compiler::Compiler;
+use crate::project::data::input::Input as InputFile;
+use crate::project::data::Directory as DataDirectory;
+use crate::project::target::Directory as TargetDirectory;
+
+use super::state::State;
+
+///
+/// The Zargo package manager `storage load` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Loads the dumped contract storage into the project input file")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// The path to the storage state file written by `zargo storage dump`.
+    #[structopt(parse(from_os_str))]
+    pub state_path: PathBuf,
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    /// The storage is written to the `storages` section of the input file as the instance with
+    /// the zero address, which is used by `zargo run` and the unit tests run by `zargo test`.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        match manifest.project.r#type {
+            zinc_project::ProjectType::Contract => {}
+            _ => anyhow::bail!(Error::NotAContract),
+        }
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let state = State::try_from_path(&self.state_path)?;
+
+        TargetDirectory::create(&manifest_path, false)?;
+        let mut binary_path = TargetDirectory::path(&manifest_path, false);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        DataDirectory::create(&manifest_path)?;
+        let mut input_path = DataDirectory::path(&manifest_path);
+        input_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::INPUT,
+            zinc_const::extension::JSON,
+        ));
+
+        Compiler::build_debug(
+            self.verbosity,
+            self.quiet,
+            manifest.project.name.as_str(),
+            &manifest.project.version,
+            &manifest_path,
+            false,
+            zinc_project::ManifestBuild::default(),
+        )?;
+
+        let bytecode =
+            fs::read(&binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let contract = match zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(anyhow::Error::msg)
+            .with_context(|| binary_path.to_string_lossy().to_string())?
+        {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => anyhow::bail!(Error::NotAContract),
+        };
+
+        if !self.quiet {
+            eprintln!(
+                "     {} the storage of the contract `{} v{}` into the input file",
+                "Loading".bright_green(),
+                state.name,
+                state.version,
+            );
+        }
+
+        let storage = state.into_input_storage(&contract)?;
+        let mut input = InputFile::try_from_path(&input_path)?.inner;
+        Self::set_storage(&mut input, storage)?;
+        fs::write(
+            &input_path,
+            serde_json::to_string_pretty(&input).expect(zinc_const::panic::DATA_CONVERSION),
+        )
+        .with_context(|| input_path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Sets the `storage` of the local contract instance in the `input` file.
+    ///
+    pub fn set_storage(
+        input: &mut serde_json::Value,
+        storage: serde_json::Value,
+    ) -> anyhow::Result<()> {
+        let storages = input
+            .as_object_mut()
+            .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?
+            .entry("storages")
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| Error::MissingInputSection("storages".to_owned()))?;
+        storages.insert(
+            "0x0000000000000000000000000000000000000000".to_owned(),
+            storage,
+        );
+
+        Ok(())
+    }
+}
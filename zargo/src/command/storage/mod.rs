@@ -0,0 +1,39 @@
+//!
+//! The Zargo package manager `storage` subcommand.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod dump;
+pub mod load;
+pub mod state;
+
+use structopt::StructOpt;
+
+use self::dump::Command as DumpCommand;
+use self::load::Command as LoadCommand;
+
+///
+/// The Zargo package manager `storage` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Exports and imports the contract storage for local development")]
+pub enum Command {
+    /// Dumps the full contract storage from the specified network.
+    Dump(DumpCommand),
+    /// Loads the dumped contract storage into the project input file.
+    Load(LoadCommand),
+}
+
+impl Command {
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        match self {
+            Self::Dump(inner) => inner.execute().await,
+            Self::Load(inner) => inner.execute().await,
+        }
+    }
+}
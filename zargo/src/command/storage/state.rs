@@ -0,0 +1,172 @@
+//!
+//! The contract storage state file representation.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Error;
+
+///
+/// The contract storage state file representation.
+///
+/// The field values are stored in the typed JSON format, which is driven by the contract storage
+/// metadata, so the state can be loaded into the `storages` section of the input file as is.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct State {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+    /// The contract ETH address.
+    pub address: zksync_types::Address,
+    /// The contract storage layout hash.
+    pub storage_layout: String,
+    /// The contract storage fields, including the private and implicit ones.
+    pub fields: Vec<Field>,
+}
+
+///
+/// The contract storage state field.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    pub r#type: String,
+    /// The field value in the typed JSON format.
+    pub value: serde_json::Value,
+}
+
+impl State {
+    ///
+    /// Creates the state from the first storage `page`.
+    ///
+    pub fn new(page: zinc_types::StorageResponseBody) -> Self {
+        Self {
+            name: page.name,
+            version: page.version,
+            address: page.address,
+            storage_layout: page.storage_layout,
+            fields: page
+                .fields
+                .into_iter()
+                .map(|field| Field {
+                    name: field.name,
+                    r#type: field.r#type,
+                    value: field.value,
+                })
+                .collect(),
+        }
+    }
+
+    ///
+    /// Appends the map entries of the next storage `page`.
+    ///
+    /// The other fields are returned by every page in full, so they are left as is.
+    ///
+    pub fn extend(&mut self, page: zinc_types::StorageResponseBody) -> Result<(), Error> {
+        if page.storage_layout != self.storage_layout || page.fields.len() != self.fields.len() {
+            return Err(Error::StorageStateChanged);
+        }
+
+        for (field, page_field) in self.fields.iter_mut().zip(page.fields) {
+            if page_field.total.is_none() {
+                continue;
+            }
+
+            match (&mut field.value, page_field.value) {
+                (serde_json::Value::Array(entries), serde_json::Value::Array(page_entries)) => {
+                    entries.extend(page_entries)
+                }
+                _ => return Err(Error::StorageStateChanged),
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Reads the state file at `path`.
+    ///
+    pub fn try_from_path(path: &PathBuf) -> anyhow::Result<Self> {
+        let data = fs::read_to_string(path).with_context(|| path.to_string_lossy().to_string())?;
+        let state = serde_json::from_str(data.as_str())
+            .with_context(|| path.to_string_lossy().to_string())?;
+        Ok(state)
+    }
+
+    ///
+    /// Writes the state file to `path`.
+    ///
+    pub fn write_to(&self, path: &PathBuf) -> anyhow::Result<()> {
+        let data = serde_json::to_string_pretty(self).expect(zinc_const::panic::DATA_CONVERSION);
+        fs::write(path, data + "\n").with_context(|| path.to_string_lossy().to_string())?;
+        Ok(())
+    }
+
+    ///
+    /// Validates the state against the `contract` storage layout and converts it into the array
+    /// of the field values, as in the `storages` section of the input file.
+    ///
+    pub fn into_input_storage(
+        self,
+        contract: &zinc_types::Contract,
+    ) -> Result<serde_json::Value, Error> {
+        if self.storage_layout != contract.storage_layout {
+            return Err(Error::StorageLayoutMismatch {
+                diff: Self::diff(self.fields.as_slice(), contract.storage.as_slice()),
+                dumped: self.storage_layout,
+                current: contract.storage_layout.to_owned(),
+            });
+        }
+
+        let mut values = Vec::with_capacity(self.fields.len());
+        for (field, r#type) in self.fields.into_iter().zip(contract.storage.iter()) {
+            let value =
+                zinc_types::Value::try_from_typed_json(field.value, r#type.r#type.to_owned())
+                    .map_err(|error| Error::StorageFieldInvalid {
+                        name: r#type.name.to_owned(),
+                        message: error.to_string(),
+                    })?;
+            values.push(value.into_json());
+        }
+
+        Ok(serde_json::Value::Array(values))
+    }
+
+    ///
+    /// Lists the mismatched fields at the same positions, prefixing the `dumped` ones with `-`
+    /// and the `current` ones with `+`.
+    ///
+    fn diff(dumped: &[Field], current: &[zinc_types::ContractFieldType]) -> String {
+        let mut lines = Vec::new();
+
+        for index in 0..std::cmp::max(dumped.len(), current.len()) {
+            let dumped = dumped
+                .get(index)
+                .map(|field| format!("{}: {}", field.name, field.r#type));
+            let current = current
+                .get(index)
+                .map(|field| format!("{}: {}", field.name, field.r#type));
+            if dumped == current {
+                continue;
+            }
+
+            if let Some(dumped) = dumped {
+                lines.push(format!("- {}", dumped));
+            }
+            if let Some(current) = current {
+                lines.push(format!("+ {}", current));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
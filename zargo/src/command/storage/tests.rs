@@ -0,0 +1,204 @@
+//!
+//! The Zargo package manager `storage` subcommand tests.
+//!
+
+use std::fs;
+
+use crate::command::storage::load::Command as LoadCommand;
+use crate::command::storage::state::State;
+use crate::error::Error;
+
+///
+/// Creates the contract fixture with an array and a map field.
+///
+fn contract(values_size: usize) -> zinc_types::Contract {
+    zinc_types::Contract::new(
+        "test".to_owned(),
+        vec![
+            zinc_types::ContractFieldType::new(
+                "address".to_owned(),
+                zinc_types::Type::eth_address(),
+                true,
+                true,
+            ),
+            zinc_types::ContractFieldType::new(
+                "values".to_owned(),
+                zinc_types::Type::Array(
+                    Box::new(zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into())),
+                    values_size,
+                ),
+                true,
+                false,
+            ),
+            zinc_types::ContractFieldType::new(
+                "entries".to_owned(),
+                zinc_types::Type::Map {
+                    key_type: Box::new(zinc_types::Type::Scalar(
+                        zinc_types::IntegerType::U8.into(),
+                    )),
+                    value_type: Box::new(zinc_types::Type::Scalar(
+                        zinc_types::IntegerType::new(false, 64).into(),
+                    )),
+                },
+                false,
+                false,
+            ),
+        ],
+        Default::default(),
+        Default::default(),
+        vec![],
+        None,
+        vec![],
+    )
+}
+
+///
+/// Creates the `contract` storage page with the map `entries` between `offset` and `limit`.
+///
+fn page(
+    contract: &zinc_types::Contract,
+    entries: &[serde_json::Value],
+    offset: usize,
+    limit: usize,
+) -> zinc_types::StorageResponseBody {
+    let values = vec![
+        serde_json::json!("0x2a"),
+        serde_json::json!(["1", "2", "3"]),
+        serde_json::Value::Array(entries.iter().skip(offset).take(limit).cloned().collect()),
+    ];
+
+    zinc_types::StorageResponseBody::new(
+        contract.name.to_owned(),
+        semver::Version::new(1, 0, 0),
+        Default::default(),
+        contract.storage_layout.to_owned(),
+        contract
+            .storage
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let total = match field.r#type {
+                    zinc_types::Type::Map { .. } => Some(entries.len()),
+                    _ => None,
+                };
+                zinc_types::StorageResponseField::new(
+                    field.name.to_owned(),
+                    field.r#type.to_string(),
+                    value,
+                    total,
+                )
+            })
+            .collect(),
+        offset,
+        limit,
+    )
+}
+
+///
+/// Creates the map entries fixture.
+///
+fn entries() -> Vec<serde_json::Value> {
+    (0..5)
+        .map(|index| {
+            serde_json::json!({
+                "key": index.to_string(),
+                "value": (index * 1_000).to_string(),
+            })
+        })
+        .collect()
+}
+
+///
+/// Dumps the `contract` storage with the map entries split into pages of `limit` entries.
+///
+fn dump(contract: &zinc_types::Contract, limit: usize) -> State {
+    let entries = entries();
+
+    let mut offset = 0;
+    let first = page(contract, entries.as_slice(), offset, limit);
+    let mut has_next_page = first.has_next_page();
+    let mut state = State::new(first);
+    while has_next_page {
+        offset += limit;
+        let next = page(contract, entries.as_slice(), offset, limit);
+        has_next_page = next.has_next_page();
+        state
+            .extend(next)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+    state
+}
+
+#[test]
+fn ok_round_trip() {
+    let contract = contract(3);
+    let state = dump(&contract, 2);
+
+    let mut path = std::env::temp_dir();
+    path.push(format!("zargo-storage-{}.json", std::process::id()));
+    state
+        .write_to(&path)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let loaded = State::try_from_path(&path);
+    let _ = fs::remove_file(path);
+    let loaded = loaded.expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(loaded, state);
+
+    let mut input = serde_json::json!({
+        "storages": {},
+        "arguments": {},
+    });
+    let storage = loaded
+        .into_input_storage(&contract)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    LoadCommand::set_storage(&mut input, storage).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        input["storages"]["0x0000000000000000000000000000000000000000"],
+        serde_json::json!(["0x2a", ["1", "2", "3"], serde_json::Value::Array(entries()),])
+    );
+}
+
+#[test]
+fn ok_single_page() {
+    let contract = contract(3);
+
+    assert_eq!(dump(&contract, 2), dump(&contract, 10));
+}
+
+#[test]
+fn error_layout_mismatch() {
+    let dumped = contract(3);
+    let current = contract(4);
+    let state = dump(&dumped, 2);
+
+    let result = state.into_input_storage(&current);
+
+    match result {
+        Err(Error::StorageLayoutMismatch {
+            dumped: dumped_layout,
+            current: current_layout,
+            diff,
+        }) => {
+            assert_eq!(dumped_layout, dumped.storage_layout);
+            assert_eq!(current_layout, current.storage_layout);
+            assert_eq!(diff, "- values: [u8; 3]\n+ values: [u8; 4]");
+        }
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn error_field_invalid() {
+    let contract = contract(3);
+    let mut state = dump(&contract, 2);
+    state.fields[1].value = serde_json::json!(["1", "2", "256"]);
+
+    let result = state.into_input_storage(&contract);
+
+    assert!(
+        matches!(result, Err(Error::StorageFieldInvalid { ref name, .. }) if name == "values"),
+        "{:?}",
+        result
+    );
+}
@@ -0,0 +1,33 @@
+//!
+//! The Zargo package manager table output.
+//!
+
+///
+/// Renders the `rows` as a table with the columns aligned to the widest cells.
+///
+/// The first row is expected to be the header.
+///
+pub fn render(rows: Vec<Vec<String>>) -> String {
+    let mut widths = Vec::new();
+    for row in rows.iter() {
+        for (index, cell) in row.iter().enumerate() {
+            if index == widths.len() {
+                widths.push(0);
+            }
+            widths[index] = std::cmp::max(widths[index], cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    for row in rows.into_iter() {
+        let line = row
+            .into_iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
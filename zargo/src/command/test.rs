@@ -14,14 +14,17 @@ use crate::executable::virtual_machine::VirtualMachine;
 use crate::http::downloader::Downloader;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
+use crate::project::data::input::Input as InputFile;
+use crate::project::data::Directory as DataDirectory;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
 use crate::project::target::Directory as TargetDirectory;
+use crate::project::tests::Directory as TestsDirectory;
 
 ///
 /// The Zargo package manager `test` subcommand.
 ///
 #[derive(Debug, StructOpt)]
-#[structopt(about = "Runs the project unit tests")]
+#[structopt(about = "Runs the project unit tests, including the ones in the `tests` directory")]
 pub struct Command {
     /// Prints more logs, if passed several times.
     #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
@@ -42,17 +45,61 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Collects the instruction coverage and writes it as an `lcov` report to the target directory.
+    #[structopt(long = "coverage")]
+    pub coverage: bool,
+
+    /// Hides the passed tests faster than the specified number of milliseconds.
+    #[structopt(long = "min-time")]
+    pub min_time: Option<u64>,
+
+    /// Evaluates all the tests without synthesizing the constraints, including the `#[proof]` ones.
+    /// The constraint satisfiability is not checked in this mode.
+    #[structopt(long = "fast")]
+    pub is_fast: bool,
+
+    /// Fails the tests running longer than the specified number of seconds.
+    #[structopt(long = "timeout")]
+    pub timeout: Option<u64>,
+
+    /// Rewrites the input file with the current template if it was generated for another version
+    /// of the application arguments, keeping the values of the unchanged arguments.
+    #[structopt(long = "regenerate-input")]
+    pub regenerate_input: bool,
+
+    /// Prints the wall time of each compilation phase and the peak memory usage.
+    /// The format is either `table` (default) or `json`. Overrides the manifest `build` section.
+    #[structopt(long = "timings", possible_values = &["table", "json"])]
+    pub timings: Option<Option<zinc_project::ManifestTimings>>,
+
+    /// The maximal number of the bytecode instructions. Overrides the manifest `build` section.
+    #[structopt(long = "max-instructions")]
+    pub max_instructions: Option<usize>,
+
+    /// Runs only the tests whose fully qualified names match the filter, which is either the whole
+    /// name or its suffix, e.g. `overflow` or `math::overflow` for `exchange::math::overflow`.
+    pub filter: Option<String>,
 }
 
 impl Command {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         verbosity: usize,
         quiet: bool,
         manifest_path: PathBuf,
         network: Option<String>,
+        coverage: bool,
+        min_time: Option<u64>,
+        is_fast: bool,
+        timeout: Option<u64>,
+        regenerate_input: bool,
+        timings: Option<Option<zinc_project::ManifestTimings>>,
+        max_instructions: Option<usize>,
+        filter: Option<String>,
     ) -> Self {
         Self {
             verbosity,
@@ -60,6 +107,14 @@ impl Command {
             manifest_path,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            coverage,
+            min_time,
+            is_fast,
+            timeout,
+            regenerate_input,
+            timings,
+            max_instructions,
+            filter,
         }
     }
 
@@ -76,7 +131,7 @@ impl Command {
 
         TargetDirectory::create(&manifest_path, true)?;
         let target_directory_path = TargetDirectory::path(&manifest_path, true);
-        let mut binary_path = target_directory_path;
+        let mut binary_path = target_directory_path.clone();
         binary_path.push(format!(
             "{}.{}",
             zinc_const::file_name::BINARY,
@@ -97,17 +152,69 @@ impl Command {
             downloader.download_dependency_list(dependencies).await?;
         }
 
-        Compiler::build_release(
+        let test_roots = TestsDirectory::files(&manifest_path)?;
+
+        Compiler::build_tests(
             self.verbosity,
             self.quiet,
             manifest.project.name.as_str(),
             &manifest.project.version,
             &manifest_path,
-            true,
+            test_roots.as_slice(),
+            self.overrides(),
         )?;
 
-        VirtualMachine::test(self.verbosity, self.quiet, &binary_path)?;
+        let mut input_path = DataDirectory::path(&manifest_path);
+        input_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::INPUT,
+            zinc_const::extension::JSON,
+        ));
+        let input_path = match manifest.project.r#type {
+            zinc_project::ProjectType::Contract if input_path.exists() => Some(input_path),
+            _ => None,
+        };
+        if let Some(ref input_path) = input_path {
+            InputFile::check_template(input_path, &binary_path, self.regenerate_input, self.quiet)?;
+        }
+
+        let coverage_path = if self.coverage {
+            let mut coverage_path = target_directory_path;
+            coverage_path.push(format!(
+                "{}.{}",
+                zinc_const::file_name::COVERAGE,
+                zinc_const::extension::COVERAGE,
+            ));
+            Some(coverage_path)
+        } else {
+            None
+        };
+
+        VirtualMachine::test(
+            self.verbosity,
+            self.quiet,
+            &binary_path,
+            input_path.as_ref(),
+            coverage_path.as_ref(),
+            self.min_time,
+            self.is_fast,
+            self.timeout,
+            self.filter.as_deref(),
+        )?;
 
         Ok(())
     }
+
+    ///
+    /// Returns the manifest `build` section values overridden by the command line options.
+    ///
+    fn overrides(&self) -> zinc_project::ManifestBuild {
+        zinc_project::ManifestBuild {
+            max_instructions: self.max_instructions,
+            timings: self
+                .timings
+                .map(|format| format.unwrap_or(zinc_project::ManifestTimings::Table)),
+            ..zinc_project::ManifestBuild::default()
+        }
+    }
 }
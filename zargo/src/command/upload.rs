@@ -117,13 +117,6 @@ impl Command {
         TargetDependenciesDirectory::create(&manifest_path)?;
 
         if let Some(dependencies) = manifest.dependencies {
-            let network = zksync::Network::from_str(self.network.as_str())
-                .map(Network::from)
-                .map_err(Error::NetworkInvalid)?;
-            let url = network
-                .try_into_url()
-                .map_err(Error::NetworkUnimplemented)?;
-            let http_client = HttpClient::new(url);
             let mut downloader = Downloader::new(&http_client, &manifest_path);
             downloader.download_dependency_list(dependencies).await?;
         }
@@ -135,6 +128,7 @@ impl Command {
             &manifest.project.version,
             &manifest_path,
             false,
+            zinc_project::ManifestBuild::default(),
         )?;
 
         let bytecode = BytecodeFile::try_from_path(&binary_path, true)?;
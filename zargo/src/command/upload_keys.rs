@@ -0,0 +1,129 @@
+//!
+//! The Zargo package manager `upload-keys` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+use crate::project::data::proving_key::ProvingKey as ProvingKeyFile;
+use crate::project::data::verifying_key::VerifyingKey as VerifyingKeyFile;
+use crate::project::data::Directory as DataDirectory;
+
+///
+/// The Zargo package manager `upload-keys` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Uploads the keys of an already uploaded project to the specified network")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Sets the network name, where the keys must be uploaded to.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Sets the bearer token required by the server to accept the keys.
+    #[structopt(long = "token")]
+    pub token: String,
+
+    /// Uploads the proving key as well, which is required for server-side proving.
+    #[structopt(long = "proving-key")]
+    pub proving_key: bool,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        manifest_path: PathBuf,
+        network: Option<String>,
+        token: String,
+        proving_key: bool,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            network: network
+                .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            token,
+            proving_key,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut proving_key_path = data_directory_path.clone();
+        proving_key_path.push(zinc_const::file_name::PROVING_KEY);
+        let mut verifying_key_path = data_directory_path;
+        verifying_key_path.push(zinc_const::file_name::VERIFYING_KEY.to_owned());
+
+        let verifying_key = VerifyingKeyFile::try_from(&verifying_key_path)?;
+        let proving_key = if self.proving_key {
+            Some(ProvingKeyFile::try_from(&proving_key_path)?.inner)
+        } else {
+            None
+        };
+
+        if !self.quiet {
+            eprintln!(
+                "   {} the keys of `{} v{}` to network `{}`",
+                "Uploading".bright_green(),
+                manifest.project.name,
+                manifest.project.version,
+                network,
+            );
+        }
+
+        http_client
+            .upload_keys(
+                zinc_types::KeysRequestQuery::new(manifest.project.name, manifest.project.version),
+                zinc_types::KeysRequestBody::new(verifying_key.inner, proving_key),
+                self.token.as_str(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
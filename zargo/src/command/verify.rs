@@ -9,6 +9,7 @@ use structopt::StructOpt;
 
 use crate::error::Error;
 use crate::executable::virtual_machine::VirtualMachine;
+use crate::project::data::key::Key as KeyFile;
 use crate::project::data::Directory as DataDirectory;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
 use crate::project::target::Directory as TargetDirectory;
@@ -42,18 +43,47 @@ pub struct Command {
     /// Uses the release build.
     #[structopt(long = "release")]
     pub is_release: bool,
+
+    /// The path to the verifying key file. Enables the standalone mode with `--proof` and
+    /// `--public-data`, where the project directories are not used.
+    #[structopt(
+        long = "verifying-key",
+        parse(from_os_str),
+        requires_all = &["proof-path", "public-data-path"]
+    )]
+    pub verifying_key_path: Option<PathBuf>,
+
+    /// The path to the proof file, which may be raw, hexadecimal, or base64.
+    #[structopt(
+        long = "proof",
+        parse(from_os_str),
+        requires_all = &["verifying-key-path", "public-data-path"]
+    )]
+    pub proof_path: Option<PathBuf>,
+
+    /// The path to the public data JSON file.
+    #[structopt(
+        long = "public-data",
+        parse(from_os_str),
+        requires_all = &["verifying-key-path", "proof-path"]
+    )]
+    pub public_data_path: Option<PathBuf>,
 }
 
 impl Command {
     ///
     /// A shortcut constructor.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         verbosity: usize,
         quiet: bool,
         manifest_path: PathBuf,
         method: Option<String>,
         is_release: bool,
+        verifying_key_path: Option<PathBuf>,
+        proof_path: Option<PathBuf>,
+        public_data_path: Option<PathBuf>,
     ) -> Self {
         Self {
             verbosity,
@@ -61,13 +91,43 @@ impl Command {
             manifest_path,
             method,
             is_release,
+            verifying_key_path,
+            proof_path,
+            public_data_path,
         }
     }
 
+    ///
+    /// Whether the verifying key, proof, and public data paths are passed explicitly.
+    ///
+    pub fn is_standalone(&self) -> bool {
+        self.verifying_key_path.is_some()
+            && self.proof_path.is_some()
+            && self.public_data_path.is_some()
+    }
+
     ///
     /// Executes the command.
     ///
     pub fn execute(self) -> anyhow::Result<()> {
+        if let (Some(verifying_key_path), Some(proof_path), Some(public_data_path)) = (
+            self.verifying_key_path.as_ref(),
+            self.proof_path.as_ref(),
+            self.public_data_path.as_ref(),
+        ) {
+            return match VirtualMachine::verify_standalone(
+                self.verbosity,
+                self.quiet,
+                verifying_key_path,
+                proof_path,
+                public_data_path,
+            )? {
+                zinc_const::VerifyExitCode::Passed => Ok(()),
+                zinc_const::VerifyExitCode::Failed => anyhow::bail!(Error::ProofInvalid),
+                zinc_const::VerifyExitCode::Invalid => anyhow::bail!(Error::ProofDataInvalid),
+            };
+        }
+
         let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
 
         match manifest.project.r#type {
@@ -101,6 +161,8 @@ impl Command {
         ));
         TargetDependenciesDirectory::create(&manifest_path)?;
 
+        KeyFile::check(&verifying_key_path)?;
+
         match self.method {
             Some(method) => VirtualMachine::verify_contract(
                 self.verbosity,
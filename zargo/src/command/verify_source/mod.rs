@@ -0,0 +1,286 @@
+//!
+//! The Zargo package manager `verify-source` subcommand.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::command::instances::ProjectReference;
+use crate::error::Error;
+use crate::executable::compiler::Compiler;
+use crate::http::downloader::Downloader;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+use crate::project::target::bytecode::Bytecode as BytecodeFile;
+use crate::project::target::deps::Directory as TargetDependenciesDirectory;
+use crate::project::target::Directory as TargetDirectory;
+
+///
+/// The Zargo package manager `verify-source` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Checks that the uploaded bytecode is built from the uploaded source")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// Sets the network name, where the project resides.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Rebuilds the source with the local compiler, even if the project has been uploaded with another one.
+    #[structopt(long = "allow-version-mismatch")]
+    pub allow_version_mismatch: bool,
+
+    /// The project name and version in the `name@version` format.
+    pub project: ProjectReference,
+}
+
+///
+/// The result of comparing the uploaded bytecode with the rebuilt one.
+///
+#[derive(Debug)]
+pub enum Verification {
+    /// The rebuilt bytecode is byte-for-byte equal to the uploaded one.
+    Passed,
+    /// The rebuilt bytecode differs from the uploaded one.
+    Failed {
+        /// The offset of the first differing byte.
+        offset: usize,
+        /// The differing artifact sections, if both artifacts can be decoded.
+        diff: Option<zinc_types::BuildDiff>,
+    },
+}
+
+impl Verification {
+    ///
+    /// Compares the `uploaded` bytecode with the `rebuilt` one.
+    ///
+    pub fn new(uploaded: &[u8], rebuilt: &[u8]) -> Self {
+        if uploaded == rebuilt {
+            return Self::Passed;
+        }
+
+        let offset = uploaded
+            .iter()
+            .zip(rebuilt.iter())
+            .position(|(uploaded, rebuilt)| uploaded != rebuilt)
+            .unwrap_or_else(|| std::cmp::min(uploaded.len(), rebuilt.len()));
+
+        let diff = match (
+            zinc_types::Application::try_decode(uploaded),
+            zinc_types::Application::try_decode(rebuilt),
+        ) {
+            (Ok((uploaded_header, uploaded)), Ok((rebuilt_header, rebuilt))) => Some(
+                zinc_types::BuildDiff::new(&uploaded_header, &uploaded, &rebuilt_header, &rebuilt),
+            ),
+            _ => None,
+        };
+
+        Self::Failed { offset, diff }
+    }
+
+    ///
+    /// Whether the verification has passed.
+    ///
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Self::Passed)
+    }
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed => writeln!(f, "PASS: the rebuilt bytecode matches the uploaded one"),
+            Self::Failed { offset, diff } => {
+                writeln!(
+                    f,
+                    "FAIL: the rebuilt bytecode differs from the uploaded one at byte {}",
+                    offset
+                )?;
+                match diff {
+                    Some(diff) => write!(f, "{}", diff),
+                    None => writeln!(f, "The uploaded bytecode cannot be decoded"),
+                }
+            }
+        }
+    }
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        network: Option<String>,
+        allow_version_mismatch: bool,
+        project: ProjectReference,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            network: network
+                .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            allow_version_mismatch,
+            project,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    /// The source is rebuilt in a temporary directory, which is removed afterwards.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let network = zksync::Network::from_str(self.network.as_str())
+            .map(Network::from)
+            .map_err(Error::NetworkInvalid)?;
+        let url = network
+            .try_into_url()
+            .map_err(Error::NetworkUnimplemented)?;
+        let http_client = HttpClient::new(url);
+
+        let (source, bytecode) = Self::download(&http_client, &self.project).await?;
+        if !self.allow_version_mismatch {
+            Self::check_version(&self.project, source.zinc_version.as_str())?;
+        }
+
+        if !self.quiet {
+            eprintln!(
+                "   {} `{} v{}` uploaded with compiler v{}",
+                "Verifying".bright_green(),
+                self.project.name,
+                self.project.version,
+                source.zinc_version,
+            );
+        }
+
+        let mut project_path = std::env::temp_dir();
+        project_path.push(format!(
+            "zargo-verify-source-{}-{}-{}",
+            self.project.name,
+            self.project.version,
+            std::process::id()
+        ));
+        if project_path.exists() {
+            fs::remove_dir_all(&project_path)?;
+        }
+
+        let rebuilt = self
+            .rebuild(&http_client, source.project, &project_path)
+            .await;
+        let _ = fs::remove_dir_all(&project_path);
+        let verification = Verification::new(bytecode.bytecode.as_slice(), rebuilt?.as_slice());
+
+        if !self.quiet {
+            print!("{}", verification);
+        }
+
+        if !verification.is_passed() {
+            anyhow::bail!(Error::SourceVerificationFailed(format!(
+                "{}@{}",
+                self.project.name, self.project.version
+            )));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Downloads the uploaded source and bytecode of the `project`.
+    ///
+    pub async fn download(
+        client: &HttpClient,
+        project: &ProjectReference,
+    ) -> anyhow::Result<(
+        zinc_types::SourceResponseBody,
+        zinc_types::BytecodeResponseBody,
+    )> {
+        let source = client
+            .source(zinc_types::SourceRequestQuery::new(
+                project.name.clone(),
+                project.version.clone(),
+            ))
+            .await?;
+        let bytecode = client
+            .bytecode(zinc_types::BytecodeRequestPath::new(
+                project.name.clone(),
+                project.version.clone(),
+            ))
+            .await?;
+
+        Ok((source, bytecode))
+    }
+
+    ///
+    /// Checks whether the local compiler is the one the `project` has been uploaded with.
+    ///
+    pub fn check_version(project: &ProjectReference, zinc_version: &str) -> anyhow::Result<()> {
+        let local = env!("CARGO_PKG_VERSION");
+        if zinc_version != local {
+            anyhow::bail!(Error::SourceCompilerVersionMismatch {
+                project: format!("{}@{}", project.name, project.version),
+                local: local.to_owned(),
+                uploaded: zinc_version.to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the `project` source at `path`, builds it in the release mode, and returns the
+    /// built bytecode.
+    ///
+    async fn rebuild(
+        &self,
+        http_client: &HttpClient,
+        project: zinc_project::Project,
+        path: &PathBuf,
+    ) -> anyhow::Result<Vec<u8>> {
+        fs::create_dir_all(path)?;
+        project.manifest.write_to(path)?;
+        project.source.write_to(path)?;
+
+        TargetDirectory::create(path, true)?;
+        let mut binary_path = TargetDirectory::path(path, true);
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        TargetDependenciesDirectory::create(path)?;
+        if let Some(dependencies) = project.manifest.dependencies.clone() {
+            let mut downloader = Downloader::new(http_client, path);
+            downloader.download_dependency_list(dependencies).await?;
+        }
+
+        Compiler::build_release(
+            self.verbosity,
+            self.quiet,
+            project.manifest.project.name.as_str(),
+            &project.manifest.project.version,
+            path,
+            false,
+            zinc_project::ManifestBuild::default(),
+        )?;
+
+        Ok(BytecodeFile::try_from_path(&binary_path, true)?.inner)
+    }
+}
@@ -0,0 +1,133 @@
+//!
+//! The Zargo package manager `verify-source` subcommand tests.
+//!
+
+use crate::command::instances::ProjectReference;
+use crate::command::verify_source::Command;
+use crate::command::verify_source::Verification;
+use crate::http::tests::client;
+use crate::http::tests::serve;
+
+///
+/// Creates the circuit fixture build artifact with `size` instructions.
+///
+fn circuit(size: usize) -> Vec<u8> {
+    zinc_types::Application::new_circuit(
+        "fixture".to_owned(),
+        2,
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Boolean),
+        Default::default(),
+        vec![zinc_types::Instruction::Return(zinc_types::Return::new(1)); size],
+    )
+    .into_vec(&semver::Version::new(0, 1, 0))
+}
+
+///
+/// Creates the project reference fixture.
+///
+fn project() -> ProjectReference {
+    ProjectReference {
+        name: "fixture".to_owned(),
+        version: semver::Version::new(0, 1, 0),
+    }
+}
+
+///
+/// Creates the source response body of the fixture project.
+///
+fn source_body() -> &'static str {
+    let project = zinc_project::Project::new(
+        zinc_project::Manifest::new("fixture", zinc_project::ProjectType::Circuit),
+        zinc_project::Source::File(zinc_project::File {
+            name: "main".to_owned(),
+            path: "main".to_owned(),
+            code: "fn main(witness: field) -> bool { true }".to_owned(),
+        }),
+    );
+    let body = zinc_types::SourceResponseBody::new(env!("CARGO_PKG_VERSION").to_owned(), project);
+
+    leak(serde_json::json!({ "data": body }))
+}
+
+///
+/// Creates the bytecode response body with the uploaded `bytecode`.
+///
+fn bytecode_body(bytecode: Vec<u8>) -> &'static str {
+    let body =
+        zinc_types::BytecodeResponseBody::new(env!("CARGO_PKG_VERSION").to_owned(), bytecode);
+
+    leak(serde_json::json!({ "data": body }))
+}
+
+///
+/// Serializes the response `body` for the mock server.
+///
+fn leak(body: serde_json::Value) -> &'static str {
+    Box::leak(body.to_string().into_boxed_str())
+}
+
+#[tokio::test]
+async fn ok_passed_then_failed_tampered() {
+    let rebuilt = circuit(2);
+    let mut tampered = rebuilt.clone();
+    let offset = tampered.len() - 1;
+    tampered[offset] ^= 0xff;
+
+    let (url, requests) = serve(vec![
+        (200, source_body()),
+        (200, bytecode_body(rebuilt.clone())),
+        (200, source_body()),
+        (200, bytecode_body(tampered)),
+    ]);
+    let client = client(url);
+
+    let (source, bytecode) = Command::download(&client, &project())
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    Command::check_version(&project(), source.zinc_version.as_str())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let verification = Verification::new(bytecode.bytecode.as_slice(), rebuilt.as_slice());
+    assert!(verification.is_passed());
+    assert!(verification.to_string().starts_with("PASS"));
+
+    let (_source, bytecode) = Command::download(&client, &project())
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let verification = Verification::new(bytecode.bytecode.as_slice(), rebuilt.as_slice());
+    assert!(!verification.is_passed());
+    assert!(verification.to_string().starts_with("FAIL"));
+    match verification {
+        Verification::Failed { offset: actual, .. } => assert_eq!(actual, offset),
+        Verification::Passed => panic!("the tampered bytecode must not pass"),
+    }
+
+    let requests = requests.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+    assert!(requests[0].starts_with("GET /api/v1/project/source?name=fixture&version=0.1.0 "));
+    assert!(requests[1].starts_with("GET /api/v1/projects/fixture/0.1.0/bytecode "));
+}
+
+#[test]
+fn ok_failed_sections_printed() {
+    let verification = Verification::new(circuit(2).as_slice(), circuit(3).as_slice());
+
+    match verification {
+        Verification::Failed {
+            diff: Some(ref diff),
+            ..
+        } => assert!(!diff.is_empty()),
+        ref verification => panic!("the artifact diff is missing: {:?}", verification),
+    }
+}
+
+#[test]
+fn error_compiler_version_mismatch() {
+    let error = Command::check_version(&project(), "0.0.1")
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .to_string();
+
+    assert!(error.contains("fixture@0.1.0"));
+    assert!(error.contains("v0.0.1"));
+    assert!(error.contains(env!("CARGO_PKG_VERSION")));
+    assert!(error.contains("--allow-version-mismatch"));
+}
@@ -0,0 +1,112 @@
+//!
+//! The Zargo package manager project version bump.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+///
+/// The project version component to increment.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bump {
+    /// Increments the patch version, e.g. `0.1.2` to `0.1.3`.
+    Patch,
+    /// Increments the minor version and resets the patch one, e.g. `0.1.2` to `0.2.0`.
+    Minor,
+    /// Increments the major version and resets the others, e.g. `0.1.2` to `1.0.0`.
+    Major,
+}
+
+impl Bump {
+    ///
+    /// Returns the `version` incremented by the bump.
+    ///
+    /// The pre-release and build metadata are dropped.
+    ///
+    pub fn apply(self, version: &semver::Version) -> semver::Version {
+        let mut version = version.to_owned();
+        match self {
+            Self::Patch => version.increment_patch(),
+            Self::Minor => version.increment_minor(),
+            Self::Major => version.increment_major(),
+        }
+        version
+    }
+}
+
+impl FromStr for Bump {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "patch" => Ok(Self::Patch),
+            "minor" => Ok(Self::Minor),
+            "major" => Ok(Self::Major),
+            value => Err(format!(
+                "expected `patch`, `minor`, or `major`, but found `{}`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Bump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Patch => write!(f, "patch"),
+            Self::Minor => write!(f, "minor"),
+            Self::Major => write!(f, "major"),
+        }
+    }
+}
+
+///
+/// Replaces the value of the `version` key in the `[project]` section of the manifest `text`.
+///
+/// The text is edited line by line, so the key spacing, quotes, trailing comments, and line
+/// endings are preserved, and only the version line differs.
+///
+pub fn set_manifest_version(text: &str, version: &semver::Version) -> Result<String, Error> {
+    let mut lines: Vec<String> = text.split('\n').map(|line| line.to_owned()).collect();
+
+    let mut section = None;
+    for line in lines.iter_mut() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            section = trimmed
+                .trim_start_matches('[')
+                .split(']')
+                .next()
+                .map(|name| name.trim().to_owned());
+            continue;
+        }
+        if section.as_deref() != Some("project") {
+            continue;
+        }
+
+        let value_start = match line.find('=') {
+            Some(index) if line[..index].trim() == "version" => index + 1,
+            _ => continue,
+        };
+        let value = &line[value_start..];
+
+        let quote_offset = match value.find(|c| c == '"' || c == '\'') {
+            Some(offset) => offset,
+            None => return Err(Error::ManifestVersionNotFound),
+        };
+        let quote = &value[quote_offset..=quote_offset];
+        let start = value_start + quote_offset + 1;
+        let end = match line[start..].find(quote) {
+            Some(length) => start + length,
+            None => return Err(Error::ManifestVersionNotFound),
+        };
+
+        line.replace_range(start..end, version.to_string().as_str());
+        return Ok(lines.join("\n"));
+    }
+
+    Err(Error::ManifestVersionNotFound)
+}
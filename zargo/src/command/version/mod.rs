@@ -0,0 +1,109 @@
+//!
+//! The Zargo package manager `version` subcommand.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod bump;
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use colored::Colorize;
+use structopt::StructOpt;
+
+use self::bump::Bump;
+
+///
+/// The Zargo package manager `version` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Bumps the project version in the manifest")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// The version component to increment, that is, `patch`, `minor`, or `major`.
+    #[structopt(name = "BUMP")]
+    pub bump: Bump,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verbosity: usize, quiet: bool, manifest_path: PathBuf, bump: Bump) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            bump,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        Self::bump_manifest(&self.manifest_path, self.bump, self.quiet)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Increments the project version in the manifest at `manifest_path` in place and
+    /// returns the new version.
+    ///
+    /// Only the `version` line of the `[project]` section is rewritten, so the rest of the
+    /// manifest formatting and comments are preserved.
+    ///
+    pub fn bump_manifest(
+        manifest_path: &PathBuf,
+        bump: Bump,
+        quiet: bool,
+    ) -> anyhow::Result<semver::Version> {
+        let manifest = zinc_project::Manifest::try_from(manifest_path)?;
+
+        let mut path = manifest_path.to_owned();
+        if path.is_dir() {
+            path.push(format!(
+                "{}.{}",
+                zinc_const::file_name::MANIFEST,
+                zinc_const::extension::MANIFEST
+            ));
+        }
+
+        let version = bump.apply(&manifest.project.version);
+        let text = fs::read_to_string(&path).with_context(|| path.to_string_lossy().to_string())?;
+        let text = bump::set_manifest_version(text.as_str(), &version)?;
+        fs::write(&path, text).with_context(|| path.to_string_lossy().to_string())?;
+
+        if !quiet {
+            eprintln!(
+                "     {} `{}` from v{} to v{}",
+                "Bumping".bright_green(),
+                manifest.project.name,
+                manifest.project.version,
+                version,
+            );
+        }
+
+        Ok(version)
+    }
+}
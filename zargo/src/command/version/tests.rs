@@ -0,0 +1,81 @@
+//!
+//! The Zargo package manager `version` subcommand tests.
+//!
+
+use crate::command::version::bump::set_manifest_version;
+use crate::command::version::bump::Bump;
+use crate::error::Error;
+
+const MANIFEST: &str = r#"# The exchange contract.
+[project]
+name = 'exchange'
+type = 'contract'
+version    =   "0.1.9" # bumped on every release
+description = "version = '0.0.0'"
+
+[dependencies]
+version = '1.0.0'
+"#;
+
+///
+/// Returns the `(line number, old line, new line)` triples of the lines, which differ.
+///
+fn diff(old: &str, new: &str) -> Vec<(usize, String, String)> {
+    assert_eq!(old.lines().count(), new.lines().count());
+
+    old.lines()
+        .zip(new.lines())
+        .enumerate()
+        .filter(|(_index, (old, new))| old != new)
+        .map(|(index, (old, new))| (index, old.to_owned(), new.to_owned()))
+        .collect()
+}
+
+#[test]
+fn ok_bump() {
+    let version =
+        semver::Version::parse("0.1.9-beta+build").expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(Bump::Patch.apply(&version), semver::Version::new(0, 1, 10));
+    assert_eq!(Bump::Minor.apply(&version), semver::Version::new(0, 2, 0));
+    assert_eq!(Bump::Major.apply(&version), semver::Version::new(1, 0, 0));
+    assert_eq!("minor".parse::<Bump>(), Ok(Bump::Minor));
+    assert!("micro".parse::<Bump>().is_err());
+}
+
+#[test]
+fn ok_only_version_line_changed() {
+    let result = set_manifest_version(MANIFEST, &semver::Version::new(0, 1, 10))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        diff(MANIFEST, result.as_str()),
+        vec![(
+            4,
+            r#"version    =   "0.1.9" # bumped on every release"#.to_owned(),
+            r#"version    =   "0.1.10" # bumped on every release"#.to_owned(),
+        )]
+    );
+}
+
+#[test]
+fn ok_line_endings_preserved() {
+    let manifest = "[project]\r\nname = 'test'\r\nversion = '0.1.0'\r\n";
+
+    let result = set_manifest_version(manifest, &semver::Version::new(1, 0, 0))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        result,
+        "[project]\r\nname = 'test'\r\nversion = '1.0.0'\r\n"
+    );
+}
+
+#[test]
+fn error_version_not_found() {
+    let manifest = "[project]\nname = 'test'\n\n[dependencies]\nversion = '1.0.0'\n";
+
+    let result = set_manifest_version(manifest, &semver::Version::new(1, 0, 0));
+
+    assert!(matches!(result, Err(Error::ManifestVersionNotFound)));
+}
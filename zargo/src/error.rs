@@ -25,6 +25,25 @@ pub enum Error {
     #[error("project version must be specified")]
     ProjectVersionMissing,
 
+    /// The manifest `[project]` section has no quoted `version` value to rewrite.
+    #[error("the manifest `[project]` section must contain the quoted `version` value")]
+    ManifestVersionNotFound,
+
+    /// The project version has been already published to the network.
+    #[error("project `{name}` version `{version}` is already published, the published versions are: {existing}")]
+    ProjectVersionExists {
+        /// The project name.
+        name: String,
+        /// The project version from the manifest.
+        version: semver::Version,
+        /// The comma-separated list of the published versions.
+        existing: String,
+    },
+
+    /// The project reference is not in the `name@version` format.
+    #[error("project must be specified as `name@version`, but found `{0}`")]
+    ProjectReferenceInvalid(String),
+
     /// The project directory does not exist. Use `new` instead.
     #[error("directory {0:?} does not exist")]
     DirectoryDoesNotExist(std::ffi::OsString),
@@ -57,6 +76,14 @@ pub enum Error {
     #[error("not a contract")]
     NotAContract,
 
+    /// The project is not a circuit.
+    #[error("not a circuit")]
+    NotACircuit,
+
+    /// The project is a library, which has no public inputs to generate the bindings for.
+    #[error("libraries have no public inputs to generate the bindings for")]
+    LibraryBindings,
+
     /// The contract method to call is missing.
     #[error("contract method to call must be specified")]
     MethodMissing,
@@ -65,14 +92,46 @@ pub enum Error {
     #[error("input file data must contain section `{0}`")]
     MissingInputSection(String),
 
+    /// The input file method arguments exceed the server size or nesting depth limit.
+    #[error("input file section `arguments.{0}`: {1}")]
+    InputLimit(String, zinc_types::ValueLimitError),
+
+    /// The input file has been generated for another version of the application arguments.
+    #[error("the input file {0:?} was generated for another version of the application arguments: rerun with `--regenerate-input` to update it, keeping the values of the unchanged arguments")]
+    InputTemplateMismatch(std::ffi::OsString),
+
+    /// The previous build artifact is missing, as the project has been built only once.
+    #[error("the previous build artifact {0:?} does not exist: it is kept when `zargo build` overwrites the current one")]
+    PreviousBuildMissing(std::ffi::OsString),
+
+    /// The proving or verifying key file has no valid footer, as it has not been written completely.
+    #[error("the key file {0:?} is incomplete: setup was interrupted, rerun `zargo setup`")]
+    SetupInterrupted(std::ffi::OsString),
+
     /// The project metadata request failure.
     #[error("project metadata request: {0}")]
     ProjectMetadata(String),
 
+    /// The project instances request failure.
+    #[error("project instances request: {0}")]
+    ProjectInstances(String),
+
+    /// The project search request failure.
+    #[error("project search request: {0}")]
+    ProjectSearch(String),
+
+    /// The project bytecode request failure.
+    #[error("project bytecode request: {0}")]
+    ProjectBytecode(String),
+
     /// The project uploading request failure.
     #[error("project uploading request: {0}")]
     ProjectUploading(String),
 
+    /// The project keys uploading request failure.
+    #[error("project keys uploading request: {0}")]
+    ProjectKeysUploading(String),
+
     /// The smart contract uploading request failure.
     #[error("contract uploading request: {0}")]
     ContractUploading(String),
@@ -85,6 +144,10 @@ pub enum Error {
     #[error("contract querying request: {0}")]
     ContractQuerying(String),
 
+    /// The smart contract storage dumping request failure.
+    #[error("contract storage dumping request: {0}")]
+    ContractStorageDumping(String),
+
     /// The smart contract fee calculating request failure.
     #[error("contract fee calculating request: {0}")]
     ContractFeeCalculating(String),
@@ -93,6 +156,18 @@ pub enum Error {
     #[error("contract calling request: {0}")]
     ContractCalling(String),
 
+    /// The smart contract dry running request failure.
+    #[error("contract dry running request: {0}")]
+    ContractDryRunning(String),
+
+    /// The smart contract delete challenge request failure.
+    #[error("contract delete challenge request: {0}")]
+    ContractDeleteChallenge(String),
+
+    /// The smart contract removing request failure.
+    #[error("contract removing request: {0}")]
+    ContractRemoving(String),
+
     /// The smart contract project downloading request failure.
     #[error("contract project downloading request: {0}")]
     ContractProjectDownloading(String),
@@ -101,7 +176,67 @@ pub enum Error {
     #[error("project {0}: compiler version mismatch: expected {1}, found {2}")]
     CompilerVersionMismatch(String, String, String),
 
+    /// The uploaded project has been built with another version of the compiler.
+    #[error("project {project} has been uploaded with compiler v{uploaded}, but the local one is v{local}: install the matching toolchain or pass `--allow-version-mismatch`")]
+    SourceCompilerVersionMismatch {
+        /// The project name and version.
+        project: String,
+        /// The local compiler version.
+        local: String,
+        /// The compiler version recorded at the project upload.
+        uploaded: String,
+    },
+
+    /// The uploaded bytecode cannot be reproduced from the uploaded source.
+    #[error("the bytecode of project {0} does not match its rebuilt source")]
+    SourceVerificationFailed(String),
+
     /// The command is temporarily unavailable.
     #[error("the proof verification is temporarily unavailable")]
     ProofVerificationUnavailable,
+
+    /// The proof has been rejected by the verifier.
+    #[error("the proof is invalid")]
+    ProofInvalid,
+
+    /// The verifying key or public data cannot be read or decoded.
+    #[error("the verifying key or public data is invalid")]
+    ProofDataInvalid,
+
+    /// The storage state has been dumped from a contract with another storage layout.
+    #[error("the storage state layout `{dumped}` does not match the contract storage layout `{current}`:\n{diff}")]
+    StorageLayoutMismatch {
+        /// The dumped storage layout hash.
+        dumped: String,
+        /// The current contract storage layout hash.
+        current: String,
+        /// The mismatched fields, where the dumped ones are prefixed with `-` and the current
+        /// ones with `+`.
+        diff: String,
+    },
+
+    /// The contract cannot replace its previous version.
+    #[error("the contract cannot replace the previous version: {0}")]
+    StorageUpgrade(zinc_types::ContractUpgradeError),
+
+    /// The contract cannot be pruned to the requested methods.
+    #[error("the contract cannot be pruned: {0}")]
+    ContractPruning(zinc_types::ContractPruningError),
+
+    /// The contract has been pruned to a subset of its methods, so it is not the canonical artifact.
+    #[error("the contract has been pruned from the circuit {0} with `--only-methods`: rebuild it without the option to publish")]
+    ContractPruned(String),
+
+    /// The storage state field value does not match its type.
+    #[error("the storage state field `{name}` is invalid: {message}")]
+    StorageFieldInvalid {
+        /// The field name.
+        name: String,
+        /// The typed JSON parsing error message.
+        message: String,
+    },
+
+    /// The storage state pages belong to different contract storages.
+    #[error("the storage state has changed while being dumped")]
+    StorageStateChanged,
 }
@@ -2,6 +2,8 @@
 //! The compiler executable.
 //!
 
+use std::convert::TryFrom;
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::process;
 
@@ -19,8 +21,14 @@ impl Compiler {
     ///
     /// Executes the compiler process, building the debug build without optimizations.
     ///
+    /// The range checks proven redundant are kept and cross-checked at runtime.
+    ///
+    /// The `debug` profile configuration values are passed to the compiler.
+    ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// The `overrides` take precedence over the manifest `build` section values.
+    ///
     pub fn build_debug(
         verbosity: usize,
         quiet: bool,
@@ -28,11 +36,15 @@ impl Compiler {
         version: &semver::Version,
         manifest_path: &PathBuf,
         is_test_only: bool,
+        overrides: zinc_project::ManifestBuild,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
         }
 
+        let (settings, cfg_args) =
+            Self::settings(manifest_path, zinc_const::cfg::PROFILE_DEBUG, overrides)?;
+
         let mut child = process::Command::new(zinc_const::app_name::COMPILER)
             .args(vec!["-v"; verbosity])
             .args(if quiet { vec!["--quiet"] } else { vec![] })
@@ -43,6 +55,9 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(Self::settings_args(&settings))
+            .args(cfg_args)
+            .arg("--opt-rce-assert")
             .spawn()
             .with_context(|| zinc_const::app_name::COMPILER)?;
 
@@ -62,8 +77,12 @@ impl Compiler {
     ///
     /// Executes the compiler process, building the release build with optimizations.
     ///
+    /// The `release` profile configuration values are passed to the compiler.
+    ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// The `overrides` take precedence over the manifest `build` section values.
+    ///
     pub fn build_release(
         verbosity: usize,
         quiet: bool,
@@ -71,11 +90,15 @@ impl Compiler {
         version: &semver::Version,
         manifest_path: &PathBuf,
         is_test_only: bool,
+        overrides: zinc_project::ManifestBuild,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
         }
 
+        let (settings, cfg_args) =
+            Self::settings(manifest_path, zinc_const::cfg::PROFILE_RELEASE, overrides)?;
+
         let mut child = process::Command::new(zinc_const::app_name::COMPILER)
             .args(vec!["-v"; verbosity])
             .args(if quiet { vec!["--quiet"] } else { vec![] })
@@ -86,7 +109,76 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(Self::settings_args(&settings))
+            .args(cfg_args)
+            .arg("--opt-dfe")
+            .args(if settings.range_check_elimination.unwrap_or(true) {
+                vec!["--opt-rce"]
+            } else {
+                vec![]
+            })
+            .spawn()
+            .with_context(|| zinc_const::app_name::COMPILER)?;
+
+        let status = child.wait()?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        if !quiet {
+            eprintln!(
+                "    {} release [optimized] target",
+                "Finished".bright_green(),
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the compiler process, building the release build of the project unit tests.
+    ///
+    /// Each of the `test_roots` is passed to be compiled as a separate module using the project
+    /// as a dependency.
+    ///
+    /// The unit tests are checked with the `debug` profile configuration values.
+    ///
+    /// The `overrides` take precedence over the manifest `build` section values.
+    ///
+    pub fn build_tests(
+        verbosity: usize,
+        quiet: bool,
+        name: &str,
+        version: &semver::Version,
+        manifest_path: &PathBuf,
+        test_roots: &[PathBuf],
+        overrides: zinc_project::ManifestBuild,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
+        }
+
+        let mut test_root_args = Vec::with_capacity(test_roots.len() * 2);
+        for path in test_roots.iter() {
+            test_root_args.push(OsString::from("--test-root"));
+            test_root_args.push(path.as_os_str().to_owned());
+        }
+
+        let (settings, cfg_args) =
+            Self::settings(manifest_path, zinc_const::cfg::PROFILE_DEBUG, overrides)?;
+
+        let mut child = process::Command::new(zinc_const::app_name::COMPILER)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("--manifest-path")
+            .arg(manifest_path)
+            .arg("--test-only")
+            .args(test_root_args)
+            .args(Self::settings_args(&settings))
+            .args(cfg_args)
             .arg("--opt-dfe")
+            .arg("--opt-rce-assert")
             .spawn()
             .with_context(|| zinc_const::app_name::COMPILER)?;
 
@@ -105,4 +197,156 @@ impl Compiler {
 
         Ok(())
     }
+
+    ///
+    /// Executes the compiler process, returning the intrinsic and standard library functions
+    /// description as JSON.
+    ///
+    pub fn target_info() -> anyhow::Result<serde_json::Value> {
+        let output = process::Command::new(zinc_const::app_name::COMPILER)
+            .arg("--print")
+            .arg("target-info")
+            .output()
+            .with_context(|| zinc_const::app_name::COMPILER)?;
+
+        if !output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(output.status));
+        }
+
+        let target_info = serde_json::from_slice(output.stdout.as_slice())
+            .with_context(|| zinc_const::app_name::COMPILER)?;
+
+        Ok(target_info)
+    }
+
+    ///
+    /// Executes the compiler process, returning the project documentation extracted from
+    /// the analyzed source code.
+    ///
+    /// The `debug` profile configuration values are passed to the compiler. The private items
+    /// are only documented if `is_private` is set.
+    ///
+    pub fn document(
+        verbosity: usize,
+        manifest_path: &PathBuf,
+        is_private: bool,
+    ) -> anyhow::Result<zinc_types::Documentation> {
+        let (_settings, cfg_args) = Self::settings(
+            manifest_path,
+            zinc_const::cfg::PROFILE_DEBUG,
+            zinc_project::ManifestBuild::default(),
+        )?;
+
+        let output = process::Command::new(zinc_const::app_name::COMPILER)
+            .args(vec!["-v"; verbosity])
+            .arg("--manifest-path")
+            .arg(manifest_path)
+            .args(cfg_args)
+            .arg("--print")
+            .arg("docs")
+            .args(if is_private {
+                vec!["--document-private-items"]
+            } else {
+                vec![]
+            })
+            .stdout(process::Stdio::piped())
+            .spawn()
+            .with_context(|| zinc_const::app_name::COMPILER)?
+            .wait_with_output()
+            .with_context(|| zinc_const::app_name::COMPILER)?;
+
+        if !output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(output.status));
+        }
+
+        let documentation = serde_json::from_slice(output.stdout.as_slice())
+            .with_context(|| zinc_const::app_name::COMPILER)?;
+
+        Ok(documentation)
+    }
+
+    ///
+    /// Returns the manifest `build` section merged with the `overrides`, and the
+    /// `--cfg key=value` compiler arguments of the build `profile`, which consist of the profile
+    /// itself and the manifest `cfg` section values.
+    ///
+    /// The unknown `build` section keys are reported as warnings, and the merged values are
+    /// logged along with their sources.
+    ///
+    fn settings(
+        manifest_path: &PathBuf,
+        profile: &str,
+        overrides: zinc_project::ManifestBuild,
+    ) -> anyhow::Result<(zinc_project::ManifestBuild, Vec<String>)> {
+        let manifest = zinc_project::Manifest::try_from(manifest_path)?;
+
+        let section = manifest.build.clone().unwrap_or_default();
+        for key in section.unknown_keys().into_iter() {
+            log::warn!(
+                "Ignoring the unknown manifest `build` section key `{}`",
+                key
+            );
+        }
+        for line in Self::describe_settings(&section, &overrides).into_iter() {
+            log::info!("{}", line);
+        }
+
+        let cfg_args = manifest
+            .cfg_values(profile)
+            .into_iter()
+            .flat_map(|(key, value)| vec!["--cfg".to_owned(), format!("{}={}", key, value)])
+            .collect();
+
+        Ok((section.merge(overrides), cfg_args))
+    }
+
+    ///
+    /// Describes the `section` values merged with the `overrides`, marking the source of each.
+    ///
+    pub fn describe_settings(
+        section: &zinc_project::ManifestBuild,
+        overrides: &zinc_project::ManifestBuild,
+    ) -> Vec<String> {
+        let overridden: Vec<&str> = overrides
+            .values()
+            .into_iter()
+            .map(|(key, _value)| key)
+            .collect();
+
+        section
+            .to_owned()
+            .merge(overrides.to_owned())
+            .values()
+            .into_iter()
+            .map(|(key, value)| {
+                format!(
+                    "Build setting `{}` = {} (from {})",
+                    key,
+                    value,
+                    if overridden.contains(&key) {
+                        "the command line"
+                    } else {
+                        "the manifest"
+                    }
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the compiler arguments of the merged `build` section `settings`, except for
+    /// the optimizations, which depend on the build profile.
+    ///
+    fn settings_args(settings: &zinc_project::ManifestBuild) -> Vec<String> {
+        let mut args = Vec::with_capacity(4);
+        if let Some(timings) = settings.timings {
+            args.push("--timings".to_owned());
+            args.push(timings.to_string());
+        }
+        if let Some(max_instructions) = settings.max_instructions {
+            args.push("--max-instructions".to_owned());
+            args.push(max_instructions.to_string());
+        }
+        args
+    }
 }
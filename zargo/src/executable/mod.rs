@@ -2,5 +2,8 @@
 //! The subprocess executables.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod compiler;
 pub mod virtual_machine;
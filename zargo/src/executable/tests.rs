@@ -0,0 +1,51 @@
+//!
+//! The subprocess executables tests.
+//!
+
+use crate::executable::compiler::Compiler;
+
+fn section() -> zinc_project::ManifestBuild {
+    zinc_project::ManifestBuild {
+        max_instructions: Some(1000),
+        range_check_elimination: Some(false),
+        ..zinc_project::ManifestBuild::default()
+    }
+}
+
+#[test]
+fn ok_settings_manifest_only() {
+    assert_eq!(
+        Compiler::describe_settings(&section(), &zinc_project::ManifestBuild::default()),
+        vec![
+            "Build setting `max_instructions` = 1000 (from the manifest)".to_owned(),
+            "Build setting `range_check_elimination` = false (from the manifest)".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn ok_settings_command_line_overrides() {
+    let overrides = zinc_project::ManifestBuild {
+        max_instructions: Some(2000),
+        timings: Some(zinc_project::ManifestTimings::Json),
+        ..zinc_project::ManifestBuild::default()
+    };
+
+    assert_eq!(
+        Compiler::describe_settings(&section(), &overrides),
+        vec![
+            "Build setting `max_instructions` = 2000 (from the command line)".to_owned(),
+            "Build setting `timings` = json (from the command line)".to_owned(),
+            "Build setting `range_check_elimination` = false (from the manifest)".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn ok_settings_empty() {
+    assert!(Compiler::describe_settings(
+        &zinc_project::ManifestBuild::default(),
+        &zinc_project::ManifestBuild::default()
+    )
+    .is_empty());
+}
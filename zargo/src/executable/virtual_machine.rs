@@ -2,6 +2,8 @@
 //! The compiler executable.
 //!
 
+use std::convert::TryFrom;
+use std::ffi::OsStr;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process;
@@ -12,6 +14,7 @@ use anyhow::Context;
 use colored::Colorize;
 
 use crate::error::Error;
+use crate::project::data::key::Key as KeyFile;
 
 ///
 /// The Zinc virtual machine process representation.
@@ -22,12 +25,16 @@ impl VirtualMachine {
     ///
     /// Executes the virtual machine `run` subcommand for circuit.
     ///
+    /// If `is_output_printed` is unset, the output is only written to the `output_path`.
+    ///
     pub fn run_circuit(
         verbosity: usize,
         quiet: bool,
         binary_path: &PathBuf,
         input_path: &PathBuf,
         output_path: &PathBuf,
+        witness_path: Option<&PathBuf>,
+        is_output_printed: bool,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!(
@@ -52,6 +59,15 @@ impl VirtualMachine {
             .arg(input_path)
             .arg("--output")
             .arg(output_path)
+            .args(match witness_path {
+                Some(path) => vec![OsStr::new("--output-witness"), path.as_os_str()],
+                None => vec![],
+            })
+            .stdout(if is_output_printed {
+                Stdio::inherit()
+            } else {
+                Stdio::null()
+            })
             .spawn()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
@@ -69,6 +85,11 @@ impl VirtualMachine {
     ///
     /// Executes the virtual machine `run` subcommand for contract.
     ///
+    /// If `is_audit` is set, the method is run in the constant-time audit mode.
+    ///
+    /// If `is_output_printed` is unset, the output is only written to the `output_path`.
+    ///
+    #[allow(clippy::too_many_arguments)]
     pub fn run_contract(
         verbosity: usize,
         quiet: bool,
@@ -76,6 +97,8 @@ impl VirtualMachine {
         input_path: &PathBuf,
         output_path: &PathBuf,
         method: &str,
+        is_audit: bool,
+        is_output_printed: bool,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!(
@@ -102,6 +125,12 @@ impl VirtualMachine {
             .arg(output_path)
             .arg("--method")
             .arg(method)
+            .args(if is_audit { vec!["--audit"] } else { vec![] })
+            .stdout(if is_output_printed {
+                Stdio::inherit()
+            } else {
+                Stdio::null()
+            })
             .spawn()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
@@ -119,10 +148,17 @@ impl VirtualMachine {
     ///
     /// Executes the virtual machine `test` subcommand.
     ///
+    #[allow(clippy::too_many_arguments)]
     pub fn test(
         verbosity: usize,
         quiet: bool,
         binary_path: &PathBuf,
+        input_path: Option<&PathBuf>,
+        coverage_path: Option<&PathBuf>,
+        min_time: Option<u64>,
+        is_fast: bool,
+        timeout: Option<u64>,
+        filter: Option<&str>,
     ) -> anyhow::Result<ExitStatus> {
         let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
@@ -130,6 +166,27 @@ impl VirtualMachine {
             .arg("test")
             .arg("--binary")
             .arg(binary_path)
+            .args(match input_path {
+                Some(input_path) => vec![OsStr::new("--input"), input_path.as_os_str()],
+                None => vec![],
+            })
+            .args(match coverage_path {
+                Some(coverage_path) => vec![OsStr::new("--coverage"), coverage_path.as_os_str()],
+                None => vec![],
+            })
+            .args(match min_time {
+                Some(min_time) => vec!["--min-time".to_owned(), min_time.to_string()],
+                None => vec![],
+            })
+            .args(if is_fast { vec!["--fast"] } else { vec![] })
+            .args(match timeout {
+                Some(timeout) => vec!["--timeout".to_owned(), timeout.to_string()],
+                None => vec![],
+            })
+            .args(match filter {
+                Some(filter) => vec!["--filter", filter],
+                None => vec![],
+            })
             .spawn()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
@@ -150,7 +207,7 @@ impl VirtualMachine {
     pub fn setup_circuit(
         _verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
+        binary_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
     ) -> anyhow::Result<()> {
@@ -163,19 +220,20 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-
-        Ok(())
+        let bytecode = std::fs::read(binary_path)
+            .with_context(|| binary_path.to_string_lossy().to_string())?;
+        let circuit_hash = zinc_types::circuit_hash(bytecode.as_slice());
+
+        KeyFile::write(vec![
+            (
+                proving_key_path,
+                [circuit_hash.as_slice(), vec![0u8; 8192].as_slice()].concat(),
+            ),
+            (
+                verifying_key_path,
+                [circuit_hash.as_slice(), vec![0u8; 1024].as_slice()].concat(),
+            ),
+        ])
     }
 
     ///
@@ -184,7 +242,7 @@ impl VirtualMachine {
     pub fn setup_contract(
         _verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
+        binary_path: &PathBuf,
         _method: &str,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
@@ -198,19 +256,20 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-
-        Ok(())
+        let bytecode = std::fs::read(binary_path)
+            .with_context(|| binary_path.to_string_lossy().to_string())?;
+        let circuit_hash = zinc_types::circuit_hash(bytecode.as_slice());
+
+        KeyFile::write(vec![
+            (
+                proving_key_path,
+                [circuit_hash.as_slice(), vec![0u8; 8192].as_slice()].concat(),
+            ),
+            (
+                verifying_key_path,
+                [circuit_hash.as_slice(), vec![0u8; 1024].as_slice()].concat(),
+            ),
+        ])
     }
 
     ///
@@ -351,6 +410,38 @@ impl VirtualMachine {
         Ok(())
     }
 
+    ///
+    /// Executes the virtual machine `verify` subcommand with the explicit verifying key, proof,
+    /// and public data paths.
+    ///
+    pub fn verify_standalone(
+        verbosity: usize,
+        quiet: bool,
+        verifying_key_path: &PathBuf,
+        proof_path: &PathBuf,
+        public_data_path: &PathBuf,
+    ) -> anyhow::Result<zinc_const::VerifyExitCode> {
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("verify")
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .arg("--proof")
+            .arg(proof_path)
+            .arg("--public-data")
+            .arg(public_data_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        zinc_const::VerifyExitCode::try_from(status)
+            .map_err(|_code| Error::SubprocessFailure(status).into())
+    }
+
     ///
     /// Executes the virtual machine `verify` subcommand.
     ///
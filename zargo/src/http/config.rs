@@ -0,0 +1,103 @@
+//!
+//! The Zandbox HTTP client configuration.
+//!
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use rand::Rng;
+
+///
+/// The Zandbox HTTP client configuration.
+///
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The connection establishing timeout.
+    pub connect_timeout: Duration,
+    /// The whole request timeout, including reading the response body.
+    pub request_timeout: Duration,
+    /// The maximal number of retries of a failed idempotent request.
+    pub retry_limit: usize,
+    /// The backoff before the first retry, which is doubled for each next one.
+    pub retry_backoff: Duration,
+    /// The backoff cap.
+    pub retry_backoff_limit: Duration,
+}
+
+impl Config {
+    ///
+    /// Creates the default configuration, overridden by the environment variables, if set.
+    ///
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Some(seconds) = Self::read_env(zinc_const::zandbox::CLIENT_CONNECT_TIMEOUT_ENV) {
+            config.connect_timeout = Duration::from_secs(seconds);
+        }
+        if let Some(seconds) = Self::read_env(zinc_const::zandbox::CLIENT_REQUEST_TIMEOUT_ENV) {
+            config.request_timeout = Duration::from_secs(seconds);
+        }
+        if let Some(limit) = Self::read_env(zinc_const::zandbox::CLIENT_RETRY_LIMIT_ENV) {
+            config.retry_limit = limit;
+        }
+
+        config
+    }
+
+    ///
+    /// Returns the backoff before the retry with the 1-based `attempt` number.
+    ///
+    /// The exponential backoff is capped and jittered, so the clients failed at the same time do
+    /// not retry at the same time as well.
+    ///
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = std::cmp::min(attempt.saturating_sub(1), 16) as u32;
+        let backoff = std::cmp::min(
+            self.retry_backoff * 2u32.pow(exponent),
+            self.retry_backoff_limit,
+        );
+
+        let backoff_millis = backoff.as_millis() as u64;
+        if backoff_millis < 2 {
+            return backoff;
+        }
+
+        let jitter = rand::thread_rng().gen_range(0, backoff_millis / 2);
+        Duration::from_millis(backoff_millis - jitter)
+    }
+
+    ///
+    /// Reads and parses the environment variable `name`, ignoring it if it is invalid.
+    ///
+    fn read_env<T: FromStr>(name: &str) -> Option<T> {
+        let value = std::env::var(name).ok()?;
+
+        match value.parse::<T>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                log::warn!("Ignoring the invalid `{}` value `{}`", name, value);
+                None
+            }
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(
+                zinc_const::zandbox::CLIENT_CONNECT_TIMEOUT_SECONDS,
+            ),
+            request_timeout: Duration::from_secs(
+                zinc_const::zandbox::CLIENT_REQUEST_TIMEOUT_SECONDS,
+            ),
+            retry_limit: zinc_const::zandbox::CLIENT_RETRY_LIMIT,
+            retry_backoff: Duration::from_millis(
+                zinc_const::zandbox::CLIENT_RETRY_BACKOFF_MILLISECONDS,
+            ),
+            retry_backoff_limit: Duration::from_millis(
+                zinc_const::zandbox::CLIENT_RETRY_BACKOFF_LIMIT_MILLISECONDS,
+            ),
+        }
+    }
+}
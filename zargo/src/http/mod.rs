@@ -2,32 +2,59 @@
 //! The Zandbox HTTP client.
 //!
 
+pub mod config;
 pub mod downloader;
 
+#[cfg(test)]
+pub(crate) mod tests;
+
 use reqwest::Method;
+use reqwest::StatusCode;
 use reqwest::Url;
 
 use crate::error::Error;
 
+use self::config::Config;
+
 ///
 /// The Zandbox HTTP client.
 ///
+/// The inner client keeps a pool of connections, so a single instance should be reused for all
+/// the requests a subcommand makes.
+///
 pub struct Client {
     /// The inner HTTP client.
     inner: reqwest::Client,
     /// The Zandbox URL.
     url: String,
+    /// The timeouts and retry configuration.
+    config: Config,
 }
 
 impl Client {
     ///
     /// A shortcut constructor.
     ///
+    /// The configuration may be overridden by the environment variables.
+    ///
     pub fn new(url: String) -> Self {
-        Self {
-            inner: reqwest::Client::new(),
-            url,
-        }
+        Self::new_with_config(url, Config::from_env())
+    }
+
+    ///
+    /// Creates a client with the specified `config`.
+    ///
+    pub fn new_with_config(url: String, config: Config) -> Self {
+        let inner = reqwest::Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .pool_idle_timeout(Some(std::time::Duration::from_secs(
+                zinc_const::zandbox::CLIENT_POOL_IDLE_TIMEOUT_SECONDS,
+            )))
+            .build()
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        Self { inner, url, config }
     }
 
     ///
@@ -35,7 +62,6 @@ impl Client {
     ///
     pub async fn metadata(&self) -> anyhow::Result<zinc_types::MetadataResponseBody> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -51,20 +77,132 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ProjectMetadata(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
+            anyhow::bail!(Error::ProjectMetadata(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<zinc_types::MetadataResponseBody>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Requests a page of the project instances from the Zandbox server.
+    ///
+    pub async fn instances(
+        &self,
+        path: zinc_types::InstancesRequestPath,
+        query: zinc_types::InstancesRequestQuery,
+    ) -> anyhow::Result<zinc_types::InstancesResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::GET,
+                        Url::parse_with_params(
+                            format!(
+                                "{}{}/{}/{}/{}",
+                                self.url,
+                                zinc_const::zandbox::PROJECTS_URL,
+                                path.name,
+                                path.version,
+                                zinc_const::zandbox::PROJECT_INSTANCES_URL_SUFFIX,
+                            )
+                            .as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ProjectInstances(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<zinc_types::InstancesResponseBody>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Downloads the bytecode uploaded with a project from the Zandbox server.
+    ///
+    pub async fn bytecode(
+        &self,
+        path: zinc_types::BytecodeRequestPath,
+    ) -> anyhow::Result<zinc_types::BytecodeResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::GET,
+                        Url::parse(
+                            format!(
+                                "{}{}/{}/{}/{}",
+                                self.url,
+                                zinc_const::zandbox::PROJECTS_URL,
+                                path.name,
+                                path.version,
+                                zinc_const::zandbox::PROJECT_BYTECODE_URL_SUFFIX,
+                            )
+                            .as_str(),
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ProjectBytecode(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<zinc_types::BytecodeResponseBody>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Searches the published projects by their names and descriptions.
+    ///
+    pub async fn search(
+        &self,
+        query: zinc_types::SearchRequestQuery,
+    ) -> anyhow::Result<zinc_types::SearchResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::GET,
+                        Url::parse_with_params(
+                            format!("{}{}", self.url, zinc_const::zandbox::PROJECTS_URL).as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ProjectSearch(Self::error(response).await));
         }
 
         Ok(response
-            .json::<zinc_types::MetadataResponseBody>()
+            .json::<zinc_types::DataResponseBody<zinc_types::SearchResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -76,7 +214,6 @@ impl Client {
         body: zinc_types::UploadRequestBody,
     ) -> anyhow::Result<()> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -94,14 +231,44 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ProjectUploading(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
+            anyhow::bail!(Error::ProjectUploading(Self::error(response).await));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Uploads the keys of an existing project to the Zandbox server.
+    ///
+    /// The server requires the bearer `token` to accept the keys.
+    ///
+    pub async fn upload_keys(
+        &self,
+        query: zinc_types::KeysRequestQuery,
+        body: zinc_types::KeysRequestBody,
+        token: &str,
+    ) -> anyhow::Result<()> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::PUT,
+                        Url::parse_with_params(
+                            format!("{}{}", self.url, zinc_const::zandbox::PROJECT_KEYS_URL)
+                                .as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .bearer_auth(token)
+                    .json(&body)
+                    .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ProjectKeysUploading(Self::error(response).await));
         }
 
         Ok(())
@@ -116,7 +283,6 @@ impl Client {
         body: zinc_types::PublishRequestBody,
     ) -> anyhow::Result<zinc_types::PublishResponseBody> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -127,6 +293,10 @@ impl Client {
                         )
                         .expect(zinc_const::panic::DATA_CONVERSION),
                     )
+                    .header(
+                        zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER,
+                        Self::idempotency_key(),
+                    )
                     .json(&body)
                     .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
@@ -134,20 +304,14 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractUploading(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            anyhow::bail!(Error::ContractUploading(Self::error(response).await));
         }
 
         Ok(response
-            .json::<zinc_types::PublishResponseBody>()
+            .json::<zinc_types::DataResponseBody<zinc_types::PublishResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -161,7 +325,6 @@ impl Client {
         body: zinc_types::InitializeRequestBody,
     ) -> anyhow::Result<zinc_types::InitializeResponseBody> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -184,20 +347,14 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractUnlocking(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            anyhow::bail!(Error::ContractUnlocking(Self::error(response).await));
         }
 
         Ok(response
-            .json::<zinc_types::InitializeResponseBody>()
+            .json::<zinc_types::DataResponseBody<zinc_types::InitializeResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -209,7 +366,6 @@ impl Client {
         body: zinc_types::QueryRequestBody,
     ) -> anyhow::Result<serde_json::Value> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -228,20 +384,53 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractQuerying(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
+            anyhow::bail!(Error::ContractQuerying(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<serde_json::Value>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Requests a page of the contract storage from the Zandbox server.
+    ///
+    /// The server requires the bearer `token`, since the private fields are returned as well.
+    ///
+    pub async fn storage(
+        &self,
+        query: zinc_types::StorageRequestQuery,
+        token: &str,
+    ) -> anyhow::Result<zinc_types::StorageResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::GET,
+                        Url::parse_with_params(
+                            format!("{}{}", self.url, zinc_const::zandbox::CONTRACT_STORAGE_URL)
+                                .as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .bearer_auth(token)
+                    .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ContractStorageDumping(Self::error(response).await));
         }
 
         Ok(response
-            .json::<serde_json::Value>()
+            .json::<zinc_types::DataResponseBody<zinc_types::StorageResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -253,7 +442,6 @@ impl Client {
         body: zinc_types::FeeRequestBody,
     ) -> anyhow::Result<zinc_types::FeeResponseBody> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -272,20 +460,14 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractFeeCalculating(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            anyhow::bail!(Error::ContractFeeCalculating(Self::error(response).await));
         }
 
         Ok(response
-            .json::<zinc_types::FeeResponseBody>()
+            .json::<zinc_types::DataResponseBody<zinc_types::FeeResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -297,7 +479,6 @@ impl Client {
         body: zinc_types::CallRequestBody,
     ) -> anyhow::Result<serde_json::Value> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -309,6 +490,10 @@ impl Client {
                         )
                         .expect(zinc_const::panic::DATA_CONVERSION),
                     )
+                    .header(
+                        zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER,
+                        Self::idempotency_key(),
+                    )
                     .json(&body)
                     .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
@@ -316,20 +501,140 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractCalling(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
+            anyhow::bail!(Error::ContractCalling(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<serde_json::Value>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Evaluates a contract call on the Zandbox server without persisting its effects.
+    ///
+    /// The request is not retried, since every attempt is counted by the server rate limit.
+    ///
+    pub async fn dry_run(
+        &self,
+        query: zinc_types::DryRunRequestQuery,
+        body: zinc_types::DryRunRequestBody,
+    ) -> anyhow::Result<zinc_types::DryRunResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::POST,
+                        Url::parse_with_params(
+                            format!("{}{}", self.url, zinc_const::zandbox::CONTRACT_DRY_RUN_URL)
+                                .as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .json(&body)
+                    .build()
                     .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ContractDryRunning(Self::error(response).await));
         }
 
         Ok(response
-            .json::<serde_json::Value>()
+            .json::<zinc_types::DataResponseBody<zinc_types::DryRunResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Requests a contract delete challenge nonce from the Zandbox server.
+    ///
+    pub async fn delete_challenge(
+        &self,
+        path: zinc_types::RemoveRequestPath,
+    ) -> anyhow::Result<zinc_types::DeleteChallengeResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::GET,
+                        Url::parse(
+                            format!(
+                                "{}{}/{}/{}",
+                                self.url,
+                                zinc_const::zandbox::CONTRACTS_URL,
+                                path.account_id,
+                                zinc_const::zandbox::CONTRACT_DELETE_CHALLENGE_URL_SUFFIX,
+                            )
+                            .as_str(),
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ContractDeleteChallenge(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<zinc_types::DeleteChallengeResponseBody>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Archives or purges a contract on the Zandbox server.
+    ///
+    /// The request is not retried, since the delete challenge is consumed by the first attempt.
+    ///
+    pub async fn remove(
+        &self,
+        path: zinc_types::RemoveRequestPath,
+        query: zinc_types::RemoveRequestQuery,
+        body: zinc_types::RemoveRequestBody,
+        token: &str,
+    ) -> anyhow::Result<zinc_types::RemoveResponseBody> {
+        let response = self
+            .execute(
+                self.inner
+                    .request(
+                        Method::DELETE,
+                        Url::parse_with_params(
+                            format!(
+                                "{}{}/{}",
+                                self.url,
+                                zinc_const::zandbox::CONTRACTS_URL,
+                                path.account_id,
+                            )
+                            .as_str(),
+                            query,
+                        )
+                        .expect(zinc_const::panic::DATA_CONVERSION),
+                    )
+                    .bearer_auth(token)
+                    .json(&body)
+                    .build()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!(Error::ContractRemoving(Self::error(response).await));
+        }
+
+        Ok(response
+            .json::<zinc_types::DataResponseBody<zinc_types::RemoveResponseBody>>()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
     }
 
     ///
@@ -340,7 +645,6 @@ impl Client {
         query: zinc_types::SourceRequestQuery,
     ) -> anyhow::Result<zinc_types::SourceResponseBody> {
         let response = self
-            .inner
             .execute(
                 self.inner
                     .request(
@@ -358,19 +662,142 @@ impl Client {
             .await?;
 
         if !response.status().is_success() {
-            anyhow::bail!(Error::ContractProjectDownloading(format!(
-                "HTTP error ({}) {}",
-                response.status(),
-                response
-                    .text()
-                    .await
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )));
+            anyhow::bail!(Error::ContractProjectDownloading(
+                Self::error(response).await
+            ));
         }
 
         Ok(response
-            .json::<zinc_types::SourceResponseBody>()
+            .json::<zinc_types::DataResponseBody<zinc_types::SourceResponseBody>>()
             .await
-            .expect(zinc_const::panic::DATA_CONVERSION))
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .data)
+    }
+
+    ///
+    /// Executes the `request`, retrying it on transient failures if it is idempotent.
+    ///
+    /// The `GET` and `PUT` requests are idempotent by definition, whereas the `POST` ones are
+    /// only retried if they carry an idempotency key, which lets the server deduplicate them.
+    /// The connection failures, timeouts, and the server-side errors are considered transient.
+    ///
+    async fn execute(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let is_idempotent = matches!(*request.method(), Method::GET | Method::PUT)
+            || request
+                .headers()
+                .contains_key(zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER);
+
+        let mut attempt = 0;
+        loop {
+            let retry = if is_idempotent && attempt < self.config.retry_limit {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            let method = request.method().to_owned();
+            let url = request.url().to_owned();
+            let result = self.inner.execute(request).await;
+
+            let retry = match retry {
+                Some(retry) => retry,
+                None => return result,
+            };
+
+            let reason = match result {
+                Ok(ref response) if Self::is_transient(response.status()) => {
+                    format!("HTTP error ({})", response.status())
+                }
+                Err(ref error) if error.is_connect() || error.is_timeout() => error.to_string(),
+                result => return result,
+            };
+
+            attempt += 1;
+            let backoff = self.config.backoff(attempt);
+            log::info!(
+                "{} {} failed with {}, retrying in {} ms ({}/{})",
+                method,
+                url.path(),
+                reason,
+                backoff.as_millis(),
+                attempt,
+                self.config.retry_limit,
+            );
+            tokio::time::delay_for(backoff).await;
+
+            request = retry;
+        }
+    }
+
+    ///
+    /// Checks whether the response `status` may be caused by a temporary server condition.
+    ///
+    fn is_transient(status: StatusCode) -> bool {
+        status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+    }
+
+    ///
+    /// Generates a random idempotency key, which must be the same for all retries of a request.
+    ///
+    fn idempotency_key() -> String {
+        format!("{:032x}", rand::random::<u128>())
+    }
+
+    ///
+    /// Extracts the error description from the failed response.
+    ///
+    /// If the response body is not the common error envelope, the raw body text is returned.
+    /// The input template violations, if any, are listed below the error message.
+    /// The server request ID, if any, is appended, so the failure may be found in the server logs.
+    ///
+    async fn error(response: reqwest::Response) -> String {
+        let status = response.status();
+        let header_request_id = response
+            .headers()
+            .get(zinc_const::zandbox::REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_owned());
+        let body = response
+            .text()
+            .await
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        let envelope = serde_json::from_str::<zinc_types::ErrorResponseBody>(body.as_str()).ok();
+        let request_id = envelope
+            .as_ref()
+            .and_then(|envelope| envelope.error.request_id.clone())
+            .or(header_request_id);
+
+        let mut message = match envelope {
+            Some(body) => {
+                let violations = body
+                    .error
+                    .details
+                    .as_ref()
+                    .and_then(|details| details.get("violations"))
+                    .cloned()
+                    .and_then(|violations| {
+                        serde_json::from_value::<Vec<zinc_types::ValueViolation>>(violations).ok()
+                    })
+                    .unwrap_or_default();
+
+                let mut message = format!("HTTP error ({}) {}", status, body.error);
+                if !violations.is_empty() {
+                    message.push('\n');
+                    message
+                        .push_str(zinc_types::ValueViolation::list(violations.as_slice()).as_str());
+                }
+                message
+            }
+            None => format!("HTTP error ({}) {}", status, body),
+        };
+
+        if let Some(request_id) = request_id {
+            message.push_str(format!("\nRequest ID: {}", request_id).as_str());
+        }
+        message
     }
 }
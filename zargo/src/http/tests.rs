@@ -0,0 +1,323 @@
+//!
+//! The Zandbox HTTP client tests.
+//!
+
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Method;
+
+use crate::http::config::Config;
+use crate::http::Client;
+
+/// The metadata response body with no projects.
+static METADATA_BODY: &str = r#"{"data":{"projects":[]}}"#;
+
+/// The project instances response body with two instances, the first of two pages.
+static INSTANCES_BODY: &str = r#"{"data":{"instances":[{"account_id":1,"instance":"first","address":"0x0101010101010101010101010101010101010101","owner":"0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa","published_at":"2020-12-20T12:00:01Z","interfaces":["Ownable"],"storage_root":"0x01"},{"account_id":2,"instance":"second","address":"0x0202020202020202020202020202020202020202","owner":null,"published_at":"2020-12-20T12:00:02Z","interfaces":["Ownable"],"storage_root":null}],"offset":0,"limit":2,"total":3}}"#;
+
+/// The project search response body with two projects, the first of two pages.
+static SEARCH_BODY: &str = r#"{"data":{"projects":[{"name":"token","version":"1.2.0","type":"library","description":"Fungible token","published_at":"2021-02-15T12:00:01Z"},{"name":"token-swap","version":"0.1.0","type":"contract","description":null,"published_at":"2021-02-15T12:00:02Z"}],"offset":0,"limit":2,"total":3}}"#;
+
+/// The common error response body.
+static ERROR_BODY: &str = r#"{"error":"invalid request"}"#;
+
+/// The error response body with the input template violations.
+static VIOLATIONS_BODY: &str = r#"{"error":{"code":"INPUT_TEMPLATE_MISMATCH","message":"Input: 2 value(s) do not match the method template","details":{"violations":[{"pointer":"/amount","expected":"u64","found":"boolean (true)"},{"pointer":"/recipients/1","expected":"zksync::Address","found":"string (\"255\")"}]}}}"#;
+
+///
+/// Starts a mock server, which replies to the consecutive requests with `responses`, and returns
+/// its URL and the log of the received request heads.
+///
+/// The request idempotency key header, if any, is echoed in the response.
+///
+pub(crate) fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect(zinc_const::panic::TEST_DATA_VALID);
+    let url = format!(
+        "http://{}",
+        listener
+            .local_addr()
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    );
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let log = requests.clone();
+    std::thread::spawn(move || {
+        for (status, body) in responses.into_iter() {
+            let (mut stream, _address) =
+                listener.accept().expect(zinc_const::panic::TEST_DATA_VALID);
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 1024];
+            let head = loop {
+                let size = stream
+                    .read(&mut buffer)
+                    .expect(zinc_const::panic::TEST_DATA_VALID);
+                request.extend_from_slice(&buffer[..size]);
+
+                let text = String::from_utf8_lossy(request.as_slice()).to_string();
+                if let Some(index) = text.find("\r\n\r\n") {
+                    let content_length = text[..index]
+                        .lines()
+                        .filter_map(header)
+                        .find(|(name, _value)| name.eq_ignore_ascii_case("content-length"))
+                        .and_then(|(_name, value)| value.trim().parse::<usize>().ok())
+                        .unwrap_or_default();
+                    if size == 0 || request.len() >= index + 4 + content_length {
+                        break text[..index].to_owned();
+                    }
+                }
+            };
+
+            let echo = head
+                .lines()
+                .filter_map(header)
+                .find(|(name, _value)| {
+                    name.eq_ignore_ascii_case(zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER)
+                })
+                .map(|(name, value)| format!("{}: {}\r\n", name, value.trim()))
+                .unwrap_or_default();
+            log.lock()
+                .expect(zinc_const::panic::SYNCHRONIZATION)
+                .push(head);
+
+            let response = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                status,
+                body.len(),
+                echo,
+                body,
+            );
+            stream
+                .write_all(response.as_bytes())
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+        }
+    });
+
+    (url, requests)
+}
+
+///
+/// Splits the request head `line` into the header name and value.
+///
+fn header(line: &str) -> Option<(&str, &str)> {
+    let mut parts = line.splitn(2, ':');
+    Some((parts.next()?, parts.next()?))
+}
+
+///
+/// Creates a client with the negligible retry backoff.
+///
+pub(crate) fn client(url: String) -> Client {
+    let config = Config {
+        retry_backoff: Duration::from_millis(1),
+        retry_backoff_limit: Duration::from_millis(1),
+        ..Config::default()
+    };
+
+    Client::new_with_config(url, config)
+}
+
+#[tokio::test]
+async fn ok_retried_after_service_unavailable() {
+    let (url, requests) = serve(vec![(503, ERROR_BODY), (200, METADATA_BODY)]);
+
+    let result = client(url).metadata().await;
+
+    assert!(result.is_ok());
+    assert_eq!(
+        requests
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn error_bad_request_not_retried() {
+    let (url, requests) = serve(vec![(400, ERROR_BODY), (200, METADATA_BODY)]);
+
+    let result = client(url).metadata().await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        requests
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn ok_idempotency_key_echoed() {
+    let (url, requests) = serve(vec![(503, ERROR_BODY), (200, METADATA_BODY)]);
+
+    let client = client(url.clone());
+    let key = Client::idempotency_key();
+    let request = client
+        .inner
+        .request(Method::POST, url.as_str())
+        .header(zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER, key.as_str())
+        .body("{}")
+        .build()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let response = client
+        .execute(request)
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        response
+            .headers()
+            .get(zinc_const::zandbox::IDEMPOTENCY_KEY_HEADER)
+            .and_then(|value| value.to_str().ok()),
+        Some(key.as_str())
+    );
+
+    let requests = requests.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|head| head.contains(key.as_str())));
+}
+
+#[test]
+fn ok_backoff_capped() {
+    let config = Config::default();
+
+    for attempt in 1..=64 {
+        assert!(config.backoff(attempt) <= config.retry_backoff_limit);
+    }
+}
+
+#[tokio::test]
+async fn ok_instances_parsed() {
+    let (url, requests) = serve(vec![(200, INSTANCES_BODY)]);
+
+    let page = client(url)
+        .instances(
+            zinc_types::InstancesRequestPath::new(
+                "token".to_owned(),
+                semver::Version::new(0, 1, 0),
+            ),
+            zinc_types::InstancesRequestQuery::new(0, Some(2)),
+        )
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(page.instances.len(), 2);
+    assert_eq!(page.total, 3);
+    assert!(page.has_next_page());
+
+    assert_eq!(page.instances[0].account_id, 1);
+    assert_eq!(page.instances[0].instance, "first");
+    assert_eq!(
+        page.instances[0].owner,
+        Some(zksync_types::Address::from(
+            [0xaa; zinc_const::size::ETH_ADDRESS]
+        ))
+    );
+    assert_eq!(page.instances[0].interfaces, vec!["Ownable".to_owned()]);
+    assert_eq!(page.instances[0].storage_root.as_deref(), Some("0x01"));
+
+    assert_eq!(
+        page.instances[1].address,
+        zksync_types::Address::from([0x02; zinc_const::size::ETH_ADDRESS])
+    );
+    assert_eq!(page.instances[1].owner, None);
+    assert_eq!(page.instances[1].storage_root, None);
+
+    let requests = requests.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+    assert!(requests[0].starts_with("GET /api/v1/projects/token/0.1.0/instances?offset=0&limit=2 "));
+}
+
+#[tokio::test]
+async fn error_instances_not_found() {
+    let (url, _requests) = serve(vec![(404, ERROR_BODY)]);
+
+    let result = client(url)
+        .instances(
+            zinc_types::InstancesRequestPath::new(
+                "token".to_owned(),
+                semver::Version::new(0, 1, 0),
+            ),
+            zinc_types::InstancesRequestQuery::default(),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn ok_search_parsed() {
+    let (url, requests) = serve(vec![(200, SEARCH_BODY)]);
+
+    let page = client(url)
+        .search(zinc_types::SearchRequestQuery::new(
+            "Token".to_owned(),
+            0,
+            Some(2),
+        ))
+        .await
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(page.projects.len(), 2);
+    assert_eq!(page.total, 3);
+    assert!(page.has_next_page());
+
+    assert_eq!(page.projects[0].name, "token");
+    assert_eq!(page.projects[0].version, semver::Version::new(1, 2, 0));
+    assert_eq!(page.projects[0].r#type, zinc_project::ProjectType::Library);
+    assert_eq!(
+        page.projects[0].description.as_deref(),
+        Some("Fungible token")
+    );
+    assert_eq!(page.projects[1].description, None);
+
+    assert_eq!(
+        crate::command::search::Command::table(&page),
+        "NAME        VERSION  TYPE      PUBLISHED             DESCRIPTION\n\
+         token       1.2.0    library   2021-02-15T12:00:01Z  Fungible token\n\
+         token-swap  0.1.0    contract  2021-02-15T12:00:02Z  -\n\
+         ... and 1 more\n"
+    );
+
+    let requests = requests.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+    assert!(requests[0].starts_with("GET /api/v1/projects?query=Token&offset=0&limit=2 "));
+}
+
+#[tokio::test]
+async fn error_search_empty_query() {
+    let (url, _requests) = serve(vec![(400, ERROR_BODY)]);
+
+    let result = client(url)
+        .search(zinc_types::SearchRequestQuery::new(String::new(), 0, None))
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn error_query_input_template_violations() {
+    let (url, _requests) = serve(vec![(400, VIOLATIONS_BODY)]);
+
+    let error = client(url)
+        .query(
+            zinc_types::QueryRequestQuery::new(
+                zksync_types::Address::zero(),
+                Some("audit".to_owned()),
+            ),
+            zinc_types::QueryRequestBody::new(Some(serde_json::json!({}))),
+        )
+        .await
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(error.to_string().ends_with(
+        "\n    /amount        expected `u64`, found boolean (true)\n    /recipients/1  expected `zksync::Address`, found string (\"255\")"
+    ));
+}
@@ -15,15 +15,22 @@ pub use self::command::call::Command as CallCommand;
 pub use self::command::clean::Command as CleanCommand;
 pub use self::command::download::Command as DownloadCommand;
 pub use self::command::init::Command as InitCommand;
+pub use self::command::instances::Command as InstancesCommand;
+pub use self::command::instances::ProjectReference;
 pub use self::command::new::Command as NewCommand;
 pub use self::command::proof_check::Command as ProofCheckCommand;
 pub use self::command::prove::Command as ProveCommand;
 pub use self::command::publish::Command as PublishCommand;
 pub use self::command::query::Command as QueryCommand;
 pub use self::command::run::Command as RunCommand;
+pub use self::command::search::Command as SearchCommand;
 pub use self::command::setup::Command as SetupCommand;
 pub use self::command::test::Command as TestCommand;
 pub use self::command::upload::Command as UploadCommand;
+pub use self::command::upload_keys::Command as UploadKeysCommand;
 pub use self::command::verify::Command as VerifyCommand;
+pub use self::command::version::bump::Bump as VersionBump;
+pub use self::command::version::Command as VersionCommand;
 pub use self::command::Command;
+pub use self::error::Error;
 pub use self::network::Network;
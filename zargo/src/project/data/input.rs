@@ -2,13 +2,17 @@
 //! The application input file representation.
 //!
 
+use std::fs;
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
 
 use anyhow::Context;
+use colored::Colorize;
 use serde::Deserialize;
 
+use crate::error::Error;
+
 ///
 /// The application input file representation.
 ///
@@ -44,6 +48,66 @@ impl Input {
         Ok(Self { inner })
     }
 
+    ///
+    /// Checks whether the input file at `path` was generated for the application at `binary_path`.
+    ///
+    /// If the input template digests do not match and `regenerate` is set, the file is rewritten
+    /// with the new template, keeping the values which are still valid for the new input types.
+    /// Otherwise, an error suggesting the regeneration is returned.
+    ///
+    /// The missing files and the files without the digest are not checked.
+    ///
+    pub fn check_template(
+        path: &PathBuf,
+        binary_path: &PathBuf,
+        regenerate: bool,
+        quiet: bool,
+    ) -> anyhow::Result<()> {
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let bytecode =
+            fs::read(binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(anyhow::Error::msg)?;
+        let expected = match application.input_template() {
+            Some(expected) => expected,
+            None => return Ok(()),
+        };
+
+        let input = Self::try_from_path(path)?;
+        let found = match input
+            .inner
+            .get("_template")
+            .and_then(serde_json::Value::as_str)
+        {
+            Some(found) => found,
+            None => return Ok(()),
+        };
+        if found == expected {
+            return Ok(());
+        }
+
+        if !regenerate {
+            anyhow::bail!(Error::InputTemplateMismatch(path.as_os_str().to_owned()));
+        }
+
+        if !quiet {
+            eprintln!(
+                "{} `{}`",
+                "Regenerating".bright_green(),
+                path.to_string_lossy(),
+            );
+        }
+
+        let input = application.regenerate_input(Some(input.inner));
+        let data = serde_json::to_vec_pretty(&input).expect(zinc_const::panic::DATA_CONVERSION);
+        fs::write(path, data).with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
     ///
     /// Creates a string with the default file name.
     ///
@@ -0,0 +1,119 @@
+//!
+//! The proving and verifying key files.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::error::Error;
+
+///
+/// The proving and verifying key files.
+///
+/// The keys are written with a footer, which is checked before the keys are used, so a file
+/// left truncated by an interrupted setup is reported instead of failing to decode.
+///
+pub struct Key {}
+
+impl Key {
+    ///
+    /// Reads the key file at `path` and returns the key without the footer.
+    ///
+    /// The footer is checked before the key is read, so an incomplete proving key is rejected
+    /// without reading it, and the key is not copied after reading.
+    ///
+    pub fn read(path: &PathBuf) -> anyhow::Result<Vec<u8>> {
+        let file = File::open(path).with_context(|| path.to_string_lossy().to_string())?;
+
+        match zinc_types::read_key(file).with_context(|| path.to_string_lossy().to_string())? {
+            Some(key) => Ok(key),
+            None => anyhow::bail!(Error::SetupInterrupted(path.as_os_str().to_owned())),
+        }
+    }
+
+    ///
+    /// Checks that the key file at `path` has been written completely.
+    ///
+    /// Only the footer is read, so the check is cheap even for large proving keys.
+    ///
+    pub fn check(path: &PathBuf) -> anyhow::Result<()> {
+        let mut file = File::open(path).with_context(|| path.to_string_lossy().to_string())?;
+
+        match zinc_types::read_key_length(&mut file)
+            .with_context(|| path.to_string_lossy().to_string())?
+        {
+            Some(_length) => Ok(()),
+            None => anyhow::bail!(Error::SetupInterrupted(path.as_os_str().to_owned())),
+        }
+    }
+
+    ///
+    /// Writes the `keys` to their paths.
+    ///
+    /// All the keys are written to the temporary files first, which are renamed to the key
+    /// paths only if every key has been written completely. Thus, an interrupted or failed
+    /// write never replaces the existing keys with incomplete ones.
+    ///
+    pub fn write(keys: Vec<(&PathBuf, Vec<u8>)>) -> anyhow::Result<()> {
+        let mut temporaries = Vec::with_capacity(keys.len());
+        for (path, key) in keys.into_iter() {
+            match Self::write_temporary(path, key) {
+                Ok(temporary) => temporaries.push((temporary, path)),
+                Err(error) => {
+                    for (temporary, _path) in temporaries.into_iter() {
+                        let _ = fs::remove_file(temporary);
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        for (temporary, path) in temporaries.into_iter() {
+            Self::commit(&temporary, path)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the `key` with the footer to the temporary file next to `path`.
+    ///
+    /// Returns the temporary file path.
+    ///
+    fn write_temporary(path: &PathBuf, key: Vec<u8>) -> anyhow::Result<PathBuf> {
+        let temporary = Self::temporary_path(path);
+
+        let mut file =
+            File::create(&temporary).with_context(|| temporary.to_string_lossy().to_string())?;
+        file.write_all(zinc_types::key_with_footer(key).as_slice())
+            .with_context(|| temporary.to_string_lossy().to_string())?;
+        file.sync_all()
+            .with_context(|| temporary.to_string_lossy().to_string())?;
+
+        Ok(temporary)
+    }
+
+    ///
+    /// Atomically replaces the key at `path` with the completely written `temporary` file.
+    ///
+    fn commit(temporary: &PathBuf, path: &PathBuf) -> anyhow::Result<()> {
+        fs::rename(temporary, path).with_context(|| path.to_string_lossy().to_string())
+    }
+
+    ///
+    /// Returns the temporary file path for the key at `path`.
+    ///
+    fn temporary_path(path: &PathBuf) -> PathBuf {
+        let mut file_name = path.file_name().unwrap_or_default().to_owned();
+        file_name.push(".");
+        file_name.push(zinc_const::extension::TEMPORARY);
+        path.with_file_name(file_name)
+    }
+}
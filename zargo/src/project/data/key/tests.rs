@@ -0,0 +1,157 @@
+//!
+//! The proving and verifying key files tests.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::error::Error;
+use crate::project::data::key::Key;
+
+///
+/// Creates an empty temporary directory for the test `name`.
+///
+fn directory(name: &str) -> PathBuf {
+    let mut path = std::env::temp_dir();
+    path.push(format!("zargo-key-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+    path
+}
+
+///
+/// Creates the key contents, which start with the circuit hash like the ones written by setup.
+///
+fn key(size: usize) -> Vec<u8> {
+    let mut key = zinc_types::circuit_hash(&[1, 2, 3]);
+    key.extend((0..size).map(|index| index as u8));
+    key
+}
+
+fn is_setup_interrupted(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::SetupInterrupted(_))
+    )
+}
+
+#[test]
+fn ok_round_trip() {
+    let directory = directory("round-trip");
+    let proving_key_path = directory.join(zinc_const::file_name::PROVING_KEY);
+    let verifying_key_path = directory.join(zinc_const::file_name::VERIFYING_KEY);
+
+    Key::write(vec![
+        (&proving_key_path, key(8192)),
+        (&verifying_key_path, key(1024)),
+    ])
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(Key::check(&proving_key_path).is_ok());
+    assert_eq!(
+        Key::read(&proving_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+        key(8192)
+    );
+    assert_eq!(
+        Key::read(&verifying_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+        key(1024)
+    );
+    assert!(!Key::temporary_path(&proving_key_path).exists());
+    assert!(!Key::temporary_path(&verifying_key_path).exists());
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn error_truncated() {
+    let directory = directory("truncated");
+    let path = directory.join(zinc_const::file_name::PROVING_KEY);
+
+    Key::write(vec![(&path, key(1024))]).expect(zinc_const::panic::TEST_DATA_VALID);
+    let file = fs::read(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    for offset in vec![
+        0,
+        1,
+        zinc_const::size::SHA256_HASH,
+        file.len() / 2,
+        file.len() - zinc_const::size::KEY_FOOTER,
+        file.len() - 1,
+    ]
+    .into_iter()
+    {
+        fs::write(&path, &file[..offset]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let error = Key::read(&path).expect_err(zinc_const::panic::TEST_DATA_VALID);
+        assert!(
+            is_setup_interrupted(&error),
+            "the key truncated at {} must be reported as interrupted setup: {:?}",
+            offset,
+            error
+        );
+
+        let error = Key::check(&path).expect_err(zinc_const::panic::TEST_DATA_VALID);
+        assert!(
+            is_setup_interrupted(&error),
+            "the key truncated at {} must be reported as interrupted setup: {:?}",
+            offset,
+            error
+        );
+        assert!(error
+            .to_string()
+            .contains("setup was interrupted, rerun `zargo setup`"));
+    }
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn error_temporary_failure_keeps_keys() {
+    let directory = directory("temporary-failure");
+    let proving_key_path = directory.join(zinc_const::file_name::PROVING_KEY);
+    let verifying_key_path = directory.join(zinc_const::file_name::VERIFYING_KEY);
+
+    Key::write(vec![
+        (&proving_key_path, key(8192)),
+        (&verifying_key_path, key(1024)),
+    ])
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    fs::create_dir_all(Key::temporary_path(&verifying_key_path))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let result = Key::write(vec![
+        (&proving_key_path, key(16)),
+        (&verifying_key_path, key(16)),
+    ]);
+    assert!(result.is_err());
+
+    assert_eq!(
+        Key::read(&proving_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+        key(8192)
+    );
+    assert_eq!(
+        Key::read(&verifying_key_path).expect(zinc_const::panic::TEST_DATA_VALID),
+        key(1024)
+    );
+    assert!(!Key::temporary_path(&proving_key_path).exists());
+
+    let _ = fs::remove_dir_all(directory);
+}
+
+#[test]
+fn error_rename_failure_keeps_key() {
+    let directory = directory("rename-failure");
+    let path = directory.join(zinc_const::file_name::VERIFYING_KEY);
+
+    Key::write(vec![(&path, key(1024))]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = Key::commit(&directory.join("missing"), &path);
+    assert!(result.is_err());
+
+    assert_eq!(
+        Key::read(&path).expect(zinc_const::panic::TEST_DATA_VALID),
+        key(1024)
+    );
+
+    let _ = fs::remove_dir_all(directory);
+}
@@ -3,7 +3,10 @@
 //!
 
 pub mod input;
+pub mod key;
+pub mod output;
 pub mod private_key;
+pub mod proving_key;
 pub mod verifying_key;
 
 use std::fs;
@@ -0,0 +1,76 @@
+//!
+//! The output file.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+///
+/// The output file representation.
+///
+pub struct Output {
+    /// The file contents.
+    pub inner: serde_json::Value,
+}
+
+impl Output {
+    ///
+    /// Reads the output file at `path`.
+    ///
+    pub fn try_from_path(path: &PathBuf) -> anyhow::Result<Self> {
+        let data = fs::read(path).with_context(|| path.to_string_lossy().to_string())?;
+        let inner = serde_json::from_slice(data.as_slice())
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(Self { inner })
+    }
+
+    ///
+    /// Renders the output with the type metadata of the application built at `binary_path`.
+    ///
+    /// The `method` must be specified for contracts. The mutable method output is wrapped along
+    /// with the storage root hash, as the virtual machine returns it. If the output type cannot
+    /// be found, the output is rendered as JSON.
+    ///
+    pub fn to_pretty_string(
+        &self,
+        binary_path: &PathBuf,
+        method: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let bytecode =
+            fs::read(binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
+        let (_header, application) = zinc_types::Application::try_decode(bytecode.as_slice())
+            .with_context(|| binary_path.to_string_lossy().to_string())?;
+
+        let r#type = match (application, method) {
+            (zinc_types::Application::Circuit(circuit), None) => Some(circuit.output),
+            (zinc_types::Application::Contract(mut contract), Some(method)) => {
+                let is_constructor = method == zinc_const::contract::CONSTRUCTOR_IDENTIFIER
+                    || contract.upgrade.as_deref() == Some(method);
+                contract.methods.remove(method).map(|method| {
+                    if method.is_mutable {
+                        method.output.into_mutable_method_output()
+                    } else if is_constructor {
+                        zinc_types::Type::eth_address()
+                    } else {
+                        method.output
+                    }
+                })
+            }
+            _ => None,
+        };
+
+        let r#type = match r#type {
+            Some(r#type) => r#type,
+            None => {
+                return Ok(serde_json::to_string_pretty(&self.inner)
+                    .expect(zinc_const::panic::DATA_CONVERSION))
+            }
+        };
+        let value = zinc_types::Value::try_from_typed_json(self.inner.to_owned(), r#type.clone())?;
+
+        Ok(zinc_types::ValuePrinter::print(&value, &r#type))
+    }
+}
@@ -0,0 +1,43 @@
+//!
+//! The proving key file.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use crate::project::data::key::Key;
+
+///
+/// The proving key file representation.
+///
+pub struct ProvingKey {
+    /// The key without the file footer.
+    pub inner: Vec<u8>,
+}
+
+impl ProvingKey {
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        zinc_const::file_name::PROVING_KEY.to_owned()
+    }
+}
+
+impl TryFrom<&PathBuf> for ProvingKey {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(zinc_const::directory::DATA) {
+                path.push(PathBuf::from(zinc_const::directory::DATA));
+            }
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        Ok(Self {
+            inner: Key::read(&path)?,
+        })
+    }
+}
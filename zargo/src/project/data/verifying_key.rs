@@ -3,17 +3,15 @@
 //!
 
 use std::convert::TryFrom;
-use std::fs::File;
-use std::io::Read;
 use std::path::PathBuf;
 
-use anyhow::Context;
+use crate::project::data::key::Key;
 
 ///
 /// The verifying key file representation.
 ///
 pub struct VerifyingKey {
-    /// The file contents.
+    /// The key without the file footer.
     pub inner: Vec<u8>,
 }
 
@@ -38,16 +36,8 @@ impl TryFrom<&PathBuf> for VerifyingKey {
             path.push(PathBuf::from(Self::file_name()));
         }
 
-        let mut file = File::open(&path).with_context(|| path.to_string_lossy().to_string())?;
-        let size = file
-            .metadata()
-            .with_context(|| path.to_string_lossy().to_string())?
-            .len() as usize;
-
-        let mut buffer = Vec::with_capacity(size);
-        file.read_to_end(&mut buffer)
-            .with_context(|| path.to_string_lossy().to_string())?;
-
-        Ok(Self { inner: buffer })
+        Ok(Self {
+            inner: Key::read(&path)?,
+        })
     }
 }
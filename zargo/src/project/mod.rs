@@ -5,3 +5,4 @@
 pub mod data;
 pub mod src;
 pub mod target;
+pub mod tests;
@@ -0,0 +1,53 @@
+//!
+//! The project `tests` directory.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+///
+/// The project `tests` directory, where each file is a separate test root.
+///
+pub struct Directory {}
+
+impl Directory {
+    ///
+    /// If the `path` does not end with the directory subpath, appends the subpath to the `path`.
+    ///
+    pub fn path(path: &PathBuf) -> PathBuf {
+        let mut path = path.to_owned();
+        if path.is_dir() && !path.ends_with(zinc_const::directory::TESTS) {
+            path.push(PathBuf::from(zinc_const::directory::TESTS));
+        }
+        path
+    }
+
+    ///
+    /// Returns the sorted paths of the Zinc source files in the directory.
+    ///
+    /// The directory is optional, so its absence means there are no test roots.
+    ///
+    pub fn files(path: &PathBuf) -> anyhow::Result<Vec<PathBuf>> {
+        let path = Self::path(path);
+        if !path.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&path).with_context(|| path.to_string_lossy().to_string())? {
+            let entry = entry.with_context(|| path.to_string_lossy().to_string())?;
+            let path = entry.path();
+            if path.is_file()
+                && path.extension().and_then(|extension| extension.to_str())
+                    == Some(zinc_const::extension::SOURCE)
+            {
+                files.push(path);
+            }
+        }
+        files.sort();
+
+        Ok(files)
+    }
+}
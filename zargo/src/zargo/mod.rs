@@ -19,9 +19,13 @@ async fn main() {
 
     process::exit(match args.command.execute().await {
         Ok(()) => zinc_const::exit_code::SUCCESS,
-        Err(error) => {
-            log::error!("{:?}", error);
-            zinc_const::exit_code::FAILURE
-        }
+        Err(error) => match error.downcast_ref::<zargo::Error>() {
+            Some(zargo::Error::ProofInvalid) => zinc_const::VerifyExitCode::Failed as i32,
+            Some(zargo::Error::ProofDataInvalid) => zinc_const::VerifyExitCode::Invalid as i32,
+            _ => {
+                log::error!("{:?}", error);
+                zinc_const::exit_code::FAILURE
+            }
+        },
     })
 }
@@ -2,8 +2,11 @@
 //! The Zinc compiler bundler error.
 //!
 
+use colored::Colorize;
 use thiserror::Error;
 
+use crate::source::error::Error as SourceError;
+
 ///
 /// The Zinc compiler bundler error.
 ///
@@ -29,4 +32,109 @@ pub enum Error {
         /// The child project type.
         child_type: String,
     },
+    /// The previous build supplied for the upgrade check is invalid.
+    #[error("the previous build is invalid: {0}")]
+    PreviousBuildInvalid(String),
+    /// The previous build supplied for the upgrade check is not a contract.
+    #[error("the previous build is not a contract")]
+    PreviousBuildNotAContract,
+    /// The contract cannot replace the previous version.
+    #[error("{0}")]
+    Upgrade(#[from] zinc_types::ContractUpgradeError),
+    /// The bytecode has more instructions than allowed.
+    #[error(
+        "the bytecode has {total} instructions, which exceeds the limit of {limit}. The largest functions are: {}. \
+Reduce the array sizes or loop bounds, or raise the limit with `--max-instructions`",
+        format_counts(.functions)
+    )]
+    InstructionLimitExceeded {
+        /// The total number of the bytecode instructions.
+        total: usize,
+        /// The maximal allowed number of the bytecode instructions.
+        limit: usize,
+        /// The functions with the largest numbers of instructions.
+        functions: Vec<(String, usize)>,
+    },
+    /// The contract storage has more leaves than the storage Merkle tree can hold.
+    #[error(
+        "the contract storage has {total} leaves, which exceeds the limit of {limit}. The storage fields are: {}. \
+Reduce the sizes of the arrays of structures in the storage",
+        format_counts(.fields)
+    )]
+    StorageLeafLimitExceeded {
+        /// The total number of the storage leaves.
+        total: usize,
+        /// The maximal allowed number of the storage leaves.
+        limit: usize,
+        /// The storage fields with their leaf counts, the largest first.
+        fields: Vec<(String, usize)>,
+    },
+    /// The dependency analysis has failed.
+    #[error("{message}{}", format_notes(.notes))]
+    Dependency {
+        /// The primary diagnostic, formatted as string.
+        message: String,
+        /// The dependents which have required the failed dependency, from the innermost one.
+        notes: Vec<String>,
+    },
+}
+
+impl Error {
+    ///
+    /// Appends the dependent `note` to the dependency analysis `error`.
+    ///
+    /// The source code analysis errors are converted into the dependency ones, whereas the
+    /// other errors are returned as is.
+    ///
+    pub fn with_dependent(error: anyhow::Error, note: String) -> anyhow::Error {
+        let (message, mut notes) = match error.downcast_ref::<Self>() {
+            Some(Self::Dependency { message, notes }) => (message.to_owned(), notes.to_owned()),
+            _ => match error.downcast_ref::<SourceError>() {
+                Some(SourceError::Compiling(message)) => (message.to_owned(), vec![]),
+                _ => return error,
+            },
+        };
+        notes.push(note);
+
+        Self::Dependency { message, notes }.into()
+    }
+
+    ///
+    /// Converts the compiler `error` into the JSON diagnostic.
+    ///
+    /// The dependency analysis errors carry their dependents chain in the `notes` array.
+    ///
+    pub fn to_json(error: &anyhow::Error) -> serde_json::Value {
+        match error.downcast_ref::<Self>() {
+            Some(Self::Dependency { message, notes }) => serde_json::json!({
+                "message": message.trim(),
+                "notes": notes,
+            }),
+            _ => serde_json::json!({
+                "message": format!("{:#}", error).trim(),
+                "notes": [],
+            }),
+        }
+    }
+}
+
+///
+/// Formats the `items`, e.g. functions with their instruction counts, as a comma-separated list.
+///
+fn format_counts(items: &[(String, usize)]) -> String {
+    items
+        .iter()
+        .map(|(name, count)| format!("`{}` ({})", name, count))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+///
+/// Formats the dependency error `notes`, each on its own line.
+///
+fn format_notes(notes: &[String]) -> String {
+    notes
+        .iter()
+        .map(|note| format!("{}: {}\n", "note".bright_white(), note.bright_blue()))
+        .collect()
 }
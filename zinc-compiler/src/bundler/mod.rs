@@ -2,20 +2,32 @@
 //! The Zinc compiler bundler.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod dependency;
 pub mod error;
 
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 use anyhow::Context;
 
+use crate::documentation::Extractor as DocumentationExtractor;
+use crate::error::Error as CompilerError;
+use crate::generator::zinc_vm::analyzer::instruction_count::Analyzer as InstructionCountAnalyzer;
 use crate::generator::zinc_vm::State as ZincVMState;
+use crate::semantic::element::r#type::function::intrinsic::cfg::values::Values as CfgValues;
 use crate::semantic::scope::Scope;
+use crate::source::error::Error as SourceError;
 use crate::source::Source;
+use crate::timings::phase::Phase as TimingsPhase;
+use crate::timings::TIMINGS;
 
 use self::dependency::Dependency;
 use self::error::Error;
@@ -31,6 +43,16 @@ pub struct Bundler {
 
     /// The optimization flag.
     optimize_dead_function_elimination: bool,
+    /// The marking of the range checks proven redundant, if the optimization is enabled.
+    range_check_elision: Option<zinc_types::RangeCheck>,
+    /// The previous contract version binary, which the storage layout is checked against.
+    previous_build_path: Option<PathBuf>,
+    /// The maximal number of the bytecode instructions, which overrides the manifest one.
+    max_instructions: Option<usize>,
+    /// The test root files, whose unit tests are compiled along with the project ones.
+    test_roots: Vec<PathBuf>,
+    /// The configuration values returned by the `cfg!` function in the project and dependencies.
+    cfg: BTreeMap<String, String>,
 
     /// The compiled dependency modules cache.
     cache: HashMap<(String, semver::Version), Dependency>,
@@ -45,6 +67,8 @@ impl Bundler {
     const DEPENDENCIES_INITIAL_CAPACITY: usize = 64;
     /// The dependency graph node indexes hashmap default capacity.
     const NODE_INDEXES_INITIAL_CAPACITY: usize = 64;
+    /// The number of the largest functions reported if the instruction limit is exceeded.
+    const INSTRUCTION_LIMIT_TOP_FUNCTIONS: usize = 5;
 
     ///
     /// A shortcut constructor.
@@ -53,12 +77,22 @@ impl Bundler {
         project_path: PathBuf,
         dependencies_directory_path: PathBuf,
         optimize_dead_function_elimination: bool,
+        range_check_elision: Option<zinc_types::RangeCheck>,
+        previous_build_path: Option<PathBuf>,
+        max_instructions: Option<usize>,
+        test_roots: Vec<PathBuf>,
+        cfg: BTreeMap<String, String>,
     ) -> Self {
         Self {
             project_path,
             dependencies_directory_path,
 
             optimize_dead_function_elimination,
+            range_check_elision,
+            previous_build_path,
+            max_instructions,
+            test_roots,
+            cfg,
 
             cache: HashMap::with_capacity(Self::DEPENDENCIES_INITIAL_CAPACITY),
             node_indexes: HashMap::with_capacity(Self::NODE_INDEXES_INITIAL_CAPACITY),
@@ -69,34 +103,168 @@ impl Bundler {
     ///
     /// Compiles the project source code with its entire dependency tree.
     ///
+    /// The instruction limit is taken from the bundler settings, the manifest `build` section,
+    /// or the default one, in that order.
+    ///
     pub fn bundle(&mut self) -> anyhow::Result<zinc_types::Build> {
         let manifest = zinc_project::Manifest::try_from(&self.project_path)
             .with_context(|| self.project_path.to_string_lossy().to_string())?;
+        let max_instructions = self
+            .max_instructions
+            .or_else(|| {
+                manifest
+                    .build
+                    .as_ref()
+                    .and_then(|build| build.max_instructions)
+            })
+            .unwrap_or(zinc_const::limit::BYTECODE_INSTRUCTIONS);
+
+        CfgValues::set(self.cfg.clone());
 
         let node_index = self.graph.add_node(manifest.project.clone());
+        let version = manifest.project.version.clone();
+
+        let mut source_directory_path = self.project_path.to_owned();
+        source_directory_path.push(zinc_const::directory::SOURCE);
+
+        let source = match Source::try_from_entry(&source_directory_path)? {
+            Source::Directory(directory) => {
+                Source::Directory(directory.with_test_roots(self.test_roots.as_slice())?)
+            }
+            source => source,
+        };
 
         let dependencies = match manifest.dependencies {
-            Some(ref dependencies) => self.compile_list(node_index, &dependencies)?,
+            Some(ref dependencies) => self.compile_list(node_index, &source, &dependencies)?,
             None => HashMap::new(),
         };
 
+        let state = source.compile(manifest, dependencies)?;
+
+        let _timer = TIMINGS.start(TimingsPhase::Generation, "application".to_owned());
+        let application = ZincVMState::unwrap_rc(state).into_application(
+            self.optimize_dead_function_elimination,
+            self.range_check_elision,
+        );
+        Self::check_instructions(&application, max_instructions)?;
+        if let zinc_types::Application::Contract(ref contract) = application {
+            Self::check_storage(contract, zinc_const::limit::CONTRACT_STORAGE_LEAVES)?;
+        }
+
+        if let Some(ref path) = self.previous_build_path {
+            Self::check_upgrade(path, &application)
+                .with_context(|| path.to_string_lossy().to_string())?;
+        }
+
+        Ok(application.into_build(&version))
+    }
+
+    ///
+    /// Analyzes the project source code with its entire dependency tree and extracts the project
+    /// documentation. The private items are only documented if `is_private` is set.
+    ///
+    pub fn document(&mut self, is_private: bool) -> anyhow::Result<zinc_types::Documentation> {
+        let manifest = zinc_project::Manifest::try_from(&self.project_path)
+            .with_context(|| self.project_path.to_string_lossy().to_string())?;
+
+        CfgValues::set(self.cfg.clone());
+
+        let node_index = self.graph.add_node(manifest.project.clone());
+
         let mut source_directory_path = self.project_path.to_owned();
         source_directory_path.push(zinc_const::directory::SOURCE);
-
         let source = Source::try_from_entry(&source_directory_path)?;
-        let state = source.compile(manifest, dependencies)?;
-        let application =
-            ZincVMState::unwrap_rc(state).into_application(self.optimize_dead_function_elimination);
 
-        Ok(application.into_build())
+        let dependencies = match manifest.dependencies {
+            Some(ref dependencies) => self.compile_list(node_index, &source, &dependencies)?,
+            None => HashMap::new(),
+        };
+
+        let scope = source.modularize(manifest.project.clone(), dependencies)?;
+
+        let documentation = DocumentationExtractor::new(is_private)
+            .extract(&manifest.project, scope)
+            .map_err(CompilerError::Semantic)
+            .map_err(|error| error.format())
+            .map_err(SourceError::Compiling)?;
+
+        Ok(documentation)
+    }
+
+    ///
+    /// Checks whether the `application` bytecode does not exceed the `limit` of instructions.
+    ///
+    /// Otherwise, the functions with the largest numbers of instructions are reported.
+    ///
+    pub fn check_instructions(
+        application: &zinc_types::Application,
+        limit: usize,
+    ) -> Result<(), Error> {
+        let mut functions = InstructionCountAnalyzer::count(application.instructions());
+        let total = functions.iter().map(|(_name, count)| count).sum();
+        if total <= limit {
+            return Ok(());
+        }
+
+        functions.truncate(Self::INSTRUCTION_LIMIT_TOP_FUNCTIONS);
+        Err(Error::InstructionLimitExceeded {
+            total,
+            limit,
+            functions,
+        })
+    }
+
+    ///
+    /// Checks whether the `contract` storage does not exceed the `limit` of leaves.
+    ///
+    /// Otherwise, all the storage fields are reported with their leaf counts, the largest first.
+    ///
+    pub fn check_storage(contract: &zinc_types::Contract, limit: usize) -> Result<(), Error> {
+        if contract.storage_leaves <= limit {
+            return Ok(());
+        }
+
+        Err(Error::StorageLeafLimitExceeded {
+            total: contract.storage_leaves,
+            limit,
+            fields: contract.storage_sizing(),
+        })
+    }
+
+    ///
+    /// Checks whether the contract `application` can replace the previous version at `path`.
+    ///
+    /// If the storage layouts differ, the contract must either only append fields to the
+    /// previous layout, or have the `#[upgrade]` method, whose argument matches the previous
+    /// version storage fields.
+    ///
+    fn check_upgrade(path: &PathBuf, application: &zinc_types::Application) -> anyhow::Result<()> {
+        let bytecode = fs::read(path)?;
+        let previous = match zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::PreviousBuildInvalid)?
+        {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => anyhow::bail!(Error::PreviousBuildNotAContract),
+        };
+
+        if let zinc_types::Application::Contract(ref contract) = application {
+            contract.migration(&previous).map_err(Error::Upgrade)?;
+        }
+
+        Ok(())
     }
 
     ///
-    /// Compiles a dependency and stores its scope in the bundler instance cache.
+    /// Compiles the dependencies of the `parent_source` project and stores their scopes in
+    /// the bundler instance cache.
+    ///
+    /// If a dependency analysis fails, the error is annotated with the `use` statement of
+    /// the parent project, which has required the dependency.
     ///
     fn compile_list(
         &mut self,
         parent_node_index: petgraph::graph::NodeIndex,
+        parent_source: &Source,
         dependencies: &HashMap<String, semver::Version>,
     ) -> anyhow::Result<HashMap<String, Rc<RefCell<Scope>>>> {
         let mut compiled = HashMap::with_capacity(dependencies.len());
@@ -110,32 +278,30 @@ impl Bundler {
 
                     dependency.scope.to_owned()
                 }
-                None => {
-                    let mut path = self.dependencies_directory_path.to_owned();
-                    path.push(format!("{}-{}", name, version));
-
-                    let manifest = zinc_project::Manifest::try_from(&path)
-                        .with_context(|| path.to_string_lossy().to_string())?;
-
-                    let node_index = self.node_index(&manifest.project);
-                    self.graph.add_edge(parent_node_index, node_index, ());
-                    self.check_dependency(parent_node_index, node_index)?;
-
-                    let dependencies = match manifest.dependencies {
-                        Some(dependencies) => self.compile_list(node_index, &dependencies)?,
-                        None => HashMap::new(),
-                    };
-
-                    let mut source_directory_path = path.clone();
-                    source_directory_path.push(zinc_const::directory::SOURCE);
-                    let source = Source::try_from_entry(&source_directory_path)?;
-                    let scope = source.modularize(manifest.project.clone(), dependencies)?;
-
-                    let dependency = Dependency::new(manifest.project, scope.clone(), node_index);
-                    self.cache
-                        .insert((name.to_owned(), version.to_owned()), dependency.clone());
-                    dependency.scope
-                }
+                None => self
+                    .compile_dependency(parent_node_index, name, version)
+                    .map_err(|error| {
+                        let parent = &self.graph[parent_node_index];
+                        let note = match parent_source.find_use(name.as_str()) {
+                            Some((path, location)) => format!(
+                                "required by `use {}` at {}, where `{}` is a dependency declared in `{}.{}`",
+                                path,
+                                location,
+                                name,
+                                zinc_const::file_name::MANIFEST,
+                                zinc_const::extension::MANIFEST,
+                            ),
+                            None => format!(
+                                "required by `{}-{}`, where `{}` is a dependency declared in `{}.{}`",
+                                parent.name,
+                                parent.version,
+                                name,
+                                zinc_const::file_name::MANIFEST,
+                                zinc_const::extension::MANIFEST,
+                            ),
+                        };
+                        Error::with_dependent(error, note)
+                    })?,
             };
 
             compiled.insert(name.to_owned(), scope);
@@ -144,6 +310,43 @@ impl Bundler {
         Ok(compiled)
     }
 
+    ///
+    /// Compiles the dependency `name` of `version` along with its own dependencies, and stores
+    /// its scope in the bundler instance cache.
+    ///
+    fn compile_dependency(
+        &mut self,
+        parent_node_index: petgraph::graph::NodeIndex,
+        name: &str,
+        version: &semver::Version,
+    ) -> anyhow::Result<Rc<RefCell<Scope>>> {
+        let mut path = self.dependencies_directory_path.to_owned();
+        path.push(format!("{}-{}", name, version));
+
+        let manifest = zinc_project::Manifest::try_from(&path)
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        let node_index = self.node_index(&manifest.project);
+        self.graph.add_edge(parent_node_index, node_index, ());
+        self.check_dependency(parent_node_index, node_index)?;
+
+        let mut source_directory_path = path.clone();
+        source_directory_path.push(zinc_const::directory::SOURCE);
+        let source = Source::try_from_entry(&source_directory_path)?;
+
+        let dependencies = match manifest.dependencies {
+            Some(dependencies) => self.compile_list(node_index, &source, &dependencies)?,
+            None => HashMap::new(),
+        };
+
+        let scope = source.modularize(manifest.project.clone(), dependencies)?;
+
+        let dependency = Dependency::new(manifest.project, scope, node_index);
+        self.cache
+            .insert((name.to_owned(), version.to_owned()), dependency.clone());
+        Ok(dependency.scope)
+    }
+
     ///
     /// Checks the dependencies for validity:
     ///
@@ -0,0 +1,476 @@
+//!
+//! The Zinc compiler bundler tests.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::bundler::error::Error;
+use crate::bundler::Bundler;
+use crate::generator::zinc_vm::analyzer::instruction_count::Analyzer as InstructionCountAnalyzer;
+use crate::generator::zinc_vm::tests::compile_with_files;
+use crate::generator::zinc_vm::tests::compile_with_modules;
+use crate::generator::zinc_vm::tests::file;
+
+static CIRCUIT: &str = r#"
+fn one(x: u8) -> u8 { x + 1 }
+
+fn two(x: u8) -> u8 { x + 1 + 2 }
+
+fn three(x: u8) -> u8 { x + 1 + 2 + 3 }
+
+fn four(x: u8) -> u8 { x + 1 + 2 + 3 + 4 }
+
+fn five(x: u8) -> u8 { x + 1 + 2 + 3 + 4 + 5 }
+
+fn six(x: u8) -> u8 { x + 1 + 2 + 3 + 4 + 5 + 6 }
+
+fn main(x: u8) -> u8 {
+    one(x) + two(x) + three(x) + four(x) + five(x) + six(x)
+}
+"#;
+
+fn application() -> zinc_types::Application {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    compile_with_files(manifest, &[("main", CIRCUIT)])
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_application(false, None)
+}
+
+static CONTRACT: &str = r#"
+struct Order {
+    amount: u64,
+    price: u64,
+}
+
+contract Test {
+    pub orders: [Order; 4];
+    pub count: u8;
+}
+"#;
+
+fn contract() -> zinc_types::Contract {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Contract);
+    let state = compile_with_files(manifest, &[("main", CONTRACT)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match state.into_application(false, None) {
+        zinc_types::Application::Contract(contract) => contract,
+        _ => panic!("the project must be a contract"),
+    }
+}
+
+static LIBRARY: &str = r#"
+pub fn add(a: u8, b: u8) -> u8 { a + b }
+
+fn main(a: u8) -> u8 { add(a, 1) }
+
+#[test]
+fn in_source() {
+    require(add(1, 2) == 3);
+}
+"#;
+
+static TEST_ROOT: &str = r#"
+use test::add;
+
+#[test]
+fn passes() {
+    require(add(2, 2) == 4);
+}
+
+#[test]
+fn fails() {
+    require(test::add(2, 2) == 5);
+}
+"#;
+
+static MODULES_ENTRY: &str = r#"
+mod math;
+mod geometry;
+
+fn main(a: u8) -> u8 { a }
+
+#[test]
+fn add() {
+    require(1 + 1 == 2);
+}
+"#;
+
+static MODULES_LEAF: &str = r#"
+#[test]
+fn add() {
+    require(2 + 2 == 4);
+}
+"#;
+
+static MODULES_NESTED: &str = r#"
+mod shapes;
+
+#[test]
+fn add() {
+    require(3 + 3 == 6);
+}
+"#;
+
+fn application_with_test_root(test_root: &str) -> anyhow::Result<zinc_types::Application> {
+    application_with_sources(
+        vec![("main", file("main", "test/main.zn", LIBRARY))],
+        vec![("arithmetic", test_root)],
+    )
+}
+
+fn application_with_sources(
+    modules: Vec<(&str, zinc_project::Source)>,
+    test_roots: Vec<(&str, &str)>,
+) -> anyhow::Result<zinc_types::Application> {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    let state = compile_with_modules(manifest, modules, test_roots)?;
+
+    Ok(state.into_application(false, None))
+}
+
+fn total(application: &zinc_types::Application) -> usize {
+    InstructionCountAnalyzer::count(application.instructions())
+        .into_iter()
+        .map(|(_name, count)| count)
+        .sum()
+}
+
+#[test]
+fn ok_instructions_under_limit() {
+    let application = application();
+    let total = total(&application);
+
+    assert!(Bundler::check_instructions(&application, total).is_ok());
+}
+
+#[test]
+fn error_instructions_over_limit() {
+    let application = application();
+    let total = total(&application);
+
+    match Bundler::check_instructions(&application, total - 1) {
+        Err(Error::InstructionLimitExceeded {
+            total: actual_total,
+            limit,
+            functions,
+        }) => {
+            assert_eq!(actual_total, total);
+            assert_eq!(limit, total - 1);
+            assert_eq!(functions.len(), 5);
+        }
+        result => panic!("expected the instruction limit error, found {:?}", result),
+    }
+}
+
+#[test]
+fn ok_instructions_top_functions() {
+    let application = application();
+
+    let functions = match Bundler::check_instructions(&application, 1) {
+        Err(Error::InstructionLimitExceeded { functions, .. }) => functions,
+        result => panic!("expected the instruction limit error, found {:?}", result),
+    };
+    let names: Vec<&str> = functions
+        .iter()
+        .map(|(name, _count)| name.as_str())
+        .collect();
+
+    assert_eq!(names, vec!["six", "main", "five", "four", "three"]);
+    assert!(functions.windows(2).all(|pair| pair[0].1 > pair[1].1));
+}
+
+#[test]
+fn ok_instructions_error_message() {
+    let application = application();
+
+    let error =
+        Bundler::check_instructions(&application, 1).expect_err(zinc_const::panic::TEST_DATA_VALID);
+    let message = error.to_string();
+
+    assert!(message.contains("exceeds the limit of 1"));
+    assert!(message.contains("`main` ("));
+    assert!(message.contains("`three` ("));
+    assert!(!message.contains("`two` ("));
+    assert!(message.contains("--max-instructions"));
+}
+
+#[test]
+fn ok_storage_under_limit() {
+    let contract = contract();
+
+    assert!(Bundler::check_storage(&contract, contract.storage_leaves).is_ok());
+}
+
+#[test]
+fn error_storage_over_limit() {
+    let contract = contract();
+    let total = contract.storage_leaves;
+
+    match Bundler::check_storage(&contract, total - 1) {
+        Err(Error::StorageLeafLimitExceeded {
+            total: actual_total,
+            limit,
+            fields,
+        }) => {
+            assert_eq!(actual_total, total);
+            assert_eq!(limit, total - 1);
+            assert_eq!(fields[0], ("orders".to_owned(), 8));
+            assert_eq!(
+                fields.iter().map(|(_name, count)| count).sum::<usize>(),
+                total
+            );
+            assert!(fields.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        }
+        result => panic!("expected the storage leaf limit error, found {:?}", result),
+    }
+}
+
+#[test]
+fn ok_storage_error_message() {
+    let contract = contract();
+
+    let error = Bundler::check_storage(&contract, 1).expect_err(zinc_const::panic::TEST_DATA_VALID);
+    let message = error.to_string();
+
+    assert!(message.contains(format!("has {} leaves", contract.storage_leaves).as_str()));
+    assert!(message.contains("exceeds the limit of 1"));
+    assert!(message.contains("`orders` (8), "));
+    assert!(message.contains("`count` (1)"));
+}
+
+#[test]
+fn ok_test_root_unit_tests() {
+    let application =
+        application_with_test_root(TEST_ROOT).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let unit_tests = match application {
+        zinc_types::Application::Circuit(circuit) => circuit.unit_tests,
+        _ => panic!("the project must be a circuit"),
+    };
+    let names: Vec<&str> = unit_tests.keys().map(|name| name.as_str()).collect();
+
+    assert_eq!(
+        names,
+        vec![
+            "in_source",
+            "tests/arithmetic.zn::passes",
+            "tests/arithmetic.zn::fails",
+        ]
+    );
+}
+
+#[test]
+fn ok_module_unit_tests_qualified() {
+    let mut geometry = HashMap::new();
+    geometry.insert(
+        "mod".to_owned(),
+        file("mod", "test/geometry/mod.zn", MODULES_NESTED),
+    );
+    geometry.insert(
+        "shapes".to_owned(),
+        file("shapes", "test/geometry/shapes.zn", MODULES_LEAF),
+    );
+
+    let application = application_with_sources(
+        vec![
+            ("main", file("main", "test/main.zn", MODULES_ENTRY)),
+            ("math", file("math", "test/math.zn", MODULES_LEAF)),
+            (
+                "geometry",
+                zinc_project::Source::Directory(zinc_project::Directory {
+                    name: "geometry".to_owned(),
+                    path: "test/geometry".to_owned(),
+                    modules: geometry,
+                }),
+            ),
+        ],
+        vec![("arithmetic", MODULES_LEAF)],
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let unit_tests = match application {
+        zinc_types::Application::Circuit(circuit) => circuit.unit_tests,
+        _ => panic!("the project must be a circuit"),
+    };
+    let mut names: Vec<&str> = unit_tests.keys().map(|name| name.as_str()).collect();
+    names.sort();
+
+    assert_eq!(
+        names,
+        vec![
+            "add",
+            "geometry::add",
+            "geometry::shapes::add",
+            "math::add",
+            "tests/arithmetic.zn::add",
+        ]
+    );
+}
+
+#[test]
+fn error_test_root_unit_test_duplicate() {
+    let error = application_with_sources(
+        vec![("main", file("main", "test/main.zn", LIBRARY))],
+        vec![("arithmetic", TEST_ROOT), ("arithmetic", TEST_ROOT)],
+    )
+    .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(error
+        .to_string()
+        .contains("unit test `tests/arithmetic.zn::passes` already declared here"));
+}
+
+#[test]
+fn error_test_root_item_undeclared() {
+    let error = application_with_test_root("use test::sub;\n")
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(error.to_string().contains("sub"));
+}
+
+///
+/// Writes a project with the `dependencies` of version `0.1.0` to the `path` directory.
+///
+fn write_project(
+    path: &Path,
+    name: &str,
+    r#type: zinc_project::ProjectType,
+    dependencies: &[&str],
+    code: &str,
+) {
+    let mut manifest = format!(
+        "[project]\nname = '{}'\ntype = '{}'\nversion = '0.1.0'\n\n[dependencies]\n",
+        name, r#type
+    );
+    for dependency in dependencies.iter() {
+        manifest.push_str(format!("{} = '0.1.0'\n", dependency).as_str());
+    }
+
+    let entry = match r#type {
+        zinc_project::ProjectType::Library => zinc_const::file_name::LIBRARY_ENTRY,
+        _ => zinc_const::file_name::APPLICATION_ENTRY,
+    };
+
+    fs::create_dir_all(path.join(zinc_const::directory::SOURCE))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::write(
+        path.join(format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        )),
+        manifest,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::write(
+        path.join(zinc_const::directory::SOURCE).join(format!(
+            "{}.{}",
+            entry,
+            zinc_const::extension::SOURCE
+        )),
+        code,
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+}
+
+///
+/// Bundles the `app` circuit, which uses the `swap` library, which in turn uses the `math`
+/// library with the `math_code` source code.
+///
+fn bundle_dependency_chain(name: &str, math_code: &str) -> anyhow::Result<zinc_types::Build> {
+    let root = std::env::temp_dir().join(format!("zinc-bundler-{}-{}", name, std::process::id()));
+    let project_path = root.join("app");
+    let dependencies_path: PathBuf = project_path.join(zinc_const::directory::TARGET_DEPS);
+
+    write_project(
+        project_path.as_path(),
+        "app",
+        zinc_project::ProjectType::Circuit,
+        &["swap"],
+        "use swap::exchange;\n\nfn main(a: u8) -> u8 { exchange(a) }\n",
+    );
+    write_project(
+        dependencies_path.join("swap-0.1.0").as_path(),
+        "swap",
+        zinc_project::ProjectType::Library,
+        &["math"],
+        "use math::sqrt;\n\npub fn exchange(a: u8) -> u8 { sqrt(a) }\n",
+    );
+    write_project(
+        dependencies_path.join("math-0.1.0").as_path(),
+        "math",
+        zinc_project::ProjectType::Library,
+        &[],
+        math_code,
+    );
+
+    let result = Bundler::new(
+        project_path,
+        dependencies_path,
+        false,
+        None,
+        None,
+        None,
+        vec![],
+        BTreeMap::new(),
+    )
+    .bundle();
+
+    let _ = fs::remove_dir_all(root);
+    result
+}
+
+#[test]
+fn ok_dependency_chain() {
+    assert!(bundle_dependency_chain("ok", "pub fn sqrt(a: u8) -> u8 { a }\n").is_ok());
+}
+
+#[test]
+fn error_dependency_chain_notes() {
+    let error = bundle_dependency_chain("notes", "pub fn sqrt(a: u8) -> u8 { b }\n")
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    let (message, notes) = match error.downcast_ref::<Error>() {
+        Some(Error::Dependency { message, notes }) => (message, notes),
+        _ => panic!("the error must be a dependency one"),
+    };
+    assert!(message.contains("math-0.1.0"));
+    assert_eq!(notes.len(), 2);
+    assert!(notes[0].starts_with("required by `use math::sqrt` at "));
+    assert!(notes[0].contains("swap-0.1.0"));
+    assert!(notes[1].starts_with("required by `use swap::exchange` at "));
+
+    let formatted = error.to_string();
+    let first = formatted
+        .find("`use math::sqrt`")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let second = formatted
+        .find("`use swap::exchange`")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(first < second);
+}
+
+#[test]
+fn error_dependency_chain_json() {
+    let error = bundle_dependency_chain("json", "pub fn sqrt(a: u8) -> u8 { b }\n")
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    let json = Error::to_json(&error);
+    let notes = json["notes"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(notes.len(), 2);
+    assert!(notes[0]
+        .as_str()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .contains("`use math::sqrt`"));
+    assert!(notes[1]
+        .as_str()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .contains("`use swap::exchange`"));
+}
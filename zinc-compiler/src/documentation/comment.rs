@@ -0,0 +1,115 @@
+//!
+//! The documentation comment reader.
+//!
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+/// The outer doc comment line prefix.
+const OUTER_PREFIX: &str = "///";
+
+/// The inner doc comment line prefix.
+const INNER_PREFIX: &str = "//!";
+
+/// The attribute line prefix, which may go between a doc comment and its item.
+const ATTRIBUTE_PREFIX: &str = "#[";
+
+///
+/// Returns the text of the outer `///` doc comment written right above the item at `location`.
+///
+/// Since the lexical analyzer drops the comments, they are read from the source code directly.
+/// The attribute lines between the comment and the item are skipped.
+///
+pub fn outer(location: Location) -> Option<String> {
+    let lines = lines(location.file)?;
+
+    let mut comment = Vec::new();
+    for line in lines
+        .iter()
+        .take(location.line.saturating_sub(1))
+        .rev()
+        .map(|line| line.trim())
+    {
+        if line.starts_with(ATTRIBUTE_PREFIX) {
+            continue;
+        }
+
+        match line.strip_prefix(OUTER_PREFIX) {
+            Some(text) if !text.starts_with('/') => comment.push(text),
+            _ => break,
+        }
+    }
+    comment.reverse();
+
+    text(comment)
+}
+
+///
+/// Returns the text of the inner `//!` doc comment written at the top of the module `file`.
+///
+pub fn inner(file: usize) -> Option<String> {
+    let lines = lines(file)?;
+
+    let comment = lines
+        .iter()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with(INNER_PREFIX))
+        .filter_map(|line| line.strip_prefix(INNER_PREFIX))
+        .collect();
+
+    text(comment)
+}
+
+///
+/// Checks whether the item at `location` is declared with the `pub` keyword.
+///
+/// The function semantic types do not keep the visibility, so it is read from the source code
+/// preceding the `fn` keyword, e.g. `pub const fn`.
+///
+pub fn is_public(location: Location) -> bool {
+    let line = match lines(location.file)
+        .and_then(|lines| lines.into_iter().nth(location.line.saturating_sub(1)))
+    {
+        Some(line) => line,
+        None => return false,
+    };
+
+    let prefix: String = line
+        .chars()
+        .take(location.column.saturating_sub(1))
+        .collect();
+    let keyword = Keyword::Pub.to_string();
+
+    prefix.split_whitespace().any(|word| word == keyword)
+}
+
+///
+/// Returns the source code lines of the `file` from the file index.
+///
+fn lines(file: usize) -> Option<Vec<String>> {
+    FILE_INDEX
+        .inner
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .get(&file)
+        .map(|data| data.code.lines().map(|line| line.to_owned()).collect())
+}
+
+///
+/// Joins the comment `lines` with their leading space removed, and trims the empty lines
+/// around the text.
+///
+fn text(lines: Vec<&str>) -> Option<String> {
+    let lines: Vec<&str> = lines
+        .into_iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect();
+
+    let text = lines.join("\n").trim_matches('\n').to_owned();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
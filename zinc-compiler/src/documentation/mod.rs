@@ -0,0 +1,319 @@
+//!
+//! The project documentation extractor.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod comment;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::constant::Constant as ConstantElement;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type as TypeElement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+///
+/// The project documentation extractor, which walks the analyzed module scopes and collects
+/// the declared items with their signatures and doc comments.
+///
+pub struct Extractor {
+    /// Whether the private functions, methods, and storage fields are documented.
+    is_private: bool,
+}
+
+impl Extractor {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(is_private: bool) -> Self {
+        Self { is_private }
+    }
+
+    ///
+    /// Extracts the documentation of the project entry module `scope` and its submodules.
+    ///
+    pub fn extract(
+        &self,
+        project: &zinc_project::ManifestProject,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<zinc_types::Documentation, Error> {
+        let file = match *RefCell::borrow(&Scope::get_module_self_alias(scope.clone())) {
+            ScopeItem::Module(ref module) => module.file,
+            _ => None,
+        };
+
+        let mut modules = Vec::new();
+        self.module(project.name.to_owned(), file, scope, &mut modules)?;
+
+        Ok(zinc_types::Documentation::new(
+            project.name.to_owned(),
+            project.version.to_string(),
+            modules,
+        ))
+    }
+
+    ///
+    /// Documents the module `scope` at `path` and, recursively, its submodules.
+    ///
+    fn module(
+        &self,
+        path: String,
+        file: Option<usize>,
+        scope: Rc<RefCell<Scope>>,
+        modules: &mut Vec<zinc_types::DocumentationModule>,
+    ) -> Result<(), Error> {
+        let mut items = Vec::new();
+        for (name, item) in RefCell::borrow(&scope).declared_items().into_iter() {
+            let item_path = format!(
+                "{}{}{}",
+                path,
+                zinc_types::Documentation::PATH_SEPARATOR,
+                name
+            );
+
+            match *RefCell::borrow(&item) {
+                ScopeItem::Module(ref module) => {
+                    self.module(item_path, module.file, module.scope()?, modules)?;
+                }
+                ScopeItem::Constant(ref constant) => {
+                    let signature = Self::constant_signature(name.as_str(), &constant.define()?);
+                    items.push(zinc_types::DocumentationItem::new(
+                        zinc_types::DocumentationItemKind::Constant,
+                        name,
+                        item_path,
+                        signature,
+                        comment::outer(constant.location),
+                        true,
+                        vec![],
+                    ));
+                }
+                ScopeItem::Type(ref r#type) => {
+                    let location = r#type
+                        .location
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    if let Some(item) =
+                        self.type_item(name, item_path, location, r#type.define()?)?
+                    {
+                        items.push(item);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        modules.push(zinc_types::DocumentationModule::new(
+            path,
+            file.and_then(comment::inner),
+            items,
+        ));
+
+        Ok(())
+    }
+
+    ///
+    /// Documents the type item `name` declared at `location`.
+    ///
+    /// Returns `None` for unit tests and the private functions, unless the latter are requested.
+    ///
+    fn type_item(
+        &self,
+        name: String,
+        path: String,
+        location: Location,
+        r#type: TypeElement,
+    ) -> Result<Option<zinc_types::DocumentationItem>, Error> {
+        let description = comment::outer(location);
+
+        let (kind, signature, members, is_public) = match r#type {
+            TypeElement::Structure(ref structure) if structure.identifier == name => {
+                let mut members: Vec<zinc_types::DocumentationMember> = structure
+                    .fields
+                    .iter()
+                    .map(|(name, r#type)| {
+                        zinc_types::DocumentationMember::new(
+                            zinc_types::DocumentationMemberKind::Field,
+                            name.to_owned(),
+                            format!("{}: {}", name, r#type),
+                            None,
+                            true,
+                        )
+                    })
+                    .collect();
+                members.extend(self.associated(structure.scope.clone())?);
+
+                let generics = match structure.generics {
+                    Some(ref generics) => format!("<{}>", generics.join(", ")),
+                    None => String::new(),
+                };
+
+                (
+                    zinc_types::DocumentationItemKind::Structure,
+                    format!("struct {}{}", name, generics),
+                    members,
+                    true,
+                )
+            }
+            TypeElement::Enumeration(ref enumeration) if enumeration.identifier == name => (
+                zinc_types::DocumentationItemKind::Enumeration,
+                format!("enum {}", name),
+                self.associated(enumeration.scope.clone())?,
+                true,
+            ),
+            TypeElement::Contract(ref contract) if contract.identifier == name => {
+                let mut members: Vec<zinc_types::DocumentationMember> = contract
+                    .fields
+                    .iter()
+                    .filter(|field| !field.is_implicit && (field.is_public || self.is_private))
+                    .map(|field| {
+                        zinc_types::DocumentationMember::new(
+                            zinc_types::DocumentationMemberKind::StorageField,
+                            field.identifier.name.to_owned(),
+                            format!("{}: {}", field.identifier.name, field.r#type),
+                            comment::outer(field.identifier.location),
+                            field.is_public,
+                        )
+                    })
+                    .collect();
+                members.extend(self.associated(contract.scope.clone())?);
+
+                (
+                    zinc_types::DocumentationItemKind::Contract,
+                    format!("contract {}", name),
+                    members,
+                    true,
+                )
+            }
+            TypeElement::Interface(ref interface) if interface.identifier == name => (
+                zinc_types::DocumentationItemKind::Interface,
+                format!("interface {}", name),
+                interface
+                    .methods
+                    .iter()
+                    .map(|method| {
+                        zinc_types::DocumentationMember::new(
+                            zinc_types::DocumentationMemberKind::Method,
+                            method.identifier.to_owned(),
+                            method.to_string(),
+                            comment::outer(method.location),
+                            true,
+                        )
+                    })
+                    .collect(),
+                true,
+            ),
+            TypeElement::Function(FunctionType::Test(_)) => return Ok(None),
+            TypeElement::Function(ref function) if function.identifier() == name => {
+                let is_public = comment::is_public(location);
+                if !is_public && !self.is_private {
+                    return Ok(None);
+                }
+
+                (
+                    zinc_types::DocumentationItemKind::Function,
+                    function.to_string(),
+                    vec![],
+                    is_public,
+                )
+            }
+            r#type => (
+                zinc_types::DocumentationItemKind::Type,
+                format!("type {} = {}", name, r#type),
+                vec![],
+                true,
+            ),
+        };
+
+        Ok(Some(zinc_types::DocumentationItem::new(
+            kind,
+            name,
+            path,
+            signature,
+            description,
+            is_public,
+            members,
+        )))
+    }
+
+    ///
+    /// Documents the enumeration variants, associated constants, and methods declared in
+    /// the structure, enumeration, or contract `scope`.
+    ///
+    fn associated(
+        &self,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Vec<zinc_types::DocumentationMember>, Error> {
+        let mut members = Vec::new();
+        for (name, item) in RefCell::borrow(&scope).declared_items().into_iter() {
+            match *RefCell::borrow(&item) {
+                ScopeItem::Variant(ref variant) => {
+                    let signature = match variant.constant {
+                        ConstantElement::Integer(ref integer) => {
+                            format!("{} = {}", name, integer.value)
+                        }
+                        _ => name.clone(),
+                    };
+
+                    members.push(zinc_types::DocumentationMember::new(
+                        zinc_types::DocumentationMemberKind::Variant,
+                        name,
+                        signature,
+                        comment::outer(variant.location),
+                        true,
+                    ));
+                }
+                ScopeItem::Constant(ref constant) => {
+                    let signature = Self::constant_signature(name.as_str(), &constant.define()?);
+                    members.push(zinc_types::DocumentationMember::new(
+                        zinc_types::DocumentationMemberKind::Constant,
+                        name,
+                        signature,
+                        comment::outer(constant.location),
+                        true,
+                    ));
+                }
+                ScopeItem::Type(ref r#type) => {
+                    let function = match r#type.define()? {
+                        TypeElement::Function(FunctionType::Intrinsic(_))
+                        | TypeElement::Function(FunctionType::Test(_)) => continue,
+                        TypeElement::Function(function) => function,
+                        _ => continue,
+                    };
+
+                    let location = r#type
+                        .location
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    let is_public = comment::is_public(location);
+                    if !is_public && !self.is_private {
+                        continue;
+                    }
+
+                    members.push(zinc_types::DocumentationMember::new(
+                        zinc_types::DocumentationMemberKind::Method,
+                        name,
+                        function.to_string(),
+                        comment::outer(location),
+                        is_public,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(members)
+    }
+
+    ///
+    /// Returns the constant signature, e.g. `const FEE: u8`.
+    ///
+    fn constant_signature(name: &str, constant: &ConstantElement) -> String {
+        format!("const {}: {}", name, constant.r#type())
+    }
+}
@@ -0,0 +1,267 @@
+//!
+//! The project documentation extractor tests.
+//!
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::documentation::Extractor;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::source::provider::memory::Memory as MemoryProvider;
+use crate::source::Source;
+
+static MAIN: &str = r#"//!
+//! The exchange library.
+//!
+
+mod math;
+
+use self::math::Fraction;
+
+/// The exchange fee in percent.
+const FEE_PERCENT: u8 = 3;
+
+/// The order side.
+enum Side {
+    /// Buys the base token.
+    Buy = 0,
+    /// Sells the base token.
+    Sell = 1,
+}
+
+/// Fills an order.
+pub fn fill(side: Side, amount: Fraction) -> u64 {
+    amount.numerator * (FEE_PERCENT as u64)
+}
+
+fn helper() -> u8 {
+    42
+}
+
+#[test]
+fn filled() {
+    require(helper() == 42);
+}
+
+/// The order book interface.
+interface Book {
+    /// The total volume.
+    fn volume(self) -> u64;
+}
+
+/// The exchange contract.
+contract Exchange implements Book {
+    /// The total volume.
+    pub total: u64;
+
+    balance: u64;
+
+    /// Returns the total volume.
+    pub fn volume(self) -> u64 {
+        self.total
+    }
+
+    fn secret(self) -> u64 {
+        self.balance
+    }
+}
+"#;
+
+static MATH: &str = r#"//!
+//! The fixed-point arithmetic.
+//!
+
+/// A fraction.
+struct Fraction {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl Fraction {
+    /// The zero fraction numerator.
+    const ZERO: u64 = 0;
+
+    /// Adds two fractions.
+    pub fn add(self, other: Fraction) -> Fraction {
+        Fraction {
+            numerator: self.numerator * other.denominator + other.numerator * self.denominator,
+            denominator: self.denominator * other.denominator,
+        }
+    }
+}
+
+type Ratio = Fraction;
+"#;
+
+fn document(is_private: bool) -> zinc_types::Documentation {
+    let root = Path::new(zinc_const::directory::SOURCE);
+    let provider = MemoryProvider::new()
+        .with_file(root.join("main.zn"), MAIN)
+        .with_file(root.join("math.zn"), MATH);
+    let source =
+        Source::try_from_provider(&provider, root, true).expect(zinc_const::panic::TEST_DATA_VALID);
+    let project = zinc_project::ManifestProject::new(
+        "exchange".to_owned(),
+        zinc_project::ProjectType::Contract,
+        semver::Version::new(0, 1, 0),
+    );
+
+    let scope = EntryAnalyzer::define(source, project.clone(), HashMap::new(), false)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    Extractor::new(is_private)
+        .extract(&project, scope)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+fn item<'a>(
+    documentation: &'a zinc_types::Documentation,
+    path: &str,
+) -> Option<&'a zinc_types::DocumentationItem> {
+    documentation
+        .modules
+        .iter()
+        .flat_map(|module| module.items.iter())
+        .find(|item| item.path == path)
+}
+
+#[test]
+fn ok_modules() {
+    let documentation = document(false);
+
+    assert_eq!(documentation.name, "exchange");
+    assert_eq!(documentation.version, "0.1.0");
+
+    let modules: Vec<(&str, Option<&str>)> = documentation
+        .modules
+        .iter()
+        .map(|module| (module.path.as_str(), module.description.as_deref()))
+        .collect();
+    assert_eq!(
+        modules,
+        vec![
+            ("exchange", Some("The exchange library.")),
+            ("exchange::math", Some("The fixed-point arithmetic.")),
+        ]
+    );
+}
+
+#[test]
+fn ok_items() {
+    let documentation = document(false);
+
+    let items: Vec<(&str, zinc_types::DocumentationItemKind)> = documentation.modules[0]
+        .items
+        .iter()
+        .map(|item| (item.name.as_str(), item.kind))
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            ("Book", zinc_types::DocumentationItemKind::Interface),
+            ("Exchange", zinc_types::DocumentationItemKind::Contract),
+            ("FEE_PERCENT", zinc_types::DocumentationItemKind::Constant),
+            ("Side", zinc_types::DocumentationItemKind::Enumeration),
+            ("fill", zinc_types::DocumentationItemKind::Function),
+        ]
+    );
+
+    let fill = item(&documentation, "exchange::fill").expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        fill.signature,
+        "fn fill(side: Side, amount: Fraction) -> u64"
+    );
+    assert_eq!(fill.description.as_deref(), Some("Fills an order."));
+
+    let ratio =
+        item(&documentation, "exchange::math::Ratio").expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(ratio.kind, zinc_types::DocumentationItemKind::Type);
+    assert_eq!(ratio.signature, "type Ratio = Fraction");
+}
+
+#[test]
+fn ok_members() {
+    let documentation = document(false);
+
+    let side = item(&documentation, "exchange::Side").expect(zinc_const::panic::TEST_DATA_VALID);
+    let variants: Vec<(&str, Option<&str>)> = side
+        .members
+        .iter()
+        .map(|member| (member.signature.as_str(), member.description.as_deref()))
+        .collect();
+    assert_eq!(
+        variants,
+        vec![
+            ("Buy = 0", Some("Buys the base token.")),
+            ("Sell = 1", Some("Sells the base token.")),
+        ]
+    );
+
+    let fraction =
+        item(&documentation, "exchange::math::Fraction").expect(zinc_const::panic::TEST_DATA_VALID);
+    let anchors: Vec<String> = fraction
+        .members
+        .iter()
+        .map(|member| member.anchor())
+        .collect();
+    assert_eq!(
+        anchors,
+        vec![
+            "field.numerator",
+            "field.denominator",
+            "constant.ZERO",
+            "method.add"
+        ]
+    );
+
+    let book = item(&documentation, "exchange::Book").expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(book.members[0].signature, "fn volume(self) -> u64");
+    assert_eq!(
+        book.members[0].description.as_deref(),
+        Some("The total volume.")
+    );
+}
+
+#[test]
+fn ok_private_items_skipped() {
+    let documentation = document(false);
+
+    assert!(item(&documentation, "exchange::helper").is_none());
+    assert!(item(&documentation, "exchange::filled").is_none());
+
+    let exchange =
+        item(&documentation, "exchange::Exchange").expect(zinc_const::panic::TEST_DATA_VALID);
+    let anchors: Vec<String> = exchange
+        .members
+        .iter()
+        .map(|member| member.anchor())
+        .collect();
+    assert_eq!(anchors, vec!["storage.total", "method.volume"]);
+}
+
+#[test]
+fn ok_private_items_documented() {
+    let documentation = document(true);
+
+    let helper =
+        item(&documentation, "exchange::helper").expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(!helper.is_public);
+    assert!(item(&documentation, "exchange::filled").is_none());
+
+    let exchange =
+        item(&documentation, "exchange::Exchange").expect(zinc_const::panic::TEST_DATA_VALID);
+    let members: Vec<(String, bool)> = exchange
+        .members
+        .iter()
+        .map(|member| (member.anchor(), member.is_public))
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            ("storage.total".to_owned(), true),
+            ("storage.balance".to_owned(), false),
+            ("method.volume".to_owned(), true),
+            ("method.secret".to_owned(), false),
+        ]
+    );
+}
@@ -94,6 +94,10 @@ impl Error {
                                      code,location,
                 None,
             ),
+            Self::Lexical(LexicalError::InvalidIntegerSuffix { location, found }) => Self::format_line( format!("invalid integer literal suffix `{}`", found).as_str(),
+                                                                                                    code,location,
+                Some("the suffix must be an integer type from `u8`/`i8` to `u248`/`i248`, or `field`"),
+            ),
             Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line( format!("invalid character `{}`", found).as_str(),
                                                                                                     code,location,
                 None,
@@ -228,6 +232,19 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::InvalidIntegerSuffix { location, value, r#type }) => {
+                Self::format_line( format!("the integer literal `{}` does not fit its suffix type `{}`", value, r#type).as_str(),
+                                   code, location,
+                                   Some("remove the suffix or specify a larger type"),
+                )
+            }
+            Self::Semantic(SemanticError::IntegerSuffixTypesMismatch { location, expected, found, reference }) => {
+                Self::format_line_with_reference(format!("the integer literal suffix type `{}` does not match the variable type `{}`", found, expected).as_str(),
+                                   code, location,
+                                   Some(reference),
+                                   Some("change the suffix or remove it to let the literal type be inferred"),
+                )
+            }
             Self::Semantic(SemanticError::InvalidInteger { location, inner: zinc_math::Error::ExponentTooSmall(exponent) }) => {
                 Self::format_line(format!("The exponent value `{}` is too small", exponent).as_str(),
                                   code, location,
@@ -568,7 +585,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorEqualsFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorEqualsFirstOperandExpectedPrimitiveType{ location, found }) => {
                 Self::format_line( format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
+                        "the equals operator `==` expected a unit, boolean, integer, or an array, tuple, or structure of them as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -581,7 +598,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorEqualsSecondOperandExpectedBoolean{ location, found }) |
             Self::Semantic(SemanticError::OperatorEqualsSecondOperandExpectedInteger{ location, found }) => {
                 Self::format_line( format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
+                        "the equals operator `==` expected a unit, boolean, integer, or an array, tuple, or structure of them as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -591,7 +608,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorEqualsTypesMismatch { location, first, second }) => {
                 Self::format_line( format!(
-                    "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
+                    "the equals operator `==` expected two operands of the same type, found `{}` and `{}`",
                     first, second,
                 )
                                        .as_str(),
@@ -602,7 +619,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorNotEqualsFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ location, found }) => {
                 Self::format_line( format!(
-                        "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
+                        "the not equals operator `!=` expected a unit, boolean, integer, or an array, tuple, or structure of them as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -615,7 +632,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorNotEqualsSecondOperandExpectedBoolean{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotEqualsSecondOperandExpectedInteger{ location, found }) => {
                 Self::format_line( format!(
-                        "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
+                        "the not equals operator `!=` expected a unit, boolean, integer, or an array, tuple, or structure of them as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -625,7 +642,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorNotEqualsTypesMismatch { location, first, second }) => {
                 Self::format_line( format!(
-                    "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
+                    "the not equals operator `!=` expected two operands of the same type, found `{}` and `{}`",
                     first, second,
                 )
                                        .as_str(),
@@ -633,6 +650,36 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::OperatorEqualityOperandTooLarge { location, found, size, limit }) => {
+                Self::format_line( format!(
+                    "the comparison of `{}` requires {} element comparisons, which exceeds the limit of {}",
+                    found, size, limit,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("compare the values in smaller chunks, e.g. using slices or a loop"),
+                )
+            }
+            Self::Semantic(SemanticError::OperatorOrderingUndefined { location, operator, found }) => {
+                Self::format_line( format!(
+                    "the ordering operator `{}` is not defined for `{}`",
+                    operator, found,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("only the equality operators `==` and `!=` are defined for arrays, tuples, and structures"),
+                )
+            }
+            Self::Semantic(SemanticError::OperatorOrderingFieldUnbounded { location, operator }) => {
+                Self::format_line( format!(
+                    "the ordering operator `{}` requires the `field` operand to fit {} bits",
+                    operator, zinc_const::bitlength::FIELD_ORDERING_MAX,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("the field elements wrap around the modulus, so they are only ordered if proven small enough, e.g. cast from an unsigned integer like `value as field`"),
+                )
+            }
             Self::Semantic(SemanticError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorGreaterEqualsFirstOperandExpectedInteger{ location, found }) => {
                 Self::format_line( format!(
@@ -915,6 +962,16 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::OperatorNominalOperandForbidden { location, operator, found }) => {
+                Self::format_line( format!(
+                        "the operator `{}` is forbidden for the `{}` type",
+                        operator, found,
+                    )
+                        .as_str(),
+                                   code,location,
+                                   Some("cast the value to its underlying integer type with `as`"),
+                )
+            }
             Self::Semantic(SemanticError::OperatorAdditionFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorAdditionFirstOperandExpectedInteger{ location, found }) => {
                 Self::format_line( format!(
@@ -1089,10 +1146,11 @@ impl Error {
                                    Some("for inversion consider using `std::ff::invert`"),
                 )
             }
-            Self::Semantic(SemanticError::OperatorDivisionByZero { location }) => {
-                Self::format_line( "division by zero",
-                                   code,location,
-                None,
+            Self::Semantic(SemanticError::OperatorDivisionByZero { location, reference }) => {
+                Self::format_line_with_reference( "division by zero",
+                                   code, location,
+                                   reference,
+                                   reference.map(|_| "the divisor is a constant equal to zero"),
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderFirstOperandExpectedEvaluable{ location, found }) |
@@ -1143,10 +1201,11 @@ impl Error {
                                    Some("`field` type values cannot be used to get a remainder"),
                 )
             }
-            Self::Semantic(SemanticError::OperatorRemainderOfDivisionByZero { location }) => {
-                Self::format_line( "remainder of division by zero",
-                                   code,location,
-                None,
+            Self::Semantic(SemanticError::OperatorRemainderOfDivisionByZero { location, reference }) => {
+                Self::format_line_with_reference( "remainder of division by zero",
+                                   code, location,
+                                   reference,
+                                   reference.map(|_| "the divisor is a constant equal to zero"),
                 )
             }
             Self::Semantic(SemanticError::OperatorCastingFirstOperandExpectedEvaluable{ location, found }) => {
@@ -1191,6 +1250,16 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::OperatorCastingInvalidVariant { location, value, r#type, variants }) => {
+                Self::format_line( format!(
+                    "the casting operator `as` cannot convert the value `{}` into enumeration `{}`",
+                    value, r#type,
+                )
+                                       .as_str(),
+                                   code, location,
+                                   Some(format!("the enumeration variants are `{}`", variants.join("`, `")).as_str()),
+                )
+            }
             Self::Semantic(SemanticError::OperatorNotExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotExpectedBoolean{ location, found }) => {
                 Self::format_line( format!(
@@ -1396,11 +1465,19 @@ impl Error {
                                    Some(format!("consider initializing the value, e.g. `{} {{ a: 42, b: 25, ... }}`", r#type).as_str()),
                 )
             }
-            Self::Semantic(SemanticError::StructureFieldDoesNotExist { location, r#type, field_name }) => {
-                Self::format_line( format!(
+            Self::Semantic(SemanticError::StructureFieldDoesNotExist { location, r#type, field_name, place }) => {
+                let message = match place {
+                    Some(place) => format!(
+                        "field or method `{}` of `{}` does not exist in `{}`",
+                        field_name, place, r#type,
+                    ),
+                    None => format!(
                         "field or method `{}` does not exist in `{}`",
                         field_name, r#type,
-                    )
+                    ),
+                };
+
+                Self::format_line( message
                         .as_str(),
                     code,location,
                 None,
@@ -1437,25 +1514,51 @@ impl Error {
                 )
             }
 
-            Self::Semantic(SemanticError::MutatingWithDifferentType { location, expected, found }) => {
-                Self::format_line( format!("expected `{}`, found `{}`", expected, found).as_str(),
+            Self::Semantic(SemanticError::MutatingWithDifferentType { location, place, expected, found }) => {
+                Self::format_line( format!("mismatched types in the assignment to `{}`: expected `{}`, found `{}`", place, expected, found).as_str(),
                     code,location,
                 None,
                 )
             }
-            Self::Semantic(SemanticError::MutatingImmutableMemory { location, name, reference }) => {
-                Self::format_line_with_reference(format!("cannot assign twice to immutable variable `{}`", name).as_str(),
+            Self::Semantic(SemanticError::MutatingImmutableMemory { location, name, place, reference }) => {
+                let message = if place == name {
+                    format!("cannot assign twice to immutable variable `{}`", name)
+                } else {
+                    format!("cannot assign to `{}`, as the variable `{}` is immutable", place, name)
+                };
+
+                Self::format_line_with_reference(message.as_str(),
                     code, location,
                     reference,
                     Some(format!("make this variable mutable: `mut {}`", name).as_str()),
                 )
             }
-            Self::Semantic(SemanticError::MutatingImmutableContractField { location, name }) => {
-                Self::format_line(format!("cannot mutate the immutable contract storage field `{}`", name).as_str(),
+            Self::Semantic(SemanticError::VariableUninitialized { location, name, reference }) => {
+                Self::format_line_with_reference(format!("variable `{}` may be used before it is assigned", name).as_str(),
+                    code, location,
+                    Some(reference),
+                    Some("assign the variable in all the branches before reading it"),
+                )
+            }
+            Self::Semantic(SemanticError::MutatingImmutableContractField { location, name, place }) => {
+                Self::format_line(format!("cannot mutate `{}`, as the contract storage field `{}` is immutable", place, name).as_str(),
                                                  code, location,
                                    Some("such fields cannot be changed by the contract logic"),
                 )
             }
+            Self::Semantic(SemanticError::UnsafeWitnessUnconstrained { location }) => {
+                Self::format_line("the `unsafe_witness` value is not checked by any `require`",
+                    code, location,
+                    Some("bind the value with `let` and check it with `require`, since it is computed without constraints"),
+                )
+            }
+            Self::Semantic(SemanticError::UnsafeWitnessMutatingOuterMemory { location, name, place, reference }) => {
+                Self::format_line_with_reference(format!("cannot mutate `{}` inside the `unsafe_witness` expression, as the variable `{}` is declared outside it", place, name).as_str(),
+                    code, location,
+                    reference,
+                    Some("the `unsafe_witness` expression may only compute its value, since its side effects are not constrained"),
+                )
+            }
 
             Self::Semantic(SemanticError::TypeAliasExpectedType { location, found }) => {
                 Self::format_line( format!(
@@ -1497,6 +1600,16 @@ impl Error {
                                    Some("variants with the same value are temporarily prohibited"),
                 )
             }
+            Self::Semantic(SemanticError::TypeBitflagsVariantNotPowerOfTwo { location, r#type, variant_name, variant_value }) => {
+                Self::format_line( format!(
+                    "`{}` has a variant `{}` with value `{}`, which is not a power of two",
+                    r#type, variant_name, variant_value,
+                )
+                                       .as_str(),
+                                   code, location,
+                                   Some("each `#[bitflags]` variant must occupy a single bit, e.g. `1`, `2`, `4`, `8`"),
+                )
+            }
             Self::Semantic(SemanticError::TypeUnexpectedGenerics { location, r#type }) => {
                 Self::format_line( format!(
                     "type `{}` got unexpected generics",
@@ -1527,6 +1640,20 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::TypePlaceholderForbidden { location }) => {
+                Self::format_line(
+                    "the type placeholder `_` cannot be inferred here",
+                    code, location,
+                    Some("placeholders are only allowed in the type annotation of an initialized `let` binding"),
+                )
+            }
+            Self::Semantic(SemanticError::TypePlaceholderMismatch { location, expected, found }) => {
+                Self::format_line(
+                    format!("the type annotation expects {}, but the initializer has type `{}`", expected, found).as_str(),
+                    code, location,
+                    Some("consider fixing the annotation component, or replacing it with `_`"),
+                )
+            }
 
             Self::Semantic(SemanticError::FunctionArgumentCount { location, function, expected, found, reference }) => {
                 Self::format_line_with_reference( format!(
@@ -1590,6 +1717,24 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::FunctionReturnStatementType { location, expected, found, reference }) => {
+                Self::format_line_with_reference(format!(
+                        "`return` must have a value of type `{}`, found `{}`",
+                        expected, found
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some(reference),
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::FunctionReturnStatementForbidden { location }) => {
+                Self::format_line(
+                    "`return` is only allowed in runtime functions and unit tests",
+                    code, location,
+                    Some("constant functions, contract constructors and upgrades, functions with `#[ensures]` postconditions, contract methods with `#[invariant]` checks, and `unsafe_witness` expressions cannot return early"),
+                )
+            }
             Self::Semantic(SemanticError::FunctionNonCallable { location, name }) => {
                 Self::format_line( format!(
                         "attempt to call a non-callable item `{}`",
@@ -1600,14 +1745,23 @@ impl Error {
                                    Some("only functions may be called"),
                 )
             }
-            Self::Semantic(SemanticError::FunctionCallMutableFromImmutable { location, function }) => {
-                Self::format_line(format!(
-                    "the mutable method `{}` was called with an immutable instance",
-                    function,
-                )
+            Self::Semantic(SemanticError::FunctionCallMutableFromImmutable { location, function, binding, place }) => {
+                let message = if place == binding {
+                    format!(
+                        "the mutable method `{}` was called with an instance in the immutable variable `{}`",
+                        function, binding,
+                    )
+                } else {
+                    format!(
+                        "the mutable method `{}` was called with the instance `{}` in the immutable variable `{}`",
+                        function, place, binding,
+                    )
+                };
+
+                Self::format_line(message
                                                      .as_str(),
                                                  code, location,
-                                   Some("consider making the instance mutable"),
+                                   Some(format!("make this variable mutable: `mut {}`", binding).as_str()),
                 )
             }
             Self::Semantic(SemanticError::FunctionUnexpectedExclamationMark { location, function }) => {
@@ -1660,6 +1814,71 @@ impl Error {
                                    Some("array indexes cannot be greater than maximum of `u64`"),
                 )
             }
+            Self::Semantic(SemanticError::FunctionStdlibConvertBitlengthInvalid { location, value }) => {
+                Self::format_line( format!(
+                        "integer bitlength `{}` is invalid",
+                        value,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some(format!("the bitlength must be a multiple of {} from {} to {}", zinc_const::bitlength::BYTE, zinc_const::bitlength::BYTE, zinc_const::bitlength::INTEGER_MAX).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionStdlibConvertBitsCountMismatch { location, bits, found }) => {
+                Self::format_line( format!(
+                        "{} bits cannot be packed into a value of type `{}`",
+                        bits, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some(format!("the bits are packed into the smallest unsigned integer type, or `field` for {} to {} bits", zinc_const::bitlength::INTEGER_MAX + 1, zinc_const::bitlength::FIELD - 1).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionStdlibArrayConcatElementType { location, expected, found, reference }) => {
+                Self::format_line_with_reference(format!(
+                        "attempt to concatenate an array of `{}` with an array of `{}`",
+                        expected, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some(reference),
+                                   Some("the arrays must have the same element type"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionCfgKeyUnknown { location, key }) => {
+                Self::format_line( format!(
+                        "the configuration value `{}` is not set",
+                        key,
+                    )
+                        .as_str(),
+                    code, location,
+                    Some(format!(
+                        "pass it to the compiler as `--cfg {0}=<value>`, set it in the `[cfg]` section of `Zargo.toml`, or specify the default value as `cfg!(\"{0}\", <default>)`",
+                        key,
+                    ).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionCfgValueInvalid { location, key, value, expected }) => {
+                Self::format_line( format!(
+                        "the configuration value `{}` is `{}`, which cannot be converted to `{}`",
+                        key, value, expected,
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("the value passed to the compiler must be of the default value type"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionEnsuresExpectedBoolean { location, function, found }) => {
+                Self::format_line(
+                    format!(
+                        "the postcondition of the function `{}` must be a `bool` expression, found `{}`",
+                        function, found,
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("the postcondition is checked with `require`, so it must be a boolean condition"),
+                )
+            }
 
             Self::Semantic(SemanticError::UnitTestCallForbidden { location, function }) => {
                 Self::format_line( format!(
@@ -1721,6 +1940,24 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::UnitTestAssertionBeyondUnitTest { location, function }) => {
+                Self::format_line( format!(
+                    "function `{}` can only be called within unit tests",
+                    function,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("consider moving the call to a function marked with `#[test]`"),
+                )
+            }
+            Self::Semantic(SemanticError::UnitTestNameDuplicate { location, name, reference }) => {
+                Self::format_line_with_reference(
+                    format!("unit test `{}` already declared here", name).as_str(),
+                    code, location,
+                    Some(reference),
+                    Some("consider renaming either the test or its module"),
+                )
+            }
 
             Self::Semantic(SemanticError::ScopeItemUndeclared { location, name }) => {
                 Self::format_line( format!(
@@ -1732,10 +1969,10 @@ impl Error {
                 None,
                 )
             }
-            Self::Semantic(SemanticError::ScopeItemRedeclared { location, name, reference }) => {
+            Self::Semantic(SemanticError::ItemRedeclared { location, name, kind, reference, reference_kind }) => {
                 Self::format_line_with_reference(format!(
-                    "item `{}` already declared here",
-                    name
+                    "{} `{}` conflicts with the {} declared here",
+                    kind, name, reference_kind,
                 )
                                                      .as_str(),
                                                  code, location,
@@ -1760,10 +1997,23 @@ impl Error {
                                                  Some("only one contract may be declared in the project"),
                 )
             }
-            Self::Semantic(SemanticError::ScopeReferenceLoop { location }) => {
-                Self::format_line( "reference loop detected",
+            Self::Semantic(SemanticError::ScopeReferenceLoop { location, cycle, .. }) => {
+                let message = if cycle.is_empty() {
+                    "reference loop detected".to_owned()
+                } else {
+                    format!(
+                        "reference loop detected: {}",
+                        cycle
+                            .iter()
+                            .map(|identifier| format!("`{}`", identifier))
+                            .collect::<Vec<String>>()
+                            .join(" -> "),
+                    )
+                };
+
+                Self::format_line( message.as_str(),
                                    code, location,
-                                   Some("consider removing circular references between the items"),
+                                   Some("consider removing circular references between the items, or add the `#[max_depth(N)]` attribute to a directly recursive function"),
                 )
             }
 
@@ -1795,7 +2045,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line( format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
+                Self::format_line( format!("match scrutinee expected a boolean, integer, or tuple expression, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
@@ -1845,6 +2095,12 @@ impl Error {
                     Some("each pattern may occur only once"),
                 )
             }
+            Self::Semantic(SemanticError::MatchBranchOrPatternBinding { location, name }) => {
+                Self::format_line( format!("binding `{}` cannot be an alternative of an or-pattern", name).as_str(),
+                    code, location,
+                    Some("or-pattern alternatives cannot bind variables, so consider moving the binding to a separate branch"),
+                )
+            }
 
             Self::Semantic(SemanticError::ForStatementWhileExpectedBooleanCondition { location, found }) => {
                 Self::format_line( format!("expected `bool`, found `{}`", found).as_str(),
@@ -1859,6 +2115,21 @@ impl Error {
                 )
             }
 
+            Self::Semantic(SemanticError::WhileStatementBoundMissing { location }) => {
+                Self::format_line(
+                    "the `while` loop must have a constant iterations bound",
+                    code, location,
+                    Some("specify the maximal number of iterations like `#[max_iterations(10)] while i < n { ... }`"),
+                )
+            }
+            Self::Semantic(SemanticError::WhileStatementBoundExpectedConstantInteger { location, found }) => {
+                Self::format_line(
+                    format!("expected a constant non-negative integer iterations bound, found `{}`", found).as_str(),
+                    code, location,
+                    Some("the bound must be known at compile time, e.g. `#[max_iterations(10)]` or `#[max_iterations(SIZE * 2)]`"),
+                )
+            }
+
             Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
                 Self::format_line( format!(
                     "`impl` expected a type with namespace, found `{}`",
@@ -1880,6 +2151,26 @@ impl Error {
                                    Some("consider specifying a valid path to an item to import"),
                 )
             }
+            Self::Semantic(SemanticError::UseStatementGlobExpectedModule { location, found }) => {
+                Self::format_line(
+                    format!("`use` expected a module path before `::*`, but got `{}`", found).as_str(),
+                    code, location,
+                    Some("only the module items can be imported with a wildcard, e.g. `use crate::utils::*;`"),
+                )
+            }
+            Self::Semantic(SemanticError::UseStatementGlobConflict { location, name, reference, imported }) => {
+                let imported = match imported {
+                    Some(imported) => format!(" from {}", imported),
+                    None => String::new(),
+                };
+
+                Self::format_line_with_reference(
+                    format!("item `{}` imported by the wildcard{} is already declared here", name, imported).as_str(),
+                    code, location,
+                    reference,
+                    Some("consider importing the items explicitly, or renaming one of them"),
+                )
+            }
 
             Self::Semantic(SemanticError::AttributeUnknown { location, found }) => {
                 Self::format_line( format!(
@@ -1919,6 +2210,79 @@ impl Error {
                     None,
                 )
             }
+            Self::Semantic(SemanticError::AttributeExpectedStringLiteral { location, name }) => {
+                Self::format_line(
+                    format!("attribute `{}` expected a string literal", name).as_str(),
+                    code, location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::AttributeMaxDepthInvalid { location, found }) => {
+                Self::format_line( format!(
+                        "the recursion depth must be a positive integer, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("specify the maximal recursion depth like `#[max_depth(10)]`"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeNegativeValue { location, name }) => {
+                Self::format_line(
+                    format!("attribute element `{}` cannot be negative", name).as_str(),
+                    code, location,
+                    Some("the zkSync addresses and token amounts are unsigned integers"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeUnexpected { location, name, item }) => {
+                Self::format_line(
+                    format!("attribute `{}` cannot be applied to a {}", name, item).as_str(),
+                    code, location,
+                    Some("see the reference to get the list of allowed attributes"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeVersionInvalid { location, found }) => {
+                Self::format_line(
+                    format!("invalid language version `{}`", found).as_str(),
+                    code, location,
+                    Some("specify the version like `#![zinc(version = \"0.2\")]`"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeVersionUnsupported { location, found, supported }) => {
+                Self::format_line(
+                    format!("the module requires the language version {}, but the compiler version is {}", found, supported).as_str(),
+                    code, location,
+                    Some("update the compiler to compile the module"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeElementUnknown { location, name, found, expected }) => {
+                Self::format_line(
+                    format!("attribute `{}` does not have element `{}`", name, found).as_str(),
+                    code, location,
+                    Some(format!("the expected elements are `{}`", expected.join("`, `")).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeElementDuplicate { location, name, element }) => {
+                Self::format_line(
+                    format!("attribute `{}` has element `{}` specified more than once", name, element).as_str(),
+                    code, location,
+                    Some("consider removing the duplicate element"),
+                )
+            }
+            Self::Semantic(SemanticError::AttributeElementsMissing { location, name, missing }) => {
+                Self::format_line(
+                    format!("attribute `{}` is missing element(s) `{}`", name, missing.join("`, `")).as_str(),
+                    code, location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::AttributeElementOverflow { location, name, bitlength }) => {
+                Self::format_line(
+                    format!("attribute element `{}` value does not fit into {} bits", name, bitlength).as_str(),
+                    code, location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::AttributeExpectedNested { location, name }) => {
                 Self::format_line(
                     format!("attribute `{}` expected a nested element", name).as_str(),
@@ -1926,6 +2290,17 @@ impl Error {
                     Some(format!("consider passing the required elements, e.g. `{}(value = 42)`", name).as_str()),
                 )
             }
+            Self::Semantic(SemanticError::AttributeExpectedExpression { location, name }) => {
+                Self::format_line(
+                    format!("attribute `{}` expected an expression", name).as_str(),
+                    code, location,
+                    Some(if name.as_str() == zinc_const::source::ATTRIBUTE_MAX_ITERATIONS_IDENTIFIER {
+                        "specify the maximal number of iterations like `#[max_iterations(10)]`".to_owned()
+                    } else {
+                        format!("consider passing the condition, e.g. `{}(result > 0)`", name)
+                    }.as_str()),
+                )
+            }
 
             Self::Semantic(SemanticError::BindingTypeRequired { location, identifier }) => {
                 Self::format_line( format!(
@@ -1991,6 +2366,153 @@ impl Error {
                                    Some("contracts may be declared only once in the entry file"),
                 )
             }
+            Self::Semantic(SemanticError::ContractConstructorNotPublic { location }) => {
+                Self::format_line( "the contract constructor `new` must be public",
+                    code, location,
+                                   Some("consider adding the `pub` modifier"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractConstructorSelfArgument { location }) => {
+                Self::format_line( "the contract constructor `new` cannot have the `self` argument",
+                    code, location,
+                                   Some("the contract instance is created by the constructor and does not exist yet"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractConstructorReturnType { location, expected, found }) => {
+                Self::format_line( format!(
+                        "the contract constructor `new` must return `{}`, found `{}`",
+                        expected, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("consider changing the return type to `Self`"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractConstructorExternalState { location, found }) => {
+                Self::format_line( format!(
+                        "the contract constructor `new` cannot access the external state `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("the constructor is executed at publish time and may only use its arguments"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractUpgradeBeyondContract { location }) => {
+                Self::format_line( "the `#[upgrade]` attribute is only allowed for contract methods",
+                    code, location,
+                                   None,
+                )
+            }
+            Self::Semantic(SemanticError::ContractUpgradeNotPublic { location }) => {
+                Self::format_line( "the contract `#[upgrade]` method must be public",
+                    code, location,
+                                   Some("consider adding the `pub` modifier"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractUpgradeArgument { location, found }) => {
+                Self::format_line( format!(
+                        "the contract `#[upgrade]` method must have exactly one structure argument, found `({})`",
+                        found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("the argument must be a structure with the previous contract version storage fields"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractUpgradeReturnType { location, expected, found }) => {
+                Self::format_line( format!(
+                        "the contract `#[upgrade]` method must return `{}`, found `{}`",
+                        expected, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("consider changing the return type to `Self`"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractUpgradeDuplicate { location, reference }) => {
+                Self::format_line_with_reference( "the contract may have only one `#[upgrade]` method",
+                    code, location,
+                                                  Some(reference),
+                                                  Some("consider removing the attribute from one of the methods"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractRequireSenderBeyondMethod { location }) => {
+                Self::format_line( "the sender requirement attribute is only allowed for contract methods",
+                    code, location,
+                                   Some("the method must be declared in a contract and take `self` as its first argument"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractRequireSenderFieldNotFound { location, field }) => {
+                Self::format_line( format!(
+                        "the contract storage field `{}` required as the sender is not declared",
+                        field,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("the attribute must name a storage field holding the allowed sender address"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractRequireSenderFieldType { location, field, expected, found }) => {
+                Self::format_line( format!(
+                        "the contract storage field `{}` required as the sender must be `{}`, found `{}`",
+                        field, expected, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("the `zksync::Address` type is also allowed"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractRequireSenderDuplicate { location, reference }) => {
+                Self::format_line_with_reference( "the method may have only one sender requirement",
+                    code, location,
+                                                  Some(reference),
+                                                  Some("consider removing one of the attributes"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractImplementsExpectedInterface { location, found }) => {
+                Self::format_line( format!(
+                        "expected an interface, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("only interfaces declared with the `interface` statement may be implemented"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractInterfaceMethodMissing { location, interface, name, reference }) => {
+                Self::format_line_with_reference( format!(
+                        "the contract does not declare the method `{}` required by the interface `{}`",
+                        name, interface,
+                    )
+                        .as_str(),
+                    code, location,
+                                                  Some(reference),
+                                                  Some("consider declaring the method with the signature from the interface"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractInterfaceMethodMismatch { location, interface, expected, found, reference }) => {
+                Self::format_line_with_reference( format!(
+                        "the contract method signature must match the interface `{}`, expected `{}`, found `{}`",
+                        interface, expected, found,
+                    )
+                        .as_str(),
+                    code, location,
+                                                  Some(reference),
+                                                  Some("consider changing the method signature"),
+                )
+            }
+            Self::Semantic(SemanticError::ContractInvariantExpectedBoolean { location, contract, found }) => {
+                Self::format_line(
+                    format!(
+                        "the invariant of the contract `{}` must be a `bool` expression, found `{}`",
+                        contract, found,
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("the invariant is checked with `require` after every method taking `mut self`, so it must be a boolean condition"),
+                )
+            }
             Self::Semantic(SemanticError::ModuleFileNotFound { location, name }) => {
                 Self::format_line( format!(
                         "file not found for module `{}`",
@@ -11,6 +11,7 @@ use std::rc::Rc;
 
 use num::BigInt;
 use num::One;
+use num::Signed;
 use num::Zero;
 
 use zinc_lexical::Location;
@@ -23,8 +24,6 @@ use crate::generator::r#type::contract_field::ContractField;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
-use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
-use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
 
 use self::element::Element;
@@ -81,15 +80,36 @@ impl Expression {
         self.elements.extend(expression.elements);
     }
 
+    ///
+    /// Checks whether the expression ends with the `MTreeMap::get` call, whose result is
+    /// the `(value, found)` pair.
+    ///
+    pub fn is_mtreemap_get_call(&self) -> bool {
+        matches!(
+            self.elements.last(),
+            Some(Element::Operator {
+                operator: Operator::CallLibrary {
+                    identifier: LibraryFunctionIdentifier::CollectionsMTreeMapGet,
+                    ..
+                },
+                ..
+            })
+        )
+    }
+
     ///
     /// Translates an assignment operator into the bytecode.
     ///
-    fn assignment(
+    /// The `write_value` callback writes the assigned value onto the evaluation stack.
+    ///
+    fn assignment<F>(
         state: Rc<RefCell<ZincVMState>>,
         mut place: Place,
-        expression: Self,
+        write_value: F,
         location: Location,
-    ) {
+    ) where
+        F: FnOnce(Rc<RefCell<ZincVMState>>),
+    {
         match place.memory_type {
             MemoryType::Stack => {
                 let is_indexed = !place.elements.is_empty();
@@ -104,7 +124,7 @@ impl Expression {
                     place.write_to_zinc_vm(state.clone());
                 }
 
-                expression.write_to_zinc_vm(state.clone());
+                write_value(state.clone());
 
                 state.borrow_mut().push_instruction(
                     if is_indexed {
@@ -121,97 +141,50 @@ impl Expression {
             }
             MemoryType::ContractStorage { .. } => {
                 let element_size = place.element_size;
-                let total_size = place.total_size;
-                let address = state.borrow_mut().define_variable(None, total_size);
                 let reference_address = state
                     .borrow()
                     .get_variable_address(place.identifier.name.as_str())
                     .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
 
-                let storage_index = if let Some(SemanticPlaceElement::ContractField {
-                    access:
-                        ContractFieldAccess {
-                            position,
-                            element_size,
-                            ..
-                        },
-                }) = place.elements.first()
-                {
-                    let position = *position;
+                let leaves = place.write_storage_leaves(state.clone());
+                let total_size = place.total_size;
+                let is_indexed = !place.elements.is_empty();
 
-                    state.borrow_mut().push_instruction(
-                        Instruction::Load(zinc_types::Load::new(
-                            reference_address,
-                            Type::eth_address().size(),
-                        )),
-                        Some(location),
+                if is_indexed {
+                    let address = state.borrow_mut().define_variable(None, total_size);
+
+                    Place::write_storage_leaves_load(
+                        state.clone(),
+                        reference_address,
+                        &leaves,
+                        location,
                     );
-                    IntegerConstant::new(
-                        BigInt::from(position),
-                        false,
-                        zinc_const::bitlength::FIELD,
-                    )
-                    .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::StorageLoad(zinc_types::StorageLoad::new(*element_size)),
-                        Some(place.identifier.location),
+                        Instruction::Store(zinc_types::Store::new(address, total_size)),
+                        Some(location),
                     );
 
-                    place.elements.remove(0);
-
-                    position
-                } else {
-                    panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
-                };
-
-                let is_indexed = !place.elements.is_empty();
-
-                state.borrow_mut().push_instruction(
-                    Instruction::Store(zinc_types::Store::new(address, total_size)),
-                    Some(location),
-                );
-
-                if is_indexed {
                     place.write_to_zinc_vm(state.clone());
-                }
 
-                expression.write_to_zinc_vm(state.clone());
+                    write_value(state.clone());
 
-                state.borrow_mut().push_instruction(
-                    if is_indexed {
+                    state.borrow_mut().push_instruction(
                         Instruction::StoreByIndex(zinc_types::StoreByIndex::new(
                             address,
                             element_size,
                             total_size,
-                        ))
-                    } else {
-                        Instruction::Store(zinc_types::Store::new(address, total_size))
-                    },
-                    Some(location),
-                );
-
-                state.borrow_mut().push_instruction(
-                    Instruction::Load(zinc_types::Load::new(address, total_size)),
-                    Some(location),
-                );
+                        )),
+                        Some(location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address, total_size)),
+                        Some(location),
+                    );
+                } else {
+                    write_value(state.clone());
+                }
 
-                state.borrow_mut().push_instruction(
-                    Instruction::Load(zinc_types::Load::new(
-                        reference_address,
-                        Type::eth_address().size(),
-                    )),
-                    Some(location),
-                );
-                IntegerConstant::new(
-                    BigInt::from(storage_index),
-                    false,
-                    zinc_const::bitlength::FIELD,
-                )
-                .write_to_zinc_vm(state.clone());
-                state.borrow_mut().push_instruction(
-                    Instruction::StorageStore(zinc_types::StorageStore::new(total_size)),
-                    Some(location),
-                );
+                Place::write_storage_leaves_store(state, reference_address, &leaves, location);
             }
         }
     }
@@ -277,48 +250,21 @@ impl Expression {
             }
             MemoryType::ContractStorage { .. } => {
                 let element_size = place.element_size;
-                let total_size = place.total_size;
-                let address = state.borrow_mut().define_variable(None, total_size);
                 let reference_address = state
                     .borrow()
                     .get_variable_address(place.identifier.name.as_str())
                     .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
 
-                let storage_index = if let Some(SemanticPlaceElement::ContractField {
-                    access:
-                        ContractFieldAccess {
-                            position,
-                            element_size,
-                            ..
-                        },
-                }) = place.elements.first()
-                {
-                    let position = *position;
-
-                    state.borrow_mut().push_instruction(
-                        Instruction::Load(zinc_types::Load::new(
-                            reference_address,
-                            Type::eth_address().size(),
-                        )),
-                        Some(location),
-                    );
-                    IntegerConstant::new(
-                        BigInt::from(position),
-                        false,
-                        zinc_const::bitlength::FIELD,
-                    )
-                    .write_to_zinc_vm(state.clone());
-                    state.borrow_mut().push_instruction(
-                        Instruction::StorageLoad(zinc_types::StorageLoad::new(*element_size)),
-                        Some(place.identifier.location),
-                    );
-
-                    place.elements.remove(0);
+                let leaves = place.write_storage_leaves(state.clone());
+                let total_size = place.total_size;
+                let address = state.borrow_mut().define_variable(None, total_size);
 
-                    position
-                } else {
-                    panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
-                };
+                Place::write_storage_leaves_load(
+                    state.clone(),
+                    reference_address,
+                    &leaves,
+                    location,
+                );
 
                 let is_indexed = !place.elements.is_empty();
 
@@ -370,24 +316,7 @@ impl Expression {
                     Instruction::Load(zinc_types::Load::new(address, total_size)),
                     Some(location),
                 );
-
-                state.borrow_mut().push_instruction(
-                    Instruction::Load(zinc_types::Load::new(
-                        reference_address,
-                        Type::eth_address().size(),
-                    )),
-                    Some(location),
-                );
-                IntegerConstant::new(
-                    BigInt::from(storage_index),
-                    false,
-                    zinc_const::bitlength::FIELD,
-                )
-                .write_to_zinc_vm(state.clone());
-                state.borrow_mut().push_instruction(
-                    Instruction::StorageStore(zinc_types::StorageStore::new(total_size)),
-                    Some(location),
-                );
+                Place::write_storage_leaves_store(state, reference_address, &leaves, location);
             }
         }
     }
@@ -401,6 +330,65 @@ impl Expression {
             .push_instruction(instruction, Some(location));
     }
 
+    ///
+    /// Translates an array, tuple, or structure `==` or `!=` operator into the bytecode.
+    ///
+    /// Both operands are moved into temporary variables, and then their elements are
+    /// compared pairwise with the results folded with AND.
+    ///
+    fn structural_equals(
+        state: Rc<RefCell<ZincVMState>>,
+        size: usize,
+        is_negated: bool,
+        location: Location,
+    ) {
+        let address_2 = state.borrow_mut().define_variable(None, size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address_2, size)),
+            Some(location),
+        );
+        let address_1 = state.borrow_mut().define_variable(None, size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address_1, size)),
+            Some(location),
+        );
+
+        if size == 0 {
+            state.borrow_mut().push_instruction(
+                Instruction::Push(zinc_types::Push::new(
+                    BigInt::one(),
+                    zinc_types::ScalarType::Boolean,
+                )),
+                Some(location),
+            );
+        }
+
+        for index in 0..size {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address_1 + index, 1)),
+                Some(location),
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address_2 + index, 1)),
+                Some(location),
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+            if index > 0 {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_types::And), Some(location));
+            }
+        }
+
+        if is_negated {
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+        }
+    }
+
     ///
     /// Translates an unary operator into the bytecode.
     ///
@@ -419,12 +407,53 @@ impl Expression {
         input_size: usize,
         location: Location,
     ) {
+        let type_id = state.borrow_mut().resolve_function(type_id);
         state.borrow_mut().push_instruction(
             Instruction::Call(zinc_types::Call::new(type_id, input_size)),
             Some(location),
         );
     }
 
+    ///
+    /// Translates a mutable method call into the bytecode.
+    ///
+    /// The method returns its `self` instance after the result, so the instance is moved to a
+    /// temporary variable and written back to the exact slots of its memory place, e.g. a single
+    /// array element, leaving the result on the evaluation stack.
+    ///
+    /// The instance of a temporary value is discarded.
+    ///
+    fn call_mutable_method(
+        state: Rc<RefCell<ZincVMState>>,
+        type_id: usize,
+        input_size: usize,
+        instance_size: usize,
+        place: Option<Place>,
+        location: Location,
+    ) {
+        Self::call(state.clone(), type_id, input_size, location);
+
+        let address = state.borrow_mut().define_variable(None, instance_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address, instance_size)),
+            Some(location),
+        );
+
+        if let Some(place) = place {
+            Self::assignment(
+                state,
+                place,
+                |state| {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address, instance_size)),
+                        Some(location),
+                    )
+                },
+                location,
+            );
+        }
+    }
+
     ///
     /// Translates a `dbg!(...)` function call into the bytecode.
     ///
@@ -450,6 +479,31 @@ impl Expression {
         );
     }
 
+    ///
+    /// Translates an `std::test::assert_eq(...)` function call into the bytecode.
+    ///
+    fn call_assert_eq(
+        state: Rc<RefCell<ZincVMState>>,
+        r#type: Type,
+        message: Option<String>,
+        location: Location,
+    ) {
+        state.borrow_mut().push_instruction(
+            Instruction::AssertEq(zinc_types::AssertEq::new(r#type.into(), message)),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Translates an `std::test::assert_storage_unchanged()` function call into the bytecode.
+    ///
+    fn call_assert_storage_unchanged(state: Rc<RefCell<ZincVMState>>, location: Location) {
+        state.borrow_mut().push_instruction(
+            Instruction::AssertStorageUnchanged(zinc_types::AssertStorageUnchanged),
+            Some(location),
+        );
+    }
+
     ///
     /// Translates an `<Contract>::fetch(...)` function call into the bytecode.
     ///
@@ -466,6 +520,226 @@ impl Expression {
         );
     }
 
+    ///
+    /// Translates an enumeration variant check into the bytecode.
+    ///
+    /// The checked value is already on the stack, so the variant values are pushed after it.
+    ///
+    fn call_enumeration(
+        state: Rc<RefCell<ZincVMState>>,
+        identifier: LibraryFunctionIdentifier,
+        bitlength: usize,
+        variants: Vec<(String, BigInt)>,
+        output_size: usize,
+        location: Location,
+    ) {
+        let is_signed = variants.iter().any(|(_name, value)| value.is_negative());
+        let input_size = variants.len() + 1;
+
+        for (_name, value) in variants.into_iter() {
+            IntegerConstant::new(value, is_signed, bitlength).write_to_zinc_vm(state.clone());
+        }
+
+        Self::call_standard_library(state, identifier, input_size, output_size, location);
+    }
+
+    ///
+    /// Translates an `std::collections::MTreeMap::get_or(...)` function call into the bytecode.
+    ///
+    /// The map, key, and default value are already on the stack. The default value is put aside,
+    /// and the `get` call result is selected between the found value and the default one.
+    ///
+    fn call_mtreemap_get_or(
+        state: Rc<RefCell<ZincVMState>>,
+        key_size: usize,
+        value_size: usize,
+        location: Location,
+    ) {
+        let default_address = state.borrow_mut().define_variable(None, value_size);
+        let value_address = state.borrow_mut().define_variable(None, value_size);
+        let found_address = state.borrow_mut().define_variable(None, 1);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(default_address, value_size)),
+            Some(location),
+        );
+        Self::call_standard_library(
+            state.clone(),
+            LibraryFunctionIdentifier::CollectionsMTreeMapGet,
+            key_size,
+            value_size + 1,
+            location,
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(found_address, 1)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(value_address, value_size)),
+            Some(location),
+        );
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(found_address, 1)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(value_address, value_size)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_types::Else), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(default_address, value_size)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+    }
+
+    ///
+    /// Translates an `std::collections::MTreeMap::insert_if_absent(...)` function call into the
+    /// bytecode.
+    ///
+    /// The map, key, and value are already on the stack. They are put aside to be passed to the
+    /// `contains` call, and then to the `insert` call, which is only written if the key is absent.
+    ///
+    fn call_mtreemap_insert_if_absent(
+        state: Rc<RefCell<ZincVMState>>,
+        key_size: usize,
+        value_size: usize,
+        location: Location,
+    ) {
+        let map_size = 2;
+        let map_address = state.borrow_mut().define_variable(None, map_size);
+        let key_address = state.borrow_mut().define_variable(None, key_size);
+        let value_address = state.borrow_mut().define_variable(None, value_size);
+        let is_absent_address = state.borrow_mut().define_variable(None, 1);
+        let previous_address = state.borrow_mut().define_variable(None, value_size + 1);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(value_address, value_size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(key_address, key_size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(map_address, map_size)),
+            Some(location),
+        );
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(map_address, map_size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(key_address, key_size)),
+            Some(location),
+        );
+        Self::call_standard_library(
+            state.clone(),
+            LibraryFunctionIdentifier::CollectionsMTreeMapContains,
+            key_size,
+            1,
+            location,
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(is_absent_address, 1)),
+            Some(location),
+        );
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(is_absent_address, 1)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(map_address, map_size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(key_address, key_size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(value_address, value_size)),
+            Some(location),
+        );
+        Self::call_standard_library(
+            state.clone(),
+            LibraryFunctionIdentifier::CollectionsMTreeMapInsert,
+            key_size + value_size,
+            value_size + 1,
+            location,
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(previous_address, value_size + 1)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(is_absent_address, 1)),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Translates an `<Enumeration>::Flags::contains(...)` function call into the bytecode.
+    ///
+    /// The flag set and the checked flags are already on the stack. The checked flags are put
+    /// aside to be compared with the result of the bitwise AND.
+    ///
+    fn call_flags_contains(state: Rc<RefCell<ZincVMState>>, location: Location) {
+        let flags_address = state.borrow_mut().define_variable(None, 1);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(flags_address, 1)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(flags_address, 1)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::BitwiseAnd(zinc_types::BitwiseAnd),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(flags_address, 1)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+    }
+
+    ///
+    /// Translates an `<Enumeration>::Flags::is_empty()` function call into the bytecode.
+    ///
+    /// The flag set is already on the stack, so it is compared with zero of the same `bitlength`.
+    ///
+    fn call_flags_is_empty(state: Rc<RefCell<ZincVMState>>, bitlength: usize, location: Location) {
+        IntegerConstant::new(BigInt::zero(), false, bitlength).write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+    }
+
     ///
     /// Translates a standard library function call into the bytecode.
     ///
@@ -497,9 +771,12 @@ impl IBytecodeWritable for Expression {
                 Element::Operator { location, operator } => match operator {
                     Operator::None => {}
 
-                    Operator::Assignment { place, expression } => {
-                        Self::assignment(state.clone(), place, expression, location)
-                    }
+                    Operator::Assignment { place, expression } => Self::assignment(
+                        state.clone(),
+                        place,
+                        |state| expression.write_to_zinc_vm(state),
+                        location,
+                    ),
 
                     Operator::AssignmentBitwiseOr {
                         place,
@@ -564,7 +841,7 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         location,
                     ),
                     Operator::AssignmentSubtraction {
@@ -575,7 +852,7 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Sub(zinc_types::Sub),
+                        Instruction::Sub(zinc_types::Sub::default()),
                         location,
                     ),
                     Operator::AssignmentMultiplication {
@@ -586,7 +863,7 @@ impl IBytecodeWritable for Expression {
                         state.clone(),
                         place,
                         expression,
-                        Instruction::Mul(zinc_types::Mul),
+                        Instruction::Mul(zinc_types::Mul::default()),
                         location,
                     ),
                     Operator::AssignmentDivision {
@@ -671,6 +948,12 @@ impl IBytecodeWritable for Expression {
                     Operator::NotEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ne(zinc_types::Ne), location)
                     }
+                    Operator::EqualsStructural { size } => {
+                        Self::structural_equals(state.clone(), size, false, location)
+                    }
+                    Operator::NotEqualsStructural { size } => {
+                        Self::structural_equals(state.clone(), size, true, location)
+                    }
                     Operator::GreaterEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ge(zinc_types::Ge), location)
                     }
@@ -710,15 +993,21 @@ impl IBytecodeWritable for Expression {
                         location,
                     ),
 
-                    Operator::Addition { .. } => {
-                        Self::binary(state.clone(), Instruction::Add(zinc_types::Add), location)
-                    }
-                    Operator::Subtraction { .. } => {
-                        Self::binary(state.clone(), Instruction::Sub(zinc_types::Sub), location)
-                    }
-                    Operator::Multiplication { .. } => {
-                        Self::binary(state.clone(), Instruction::Mul(zinc_types::Mul), location)
-                    }
+                    Operator::Addition { .. } => Self::binary(
+                        state.clone(),
+                        Instruction::Add(zinc_types::Add::default()),
+                        location,
+                    ),
+                    Operator::Subtraction { .. } => Self::binary(
+                        state.clone(),
+                        Instruction::Sub(zinc_types::Sub::default()),
+                        location,
+                    ),
+                    Operator::Multiplication { .. } => Self::binary(
+                        state.clone(),
+                        Instruction::Mul(zinc_types::Mul::default()),
+                        location,
+                    ),
                     Operator::Division { .. } => {
                         Self::binary(state.clone(), Instruction::Div(zinc_types::Div), location)
                     }
@@ -726,6 +1015,20 @@ impl IBytecodeWritable for Expression {
                         Self::binary(state.clone(), Instruction::Rem(zinc_types::Rem), location)
                     }
 
+                    Operator::Casting {
+                        r#type:
+                            Type::Enumeration {
+                                bitlength,
+                                variants,
+                            },
+                    } => Self::call_enumeration(
+                        state.clone(),
+                        LibraryFunctionIdentifier::EnumerationCast,
+                        bitlength,
+                        variants,
+                        1,
+                        location,
+                    ),
                     Operator::Casting { r#type } => {
                         if let Some(scalar_type) = r#type.into() {
                             Self::unary(
@@ -735,6 +1038,13 @@ impl IBytecodeWritable for Expression {
                             )
                         }
                     }
+                    Operator::CastingUnchecked { r#type } => {
+                        if let Some(scalar_type) = r#type.into() {
+                            let mut instruction = zinc_types::Cast::new(scalar_type);
+                            instruction.range_check = zinc_types::RangeCheck::Elided;
+                            Self::unary(state.clone(), Instruction::Cast(instruction), location)
+                        }
+                    }
 
                     Operator::Not => {
                         Self::unary(state.clone(), Instruction::Not(zinc_types::Not), location)
@@ -772,7 +1082,7 @@ impl IBytecodeWritable for Expression {
                                 )
                                 .write_to_zinc_vm(state.clone());
                                 state.borrow_mut().push_instruction(
-                                    Instruction::Mul(zinc_types::Mul),
+                                    Instruction::Mul(zinc_types::Mul::default()),
                                     Some(location),
                                 );
                             }
@@ -805,6 +1115,19 @@ impl IBytecodeWritable for Expression {
                         type_id,
                         input_size,
                     } => Self::call(state.clone(), type_id, input_size, location),
+                    Operator::CallMutableMethod {
+                        type_id,
+                        input_size,
+                        instance_size,
+                        place,
+                    } => Self::call_mutable_method(
+                        state.clone(),
+                        type_id,
+                        input_size,
+                        instance_size,
+                        place,
+                        location,
+                    ),
                     Operator::CallDebug {
                         format,
                         argument_types,
@@ -820,9 +1143,45 @@ impl IBytecodeWritable for Expression {
                     Operator::CallRequire { message } => {
                         Self::call_require(state.clone(), message, location)
                     }
+                    Operator::CallAssertEq { r#type, message } => {
+                        Self::call_assert_eq(state.clone(), r#type, message, location)
+                    }
+                    Operator::CallAssertStorageUnchanged => {
+                        Self::call_assert_storage_unchanged(state.clone(), location)
+                    }
                     Operator::CallContractFetch { fields } => {
                         Self::call_contract_fetch(state.clone(), fields, location)
                     }
+                    Operator::CallEnumerationFrom {
+                        bitlength,
+                        variants,
+                    } => Self::call_enumeration(
+                        state.clone(),
+                        LibraryFunctionIdentifier::EnumerationFrom,
+                        bitlength,
+                        variants,
+                        2,
+                        location,
+                    ),
+                    Operator::CallMTreeMapGetOr {
+                        key_size,
+                        value_size,
+                    } => Self::call_mtreemap_get_or(state.clone(), key_size, value_size, location),
+                    Operator::CallMTreeMapInsertIfAbsent {
+                        key_size,
+                        value_size,
+                    } => Self::call_mtreemap_insert_if_absent(
+                        state.clone(),
+                        key_size,
+                        value_size,
+                        location,
+                    ),
+                    Operator::CallFlagsContains => {
+                        Self::call_flags_contains(state.clone(), location)
+                    }
+                    Operator::CallFlagsIsEmpty { bitlength } => {
+                        Self::call_flags_is_empty(state.clone(), bitlength, location)
+                    }
                     Operator::CallLibrary {
                         identifier,
                         input_size,
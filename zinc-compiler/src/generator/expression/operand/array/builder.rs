@@ -14,6 +14,8 @@ pub struct Builder {
     expressions: Vec<GeneratorExpression>,
     /// The explicit array size. If set, the array is created as repeated.
     size: Option<usize>,
+    /// The size of the repeated array element.
+    element_size: usize,
 }
 
 impl Builder {
@@ -31,6 +33,13 @@ impl Builder {
         self.size = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_element_size(&mut self, value: usize) {
+        self.element_size = value;
+    }
+
     ///
     /// Finilizes the builder and returns the built item.
     ///
@@ -45,7 +54,7 @@ impl Builder {
                     )
                 });
 
-                ArrayExpression::new_repeated(expression, size)
+                ArrayExpression::new_repeated(expression, size, self.element_size)
             }
             None => ArrayExpression::new_list(self.expressions),
         }
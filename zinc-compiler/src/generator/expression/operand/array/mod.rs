@@ -8,6 +8,8 @@ pub mod variant;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_types::Instruction;
+
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -36,9 +38,11 @@ impl Expression {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new_repeated(expression: GeneratorExpression, size: usize) -> Self {
+    /// The `expression` is evaluated once and its result is copied `size` times.
+    ///
+    pub fn new_repeated(expression: GeneratorExpression, size: usize, element_size: usize) -> Self {
         Self {
-            variant: Variant::new_repeated(expression, size),
+            variant: Variant::new_repeated(expression, size, element_size),
         }
     }
 }
@@ -51,9 +55,30 @@ impl IBytecodeWritable for Expression {
                     expression.write_to_zinc_vm(state.clone());
                 }
             }
-            Variant::Repeated { expression, size } => {
-                for expression in vec![expression; size].into_iter() {
-                    expression.write_to_zinc_vm(state.clone());
+            Variant::Repeated {
+                expression,
+                size,
+                element_size,
+            } => {
+                if size == 0 {
+                    return;
+                }
+
+                expression.write_to_zinc_vm(state.clone());
+                if size == 1 {
+                    return;
+                }
+
+                let address = state.borrow_mut().define_variable(None, element_size);
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(address, element_size)),
+                    None,
+                );
+                for _ in 0..size {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address, element_size)),
+                        None,
+                    );
                 }
             }
         }
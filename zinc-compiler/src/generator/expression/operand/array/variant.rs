@@ -20,6 +20,8 @@ pub enum Variant {
         expression: GeneratorExpression,
         /// The number of times to repeat the `expression`.
         size: usize,
+        /// The size of the array element.
+        element_size: usize,
     },
 }
 
@@ -34,7 +36,11 @@ impl Variant {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new_repeated(expression: GeneratorExpression, size: usize) -> Self {
-        Self::Repeated { expression, size }
+    pub fn new_repeated(expression: GeneratorExpression, size: usize, element_size: usize) -> Self {
+        Self::Repeated {
+            expression,
+            size,
+            element_size,
+        }
     }
 }
@@ -4,6 +4,7 @@
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 
 ///
@@ -15,6 +16,8 @@ pub struct Builder {
     statements: Vec<Statement>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The block expression type.
+    value_type: Option<Type>,
 }
 
 impl Builder {
@@ -32,10 +35,21 @@ impl Builder {
         self.expression = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_value_type(&mut self, value: Type) {
+        self.value_type = Some(value);
+    }
+
     ///
     /// Finilizes the builder and returns the built item.
     ///
     pub fn finish(self) -> BlockExpression {
-        BlockExpression::new(self.statements, self.expression)
+        let mut block = BlockExpression::new(self.statements, self.expression);
+        if let Some(value_type) = self.value_type {
+            block.set_value_type(value_type);
+        }
+        block
     }
 }
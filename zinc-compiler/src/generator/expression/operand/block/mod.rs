@@ -8,6 +8,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -15,12 +16,21 @@ use crate::generator::IBytecodeWritable;
 ///
 /// The block expression.
 ///
+/// If a statement contains an early `return`, the statements and expression after it are only
+/// executed if the function has not returned yet.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     /// The block statements.
     statements: Vec<Statement>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The block expression type, whose zeros are pushed instead of the value skipped after
+    /// an early return.
+    value_type: Option<Type>,
+    /// The type of the other branch of the conditional, if the block always returns early.
+    /// Its zeros are pushed after the block to balance the conditional branches.
+    diverging_type: Option<Type>,
 }
 
 impl Expression {
@@ -31,17 +41,77 @@ impl Expression {
         Self {
             statements,
             expression,
+            value_type: None,
+            diverging_type: None,
         }
     }
+
+    ///
+    /// Sets the block expression type.
+    ///
+    pub fn set_value_type(&mut self, value: Type) {
+        self.value_type = Some(value);
+    }
+
+    ///
+    /// Sets the type of the other conditional branch, if the block always returns early.
+    ///
+    pub fn set_diverging_type(&mut self, value: Type) {
+        self.diverging_type = Some(value);
+    }
+
+    ///
+    /// The size of the value left by the block on the evaluation stack.
+    ///
+    pub fn value_size(&self) -> usize {
+        let expression_size = match (self.expression.as_ref(), self.value_type.as_ref()) {
+            (Some(_expression), Some(r#type)) => r#type.size(),
+            _ => 0,
+        };
+        let diverging_size = self
+            .diverging_type
+            .as_ref()
+            .map(Type::size)
+            .unwrap_or_default();
+
+        expression_size + diverging_size
+    }
 }
 
 impl IBytecodeWritable for Expression {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let mut is_guarded = false;
+
         for statement in self.statements.into_iter() {
+            let returns_count = state.borrow().returns_count();
             statement.write_to_zinc_vm(state.clone());
+
+            if state.borrow().returns_count() > returns_count {
+                if is_guarded {
+                    state.borrow_mut().write_return_guard_end();
+                }
+                state.borrow_mut().write_return_guard_start();
+                is_guarded = true;
+            }
+        }
+
+        match self.expression {
+            Some(expression) if is_guarded => {
+                expression.write_to_zinc_vm(state.clone());
+                if let Some(value_type) = self.value_type {
+                    if value_type.size() > 0 {
+                        state.borrow_mut().write_return_guard_else(value_type);
+                    }
+                }
+                state.borrow_mut().write_return_guard_end();
+            }
+            Some(expression) => expression.write_to_zinc_vm(state.clone()),
+            None if is_guarded => state.borrow_mut().write_return_guard_end(),
+            None => {}
         }
-        if let Some(expression) = self.expression {
-            expression.write_to_zinc_vm(state);
+
+        if let Some(diverging_type) = self.diverging_type {
+            state.borrow_mut().write_zeros(diverging_type);
         }
     }
 }
@@ -0,0 +1,109 @@
+//!
+//! The generator expression match operand branch.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The match expression branch.
+///
+#[derive(Debug, Clone)]
+pub struct Branch {
+    /// The alternatives of the scrutinee scalar offsets and the constants they are compared with.
+    /// The branch is taken if all of them are equal in any alternative. Empty for the fallback branch.
+    pub conditions: Vec<Vec<(usize, Constant)>>,
+    /// The variables extracted from the scrutinee, as their names, offsets, and sizes.
+    pub bindings: Vec<(String, usize, usize)>,
+    /// The branch result expression.
+    pub expression: GeneratorExpression,
+}
+
+impl Branch {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        conditions: Vec<Vec<(usize, Constant)>>,
+        bindings: Vec<(String, usize, usize)>,
+        expression: GeneratorExpression,
+    ) -> Self {
+        Self {
+            conditions,
+            bindings,
+            expression,
+        }
+    }
+
+    ///
+    /// Writes the disjunction of the branch alternatives, each being the conjunction of its
+    /// conditions, checked against the scrutinee stored at `scrutinee_address`.
+    ///
+    pub fn write_conditions(
+        &mut self,
+        state: Rc<RefCell<ZincVMState>>,
+        scrutinee_address: usize,
+        location: Location,
+    ) {
+        let alternative_count = self.conditions.len();
+
+        for alternative in self.conditions.drain(..) {
+            let condition_count = alternative.len();
+
+            for (offset, constant) in alternative.into_iter() {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(scrutinee_address + offset, 1)),
+                    Some(location),
+                );
+                constant.write_to_zinc_vm(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+            }
+
+            for _ in 1..condition_count {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_types::And), Some(location));
+            }
+        }
+
+        for _ in 1..alternative_count {
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Or(zinc_types::Or), Some(location));
+        }
+    }
+
+    ///
+    /// Writes the bindings extraction and the branch expression.
+    ///
+    pub fn write_expression(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        scrutinee_address: usize,
+        location: Location,
+    ) {
+        for (name, offset, size) in self.bindings.into_iter() {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(scrutinee_address + offset, size)),
+                Some(location),
+            );
+            let address = state.borrow_mut().define_variable(Some(name), size);
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(address, size)),
+                Some(location),
+            );
+        }
+
+        self.expression.write_to_zinc_vm(state);
+    }
+}
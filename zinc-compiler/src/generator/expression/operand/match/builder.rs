@@ -3,6 +3,7 @@
 //!
 
 use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::branch::Branch;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -19,12 +20,12 @@ pub struct Builder {
     scrutinee: Option<GeneratorExpression>,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Option<Type>,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The refutable branches ordered array.
+    branches: Vec<Branch>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
     /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    wildcard_branch: Option<Branch>,
 }
 
 impl Builder {
@@ -47,7 +48,35 @@ impl Builder {
     /// Pushes a branch, which consists of a `pattern` and `expression`.
     ///
     pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
+        self.branches
+            .push(Branch::new(vec![vec![(0, pattern)]], vec![], expression));
+    }
+
+    ///
+    /// Pushes an or-pattern branch, which is taken if all the conditions of any of the
+    /// `alternatives` hold.
+    ///
+    pub fn push_or_branch(
+        &mut self,
+        alternatives: Vec<Vec<(usize, Constant)>>,
+        expression: GeneratorExpression,
+    ) {
+        self.branches
+            .push(Branch::new(alternatives, vec![], expression));
+    }
+
+    ///
+    /// Pushes a tuple pattern branch, which is taken if all the `conditions` hold, and extracts
+    /// the `bindings` from the scrutinee.
+    ///
+    pub fn push_tuple_branch(
+        &mut self,
+        conditions: Vec<(usize, Constant)>,
+        bindings: Vec<(String, usize, usize)>,
+        expression: GeneratorExpression,
+    ) {
+        self.branches
+            .push(Branch::new(vec![conditions], bindings, expression));
     }
 
     ///
@@ -61,7 +90,18 @@ impl Builder {
     /// Sets the corresponding builder value.
     ///
     pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+        self.wildcard_branch = Some(Branch::new(vec![], vec![], value));
+    }
+
+    ///
+    /// Sets the irrefutable tuple pattern branch, which extracts the `bindings` from the scrutinee.
+    ///
+    pub fn set_tuple_wildcard_branch(
+        &mut self,
+        bindings: Vec<(String, usize, usize)>,
+        expression: GeneratorExpression,
+    ) {
+        self.wildcard_branch = Some(Branch::new(vec![], bindings, expression));
     }
 
     ///
@@ -2,6 +2,7 @@
 //! The generator expression match operand.
 //!
 
+pub mod branch;
 pub mod builder;
 
 use std::cell::RefCell;
@@ -9,7 +10,7 @@ use std::rc::Rc;
 
 use zinc_types::Instruction;
 
-use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::branch::Branch;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
@@ -27,12 +28,13 @@ pub struct Expression {
     scrutinee: GeneratorExpression,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Type,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The refutable branches ordered array.
+    branches: Vec<Branch>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
     /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// May extract bindings from a tuple scrutinee, e.g. `(_, value)`.
+    wildcard_branch: Option<Branch>,
 }
 
 impl Expression {
@@ -43,9 +45,9 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
+        branches: Vec<Branch>,
         binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        wildcard_branch: Option<Branch>,
     ) -> Self {
         Self {
             location,
@@ -78,19 +80,12 @@ impl IBytecodeWritable for Expression {
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            state.borrow_mut().push_instruction(
-                Instruction::Load(zinc_types::Load::new(scrutinee_address, scrutinee_size)),
-                Some(self.location),
-            );
-            branch_pattern.write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_types::Eq), Some(self.location));
+        for mut branch in self.branches.into_iter() {
+            branch.write_conditions(state.clone(), scrutinee_address, self.location);
             state
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
-            branch_expression.write_to_zinc_vm(state.clone());
+            branch.write_expression(state.clone(), scrutinee_address, self.location);
             state
                 .borrow_mut()
                 .push_instruction(Instruction::Else(zinc_types::Else), Some(self.location));
@@ -99,7 +94,7 @@ impl IBytecodeWritable for Expression {
         if let Some(binding_branch) = binding_branch {
             binding_branch.write_to_zinc_vm(state.clone());
         } else if let Some(wildcard_branch) = self.wildcard_branch {
-            wildcard_branch.write_to_zinc_vm(state.clone());
+            wildcard_branch.write_expression(state.clone(), scrutinee_address, self.location);
         }
 
         state
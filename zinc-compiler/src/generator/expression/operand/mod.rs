@@ -10,6 +10,7 @@ pub mod group;
 pub mod list;
 pub mod r#match;
 pub mod place;
+pub mod unsafe_witness;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -22,7 +23,6 @@ use crate::generator::expression::operand::constant::integer::Integer as Integer
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
-use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::memory_type::MemoryType;
 
@@ -34,6 +34,7 @@ use self::group::Expression as GroupExpression;
 use self::list::Expression as ListExpression;
 use self::place::Place;
 use self::r#match::Expression as MatchExpression;
+use self::unsafe_witness::Expression as UnsafeWitnessExpression;
 
 ///
 /// The expression operand which is translated to some data.
@@ -56,6 +57,8 @@ pub enum Operand {
     Conditional(ConditionalExpression),
     /// The `match` expression.
     Match(MatchExpression),
+    /// The `unsafe_witness` expression.
+    UnsafeWitness(UnsafeWitnessExpression),
 }
 
 impl IBytecodeWritable for Operand {
@@ -94,49 +97,44 @@ impl IBytecodeWritable for Operand {
                 MemoryType::ContractStorage { .. } => {
                     let location = inner.identifier.location;
                     let element_size = inner.element_size;
-                    let total_size = inner.total_size;
-
-                    if let Some(SemanticPlaceElement::ContractField {
-                        access:
-                            ContractFieldAccess {
-                                position,
-                                element_size,
-                                is_mtreemap,
-                                ..
-                            },
-                    }) = inner.elements.first()
-                    {
-                        let address = state
-                            .borrow()
-                            .get_variable_address(inner.identifier.name.as_str())
-                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
-                        state.borrow_mut().push_instruction(
-                            Instruction::Load(zinc_types::Load::new(
-                                address,
-                                Type::eth_address().size(),
-                            )),
-                            Some(location),
-                        );
-                        IntegerConstant::new(
-                            BigInt::from(*position),
-                            false,
-                            zinc_const::bitlength::FIELD,
-                        )
-                        .write_to_zinc_vm(state.clone());
+                    let address = state
+                        .borrow()
+                        .get_variable_address(inner.identifier.name.as_str())
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
 
-                        if !*is_mtreemap {
+                    match inner.elements.first() {
+                        Some(SemanticPlaceElement::ContractField { access })
+                            if access.is_mtreemap =>
+                        {
                             state.borrow_mut().push_instruction(
-                                Instruction::StorageLoad(zinc_types::StorageLoad::new(
-                                    *element_size,
+                                Instruction::Load(zinc_types::Load::new(
+                                    address,
+                                    Type::eth_address().size(),
                                 )),
-                                Some(inner.identifier.location),
+                                Some(location),
                             );
-                        }
+                            IntegerConstant::new(
+                                BigInt::from(access.position),
+                                false,
+                                zinc_const::bitlength::FIELD,
+                            )
+                            .write_to_zinc_vm(state.clone());
 
-                        inner.elements.remove(0);
+                            inner.elements.remove(0);
+                        }
+                        _ => {
+                            let leaves = inner.write_storage_leaves(state.clone());
+                            Place::write_storage_leaves_load(
+                                state.clone(),
+                                address,
+                                &leaves,
+                                location,
+                            );
+                        }
                     }
 
                     if !inner.elements.is_empty() {
+                        let total_size = inner.total_size;
                         inner.write_to_zinc_vm(state.clone());
                         state.borrow_mut().push_instruction(
                             Instruction::Slice(zinc_types::Slice::new(element_size, total_size)),
@@ -151,6 +149,7 @@ impl IBytecodeWritable for Operand {
             Self::Block(inner) => inner.write_to_zinc_vm(state),
             Self::Conditional(inner) => inner.write_to_zinc_vm(state),
             Self::Match(inner) => inner.write_to_zinc_vm(state),
+            Self::UnsafeWitness(inner) => inner.write_to_zinc_vm(state),
         }
     }
 }
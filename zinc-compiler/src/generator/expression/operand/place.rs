@@ -8,10 +8,13 @@ use std::rc::Rc;
 use num::BigInt;
 use num::Zero;
 
+use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 use zinc_types::Instruction;
+use zinc_types::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
@@ -36,6 +39,313 @@ pub struct Place {
     pub memory_type: MemoryType,
 }
 
+///
+/// The contract storage leaves selected by a place path.
+///
+#[derive(Debug, Clone)]
+pub struct StorageLeaves {
+    /// The address of the temporary variable holding the first selected leaf index.
+    pub index_address: usize,
+    /// The selected leaves in the declaration order.
+    pub leaves: Vec<zinc_types::ContractStorageLeaf>,
+}
+
+impl Place {
+    ///
+    /// Writes the index of the first contract storage leaf selected by the place path, which
+    /// must start with a contract field, and stores it in a temporary variable.
+    ///
+    /// The path elements selecting the fields and elements of the flattened structures and
+    /// arrays are consumed, so the rest of the path and the total size of the place are
+    /// relative to the selected value. The runtime array indexes are checked against the
+    /// array size, so they cannot select a leaf of another field.
+    ///
+    pub fn write_storage_leaves(&mut self, state: Rc<RefCell<ZincVMState>>) -> StorageLeaves {
+        let location = self.identifier.location;
+
+        let access = match self.elements.remove(0) {
+            SemanticPlaceElement::ContractField { access } => access,
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        let mut r#type: zinc_types::Type = Type::try_from_semantic(&access.r#type)
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            .into();
+
+        IntegerConstant::new(
+            BigInt::from(access.position),
+            false,
+            zinc_const::bitlength::FIELD,
+        )
+        .write_to_zinc_vm(state.clone());
+
+        while r#type.is_storage_flattened() && !self.elements.is_empty() {
+            r#type = match (r#type, self.elements.remove(0)) {
+                (
+                    zinc_types::Type::Structure(mut fields),
+                    SemanticPlaceElement::StackField { access },
+                ) => {
+                    let offset: usize = fields
+                        .iter()
+                        .take(access.position)
+                        .map(|(_name, r#type)| r#type.storage_leaf_count())
+                        .sum();
+                    Self::write_storage_offset(state.clone(), BigInt::from(offset), location);
+
+                    fields.remove(access.position).1
+                }
+                (
+                    zinc_types::Type::Array(r#type, _size),
+                    SemanticPlaceElement::IndexConstant { constant, .. },
+                ) => {
+                    let offset = constant.value * BigInt::from(r#type.storage_leaf_count());
+                    Self::write_storage_offset(state.clone(), offset, location);
+
+                    *r#type
+                }
+                (
+                    zinc_types::Type::Array(r#type, size),
+                    SemanticPlaceElement::IndexExpression { expression, .. },
+                ) => {
+                    expression.write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Cast(zinc_types::Cast::new(zinc_types::ScalarType::Field)),
+                        Some(location),
+                    );
+
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Copy(zinc_types::Copy), Some(location));
+                    IntegerConstant::new(BigInt::from(size), false, zinc_const::bitlength::FIELD)
+                        .write_to_zinc_vm(state.clone());
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Lt(zinc_types::Lt), Some(location));
+                    state.borrow_mut().push_instruction(
+                        Instruction::Require(zinc_types::Require::new(Some(
+                            "index out of bounds".to_owned(),
+                        ))),
+                        Some(location),
+                    );
+
+                    IntegerConstant::new(
+                        BigInt::from(r#type.storage_leaf_count()),
+                        false,
+                        zinc_const::bitlength::FIELD,
+                    )
+                    .write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Mul(zinc_types::Mul::default()),
+                        Some(location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::Add(zinc_types::Add::default()),
+                        Some(location),
+                    );
+
+                    *r#type
+                }
+                (
+                    zinc_types::Type::Array(r#type, _size),
+                    SemanticPlaceElement::IndexRange { start, access, .. },
+                )
+                | (
+                    zinc_types::Type::Array(r#type, _size),
+                    SemanticPlaceElement::IndexRangeInclusive { start, access, .. },
+                ) => {
+                    let offset = start * BigInt::from(r#type.storage_leaf_count());
+                    Self::write_storage_offset(state.clone(), offset, location);
+
+                    zinc_types::Type::Array(r#type, access.slice_length)
+                }
+                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            };
+        }
+
+        self.total_size = r#type.size();
+
+        let index_address = state.borrow_mut().define_variable(None, 1);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(index_address, 1)),
+            Some(location),
+        );
+
+        StorageLeaves {
+            index_address,
+            leaves: zinc_types::ContractStorageLeaf::flatten(access.name, r#type),
+        }
+    }
+
+    ///
+    /// Writes the loading of the selected contract storage `leaves` onto the evaluation stack,
+    /// so their values form the selected value in the flat representation.
+    ///
+    pub fn write_storage_leaves_load(
+        state: Rc<RefCell<ZincVMState>>,
+        reference_address: usize,
+        leaves: &StorageLeaves,
+        location: Location,
+    ) {
+        for (index, leaf) in leaves.leaves.iter().enumerate() {
+            Self::write_storage_leaf_address(
+                state.clone(),
+                reference_address,
+                leaves.index_address,
+                index,
+                location,
+            );
+            Self::write_storage_load(
+                state.clone(),
+                leaf.r#type.size(),
+                leaf.packed_bits,
+                location,
+            );
+        }
+    }
+
+    ///
+    /// Writes the storing of the value on the top of the evaluation stack into the selected
+    /// contract storage `leaves`.
+    ///
+    /// The leaves are stored in the reverse order, since the last leaf is on the stack top.
+    ///
+    pub fn write_storage_leaves_store(
+        state: Rc<RefCell<ZincVMState>>,
+        reference_address: usize,
+        leaves: &StorageLeaves,
+        location: Location,
+    ) {
+        for (index, leaf) in leaves.leaves.iter().enumerate().rev() {
+            let storage_size = Self::write_storage_pack(
+                state.clone(),
+                leaf.packed_bits,
+                leaf.r#type.size(),
+                location,
+            );
+            Self::write_storage_leaf_address(
+                state.clone(),
+                reference_address,
+                leaves.index_address,
+                index,
+                location,
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::StorageStore(zinc_types::StorageStore::new(storage_size)),
+                Some(location),
+            );
+        }
+    }
+
+    ///
+    /// Writes the contract address and the index of the leaf at `offset` from the first
+    /// selected one onto the evaluation stack.
+    ///
+    fn write_storage_leaf_address(
+        state: Rc<RefCell<ZincVMState>>,
+        reference_address: usize,
+        index_address: usize,
+        offset: usize,
+        location: Location,
+    ) {
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                reference_address,
+                Type::eth_address().size(),
+            )),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(index_address, 1)),
+            Some(location),
+        );
+        if offset > 0 {
+            Self::write_storage_offset(state, BigInt::from(offset), location);
+        }
+    }
+
+    ///
+    /// Adds the constant `offset` to the leaf index on the top of the evaluation stack.
+    ///
+    fn write_storage_offset(state: Rc<RefCell<ZincVMState>>, offset: BigInt, location: Location) {
+        IntegerConstant::new(offset, false, zinc_const::bitlength::FIELD)
+            .write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Add(zinc_types::Add::default()), Some(location));
+    }
+
+    ///
+    /// Writes the contract storage field loading, expecting the contract address and the field
+    /// index on the evaluation stack.
+    ///
+    /// A packed field is unpacked right after loading, so the rest of the code sees the
+    /// boolean array as is.
+    ///
+    pub fn write_storage_load(
+        state: Rc<RefCell<ZincVMState>>,
+        element_size: usize,
+        packed_bits: Option<usize>,
+        location: Location,
+    ) {
+        match packed_bits {
+            Some(bits) => {
+                let packed_size = zinc_types::ContractFieldType::packed_size(bits);
+
+                state.borrow_mut().push_instruction(
+                    Instruction::StorageLoad(zinc_types::StorageLoad::new(packed_size)),
+                    Some(location),
+                );
+                IntegerConstant::new(BigInt::from(bits), false, zinc_const::bitlength::INDEX)
+                    .write_to_zinc_vm(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::CallLibrary(zinc_types::CallLibrary::new(
+                        LibraryFunctionIdentifier::ConvertUnpackBits,
+                        packed_size + 1,
+                        bits,
+                    )),
+                    Some(location),
+                );
+            }
+            None => {
+                state.borrow_mut().push_instruction(
+                    Instruction::StorageLoad(zinc_types::StorageLoad::new(element_size)),
+                    Some(location),
+                );
+            }
+        }
+    }
+
+    ///
+    /// Packs the contract storage field value on the top of the evaluation stack, if the field
+    /// is packed.
+    ///
+    /// Returns the size of the value in the contract storage encoding.
+    ///
+    pub fn write_storage_pack(
+        state: Rc<RefCell<ZincVMState>>,
+        packed_bits: Option<usize>,
+        size: usize,
+        location: Location,
+    ) -> usize {
+        match packed_bits {
+            Some(bits) => {
+                let packed_size = zinc_types::ContractFieldType::packed_size(bits);
+
+                state.borrow_mut().push_instruction(
+                    Instruction::CallLibrary(zinc_types::CallLibrary::new(
+                        LibraryFunctionIdentifier::ConvertPackBits,
+                        bits,
+                        packed_size,
+                    )),
+                    Some(location),
+                );
+
+                packed_size
+            }
+            None => size,
+        }
+    }
+}
+
 impl IBytecodeWritable for Place {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
         if !self.elements.is_empty() {
@@ -58,15 +368,17 @@ impl IBytecodeWritable for Place {
                     )
                     .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::Mul(zinc_types::Mul),
+                        Instruction::Mul(zinc_types::Mul::default()),
                         Some(self.identifier.location),
                     );
                     state.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         Some(self.identifier.location),
                     );
                 }
-                SemanticPlaceElement::IndexExpression { expression, access } => {
+                SemanticPlaceElement::IndexExpression {
+                    expression, access, ..
+                } => {
                     expression.write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
                         Instruction::Cast(zinc_types::Cast::new(zinc_types::ScalarType::Field)),
@@ -79,11 +391,11 @@ impl IBytecodeWritable for Place {
                     )
                     .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::Mul(zinc_types::Mul),
+                        Instruction::Mul(zinc_types::Mul::default()),
                         Some(self.identifier.location),
                     );
                     state.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         Some(self.identifier.location),
                     );
                 }
@@ -95,7 +407,7 @@ impl IBytecodeWritable for Place {
                     )
                     .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         Some(self.identifier.location),
                     );
                 }
@@ -107,7 +419,7 @@ impl IBytecodeWritable for Place {
                     )
                     .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         Some(self.identifier.location),
                     );
                 }
@@ -119,7 +431,7 @@ impl IBytecodeWritable for Place {
                     )
                     .write_to_zinc_vm(state.clone());
                     state.borrow_mut().push_instruction(
-                        Instruction::Add(zinc_types::Add),
+                        Instruction::Add(zinc_types::Add::default()),
                         Some(self.identifier.location),
                     );
                 }
@@ -0,0 +1,64 @@
+//!
+//! The generator expression unconstrained witness operand.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::block::Expression as BlockExpression;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The unconstrained witness expression.
+///
+/// The block is evaluated without constraints, and its result is allocated as a new witness,
+/// which must be checked by the following `require` calls.
+///
+#[derive(Debug, Clone)]
+pub struct Expression {
+    /// The `unsafe_witness` expression location.
+    location: Location,
+    /// The block expression, which computes the witness value.
+    block: BlockExpression,
+    /// The witness value type.
+    r#type: Type,
+}
+
+impl Expression {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, block: BlockExpression, r#type: Type) -> Self {
+        Self {
+            location,
+            block,
+            r#type,
+        }
+    }
+}
+
+impl IBytecodeWritable for Expression {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        state.borrow_mut().push_instruction(
+            Instruction::UnsafeWitnessBegin(zinc_types::UnsafeWitnessBegin),
+            Some(self.location),
+        );
+        self.block.write_to_zinc_vm(state.clone());
+
+        let r#type: zinc_types::Type = match self.r#type {
+            Type::Contract { .. } => Type::eth_address().into(),
+            r#type => r#type.into(),
+        };
+        state.borrow_mut().push_instruction(
+            Instruction::UnsafeWitnessEnd(zinc_types::UnsafeWitnessEnd::new(
+                r#type.into_flat_scalar_types(),
+            )),
+            Some(self.location),
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //! The generator expression operator.
 //!
 
+use num::BigInt;
+
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::place::Place;
@@ -10,6 +12,7 @@ use crate::generator::r#type::contract_field::ContractField;
 use crate::generator::r#type::Type;
 use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::access::index::Index as IndexAccess;
+use crate::semantic::element::place::Place as SemanticPlace;
 use crate::semantic::element::r#type::Type as SemanticType;
 
 ///
@@ -147,12 +150,25 @@ pub enum Operator {
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
     },
+    /// The binary `==` comparison operator for arrays, tuples, and structures.
+    EqualsStructural {
+        /// The operand size, that is, the number of the element-wise comparisons.
+        size: usize,
+    },
+    /// The binary `!=` comparison operator for arrays, tuples, and structures.
+    NotEqualsStructural {
+        /// The operand size, that is, the number of the element-wise comparisons.
+        size: usize,
+    },
     /// The binary `>=` comparison operator.
     GreaterEquals {
         /// The type to cast the first operand into. Present only for integer literals.
         operand_1_inferred_type: Option<Type>,
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
+        /// Whether the operands are `field` values proven to fit the inferred types, so they
+        /// are cast without the range checks.
+        is_field_bounded: bool,
     },
     /// The binary `<=` comparison operator.
     LesserEquals {
@@ -160,6 +176,9 @@ pub enum Operator {
         operand_1_inferred_type: Option<Type>,
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
+        /// Whether the operands are `field` values proven to fit the inferred types, so they
+        /// are cast without the range checks.
+        is_field_bounded: bool,
     },
     /// The binary `>` comparison operator.
     Greater {
@@ -167,6 +186,9 @@ pub enum Operator {
         operand_1_inferred_type: Option<Type>,
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
+        /// Whether the operands are `field` values proven to fit the inferred types, so they
+        /// are cast without the range checks.
+        is_field_bounded: bool,
     },
     /// The binary `<` comparison operator.
     Lesser {
@@ -174,6 +196,9 @@ pub enum Operator {
         operand_1_inferred_type: Option<Type>,
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
+        /// Whether the operands are `field` values proven to fit the inferred types, so they
+        /// are cast without the range checks.
+        is_field_bounded: bool,
     },
 
     /// The binary `|` bitwise OR operator.
@@ -243,6 +268,11 @@ pub enum Operator {
         /// The type to cast into.
         r#type: Type,
     },
+    /// The type casting operator, whose range check is proven redundant by the semantic analyzer.
+    CastingUnchecked {
+        /// The type to cast into.
+        r#type: Type,
+    },
 
     /// The unary logical `!` NOT operator.
     Not,
@@ -274,6 +304,17 @@ pub enum Operator {
         /// The function arguments size.
         input_size: usize,
     },
+    /// The mutable method call operator, which writes the returned instance back.
+    CallMutableMethod {
+        /// The function unique ID assigned during semantic analysis.
+        type_id: usize,
+        /// The function arguments size.
+        input_size: usize,
+        /// The `self` instance size, which is returned after the function result.
+        instance_size: usize,
+        /// The memory place of the instance. `None` if the instance is a temporary value.
+        place: Option<Place>,
+    },
     /// The `dbg!(...)` function call operator.
     CallDebug {
         /// The format string with `{}` placeholders.
@@ -286,11 +327,48 @@ pub enum Operator {
         /// The optional error description message.
         message: Option<String>,
     },
+    /// The `std::test::assert_eq(...)` function call operator.
+    CallAssertEq {
+        /// The compared values type.
+        r#type: Type,
+        /// The optional error description message.
+        message: Option<String>,
+    },
+    /// The `std::test::assert_storage_unchanged()` function call operator.
+    CallAssertStorageUnchanged,
     /// The `<Contract>::fetch(...)` function call operator.
     CallContractFetch {
         /// The contract storage fields.
         fields: Vec<ContractField>,
     },
+    /// The `<Enumeration>::from(...)` function call operator.
+    CallEnumerationFrom {
+        /// The enumeration type bitlength.
+        bitlength: usize,
+        /// The enumeration variant list.
+        variants: Vec<(String, BigInt)>,
+    },
+    /// The `<Enumeration>::Flags::contains(...)` function call operator.
+    CallFlagsContains,
+    /// The `<Enumeration>::Flags::is_empty()` function call operator.
+    CallFlagsIsEmpty {
+        /// The enumeration type bitlength.
+        bitlength: usize,
+    },
+    /// The `std::collections::MTreeMap::get_or(...)` function call operator.
+    CallMTreeMapGetOr {
+        /// The map key size.
+        key_size: usize,
+        /// The map value size.
+        value_size: usize,
+    },
+    /// The `std::collections::MTreeMap::insert_if_absent(...)` function call operator.
+    CallMTreeMapInsertIfAbsent {
+        /// The map key size.
+        key_size: usize,
+        /// The map value size.
+        value_size: usize,
+    },
     /// The standard library function call.
     CallLibrary {
         /// The unique standard library function identifier.
@@ -361,6 +439,20 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn equals_structural(size: usize) -> Self {
+        Self::EqualsStructural { size }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn not_equals_structural(size: usize) -> Self {
+        Self::NotEqualsStructural { size }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -368,6 +460,7 @@ impl Operator {
         Self::GreaterEquals {
             operand_1_inferred_type: None,
             operand_2_inferred_type: None,
+            is_field_bounded: false,
         }
     }
 
@@ -387,6 +480,7 @@ impl Operator {
             operand_2_inferred_type: operand_2_inferred_type
                 .as_ref()
                 .and_then(Type::try_from_semantic),
+            is_field_bounded: false,
         }
     }
 
@@ -397,6 +491,7 @@ impl Operator {
         Self::LesserEquals {
             operand_1_inferred_type: None,
             operand_2_inferred_type: None,
+            is_field_bounded: false,
         }
     }
 
@@ -416,6 +511,7 @@ impl Operator {
             operand_2_inferred_type: operand_2_inferred_type
                 .as_ref()
                 .and_then(Type::try_from_semantic),
+            is_field_bounded: false,
         }
     }
 
@@ -426,6 +522,7 @@ impl Operator {
         Self::Greater {
             operand_1_inferred_type: None,
             operand_2_inferred_type: None,
+            is_field_bounded: false,
         }
     }
 
@@ -445,6 +542,7 @@ impl Operator {
             operand_2_inferred_type: operand_2_inferred_type
                 .as_ref()
                 .and_then(Type::try_from_semantic),
+            is_field_bounded: false,
         }
     }
 
@@ -455,6 +553,7 @@ impl Operator {
         Self::Lesser {
             operand_1_inferred_type: None,
             operand_2_inferred_type: None,
+            is_field_bounded: false,
         }
     }
 
@@ -474,6 +573,7 @@ impl Operator {
             operand_2_inferred_type: operand_2_inferred_type
                 .as_ref()
                 .and_then(Type::try_from_semantic),
+            is_field_bounded: false,
         }
     }
 
@@ -725,6 +825,66 @@ impl Operator {
         Self::Casting { r#type }
     }
 
+    ///
+    /// Sets the unsigned integer `type`, which both `field` operands of the ordering operator
+    /// are proven to fit, so they are cast into it without the range checks and compared as
+    /// integers, which requires fewer constraints than the `field` comparison.
+    ///
+    /// The other operators are returned as is.
+    ///
+    pub fn into_field_bounded(self, r#type: SemanticType) -> Self {
+        let r#type = Type::try_from_semantic(&r#type);
+
+        match self {
+            Self::GreaterEquals { .. } => Self::GreaterEquals {
+                operand_1_inferred_type: r#type.clone(),
+                operand_2_inferred_type: r#type,
+                is_field_bounded: true,
+            },
+            Self::LesserEquals { .. } => Self::LesserEquals {
+                operand_1_inferred_type: r#type.clone(),
+                operand_2_inferred_type: r#type,
+                is_field_bounded: true,
+            },
+            Self::Greater { .. } => Self::Greater {
+                operand_1_inferred_type: r#type.clone(),
+                operand_2_inferred_type: r#type,
+                is_field_bounded: true,
+            },
+            Self::Lesser { .. } => Self::Lesser {
+                operand_1_inferred_type: r#type.clone(),
+                operand_2_inferred_type: r#type,
+                is_field_bounded: true,
+            },
+            operator => operator,
+        }
+    }
+
+    ///
+    /// Returns the casting operator of an operand into its inferred `type`.
+    ///
+    pub fn inferred_casting(&self, r#type: Type) -> Self {
+        match self {
+            Self::GreaterEquals {
+                is_field_bounded: true,
+                ..
+            }
+            | Self::LesserEquals {
+                is_field_bounded: true,
+                ..
+            }
+            | Self::Greater {
+                is_field_bounded: true,
+                ..
+            }
+            | Self::Lesser {
+                is_field_bounded: true,
+                ..
+            } => Self::CastingUnchecked { r#type },
+            _ => Self::Casting { r#type },
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -749,6 +909,23 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_mutable_method(
+        type_id: usize,
+        input_size: usize,
+        instance_size: usize,
+        place: Option<SemanticPlace>,
+    ) -> Self {
+        Self::CallMutableMethod {
+            type_id,
+            input_size,
+            instance_size,
+            place: place.map(Place::from),
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -770,6 +947,24 @@ impl Operator {
         Self::CallRequire { message }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_assert_eq(r#type: SemanticType, message: Option<String>) -> Self {
+        Self::CallAssertEq {
+            r#type: Type::try_from_semantic(&r#type)
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            message,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_assert_storage_unchanged() -> Self {
+        Self::CallAssertStorageUnchanged
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -777,6 +972,49 @@ impl Operator {
         Self::CallContractFetch { fields }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_enumeration_from(bitlength: usize, variants: Vec<(String, BigInt)>) -> Self {
+        Self::CallEnumerationFrom {
+            bitlength,
+            variants,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_flags_contains() -> Self {
+        Self::CallFlagsContains
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_flags_is_empty(bitlength: usize) -> Self {
+        Self::CallFlagsIsEmpty { bitlength }
+    }
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_mtreemap_get_or(key_size: usize, value_size: usize) -> Self {
+        Self::CallMTreeMapGetOr {
+            key_size,
+            value_size,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_mtreemap_insert_if_absent(key_size: usize, value_size: usize) -> Self {
+        Self::CallMTreeMapInsertIfAbsent {
+            key_size,
+            value_size,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -23,6 +23,8 @@ pub struct Statement {
     pub project: zinc_project::ManifestProject,
     /// The contract storage fields ordered array.
     pub fields: Vec<ContractFieldType>,
+    /// The names of the interfaces, which the contract implements.
+    pub interfaces: Vec<String>,
     /// Whether the contract is declared within a dependency project.
     pub is_in_dependency: bool,
 }
@@ -35,6 +37,7 @@ impl Statement {
         location: Location,
         project: zinc_project::ManifestProject,
         fields: Vec<SemanticContractFieldType>,
+        interfaces: Vec<String>,
         is_in_dependency: bool,
     ) -> Self {
         Self {
@@ -44,6 +47,7 @@ impl Statement {
                 .into_iter()
                 .filter_map(|field| ContractFieldType::try_from_semantic(&field))
                 .collect(),
+            interfaces,
             is_in_dependency,
         }
     }
@@ -52,7 +56,9 @@ impl Statement {
 impl IBytecodeWritable for Statement {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
         if !self.is_in_dependency {
-            state.borrow_mut().set_contract_storage(self.fields);
+            let mut state = state.borrow_mut();
+            state.set_contract_storage(self.fields);
+            state.set_contract_interfaces(self.interfaces);
         }
     }
 }
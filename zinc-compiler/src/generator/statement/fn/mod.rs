@@ -7,6 +7,9 @@ pub mod role;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 use zinc_types::Instruction;
 
@@ -17,6 +20,7 @@ use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::Type as SemanticType;
+use crate::semantic::scope::item::r#type::index::INDEX as TYPE_INDEX;
 
 use self::role::Role;
 
@@ -33,10 +37,15 @@ pub struct Statement {
     pub is_mutable: bool,
     /// The function arguments, where the compile time only ones like `()` are already filtered out.
     pub input_arguments: Vec<(String, bool, Type)>,
+    /// The function arguments declaration locations, in the `input_arguments` order.
+    pub input_locations: Vec<Location>,
     /// The function body.
     pub body: Expression,
     /// The function result type, which defaults to `()` if not specified.
     pub output_type: Type,
+    /// The mutable `self` instance type, which is returned after the function result to be
+    /// written back by the caller.
+    pub instance_type: Option<Type>,
     /// The function unique ID, which is assigned during the semantic analysis.
     pub type_id: usize,
     /// The special function role, e.g. circuit entry or contract constructor.
@@ -61,13 +70,21 @@ impl Statement {
         role: Role,
         attributes: Vec<Attribute>,
     ) -> Self {
-        let input_arguments = bindings
+        let instance_type = bindings
+            .first()
+            .filter(|binding| binding.is_instance_written_back())
+            .and_then(|binding| Type::try_from_semantic(&binding.r#type));
+
+        let (input_arguments, input_locations) = bindings
             .into_iter()
             .filter_map(|binding| match Type::try_from_semantic(&binding.r#type) {
-                Some(r#type) => Some((binding.identifier.name, binding.is_mutable, r#type)),
+                Some(r#type) => Some((
+                    (binding.identifier.name, binding.is_mutable, r#type),
+                    binding.identifier.location,
+                )),
                 None => None,
             })
-            .collect();
+            .unzip();
 
         let output_type = Type::try_from_semantic(&output_type).unwrap_or_else(Type::unit);
 
@@ -76,8 +93,10 @@ impl Statement {
             identifier,
             is_mutable,
             input_arguments,
+            input_locations,
             body,
             output_type,
+            instance_type,
             type_id,
             role,
             attributes,
@@ -85,9 +104,181 @@ impl Statement {
     }
 }
 
-impl IBytecodeWritable for Statement {
-    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+impl Statement {
+    /// The runtime error message of a call exceeding the maximal recursion depth.
+    const RECURSION_DEPTH_EXCEEDED: &'static str = "recursion depth exceeded";
+
+    ///
+    /// Writes the function `max_depth` times, redirecting the recursive calls of each copy to
+    /// the next one. The calls of the last copy are redirected to a stub, which fails at runtime.
+    ///
+    /// If the function does not call itself, only the original copy is written.
+    ///
+    fn write_unrolled(self, state: Rc<RefCell<ZincVMState>>, max_depth: usize) {
+        let location = self.location;
+        let identifier = self.identifier.clone();
+        let output_type = self.output_type.clone();
+        let instance_type = self.instance_type.clone();
+        let original_id = self.type_id;
+
+        let mut type_id = original_id;
+        for depth in 1..=max_depth {
+            let next_id = TYPE_INDEX.next(format!("function {} (depth {})", identifier, depth + 1));
+
+            let mut copy = self.clone();
+            copy.type_id = type_id;
+            if depth > 1 {
+                copy.role = Role::Ordinar;
+            }
+
+            state
+                .borrow_mut()
+                .set_function_redirect(Some((original_id, next_id)));
+            copy.write(state.clone());
+            let is_recursive = state.borrow().is_function_redirect_used();
+            state.borrow_mut().set_function_redirect(None);
+
+            if !is_recursive {
+                return;
+            }
+
+            type_id = next_id;
+        }
+
+        Self::write_depth_exceeded(
+            state,
+            location,
+            type_id,
+            identifier,
+            output_type,
+            instance_type,
+        );
+    }
+
+    ///
+    /// Writes the stub function, which is called instead of the copy exceeding the maximal
+    /// recursion depth.
+    ///
+    /// The stub fails if reached in a taken branch and returns zeros otherwise.
+    ///
+    fn write_depth_exceeded(
+        state: Rc<RefCell<ZincVMState>>,
+        location: Location,
+        type_id: usize,
+        identifier: String,
+        output_type: Type,
+        instance_type: Option<Type>,
+    ) {
+        let output_size =
+            output_type.size() + instance_type.as_ref().map(Type::size).unwrap_or_default();
+
+        state
+            .borrow_mut()
+            .start_function(location, type_id, identifier);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            )),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_types::Require::new(Some(
+                Self::RECURSION_DEPTH_EXCEEDED.to_owned(),
+            ))),
+            Some(location),
+        );
+
+        for r#type in std::iter::once(output_type).chain(instance_type) {
+            let r#type: zinc_types::Type = r#type.into();
+            for scalar_type in r#type.into_flat_scalar_types().into_iter() {
+                state.borrow_mut().push_instruction(
+                    Instruction::Push(zinc_types::Push::new(BigInt::zero(), scalar_type)),
+                    Some(location),
+                );
+            }
+        }
+
+        state.borrow_mut().push_instruction(
+            Instruction::Return(zinc_types::Return::new(output_size)),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Writes the result selection of the function containing `return` statements.
+    ///
+    /// If the function has returned early, the stored result is taken instead of the body value.
+    /// If the body has no value, that is, it always returns early, the stored result is taken
+    /// unconditionally.
+    ///
+    fn write_returned_result(
+        state: Rc<RefCell<ZincVMState>>,
+        location: Location,
+        returned_address: usize,
+        result_address: usize,
+        body_size: usize,
+        output_size: usize,
+    ) {
+        if output_size == 0 {
+            return;
+        }
+
+        if body_size != output_size {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(result_address, output_size)),
+                Some(location),
+            );
+            return;
+        }
+
+        let value_address = state.borrow_mut().define_variable(None, output_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(value_address, output_size)),
+            Some(location),
+        );
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                returned_address,
+                Type::boolean().size(),
+            )),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(result_address, output_size)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_types::Else), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(value_address, output_size)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+    }
+
+    ///
+    /// Writes the function to the bytecode.
+    ///
+    fn write(self, state: Rc<RefCell<ZincVMState>>) {
         let output_size = self.output_type.size();
+        let is_entry = matches!(
+            self.role,
+            Role::CircuitEntry
+                | Role::ContractMethodEntry
+                | Role::ContractConstuctor {
+                    is_dependency: false,
+                    ..
+                }
+        );
 
         match self.role {
             Role::CircuitEntry
@@ -96,11 +287,26 @@ impl IBytecodeWritable for Statement {
                 is_dependency: false,
                 ..
             } => {
+                if self.attributes.contains(&Attribute::Upgrade) {
+                    state
+                        .borrow_mut()
+                        .set_contract_upgrade(self.identifier.clone());
+                }
+
+                let required_sender =
+                    self.attributes
+                        .iter()
+                        .find_map(|attribute| match attribute {
+                            Attribute::RequireSender { field, .. } => Some(field.to_owned()),
+                            _ => None,
+                        });
+
                 state.borrow_mut().start_entry_function(
                     self.location,
                     self.type_id,
                     self.identifier,
                     self.is_mutable,
+                    required_sender,
                     self.input_arguments.clone(),
                     self.output_type.clone(),
                 );
@@ -120,16 +326,35 @@ impl IBytecodeWritable for Statement {
             }
         }
 
-        for (name, _is_mutable, r#type) in self.input_arguments.into_iter() {
+        let mut arguments = Vec::with_capacity(self.input_arguments.len());
+        for ((name, _is_mutable, r#type), location) in self
+            .input_arguments
+            .into_iter()
+            .zip(self.input_locations.into_iter())
+        {
             let size = match r#type {
                 Type::Contract { .. } => Type::eth_address().size(),
                 argument_type => argument_type.size(),
             };
 
-            state.borrow_mut().define_variable(Some(name), size);
+            let address = state.borrow_mut().define_variable(Some(name.clone()), size);
+            arguments.push((name, location, address, size));
         }
 
+        let body_address = state.borrow_mut().start_returns(self.output_type.clone());
+        let body_size = self.body.value_size();
         self.body.write_to_zinc_vm(state.clone());
+        let returns = state.borrow_mut().finish_returns(body_address);
+        if let Some((returned_address, result_address)) = returns {
+            Self::write_returned_result(
+                state.clone(),
+                self.location,
+                returned_address,
+                result_address,
+                body_size,
+                output_size,
+            );
+        }
 
         match self.role {
             Role::ContractConstuctor { project, .. } => {
@@ -150,11 +375,46 @@ impl IBytecodeWritable for Statement {
                 );
             }
             _ => {
+                let instance_size = match (self.instance_type.as_ref(), arguments.first()) {
+                    (Some(instance_type), Some((_name, _location, address, _size))) => {
+                        let instance_size = instance_type.size();
+                        state.borrow_mut().push_instruction(
+                            Instruction::Load(zinc_types::Load::new(*address, instance_size)),
+                            Some(self.location),
+                        );
+                        instance_size
+                    }
+                    _ => 0,
+                };
+
                 state.borrow_mut().push_instruction(
-                    Instruction::Return(zinc_types::Return::new(output_size)),
+                    Instruction::Return(zinc_types::Return::new(output_size + instance_size)),
                     Some(self.location),
                 );
             }
         }
+
+        if is_entry {
+            state
+                .borrow_mut()
+                .check_unconstrained_inputs(self.type_id, arguments);
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let max_depth = self
+            .attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::MaxDepth(max_depth) => Some(*max_depth),
+                _ => None,
+            });
+
+        match max_depth {
+            Some(max_depth) => self.write_unrolled(state, max_depth),
+            None => self.write(state),
+        }
     }
 }
@@ -74,6 +74,23 @@ impl Statement {
     }
 }
 
+impl Statement {
+    ///
+    /// Writes the loop `body`. If it contains a `return` statement, the body is only executed
+    /// until the function returns, like with a `while` condition.
+    ///
+    pub fn write_body(body: BlockExpression, state: Rc<RefCell<ZincVMState>>) {
+        let body_address = state.borrow().instructions_count();
+        let returns_count = state.borrow().returns_count();
+
+        body.write_to_zinc_vm(state.clone());
+
+        if state.borrow().returns_count() > returns_count {
+            state.borrow_mut().wrap_return_guard(body_address);
+        }
+    }
+}
+
 impl IBytecodeWritable for Statement {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
         let index_type =
@@ -145,12 +162,12 @@ impl IBytecodeWritable for Statement {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, state.clone());
             state
                 .borrow_mut()
                 .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
         } else {
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, state.clone());
         }
 
         if self.is_reversed {
@@ -176,9 +193,10 @@ impl IBytecodeWritable for Statement {
                 self.index_variable_bitlength,
             )
             .write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Sub(zinc_types::Sub), Some(self.location));
+            state.borrow_mut().push_instruction(
+                Instruction::Sub(zinc_types::Sub::default()),
+                Some(self.location),
+            );
             state.borrow_mut().push_instruction(
                 Instruction::Store(zinc_types::Store::new(index_address, 1)),
                 Some(self.location),
@@ -209,9 +227,10 @@ impl IBytecodeWritable for Statement {
                 self.index_variable_bitlength,
             )
             .write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Add(zinc_types::Add), Some(self.location));
+            state.borrow_mut().push_instruction(
+                Instruction::Add(zinc_types::Add::default()),
+                Some(self.location),
+            );
             state.borrow_mut().push_instruction(
                 Instruction::Store(zinc_types::Store::new(index_address, 1)),
                 Some(self.location),
@@ -5,6 +5,9 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 use zinc_types::Instruction;
 
@@ -23,8 +26,8 @@ pub struct Statement {
     pub location: Location,
     /// The declaration bindings.
     pub bindings: Vec<Binding>,
-    /// The expression assigned to the bindings.
-    pub expression: Expression,
+    /// The expression assigned to the bindings. `None` if the variable is assigned later.
+    pub expression: Option<Expression>,
 }
 
 impl Statement {
@@ -35,14 +38,43 @@ impl Statement {
         Self {
             location,
             bindings,
-            expression,
+            expression: Some(expression),
+        }
+    }
+
+    ///
+    /// A shortcut constructor for a declaration without an initializer.
+    ///
+    pub fn new_uninitialized(location: Location, bindings: Vec<Binding>) -> Self {
+        Self {
+            location,
+            bindings,
+            expression: None,
         }
     }
 }
 
 impl IBytecodeWritable for Statement {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
-        self.expression.write_to_zinc_vm(state.clone());
+        match self.expression {
+            Some(expression) => expression.write_to_zinc_vm(state.clone()),
+            None => {
+                for binding in self.bindings.iter() {
+                    let r#type: zinc_types::Type = match Type::try_from_semantic(&binding.r#type) {
+                        Some(Type::Contract { .. }) => Type::eth_address().into(),
+                        Some(r#type) => r#type.into(),
+                        None => continue,
+                    };
+
+                    for scalar_type in r#type.into_flat_scalar_types() {
+                        state.borrow_mut().push_instruction(
+                            Instruction::Push(zinc_types::Push::new(BigInt::zero(), scalar_type)),
+                            Some(self.location),
+                        );
+                    }
+                }
+            }
+        }
 
         for binding in self.bindings.into_iter().rev() {
             let r#type = match Type::try_from_semantic(&binding.r#type) {
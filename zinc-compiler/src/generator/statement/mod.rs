@@ -6,6 +6,8 @@ pub mod contract;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#let;
+pub mod r#return;
+pub mod r#while;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -18,6 +20,8 @@ use self::contract::Statement as ContractStatement;
 use self::r#fn::Statement as FnStatement;
 use self::r#for::Statement as ForStatement;
 use self::r#let::Statement as LetStatement;
+use self::r#return::Statement as ReturnStatement;
+use self::r#while::Statement as WhileStatement;
 
 ///
 /// The generator statement.
@@ -32,6 +36,10 @@ pub enum Statement {
     Contract(ContractStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The `while` statement.
+    While(WhileStatement),
+    /// The `return` statement.
+    Return(ReturnStatement),
     /// The expression statement, which is actually a large class of expression-like statements.
     Expression(Expression),
 }
@@ -43,6 +51,8 @@ impl IBytecodeWritable for Statement {
             Self::Let(inner) => inner.write_to_zinc_vm(state),
             Self::Contract(inner) => inner.write_to_zinc_vm(state),
             Self::For(inner) => inner.write_to_zinc_vm(state),
+            Self::While(inner) => inner.write_to_zinc_vm(state),
+            Self::Return(inner) => inner.write_to_zinc_vm(state),
             Self::Expression(inner) => inner.write_to_zinc_vm(state),
         }
     }
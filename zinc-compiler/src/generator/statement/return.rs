@@ -0,0 +1,66 @@
+//!
+//! The generator `return` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::Expression;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `return` statement.
+///
+/// The returned value is stored to the function result slot, and the function is marked as
+/// returned, so the code after the statement is not executed.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The returned expression. `None` for the `return;` statement.
+    pub expression: Option<Expression>,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, expression: Option<Expression>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let (returned_address, result_address, result_size) = state.borrow_mut().define_return();
+
+        if let Some(expression) = self.expression {
+            expression.write_to_zinc_vm(state.clone());
+            if result_size > 0 {
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(result_address, result_size)),
+                    Some(self.location),
+                );
+            }
+        }
+
+        BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(
+                returned_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//!
+//! The generator `while` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::block::Expression as BlockExpression;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::statement::r#for::Statement as ForStatement;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `while` statement.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The maximal number of loop iterations, specified with the `#[max_iterations(N)]` attribute.
+    pub iterations_count: usize,
+    /// The loop condition, which suppresses the loop side effects if false.
+    pub condition: GeneratorExpression,
+    /// The loop body.
+    pub body: BlockExpression,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        iterations_count: usize,
+        condition: GeneratorExpression,
+        body: BlockExpression,
+    ) -> Self {
+        Self {
+            location,
+            iterations_count,
+            condition,
+            body,
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    ///
+    /// The loop is unrolled `iterations_count` times, and each iteration body is only executed
+    /// if the condition holds. Once the condition is false, the body side effects are suppressed,
+    /// so the condition stays false until the end of the loop.
+    ///
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        if self.iterations_count == 0 {
+            return;
+        }
+
+        state.borrow_mut().push_instruction(
+            Instruction::LoopBegin(zinc_types::LoopBegin::new(self.iterations_count)),
+            Some(self.location),
+        );
+
+        self.condition.write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+        ForStatement::write_body(self.body, state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
+
+        state.borrow_mut().push_instruction(
+            Instruction::LoopEnd(zinc_types::LoopEnd),
+            Some(self.location),
+        );
+    }
+}
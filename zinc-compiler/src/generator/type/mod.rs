@@ -43,6 +43,17 @@ pub enum Type {
         /// The enumeration variant list.
         variants: Vec<(String, BigInt)>,
     },
+    /// The IR `#[bitflags]` enumeration flag set type.
+    Flags {
+        /// The enumeration type bitlength.
+        bitlength: usize,
+        /// The enumeration variant list.
+        variants: Vec<(String, BigInt)>,
+    },
+    /// The IR `zksync::Address` type.
+    Address,
+    /// The IR `zksync::Balance` type.
+    Balance,
     /// The IR array type.
     Array {
         /// The array element type.
@@ -140,6 +151,30 @@ impl Type {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn flags(bitlength: usize, variants: Vec<(String, BigInt)>) -> Self {
+        Self::Flags {
+            bitlength,
+            variants,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn address() -> Self {
+        Self::Address
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn balance() -> Self {
+        Self::Balance
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -192,6 +227,9 @@ impl Type {
             Self::IntegerSigned { .. } => 1,
             Self::Field => 1,
             Self::Enumeration { .. } => 1,
+            Self::Flags { .. } => 1,
+            Self::Address => 1,
+            Self::Balance => 1,
             Self::Array { r#type, size } => r#type.size() * size,
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
@@ -215,6 +253,20 @@ impl Type {
             }
             SemanticType::IntegerSigned { bitlength, .. } => Some(Self::integer_signed(*bitlength)),
             SemanticType::Field(_) => Some(Self::field()),
+            SemanticType::Nominal(inner) if inner.is_address() => Some(Self::address()),
+            SemanticType::Nominal(inner) if inner.is_flags() => {
+                let enumeration = inner.enumeration.as_ref()?;
+                Some(Self::flags(
+                    enumeration.bitlength,
+                    enumeration
+                        .names
+                        .to_owned()
+                        .into_iter()
+                        .zip(enumeration.values.to_owned())
+                        .collect::<Vec<(String, BigInt)>>(),
+                ))
+            }
+            SemanticType::Nominal(_) => Some(Self::balance()),
             SemanticType::Array(inner) => Self::try_from_semantic(&*inner.r#type)
                 .map(|r#type| Self::array(r#type, inner.size)),
             SemanticType::Tuple(inner) => {
@@ -280,6 +332,7 @@ impl Type {
             SemanticType::Range(_) => None,
             SemanticType::RangeInclusive(_) => None,
             SemanticType::Function(_) => None,
+            SemanticType::Interface(_) => None,
         }
     }
 }
@@ -309,6 +362,15 @@ impl Into<zinc_types::Type> for Type {
                 bitlength,
                 variants,
             },
+            Self::Flags {
+                bitlength,
+                variants,
+            } => zinc_types::Type::Flags {
+                bitlength,
+                variants,
+            },
+            Self::Address => zinc_types::Type::Address,
+            Self::Balance => zinc_types::Type::Balance,
             Self::Array { r#type, size } => {
                 let element_type: zinc_types::Type = (*r#type).into();
                 zinc_types::Type::Array(Box::new(element_type), size)
@@ -357,6 +419,18 @@ impl Into<Option<zinc_types::ScalarType>> for Type {
                 }))
             }
             Self::Field => Some(zinc_types::ScalarType::Field),
+            Self::Flags { bitlength, .. } => {
+                Some(zinc_types::ScalarType::Integer(zinc_types::IntegerType {
+                    is_signed: false,
+                    bitlength,
+                }))
+            }
+            Self::Address => Some(zinc_types::ScalarType::Integer(
+                zinc_types::IntegerType::ETH_ADDRESS,
+            )),
+            Self::Balance => Some(zinc_types::ScalarType::Integer(
+                zinc_types::IntegerType::BALANCE,
+            )),
             _ => None,
         }
     }
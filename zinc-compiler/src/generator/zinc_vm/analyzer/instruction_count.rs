@@ -0,0 +1,54 @@
+//!
+//! The bytecode instruction count analyzer.
+//!
+
+use std::collections::HashMap;
+
+use zinc_types::Instruction;
+
+///
+/// The instruction count analysis.
+///
+/// The numbers of instructions written for each function, which show where the bytecode size
+/// comes from.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    /// The name, which the instructions written before the first function marker are counted as.
+    pub const PROLOGUE_NAME: &'static str = "<prologue>";
+
+    ///
+    /// Counts the `instructions` of each function, that is, the ones between its marker and the
+    /// next function marker.
+    ///
+    /// The debug information markers and no-operations are not counted. The unrolled copies of
+    /// a recursive function share its name, so they are counted together.
+    ///
+    /// The result is sorted by the count in the descending order, and then by the function name.
+    ///
+    pub fn count(instructions: &[Instruction]) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+
+        let mut function = Self::PROLOGUE_NAME;
+        for instruction in instructions.iter() {
+            match instruction {
+                Instruction::FunctionMarker(marker) => {
+                    function = marker.function.as_str();
+                }
+                Instruction::NoOperation(_) => {}
+                instruction if instruction.is_debug() => {}
+                _ => *counts.entry(function).or_default() += 1,
+            }
+        }
+
+        let mut counts: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(function, count)| (function.to_owned(), count))
+            .collect();
+        counts.sort_by(|(name_1, count_1), (name_2, count_2)| {
+            count_2.cmp(count_1).then_with(|| name_1.cmp(name_2))
+        });
+        counts
+    }
+}
@@ -0,0 +1,10 @@
+//!
+//! The bytecode analyzers.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod instruction_count;
+pub mod transaction_read;
+pub mod unconstrained_input;
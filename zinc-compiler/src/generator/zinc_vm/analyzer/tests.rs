@@ -0,0 +1,158 @@
+//!
+//! The bytecode analyzer tests.
+//!
+
+use crate::generator::zinc_vm::tests::compile_with_files;
+
+fn unconstrained_inputs(code: &str, project_type: zinc_project::ProjectType) -> Vec<String> {
+    let manifest = zinc_project::Manifest::new("test", project_type);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    state
+        .unconstrained_inputs()
+        .iter()
+        .map(|(name, _location)| name.to_owned())
+        .collect()
+}
+
+fn methods_reading_msg(code: &str) -> Vec<String> {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Contract);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match state.into_application(false, None) {
+        zinc_types::Application::Contract(contract) => contract
+            .methods
+            .into_iter()
+            .filter(|(_name, method)| method.reads_msg)
+            .map(|(name, _method)| name)
+            .collect(),
+        _ => panic!("the application must be a contract"),
+    }
+}
+
+#[test]
+fn ok_used() {
+    let input = r#"
+fn main(a: u8, b: [u8; 4], c: (bool, field)) -> u8 {
+    require(c.0);
+    dbg!("{}", b);
+    a * b[a]
+}
+"#;
+
+    assert!(unconstrained_inputs(input, zinc_project::ProjectType::Circuit).is_empty());
+}
+
+#[test]
+fn ok_underscore_prefixed() {
+    let input = r#"
+fn main(a: u8, _b: u8) -> u8 {
+    a
+}
+"#;
+
+    assert!(unconstrained_inputs(input, zinc_project::ProjectType::Circuit).is_empty());
+}
+
+#[test]
+fn warning_unused() {
+    let input = r#"
+fn main(a: u8, b: u8) -> u8 {
+    a
+}
+"#;
+
+    assert_eq!(
+        unconstrained_inputs(input, zinc_project::ProjectType::Circuit),
+        vec!["b".to_owned()]
+    );
+}
+
+#[test]
+fn warning_used_in_dbg_only() {
+    let input = r#"
+fn main(a: u8, b: u8) -> u8 {
+    dbg!("{} and {}", a, b);
+    a
+}
+"#;
+
+    assert_eq!(
+        unconstrained_inputs(input, zinc_project::ProjectType::Circuit),
+        vec!["b".to_owned()]
+    );
+}
+
+#[test]
+fn warning_contract_method_unused() {
+    let input = r#"
+contract Test {
+    pub value: u64;
+
+    pub fn new(value: u64) -> Self {
+        Self { value: value }
+    }
+
+    pub fn deposit(mut self, amount: u64, memo: u8) {
+        self.value += amount;
+    }
+}
+"#;
+
+    assert_eq!(
+        unconstrained_inputs(input, zinc_project::ProjectType::Contract),
+        vec!["memo".to_owned()]
+    );
+}
+
+#[test]
+fn ok_msg_read() {
+    let input = r#"
+contract Test {
+    pub owner: u160;
+
+    pub fn new(owner: u160) -> Self {
+        Self { owner: owner }
+    }
+
+    pub fn is_owner(self) -> bool {
+        zksync::msg.sender == self.owner
+    }
+
+    pub fn is_self(self) -> bool {
+        self.address == self.owner
+    }
+}
+"#;
+
+    assert_eq!(methods_reading_msg(input), vec!["is_owner".to_owned()]);
+}
+
+#[test]
+fn ok_msg_read_by_callee() {
+    let input = r#"
+fn amount() -> u248 {
+    zksync::msg.amount
+}
+
+contract Test {
+    pub value: u248;
+
+    pub fn new(value: u248) -> Self {
+        Self { value: value }
+    }
+
+    pub fn deposit(mut self) {
+        self.value += amount();
+    }
+
+    pub fn get_value(self) -> u248 {
+        self.value
+    }
+}
+"#;
+
+    assert_eq!(methods_reading_msg(input), vec!["deposit".to_owned()]);
+}
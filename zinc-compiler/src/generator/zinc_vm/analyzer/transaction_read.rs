@@ -0,0 +1,56 @@
+//!
+//! The bytecode transaction read analyzer.
+//!
+
+use std::collections::HashSet;
+
+use zinc_types::Instruction;
+
+///
+/// The transaction read analysis.
+///
+/// Each contract function has the `zksync::msg` transaction variable at the beginning of its data
+/// stack frame. A method reads the transaction if it or any function it calls loads the variable,
+/// so the server must supply the transaction to it.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Checks whether the function at `address` or any function it calls reads the transaction
+    /// variable.
+    ///
+    /// The `Call` instructions must already contain the function addresses instead of their
+    /// type IDs.
+    ///
+    pub fn is_transaction_read(instructions: &[Instruction], address: usize) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = vec![address];
+
+        while let Some(address) = pending.pop() {
+            if !visited.insert(address) {
+                continue;
+            }
+
+            for instruction in instructions.iter().skip(address) {
+                match instruction {
+                    Instruction::Load(inner)
+                        if inner.address < zinc_const::contract::TRANSACTION_SIZE =>
+                    {
+                        return true;
+                    }
+                    Instruction::LoadByIndex(inner)
+                        if inner.address < zinc_const::contract::TRANSACTION_SIZE =>
+                    {
+                        return true;
+                    }
+                    Instruction::Call(inner) => pending.push(inner.address),
+                    Instruction::Return(_) => break,
+                    _ => {}
+                }
+            }
+        }
+
+        false
+    }
+}
@@ -0,0 +1,92 @@
+//!
+//! The bytecode unconstrained input analyzer.
+//!
+
+use zinc_types::Instruction;
+
+///
+/// The unconstrained input analysis.
+///
+/// An entry input argument, whose value never flows into any constraint or the output, can be
+/// set to anything by the prover, which is usually a soundness mistake.
+///
+/// The analysis is conservative, that is, any doubtful read of the argument is considered a use,
+/// since missing a warning is preferred over a false one.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Checks whether the data stack range of `size` at `address` is read by the function
+    /// `instructions` anywhere except for the `dbg!` calls.
+    ///
+    /// The algorithm works as follows:
+    ///
+    /// 1. Filter out the debug information markers.
+    ///
+    /// 2. Find the `Load` and `LoadByIndex` instructions reading the range.
+    ///
+    /// 3. Check whether the loaded values are only pushed onto the evaluation stack until they are
+    /// consumed by a `Dbg` instruction. Otherwise, they may flow into a constraint or the output.
+    ///
+    pub fn is_constrained(instructions: &[Instruction], address: usize, size: usize) -> bool {
+        let instructions: Vec<&Instruction> = instructions
+            .iter()
+            .filter(|instruction| {
+                !matches!(
+                    instruction,
+                    Instruction::FileMarker(_)
+                        | Instruction::FunctionMarker(_)
+                        | Instruction::LineMarker(_)
+                        | Instruction::ColumnMarker(_)
+                        | Instruction::NoOperation(_)
+                )
+            })
+            .collect();
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            let (load_address, load_size) = match instruction {
+                Instruction::Load(inner) => (inner.address, inner.size),
+                Instruction::LoadByIndex(inner) => (inner.address, inner.total_size),
+                _ => continue,
+            };
+
+            if load_address >= address + size || address >= load_address + load_size {
+                continue;
+            }
+
+            if !Self::is_debug_only(&instructions[index..]) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    ///
+    /// Checks whether the values pushed by the first of `instructions` are consumed by a `Dbg`
+    /// instruction, and nothing is popped from the evaluation stack in between.
+    ///
+    fn is_debug_only(instructions: &[&Instruction]) -> bool {
+        let mut pushed_size = 0;
+
+        for instruction in instructions.iter() {
+            match instruction {
+                Instruction::Load(inner) => pushed_size += inner.size,
+                Instruction::Push(_) => pushed_size += 1,
+                Instruction::Dbg(inner) => {
+                    let arguments_size: usize = inner
+                        .argument_types
+                        .iter()
+                        .map(zinc_types::Type::size)
+                        .sum();
+
+                    return pushed_size <= arguments_size;
+                }
+                _ => return false,
+            }
+        }
+
+        false
+    }
+}
@@ -15,6 +15,8 @@ pub struct Entry {
     pub name: String,
     /// If the entry can mutate the contract storage state. Only for contracts.
     pub is_mutable: bool,
+    /// The contract storage field holding the only allowed sender address. Only for contracts.
+    pub required_sender: Option<String>,
     /// The entry function input arguments.
     pub input_fields: Vec<(String, bool, Type)>,
     /// The entry function result type.
@@ -29,6 +31,7 @@ impl Entry {
         type_id: usize,
         name: String,
         is_mutable: bool,
+        required_sender: Option<String>,
         input_fields: Vec<(String, bool, Type)>,
         output_type: Type,
     ) -> Self {
@@ -36,6 +39,7 @@ impl Entry {
             type_id,
             name,
             is_mutable,
+            required_sender,
             input_fields,
             output_type,
         }
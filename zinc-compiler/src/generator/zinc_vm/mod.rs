@@ -2,6 +2,10 @@
 //! The Zinc VM generator state.
 //!
 
+#[cfg(test)]
+pub(crate) mod tests;
+
+pub mod analyzer;
 pub mod entry;
 pub mod optimizer;
 pub mod unit_test;
@@ -10,6 +14,11 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use indexmap::IndexMap;
+use num::BigInt;
+use num::Zero;
+
+use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 use zinc_lexical::FILE_INDEX;
 use zinc_types::Instruction;
@@ -17,9 +26,14 @@ use zinc_types::Instruction;
 use crate::generator::r#type::contract_field::ContractField as ContractFieldType;
 use crate::generator::r#type::Type;
 use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
 
+use self::analyzer::transaction_read::Analyzer as TransactionReadAnalyzer;
+use self::analyzer::unconstrained_input::Analyzer as UnconstrainedInputAnalyzer;
 use self::entry::Entry;
 use self::optimizer::dead_function_code_elimination::Optimizer as DeadFunctionCodeEliminationOptimizer;
+use self::optimizer::range_check_elimination::Optimizer as RangeCheckEliminationOptimizer;
 use self::unit_test::UnitTest;
 
 ///
@@ -34,13 +48,30 @@ pub struct State {
     instructions: Vec<Instruction>,
     /// The contract storage structure.
     contract_storage: Option<Vec<ContractFieldType>>,
+    /// The contract storage migration method marked with the `#[upgrade]` attribute.
+    contract_upgrade: Option<String>,
+    /// The names of the interfaces, which the contract implements.
+    contract_interfaces: Vec<String>,
     /// Metadata of each application entry.
-    entries: HashMap<usize, Entry>,
+    entries: IndexMap<usize, Entry>,
     /// Unit tests.
-    unit_tests: HashMap<usize, UnitTest>,
+    unit_tests: IndexMap<usize, UnitTest>,
+    /// The entry input arguments, which do not constrain the application, with their locations.
+    unconstrained_inputs: Vec<(String, Location)>,
 
     /// Bytecode addresses of the functions written to the bytecode.
     function_addresses: HashMap<usize, usize>,
+    /// The recursive call redirect to the next unrolled copy of the function being written.
+    function_redirect: Option<(usize, usize)>,
+    /// Whether the redirect has been used, that is, the function calls itself.
+    is_function_redirect_used: bool,
+    /// The result type of the function being written, which is stored by its `return` statements.
+    return_type: Type,
+    /// The data stack addresses of the returned flag and the result of the function being written,
+    /// which are allocated by its first `return` statement.
+    return_addresses: Option<(usize, usize)>,
+    /// The number of the `return` statements written so far.
+    returns_count: usize,
     /// Data stack addresses of variables declared at runtime.
     variable_addresses: HashMap<String, usize>,
     /// The pointer which is reset at the beginning of each function.
@@ -74,10 +105,18 @@ impl State {
 
             instructions: Vec::with_capacity(Self::INSTRUCTIONS_INITIAL_CAPACITY),
             contract_storage: None,
-            entries: HashMap::with_capacity(Self::ENTRIES_INITIAL_CAPACITY),
-            unit_tests: HashMap::with_capacity(Self::UNIT_TESTS_INITIAL_CAPACITY),
+            contract_upgrade: None,
+            contract_interfaces: Vec::new(),
+            entries: IndexMap::with_capacity(Self::ENTRIES_INITIAL_CAPACITY),
+            unit_tests: IndexMap::with_capacity(Self::UNIT_TESTS_INITIAL_CAPACITY),
+            unconstrained_inputs: Vec::new(),
 
             function_addresses: HashMap::with_capacity(Self::FUNCTION_ADDRESSES_INITIAL_CAPACITY),
+            function_redirect: None,
+            is_function_redirect_used: false,
+            return_type: Type::unit(),
+            return_addresses: None,
+            returns_count: 0,
             variable_addresses: HashMap::with_capacity(Self::VARIABLE_ADDRESSES_INITIAL_CAPACITY),
             data_stack_pointer: 0,
             current_location: Location::default(),
@@ -114,6 +153,20 @@ impl State {
         self.contract_storage = Some(fields);
     }
 
+    ///
+    /// Sets the contract storage migration method name.
+    ///
+    pub fn set_contract_upgrade(&mut self, identifier: String) {
+        self.contract_upgrade = Some(identifier);
+    }
+
+    ///
+    /// Sets the names of the interfaces, which the contract implements.
+    ///
+    pub fn set_contract_interfaces(&mut self, interfaces: Vec<String>) {
+        self.contract_interfaces = interfaces;
+    }
+
     ///
     /// Starts a new function, resetting the data stack pointer and writing the
     /// function debug information.
@@ -151,6 +204,7 @@ impl State {
         type_id: usize,
         identifier: String,
         is_mutable: bool,
+        required_sender: Option<String>,
         input_arguments: Vec<(String, bool, Type)>,
         output_type: Type,
     ) {
@@ -158,6 +212,7 @@ impl State {
             type_id,
             identifier.clone(),
             is_mutable,
+            required_sender,
             input_arguments,
             output_type,
         );
@@ -177,13 +232,23 @@ impl State {
         attributes: Vec<Attribute>,
     ) {
         let mut should_panic = false;
+        let mut expected_panic = None;
         let mut is_ignored = false;
+        let mut is_proof = false;
         let mut zksync_msg = None;
+        let mut zksync_msg_location = None;
         for attribute in attributes.into_iter() {
             match attribute {
-                Attribute::ShouldPanic => should_panic = true,
+                Attribute::ShouldPanic { expected } => {
+                    should_panic = true;
+                    expected_panic = expected;
+                }
                 Attribute::Ignore => is_ignored = true,
-                Attribute::ZksyncMsg(inner) => zksync_msg = Some(inner),
+                Attribute::Proof => is_proof = true,
+                Attribute::ZksyncMsg { msg, location } => {
+                    zksync_msg = Some(msg);
+                    zksync_msg_location = Some(location);
+                }
                 _ => {}
             }
         }
@@ -192,14 +257,259 @@ impl State {
             type_id,
             identifier.clone(),
             should_panic,
+            expected_panic,
             is_ignored,
+            is_proof,
             zksync_msg,
+            zksync_msg_location,
         );
         self.unit_tests.insert(type_id, test);
 
         self.start_function(location, type_id, identifier);
     }
 
+    ///
+    /// Checks the input `arguments` of the entry function `type_id`, which has been just written,
+    /// and warns about the ones whose values never influence the application constraints or
+    /// output, since the prover can set them to anything.
+    ///
+    /// The `arguments` are tuples of the name, declaration location, data stack address, and size.
+    /// The `self` argument and the ones prefixed with `_` are not checked.
+    ///
+    pub fn check_unconstrained_inputs(
+        &mut self,
+        type_id: usize,
+        arguments: Vec<(String, Location, usize, usize)>,
+    ) {
+        let start_address = match self.function_addresses.get(&type_id) {
+            Some(address) => *address,
+            None => return,
+        };
+
+        for (name, location, address, size) in arguments.into_iter() {
+            if name.starts_with('_') || name == Keyword::SelfLowercase.to_string() {
+                continue;
+            }
+
+            if UnconstrainedInputAnalyzer::is_constrained(
+                &self.instructions[start_address..],
+                address,
+                size,
+            ) {
+                continue;
+            }
+
+            if LINT_LEVELS.is_enabled(Lint::UnconstrainedInput, location) {
+                log::warn!(
+                    "{}: the input argument `{}` is never used in the constraints or output, so the prover can set it to anything",
+                    location,
+                    name,
+                );
+            }
+            self.unconstrained_inputs.push((name, location));
+        }
+    }
+
+    ///
+    /// Returns the entry input arguments, which do not constrain the application.
+    ///
+    pub fn unconstrained_inputs(&self) -> &[(String, Location)] {
+        self.unconstrained_inputs.as_slice()
+    }
+
+    ///
+    /// Sets the recursive call redirect from `type_id` to `target_id`, which is used to unroll
+    /// the bounded recursion. Passing `None` removes the redirect.
+    ///
+    pub fn set_function_redirect(&mut self, redirect: Option<(usize, usize)>) {
+        self.function_redirect = redirect;
+        self.is_function_redirect_used = false;
+    }
+
+    ///
+    /// Checks whether the current function redirect has been used since it was set.
+    ///
+    pub fn is_function_redirect_used(&self) -> bool {
+        self.is_function_redirect_used
+    }
+
+    ///
+    /// Returns the type ID of the function which must be actually called.
+    ///
+    /// If the call is recursive and the function is being unrolled, the ID of the next copy is
+    /// returned.
+    ///
+    pub fn resolve_function(&mut self, type_id: usize) -> usize {
+        match self.function_redirect {
+            Some((source_id, target_id)) if source_id == type_id => {
+                self.is_function_redirect_used = true;
+                target_id
+            }
+            _ => type_id,
+        }
+    }
+
+    ///
+    /// Starts tracking the `return` statements of the function with the result `r#type`, whose
+    /// body is about to be written.
+    ///
+    /// Returns the bytecode address, where the returned flag and result are initialized, if
+    /// the body contains a `return` statement.
+    ///
+    pub fn start_returns(&mut self, r#type: Type) -> usize {
+        self.return_type = r#type;
+        self.return_addresses = None;
+        self.instructions.len()
+    }
+
+    ///
+    /// Finishes tracking the `return` statements of the function, whose body has been just written.
+    ///
+    /// If the body contains a `return` statement, the returned flag and the result are initialized
+    /// with `false` and zeros at the bytecode `address`, and their data stack addresses are returned.
+    ///
+    pub fn finish_returns(&mut self, address: usize) -> Option<(usize, usize)> {
+        let (returned_address, result_address) = self.return_addresses.take()?;
+
+        let mut instructions = vec![
+            Instruction::Push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            )),
+            Instruction::Store(zinc_types::Store::new(
+                returned_address,
+                Type::boolean().size(),
+            )),
+        ];
+        let result_size = self.return_type.size();
+        if result_size > 0 {
+            let r#type: zinc_types::Type = self.return_type.clone().into();
+            for scalar_type in r#type.into_flat_scalar_types().into_iter() {
+                instructions.push(Instruction::Push(zinc_types::Push::new(
+                    BigInt::zero(),
+                    scalar_type,
+                )));
+            }
+            instructions.push(Instruction::Store(zinc_types::Store::new(
+                result_address,
+                result_size,
+            )));
+        }
+        self.instructions.splice(address..address, instructions);
+
+        Some((returned_address, result_address))
+    }
+
+    ///
+    /// Returns the data stack addresses of the returned flag and the result of the function being
+    /// written, and the result size. The addresses are allocated by the first `return` statement.
+    ///
+    pub fn define_return(&mut self) -> (usize, usize, usize) {
+        let result_size = self.return_type.size();
+        let (returned_address, result_address) = match self.return_addresses {
+            Some(addresses) => addresses,
+            None => {
+                let returned_address = self.define_variable(None, Type::boolean().size());
+                let result_address = self.define_variable(None, result_size);
+                self.return_addresses = Some((returned_address, result_address));
+                (returned_address, result_address)
+            }
+        };
+        self.returns_count += 1;
+
+        (returned_address, result_address, result_size)
+    }
+
+    ///
+    /// Returns the number of the `return` statements written so far.
+    ///
+    /// Is used to check whether a statement contains a `return` statement.
+    ///
+    pub fn returns_count(&self) -> usize {
+        self.returns_count
+    }
+
+    ///
+    /// Opens the conditional block, which is only executed if the function has not returned yet.
+    ///
+    pub fn write_return_guard_start(&mut self) {
+        let (returned_address, _result_address) = self
+            .return_addresses
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        self.push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                returned_address,
+                Type::boolean().size(),
+            )),
+            None,
+        );
+        self.push_instruction(Instruction::Not(zinc_types::Not), None);
+        self.push_instruction(Instruction::If(zinc_types::If), None);
+    }
+
+    ///
+    /// Writes the `else` branch of the conditional block opened by `write_return_guard_start`,
+    /// which pushes zeros of `r#type` instead of the skipped value.
+    ///
+    pub fn write_return_guard_else(&mut self, r#type: Type) {
+        self.push_instruction(Instruction::Else(zinc_types::Else), None);
+        self.write_zeros(r#type);
+    }
+
+    ///
+    /// Closes the conditional block opened by `write_return_guard_start`.
+    ///
+    pub fn write_return_guard_end(&mut self) {
+        self.push_instruction(Instruction::EndIf(zinc_types::EndIf), None);
+    }
+
+    ///
+    /// Wraps the instructions written since the bytecode `address` into the conditional block,
+    /// which is only executed if the function has not returned yet.
+    ///
+    /// Is used for the loop bodies containing a `return` statement, since the instructions
+    /// preceding the statement must not be executed in the next iterations.
+    ///
+    pub fn wrap_return_guard(&mut self, address: usize) {
+        let (returned_address, _result_address) = self
+            .return_addresses
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        self.instructions.splice(
+            address..address,
+            vec![
+                Instruction::Load(zinc_types::Load::new(
+                    returned_address,
+                    Type::boolean().size(),
+                )),
+                Instruction::Not(zinc_types::Not),
+                Instruction::If(zinc_types::If),
+            ],
+        );
+        self.write_return_guard_end();
+    }
+
+    ///
+    /// Pushes zeros of `r#type` onto the evaluation stack.
+    ///
+    pub fn write_zeros(&mut self, r#type: Type) {
+        let r#type: zinc_types::Type = r#type.into();
+        for scalar_type in r#type.into_flat_scalar_types().into_iter() {
+            self.push_instruction(
+                Instruction::Push(zinc_types::Push::new(BigInt::zero(), scalar_type)),
+                None,
+            );
+        }
+    }
+
+    ///
+    /// Returns the current bytecode address.
+    ///
+    pub fn instructions_count(&self) -> usize {
+        self.instructions.len()
+    }
+
     ///
     /// Defines a variable, saving its address within the current data stack frame.
     ///
@@ -250,10 +560,17 @@ impl State {
     /// Converts the compiled application state into a set of byte arrays, which are ready to be
     /// written to the Zinc project build files.
     ///
+    /// If `range_check_elision` is set, the range checks proven redundant are marked with it.
+    ///
     pub fn into_application(
         mut self,
         optimize_dead_function_elimination: bool,
+        range_check_elision: Option<zinc_types::RangeCheck>,
     ) -> zinc_types::Application {
+        // the type IDs are allocated in the declaration order, unlike the generation order
+        self.entries.sort_keys();
+        self.unit_tests.sort_keys();
+
         match self.contract_storage.take() {
             Some(storage) => {
                 let storage = storage.into_iter().map(|field| field.into()).collect();
@@ -283,7 +600,9 @@ impl State {
                     )
                 }
 
-                let mut methods = HashMap::with_capacity(self.entries.len());
+                Self::eliminate_range_checks(self.instructions.as_mut_slice(), range_check_elision);
+
+                let mut methods = IndexMap::with_capacity(self.entries.len());
                 for (type_id, method) in self.entries.into_iter() {
                     let address = self
                         .function_addresses
@@ -293,6 +612,10 @@ impl State {
                     let mut input: zinc_types::Type = method.input_fields_as_struct().into();
                     input.set_contract_address();
                     let output = method.output_type.into();
+                    let reads_msg = TransactionReadAnalyzer::is_transaction_read(
+                        self.instructions.as_slice(),
+                        address,
+                    );
                     methods.insert(
                         method.name.clone(),
                         zinc_types::ContractMethod::new(
@@ -300,13 +623,15 @@ impl State {
                             method.name,
                             address,
                             method.is_mutable,
+                            reads_msg,
+                            method.required_sender,
                             input,
                             output,
                         ),
                     );
                 }
 
-                let mut unit_tests = HashMap::with_capacity(self.unit_tests.len());
+                let mut unit_tests = IndexMap::with_capacity(self.unit_tests.len());
                 for (type_id, unit_test) in self.unit_tests.into_iter() {
                     let address = self
                         .function_addresses
@@ -318,8 +643,13 @@ impl State {
                         zinc_types::UnitTest::new(
                             address,
                             unit_test.should_panic,
+                            unit_test.expected_panic,
                             unit_test.is_ignored,
+                            unit_test.is_proof,
                             unit_test.zksync_msg,
+                            unit_test
+                                .zksync_msg_location
+                                .map(|location| location.to_string()),
                         ),
                     );
                 }
@@ -332,6 +662,8 @@ impl State {
                     methods,
                     unit_tests,
                     self.instructions,
+                    self.contract_upgrade,
+                    self.contract_interfaces,
                 )
             }
             None if !self.entries.is_empty() => {
@@ -363,8 +695,9 @@ impl State {
                         &self.function_addresses,
                     );
                 }
+                Self::eliminate_range_checks(self.instructions.as_mut_slice(), range_check_elision);
 
-                let mut unit_tests = HashMap::with_capacity(self.unit_tests.len());
+                let mut unit_tests = IndexMap::with_capacity(self.unit_tests.len());
                 for (type_id, unit_test) in self.unit_tests.into_iter() {
                     let address = self
                         .function_addresses
@@ -376,8 +709,13 @@ impl State {
                         zinc_types::UnitTest::new(
                             address,
                             unit_test.should_panic,
+                            unit_test.expected_panic,
                             unit_test.is_ignored,
+                            unit_test.is_proof,
                             unit_test.zksync_msg,
+                            unit_test
+                                .zksync_msg_location
+                                .map(|location| location.to_string()),
                         ),
                     );
                 }
@@ -404,8 +742,9 @@ impl State {
                     &mut self.instructions,
                     &self.function_addresses,
                 );
+                Self::eliminate_range_checks(self.instructions.as_mut_slice(), range_check_elision);
 
-                let mut unit_tests = HashMap::with_capacity(self.unit_tests.len());
+                let mut unit_tests = IndexMap::with_capacity(self.unit_tests.len());
                 for (type_id, unit_test) in self.unit_tests.into_iter() {
                     let address = self
                         .function_addresses
@@ -417,8 +756,13 @@ impl State {
                         zinc_types::UnitTest::new(
                             address,
                             unit_test.should_panic,
+                            unit_test.expected_panic,
                             unit_test.is_ignored,
+                            unit_test.is_proof,
                             unit_test.zksync_msg,
+                            unit_test
+                                .zksync_msg_location
+                                .map(|location| location.to_string()),
                         ),
                     );
                 }
@@ -434,6 +778,23 @@ impl State {
         }
     }
 
+    ///
+    /// Marks the range checks proven redundant with `range_check_elision`, if it is set.
+    ///
+    fn eliminate_range_checks(
+        instructions: &mut [Instruction],
+        range_check_elision: Option<zinc_types::RangeCheck>,
+    ) {
+        if let Some(range_check) = range_check_elision {
+            let count = RangeCheckEliminationOptimizer::optimize(instructions, range_check);
+            log::debug!(
+                "{} redundant range checks are marked as {:?}",
+                count,
+                range_check
+            );
+        }
+    }
+
     ///
     /// Prints the bytecode instructions to the terminal.
     ///
@@ -2,4 +2,8 @@
 //! The bytecode optimizers.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod dead_function_code_elimination;
+pub mod range_check_elimination;
@@ -0,0 +1,807 @@
+//!
+//! The bytecode redundant range check eliminator.
+//!
+
+use std::collections::HashMap;
+use std::mem;
+
+use num::BigInt;
+use num::One;
+
+use zinc_types::Instruction;
+
+///
+/// The redundant range check elimination optimization.
+///
+/// Every arithmetic operation and cast checks whether its result fits the result type, which
+/// costs a number of constraints proportional to the type bitlength. If the operand bounds are
+/// known, e.g. after `require(x < 100)`, the check can be proven redundant and skipped.
+///
+pub struct Optimizer {}
+
+impl Optimizer {
+    ///
+    /// The algorithm works as follows:
+    ///
+    /// 1. Find the function starts, which are marked with the `FunctionMarker` instructions.
+    ///
+    /// 2. Interpret each function from its start to its `Return` instruction over the interval
+    /// domain, where each evaluation and data stack cell has its scalar type and value bounds,
+    /// if they are known. The bounds are taken from constants, casts, and checked operation
+    /// results, and refined by `require` calls and the `if` conditions.
+    ///
+    /// 3. Join the states of the `if` and `else` branches with the interval union. Forget the
+    /// bounds of the data stack cells written within a loop before its first iteration.
+    ///
+    /// 4. Mark the range check of the `Add`, `Sub`, `Mul`, and `Cast` instructions with
+    /// `range_check` if the result bounds fit the result type.
+    ///
+    /// If an instruction stack effect cannot be followed, the rest of the function is skipped.
+    ///
+    /// Returns the number of the marked instructions.
+    ///
+    pub fn optimize(
+        instructions: &mut [Instruction],
+        range_check: zinc_types::RangeCheck,
+    ) -> usize {
+        let mut marked_count = 0;
+
+        for address in 0..instructions.len() {
+            if let Instruction::FunctionMarker(_) = instructions[address] {
+                marked_count += Self::optimize_function(instructions, address + 1, range_check);
+            }
+        }
+
+        marked_count
+    }
+
+    ///
+    /// Interprets the function starting at `address` and marks its redundant range checks.
+    ///
+    fn optimize_function(
+        instructions: &mut [Instruction],
+        address: usize,
+        range_check: zinc_types::RangeCheck,
+    ) -> usize {
+        let mut interpreter = Interpreter::default();
+        let mut marked_count = 0;
+
+        for address in address..instructions.len() {
+            if let Instruction::Return(_) = instructions[address] {
+                break;
+            }
+
+            let is_redundant = match interpreter.execute(instructions, address) {
+                Some(is_redundant) => is_redundant,
+                None => break,
+            };
+            if !is_redundant {
+                continue;
+            }
+
+            match instructions[address] {
+                Instruction::Add(ref mut inner) => inner.range_check = range_check,
+                Instruction::Sub(ref mut inner) => inner.range_check = range_check,
+                Instruction::Mul(ref mut inner) => inner.range_check = range_check,
+                Instruction::Cast(ref mut inner) => inner.range_check = range_check,
+                _ => continue,
+            }
+            marked_count += 1;
+        }
+
+        marked_count
+    }
+}
+
+///
+/// The fact about a data stack cell, which holds if some boolean value is true.
+///
+#[derive(Debug, Clone, PartialEq)]
+struct Fact {
+    /// The data stack address.
+    address: usize,
+    /// The cell type.
+    r#type: zinc_types::IntegerType,
+    /// The inclusive lower bound.
+    min: BigInt,
+    /// The inclusive upper bound.
+    max: BigInt,
+}
+
+///
+/// The abstract value of an evaluation or data stack cell.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Bound {
+    /// The scalar type, if it is known.
+    r#type: Option<zinc_types::ScalarType>,
+    /// The inclusive value bounds, if they are known.
+    range: Option<(BigInt, BigInt)>,
+    /// The data stack address the value has been loaded from, if the address is not written since.
+    source: Option<usize>,
+    /// The facts which hold if the boolean value is true.
+    facts: Vec<Fact>,
+}
+
+impl Bound {
+    ///
+    /// Creates the bound of a constant `value`.
+    ///
+    /// The field constants are not bounded, since the field arithmetic wraps around the modulus.
+    ///
+    fn constant(value: BigInt, r#type: zinc_types::ScalarType) -> Self {
+        let range = match r#type {
+            zinc_types::ScalarType::Field => None,
+            _ => Some((value.clone(), value)),
+        };
+
+        Self {
+            r#type: Some(r#type),
+            range,
+            ..Self::default()
+        }
+    }
+
+    ///
+    /// Creates the bound of a boolean value, which implies `facts` if it is true.
+    ///
+    fn boolean(facts: Vec<Fact>) -> Self {
+        Self {
+            r#type: Some(zinc_types::ScalarType::Boolean),
+            range: Some((BigInt::from(0), BigInt::one())),
+            source: None,
+            facts,
+        }
+    }
+
+    ///
+    /// Creates the bound of a checked value of `type`.
+    ///
+    fn typed(r#type: Option<zinc_types::ScalarType>) -> Self {
+        let range = match r#type {
+            Some(zinc_types::ScalarType::Integer(ref int_type)) => {
+                Some((int_type.min(), int_type.max()))
+            }
+            Some(zinc_types::ScalarType::Boolean) => Some((BigInt::from(0), BigInt::one())),
+            _ => None,
+        };
+
+        Self {
+            r#type,
+            range,
+            ..Self::default()
+        }
+    }
+
+    ///
+    /// Returns the constant value, if the bound is a single integer or boolean value.
+    ///
+    fn constant_value(&self) -> Option<&BigInt> {
+        match self.range {
+            Some((ref min, ref max)) if min == max => Some(min),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Checks whether the value is always `false`.
+    ///
+    fn is_false(&self) -> bool {
+        self.r#type == Some(zinc_types::ScalarType::Boolean)
+            && self.constant_value() == Some(&BigInt::from(0))
+    }
+
+    ///
+    /// Joins the bounds of the values coming from two branches.
+    ///
+    fn join(&self, other: &Self) -> Self {
+        let r#type = if self.r#type == other.r#type {
+            self.r#type.clone()
+        } else {
+            None
+        };
+        let range = match (self.range.as_ref(), other.range.as_ref()) {
+            (Some((min_1, max_1)), Some((min_2, max_2))) if r#type.is_some() => {
+                Some((min_1.min(min_2).to_owned(), max_1.max(max_2).to_owned()))
+            }
+            _ => None,
+        };
+        let source = if self.source == other.source {
+            self.source
+        } else {
+            None
+        };
+        let facts = if self.facts == other.facts {
+            self.facts.clone()
+        } else {
+            vec![]
+        };
+
+        Self {
+            r#type,
+            range,
+            source,
+            facts,
+        }
+    }
+}
+
+///
+/// The abstract function execution state.
+///
+#[derive(Debug, Default, Clone)]
+struct State {
+    /// The evaluation stack cells.
+    evaluation_stack: Vec<Bound>,
+    /// The data stack cells with known bounds.
+    data_stack: HashMap<usize, Bound>,
+}
+
+impl State {
+    ///
+    /// Pops a cell from the evaluation stack.
+    ///
+    fn pop(&mut self) -> Option<Bound> {
+        self.evaluation_stack.pop()
+    }
+
+    ///
+    /// Pops `count` cells from the evaluation stack.
+    ///
+    fn pop_many(&mut self, count: usize) -> Option<()> {
+        let length = self.evaluation_stack.len().checked_sub(count)?;
+        self.evaluation_stack.truncate(length);
+        Some(())
+    }
+
+    ///
+    /// Pushes a cell onto the evaluation stack.
+    ///
+    fn push(&mut self, bound: Bound) {
+        self.evaluation_stack.push(bound);
+    }
+
+    ///
+    /// Pushes `count` unknown cells onto the evaluation stack.
+    ///
+    fn push_unknown(&mut self, count: usize) {
+        for _ in 0..count {
+            self.evaluation_stack.push(Bound::default());
+        }
+    }
+
+    ///
+    /// Pushes the operation result of `type` with `range`, which is checked to fit the type.
+    ///
+    /// Returns whether the check is redundant.
+    ///
+    fn push_checked(
+        &mut self,
+        r#type: Option<zinc_types::ScalarType>,
+        range: Option<(BigInt, BigInt)>,
+    ) -> bool {
+        let is_redundant = match (r#type.as_ref(), range.as_ref()) {
+            (Some(zinc_types::ScalarType::Integer(int_type)), Some((min, max))) => {
+                min >= &int_type.min() && max <= &int_type.max()
+            }
+            _ => false,
+        };
+
+        if is_redundant {
+            self.push(Bound {
+                r#type,
+                range,
+                ..Bound::default()
+            });
+        } else {
+            self.push(Bound::typed(r#type));
+        }
+        is_redundant
+    }
+
+    ///
+    /// Stores the `bound` at the data stack `address`.
+    ///
+    fn store(&mut self, address: usize, bound: Bound) {
+        self.invalidate(address, 1);
+        self.data_stack.insert(
+            address,
+            Bound {
+                source: None,
+                facts: vec![],
+                ..bound
+            },
+        );
+    }
+
+    ///
+    /// Forgets the bounds of the data stack range of `size` at `address`, and the evaluation stack
+    /// facts about the range.
+    ///
+    fn invalidate(&mut self, address: usize, size: usize) {
+        let is_inside = |cell: usize| cell >= address && cell < address + size;
+
+        self.data_stack.retain(|cell, _bound| !is_inside(*cell));
+        for bound in self.evaluation_stack.iter_mut() {
+            if bound.source.map(is_inside).unwrap_or_default() {
+                bound.source = None;
+            }
+            bound.facts.retain(|fact| !is_inside(fact.address));
+        }
+    }
+
+    ///
+    /// Narrows the data stack cell bounds with the `facts`.
+    ///
+    fn refine(&mut self, facts: Vec<Fact>) {
+        for fact in facts.into_iter() {
+            let bound = self.data_stack.entry(fact.address).or_default();
+            if bound.r#type.is_none() {
+                bound.r#type = Some(zinc_types::ScalarType::Integer(fact.r#type.clone()));
+            }
+            if bound.r#type != Some(zinc_types::ScalarType::Integer(fact.r#type.clone())) {
+                continue;
+            }
+
+            let (min, max) = bound
+                .range
+                .take()
+                .unwrap_or_else(|| (fact.r#type.min(), fact.r#type.max()));
+            bound.range = Some((min.max(fact.min), max.min(fact.max)));
+        }
+    }
+
+    ///
+    /// Joins the states of two branches.
+    ///
+    /// Returns `None` if the evaluation stack shapes are different.
+    ///
+    fn join(&self, other: &Self) -> Option<Self> {
+        if self.evaluation_stack.len() != other.evaluation_stack.len() {
+            return None;
+        }
+
+        let evaluation_stack = self
+            .evaluation_stack
+            .iter()
+            .zip(other.evaluation_stack.iter())
+            .map(|(bound_1, bound_2)| bound_1.join(bound_2))
+            .collect();
+        let data_stack = self
+            .data_stack
+            .iter()
+            .filter_map(|(address, bound_1)| {
+                other
+                    .data_stack
+                    .get(address)
+                    .map(|bound_2| (*address, bound_1.join(bound_2)))
+            })
+            .collect();
+
+        Some(Self {
+            evaluation_stack,
+            data_stack,
+        })
+    }
+}
+
+///
+/// The comparison operator, which produces facts about its operands.
+///
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    /// The `<` operator.
+    Lesser,
+    /// The `<=` operator.
+    LesserEquals,
+    /// The `==` operator.
+    Equals,
+    /// The `!=` operator.
+    NotEquals,
+    /// The `>=` operator.
+    GreaterEquals,
+    /// The `>` operator.
+    Greater,
+}
+
+impl Comparison {
+    ///
+    /// Returns the operator with the operands swapped, e.g. `c < x` is `x > c`.
+    ///
+    fn swapped(self) -> Self {
+        match self {
+            Self::Lesser => Self::Greater,
+            Self::LesserEquals => Self::GreaterEquals,
+            Self::Equals => Self::Equals,
+            Self::NotEquals => Self::NotEquals,
+            Self::GreaterEquals => Self::LesserEquals,
+            Self::Greater => Self::Lesser,
+        }
+    }
+
+    ///
+    /// Returns the bounds of `x` of `type` if `x <operator> constant` holds.
+    ///
+    fn bounds(
+        self,
+        constant: &BigInt,
+        r#type: &zinc_types::IntegerType,
+    ) -> Option<(BigInt, BigInt)> {
+        match self {
+            Self::Lesser => Some((r#type.min(), constant - 1u8)),
+            Self::LesserEquals => Some((r#type.min(), constant.to_owned())),
+            Self::Equals => Some((constant.to_owned(), constant.to_owned())),
+            Self::NotEquals => None,
+            Self::GreaterEquals => Some((constant.to_owned(), r#type.max())),
+            Self::Greater => Some((constant + 1u8, r#type.max())),
+        }
+    }
+}
+
+///
+/// The branch being interpreted.
+///
+#[derive(Debug)]
+struct Branch {
+    /// The state before the branch.
+    before: State,
+    /// The state after the main branch, if the `else` branch is being interpreted.
+    main: Option<State>,
+    /// The facts implied by the branch condition.
+    condition_facts: Vec<Fact>,
+}
+
+///
+/// The bytecode interpreter over the interval domain.
+///
+#[derive(Debug, Default)]
+struct Interpreter {
+    /// The current state.
+    state: State,
+    /// The enclosing branches.
+    branches: Vec<Branch>,
+    /// The states before the enclosing loops.
+    loops: Vec<State>,
+    /// The states before the enclosing unconstrained witness blocks.
+    witnesses: Vec<State>,
+}
+
+impl Interpreter {
+    ///
+    /// Interprets the instruction at `address`.
+    ///
+    /// Returns whether the instruction range check is redundant, or `None` if the instruction
+    /// stack effect cannot be followed.
+    ///
+    fn execute(&mut self, instructions: &[Instruction], address: usize) -> Option<bool> {
+        match instructions[address] {
+            Instruction::NoOperation(_)
+            | Instruction::FileMarker(_)
+            | Instruction::FunctionMarker(_)
+            | Instruction::LineMarker(_)
+            | Instruction::ColumnMarker(_)
+            | Instruction::AssertStorageUnchanged(_) => {}
+
+            Instruction::Push(ref inner) => self.state.push(Bound::constant(
+                inner.value.to_owned(),
+                inner.scalar_type.clone(),
+            )),
+            Instruction::Slice(ref inner) => {
+                self.state.pop_many(inner.total_size + 1)?;
+                self.state.push_unknown(inner.slice_length);
+            }
+            Instruction::Copy(_) => {
+                let bound = self.state.pop()?;
+                self.state.push(bound.clone());
+                self.state.push(bound);
+            }
+
+            Instruction::Load(ref inner) => {
+                for cell in inner.address..inner.address + inner.size {
+                    let mut bound = self
+                        .state
+                        .data_stack
+                        .get(&cell)
+                        .cloned()
+                        .unwrap_or_default();
+                    bound.source = Some(cell);
+                    self.state.push(bound);
+                }
+            }
+            Instruction::LoadByIndex(ref inner) => {
+                self.state.pop()?;
+                self.state.push_unknown(inner.value_size);
+            }
+            Instruction::Store(ref inner) => {
+                for cell in (inner.address..inner.address + inner.size).rev() {
+                    let bound = self.state.pop()?;
+                    self.state.store(cell, bound);
+                }
+            }
+            Instruction::StoreByIndex(ref inner) => {
+                self.state.pop_many(inner.value_size + 1)?;
+                self.state.invalidate(inner.address, inner.total_size);
+            }
+
+            Instruction::StorageInit(ref inner) => {
+                let size: usize = inner
+                    .field_types
+                    .iter()
+                    .map(|field| field.r#type.size())
+                    .sum();
+                self.state
+                    .pop_many(size.checked_sub(zinc_const::contract::IMPLICIT_FIELDS_SIZE)?)?;
+                self.state.push_unknown(1);
+            }
+            Instruction::StorageFetch(_) => {
+                self.state.pop()?;
+                self.state.push_unknown(1);
+            }
+            Instruction::StorageStore(ref inner) => self.state.pop_many(inner.size + 2)?,
+            Instruction::StorageLoad(ref inner) => {
+                self.state.pop_many(2)?;
+                self.state.push_unknown(inner.size);
+            }
+
+            Instruction::Add(_) => {
+                return self
+                    .arithmetic(|(min_1, max_1), (min_2, max_2)| (min_1 + min_2, max_1 + max_2))
+            }
+            Instruction::Sub(_) => {
+                return self
+                    .arithmetic(|(min_1, max_1), (min_2, max_2)| (min_1 - max_2, max_1 - min_2))
+            }
+            Instruction::Mul(_) => {
+                return self.arithmetic(|(min_1, max_1), (min_2, max_2)| {
+                    let products = [min_1 * min_2, min_1 * max_2, max_1 * min_2, max_1 * max_2];
+                    let min = products.iter().min().cloned().unwrap_or_default();
+                    let max = products.iter().max().cloned().unwrap_or_default();
+                    (min, max)
+                })
+            }
+            Instruction::Div(_)
+            | Instruction::Rem(_)
+            | Instruction::BitwiseShiftLeft(_)
+            | Instruction::BitwiseShiftRight(_)
+            | Instruction::BitwiseAnd(_)
+            | Instruction::BitwiseOr(_)
+            | Instruction::BitwiseXor(_) => {
+                let right = self.state.pop()?;
+                let left = self.state.pop()?;
+                self.state.push(Bound::typed(left.r#type.or(right.r#type)));
+            }
+            Instruction::Neg(_) => {
+                self.state.pop()?;
+                self.state.push_unknown(1);
+            }
+            Instruction::BitwiseNot(_) => {
+                let operand = self.state.pop()?;
+                self.state.push(Bound::typed(operand.r#type));
+            }
+
+            Instruction::Not(_) => {
+                self.state.pop()?;
+                self.state.push(Bound::boolean(vec![]));
+            }
+            Instruction::And(_) => {
+                let right = self.state.pop()?;
+                let left = self.state.pop()?;
+                let mut facts = left.facts;
+                facts.extend(right.facts);
+                self.state.push(Bound::boolean(facts));
+            }
+            Instruction::Or(_) | Instruction::Xor(_) => {
+                self.state.pop_many(2)?;
+                self.state.push(Bound::boolean(vec![]));
+            }
+
+            Instruction::Lt(_) => self.comparison(Comparison::Lesser)?,
+            Instruction::Le(_) => self.comparison(Comparison::LesserEquals)?,
+            Instruction::Eq(_) => self.comparison(Comparison::Equals)?,
+            Instruction::Ne(_) => self.comparison(Comparison::NotEquals)?,
+            Instruction::Ge(_) => self.comparison(Comparison::GreaterEquals)?,
+            Instruction::Gt(_) => self.comparison(Comparison::Greater)?,
+
+            Instruction::Cast(ref inner) => {
+                let operand = self.state.pop()?;
+                return Some(match inner.r#type {
+                    zinc_types::ScalarType::Integer(_) => self
+                        .state
+                        .push_checked(Some(inner.r#type.clone()), operand.range),
+                    ref r#type => {
+                        self.state.push(Bound::typed(Some(r#type.clone())));
+                        false
+                    }
+                });
+            }
+
+            Instruction::If(_) => {
+                let condition = self.state.pop()?;
+                self.branches.push(Branch {
+                    before: self.state.clone(),
+                    main: None,
+                    condition_facts: condition.facts.clone(),
+                });
+                self.state.refine(condition.facts);
+            }
+            Instruction::Else(_) => {
+                let branch = self.branches.last_mut()?;
+                let before = branch.before.clone();
+                branch.main = Some(mem::replace(&mut self.state, before));
+            }
+            Instruction::EndIf(_) => {
+                let branch = self.branches.pop()?;
+                self.state = match branch.main {
+                    Some(main) => {
+                        // the short-circuit `a && b` is true only if both `a` and `b` are true
+                        let is_short_circuit_and = match (
+                            main.evaluation_stack.last(),
+                            self.state.evaluation_stack.last(),
+                        ) {
+                            (Some(main_top), Some(top)) => {
+                                top.is_false()
+                                    && main_top.r#type == Some(zinc_types::ScalarType::Boolean)
+                                    && main.evaluation_stack.len()
+                                        == branch.before.evaluation_stack.len() + 1
+                            }
+                            _ => false,
+                        };
+
+                        let mut facts = match main.evaluation_stack.last() {
+                            Some(main_top) if is_short_circuit_and => main_top.facts.clone(),
+                            _ => vec![],
+                        };
+                        let mut state = main.join(&self.state)?;
+                        if is_short_circuit_and {
+                            facts.extend(branch.condition_facts);
+                            if let Some(top) = state.evaluation_stack.last_mut() {
+                                top.facts = facts;
+                            }
+                        }
+                        state
+                    }
+                    None => branch.before.join(&self.state)?,
+                };
+            }
+
+            Instruction::LoopBegin(_) => {
+                let mut depth = 0;
+                for instruction in instructions[address + 1..].iter() {
+                    match instruction {
+                        Instruction::LoopBegin(_) => depth += 1,
+                        Instruction::LoopEnd(_) if depth == 0 => break,
+                        Instruction::LoopEnd(_) => depth -= 1,
+                        Instruction::Store(inner) => {
+                            self.state.invalidate(inner.address, inner.size)
+                        }
+                        Instruction::StoreByIndex(inner) => {
+                            self.state.invalidate(inner.address, inner.total_size)
+                        }
+                        _ => {}
+                    }
+                }
+                self.loops.push(self.state.clone());
+            }
+            Instruction::LoopEnd(_) => {
+                let before = self.loops.pop()?;
+                self.state = before.join(&self.state)?;
+            }
+
+            Instruction::Call(ref inner) => {
+                self.state.pop_many(inner.input_size)?;
+                let output_size =
+                    instructions
+                        .get(inner.address..)?
+                        .iter()
+                        .find_map(|instruction| match instruction {
+                            Instruction::Return(inner) => Some(inner.output_size),
+                            _ => None,
+                        })?;
+                self.state.push_unknown(output_size);
+            }
+            Instruction::Return(_) => return None,
+
+            Instruction::Dbg(ref inner) => {
+                let size = inner
+                    .argument_types
+                    .iter()
+                    .map(zinc_types::Type::size)
+                    .sum();
+                self.state.pop_many(size)?;
+            }
+            Instruction::Require(_) => {
+                let condition = self.state.pop()?;
+                self.state.refine(condition.facts);
+            }
+            Instruction::CallLibrary(ref inner) => {
+                self.state.pop_many(inner.input_size)?;
+                self.state.push_unknown(inner.output_size);
+            }
+            Instruction::UnsafeWitnessBegin(_) => {
+                let mut before = self.state.clone();
+                let mut depth = 0;
+                for instruction in instructions[address + 1..].iter() {
+                    match instruction {
+                        Instruction::UnsafeWitnessBegin(_) => depth += 1,
+                        Instruction::UnsafeWitnessEnd(_) if depth == 0 => break,
+                        Instruction::UnsafeWitnessEnd(_) => depth -= 1,
+                        Instruction::Store(inner) => before.invalidate(inner.address, inner.size),
+                        Instruction::StoreByIndex(inner) => {
+                            before.invalidate(inner.address, inner.total_size)
+                        }
+                        _ => {}
+                    }
+                }
+                self.witnesses.push(before);
+            }
+            Instruction::UnsafeWitnessEnd(ref inner) => {
+                // the facts refined inside the block are not enforced, so they are discarded
+                self.state = self.witnesses.pop()?;
+                for r#type in inner.output_types.iter() {
+                    self.state.push(Bound::typed(Some(r#type.clone())));
+                }
+            }
+            Instruction::AssertEq(ref inner) => self.state.pop_many(inner.r#type.size() * 2)?,
+        }
+
+        Some(false)
+    }
+
+    ///
+    /// Interprets the binary arithmetic operation, whose result bounds are computed by
+    /// `operation` from the operand bounds.
+    ///
+    fn arithmetic<F>(&mut self, operation: F) -> Option<bool>
+    where
+        F: Fn((&BigInt, &BigInt), (&BigInt, &BigInt)) -> (BigInt, BigInt),
+    {
+        let right = self.state.pop()?;
+        let left = self.state.pop()?;
+
+        let r#type = left.r#type.or(right.r#type);
+        let range = match (r#type.as_ref(), left.range.as_ref(), right.range.as_ref()) {
+            (
+                Some(zinc_types::ScalarType::Integer(_)),
+                Some((min_1, max_1)),
+                Some((min_2, max_2)),
+            ) => Some(operation((min_1, max_1), (min_2, max_2))),
+            _ => None,
+        };
+
+        Some(self.state.push_checked(r#type, range))
+    }
+
+    ///
+    /// Interprets the comparison, producing the fact about its data stack operand compared to
+    /// a constant.
+    ///
+    fn comparison(&mut self, comparison: Comparison) -> Option<()> {
+        let right = self.state.pop()?;
+        let left = self.state.pop()?;
+
+        let (variable, constant, comparison) = if right.source.is_none() {
+            (left, right, comparison)
+        } else {
+            (right, left, comparison.swapped())
+        };
+
+        let mut facts = vec![];
+        if let (Some(address), Some(value), Some(zinc_types::ScalarType::Integer(r#type))) = (
+            variable.source,
+            constant.constant_value(),
+            constant.r#type.clone(),
+        ) {
+            if let Some((min, max)) = comparison.bounds(value, &r#type) {
+                facts.push(Fact {
+                    address,
+                    r#type,
+                    min,
+                    max,
+                });
+            }
+        }
+
+        self.state.push(Bound::boolean(facts));
+        Some(())
+    }
+}
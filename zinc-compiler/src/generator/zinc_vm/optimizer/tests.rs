@@ -0,0 +1,151 @@
+//!
+//! The bytecode optimizers tests.
+//!
+
+use zinc_types::Instruction;
+use zinc_types::RangeCheck;
+
+use crate::generator::zinc_vm::tests::compile_with_files;
+
+///
+/// Compiles the circuit `code` with the range checks proven redundant marked as elided.
+///
+fn compile(code: &str) -> Vec<Instruction> {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match state.into_application(false, Some(RangeCheck::Elided)) {
+        zinc_types::Application::Circuit(circuit) => circuit.instructions,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    }
+}
+
+///
+/// Returns the range checks of the arithmetic instructions in the bytecode order.
+///
+fn arithmetic_range_checks(instructions: &[Instruction]) -> Vec<RangeCheck> {
+    instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Add(inner) => Some(inner.range_check),
+            Instruction::Sub(inner) => Some(inner.range_check),
+            Instruction::Mul(inner) => Some(inner.range_check),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn ok_require_bounds() {
+    let instructions = compile(
+        r#"
+fn main(a: u8, b: u8) -> u8 {
+    require(a < 100);
+    require(b <= 100);
+    a + b
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Elided]
+    );
+}
+
+#[test]
+fn ok_chained_require_bounds() {
+    let instructions = compile(
+        r#"
+fn main(a: u16, b: u16) -> u16 {
+    require(a >= 10 && a < 200);
+    require(b < 200);
+    let c = a - 10;
+    let d = c * b;
+    d - 1
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Elided, RangeCheck::Elided, RangeCheck::Enforced]
+    );
+}
+
+#[test]
+fn ok_unbounded_enforced() {
+    let instructions = compile(
+        r#"
+fn main(a: u8, b: u8) -> u8 {
+    require(a < 100);
+    a + b
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Enforced]
+    );
+}
+
+#[test]
+fn ok_if_else_join() {
+    let instructions = compile(
+        r#"
+fn main(condition: bool, a: u8) -> u8 {
+    require(a < 100);
+    let b = if condition { a + 100 } else { a };
+    b + 100
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Elided, RangeCheck::Enforced]
+    );
+}
+
+#[test]
+fn ok_if_condition_refines_main_branch_only() {
+    let instructions = compile(
+        r#"
+fn main(a: u8) -> u8 {
+    if a < 100 {
+        a + 100
+    } else {
+        a + 100
+    }
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Elided, RangeCheck::Enforced]
+    );
+}
+
+#[test]
+fn ok_loop_invalidates_stored_bounds() {
+    let instructions = compile(
+        r#"
+fn main(a: u8) -> u8 {
+    require(a < 10);
+    let mut sum = a;
+    for i in 0..10 {
+        sum = sum + 10;
+    }
+    sum
+}
+"#,
+    );
+
+    assert_eq!(
+        arithmetic_range_checks(instructions.as_slice()),
+        vec![RangeCheck::Enforced, RangeCheck::Elided]
+    );
+}
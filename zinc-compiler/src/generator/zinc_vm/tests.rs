@@ -0,0 +1,605 @@
+//!
+//! The Zinc VM generator state tests.
+//!
+
+use std::collections::HashMap;
+
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::semantic::element::r#type::function::intrinsic::cfg::values::Values as CfgValues;
+use crate::source::file::File;
+use crate::source::Source;
+
+///
+/// Creates the in-memory project source file `name` located at `path`.
+///
+pub(crate) fn file(name: &str, path: &str, code: &str) -> zinc_project::Source {
+    zinc_project::Source::File(zinc_project::File {
+        name: name.to_owned(),
+        path: path.to_owned(),
+        code: code.to_owned(),
+    })
+}
+
+///
+/// Compiles the project described by the `manifest`, which consists of the in-memory `modules`
+/// and the integration test roots `test_roots`, into the bytecode generator state.
+/// The entry module must be called `main`.
+///
+pub(crate) fn compile_with_modules(
+    manifest: zinc_project::Manifest,
+    modules: Vec<(&str, zinc_project::Source)>,
+    test_roots: Vec<(&str, &str)>,
+) -> anyhow::Result<ZincVMState> {
+    let name = manifest.project.name.clone();
+    let modules = modules
+        .into_iter()
+        .map(|(name, module)| (name.to_owned(), module))
+        .collect();
+    let source = zinc_project::Source::Directory(zinc_project::Directory {
+        name: "src".to_owned(),
+        path: name,
+        modules,
+    });
+
+    let mut directory = match Source::try_from_string(source, true)? {
+        Source::Directory(directory) => directory,
+        Source::File(_) => panic!("the project source must be a directory"),
+    };
+    for (name, code) in test_roots.into_iter() {
+        directory
+            .test_roots
+            .push(File::try_from_string(zinc_project::File {
+                name: name.to_owned(),
+                path: format!("tests/{}.zn", name),
+                code: code.to_owned(),
+            })?);
+    }
+
+    let state = Source::Directory(directory).compile(manifest, HashMap::new())?;
+
+    Ok(ZincVMState::unwrap_rc(state))
+}
+
+///
+/// Compiles the project described by the `manifest`, which consists of the in-memory `files`,
+/// given as the module names and their code, into the bytecode generator state.
+///
+pub(crate) fn compile_with_files(
+    manifest: zinc_project::Manifest,
+    files: &[(&str, &str)],
+) -> anyhow::Result<ZincVMState> {
+    let modules = files
+        .iter()
+        .map(|(name, code)| {
+            let path = format!("{}/{}.zn", manifest.project.name, name);
+            (*name, file(name, path.as_str(), code))
+        })
+        .collect();
+
+    compile_with_modules(manifest, modules, vec![])
+}
+
+static CONTRACT: &str = r#"
+contract Ordered {
+    pub zeta: u64;
+    alpha: bool;
+    pub middle: (u8, field);
+
+    pub fn new(zeta: u64, alpha: bool) -> Self {
+        Self {
+            zeta: zeta,
+            alpha: alpha,
+            middle: (0, 0 as field),
+        }
+    }
+
+    pub fn withdraw(mut self, to: zksync::Address, amount: u64, memo: u8) {
+        self.zeta -= amount;
+    }
+
+    pub fn deposit(mut self, zebra: u64, apple: u64) {
+        self.zeta += zebra + apple;
+    }
+
+    pub fn audit(self) -> u64 {
+        self.zeta
+    }
+}
+"#;
+
+fn build_template() -> String {
+    let manifest = zinc_project::Manifest::new("ordered", zinc_project::ProjectType::Contract);
+    let version = manifest.project.version.clone();
+    let build = compile_with_files(manifest, &[("main", CONTRACT)])
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_application(false, None)
+        .into_build(&version);
+
+    serde_json::to_string_pretty(&build.input).expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+fn assert_ordered(template: &str, keys: &[&str]) {
+    let mut position = 0;
+    for key in keys.iter() {
+        let offset = template[position..]
+            .find(format!("\"{}\"", key).as_str())
+            .unwrap_or_else(|| {
+                panic!(
+                    "the key `{}` is missing or out of the declaration order:\n{}",
+                    key, template
+                )
+            });
+        position += offset + key.len();
+    }
+}
+
+#[test]
+fn ok_template_deterministic() {
+    let first = build_template();
+    let second = build_template();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn ok_template_declaration_order() {
+    let template = build_template();
+
+    assert_ordered(
+        template.as_str(),
+        &[
+            "new", "withdraw", "to", "amount", "memo", "deposit", "zebra", "apple", "audit",
+        ],
+    );
+    assert_ordered(
+        template.as_str(),
+        &[
+            "msg",
+            "sender",
+            "recipient",
+            "token_address",
+            "amount",
+            "arguments",
+        ],
+    );
+}
+
+///
+/// Compiles the circuit `code` into the bytecode instructions.
+///
+fn compile_circuit(code: &str) -> Vec<zinc_types::Instruction> {
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match state.into_application(false, None) {
+        zinc_types::Application::Circuit(circuit) => circuit.instructions,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    }
+}
+
+#[test]
+fn ok_short_circuit_right_operand_conditional() {
+    for operator in ["&&", "||"].iter() {
+        let instructions = compile_circuit(
+            format!(
+                "fn main(a: u8, b: u8) -> bool {{ a != 0 {} b / a > 2 }}",
+                operator
+            )
+            .as_str(),
+        );
+
+        let position = |predicate: fn(&zinc_types::Instruction) -> bool| {
+            instructions
+                .iter()
+                .position(predicate)
+                .unwrap_or_else(|| panic!("{:?}", instructions))
+        };
+        let r#if = position(|instruction| matches!(instruction, zinc_types::Instruction::If(_)));
+        let div = position(|instruction| matches!(instruction, zinc_types::Instruction::Div(_)));
+        let r#else =
+            position(|instruction| matches!(instruction, zinc_types::Instruction::Else(_)));
+
+        assert!(
+            r#if < div && div < r#else,
+            "the right operand of `{}` must be evaluated conditionally: {:?}",
+            operator,
+            instructions
+        );
+    }
+}
+
+#[test]
+fn ok_flags_contains_masked_comparison() {
+    let instructions = compile_circuit(
+        r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+}
+
+fn main(permissions: Permission::Flags) -> bool {
+    permissions.contains(Permission::Read | Permission::Write)
+}
+"#,
+    );
+
+    assert!(
+        instructions.windows(3).any(|window| matches!(
+            window,
+            [
+                zinc_types::Instruction::BitwiseAnd(_),
+                zinc_types::Instruction::Load(_),
+                zinc_types::Instruction::Eq(_),
+            ]
+        )),
+        "the flag set must be masked and compared with the flags: {:?}",
+        instructions
+    );
+}
+
+///
+/// Checks whether the `instructions` contain any conditional instructions.
+///
+fn has_conditional(instructions: &[zinc_types::Instruction]) -> bool {
+    instructions.iter().any(|instruction| {
+        matches!(
+            instruction,
+            zinc_types::Instruction::If(_)
+                | zinc_types::Instruction::Else(_)
+                | zinc_types::Instruction::EndIf(_)
+        )
+    })
+}
+
+#[test]
+fn ok_constant_condition_false_else_branch_only() {
+    let instructions = compile_circuit(
+        r#"
+const DEBUG: bool = false;
+
+fn main(a: u8) -> u8 {
+    if DEBUG { a * 3 } else { a + 7 }
+}
+"#,
+    );
+
+    assert!(
+        !has_conditional(instructions.as_slice()),
+        "the conditional instructions must not be generated: {:?}",
+        instructions
+    );
+    assert!(
+        instructions
+            .iter()
+            .any(|instruction| matches!(instruction, zinc_types::Instruction::Add(_))),
+        "the else branch must be generated: {:?}",
+        instructions
+    );
+    assert!(
+        !instructions
+            .iter()
+            .any(|instruction| matches!(instruction, zinc_types::Instruction::Mul(_))),
+        "the main branch must not be generated: {:?}",
+        instructions
+    );
+}
+
+#[test]
+fn ok_constant_condition_true_main_branch_only() {
+    let instructions = compile_circuit(
+        r#"
+const DEBUG: bool = true;
+
+fn main(a: u8) -> u8 {
+    let mut result = a;
+    if !DEBUG {
+        result = a * 3;
+    } else if DEBUG {
+        result += 7;
+    }
+    result
+}
+"#,
+    );
+
+    assert!(
+        !has_conditional(instructions.as_slice()),
+        "the conditional instructions must not be generated: {:?}",
+        instructions
+    );
+    assert!(
+        instructions
+            .iter()
+            .any(|instruction| matches!(instruction, zinc_types::Instruction::Add(_))),
+        "the taken branch must be generated: {:?}",
+        instructions
+    );
+    assert!(
+        !instructions
+            .iter()
+            .any(|instruction| matches!(instruction, zinc_types::Instruction::Mul(_))),
+        "the dead branch must not be generated: {:?}",
+        instructions
+    );
+}
+
+#[test]
+fn ok_constant_scrutinee_taken_branch_only() {
+    let instructions = compile_circuit(
+        r#"
+const MODE: u8 = 2;
+
+fn main(a: u8) -> u8 {
+    match MODE {
+        1 => a * 3,
+        2 => a + 7,
+        _ => a - 1,
+    }
+}
+"#,
+    );
+
+    assert!(
+        !has_conditional(instructions.as_slice()),
+        "the conditional instructions must not be generated: {:?}",
+        instructions
+    );
+    assert!(
+        instructions
+            .iter()
+            .any(|instruction| matches!(instruction, zinc_types::Instruction::Add(_))),
+        "the taken branch must be generated: {:?}",
+        instructions
+    );
+    assert!(
+        !instructions.iter().any(|instruction| matches!(
+            instruction,
+            zinc_types::Instruction::Mul(_) | zinc_types::Instruction::Sub(_)
+        )),
+        "the other branches must not be generated: {:?}",
+        instructions
+    );
+}
+
+#[test]
+fn ok_runtime_condition_both_branches() {
+    let instructions = compile_circuit(
+        r#"
+fn main(a: u8, flag: bool) -> u8 {
+    if flag { a * 3 } else { a + 7 }
+}
+"#,
+    );
+
+    assert!(
+        has_conditional(instructions.as_slice()),
+        "the conditional instructions must be generated: {:?}",
+        instructions
+    );
+}
+
+static ENSURES: &str = r#"
+#[ensures(result <= limit)]
+#[ensures(result <= value)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    if value > limit { limit } else { value }
+}
+
+fn main(value: u8, limit: u8) -> u8 {
+    clamp(value, limit)
+}
+"#;
+
+///
+/// Compiles the circuit `code` with the build `profile` set.
+///
+fn compile_circuit_with_profile(code: &str, profile: &str) -> Vec<zinc_types::Instruction> {
+    CfgValues::set(
+        vec![(zinc_const::cfg::PROFILE.to_owned(), profile.to_owned())]
+            .into_iter()
+            .collect(),
+    );
+
+    compile_circuit(code)
+}
+
+///
+/// Returns the `require` instructions messages.
+///
+fn require_messages(instructions: &[zinc_types::Instruction]) -> Vec<String> {
+    instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            zinc_types::Instruction::Require(require) => require.message.clone(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn ok_ensures_debug_checked() {
+    let instructions = compile_circuit_with_profile(ENSURES, zinc_const::cfg::PROFILE_DEBUG);
+
+    let messages = require_messages(instructions.as_slice());
+    assert_eq!(messages.len(), 2, "{:?}", instructions);
+    assert!(
+        messages
+            .iter()
+            .all(|message| message.starts_with("The postcondition of the function `clamp` at ")),
+        "{:?}",
+        messages
+    );
+    assert!(messages[0].ends_with(":2:1 is violated"), "{:?}", messages);
+    assert!(messages[1].ends_with(":3:1 is violated"), "{:?}", messages);
+}
+
+#[test]
+fn ok_ensures_release_compiled_out() {
+    let debug = compile_circuit_with_profile(ENSURES, zinc_const::cfg::PROFILE_DEBUG);
+    let release = compile_circuit_with_profile(ENSURES, zinc_const::cfg::PROFILE_RELEASE);
+    let unchecked = compile_circuit_with_profile(
+        ENSURES
+            .replace("#[ensures(result <= limit)]", "")
+            .replace("#[ensures(result <= value)]", "")
+            .as_str(),
+        zinc_const::cfg::PROFILE_RELEASE,
+    );
+
+    assert!(
+        require_messages(release.as_slice()).is_empty(),
+        "the postconditions must be compiled out: {:?}",
+        release
+    );
+    assert_eq!(
+        release.len(),
+        unchecked.len(),
+        "the release build must not contain any trace of the postconditions: {:?}",
+        release
+    );
+    assert!(
+        debug.len() > release.len(),
+        "the debug build must check the postconditions: {:?}",
+        debug
+    );
+}
+
+static INVARIANT: &str = r#"
+#[invariant(self.fee <= Self::MAX_FEE)]
+contract Vault {
+    const MAX_FEE: u16 = 10000;
+
+    fee: u16;
+
+    pub fn new(fee: u16) -> Self {
+        Self { fee: fee }
+    }
+
+    pub fn set_fee(mut self, fee: u16) {
+        self.fee = fee;
+    }
+
+    pub fn reset_fee(mut self) -> u16 {
+        let fee = self.fee;
+        self.fee = 0;
+        fee
+    }
+
+    pub fn get_fee(self) -> u16 {
+        self.fee
+    }
+}
+"#;
+
+///
+/// Compiles the contract `code` with the build `profile` set into the bytecode instructions.
+///
+fn compile_contract_with_profile(code: &str, profile: &str) -> Vec<zinc_types::Instruction> {
+    CfgValues::set(
+        vec![(zinc_const::cfg::PROFILE.to_owned(), profile.to_owned())]
+            .into_iter()
+            .collect(),
+    );
+
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Contract);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match state.into_application(false, None) {
+        zinc_types::Application::Contract(contract) => contract.instructions,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    }
+}
+
+#[test]
+fn ok_invariant_checked_after_mutable_methods() {
+    let instructions = compile_contract_with_profile(INVARIANT, zinc_const::cfg::PROFILE_RELEASE);
+
+    let messages = require_messages(instructions.as_slice());
+    assert_eq!(messages.len(), 2, "{:?}", instructions);
+    assert!(
+        messages.iter().all(|message| message
+            .starts_with("The invariant of the contract `Vault` at ")
+            && message.ends_with(":2:1 is violated")),
+        "{:?}",
+        messages
+    );
+}
+
+static INVARIANT_VIEW: &str = r#"
+#[invariant(self.fee <= 10000)]
+contract Vault {
+    fee: u16;
+
+    pub fn new(fee: u16) -> Self {
+        Self { fee: fee }
+    }
+
+    pub fn get_fee(self) -> u16 {
+        self.fee
+    }
+
+    pub fn is_free(self) -> bool {
+        self.fee == 0
+    }
+}
+"#;
+
+#[test]
+fn ok_invariant_view_methods_unchecked() {
+    let checked = compile_contract_with_profile(INVARIANT_VIEW, zinc_const::cfg::PROFILE_DEBUG);
+    let unchecked = compile_contract_with_profile(
+        INVARIANT_VIEW
+            .replace("#[invariant(self.fee <= 10000)]", "")
+            .as_str(),
+        zinc_const::cfg::PROFILE_DEBUG,
+    );
+
+    assert!(
+        require_messages(checked.as_slice()).is_empty(),
+        "the methods without `mut self` must not check the invariants: {:?}",
+        checked
+    );
+    assert_eq!(
+        checked.len(),
+        unchecked.len(),
+        "the methods without `mut self` must not pay for the invariants: {:?}",
+        checked
+    );
+}
+
+#[test]
+fn ok_unit_test_expected_panic() {
+    let code = r#"
+fn main() {}
+
+#[test]
+#[should_panic(expected = "too low")]
+fn expected() {
+    require(false, "The balance is too low");
+}
+
+#[test]
+#[should_panic]
+fn plain() {
+    require(false);
+}
+"#;
+
+    let manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    let state =
+        compile_with_files(manifest, &[("main", code)]).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let unit_tests = match state.into_application(false, None) {
+        zinc_types::Application::Circuit(circuit) => circuit.unit_tests,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    };
+
+    let mut expected: Vec<(bool, Option<&str>)> = unit_tests
+        .values()
+        .map(|unit_test| (unit_test.should_panic, unit_test.expected_panic.as_deref()))
+        .collect();
+    expected.sort();
+    assert_eq!(expected, vec![(true, None), (true, Some("too low"))]);
+}
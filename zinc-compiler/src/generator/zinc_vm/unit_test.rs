@@ -2,6 +2,8 @@
 //! The bytecode unit test.
 //!
 
+use zinc_lexical::Location;
+
 ///
 /// Unit test metadata.
 ///
@@ -13,10 +15,16 @@ pub struct UnitTest {
     pub name: String,
     /// Whether the test should fail to be successful.
     pub should_panic: bool,
+    /// The substring the error message of the failed test must contain.
+    pub expected_panic: Option<String>,
     /// Whether the test is marked as ignored.
     pub is_ignored: bool,
+    /// Whether the test must synthesize and check the constraints.
+    pub is_proof: bool,
     /// The optional transaction variable.
     pub zksync_msg: Option<zinc_types::TransactionMsg>,
+    /// The `zksync::msg` attribute location, which is set along with the transaction variable.
+    pub zksync_msg_location: Option<Location>,
 }
 
 impl UnitTest {
@@ -27,15 +35,21 @@ impl UnitTest {
         type_id: usize,
         name: String,
         should_panic: bool,
+        expected_panic: Option<String>,
         is_ignored: bool,
+        is_proof: bool,
         zksync_msg: Option<zinc_types::TransactionMsg>,
+        zksync_msg_location: Option<Location>,
     ) -> Self {
         Self {
             type_id,
             name,
             should_panic,
+            expected_panic,
             is_ignored,
+            is_proof,
             zksync_msg,
+            zksync_msg_location,
         }
     }
 }
@@ -5,10 +5,19 @@
 #[cfg(test)]
 mod tests;
 
+pub mod module;
+
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
+use num::BigInt;
+use num::ToPrimitive;
+
+use zinc_lexical::Location;
 use zinc_syntax::Attribute as SyntaxAttribute;
+use zinc_syntax::AttributeElement as SyntaxAttributeElement;
 use zinc_syntax::AttributeElementVariant as SyntaxAttributeElementVariant;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::Literal;
 
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -21,26 +30,135 @@ use crate::semantic::error::Error;
 pub enum Attribute {
     /// The `#[test]` attribute.
     Test,
-    /// The `#[should_panic]` attribute.
-    ShouldPanic,
+    /// The `#[should_panic]` or `#[should_panic(expected = "message")]` attribute.
+    ShouldPanic {
+        /// The substring, which the test error message must contain.
+        expected: Option<String>,
+    },
     /// The `#[ignore]` attribute.
     Ignore,
+    /// The `#[proof]` attribute, which makes the unit test synthesize and check the constraints.
+    Proof,
     /// The `#[zksync::msg(...)]` attribute.
-    ZksyncMsg(zinc_types::TransactionMsg),
+    ZksyncMsg {
+        /// The transaction variable parsed from the attribute fields.
+        msg: zinc_types::TransactionMsg,
+        /// The attribute location, which is reported by the failing unit test transfers.
+        location: Location,
+    },
+    /// The `#[max_depth(N)]` attribute, which allows direct recursion up to `N` levels deep.
+    MaxDepth(usize),
+    /// The `#[upgrade]` attribute, which marks the contract storage migration method.
+    Upgrade,
+    /// The `#[bitflags]` attribute, which turns the enumeration variants into combinable flags.
+    Bitflags,
+    /// The `#[require_sender(field)]` or `#[only_owner]` attribute, which restricts the contract
+    /// method calls to the address stored in the contract storage `field`.
+    RequireSender {
+        /// The contract storage field name, which holds the allowed sender address.
+        field: String,
+        /// The attribute location, which is used for the generated sender check.
+        location: Location,
+    },
+    /// The `#[ensures(condition)]` attribute, which checks the function postcondition in the
+    /// debug builds and is compiled out in the release ones.
+    Ensures {
+        /// The condition expression, which may reference `result` and the function arguments.
+        expression: ExpressionTree,
+        /// The attribute location, which is reported by the failing postcondition check.
+        location: Location,
+    },
+    /// The `#[max_iterations(N)]` attribute, which bounds the `while` loop iterations number.
+    MaxIterations {
+        /// The bound expression, which must be evaluated to a constant integer.
+        expression: ExpressionTree,
+        /// The attribute location.
+        location: Location,
+    },
+    /// The `#[invariant(condition)]` contract attribute, which is checked at the end of every
+    /// method taking `mut self`.
+    Invariant {
+        /// The condition expression, which may reference the contract storage via `self`.
+        expression: ExpressionTree,
+        /// The attribute location, which is reported by the failing invariant check.
+        location: Location,
+    },
 }
 
 impl Attribute {
+    /// The storage field checked by the `#[only_owner]` attribute.
+    pub const ONLY_OWNER_FIELD: &'static str = "owner";
+
+    /// The variable holding the function result in the `#[ensures(...)]` conditions.
+    pub const ENSURES_RESULT: &'static str = "result";
+
+    /// The `#[should_panic(...)]` attribute element with the expected error message substring.
+    pub const SHOULD_PANIC_EXPECTED: &'static str = "expected";
+
+    /// The `#[zksync::msg(...)]` attribute fields, which may be specified in any order.
+    pub const ZKSYNC_MSG_FIELDS: [&'static str; zinc_const::contract::TRANSACTION_FIELDS_COUNT] =
+        ["sender", "recipient", "token_address", "amount"];
+
     ///
     /// If the attribute is related to unit tests.
     ///
     pub fn is_test(&self) -> bool {
         match self {
             Self::Test => true,
-            Self::ShouldPanic => true,
+            Self::ShouldPanic { .. } => true,
             Self::Ignore => true,
+            Self::Proof => true,
             Self::ZksyncMsg { .. } => true,
+            Self::MaxDepth(_) => false,
+            Self::Upgrade => false,
+            Self::Bitflags => false,
+            Self::RequireSender { .. } => false,
+            Self::Ensures { .. } => false,
+            Self::MaxIterations { .. } => false,
+            Self::Invariant { .. } => false,
         }
     }
+
+    ///
+    /// Parses the `#[zksync::msg(...)]` attribute `field` value, which must be a non-negative
+    /// decimal or hexadecimal integer literal fitting into `bitlength` bits.
+    ///
+    /// The `field` is always present, since the missing fields are checked beforehand.
+    ///
+    fn zksync_msg_field(
+        field: Option<SyntaxAttributeElement>,
+        bitlength: usize,
+    ) -> Result<BigInt, Error> {
+        let field = field.expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        let name = field.path.to_string();
+
+        let integer = match field.variant {
+            Some(SyntaxAttributeElementVariant::Value(Literal::Integer(ref integer))) => {
+                IntegerConstant::try_from(integer)?
+            }
+            Some(SyntaxAttributeElementVariant::NegativeValue(_)) => {
+                return Err(Error::AttributeNegativeValue {
+                    location: field.location,
+                    name,
+                })
+            }
+            _ => {
+                return Err(Error::AttributeExpectedIntegerLiteral {
+                    location: field.location,
+                    name,
+                })
+            }
+        };
+        if integer.bitlength > bitlength {
+            return Err(Error::AttributeElementOverflow {
+                location: integer.location,
+                name,
+                bitlength,
+            });
+        }
+
+        Ok(integer.value)
+    }
 }
 
 impl TryFrom<SyntaxAttribute> for Attribute {
@@ -54,162 +172,228 @@ impl TryFrom<SyntaxAttribute> for Attribute {
 
         Ok(match identifier.as_str() {
             "test" => Self::Test,
-            "should_panic" => Self::ShouldPanic,
-            "ignore" => Self::Ignore,
-            "zksync::msg" => match element.variant {
+            "should_panic" => match element.variant {
+                None => Self::ShouldPanic { expected: None },
                 Some(SyntaxAttributeElementVariant::Nested(ref mut nested)) => {
-                    if nested.len() != zinc_const::contract::TRANSACTION_FIELDS_COUNT {
+                    if nested.len() != 1 {
                         return Err(Error::AttributeElementsCount {
                             location: element.location,
                             name: identifier,
-                            expected: zinc_const::contract::TRANSACTION_FIELDS_COUNT,
+                            expected: 1,
                             found: nested.len(),
                         });
                     }
 
-                    let sender = nested.remove(0);
-                    let name = sender.path.to_string();
-                    if name.as_str() != "sender" {
+                    let expected = nested.remove(0);
+                    let name = expected.path.to_string();
+                    if name.as_str() != Self::SHOULD_PANIC_EXPECTED {
                         return Err(Error::AttributeExpectedElement {
-                            location: sender.location,
-                            name: "zksync::msg".to_owned(),
+                            location: expected.location,
+                            name: identifier,
                             position: 1,
-                            expected: "sender".to_owned(),
+                            expected: Self::SHOULD_PANIC_EXPECTED.to_owned(),
                             found: name,
                         });
                     }
-                    let sender = match sender.variant {
-                        Some(SyntaxAttributeElementVariant::Value(Literal::Integer(
-                            ref integer,
-                        ))) => IntegerConstant::try_from(integer)?,
+
+                    match expected.variant {
+                        Some(SyntaxAttributeElementVariant::Value(Literal::String(literal))) => {
+                            Self::ShouldPanic {
+                                expected: Some(literal.into()),
+                            }
+                        }
                         _ => {
-                            return Err(Error::AttributeExpectedIntegerLiteral {
-                                location: sender.location,
-                                name: "sender".to_owned(),
+                            return Err(Error::AttributeExpectedStringLiteral {
+                                location: expected.location,
+                                name,
                             })
                         }
-                    };
-                    if sender.bitlength > zinc_const::bitlength::ETH_ADDRESS {
-                        return Err(Error::InvalidInteger {
-                            location: sender.location,
-                            inner: zinc_math::Error::Overflow {
-                                value: sender.value,
-                                is_signed: sender.is_signed,
-                                bitlength: zinc_const::bitlength::ETH_ADDRESS,
-                            },
+                    }
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedNested {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "ignore" => Self::Ignore,
+            "proof" => Self::Proof,
+            "upgrade" => Self::Upgrade,
+            "bitflags" => Self::Bitflags,
+            "only_owner" => Self::RequireSender {
+                field: Self::ONLY_OWNER_FIELD.to_owned(),
+                location: value.location,
+            },
+            "require_sender" => match element.variant {
+                Some(SyntaxAttributeElementVariant::Nested(ref mut nested)) => {
+                    if nested.len() != 1 {
+                        return Err(Error::AttributeElementsCount {
+                            location: element.location,
+                            name: identifier,
+                            expected: 1,
+                            found: nested.len(),
                         });
                     }
 
-                    let recipient = nested.remove(0);
-                    let name = recipient.path.to_string();
-                    if name.as_str() != "recipient" {
+                    let field = nested.remove(0);
+                    let name = field.path.to_string();
+                    if field.variant.is_some() {
                         return Err(Error::AttributeExpectedElement {
-                            location: recipient.location,
-                            name: "zksync::msg".to_owned(),
-                            position: 2,
-                            expected: "recipient".to_owned(),
+                            location: field.location,
+                            name: identifier,
+                            position: 1,
+                            expected: "a storage field name".to_owned(),
                             found: name,
                         });
                     }
-                    let recipient = match recipient.variant {
-                        Some(SyntaxAttributeElementVariant::Value(Literal::Integer(
-                            ref integer,
-                        ))) => IntegerConstant::try_from(integer)?,
-                        _ => {
-                            return Err(Error::AttributeExpectedIntegerLiteral {
-                                location: recipient.location,
-                                name: "recipient".to_owned(),
-                            })
-                        }
-                    };
-                    if recipient.bitlength > zinc_const::bitlength::ETH_ADDRESS {
-                        return Err(Error::InvalidInteger {
-                            location: recipient.location,
-                            inner: zinc_math::Error::Overflow {
-                                value: recipient.value,
-                                is_signed: recipient.is_signed,
-                                bitlength: zinc_const::bitlength::ETH_ADDRESS,
-                            },
-                        });
-                    }
 
-                    let token_address = nested.remove(0);
-                    let name = token_address.path.to_string();
-                    if name.as_str() != "token_address" {
-                        return Err(Error::AttributeExpectedElement {
-                            location: token_address.location,
-                            name: "zksync::msg".to_owned(),
-                            position: 3,
-                            expected: "token_address".to_owned(),
-                            found: name,
-                        });
+                    Self::RequireSender {
+                        field: name,
+                        location: value.location,
                     }
-                    let token_address = match token_address.variant {
-                        Some(SyntaxAttributeElementVariant::Value(Literal::Integer(
-                            ref integer,
-                        ))) => IntegerConstant::try_from(integer)?,
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedNested {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "ensures" => match element.variant.take() {
+                Some(SyntaxAttributeElementVariant::Expression(expression)) => Self::Ensures {
+                    expression,
+                    location: value.location,
+                },
+                _ => {
+                    return Err(Error::AttributeExpectedExpression {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "max_iterations" => match element.variant.take() {
+                Some(SyntaxAttributeElementVariant::Expression(expression)) => {
+                    Self::MaxIterations {
+                        expression,
+                        location: value.location,
+                    }
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedExpression {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "invariant" => match element.variant.take() {
+                Some(SyntaxAttributeElementVariant::Expression(expression)) => Self::Invariant {
+                    expression,
+                    location: value.location,
+                },
+                _ => {
+                    return Err(Error::AttributeExpectedExpression {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "max_depth" => match element.variant {
+                Some(SyntaxAttributeElementVariant::Value(Literal::Integer(ref integer))) => {
+                    let depth = IntegerConstant::try_from(integer)?;
+                    match depth.value.to_usize() {
+                        Some(depth) if depth > 0 => Self::MaxDepth(depth),
                         _ => {
-                            return Err(Error::AttributeExpectedIntegerLiteral {
-                                location: token_address.location,
-                                name: "token_address".to_owned(),
+                            return Err(Error::AttributeMaxDepthInvalid {
+                                location: element.location,
+                                found: depth.value.to_string(),
                             })
                         }
-                    };
-                    if token_address.bitlength > zinc_const::bitlength::ETH_ADDRESS {
-                        return Err(Error::InvalidInteger {
-                            location: token_address.location,
-                            inner: zinc_math::Error::Overflow {
-                                value: token_address.value,
-                                is_signed: token_address.is_signed,
-                                bitlength: zinc_const::bitlength::ETH_ADDRESS,
-                            },
-                        });
-                    }
-
-                    let amount = nested.remove(0);
-                    let name = amount.path.to_string();
-                    if name.as_str() != "amount" {
-                        return Err(Error::AttributeExpectedElement {
-                            location: amount.location,
-                            name: "zksync::msg".to_owned(),
-                            position: 4,
-                            expected: "amount".to_owned(),
-                            found: name,
-                        });
                     }
-                    let amount = match amount.variant {
-                        Some(SyntaxAttributeElementVariant::Value(Literal::Integer(
-                            ref integer,
-                        ))) => IntegerConstant::try_from(integer)?,
-                        _ => {
-                            return Err(Error::AttributeExpectedIntegerLiteral {
-                                location: amount.location,
-                                name: "amount".to_owned(),
-                            })
+                }
+                Some(SyntaxAttributeElementVariant::NegativeValue(ref integer)) => {
+                    let depth = IntegerConstant::try_from(integer)?;
+                    return Err(Error::AttributeMaxDepthInvalid {
+                        location: element.location,
+                        found: (-depth.value).to_string(),
+                    });
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedIntegerLiteral {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "zksync::msg" => match element.variant.take() {
+                Some(SyntaxAttributeElementVariant::Nested(nested)) => {
+                    let mut fields = HashMap::with_capacity(nested.len());
+                    for field in nested.into_iter() {
+                        let name = field.path.to_string();
+                        if !Self::ZKSYNC_MSG_FIELDS.contains(&name.as_str()) {
+                            return Err(Error::AttributeElementUnknown {
+                                location: field.location,
+                                name: identifier,
+                                found: name,
+                                expected: Self::ZKSYNC_MSG_FIELDS
+                                    .iter()
+                                    .map(|field| field.to_string())
+                                    .collect(),
+                            });
+                        }
+                        if fields.insert(name.clone(), field).is_some() {
+                            return Err(Error::AttributeElementDuplicate {
+                                location: element.location,
+                                name: identifier,
+                                element: name,
+                            });
                         }
-                    };
-                    if amount.bitlength > zinc_const::bitlength::BALANCE {
-                        return Err(Error::InvalidInteger {
-                            location: amount.location,
-                            inner: zinc_math::Error::Overflow {
-                                value: amount.value,
-                                is_signed: amount.is_signed,
-                                bitlength: zinc_const::bitlength::BALANCE,
-                            },
+                    }
+
+                    if fields.len() != zinc_const::contract::TRANSACTION_FIELDS_COUNT {
+                        return Err(Error::AttributeElementsMissing {
+                            location: element.location,
+                            name: identifier,
+                            missing: Self::ZKSYNC_MSG_FIELDS
+                                .iter()
+                                .filter(|field| !fields.contains_key(**field))
+                                .map(|field| field.to_string())
+                                .collect(),
                         });
                     }
 
-                    Self::ZksyncMsg(zinc_types::TransactionMsg::new_from_bigints(
-                        sender.value,
-                        recipient.value,
-                        token_address.value,
-                        amount.value,
-                    ))
+                    let [sender, recipient, token_address, amount] = Self::ZKSYNC_MSG_FIELDS;
+                    let sender = Self::zksync_msg_field(
+                        fields.remove(sender),
+                        zinc_const::bitlength::ETH_ADDRESS,
+                    )?;
+                    let recipient = Self::zksync_msg_field(
+                        fields.remove(recipient),
+                        zinc_const::bitlength::ETH_ADDRESS,
+                    )?;
+                    let token_address = Self::zksync_msg_field(
+                        fields.remove(token_address),
+                        zinc_const::bitlength::ETH_ADDRESS,
+                    )?;
+                    let amount = Self::zksync_msg_field(
+                        fields.remove(amount),
+                        zinc_const::bitlength::BALANCE,
+                    )?;
+
+                    Self::ZksyncMsg {
+                        msg: zinc_types::TransactionMsg::new_from_bigints(
+                            sender,
+                            recipient,
+                            token_address,
+                            amount,
+                        ),
+                        location: value.location,
+                    }
                 }
                 _ => {
                     return Err(Error::AttributeExpectedNested {
                         location: element.location,
-                        name: "zksync::msg".to_owned(),
+                        name: identifier,
                     })
                 }
             },
@@ -0,0 +1,176 @@
+//!
+//! The semantic module attribute.
+//!
+
+use std::convert::TryFrom;
+
+use zinc_lexical::Location;
+use zinc_syntax::Attribute as SyntaxAttribute;
+use zinc_syntax::AttributeElementVariant as SyntaxAttributeElementVariant;
+use zinc_syntax::Literal;
+
+use crate::semantic::error::Error;
+use crate::semantic::lint::Lint;
+
+///
+/// The semantic module attribute, which is written in the inner form `#![...]` at the beginning
+/// of the module file.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Attribute {
+    /// The `#![allow(lint, ...)]` attribute, which silences the lint warnings in the module.
+    Allow(Vec<Lint>),
+    /// The `#![zinc(version = "X.Y.Z")]` attribute, which declares the minimal language version.
+    Zinc {
+        /// The declared language version.
+        version: semver::Version,
+        /// The attribute location, which is reported if the version is not supported.
+        location: Location,
+    },
+}
+
+impl Attribute {
+    ///
+    /// Checks the attribute against the compiler, e.g. the declared language version must not
+    /// be newer than the compiler one.
+    ///
+    pub fn check(&self) -> Result<(), Error> {
+        match self {
+            Self::Allow(_) => Ok(()),
+            Self::Zinc { version, location } => {
+                let supported = semver::Version::parse(env!("CARGO_PKG_VERSION"))
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+                if version > &supported {
+                    return Err(Error::AttributeVersionUnsupported {
+                        location: *location,
+                        found: version.to_string(),
+                        supported: supported.to_string(),
+                    });
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Parses the `major[.minor[.patch]]` version, where the omitted parts are zeros.
+    ///
+    fn parse_version(value: &str) -> Option<semver::Version> {
+        let parts = value
+            .split('.')
+            .map(|part| part.parse::<u64>().ok())
+            .collect::<Option<Vec<u64>>>()?;
+
+        match parts.as_slice() {
+            [major] => Some(semver::Version::new(*major, 0, 0)),
+            [major, minor] => Some(semver::Version::new(*major, *minor, 0)),
+            [major, minor, patch] => Some(semver::Version::new(*major, *minor, *patch)),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<SyntaxAttribute> for Attribute {
+    type Error = Error;
+
+    fn try_from(mut value: SyntaxAttribute) -> Result<Self, Self::Error> {
+        let element = value.elements.get_mut(0).ok_or(Error::AttributeEmpty {
+            location: value.location,
+        })?;
+        let identifier = element.path.to_string();
+
+        Ok(match identifier.as_str() {
+            "allow" => match element.variant.take() {
+                Some(SyntaxAttributeElementVariant::Nested(nested)) => {
+                    let mut lints = Vec::with_capacity(nested.len());
+                    for (index, entry) in nested.into_iter().enumerate() {
+                        let name = entry.path.to_string();
+                        if entry.variant.is_some() {
+                            return Err(Error::AttributeExpectedElement {
+                                location: entry.location,
+                                name: identifier,
+                                position: index + 1,
+                                expected: "a lint name".to_owned(),
+                                found: name,
+                            });
+                        }
+
+                        match name.parse::<Lint>() {
+                            Ok(lint) => lints.push(lint),
+                            Err(name) => {
+                                log::warn!("{}: unknown lint `{}`", entry.location, name)
+                            }
+                        }
+                    }
+
+                    Self::Allow(lints)
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedNested {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            "zinc" => match element.variant {
+                Some(SyntaxAttributeElementVariant::Nested(ref mut nested)) => {
+                    if nested.len() != 1 {
+                        return Err(Error::AttributeElementsCount {
+                            location: element.location,
+                            name: identifier,
+                            expected: 1,
+                            found: nested.len(),
+                        });
+                    }
+
+                    let version = nested.remove(0);
+                    let name = version.path.to_string();
+                    if name.as_str() != "version" {
+                        return Err(Error::AttributeExpectedElement {
+                            location: version.location,
+                            name: identifier,
+                            position: 1,
+                            expected: "version".to_owned(),
+                            found: name,
+                        });
+                    }
+
+                    match version.variant {
+                        Some(SyntaxAttributeElementVariant::Value(Literal::String(literal))) => {
+                            let literal: String = literal.into();
+                            Self::Zinc {
+                                version: Self::parse_version(literal.as_str()).ok_or(
+                                    Error::AttributeVersionInvalid {
+                                        location: version.location,
+                                        found: literal,
+                                    },
+                                )?,
+                                location: value.location,
+                            }
+                        }
+                        _ => {
+                            return Err(Error::AttributeExpectedStringLiteral {
+                                location: version.location,
+                                name,
+                            })
+                        }
+                    }
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedNested {
+                        location: element.location,
+                        name: identifier,
+                    })
+                }
+            },
+            _ => {
+                return Err(Error::AttributeUnexpected {
+                    location: value.location,
+                    name: identifier,
+                    item: "module".to_owned(),
+                })
+            }
+        })
+    }
+}
@@ -2,9 +2,16 @@
 //! The attribute tests.
 //!
 
+use std::convert::TryFrom;
+
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 
 use crate::error::Error;
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::element::r#type::function::intrinsic::cfg::values::Values as CfgValues;
 use crate::semantic::error::Error as SemanticError;
 
 #[test]
@@ -24,6 +31,7 @@ fn ok_should_panic() {
     let input = r#"
 fn main() {}
 
+#[test]
 #[should_panic]
 fn test() {}
 "#;
@@ -31,6 +39,86 @@ fn test() {}
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_should_panic_expected() {
+    let input = r#"
+fn main() {}
+
+#[test]
+#[should_panic(expected = "too low")]
+fn test() {
+    require(false, "The balance is too low");
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_should_panic_without_test() {
+    let input = r#"
+fn main() {}
+
+#[should_panic(expected = "too low")]
+fn check() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(4, 1),
+        name: "should_panic".to_owned(),
+        item: "function without `#[test]`".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_should_panic_expected_element() {
+    let input = r#"
+fn main() {}
+
+#[test]
+#[should_panic(message = "too low")]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedElement {
+        location: Location::test(5, 16),
+        name: "should_panic".to_owned(),
+        position: 1,
+        expected: "expected".to_owned(),
+        found: "message".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_should_panic_expected_string_literal() {
+    let input = r#"
+fn main() {}
+
+#[test]
+#[should_panic(expected = 42)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::AttributeExpectedStringLiteral {
+            location: Location::test(5, 16),
+            name: "expected".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn ok_ignore() {
     let input = r#"
@@ -43,6 +131,19 @@ fn test() {}
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_proof() {
+    let input = r#"
+fn main() {}
+
+#[test]
+#[proof]
+fn test() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn ok_multiple() {
     let input = r#"
@@ -112,23 +213,146 @@ fn test() {}
 }
 
 #[test]
-fn error_elements_count_zksync_msg() {
+fn error_elements_missing_zksync_msg() {
     let input = r#"
 fn main() {}
 
 #[zksync::msg(
     sender = 0x0001,
+    token_address = 0x0003,
+)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementsMissing {
+        location: Location::test(4, 3),
+        name: "zksync::msg".to_owned(),
+        missing: vec!["recipient".to_owned(), "amount".to_owned()],
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_zksync_msg_hexadecimal() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    sender = 0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef,
+    recipient = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF,
+    token_address = 0x0000000000000000000000000000000000000000,
+    amount = 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff,
+)]
+fn test() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_zksync_msg_hexadecimal_values() {
+    let input = r#"
+#[zksync::msg(
+    sender = 0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef,
     recipient = 0x0002,
+    token_address = 0x0,
+    amount = 0x0de0b6b3a7640000,
+)]
+fn test() {}
+"#;
+
+    let expected = Attribute::ZksyncMsg {
+        msg: zinc_types::TransactionMsg::new_from_bigints(
+            zinc_math::bigint_from_str("0xdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef")
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            BigInt::from(2),
+            BigInt::zero(),
+            BigInt::from(1_000_000_000_000_000_000_u64),
+        ),
+        location: Location::test(2, 1),
+    };
+
+    let module = zinc_syntax::Parser::default()
+        .parse(input, 0)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let attribute = match module.statements.into_iter().next() {
+        Some(zinc_syntax::ModuleLocalStatement::Fn(mut statement)) => {
+            statement.attributes.remove(0)
+        }
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    };
+
+    assert_eq!(Attribute::try_from(attribute), Ok(expected));
+}
+
+#[test]
+fn ok_zksync_msg_reordered() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    amount = 1.0_E18,
     token_address = 0x0003,
+    sender = 0x0001,
+    recipient = 0x0002,
 )]
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::AttributeElementsCount {
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_element_duplicate_zksync_msg() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    sender = 0x0001,
+    recipient = 0x0002,
+    sender = 0x0003,
+    amount = 1000,
+)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementDuplicate {
         location: Location::test(4, 3),
         name: "zksync::msg".to_owned(),
-        expected: zinc_const::contract::TRANSACTION_FIELDS_COUNT,
-        found: zinc_const::contract::TRANSACTION_FIELDS_COUNT - 1,
+        element: "sender".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_element_unknown_zksync_msg_extra() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    sender = 0x0001,
+    recipient = 0x0002,
+    token_address = 0x0003,
+    amount = 1000,
+    nonce = 1,
+)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementUnknown {
+        location: Location::test(9, 5),
+        name: "zksync::msg".to_owned(),
+        found: "nonce".to_owned(),
+        expected: Attribute::ZKSYNC_MSG_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -156,7 +380,7 @@ fn test() {}
 }
 
 #[test]
-fn error_expected_element_zksync_msg_sender() {
+fn error_element_unknown_zksync_msg_sender() {
     let input = r#"
 fn main() {}
 
@@ -169,12 +393,14 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedElement {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementUnknown {
         location: Location::test(5, 5),
         name: "zksync::msg".to_owned(),
-        position: 1,
-        expected: "sender".to_owned(),
         found: "unknown".to_owned(),
+        expected: Attribute::ZKSYNC_MSG_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -222,14 +448,10 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::InvalidInteger {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementOverflow {
         location: Location::test(5, 14),
-        inner: zinc_math::Error::Overflow {
-            value: zinc_math::bigint_from_str("0x10000000000000000000000000000000000000000")
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            is_signed: false,
-            bitlength: zinc_const::bitlength::ETH_ADDRESS,
-        },
+        name: "sender".to_owned(),
+        bitlength: zinc_const::bitlength::ETH_ADDRESS,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -238,7 +460,7 @@ fn test() {}
 }
 
 #[test]
-fn error_expected_element_zksync_msg_recipient() {
+fn error_element_unknown_zksync_msg_recipient() {
     let input = r#"
 fn main() {}
 
@@ -251,12 +473,14 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedElement {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementUnknown {
         location: Location::test(6, 5),
         name: "zksync::msg".to_owned(),
-        position: 2,
-        expected: "recipient".to_owned(),
         found: "unknown".to_owned(),
+        expected: Attribute::ZKSYNC_MSG_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -304,14 +528,10 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::InvalidInteger {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementOverflow {
         location: Location::test(6, 17),
-        inner: zinc_math::Error::Overflow {
-            value: zinc_math::bigint_from_str("0x10000000000000000000000000000000000000000")
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            is_signed: false,
-            bitlength: zinc_const::bitlength::ETH_ADDRESS,
-        },
+        name: "recipient".to_owned(),
+        bitlength: zinc_const::bitlength::ETH_ADDRESS,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -320,7 +540,7 @@ fn test() {}
 }
 
 #[test]
-fn error_expected_element_zksync_msg_token_address() {
+fn error_element_unknown_zksync_msg_token_address() {
     let input = r#"
 fn main() {}
 
@@ -333,12 +553,14 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedElement {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementUnknown {
         location: Location::test(7, 5),
         name: "zksync::msg".to_owned(),
-        position: 3,
-        expected: "token_address".to_owned(),
         found: "unknown".to_owned(),
+        expected: Attribute::ZKSYNC_MSG_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -386,14 +608,10 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::InvalidInteger {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementOverflow {
         location: Location::test(7, 21),
-        inner: zinc_math::Error::Overflow {
-            value: zinc_math::bigint_from_str("0x10000000000000000000000000000000000000000")
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            is_signed: false,
-            bitlength: zinc_const::bitlength::ETH_ADDRESS,
-        },
+        name: "token_address".to_owned(),
+        bitlength: zinc_const::bitlength::ETH_ADDRESS,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -402,7 +620,7 @@ fn test() {}
 }
 
 #[test]
-fn error_expected_element_zksync_msg_amount() {
+fn error_element_unknown_zksync_msg_amount() {
     let input = r#"
 fn main() {}
 
@@ -415,12 +633,14 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedElement {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementUnknown {
         location: Location::test(8, 5),
         name: "zksync::msg".to_owned(),
-        position: 4,
-        expected: "amount".to_owned(),
         found: "unknown".to_owned(),
+        expected: Attribute::ZKSYNC_MSG_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -468,16 +688,476 @@ fn main() {}
 fn test() {}
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::InvalidInteger {
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementOverflow {
         location: Location::test(8, 14),
-        inner: zinc_math::Error::Overflow {
-            value: zinc_math::bigint_from_str(
-                "0x100000000000000000000000000000000000000000000000000000000000000",
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID),
-            is_signed: false,
-            bitlength: zinc_const::bitlength::BALANCE,
+        name: "amount".to_owned(),
+        bitlength: zinc_const::bitlength::BALANCE,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_negative_zksync_msg_sender() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    sender = -1,
+    recipient = 0x0002,
+    token_address = 0x0003,
+    amount = 1,
+)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeNegativeValue {
+        location: Location::test(5, 5),
+        name: "sender".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_negative_zksync_msg_amount() {
+    let input = r#"
+fn main() {}
+
+#[zksync::msg(
+    sender = 0x0001,
+    recipient = 0x0002,
+    token_address = 0x0003,
+    amount = -1,
+)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeNegativeValue {
+        location: Location::test(8, 5),
+        name: "amount".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_elements_count_require_sender() {
+    let input = r#"
+fn main() {}
+
+#[require_sender(owner, admin)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeElementsCount {
+        location: Location::test(4, 3),
+        name: "require_sender".to_owned(),
+        expected: 1,
+        found: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_nested_require_sender() {
+    let input = r#"
+fn main() {}
+
+#[require_sender]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedNested {
+        location: Location::test(4, 3),
+        name: "require_sender".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_max_depth() {
+    let input = r#"
+fn main() -> u8 { factorial(5) }
+
+#[max_depth(10)]
+fn factorial(value: u8) -> u8 {
+    if value < 2 { 1 } else { value * factorial(value - 1) }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_max_depth_zero() {
+    let input = r#"
+fn main() {}
+
+#[max_depth(0)]
+fn recursive() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeMaxDepthInvalid {
+        location: Location::test(4, 3),
+        found: "0".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_integer_literal_max_depth() {
+    let input = r#"
+fn main() {}
+
+#[max_depth]
+fn recursive() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::AttributeExpectedIntegerLiteral {
+            location: Location::test(4, 3),
+            name: "max_depth".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_max_depth_negative() {
+    let input = r#"
+fn main() {}
+
+#[max_depth(-10)]
+fn recursive() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeMaxDepthInvalid {
+        location: Location::test(4, 3),
+        found: "-10".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_bitflags_function() {
+    let input = r#"
+fn main() {}
+
+#[bitflags]
+fn flags() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(4, 1),
+        name: "bitflags".to_owned(),
+        item: "function".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_test_enumeration() {
+    let input = r#"
+fn main() {}
+
+#[test]
+enum List {
+    A = 1,
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(4, 1),
+        name: "test".to_owned(),
+        item: "enumeration".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_ensures() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result <= limit)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    if value > limit { limit } else { value }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_ensures_multiple() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result <= limit)]
+#[ensures(result >= value || result == limit)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    if value > limit { limit } else { value }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_ensures_without_result() {
+    let input = r#"
+fn main() {}
+
+#[ensures(value > 0)]
+fn check(value: u8) {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expected_expression_ensures() {
+    let input = r#"
+fn main() {}
+
+#[ensures]
+fn clamp(value: u8, limit: u8) -> u8 {
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::AttributeExpectedExpression {
+            location: Location::test(4, 3),
+            name: "ensures".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_expression_invariant() {
+    let input = r#"
+#[invariant]
+contract Test {
+    value: u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::AttributeExpectedExpression {
+            location: Location::test(2, 3),
+            name: "invariant".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ensures_expected_boolean() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result + 1)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionEnsuresExpectedBoolean {
+            location: Location::test(4, 18),
+            function: "clamp".to_owned(),
+            found: "u8".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ensures_expected_boolean_release() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result + 1)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    value
+}
+"#;
+
+    CfgValues::set(
+        vec![(
+            zinc_const::cfg::PROFILE.to_owned(),
+            zinc_const::cfg::PROFILE_RELEASE.to_owned(),
+        )]
+        .into_iter()
+        .collect(),
+    );
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionEnsuresExpectedBoolean {
+            location: Location::test(4, 18),
+            function: "clamp".to_owned(),
+            found: "u8".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_ensures_result_without_return_type() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result > 0)]
+fn check(value: u8) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemUndeclared {
+        location: Location::test(4, 11),
+        name: "result".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_ensures_test() {
+    let input = r#"
+fn main() {}
+
+#[test]
+#[ensures(true)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(5, 1),
+        name: "ensures".to_owned(),
+        item: "unit test".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_module_shebang() {
+    let input = r#"#!/usr/bin/env zargo run
+#![allow(deprecated, linear_search)]
+#![zinc(version = "0.2")]
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_module_allow_unknown_lint() {
+    let input = r#"
+#![allow(dead_code)]
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_module_version_unsupported() {
+    let input = r#"
+#![zinc(version = "99.1")]
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::AttributeVersionUnsupported {
+            location: Location::test(2, 1),
+            found: "99.1.0".to_owned(),
+            supported: env!("CARGO_PKG_VERSION").to_owned(),
         },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_module_version_invalid() {
+    let input = r#"
+#![zinc(version = "0.two")]
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeVersionInvalid {
+        location: Location::test(2, 9),
+        found: "0.two".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unexpected_test_module() {
+    let input = r#"
+#![test]
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(2, 1),
+        name: "test".to_owned(),
+        item: "module".to_owned(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
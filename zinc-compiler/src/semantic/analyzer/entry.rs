@@ -6,6 +6,10 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+
+use crate::generator::statement::r#fn::role::Role as GeneratorFunctionRole;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::module::Module as ScopeModuleItem;
 use crate::semantic::scope::item::Item as ScopeItem;
@@ -51,4 +55,49 @@ impl Analyzer {
             panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
         }
     }
+
+    ///
+    /// Forcibly and recursively defines the test root module, which uses the project entry
+    /// `project_scope` as a dependency.
+    ///
+    pub fn define_test_root(
+        module: Source,
+        project: zinc_project::ManifestProject,
+        project_scope: Rc<RefCell<Scope>>,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        let scope =
+            ScopeModuleItem::declare_test_root(module, project, project_scope, dependencies)?;
+        Scope::get_module_self_alias(scope.clone())
+            .borrow()
+            .define()?;
+
+        Ok(scope)
+    }
+
+    ///
+    /// Checks that the fully qualified names of the unit tests in the application `intermediate`
+    /// representation are unique, since the tests are stored and reported by their names.
+    ///
+    pub fn check_unit_test_names(intermediate: &[GeneratorStatement]) -> Result<(), Error> {
+        let mut names: HashMap<&str, Location> = HashMap::with_capacity(intermediate.len());
+
+        for statement in intermediate.iter() {
+            if let GeneratorStatement::Fn(ref statement) = statement {
+                if let GeneratorFunctionRole::UnitTest = statement.role {
+                    if let Some(reference) =
+                        names.insert(statement.identifier.as_str(), statement.location)
+                    {
+                        return Err(Error::UnitTestNameDuplicate {
+                            location: statement.location,
+                            name: statement.identifier.to_owned(),
+                            reference,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
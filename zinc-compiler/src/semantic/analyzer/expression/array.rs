@@ -95,10 +95,12 @@ impl Analyzer {
                     ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
                         .analyze(expression)?;
                 let element_type = Type::from_element(&element, scope)?;
+                let element_size = element_type.size();
                 result.extend(element_type, size, element.location())?;
 
                 builder.push_expression(expression);
                 builder.set_size(size);
+                builder.set_element_size(element_size);
             }
         }
 
@@ -10,12 +10,16 @@ use zinc_syntax::FunctionLocalStatement;
 
 use crate::generator::expression::operand::block::builder::Builder as GeneratorBlockExpressionBuilder;
 use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::analyzer::statement::r#const::Analyzer as ConstStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#for::Analyzer as ForStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#let::Analyzer as LetStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#return::Analyzer as ReturnStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#while::Analyzer as WhileStatementAnalyzer;
+use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
@@ -60,6 +64,20 @@ impl Analyzer {
                 FunctionLocalStatement::For(statement) => Some(GeneratorStatement::For(
                     ForStatementAnalyzer::define(scope_stack.top(), statement)?,
                 )),
+                FunctionLocalStatement::While(statement) => Some(GeneratorStatement::While(
+                    WhileStatementAnalyzer::define(scope_stack.top(), statement)?,
+                )),
+                FunctionLocalStatement::Return(statement) => {
+                    if let TranslationRule::Constant = rule {
+                        return Err(Error::FunctionReturnStatementForbidden {
+                            location: statement.location,
+                        });
+                    }
+
+                    Some(GeneratorStatement::Return(
+                        ReturnStatementAnalyzer::analyze(scope_stack.top(), statement)?,
+                    ))
+                }
                 FunctionLocalStatement::Expression(expression) => {
                     let (_result, expression) =
                         ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(expression)?;
@@ -79,11 +97,27 @@ impl Analyzer {
                 let (element, expression) =
                     ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(*expression)?;
                 builder.set_expression(expression);
+
+                let value_type = match element {
+                    Element::Value(ref value) => GeneratorType::try_from_semantic(&value.r#type()),
+                    Element::Constant(ref constant) => {
+                        GeneratorType::try_from_semantic(&constant.r#type())
+                    }
+                    _ => None,
+                };
+                if let Some(value_type) = value_type {
+                    builder.set_value_type(value_type);
+                }
+
                 element
             }
             None => Element::Value(Value::Unit(UnitValue::new(Some(block.location)))),
         };
 
+        if let Some(location) = RefCell::borrow(&scope_stack.top()).get_unconstrained_location() {
+            return Err(Error::UnsafeWitnessUnconstrained { location });
+        }
+
         scope_stack.pop();
 
         Ok((element, builder.finish()))
@@ -14,12 +14,18 @@ use crate::generator::expression::operand::constant::Constant as GeneratorConsta
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::r#type::contract_field::ContractField as GeneratorContractField;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get_or::Function as MTreeMapGetOrFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert_if_absent::Function as MTreeMapInsertIfAbsentFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::Function as StandardLibraryFunction;
 use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
 
@@ -36,12 +42,16 @@ impl Analyzer {
     ///
     /// Returns the semantic element and the intermediate representation.
     ///
+    /// If the `rule` is constant, the standard library calls which support constant folding
+    /// are evaluated at compile time.
+    ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
         operand_1: Element,
         operand_2: Element,
         call_type: CallType,
         location: Location,
+        rule: TranslationRule,
     ) -> Result<(Element, GeneratorExpressionElement), Error> {
         let function_location = operand_1.location();
 
@@ -83,28 +93,63 @@ impl Analyzer {
 
         let is_called_with_exclamation_mark = matches!(call_type, CallType::MacroLike);
 
-        if let CallType::Method {
-            instance,
-            is_mutable,
-        } = call_type
-        {
+        let mut instance_place = None;
+        if let CallType::Method { instance, place } = call_type {
             argument_list.arguments.insert(0, *instance);
 
-            if !is_mutable && function.is_mutable() {
-                return Err(Error::FunctionCallMutableFromImmutable {
-                    location,
-                    function: function.identifier(),
-                });
+            if let Some(place) = place {
+                if function.is_mutable() {
+                    if !place.is_mutable {
+                        return Err(Error::FunctionCallMutableFromImmutable {
+                            location,
+                            function: function.identifier(),
+                            place: place.to_string(),
+                            binding: place.identifier.name,
+                        });
+                    }
+                    if let Some(name) = place.check_immutable_field() {
+                        return Err(Error::MutatingImmutableContractField {
+                            location: place.identifier.location,
+                            name,
+                            place: place.to_string(),
+                        });
+                    }
+                    if RefCell::borrow(&scope).is_within_unsafe_witness()
+                        && RefCell::borrow(&scope).is_beyond_unsafe_witness(&place.identifier)
+                    {
+                        let reference = RefCell::borrow(&scope)
+                            .resolve_item(&place.identifier, true)?
+                            .borrow()
+                            .location();
+
+                        return Err(Error::UnsafeWitnessMutatingOuterMemory {
+                            location,
+                            place: place.to_string(),
+                            name: place.identifier.name,
+                            reference,
+                        });
+                    }
+                }
+
+                instance_place = Some(*place);
             }
         }
 
-        let mut input_size = 0;
+        let mut argument_sizes = Vec::with_capacity(argument_list.arguments.len());
         for element in argument_list.arguments.iter() {
-            input_size += Type::from_element(element, scope.clone())?.size();
+            argument_sizes.push(Type::from_element(element, scope.clone())?.size());
         }
+        let input_size = argument_sizes.iter().sum();
 
         let (element, intermediate) = match function {
             FunctionType::Intrinsic(function) => {
+                if let Some(deprecation) = function.deprecation() {
+                    let location = function_location.unwrap_or(location);
+                    if LINT_LEVELS.is_enabled(Lint::Deprecated, location) {
+                        log::warn!("{}", deprecation.warning(location));
+                    }
+                }
+
                 if function.requires_exclamation_mark() && !is_called_with_exclamation_mark {
                     return Err(Error::FunctionExpectedExclamationMark {
                         location: function_location.unwrap_or(location),
@@ -112,6 +157,31 @@ impl Analyzer {
                     });
                 }
 
+                if let IntrinsicFunctionType::ContractFetch(_)
+                | IntrinsicFunctionType::ContractTransfer(_) = function
+                {
+                    if RefCell::borrow(&scope).is_within_constructor() {
+                        return Err(Error::ContractConstructorExternalState {
+                            location: function_location.unwrap_or(location),
+                            found: function.identifier().to_owned(),
+                        });
+                    }
+                }
+
+                if let IntrinsicFunctionType::AssertEq(_)
+                | IntrinsicFunctionType::AssertStorageUnchanged(_)
+                | IntrinsicFunctionType::StandardLibrary(StandardLibraryFunction::TestRandU(
+                    _,
+                )) = function
+                {
+                    if !RefCell::borrow(&scope).is_within_unit_test() {
+                        return Err(Error::UnitTestAssertionBeyondUnitTest {
+                            location: function_location.unwrap_or(location),
+                            function: function.identifier().to_owned(),
+                        });
+                    }
+                }
+
                 match function {
                     IntrinsicFunctionType::Debug(function) => {
                         let (return_type, format, argument_types) =
@@ -131,6 +201,24 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::Cfg(function) => {
+                        let constant =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        // the string values only exist at compile time, so nothing is written
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .unwrap_or_else(|| {
+                                GeneratorExpressionOperand::Constant(GeneratorConstant::Group(
+                                    vec![],
+                                ))
+                            });
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
                     IntrinsicFunctionType::Require(function) => {
                         let (return_type, message) =
                             function.call(function_location.unwrap_or(location), argument_list)?;
@@ -148,6 +236,42 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::AssertEq(function) => {
+                        let (return_type, compared_type, message) =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate =
+                            GeneratorExpressionOperator::call_assert_eq(compared_type, message);
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::AssertStorageUnchanged(function) => {
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate =
+                            GeneratorExpressionOperator::call_assert_storage_unchanged();
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
                     IntrinsicFunctionType::ContractFetch(function) => {
                         let return_type =
                             function.call(function_location.unwrap_or(location), argument_list)?;
@@ -198,6 +322,190 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::EnumerationFrom(function)
+                        if matches!(rule, TranslationRule::Constant) =>
+                    {
+                        let constant = function
+                            .call_constant(function_location.unwrap_or(location), argument_list)?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
+                    IntrinsicFunctionType::EnumerationFrom(function) => {
+                        let enumeration = function.enumeration.clone();
+
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate = GeneratorExpressionOperator::call_enumeration_from(
+                            enumeration.bitlength,
+                            enumeration
+                                .names
+                                .into_iter()
+                                .zip(enumeration.values)
+                                .collect(),
+                        );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::FlagsContains(function)
+                        if matches!(rule, TranslationRule::Constant) =>
+                    {
+                        let constant = function
+                            .call_constant(function_location.unwrap_or(location), argument_list)?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
+                    IntrinsicFunctionType::FlagsContains(function) => {
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate = GeneratorExpressionOperator::call_flags_contains();
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::FlagsIsEmpty(function)
+                        if matches!(rule, TranslationRule::Constant) =>
+                    {
+                        let constant = function
+                            .call_constant(function_location.unwrap_or(location), argument_list)?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
+                    IntrinsicFunctionType::FlagsIsEmpty(function) => {
+                        let bitlength = function.enumeration.bitlength;
+
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate =
+                            GeneratorExpressionOperator::call_flags_is_empty(bitlength);
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::StandardLibrary(
+                        StandardLibraryFunction::CollectionsMTreeMapGetOr(function),
+                    ) => {
+                        if is_called_with_exclamation_mark {
+                            return Err(Error::FunctionUnexpectedExclamationMark {
+                                location: function_location.unwrap_or(location),
+                                function: function.identifier.to_owned(),
+                            });
+                        }
+
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate = GeneratorExpressionOperator::call_mtreemap_get_or(
+                            argument_sizes[MTreeMapGetOrFunction::ARGUMENT_INDEX_KEY],
+                            return_type.size(),
+                        );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::StandardLibrary(
+                        StandardLibraryFunction::CollectionsMTreeMapInsertIfAbsent(function),
+                    ) => {
+                        if is_called_with_exclamation_mark {
+                            return Err(Error::FunctionUnexpectedExclamationMark {
+                                location: function_location.unwrap_or(location),
+                                function: function.identifier.to_owned(),
+                            });
+                        }
+
+                        let return_type =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let element =
+                            Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                        let intermediate =
+                            GeneratorExpressionOperator::call_mtreemap_insert_if_absent(
+                                argument_sizes[MTreeMapInsertIfAbsentFunction::ARGUMENT_INDEX_KEY],
+                                argument_sizes
+                                    [MTreeMapInsertIfAbsentFunction::ARGUMENT_INDEX_VALUE],
+                            );
+
+                        (
+                            element,
+                            GeneratorExpressionElement::Operator {
+                                location: function_location.unwrap_or(location),
+                                operator: intermediate,
+                            },
+                        )
+                    }
+                    IntrinsicFunctionType::StandardLibrary(function)
+                        if function.is_constant_evaluable()
+                            && matches!(rule, TranslationRule::Constant) =>
+                    {
+                        let constant = function
+                            .call_constant(function_location.unwrap_or(location), argument_list)?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .map(GeneratorExpressionOperand::Constant)
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(intermediate),
+                        )
+                    }
                     IntrinsicFunctionType::StandardLibrary(function) => {
                         if is_called_with_exclamation_mark {
                             return Err(Error::FunctionUnexpectedExclamationMark {
@@ -241,12 +549,22 @@ impl Analyzer {
                 let location = function.location;
                 let type_id = function.type_id;
 
+                let instance_size = function.written_back_instance_size();
+
                 let return_type = function.call(argument_list)?;
 
                 let element =
                     Value::try_from_type(&return_type, false, None).map(Element::Value)?;
 
-                let intermediate = GeneratorExpressionOperator::call(type_id, input_size);
+                let intermediate = match instance_size {
+                    Some(instance_size) => GeneratorExpressionOperator::call_mutable_method(
+                        type_id,
+                        input_size,
+                        instance_size,
+                        instance_place,
+                    ),
+                    None => GeneratorExpressionOperator::call(type_id, input_size),
+                };
 
                 (
                     element,
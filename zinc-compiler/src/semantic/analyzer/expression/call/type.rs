@@ -4,6 +4,7 @@
 
 use std::mem;
 
+use crate::semantic::element::place::Place;
 use crate::semantic::element::Element;
 
 ///
@@ -19,8 +20,9 @@ pub enum Type {
     Method {
         /// The `self` instance, for which the method is called.
         instance: Box<Element>,
-        /// Whether the instance, for which the method is called, is mutable.
-        is_mutable: bool,
+        /// The memory place of the instance, which the mutable method writes the instance back to.
+        /// `None` if the instance is a temporary value, which is always mutable.
+        place: Option<Box<Place>>,
     },
 }
 
@@ -34,10 +36,10 @@ impl Type {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new_method(instance: Element, is_mutable: bool) -> Self {
+    pub fn new_method(instance: Element, place: Option<Place>) -> Self {
         Self::Method {
             instance: Box::new(instance),
-            is_mutable,
+            place: place.map(Box::new),
         }
     }
 }
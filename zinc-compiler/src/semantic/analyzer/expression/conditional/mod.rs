@@ -10,8 +10,10 @@ use std::rc::Rc;
 
 use zinc_syntax::ConditionalExpression;
 
+use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
@@ -21,6 +23,7 @@ use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::assignment::Tracker as AssignmentTracker;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -53,6 +56,11 @@ impl Analyzer {
     ///
     /// Returns the runtime conditional semantic element and intermediate representation.
     ///
+    /// If the condition is a constant, both branches are still analyzed, but only the taken one
+    /// is translated into a plain block without the conditional instructions.
+    ///
+    /// If one of the branches always returns early, the conditional has the type of the other one.
+    ///
     fn runtime(
         scope: Rc<RefCell<Scope>>,
         conditional: ConditionalExpression,
@@ -95,43 +103,105 @@ impl Analyzer {
                 });
             }
         }
+        let constant_condition = match condition_result {
+            Element::Constant(Constant::Boolean(ref boolean)) => Some(boolean.is_true()),
+            _ => None,
+        };
         builder.set_condition(condition);
 
+        let mut assignment_tracker = AssignmentTracker::new(scope_stack.top());
+
+        let is_main_diverging = conditional.main_block.is_diverging();
+        let is_else_diverging = conditional
+            .else_block
+            .as_ref()
+            .map(|block| block.is_diverging())
+            .unwrap_or_default();
+
+        let main_block_location = conditional.main_block.location;
         scope_stack.push(None, ScopeType::Conditional);
-        let (main_result, main_block) = BlockAnalyzer::analyze(
+        let (main_result, mut main_block) = BlockAnalyzer::analyze(
             scope_stack.top(),
             conditional.main_block,
             TranslationRule::Value,
         )?;
         let main_type = Type::from_element(&main_result, scope_stack.top())?;
         scope_stack.pop();
-        builder.set_main_block(main_block);
+        assignment_tracker.branch(main_block_location);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
+        let mut else_block = None;
+        let mut else_result = None;
+        let else_type = if let Some(block) = conditional.else_block {
+            let else_block_location = block.location;
             scope_stack.push(None, ScopeType::Conditional);
-            let (else_result, else_block) =
-                BlockAnalyzer::analyze(scope_stack.top(), else_block, TranslationRule::Value)?;
-            let else_type = Type::from_element(&else_result, scope_stack.top())?;
+            let (result, block) =
+                BlockAnalyzer::analyze(scope_stack.top(), block, TranslationRule::Value)?;
+            let else_type = Type::from_element(&result, scope_stack.top())?;
             scope_stack.pop();
-            builder.set_else_block(else_block);
+            else_block = Some(block);
+            else_result = Some(result);
+            assignment_tracker.branch(else_block_location);
 
             else_type
         } else {
+            assignment_tracker.skip(conditional.location);
+
             Type::unit(None)
         };
+        assignment_tracker.finish();
 
-        if main_type != else_type {
-            return Err(Error::ConditionalBranchTypesMismatch {
-                location: main_expression_location,
-                expected: main_type.to_string(),
-                found: else_type.to_string(),
-                reference: else_expression_location,
-            });
-        }
-
-        let element = main_result;
+        let element = match (is_main_diverging, is_else_diverging) {
+            (true, false) => {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&else_type) {
+                    main_block.set_diverging_type(r#type);
+                }
+                else_result.unwrap_or(main_result)
+            }
+            (false, true) => {
+                if let (Some(r#type), Some(block)) = (
+                    GeneratorType::try_from_semantic(&main_type),
+                    else_block.as_mut(),
+                ) {
+                    block.set_diverging_type(r#type);
+                }
+                main_result
+            }
+            _ if main_type != else_type => {
+                return Err(Error::ConditionalBranchTypesMismatch {
+                    location: main_expression_location,
+                    expected: main_type.to_string(),
+                    found: else_type.to_string(),
+                    reference: else_expression_location,
+                });
+            }
+            _ => main_result,
+        };
 
-        let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
+        let intermediate = match constant_condition {
+            Some(true) => {
+                log::info!(
+                    "{}: the condition is always true, so the `else` branch is not generated",
+                    condition_location,
+                );
+                GeneratorExpressionOperand::Block(main_block)
+            }
+            Some(false) => {
+                log::info!(
+                    "{}: the condition is always false, so the main branch is not generated",
+                    condition_location,
+                );
+                GeneratorExpressionOperand::Block(
+                    else_block.unwrap_or_else(|| GeneratorBlockExpression::new(vec![], None)),
+                )
+            }
+            None => {
+                builder.set_main_block(main_block);
+                if let Some(else_block) = else_block {
+                    builder.set_else_block(else_block);
+                }
+                GeneratorExpressionOperand::Conditional(builder.finish())
+            }
+        };
 
         Ok((element, intermediate))
     }
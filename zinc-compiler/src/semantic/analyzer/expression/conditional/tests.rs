@@ -104,3 +104,71 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_constant_condition() {
+    let input = r#"
+const DEBUG: bool = false;
+
+fn main(value: u8) -> u8 {
+    if DEBUG {
+        dbg!("{}", value);
+        value * 2
+    } else {
+        value
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_constant_condition_dead_branch_checked() {
+    let input = r#"
+const DEBUG: bool = false;
+
+fn main() -> u8 {
+    if DEBUG {
+        if 42 { 1 } else { 2 }
+    } else {
+        3
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalExpectedBooleanCondition {
+            location: Location::test(6, 12),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_condition_dead_branch_types_mismatch() {
+    let input = r#"
+const DEBUG: bool = true;
+
+fn main() {
+    if DEBUG { 42 } else { false }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::test(5, 16),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(5, 28),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
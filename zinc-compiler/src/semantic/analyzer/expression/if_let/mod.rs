@@ -0,0 +1,109 @@
+//!
+//! The `if let` expression semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::BlockExpression;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::IfLetExpression;
+use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::semantic::analyzer::expression::r#match::Analyzer as MatchAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `if let` expression semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `if let` expression.
+    ///
+    /// The expression is desugared into a `match` with the pattern branch and the wildcard one,
+    /// so the pattern bindings are only visible in the main block, and the intermediate
+    /// representation is the same as of the equivalent `match` expression.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        if_let: IfLetExpression,
+        rule: TranslationRule,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let location = if_let.location;
+
+        let main_expression_location = if_let
+            .main_block
+            .expression
+            .as_ref()
+            .map(|expression| expression.location)
+            .unwrap_or(if_let.main_block.location);
+        let else_expression_location = if_let
+            .else_block
+            .as_ref()
+            .map(|block| {
+                block
+                    .expression
+                    .as_ref()
+                    .map(|expression| expression.location)
+                    .unwrap_or(block.location)
+            })
+            .unwrap_or(location);
+
+        let else_block = if_let
+            .else_block
+            .unwrap_or_else(|| BlockExpression::new(location, vec![], None));
+
+        let r#match = MatchExpression::new(
+            location,
+            *if_let.scrutinee,
+            vec![
+                (
+                    if_let.pattern,
+                    ExpressionTree::new(
+                        main_expression_location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Block(if_let.main_block)),
+                    ),
+                ),
+                (
+                    MatchPattern::new(else_expression_location, MatchPatternVariant::Wildcard),
+                    ExpressionTree::new(
+                        else_expression_location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Block(else_block)),
+                    ),
+                ),
+            ],
+        );
+
+        MatchAnalyzer::analyze(scope, r#match, rule).map_err(|error| match error {
+            Error::MatchBranchExpressionInvalidType {
+                location,
+                expected,
+                found,
+                reference,
+            } if location == else_expression_location && reference == main_expression_location => {
+                Error::ConditionalBranchTypesMismatch {
+                    location: main_expression_location,
+                    expected,
+                    found,
+                    reference: else_expression_location,
+                }
+            }
+            error => error,
+        })
+    }
+}
@@ -0,0 +1,114 @@
+//!
+//! The `if let` expression tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_enumeration_variant() {
+    let input = r#"
+enum State {
+    Idle = 1,
+    Busy = 2,
+}
+
+fn main() -> u8 {
+    let state = State::Busy;
+    if let State::Idle = state { 1 } else { 2 }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_tuple_binding() {
+    let input = r#"
+fn main() -> u8 {
+    let pair = (1, 42);
+    if let (1, value) = pair { value } else { 0 }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_binding_out_of_scope() {
+    let input = r#"
+fn main() -> u8 {
+    let pair = (1, 42);
+    if let (1, value) = pair { value } else { 0 };
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemUndeclared {
+        location: Location::test(5, 5),
+        name: "value".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_types_mismatch() {
+    let input = r#"
+enum State {
+    Idle = 1,
+    Busy = 2,
+}
+
+fn main() {
+    let state = State::Busy;
+    if let State::Idle = state { 42 } else { false };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::test(9, 34),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(9, 46),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_types_mismatch_without_else() {
+    let input = r#"
+enum State {
+    Idle = 1,
+    Busy = 2,
+}
+
+fn main() {
+    let state = State::Busy;
+    if let State::Idle = state { 42 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalBranchTypesMismatch {
+            location: Location::test(9, 34),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::unit(None).to_string(),
+            reference: Location::test(9, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -2,6 +2,7 @@
 //! The match expression exhausting data.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use num::BigInt;
@@ -20,6 +21,9 @@ pub struct Data {
     /// The enumeration type, is the `match` expressions matches one.
     /// In this case, all the enumeration variant must be covered at least once.
     enumeration_type: Option<Enumeration>,
+    /// The refutable tuple patterns, which appear in the `match` expression, as the compared
+    /// scrutinee scalar offsets and values.
+    tuple_patterns: Vec<(BTreeMap<usize, BigInt>, Location)>,
 }
 
 impl Data {
@@ -33,6 +37,7 @@ impl Data {
         Self {
             patterns: HashMap::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
             enumeration_type: None,
+            tuple_patterns: Vec::new(),
         }
     }
 
@@ -57,6 +62,40 @@ impl Data {
         self.patterns.insert(value, location)
     }
 
+    ///
+    /// Inserts a refutable tuple pattern to the exhaustion list.
+    ///
+    /// Returns the location of the earlier pattern, which is equal to the inserted one.
+    ///
+    pub fn insert_tuple(
+        &mut self,
+        keys: BTreeMap<usize, BigInt>,
+        location: Location,
+    ) -> Option<Location> {
+        if let Some((_keys, duplicate)) = self
+            .tuple_patterns
+            .iter()
+            .find(|(earlier, _location)| earlier == &keys)
+        {
+            return Some(*duplicate);
+        }
+
+        self.tuple_patterns.push((keys, location));
+        None
+    }
+
+    ///
+    /// Checks if the tuple pattern is matched by an earlier one, that is, each scalar compared
+    /// by the earlier pattern is compared by the checked one with the same value.
+    ///
+    pub fn is_tuple_covered(&self, keys: &BTreeMap<usize, BigInt>) -> bool {
+        self.tuple_patterns.iter().any(|(earlier, _location)| {
+            earlier
+                .iter()
+                .all(|(offset, value)| keys.get(offset) == Some(value))
+        })
+    }
+
     ///
     /// Checks if the boolean patterns cover all the possible boolean values.
     ///
@@ -6,14 +6,18 @@
 mod tests;
 
 pub mod exhausting;
+pub mod tuple;
 
 use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
 use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
 use zinc_syntax::MatchPatternVariant;
 
+use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
@@ -29,11 +33,14 @@ use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::assignment::Tracker as AssignmentTracker;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
 use self::exhausting::Data as ExhaustingData;
+use self::tuple::Condition as TupleCondition;
+use self::tuple::Pattern as TuplePattern;
 
 ///
 /// The `match` expression semantic analyzer.
@@ -66,6 +73,10 @@ impl Analyzer {
     ///
     /// Returns the runtime match semantic element and intermediate representation.
     ///
+    /// If the scalar scrutinee is a constant, all the branches are still analyzed, but only the
+    /// taken one is translated into a plain block without the conditional instructions, unless
+    /// it is a binding branch, which needs the scrutinee stored in memory.
+    ///
     fn runtime(
         scope: Rc<RefCell<Scope>>,
         r#match: MatchExpression,
@@ -83,7 +94,12 @@ impl Analyzer {
             ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                 .analyze(r#match.scrutinee)?;
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
+        let is_tuple = matches!(scrutinee_type, Type::Tuple(_));
+        let scrutinee_constant = match scrutinee_result {
+            Element::Constant(ref constant) if !is_tuple => Some(constant.to_owned()),
+            _ => None,
+        };
+        if scrutinee_type.is_scalar() || is_tuple {
             builder.set_scrutinee(
                 scrutinee_expression,
                 GeneratorType::try_from_semantic(&scrutinee_type)
@@ -101,9 +117,12 @@ impl Analyzer {
         }
 
         let first_branch_expression_location = r#match.branches[0].1.location;
+        let mut assignment_tracker = AssignmentTracker::new(scope_stack.top());
         let mut is_exhausted = false;
         let mut exhausting_data = ExhaustingData::new();
         let mut match_result = None;
+        let mut is_taken_found = scrutinee_constant.is_none();
+        let mut taken_branch = None;
 
         for (pattern, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
@@ -116,6 +135,80 @@ impl Analyzer {
             }
 
             let result = match pattern.variant {
+                MatchPatternVariant::Or(alternatives) if is_tuple => {
+                    let patterns = Self::tuple_alternatives(
+                        scope_stack.top(),
+                        alternatives,
+                        &scrutinee_type,
+                        TranslationRule::Value,
+                        scrutinee_location,
+                        &mut exhausting_data,
+                    )?;
+                    let (result, branch) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(expression)?;
+
+                    if patterns.iter().any(TuplePattern::is_irrefutable) {
+                        is_exhausted = true;
+                        builder.set_wildcard_branch(branch);
+                    } else {
+                        let alternatives = patterns
+                            .into_iter()
+                            .map(|pattern| Self::tuple_conditions(pattern.conditions))
+                            .collect();
+                        builder.push_or_branch(alternatives, branch);
+                    }
+
+                    result
+                }
+                variant if is_tuple => {
+                    let pattern = TuplePattern::analyze(
+                        scope_stack.top(),
+                        MatchPattern::new(pattern_location, variant),
+                        &scrutinee_type,
+                        TranslationRule::Value,
+                        scrutinee_location,
+                    )?;
+                    Self::check_tuple_reachability(&pattern, &mut exhausting_data)?;
+
+                    scope_stack.push(None, ScopeType::Block);
+                    let mut bindings = Vec::with_capacity(pattern.bindings.len());
+                    for binding in pattern.bindings.into_iter() {
+                        bindings.push((
+                            binding.identifier.name.clone(),
+                            binding.offset,
+                            binding.r#type.size(),
+                        ));
+                        Scope::define_variable(
+                            scope_stack.top(),
+                            binding.identifier,
+                            false,
+                            binding.r#type,
+                        )?;
+                    }
+                    let (result, branch) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(expression)?;
+                    scope_stack.pop();
+
+                    if pattern.conditions.is_empty() {
+                        is_exhausted = true;
+                        builder.set_tuple_wildcard_branch(bindings, branch);
+                    } else {
+                        let conditions = Self::tuple_conditions(pattern.conditions);
+                        builder.push_tuple_branch(conditions, bindings, branch);
+                    }
+
+                    result
+                }
+                MatchPatternVariant::Tuple(elements) => {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location: pattern_location,
+                        expected: scrutinee_type.to_string(),
+                        found: TuplePattern::placeholder(elements.len()),
+                        reference: scrutinee_location,
+                    });
+                }
                 MatchPatternVariant::BooleanLiteral(boolean) => {
                     let location = boolean.location;
 
@@ -139,12 +232,17 @@ impl Analyzer {
                         });
                     }
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
-                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let constant = Constant::Boolean(constant);
+                    let is_taken = Self::is_taken(scrutinee_constant.as_ref(), &constant);
+                    let constant = GeneratorConstant::try_from_semantic(&constant)
+                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
                     let (result, branch) =
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
+                    if is_taken && !is_taken_found {
+                        is_taken_found = true;
+                        taken_branch = Some((pattern_location, branch.clone()));
+                    }
 
                     if exhausting_data.has_exhausted_boolean() {
                         is_exhausted = true;
@@ -178,12 +276,17 @@ impl Analyzer {
                         });
                     }
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
-                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let constant = Constant::Integer(constant);
+                    let is_taken = Self::is_taken(scrutinee_constant.as_ref(), &constant);
+                    let constant = GeneratorConstant::try_from_semantic(&constant)
+                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
                     let (result, branch) =
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
+                    if is_taken && !is_taken_found {
+                        is_taken_found = true;
+                        taken_branch = Some((pattern_location, branch.clone()));
+                    }
 
                     if exhausting_data.has_exhausted_integer() {
                         is_exhausted = true;
@@ -233,11 +336,16 @@ impl Analyzer {
                         });
                     }
 
+                    let is_taken = Self::is_taken(scrutinee_constant.as_ref(), &constant);
                     let constant = GeneratorConstant::try_from_semantic(&constant)
                         .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
                     let (result, branch) =
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
+                    if is_taken && !is_taken_found {
+                        is_taken_found = true;
+                        taken_branch = Some((pattern_location, branch.clone()));
+                    }
 
                     if exhausting_data.has_exhausted_integer() {
                         is_exhausted = true;
@@ -250,6 +358,7 @@ impl Analyzer {
                 }
                 MatchPatternVariant::Binding(identifier) => {
                     is_exhausted = true;
+                    is_taken_found = true;
 
                     scope_stack.push(None, ScopeType::Block);
                     Scope::define_variable(
@@ -272,13 +381,60 @@ impl Analyzer {
                     let (result, branch) =
                         ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                             .analyze(expression)?;
+                    if !is_taken_found {
+                        is_taken_found = true;
+                        taken_branch = Some((pattern_location, branch.clone()));
+                    }
 
                     builder.set_wildcard_branch(branch);
 
+                    result
+                }
+                MatchPatternVariant::Or(alternatives) => {
+                    let (constants, is_irrefutable) = Self::scalar_alternatives(
+                        scope_stack.top(),
+                        alternatives,
+                        &scrutinee_type,
+                        TranslationRule::Value,
+                        scrutinee_location,
+                        &mut exhausting_data,
+                    )?;
+
+                    let is_taken = is_irrefutable
+                        || constants
+                            .iter()
+                            .any(|constant| Self::is_taken(scrutinee_constant.as_ref(), constant));
+                    let alternatives = constants
+                        .iter()
+                        .map(|constant| {
+                            vec![(
+                                0,
+                                GeneratorConstant::try_from_semantic(constant)
+                                    .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                            )]
+                        })
+                        .collect();
+                    let (result, branch) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(expression)?;
+                    if is_taken && !is_taken_found {
+                        is_taken_found = true;
+                        taken_branch = Some((pattern_location, branch.clone()));
+                    }
+
+                    if is_irrefutable || Self::is_exhausted(&exhausting_data, &scrutinee_type) {
+                        is_exhausted = true;
+                        builder.set_wildcard_branch(branch);
+                    } else {
+                        builder.push_or_branch(alternatives, branch);
+                    }
+
                     result
                 }
             };
 
+            assignment_tracker.branch(pattern_location);
+
             let result_type = Type::from_element(&result, scope_stack.top())?;
             if let Some(ref match_result) = match_result {
                 let match_result_type = Type::from_element(match_result, scope_stack.top())?;
@@ -300,17 +456,46 @@ impl Analyzer {
         if !is_exhausted {
             return Err(Error::MatchNotExhausted { location });
         }
+        assignment_tracker.finish();
 
         let element = match match_result.take() {
             Some(result) => result,
             None => Element::Constant(Constant::Unit(UnitConstant::new(location))),
         };
 
-        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+        let intermediate = match taken_branch {
+            Some((taken_location, branch)) => {
+                log::info!(
+                    "{}: the scrutinee is always matched by the branch at {}, so the other branches are not generated",
+                    scrutinee_location,
+                    taken_location,
+                );
+                GeneratorExpressionOperand::Block(GeneratorBlockExpression::new(
+                    vec![],
+                    Some(branch),
+                ))
+            }
+            None => GeneratorExpressionOperand::Match(builder.finish()),
+        };
 
         Ok((element, intermediate))
     }
 
+    ///
+    /// Checks whether the constant `scrutinee` is matched by the branch `pattern`.
+    ///
+    fn is_taken(scrutinee: Option<&Constant>, pattern: &Constant) -> bool {
+        match (scrutinee, pattern) {
+            (Some(Constant::Boolean(scrutinee)), Constant::Boolean(pattern)) => {
+                scrutinee.inner == pattern.inner
+            }
+            (Some(Constant::Integer(scrutinee)), Constant::Integer(pattern)) => {
+                scrutinee.value == pattern.value
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Returns the constant match semantic element.
     ///
@@ -333,7 +518,8 @@ impl Analyzer {
             }
         };
         let scrutinee_type = scrutinee_result.r#type();
-        if !scrutinee_type.is_scalar() {
+        let is_tuple = matches!(scrutinee_type, Type::Tuple(_));
+        if !scrutinee_type.is_scalar() && !is_tuple {
             return Err(Error::MatchScrutineeInvalidType {
                 location: scrutinee_location,
                 found: scrutinee_type.to_string(),
@@ -360,6 +546,95 @@ impl Analyzer {
             }
 
             let result = match pattern.variant {
+                MatchPatternVariant::Or(alternatives) if is_tuple => {
+                    let patterns = Self::tuple_alternatives(
+                        scope_stack.top(),
+                        alternatives,
+                        &scrutinee_type,
+                        TranslationRule::Constant,
+                        scrutinee_location,
+                        &mut exhausting_data,
+                    )?;
+                    let is_matched = patterns
+                        .iter()
+                        .any(|pattern| pattern.matches(&scrutinee_result));
+
+                    let (result, _) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                            .analyze(expression)?;
+                    match result {
+                        Element::Constant(ref result) => {
+                            if is_matched && match_result.is_none() {
+                                match_result = Some(result.to_owned());
+                            }
+                        }
+                        element => {
+                            return Err(Error::ExpressionNonConstantElement {
+                                location: expression_location,
+                                found: element.to_string(),
+                            });
+                        }
+                    }
+
+                    if patterns.iter().any(TuplePattern::is_irrefutable) {
+                        is_exhausted = true;
+                    }
+
+                    result
+                }
+                variant if is_tuple => {
+                    let pattern = TuplePattern::analyze(
+                        scope_stack.top(),
+                        MatchPattern::new(pattern_location, variant),
+                        &scrutinee_type,
+                        TranslationRule::Constant,
+                        scrutinee_location,
+                    )?;
+                    Self::check_tuple_reachability(&pattern, &mut exhausting_data)?;
+                    let is_matched = pattern.matches(&scrutinee_result);
+
+                    scope_stack.push(None, ScopeType::Block);
+                    for binding in pattern.bindings.iter() {
+                        Scope::define_constant(
+                            scope_stack.top(),
+                            binding.identifier.to_owned(),
+                            TuplePattern::element_at(&scrutinee_result, binding.path.as_slice())
+                                .to_owned(),
+                        )?;
+                    }
+                    let (result, _) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                            .analyze(expression)?;
+                    scope_stack.pop();
+
+                    match result {
+                        Element::Constant(ref result) => {
+                            if is_matched && match_result.is_none() {
+                                match_result = Some(result.to_owned());
+                            }
+                        }
+                        element => {
+                            return Err(Error::ExpressionNonConstantElement {
+                                location: expression_location,
+                                found: element.to_string(),
+                            });
+                        }
+                    }
+
+                    if pattern.is_irrefutable() {
+                        is_exhausted = true;
+                    }
+
+                    result
+                }
+                MatchPatternVariant::Tuple(elements) => {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location: pattern_location,
+                        expected: scrutinee_type.to_string(),
+                        found: TuplePattern::placeholder(elements.len()),
+                        reference: scrutinee_location,
+                    });
+                }
                 MatchPatternVariant::BooleanLiteral(boolean) => {
                     let location = boolean.location;
 
@@ -569,6 +844,43 @@ impl Analyzer {
                         }
                     }
 
+                    result
+                }
+                MatchPatternVariant::Or(alternatives) => {
+                    let (constants, is_irrefutable) = Self::scalar_alternatives(
+                        scope_stack.top(),
+                        alternatives,
+                        &scrutinee_type,
+                        TranslationRule::Constant,
+                        scrutinee_location,
+                        &mut exhausting_data,
+                    )?;
+                    let is_matched = is_irrefutable
+                        || constants
+                            .iter()
+                            .any(|constant| Self::is_taken(Some(&scrutinee_result), constant));
+
+                    let (result, _) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                            .analyze(expression)?;
+                    match result {
+                        Element::Constant(ref result) => {
+                            if is_matched && match_result.is_none() {
+                                match_result = Some(result.to_owned());
+                            }
+                        }
+                        element => {
+                            return Err(Error::ExpressionNonConstantElement {
+                                location: expression_location,
+                                found: element.to_string(),
+                            });
+                        }
+                    }
+
+                    if is_irrefutable || Self::is_exhausted(&exhausting_data, &scrutinee_type) {
+                        is_exhausted = true;
+                    }
+
                     result
                 }
             };
@@ -598,4 +910,228 @@ impl Analyzer {
 
         Ok(element)
     }
+
+    ///
+    /// Analyzes the or-pattern `alternatives` matched against the scalar scrutinee of `type`.
+    ///
+    /// Returns the constants the scrutinee is compared with, and whether some alternative is a
+    /// wildcard, which makes the whole or-pattern irrefutable.
+    ///
+    fn scalar_alternatives(
+        scope: Rc<RefCell<Scope>>,
+        alternatives: Vec<MatchPattern>,
+        r#type: &Type,
+        rule: TranslationRule,
+        reference: Location,
+        exhausting_data: &mut ExhaustingData,
+    ) -> Result<(Vec<Constant>, bool), Error> {
+        let mut constants = Vec::with_capacity(alternatives.len());
+        let mut is_irrefutable = false;
+
+        for alternative in alternatives.into_iter() {
+            let location = alternative.location;
+
+            let constant = match alternative.variant {
+                MatchPatternVariant::BooleanLiteral(boolean) => {
+                    let constant = BooleanConstant::from(boolean);
+                    let pattern_type = constant.r#type();
+                    if &pattern_type != r#type {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference,
+                        });
+                    }
+
+                    if let Some(duplicate) =
+                        exhausting_data.insert_boolean(constant.inner, location)
+                    {
+                        return Err(Error::MatchBranchDuplicate {
+                            location,
+                            reference: duplicate,
+                        });
+                    }
+
+                    Constant::Boolean(constant)
+                }
+                MatchPatternVariant::IntegerLiteral(integer) => {
+                    let constant = IntegerConstant::try_from(&integer)?;
+                    let pattern_type = constant.r#type();
+                    if Caster::cast(&pattern_type, r#type).is_err() {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference,
+                        });
+                    }
+
+                    if let Some(duplicate) =
+                        exhausting_data.insert_integer(constant.value.clone(), None, location)
+                    {
+                        return Err(Error::MatchBranchDuplicate {
+                            location,
+                            reference: duplicate,
+                        });
+                    }
+
+                    Constant::Integer(constant)
+                }
+                MatchPatternVariant::Path(path) => {
+                    let path_location = path.location;
+
+                    let constant =
+                        match ExpressionAnalyzer::new(scope.clone(), rule).analyze(path)? {
+                            (Element::Constant(constant), _intermediate) => constant,
+                            (element, _intermediate) => {
+                                return Err(Error::MatchBranchPatternPathExpectedConstant {
+                                    location: path_location,
+                                    found: element.to_string(),
+                                });
+                            }
+                        };
+                    let pattern_type = constant.r#type();
+                    if &pattern_type != r#type {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: pattern_type.to_string(),
+                            reference,
+                        });
+                    }
+
+                    if let Constant::Integer(ref integer) = constant {
+                        if let Some(duplicate) = exhausting_data.insert_integer(
+                            integer.value.to_owned(),
+                            integer.enumeration.to_owned(),
+                            path_location,
+                        ) {
+                            return Err(Error::MatchBranchDuplicate {
+                                location: path_location,
+                                reference: duplicate,
+                            });
+                        }
+                    }
+
+                    constant
+                }
+                MatchPatternVariant::Binding(identifier) => {
+                    return Err(Error::MatchBranchOrPatternBinding {
+                        location: identifier.location,
+                        name: identifier.name,
+                    });
+                }
+                MatchPatternVariant::Tuple(elements) => {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: TuplePattern::placeholder(elements.len()),
+                        reference,
+                    });
+                }
+                MatchPatternVariant::Wildcard => {
+                    is_irrefutable = true;
+                    continue;
+                }
+                MatchPatternVariant::Or(_alternatives) => {
+                    panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
+                }
+            };
+
+            constants.push(constant);
+        }
+
+        Ok((constants, is_irrefutable))
+    }
+
+    ///
+    /// Analyzes the or-pattern `alternatives` matched against the tuple scrutinee of `type`.
+    ///
+    /// Each alternative is checked for reachability on its own, and none of them may bind
+    /// variables, since the branch expression cannot tell which alternative has matched.
+    ///
+    fn tuple_alternatives(
+        scope: Rc<RefCell<Scope>>,
+        alternatives: Vec<MatchPattern>,
+        r#type: &Type,
+        rule: TranslationRule,
+        reference: Location,
+        exhausting_data: &mut ExhaustingData,
+    ) -> Result<Vec<TuplePattern>, Error> {
+        let mut patterns = Vec::with_capacity(alternatives.len());
+
+        for alternative in alternatives.into_iter() {
+            let pattern =
+                TuplePattern::analyze(scope.clone(), alternative, r#type, rule, reference)?;
+            if let Some(binding) = pattern.bindings.first() {
+                return Err(Error::MatchBranchOrPatternBinding {
+                    location: binding.identifier.location,
+                    name: binding.identifier.name.to_owned(),
+                });
+            }
+            Self::check_tuple_reachability(&pattern, exhausting_data)?;
+
+            patterns.push(pattern);
+        }
+
+        Ok(patterns)
+    }
+
+    ///
+    /// Converts the tuple pattern `conditions` into the scrutinee scalar offsets and the
+    /// constants they are compared with.
+    ///
+    fn tuple_conditions(conditions: Vec<TupleCondition>) -> Vec<(usize, GeneratorConstant)> {
+        conditions
+            .into_iter()
+            .map(|condition| {
+                (
+                    condition.offset,
+                    GeneratorConstant::try_from_semantic(&condition.constant)
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks if the constant patterns inserted so far cover all the values of the scalar
+    /// scrutinee of `type`.
+    ///
+    fn is_exhausted(exhausting_data: &ExhaustingData, r#type: &Type) -> bool {
+        match r#type {
+            Type::Boolean(_) => exhausting_data.has_exhausted_boolean(),
+            _ => exhausting_data.has_exhausted_integer(),
+        }
+    }
+
+    ///
+    /// Checks that the refutable tuple `pattern` is neither a duplicate of an earlier one nor
+    /// matched by an earlier one.
+    ///
+    fn check_tuple_reachability(
+        pattern: &TuplePattern,
+        exhausting_data: &mut ExhaustingData,
+    ) -> Result<(), Error> {
+        if pattern.is_irrefutable() {
+            return Ok(());
+        }
+
+        let keys = pattern.keys();
+        let is_covered = exhausting_data.is_tuple_covered(&keys);
+        if let Some(duplicate) = exhausting_data.insert_tuple(keys, pattern.location) {
+            return Err(Error::MatchBranchDuplicate {
+                location: pattern.location,
+                reference: duplicate,
+            });
+        }
+        if is_covered {
+            return Err(Error::MatchBranchUnreachable {
+                location: pattern.location,
+            });
+        }
+
+        Ok(())
+    }
 }
@@ -2,16 +2,12 @@
 //! The match expression tests.
 //!
 
-use std::collections::HashMap;
-use std::path::PathBuf;
-
 use zinc_lexical::Location;
 
 use crate::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
-use crate::source::Source;
 
 #[test]
 fn ok_boolean() {
@@ -252,16 +248,10 @@ fn main() -> u8 {
         },
     ));
 
-    let result = crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "module_1".to_owned(),
-            Source::test(module_1, PathBuf::from("module_1.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>(),
-    );
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("module_1.zn", module_1),
+    ]);
 
     assert_eq!(result, expected);
 }
@@ -400,3 +390,387 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_tuple_state_machine() {
+    let input = r#"
+enum State {
+    Idle = 0,
+    Running = 1,
+    Done = 2,
+}
+
+fn step(state: State, flag: bool, counter: u8) -> (State, u8) {
+    match (state, flag, counter) {
+        (State::Idle, true, _) => (State::Running, 0),
+        (State::Running, false, 10) => (State::Done, 10),
+        (State::Running, false, value) => (State::Running, value + 1),
+        (State::Done, _, value) => (State::Done, value),
+        _ => (State::Idle, 0),
+    }
+}
+
+fn main() -> (State, u8) {
+    step(State::Running, false, 5)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_tuple_nested() {
+    let input = r#"
+fn main() -> u8 {
+    let scrutinee = ((1, true), 42);
+    match scrutinee {
+        ((1, false), _) => 10,
+        ((inner, true), value) => inner + value,
+        (_, _) => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_tuple_constant() {
+    let input = r#"
+const RESULT: u8 = match (2, true) {
+    (1, true) => 10,
+    (value, true) => value * 10,
+    _ => 0,
+};
+
+fn main() -> [u8; 20] {
+    [0; RESULT]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_tuple_not_exhausted() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, true);
+    let result = match scrutinee {
+        (1, true) => 10,
+        (1, false) => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(4, 18),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_tuple_branch_unreachable() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, true);
+    let result = match scrutinee {
+        (1, _) => 10,
+        (1, false) => 20,
+        _ => 30,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+        location: Location::test(6, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_tuple_branch_pattern_invalid_length() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, true);
+    let result = match scrutinee {
+        (1, true, 2) => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternInvalidType {
+            location: Location::test(5, 9),
+            expected: Type::tuple(
+                None,
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::boolean(None),
+                ],
+            )
+            .to_string(),
+            found: "(_, _, _)".to_owned(),
+            reference: Location::test(4, 24),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_tuple_branch_duplicate() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, true);
+    let result = match scrutinee {
+        (1, true) => 10,
+        (2, false) => 20,
+        (1, true) => 30,
+        _ => 40,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchDuplicate {
+        location: Location::test(7, 9),
+        reference: Location::test(5, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_scrutinee() {
+    let input = r#"
+const MODE: u8 = 2;
+
+fn main(value: u8) -> u8 {
+    match MODE {
+        1 => value * 2,
+        2 => value + 1,
+        other => value - other,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_constant_scrutinee_dead_branch_checked() {
+    let input = r#"
+const MODE: u8 = 2;
+
+fn main() -> u8 {
+    match MODE {
+        1 => if 42 { 1 } else { 2 },
+        _ => 3,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalExpectedBooleanCondition {
+            location: Location::test(6, 17),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_or_pattern_integer() {
+    let input = r#"
+fn main() -> bool {
+    let value = 42;
+    match value {
+        1 | 2 | 3 => false,
+        42 => true,
+        _ => false,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_or_pattern_enumeration_exhausted() {
+    let input = r#"
+enum List {
+    A = 1,
+    B = 2,
+    C = 3,
+}
+
+fn main() -> u8 {
+    let value = List::A;
+    match value {
+        List::A => 10,
+        List::B | List::C => 20,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_or_pattern_tuple() {
+    let input = r#"
+fn main() -> u8 {
+    let scrutinee = (1, true);
+    match scrutinee {
+        (1, true) | (2, _) => 10,
+        _ => 20,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_or_pattern_constant() {
+    let input = r#"
+const RESULT: u8 = match 3 {
+    1 | 3 => 10,
+    _ => 0,
+};
+
+fn main() -> [u8; 10] {
+    [0; RESULT]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_or_pattern_duplicate_across_branches() {
+    let input = r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        1 | 2 => 10,
+        3 | 1 => 20,
+        _ => 30,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchDuplicate {
+        location: Location::test(6, 13),
+        reference: Location::test(5, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_or_pattern_duplicate_within_branch() {
+    let input = r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        1 | 2 | 1 => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchDuplicate {
+        location: Location::test(5, 17),
+        reference: Location::test(5, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_or_pattern_branch_unreachable_exhausted_boolean() {
+    let input = r#"
+fn main() {
+    let scrutinee = true;
+    let result = match scrutinee {
+        true | false => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+        location: Location::test(6, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_or_pattern_binding() {
+    let input = r#"
+fn main() {
+    let scrutinee = 42;
+    let result = match scrutinee {
+        1 | value => value,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchOrPatternBinding {
+            location: Location::test(5, 13),
+            name: "value".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_or_pattern_tuple_binding() {
+    let input = r#"
+fn main() {
+    let scrutinee = (1, true);
+    let result = match scrutinee {
+        (1, flag) | (2, true) => 10,
+        _ => 20,
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchOrPatternBinding {
+            location: Location::test(5, 13),
+            name: "flag".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,292 @@
+//!
+//! The `match` expression tuple pattern.
+//!
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::casting::Caster;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The tuple pattern condition, which holds if the scrutinee scalar is equal to the constant.
+///
+#[derive(Debug, Clone)]
+pub struct Condition {
+    /// The scrutinee scalar offset.
+    pub offset: usize,
+    /// The tuple element indexes leading to the scrutinee scalar.
+    pub path: Vec<usize>,
+    /// The constant the scrutinee scalar is compared with.
+    pub constant: Constant,
+}
+
+///
+/// The tuple pattern binding, which is extracted from the scrutinee.
+///
+#[derive(Debug, Clone)]
+pub struct Binding {
+    /// The binding identifier.
+    pub identifier: Identifier,
+    /// The scrutinee element offset.
+    pub offset: usize,
+    /// The tuple element indexes leading to the scrutinee element.
+    pub path: Vec<usize>,
+    /// The scrutinee element type.
+    pub r#type: Type,
+}
+
+///
+/// The analyzed tuple pattern, flattened into the conditions and bindings.
+///
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    /// The pattern location.
+    pub location: Location,
+    /// The conditions, all of which must hold for the pattern to match.
+    pub conditions: Vec<Condition>,
+    /// The bindings extracted from the scrutinee if the pattern matches.
+    pub bindings: Vec<Binding>,
+}
+
+impl Pattern {
+    ///
+    /// Analyzes the `pattern` matched against the scrutinee of `type`.
+    ///
+    /// The `reference` is the scrutinee location, which is used in the type mismatch errors.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        r#type: &Type,
+        rule: TranslationRule,
+        reference: Location,
+    ) -> Result<Self, Error> {
+        let mut result = Self {
+            location: pattern.location,
+            conditions: Vec::new(),
+            bindings: Vec::new(),
+        };
+
+        result.element(scope, pattern, r#type, 0, vec![], rule, reference)?;
+
+        Ok(result)
+    }
+
+    ///
+    /// Returns the placeholder description of a tuple pattern with `count` elements, which is
+    /// used in the type mismatch errors.
+    ///
+    pub fn placeholder(count: usize) -> String {
+        format!("({})", vec!["_"; count].join(", "))
+    }
+
+    ///
+    /// Checks if the pattern matches any scrutinee value.
+    ///
+    pub fn is_irrefutable(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    ///
+    /// Returns the compared scrutinee scalar offsets and values, which are used to find the
+    /// duplicate and unreachable patterns.
+    ///
+    pub fn keys(&self) -> BTreeMap<usize, BigInt> {
+        self.conditions
+            .iter()
+            .filter_map(|condition| {
+                Self::key(&condition.constant).map(|key| (condition.offset, key))
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks if the pattern matches the constant `scrutinee`.
+    ///
+    pub fn matches(&self, scrutinee: &Constant) -> bool {
+        self.conditions.iter().all(|condition| {
+            let element = Self::element_at(scrutinee, condition.path.as_slice());
+            match (Self::key(element), Self::key(&condition.constant)) {
+                (Some(actual), Some(expected)) => actual == expected,
+                _ => false,
+            }
+        })
+    }
+
+    ///
+    /// Returns the constant `scrutinee` element at `path`.
+    ///
+    pub fn element_at<'a>(scrutinee: &'a Constant, path: &[usize]) -> &'a Constant {
+        path.iter()
+            .fold(scrutinee, |constant, index| match constant {
+                Constant::Tuple(tuple) => &tuple.values[*index],
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            })
+    }
+
+    ///
+    /// Analyzes the pattern element at `offset` and `path`, matched against the scrutinee
+    /// element of `type`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    fn element(
+        &mut self,
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+        path: Vec<usize>,
+        rule: TranslationRule,
+        reference: Location,
+    ) -> Result<(), Error> {
+        let location = pattern.location;
+
+        let constant = match pattern.variant {
+            MatchPatternVariant::Tuple(elements) => {
+                let types = match r#type {
+                    Type::Tuple(tuple) if tuple.types.len() == elements.len() => {
+                        tuple.types.to_owned()
+                    }
+                    r#type => {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: Self::placeholder(elements.len()),
+                            reference,
+                        });
+                    }
+                };
+
+                let mut offset = offset;
+                for (index, (element, r#type)) in elements.into_iter().zip(types).enumerate() {
+                    let mut path = path.clone();
+                    path.push(index);
+
+                    self.element(
+                        scope.clone(),
+                        element,
+                        &r#type,
+                        offset,
+                        path,
+                        rule,
+                        reference,
+                    )?;
+                    offset += r#type.size();
+                }
+
+                return Ok(());
+            }
+            MatchPatternVariant::Binding(identifier) => {
+                self.bindings.push(Binding {
+                    identifier,
+                    offset,
+                    path,
+                    r#type: r#type.to_owned(),
+                });
+
+                return Ok(());
+            }
+            MatchPatternVariant::Wildcard => return Ok(()),
+            MatchPatternVariant::Or(_alternatives) => {
+                panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
+            }
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let constant = BooleanConstant::from(boolean);
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference,
+                    });
+                }
+
+                Constant::Boolean(constant)
+            }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let constant = IntegerConstant::try_from(&integer)?;
+                let pattern_type = constant.r#type();
+                if Caster::cast(&pattern_type, r#type).is_err() {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference,
+                    });
+                }
+
+                Constant::Integer(constant)
+            }
+            MatchPatternVariant::Path(path) => {
+                let constant = match ExpressionAnalyzer::new(scope, rule).analyze(path)? {
+                    (Element::Constant(constant), _intermediate) => constant,
+                    (element, _intermediate) => {
+                        return Err(Error::MatchBranchPatternPathExpectedConstant {
+                            location,
+                            found: element.to_string(),
+                        });
+                    }
+                };
+                let pattern_type = constant.r#type();
+                if &pattern_type != r#type {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location,
+                        expected: r#type.to_string(),
+                        found: pattern_type.to_string(),
+                        reference,
+                    });
+                }
+                if !pattern_type.is_scalar() {
+                    return Err(Error::MatchScrutineeInvalidType {
+                        location,
+                        found: pattern_type.to_string(),
+                    });
+                }
+
+                constant
+            }
+        };
+
+        self.conditions.push(Condition {
+            offset,
+            path,
+            constant,
+        });
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the scalar `constant` value.
+    ///
+    fn key(constant: &Constant) -> Option<BigInt> {
+        match constant {
+            Constant::Boolean(boolean) if boolean.inner => Some(BigInt::one()),
+            Constant::Boolean(_boolean) => Some(BigInt::zero()),
+            Constant::Integer(integer) => Some(integer.value.to_owned()),
+            _ => None,
+        }
+    }
+}
@@ -10,6 +10,7 @@ pub mod block;
 pub mod call;
 pub mod conditional;
 pub mod identifier;
+pub mod if_let;
 pub mod list;
 pub mod literal;
 pub mod r#match;
@@ -20,6 +21,7 @@ pub mod structure;
 pub mod tuple;
 pub mod tuple_index;
 pub mod r#type;
+pub mod unsafe_witness;
 
 use std::cell::RefCell;
 use std::ops::Add;
@@ -53,12 +55,15 @@ use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
@@ -68,6 +73,7 @@ use self::call::r#type::Type as CallType;
 use self::call::Analyzer as CallAnalyzer;
 use self::conditional::Analyzer as ConditionalAnalyzer;
 use self::identifier::Analyzer as IdentifierAnalyzer;
+use self::if_let::Analyzer as IfLetAnalyzer;
 use self::list::Analyzer as ListAnalyzer;
 use self::literal::Analyzer as LiteralAnalyzer;
 use self::path::Translator as PathTranslator;
@@ -79,6 +85,7 @@ use self::stack::Stack as EvaluationStack;
 use self::structure::Analyzer as StructureAnalyzer;
 use self::tuple::Analyzer as TupleAnalyzer;
 use self::tuple_index::Analyzer as TupleIndexAnalyzer;
+use self::unsafe_witness::Analyzer as UnsafeWitnessAnalyzer;
 
 ///
 /// The expression semantic analyzer.
@@ -165,7 +172,7 @@ impl Analyzer {
                 ExpressionOperator::Assignment => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, _operator) = self.assignment(Element::assign)?;
+                    let (place, _operator) = self.assignment(Element::assign, true)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::Assignment {
@@ -177,7 +184,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentBitwiseOr => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_bitor)?;
+                    let (place, operator) = self.assignment(Element::assign_bitor, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentBitwiseOr {
@@ -190,7 +197,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentBitwiseXor => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_bitxor)?;
+                    let (place, operator) = self.assignment(Element::assign_bitxor, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentBitwiseXor {
@@ -203,7 +210,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentBitwiseAnd => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_bitand)?;
+                    let (place, operator) = self.assignment(Element::assign_bitand, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentBitwiseAnd {
@@ -216,7 +223,8 @@ impl Analyzer {
                 ExpressionOperator::AssignmentBitwiseShiftLeft => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_bitwise_shift_left)?;
+                    let (place, operator) =
+                        self.assignment(Element::assign_bitwise_shift_left, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentBitwiseShiftLeft {
@@ -229,7 +237,8 @@ impl Analyzer {
                 ExpressionOperator::AssignmentBitwiseShiftRight => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_bitwise_shift_right)?;
+                    let (place, operator) =
+                        self.assignment(Element::assign_bitwise_shift_right, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentBitwiseShiftRight {
@@ -242,7 +251,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentAddition => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_add)?;
+                    let (place, operator) = self.assignment(Element::assign_add, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentAddition {
@@ -255,7 +264,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentSubtraction => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_subtract)?;
+                    let (place, operator) = self.assignment(Element::assign_subtract, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentSubtraction {
@@ -268,7 +277,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentMultiplication => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_multiply)?;
+                    let (place, operator) = self.assignment(Element::assign_multiply, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentMultiplication {
@@ -281,7 +290,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentDivision => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_divide)?;
+                    let (place, operator) = self.assignment(Element::assign_divide, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentDivision {
@@ -294,7 +303,7 @@ impl Analyzer {
                 ExpressionOperator::AssignmentRemainder => {
                     self.left_separate(tree.left, operator, rule)?;
                     let expression = self.right_separate(tree.right, operator, rule)?;
-                    let (place, operator) = self.assignment(Element::assign_remainder)?;
+                    let (place, operator) = self.assignment(Element::assign_remainder, false)?;
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentRemainder {
@@ -516,10 +525,17 @@ impl Analyzer {
                 }
 
                 ExpressionOperator::Index => {
+                    let index_identifier = tree.right.as_ref().and_then(|tree| match *tree.value {
+                        ExpressionTreeNode::Operand(ExpressionOperand::Identifier(
+                            ref identifier,
+                        )) => Some(identifier.name.to_owned()),
+                        _ => None,
+                    });
+
                     self.left_local(tree.left, operator, rule)?;
                     let intermediate_2 = self.right_separate(tree.right, operator, rule)?;
 
-                    let intermediate = self.index(intermediate_2)?;
+                    let intermediate = self.index(intermediate_2, index_identifier)?;
                     if let Some(intermediate) = intermediate {
                         self.intermediate.push_operator(tree.location, intermediate);
                     }
@@ -529,7 +545,7 @@ impl Analyzer {
                     let _ = self.left_local(tree.left, operator, rule)?;
                     let _ = self.right_local(tree.right, operator, rule)?;
 
-                    let intermediate = self.dot()?;
+                    let intermediate = self.dot(tree.location)?;
                     if let Some(intermediate) = intermediate {
                         self.intermediate.push_operator(tree.location, intermediate);
                     }
@@ -538,10 +554,22 @@ impl Analyzer {
                 ExpressionOperator::Call => {
                     self.left_local(tree.left, operator, rule)?;
 
+                    if let StackElement::Evaluated(Element::Type(Type::Function(
+                        FunctionType::Intrinsic(IntrinsicFunctionType::Require(_)),
+                    ))) = self.evaluation_stack.top()
+                    {
+                        if let Some(ref arguments) = tree.right {
+                            UnsafeWitnessAnalyzer::constrain(self.scope_stack.top(), arguments);
+                        }
+                    }
+
                     // forces the constant translation rule, which prevents the arguments to be written to the IR
                     let rule = match self.evaluation_stack.top() {
                         StackElement::Evaluated(Element::Type(Type::Function(
                             FunctionType::Constant(_),
+                        )))
+                        | StackElement::Evaluated(Element::Type(Type::Function(
+                            FunctionType::Intrinsic(IntrinsicFunctionType::Cfg(_)),
                         ))) => TranslationRule::Constant,
                         _element => self.rule,
                     };
@@ -673,7 +701,14 @@ impl Analyzer {
     ///
     /// Analyzes the assignment operation.
     ///
-    fn assignment<F>(&mut self, callback: F) -> Result<(Place, GeneratorExpressionOperator), Error>
+    /// Only the plain assignment, specified with `is_plain`, can assign a variable declared
+    /// without an initializer, since the compound ones read the variable first.
+    ///
+    fn assignment<F>(
+        &mut self,
+        callback: F,
+        is_plain: bool,
+    ) -> Result<(Place, GeneratorExpressionOperator), Error>
     where
         F: FnOnce(Element, Element) -> Result<(Place, GeneratorExpressionOperator), Error>,
     {
@@ -697,6 +732,7 @@ impl Analyzer {
             return Err(Error::MutatingImmutableContractField {
                 location: place.identifier.location,
                 name,
+                place: place.to_string(),
             });
         }
         if !place.is_mutable {
@@ -710,6 +746,25 @@ impl Analyzer {
 
             return Err(Error::MutatingImmutableMemory {
                 location: place.identifier.location,
+                place: place.to_string(),
+                name: place.identifier.name,
+                reference: item_location,
+            });
+        }
+        if RefCell::borrow(&self.scope_stack.top()).is_within_unsafe_witness()
+            && RefCell::borrow(&self.scope_stack.top()).is_beyond_unsafe_witness(&place.identifier)
+        {
+            let item_location = self
+                .scope_stack
+                .top()
+                .borrow()
+                .resolve_item(&place.identifier, true)?
+                .borrow()
+                .location();
+
+            return Err(Error::UnsafeWitnessMutatingOuterMemory {
+                location: place.identifier.location,
+                place: place.to_string(),
                 name: place.identifier.name,
                 reference: item_location,
             });
@@ -717,10 +772,22 @@ impl Analyzer {
         if place.r#type != r#type {
             return Err(Error::MutatingWithDifferentType {
                 location: place.identifier.location,
+                place: place.to_string(),
                 expected: r#type.to_string(),
                 found: place.r#type.to_string(),
             });
         }
+        if let Some(reference) = place.uninitialized {
+            if !is_plain || !place.elements.is_empty() {
+                return Err(Error::VariableUninitialized {
+                    location: place.identifier.location,
+                    name: place.identifier.name,
+                    reference,
+                });
+            }
+
+            Scope::assign_variable(self.scope_stack.top(), &place.identifier)?;
+        }
 
         self.evaluation_stack
             .push(StackElement::Evaluated(Element::Value(Value::Unit(
@@ -760,7 +827,7 @@ impl Analyzer {
         self.intermediate.append_expression(intermediate_1);
         if let Some(r#type) = operator.operand_1_inferred_type() {
             self.intermediate
-                .push_operator(location, GeneratorExpressionOperator::casting(r#type));
+                .push_operator(location, operator.inferred_casting(r#type));
         }
         match operator {
             GeneratorExpressionOperator::Or => {
@@ -776,7 +843,7 @@ impl Analyzer {
         self.intermediate.append_expression(intermediate_2);
         if let Some(r#type) = operator.operand_2_inferred_type() {
             self.intermediate
-                .push_operator(location, GeneratorExpressionOperator::casting(r#type));
+                .push_operator(location, operator.inferred_casting(r#type));
         }
         match operator {
             GeneratorExpressionOperator::Or => {
@@ -887,9 +954,13 @@ impl Analyzer {
     ///
     /// Analyzes the array index operation.
     ///
+    /// The `identifier` is the index variable name, if the index expression is a single identifier.
+    /// It is only used to render the place path in error messages.
+    ///
     fn index(
         &mut self,
         expression: GeneratorExpression,
+        identifier: Option<String>,
     ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
@@ -927,7 +998,11 @@ impl Analyzer {
                             access,
                         })
                     }
-                    _ => place.push_element(PlaceElement::IndexExpression { expression, access }),
+                    _ => place.push_element(PlaceElement::IndexExpression {
+                        expression,
+                        access,
+                        identifier,
+                    }),
                 }
 
                 self.evaluation_stack
@@ -946,7 +1021,10 @@ impl Analyzer {
     ///
     /// Analyzes the tuple or structure field access operation.
     ///
-    fn dot(&mut self) -> Result<Option<GeneratorExpressionOperator>, Error> {
+    /// Warns if only the value is taken from the `MTreeMap::get` result, so its `found` flag
+    /// is discarded.
+    ///
+    fn dot(&mut self, location: Location) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
@@ -971,6 +1049,16 @@ impl Analyzer {
                     Ok(None)
                 }
                 element => {
+                    if access.position == 0
+                        && self.intermediate.is_mtreemap_get_call()
+                        && LINT_LEVELS.is_enabled(Lint::DiscardedFoundFlag, location)
+                    {
+                        log::warn!(
+                            "{}: the `found` flag of `MTreeMap::get` is discarded, so a missing key reads as zero; use `get_or` or `contains` instead",
+                            location,
+                        );
+                    }
+
                     self.evaluation_stack.push(StackElement::Evaluated(element));
 
                     Ok(Some(GeneratorExpressionOperator::slice(access)))
@@ -992,8 +1080,8 @@ impl Analyzer {
                 }
             },
             DotAccess::Method { instance } => {
-                let (instance, is_mutable) = if let Element::Place(instance) = *instance {
-                    let is_mutable = instance.is_mutable;
+                let (instance, place) = if let Element::Place(instance) = *instance {
+                    let place = instance.clone();
 
                     let (instance, intermedidate) = Self::evaluate(
                         self.scope_stack.top(),
@@ -1004,13 +1092,13 @@ impl Analyzer {
                         self.intermediate.push_operand(intermediate);
                     }
 
-                    (instance, is_mutable)
+                    (instance, Some(place))
                 } else {
-                    (*instance, true)
+                    (*instance, None)
                 };
 
                 self.evaluation_stack.push(StackElement::Evaluated(result));
-                self.next_call_type = CallType::new_method(instance, is_mutable);
+                self.next_call_type = CallType::new_method(instance, place);
 
                 Ok(None)
             }
@@ -1041,6 +1129,7 @@ impl Analyzer {
             operand_2,
             call_type,
             location,
+            rule,
         )?;
 
         self.evaluation_stack.push(StackElement::Evaluated(element));
@@ -1133,7 +1222,14 @@ impl Analyzer {
                 ExpressionOperand::Conditional(inner) => {
                     ConditionalAnalyzer::analyze(scope, inner, rule)
                 }
+                ExpressionOperand::IfLet(inner) => IfLetAnalyzer::analyze(scope, inner, rule),
                 ExpressionOperand::Match(inner) => MatchAnalyzer::analyze(scope, inner, rule),
+                ExpressionOperand::UnsafeWitness(inner) => {
+                    // the value can be checked by `require` only if it is bound with `let`
+                    Err(Error::UnsafeWitnessUnconstrained {
+                        location: inner.location,
+                    })
+                }
             },
             StackElement::Evaluated(inner) => match inner {
                 Element::Path(path) => PathTranslator::translate(scope, path, rule),
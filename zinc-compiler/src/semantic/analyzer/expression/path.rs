@@ -37,20 +37,34 @@ impl Translator {
         let location = path.location;
         let path_last_identifier = path.last().to_owned();
 
+        let is_within_constructor = RefCell::borrow(&scope).is_within_constructor();
+
         match rule {
             TranslationRule::Place => match *Scope::resolve_path(scope, &path)?.borrow() {
-                ScopeItem::Variable(ref variable) => Ok((
-                    Element::Place(Place::new(
+                ScopeItem::Variable(ref variable)
+                    if is_within_constructor && variable.is_transaction() =>
+                {
+                    Err(Error::ContractConstructorExternalState {
+                        location,
+                        found: path.to_string(),
+                    })
+                }
+                ScopeItem::Variable(ref variable) => {
+                    let mut place = Place::new(
                         path_last_identifier,
                         variable.r#type.to_owned(),
                         variable.is_mutable,
                         MemoryType::Stack,
-                    )),
-                    None,
-                )),
+                    );
+                    place.uninitialized = variable.uninitialized;
+
+                    Ok((Element::Place(place), None))
+                }
                 ScopeItem::Constant(ref constant) => {
+                    let declaration = constant.location;
                     let mut constant = constant.define()?;
                     constant.set_location(location);
+                    constant.set_declaration(declaration);
 
                     let intermediate = GeneratorConstant::try_from_semantic(&constant);
 
@@ -83,7 +97,23 @@ impl Translator {
                 }),
             },
             TranslationRule::Value => match *Scope::resolve_path(scope, &path)?.borrow() {
+                ScopeItem::Variable(ref variable)
+                    if is_within_constructor && variable.is_transaction() =>
+                {
+                    Err(Error::ContractConstructorExternalState {
+                        location,
+                        found: path.to_string(),
+                    })
+                }
                 ScopeItem::Variable(ref variable) => {
+                    if let Some(reference) = variable.uninitialized {
+                        return Err(Error::VariableUninitialized {
+                            location,
+                            name: path_last_identifier.name,
+                            reference,
+                        });
+                    }
+
                     let value = Value::try_from_type(&variable.r#type, false, Some(location))?;
                     let r#type = value.r#type();
                     let element = Element::Value(value);
@@ -103,8 +133,10 @@ impl Translator {
                     Ok((element, intermediate))
                 }
                 ScopeItem::Constant(ref constant) => {
+                    let declaration = constant.location;
                     let mut constant = constant.define()?;
                     constant.set_location(location);
+                    constant.set_declaration(declaration);
 
                     let intermediate = GeneratorConstant::try_from_semantic(&constant)
                         .map(GeneratorExpressionOperand::Constant);
@@ -146,8 +178,10 @@ impl Translator {
             },
             TranslationRule::Constant => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Constant(ref constant) => {
+                    let declaration = constant.location;
                     let mut constant = constant.define()?;
                     constant.set_location(location);
+                    constant.set_declaration(declaration);
 
                     let intermediate = GeneratorConstant::try_from_semantic(&constant)
                         .map(GeneratorExpressionOperand::Constant);
@@ -24,6 +24,14 @@ impl Translator {
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
         match rule {
             TranslationRule::Value => {
+                if let Some(reference) = place.uninitialized {
+                    return Err(Error::VariableUninitialized {
+                        location: place.identifier.location,
+                        name: place.identifier.name,
+                        reference,
+                    });
+                }
+
                 let element = Value::try_from_place(&place).map(Element::Value)?;
 
                 Ok((
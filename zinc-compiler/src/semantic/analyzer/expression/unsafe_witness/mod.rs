@@ -0,0 +1,112 @@
+//!
+//! The `unsafe_witness` expression semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionOperator;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::UnsafeWitnessExpression;
+
+use crate::generator::expression::operand::unsafe_witness::Expression as GeneratorUnsafeWitnessExpression;
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::r#type::Type as GeneratorType;
+use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+
+///
+/// The `unsafe_witness` expression semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `unsafe_witness` expression.
+    ///
+    /// The block is evaluated without constraints, so its result is a runtime value of the block
+    /// type, which is allocated as a new witness.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        expression: UnsafeWitnessExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let location = expression.location;
+
+        let mut scope_stack = ScopeStack::new(scope);
+        scope_stack.push(None, ScopeType::UnsafeWitness);
+
+        let (element, block) =
+            BlockAnalyzer::analyze(scope_stack.top(), expression.block, TranslationRule::Value)?;
+        let r#type = Type::from_element(&element, scope_stack.top())?;
+
+        scope_stack.pop();
+
+        let generator_type = match GeneratorType::try_from_semantic(&r#type) {
+            Some(generator_type) if r#type.is_instantiatable(false) => generator_type,
+            _ => {
+                return Err(Error::TypeInstantiationForbidden {
+                    location,
+                    found: r#type.to_string(),
+                })
+            }
+        };
+
+        let value = Value::try_from_type(&r#type, false, Some(location))?;
+        let intermediate = GeneratorUnsafeWitnessExpression::new(location, block, generator_type);
+
+        Ok((
+            Element::Value(value),
+            GeneratorExpressionOperand::UnsafeWitness(intermediate),
+        ))
+    }
+
+    ///
+    /// Marks the variables referenced by the `require` call arguments `tree` as checked.
+    ///
+    pub fn constrain(scope: Rc<RefCell<Scope>>, tree: &ExpressionTree) {
+        match tree.value.as_ref() {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)) => {
+                Scope::constrain_variable(scope, identifier);
+            }
+            ExpressionTreeNode::Operand(ExpressionOperand::List(list)) => {
+                for element in list.elements.iter() {
+                    Self::constrain(scope.clone(), element);
+                }
+            }
+            ExpressionTreeNode::Operand(ExpressionOperand::Tuple(tuple)) => {
+                for element in tuple.elements.iter() {
+                    Self::constrain(scope.clone(), element);
+                }
+            }
+            ExpressionTreeNode::Operand(_) => {}
+            ExpressionTreeNode::Operator(operator) => {
+                if let Some(ref left) = tree.left {
+                    Self::constrain(scope.clone(), left);
+                }
+
+                // the right operand of the access operators is a field or item name
+                if let ExpressionOperator::Dot | ExpressionOperator::Path = operator {
+                    return;
+                }
+                if let Some(ref right) = tree.right {
+                    Self::constrain(scope, right);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! The `unsafe_witness` expression tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_square_root() {
+    let input = r#"
+fn main(square: u16) -> u8 {
+    let root = unsafe_witness {
+        let mut candidate: u8 = 0;
+        for i in 0..16 {
+            if (i * i) as u16 <= square {
+                candidate = i;
+            }
+        }
+        candidate
+    };
+    require(root as u16 * root as u16 <= square);
+    require(square < (root as u16 + 1) * (root as u16 + 1));
+    root
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_tuple_binding() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let (quotient, remainder) = unsafe_witness { (value / 3, value % 3) };
+    require(quotient * 3 + remainder == value && remainder < 3);
+    quotient
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_unconstrained() {
+    let input = r#"
+fn main() -> u8 {
+    let root = unsafe_witness { 4 };
+    root
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::UnsafeWitnessUnconstrained {
+        location: Location::test(3, 16),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unconstrained_tuple_element() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let (quotient, remainder) = unsafe_witness { (value / 3, value % 3) };
+    require(quotient < 86);
+    quotient
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::UnsafeWitnessUnconstrained {
+        location: Location::test(3, 33),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unbound() {
+    let input = r#"
+fn main() -> u8 {
+    unsafe_witness { 4 }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::UnsafeWitnessUnconstrained {
+        location: Location::test(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_wildcard() {
+    let input = r#"
+fn main() {
+    let _ = unsafe_witness { 4 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::UnsafeWitnessUnconstrained {
+        location: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_outer_memory() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8 = 2;
+    let root = unsafe_witness {
+        value = 4;
+        value
+    };
+    require(root == value);
+    root
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::UnsafeWitnessMutatingOuterMemory {
+            location: Location::test(5, 9),
+            name: "value".to_owned(),
+            place: "value".to_owned(),
+            reference: Some(Location::test(3, 13)),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return() {
+    let input = r#"
+fn main() -> u8 {
+    let root = unsafe_witness {
+        return 4;
+    };
+    require(root == 4);
+    root
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementForbidden {
+            location: Location::test(4, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
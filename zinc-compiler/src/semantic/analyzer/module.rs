@@ -4,16 +4,19 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::rc::Rc;
 
 use zinc_lexical::Keyword;
 use zinc_syntax::Module as SyntaxModule;
 use zinc_syntax::ModuleLocalStatement;
 
+use crate::semantic::analyzer::attribute::module::Attribute as ModuleAttribute;
 use crate::semantic::analyzer::statement::module::Analyzer as ModStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#impl::Analyzer as ImplStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#use::Analyzer as UseStatementAnalyzer;
 use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
@@ -34,6 +37,9 @@ impl Analyzer {
     /// `<module>.zn` in the module directory. For example, `mod foo;` will look for a file called
     /// `./foo.zn` and yield an error if it is absent.
     ///
+    /// The module inner attributes are checked before the statements, so a module requiring a
+    /// newer language version is rejected before any other error is reported.
+    ///
     /// Returns the module without the hoisted statements and the implementation scopes which
     /// must be defined forcibly.
     ///
@@ -46,6 +52,18 @@ impl Analyzer {
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
         is_entry: bool,
     ) -> Result<(SyntaxModule, Vec<Rc<RefCell<Scope>>>), Error> {
+        for attribute in module.attributes.drain(..) {
+            let file = attribute.location.file;
+            let attribute = ModuleAttribute::try_from(attribute)?;
+            attribute.check()?;
+
+            if let ModuleAttribute::Allow(lints) = attribute {
+                for lint in lints.into_iter() {
+                    LINT_LEVELS.allow(file, lint);
+                }
+            }
+        }
+
         let mut instant_statements = Vec::with_capacity(module.statements.len());
         let mut implementation_scopes = Vec::with_capacity(module.statements.len());
 
@@ -63,6 +81,9 @@ impl Analyzer {
                 ModuleLocalStatement::Enum(statement) => {
                     Scope::declare_type(scope.clone(), TypeStatementVariant::Enum(statement))?;
                 }
+                ModuleLocalStatement::Interface(statement) => {
+                    Scope::declare_type(scope.clone(), TypeStatementVariant::Interface(statement))?;
+                }
                 ModuleLocalStatement::Fn(statement) => {
                     if !is_entry
                         && statement.identifier.name.as_str()
@@ -6,18 +6,32 @@
 mod tests;
 
 use std::cell::RefCell;
+use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_syntax::Attribute as SyntaxAttribute;
 use zinc_syntax::ContractLocalStatement;
 use zinc_syntax::ContractStatement;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::Identifier;
 
 use crate::generator::statement::contract::Statement as GeneratorContractStatement;
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::analyzer::statement::field::Analyzer as FieldStatementAnalyzer;
 use crate::semantic::element::r#type::contract::field::Field as ContractFieldType;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::interface::Method as InterfaceMethod;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
 ///
@@ -35,6 +49,8 @@ impl Analyzer {
         scope: Rc<RefCell<Scope>>,
         mut statement: ContractStatement,
     ) -> Result<(ContractStatement, Rc<RefCell<Scope>>), Error> {
+        let mut upgrade_location: Option<Location> = None;
+
         let mut instant_statements = Vec::with_capacity(statement.statements.len());
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
@@ -42,6 +58,23 @@ impl Analyzer {
                     Scope::declare_constant(scope.clone(), statement)?;
                 }
                 ContractLocalStatement::Fn(statement) => {
+                    let is_upgrade = statement.attributes.iter().any(|attribute| {
+                        attribute
+                            .elements
+                            .first()
+                            .map(|element| element.path.to_string() == "upgrade")
+                            .unwrap_or_default()
+                    });
+                    if is_upgrade {
+                        if let Some(reference) = upgrade_location {
+                            return Err(Error::ContractUpgradeDuplicate {
+                                location: statement.location,
+                                reference,
+                            });
+                        }
+                        upgrade_location = Some(statement.location);
+                    }
+
                     Scope::declare_type(scope.clone(), TypeStatementVariant::Fn(statement))?;
                 }
                 ContractLocalStatement::Empty(_location) => {}
@@ -57,12 +90,16 @@ impl Analyzer {
     ///
     /// Defines the instant items and forcibly defines the hoisted ones.
     ///
+    /// The only attribute allowed for contracts is `#[invariant(...)]`.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         statement: ContractStatement,
     ) -> Result<(Type, GeneratorContractStatement), Error> {
         let location = statement.location;
 
+        let invariants = Self::invariants(statement.attributes)?;
+
         let mut storage_fields = Vec::with_capacity(zinc_const::contract::IMPLICIT_FIELDS_COUNT);
         storage_fields.insert(
             zinc_const::contract::FIELD_INDEX_ADDRESS,
@@ -117,14 +154,186 @@ impl Analyzer {
             statement.identifier.name,
             project.clone(),
             storage_fields.clone(),
+            invariants.clone(),
             scope.clone(),
         )?;
 
+        Self::validate_invariants(scope.clone(), &r#type, invariants)?;
+
         scope.borrow().define()?;
 
-        let intermediate =
-            GeneratorContractStatement::new(location, project, storage_fields, is_in_dependency);
+        let interfaces = Self::check_interfaces(scope, statement.implements)?;
+
+        let intermediate = GeneratorContractStatement::new(
+            location,
+            project,
+            storage_fields,
+            interfaces,
+            is_in_dependency,
+        );
 
         Ok((r#type, intermediate))
     }
+
+    ///
+    /// Collects the `#[invariant(condition)]` attributes of the contract.
+    ///
+    fn invariants(
+        attributes: Vec<SyntaxAttribute>,
+    ) -> Result<Vec<(ExpressionTree, Location)>, Error> {
+        let mut invariants = Vec::with_capacity(attributes.len());
+
+        for attribute in attributes.into_iter() {
+            let location = attribute.location;
+            let name = attribute
+                .elements
+                .first()
+                .map(|element| element.path.to_string())
+                .unwrap_or_default();
+
+            match Attribute::try_from(attribute)? {
+                Attribute::Invariant {
+                    expression,
+                    location,
+                } => invariants.push((expression, location)),
+                _ => {
+                    return Err(Error::AttributeUnexpected {
+                        location,
+                        name,
+                        item: "contract".to_owned(),
+                    })
+                }
+            }
+        }
+
+        Ok(invariants)
+    }
+
+    ///
+    /// Validates the `#[invariant(condition)]` attributes of the `contract`.
+    ///
+    /// Each condition is analyzed in a child scope of the contract one, where the immutable
+    /// `self` variable of the contract type is declared, and must be a boolean expression.
+    ///
+    fn validate_invariants(
+        scope: Rc<RefCell<Scope>>,
+        contract: &Type,
+        invariants: Vec<(ExpressionTree, Location)>,
+    ) -> Result<(), Error> {
+        for (expression, location) in invariants.into_iter() {
+            let mut scope_stack = ScopeStack::new(scope.clone());
+            scope_stack.push(None, ScopeType::Block);
+            Scope::define_variable(
+                scope_stack.top(),
+                Identifier::new(location, Keyword::SelfLowercase.to_string()),
+                false,
+                contract.to_owned(),
+            )?;
+
+            let expression_location = expression.location;
+            let (element, _intermediate) =
+                ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                    .analyze(expression)?;
+            match Type::from_element(&element, scope_stack.top())? {
+                Type::Boolean(_) => {}
+                r#type => {
+                    return Err(Error::ContractInvariantExpectedBoolean {
+                        location: expression_location,
+                        contract: contract.to_string(),
+                        found: r#type.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the contract declares all the methods required by the interfaces
+    /// from the `implements` list, and their signatures match.
+    ///
+    /// Returns the implemented interface names.
+    ///
+    fn check_interfaces(
+        scope: Rc<RefCell<Scope>>,
+        implements: Vec<ExpressionTree>,
+    ) -> Result<Vec<String>, Error> {
+        let mut interfaces = Vec::with_capacity(implements.len());
+
+        for path in implements.into_iter() {
+            let location = path.location;
+            let interface = match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Type)
+                .analyze(path)?
+            {
+                (Element::Type(Type::Interface(interface)), _intermediate) => interface,
+                (element, _intermediate) => {
+                    return Err(Error::ContractImplementsExpectedInterface {
+                        location,
+                        found: element.to_string(),
+                    });
+                }
+            };
+
+            for expected in interface.methods.iter() {
+                let item = RefCell::borrow(&scope)
+                    .resolve_item(
+                        &Identifier::new(location, expected.identifier.to_owned()),
+                        false,
+                    )
+                    .map_err(|_error| Error::ContractInterfaceMethodMissing {
+                        location,
+                        interface: interface.identifier.to_owned(),
+                        name: expected.identifier.to_owned(),
+                        reference: expected.location,
+                    })?;
+
+                let (method_location, found) = match *RefCell::borrow(&item) {
+                    ScopeItem::Type(ref r#type) => match r#type.define()? {
+                        Type::Function(FunctionType::Runtime(function)) => {
+                            let is_instance = function
+                                .bindings
+                                .first()
+                                .map(|binding| binding.identifier.is_self_lowercase())
+                                .unwrap_or_default();
+
+                            let found = InterfaceMethod::new(
+                                function.location,
+                                function.identifier.to_owned(),
+                                is_instance,
+                                is_instance && function.is_mutable(),
+                                function
+                                    .bindings
+                                    .iter()
+                                    .skip(if is_instance { 1 } else { 0 })
+                                    .map(|binding| binding.r#type.to_owned())
+                                    .collect(),
+                                *function.return_type.to_owned(),
+                            );
+
+                            if found.matches(expected) {
+                                continue;
+                            }
+
+                            (function.location, found.to_string())
+                        }
+                        r#type => (r#type.location().unwrap_or(location), r#type.to_string()),
+                    },
+                    ref item => (item.location().unwrap_or(location), item.to_string()),
+                };
+
+                return Err(Error::ContractInterfaceMethodMismatch {
+                    location: method_location,
+                    interface: interface.identifier.to_owned(),
+                    expected: expected.to_string(),
+                    found,
+                    reference: expected.location,
+                });
+            }
+
+            interfaces.push(interface.identifier);
+        }
+
+        Ok(interfaces)
+    }
 }
@@ -2,6 +2,12 @@
 //! The `contract` statement tests.
 //!
 
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
 #[test]
 fn ok_empty() {
     let input = r#"
@@ -152,3 +158,709 @@ contract Uniswap {
 
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
+
+#[test]
+fn ok_constructor_require() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    pub fn new(value: u64) -> Self {
+        require(value != 0, "The value must not be zero");
+
+        Self {
+            value: value,
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_constructor_not_public() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    fn new(value: u64) -> Self {
+        Self {
+            value: value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractConstructorNotPublic {
+            location: Location::test(5, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constructor_self_argument() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    pub fn new(self, value: u64) -> Self {
+        Self {
+            value: value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractConstructorSelfArgument {
+            location: Location::test(5, 16),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constructor_return_type() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    pub fn new(value: u64) -> u64 {
+        value
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractConstructorReturnType {
+            location: Location::test(5, 31),
+            expected: "Self".to_owned(),
+            found: "u64".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constructor_external_state_transaction() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    pub fn new(value: u64) -> Self {
+        if value == 0 {
+            require(zksync::msg.amount > 0, "No tokens sent");
+        }
+
+        Self {
+            value: value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractConstructorExternalState {
+            location: Location::test(7, 21),
+            found: "zksync::msg".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constructor_external_state_fetch() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    pub fn new(value: u64) -> Self {
+        let other = Self::fetch(0x42 as u160);
+
+        Self {
+            value: other.value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractConstructorExternalState {
+            location: Location::test(6, 21),
+            found: "fetch".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_upgrade() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+contract Uniswap {
+    value: u64;
+    total: u64;
+
+    #[upgrade]
+    pub fn migrate(old: StorageV1) -> Self {
+        Self {
+            value: old.value,
+            total: old.value * 2,
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_upgrade_beyond_contract() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+#[upgrade]
+fn migrate(old: StorageV1) -> u64 {
+    old.value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractUpgradeBeyondContract {
+            location: Location::test(7, 1),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_not_public() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+contract Uniswap {
+    value: u64;
+
+    #[upgrade]
+    fn migrate(old: StorageV1) -> Self {
+        Self {
+            value: old.value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractUpgradeNotPublic {
+        location: Location::test(10, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_argument_self() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+contract Uniswap {
+    value: u64;
+
+    #[upgrade]
+    pub fn migrate(self, old: StorageV1) -> Self {
+        Self {
+            value: old.value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractUpgradeArgument {
+        location: Location::test(10, 5),
+        found: "contract Uniswap, structure StorageV1".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_argument_scalar() {
+    let input = r#"
+contract Uniswap {
+    value: u64;
+
+    #[upgrade]
+    pub fn migrate(value: u64) -> Self {
+        Self {
+            value: value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractUpgradeArgument {
+        location: Location::test(6, 5),
+        found: "u64".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_return_type() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+contract Uniswap {
+    value: u64;
+
+    #[upgrade]
+    pub fn migrate(old: StorageV1) -> u64 {
+        old.value
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractUpgradeReturnType {
+        location: Location::test(10, 39),
+        expected: "Self".to_owned(),
+        found: "u64".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_upgrade_duplicate() {
+    let input = r#"
+struct StorageV1 {
+    value: u64,
+}
+
+contract Uniswap {
+    value: u64;
+
+    #[upgrade]
+    pub fn migrate(old: StorageV1) -> Self {
+        Self {
+            value: old.value,
+        }
+    }
+
+    #[upgrade]
+    pub fn migrate_again(old: StorageV1) -> Self {
+        Self {
+            value: old.value,
+        }
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ContractUpgradeDuplicate {
+        location: Location::test(17, 5),
+        reference: Location::test(10, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_require_sender() {
+    let input = r#"
+contract Uniswap {
+    owner: u160;
+    admin: zksync::Address;
+    value: u64;
+
+    #[only_owner]
+    pub fn set(mut self, value: u64) {
+        self.value = value;
+    }
+
+    #[require_sender(admin)]
+    pub fn get(self) -> u64 {
+        self.value
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_require_sender_beyond_method() {
+    let input = r#"
+contract Uniswap {
+    owner: u160;
+
+    #[only_owner]
+    pub fn get() -> u64 {
+        42
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractRequireSenderBeyondMethod {
+            location: Location::test(5, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_require_sender_field_not_found() {
+    let input = r#"
+contract Uniswap {
+    owner: u160;
+
+    #[require_sender(admin)]
+    pub fn get(self) -> u160 {
+        self.owner
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractRequireSenderFieldNotFound {
+            location: Location::test(5, 5),
+            field: "admin".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_require_sender_field_type() {
+    let input = r#"
+contract Uniswap {
+    owner: u64;
+
+    #[only_owner]
+    pub fn get(self) -> u64 {
+        self.owner
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractRequireSenderFieldType {
+            location: Location::test(5, 5),
+            field: "owner".to_owned(),
+            expected: "u160".to_owned(),
+            found: "u64".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_require_sender_duplicate() {
+    let input = r#"
+contract Uniswap {
+    owner: u160;
+    admin: u160;
+
+    #[only_owner]
+    #[require_sender(admin)]
+    pub fn get(self) -> u160 {
+        self.owner
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractRequireSenderDuplicate {
+            location: Location::test(7, 5),
+            reference: Location::test(6, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_implements() {
+    let input = r#"
+interface Token {
+    fn balance(self) -> u248;
+
+    fn deposit(mut self, amount: u248);
+}
+
+contract Uniswap implements Token {
+    total: u248;
+
+    pub fn balance(self) -> u248 {
+        self.total
+    }
+
+    pub fn deposit(mut self, amount: u248) {
+        self.total += amount;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_implements_expected_interface() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+contract Uniswap implements Data {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractImplementsExpectedInterface {
+            location: Location::test(6, 29),
+            found: "type structure Data".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_interface_method_missing() {
+    let input = r#"
+interface Token {
+    fn balance(self) -> u248;
+}
+
+contract Uniswap implements Token {
+    total: u248;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractInterfaceMethodMissing {
+            location: Location::test(6, 29),
+            interface: "Token".to_owned(),
+            name: "balance".to_owned(),
+            reference: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_interface_method_mismatch() {
+    let input = r#"
+interface Token {
+    fn deposit(mut self, amount: u248);
+}
+
+contract Uniswap implements Token {
+    total: u248;
+
+    pub fn deposit(mut self, amount: u64) {
+        self.total += amount as u248;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractInterfaceMethodMismatch {
+            location: Location::test(9, 5),
+            interface: "Token".to_owned(),
+            expected: "fn deposit(mut self, u248) -> ()".to_owned(),
+            found: "fn deposit(mut self, u64) -> ()".to_owned(),
+            reference: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_invariant() {
+    let input = r#"
+#[invariant(self.fee <= Self::MAX_FEE)]
+#[invariant(self.reserve >= self.fee as u64)]
+contract Exchange {
+    const MAX_FEE: u16 = 10000;
+
+    fee: u16;
+    reserve: u64;
+
+    pub fn new(fee: u16) -> Self {
+        Self {
+            fee: fee,
+            reserve: 10000,
+        }
+    }
+
+    pub fn set_fee(mut self, fee: u16) {
+        self.fee = fee;
+    }
+
+    pub fn get_fee(self) -> u16 {
+        if self.fee == 0 {
+            return 0;
+        }
+        self.fee
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_invariant_expected_boolean() {
+    let input = r#"
+#[invariant(self.fee)]
+contract Exchange {
+    fee: u16;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContractInvariantExpectedBoolean {
+            location: Location::test(2, 17),
+            contract: "Exchange".to_owned(),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 2).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invariant_attribute_unexpected() {
+    let input = r#"
+#[invariant(self.fee <= 10000)]
+#[only_owner]
+contract Exchange {
+    fee: u16;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(3, 1),
+        name: "only_owner".to_owned(),
+        item: "contract".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invariant_function() {
+    let input = r#"
+contract Exchange {
+    fee: u16;
+
+    #[invariant(self.fee <= 10000)]
+    pub fn set_fee(mut self, fee: u16) {
+        self.fee = fee;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(5, 5),
+        name: "invariant".to_owned(),
+        item: "function".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invariant_return_forbidden() {
+    let input = r#"
+#[invariant(self.fee <= 10000)]
+contract Exchange {
+    fee: u16;
+
+    pub fn set_fee(mut self, fee: u16) {
+        if fee > 10000 {
+            return;
+        }
+        self.fee = fee;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementForbidden {
+            location: Location::test(8, 13),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -3,10 +3,12 @@
 //!
 
 use std::cell::RefCell;
+use std::convert::TryFrom;
 use std::rc::Rc;
 
 use zinc_syntax::EnumStatement;
 
+use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -20,12 +22,36 @@ impl Analyzer {
     ///
     /// Defines a compile-time only enumeration type.
     ///
+    /// The only attribute allowed for enumerations is `#[bitflags]`.
+    ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: EnumStatement) -> Result<Type, Error> {
+        let mut is_bitflags = false;
+        for attribute in statement.attributes.into_iter() {
+            let location = attribute.location;
+            let name = attribute
+                .elements
+                .first()
+                .map(|element| element.path.to_string())
+                .unwrap_or_default();
+
+            match Attribute::try_from(attribute)? {
+                Attribute::Bitflags => is_bitflags = true,
+                _ => {
+                    return Err(Error::AttributeUnexpected {
+                        location,
+                        name,
+                        item: "enumeration".to_owned(),
+                    })
+                }
+            }
+        }
+
         let r#type = Type::enumeration(
             statement.location,
             statement.identifier.name,
             statement.variants,
             vec![],
+            is_bitflags,
             scope,
         )?;
 
@@ -7,17 +7,38 @@ use std::convert::TryFrom;
 use std::rc::Rc;
 
 use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_lexical::StringLiteral as LexicalStringLiteral;
+use zinc_syntax::Binding as SyntaxBinding;
+use zinc_syntax::BindingPattern;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::BlockExpression;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionOperator;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
 use zinc_syntax::FnStatement;
+use zinc_syntax::FunctionLocalStatement;
 use zinc_syntax::Identifier;
+use zinc_syntax::LetStatement;
+use zinc_syntax::ListExpression;
+use zinc_syntax::StringLiteral;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 
 use crate::generator::statement::r#fn::role::Role as GeneratorFunctionRole;
 use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
 use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binder;
+use crate::semantic::binding::Binding;
+use crate::semantic::element::r#type::function::intrinsic::cfg::values::Values as CfgValues;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -44,11 +65,65 @@ impl Analyzer {
         }
 
         let mut attributes = Vec::with_capacity(statement.attributes.len());
+        let mut should_panic_location = None;
         for attribute in statement.attributes.drain(..).into_iter() {
+            let location = attribute.location;
             let attribute = Attribute::try_from(attribute)?;
+            match attribute {
+                Attribute::ShouldPanic { .. } => should_panic_location = Some(location),
+                Attribute::Bitflags => {
+                    return Err(Error::AttributeUnexpected {
+                        location,
+                        name: "bitflags".to_owned(),
+                        item: "function".to_owned(),
+                    })
+                }
+                Attribute::MaxIterations { .. } => {
+                    return Err(Error::AttributeUnexpected {
+                        location,
+                        name: "max_iterations".to_owned(),
+                        item: "function".to_owned(),
+                    })
+                }
+                Attribute::Invariant { .. } => {
+                    return Err(Error::AttributeUnexpected {
+                        location,
+                        name: "invariant".to_owned(),
+                        item: "function".to_owned(),
+                    })
+                }
+                _ => {}
+            }
             attributes.push(attribute);
         }
 
+        if let Some(location) = attributes.iter().find_map(|attribute| match attribute {
+            Attribute::Ensures { location, .. } => Some(*location),
+            _ => None,
+        }) {
+            if attributes.contains(&Attribute::Test) || statement.is_constant {
+                return Err(Error::AttributeUnexpected {
+                    location,
+                    name: "ensures".to_owned(),
+                    item: if statement.is_constant {
+                        "constant function".to_owned()
+                    } else {
+                        "unit test".to_owned()
+                    },
+                });
+            }
+        }
+
+        if let Some(location) = should_panic_location {
+            if !attributes.contains(&Attribute::Test) {
+                return Err(Error::AttributeUnexpected {
+                    location,
+                    name: "should_panic".to_owned(),
+                    item: "function without `#[test]`".to_owned(),
+                });
+            }
+        }
+
         if attributes.contains(&Attribute::Test) {
             return Self::test(scope, statement, attributes)
                 .map(|(r#type, intermediate)| (r#type, Some(intermediate)));
@@ -67,10 +142,19 @@ impl Analyzer {
     ///
     fn runtime(
         scope: Rc<RefCell<Scope>>,
-        statement: FnStatement,
+        mut statement: FnStatement,
         attributes: Vec<Attribute>,
     ) -> Result<(Type, GeneratorFunctionStatement), Error> {
         let scope_type = RefCell::borrow(&scope).r#type();
+        let is_constructor = scope_type == ScopeType::Contract
+            && statement.identifier.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER;
+        let is_upgrade = attributes.contains(&Attribute::Upgrade);
+        let function_scope_type = if is_constructor || is_upgrade {
+            ScopeType::Constructor
+        } else {
+            ScopeType::Function
+        };
+
         let mut scope_stack = if scope_type.is_implementation() {
             let alias_identifier =
                 Identifier::new(statement.location, Keyword::SelfUppercase.to_string());
@@ -81,12 +165,12 @@ impl Analyzer {
                     .parent()
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
             );
-            scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+            scope_stack.push(Some(statement.identifier.name.clone()), function_scope_type);
             Scope::define_item(scope_stack.top(), alias_identifier, item)?;
             scope_stack
         } else {
-            let mut scope_stack = ScopeStack::new(scope);
-            scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+            let mut scope_stack = ScopeStack::new(scope.clone());
+            scope_stack.push(Some(statement.identifier.name.clone()), function_scope_type);
             scope_stack
         };
 
@@ -107,6 +191,63 @@ impl Analyzer {
             });
         }
 
+        if let Some((field, location)) = Self::validate_require_sender(
+            scope.clone(),
+            attributes.as_slice(),
+            bindings.as_slice(),
+        )? {
+            statement.body.statements.insert(
+                0,
+                Self::require_sender(location, statement.identifier.name.as_str(), field),
+            );
+        }
+
+        if is_upgrade {
+            Self::validate_upgrade(
+                scope.clone(),
+                statement.location,
+                statement.is_public,
+                statement.return_type.as_ref().map(|r#type| r#type.location),
+                bindings.as_slice(),
+                &expected_type,
+            )?;
+        }
+
+        if is_constructor {
+            Self::validate_constructor(
+                scope,
+                statement.location,
+                statement.is_public,
+                statement.return_type.as_ref().map(|r#type| r#type.location),
+                bindings.as_slice(),
+                &expected_type,
+            )?;
+        }
+
+        let (r#type, type_id) = Type::runtime_function(
+            statement.location,
+            statement.identifier.name.clone(),
+            bindings.clone(),
+            expected_type.clone(),
+        );
+
+        if attributes
+            .iter()
+            .any(|attribute| matches!(attribute, Attribute::MaxDepth(_)))
+        {
+            Scope::insert_item(
+                scope_stack.top(),
+                statement.identifier.name.clone(),
+                ScopeItem::Type(ScopeTypeItem::new_defined(
+                    Some(statement.location),
+                    r#type.clone(),
+                    false,
+                    None,
+                ))
+                .wrap(),
+            );
+        }
+
         let return_expression_location = match statement
             .body
             .expression
@@ -122,12 +263,78 @@ impl Analyzer {
                 .unwrap_or(statement.location),
         };
 
+        let postconditions = Self::validate_ensures(
+            scope_stack.top(),
+            statement.identifier.name.as_str(),
+            attributes.as_slice(),
+            statement.return_type.as_ref().map(|_| &expected_type),
+        )?;
+        let invariants = match bindings.first() {
+            Some(Binding {
+                identifier,
+                r#type: Type::Contract(contract),
+                is_mutable: true,
+                ..
+            }) if scope_type == ScopeType::Contract && identifier.is_self_lowercase() => contract
+                .invariants
+                .iter()
+                .map(|(condition, location)| {
+                    (
+                        condition.to_owned(),
+                        *location,
+                        format!(
+                            "The invariant of the contract `{}` at {} is violated",
+                            contract.identifier, location
+                        ),
+                    )
+                })
+                .collect(),
+            _ => vec![],
+        };
+        let is_return_allowed = function_scope_type == ScopeType::Function
+            && postconditions.is_empty()
+            && invariants.is_empty();
+
+        let mut checks = Vec::with_capacity(postconditions.len() + invariants.len());
+        if CfgValues::get(zinc_const::cfg::PROFILE).as_deref()
+            != Some(zinc_const::cfg::PROFILE_RELEASE)
+        {
+            checks.extend(postconditions.into_iter().map(|(condition, location)| {
+                (
+                    condition,
+                    location,
+                    format!(
+                        "The postcondition of the function `{}` at {} is violated",
+                        statement.identifier.name, location
+                    ),
+                )
+            }));
+        }
+        checks.extend(invariants);
+        if !checks.is_empty() {
+            statement.body =
+                Self::check_result(statement.body, statement.return_type.clone(), checks);
+        }
+
+        if is_return_allowed {
+            Self::declare_return(
+                scope_stack.top(),
+                statement
+                    .return_type
+                    .as_ref()
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.location),
+                expected_type.clone(),
+            );
+        }
+
+        let is_diverging = statement.body.is_diverging();
         let (result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
         scope_stack.pop();
 
         let result_type = Type::from_element(&result, scope_stack.top())?;
-        if expected_type != result_type {
+        if !is_diverging && expected_type != result_type {
             return Err(Error::FunctionReturnType {
                 location: return_expression_location,
                 function: statement.identifier.name.clone(),
@@ -178,13 +385,6 @@ impl Analyzer {
             .map(|binding| binding.is_mutable)
             .unwrap_or_default();
 
-        let (r#type, type_id) = Type::runtime_function(
-            statement.location,
-            statement.identifier.name.clone(),
-            bindings.clone(),
-            expected_type.clone(),
-        );
-
         let intermediate = GeneratorFunctionStatement::new(
             statement.location,
             statement.identifier.name,
@@ -276,6 +476,9 @@ impl Analyzer {
     ///
     /// Analyzes a test function statement and returns its IR for the next compiler phase.
     ///
+    /// The IR function is named with the module path, e.g. `exchange::math::overflow`, so
+    /// the tests with the same name in different modules are reported distinctly.
+    ///
     fn test(
         scope: Rc<RefCell<Scope>>,
         statement: FnStatement,
@@ -320,7 +523,8 @@ impl Analyzer {
             });
         }
 
-        scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+        scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::UnitTest);
+        Self::declare_return(scope_stack.top(), location, Type::unit(None));
         let (_result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
         scope_stack.pop();
@@ -328,9 +532,12 @@ impl Analyzer {
         let (r#type, type_id) =
             Type::test_function(statement.location, statement.identifier.name.clone());
 
+        let mut path = RefCell::borrow(&scope_stack.top()).module_path();
+        path.push(statement.identifier.name);
+
         let intermediate = GeneratorFunctionStatement::new(
             location,
-            statement.identifier.name,
+            path.join("::"),
             false,
             vec![],
             intermediate,
@@ -342,4 +549,385 @@ impl Analyzer {
 
         Ok((r#type, intermediate))
     }
+
+    ///
+    /// Declares the hidden `return` item with the function result type, which allows the `return`
+    /// statements in the function `scope`.
+    ///
+    fn declare_return(scope: Rc<RefCell<Scope>>, location: Location, r#type: Type) {
+        Scope::insert_item(
+            scope,
+            Keyword::Return.to_string(),
+            ScopeItem::Type(ScopeTypeItem::new_defined(
+                Some(location),
+                r#type,
+                false,
+                None,
+            ))
+            .wrap(),
+        );
+    }
+
+    ///
+    /// Validates the contract constructor `new` signature.
+    ///
+    /// The constructor must be public, must not have the `self` argument, and must return
+    /// the type of the contract it is declared in.
+    ///
+    fn validate_constructor(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        is_public: bool,
+        return_type_location: Option<Location>,
+        bindings: &[Binding],
+        expected_type: &Type,
+    ) -> Result<(), Error> {
+        if !is_public {
+            return Err(Error::ContractConstructorNotPublic { location });
+        }
+
+        if let Some(binding) = bindings
+            .first()
+            .filter(|binding| binding.identifier.is_self_lowercase())
+        {
+            return Err(Error::ContractConstructorSelfArgument {
+                location: binding.identifier.location,
+            });
+        }
+
+        match expected_type {
+            Type::Contract(ref contract) if Rc::ptr_eq(&contract.scope, &scope) => Ok(()),
+            r#type => Err(Error::ContractConstructorReturnType {
+                location: return_type_location.unwrap_or(location),
+                expected: Keyword::SelfUppercase.to_string(),
+                found: r#type.to_string(),
+            }),
+        }
+    }
+    ///
+    /// Validates the contract `#[upgrade]` method signature.
+    ///
+    /// The method must be public, must have the only structure argument with the previous
+    /// contract version storage fields, and must return the type of the contract it is
+    /// declared in.
+    ///
+    fn validate_upgrade(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        is_public: bool,
+        return_type_location: Option<Location>,
+        bindings: &[Binding],
+        expected_type: &Type,
+    ) -> Result<(), Error> {
+        if RefCell::borrow(&scope).r#type() != ScopeType::Contract {
+            return Err(Error::ContractUpgradeBeyondContract { location });
+        }
+
+        if !is_public {
+            return Err(Error::ContractUpgradeNotPublic { location });
+        }
+
+        match bindings {
+            [binding] if matches!(binding.r#type, Type::Structure(_)) => {}
+            bindings => {
+                return Err(Error::ContractUpgradeArgument {
+                    location,
+                    found: bindings
+                        .iter()
+                        .map(|binding| binding.r#type.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                })
+            }
+        }
+
+        match expected_type {
+            Type::Contract(ref contract) if Rc::ptr_eq(&contract.scope, &scope) => Ok(()),
+            r#type => Err(Error::ContractUpgradeReturnType {
+                location: return_type_location.unwrap_or(location),
+                expected: Keyword::SelfUppercase.to_string(),
+                found: r#type.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Validates the contract method `#[require_sender(field)]` or `#[only_owner]` attribute.
+    ///
+    /// The method must be declared in a contract with the `self` first argument, and the field
+    /// must be a contract storage field of the `zksync::Address` or `u160` type.
+    ///
+    /// Returns the field name and the attribute location, if the attribute is present.
+    ///
+    fn validate_require_sender<'a>(
+        scope: Rc<RefCell<Scope>>,
+        attributes: &'a [Attribute],
+        bindings: &[Binding],
+    ) -> Result<Option<(&'a str, Location)>, Error> {
+        let mut requirements = attributes.iter().filter_map(|attribute| match attribute {
+            Attribute::RequireSender { field, location } => Some((field.as_str(), *location)),
+            _ => None,
+        });
+
+        let (field, location) = match requirements.next() {
+            Some(requirement) => requirement,
+            None => return Ok(None),
+        };
+        if let Some((_field, duplicate)) = requirements.next() {
+            return Err(Error::ContractRequireSenderDuplicate {
+                location: duplicate,
+                reference: location,
+            });
+        }
+
+        let contract = match bindings.first() {
+            Some(Binding {
+                identifier,
+                r#type: Type::Contract(contract),
+                ..
+            }) if RefCell::borrow(&scope).r#type() == ScopeType::Contract
+                && identifier.is_self_lowercase() =>
+            {
+                contract
+            }
+            _ => return Err(Error::ContractRequireSenderBeyondMethod { location }),
+        };
+
+        let r#type = match contract
+            .fields
+            .iter()
+            .find(|contract_field| contract_field.identifier.name == field)
+        {
+            Some(contract_field) => &contract_field.r#type,
+            None => {
+                return Err(Error::ContractRequireSenderFieldNotFound {
+                    location,
+                    field: field.to_owned(),
+                })
+            }
+        };
+
+        match r#type {
+            Type::Nominal(nominal) if nominal.is_address() => {}
+            Type::IntegerUnsigned { bitlength, .. }
+                if *bitlength == zinc_const::bitlength::ETH_ADDRESS => {}
+            r#type => {
+                return Err(Error::ContractRequireSenderFieldType {
+                    location,
+                    field: field.to_owned(),
+                    expected: Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS)
+                        .to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+        }
+
+        Ok(Some((field, location)))
+    }
+
+    ///
+    /// Builds the `require(zksync::msg.sender == self.{field} as u160, "...")` statement,
+    /// which is prepended to the body of the `method` with the sender requirement.
+    ///
+    fn require_sender(location: Location, method: &str, field: &str) -> FunctionLocalStatement {
+        let identifier = |name: &str| {
+            ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                    location,
+                    name.to_owned(),
+                ))),
+            )
+        };
+        let operator =
+            |operator: ExpressionOperator, left: ExpressionTree, right: ExpressionTree| {
+                ExpressionTree::new_with_leaves(
+                    location,
+                    ExpressionTreeNode::operator(operator),
+                    Some(left),
+                    Some(right),
+                )
+            };
+
+        let sender = operator(
+            ExpressionOperator::Dot,
+            operator(
+                ExpressionOperator::Path,
+                identifier("zksync"),
+                identifier("msg"),
+            ),
+            identifier("sender"),
+        );
+        let allowed = operator(
+            ExpressionOperator::Casting,
+            operator(
+                ExpressionOperator::Dot,
+                identifier(Keyword::SelfLowercase.to_string().as_str()),
+                identifier(field),
+            ),
+            ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Type(SyntaxType::new(
+                    location,
+                    SyntaxTypeVariant::integer_unsigned(zinc_const::bitlength::ETH_ADDRESS),
+                ))),
+            ),
+        );
+
+        Self::require(
+            location,
+            operator(ExpressionOperator::Equals, sender, allowed),
+            format!(
+                "The method `{}` may only be called by `self.{}`",
+                method, field
+            ),
+        )
+    }
+
+    ///
+    /// Validates the `#[ensures(condition)]` attributes of the `function`.
+    ///
+    /// Each condition is analyzed in a child scope of the function one, where the `result`
+    /// variable of `result_type` is declared if the function returns a value, and must be
+    /// a boolean expression.
+    ///
+    /// The conditions are validated in all build profiles, so the release build rejects the
+    /// same invalid postconditions as the debug one.
+    ///
+    fn validate_ensures(
+        scope: Rc<RefCell<Scope>>,
+        function: &str,
+        attributes: &[Attribute],
+        result_type: Option<&Type>,
+    ) -> Result<Vec<(ExpressionTree, Location)>, Error> {
+        let mut postconditions = Vec::new();
+
+        for attribute in attributes.iter() {
+            let (expression, location) = match attribute {
+                Attribute::Ensures {
+                    expression,
+                    location,
+                } => (expression.to_owned(), *location),
+                _ => continue,
+            };
+
+            let mut scope_stack = ScopeStack::new(scope.clone());
+            scope_stack.push(None, ScopeType::Block);
+            if let Some(result_type) = result_type {
+                Scope::define_variable(
+                    scope_stack.top(),
+                    Identifier::new(location, Attribute::ENSURES_RESULT.to_owned()),
+                    false,
+                    result_type.to_owned(),
+                )?;
+            }
+
+            let expression_location = expression.location;
+            let (element, _intermediate) =
+                ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                    .analyze(expression.clone())?;
+            match Type::from_element(&element, scope_stack.top())? {
+                Type::Boolean(_) => {}
+                r#type => {
+                    return Err(Error::FunctionEnsuresExpectedBoolean {
+                        location: expression_location,
+                        function: function.to_owned(),
+                        found: r#type.to_string(),
+                    })
+                }
+            }
+
+            postconditions.push((expression, location));
+        }
+
+        Ok(postconditions)
+    }
+
+    ///
+    /// Wraps the function body into the block, which binds the body value to `result`,
+    /// checks the `#[ensures]` postconditions and the contract `#[invariant]` conditions
+    /// with `require`, and returns `result`.
+    ///
+    /// If the function does not return a value, the body is evaluated as a statement.
+    ///
+    fn check_result(
+        body: BlockExpression,
+        return_type: Option<SyntaxType>,
+        checks: Vec<(ExpressionTree, Location, String)>,
+    ) -> BlockExpression {
+        let location = body.location;
+        let body = ExpressionTree::new(
+            location,
+            ExpressionTreeNode::operand(ExpressionOperand::Block(body)),
+        );
+
+        let mut statements = Vec::with_capacity(checks.len() + 1);
+        let result = match return_type {
+            Some(r#type) => {
+                let identifier = Identifier::new(location, Attribute::ENSURES_RESULT.to_owned());
+                statements.push(FunctionLocalStatement::Let(LetStatement::new(
+                    location,
+                    SyntaxBinding::new(
+                        location,
+                        BindingPattern::new(
+                            location,
+                            BindingPatternVariant::new_binding(identifier.clone(), false),
+                        ),
+                        Some(r#type),
+                    ),
+                    body,
+                )));
+                Some(ExpressionTree::new(
+                    location,
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(identifier)),
+                ))
+            }
+            None => {
+                statements.push(FunctionLocalStatement::Expression(body));
+                None
+            }
+        };
+
+        for (condition, location, message) in checks.into_iter() {
+            statements.push(Self::require(location, condition, message));
+        }
+
+        BlockExpression::new(location, statements, result)
+    }
+
+    ///
+    /// Builds the `require(condition, "message")` statement.
+    ///
+    fn require(
+        location: Location,
+        condition: ExpressionTree,
+        message: String,
+    ) -> FunctionLocalStatement {
+        let message = ExpressionTree::new(
+            location,
+            ExpressionTreeNode::operand(ExpressionOperand::LiteralString(StringLiteral::new(
+                location,
+                LexicalStringLiteral::new(message),
+            ))),
+        );
+
+        FunctionLocalStatement::Expression(ExpressionTree::new_with_leaves(
+            location,
+            ExpressionTreeNode::operator(ExpressionOperator::Call),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                    location,
+                    "require".to_owned(),
+                ))),
+            )),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::List(ListExpression::new(
+                    location,
+                    vec![condition, message],
+                ))),
+            )),
+        ))
+    }
 }
@@ -21,6 +21,7 @@ use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::assignment::Tracker as AssignmentTracker;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -69,6 +70,8 @@ impl Analyzer {
                 }
             };
 
+        let mut assignment_tracker = AssignmentTracker::new(scope_stack.top());
+
         scope_stack.push(None, ScopeType::Loop);
 
         let index_location = statement.index_identifier.location;
@@ -101,11 +104,16 @@ impl Analyzer {
             None
         };
 
+        let block_location = statement.block.location;
         let (_element, body) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
 
         scope_stack.pop();
 
+        assignment_tracker.branch(block_location);
+        assignment_tracker.skip(location);
+        assignment_tracker.finish();
+
         let is_reversed = range_start > range_end;
 
         let iterations_count = (range_end - range_start.clone()).abs();
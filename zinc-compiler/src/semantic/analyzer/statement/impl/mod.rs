@@ -70,6 +70,8 @@ impl Analyzer {
                 None => {
                     return Err(Error::ScopeReferenceLoop {
                         location: identifier_location,
+                        cycle: vec![statement.identifier.name],
+                        is_closed: true,
                     });
                 }
             },
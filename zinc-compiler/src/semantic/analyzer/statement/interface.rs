@@ -0,0 +1,104 @@
+//!
+//! The `interface` statement semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::InterfaceStatement;
+
+use crate::semantic::element::r#type::interface::Method as InterfaceMethod;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `interface` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a compile-time only interface type.
+    ///
+    pub fn define(scope: Rc<RefCell<Scope>>, statement: InterfaceStatement) -> Result<Type, Error> {
+        let mut methods: Vec<InterfaceMethod> = Vec::with_capacity(statement.methods.len());
+        for method in statement.methods.into_iter() {
+            if let Some(reference) = methods
+                .iter()
+                .find(|existing| existing.identifier == method.identifier.name)
+            {
+                return Err(Error::ItemRedeclared {
+                    location: method.identifier.location,
+                    name: method.identifier.name,
+                    kind: "method".to_owned(),
+                    reference: Some(reference.location),
+                    reference_kind: "method".to_owned(),
+                });
+            }
+
+            let mut is_instance = false;
+            let mut is_mutable = false;
+            let mut argument_types = Vec::with_capacity(method.argument_bindings.len());
+            for (index, binding) in method.argument_bindings.into_iter().enumerate() {
+                match binding.pattern.variant {
+                    BindingPatternVariant::Binding {
+                        identifier,
+                        is_mutable: is_self_mutable,
+                    } if identifier.is_self_lowercase() => {
+                        if index != 0 {
+                            return Err(Error::BindingSelfNotFirstMethodArgument {
+                                location: identifier.location,
+                                name: identifier.name,
+                                position: index + 1,
+                            });
+                        }
+
+                        is_instance = true;
+                        is_mutable = is_self_mutable;
+                    }
+                    BindingPatternVariant::Binding { identifier, .. } => {
+                        let r#type = binding.r#type.ok_or(Error::BindingTypeRequired {
+                            location: identifier.location,
+                            identifier: identifier.name.to_owned(),
+                        })?;
+                        argument_types.push(Type::try_from_syntax(r#type, scope.clone())?);
+                    }
+                    BindingPatternVariant::BindingList { .. } => {
+                        return Err(Error::BindingFunctionArgumentDestructuringUnavailable {
+                            location: binding.location,
+                        })
+                    }
+                    BindingPatternVariant::Wildcard => {
+                        let r#type = binding.r#type.ok_or(Error::BindingTypeRequired {
+                            location: binding.location,
+                            identifier: "_".to_owned(),
+                        })?;
+                        argument_types.push(Type::try_from_syntax(r#type, scope.clone())?);
+                    }
+                }
+            }
+
+            let return_type = match method.return_type {
+                Some(r#type) => Type::try_from_syntax(r#type, scope.clone())?,
+                None => Type::unit(None),
+            };
+
+            methods.push(InterfaceMethod::new(
+                method.location,
+                method.identifier.name,
+                is_instance,
+                is_mutable,
+                argument_types,
+                return_type,
+            ));
+        }
+
+        Ok(Type::interface(
+            statement.location,
+            statement.identifier.name,
+            methods,
+        ))
+    }
+}
@@ -0,0 +1,233 @@
+//!
+//! The `let` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::LetStatement;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::statement::r#let::Statement as GeneratorDeclarationStatement;
+use crate::semantic::analyzer::expression::unsafe_witness::Analyzer as UnsafeWitnessAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::binding::Binder;
+use crate::semantic::binding::Binding;
+use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
+use crate::semantic::scope::Scope;
+
+///
+/// The `let` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a variable and returns its IR for the next compiler phase.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: LetStatement,
+    ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
+        let expression = match statement.expression {
+            Some(expression) => expression,
+            None => return Self::define_uninitialized(scope, statement),
+        };
+
+        let is_suffixed_literal = Self::is_suffixed_literal(&expression);
+        let expression_location = expression.location;
+
+        let mut unsafe_witness_location = None;
+        let (element, expression) = match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::UnsafeWitness(witness)) => {
+                unsafe_witness_location = Some(witness.location);
+
+                let (element, operand) = UnsafeWitnessAnalyzer::analyze(scope.clone(), witness)?;
+                let mut intermediate = GeneratorExpression::new();
+                intermediate.push_operand(operand);
+                (element, intermediate)
+            }
+            value => ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(
+                ExpressionTree {
+                    value: Box::new(value),
+                    ..expression
+                },
+            )?,
+        };
+
+        let annotation = statement
+            .binding
+            .r#type
+            .filter(|r#type| r#type.variant != SyntaxTypeVariant::Inferred);
+        let r#type = if let Some(r#type) = annotation {
+            let r#type = if r#type.has_placeholders() {
+                let found = Type::from_element(&element, scope.clone())?;
+                Type::try_from_syntax_inferred(r#type, &found, scope.clone())?
+            } else {
+                Type::try_from_syntax(r#type, scope.clone())?
+            };
+            if is_suffixed_literal {
+                let found = Type::from_element(&element, scope.clone())?;
+                if found != r#type {
+                    return Err(Error::IntegerSuffixTypesMismatch {
+                        location: expression_location,
+                        expected: r#type.to_string(),
+                        found: found.to_string(),
+                        reference: r#type
+                            .location()
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    });
+                }
+            }
+            Self::check_implicit_enumeration(&element, &r#type)?;
+            element.cast(Element::Type(r#type.clone()))?;
+            r#type
+        } else {
+            Type::from_element(&element, scope.clone())?
+        };
+
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement.binding.location,
+                found: r#type.to_string(),
+            });
+        }
+
+        if expression.is_mtreemap_get_call()
+            && Self::is_second_discarded(&statement.binding.pattern.variant)
+            && LINT_LEVELS.is_enabled(Lint::DiscardedFoundFlag, statement.binding.pattern.location)
+        {
+            log::warn!(
+                "{}: the `found` flag of `MTreeMap::get` is discarded, so a missing key reads as zero; use `get_or` or `contains` instead",
+                statement.binding.pattern.location,
+            );
+        }
+
+        let bindings = Binder::bind_variables(statement.binding.pattern, r#type, scope.clone())?;
+        if let Some(location) = unsafe_witness_location {
+            for binding in bindings.iter() {
+                if binding.is_wildcard {
+                    return Err(Error::UnsafeWitnessUnconstrained { location });
+                }
+
+                Scope::define_variable_unconstrained(scope.clone(), &binding.identifier, location)?;
+            }
+        }
+
+        Ok(if bindings.is_empty() {
+            None
+        } else {
+            Some(GeneratorDeclarationStatement::new(
+                statement.location,
+                bindings,
+                expression,
+            ))
+        })
+    }
+
+    ///
+    /// Checks whether the `expression` is a single integer literal with a type suffix.
+    ///
+    /// The suffix is the definitive literal type, so it must not be converted implicitly
+    /// into the type annotation.
+    ///
+    fn is_suffixed_literal(expression: &ExpressionTree) -> bool {
+        match expression.value.as_ref() {
+            ExpressionTreeNode::Operand(ExpressionOperand::LiteralInteger(literal)) => {
+                literal.inner.suffix().is_some()
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks whether the pair binding pattern discards its second element, e.g. `(value, _)`.
+    ///
+    fn is_second_discarded(pattern: &BindingPatternVariant) -> bool {
+        match pattern {
+            BindingPatternVariant::BindingList { bindings } => matches!(
+                bindings.get(1).map(|binding| &binding.variant),
+                Some(BindingPatternVariant::Wildcard)
+            ),
+            BindingPatternVariant::Wildcard => true,
+            BindingPatternVariant::Binding { .. } => false,
+        }
+    }
+
+    ///
+    /// Checks that a runtime value is not implicitly converted into an enumeration.
+    ///
+    /// The conversion requires a runtime variant check, which is only performed by the explicit
+    /// `as` operator, whereas constants are checked at compile time.
+    ///
+    fn check_implicit_enumeration(element: &Element, r#type: &Type) -> Result<(), Error> {
+        if let (Element::Value(value), Type::Enumeration(_)) = (element, r#type) {
+            let from = value.r#type();
+            if &from != r#type {
+                return Err(Error::OperatorCastingTypesMismatch {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    inner: CastingError::CastingToInvalidType {
+                        from: from.to_string(),
+                        to: r#type.to_string(),
+                    },
+                    reference: r#type
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Defines a variable without an initializer, which must be assigned before it is read.
+    ///
+    /// The syntax analyzer allows only a single mutable binding with an explicit type here.
+    ///
+    fn define_uninitialized(
+        scope: Rc<RefCell<Scope>>,
+        statement: LetStatement,
+    ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
+        let r#type = statement
+            .binding
+            .r#type
+            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+        let r#type = Type::try_from_syntax(r#type, scope.clone())?;
+
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement.binding.location,
+                found: r#type.to_string(),
+            });
+        }
+
+        let identifier = match statement.binding.pattern.variant {
+            BindingPatternVariant::Binding { identifier, .. } => identifier,
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+        Scope::define_variable_uninitialized(scope, identifier.clone(), r#type.clone())?;
+
+        Ok(Some(GeneratorDeclarationStatement::new_uninitialized(
+            statement.location,
+            vec![Binding::new(identifier, true, false, r#type)],
+        )))
+    }
+}
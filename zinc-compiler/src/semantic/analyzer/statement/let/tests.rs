@@ -0,0 +1,381 @@
+//!
+//! The `let` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_uninitialized_assigned() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    value = 42;
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_uninitialized_assigned_in_all_conditional_branches() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    let mut value: u8;
+    if condition {
+        value = 42;
+    } else {
+        value = 64;
+    }
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_uninitialized_assigned_in_all_match_arms() {
+    let input = r#"
+fn main(scrutinee: u8) -> u8 {
+    let mut value: u8;
+    match scrutinee {
+        1 => { value = 42; },
+        2 => { value = 64; },
+        _ => { value = 128; },
+    }
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_uninitialized_assigned_before_loop() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    value = 0;
+    for i in 0..10 {
+        value += i;
+    }
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_uninitialized_never_assigned() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(4, 5),
+        name: "value".to_owned(),
+        reference: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_assigned_in_one_conditional_branch() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    let mut value: u8;
+    if condition {
+        value = 42;
+    } else {
+        dbg!("skipped");
+    }
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(9, 5),
+        name: "value".to_owned(),
+        reference: Location::test(6, 12),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_conditional_without_else() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    let mut value: u8;
+    if condition {
+        value = 42;
+    }
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(7, 5),
+        name: "value".to_owned(),
+        reference: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_assigned_in_one_match_arm() {
+    let input = r#"
+fn main(scrutinee: u8) -> u8 {
+    let mut value: u8;
+    match scrutinee {
+        1 => { value = 42; },
+        _ => { dbg!("skipped"); },
+    }
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(8, 5),
+        name: "value".to_owned(),
+        reference: Location::test(6, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_assigned_in_loop() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    for i in 0..10 {
+        value = i;
+    }
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(7, 5),
+        name: "value".to_owned(),
+        reference: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_compound_assignment() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    value += 1;
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(4, 5),
+        name: "value".to_owned(),
+        reference: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_uninitialized_self_assignment() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value: u8;
+    value = value + 1;
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::VariableUninitialized {
+        location: Location::test(4, 13),
+        name: "value".to_owned(),
+        reference: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_placeholder() {
+    let input = r#"
+fn main() -> u8 {
+    let value: _ = 42;
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_placeholder_array_size() {
+    let input = r#"
+fn main(a: u8, b: u8, c: u8, d: u8) -> [u8; 4] {
+    let values: [u8; _] = [a, b, c, d];
+    values
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_placeholder_array_type() {
+    let input = r#"
+fn main(a: u8, b: u8, c: u8, d: u8) -> [u8; 4] {
+    let values: [_; 4] = [a, b, c, d];
+    values
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_placeholder_tuple_nested() {
+    let input = r#"
+fn main(balance: u64, flag: bool) -> (u64, (bool, [u8; 2])) {
+    let pair: (u64, (_, [_; _])) = (balance, (flag, [1, 2]));
+    pair
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_placeholder_tuple_element_mismatch() {
+    let input = r#"
+fn main(balance: u64, flag: bool) {
+    let pair: (u64, (_, u8)) = (balance, (flag, true));
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypePlaceholderMismatch {
+        location: Location::test(3, 25),
+        expected: format!(
+            "type `{}`",
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE)
+        ),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_placeholder_tuple_length_mismatch() {
+    let input = r#"
+fn main(balance: u64, flag: bool) {
+    let pair: (u64, _, _) = (balance, flag);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypePlaceholderMismatch {
+        location: Location::test(3, 15),
+        expected: "a tuple of 3 elements".to_owned(),
+        found: Type::tuple(
+            None,
+            vec![
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8),
+                Type::boolean(None),
+            ],
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_placeholder_array_size_mismatch() {
+    let input = r#"
+fn main(a: u8, b: u8, c: u8, d: u8) {
+    let values: [_; 3] = [a, b, c, d];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypePlaceholderMismatch {
+        location: Location::test(3, 17),
+        expected: "an array of 3 elements".to_owned(),
+        found: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            4,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_placeholder_forbidden_uninitialized() {
+    let input = r#"
+fn main() {
+    let mut values: [u8; _];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypePlaceholderForbidden {
+        location: Location::test(3, 21),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_placeholder_forbidden_argument() {
+    let input = r#"
+fn main(value: _) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypePlaceholderForbidden {
+        location: Location::test(2, 16),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -9,8 +9,11 @@ pub mod field;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#impl;
+pub mod interface;
 pub mod r#let;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
 pub mod r#type;
 pub mod r#use;
+pub mod r#while;
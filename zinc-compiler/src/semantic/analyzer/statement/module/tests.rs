@@ -2,10 +2,10 @@
 //! The `mod` statement tests.
 //!
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use zinc_lexical::Location;
 
-use crate::source::Source;
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
 
 #[test]
 fn ok_module_constants_flat() {
@@ -31,28 +31,12 @@ fn main() -> u8 {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "one".to_owned(),
-                Source::test(one, PathBuf::from("one.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "two".to_owned(),
-                Source::test(two, PathBuf::from("two.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "three".to_owned(),
-                Source::test(three, PathBuf::from("three.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one.zn", one),
+        ("two.zn", two),
+        ("three.zn", three),
+    ])
     .is_ok());
 }
 
@@ -82,40 +66,12 @@ fn main() -> u8 {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "level_1".to_owned(),
-            Source::test(
-                level_1,
-                PathBuf::from("level_1.zn"),
-                vec![(
-                    "level_2".to_owned(),
-                    Source::test(
-                        level_2,
-                        PathBuf::from("level_1/level_2.zn"),
-                        vec![(
-                            "level_3".to_owned(),
-                            Source::test(
-                                level_3,
-                                PathBuf::from("level_1/level_2/level_3.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        )]
-                        .into_iter()
-                        .collect::<HashMap<String, Source>>()
-                    )
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-                )]
-                .into_iter()
-                .collect::<HashMap<String, Source>>()
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID)
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("level_1/mod.zn", level_1),
+        ("level_1/level_2/mod.zn", level_2),
+        ("level_1/level_2/level_3.zn", level_3),
+    ])
     .is_ok());
 }
 
@@ -180,103 +136,18 @@ fn main() -> u8 {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "level_1_first".to_owned(),
-                Source::test(
-                    level_1_first,
-                    PathBuf::from("level_1_first.zn"),
-                    vec![
-                        (
-                            "level_2_first".to_owned(),
-                            Source::test(
-                                first_level_2_first,
-                                PathBuf::from("level_1_first/level_2_first.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                        (
-                            "level_2_second".to_owned(),
-                            Source::test(
-                                first_level_2_second,
-                                PathBuf::from("level_1_first/level_2_second.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                    ]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "level_1_second".to_owned(),
-                Source::test(
-                    level_1_second,
-                    PathBuf::from("level_1_second.zn"),
-                    vec![
-                        (
-                            "level_2_first".to_owned(),
-                            Source::test(
-                                second_level_2_first,
-                                PathBuf::from("level_1_second/level_2_first.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                        (
-                            "level_2_second".to_owned(),
-                            Source::test(
-                                second_level_2_second,
-                                PathBuf::from("level_1_second/level_2_second.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                    ]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "level_1_third".to_owned(),
-                Source::test(
-                    level_1_third,
-                    PathBuf::from("level_1_third.zn"),
-                    vec![
-                        (
-                            "level_2_first".to_owned(),
-                            Source::test(
-                                third_level_2_first,
-                                PathBuf::from("level_1_third/level_2_first.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                        (
-                            "level_2_second".to_owned(),
-                            Source::test(
-                                third_level_2_second,
-                                PathBuf::from("level_1_third/level_2_second.zn"),
-                                HashMap::new()
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),
-                    ]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            )
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("level_1_first/mod.zn", level_1_first),
+        ("level_1_first/level_2_first.zn", first_level_2_first),
+        ("level_1_first/level_2_second.zn", first_level_2_second),
+        ("level_1_second/mod.zn", level_1_second),
+        ("level_1_second/level_2_first.zn", second_level_2_first),
+        ("level_1_second/level_2_second.zn", second_level_2_second),
+        ("level_1_third/mod.zn", level_1_third),
+        ("level_1_third/level_2_first.zn", third_level_2_first),
+        ("level_1_third/level_2_second.zn", third_level_2_second),
+    ])
     .is_ok());
 }
 
@@ -326,28 +197,12 @@ fn main() -> Together {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "one".to_owned(),
-                Source::test(one, PathBuf::from("one.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "two".to_owned(),
-                Source::test(two, PathBuf::from("two.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "three".to_owned(),
-                Source::test(three, PathBuf::from("three.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one.zn", one),
+        ("two.zn", two),
+        ("three.zn", three),
+    ])
     .is_ok());
 }
 
@@ -401,28 +256,12 @@ fn main() -> Together {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "one".to_owned(),
-                Source::test(one, PathBuf::from("one.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "two".to_owned(),
-                Source::test(two, PathBuf::from("two.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "three".to_owned(),
-                Source::test(three, PathBuf::from("three.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one.zn", one),
+        ("two.zn", two),
+        ("three.zn", three),
+    ])
     .is_ok());
 }
 
@@ -470,36 +309,12 @@ fn main() -> Together {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "one".to_owned(),
-            Source::test(
-                one,
-                PathBuf::from("one.zn"),
-                vec![(
-                    "two".to_owned(),
-                    Source::test(
-                        two,
-                        PathBuf::from("one/two.zn"),
-                        vec![(
-                            "three".to_owned(),
-                            Source::test(three, PathBuf::from("one/two/three.zn"), HashMap::new())
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                        ),]
-                        .into_iter()
-                        .collect::<HashMap<String, Source>>()
-                    )
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-                ),]
-                .into_iter()
-                .collect::<HashMap<String, Source>>()
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one/mod.zn", one),
+        ("one/two/mod.zn", two),
+        ("one/two/three.zn", three),
+    ])
     .is_ok());
 }
 
@@ -555,36 +370,12 @@ fn main() -> Together {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "one".to_owned(),
-            Source::test(
-                one,
-                PathBuf::from("one.zn"),
-                vec![(
-                    "two".to_owned(),
-                    Source::test(
-                        two,
-                        PathBuf::from("one/two.zn"),
-                        vec![(
-                            "three".to_owned(),
-                            Source::test(three, PathBuf::from("one/two/three.zn"), HashMap::new())
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                        )]
-                        .into_iter()
-                        .collect::<HashMap<String, Source>>()
-                    )
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-                ),]
-                .into_iter()
-                .collect::<HashMap<String, Source>>()
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one/mod.zn", one),
+        ("one/two/mod.zn", two),
+        ("one/two/three.zn", three),
+    ])
     .is_ok());
 }
 
@@ -616,16 +407,10 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ])
     .is_ok());
 }
 
@@ -657,16 +442,10 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ])
     .is_ok());
 }
 
@@ -702,16 +481,10 @@ contract Test {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ])
     .is_ok());
 }
 
@@ -747,16 +520,10 @@ contract Test {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-        ),]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ])
     .is_ok());
 }
 
@@ -792,23 +559,11 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other".to_owned(),
-                Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed".to_owned(),
-                Source::test(accessed, PathBuf::from("accessed.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+        ("accessed.zn", accessed),
+    ])
     .is_ok());
 }
 
@@ -844,23 +599,11 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other".to_owned(),
-                Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed".to_owned(),
-                Source::test(accessed, PathBuf::from("accessed.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+        ("accessed.zn", accessed),
+    ])
     .is_ok());
 }
 
@@ -912,73 +655,17 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other_level_1".to_owned(),
-                Source::test(
-                    other_level_1,
-                    PathBuf::from("other_level_1.zn"),
-                    vec![(
-                        "other_level_2".to_owned(),
-                        Source::test(
-                            other_level_2,
-                            PathBuf::from("other_level_1/other_level_2.zn"),
-                            vec![(
-                                "other_level_3".to_owned(),
-                                Source::test(
-                                    other_level_3,
-                                    PathBuf::from("other_level_1/other_level_2/other_level_3.zn"),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed_level_1".to_owned(),
-                Source::test(
-                    accessed_level_1,
-                    PathBuf::from("accessed_level_1.zn"),
-                    vec![(
-                        "accessed_level_2".to_owned(),
-                        Source::test(
-                            accessed_level_2,
-                            PathBuf::from("accessed_level_1/accessed_level_2.zn"),
-                            vec![(
-                                "accessed_level_3".to_owned(),
-                                Source::test(
-                                    accessed_level_3,
-                                    PathBuf::from(
-                                        "accessed_level_1/accessed_level_2/accessed_level_3.zn"
-                                    ),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other_level_1/mod.zn", other_level_1),
+        ("other_level_1/other_level_2/mod.zn", other_level_2),
+        (
+            "other_level_1/other_level_2/other_level_3.zn",
+            other_level_3
+        ),
+        ("accessed_level_1/mod.zn", accessed_level_1),
+        ("accessed_level_1/accessed_level_2/mod.zn", accessed_level_2),
+    ])
     .is_ok());
 }
 
@@ -1030,73 +717,17 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other_level_1".to_owned(),
-                Source::test(
-                    other_level_1,
-                    PathBuf::from("other_level_1.zn"),
-                    vec![(
-                        "other_level_2".to_owned(),
-                        Source::test(
-                            other_level_2,
-                            PathBuf::from("other_level_1/other_level_2.zn"),
-                            vec![(
-                                "other_level_3".to_owned(),
-                                Source::test(
-                                    other_level_3,
-                                    PathBuf::from("other_level_1/other_level_2/other_level_3.zn"),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed_level_1".to_owned(),
-                Source::test(
-                    accessed_level_1,
-                    PathBuf::from("accessed_level_1.zn"),
-                    vec![(
-                        "accessed_level_2".to_owned(),
-                        Source::test(
-                            accessed_level_2,
-                            PathBuf::from("accessed_level_1/accessed_level_2.zn"),
-                            vec![(
-                                "accessed_level_3".to_owned(),
-                                Source::test(
-                                    accessed_level_3,
-                                    PathBuf::from(
-                                        "accessed_level_1/accessed_level_2/accessed_level_3.zn"
-                                    ),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other_level_1/mod.zn", other_level_1),
+        ("other_level_1/other_level_2/mod.zn", other_level_2),
+        (
+            "other_level_1/other_level_2/other_level_3.zn",
+            other_level_3
+        ),
+        ("accessed_level_1/mod.zn", accessed_level_1),
+        ("accessed_level_1/accessed_level_2/mod.zn", accessed_level_2),
+    ])
     .is_ok());
 }
 
@@ -1144,23 +775,11 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other".to_owned(),
-                Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed".to_owned(),
-                Source::test(accessed, PathBuf::from("accessed.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+        ("accessed.zn", accessed),
+    ])
     .is_ok());
 }
 
@@ -1208,23 +827,11 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other".to_owned(),
-                Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed".to_owned(),
-                Source::test(accessed, PathBuf::from("accessed.zn"), HashMap::new())
-                    .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+        ("accessed.zn", accessed),
+    ])
     .is_ok());
 }
 
@@ -1288,73 +895,17 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other_level_1".to_owned(),
-                Source::test(
-                    other_level_1,
-                    PathBuf::from("other_level_1.zn"),
-                    vec![(
-                        "other_level_2".to_owned(),
-                        Source::test(
-                            other_level_2,
-                            PathBuf::from("other_level_1/other_level_2.zn"),
-                            vec![(
-                                "other_level_3".to_owned(),
-                                Source::test(
-                                    other_level_3,
-                                    PathBuf::from("other_level_1/other_level_2/other_level_3.zn"),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed_level_1".to_owned(),
-                Source::test(
-                    accessed_level_1,
-                    PathBuf::from("accessed_level_1.zn"),
-                    vec![(
-                        "accessed_level_2".to_owned(),
-                        Source::test(
-                            accessed_level_2,
-                            PathBuf::from("accessed_level_1/accessed_level_2.zn"),
-                            vec![(
-                                "accessed_level_3".to_owned(),
-                                Source::test(
-                                    accessed_level_3,
-                                    PathBuf::from(
-                                        "accessed_level_1/accessed_level_2/accessed_level_3.zn"
-                                    ),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other_level_1/mod.zn", other_level_1),
+        ("other_level_1/other_level_2/mod.zn", other_level_2),
+        (
+            "other_level_1/other_level_2/other_level_3.zn",
+            other_level_3
+        ),
+        ("accessed_level_1/mod.zn", accessed_level_1),
+        ("accessed_level_1/accessed_level_2/mod.zn", accessed_level_2),
+    ])
     .is_ok());
 }
 
@@ -1418,72 +969,52 @@ fn main() -> Other {
 }
 "#;
 
-    assert!(crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![
-            (
-                "other_level_1".to_owned(),
-                Source::test(
-                    other_level_1,
-                    PathBuf::from("other_level_1.zn"),
-                    vec![(
-                        "other_level_2".to_owned(),
-                        Source::test(
-                            other_level_2,
-                            PathBuf::from("other_level_1/other_level_2.zn"),
-                            vec![(
-                                "other_level_3".to_owned(),
-                                Source::test(
-                                    other_level_3,
-                                    PathBuf::from("other_level_1/other_level_2/other_level_3.zn"),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-            (
-                "accessed_level_1".to_owned(),
-                Source::test(
-                    accessed_level_1,
-                    PathBuf::from("accessed_level_1.zn"),
-                    vec![(
-                        "accessed_level_2".to_owned(),
-                        Source::test(
-                            accessed_level_2,
-                            PathBuf::from("accessed_level_1/accessed_level_2.zn"),
-                            vec![(
-                                "accessed_level_3".to_owned(),
-                                Source::test(
-                                    accessed_level_3,
-                                    PathBuf::from(
-                                        "accessed_level_1/accessed_level_2/accessed_level_3.zn"
-                                    ),
-                                    HashMap::new()
-                                )
-                                .expect(zinc_const::panic::TEST_DATA_VALID)
-                            ),]
-                            .into_iter()
-                            .collect::<HashMap<String, Source>>()
-                        )
-                        .expect(zinc_const::panic::TEST_DATA_VALID)
-                    ),]
-                    .into_iter()
-                    .collect::<HashMap<String, Source>>()
-                )
-                .expect(zinc_const::panic::TEST_DATA_VALID)
-            ),
-        ]
-        .into_iter()
-        .collect::<HashMap<String, Source>>()
-    )
+    assert!(crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other_level_1/mod.zn", other_level_1),
+        ("other_level_1/other_level_2/mod.zn", other_level_2),
+        (
+            "other_level_1/other_level_2/other_level_3.zn",
+            other_level_3
+        ),
+        ("accessed_level_1/mod.zn", accessed_level_1),
+        ("accessed_level_1/accessed_level_2/mod.zn", accessed_level_2),
+    ])
     .is_ok());
 }
+
+#[test]
+fn error_module_file_not_found_virtual_path() {
+    let one = r#"
+mod two;
+mod unknown;
+"#;
+
+    let two = r#"
+const VALUE: u8 = 42;
+"#;
+
+    let entry = r#"
+mod one;
+
+fn main() -> u8 {
+    one::two::VALUE
+}
+"#;
+
+    let error = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("one/mod.zn", one),
+        ("one/two.zn", two),
+    ])
+    .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        error,
+        Error::Semantic(SemanticError::ModuleFileNotFound {
+            location: Location::test(3, 5),
+            name: "unknown".to_owned(),
+        })
+    );
+    assert!(error.format().contains("src/one/mod.zn:3:5"));
+}
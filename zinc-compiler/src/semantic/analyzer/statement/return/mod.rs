@@ -0,0 +1,80 @@
+//!
+//! The `return` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_syntax::Identifier;
+use zinc_syntax::ReturnStatement;
+
+use crate::generator::statement::r#return::Statement as GeneratorReturnStatement;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+///
+/// The `return` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `return` statement and returns its IR for the next compiler phase.
+    ///
+    /// The expected type is resolved from the hidden `return` item, which is declared in the
+    /// scope of the functions allowed to return early.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        statement: ReturnStatement,
+    ) -> Result<GeneratorReturnStatement, Error> {
+        let location = statement.location;
+
+        if RefCell::borrow(&scope).is_within_unsafe_witness() {
+            return Err(Error::FunctionReturnStatementForbidden { location });
+        }
+
+        let item = RefCell::borrow(&scope)
+            .resolve_item(
+                &Identifier::new(location, Keyword::Return.to_string()),
+                true,
+            )
+            .map_err(|_error| Error::FunctionReturnStatementForbidden { location })?;
+        let reference = RefCell::borrow(&item)
+            .location()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let expected_type = match *RefCell::borrow(&item) {
+            ScopeItem::Type(ref r#type) => r#type.define()?,
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let (found_type, expression) = match statement.expression {
+            Some(expression) => {
+                let (element, expression) =
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                        .analyze(expression)?;
+                (Type::from_element(&element, scope)?, Some(expression))
+            }
+            None => (Type::unit(None), None),
+        };
+
+        if expected_type != found_type {
+            return Err(Error::FunctionReturnStatementType {
+                location,
+                expected: expected_type.to_string(),
+                found: found_type.to_string(),
+                reference,
+            });
+        }
+
+        Ok(GeneratorReturnStatement::new(location, expression))
+    }
+}
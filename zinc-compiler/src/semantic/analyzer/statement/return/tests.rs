@@ -0,0 +1,228 @@
+//!
+//! The `return` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_multiple() {
+    let input = r#"
+fn main(gates: [bool; 3]) -> u8 {
+    if gates[0] {
+        return 1;
+    }
+    if gates[1] && gates[2] {
+        return 2;
+    }
+    if gates[1] {
+        return 3;
+    }
+    4
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_body() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    if value > 10 {
+        return 10;
+    } else {
+        return value;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_branch() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    let value = if condition {
+        return 1;
+    } else {
+        5
+    };
+    value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_loop() {
+    let input = r#"
+fn main(values: [u8; 4], expected: u8) -> u8 {
+    for index in 0..4 {
+        if values[index] == expected {
+            return index as u8;
+        }
+    }
+    255
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_unit() {
+    let input = r#"
+fn main(condition: bool) {
+    if condition {
+        return;
+    }
+    require(false);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_unit_test() {
+    let input = r#"
+fn main() {}
+
+#[test]
+fn test() {
+    if true {
+        return;
+    }
+    require(false);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_type() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    if condition {
+        return true;
+    }
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementType {
+            location: Location::test(4, 9),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(2, 29),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_unit() {
+    let input = r#"
+fn main(condition: bool) -> u8 {
+    if condition {
+        return;
+    }
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementType {
+            location: Location::test(4, 9),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::unit(None).to_string(),
+            reference: Location::test(2, 29),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_forbidden_constant_function() {
+    let input = r#"
+const fn limit(value: u8) -> u8 {
+    return value;
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementForbidden {
+            location: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_forbidden_constructor() {
+    let input = r#"
+contract Test {
+    value: u64;
+
+    pub fn new(value: u64) -> Self {
+        return Self {
+            value: value,
+        };
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementForbidden {
+            location: Location::test(6, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_forbidden_ensures() {
+    let input = r#"
+fn main() {}
+
+#[ensures(result <= limit)]
+fn clamp(value: u8, limit: u8) -> u8 {
+    if value > limit {
+        return limit;
+    }
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReturnStatementForbidden {
+            location: Location::test(7, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
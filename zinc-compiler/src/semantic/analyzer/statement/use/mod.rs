@@ -14,6 +14,7 @@ use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
 
 ///
@@ -25,6 +26,11 @@ impl Analyzer {
     ///
     /// Defines an item imported by the compile-time only `use` statement.
     ///
+    /// The grouped items are resolved relatively to the statement path and defined one by one.
+    ///
+    /// The wildcard imports all the items of the module at the statement path, except the ones
+    /// it has imported by wildcards itself.
+    ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: UseStatement) -> Result<(), Error> {
         let path_location = statement.path.location;
 
@@ -40,12 +46,48 @@ impl Analyzer {
             }
         };
 
-        let item = Scope::resolve_path(scope.clone(), &path)?.borrow().clone();
-        let identifier = match statement.alias_identifier {
-            Some(alias_identifier) => alias_identifier,
-            None => path.last().to_owned(),
+        if statement.is_glob {
+            let module_scope = match *RefCell::borrow(&Scope::resolve_path(scope.clone(), &path)?) {
+                ScopeItem::Module(ref module) => module.define()?,
+                ref item => {
+                    return Err(Error::UseStatementGlobExpectedModule {
+                        location: path_location,
+                        found: item.to_string(),
+                    })
+                }
+            };
+            let items = RefCell::borrow(&module_scope).glob_items();
+
+            return Scope::define_glob_items(scope, path_location, items);
+        }
+
+        let group = match statement.group {
+            Some(group) => group,
+            None => {
+                let item = Scope::resolve_path(scope.clone(), &path)?.borrow().clone();
+                let identifier = match statement.alias_identifier {
+                    Some(alias_identifier) => alias_identifier,
+                    None => path.last().to_owned(),
+                };
+                Scope::define_import(scope, identifier, path_location, item.wrap())?;
+
+                return Ok(());
+            }
         };
-        Scope::define_item(scope, identifier, item.wrap())?;
+
+        for group_item in group.into_iter() {
+            let mut path = path.clone();
+            for element in group_item.path.into_iter() {
+                path.push_element(element);
+            }
+
+            let item = Scope::resolve_path(scope.clone(), &path)?.borrow().clone();
+            let identifier = match group_item.alias_identifier {
+                Some(alias_identifier) => alias_identifier,
+                None => path.last().to_owned(),
+            };
+            Scope::define_import(scope.clone(), identifier, group_item.location, item.wrap())?;
+        }
 
         Ok(())
     }
@@ -76,6 +76,72 @@ fn main() -> u8 {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_group() {
+    let input = r#"
+enum Data {
+    A = 1,
+    B = 2,
+    C = 3,
+}
+
+use Data::{A, B, C};
+
+fn main() -> u8 {
+    (A + B + C) as u8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_group_with_aliases() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+impl Data {
+    const C: u8 = 42;
+
+    pub fn method() -> u8 {
+        25
+    }
+}
+
+use Data::{C as CONSTANT, method as answer,};
+
+fn main() -> u8 {
+    CONSTANT + answer()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_group_item_undeclared() {
+    let input = r#"
+enum Data {
+    A = 1,
+}
+
+use Data::{A, Missing};
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemUndeclared {
+        location: Location::test(6, 15),
+        name: "Missing".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_expected_path() {
     let input = r#"
@@ -100,3 +166,198 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_glob() {
+    let utils = r#"
+const LIMIT: u8 = 42;
+
+struct Pair {
+    a: u8,
+    b: u8,
+}
+
+fn sum(pair: Pair) -> u8 {
+    pair.a + pair.b
+}
+"#;
+
+    let entry = r#"
+mod utils;
+
+use utils::*;
+
+fn main() -> u8 {
+    let pair = Pair { a: 1, b: 2 };
+    sum(pair) + LIMIT
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("utils.zn", utils),
+    ]);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_glob_with_explicit_import() {
+    let utils = r#"
+const LIMIT: u8 = 42;
+"#;
+
+    let entry = r#"
+mod utils;
+
+use utils::LIMIT;
+use utils::*;
+
+fn main() -> u8 {
+    LIMIT
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("utils.zn", utils),
+    ]);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_glob_conflict() {
+    let utils = r#"
+const LIMIT: u8 = 42;
+"#;
+
+    let entry = r#"
+mod utils;
+
+use utils::*;
+
+const LIMIT: u8 = 25;
+
+fn main() -> u8 {
+    LIMIT
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::UseStatementGlobConflict {
+        location: Location::test(4, 5),
+        name: "LIMIT".to_owned(),
+        reference: Some(Location::test(6, 1)),
+        imported: Some(Location::test(2, 1)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("utils.zn", utils),
+    ]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_glob_conflict_explicit() {
+    let utils = r#"
+const LIMIT: u8 = 42;
+"#;
+
+    let entry = r#"
+mod utils;
+
+use utils::*;
+
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    const LIMIT: u8 = 25;
+}
+
+use Data::LIMIT;
+
+fn main() -> u8 {
+    LIMIT
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(14, 5),
+        name: "LIMIT".to_owned(),
+        kind: "imported constant".to_owned(),
+        reference: Some(Location::test(4, 5)),
+        reference_kind: "imported constant".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("utils.zn", utils),
+    ]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_glob_not_transitive() {
+    let inner = r#"
+const LIMIT: u8 = 42;
+"#;
+
+    let outer = r#"
+mod inner;
+
+use inner::*;
+
+fn double() -> u8 {
+    LIMIT * 2
+}
+"#;
+
+    let entry = r#"
+mod outer;
+
+use outer::*;
+
+fn main() -> u8 {
+    double() + LIMIT
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemUndeclared {
+        location: Location::test(7, 16),
+        name: "LIMIT".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("outer/mod.zn", outer),
+        ("outer/inner.zn", inner),
+    ]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_glob_expected_module() {
+    let input = r#"
+struct Data {
+    a: u8,
+}
+
+use Data::*;
+
+fn main() {}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(matches!(
+        result,
+        Err(Error::Semantic(SemanticError::UseStatementGlobExpectedModule { location, .. }))
+            if location == Location::test(6, 5)
+    ));
+}
@@ -0,0 +1,139 @@
+//!
+//! The `while` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+use num::Signed;
+
+use zinc_syntax::WhileStatement;
+
+use crate::generator::statement::r#while::Statement as GeneratorWhileLoopStatement;
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::assignment::Tracker as AssignmentTracker;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+
+///
+/// The `while` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a while-loop and returns its IR for the next compiler phase.
+    ///
+    /// The only attribute allowed for while-loops is `#[max_iterations(N)]`, which is required,
+    /// since the loop is unrolled at compile time.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: WhileStatement,
+    ) -> Result<GeneratorWhileLoopStatement, Error> {
+        let location = statement.location;
+
+        let mut bound = None;
+        for attribute in statement.attributes.into_iter() {
+            let attribute_location = attribute.location;
+            let name = attribute
+                .elements
+                .first()
+                .map(|element| element.path.to_string())
+                .unwrap_or_default();
+
+            match Attribute::try_from(attribute)? {
+                Attribute::MaxIterations { expression, .. } if bound.is_none() => {
+                    bound = Some(expression)
+                }
+                Attribute::MaxIterations { .. } => {
+                    return Err(Error::AttributeUnexpected {
+                        location: attribute_location,
+                        name,
+                        item: "`while` loop more than once".to_owned(),
+                    })
+                }
+                _ => {
+                    return Err(Error::AttributeUnexpected {
+                        location: attribute_location,
+                        name,
+                        item: "`while` loop".to_owned(),
+                    })
+                }
+            }
+        }
+        let bound = bound.ok_or(Error::WhileStatementBoundMissing { location })?;
+        let bound_location = bound.location;
+
+        let mut scope_stack = ScopeStack::new(scope);
+
+        let iterations_count =
+            match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                .analyze(bound)
+                .map_err(|error| match error {
+                    Error::ExpressionNonConstantElement { location, found } => {
+                        Error::WhileStatementBoundExpectedConstantInteger { location, found }
+                    }
+                    error => error,
+                })? {
+                (Element::Constant(Constant::Integer(integer)), _intermediate)
+                    if !integer.value.is_negative() =>
+                {
+                    integer.to_usize()?
+                }
+                (element, _intermediate) => {
+                    return Err(Error::WhileStatementBoundExpectedConstantInteger {
+                        location: bound_location,
+                        found: element.to_string(),
+                    });
+                }
+            };
+
+        let mut assignment_tracker = AssignmentTracker::new(scope_stack.top());
+
+        scope_stack.push(None, ScopeType::Loop);
+
+        let condition_location = statement.condition.location;
+        let (condition_result, condition) =
+            ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                .analyze(statement.condition)?;
+        match Type::from_element(&condition_result, scope_stack.top())? {
+            Type::Boolean(_) => {}
+            r#type => {
+                return Err(Error::ForStatementWhileExpectedBooleanCondition {
+                    location: condition_location,
+                    found: r#type.to_string(),
+                });
+            }
+        }
+
+        let block_location = statement.block.location;
+        let (_element, body) =
+            BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
+
+        scope_stack.pop();
+
+        assignment_tracker.branch(block_location);
+        assignment_tracker.skip(location);
+        assignment_tracker.finish();
+
+        Ok(GeneratorWhileLoopStatement::new(
+            location,
+            iterations_count,
+            condition,
+            body,
+        ))
+    }
+}
@@ -0,0 +1,254 @@
+//!
+//! The `while` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::item::variable::Variable as ScopeVariableItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+
+#[test]
+fn ok_ordinar() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut remaining = value;
+    let mut steps = 0;
+    #[max_iterations(8)]
+    while remaining > 0 {
+        remaining /= 2;
+        steps += 1;
+    }
+    steps
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_expression_bound() {
+    let input = r#"
+const SIZE: u8 = 4;
+
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(SIZE * 2)]
+    while result < 200 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_nested_in_for() {
+    let input = r#"
+fn main(values: [u8; 3]) -> u8 {
+    let mut total = 0;
+    for i in 0..3 {
+        let mut value = values[i];
+        #[max_iterations(4)]
+        while value >= 10 {
+            value -= 10;
+            total += 1;
+        }
+    }
+    total
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_zero_iterations() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(0)]
+    while true {
+        result = 0;
+    }
+    result
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_return() {
+    let input = r#"
+fn main(values: [u8; 4], expected: u8) -> u8 {
+    let mut index = 0;
+    #[max_iterations(4)]
+    while index < 4 {
+        if values[index] == expected {
+            return index;
+        }
+        index += 1;
+    }
+    255
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_bound_missing() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    while result < 200 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::WhileStatementBoundMissing {
+        location: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_non_constant() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(value)]
+    while result < 200 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::WhileStatementBoundExpectedConstantInteger {
+            location: Location::test(4, 22),
+            found: ScopeItem::Variable(ScopeVariableItem::new(
+                Some(Location::test(2, 9)),
+                false,
+                "value".to_owned(),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            ))
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_integer() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(true)]
+    while result < 200 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::WhileStatementBoundExpectedConstantInteger {
+            location: Location::test(4, 22),
+            found: Element::Constant(Constant::Boolean(BooleanConstant::new(
+                Location::test(4, 22),
+                true,
+            )))
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_boolean_condition() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(8)]
+    while 42 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ForStatementWhileExpectedBooleanCondition {
+            location: Location::test(5, 11),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_attribute_unexpected() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    let mut result = value;
+    #[max_iterations(8)]
+    #[test]
+    while result < 200 {
+        result += 25;
+    }
+    result
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(5, 5),
+        name: "test".to_owned(),
+        item: "`while` loop".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_attribute_unexpected_function() {
+    let input = r#"
+#[max_iterations(8)]
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnexpected {
+        location: Location::test(2, 1),
+        name: "max_iterations".to_owned(),
+        item: "function".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -50,6 +50,19 @@ impl Binding {
             r#type,
         }
     }
+
+    ///
+    /// Whether the binding is the mutable `self` instance, which the method returns along with
+    /// its result to be written back to the memory place the method is called for.
+    ///
+    /// The contract instance is not returned, since its fields are written to the storage directly.
+    ///
+    pub fn is_instance_written_back(&self) -> bool {
+        self.is_mutable
+            && self.identifier.is_self_lowercase()
+            && !matches!(self.r#type, Type::Contract(_))
+            && self.r#type.size() > 0
+    }
 }
 
 impl Binder {
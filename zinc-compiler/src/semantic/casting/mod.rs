@@ -30,9 +30,20 @@ impl Caster {
     /// enum<b1> -> i<b2>
     /// enum<b1> -> u<b2>
     /// enum<b1> -> field
+    /// u<b1> -> enum<b2> (variant-checked)
+    /// i<b1> -> enum<b2> (variant-checked)
+    /// field -> enum<b2> (variant-checked)
+    /// u<b1> -> nominal
+    /// nominal -> u<b2>
+    /// nominal -> field
+    /// field -> u<b2> (range-checked at runtime)
     /// T -> T (no effect, no errors)
     ///
     /// `b1` and `b2` are bitlengths
+    /// `enum<b2>` must have at least one variant to be casted into
+    /// `variant-checked` means that constants are checked at compile time and the other values
+    /// are checked at runtime to be one of the enumeration variants
+    /// `nominal` is a built-in nominal type, e.g. `zksync::Address`
     /// `T` is any type
     ///
     /// For more information on type semantics, see the official Zinc book.
@@ -48,6 +59,17 @@ impl Caster {
             (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::Field(_)) => Ok(()),
+            (Type::IntegerUnsigned { .. }, Type::Enumeration(inner))
+            | (Type::IntegerSigned { .. }, Type::Enumeration(inner))
+            | (Type::Field(_), Type::Enumeration(inner))
+                if !inner.values.is_empty() =>
+            {
+                Ok(())
+            }
+            (Type::IntegerUnsigned { .. }, Type::Nominal(_)) => Ok(()),
+            (Type::Nominal(_), Type::IntegerUnsigned { .. }) => Ok(()),
+            (Type::Nominal(_), Type::Field(_)) => Ok(()),
+            (Type::Field(_), Type::IntegerUnsigned { .. }) => Ok(()),
             (from, to) if from == to => Ok(()),
 
             (from @ Type::IntegerUnsigned { .. }, to) => Err(Error::CastingToInvalidType {
@@ -58,6 +80,10 @@ impl Caster {
                 from: from.to_string(),
                 to: to.to_string(),
             }),
+            (from @ Type::Field(_), to) => Err(Error::CastingToInvalidType {
+                from: from.to_string(),
+                to: to.to_string(),
+            }),
             (from, to) => Err(Error::CastingFromInvalidType {
                 from: from.to_string(),
                 to: to.to_string(),
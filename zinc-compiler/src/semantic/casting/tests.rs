@@ -2,6 +2,8 @@
 //! The type caster tests.
 //!
 
+use num::BigInt;
+
 use zinc_lexical::Location;
 
 use crate::error::Error;
@@ -153,6 +155,56 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_integer_unsigned_to_enumeration() {
+    let input = r#"
+enum List {
+    FIRST = 1,
+    SECOND = 2,
+}
+
+fn main(value: u8) -> List {
+    value as List
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_integer_signed_to_enumeration() {
+    let input = r#"
+enum List {
+    NEGATIVE = -1,
+    POSITIVE = 1,
+}
+
+fn main(value: i8) -> List {
+    value as List
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_integer_constant_to_enumeration() {
+    let input = r#"
+enum List {
+    FIRST = 1,
+    SECOND = 2,
+}
+
+const VALUE: List = 2 as List;
+
+fn main() -> [u8; 2] {
+    [0; VALUE as u64]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn ok_integer_field_to_field() {
     let input = r#"
@@ -181,11 +233,109 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_field_to_integer_unsigned() {
+    let input = r#"
+fn main() {
+    let value: field = 42;
+    let result = value as u64;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_field_to_integer_unsigned_constant_max() {
+    let input = r#"
+const VALUE: u8 = 255 as field as u8;
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_field_to_integer_unsigned_constant_overflow() {
+    let input = r#"
+const VALUE: u8 = 256 as field as u8;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorCastingOverflow {
+        location: Location::test(2, 19),
+        value: BigInt::from(256),
+        r#type: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_integer_constant_to_enumeration_invalid_variant() {
+    let input = r#"
+enum List {
+    FIRST = 1,
+    SECOND = 2,
+}
+
+const VALUE: List = 3 as List;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorCastingInvalidVariant {
+            location: Location::test(7, 21),
+            value: BigInt::from(3),
+            r#type: "List".to_owned(),
+            variants: vec!["FIRST = 1".to_owned(), "SECOND = 2".to_owned()],
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_integer_to_enumeration_let_implicit() {
+    let input = r#"
+enum List {
+    FIRST = 1,
+    SECOND = 2,
+}
+
+fn main(value: u8) {
+    let result: List = value;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorCastingTypesMismatch {
+            location: Location::test(8, 24),
+            inner: CastingError::CastingToInvalidType {
+                from: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+                to: "enumeration List".to_owned(),
+            },
+            reference: Location::test(8, 17),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_casting_from_invalid_type() {
     let input = r#"
 fn main() {
-    let value: field = 0;
+    let value: bool = false;
     let result = value as u8;
 }
 "#;
@@ -194,7 +344,7 @@ fn main() {
         SemanticError::OperatorCastingTypesMismatch {
             location: Location::test(4, 18),
             inner: CastingError::CastingFromInvalidType {
-                from: Type::field(None).to_string(),
+                from: Type::boolean(None).to_string(),
                 to: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
             },
             reference: Location::test(4, 27),
@@ -255,3 +405,28 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_casting_field_to_integer_signed() {
+    let input = r#"
+fn main() {
+    let value: field = 0;
+    let result = value as i8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorCastingTypesMismatch {
+            location: Location::test(4, 18),
+            inner: CastingError::CastingToInvalidType {
+                from: Type::field(None).to_string(),
+                to: Type::integer_signed(None, zinc_const::bitlength::BYTE).to_string(),
+            },
+            reference: Location::test(4, 27),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -2,6 +2,8 @@
 //! The semantic analyzer element dot contract storage data field access.
 //!
 
+use crate::semantic::element::r#type::Type;
+
 ///
 /// The contract field dot access data.
 ///
@@ -12,8 +14,11 @@
 pub struct ContractField {
     /// The name of the tuple or structure element.
     pub name: String,
-    /// The position of the element in the contract storage.
+    /// The position of the first field leaf in the contract storage.
     pub position: usize,
+    /// The field type, which is walked through to find the leaves of the accessed place, if
+    /// the field is flattened into several leaves.
+    pub r#type: Type,
     /// The offset of the element in the tuple or structure.
     pub offset: usize,
     /// The size of the contract storage field.
@@ -36,6 +41,7 @@ impl ContractField {
     pub fn new(
         name: String,
         position: usize,
+        r#type: Type,
         offset: usize,
         element_size: usize,
         total_size: usize,
@@ -46,6 +52,7 @@ impl ContractField {
             name,
             offset,
             position,
+            r#type,
             element_size,
             total_size,
             is_immutable,
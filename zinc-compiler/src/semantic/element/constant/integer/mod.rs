@@ -23,8 +23,10 @@ use std::ops::Sub;
 use num::BigInt;
 use num::Signed;
 use num::ToPrimitive;
+use num::Zero;
 
 use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 use zinc_syntax::IntegerLiteral;
 
@@ -34,6 +36,7 @@ use crate::semantic::element::constant::range::Range;
 use crate::semantic::element::constant::range_inclusive::RangeInclusive;
 use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::nominal::Nominal;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 
@@ -41,6 +44,7 @@ use crate::semantic::error::Error;
 /// Integer constants consist of the value, sign, and bitlength.
 /// If a constant belongs to an enumeration, the enumeration type is stored in `enumeration`.
 /// Enumeration uniquely defines the constant type, even if the sign and bitlength are the same.
+/// The same applies to the built-in nominal types stored in `nominal`.
 ///
 #[derive(Debug, Clone)]
 pub struct Integer {
@@ -54,8 +58,12 @@ pub struct Integer {
     pub bitlength: usize,
     /// If the constant is an enumeration variant.
     pub enumeration: Option<Enumeration>,
+    /// If the constant belongs to a built-in nominal type.
+    pub nominal: Option<Nominal>,
     /// If the constant was created from an integer literal.
     pub is_literal: bool,
+    /// The named constant declaration location, if the value is referenced by the constant name.
+    pub declaration: Option<Location>,
 }
 
 impl Integer {
@@ -75,6 +83,8 @@ impl Integer {
             is_signed,
             bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal,
         }
     }
@@ -86,6 +96,81 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    ///
+    /// Set the nominal type for the constant, if the constant belongs to a built-in nominal type.
+    ///
+    pub fn set_nominal(&mut self, nominal: Nominal) {
+        self.nominal = Some(nominal);
+    }
+
+    ///
+    /// Converts the `#[bitflags]` enumeration variant into the enumeration flag set, so the
+    /// variants and flag sets of the same enumeration can be combined with each other.
+    ///
+    fn enumeration_into_flags(&mut self) {
+        if self
+            .enumeration
+            .as_ref()
+            .map_or(false, Enumeration::is_bitflags)
+        {
+            self.nominal = self.enumeration.take().map(Nominal::flags);
+        }
+    }
+
+    ///
+    /// Checks if the `operator` is applicable to the constant of the nominal type, if it is one.
+    ///
+    fn check_nominal(&self, operator: &str, is_allowed: fn(&Nominal) -> bool) -> Result<(), Error> {
+        match self.nominal {
+            Some(ref nominal) if !is_allowed(nominal) => {
+                Err(Error::OperatorNominalOperandForbidden {
+                    location: self.location,
+                    operator: operator.to_owned(),
+                    found: nominal.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks that both operands of the ordering `operator` fit the allowed `field` ordering
+    /// bitlength, if they are `field` constants, so the constant folding matches the runtime
+    /// comparison rules.
+    ///
+    fn check_field_ordering(&self, other: &Self, operator: &str) -> Result<(), Error> {
+        if self.bitlength != zinc_const::bitlength::FIELD {
+            return Ok(());
+        }
+
+        for constant in [self, other].iter() {
+            if constant.value.is_negative()
+                || constant.value.bits() as usize > zinc_const::bitlength::FIELD_ORDERING_MAX
+            {
+                return Err(Error::OperatorOrderingFieldUnbounded {
+                    location: constant.location,
+                    operator: operator.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Sets the named constant declaration location, if the value is referenced by the constant name.
+    ///
+    pub fn set_declaration(&mut self, declaration: Location) {
+        self.declaration = Some(declaration);
+    }
+
+    ///
+    /// Checks whether the constant is zero.
+    ///
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
     ///
     /// Returns the inner `BigInt` value.
     ///
@@ -239,6 +324,9 @@ impl Integer {
             });
         }
 
+        self.check_nominal(">=", Nominal::is_ordered)?;
+        self.check_field_ordering(&other, ">=")?;
+
         let result = BooleanConstant::new(self.location, self.value >= other.value);
 
         let operator = GeneratorExpressionOperator::greater_equals_inferred(
@@ -277,6 +365,9 @@ impl Integer {
             });
         }
 
+        self.check_nominal("<=", Nominal::is_ordered)?;
+        self.check_field_ordering(&other, "<=")?;
+
         let result = BooleanConstant::new(self.location, self.value <= other.value);
 
         let operator = GeneratorExpressionOperator::lesser_equals_inferred(
@@ -315,6 +406,9 @@ impl Integer {
             });
         }
 
+        self.check_nominal(">", Nominal::is_ordered)?;
+        self.check_field_ordering(&other, ">")?;
+
         let result = BooleanConstant::new(self.location, self.value > other.value);
 
         let operator = GeneratorExpressionOperator::greater_inferred(
@@ -353,6 +447,9 @@ impl Integer {
             });
         }
 
+        self.check_nominal("<", Nominal::is_ordered)?;
+        self.check_field_ordering(&other, "<")?;
+
         let result = BooleanConstant::new(self.location, self.value < other.value);
 
         let operator = GeneratorExpressionOperator::lesser_inferred(
@@ -372,6 +469,9 @@ impl BitOr for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitor(mut self, mut other: Self) -> Self::Output {
+        self.enumeration_into_flags();
+        other.enumeration_into_flags();
+
         let location_1 = self.location;
         let location_2 = other.location;
 
@@ -392,6 +492,8 @@ impl BitOr for Integer {
             });
         }
 
+        self.check_nominal("|", Nominal::is_combinable)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -410,6 +512,8 @@ impl BitOr for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: self.nominal,
+            declaration: None,
             is_literal: self.is_literal && other.is_literal,
         };
 
@@ -450,6 +554,8 @@ impl BitXor for Integer {
             });
         }
 
+        self.check_nominal("^", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -468,6 +574,8 @@ impl BitXor for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal: self.is_literal && other.is_literal,
         };
 
@@ -488,6 +596,9 @@ impl BitAnd for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitand(mut self, mut other: Self) -> Self::Output {
+        self.enumeration_into_flags();
+        other.enumeration_into_flags();
+
         let location_1 = self.location;
         let location_2 = other.location;
 
@@ -508,6 +619,8 @@ impl BitAnd for Integer {
             });
         }
 
+        self.check_nominal("&", Nominal::is_combinable)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -526,6 +639,8 @@ impl BitAnd for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: self.nominal,
+            declaration: None,
             is_literal: self.is_literal && other.is_literal,
         };
 
@@ -546,6 +661,9 @@ impl Shl<Self> for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shl(self, other: Self) -> Self::Output {
+        self.check_nominal("<<", |_| false)?;
+        other.check_nominal("<<", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -582,6 +700,8 @@ impl Shl<Self> for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal: self.is_literal,
         };
 
@@ -595,6 +715,9 @@ impl Shr<Self> for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shr(self, other: Self) -> Self::Output {
+        self.check_nominal(">>", |_| false)?;
+        other.check_nominal(">>", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -631,6 +754,8 @@ impl Shr<Self> for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal: self.is_literal,
         };
 
@@ -664,6 +789,8 @@ impl Add for Integer {
             });
         }
 
+        self.check_nominal("+", Nominal::is_additive)?;
+
         let result = self.value + other.value;
         if result.is_negative() && !self.is_signed {
             return Err(Error::OperatorAdditionOverflow {
@@ -697,6 +824,8 @@ impl Add for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: self.nominal,
+            declaration: None,
             is_literal,
         };
 
@@ -737,6 +866,8 @@ impl Sub for Integer {
             });
         }
 
+        self.check_nominal("-", Nominal::is_additive)?;
+
         let result = self.value - other.value;
         if result.is_negative() && !self.is_signed {
             return Err(Error::OperatorSubtractionOverflow {
@@ -770,6 +901,8 @@ impl Sub for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: self.nominal,
+            declaration: None,
             is_literal,
         };
 
@@ -810,6 +943,8 @@ impl Mul for Integer {
             });
         }
 
+        self.check_nominal("*", |_| false)?;
+
         let result = self.value * other.value;
         if result.is_negative() && !self.is_signed {
             return Err(Error::OperatorMultiplicationOverflow {
@@ -843,6 +978,8 @@ impl Mul for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal,
         };
 
@@ -883,6 +1020,8 @@ impl Div for Integer {
             });
         }
 
+        self.check_nominal("/", |_| false)?;
+
         if self.bitlength == zinc_const::bitlength::FIELD {
             return Err(Error::OperatorDivisionFieldOperandForbidden {
                 location: self.location,
@@ -892,6 +1031,7 @@ impl Div for Integer {
         let (result, _remainder) = zinc_math::euclidean_div_rem(&self.value, &other.value).ok_or(
             Error::OperatorDivisionByZero {
                 location: other.location,
+                reference: other.declaration,
             },
         )?;
         if result.is_negative() && !self.is_signed {
@@ -926,6 +1066,8 @@ impl Div for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal,
         };
 
@@ -966,6 +1108,8 @@ impl Rem for Integer {
             });
         }
 
+        self.check_nominal("%", |_| false)?;
+
         if self.bitlength == zinc_const::bitlength::FIELD {
             return Err(Error::OperatorRemainderFieldOperandForbidden {
                 location: self.location,
@@ -975,6 +1119,7 @@ impl Rem for Integer {
         let (_quotient, result) = zinc_math::euclidean_div_rem(&self.value, &other.value).ok_or(
             Error::OperatorRemainderOfDivisionByZero {
                 location: other.location,
+                reference: other.declaration,
             },
         )?;
         if result.is_negative() && !self.is_signed {
@@ -1009,6 +1154,8 @@ impl Rem for Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal,
         };
 
@@ -1029,28 +1176,46 @@ impl Integer {
     ///
     /// Executes the `as` casting operator.
     ///
+    /// The casts which change only the signedness reinterpret the two's complement representation
+    /// the same way the virtual machine does, whereas the integer literals are always checked
+    /// to fit the type.
+    ///
     pub fn cast(
         self,
         is_signed: bool,
         bitlength: usize,
+        nominal: Option<Nominal>,
     ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
-        if self.value.is_negative() && !is_signed {
+        let target_type = zinc_types::IntegerType::new(is_signed, bitlength);
+        let value = if !self.is_literal
+            && target_type.is_reinterpreted_from(&zinc_types::IntegerType::new(
+                self.is_signed,
+                self.bitlength,
+            )) {
+            target_type.reinterpret(self.value)
+        } else {
+            self.value
+        };
+
+        if value.is_negative() && !is_signed {
             return Err(Error::OperatorCastingOverflow {
                 location: self.location,
-                value: self.value,
+                value,
                 r#type: Type::scalar(Some(self.location), is_signed, bitlength).to_string(),
             });
         }
 
-        let inferred_bitlength = zinc_math::infer_minimal_bitlength(&self.value, is_signed)
-            .map_err(|error| Error::InvalidInteger {
-                location: self.location,
-                inner: error,
+        let inferred_bitlength =
+            zinc_math::infer_minimal_bitlength(&value, is_signed).map_err(|error| {
+                Error::InvalidInteger {
+                    location: self.location,
+                    inner: error,
+                }
             })?;
         if inferred_bitlength > bitlength {
             return Err(Error::OperatorCastingOverflow {
                 location: self.location,
-                value: self.value,
+                value,
                 r#type: Type::scalar(Some(self.location), is_signed, bitlength).to_string(),
             });
         }
@@ -1067,10 +1232,60 @@ impl Integer {
 
         let result = Self {
             location: self.location,
-            value: self.value,
+            value,
             is_signed,
             bitlength,
             enumeration: None,
+            nominal,
+            declaration: None,
+            is_literal: false,
+        };
+
+        Ok((result, operator))
+    }
+
+    ///
+    /// Executes the `as` casting operator into an enumeration type.
+    ///
+    /// The constant is checked at compile time to be one of the enumeration variants.
+    ///
+    pub fn cast_enumeration(
+        self,
+        enumeration: Enumeration,
+    ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
+        if !enumeration.values.contains(&self.value) {
+            return Err(Error::OperatorCastingInvalidVariant {
+                location: self.location,
+                value: self.value,
+                r#type: enumeration.identifier.to_owned(),
+                variants: enumeration
+                    .names
+                    .iter()
+                    .zip(enumeration.values.iter())
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect(),
+            });
+        }
+
+        let operator =
+            if self.is_signed != enumeration.is_signed || self.bitlength != enumeration.bitlength {
+                GeneratorExpressionOperator::try_casting(&Type::scalar(
+                    Some(self.location),
+                    enumeration.is_signed,
+                    enumeration.bitlength,
+                ))
+            } else {
+                None
+            };
+
+        let result = Self {
+            location: self.location,
+            value: self.value,
+            is_signed: enumeration.is_signed,
+            bitlength: enumeration.bitlength,
+            enumeration: Some(enumeration),
+            nominal: None,
+            declaration: None,
             is_literal: false,
         };
 
@@ -1081,6 +1296,8 @@ impl Integer {
     /// Executes the `~` bitwise NOT operator.
     ///
     pub fn bitwise_not(self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        self.check_nominal("~", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location,
@@ -1099,6 +1316,8 @@ impl Integer {
             is_signed: self.is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal: self.is_literal,
         };
 
@@ -1112,6 +1331,8 @@ impl Neg for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn neg(self) -> Self::Output {
+        self.check_nominal("-", |_| false)?;
+
         let location = self.location;
 
         if self.bitlength == zinc_const::bitlength::FIELD {
@@ -1144,6 +1365,8 @@ impl Neg for Integer {
             is_signed,
             bitlength: self.bitlength,
             enumeration: None,
+            nominal: None,
+            declaration: None,
             is_literal: self.is_literal,
         };
 
@@ -1216,9 +1439,10 @@ impl Integer {
 
 impl ITyped for Integer {
     fn r#type(&self) -> Type {
-        match self.enumeration {
-            Some(ref enumeration) => Type::Enumeration(enumeration.to_owned()),
-            None => Type::scalar(Some(self.location), self.is_signed, self.bitlength),
+        match (self.enumeration.as_ref(), self.nominal.as_ref()) {
+            (Some(enumeration), _) => Type::Enumeration(enumeration.to_owned()),
+            (None, Some(nominal)) => Type::Nominal(nominal.to_owned()),
+            (None, None) => Type::scalar(Some(self.location), self.is_signed, self.bitlength),
         }
     }
 
@@ -1230,6 +1454,7 @@ impl ITyped for Integer {
                 (None, None) => true,
                 _ => false,
             }
+            && self.nominal == other.nominal
     }
 }
 
@@ -1238,16 +1463,19 @@ impl TryFrom<&IntegerLiteral> for Integer {
 
     ///
     /// Converts `literal` to a `BigInt` and its bitlength.
-    /// For now, the minimal bitlength enough to contain the number is inferred.
+    ///
+    /// If the literal has a type suffix, the suffix type is used, and the value must fit it.
+    /// Otherwise, the minimal bitlength enough to contain the number is inferred.
     ///
     fn try_from(literal: &IntegerLiteral) -> Result<Self, Self::Error> {
         let value_string = match literal.inner {
-            LexicalIntegerLiteral::Binary { ref inner } => format!("0b{}", inner.to_owned()),
-            LexicalIntegerLiteral::Octal { ref inner } => format!("0o{}", inner.to_owned()),
+            LexicalIntegerLiteral::Binary { ref inner, .. } => format!("0b{}", inner.to_owned()),
+            LexicalIntegerLiteral::Octal { ref inner, .. } => format!("0o{}", inner.to_owned()),
             LexicalIntegerLiteral::Decimal {
                 ref integer,
                 ref fractional,
                 ref exponent,
+                ..
             } => {
                 let mut string = integer.to_owned();
                 if let Some(fractional) = fractional {
@@ -1260,7 +1488,9 @@ impl TryFrom<&IntegerLiteral> for Integer {
                 }
                 string
             }
-            LexicalIntegerLiteral::Hexadecimal { ref inner } => format!("0x{}", inner.to_owned()),
+            LexicalIntegerLiteral::Hexadecimal { ref inner, .. } => {
+                format!("0x{}", inner.to_owned())
+            }
         };
 
         let value = zinc_math::bigint_from_str(value_string.as_str()).map_err(|error| {
@@ -1270,14 +1500,41 @@ impl TryFrom<&IntegerLiteral> for Integer {
             }
         })?;
 
-        let bitlength = zinc_math::infer_minimal_bitlength(&value, false).map_err(|error| {
-            Error::InvalidInteger {
-                location: literal.location,
-                inner: error,
+        let (is_signed, bitlength) = match literal.inner.suffix() {
+            Some(Keyword::IntegerUnsigned { bitlength }) => (false, *bitlength),
+            Some(Keyword::IntegerSigned { bitlength }) => (true, *bitlength),
+            Some(Keyword::Field) => (false, zinc_const::bitlength::FIELD),
+            Some(_) | None => {
+                let bitlength =
+                    zinc_math::infer_minimal_bitlength(&value, false).map_err(|error| {
+                        Error::InvalidInteger {
+                            location: literal.location,
+                            inner: error,
+                        }
+                    })?;
+
+                return Ok(Self::new(literal.location, value, false, bitlength, true));
             }
-        })?;
+        };
+
+        let fits = zinc_math::infer_minimal_bitlength(&value, is_signed)
+            .map(|minimal_bitlength| minimal_bitlength <= bitlength)
+            .unwrap_or_default();
+        if !fits {
+            return Err(Error::InvalidIntegerSuffix {
+                location: literal.location,
+                value,
+                r#type: Type::scalar(None, is_signed, bitlength).to_string(),
+            });
+        }
 
-        Ok(Self::new(literal.location, value, false, bitlength, true))
+        Ok(Self::new(
+            literal.location,
+            value,
+            is_signed,
+            bitlength,
+            false,
+        ))
     }
 }
 
@@ -1293,6 +1550,7 @@ impl PartialEq<Self> for Integer {
             && self.is_signed == other.is_signed
             && self.bitlength == other.bitlength
             && are_enum_types_equal
+            && self.nominal == other.nominal
     }
 }
 
@@ -1235,6 +1235,57 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_overflow_casting_signed_narrowing() {
+    let input = r#"
+fn main() {
+    let value = (-1 as i16) as u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorCastingOverflow {
+        location: Location::test(3, 19),
+        value: BigInt::from(-1),
+        r#type: Type::integer(
+            Some(Location::default()),
+            false,
+            zinc_const::bitlength::BYTE,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_casting_reinterpreted_signed_to_unsigned() {
+    let input = r#"
+const VALUE: u8 = (-1 as i8) as u8;
+const EXTENDED: u16 = (-1 as i8) as u16;
+
+fn main() -> ([u8; 255], [u8; 65535]) {
+    ([0; VALUE as u64], [0; EXTENDED as u64])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_casting_reinterpreted_unsigned_to_signed() {
+    let input = r#"
+const VALUE: i8 = (200 as u8) as i8;
+
+fn main() -> [u8; 1] {
+    [0; (VALUE + 57) as u64]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_overflow_negation_signed_positive() {
     let input = r#"
@@ -1570,6 +1621,7 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::OperatorDivisionByZero {
         location: Location::test(3, 22),
+        reference: None,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -1588,6 +1640,126 @@ fn main() {
     let expected = Err(Error::Semantic(
         SemanticError::OperatorRemainderOfDivisionByZero {
             location: Location::test(3, 22),
+            reference: None,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_division_constant() {
+    let input = r#"
+const VALUE: u8 = 1 / 0;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorDivisionByZero {
+        location: Location::test(2, 23),
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_division_named_constant() {
+    let input = r#"
+const ZERO: u8 = 0;
+
+const VALUE: u8 = 42 / ZERO;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorDivisionByZero {
+        location: Location::test(4, 24),
+        reference: Some(Location::test(2, 7)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_division_folded_expression() {
+    let input = r#"
+fn main() {
+    let value = 42 / (3 - 3);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorDivisionByZero {
+        location: Location::test(3, 23),
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_division_runtime_value() {
+    let input = r#"
+const ZERO: u8 = 0;
+
+fn main(value: u8) -> u8 {
+    value / ZERO
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorDivisionByZero {
+        location: Location::test(5, 13),
+        reference: Some(Location::test(2, 7)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_remainder_named_constant() {
+    let input = r#"
+const ZERO: u8 = 0;
+
+fn main() {
+    let value = 42 % ZERO;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorRemainderOfDivisionByZero {
+            location: Location::test(5, 22),
+            reference: Some(Location::test(2, 7)),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zero_remainder_assignment() {
+    let input = r#"
+fn main(mut value: u8) -> u8 {
+    value %= 0;
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorRemainderOfDivisionByZero {
+            location: Location::test(3, 14),
+            reference: None,
         },
     ));
 
@@ -1675,3 +1847,108 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_suffix_array_repeat() {
+    let input = r#"
+fn main() -> [u64; 4] {
+    [0xff_u64; 4]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_suffix_array_repeat_size() {
+    let input = r#"
+fn main() -> [field; 4] {
+    [1field; 0b100u64]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_suffix_annotation_matching() {
+    let input = r#"
+fn main() -> field {
+    let value: u64 = 42u64;
+    let hash: field = 0xff_ff_field;
+    hash
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_suffix_out_of_range() {
+    let input = r#"
+fn main() {
+    let value = 256u8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::InvalidIntegerSuffix {
+        location: Location::test(3, 17),
+        value: BigInt::from(256),
+        r#type: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_suffix_annotation_mismatch() {
+    let input = r#"
+fn main() {
+    let value: u8 = 1u16;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::IntegerSuffixTypesMismatch {
+        location: Location::test(3, 21),
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 2).to_string(),
+        reference: Location::test(3, 16),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_suffix_array_repeat_mismatch() {
+    let input = r#"
+fn main() -> [u8; 4] {
+    [0u64; 4]
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionReturnType {
+        location: Location::test(3, 5),
+        function: "main".to_owned(),
+        expected: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            4,
+        )
+        .to_string(),
+        found: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE * 8),
+            4,
+        )
+        .to_string(),
+        reference: Location::test(2, 14),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -218,6 +218,25 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
+            (constant_1 @ Self::Array(_), constant_2)
+            | (constant_1 @ Self::Tuple(_), constant_2)
+            | (constant_1 @ Self::Structure(_), constant_2) => {
+                let location = constant_1.location();
+                let size = Type::structural_equality_size(
+                    location,
+                    constant_1.r#type(),
+                    constant_2.r#type(),
+                    true,
+                )?;
+
+                Ok((
+                    Self::Boolean(Boolean::new(
+                        location,
+                        constant_1.is_structurally_equal(&constant_2),
+                    )),
+                    GeneratorExpressionOperator::equals_structural(size),
+                ))
+            }
             (constant_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
@@ -257,6 +276,25 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
+            (constant_1 @ Self::Array(_), constant_2)
+            | (constant_1 @ Self::Tuple(_), constant_2)
+            | (constant_1 @ Self::Structure(_), constant_2) => {
+                let location = constant_1.location();
+                let size = Type::structural_equality_size(
+                    location,
+                    constant_1.r#type(),
+                    constant_2.r#type(),
+                    false,
+                )?;
+
+                Ok((
+                    Self::Boolean(Boolean::new(
+                        location,
+                        !constant_1.is_structurally_equal(&constant_2),
+                    )),
+                    GeneratorExpressionOperator::not_equals_structural(size),
+                ))
+            }
             (constant_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
@@ -264,6 +302,41 @@ impl Constant {
         }
     }
 
+    ///
+    /// Compares the constants element-wise.
+    ///
+    /// The constant types must be checked to be equal beforehand.
+    ///
+    fn is_structurally_equal(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unit(_), Self::Unit(_)) => true,
+            (Self::Boolean(constant_1), Self::Boolean(constant_2)) => {
+                constant_1.inner == constant_2.inner
+            }
+            (Self::Integer(constant_1), Self::Integer(constant_2)) => {
+                constant_1.value == constant_2.value
+            }
+            (Self::Array(constant_1), Self::Array(constant_2)) => constant_1
+                .values
+                .iter()
+                .zip(constant_2.values.iter())
+                .all(|(constant_1, constant_2)| constant_1.is_structurally_equal(constant_2)),
+            (Self::Tuple(constant_1), Self::Tuple(constant_2)) => constant_1
+                .values
+                .iter()
+                .zip(constant_2.values.iter())
+                .all(|(constant_1, constant_2)| constant_1.is_structurally_equal(constant_2)),
+            (Self::Structure(constant_1), Self::Structure(constant_2)) => {
+                constant_1.values.iter().zip(constant_2.values.iter()).all(
+                    |((_, constant_1), (_, constant_2))| {
+                        constant_1.is_structurally_equal(constant_2)
+                    },
+                )
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Executes the `>=` greater-equals comparison operator.
     ///
@@ -587,16 +660,25 @@ impl Constant {
             reference: to.location().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
         })?;
 
-        let (is_signed, bitlength) = match to {
-            Type::IntegerUnsigned { bitlength, .. } => (false, bitlength),
-            Type::IntegerSigned { bitlength, .. } => (true, bitlength),
-            Type::Field(_) => (false, zinc_const::bitlength::FIELD),
+        let (is_signed, bitlength, nominal) = match to {
+            Type::IntegerUnsigned { bitlength, .. } => (false, bitlength, None),
+            Type::IntegerSigned { bitlength, .. } => (true, bitlength, None),
+            Type::Field(_) => (false, zinc_const::bitlength::FIELD, None),
+            Type::Nominal(inner) => (false, inner.bitlength, Some(inner)),
+            Type::Enumeration(inner) => {
+                return Ok(match self {
+                    Self::Integer(integer) => integer
+                        .cast_enumeration(inner)
+                        .map(|(integer, operator)| (Self::Integer(integer), operator))?,
+                    operand => (operand, None),
+                })
+            }
             _ => return Ok((self, None)),
         };
 
         Ok(match self {
             Self::Integer(integer) => integer
-                .cast(is_signed, bitlength)
+                .cast(is_signed, bitlength, nominal)
                 .map(|(integer, operator)| (Self::Integer(integer), operator))?,
             operand => (operand, None),
         })
@@ -743,6 +825,17 @@ impl Constant {
         }
     }
 
+    ///
+    /// Sets the named constant declaration location.
+    ///
+    /// Only integers keep the declaration location, which is used in the division by zero errors.
+    ///
+    pub fn set_declaration(&mut self, value: Location) {
+        if let Self::Integer(inner) = self {
+            inner.set_declaration(value);
+        }
+    }
+
     ///
     /// Returns the constant location in the code.
     ///
@@ -131,6 +131,7 @@ impl Structure {
                 .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
                 .identifier,
             field_name: identifier.name,
+            place: None,
         })
     }
 }
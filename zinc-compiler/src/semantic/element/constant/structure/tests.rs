@@ -26,6 +26,7 @@ fn main() {
         location: Location::test(9, 7),
         r#type: "Data".to_owned(),
         field_name: "b".to_owned(),
+        place: None,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
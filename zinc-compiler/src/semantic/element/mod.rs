@@ -407,6 +407,12 @@ impl Element {
                     Self::Value(value_2) => value_1
                         .div(value_2)
                         .map(|(_value, operator)| (place, operator)),
+                    Self::Constant(Constant::Integer(ref integer)) if integer.is_zero() => {
+                        Err(Error::OperatorDivisionByZero {
+                            location: integer.location,
+                            reference: integer.declaration,
+                        })
+                    }
                     Self::Constant(value_2) => value_1
                         .div(Value::try_from_constant(value_2)?)
                         .map(|(_value, operator)| (place, operator)),
@@ -443,6 +449,12 @@ impl Element {
                     Self::Value(value_2) => value_1
                         .rem(value_2)
                         .map(|(_value, operator)| (place, operator)),
+                    Self::Constant(Constant::Integer(ref integer)) if integer.is_zero() => {
+                        Err(Error::OperatorRemainderOfDivisionByZero {
+                            location: integer.location,
+                            reference: integer.declaration,
+                        })
+                    }
                     Self::Constant(value_2) => value_1
                         .rem(Value::try_from_constant(value_2)?)
                         .map(|(_value, operator)| (place, operator)),
@@ -737,10 +749,42 @@ impl Element {
         }
     }
 
+    ///
+    /// Checks that the `operator` operands are not arrays, tuples, or structures, since only
+    /// the equality operators are defined for them.
+    ///
+    fn check_ordering_operands(
+        operator: &str,
+        operand_1: &Self,
+        operand_2: &Self,
+    ) -> Result<(), Error> {
+        for operand in [operand_1, operand_2].iter() {
+            let r#type = match operand {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                _ => continue,
+            };
+
+            if let Type::Array(_) | Type::Tuple(_) | Type::Structure(_) = r#type {
+                return Err(Error::OperatorOrderingUndefined {
+                    location: operand
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: operator.to_owned(),
+                    found: r#type.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     ///
     /// Executes the `>=` greater-equals comparison operator.
     ///
     pub fn greater_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        Self::check_ordering_operands(">=", &self, &other)?;
+
         match (self, other) {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .greater_equals(value_2)
@@ -785,6 +829,8 @@ impl Element {
     /// Executes the `<=` lesser-equals comparison operator.
     ///
     pub fn lesser_equals(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        Self::check_ordering_operands("<=", &self, &other)?;
+
         match (self, other) {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .lesser_equals(value_2)
@@ -829,6 +875,8 @@ impl Element {
     /// Executes the `>` greater comparison operator.
     ///
     pub fn greater(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        Self::check_ordering_operands(">", &self, &other)?;
+
         match (self, other) {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .greater(value_2)
@@ -873,6 +921,8 @@ impl Element {
     /// Executes the `<` lesser comparison operator.
     ///
     pub fn lesser(self, other: Self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        Self::check_ordering_operands("<", &self, &other)?;
+
         match (self, other) {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .lesser(value_2)
@@ -1270,6 +1320,14 @@ impl Div for Element {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .div(value_2)
                 .map(|(value, operator)| (Self::Value(value), operator)),
+            (Element::Value(_), Element::Constant(Constant::Integer(ref integer)))
+                if integer.is_zero() =>
+            {
+                Err(Error::OperatorDivisionByZero {
+                    location: integer.location,
+                    reference: integer.declaration,
+                })
+            }
             (Element::Value(value_1), Element::Constant(value_2)) => value_1
                 .div(Value::try_from_constant(value_2)?)
                 .map(|(value, operator)| (Self::Value(value), operator)),
@@ -1315,6 +1373,14 @@ impl Rem for Element {
             (Element::Value(value_1), Element::Value(value_2)) => value_1
                 .rem(value_2)
                 .map(|(value, operator)| (Self::Value(value), operator)),
+            (Element::Value(_), Element::Constant(Constant::Integer(ref integer)))
+                if integer.is_zero() =>
+            {
+                Err(Error::OperatorRemainderOfDivisionByZero {
+                    location: integer.location,
+                    reference: integer.declaration,
+                })
+            }
             (Element::Value(value_1), Element::Constant(value_2)) => value_1
                 .rem(Value::try_from_constant(value_2)?)
                 .map(|(value, operator)| (Self::Value(value), operator)),
@@ -1514,6 +1580,11 @@ impl Element {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
+                        Type::Nominal(ref inner) if inner.is_flags() => inner
+                            .enumeration
+                            .as_ref()
+                            .and_then(|enumeration| enumeration.flags_scope.to_owned())
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                         _ => {
                             return place
                                 .structure_field(identifier)
@@ -1558,6 +1629,11 @@ impl Element {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
+                        Type::Nominal(ref inner) if inner.is_flags() => inner
+                            .enumeration
+                            .as_ref()
+                            .and_then(|enumeration| enumeration.flags_scope.to_owned())
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                         _ => {
                             return value
                                 .structure_field(identifier)
@@ -1605,6 +1681,11 @@ impl Element {
                         Type::Structure(ref inner) => inner.scope.to_owned(),
                         Type::Enumeration(ref inner) => inner.scope.to_owned(),
                         Type::Contract(ref inner) => inner.scope.to_owned(),
+                        Type::Nominal(ref inner) if inner.is_flags() => inner
+                            .enumeration
+                            .as_ref()
+                            .and_then(|enumeration| enumeration.flags_scope.to_owned())
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                         _ => {
                             return constant.structure_field(identifier).map(
                                 |(constant, access)| {
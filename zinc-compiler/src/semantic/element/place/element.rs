@@ -23,6 +23,9 @@ pub enum Element {
         expression: GeneratorExpression,
         /// The array access data, which helps to generate the target code without redundant calculations.
         access: IndexAccess,
+        /// The index variable name, if the index expression is a single identifier.
+        /// Other expressions are elided as `[..]` in the place path.
+        identifier: Option<String>,
     },
     /// Array indexing with a constant index, which is the second operand of the `[]` index operator.
     IndexConstant {
@@ -64,7 +67,13 @@ pub enum Element {
 impl fmt::Display for Element {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::IndexExpression { .. } => write!(f, "[<runtime>]"),
+            Self::IndexExpression {
+                identifier: Some(identifier),
+                ..
+            } => write!(f, "[{}]", identifier),
+            Self::IndexExpression {
+                identifier: None, ..
+            } => write!(f, "[..]"),
             Self::IndexConstant { constant, .. } => write!(f, "[{}]", constant.value),
             Self::IndexRange { start, end, .. } => write!(f, "[{} .. {}]", start, end),
             Self::IndexRangeInclusive { start, end, .. } => write!(f, "[{} ..= {}]", start, end),
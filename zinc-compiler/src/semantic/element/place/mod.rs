@@ -15,6 +15,7 @@ use num::BigInt;
 use num::Signed;
 use num::ToPrimitive;
 
+use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
 use crate::semantic::element::access::dot::contract_field::ContractField as ContractFieldAccess;
@@ -49,6 +50,9 @@ pub struct Place {
     pub memory_type: MemoryType,
     /// The memory place path, which consists of array indexes and fields accesses.
     pub elements: Vec<PlaceElement>,
+    /// The location of the declaration or the branch, which leave the variable unassigned.
+    /// `None` if the variable is definitely assigned.
+    pub uninitialized: Option<Location>,
 }
 
 impl Place {
@@ -71,6 +75,7 @@ impl Place {
             is_mutable,
             memory_type,
             elements: vec![],
+            uninitialized: None,
         }
     }
 
@@ -310,6 +315,7 @@ impl Place {
                     location: identifier.location,
                     r#type: structure.identifier.to_owned(),
                     field_name: identifier.name,
+                    place: Some(self.to_string()),
                 })
             }
             Type::Contract(ref contract) => {
@@ -318,10 +324,17 @@ impl Place {
                 if let Ok(item) = item {
                     if let ScopeItem::Field(ref field) = *item.borrow() {
                         let element_size = field.r#type.size();
+                        let position = contract
+                            .fields
+                            .iter()
+                            .take(field.index)
+                            .map(|field| field.r#type.storage_leaf_count())
+                            .sum();
 
                         let access = DotAccessVariant::ContractField(ContractFieldAccess::new(
                             identifier.name,
-                            field.index,
+                            position,
+                            field.r#type.to_owned(),
                             offset,
                             element_size,
                             total_size,
@@ -344,6 +357,7 @@ impl Place {
                     location: identifier.location,
                     r#type: contract.identifier.to_owned(),
                     field_name: identifier.name,
+                    place: Some(self.to_string()),
                 })
             }
             ref r#type => Err(Error::OperatorDotFirstOperandExpectedInstance {
@@ -177,6 +177,7 @@ fn main() {
     let expected = Err(Error::Semantic(SemanticError::MutatingImmutableMemory {
         location: Location::test(4, 5),
         name: "result".to_string(),
+        place: "result".to_string(),
         reference: Some(Location::test(3, 9)),
     }));
 
@@ -185,6 +186,41 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_mutating_immutable_memory_nested() {
+    let input = r#"
+struct Order {
+    amount: u8,
+}
+
+struct Book {
+    orders: [Order; 2],
+}
+
+fn main() {
+    let book = Book { orders: [Order { amount: 0 }; 2] };
+    let i = 1;
+    book.orders[i].amount = 42;
+}
+"#;
+
+    let error =
+        crate::semantic::tests::compile_entry(input).expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        error,
+        Error::Semantic(SemanticError::MutatingImmutableMemory {
+            location: Location::test(13, 5),
+            name: "book".to_owned(),
+            place: "book.orders[i].amount".to_owned(),
+            reference: Some(Location::test(11, 9)),
+        })
+    );
+    assert!(error
+        .format()
+        .contains("cannot assign to `book.orders[i].amount`, as the variable `book` is immutable"));
+}
+
 #[test]
 fn error_mutating_immutable_contract_field_address() {
     let input = r#"
@@ -199,6 +235,7 @@ contract Test {
         SemanticError::MutatingImmutableContractField {
             location: Location::test(4, 9),
             name: zinc_const::contract::FIELD_NAME_ADDRESS.to_string(),
+            place: format!("self.{}", zinc_const::contract::FIELD_NAME_ADDRESS),
         },
     ));
 
@@ -218,6 +255,7 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::MutatingWithDifferentType {
         location: Location::test(4, 5),
+        place: "result".to_owned(),
         expected: Type::boolean(None).to_string(),
         found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
     }));
@@ -418,6 +456,35 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_mutating_with_different_type_nested() {
+    let input = r#"
+struct Order {
+    amount: u8,
+}
+
+struct Book {
+    orders: [Order; 2],
+}
+
+fn main() {
+    let mut book = Book { orders: [Order { amount: 0 }; 2] };
+    book.orders[1].amount = false;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MutatingWithDifferentType {
+        location: Location::test(12, 5),
+        place: "book.orders[1].amount".to_owned(),
+        expected: Type::boolean(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_structure_field_does_not_exist() {
     let input = r#"
@@ -437,6 +504,7 @@ fn main() {
         location: Location::test(10, 22),
         r#type: "Data".to_owned(),
         field_name: "b".to_owned(),
+        place: Some("data".to_owned()),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -460,9 +528,45 @@ contract Test {
         location: Location::test(6, 14),
         r#type: "Test".to_owned(),
         field_name: "b".to_owned(),
+        place: Some("self".to_owned()),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_structure_field_does_not_exist_nested() {
+    let input = r#"
+struct Order {
+    amount: u8,
+}
+
+struct Book {
+    orders: [Order; 2],
+}
+
+fn main() {
+    let book = Book { orders: [Order { amount: 0 }; 2] };
+    let i = 0;
+    let value = book.orders[i + 1].amout;
+}
+"#;
+
+    let error =
+        crate::semantic::tests::compile_entry(input).expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        error,
+        Error::Semantic(SemanticError::StructureFieldDoesNotExist {
+            location: Location::test(13, 36),
+            r#type: "Order".to_owned(),
+            field_name: "amout".to_owned(),
+            place: Some("book.orders[..]".to_owned()),
+        })
+    );
+    assert!(error
+        .format()
+        .contains("field or method `amout` of `book.orders[..]` does not exist in `Order`"));
+}
@@ -921,6 +921,82 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_ordering_field_unbounded() {
+    let input = r#"
+fn main(a: field, b: u64) -> bool {
+    (b as field) < a
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorOrderingFieldUnbounded {
+            location: Location::test(3, 20),
+            operator: "<".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_ordering_field_unbounded_arithmetic() {
+    let input = r#"
+fn main(a: u64) -> bool {
+    let value = (a as field) - 1;
+    value >= 0
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorOrderingFieldUnbounded {
+            location: Location::test(4, 5),
+            operator: ">=".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_ordering_field_unbounded_constant() {
+    let input = r#"
+const MAX: field = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000;
+
+fn main() {
+    let value = 1 as field > MAX;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorOrderingFieldUnbounded {
+            location: Location::test(5, 30),
+            operator: ">".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_operator_ordering_field_bounded() {
+    let input = r#"
+const MAX: field = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff;
+
+fn main(a: u64, b: u248) -> bool {
+    (a as field) < (b as field) && (a as field) <= MAX && 1 as field < MAX
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_operator_greater_equals_1st_operand_expected_evaluable() {
     let input = r#"
@@ -1793,3 +1869,54 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_operator_ordering_undefined_array() {
+    let input = r#"
+fn main() {
+    let array_1 = [1, 2, 3];
+    let array_2 = [1, 2, 4];
+    let value = array_1 < array_2;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorOrderingUndefined {
+        location: Location::test(5, 17),
+        operator: "<".to_owned(),
+        found: Type::array(
+            Some(Location::test(5, 17)),
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            3,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_ordering_undefined_structure() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+fn main() {
+    let data_1 = Data { value: 1 };
+    let data_2 = Data { value: 2 };
+    let value = data_1 >= data_2;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorOrderingUndefined {
+        location: Location::test(9, 17),
+        operator: ">=".to_owned(),
+        found: "structure Data".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -13,6 +13,7 @@ use std::rc::Rc;
 
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::Identifier;
 
 use crate::semantic::element::r#type::function::intrinsic::contract_fetch::Function as ContractFetchFunction;
@@ -45,6 +46,9 @@ pub struct Contract {
     pub type_id: usize,
     /// The ordered contract storage fields array.
     pub fields: Vec<Field>,
+    /// The `#[invariant(...)]` conditions with their attribute locations, which are checked at
+    /// the end of every method taking `mut self`.
+    pub invariants: Vec<(ExpressionTree, Location)>,
     /// The contract scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
 }
@@ -59,6 +63,7 @@ impl Contract {
         project: zinc_project::ManifestProject,
         type_id: usize,
         fields: Vec<Field>,
+        invariants: Vec<(ExpressionTree, Location)>,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         Scope::define_field(
@@ -92,6 +97,7 @@ impl Contract {
             project,
             type_id,
             fields,
+            invariants,
             scope: scope.clone(),
         };
 
@@ -17,10 +17,12 @@ contract Contract {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
         location: Location::test(5, 5),
         name: "b".to_owned(),
+        kind: "field".to_owned(),
         reference: Some(Location::test(4, 5)),
+        reference_kind: "field".to_owned(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
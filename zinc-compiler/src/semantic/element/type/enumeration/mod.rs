@@ -11,13 +11,26 @@ use std::fmt;
 use std::rc::Rc;
 
 use num::BigInt;
+use num::One;
+use num::Signed;
+use num::Zero;
 
 use zinc_lexical::Location;
 use zinc_syntax::Variant;
 
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::intrinsic::enumeration_from::Function as EnumerationFromFunction;
+use crate::semantic::element::r#type::function::intrinsic::flags_contains::Function as FlagsContainsFunction;
+use crate::semantic::element::r#type::function::intrinsic::flags_is_empty::Function as FlagsIsEmptyFunction;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunction;
+use crate::semantic::element::r#type::function::Function;
+use crate::semantic::element::r#type::nominal::Nominal;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
 
 ///
@@ -27,6 +40,10 @@ use crate::semantic::scope::Scope;
 /// and the implementation `scope`, which contains the enumeration variants and
 /// reference to its parent scope.
 ///
+/// The `#[bitflags]` enumerations also declare the `Flags` type in their scope, whose values are
+/// produced by combining the variants with the `|` and `&` operators. The flag set methods are
+/// declared in the separate `flags_scope`.
+///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     /// The enumeration type location in the code.
@@ -35,6 +52,8 @@ pub struct Enumeration {
     pub identifier: String,
     /// The unique enumeration type ID.
     pub type_id: usize,
+    /// If the enumeration elements are signed, that is, if any of the variants is negative.
+    pub is_signed: bool,
     /// The enumeration elements bitlength, enough to fit the largest variant.
     pub bitlength: usize,
     /// The ordered list of the variant names.
@@ -45,6 +64,8 @@ pub struct Enumeration {
     pub generics: Vec<String>,
     /// The enumeration scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
+    /// The flag set scope, where the flag set methods are declared, if the enumeration is `#[bitflags]`.
+    pub flags_scope: Option<Rc<RefCell<Scope>>>,
 }
 
 impl Enumeration {
@@ -57,12 +78,16 @@ impl Enumeration {
         type_id: usize,
         variants: Vec<Variant>,
         generics: Vec<String>,
+        is_bitflags: bool,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         let mut variants_bigint = Vec::with_capacity(variants.len());
         for variant in variants.iter() {
-            let value = IntegerConstant::try_from(&variant.literal)?;
-            variants_bigint.push((variant.identifier.to_owned(), value.value.to_owned()));
+            let mut value = IntegerConstant::try_from(&variant.literal)?.value;
+            if variant.is_negative {
+                value = -value;
+            }
+            variants_bigint.push((variant.identifier.to_owned(), value));
         }
         let names: Vec<String> = variants_bigint
             .iter()
@@ -87,36 +112,160 @@ impl Enumeration {
             }
         }
 
+        if is_bitflags {
+            for (index, bigint) in bigints.iter().enumerate() {
+                if !bigint.is_positive() || !(bigint & (bigint - BigInt::one())).is_zero() {
+                    let variant = variants
+                        .get(index)
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    return Err(Error::TypeBitflagsVariantNotPowerOfTwo {
+                        location: variant.identifier.location,
+                        r#type: identifier,
+                        variant_name: variant.identifier.name.to_owned(),
+                        variant_value: bigint.to_owned(),
+                    });
+                }
+            }
+        }
+
+        let is_signed = bigints.iter().any(BigInt::is_negative);
         let minimal_bitlength = IntegerConstant::minimal_bitlength_bigints(
             bigints.iter().collect::<Vec<&BigInt>>().as_slice(),
-            false,
+            is_signed,
             location,
         )?;
+        if minimal_bitlength > zinc_const::bitlength::INTEGER_MAX && is_signed {
+            let value = bigints
+                .iter()
+                .max_by_key(|value| value.abs())
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+            return Err(Error::InvalidInteger {
+                location,
+                inner: zinc_math::Error::Overflow {
+                    value: value.to_owned(),
+                    is_signed,
+                    bitlength: zinc_const::bitlength::INTEGER_MAX,
+                },
+            });
+        }
+
+        let flags_scope = if is_bitflags {
+            Some(Scope::new_child(
+                format!("{}::{}", identifier, Nominal::IDENTIFIER_FLAGS),
+                ScopeType::Enumeration,
+                scope.clone(),
+            ))
+        } else {
+            None
+        };
 
         let enumeration = Self {
             location: Some(location),
             identifier,
             type_id,
+            is_signed,
             bitlength: minimal_bitlength,
             names,
             values: bigints,
             generics,
             scope: scope.clone(),
+            flags_scope,
         };
 
         for (identifier, value) in variants_bigint.into_iter() {
             let identifier_location = identifier.location;
 
-            let mut constant =
-                IntegerConstant::new(identifier_location, value, false, minimal_bitlength, false);
+            let mut constant = IntegerConstant::new(
+                identifier_location,
+                value,
+                is_signed,
+                minimal_bitlength,
+                false,
+            );
 
             constant.set_enumeration(enumeration.clone());
 
             Scope::define_variant(scope.clone(), identifier, Constant::Integer(constant))?;
         }
 
+        let is_from_variant = enumeration
+            .names
+            .iter()
+            .any(|name| name == EnumerationFromFunction::IDENTIFIER);
+        if !enumeration.values.is_empty() && !is_from_variant {
+            Scope::insert_item(
+                scope.clone(),
+                EnumerationFromFunction::IDENTIFIER.to_owned(),
+                ScopeItem::Type(ScopeTypeItem::new_defined(
+                    Some(location),
+                    Type::Function(Function::Intrinsic(IntrinsicFunction::enumeration_from(
+                        enumeration.clone(),
+                    ))),
+                    false,
+                    None,
+                ))
+                .wrap(),
+            );
+        }
+
+        if let Some(ref flags_scope) = enumeration.flags_scope {
+            let is_flags_variant = enumeration
+                .names
+                .iter()
+                .any(|name| name == Nominal::IDENTIFIER_FLAGS);
+            if !is_flags_variant {
+                Scope::insert_item(
+                    scope,
+                    Nominal::IDENTIFIER_FLAGS.to_owned(),
+                    ScopeItem::Type(ScopeTypeItem::new_defined(
+                        Some(location),
+                        Type::Nominal(Nominal::flags(enumeration.clone())),
+                        false,
+                        None,
+                    ))
+                    .wrap(),
+                );
+            }
+
+            Scope::insert_item(
+                flags_scope.clone(),
+                FlagsContainsFunction::IDENTIFIER.to_owned(),
+                ScopeItem::Type(ScopeTypeItem::new_defined(
+                    Some(location),
+                    Type::Function(Function::Intrinsic(IntrinsicFunction::flags_contains(
+                        enumeration.clone(),
+                    ))),
+                    false,
+                    None,
+                ))
+                .wrap(),
+            );
+            Scope::insert_item(
+                flags_scope.clone(),
+                FlagsIsEmptyFunction::IDENTIFIER.to_owned(),
+                ScopeItem::Type(ScopeTypeItem::new_defined(
+                    Some(location),
+                    Type::Function(Function::Intrinsic(IntrinsicFunction::flags_is_empty(
+                        enumeration.clone(),
+                    ))),
+                    false,
+                    None,
+                ))
+                .wrap(),
+            );
+        }
+
         Ok(enumeration)
     }
+
+    ///
+    /// Checks if the enumeration variants are `#[bitflags]`, that is, can be combined into flag sets.
+    ///
+    pub fn is_bitflags(&self) -> bool {
+        self.flags_scope.is_some()
+    }
 }
 
 impl PartialEq<Self> for Enumeration {
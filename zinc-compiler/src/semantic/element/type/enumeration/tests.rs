@@ -34,3 +34,179 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_negative() {
+    let input = r#"
+enum Delta {
+    Down = -1,
+    Up = 1,
+}
+
+fn main() -> i8 {
+    Delta::Down as i8 + Delta::Up as i8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_duplicate_field_negative() {
+    let input = r#"
+enum List {
+    A = -1,
+    B = -1,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeDuplicateVariantValue {
+        location: Location::test(3, 5),
+        r#type: "List".to_owned(),
+        variant_name: "A".to_owned(),
+        variant_value: BigInt::from(-1),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_negative_overflow() {
+    let input = r#"
+enum List {
+    A = -0x100000000000000000000000000000000000000000000000000000000000000,
+    B = 1,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::InvalidInteger {
+        location: Location::test(2, 1),
+        inner: zinc_math::Error::Overflow {
+            value: -zinc_math::bigint_from_str(
+                "0x100000000000000000000000000000000000000000000000000000000000000",
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+            is_signed: true,
+            bitlength: zinc_const::bitlength::INTEGER_MAX,
+        },
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_bitflags() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+fn main(permissions: Permission::Flags) -> (Permission::Flags, bool, bool) {
+    let all = Permission::Read | Permission::Write | Permission::Execute;
+    let mut granted = permissions & all;
+    granted = granted | Permission::Read;
+
+    (granted, granted.contains(Permission::Write), (permissions & Permission::Execute).is_empty())
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_bitflags_constant() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+const READ_WRITE: Permission::Flags = Permission::Read | Permission::Write;
+const NONE: Permission::Flags = READ_WRITE & Permission::Execute;
+
+fn main() -> ([u8; 3], [u8; 1]) {
+    (
+        [0; READ_WRITE as u8],
+        [0; if NONE.is_empty() { 1 } else { 2 }],
+    )
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_bitflags_variant_not_power_of_two() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    ReadWrite = 3,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeBitflagsVariantNotPowerOfTwo {
+            location: Location::test(6, 5),
+            r#type: "Permission".to_owned(),
+            variant_name: "ReadWrite".to_owned(),
+            variant_value: BigInt::from(3),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bitflags_mixed_enumeration() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+}
+
+enum List {
+    A = 1,
+}
+
+fn main() -> Permission::Flags {
+    Permission::Read | List::A
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorBitwiseOrTypesMismatch {
+            location: Location::test(12, 5),
+            first: "Permission::Flags".to_owned(),
+            second: "enumeration List".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
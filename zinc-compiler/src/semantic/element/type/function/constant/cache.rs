@@ -0,0 +1,357 @@
+//!
+//! The semantic analyzer constant function call cache.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::scope::Scope;
+use crate::timings::TIMINGS;
+
+thread_local! {
+    ///
+    /// The cache of the thread the compiler is running in.
+    ///
+    static CACHE: RefCell<Cache> = RefCell::new(Cache::new());
+}
+
+///
+/// The cache key, which consists of the function unique type ID and the structural key
+/// of its argument values.
+///
+pub type Key = (usize, String);
+
+///
+/// The constant function call cache, where the call results are memoized by the function and
+/// its argument values.
+///
+/// A constant function body is analyzed in the scope of its call site, so the names it does not
+/// declare itself may resolve to different items at different call sites. That is why every
+/// result is stored along with the unique IDs of such items, and is only reused if the names
+/// resolve to the same items at the next call site. The items never change after their
+/// definition, so no other invalidation is needed.
+///
+pub struct Cache {
+    /// Whether the results are stored and reused.
+    is_enabled: bool,
+    /// The stored results.
+    entries: HashMap<Key, Vec<Entry>>,
+    /// The function calls being evaluated, from the outermost to the innermost.
+    calls: Vec<Call>,
+    /// The calls whose results have been taken from the cache.
+    hits: usize,
+    /// The calls which have been evaluated.
+    misses: usize,
+}
+
+///
+/// The stored constant function call result.
+///
+struct Entry {
+    /// The names resolved beyond the function scope, and the unique IDs of their items.
+    dependencies: Vec<(String, Option<usize>)>,
+    /// The call result.
+    result: Constant,
+}
+
+///
+/// The constant function call being evaluated.
+///
+struct Call {
+    /// The function body scope, which is checked when a name resolution leaves a scope.
+    scope: *const Scope,
+    /// The names resolved beyond the function scope.
+    dependencies: Vec<String>,
+}
+
+impl Cache {
+    /// The entries hashmap default capacity.
+    const ENTRIES_INITIAL_CAPACITY: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            is_enabled: true,
+            entries: HashMap::with_capacity(Self::ENTRIES_INITIAL_CAPACITY),
+            calls: Vec::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    ///
+    /// Enables or disables the cache of the current thread, and drops the stored results
+    /// and statistics.
+    ///
+    /// The compiler output must be the same either way, so the switch is only used for testing.
+    ///
+    #[cfg(test)]
+    pub fn set_enabled(is_enabled: bool) {
+        CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            cache.is_enabled = is_enabled;
+            cache.entries.clear();
+            cache.hits = 0;
+            cache.misses = 0;
+        });
+    }
+
+    ///
+    /// Returns the cache hits and misses of the current thread.
+    ///
+    #[cfg(test)]
+    pub fn statistics() -> (usize, usize) {
+        CACHE.with(|cache| {
+            let cache = cache.borrow();
+            (cache.hits, cache.misses)
+        })
+    }
+
+    ///
+    /// Builds the cache key for the function `type_id` called with `arguments`.
+    ///
+    /// Returns `None` if the cache is disabled.
+    ///
+    pub fn key(type_id: usize, arguments: &[(String, Constant)]) -> Option<Key> {
+        if !CACHE.with(|cache| cache.borrow().is_enabled) {
+            return None;
+        }
+
+        let arguments = arguments
+            .iter()
+            .map(|(_name, constant)| Self::constant_key(constant))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        Some((type_id, arguments))
+    }
+
+    ///
+    /// Returns the stored result for `key`, whose dependencies resolve to the same items from
+    /// the call site `scope`.
+    ///
+    pub fn get(key: &Key, scope: Rc<RefCell<Scope>>) -> Option<Constant> {
+        let names: Vec<String> = CACHE.with(|cache| {
+            let cache = cache.borrow();
+            let mut names: Vec<String> = cache
+                .entries
+                .get(key)
+                .into_iter()
+                .flatten()
+                .flat_map(|entry| entry.dependencies.iter().map(|(name, _)| name.to_owned()))
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        });
+
+        // the names are resolved beyond the cache borrow, since the resolution is recorded
+        let resolved: HashMap<String, Option<usize>> = names
+            .into_iter()
+            .map(|name| {
+                let item_id = Self::resolve(scope.clone(), name.as_str());
+                (name, item_id)
+            })
+            .collect();
+
+        let result = CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            let result = cache
+                .entries
+                .get(key)
+                .into_iter()
+                .flatten()
+                .find(|entry| {
+                    entry
+                        .dependencies
+                        .iter()
+                        .all(|(name, item_id)| resolved.get(name) == Some(item_id))
+                })
+                .map(|entry| entry.result.clone());
+            if result.is_some() {
+                cache.hits += 1;
+            } else {
+                cache.misses += 1;
+            }
+            result
+        });
+
+        if TIMINGS.is_enabled() {
+            TIMINGS.record_constant_cache(result.is_some());
+        }
+
+        result
+    }
+
+    ///
+    /// Starts recording the names resolved beyond the function body `scope`.
+    ///
+    pub fn start(scope: &Rc<RefCell<Scope>>) {
+        CACHE.with(|cache| {
+            cache.borrow_mut().calls.push(Call {
+                scope: RefCell::as_ptr(scope),
+                dependencies: Vec::new(),
+            })
+        });
+    }
+
+    ///
+    /// Finishes the innermost recording and returns the recorded names.
+    ///
+    pub fn finish() -> Vec<String> {
+        let mut dependencies = CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .calls
+                .pop()
+                .map(|call| call.dependencies)
+                .unwrap_or_default()
+        });
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
+
+    ///
+    /// Stores the call `result` along with the items the `dependencies` resolve to from
+    /// the call site `scope`.
+    ///
+    pub fn insert(
+        key: Key,
+        dependencies: Vec<String>,
+        scope: Rc<RefCell<Scope>>,
+        result: Constant,
+    ) {
+        let dependencies = dependencies
+            .into_iter()
+            .map(|name| {
+                let item_id = Self::resolve(scope.clone(), name.as_str());
+                (name, item_id)
+            })
+            .collect();
+
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entries
+                .entry(key)
+                .or_insert_with(Vec::new)
+                .push(Entry {
+                    dependencies,
+                    result,
+                })
+        });
+    }
+
+    ///
+    /// Records the `name` resolution leaving the `scope`, if the scope belongs to
+    /// a function call being evaluated.
+    ///
+    pub fn record_escape(scope: &Scope, name: &str) {
+        CACHE.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            let scope = scope as *const Scope;
+            if let Some(call) = cache.calls.iter_mut().find(|call| call.scope == scope) {
+                call.dependencies.push(name.to_owned());
+            }
+        });
+    }
+
+    ///
+    /// Resolves the `name` from the `scope`, returning the item unique ID.
+    ///
+    fn resolve(scope: Rc<RefCell<Scope>>, name: &str) -> Option<usize> {
+        let identifier = Identifier::new(Location::default(), name.to_owned());
+        let item = RefCell::borrow(&scope)
+            .resolve_item(&identifier, true)
+            .ok()?;
+        let item_id = RefCell::borrow(&item).item_id();
+        Some(item_id)
+    }
+
+    ///
+    /// Builds the structural key of the `constant` value, which ignores the locations.
+    ///
+    fn constant_key(constant: &Constant) -> String {
+        match constant {
+            Constant::Unit(_) => "()".to_owned(),
+            Constant::Boolean(inner) => inner.inner.to_string(),
+            Constant::Integer(inner) => format!(
+                "{}{}{}{}{}{}",
+                inner.value,
+                if inner.is_signed { 'i' } else { 'u' },
+                inner.bitlength,
+                if inner.is_literal { "l" } else { "" },
+                inner
+                    .enumeration
+                    .as_ref()
+                    .map(|enumeration| format!("e{}", enumeration.type_id))
+                    .unwrap_or_default(),
+                inner
+                    .nominal
+                    .as_ref()
+                    .map(|nominal| format!("n{}", nominal.type_id))
+                    .unwrap_or_default(),
+            ),
+            Constant::Range(inner) => format!(
+                "{}..{}{}{}",
+                inner.start,
+                inner.end,
+                if inner.is_signed { 'i' } else { 'u' },
+                inner.bitlength
+            ),
+            Constant::RangeInclusive(inner) => format!(
+                "{}..={}{}{}",
+                inner.start,
+                inner.end,
+                if inner.is_signed { 'i' } else { 'u' },
+                inner.bitlength
+            ),
+            Constant::String(inner) => format!("{:?}", inner.inner),
+            Constant::Array(inner) => format!(
+                "[{}; {}]",
+                inner.r#type,
+                inner
+                    .values
+                    .iter()
+                    .map(Self::constant_key)
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            Constant::Tuple(inner) => format!(
+                "({})",
+                inner
+                    .values
+                    .iter()
+                    .map(Self::constant_key)
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            Constant::Structure(inner) => format!(
+                "s{}{{{}}}",
+                inner
+                    .r#type
+                    .as_ref()
+                    .map(|r#type| r#type.type_id.to_string())
+                    .unwrap_or_default(),
+                inner
+                    .values
+                    .iter()
+                    .map(|(identifier, value)| format!(
+                        "{}:{}",
+                        identifier.name,
+                        Self::constant_key(value)
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+        }
+    }
+}
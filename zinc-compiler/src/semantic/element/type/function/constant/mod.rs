@@ -5,6 +5,8 @@
 #[cfg(test)]
 mod tests;
 
+pub mod cache;
+
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
@@ -26,6 +28,8 @@ use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
 
+use self::cache::Cache;
+
 ///
 /// The semantic analyzer constant function element.
 ///
@@ -174,6 +178,9 @@ impl Function {
     /// declared in their own `scope`, and then executes the function body in a constant context,
     /// where the result is calculated and checked for possible violations, like integer overflow.
     ///
+    /// The results are memoized, so the calls with the same arguments are only evaluated once.
+    /// See the cache description for details.
+    ///
     pub fn call(
         self,
         arguments: Vec<(String, Constant)>,
@@ -181,6 +188,14 @@ impl Function {
     ) -> Result<Constant, Error> {
         let location = self.location;
 
+        let key = Cache::key(self.type_id, arguments.as_slice());
+        if let Some(ref key) = key {
+            if let Some(constant) = Cache::get(key, scope.clone()) {
+                return Ok(constant);
+            }
+        }
+
+        let call_site_scope = scope.clone();
         let scope = Scope::new_child(self.identifier, ScopeType::Function, scope);
 
         for (name, constant) in arguments.into_iter() {
@@ -195,10 +210,23 @@ impl Function {
             );
         }
 
-        let (element, _intermediate) =
-            BlockExpressionAnalyzer::analyze(scope, self.body, TranslationRule::Constant)?;
+        if key.is_some() {
+            Cache::start(&scope);
+        }
+        let result = BlockExpressionAnalyzer::analyze(scope, self.body, TranslationRule::Constant);
+        let dependencies = match key {
+            Some(_) => Cache::finish(),
+            None => vec![],
+        };
+
+        let (element, _intermediate) = result?;
         match element {
-            Element::Constant(constant) => Ok(constant),
+            Element::Constant(constant) => {
+                if let Some(key) = key {
+                    Cache::insert(key, dependencies, call_site_scope, constant.clone());
+                }
+                Ok(constant)
+            }
             element => Err(Error::ExpressionNonConstantElement {
                 location: element.location().unwrap_or(location),
                 found: element.to_string(),
@@ -2,9 +2,13 @@
 //! The constant function tests.
 //!
 
+use std::time::Instant;
+
 use zinc_lexical::Location;
 
 use crate::error::Error;
+use crate::generator::zinc_vm::tests::compile_with_files;
+use crate::semantic::element::r#type::function::constant::cache::Cache;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
@@ -193,3 +197,122 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+fn compile_bytecode(files: Vec<(&str, String)>, is_cache_enabled: bool) -> (Vec<u8>, usize, usize) {
+    Cache::set_enabled(is_cache_enabled);
+
+    let files: Vec<(&str, &str)> = files
+        .iter()
+        .map(|(name, code)| (*name, code.as_str()))
+        .collect();
+
+    let manifest =
+        zinc_project::Manifest::new("constant_cache", zinc_project::ProjectType::Circuit);
+    let version = manifest.project.version.clone();
+    let bytecode = compile_with_files(manifest, files.as_slice())
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .into_application(false, None)
+        .into_vec(&version);
+
+    let (hits, misses) = Cache::statistics();
+    Cache::set_enabled(true);
+    (bytecode, hits, misses)
+}
+
+fn heavy_table() -> Vec<(&'static str, String)> {
+    let entries: Vec<String> = (0..256)
+        .map(|index| format!("entry({} as u64)", index % 16))
+        .collect();
+
+    let code = format!(
+        r#"
+const OFFSET: u64 = 7;
+
+const fn base() -> u64 {{
+    OFFSET * 3
+}}
+
+const fn entry(index: u64) -> u64 {{
+    index * index + base()
+}}
+
+const TABLE: [u64; 256] = [{}];
+
+fn main(index: u8) -> u64 {{
+    let table = TABLE;
+    table[index]
+}}
+"#,
+        entries.join(", ")
+    );
+
+    vec![("main", code)]
+}
+
+#[test]
+fn ok_cache_differential() {
+    let (uncached, _hits, _misses) = compile_bytecode(heavy_table(), false);
+    let (cached, hits, misses) = compile_bytecode(heavy_table(), true);
+
+    assert_eq!(cached, uncached);
+    // 16 distinct `entry` calls, and `base` is only evaluated once
+    assert_eq!(misses, 16 + 1);
+    assert_eq!(hits, (256 - 16) + 16);
+}
+
+#[test]
+fn ok_cache_call_site_scope() {
+    let files = vec![
+        (
+            "main",
+            r#"
+mod inner;
+
+const VALUE: u8 = 2;
+
+fn main() -> (u8, u8, u8) {
+    (inner::RESULT, inner::get(), inner::get())
+}
+"#
+            .to_owned(),
+        ),
+        (
+            "inner",
+            r#"
+pub const VALUE: u8 = 1;
+
+pub const fn get() -> u8 {
+    VALUE
+}
+
+pub const RESULT: u8 = get();
+"#
+            .to_owned(),
+        ),
+    ];
+
+    let (uncached, _hits, _misses) = compile_bytecode(files.clone(), false);
+    let (cached, hits, misses) = compile_bytecode(files, true);
+
+    assert_eq!(cached, uncached);
+    assert_eq!(misses, 2);
+    assert_eq!(hits, 1);
+}
+
+#[test]
+fn ok_cache_wall_time() {
+    let started_at = Instant::now();
+    compile_bytecode(heavy_table(), false);
+    let uncached = started_at.elapsed();
+
+    let started_at = Instant::now();
+    compile_bytecode(heavy_table(), true);
+    let cached = started_at.elapsed();
+
+    assert!(
+        cached <= uncached * 2,
+        "the cached compilation has taken {:?}, the uncached one {:?}",
+        cached,
+        uncached
+    );
+}
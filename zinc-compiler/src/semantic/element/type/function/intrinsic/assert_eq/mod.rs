@@ -0,0 +1,202 @@
+//!
+//! The semantic analyzer `std::test::assert_eq` intrinsic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::test::assert_eq` intrinsic function element.
+///
+/// Compares two values of the same type structurally and fails the unit test with a rendered
+/// difference if they are not equal.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "assert_eq";
+
+    /// The position of the `actual` argument in the function argument list.
+    pub const ARGUMENT_INDEX_ACTUAL: usize = 0;
+
+    /// The position of the `expected` argument in the function argument list.
+    pub const ARGUMENT_INDEX_EXPECTED: usize = 1;
+
+    /// The position of the optional `message` argument in the function argument list.
+    pub const ARGUMENT_INDEX_MESSAGE: usize = 2;
+
+    /// The number of arguments, not including the optional ones.
+    pub const ARGUMENT_COUNT_MANDATORY: usize = 2;
+
+    /// The number of arguments, including the optional ones.
+    pub const ARGUMENT_COUNT_OPTIONAL: usize = 3;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the function return type, the compared values type, and the optional message.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, Type, Option<String>), Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, is_constant, is_literal, string) = match element {
+                Element::Value(value) => (value.r#type(), false, false, None),
+                Element::Constant(Constant::String(inner)) => {
+                    (inner.r#type(), true, false, Some(inner.inner))
+                }
+                Element::Constant(Constant::Integer(inner)) => {
+                    (inner.r#type(), true, inner.is_literal, None)
+                }
+                Element::Constant(constant) => (constant.r#type(), true, false, None),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, is_constant, is_literal, string, location));
+        }
+
+        if actual_params.len() < Self::ARGUMENT_COUNT_MANDATORY {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT_MANDATORY,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        let (actual_type, _is_constant, is_actual_literal, _string, actual_location) =
+            actual_params[Self::ARGUMENT_INDEX_ACTUAL].to_owned();
+        let (expected_type, _is_constant, is_expected_literal, _string, expected_location) =
+            actual_params[Self::ARGUMENT_INDEX_EXPECTED].to_owned();
+
+        if !actual_type.is_comparable() {
+            return Err(Error::FunctionArgumentType {
+                location: actual_location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                function: self.identifier.to_owned(),
+                name: "actual".to_owned(),
+                position: Self::ARGUMENT_INDEX_ACTUAL + 1,
+                expected: "{comparable}".to_owned(),
+                found: actual_type.to_string(),
+            });
+        }
+
+        let r#type = match (actual_type, expected_type) {
+            (actual_type, expected_type) if actual_type == expected_type => actual_type,
+            (actual_type, expected_type)
+                if is_expected_literal && Self::is_numeric(&actual_type, &expected_type) =>
+            {
+                actual_type
+            }
+            (actual_type, expected_type)
+                if is_actual_literal && Self::is_numeric(&actual_type, &expected_type) =>
+            {
+                expected_type
+            }
+            (actual_type, expected_type) => {
+                return Err(Error::FunctionArgumentType {
+                    location: expected_location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "expected".to_owned(),
+                    position: Self::ARGUMENT_INDEX_EXPECTED + 1,
+                    expected: actual_type.to_string(),
+                    found: expected_type.to_string(),
+                })
+            }
+        };
+
+        let string = match actual_params.get(Self::ARGUMENT_INDEX_MESSAGE) {
+            Some((Type::String(_), true, _is_literal, string, _location)) => string.to_owned(),
+            Some((r#type, true, _is_literal, _string, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "message".to_owned(),
+                    position: Self::ARGUMENT_INDEX_MESSAGE + 1,
+                    expected: Type::string(None).to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            Some((r#type, false, _is_literal, _string, location)) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "message".to_owned(),
+                    position: Self::ARGUMENT_INDEX_MESSAGE + 1,
+                    found: r#type.to_string(),
+                });
+            }
+            None => None,
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT_OPTIONAL {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT_OPTIONAL,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok((Type::unit(None), r#type, string))
+    }
+
+    ///
+    /// Checks whether both types are numeric, so an integer literal may adopt the other type.
+    ///
+    fn is_numeric(type_1: &Type, type_2: &Type) -> bool {
+        [type_1, type_2]
+            .iter()
+            .all(|r#type| r#type.is_scalar() && !matches!(r#type, Type::Boolean(_)))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "test::{}(actual: T, expected: T, [message: str])",
+            self.identifier
+        )
+    }
+}
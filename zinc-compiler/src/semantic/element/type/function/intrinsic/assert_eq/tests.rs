@@ -0,0 +1,144 @@
+//!
+//! The `std::test::assert_eq` intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::assert_eq::Function as AssertEqFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Data {
+    value: u8,
+    flags: [bool; 2],
+}
+
+#[test]
+fn test() {
+    let actual = Data { value: 42, flags: [true, false] };
+    let expected = Data { value: 42, flags: [true, false] };
+
+    std::test::assert_eq(actual, expected, "the data differs");
+}
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_integer_literal() {
+    let input = r#"
+#[test]
+fn test() {
+    let value: u64 = 42;
+
+    std::test::assert_eq(value, 42);
+}
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_beyond_unit_test() {
+    let input = r#"
+fn main() {
+    std::test::assert_eq(42, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::UnitTestAssertionBeyondUnitTest {
+            location: Location::test(3, 5),
+            function: AssertEqFunction::IDENTIFIER.to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_lesser() {
+    let input = r#"
+#[test]
+fn test() {
+    std::test::assert_eq(42);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(4, 5),
+        function: AssertEqFunction::IDENTIFIER.to_owned(),
+        expected: AssertEqFunction::ARGUMENT_COUNT_MANDATORY,
+        found: AssertEqFunction::ARGUMENT_COUNT_MANDATORY - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_2_expected_type_mismatch() {
+    let input = r#"
+#[test]
+fn test() {
+    let value: u8 = 42;
+
+    std::test::assert_eq(value, true);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(6, 33),
+        function: AssertEqFunction::IDENTIFIER.to_owned(),
+        name: "expected".to_owned(),
+        position: AssertEqFunction::ARGUMENT_INDEX_EXPECTED + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_3_message_expected_string() {
+    let input = r#"
+#[test]
+fn test() {
+    std::test::assert_eq(42, 42, 42);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(4, 34),
+        function: AssertEqFunction::IDENTIFIER.to_owned(),
+        name: "message".to_owned(),
+        position: AssertEqFunction::ARGUMENT_INDEX_MESSAGE + 1,
+        expected: Type::string(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,65 @@
+//!
+//! The semantic analyzer `std::test::assert_storage_unchanged` intrinsic function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::test::assert_storage_unchanged` intrinsic function element.
+///
+/// Fails the unit test if the storage of any contract instance differs from its snapshot,
+/// which is taken when the instance is created or fetched.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "assert_storage_unchanged";
+
+    /// The number of arguments.
+    pub const ARGUMENT_COUNT: usize = 0;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        if argument_list.arguments.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: argument_list.arguments.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::unit(None))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "test::{}()", self.identifier)
+    }
+}
@@ -0,0 +1,255 @@
+//!
+//! The semantic analyzer `cfg!` intrinsic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod values;
+
+use std::fmt;
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::string::String as StringConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+use self::values::Values;
+
+///
+/// The semantic analyzer `cfg!` intrinsic function element.
+///
+/// Returns the configuration value passed to the compiler as `--cfg key=value`, which is
+/// resolved to a constant at compile time.
+///
+/// Without the default value, the `true` and `false` values become booleans, the numbers become
+/// integer literals, and the other values become strings. With the default value, the value
+/// must be of the default value type.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "cfg";
+
+    /// The position of the `key` argument in the function argument list.
+    pub const ARGUMENT_INDEX_KEY: usize = 0;
+
+    /// The position of the optional `default` argument in the function argument list.
+    pub const ARGUMENT_INDEX_DEFAULT: usize = 1;
+
+    /// The maximal number of the function arguments.
+    pub const ARGUMENT_COUNT_MAX: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the configuration value or the default one.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Constant, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location().unwrap_or(location);
+
+            let constant = match element {
+                Element::Constant(constant) => constant,
+                Element::Value(value) => {
+                    return Err(Error::FunctionArgumentConstantness {
+                        location,
+                        function: self.identifier.to_owned(),
+                        name: if index == Self::ARGUMENT_INDEX_KEY {
+                            "key".to_owned()
+                        } else {
+                            "default".to_owned()
+                        },
+                        position: index + 1,
+                        found: value.r#type().to_string(),
+                    })
+                }
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location,
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((constant, location));
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT_MAX {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT_MAX,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        let mut actual_params = actual_params.into_iter();
+
+        let key = match actual_params.next() {
+            Some((Constant::String(key), _location)) => key.inner,
+            Some((constant, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location,
+                    function: self.identifier.to_owned(),
+                    name: "key".to_owned(),
+                    position: Self::ARGUMENT_INDEX_KEY + 1,
+                    expected: Type::string(None).to_string(),
+                    found: constant.r#type().to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_INDEX_KEY + 1,
+                    found: 0,
+                    reference: None,
+                })
+            }
+        };
+
+        let default = match actual_params.next() {
+            Some((constant @ Constant::Boolean(_), _location))
+            | Some((constant @ Constant::String(_), _location)) => Some(constant),
+            Some((Constant::Integer(integer), _location)) if integer.enumeration.is_none() => {
+                Some(Constant::Integer(integer))
+            }
+            Some((constant, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location,
+                    function: self.identifier.to_owned(),
+                    name: "default".to_owned(),
+                    position: Self::ARGUMENT_INDEX_DEFAULT + 1,
+                    expected: "bool, {integer} or str".to_owned(),
+                    found: constant.r#type().to_string(),
+                })
+            }
+            None => None,
+        };
+
+        let (value, default) = match (Values::get(key.as_str()), default) {
+            (Some(value), default) => (value, default),
+            (None, Some(default)) => return Ok(default),
+            (None, None) => return Err(Error::FunctionCfgKeyUnknown { location, key }),
+        };
+
+        match default {
+            None => Ok(Self::infer(location, value)),
+            Some(Constant::Boolean(_)) => match value.as_str() {
+                "true" => Ok(Constant::Boolean(BooleanConstant::new(location, true))),
+                "false" => Ok(Constant::Boolean(BooleanConstant::new(location, false))),
+                _ => Err(Error::FunctionCfgValueInvalid {
+                    location,
+                    key,
+                    value,
+                    expected: Type::boolean(None).to_string(),
+                }),
+            },
+            Some(Constant::Integer(mut integer)) => {
+                let expected = integer.r#type().to_string();
+                let parsed = Self::parse_integer(value.as_str()).and_then(|parsed| {
+                    zinc_math::infer_minimal_bitlength(&parsed, integer.is_signed)
+                        .ok()
+                        .filter(|bitlength| integer.is_literal || *bitlength <= integer.bitlength)
+                        .map(|bitlength| (parsed, bitlength))
+                });
+
+                match parsed {
+                    Some((parsed, bitlength)) => {
+                        if integer.is_literal {
+                            integer.bitlength = bitlength;
+                        }
+                        integer.location = location;
+                        integer.value = parsed;
+                        Ok(Constant::Integer(integer))
+                    }
+                    None => Err(Error::FunctionCfgValueInvalid {
+                        location,
+                        key,
+                        value,
+                        expected,
+                    }),
+                }
+            }
+            Some(_) => Ok(Constant::String(StringConstant::new(location, value))),
+        }
+    }
+
+    ///
+    /// Infers the constant from the configuration `value` without the default one.
+    ///
+    fn infer(location: Location, value: String) -> Constant {
+        match value.as_str() {
+            "true" => return Constant::Boolean(BooleanConstant::new(location, true)),
+            "false" => return Constant::Boolean(BooleanConstant::new(location, false)),
+            _ => {}
+        }
+
+        if let Some(integer) = Self::parse_integer(value.as_str()).and_then(|integer| {
+            let is_signed = integer < BigInt::from(0);
+            zinc_math::infer_minimal_bitlength(&integer, is_signed)
+                .ok()
+                .map(|bitlength| {
+                    IntegerConstant::new(location, integer, is_signed, bitlength, true)
+                })
+        }) {
+            return Constant::Integer(integer);
+        }
+
+        Constant::String(StringConstant::new(location, value))
+    }
+
+    ///
+    /// Parses the integer configuration value, which may be negative.
+    ///
+    fn parse_integer(value: &str) -> Option<BigInt> {
+        let (is_negative, value) = match value.strip_prefix('-') {
+            Some(value) => (true, value),
+            None => (false, value),
+        };
+
+        if !value.starts_with(|character: char| character.is_ascii_digit()) {
+            return None;
+        }
+
+        zinc_math::bigint_from_str(value)
+            .ok()
+            .map(|value| if is_negative { -value } else { value })
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}!(key: str, default: T) -> T", self.identifier)
+    }
+}
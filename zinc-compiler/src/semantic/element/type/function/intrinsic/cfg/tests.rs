@@ -0,0 +1,269 @@
+//!
+//! The `cfg!` intrinsic function tests.
+//!
+
+use std::collections::BTreeMap;
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::cfg::values::Values;
+use crate::semantic::element::r#type::function::intrinsic::cfg::Function as CfgFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+fn set(values: &[(&str, &str)]) {
+    Values::set(
+        values
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect(),
+    );
+}
+
+fn manifest() -> zinc_project::Manifest {
+    let mut profiles = BTreeMap::new();
+    profiles.insert(
+        zinc_const::cfg::PROFILE_DEBUG.to_owned(),
+        zinc_project::ManifestCfgValue::Integer(4),
+    );
+    profiles.insert(
+        zinc_const::cfg::PROFILE_RELEASE.to_owned(),
+        zinc_project::ManifestCfgValue::Integer(16),
+    );
+
+    let mut cfg = BTreeMap::new();
+    cfg.insert(
+        "bound".to_owned(),
+        zinc_project::ManifestCfgValue::Profiles(profiles),
+    );
+
+    let mut manifest = zinc_project::Manifest::new("test", zinc_project::ProjectType::Circuit);
+    manifest.cfg = Some(cfg);
+    manifest
+}
+
+#[test]
+fn ok_default() {
+    let input = r#"
+const SIZE: u64 = cfg!("size", 4);
+
+fn main() -> [u8; 4] {
+    [0; SIZE]
+}
+"#;
+
+    set(&[]);
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_supplied() {
+    let input = r#"
+const SIZE: u64 = cfg!("size", 4);
+
+fn main() -> [u8; 8] {
+    [0; SIZE]
+}
+"#;
+
+    set(&[("size", "8")]);
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_inferred() {
+    let input = r#"
+fn main() -> (bool, u8, u16) {
+    (cfg!("flag"), cfg!("small"), cfg!("big"))
+}
+"#;
+
+    set(&[("flag", "true"), ("small", "42"), ("big", "0x1_000")]);
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_runtime_context() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 1;
+    if cfg!("verbose", false) {
+        dbg!("{}", value);
+    }
+    value + cfg!("offset", 2)
+}
+"#;
+
+    set(&[("verbose", "true")]);
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+static PROFILE_ARRAY_SIZE: &str = r#"
+const BOUND: u64 = cfg!("bound");
+
+fn main() -> [u8; 4] {
+    [0; BOUND]
+}
+"#;
+
+#[test]
+fn ok_profile_debug_array_size() {
+    Values::set(manifest().cfg_values(zinc_const::cfg::PROFILE_DEBUG));
+
+    let result = crate::semantic::tests::compile_entry(PROFILE_ARRAY_SIZE);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_profile_release_array_size() {
+    Values::set(manifest().cfg_values(zinc_const::cfg::PROFILE_RELEASE));
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionReturnType {
+        location: Location::test(5, 5),
+        function: "main".to_owned(),
+        expected: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            4,
+        )
+        .to_string(),
+        found: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            16,
+        )
+        .to_string(),
+        reference: Location::test(4, 14),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(PROFILE_ARRAY_SIZE);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_key_unknown() {
+    let input = r#"
+const SIZE: u64 = cfg!("size");
+
+fn main() -> [u8; 4] {
+    [0; SIZE]
+}
+"#;
+
+    set(&[("profile", "debug")]);
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionCfgKeyUnknown {
+        location: Location::test(2, 19),
+        key: "size".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_value_invalid() {
+    let input = r#"
+const SIZE: u8 = cfg!("size", 4 as u8);
+
+fn main() {}
+"#;
+
+    set(&[("size", "256")]);
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionCfgValueInvalid {
+        location: Location::test(2, 18),
+        key: "size".to_owned(),
+        value: "256".to_owned(),
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_value_invalid_boolean() {
+    let input = r#"
+const FLAG: bool = cfg!("flag", false);
+
+fn main() {}
+"#;
+
+    set(&[("flag", "yes")]);
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionCfgValueInvalid {
+        location: Location::test(2, 20),
+        key: "flag".to_owned(),
+        value: "yes".to_owned(),
+        expected: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_1_key_expected_string() {
+    let input = r#"
+const SIZE: u8 = cfg!(42);
+
+fn main() {}
+"#;
+
+    set(&[]);
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(2, 23),
+        function: CfgFunction::IDENTIFIER.to_owned(),
+        name: "key".to_owned(),
+        position: CfgFunction::ARGUMENT_INDEX_KEY + 1,
+        expected: Type::string(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+const SIZE: u8 = cfg!("size", 4, 8);
+
+fn main() {}
+"#;
+
+    set(&[]);
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(2, 18),
+        function: CfgFunction::IDENTIFIER.to_owned(),
+        expected: CfgFunction::ARGUMENT_COUNT_MAX,
+        found: CfgFunction::ARGUMENT_COUNT_MAX + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
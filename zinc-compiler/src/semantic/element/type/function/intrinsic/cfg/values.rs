@@ -0,0 +1,41 @@
+//!
+//! The semantic analyzer `cfg!` configuration values.
+//!
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    ///
+    /// The configuration values of the thread the compiler is running in.
+    ///
+    static VALUES: RefCell<Values> = RefCell::new(Values::default());
+}
+
+///
+/// The configuration values, which are passed to the compiler as the `--cfg key=value` flags.
+///
+/// The values are shared by the project and all its dependencies, and are set by the bundler
+/// before the compilation starts.
+///
+#[derive(Debug, Default)]
+pub struct Values {
+    /// The values by their keys.
+    inner: BTreeMap<String, String>,
+}
+
+impl Values {
+    ///
+    /// Replaces the configuration values of the current thread.
+    ///
+    pub fn set(values: BTreeMap<String, String>) {
+        VALUES.with(|inner| inner.borrow_mut().inner = values);
+    }
+
+    ///
+    /// Returns the configuration value of `key`, if it has been set.
+    ///
+    pub fn get(key: &str) -> Option<String> {
+        VALUES.with(|inner| inner.borrow().inner.get(key).cloned())
+    }
+}
@@ -113,6 +113,7 @@ impl Function {
                 },
                 _location,
             )) => {}
+            Some((Type::Nominal(inner), _location)) if inner.is_address() => {}
             Some((r#type, location)) => {
                 return Err(Error::FunctionArgumentType {
                     location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -137,6 +138,7 @@ impl Function {
 
         match actual_params.get(Self::ARGUMENT_INDEX_TOKEN_ADDRESS) {
             Some((r#type, _location)) if r#type.is_integer_unsigned() => {}
+            Some((Type::Nominal(inner), _location)) if inner.is_address() => {}
             Some((r#type, location)) => {
                 return Err(Error::FunctionArgumentType {
                     location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -161,6 +163,7 @@ impl Function {
 
         match actual_params.get(Self::ARGUMENT_INDEX_AMOUNT) {
             Some((r#type, _location)) if r#type.is_integer_unsigned() => {}
+            Some((Type::Nominal(inner), _location)) if inner.is_balance() => {}
             Some((r#type, location)) => {
                 return Err(Error::FunctionArgumentType {
                     location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
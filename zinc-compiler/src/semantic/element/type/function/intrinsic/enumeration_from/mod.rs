@@ -0,0 +1,195 @@
+//!
+//! The semantic analyzer `<Enumeration>::from` intrinsic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `<Enumeration>::from` intrinsic function element.
+///
+/// Converts an integer into the enumeration, checking whether it is one of the variants.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The enumeration type, which the value is converted into.
+    pub enumeration: EnumerationType,
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "from";
+
+    /// The position of the `value` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(enumeration: EnumerationType) -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::EnumerationFrom,
+            identifier: Self::IDENTIFIER,
+            enumeration,
+        }
+    }
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((Type::IntegerUnsigned { .. }, _location))
+            | Some((Type::IntegerSigned { .. }, _location))
+            | Some((Type::Field(_), _location)) => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "value".to_owned(),
+                    position: Self::ARGUMENT_INDEX_VALUE + 1,
+                    expected: "{integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::tuple(
+            Some(location),
+            vec![Type::boolean(None), Type::Enumeration(self.enumeration)],
+        ))
+    }
+
+    ///
+    /// Calls the function with the constant `argument_list`, converting the value at compile time.
+    ///
+    /// The result is the same as the one computed by the virtual machine: the value itself if it
+    /// is one of the variants, and the first variant otherwise.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        let enumeration = self.enumeration.clone();
+        self.call(location, argument_list.clone())?;
+
+        let value = match argument_list
+            .arguments
+            .into_iter()
+            .nth(Self::ARGUMENT_INDEX_VALUE)
+        {
+            Some(Element::Constant(Constant::Integer(integer))) => integer.value,
+            Some(element) => {
+                return Err(Error::ExpressionNonConstantElement {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+            None => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let is_member = enumeration.values.contains(&value);
+        let result = if is_member {
+            value
+        } else {
+            enumeration
+                .values
+                .first()
+                .cloned()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+        };
+
+        let mut result = IntegerConstant::new(
+            location,
+            result,
+            enumeration.is_signed,
+            enumeration.bitlength,
+            false,
+        );
+        result.set_enumeration(enumeration);
+
+        Ok(Constant::Tuple(TupleConstant::new_with_values(
+            location,
+            vec![
+                Constant::Boolean(BooleanConstant::new(location, is_member)),
+                Constant::Integer(result),
+            ],
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{0}::{1}(value: {{integer}}) -> (bool, {0})",
+            self.enumeration.identifier, self.identifier
+        )
+    }
+}
@@ -0,0 +1,126 @@
+//!
+//! The `<Enumeration>::from` intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::enumeration_from::Function as EnumerationFromFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_runtime() {
+    let input = r#"
+enum List {
+    A = 1,
+    B = 2,
+    C = 4,
+}
+
+fn main(value: u8) -> (bool, List) {
+    List::from(value)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant() {
+    let input = r#"
+enum List {
+    A = 1,
+    B = 2,
+    C = 4,
+}
+
+const VARIANT: (bool, List) = List::from(4);
+const NOT_VARIANT: (bool, List) = List::from(3);
+
+fn main() -> ([u8; 5], [u8; 2]) {
+    (
+        [0; if VARIANT.0 { VARIANT.1 as u64 + 1 } else { 0 as u64 }],
+        [0; if NOT_VARIANT.0 { 0 as u64 } else { NOT_VARIANT.1 as u64 + 1 }],
+    )
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count_lesser() {
+    let input = r#"
+enum List {
+    A = 1,
+}
+
+fn main() -> (bool, List) {
+    List::from()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(7, 5),
+        function: EnumerationFromFunction::IDENTIFIER.to_owned(),
+        expected: EnumerationFromFunction::ARGUMENT_COUNT,
+        found: EnumerationFromFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_greater() {
+    let input = r#"
+enum List {
+    A = 1,
+}
+
+fn main(value: u8) -> (bool, List) {
+    List::from(value, value)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(7, 5),
+        function: EnumerationFromFunction::IDENTIFIER.to_owned(),
+        expected: EnumerationFromFunction::ARGUMENT_COUNT,
+        found: EnumerationFromFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_1_value_expected_integer() {
+    let input = r#"
+enum List {
+    A = 1,
+}
+
+fn main(value: bool) -> (bool, List) {
+    List::from(value)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 16),
+        function: EnumerationFromFunction::IDENTIFIER.to_owned(),
+        name: "value".to_owned(),
+        position: EnumerationFromFunction::ARGUMENT_INDEX_VALUE + 1,
+        expected: "{integer}".to_owned(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,191 @@
+//!
+//! The semantic analyzer `<Enumeration>::Flags::contains` intrinsic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::nominal::Nominal;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `<Enumeration>::Flags::contains` intrinsic function element.
+///
+/// Checks whether all the flags of the argument are set in the flag set.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The `#[bitflags]` enumeration type, whose flag set is checked.
+    pub enumeration: EnumerationType,
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "contains";
+
+    /// The position of the flag set argument in the function argument list.
+    pub const ARGUMENT_INDEX_SELF: usize = 0;
+
+    /// The position of the `flags` argument in the function argument list.
+    pub const ARGUMENT_INDEX_FLAGS: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(enumeration: EnumerationType) -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+            enumeration,
+        }
+    }
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        let flags = Nominal::flags(self.enumeration.clone());
+
+        match actual_params.get(Self::ARGUMENT_INDEX_SELF) {
+            Some((Type::Nominal(nominal), _location)) if nominal == &flags => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: Keyword::SelfLowercase.to_string(),
+                    position: Self::ARGUMENT_INDEX_SELF + 1,
+                    expected: flags.to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_FLAGS) {
+            Some((Type::Enumeration(enumeration), _location))
+                if enumeration == &self.enumeration => {}
+            Some((Type::Nominal(nominal), _location)) if nominal == &flags => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "flags".to_owned(),
+                    position: Self::ARGUMENT_INDEX_FLAGS + 1,
+                    expected: format!("{} | {}", self.enumeration, flags),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::boolean(None))
+    }
+
+    ///
+    /// Calls the function with the constant `argument_list`, checking the flags at compile time.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        self.call(location, argument_list.clone())?;
+
+        let mut values = Vec::with_capacity(Self::ARGUMENT_COUNT);
+        for element in argument_list.arguments.into_iter() {
+            match element {
+                Element::Constant(Constant::Integer(integer)) => values.push(integer.value),
+                element => {
+                    return Err(Error::ExpressionNonConstantElement {
+                        location: element.location().unwrap_or(location),
+                        found: element.to_string(),
+                    })
+                }
+            }
+        }
+        let flags = values.pop().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let set = values.pop().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        Ok(Constant::Boolean(BooleanConstant::new(
+            location,
+            set & &flags == flags,
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{0}::{1}::{2}(flags: {0} | {0}::{1}) -> bool",
+            self.enumeration.identifier,
+            Nominal::IDENTIFIER_FLAGS,
+            self.identifier
+        )
+    }
+}
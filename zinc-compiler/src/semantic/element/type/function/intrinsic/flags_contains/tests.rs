@@ -0,0 +1,107 @@
+//!
+//! The `<Enumeration>::Flags::contains` intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::flags_contains::Function as FlagsContainsFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_runtime() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+fn main(permissions: Permission::Flags) -> (bool, bool) {
+    (
+        permissions.contains(Permission::Read),
+        permissions.contains(Permission::Read | Permission::Write),
+    )
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+const PERMISSIONS: Permission::Flags = Permission::Read | Permission::Execute;
+
+fn main() -> ([u8; 1], [u8; 2]) {
+    (
+        [0; if PERMISSIONS.contains(Permission::Write) { 2 } else { 1 }],
+        [0; if PERMISSIONS.contains(Permission::Read | Permission::Execute) { 2 } else { 1 }],
+    )
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count_lesser() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+}
+
+fn main(permissions: Permission::Flags) -> bool {
+    permissions.contains()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(8, 25),
+        function: FlagsContainsFunction::IDENTIFIER.to_owned(),
+        expected: FlagsContainsFunction::ARGUMENT_COUNT,
+        found: FlagsContainsFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_2_flags_expected_flags() {
+    let input = r#"
+#[bitflags]
+enum Permission {
+    Read = 1,
+}
+
+fn main(permissions: Permission::Flags, value: u8) -> bool {
+    permissions.contains(value)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(8, 26),
+        function: FlagsContainsFunction::IDENTIFIER.to_owned(),
+        name: "flags".to_owned(),
+        position: FlagsContainsFunction::ARGUMENT_INDEX_FLAGS + 1,
+        expected: "Permission | Permission::Flags".to_owned(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -5,9 +5,15 @@
 #[cfg(test)]
 mod tests;
 
+pub mod assert_eq;
+pub mod assert_storage_unchanged;
+pub mod cfg;
 pub mod contract_fetch;
 pub mod contract_transfer;
 pub mod debug;
+pub mod enumeration_from;
+pub mod flags_contains;
+pub mod flags_is_empty;
 pub mod require;
 pub mod stdlib;
 
@@ -17,26 +23,45 @@ use zinc_lexical::Location;
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::semantic::element::r#type::contract::Contract as ContractType;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
 
+use self::assert_eq::Function as AssertEqFunction;
+use self::assert_storage_unchanged::Function as AssertStorageUnchangedFunction;
+use self::cfg::Function as CfgFunction;
 use self::contract_fetch::Function as ContractFetchFunction;
 use self::contract_transfer::Function as ContractTransferFunction;
 use self::debug::Function as DebugFunction;
+use self::enumeration_from::Function as EnumerationFromFunction;
+use self::flags_contains::Function as FlagsContainsFunction;
+use self::flags_is_empty::Function as FlagsIsEmptyFunction;
 use self::require::Function as RequireFunction;
+use self::stdlib::array_binary_search::Function as StdArrayBinarySearchFunction;
+use self::stdlib::array_concat::Function as StdArrayConcatFunction;
+use self::stdlib::array_is_sorted::Function as StdArrayIsSortedFunction;
 use self::stdlib::array_pad::Function as StdArrayPadFunction;
 use self::stdlib::array_reverse::Function as StdArrayReverseFunction;
 use self::stdlib::array_truncate::Function as StdArrayTruncateFunction;
 use self::stdlib::collections_mtreemap_contains::Function as StdCollectionsMTreeMapContainsFunction;
 use self::stdlib::collections_mtreemap_get::Function as StdCollectionsMTreeMapGetFunction;
+use self::stdlib::collections_mtreemap_get_or::Function as StdCollectionsMTreeMapGetOrFunction;
 use self::stdlib::collections_mtreemap_insert::Function as StdCollectionsMTreeMapInsertFunction;
+use self::stdlib::collections_mtreemap_insert_if_absent::Function as StdCollectionsMTreeMapInsertIfAbsentFunction;
 use self::stdlib::collections_mtreemap_remove::Function as StdCollectionsMTreeMapRemoveFunction;
 use self::stdlib::convert_from_bits_field::Function as StdConvertFromBitsFieldFunction;
 use self::stdlib::convert_from_bits_signed::Function as StdConvertFromBitsSignedFunction;
 use self::stdlib::convert_from_bits_unsigned::Function as StdConvertFromBitsUnsignedFunction;
+use self::stdlib::convert_pack_bits::Function as StdConvertPackBitsFunction;
 use self::stdlib::convert_to_bits::Function as StdConvertToBitsFunction;
+use self::stdlib::convert_try_cast::Function as StdConvertTryCastFunction;
+use self::stdlib::convert_try_into_unsigned::Function as StdConvertTryIntoUnsignedFunction;
+use self::stdlib::convert_unpack_bits::Function as StdConvertUnpackBitsFunction;
 use self::stdlib::crypto_pedersen::Function as StdConvertPedersenFunction;
 use self::stdlib::crypto_schnorr_signature_verify::Function as StdCryptoSchnorrSignatureVerifyFunction;
 use self::stdlib::crypto_sha256::Function as StdCryptoSha256Function;
+use self::stdlib::crypto_verify_proof::Function as StdCryptoVerifyProofFunction;
+use self::stdlib::deprecation::Deprecation;
 use self::stdlib::ff_invert::Function as StdFfInvertFunction;
+use self::stdlib::test_rand_u::Function as StdTestRandUFunction;
 use self::stdlib::Function as StandardLibraryFunction;
 
 ///
@@ -48,12 +73,24 @@ pub enum Function {
     Require(RequireFunction),
     /// The `dbg!(...)` function. See the inner element description.
     Debug(DebugFunction),
+    /// The `cfg!(...)` function. See the inner element description.
+    Cfg(CfgFunction),
     /// The `<Contract>::fetch(...)` function. See the inner element description.
     ContractFetch(ContractFetchFunction),
     /// The `<Contract>::transfer(...)` function. See the inner element description.
     ContractTransfer(ContractTransferFunction),
+    /// The `<Enumeration>::from(...)` function. See the inner element description.
+    EnumerationFrom(EnumerationFromFunction),
+    /// The `<Enumeration>::Flags::contains(...)` function. See the inner element description.
+    FlagsContains(FlagsContainsFunction),
+    /// The `<Enumeration>::Flags::is_empty()` function. See the inner element description.
+    FlagsIsEmpty(FlagsIsEmptyFunction),
     /// The standard library function. See the inner element description.
     StandardLibrary(StandardLibraryFunction),
+    /// The `std::test::assert_eq(...)` function. See the inner element description.
+    AssertEq(AssertEqFunction),
+    /// The `std::test::assert_storage_unchanged()` function. See the inner element description.
+    AssertStorageUnchanged(AssertStorageUnchangedFunction),
 }
 
 impl Function {
@@ -71,6 +108,27 @@ impl Function {
         Self::Debug(DebugFunction::default())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn cfg() -> Self {
+        Self::Cfg(CfgFunction::default())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn assert_eq() -> Self {
+        Self::AssertEq(AssertEqFunction::default())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn assert_storage_unchanged() -> Self {
+        Self::AssertStorageUnchanged(AssertStorageUnchangedFunction::default())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -85,6 +143,45 @@ impl Function {
         Self::ContractFetch(ContractFetchFunction::new(contract_type))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn enumeration_from(enumeration: EnumerationType) -> Self {
+        Self::EnumerationFrom(EnumerationFromFunction::new(enumeration))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn flags_contains(enumeration: EnumerationType) -> Self {
+        Self::FlagsContains(FlagsContainsFunction::new(enumeration))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn flags_is_empty(enumeration: EnumerationType) -> Self {
+        Self::FlagsIsEmpty(FlagsIsEmptyFunction::new(enumeration))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn mtreemap_get_or() -> Self {
+        Self::StandardLibrary(StandardLibraryFunction::CollectionsMTreeMapGetOr(
+            StdCollectionsMTreeMapGetOrFunction::default(),
+        ))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn mtreemap_insert_if_absent() -> Self {
+        Self::StandardLibrary(StandardLibraryFunction::CollectionsMTreeMapInsertIfAbsent(
+            StdCollectionsMTreeMapInsertIfAbsentFunction::default(),
+        ))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -101,6 +198,9 @@ impl Function {
                     StdCryptoSchnorrSignatureVerifyFunction::default(),
                 ))
             }
+            LibraryFunctionIdentifier::CryptoVerifyProof => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoVerifyProof(StdCryptoVerifyProofFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::ConvertToBits => Self::StandardLibrary(
                 StandardLibraryFunction::ConvertToBits(StdConvertToBitsFunction::default()),
@@ -120,6 +220,20 @@ impl Function {
                     StdConvertFromBitsFieldFunction::default(),
                 ))
             }
+            LibraryFunctionIdentifier::ConvertTryIntoUnsigned => {
+                Self::StandardLibrary(StandardLibraryFunction::ConvertTryIntoUnsigned(
+                    StdConvertTryIntoUnsignedFunction::default(),
+                ))
+            }
+            LibraryFunctionIdentifier::ConvertTryCast => Self::StandardLibrary(
+                StandardLibraryFunction::ConvertTryCast(StdConvertTryCastFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ConvertPackBits => Self::StandardLibrary(
+                StandardLibraryFunction::ConvertPackBits(StdConvertPackBitsFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ConvertUnpackBits => Self::StandardLibrary(
+                StandardLibraryFunction::ConvertUnpackBits(StdConvertUnpackBitsFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::ArrayReverse => Self::StandardLibrary(
                 StandardLibraryFunction::ArrayReverse(StdArrayReverseFunction::default()),
@@ -130,15 +244,34 @@ impl Function {
             LibraryFunctionIdentifier::ArrayPad => Self::StandardLibrary(
                 StandardLibraryFunction::ArrayPad(StdArrayPadFunction::default()),
             ),
+            LibraryFunctionIdentifier::ArrayConcat => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayConcat(StdArrayConcatFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayBinarySearch => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayBinarySearch(StdArrayBinarySearchFunction::default()),
+            ),
+            LibraryFunctionIdentifier::ArrayIsSorted => Self::StandardLibrary(
+                StandardLibraryFunction::ArrayIsSorted(StdArrayIsSortedFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::FfInvert => Self::StandardLibrary(
                 StandardLibraryFunction::FfInvert(StdFfInvertFunction::default()),
             ),
 
+            LibraryFunctionIdentifier::TestRandU => Self::StandardLibrary(
+                StandardLibraryFunction::TestRandU(StdTestRandUFunction::default()),
+            ),
+
             LibraryFunctionIdentifier::ContractTransfer => {
                 Self::ContractTransfer(ContractTransferFunction::default())
             }
 
+            // the enumeration functions are defined along with their enumeration types
+            LibraryFunctionIdentifier::EnumerationFrom
+            | LibraryFunctionIdentifier::EnumerationCast => {
+                panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
+
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => {
                 Self::StandardLibrary(StandardLibraryFunction::CollectionsMTreeMapGet(
                     StdCollectionsMTreeMapGetFunction::default(),
@@ -162,11 +295,24 @@ impl Function {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Creates the function called by its `deprecation` alias.
+    ///
+    pub fn library_deprecated(deprecation: Deprecation) -> Self {
+        let mut function = Self::library(deprecation.library_identifier);
+        if let Self::StandardLibrary(ref mut inner) = function {
+            inner.set_identifier(deprecation.identifier);
+        }
+        function
+    }
+
     ///
     /// Whether the function requires the Rust-macro-like `!` specifier.
     ///
     pub fn requires_exclamation_mark(&self) -> bool {
-        matches!(self, Self::Debug(_))
+        matches!(self, Self::Debug(_) | Self::Cfg(_))
     }
 
     ///
@@ -176,9 +322,29 @@ impl Function {
         match self {
             Self::Require(_) => false,
             Self::Debug(_) => false,
+            Self::Cfg(_) => false,
             Self::ContractFetch(_) => false,
             Self::ContractTransfer(_) => true,
+            Self::EnumerationFrom(_) => false,
+            Self::FlagsContains(_) => false,
+            Self::FlagsIsEmpty(_) => false,
             Self::StandardLibrary(inner) => inner.is_mutable(),
+            Self::AssertEq(_) => false,
+            Self::AssertStorageUnchanged(_) => false,
+        }
+    }
+
+    ///
+    /// Whether the function can be evaluated at compile time with constant arguments.
+    ///
+    pub fn is_constant_evaluable(&self) -> bool {
+        match self {
+            Self::Cfg(_) => true,
+            Self::EnumerationFrom(_) => true,
+            Self::FlagsContains(_) => true,
+            Self::FlagsIsEmpty(_) => true,
+            Self::StandardLibrary(inner) => inner.is_constant_evaluable(),
+            _ => false,
         }
     }
 
@@ -189,9 +355,25 @@ impl Function {
         match self {
             Self::Require(inner) => inner.identifier,
             Self::Debug(inner) => inner.identifier,
+            Self::Cfg(inner) => inner.identifier,
             Self::ContractFetch(inner) => inner.identifier,
             Self::ContractTransfer(inner) => inner.identifier,
+            Self::EnumerationFrom(inner) => inner.identifier,
+            Self::FlagsContains(inner) => inner.identifier,
+            Self::FlagsIsEmpty(inner) => inner.identifier,
             Self::StandardLibrary(inner) => inner.identifier(),
+            Self::AssertEq(inner) => inner.identifier,
+            Self::AssertStorageUnchanged(inner) => inner.identifier,
+        }
+    }
+
+    ///
+    /// Returns the deprecation, if the function is called by its deprecated alias.
+    ///
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        match self {
+            Self::StandardLibrary(inner) => inner.deprecation(),
+            _ => None,
         }
     }
 
@@ -202,9 +384,15 @@ impl Function {
         match self {
             Self::Require(inner) => inner.location = Some(location),
             Self::Debug(inner) => inner.location = Some(location),
+            Self::Cfg(inner) => inner.location = Some(location),
             Self::ContractFetch(inner) => inner.location = Some(location),
             Self::ContractTransfer(inner) => inner.location = Some(location),
+            Self::EnumerationFrom(inner) => inner.location = Some(location),
+            Self::FlagsContains(inner) => inner.location = Some(location),
+            Self::FlagsIsEmpty(inner) => inner.location = Some(location),
             Self::StandardLibrary(inner) => inner.set_location(location),
+            Self::AssertEq(inner) => inner.location = Some(location),
+            Self::AssertStorageUnchanged(inner) => inner.location = Some(location),
         }
     }
 
@@ -215,9 +403,15 @@ impl Function {
         match self {
             Self::Require(inner) => inner.location,
             Self::Debug(inner) => inner.location,
+            Self::Cfg(inner) => inner.location,
             Self::ContractFetch(inner) => inner.location,
             Self::ContractTransfer(inner) => inner.location,
+            Self::EnumerationFrom(inner) => inner.location,
+            Self::FlagsContains(inner) => inner.location,
+            Self::FlagsIsEmpty(inner) => inner.location,
             Self::StandardLibrary(inner) => inner.location(),
+            Self::AssertEq(inner) => inner.location,
+            Self::AssertStorageUnchanged(inner) => inner.location,
         }
     }
 }
@@ -227,9 +421,15 @@ impl fmt::Display for Function {
         match self {
             Self::Require(inner) => write!(f, "{}", inner),
             Self::Debug(inner) => write!(f, "{}", inner),
+            Self::Cfg(inner) => write!(f, "{}", inner),
             Self::ContractFetch(inner) => write!(f, "{}", inner),
             Self::ContractTransfer(inner) => write!(f, "{}", inner),
+            Self::EnumerationFrom(inner) => write!(f, "{}", inner),
+            Self::FlagsContains(inner) => write!(f, "{}", inner),
+            Self::FlagsIsEmpty(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "std::{}", inner),
+            Self::AssertEq(inner) => write!(f, "std::{}", inner),
+            Self::AssertStorageUnchanged(inner) => write!(f, "std::{}", inner),
         }
     }
 }
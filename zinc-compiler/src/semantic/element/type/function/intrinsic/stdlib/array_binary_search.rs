@@ -0,0 +1,239 @@
+//!
+//! The semantic analyzer standard library `std::array::binary_search` function element.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
+
+///
+/// The semantic analyzer standard library `std::array::binary_search` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ArrayBinarySearch,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "binary_search";
+
+    /// The position of the `sorted` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SORTED: usize = 0;
+
+    /// The position of the `needle` argument in the function argument list.
+    pub const ARGUMENT_INDEX_NEEDLE: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, is_constant) = match element {
+                Element::Value(value) => (value.r#type(), false),
+                Element::Constant(constant) => (constant.r#type(), true),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, is_constant, location));
+        }
+
+        let element_type = match actual_params.get(Self::ARGUMENT_INDEX_SORTED) {
+            Some((Type::Array(array), is_constant, location))
+                if matches!(
+                    array.r#type.as_ref(),
+                    Type::IntegerUnsigned { .. } | Type::IntegerSigned { .. }
+                ) =>
+            {
+                if !is_constant {
+                    let location = location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    if LINT_LEVELS.is_enabled(Lint::LinearSearch, location) {
+                        log::warn!(
+                            "{}: `std::array::{}` is called with a runtime array, so the linear scan is used",
+                            location,
+                            self.identifier,
+                        );
+                    }
+                }
+
+                array.r#type.as_ref().to_owned()
+            }
+            Some((r#type, _is_constant, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "sorted".to_owned(),
+                    position: Self::ARGUMENT_INDEX_SORTED + 1,
+                    expected: "[{integer}; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_NEEDLE) {
+            Some((r#type, _is_constant, _location)) if r#type == &element_type => {}
+            Some((r#type, _is_constant, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "needle".to_owned(),
+                    position: Self::ARGUMENT_INDEX_NEEDLE + 1,
+                    expected: element_type.to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::tuple(
+            Some(location),
+            vec![
+                Type::boolean(Some(location)),
+                Type::integer_unsigned(Some(location), zinc_const::bitlength::INDEX),
+            ],
+        ))
+    }
+
+    ///
+    /// Calls the function with the constant `argument_list`, searching the array at compile time.
+    ///
+    /// The result is the same as at runtime: whether the needle is found, and the index of its
+    /// first occurrence or the position where it could be inserted to keep the array sorted.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        self.call(location, argument_list.clone())?;
+
+        let mut arguments = argument_list.arguments.into_iter();
+        let values = match arguments.next() {
+            Some(Element::Constant(Constant::Array(array))) => array.values,
+            Some(element) => {
+                return Err(Error::ExpressionNonConstantElement {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+            None => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        let needle = match arguments.next() {
+            Some(Element::Constant(Constant::Integer(integer))) => integer.value,
+            Some(element) => {
+                return Err(Error::ExpressionNonConstantElement {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+            None => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let mut index = 0;
+        let mut is_found = false;
+        for value in values.into_iter() {
+            match value {
+                Constant::Integer(integer) if integer.value < needle => index += 1,
+                Constant::Integer(integer) => {
+                    is_found = integer.value == needle;
+                    break;
+                }
+                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            }
+        }
+
+        Ok(Constant::Tuple(TupleConstant::new_with_values(
+            location,
+            vec![
+                Constant::Boolean(BooleanConstant::new(location, is_found)),
+                Constant::Integer(IntegerConstant::new(
+                    location,
+                    BigInt::from(index),
+                    false,
+                    zinc_const::bitlength::INDEX,
+                    false,
+                )),
+            ],
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array::{}(sorted: [T; N], needle: T) -> (bool, u64)",
+            self.identifier,
+        )
+    }
+}
@@ -0,0 +1,183 @@
+//!
+//! The semantic analyzer standard library `std::array::concat` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::array::concat` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ArrayConcat,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "concat";
+
+    /// The position of the `first` argument in the function argument list.
+    pub const ARGUMENT_INDEX_FIRST: usize = 0;
+
+    /// The position of the `second` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SECOND: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        let mut arrays = Vec::with_capacity(Self::ARGUMENT_COUNT);
+        for (index, name) in [
+            (Self::ARGUMENT_INDEX_FIRST, "first"),
+            (Self::ARGUMENT_INDEX_SECOND, "second"),
+        ]
+        .iter()
+        {
+            match actual_params.get(*index) {
+                Some((Type::Array(array), location)) => arrays.push((
+                    array.r#type.as_ref().to_owned(),
+                    array.size,
+                    location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                )),
+                Some((r#type, location)) => {
+                    return Err(Error::FunctionArgumentType {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: (*name).to_owned(),
+                        position: index + 1,
+                        expected: "[T; N]".to_owned(),
+                        found: r#type.to_string(),
+                    })
+                }
+                None => {
+                    return Err(Error::FunctionArgumentCount {
+                        location,
+                        function: self.identifier.to_owned(),
+                        expected: Self::ARGUMENT_COUNT,
+                        found: actual_params.len(),
+                        reference: None,
+                    })
+                }
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        let (second_type, second_size, second_location) =
+            arrays.remove(Self::ARGUMENT_INDEX_SECOND);
+        let (first_type, first_size, first_location) = arrays.remove(Self::ARGUMENT_INDEX_FIRST);
+
+        if first_type != second_type {
+            return Err(Error::FunctionStdlibArrayConcatElementType {
+                location: second_location,
+                expected: first_type.to_string(),
+                found: second_type.to_string(),
+                reference: first_location,
+            });
+        }
+
+        Ok(Type::array(
+            Some(location),
+            first_type,
+            first_size + second_size,
+        ))
+    }
+
+    ///
+    /// Calls the function with the constant `argument_list`, folding the arrays at compile time.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        let r#type = match self.call(location, argument_list.clone())? {
+            Type::Array(array) => *array.r#type,
+            _type => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let mut values = Vec::new();
+        for element in argument_list.arguments.into_iter() {
+            match element {
+                Element::Constant(Constant::Array(array)) => values.extend(array.values),
+                element => {
+                    return Err(Error::ExpressionNonConstantElement {
+                        location: element.location().unwrap_or(location),
+                        found: element.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Constant::Array(ArrayConstant::new_with_values(
+            location, r#type, values,
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array::{}(first: [T; N], second: [T; M]) -> [T; N + M]",
+            self.identifier,
+        )
+    }
+}
@@ -0,0 +1,286 @@
+//!
+//! The semantic analyzer standard library `std::convert::try_cast` function element.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::tuple::Tuple as TupleConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::convert::try_cast` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ConvertTryCast,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "try_cast";
+
+    /// The position of the `value` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    /// The position of the `is_signed` argument in the function argument list.
+    pub const ARGUMENT_INDEX_IS_SIGNED: usize = 1;
+
+    /// The position of the `bitlength` argument in the function argument list.
+    pub const ARGUMENT_INDEX_BITLENGTH: usize = 2;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 3;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, is_constant, number, flag) = match element {
+                Element::Value(value) => (value.r#type(), false, None, None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    let number = integer.to_usize().ok();
+
+                    (integer.r#type(), true, number, None)
+                }
+                Element::Constant(Constant::Boolean(boolean)) => {
+                    (boolean.r#type(), true, None, Some(boolean.inner))
+                }
+                Element::Constant(constant) => (constant.r#type(), true, None, None),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, is_constant, number, flag, location));
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((Type::IntegerUnsigned { .. }, _is_constant, _number, _flag, _location))
+            | Some((Type::IntegerSigned { .. }, _is_constant, _number, _flag, _location)) => {}
+            Some((r#type, _is_constant, _number, _flag, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "value".to_owned(),
+                    position: Self::ARGUMENT_INDEX_VALUE + 1,
+                    expected: "{integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        let is_signed = match actual_params.get(Self::ARGUMENT_INDEX_IS_SIGNED) {
+            Some((Type::Boolean(_), true, _number, Some(flag), _location)) => *flag,
+            Some((Type::Boolean(_), false, _number, _flag, location)) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "is_signed".to_owned(),
+                    position: Self::ARGUMENT_INDEX_IS_SIGNED + 1,
+                    found: Type::boolean(None).to_string(),
+                })
+            }
+            Some((r#type, _is_constant, _number, _flag, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "is_signed".to_owned(),
+                    position: Self::ARGUMENT_INDEX_IS_SIGNED + 1,
+                    expected: Type::boolean(None).to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let bitlength = match actual_params.get(Self::ARGUMENT_INDEX_BITLENGTH) {
+            Some((r#type, true, number, _flag, location)) if r#type.is_scalar_unsigned() => {
+                match number {
+                    Some(number)
+                        if *number >= zinc_const::bitlength::BYTE
+                            && *number <= zinc_const::bitlength::INTEGER_MAX
+                            && *number % zinc_const::bitlength::BYTE == 0 =>
+                    {
+                        *number
+                    }
+                    _ => {
+                        return Err(Error::FunctionStdlibConvertBitlengthInvalid {
+                            location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                            value: number
+                                .map(|number| number.to_string())
+                                .unwrap_or_else(|| r#type.to_string()),
+                        })
+                    }
+                }
+            }
+            Some((r#type, true, _number, _flag, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bitlength".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITLENGTH + 1,
+                    expected: "{unsigned integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            Some((r#type, false, _number, _flag, location)) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bitlength".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITLENGTH + 1,
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::tuple(
+            Some(location),
+            vec![
+                Type::boolean(None),
+                Type::integer(None, is_signed, bitlength),
+            ],
+        ))
+    }
+
+    ///
+    /// Calls the function with the constant `argument_list`, converting the value at compile time.
+    ///
+    /// The result is the same as the one computed by the virtual machine: the value itself if it
+    /// fits into the integer type, and zero otherwise.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        let (is_signed, bitlength) = match self.call(location, argument_list.clone())? {
+            Type::Tuple(tuple) => match tuple.types.get(1) {
+                Some(Type::IntegerUnsigned { bitlength, .. }) => (false, *bitlength),
+                Some(Type::IntegerSigned { bitlength, .. }) => (true, *bitlength),
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            },
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let value = match argument_list
+            .arguments
+            .into_iter()
+            .nth(Self::ARGUMENT_INDEX_VALUE)
+        {
+            Some(Element::Constant(Constant::Integer(integer))) => integer.value,
+            Some(element) => {
+                return Err(Error::ExpressionNonConstantElement {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+            None => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let (min, max) = if is_signed {
+            (
+                -(BigInt::one() << (bitlength - 1)),
+                (BigInt::one() << (bitlength - 1)) - BigInt::one(),
+            )
+        } else {
+            (BigInt::zero(), (BigInt::one() << bitlength) - BigInt::one())
+        };
+        let is_fitting = value >= min && value <= max;
+        let result = if is_fitting { value } else { BigInt::zero() };
+
+        Ok(Constant::Tuple(TupleConstant::new_with_values(
+            location,
+            vec![
+                Constant::Boolean(BooleanConstant::new(location, is_fitting)),
+                Constant::Integer(IntegerConstant::new(
+                    location, result, is_signed, bitlength, false,
+                )),
+            ],
+        )))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "convert::{}(value: T, is_signed: bool, bitlength: N) -> (bool, {{i|u}}{{N}})",
+            self.identifier,
+        )
+    }
+}
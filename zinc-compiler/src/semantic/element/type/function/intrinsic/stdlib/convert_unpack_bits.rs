@@ -0,0 +1,184 @@
+//!
+//! The semantic analyzer standard library `std::convert::unpack_bits` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_pack_bits::Function as PackBitsFunction;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::convert::unpack_bits` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::ConvertUnpackBits,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "unpack_bits";
+
+    /// The position of the `value` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    /// The position of the `bits` argument in the function argument list.
+    pub const ARGUMENT_INDEX_BITS: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, is_constant, number) = match element {
+                Element::Value(value) => (value.r#type(), false, None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    let number = integer.to_usize().ok();
+
+                    (integer.r#type(), true, number)
+                }
+                Element::Constant(constant) => (constant.r#type(), true, None),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, is_constant, number, location));
+        }
+
+        let value_type = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((r#type @ Type::IntegerUnsigned { .. }, _is_constant, _number, _location)) => {
+                r#type.to_owned()
+            }
+            Some((r#type @ Type::Field(_), _is_constant, _number, _location)) => r#type.to_owned(),
+            Some((r#type, _is_constant, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "value".to_owned(),
+                    position: Self::ARGUMENT_INDEX_VALUE + 1,
+                    expected: format!("{{unsigned integer}} or {}", Type::field(None)),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let bits = match actual_params.get(Self::ARGUMENT_INDEX_BITS) {
+            Some((r#type, true, number, location)) if r#type.is_scalar_unsigned() => {
+                let bits = number.unwrap_or_default();
+                let is_matching = match (PackBitsFunction::packed_type(bits), &value_type) {
+                    (
+                        Some(Type::IntegerUnsigned {
+                            bitlength: expected,
+                            ..
+                        }),
+                        Type::IntegerUnsigned { bitlength, .. },
+                    ) => expected == *bitlength,
+                    (Some(Type::Field(_)), Type::Field(_)) => true,
+                    _ => false,
+                };
+                if !is_matching {
+                    return Err(Error::FunctionStdlibConvertBitsCountMismatch {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        bits,
+                        found: value_type.to_string(),
+                    });
+                }
+                bits
+            }
+            Some((r#type, true, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bits".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITS + 1,
+                    expected: "{unsigned integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            Some((r#type, false, _number, location)) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bits".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITS + 1,
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::array(Some(location), Type::boolean(None), bits))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "convert::{}(value: u{{M}}, bits: N) -> [bool; N]",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,187 @@
+//!
+//! The semantic analyzer standard library `std::crypto::verify_proof` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::crypto::verify_proof` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The function return type, which is always the same and known.
+    pub return_type: Box<Type>,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::CryptoVerifyProof,
+            identifier: Self::IDENTIFIER,
+            return_type: Box::new(Type::boolean(None)),
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "verify_proof";
+
+    /// The position of the `vk_digest` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VK_DIGEST: usize = 0;
+
+    /// The position of the `public_inputs` argument in the function argument list.
+    pub const ARGUMENT_INDEX_PUBLIC_INPUTS: usize = 1;
+
+    /// The position of the `proof` argument in the function argument list.
+    pub const ARGUMENT_INDEX_PROOF: usize = 2;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 3;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_VK_DIGEST) {
+            Some((Type::Array(array), _location))
+                if matches!(array.r#type.deref(), Type::Boolean(_))
+                    && array.size == zinc_const::bitlength::SHA256_HASH => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "vk_digest".to_owned(),
+                    position: Self::ARGUMENT_INDEX_VK_DIGEST + 1,
+                    expected: format!("[bool; {}]", zinc_const::bitlength::SHA256_HASH),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_PUBLIC_INPUTS) {
+            Some((Type::Array(array), _location))
+                if matches!(array.r#type.deref(), Type::Field(_))
+                    && array.size > 0
+                    && array.size <= zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "public_inputs".to_owned(),
+                    position: Self::ARGUMENT_INDEX_PUBLIC_INPUTS + 1,
+                    expected: format!(
+                        "[field; N], 0 < N <= {}",
+                        zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS
+                    ),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_PROOF) {
+            Some((Type::Array(array), _location))
+                if matches!(array.r#type.deref(), Type::Field(_))
+                    && array.size == zinc_const::limit::VERIFY_PROOF_FIELDS => {}
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "proof".to_owned(),
+                    position: Self::ARGUMENT_INDEX_PROOF + 1,
+                    expected: format!("[field; {}]", zinc_const::limit::VERIFY_PROOF_FIELDS),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(*self.return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "crypto::{}(vk_digest: [bool; {}], public_inputs: [field; N], proof: [field; {}]) -> {}",
+            self.identifier,
+            zinc_const::bitlength::SHA256_HASH,
+            zinc_const::limit::VERIFY_PROOF_FIELDS,
+            self.return_type,
+        )
+    }
+}
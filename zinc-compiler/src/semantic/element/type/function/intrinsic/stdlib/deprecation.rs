@@ -0,0 +1,70 @@
+//!
+//! The semantic analyzer standard library function deprecation.
+//!
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+///
+/// The standard library function deprecation.
+///
+/// The deprecated functions are registered as aliases of their replacements, so the existing
+/// code keeps compiling, but each call produces a warning.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Deprecation {
+    /// The module path of the deprecated function.
+    pub module: &'static str,
+    /// The deprecated function identifier.
+    pub identifier: &'static str,
+    /// The replacement function, which the deprecated one is an alias of.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The standard library version since which the function is deprecated.
+    pub since: u16,
+    /// The replacement function path.
+    pub replacement: &'static str,
+}
+
+impl Deprecation {
+    /// The `std::ff::inverse` alias of `std::ff::invert`.
+    pub const FF_INVERSE: Self = Self {
+        module: "std::ff",
+        identifier: "inverse",
+        library_identifier: LibraryFunctionIdentifier::FfInvert,
+        since: 2,
+        replacement: "std::ff::invert",
+    };
+
+    /// The deprecated standard library functions.
+    pub const ALL: [Self; 1] = [Self::FF_INVERSE];
+
+    ///
+    /// Finds the deprecation of the `library_identifier` function called as `identifier`.
+    ///
+    pub fn find(library_identifier: LibraryFunctionIdentifier, identifier: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|deprecation| {
+            deprecation.library_identifier == library_identifier
+                && deprecation.identifier == identifier
+        })
+    }
+
+    ///
+    /// Returns the deprecated function full path.
+    ///
+    pub fn path(&self) -> String {
+        format!("{}::{}", self.module, self.identifier)
+    }
+
+    ///
+    /// Returns the warning printed at the deprecated function call `location`.
+    ///
+    pub fn warning(&self, location: Location) -> String {
+        format!(
+            "{}: `{}` is deprecated since the standard library version {}, use `{}` instead",
+            location,
+            self.path(),
+            self.since,
+            self.replacement,
+        )
+    }
+}
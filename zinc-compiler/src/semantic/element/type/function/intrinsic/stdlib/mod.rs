@@ -5,21 +5,33 @@
 #[cfg(test)]
 mod tests;
 
+pub mod array_binary_search;
+pub mod array_concat;
+pub mod array_is_sorted;
 pub mod array_pad;
 pub mod array_reverse;
 pub mod array_truncate;
 pub mod collections_mtreemap_contains;
 pub mod collections_mtreemap_get;
+pub mod collections_mtreemap_get_or;
 pub mod collections_mtreemap_insert;
+pub mod collections_mtreemap_insert_if_absent;
 pub mod collections_mtreemap_remove;
 pub mod convert_from_bits_field;
 pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
+pub mod convert_pack_bits;
 pub mod convert_to_bits;
+pub mod convert_try_cast;
+pub mod convert_try_into_unsigned;
+pub mod convert_unpack_bits;
 pub mod crypto_pedersen;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
+pub mod crypto_verify_proof;
+pub mod deprecation;
 pub mod ff_invert;
+pub mod test_rand_u;
 
 use std::fmt;
 
@@ -27,24 +39,37 @@ use zinc_lexical::Location;
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 
+use self::array_binary_search::Function as ArrayBinarySearchFunction;
+use self::array_concat::Function as ArrayConcatFunction;
+use self::array_is_sorted::Function as ArrayIsSortedFunction;
 use self::array_pad::Function as ArrayPadFunction;
 use self::array_reverse::Function as ArrayReverseFunction;
 use self::array_truncate::Function as ArrayTruncateFunction;
 use self::collections_mtreemap_contains::Function as MTreeMapContainsFunction;
 use self::collections_mtreemap_get::Function as MTreeMapGetFunction;
+use self::collections_mtreemap_get_or::Function as MTreeMapGetOrFunction;
 use self::collections_mtreemap_insert::Function as MTreeMapInsertFunction;
+use self::collections_mtreemap_insert_if_absent::Function as MTreeMapInsertIfAbsentFunction;
 use self::collections_mtreemap_remove::Function as MTreeMapRemoveFunction;
 use self::convert_from_bits_field::Function as FromBitsFieldFunction;
 use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
+use self::convert_pack_bits::Function as PackBitsFunction;
 use self::convert_to_bits::Function as ToBitsFunction;
+use self::convert_try_cast::Function as TryCastFunction;
+use self::convert_try_into_unsigned::Function as TryIntoUnsignedFunction;
+use self::convert_unpack_bits::Function as UnpackBitsFunction;
 use self::crypto_pedersen::Function as PedersenFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
+use self::crypto_verify_proof::Function as VerifyProofFunction;
+use self::deprecation::Deprecation;
 use self::ff_invert::Function as FfInvertFunction;
+use self::test_rand_u::Function as TestRandUFunction;
 
 ///
 /// The semantic analyzer standard library function element.
@@ -57,6 +82,8 @@ pub enum Function {
     CryptoPedersen(PedersenFunction),
     /// The `std::crypto::schnorr::Signature::verify` function variant.
     CryptoSchnorrSignatureVerify(SchnorrSignatureVerifyFunction),
+    /// The `std::crypto::verify_proof` function variant.
+    CryptoVerifyProof(VerifyProofFunction),
 
     /// The `std::convert::to_bits` function variant.
     ConvertToBits(ToBitsFunction),
@@ -66,6 +93,14 @@ pub enum Function {
     ConvertFromBitsSigned(FromBitsSignedFunction),
     /// The `std::convert::from_bits_field` function variant.
     ConvertFromBitsField(FromBitsFieldFunction),
+    /// The `std::convert::try_into_unsigned` function variant.
+    ConvertTryIntoUnsigned(TryIntoUnsignedFunction),
+    /// The `std::convert::try_cast` function variant.
+    ConvertTryCast(TryCastFunction),
+    /// The `std::convert::pack_bits` function variant.
+    ConvertPackBits(PackBitsFunction),
+    /// The `std::convert::unpack_bits` function variant.
+    ConvertUnpackBits(UnpackBitsFunction),
 
     /// The `std::array::reverse` function variant.
     ArrayReverse(ArrayReverseFunction),
@@ -73,10 +108,19 @@ pub enum Function {
     ArrayTruncate(ArrayTruncateFunction),
     /// The `std::array::pad` function variant.
     ArrayPad(ArrayPadFunction),
+    /// The `std::array::concat` function variant.
+    ArrayConcat(ArrayConcatFunction),
+    /// The `std::array::binary_search` function variant.
+    ArrayBinarySearch(ArrayBinarySearchFunction),
+    /// The `std::array::is_sorted` function variant.
+    ArrayIsSorted(ArrayIsSortedFunction),
 
     /// The `std::ff::invert` function variant.
     FfInvert(FfInvertFunction),
 
+    /// The `std::test::rand_u` function variant.
+    TestRandU(TestRandUFunction),
+
     /// The `std::collections::MTreeMap::get` function variant.
     CollectionsMTreeMapGet(MTreeMapGetFunction),
     /// The `std::collections::MTreeMap::contains` function variant.
@@ -85,6 +129,10 @@ pub enum Function {
     CollectionsMTreeMapInsert(MTreeMapInsertFunction),
     /// The `std::collections::MTreeMap::remove` function variant.
     CollectionsMTreeMapRemove(MTreeMapRemoveFunction),
+    /// The `std::collections::MTreeMap::get_or` function variant.
+    CollectionsMTreeMapGetOr(MTreeMapGetOrFunction),
+    /// The `std::collections::MTreeMap::insert_if_absent` function variant.
+    CollectionsMTreeMapInsertIfAbsent(MTreeMapInsertIfAbsentFunction),
 }
 
 impl Function {
@@ -96,25 +144,71 @@ impl Function {
             Self::CryptoSha256(inner) => inner.call(location, argument_list),
             Self::CryptoPedersen(inner) => inner.call(location, argument_list),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.call(location, argument_list),
+            Self::CryptoVerifyProof(inner) => inner.call(location, argument_list),
 
             Self::ConvertToBits(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsUnsigned(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsSigned(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsField(inner) => inner.call(location, argument_list),
+            Self::ConvertTryIntoUnsigned(inner) => inner.call(location, argument_list),
+            Self::ConvertTryCast(inner) => inner.call(location, argument_list),
+            Self::ConvertPackBits(inner) => inner.call(location, argument_list),
+            Self::ConvertUnpackBits(inner) => inner.call(location, argument_list),
 
             Self::ArrayReverse(inner) => inner.call(location, argument_list),
             Self::ArrayTruncate(inner) => inner.call(location, argument_list),
             Self::ArrayPad(inner) => inner.call(location, argument_list),
+            Self::ArrayConcat(inner) => inner.call(location, argument_list),
+            Self::ArrayBinarySearch(inner) => inner.call(location, argument_list),
+            Self::ArrayIsSorted(inner) => inner.call(location, argument_list),
 
             Self::FfInvert(inner) => inner.call(location, argument_list),
 
+            Self::TestRandU(inner) => inner.call(location, argument_list),
+
             Self::CollectionsMTreeMapGet(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapContains(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapInsert(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapRemove(inner) => inner.call(location, argument_list),
+            Self::CollectionsMTreeMapGetOr(inner) => inner.call(location, argument_list),
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.call(location, argument_list),
         }
     }
 
+    ///
+    /// Calls the function with the constant `argument_list`, evaluating it at compile time.
+    ///
+    /// Must only be called for the functions which are `is_constant_evaluable`.
+    ///
+    pub fn call_constant(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<Constant, Error> {
+        match self {
+            Self::ArrayConcat(inner) => inner.call_constant(location, argument_list),
+            Self::ArrayBinarySearch(inner) => inner.call_constant(location, argument_list),
+            Self::ArrayIsSorted(inner) => inner.call_constant(location, argument_list),
+            Self::ConvertTryIntoUnsigned(inner) => inner.call_constant(location, argument_list),
+            Self::ConvertTryCast(inner) => inner.call_constant(location, argument_list),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Whether the function can be evaluated at compile time with constant arguments.
+    ///
+    pub fn is_constant_evaluable(&self) -> bool {
+        matches!(
+            self,
+            Self::ArrayConcat(_)
+                | Self::ArrayBinarySearch(_)
+                | Self::ArrayIsSorted(_)
+                | Self::ConvertTryIntoUnsigned(_)
+                | Self::ConvertTryCast(_)
+        )
+    }
+
     ///
     /// Returns the function identifier, which is known at compile time.
     ///
@@ -123,22 +217,34 @@ impl Function {
             Self::CryptoSha256(inner) => inner.identifier,
             Self::CryptoPedersen(inner) => inner.identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.identifier,
+            Self::CryptoVerifyProof(inner) => inner.identifier,
 
             Self::ConvertToBits(inner) => inner.identifier,
             Self::ConvertFromBitsUnsigned(inner) => inner.identifier,
             Self::ConvertFromBitsSigned(inner) => inner.identifier,
             Self::ConvertFromBitsField(inner) => inner.identifier,
+            Self::ConvertTryIntoUnsigned(inner) => inner.identifier,
+            Self::ConvertTryCast(inner) => inner.identifier,
+            Self::ConvertPackBits(inner) => inner.identifier,
+            Self::ConvertUnpackBits(inner) => inner.identifier,
 
             Self::ArrayReverse(inner) => inner.identifier,
             Self::ArrayTruncate(inner) => inner.identifier,
             Self::ArrayPad(inner) => inner.identifier,
+            Self::ArrayConcat(inner) => inner.identifier,
+            Self::ArrayBinarySearch(inner) => inner.identifier,
+            Self::ArrayIsSorted(inner) => inner.identifier,
 
             Self::FfInvert(inner) => inner.identifier,
 
+            Self::TestRandU(inner) => inner.identifier,
+
             Self::CollectionsMTreeMapGet(inner) => inner.identifier,
             Self::CollectionsMTreeMapContains(inner) => inner.identifier,
             Self::CollectionsMTreeMapInsert(inner) => inner.identifier,
             Self::CollectionsMTreeMapRemove(inner) => inner.identifier,
+            Self::CollectionsMTreeMapGetOr(inner) => inner.identifier,
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.identifier,
         }
     }
 
@@ -150,22 +256,34 @@ impl Function {
             Self::CryptoSha256(inner) => inner.library_identifier,
             Self::CryptoPedersen(inner) => inner.library_identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.library_identifier,
+            Self::CryptoVerifyProof(inner) => inner.library_identifier,
 
             Self::ConvertToBits(inner) => inner.library_identifier,
             Self::ConvertFromBitsUnsigned(inner) => inner.library_identifier,
             Self::ConvertFromBitsSigned(inner) => inner.library_identifier,
             Self::ConvertFromBitsField(inner) => inner.library_identifier,
+            Self::ConvertTryIntoUnsigned(inner) => inner.library_identifier,
+            Self::ConvertTryCast(inner) => inner.library_identifier,
+            Self::ConvertPackBits(inner) => inner.library_identifier,
+            Self::ConvertUnpackBits(inner) => inner.library_identifier,
 
             Self::ArrayReverse(inner) => inner.library_identifier,
             Self::ArrayTruncate(inner) => inner.library_identifier,
             Self::ArrayPad(inner) => inner.library_identifier,
+            Self::ArrayConcat(inner) => inner.library_identifier,
+            Self::ArrayBinarySearch(inner) => inner.library_identifier,
+            Self::ArrayIsSorted(inner) => inner.library_identifier,
 
             Self::FfInvert(inner) => inner.library_identifier,
 
+            Self::TestRandU(inner) => inner.library_identifier,
+
             Self::CollectionsMTreeMapGet(inner) => inner.library_identifier,
             Self::CollectionsMTreeMapContains(inner) => inner.library_identifier,
             Self::CollectionsMTreeMapInsert(inner) => inner.library_identifier,
             Self::CollectionsMTreeMapRemove(inner) => inner.library_identifier,
+            Self::CollectionsMTreeMapGetOr(inner) => inner.library_identifier,
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.library_identifier,
         }
     }
 
@@ -177,22 +295,34 @@ impl Function {
             Self::CryptoSha256(_) => false,
             Self::CryptoPedersen(_) => false,
             Self::CryptoSchnorrSignatureVerify(_) => false,
+            Self::CryptoVerifyProof(_) => false,
 
             Self::ConvertToBits(_) => false,
             Self::ConvertFromBitsUnsigned(_) => false,
             Self::ConvertFromBitsSigned(_) => false,
             Self::ConvertFromBitsField(_) => false,
+            Self::ConvertTryIntoUnsigned(_) => false,
+            Self::ConvertTryCast(_) => false,
+            Self::ConvertPackBits(_) => false,
+            Self::ConvertUnpackBits(_) => false,
 
             Self::ArrayReverse(_) => false,
             Self::ArrayTruncate(_) => false,
             Self::ArrayPad(_) => false,
+            Self::ArrayConcat(_) => false,
+            Self::ArrayBinarySearch(_) => false,
+            Self::ArrayIsSorted(_) => false,
 
             Self::FfInvert(_) => false,
 
+            Self::TestRandU(_) => false,
+
             Self::CollectionsMTreeMapGet(_) => false,
             Self::CollectionsMTreeMapContains(_) => false,
             Self::CollectionsMTreeMapInsert(_) => true,
             Self::CollectionsMTreeMapRemove(_) => true,
+            Self::CollectionsMTreeMapGetOr(_) => false,
+            Self::CollectionsMTreeMapInsertIfAbsent(_) => true,
         }
     }
 
@@ -204,25 +334,83 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location = Some(location),
             Self::CryptoPedersen(inner) => inner.location = Some(location),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location = Some(location),
+            Self::CryptoVerifyProof(inner) => inner.location = Some(location),
 
             Self::ConvertToBits(inner) => inner.location = Some(location),
             Self::ConvertFromBitsUnsigned(inner) => inner.location = Some(location),
             Self::ConvertFromBitsSigned(inner) => inner.location = Some(location),
             Self::ConvertFromBitsField(inner) => inner.location = Some(location),
+            Self::ConvertTryIntoUnsigned(inner) => inner.location = Some(location),
+            Self::ConvertTryCast(inner) => inner.location = Some(location),
+            Self::ConvertPackBits(inner) => inner.location = Some(location),
+            Self::ConvertUnpackBits(inner) => inner.location = Some(location),
 
             Self::ArrayReverse(inner) => inner.location = Some(location),
             Self::ArrayTruncate(inner) => inner.location = Some(location),
             Self::ArrayPad(inner) => inner.location = Some(location),
+            Self::ArrayConcat(inner) => inner.location = Some(location),
+            Self::ArrayBinarySearch(inner) => inner.location = Some(location),
+            Self::ArrayIsSorted(inner) => inner.location = Some(location),
 
             Self::FfInvert(inner) => inner.location = Some(location),
 
+            Self::TestRandU(inner) => inner.location = Some(location),
+
             Self::CollectionsMTreeMapGet(inner) => inner.location = Some(location),
             Self::CollectionsMTreeMapContains(inner) => inner.location = Some(location),
             Self::CollectionsMTreeMapInsert(inner) => inner.location = Some(location),
             Self::CollectionsMTreeMapRemove(inner) => inner.location = Some(location),
+            Self::CollectionsMTreeMapGetOr(inner) => inner.location = Some(location),
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.location = Some(location),
+        }
+    }
+
+    ///
+    /// Sets the function identifier, which differs from the default one for the deprecated aliases.
+    ///
+    pub fn set_identifier(&mut self, identifier: &'static str) {
+        match self {
+            Self::CryptoSha256(inner) => inner.identifier = identifier,
+            Self::CryptoPedersen(inner) => inner.identifier = identifier,
+            Self::CryptoSchnorrSignatureVerify(inner) => inner.identifier = identifier,
+            Self::CryptoVerifyProof(inner) => inner.identifier = identifier,
+
+            Self::ConvertToBits(inner) => inner.identifier = identifier,
+            Self::ConvertFromBitsUnsigned(inner) => inner.identifier = identifier,
+            Self::ConvertFromBitsSigned(inner) => inner.identifier = identifier,
+            Self::ConvertFromBitsField(inner) => inner.identifier = identifier,
+            Self::ConvertTryIntoUnsigned(inner) => inner.identifier = identifier,
+            Self::ConvertTryCast(inner) => inner.identifier = identifier,
+            Self::ConvertPackBits(inner) => inner.identifier = identifier,
+            Self::ConvertUnpackBits(inner) => inner.identifier = identifier,
+
+            Self::ArrayReverse(inner) => inner.identifier = identifier,
+            Self::ArrayTruncate(inner) => inner.identifier = identifier,
+            Self::ArrayPad(inner) => inner.identifier = identifier,
+            Self::ArrayConcat(inner) => inner.identifier = identifier,
+            Self::ArrayBinarySearch(inner) => inner.identifier = identifier,
+            Self::ArrayIsSorted(inner) => inner.identifier = identifier,
+
+            Self::FfInvert(inner) => inner.identifier = identifier,
+
+            Self::TestRandU(inner) => inner.identifier = identifier,
+
+            Self::CollectionsMTreeMapGet(inner) => inner.identifier = identifier,
+            Self::CollectionsMTreeMapContains(inner) => inner.identifier = identifier,
+            Self::CollectionsMTreeMapInsert(inner) => inner.identifier = identifier,
+            Self::CollectionsMTreeMapRemove(inner) => inner.identifier = identifier,
+            Self::CollectionsMTreeMapGetOr(inner) => inner.identifier = identifier,
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.identifier = identifier,
         }
     }
 
+    ///
+    /// Returns the deprecation, if the function is called by its deprecated alias.
+    ///
+    pub fn deprecation(&self) -> Option<Deprecation> {
+        Deprecation::find(self.library_identifier(), self.identifier())
+    }
+
     ///
     /// Returns the location of the function call.
     ///
@@ -231,22 +419,34 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location,
             Self::CryptoPedersen(inner) => inner.location,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location,
+            Self::CryptoVerifyProof(inner) => inner.location,
 
             Self::ConvertToBits(inner) => inner.location,
             Self::ConvertFromBitsUnsigned(inner) => inner.location,
             Self::ConvertFromBitsSigned(inner) => inner.location,
             Self::ConvertFromBitsField(inner) => inner.location,
+            Self::ConvertTryIntoUnsigned(inner) => inner.location,
+            Self::ConvertTryCast(inner) => inner.location,
+            Self::ConvertPackBits(inner) => inner.location,
+            Self::ConvertUnpackBits(inner) => inner.location,
 
             Self::ArrayReverse(inner) => inner.location,
             Self::ArrayTruncate(inner) => inner.location,
             Self::ArrayPad(inner) => inner.location,
+            Self::ArrayConcat(inner) => inner.location,
+            Self::ArrayBinarySearch(inner) => inner.location,
+            Self::ArrayIsSorted(inner) => inner.location,
 
             Self::FfInvert(inner) => inner.location,
 
+            Self::TestRandU(inner) => inner.location,
+
             Self::CollectionsMTreeMapGet(inner) => inner.location,
             Self::CollectionsMTreeMapContains(inner) => inner.location,
             Self::CollectionsMTreeMapInsert(inner) => inner.location,
             Self::CollectionsMTreeMapRemove(inner) => inner.location,
+            Self::CollectionsMTreeMapGetOr(inner) => inner.location,
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => inner.location,
         }
     }
 }
@@ -257,22 +457,34 @@ impl fmt::Display for Function {
             Self::CryptoSha256(inner) => write!(f, "{}", inner),
             Self::CryptoPedersen(inner) => write!(f, "{}", inner),
             Self::CryptoSchnorrSignatureVerify(inner) => write!(f, "{}", inner),
+            Self::CryptoVerifyProof(inner) => write!(f, "{}", inner),
 
             Self::ConvertToBits(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsUnsigned(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsSigned(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsField(inner) => write!(f, "{}", inner),
+            Self::ConvertTryIntoUnsigned(inner) => write!(f, "{}", inner),
+            Self::ConvertTryCast(inner) => write!(f, "{}", inner),
+            Self::ConvertPackBits(inner) => write!(f, "{}", inner),
+            Self::ConvertUnpackBits(inner) => write!(f, "{}", inner),
 
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
             Self::ArrayPad(inner) => write!(f, "{}", inner),
+            Self::ArrayConcat(inner) => write!(f, "{}", inner),
+            Self::ArrayBinarySearch(inner) => write!(f, "{}", inner),
+            Self::ArrayIsSorted(inner) => write!(f, "{}", inner),
 
             Self::FfInvert(inner) => write!(f, "{}", inner),
 
+            Self::TestRandU(inner) => write!(f, "{}", inner),
+
             Self::CollectionsMTreeMapGet(inner) => write!(f, "{}", inner),
             Self::CollectionsMTreeMapContains(inner) => write!(f, "{}", inner),
             Self::CollectionsMTreeMapInsert(inner) => write!(f, "{}", inner),
             Self::CollectionsMTreeMapRemove(inner) => write!(f, "{}", inner),
+            Self::CollectionsMTreeMapGetOr(inner) => write!(f, "{}", inner),
+            Self::CollectionsMTreeMapInsertIfAbsent(inner) => write!(f, "{}", inner),
         }
     }
 }
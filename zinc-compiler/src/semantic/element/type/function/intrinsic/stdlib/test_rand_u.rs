@@ -0,0 +1,175 @@
+//!
+//! The semantic analyzer standard library `std::test::rand_u` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::test::rand_u` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::TestRandU,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "rand_u";
+
+    /// The position of the `bits` argument in the function argument list.
+    pub const ARGUMENT_INDEX_BITS: usize = 0;
+
+    /// The position of the `seed_offset` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SEED_OFFSET: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, is_constant, number) = match element {
+                Element::Value(value) => (value.r#type(), false, None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    let number = integer.to_usize().ok();
+
+                    (integer.r#type(), true, number)
+                }
+                Element::Constant(constant) => (constant.r#type(), true, None),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, is_constant, number, location));
+        }
+
+        let bits = match actual_params.get(Self::ARGUMENT_INDEX_BITS) {
+            Some((r#type, true, number, location)) if r#type.is_scalar_unsigned() => match number {
+                Some(number)
+                    if *number >= zinc_const::bitlength::BYTE
+                        && *number <= zinc_const::bitlength::INTEGER_MAX
+                        && *number % zinc_const::bitlength::BYTE == 0 =>
+                {
+                    *number
+                }
+                _ => {
+                    return Err(Error::FunctionStdlibConvertBitlengthInvalid {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        value: number
+                            .map(|number| number.to_string())
+                            .unwrap_or_else(|| r#type.to_string()),
+                    })
+                }
+            },
+            Some((r#type, true, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bits".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITS + 1,
+                    expected: "{unsigned integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            Some((r#type, false, _number, location)) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "bits".to_owned(),
+                    position: Self::ARGUMENT_INDEX_BITS + 1,
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_SEED_OFFSET) {
+            Some((r#type, _is_constant, _number, _location)) if r#type.is_scalar_unsigned() => {}
+            Some((r#type, _is_constant, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "seed_offset".to_owned(),
+                    position: Self::ARGUMENT_INDEX_SEED_OFFSET + 1,
+                    expected: "{unsigned integer}".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::integer_unsigned(Some(location), bits))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "test::{}(bits: N, seed_offset: u{{M}}) -> u{{N}}",
+            self.identifier,
+        )
+    }
+}
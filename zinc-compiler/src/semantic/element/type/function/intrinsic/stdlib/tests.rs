@@ -12,20 +12,31 @@ use zinc_lexical::Location;
 use crate::error::Error;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get::Function as CollectionsMTreeMapGetFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get_or::Function as CollectionsMTreeMapGetOrFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_contains::Function as CollectionsMTreeMapContainsFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert::Function as CollectionsMTreeMapInsertFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert_if_absent::Function as CollectionsMTreeMapInsertIfAbsentFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_remove::Function as CollectionsMTreeMapRemoveFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_binary_search::Function as ArrayBinarySearchFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_concat::Function as ArrayConcatFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_is_sorted::Function as ArrayIsSortedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_pad::Function as ArrayPadFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_reverse::Function as ArrayReverseFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_truncate::Function as ArrayTruncateFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_field::Function as ConvertFromBitsFieldFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_signed::Function as ConvertFromBitsSignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_pack_bits::Function as ConvertPackBitsFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ConvertToBitsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_try_cast::Function as ConvertTryCastFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_try_into_unsigned::Function as ConvertTryIntoUnsignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as CryptoPedersenFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as CryptoSha256Function;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_verify_proof::Function as CryptoVerifyProofFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::deprecation::Deprecation;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::ff_invert::Function as FfInvertFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::test_rand_u::Function as TestRandUFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 
@@ -47,6 +58,8 @@ contract Data {
         SemanticError::FunctionCallMutableFromImmutable {
             location: Location::test(8, 25),
             function: CollectionsMTreeMapInsertFunction::IDENTIFIER.to_owned(),
+            binding: "self".to_owned(),
+            place: "self.data".to_owned(),
         },
     ));
 
@@ -73,6 +86,36 @@ contract Data {
         SemanticError::FunctionCallMutableFromImmutable {
             location: Location::test(8, 25),
             function: CollectionsMTreeMapRemoveFunction::IDENTIFIER.to_owned(),
+            binding: "self".to_owned(),
+            place: "self.data".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_calling_mutable_from_immutable_contract_mtreemap_insert_if_absent() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Data {
+    data: MTreeMap<u8, field>;
+
+    pub fn immutable(self) -> bool {
+        self.data.insert_if_absent(42, 25 as field)
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionCallMutableFromImmutable {
+            location: Location::test(8, 35),
+            function: CollectionsMTreeMapInsertIfAbsentFunction::IDENTIFIER.to_owned(),
+            binding: "self".to_owned(),
+            place: "self.data".to_owned(),
         },
     ));
 
@@ -547,6 +590,158 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_crypto_verify_proof() {
+    let input = r#"
+fn main(vk_digest: [bool; 256], public_inputs: [field; 2], proof: [field; 8]) -> bool {
+    std::crypto::verify_proof(vk_digest, public_inputs, proof)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 256], [1 as field; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        expected: CryptoVerifyProofFunction::ARGUMENT_COUNT,
+        found: CryptoVerifyProofFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 256], [1 as field; 2], [0 as field; 8], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        expected: CryptoVerifyProofFunction::ARGUMENT_COUNT,
+        found: CryptoVerifyProofFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_1_vk_digest_expected_digest() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 128], [1 as field; 2], [0 as field; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 31),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        name: "vk_digest".to_owned(),
+        position: CryptoVerifyProofFunction::ARGUMENT_INDEX_VK_DIGEST + 1,
+        expected: format!("[bool; {}]", zinc_const::bitlength::SHA256_HASH),
+        found: Type::array(None, Type::boolean(None), 128).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_2_public_inputs_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 256], [1; 2], [0 as field; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 44),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        name: "public_inputs".to_owned(),
+        position: CryptoVerifyProofFunction::ARGUMENT_INDEX_PUBLIC_INPUTS + 1,
+        expected: format!(
+            "[field; N], 0 < N <= {}",
+            zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS
+        ),
+        found: Type::array(
+            None,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            2,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_2_public_inputs_expected_field_array_size_limit() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 256], [1 as field; 9], [0 as field; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 44),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        name: "public_inputs".to_owned(),
+        position: CryptoVerifyProofFunction::ARGUMENT_INDEX_PUBLIC_INPUTS + 1,
+        expected: format!(
+            "[field; N], 0 < N <= {}",
+            zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS
+        ),
+        found: Type::array(None, Type::field(None), 9).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_verify_proof_argument_3_proof_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::verify_proof([true; 256], [1 as field; 2], [0 as field; 4]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 61),
+        function: CryptoVerifyProofFunction::IDENTIFIER.to_owned(),
+        name: "proof".to_owned(),
+        position: CryptoVerifyProofFunction::ARGUMENT_INDEX_PROOF + 1,
+        expected: format!("[field; {}]", zinc_const::limit::VERIFY_PROOF_FIELDS),
+        found: Type::array(None, Type::field(None), 4).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_convert_from_bits_unsigned_argument_count_lesser() {
     let input = r#"
@@ -1035,39 +1230,46 @@ fn main() {
 }
 
 #[test]
-fn error_array_reverse_argument_count_lesser() {
+fn ok_convert_try_into_unsigned_constant() {
     let input = r#"
-fn main() {
-    std::array::reverse();
+const FITTING: (bool, u8) = std::convert::try_into_unsigned(255 as field, 8);
+const OVERFLOWING: (bool, u8) = std::convert::try_into_unsigned(256 as field, 8);
+
+fn main() -> ([u8; 256], [u8; 1]) {
+    (
+        [0; if FITTING.0 { FITTING.1 as u64 + 1 } else { 0 as u64 }],
+        [0; if OVERFLOWING.0 { 0 as u64 } else { OVERFLOWING.1 as u64 + 1 }],
+    )
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
-        expected: ArrayReverseFunction::ARGUMENT_COUNT,
-        found: ArrayReverseFunction::ARGUMENT_COUNT - 1,
-        reference: None,
-    }));
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
 
-    let result = crate::semantic::tests::compile_entry(input);
+#[test]
+fn ok_convert_try_into_unsigned_runtime() {
+    let input = r#"
+fn main(value: field) -> (bool, u64) {
+    std::convert::try_into_unsigned(value, 64)
+}
+"#;
 
-    assert_eq!(result, expected);
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
 #[test]
-fn error_array_reverse_argument_count_greater() {
+fn error_convert_try_into_unsigned_argument_count_lesser() {
     let input = r#"
-fn main() {
-    std::array::reverse([true; 8], 42);
+fn main(value: field) -> (bool, u64) {
+    std::convert::try_into_unsigned(value)
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
         location: Location::test(3, 5),
-        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
-        expected: ArrayReverseFunction::ARGUMENT_COUNT,
-        found: ArrayReverseFunction::ARGUMENT_COUNT + 1,
+        function: ConvertTryIntoUnsignedFunction::IDENTIFIER.to_owned(),
+        expected: ConvertTryIntoUnsignedFunction::ARGUMENT_COUNT,
+        found: ConvertTryIntoUnsignedFunction::ARGUMENT_COUNT - 1,
         reference: None,
     }));
 
@@ -1077,20 +1279,20 @@ fn main() {
 }
 
 #[test]
-fn error_array_reverse_argument_1_array_expected_array() {
+fn error_convert_try_into_unsigned_argument_1_value_expected_field() {
     let input = r#"
-fn main() {
-    std::array::reverse(42);
+fn main(value: u128) -> (bool, u64) {
+    std::convert::try_into_unsigned(value, 64)
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
-        location: Location::test(3, 25),
-        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
-        name: "array".to_owned(),
-        position: ArrayReverseFunction::ARGUMENT_INDEX_ARRAY + 1,
-        expected: "[{scalar}; N]".to_owned(),
-        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        location: Location::test(3, 37),
+        function: ConvertTryIntoUnsignedFunction::IDENTIFIER.to_owned(),
+        name: "value".to_owned(),
+        position: ConvertTryIntoUnsignedFunction::ARGUMENT_INDEX_VALUE + 1,
+        expected: Type::field(None).to_string(),
+        found: Type::integer_unsigned(None, 128).to_string(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -1099,20 +1301,22 @@ fn main() {
 }
 
 #[test]
-fn error_array_truncate_argument_count_lesser() {
+fn error_convert_try_into_unsigned_argument_2_bitlength_expected_constant() {
     let input = r#"
-fn main() {
-    std::array::truncate([true; 8]);
+fn main(value: field, bitlength: u8) -> (bool, u64) {
+    std::convert::try_into_unsigned(value, bitlength)
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
-        expected: ArrayTruncateFunction::ARGUMENT_COUNT,
-        found: ArrayTruncateFunction::ARGUMENT_COUNT - 1,
-        reference: None,
-    }));
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentConstantness {
+            location: Location::test(3, 44),
+            function: ConvertTryIntoUnsignedFunction::IDENTIFIER.to_owned(),
+            name: "bitlength".to_owned(),
+            position: ConvertTryIntoUnsignedFunction::ARGUMENT_INDEX_BITLENGTH + 1,
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
 
     let result = crate::semantic::tests::compile_entry(input);
 
@@ -1120,20 +1324,19 @@ fn main() {
 }
 
 #[test]
-fn error_array_truncate_argument_count_greater() {
+fn error_convert_try_into_unsigned_argument_2_bitlength_invalid() {
     let input = r#"
-fn main() {
-    std::array::truncate([true; 8], 4, 42);
+fn main(value: field) -> (bool, u64) {
+    std::convert::try_into_unsigned(value, 63)
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
-        expected: ArrayTruncateFunction::ARGUMENT_COUNT,
-        found: ArrayTruncateFunction::ARGUMENT_COUNT + 1,
-        reference: None,
-    }));
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibConvertBitlengthInvalid {
+            location: Location::test(3, 44),
+            value: "63".to_owned(),
+        },
+    ));
 
     let result = crate::semantic::tests::compile_entry(input);
 
@@ -1141,42 +1344,48 @@ fn main() {
 }
 
 #[test]
-fn error_array_truncate_argument_1_array_expected_array() {
+fn ok_convert_try_cast_constant() {
     let input = r#"
-fn main() {
-    std::array::truncate(42, 42);
+const NEGATIVE: (bool, u8) = std::convert::try_cast(-1 as i8, false, 8);
+const FITTING: (bool, i8) = std::convert::try_cast(127 as u8, true, 8);
+
+fn main() -> ([u8; 1], [u8; 128]) {
+    (
+        [0; if NEGATIVE.0 { 0 as u64 } else { NEGATIVE.1 as u64 + 1 }],
+        [0; if FITTING.0 { FITTING.1 as u64 + 1 } else { 0 as u64 }],
+    )
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
-        location: Location::test(3, 26),
-        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
-        name: "array".to_owned(),
-        position: ArrayTruncateFunction::ARGUMENT_INDEX_ARRAY + 1,
-        expected: "[{scalar}; N]".to_owned(),
-        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
-    }));
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
 
-    let result = crate::semantic::tests::compile_entry(input);
+#[test]
+fn ok_convert_try_cast_runtime() {
+    let input = r#"
+fn main(value: i64) -> (bool, u32) {
+    std::convert::try_cast(value, false, 32)
+}
+"#;
 
-    assert_eq!(result, expected);
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
 #[test]
-fn error_array_truncate_argument_2_new_length_expected_unsigned_integer() {
+fn error_convert_try_cast_argument_1_value_expected_integer() {
     let input = r#"
-fn main() {
-    std::array::truncate([true; 8], true);
+fn main(value: field) -> (bool, u64) {
+    std::convert::try_cast(value, false, 64)
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
-        location: Location::test(3, 37),
-        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
-        name: "new_length".to_owned(),
-        position: ArrayTruncateFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
-        expected: "{unsigned integer}".to_owned(),
-        found: Type::boolean(None).to_string(),
+        location: Location::test(3, 28),
+        function: ConvertTryCastFunction::IDENTIFIER.to_owned(),
+        name: "value".to_owned(),
+        position: ConvertTryCastFunction::ARGUMENT_INDEX_VALUE + 1,
+        expected: "{integer}".to_owned(),
+        found: Type::field(None).to_string(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -1185,21 +1394,20 @@ fn main() {
 }
 
 #[test]
-fn error_array_truncate_argument_2_new_length_expected_unsigned_integer_constant() {
+fn error_convert_try_cast_argument_2_is_signed_expected_constant() {
     let input = r#"
-fn main() {
-    let new_length = 4;
-    std::array::truncate([true; 8], new_length);
+fn main(value: i64, is_signed: bool) -> (bool, u64) {
+    std::convert::try_cast(value, is_signed, 64)
 }
 "#;
 
     let expected = Err(Error::Semantic(
         SemanticError::FunctionArgumentConstantness {
-            location: Location::test(4, 37),
-            function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
-            name: "new_length".to_owned(),
-            position: ArrayTruncateFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
-            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            location: Location::test(3, 35),
+            function: ConvertTryCastFunction::IDENTIFIER.to_owned(),
+            name: "is_signed".to_owned(),
+            position: ConvertTryCastFunction::ARGUMENT_INDEX_IS_SIGNED + 1,
+            found: Type::boolean(None).to_string(),
         },
     ));
 
@@ -1209,18 +1417,17 @@ fn main() {
 }
 
 #[test]
-fn error_array_truncating_to_bigger_size() {
+fn error_convert_try_cast_argument_3_bitlength_invalid() {
     let input = r#"
-fn main() -> [u8; 4] {
-    std::array::truncate([1, 2], 4)
+fn main(value: i64) -> (bool, u64) {
+    std::convert::try_cast(value, false, 63)
 }
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::FunctionStdlibArrayTruncatingToBiggerSize {
-            location: Location::test(3, 5),
-            from: 2,
-            to: 4,
+        SemanticError::FunctionStdlibConvertBitlengthInvalid {
+            location: Location::test(3, 42),
+            value: "63".to_owned(),
         },
     ));
 
@@ -1230,37 +1437,354 @@ fn main() -> [u8; 4] {
 }
 
 #[test]
-fn error_array_pad_argument_count_lesser() {
+fn ok_convert_pack_bits_round_trip_1() {
     let input = r#"
-fn main() {
-    std::array::pad([true; 8], 12);
+fn main(bits: [bool; 1]) -> [bool; 1] {
+    let packed: u8 = std::convert::pack_bits(bits);
+    std::convert::unpack_bits(packed, 1)
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: ArrayPadFunction::IDENTIFIER.to_owned(),
-        expected: ArrayPadFunction::ARGUMENT_COUNT,
-        found: ArrayPadFunction::ARGUMENT_COUNT - 1,
-        reference: None,
-    }));
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
 
-    let result = crate::semantic::tests::compile_entry(input);
+#[test]
+fn ok_convert_pack_bits_round_trip_248() {
+    let input = r#"
+fn main(bits: [bool; 248]) -> [bool; 248] {
+    let packed: u248 = std::convert::pack_bits(bits);
+    std::convert::unpack_bits(packed, 248)
+}
+"#;
 
-    assert_eq!(result, expected);
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
 #[test]
-fn error_array_pad_argument_count_greater() {
+fn ok_convert_pack_bits_round_trip_249() {
     let input = r#"
-fn main() {
-    std::array::pad([true; 8], 12, false, 42);
+fn main(bits: [bool; 249]) -> [bool; 249] {
+    let packed: field = std::convert::pack_bits(bits);
+    std::convert::unpack_bits(packed, 249)
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: ArrayPadFunction::IDENTIFIER.to_owned(),
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_convert_pack_bits_argument_1_bits_expected_bit_array() {
+    let input = r#"
+fn main(bits: [u8; 8]) -> u8 {
+    std::convert::pack_bits(bits)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 29),
+        function: ConvertPackBitsFunction::IDENTIFIER.to_owned(),
+        name: "bits".to_owned(),
+        position: ConvertPackBitsFunction::ARGUMENT_INDEX_BITS + 1,
+        expected: format!("[bool; N], 1 <= N <= {}", ConvertPackBitsFunction::BITS_MAX),
+        found: format!("array [{}; {}]", Type::integer_unsigned(None, 8), 8),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_pack_bits_argument_1_bits_too_many() {
+    let input = r#"
+fn main(bits: [bool; 254]) -> field {
+    std::convert::pack_bits(bits)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 29),
+        function: ConvertPackBitsFunction::IDENTIFIER.to_owned(),
+        name: "bits".to_owned(),
+        position: ConvertPackBitsFunction::ARGUMENT_INDEX_BITS + 1,
+        expected: format!("[bool; N], 1 <= N <= {}", ConvertPackBitsFunction::BITS_MAX),
+        found: format!("array [{}; {}]", Type::boolean(None), 254),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_unpack_bits_bits_count_mismatch() {
+    let input = r#"
+fn main(value: u8) -> [bool; 16] {
+    std::convert::unpack_bits(value, 16)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibConvertBitsCountMismatch {
+            location: Location::test(3, 38),
+            bits: 16,
+            found: Type::integer_unsigned(None, 8).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_convert_unpack_bits_bits_count_mismatch_field() {
+    let input = r#"
+fn main(value: field) -> [bool; 248] {
+    std::convert::unpack_bits(value, 248)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibConvertBitsCountMismatch {
+            location: Location::test(3, 38),
+            bits: 248,
+            found: Type::field(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_reverse_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::reverse();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
+        expected: ArrayReverseFunction::ARGUMENT_COUNT,
+        found: ArrayReverseFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_reverse_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::array::reverse([true; 8], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
+        expected: ArrayReverseFunction::ARGUMENT_COUNT,
+        found: ArrayReverseFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_reverse_argument_1_array_expected_array() {
+    let input = r#"
+fn main() {
+    std::array::reverse(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 25),
+        function: ArrayReverseFunction::IDENTIFIER.to_owned(),
+        name: "array".to_owned(),
+        position: ArrayReverseFunction::ARGUMENT_INDEX_ARRAY + 1,
+        expected: "[{scalar}; N]".to_owned(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncate_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::truncate([true; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
+        expected: ArrayTruncateFunction::ARGUMENT_COUNT,
+        found: ArrayTruncateFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncate_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::array::truncate([true; 8], 4, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
+        expected: ArrayTruncateFunction::ARGUMENT_COUNT,
+        found: ArrayTruncateFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncate_argument_1_array_expected_array() {
+    let input = r#"
+fn main() {
+    std::array::truncate(42, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 26),
+        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
+        name: "array".to_owned(),
+        position: ArrayTruncateFunction::ARGUMENT_INDEX_ARRAY + 1,
+        expected: "[{scalar}; N]".to_owned(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncate_argument_2_new_length_expected_unsigned_integer() {
+    let input = r#"
+fn main() {
+    std::array::truncate([true; 8], true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 37),
+        function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
+        name: "new_length".to_owned(),
+        position: ArrayTruncateFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
+        expected: "{unsigned integer}".to_owned(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncate_argument_2_new_length_expected_unsigned_integer_constant() {
+    let input = r#"
+fn main() {
+    let new_length = 4;
+    std::array::truncate([true; 8], new_length);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentConstantness {
+            location: Location::test(4, 37),
+            function: ArrayTruncateFunction::IDENTIFIER.to_owned(),
+            name: "new_length".to_owned(),
+            position: ArrayTruncateFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_truncating_to_bigger_size() {
+    let input = r#"
+fn main() -> [u8; 4] {
+    std::array::truncate([1, 2], 4)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibArrayTruncatingToBiggerSize {
+            location: Location::test(3, 5),
+            from: 2,
+            to: 4,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_pad_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::pad([true; 8], 12);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayPadFunction::IDENTIFIER.to_owned(),
+        expected: ArrayPadFunction::ARGUMENT_COUNT,
+        found: ArrayPadFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_pad_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::array::pad([true; 8], 12, false, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayPadFunction::IDENTIFIER.to_owned(),
         expected: ArrayPadFunction::ARGUMENT_COUNT,
         found: ArrayPadFunction::ARGUMENT_COUNT + 1,
         reference: None,
@@ -1316,23 +1840,371 @@ fn main() {
 }
 
 #[test]
-fn error_array_pad_argument_2_new_length_expected_unsigned_integer_constant() {
+fn error_array_pad_argument_2_new_length_expected_unsigned_integer_constant() {
+    let input = r#"
+fn main() {
+    let new_length = 4;
+    std::array::pad([true; 8], new_length, false);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentConstantness {
+            location: Location::test(4, 32),
+            function: ArrayPadFunction::IDENTIFIER.to_owned(),
+            name: "new_length".to_owned(),
+            position: ArrayPadFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_pad_argument_3_fill_value_expected_array_element() {
+    let input = r#"
+fn main() {
+    std::array::pad([true; 8], 12, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 36),
+        function: ArrayPadFunction::IDENTIFIER.to_owned(),
+        name: "fill_value".to_owned(),
+        position: ArrayPadFunction::ARGUMENT_INDEX_FILL_VALUE + 1,
+        expected: Type::boolean(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_padding_to_lesser_size() {
+    let input = r#"
+fn main() -> [u8; 4] {
+    std::array::pad([1, 2, 3, 4], 2, 0)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibArrayPaddingToLesserSize {
+            location: Location::test(3, 5),
+            from: 4,
+            to: 2,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_array_concat_constant() {
+    let input = r#"
+const FIRST: [u8; 2] = [1, 2];
+const CONCATENATED: [u8; 4] = std::array::concat(FIRST, [3; 2]);
+const LAST: u8 = CONCATENATED[3];
+
+fn main() -> u8 {
+    LAST
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_concat_runtime() {
+    let input = r#"
+fn main(first: [u8; 2], value: u8) -> [u8; 5] {
+    std::array::concat(first, [value; 3])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_array_concat_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::concat([true; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayConcatFunction::IDENTIFIER.to_owned(),
+        expected: ArrayConcatFunction::ARGUMENT_COUNT,
+        found: ArrayConcatFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_concat_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::array::concat([true; 8], [true; 8], [true; 8]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayConcatFunction::IDENTIFIER.to_owned(),
+        expected: ArrayConcatFunction::ARGUMENT_COUNT,
+        found: ArrayConcatFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_concat_argument_2_second_expected_array() {
+    let input = r#"
+fn main() {
+    std::array::concat([true; 8], true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 35),
+        function: ArrayConcatFunction::IDENTIFIER.to_owned(),
+        name: "second".to_owned(),
+        position: ArrayConcatFunction::ARGUMENT_INDEX_SECOND + 1,
+        expected: "[T; N]".to_owned(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_concat_element_type() {
+    let input = r#"
+fn main(first: [u8; 4]) {
+    std::array::concat(first, [true; 4]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibArrayConcatElementType {
+            location: Location::test(3, 31),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(3, 24),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_concat_constant_element_type() {
+    let input = r#"
+const CONCATENATED: [u8; 4] = std::array::concat([1, 2], [true, false]);
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibArrayConcatElementType {
+            location: Location::test(2, 58),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(2, 50),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_array_binary_search_constant_found_first() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 1);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 2], [u8; 1]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_constant_found_duplicate() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 3);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 2], [u8; 2]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_constant_found_last() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 9);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 2], [u8; 5]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_constant_absent_before_first() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 0);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 1], [u8; 1]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_constant_absent_after_last() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 10);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 1], [u8; 6]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_constant_absent_middle() {
+    let input = r#"
+const TABLE: [u8; 5] = [1, 3, 3, 7, 9];
+const RESULT: (bool, u64) = std::array::binary_search(TABLE, 5);
+const FOUND: u64 = if RESULT.0 { 1 } else { 0 };
+const INDEX: u64 = RESULT.1;
+
+fn main() -> ([u8; 1], [u8; 4]) {
+    ([0; FOUND + 1], [0; INDEX + 1])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_binary_search_runtime() {
+    let input = r#"
+fn main(table: [u8; 4], needle: u8) -> (bool, u64) {
+    std::array::binary_search(table, needle)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_array_binary_search_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::array::binary_search([1, 2, 3]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayBinarySearchFunction::IDENTIFIER.to_owned(),
+        expected: ArrayBinarySearchFunction::ARGUMENT_COUNT,
+        found: ArrayBinarySearchFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_binary_search_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::array::binary_search([1, 2, 3], 2, 3);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: ArrayBinarySearchFunction::IDENTIFIER.to_owned(),
+        expected: ArrayBinarySearchFunction::ARGUMENT_COUNT,
+        found: ArrayBinarySearchFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_binary_search_argument_1_sorted_expected_integer_array() {
     let input = r#"
 fn main() {
-    let new_length = 4;
-    std::array::pad([true; 8], new_length, false);
+    std::array::binary_search([true, false], true);
 }
 "#;
 
-    let expected = Err(Error::Semantic(
-        SemanticError::FunctionArgumentConstantness {
-            location: Location::test(4, 32),
-            function: ArrayPadFunction::IDENTIFIER.to_owned(),
-            name: "new_length".to_owned(),
-            position: ArrayPadFunction::ARGUMENT_INDEX_NEW_LENGTH + 1,
-            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
-        },
-    ));
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 31),
+        function: ArrayBinarySearchFunction::IDENTIFIER.to_owned(),
+        name: "sorted".to_owned(),
+        position: ArrayBinarySearchFunction::ARGUMENT_INDEX_SORTED + 1,
+        expected: "[{integer}; N]".to_owned(),
+        found: Type::array(None, Type::boolean(None), 2).to_string(),
+    }));
 
     let result = crate::semantic::tests::compile_entry(input);
 
@@ -1340,20 +2212,20 @@ fn main() {
 }
 
 #[test]
-fn error_array_pad_argument_3_fill_value_expected_array_element() {
+fn error_array_binary_search_argument_2_needle_expected_element_type() {
     let input = r#"
-fn main() {
-    std::array::pad([true; 8], 12, 42);
+fn main(table: [u8; 4]) {
+    std::array::binary_search(table, true);
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
-        location: Location::test(3, 36),
-        function: ArrayPadFunction::IDENTIFIER.to_owned(),
-        name: "fill_value".to_owned(),
-        position: ArrayPadFunction::ARGUMENT_INDEX_FILL_VALUE + 1,
-        expected: Type::boolean(None).to_string(),
-        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        location: Location::test(3, 38),
+        function: ArrayBinarySearchFunction::IDENTIFIER.to_owned(),
+        name: "needle".to_owned(),
+        position: ArrayBinarySearchFunction::ARGUMENT_INDEX_NEEDLE + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -1362,20 +2234,46 @@ fn main() {
 }
 
 #[test]
-fn error_array_padding_to_lesser_size() {
+fn ok_array_is_sorted_constant() {
     let input = r#"
-fn main() -> [u8; 4] {
-    std::array::pad([1, 2, 3, 4], 2, 0)
+const SORTED: u64 = if std::array::is_sorted([1, 3, 3, 7]) { 1 } else { 0 };
+const UNSORTED: u64 = if std::array::is_sorted([1, 3, 2, 7]) { 1 } else { 0 };
+
+fn main() -> ([u8; 2], [u8; 1]) {
+    ([0; SORTED + 1], [0; UNSORTED + 1])
 }
 "#;
 
-    let expected = Err(Error::Semantic(
-        SemanticError::FunctionStdlibArrayPaddingToLesserSize {
-            location: Location::test(3, 5),
-            from: 4,
-            to: 2,
-        },
-    ));
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_is_sorted_runtime() {
+    let input = r#"
+fn main(array: [i8; 4]) -> bool {
+    std::array::is_sorted(array)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_array_is_sorted_argument_1_array_expected_integer_array() {
+    let input = r#"
+fn main(array: [field; 4]) -> bool {
+    std::array::is_sorted(array)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 27),
+        function: ArrayIsSortedFunction::IDENTIFIER.to_owned(),
+        name: "array".to_owned(),
+        position: ArrayIsSortedFunction::ARGUMENT_INDEX_ARRAY + 1,
+        expected: "[{integer}; N]".to_owned(),
+        found: Type::array(None, Type::field(None), 4).to_string(),
+    }));
 
     let result = crate::semantic::tests::compile_entry(input);
 
@@ -1473,6 +2371,73 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_ff_inverse_deprecated() {
+    let input = r#"
+fn main(value: field) -> field {
+    std::ff::inverse(value)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_ff_inverse_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::ff::inverse();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: Deprecation::FF_INVERSE.identifier.to_owned(),
+        expected: FfInvertFunction::ARGUMENT_COUNT,
+        found: FfInvertFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_deprecation_warning() {
+    let location = Location::test(3, 5);
+
+    assert_eq!(
+        Deprecation::FF_INVERSE.warning(location),
+        format!(
+            "{}: `std::ff::inverse` is deprecated since the standard library version 2, use `std::ff::invert` instead",
+            location
+        )
+    );
+}
+
+#[test]
+fn ok_deprecation_find() {
+    assert_eq!(
+        Deprecation::find(
+            FfInvertFunction::default().library_identifier,
+            Deprecation::FF_INVERSE.identifier
+        ),
+        Some(Deprecation::FF_INVERSE)
+    );
+    assert_eq!(
+        Deprecation::find(
+            FfInvertFunction::default().library_identifier,
+            FfInvertFunction::IDENTIFIER
+        ),
+        None
+    );
+
+    for deprecation in Deprecation::ALL.iter() {
+        assert!(deprecation.since <= zinc_const::version::STD);
+    }
+}
+
 #[test]
 fn error_collections_mtreemap_get_argument_count_lesser() {
     let input = r#"
@@ -1940,3 +2905,226 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_collections_mtreemap_get_or_argument_count_lesser() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    values: MTreeMap<u160, u248>;
+
+    pub fn test(mut self) -> u248 {
+        self.values.get_or(0x0000000000000000000000000000000000000000 as u160);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(8, 27),
+        function: CollectionsMTreeMapGetOrFunction::IDENTIFIER.to_owned(),
+        expected: CollectionsMTreeMapGetOrFunction::ARGUMENT_COUNT,
+        found: CollectionsMTreeMapGetOrFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_collections_mtreemap_get_or_argument_3_default_expected_u248() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    values: MTreeMap<u160, u248>;
+
+    pub fn test(mut self) -> u248 {
+        self.values.get_or(0x0000000000000000000000000000000000000000 as u160, false);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(8, 80),
+        function: CollectionsMTreeMapGetOrFunction::IDENTIFIER.to_owned(),
+        name: "default".to_owned(),
+        position: CollectionsMTreeMapGetOrFunction::ARGUMENT_INDEX_DEFAULT + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BALANCE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_collections_mtreemap_insert_if_absent_argument_count_lesser() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    values: MTreeMap<u160, u248>;
+
+    pub fn test(mut self) -> u248 {
+        self.values.insert_if_absent(0x0000000000000000000000000000000000000000 as u160);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(8, 37),
+        function: CollectionsMTreeMapInsertIfAbsentFunction::IDENTIFIER.to_owned(),
+        expected: CollectionsMTreeMapInsertIfAbsentFunction::ARGUMENT_COUNT,
+        found: CollectionsMTreeMapInsertIfAbsentFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_collections_mtreemap_insert_if_absent_argument_3_value_expected_u248() {
+    let input = r#"
+use std::collections::MTreeMap;
+
+contract Test {
+    values: MTreeMap<u160, u248>;
+
+    pub fn test(mut self) -> u248 {
+        self.values.insert_if_absent(0x0000000000000000000000000000000000000000 as u160, false);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(8, 90),
+        function: CollectionsMTreeMapInsertIfAbsentFunction::IDENTIFIER.to_owned(),
+        name: "value".to_owned(),
+        position: CollectionsMTreeMapInsertIfAbsentFunction::ARGUMENT_INDEX_VALUE + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BALANCE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_test_rand_u() {
+    let input = r#"
+#[test]
+fn test() {
+    for i in 0..10 as u8 {
+        let a: u64 = std::test::rand_u(64, i * 2);
+        let b: u64 = std::test::rand_u(64, i * 2 + 1);
+        require(a as u72 + b as u72 == b as u72 + a as u72);
+    }
+}
+
+fn main() {}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_test_rand_u_beyond_unit_test() {
+    let input = r#"
+fn main() -> u64 {
+    std::test::rand_u(64, 0)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::UnitTestAssertionBeyondUnitTest {
+            location: Location::test(3, 5),
+            function: TestRandUFunction::IDENTIFIER.to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_test_rand_u_argument_1_bits_expected_constant() {
+    let input = r#"
+#[test]
+fn test() {
+    let bits = 64;
+    let value = std::test::rand_u(bits, 0);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentConstantness {
+            location: Location::test(5, 35),
+            function: TestRandUFunction::IDENTIFIER.to_owned(),
+            name: "bits".to_owned(),
+            position: TestRandUFunction::ARGUMENT_INDEX_BITS + 1,
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_test_rand_u_argument_1_bits_invalid() {
+    let input = r#"
+#[test]
+fn test() {
+    let value = std::test::rand_u(63, 0);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibConvertBitlengthInvalid {
+            location: Location::test(4, 35),
+            value: "63".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_test_rand_u_argument_2_seed_offset_expected_unsigned_integer() {
+    let input = r#"
+#[test]
+fn test() {
+    let value = std::test::rand_u(64, true);
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(4, 39),
+        function: TestRandUFunction::IDENTIFIER.to_owned(),
+        name: "seed_offset".to_owned(),
+        position: TestRandUFunction::ARGUMENT_INDEX_SEED_OFFSET + 1,
+        expected: "{unsigned integer}".to_owned(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
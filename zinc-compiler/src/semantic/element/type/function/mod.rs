@@ -18,6 +18,7 @@ use crate::semantic::element::r#type::contract::Contract as ContractType;
 use crate::semantic::element::r#type::Type;
 
 use self::constant::Function as ConstantFunction;
+use self::intrinsic::stdlib::deprecation::Deprecation;
 use self::intrinsic::Function as IntrinsicFunction;
 use self::runtime::Function as RuntimeFunction;
 use self::test::Function as TestFunction;
@@ -52,6 +53,13 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::debug())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn cfg() -> Self {
+        Self::Intrinsic(IntrinsicFunction::cfg())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -59,6 +67,20 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::require())
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn assert_eq() -> Self {
+        Self::Intrinsic(IntrinsicFunction::assert_eq())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn assert_storage_unchanged() -> Self {
+        Self::Intrinsic(IntrinsicFunction::assert_storage_unchanged())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -66,6 +88,20 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::contract_fetch(contract_type))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn mtreemap_get_or() -> Self {
+        Self::Intrinsic(IntrinsicFunction::mtreemap_get_or())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn mtreemap_insert_if_absent() -> Self {
+        Self::Intrinsic(IntrinsicFunction::mtreemap_insert_if_absent())
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -73,6 +109,13 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::library(identifier))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn library_deprecated(deprecation: Deprecation) -> Self {
+        Self::Intrinsic(IntrinsicFunction::library_deprecated(deprecation))
+    }
+
     ///
     /// A shortcut constructor.
     ///
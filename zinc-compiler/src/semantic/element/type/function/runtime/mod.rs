@@ -80,6 +80,18 @@ impl Function {
             .unwrap_or_default()
     }
 
+    ///
+    /// The size of the mutable `self` instance, which is returned after the function result.
+    ///
+    /// `None` if the function does not write its instance back.
+    ///
+    pub fn written_back_instance_size(&self) -> Option<usize> {
+        self.bindings
+            .first()
+            .filter(|instance| instance.is_instance_written_back())
+            .map(|instance| instance.r#type.size())
+    }
+
     ///
     /// Calls the function with the `argument_list`, validating the call.
     ///
@@ -237,6 +237,73 @@ fn main() {}
         SemanticError::FunctionCallMutableFromImmutable {
             location: Location::test(8, 21),
             function: "mutable".to_owned(),
+            binding: "self".to_owned(),
+            place: "self".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_calling_mutable_on_nested_place() {
+    let input = r#"
+struct Inner {
+    value: u8,
+}
+
+impl Inner {
+    pub fn set(mut self, value: u8) -> u8 {
+        self.value = value;
+        self.value
+    }
+}
+
+struct Outer {
+    inner: [Inner; 2],
+}
+
+fn main() {
+    let mut outers = [Outer { inner: [Inner { value: 0 }; 2] }; 3];
+    outers[2].inner[1].set(42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_calling_mutable_on_immutable_nested_place() {
+    let input = r#"
+struct Inner {
+    value: u8,
+}
+
+impl Inner {
+    pub fn set(mut self, value: u8) -> u8 {
+        self.value = value;
+        self.value
+    }
+}
+
+struct Outer {
+    inner: [Inner; 2],
+}
+
+fn main() {
+    let outers = [Outer { inner: [Inner { value: 0 }; 2] }; 3];
+    outers[2].inner[1].set(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionCallMutableFromImmutable {
+            location: Location::test(19, 27),
+            function: "set".to_owned(),
+            binding: "outers".to_owned(),
+            place: "outers[2].inner[1]".to_owned(),
         },
     ));
 
@@ -288,6 +355,8 @@ contract Data {
         SemanticError::FunctionCallMutableFromImmutable {
             location: Location::test(6, 21),
             function: "mutable".to_owned(),
+            binding: "self".to_owned(),
+            place: "self".to_owned(),
         },
     ));
 
@@ -0,0 +1,130 @@
+//!
+//! The semantic analyzer interface type element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// Describes an interface type, that is, a set of contract method signatures.
+///
+#[derive(Debug, Clone)]
+pub struct Interface {
+    /// The interface type location in the code.
+    pub location: Location,
+    /// The interface type identifier.
+    pub identifier: String,
+    /// The unique interface type ID.
+    pub type_id: usize,
+    /// The interface method signatures.
+    pub methods: Vec<Method>,
+}
+
+impl Interface {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        type_id: usize,
+        methods: Vec<Method>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            type_id,
+            methods,
+        }
+    }
+}
+
+impl PartialEq<Self> for Interface {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id
+    }
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier)
+    }
+}
+
+///
+/// The interface method signature.
+///
+#[derive(Debug, Clone)]
+pub struct Method {
+    /// The method signature location in the code.
+    pub location: Location,
+    /// The method identifier.
+    pub identifier: String,
+    /// Whether the method has the `self` argument.
+    pub is_instance: bool,
+    /// Whether the method has the `mut self` argument.
+    pub is_mutable: bool,
+    /// The argument types except `self`.
+    pub argument_types: Vec<Type>,
+    /// The method return type.
+    pub return_type: Type,
+}
+
+impl Method {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        is_instance: bool,
+        is_mutable: bool,
+        argument_types: Vec<Type>,
+        return_type: Type,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            is_instance,
+            is_mutable,
+            argument_types,
+            return_type,
+        }
+    }
+
+    ///
+    /// Checks whether the contract method signature matches the interface one.
+    ///
+    pub fn matches(&self, other: &Self) -> bool {
+        self.is_instance == other.is_instance
+            && self.is_mutable == other.is_mutable
+            && self.argument_types == other.argument_types
+            && self.return_type == other.return_type
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut arguments = Vec::with_capacity(self.argument_types.len() + 1);
+        if self.is_instance {
+            arguments.push(if self.is_mutable {
+                format!("{} {}", Keyword::Mut, Keyword::SelfLowercase)
+            } else {
+                Keyword::SelfLowercase.to_string()
+            });
+        }
+        arguments.extend(self.argument_types.iter().map(|r#type| r#type.to_string()));
+
+        write!(
+            f,
+            "fn {}({}) -> {}",
+            self.identifier,
+            arguments.join(", "),
+            self.return_type,
+        )
+    }
+}
@@ -10,6 +10,8 @@ pub mod contract;
 pub mod enumeration;
 pub mod function;
 pub mod i_typed;
+pub mod interface;
+pub mod nominal;
 pub mod range;
 pub mod range_inclusive;
 pub mod structure;
@@ -22,6 +24,7 @@ use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::Type as SyntaxType;
 use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 use zinc_syntax::Variant;
@@ -43,6 +46,9 @@ use self::contract::Contract;
 use self::enumeration::Enumeration;
 use self::function::Function;
 use self::i_typed::ITyped;
+use self::interface::Interface;
+use self::interface::Method as InterfaceMethod;
+use self::nominal::Nominal;
 use self::range::Range;
 use self::range_inclusive::RangeInclusive;
 use self::structure::Structure;
@@ -75,6 +81,8 @@ pub enum Type {
     },
     /// The `field` type.
     Field(Option<Location>),
+    /// The built-in nominal integer type, that is, `zksync::Address` or `zksync::Balance`.
+    Nominal(Nominal),
     /// The compile-time only type used mostly for `dbg!` format strings and `require` messages.
     String(Option<Location>),
     /// The compile-time only type used for loop bounds and array slicing.
@@ -93,6 +101,8 @@ pub enum Type {
     Function(Function),
     /// The special contract type declared with a `contract` statement.
     Contract(Contract),
+    /// The special interface type declared with an `interface` statement.
+    Interface(Interface),
 }
 
 impl Type {
@@ -163,6 +173,20 @@ impl Type {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn address(location: Option<Location>) -> Self {
+        Self::Nominal(Nominal::address(location))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn balance(location: Option<Location>) -> Self {
+        Self::Nominal(Nominal::balance(location))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -223,12 +247,21 @@ impl Type {
         identifier: String,
         variants: Vec<Variant>,
         generics: Vec<String>,
+        is_bitflags: bool,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         let type_id = TYPE_INDEX.next(format!("enumeration {}", identifier));
 
-        Enumeration::new(location, identifier, type_id, variants, generics, scope)
-            .map(Self::Enumeration)
+        Enumeration::new(
+            location,
+            identifier,
+            type_id,
+            variants,
+            generics,
+            is_bitflags,
+            scope,
+        )
+        .map(Self::Enumeration)
     }
 
     ///
@@ -296,11 +329,28 @@ impl Type {
         identifier: String,
         project: zinc_project::ManifestProject,
         fields: Vec<ContractField>,
+        invariants: Vec<(ExpressionTree, Location)>,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         let type_id = TYPE_INDEX.next(format!("contract {}", identifier));
 
-        Contract::new(location, identifier, project, type_id, fields, scope).map(Self::Contract)
+        Contract::new(
+            location, identifier, project, type_id, fields, invariants, scope,
+        )
+        .map(Self::Contract)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn interface(
+        location: Location,
+        identifier: String,
+        methods: Vec<InterfaceMethod>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("interface {}", identifier));
+
+        Self::Interface(Interface::new(location, identifier, type_id, methods))
     }
 
     ///
@@ -316,6 +366,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => 1,
             Self::IntegerSigned { .. } => 1,
             Self::Field(_) => 1,
+            Self::Nominal(_) => 1,
             Self::String(_) => 0,
             Self::Range(_) => 0,
             Self::RangeInclusive(_) => 0,
@@ -329,6 +380,7 @@ impl Type {
             Self::Enumeration(_inner) => 1,
             Self::Contract(_inner) => 1,
             Self::Function(_inner) => 0,
+            Self::Interface(_inner) => 0,
         }
     }
 
@@ -341,19 +393,21 @@ impl Type {
             Self::IntegerUnsigned { .. } |
             Self::IntegerSigned { .. } |
             Self::Field(_) |
+            Self::Nominal(_) |
             Self::Enumeration { .. }
         )
     }
 
     ///
-    /// Checks if the type is an unsigned scalar one (booleans, unsigned integers, fields and
-    /// enumeration values).
+    /// Checks if the type is an unsigned scalar one (booleans, unsigned integers, fields,
+    /// nominal and enumeration values).
     ///
     pub fn is_scalar_unsigned(&self) -> bool {
         matches!(self,
             Self::Boolean(_) |
             Self::IntegerUnsigned { .. } |
             Self::Field(_) |
+            Self::Nominal(_) |
             Self::Enumeration { .. }
         )
     }
@@ -421,7 +475,7 @@ impl Type {
     ///
     /// Checks if the type can be instantiated.
     ///
-    /// Instantiation is currently impossible for strings, ranges, functions,
+    /// Instantiation is currently impossible for strings, ranges, functions, interfaces,
     /// and maps beyond the contract storage.
     ///
     pub fn is_instantiatable(&self, is_contract_field: bool) -> bool {
@@ -431,6 +485,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
             Self::Field(_) => true,
+            Self::Nominal(_) => true,
             Self::String(_) => false,
             Self::Range(_) => false,
             Self::RangeInclusive(_) => false,
@@ -461,6 +516,7 @@ impl Type {
                 .iter()
                 .map(|field| &field.r#type)
                 .all(|r#type| Self::is_instantiatable(r#type, true)),
+            Self::Interface(_) => false,
         }
     }
 
@@ -475,6 +531,120 @@ impl Type {
         }
     }
 
+    ///
+    /// Returns the number of leaves the type occupies in the contract storage.
+    ///
+    /// Structures and arrays of structures are flattened into the leaves of their elements in
+    /// the declaration order, whereas the other types, including `MTreeMap`, occupy a single leaf.
+    ///
+    pub fn storage_leaf_count(&self) -> usize {
+        match self {
+            Self::Structure(inner) if !self.is_mtreemap() => inner
+                .fields
+                .iter()
+                .map(|(_name, r#type)| r#type.storage_leaf_count())
+                .sum(),
+            Self::Array(inner) if inner.r#type.is_storage_flattened() => {
+                inner.r#type.storage_leaf_count() * inner.size
+            }
+            _ => 1,
+        }
+    }
+
+    ///
+    /// Checks if the type is flattened into several leaves in the contract storage.
+    ///
+    pub fn is_storage_flattened(&self) -> bool {
+        match self {
+            Self::Structure(_) => !self.is_mtreemap(),
+            Self::Array(inner) => inner.r#type.is_storage_flattened(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the values of the type can be compared with the `==` and `!=` operators.
+    ///
+    /// Arrays, tuples, and structures are compared element-wise, so they are comparable if all
+    /// their elements are.
+    ///
+    pub fn is_comparable(&self) -> bool {
+        match self {
+            Self::Unit(_) => true,
+            Self::Boolean(_) => true,
+            Self::IntegerUnsigned { .. } => true,
+            Self::IntegerSigned { .. } => true,
+            Self::Field(_) => true,
+            Self::Nominal(_) => true,
+            Self::Enumeration(_) => true,
+            Self::Array(inner) => inner.r#type.is_comparable(),
+            Self::Tuple(inner) => inner.types.iter().all(Self::is_comparable),
+            Self::Structure(inner) => {
+                !self.is_mtreemap()
+                    && inner
+                        .fields
+                        .iter()
+                        .all(|(_name, r#type)| r#type.is_comparable())
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks the array, tuple, or structure operands of the `==` or `!=` operator.
+    ///
+    /// Returns the number of the element-wise comparisons, which is limited to prevent
+    /// the pathological bytecode size.
+    ///
+    pub fn structural_equality_size(
+        location: Location,
+        type_1: Self,
+        type_2: Self,
+        is_equals: bool,
+    ) -> Result<usize, Error> {
+        if !type_1.is_comparable() {
+            return Err(if is_equals {
+                Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
+                    location,
+                    found: type_1.to_string(),
+                }
+            } else {
+                Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+                    location,
+                    found: type_1.to_string(),
+                }
+            });
+        }
+
+        if type_1 != type_2 {
+            return Err(if is_equals {
+                Error::OperatorEqualsTypesMismatch {
+                    location,
+                    first: type_1.to_string(),
+                    second: type_2.to_string(),
+                }
+            } else {
+                Error::OperatorNotEqualsTypesMismatch {
+                    location,
+                    first: type_1.to_string(),
+                    second: type_2.to_string(),
+                }
+            });
+        }
+
+        let size = type_1.size();
+        if size > zinc_const::limit::STRUCTURAL_EQUALITY_SIZE {
+            return Err(Error::OperatorEqualityOperandTooLarge {
+                location,
+                found: type_1.to_string(),
+                size,
+                limit: zinc_const::limit::STRUCTURAL_EQUALITY_SIZE,
+            });
+        }
+
+        Ok(size)
+    }
+
     ///
     /// Sets the generic arguments for the type.
     ///
@@ -517,19 +687,9 @@ impl Type {
             SyntaxTypeVariant::Array { inner, size } => {
                 let r#type = Self::try_from_syntax(*inner, scope.clone())?;
 
-                let size_location = size.location;
-                let size = match ExpressionAnalyzer::new(scope, TranslationRule::Constant)
-                    .analyze(size)?
-                {
-                    (Element::Constant(Constant::Integer(integer)), _intermediate) => {
-                        integer.to_usize()?
-                    }
-                    (element, _intermediate) => {
-                        return Err(Error::ExpressionNonConstantElement {
-                            location: size_location,
-                            found: element.to_string(),
-                        });
-                    }
+                let size = match size {
+                    Some(size) => Self::array_size_from_syntax(size, scope)?,
+                    None => return Err(Error::TypePlaceholderForbidden { location }),
                 };
 
                 Self::array(Some(location), r#type, size)
@@ -567,9 +727,106 @@ impl Type {
                     }
                 }
             }
+            SyntaxTypeVariant::Inferred => {
+                return Err(Error::TypePlaceholderForbidden { location });
+            }
+        })
+    }
+
+    ///
+    /// Resolves the semantic type from the syntax one, inferring the `_` placeholders from the
+    /// `found` type of the `let` statement initializer.
+    ///
+    /// The array and tuple annotations are matched against the `found` type component-wise,
+    /// so the components without placeholders must be equal to their `found` counterparts.
+    ///
+    pub fn try_from_syntax_inferred(
+        r#type: SyntaxType,
+        found: &Self,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Self, Error> {
+        let location = r#type.location;
+
+        if !r#type.has_placeholders() {
+            let expected = Self::try_from_syntax(r#type, scope)?;
+            if &expected != found {
+                return Err(Error::TypePlaceholderMismatch {
+                    location,
+                    expected: format!("type `{}`", expected),
+                    found: found.to_string(),
+                });
+            }
+            return Ok(expected);
+        }
+
+        Ok(match (r#type.variant, found) {
+            (SyntaxTypeVariant::Inferred, found) => {
+                let mut r#type = found.to_owned();
+                r#type.set_location(location);
+                r#type
+            }
+            (SyntaxTypeVariant::Array { inner, size }, Self::Array(array)) => {
+                if let Some(size) = size {
+                    let size = Self::array_size_from_syntax(size, scope.clone())?;
+                    if size != array.size {
+                        return Err(Error::TypePlaceholderMismatch {
+                            location,
+                            expected: format!("an array of {} elements", size),
+                            found: found.to_string(),
+                        });
+                    }
+                }
+
+                let r#type = Self::try_from_syntax_inferred(*inner, array.r#type.as_ref(), scope)?;
+                Self::array(Some(location), r#type, array.size)
+            }
+            (SyntaxTypeVariant::Tuple { inners }, Self::Tuple(tuple))
+                if inners.len() == tuple.types.len() =>
+            {
+                let mut types = Vec::with_capacity(inners.len());
+                for (inner, found) in inners.into_iter().zip(tuple.types.iter()) {
+                    types.push(Self::try_from_syntax_inferred(inner, found, scope.clone())?);
+                }
+                Self::tuple(Some(location), types)
+            }
+            (SyntaxTypeVariant::Array { .. }, found) => {
+                return Err(Error::TypePlaceholderMismatch {
+                    location,
+                    expected: "an array".to_owned(),
+                    found: found.to_string(),
+                });
+            }
+            (SyntaxTypeVariant::Tuple { inners }, found) => {
+                return Err(Error::TypePlaceholderMismatch {
+                    location,
+                    expected: format!("a tuple of {} elements", inners.len()),
+                    found: found.to_string(),
+                });
+            }
+            (_variant, _found) => return Err(Error::TypePlaceholderForbidden { location }),
         })
     }
 
+    ///
+    /// Evaluates the array type size expression, which must be a constant integer.
+    ///
+    fn array_size_from_syntax(
+        size: ExpressionTree,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<usize, Error> {
+        let location = size.location;
+
+        match ExpressionAnalyzer::new(scope, TranslationRule::Constant).analyze(size)? {
+            (Element::Constant(Constant::Integer(integer)), _intermediate) => {
+                Ok(integer.to_usize()?)
+            }
+            (element, _intermediate) => Err(Error::ExpressionNonConstantElement {
+                location,
+                found: element.to_string(),
+            }),
+        }
+    }
+
     ///
     /// Gets the semantic element type where it is possible.
     ///
@@ -618,6 +875,7 @@ impl Type {
             Self::IntegerUnsigned { location, .. } => *location = Some(value),
             Self::IntegerSigned { location, .. } => *location = Some(value),
             Self::Field(location) => *location = Some(value),
+            Self::Nominal(inner) => inner.location = Some(value),
             Self::String(location) => *location = Some(value),
             Self::Range(inner) => inner.location = Some(value),
             Self::RangeInclusive(inner) => inner.location = Some(value),
@@ -627,6 +885,7 @@ impl Type {
             Self::Enumeration(inner) => inner.location = Some(value),
             Self::Function(inner) => inner.set_location(value),
             Self::Contract(inner) => inner.location = value,
+            Self::Interface(inner) => inner.location = value,
         }
     }
 
@@ -640,6 +899,7 @@ impl Type {
             Self::IntegerUnsigned { location, .. } => *location,
             Self::IntegerSigned { location, .. } => *location,
             Self::Field(location) => *location,
+            Self::Nominal(inner) => inner.location,
             Self::String(location) => *location,
             Self::Range(inner) => inner.location,
             Self::RangeInclusive(inner) => inner.location,
@@ -649,6 +909,7 @@ impl Type {
             Self::Enumeration(inner) => inner.location,
             Self::Function(inner) => inner.location(),
             Self::Contract(inner) => Some(inner.location),
+            Self::Interface(inner) => Some(inner.location),
         }
     }
 }
@@ -667,6 +928,7 @@ impl PartialEq<Type> for Type {
                 Self::IntegerSigned { bitlength: b2, .. },
             ) => b1 == b2,
             (Self::Field(_), Self::Field(_)) => true,
+            (Self::Nominal(inner_1), Self::Nominal(inner_2)) => inner_1 == inner_2,
             (Self::String(_), Self::String(_)) => true,
             (Self::Range(inner_1), Self::Range(inner_2)) => inner_1.r#type == inner_2.r#type,
             (Self::RangeInclusive(inner_1), Self::RangeInclusive(inner_2)) => {
@@ -679,6 +941,7 @@ impl PartialEq<Type> for Type {
             (Self::Structure(inner_1), Self::Structure(inner_2)) => inner_1 == inner_2,
             (Self::Enumeration(inner_1), Self::Enumeration(inner_2)) => inner_1 == inner_2,
             (Self::Contract(inner_1), Self::Contract(inner_2)) => inner_1 == inner_2,
+            (Self::Interface(inner_1), Self::Interface(inner_2)) => inner_1 == inner_2,
             _ => false,
         }
     }
@@ -692,6 +955,7 @@ impl fmt::Display for Type {
             Self::IntegerUnsigned { bitlength, .. } => write!(f, "u{}", bitlength),
             Self::IntegerSigned { bitlength, .. } => write!(f, "i{}", bitlength),
             Self::Field(_) => write!(f, "field"),
+            Self::Nominal(inner) => write!(f, "{}", inner),
             Self::String(_) => write!(f, "str"),
             Self::Range(inner) => write!(f, "range {}", inner),
             Self::RangeInclusive(inner) => write!(f, "range inclusive {}", inner),
@@ -701,6 +965,7 @@ impl fmt::Display for Type {
             Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Function(inner) => write!(f, "function {}", inner),
             Self::Contract(inner) => write!(f, "contract {}", inner),
+            Self::Interface(inner) => write!(f, "interface {}", inner),
         }
     }
 }
@@ -0,0 +1,144 @@
+//!
+//! The semantic analyzer nominal integer type element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::scope::intrinsic::IntrinsicTypeId;
+
+///
+/// Describes a built-in nominal integer type, that is, `zksync::Address`, `zksync::Balance`, or
+/// the flag set of a `#[bitflags]` enumeration.
+///
+/// The nominal type values are stored as unsigned integers of the type `bitlength`, but cannot be
+/// implicitly mixed with the ordinar integers or other nominal types. The conversion is only
+/// possible with the explicit `as` casting.
+///
+#[derive(Debug, Clone)]
+pub struct Nominal {
+    /// The nominal type location in the code.
+    pub location: Option<Location>,
+    /// The nominal type identifier.
+    pub identifier: &'static str,
+    /// The unique nominal type ID.
+    pub type_id: usize,
+    /// The underlying unsigned integer bitlength.
+    pub bitlength: usize,
+    /// The `#[bitflags]` enumeration, if the type is its flag set.
+    pub enumeration: Option<Enumeration>,
+}
+
+impl Nominal {
+    /// The `zksync::Address` type identifier.
+    pub const IDENTIFIER_ADDRESS: &'static str = "Address";
+
+    /// The `zksync::Balance` type identifier.
+    pub const IDENTIFIER_BALANCE: &'static str = "Balance";
+
+    /// The `<Enumeration>::Flags` type identifier.
+    pub const IDENTIFIER_FLAGS: &'static str = "Flags";
+
+    ///
+    /// Creates the `zksync::Address` type, which wraps `u160`.
+    ///
+    pub fn address(location: Option<Location>) -> Self {
+        Self {
+            location,
+            identifier: Self::IDENTIFIER_ADDRESS,
+            type_id: IntrinsicTypeId::ZkSyncAddress as usize,
+            bitlength: zinc_const::bitlength::ETH_ADDRESS,
+            enumeration: None,
+        }
+    }
+
+    ///
+    /// Creates the `zksync::Balance` type, which wraps `u248`.
+    ///
+    pub fn balance(location: Option<Location>) -> Self {
+        Self {
+            location,
+            identifier: Self::IDENTIFIER_BALANCE,
+            type_id: IntrinsicTypeId::ZkSyncBalance as usize,
+            bitlength: zinc_const::bitlength::BALANCE,
+            enumeration: None,
+        }
+    }
+
+    ///
+    /// Creates the `<Enumeration>::Flags` type, which wraps the `enumeration` integer type.
+    ///
+    pub fn flags(enumeration: Enumeration) -> Self {
+        Self {
+            location: enumeration.location,
+            identifier: Self::IDENTIFIER_FLAGS,
+            type_id: IntrinsicTypeId::EnumerationFlags as usize,
+            bitlength: enumeration.bitlength,
+            enumeration: Some(enumeration),
+        }
+    }
+
+    ///
+    /// Checks if the type is `zksync::Address`.
+    ///
+    pub fn is_address(&self) -> bool {
+        self.type_id == IntrinsicTypeId::ZkSyncAddress as usize
+    }
+
+    ///
+    /// Checks if the type is `zksync::Balance`.
+    ///
+    pub fn is_balance(&self) -> bool {
+        self.type_id == IntrinsicTypeId::ZkSyncBalance as usize
+    }
+
+    ///
+    /// Checks if the type is a `#[bitflags]` enumeration flag set.
+    ///
+    pub fn is_flags(&self) -> bool {
+        self.type_id == IntrinsicTypeId::EnumerationFlags as usize
+    }
+
+    ///
+    /// Checks if the type values can be ordered with the `<`, `<=`, `>`, `>=` operators.
+    ///
+    /// Only balances can be ordered, whereas addresses are only compared for equality.
+    ///
+    pub fn is_ordered(&self) -> bool {
+        self.is_balance()
+    }
+
+    ///
+    /// Checks if the type values can be added and subtracted, preserving the type.
+    ///
+    pub fn is_additive(&self) -> bool {
+        self.is_balance()
+    }
+
+    ///
+    /// Checks if the type values can be combined with the `|` and `&` operators, preserving the type.
+    ///
+    pub fn is_combinable(&self) -> bool {
+        self.is_flags()
+    }
+}
+
+impl PartialEq<Self> for Nominal {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id && self.enumeration == other.enumeration
+    }
+}
+
+impl fmt::Display for Nominal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.enumeration {
+            Some(ref enumeration) => write!(f, "{}::{}", enumeration, self.identifier),
+            None => write!(f, "{}", self.identifier),
+        }
+    }
+}
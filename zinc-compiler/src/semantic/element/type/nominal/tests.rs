@@ -0,0 +1,316 @@
+//!
+//! The semantic analyzer nominal integer type element tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::casting::error::Error as CastingError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_casting_from_integer() {
+    let input = r#"
+fn main() -> (zksync::Address, zksync::Balance) {
+    let address = 0x42 as u160;
+    let balance = 1_000_000 as u248;
+    (address as zksync::Address, balance as zksync::Balance)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_casting_to_integer() {
+    let input = r#"
+fn main(address: zksync::Address, balance: zksync::Balance) -> (u160, u248, field) {
+    (address as u160, balance as u248, balance as field)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_address_equality() {
+    let input = r#"
+fn main(sender: zksync::Address, recipient: zksync::Address) -> bool {
+    sender == recipient || sender != 0x42 as zksync::Address
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_balance_ordering_and_arithmetic() {
+    let input = r#"
+fn main(amount: zksync::Balance, fee: zksync::Balance) -> zksync::Balance {
+    let limit = 1000 as zksync::Balance;
+    if amount + fee > limit {
+        amount - fee
+    } else {
+        amount + fee
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant() {
+    let input = r#"
+const OWNER: zksync::Address = 0x42 as zksync::Address;
+
+fn main(sender: zksync::Address) -> bool {
+    sender == OWNER
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_implicit_mixing_with_integer() {
+    let input = r#"
+fn main(amount: zksync::Balance, value: u248) -> zksync::Balance {
+    amount + value
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorAdditionTypesMismatch {
+            location: Location::test(3, 5),
+            first: Type::balance(None).to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BALANCE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_implicit_mixing_with_literal() {
+    let input = r#"
+fn main(amount: zksync::Balance) -> zksync::Balance {
+    amount + 1
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorAdditionTypesMismatch {
+            location: Location::test(3, 5),
+            first: Type::balance(None).to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BALANCE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_implicit_mixing_address_with_balance() {
+    let input = r#"
+fn main(address: zksync::Address, amount: zksync::Balance) -> bool {
+    address == amount
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualsTypesMismatch {
+            location: Location::test(3, 5),
+            first: Type::address(None).to_string(),
+            second: Type::balance(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_implicit_argument_from_integer() {
+    let input = r#"
+fn check(address: zksync::Address) -> zksync::Address {
+    address
+}
+
+fn main(value: u160) -> zksync::Address {
+    check(value)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 11),
+        function: "check".to_owned(),
+        name: "address".to_owned(),
+        position: 1,
+        expected: Type::address(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_address_ordering() {
+    let input = r#"
+fn main(sender: zksync::Address, recipient: zksync::Address) -> bool {
+    sender < recipient
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNominalOperandForbidden {
+            location: Location::test(3, 5),
+            operator: "<".to_owned(),
+            found: Type::address(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_address_addition() {
+    let input = r#"
+fn main(sender: zksync::Address, recipient: zksync::Address) -> zksync::Address {
+    sender + recipient
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNominalOperandForbidden {
+            location: Location::test(3, 5),
+            operator: "+".to_owned(),
+            found: Type::address(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_balance_multiplication() {
+    let input = r#"
+fn main(amount: zksync::Balance, fee: zksync::Balance) -> zksync::Balance {
+    amount * fee
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNominalOperandForbidden {
+            location: Location::test(3, 5),
+            operator: "*".to_owned(),
+            found: Type::balance(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_balance_bitwise_shift() {
+    let input = r#"
+fn main(amount: zksync::Balance) -> zksync::Balance {
+    amount << 1
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNominalOperandForbidden {
+            location: Location::test(3, 5),
+            operator: "<<".to_owned(),
+            found: Type::balance(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_balance_negation() {
+    let input = r#"
+fn main(amount: zksync::Balance) -> zksync::Balance {
+    -amount
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNominalOperandForbidden {
+            location: Location::test(3, 6),
+            operator: "-".to_owned(),
+            found: Type::balance(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_casting_address_to_balance() {
+    let input = r#"
+fn main(address: zksync::Address) -> zksync::Balance {
+    address as zksync::Balance
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorCastingTypesMismatch {
+            location: Location::test(3, 5),
+            inner: CastingError::CastingFromInvalidType {
+                from: Type::address(None).to_string(),
+                to: Type::balance(None).to_string(),
+            },
+            reference: Location::test(3, 16),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_casting_signed_to_balance() {
+    let input = r#"
+fn main(value: i8) -> zksync::Balance {
+    value as zksync::Balance
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorCastingTypesMismatch {
+            location: Location::test(3, 5),
+            inner: CastingError::CastingToInvalidType {
+                from: Type::integer_signed(None, zinc_const::bitlength::BYTE).to_string(),
+                to: Type::balance(None).to_string(),
+            },
+            reference: Location::test(3, 14),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
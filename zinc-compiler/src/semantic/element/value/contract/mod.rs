@@ -142,13 +142,15 @@ impl Contract {
     ///
     pub fn slice(self, expected: Identifier) -> Result<(Value, ContractFieldAccess), Error> {
         let mut offset = 0;
+        let mut position = 0;
         let total_size = self.r#type().size();
 
-        for (index, (name, _location, r#type)) in self.fields.iter().enumerate() {
+        for (name, _location, r#type) in self.fields.iter() {
             if name == expected.name.as_str() {
                 let access = ContractFieldAccess::new(
                     expected.name,
-                    index,
+                    position,
+                    r#type.to_owned(),
                     offset,
                     r#type.size(),
                     total_size,
@@ -162,6 +164,7 @@ impl Contract {
                 return Ok((result, access));
             }
             offset += r#type.size();
+            position += r#type.storage_leaf_count();
         }
 
         Err(Error::StructureFieldDoesNotExist {
@@ -171,6 +174,7 @@ impl Contract {
                 .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
                 .identifier,
             field_name: expected.name,
+            place: None,
         })
     }
 }
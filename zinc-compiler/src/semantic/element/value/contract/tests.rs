@@ -23,6 +23,7 @@ contract Test {
         location: Location::test(6, 44),
         r#type: "Test".to_owned(),
         field_name: "c".to_owned(),
+        place: None,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
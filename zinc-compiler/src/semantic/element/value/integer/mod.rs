@@ -5,6 +5,7 @@
 #[cfg(test)]
 mod tests;
 
+use std::cmp;
 use std::fmt;
 use std::ops::Add;
 use std::ops::BitAnd;
@@ -23,6 +24,7 @@ use zinc_lexical::Location;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::nominal::Nominal;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 
@@ -30,6 +32,7 @@ use crate::semantic::error::Error;
 /// Integer values consist of the value, sign, and bitlength.
 /// If a value belongs to an enumeration, the enumeration type is stored in `enumeration`.
 /// Enumeration uniquely defines the value type, even if the sign and bitlength are the same.
+/// The same applies to the built-in nominal types stored in `nominal`.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Integer {
@@ -41,8 +44,13 @@ pub struct Integer {
     pub bitlength: usize,
     /// If the value was created from an enumeration variant.
     pub enumeration: Option<Enumeration>,
+    /// If the value belongs to a built-in nominal type.
+    pub nominal: Option<Nominal>,
     /// If the value was created from an integer literal.
     pub is_literal: bool,
+    /// The number of bits the `field` value is proven to fit, e.g. if it is cast from an unsigned
+    /// integer or created from a constant.
+    pub field_bitlength: Option<usize>,
 }
 
 impl Integer {
@@ -60,7 +68,9 @@ impl Integer {
             is_signed,
             bitlength,
             enumeration: None,
+            nominal: None,
             is_literal,
+            field_bitlength: None,
         }
     }
 
@@ -71,6 +81,102 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    ///
+    /// Set the nominal type for the value, if the value belongs to a built-in nominal type.
+    ///
+    pub fn set_nominal(&mut self, nominal: Nominal) {
+        self.nominal = Some(nominal);
+    }
+
+    ///
+    /// Converts the `#[bitflags]` enumeration variant into the enumeration flag set, so the
+    /// variants and flag sets of the same enumeration can be combined with each other.
+    ///
+    fn enumeration_into_flags(&mut self) {
+        if self
+            .enumeration
+            .as_ref()
+            .map_or(false, Enumeration::is_bitflags)
+        {
+            self.nominal = self.enumeration.take().map(Nominal::flags);
+        }
+    }
+
+    ///
+    /// Checks if the `operator` is applicable to the value of the nominal type, if it is one.
+    ///
+    fn check_nominal(&self, operator: &str, is_allowed: fn(&Nominal) -> bool) -> Result<(), Error> {
+        match self.nominal {
+            Some(ref nominal) if !is_allowed(nominal) => {
+                Err(Error::OperatorNominalOperandForbidden {
+                    location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    operator: operator.to_owned(),
+                    found: nominal.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Returns the number of bits the value is proven to fit as an unsigned number, which is
+    /// its type bitlength, unless it is a signed or `field` value.
+    ///
+    fn ordering_bitlength(&self) -> Option<usize> {
+        match self.bitlength {
+            zinc_const::bitlength::FIELD => self.field_bitlength,
+            _ if self.is_signed => None,
+            bitlength => Some(bitlength),
+        }
+    }
+
+    ///
+    /// Checks that both operands of the ordering `operator` are proven to fit the allowed `field`
+    /// ordering bitlength, if they are `field` values, since the field elements wrap around the
+    /// modulus and have no ordering consistent with the arithmetic.
+    ///
+    /// If the operands also fit an integer type, they are compared as unsigned integers.
+    ///
+    fn check_field_ordering(
+        &self,
+        other: &Self,
+        operator: &str,
+        bitlengths: (Option<usize>, Option<usize>),
+        generator_operator: GeneratorExpressionOperator,
+    ) -> Result<GeneratorExpressionOperator, Error> {
+        if self.bitlength != zinc_const::bitlength::FIELD {
+            return Ok(generator_operator);
+        }
+
+        let mut bitlength = 1;
+        for (location, operand_bitlength) in [
+            (self.location, bitlengths.0),
+            (other.location, bitlengths.1),
+        ]
+        .iter()
+        {
+            match operand_bitlength {
+                Some(operand_bitlength)
+                    if *operand_bitlength <= zinc_const::bitlength::FIELD_ORDERING_MAX =>
+                {
+                    bitlength = cmp::max(bitlength, *operand_bitlength);
+                }
+                _ => {
+                    return Err(Error::OperatorOrderingFieldUnbounded {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        operator: operator.to_owned(),
+                    })
+                }
+            }
+        }
+
+        if bitlength > zinc_const::bitlength::INTEGER_MAX {
+            return Ok(generator_operator);
+        }
+
+        Ok(generator_operator.into_field_bounded(Type::integer_unsigned(self.location, bitlength)))
+    }
+
     ///
     /// Executes the `==` equals comparison operator.
     ///
@@ -145,6 +251,8 @@ impl Integer {
     /// Executes the `>=` greater-equals comparison operator.
     ///
     pub fn greater_equals(mut self, mut other: Self) -> Result<GeneratorExpressionOperator, Error> {
+        let bitlengths = (self.ordering_bitlength(), other.ordering_bitlength());
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -162,6 +270,8 @@ impl Integer {
             });
         }
 
+        self.check_nominal(">=", Nominal::is_ordered)?;
+
         let operator = GeneratorExpressionOperator::greater_equals_inferred(
             inference_result
                 .first
@@ -170,6 +280,7 @@ impl Integer {
                 .second
                 .map(|r#type| Type::scalar(other.location, r#type.is_signed, r#type.bitlength)),
         );
+        let operator = self.check_field_ordering(&other, ">=", bitlengths, operator)?;
 
         self.is_literal = false;
 
@@ -180,6 +291,8 @@ impl Integer {
     /// Executes the `<=` lesser-equals comparison operator.
     ///
     pub fn lesser_equals(mut self, mut other: Self) -> Result<GeneratorExpressionOperator, Error> {
+        let bitlengths = (self.ordering_bitlength(), other.ordering_bitlength());
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -197,6 +310,8 @@ impl Integer {
             });
         }
 
+        self.check_nominal("<=", Nominal::is_ordered)?;
+
         let operator = GeneratorExpressionOperator::lesser_equals_inferred(
             inference_result
                 .first
@@ -205,6 +320,7 @@ impl Integer {
                 .second
                 .map(|r#type| Type::scalar(other.location, r#type.is_signed, r#type.bitlength)),
         );
+        let operator = self.check_field_ordering(&other, "<=", bitlengths, operator)?;
 
         self.is_literal = false;
 
@@ -215,6 +331,8 @@ impl Integer {
     /// Executes the `>` greater comparison operator.
     ///
     pub fn greater(mut self, mut other: Self) -> Result<GeneratorExpressionOperator, Error> {
+        let bitlengths = (self.ordering_bitlength(), other.ordering_bitlength());
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -232,6 +350,8 @@ impl Integer {
             });
         }
 
+        self.check_nominal(">", Nominal::is_ordered)?;
+
         let operator = GeneratorExpressionOperator::greater_inferred(
             inference_result
                 .first
@@ -240,6 +360,7 @@ impl Integer {
                 .second
                 .map(|r#type| Type::scalar(other.location, r#type.is_signed, r#type.bitlength)),
         );
+        let operator = self.check_field_ordering(&other, ">", bitlengths, operator)?;
 
         self.is_literal = false;
 
@@ -250,6 +371,8 @@ impl Integer {
     /// Executes the `<` lesser comparison operator.
     ///
     pub fn lesser(mut self, mut other: Self) -> Result<GeneratorExpressionOperator, Error> {
+        let bitlengths = (self.ordering_bitlength(), other.ordering_bitlength());
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -267,6 +390,8 @@ impl Integer {
             });
         }
 
+        self.check_nominal("<", Nominal::is_ordered)?;
+
         let operator = GeneratorExpressionOperator::lesser_inferred(
             inference_result
                 .first
@@ -275,6 +400,7 @@ impl Integer {
                 .second
                 .map(|r#type| Type::scalar(other.location, r#type.is_signed, r#type.bitlength)),
         );
+        let operator = self.check_field_ordering(&other, "<", bitlengths, operator)?;
 
         self.is_literal = false;
 
@@ -286,6 +412,9 @@ impl BitOr for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitor(mut self, mut other: Self) -> Self::Output {
+        self.enumeration_into_flags();
+        other.enumeration_into_flags();
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -303,6 +432,8 @@ impl BitOr for Integer {
             });
         }
 
+        self.check_nominal("|", Nominal::is_combinable)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -326,6 +457,7 @@ impl BitOr for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -352,6 +484,8 @@ impl BitXor for Integer {
             });
         }
 
+        self.check_nominal("^", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -375,6 +509,7 @@ impl BitXor for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -384,6 +519,9 @@ impl BitAnd for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn bitand(mut self, mut other: Self) -> Self::Output {
+        self.enumeration_into_flags();
+        other.enumeration_into_flags();
+
         let inference_result = zinc_math::infer_literal_types(
             self.is_literal,
             &mut self.is_signed,
@@ -401,6 +539,8 @@ impl BitAnd for Integer {
             });
         }
 
+        self.check_nominal("&", Nominal::is_combinable)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -424,6 +564,7 @@ impl BitAnd for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -433,6 +574,9 @@ impl Shl<Self> for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shl(mut self, other: Self) -> Self::Output {
+        self.check_nominal("<<", |_| false)?;
+        other.check_nominal("<<", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -460,6 +604,7 @@ impl Shl<Self> for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -469,6 +614,9 @@ impl Shr<Self> for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn shr(mut self, other: Self) -> Self::Output {
+        self.check_nominal(">>", |_| false)?;
+        other.check_nominal(">>", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -496,6 +644,7 @@ impl Shr<Self> for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -522,6 +671,8 @@ impl Add for Integer {
             });
         }
 
+        self.check_nominal("+", Nominal::is_additive)?;
+
         let operator = GeneratorExpressionOperator::addition_inferred(
             inference_result
                 .first
@@ -533,6 +684,7 @@ impl Add for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -559,6 +711,8 @@ impl Sub for Integer {
             });
         }
 
+        self.check_nominal("-", Nominal::is_additive)?;
+
         let operator = GeneratorExpressionOperator::subtraction_inferred(
             inference_result
                 .first
@@ -570,6 +724,7 @@ impl Sub for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -596,6 +751,8 @@ impl Mul for Integer {
             });
         }
 
+        self.check_nominal("*", |_| false)?;
+
         let operator = GeneratorExpressionOperator::multiplication_inferred(
             inference_result
                 .first
@@ -607,6 +764,7 @@ impl Mul for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -633,6 +791,8 @@ impl Div for Integer {
             });
         }
 
+        self.check_nominal("/", |_| false)?;
+
         if self.bitlength == zinc_const::bitlength::FIELD {
             return Err(Error::OperatorDivisionFieldOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -650,6 +810,7 @@ impl Div for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -676,6 +837,8 @@ impl Rem for Integer {
             });
         }
 
+        self.check_nominal("%", |_| false)?;
+
         if self.bitlength == zinc_const::bitlength::FIELD {
             return Err(Error::OperatorRemainderFieldOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -693,6 +856,7 @@ impl Rem for Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -706,6 +870,7 @@ impl Integer {
         mut self,
         is_signed: bool,
         bitlength: usize,
+        nominal: Option<Nominal>,
     ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
         let operator = if self.is_signed != is_signed || self.bitlength != bitlength {
             GeneratorExpressionOperator::try_casting(&Type::scalar(
@@ -717,18 +882,53 @@ impl Integer {
             None
         };
 
+        if bitlength == zinc_const::bitlength::FIELD {
+            self.field_bitlength = self.ordering_bitlength();
+        } else {
+            self.field_bitlength = None;
+        }
+
         self.is_signed = is_signed;
         self.bitlength = bitlength;
         self.enumeration = None;
+        self.nominal = nominal;
         self.is_literal = false;
 
         Ok((self, operator))
     }
 
+    ///
+    /// Executes the `as` casting operator into an enumeration type.
+    ///
+    /// The value is checked at runtime to be one of the enumeration variants, unless it already
+    /// belongs to the enumeration.
+    ///
+    pub fn cast_enumeration(
+        mut self,
+        enumeration: Enumeration,
+    ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
+        let operator = if self.enumeration.as_ref() != Some(&enumeration) {
+            GeneratorExpressionOperator::try_casting(&Type::Enumeration(enumeration.clone()))
+        } else {
+            None
+        };
+
+        self.is_signed = enumeration.is_signed;
+        self.bitlength = enumeration.bitlength;
+        self.enumeration = Some(enumeration);
+        self.nominal = None;
+        self.is_literal = false;
+        self.field_bitlength = None;
+
+        Ok((self, operator))
+    }
+
     ///
     /// Executes the `~` bitwise NOT operator.
     ///
     pub fn bitwise_not(mut self) -> Result<(Self, GeneratorExpressionOperator), Error> {
+        self.check_nominal("~", |_| false)?;
+
         if self.is_signed {
             return Err(Error::OperatorBitwiseSignedOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -745,6 +945,7 @@ impl Integer {
 
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -754,6 +955,8 @@ impl Neg for Integer {
     type Output = Result<(Self, GeneratorExpressionOperator), Error>;
 
     fn neg(mut self) -> Self::Output {
+        self.check_nominal("-", |_| false)?;
+
         if self.bitlength == zinc_const::bitlength::FIELD {
             return Err(Error::OperatorNegationFieldOperandForbidden {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -765,6 +968,7 @@ impl Neg for Integer {
         self.is_signed = true;
         self.enumeration = None;
         self.is_literal = false;
+        self.field_bitlength = None;
 
         Ok((self, operator))
     }
@@ -772,9 +976,10 @@ impl Neg for Integer {
 
 impl ITyped for Integer {
     fn r#type(&self) -> Type {
-        match self.enumeration {
-            Some(ref enumeration) => Type::Enumeration(enumeration.to_owned()),
-            None => Type::scalar(self.location, self.is_signed, self.bitlength),
+        match (self.enumeration.as_ref(), self.nominal.as_ref()) {
+            (Some(enumeration), _) => Type::Enumeration(enumeration.to_owned()),
+            (None, Some(nominal)) => Type::Nominal(nominal.to_owned()),
+            (None, None) => Type::scalar(self.location, self.is_signed, self.bitlength),
         }
     }
 
@@ -786,6 +991,7 @@ impl ITyped for Integer {
                 (None, None) => true,
                 _ => false,
             }
+            && self.nominal == other.nominal
     }
 }
 
@@ -26,6 +26,8 @@ use std::ops::Shl;
 use std::ops::Shr;
 use std::ops::Sub;
 
+use num::Signed;
+
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
@@ -181,6 +183,24 @@ impl Value {
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                 found: value_2.r#type().to_string(),
             }),
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2) => {
+                let location = value_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let size = Type::structural_equality_size(
+                    location,
+                    value_1.r#type(),
+                    value_2.r#type(),
+                    true,
+                )?;
+
+                Ok((
+                    Self::Boolean(Boolean::new(Some(location))),
+                    GeneratorExpressionOperator::equals_structural(size),
+                ))
+            }
             (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
                 location: value_1
                     .location()
@@ -232,6 +252,24 @@ impl Value {
                     found: value_2.r#type().to_string(),
                 })
             }
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2) => {
+                let location = value_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let size = Type::structural_equality_size(
+                    location,
+                    value_1.r#type(),
+                    value_2.r#type(),
+                    false,
+                )?;
+
+                Ok((
+                    Self::Boolean(Boolean::new(Some(location))),
+                    GeneratorExpressionOperator::not_equals_structural(size),
+                ))
+            }
             (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
                 location: value_1
                     .location()
@@ -638,16 +676,25 @@ impl Value {
             reference: to.location().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
         })?;
 
-        let (is_signed, bitlength) = match to {
-            Type::IntegerUnsigned { bitlength, .. } => (false, bitlength),
-            Type::IntegerSigned { bitlength, .. } => (true, bitlength),
-            Type::Field(_) => (false, zinc_const::bitlength::FIELD),
+        let (is_signed, bitlength, nominal) = match to {
+            Type::IntegerUnsigned { bitlength, .. } => (false, bitlength, None),
+            Type::IntegerSigned { bitlength, .. } => (true, bitlength, None),
+            Type::Field(_) => (false, zinc_const::bitlength::FIELD, None),
+            Type::Nominal(inner) => (false, inner.bitlength, Some(inner)),
+            Type::Enumeration(inner) => {
+                return Ok(match self {
+                    Self::Integer(integer) => integer
+                        .cast_enumeration(inner)
+                        .map(|(integer, operator)| (Self::Integer(integer), operator))?,
+                    operand => (operand, None),
+                })
+            }
             _ => return Ok((self, None)),
         };
 
         Ok(match self {
             Self::Integer(integer) => integer
-                .cast(is_signed, bitlength)
+                .cast(is_signed, bitlength, nominal)
                 .map(|(integer, operator)| (Self::Integer(integer), operator))?,
             operand => (operand, None),
         })
@@ -848,9 +895,19 @@ impl Value {
                 inner.to_owned(),
             )),
             Type::Enumeration(inner) => {
+                let mut integer = Integer::new(
+                    location.or(inner.location),
+                    inner.is_signed,
+                    inner.bitlength,
+                    false,
+                );
+                integer.set_enumeration(inner.to_owned());
+                Self::Integer(integer)
+            }
+            Type::Nominal(inner) => {
                 let mut integer =
                     Integer::new(location.or(inner.location), false, inner.bitlength, false);
-                integer.set_enumeration(inner.to_owned());
+                integer.set_nominal(inner.to_owned());
                 Self::Integer(integer)
             }
             Type::Contract(inner) => Self::Contract(Contract::new_with_type(
@@ -865,12 +922,30 @@ impl Value {
     /// Tries to create a value from the `constant`, passing its type to the
     /// `try_from_type` function.
     ///
+    /// The `field` constant value bitlength is kept, so it can be compared with the ordering
+    /// operators.
+    ///
     pub fn try_from_constant(constant: Constant) -> Result<Self, Error> {
-        Self::try_from_type(
+        let field_bitlength = match constant {
+            Constant::Integer(ref integer)
+                if integer.bitlength == zinc_const::bitlength::FIELD
+                    && !integer.value.is_negative() =>
+            {
+                Some(integer.value.bits() as usize)
+            }
+            _ => None,
+        };
+
+        let mut value = Self::try_from_type(
             &constant.r#type(),
             constant.is_literal(),
             Some(constant.location()),
-        )
+        )?;
+        if let Self::Integer(ref mut integer) = value {
+            integer.field_bitlength = field_bitlength;
+        }
+
+        Ok(value)
     }
 
     ///
@@ -157,6 +157,7 @@ impl Structure {
                 .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
                 .identifier,
             field_name: expected.name,
+            place: None,
         })
     }
 }
@@ -146,22 +146,20 @@ fn main() {
 #[test]
 fn error_operator_equals_1st_operand_expected_primitive() {
     let input = r#"
-fn main() {
-    let array = [1, 2, 3];
-    let integer = 42;
-    let value = array == integer;
+contract Test {
+    a: u8;
+
+    pub fn main(self) -> bool {
+        let integer = 42;
+        self == integer
+    }
 }
 "#;
 
     let expected = Err(Error::Semantic(
         SemanticError::OperatorEqualsFirstOperandExpectedPrimitiveType {
-            location: Location::test(5, 17),
-            found: Type::array(
-                Some(Location::test(5, 17)),
-                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
-                3,
-            )
-            .to_string(),
+            location: Location::test(7, 9),
+            found: "contract Test".to_owned(),
         },
     ));
 
@@ -237,24 +235,77 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
     let integer = 42;
-    let value = array != integer;
+    let value = array == integer;
 }
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+        SemanticError::OperatorEqualsTypesMismatch {
             location: Location::test(5, 17),
-            found: Type::array(
+            first: Type::array(
                 Some(Location::test(5, 17)),
                 Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
                 3,
             )
             .to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_equals_operand_too_large() {
+    let input = r#"
+fn main(a: [u8; 1025], b: [u8; 1025]) -> bool {
+    a == b
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualityOperandTooLarge {
+            location: Location::test(3, 5),
+            found: Type::array(
+                Some(Location::test(2, 12)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                1025,
+            )
+            .to_string(),
+            size: 1025,
+            limit: zinc_const::limit::STRUCTURAL_EQUALITY_SIZE,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_not_equals_1st_operand_expected_primitive() {
+    let input = r#"
+contract Test {
+    a: u8;
+
+    pub fn main(self) -> bool {
+        let integer = 42;
+        self != integer
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+            location: Location::test(7, 9),
+            found: "contract Test".to_owned(),
         },
     ));
 
@@ -20,6 +20,26 @@ pub enum Error {
         /// The inner inference error.
         inner: zinc_math::Error,
     },
+    /// The integer literal value does not fit the type specified by its suffix.
+    InvalidIntegerSuffix {
+        /// The error location data.
+        location: Location,
+        /// The literal value.
+        value: BigInt,
+        /// The stringified suffix type.
+        r#type: String,
+    },
+    /// The suffixed integer literal type does not match the variable type annotation.
+    IntegerSuffixTypesMismatch {
+        /// The error location data.
+        location: Location,
+        /// The stringified annotation type.
+        expected: String,
+        /// The stringified suffix type.
+        found: String,
+        /// The type annotation location.
+        reference: Location,
+    },
 
     /// The `=` operator expects a memory place as the first operand.
     OperatorAssignmentFirstOperandExpectedPlace {
@@ -383,6 +403,17 @@ pub enum Error {
         /// The stringified second operand.
         second: String,
     },
+    /// The `==` or `!=` operator operands are too large to be compared element-wise.
+    OperatorEqualityOperandTooLarge {
+        /// The error location data.
+        location: Location,
+        /// The stringified operand type.
+        found: String,
+        /// The number of the element-wise comparisons.
+        size: usize,
+        /// The maximal number of the element-wise comparisons.
+        limit: usize,
+    },
 
     /// The `!=` operator expects an evaluable element as the first operand.
     OperatorNotEqualsFirstOperandExpectedEvaluable {
@@ -437,6 +468,25 @@ pub enum Error {
         second: String,
     },
 
+    /// The ordering operators are not defined for arrays, tuples, and structures.
+    OperatorOrderingUndefined {
+        /// The error location data.
+        location: Location,
+        /// The ordering operator, e.g. `>=`.
+        operator: String,
+        /// The stringified operand type.
+        found: String,
+    },
+
+    /// The ordering operator is applied to a `field` value, which is not proven to fit the
+    /// allowed bitlength.
+    OperatorOrderingFieldUnbounded {
+        /// The error location data.
+        location: Location,
+        /// The ordering operator, e.g. `>=`.
+        operator: String,
+    },
+
     /// The `>=` operator expects an evaluable element as the first operand.
     OperatorGreaterEqualsFirstOperandExpectedEvaluable {
         /// The error location data.
@@ -785,6 +835,15 @@ pub enum Error {
         /// The error location data.
         location: Location,
     },
+    /// The operator is forbidden for the built-in nominal type, e.g. `zksync::Address`.
+    OperatorNominalOperandForbidden {
+        /// The error location data.
+        location: Location,
+        /// The stringified operator.
+        operator: String,
+        /// The stringified nominal type.
+        found: String,
+    },
 
     /// The `+` operator expects an evaluable element as the first operand.
     OperatorAdditionFirstOperandExpectedEvaluable {
@@ -832,6 +891,17 @@ pub enum Error {
         /// The type overflowed by `value`.
         r#type: String,
     },
+    /// The binary `as` operator casts a constant, which is not an enumeration variant.
+    OperatorCastingInvalidVariant {
+        /// The error location data.
+        location: Location,
+        /// The value which is not a variant of `r#type`.
+        value: BigInt,
+        /// The enumeration type name.
+        r#type: String,
+        /// The stringified enumeration variants, e.g. `A = 1`.
+        variants: Vec<String>,
+    },
 
     /// The `-` operator expects an evaluable element as the first operand.
     OperatorSubtractionFirstOperandExpectedEvaluable {
@@ -982,6 +1052,8 @@ pub enum Error {
     OperatorDivisionByZero {
         /// The error location data.
         location: Location,
+        /// The zero constant declaration location, if the divisor is a named constant.
+        reference: Option<Location>,
     },
 
     /// The `%` operator expects an evaluable element as the first operand.
@@ -1039,6 +1111,8 @@ pub enum Error {
     OperatorRemainderOfDivisionByZero {
         /// The error location data.
         location: Location,
+        /// The zero constant declaration location, if the divisor is a named constant.
+        reference: Option<Location>,
     },
 
     /// The `as` operator expects an evaluable element as the first operand.
@@ -1291,6 +1365,8 @@ pub enum Error {
         r#type: String,
         /// The name of the invalid field.
         field_name: String,
+        /// The full path of the accessed memory place. `None` if the instance is not a place.
+        place: Option<String>,
     },
     /// A provided field name does not match the one in the structure type at the same position.
     StructureFieldExpected {
@@ -1334,6 +1410,8 @@ pub enum Error {
     MutatingWithDifferentType {
         /// The memory descriptor location, usually a variable name.
         location: Location,
+        /// The full path of the mutated memory place, e.g. `self.orders[i].amount`.
+        place: String,
         /// The stringified expected type.
         expected: String,
         /// The invalid actual type.
@@ -1345,15 +1423,44 @@ pub enum Error {
         location: Location,
         /// The name of the immutable variable.
         name: String,
+        /// The full path of the mutated memory place, e.g. `self.orders[i].amount`.
+        place: String,
         /// The location of the immutable variable. `None` for intrinsic items.
         reference: Option<Location>,
     },
+    /// Tried to read a variable, which may be unassigned at this point.
+    VariableUninitialized {
+        /// The variable usage location.
+        location: Location,
+        /// The variable name.
+        name: String,
+        /// The location of the declaration or the branch, which skips the assignment.
+        reference: Location,
+    },
     /// Tried to change an immutable contract storage field.
     MutatingImmutableContractField {
         /// The mutating expression location.
         location: Location,
         /// The name of the immutable contract storage field.
         name: String,
+        /// The full path of the mutated memory place, e.g. `self.owner.address`.
+        place: String,
+    },
+    /// The `unsafe_witness` value is not checked by any `require`.
+    UnsafeWitnessUnconstrained {
+        /// The `unsafe_witness` expression location.
+        location: Location,
+    },
+    /// Tried to change the memory declared outside the `unsafe_witness` expression from inside it.
+    UnsafeWitnessMutatingOuterMemory {
+        /// The memory descriptor location, usually a variable name.
+        location: Location,
+        /// The name of the outer variable.
+        name: String,
+        /// The full path of the mutated memory place, e.g. `self.orders[i].amount`.
+        place: String,
+        /// The location of the outer variable. `None` for implicit items.
+        reference: Option<Location>,
     },
 
     /// The path expression must point to a type, but points to another kind of item.
@@ -1390,6 +1497,17 @@ pub enum Error {
         /// The duplicate variant value.
         variant_value: BigInt,
     },
+    /// A `#[bitflags]` enumeration variant value is not a power of two.
+    TypeBitflagsVariantNotPowerOfTwo {
+        /// The invalid variant location.
+        location: Location,
+        /// The enumeration type name.
+        r#type: String,
+        /// The invalid variant name.
+        variant_name: String,
+        /// The invalid variant value.
+        variant_value: BigInt,
+    },
     /// The generic items are not supported by the type.
     TypeUnexpectedGenerics {
         /// The path expression location.
@@ -1417,6 +1535,20 @@ pub enum Error {
         /// The number of found generics.
         found: usize,
     },
+    /// The `_` type placeholder is used where it cannot be inferred.
+    TypePlaceholderForbidden {
+        /// The placeholder type location.
+        location: Location,
+    },
+    /// The `let` statement type annotation does not match the initializer type.
+    TypePlaceholderMismatch {
+        /// The mismatching annotation component location.
+        location: Location,
+        /// The description of the annotation component.
+        expected: String,
+        /// The stringified initializer component type.
+        found: String,
+    },
 
     /// The actual arguments number does not match the formal arguments number.
     FunctionArgumentCount {
@@ -1492,6 +1624,23 @@ pub enum Error {
         /// The reference to the return type location in the function prototype.
         reference: Location,
     },
+    /// The `return` statement value type does not match the one in the function prototype.
+    FunctionReturnStatementType {
+        /// The error location data.
+        location: Location,
+        /// The stringified type, which is expected.
+        expected: String,
+        /// The stringified invalid type.
+        found: String,
+        /// The reference to the return type location in the function prototype.
+        reference: Location,
+    },
+    /// The `return` statement is used outside a runtime function or unit test, or in a function,
+    /// which cannot return early, or inside an `unsafe_witness` expression.
+    FunctionReturnStatementForbidden {
+        /// The error location data.
+        location: Location,
+    },
     /// Calling a non-callable object, that is, not a function.
     FunctionNonCallable {
         /// The error location data.
@@ -1505,6 +1654,10 @@ pub enum Error {
         location: Location,
         /// The function identifier.
         function: String,
+        /// The root variable of the instance memory place, e.g. `orders` in `orders[1].apply()`.
+        binding: String,
+        /// The full path of the instance memory place, e.g. `orders[1]` in `orders[1].apply()`.
+        place: String,
     },
     /// Tried to call a function with the `!` specifier, but the function does not require it.
     FunctionUnexpectedExclamationMark {
@@ -1545,6 +1698,60 @@ pub enum Error {
         /// The stringified new length argument value.
         value: String,
     },
+    /// The integer bitlength is not a positive multiple of 8 within the integer bitlength range.
+    FunctionStdlibConvertBitlengthInvalid {
+        /// The error location data.
+        location: Location,
+        /// The stringified bitlength argument value.
+        value: String,
+    },
+    /// The number of packed bits does not match the unsigned integer type bitlength.
+    FunctionStdlibConvertBitsCountMismatch {
+        /// The error location data.
+        location: Location,
+        /// The number of bits.
+        bits: usize,
+        /// The stringified packed value type.
+        found: String,
+    },
+    /// The arrays being concatenated have different element types.
+    FunctionStdlibArrayConcatElementType {
+        /// The error location data.
+        location: Location,
+        /// The stringified first array element type.
+        expected: String,
+        /// The stringified second array element type.
+        found: String,
+        /// The location of the first array.
+        reference: Location,
+    },
+    /// The `cfg!` configuration value is neither passed to the compiler nor has a default.
+    FunctionCfgKeyUnknown {
+        /// The error location data.
+        location: Location,
+        /// The configuration value key.
+        key: String,
+    },
+    /// The `cfg!` configuration value cannot be converted to the default value type.
+    FunctionCfgValueInvalid {
+        /// The error location data.
+        location: Location,
+        /// The configuration value key.
+        key: String,
+        /// The configuration value passed to the compiler.
+        value: String,
+        /// The stringified default value type.
+        expected: String,
+    },
+    /// The `#[ensures(...)]` postcondition expression is not a boolean.
+    FunctionEnsuresExpectedBoolean {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The stringified invalid type.
+        found: String,
+    },
 
     /// The unit test function cannot be called.
     UnitTestCallForbidden {
@@ -1588,6 +1795,22 @@ pub enum Error {
         /// The function identifier.
         function: String,
     },
+    /// The `std::test` function is called outside a unit test.
+    UnitTestAssertionBeyondUnitTest {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+    },
+    /// Another unit test with the same fully qualified name is already declared.
+    UnitTestNameDuplicate {
+        /// The error location data.
+        location: Location,
+        /// The fully qualified unit test name, e.g. `exchange::math::overflow`.
+        name: String,
+        /// The location where the first unit test is declared.
+        reference: Location,
+    },
 
     /// The item is undeclared within the current scope stack.
     ScopeItemUndeclared {
@@ -1597,13 +1820,19 @@ pub enum Error {
         name: String,
     },
     /// The item is already declared within the current scope stack.
-    ScopeItemRedeclared {
+    ///
+    /// If either item is imported, the location of its `use` statement path is reported.
+    ItemRedeclared {
         /// The error location data.
         location: Location,
         /// The redeclared item name.
         name: String,
-        /// The location where the item is declared for the first item. `None` for intrinsic items.
+        /// The redeclared item kind, e.g. `constant` or `function`.
+        kind: String,
+        /// The location where the item is declared for the first time. `None` for intrinsic items.
         reference: Option<Location>,
+        /// The first declared item kind, e.g. `imported type`.
+        reference_kind: String,
     },
     /// The item is not a namespace, and cannot be a part of a path expression.
     ScopeExpectedNamespace {
@@ -1625,6 +1854,10 @@ pub enum Error {
     ScopeReferenceLoop {
         /// The error location data.
         location: Location,
+        /// The identifiers of the items forming the loop, where the first and last ones are equal.
+        cycle: Vec<String>,
+        /// Whether the loop has been unwound up to the item closing it.
+        is_closed: bool,
     },
 
     /// A non-constant element is found in a constant context.
@@ -1720,6 +1953,13 @@ pub enum Error {
         /// The first branch location, which helps user to find the error.
         reference: Location,
     },
+    /// A binding is an alternative of an or-pattern, e.g. `value | 42 => ...`.
+    MatchBranchOrPatternBinding {
+        /// The binding location.
+        location: Location,
+        /// The binding identifier.
+        name: String,
+    },
 
     /// The `while` condition is not of boolean type.
     ForStatementWhileExpectedBooleanCondition {
@@ -1736,6 +1976,19 @@ pub enum Error {
         found: String,
     },
 
+    /// The `while` loop has no `#[max_iterations(N)]` attribute, so it cannot be unrolled.
+    WhileStatementBoundMissing {
+        /// The `while` keyword location.
+        location: Location,
+    },
+    /// The `#[max_iterations(N)]` bound is not a constant non-negative integer.
+    WhileStatementBoundExpectedConstantInteger {
+        /// The bound expression location.
+        location: Location,
+        /// The stringified invalid bound element.
+        found: String,
+    },
+
     /// Only structure or enumeration types can have an implementation, but another type was found.
     ImplStatementExpectedStructureOrEnumeration {
         /// The invalid type location in the code.
@@ -1751,6 +2004,25 @@ pub enum Error {
         /// The stringified invalid element.
         found: String,
     },
+    /// The wildcard `use` statement path must point to a module.
+    UseStatementGlobExpectedModule {
+        /// The path location in the code.
+        location: Location,
+        /// The stringified non-module item.
+        found: String,
+    },
+    /// The item imported by the wildcard `use` statement has the name of an item already
+    /// declared in the scope.
+    UseStatementGlobConflict {
+        /// The wildcard `use` statement path location.
+        location: Location,
+        /// The conflicting item name.
+        name: String,
+        /// The location where the item is already declared. `None` for intrinsic items.
+        reference: Option<Location>,
+        /// The location where the imported item is declared.
+        imported: Option<Location>,
+    },
 
     /// The attribute is unknown. Check the known attribute list for more information.
     AttributeUnknown {
@@ -1795,6 +2067,13 @@ pub enum Error {
         /// The attribute name.
         name: String,
     },
+    /// The attribute expected a string literal.
+    AttributeExpectedStringLiteral {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+    },
     /// The attribute expected nested data.
     AttributeExpectedNested {
         /// The error location data.
@@ -1802,6 +2081,91 @@ pub enum Error {
         /// The attribute name.
         name: String,
     },
+    /// The attribute expected a parenthesized expression.
+    AttributeExpectedExpression {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+    },
+    /// The `max_depth` attribute value is not a positive integer.
+    AttributeMaxDepthInvalid {
+        /// The error location data.
+        location: Location,
+        /// The invalid stringified value.
+        found: String,
+    },
+    /// The attribute element value must not be negative.
+    AttributeNegativeValue {
+        /// The error location data.
+        location: Location,
+        /// The attribute element name.
+        name: String,
+    },
+    /// The attribute is not applicable to the item it is attached to.
+    AttributeUnexpected {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+        /// The stringified item kind, e.g. `function`.
+        item: String,
+    },
+    /// The `zinc(version = "...")` module attribute value is not a `major[.minor[.patch]]` version.
+    AttributeVersionInvalid {
+        /// The error location data.
+        location: Location,
+        /// The invalid version string.
+        found: String,
+    },
+    /// The language version declared with the `zinc(version = "...")` module attribute is newer
+    /// than the compiler one.
+    AttributeVersionUnsupported {
+        /// The error location data.
+        location: Location,
+        /// The declared language version.
+        found: String,
+        /// The compiler version.
+        supported: String,
+    },
+    /// The attribute element name is not among the expected ones.
+    AttributeElementUnknown {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+        /// The invalid element name.
+        found: String,
+        /// The expected element names.
+        expected: Vec<String>,
+    },
+    /// The attribute element is specified more than once.
+    AttributeElementDuplicate {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+        /// The duplicate element name.
+        element: String,
+    },
+    /// The attribute required elements are missing.
+    AttributeElementsMissing {
+        /// The error location data.
+        location: Location,
+        /// The attribute name.
+        name: String,
+        /// The missing element names.
+        missing: Vec<String>,
+    },
+    /// The attribute element value does not fit into its bitlength.
+    AttributeElementOverflow {
+        /// The error location data.
+        location: Location,
+        /// The attribute element name.
+        name: String,
+        /// The maximal allowed value bitlength.
+        bitlength: usize,
+    },
 
     /// The type must be explicitly specified for this binding.
     BindingTypeRequired {
@@ -1856,6 +2220,135 @@ pub enum Error {
         /// The location where the contract is declared.
         location: Location,
     },
+    /// The contract constructor `new` is not declared as public.
+    ContractConstructorNotPublic {
+        /// The location where the constructor is declared.
+        location: Location,
+    },
+    /// The contract constructor `new` has the `self` argument.
+    ContractConstructorSelfArgument {
+        /// The location where the constructor is declared.
+        location: Location,
+    },
+    /// The contract constructor `new` does not return the contract type.
+    ContractConstructorReturnType {
+        /// The location of the constructor return type.
+        location: Location,
+        /// The expected contract type.
+        expected: String,
+        /// The invalid return type found instead.
+        found: String,
+    },
+    /// The contract constructor `new` accesses the state beyond the contract being created.
+    ContractConstructorExternalState {
+        /// The location of the external state access.
+        location: Location,
+        /// The stringified external state item.
+        found: String,
+    },
+    /// The `#[upgrade]` method is declared beyond a contract.
+    ContractUpgradeBeyondContract {
+        /// The location where the method is declared.
+        location: Location,
+    },
+    /// The `#[upgrade]` method is not declared as public.
+    ContractUpgradeNotPublic {
+        /// The location where the method is declared.
+        location: Location,
+    },
+    /// The `#[upgrade]` method does not have exactly one structure argument.
+    ContractUpgradeArgument {
+        /// The location where the method is declared.
+        location: Location,
+        /// The stringified argument types found instead.
+        found: String,
+    },
+    /// The `#[upgrade]` method does not return the contract type.
+    ContractUpgradeReturnType {
+        /// The location of the method return type.
+        location: Location,
+        /// The expected contract type.
+        expected: String,
+        /// The invalid return type found instead.
+        found: String,
+    },
+    /// The contract has more than one `#[upgrade]` method.
+    ContractUpgradeDuplicate {
+        /// The location of the second method.
+        location: Location,
+        /// The location of the first method.
+        reference: Location,
+    },
+    /// The `#[require_sender]` or `#[only_owner]` attribute is used beyond a contract method.
+    ContractRequireSenderBeyondMethod {
+        /// The location of the attribute.
+        location: Location,
+    },
+    /// The `#[require_sender]` or `#[only_owner]` storage field is not declared in the contract.
+    ContractRequireSenderFieldNotFound {
+        /// The location of the attribute.
+        location: Location,
+        /// The undeclared storage field name.
+        field: String,
+    },
+    /// The `#[require_sender]` or `#[only_owner]` storage field is not an address.
+    ContractRequireSenderFieldType {
+        /// The location of the attribute.
+        location: Location,
+        /// The storage field name.
+        field: String,
+        /// The expected address type.
+        expected: String,
+        /// The invalid field type found instead.
+        found: String,
+    },
+    /// The method has more than one `#[require_sender]` or `#[only_owner]` attribute.
+    ContractRequireSenderDuplicate {
+        /// The location of the second attribute.
+        location: Location,
+        /// The location of the first attribute.
+        reference: Location,
+    },
+    /// The contract `implements` list item is not an interface.
+    ContractImplementsExpectedInterface {
+        /// The location of the `implements` list item.
+        location: Location,
+        /// The stringified item found instead.
+        found: String,
+    },
+    /// The contract does not declare a method required by an implemented interface.
+    ContractInterfaceMethodMissing {
+        /// The location of the `implements` list item.
+        location: Location,
+        /// The interface name.
+        interface: String,
+        /// The missing method name.
+        name: String,
+        /// The location of the interface method signature.
+        reference: Location,
+    },
+    /// The contract method signature differs from the implemented interface one.
+    ContractInterfaceMethodMismatch {
+        /// The location of the contract method.
+        location: Location,
+        /// The interface name.
+        interface: String,
+        /// The interface method signature.
+        expected: String,
+        /// The contract method signature found instead.
+        found: String,
+        /// The location of the interface method signature.
+        reference: Location,
+    },
+    /// The contract `#[invariant(...)]` condition is not a boolean expression.
+    ContractInvariantExpectedBoolean {
+        /// The location of the condition.
+        location: Location,
+        /// The contract identifier.
+        contract: String,
+        /// The stringified invalid type.
+        found: String,
+    },
     /// The source code file for module `name` cannot be found.
     ModuleFileNotFound {
         /// The location where the module is declared.
@@ -1869,7 +2362,7 @@ impl Error {
     ///
     /// Returns the semantic error code.
     ///
-    /// The last error code is `243` at `AttributeExpectedNested`.
+    /// The last error code is `279` at `FunctionEnsuresExpectedBoolean`.
     ///
     /// Do not remove nor uncomment the commented out errors, as they
     /// help to see error codes from the previous Zinc versions.
@@ -1881,6 +2374,23 @@ impl Error {
             Self::EntryPointConstant { .. } => 3,
             Self::FunctionMainBeyondEntry { .. } => 4,
             Self::ContractBeyondEntry { .. } => 5,
+            Self::ContractConstructorNotPublic { .. } => 244,
+            Self::ContractConstructorSelfArgument { .. } => 245,
+            Self::ContractConstructorReturnType { .. } => 246,
+            Self::ContractConstructorExternalState { .. } => 247,
+            Self::ContractUpgradeBeyondContract { .. } => 251,
+            Self::ContractUpgradeNotPublic { .. } => 252,
+            Self::ContractUpgradeArgument { .. } => 253,
+            Self::ContractUpgradeReturnType { .. } => 254,
+            Self::ContractUpgradeDuplicate { .. } => 255,
+            Self::ContractRequireSenderBeyondMethod { .. } => 272,
+            Self::ContractRequireSenderFieldNotFound { .. } => 273,
+            Self::ContractRequireSenderFieldType { .. } => 274,
+            Self::ContractRequireSenderDuplicate { .. } => 275,
+            Self::ContractImplementsExpectedInterface { .. } => 259,
+            Self::ContractInterfaceMethodMissing { .. } => 260,
+            Self::ContractInterfaceMethodMismatch { .. } => 261,
+            Self::ContractInvariantExpectedBoolean { .. } => 296,
             Self::ModuleFileNotFound { .. } => 6,
 
             Self::ExpressionNonConstantElement { .. } => 7,
@@ -1897,13 +2407,19 @@ impl Error {
             Self::MatchBranchPatternInvalidType { .. } => 16,
             Self::MatchBranchExpressionInvalidType { .. } => 17,
             Self::MatchBranchDuplicate { .. } => 18,
+            Self::MatchBranchOrPatternBinding { .. } => 299,
 
             Self::ForStatementWhileExpectedBooleanCondition { .. } => 19,
             Self::ForStatementBoundsExpectedConstantRangeExpression { .. } => 20,
 
+            Self::WhileStatementBoundMissing { .. } => 286,
+            Self::WhileStatementBoundExpectedConstantInteger { .. } => 287,
+
             Self::ImplStatementExpectedStructureOrEnumeration { .. } => 21,
 
             Self::UseStatementExpectedPath { .. } => 22,
+            Self::UseStatementGlobExpectedModule { .. } => 288,
+            Self::UseStatementGlobConflict { .. } => 289,
 
             Self::AttributeUnknown { .. } => 23,
             Self::AttributeEmpty { .. } => 239,
@@ -1911,6 +2427,17 @@ impl Error {
             Self::AttributeExpectedElement { .. } => 241,
             Self::AttributeExpectedIntegerLiteral { .. } => 242,
             Self::AttributeExpectedNested { .. } => 243,
+            Self::AttributeExpectedExpression { .. } => 278,
+            Self::AttributeMaxDepthInvalid { .. } => 248,
+            Self::AttributeNegativeValue { .. } => 265,
+            Self::AttributeUnexpected { .. } => 270,
+            Self::AttributeExpectedStringLiteral { .. } => 280,
+            Self::AttributeVersionInvalid { .. } => 281,
+            Self::AttributeVersionUnsupported { .. } => 282,
+            Self::AttributeElementUnknown { .. } => 290,
+            Self::AttributeElementDuplicate { .. } => 291,
+            Self::AttributeElementsMissing { .. } => 292,
+            Self::AttributeElementOverflow { .. } => 293,
 
             Self::BindingTypeRequired { .. } => 24,
             Self::BindingExpectedTuple { .. } => 25,
@@ -1918,7 +2445,7 @@ impl Error {
             Self::BindingFunctionArgumentDestructuringUnavailable { .. } => 27,
 
             Self::ScopeItemUndeclared { .. } => 28,
-            Self::ScopeItemRedeclared { .. } => 29,
+            Self::ItemRedeclared { .. } => 29,
             Self::ScopeExpectedNamespace { .. } => 30,
             Self::ScopeContractRedeclared { .. } => 31,
             Self::ScopeReferenceLoop { .. } => 32,
@@ -1926,14 +2453,20 @@ impl Error {
             Self::MutatingWithDifferentType { .. } => 33,
             Self::MutatingImmutableMemory { .. } => 34,
             Self::MutatingImmutableContractField { .. } => 35,
+            Self::VariableUninitialized { .. } => 256,
+            Self::UnsafeWitnessUnconstrained { .. } => 297,
+            Self::UnsafeWitnessMutatingOuterMemory { .. } => 298,
 
             Self::TypeAliasExpectedType { .. } => 36,
             Self::TypeInstantiationForbidden { .. } => 37,
             Self::TypeDuplicateField { .. } => 38,
             Self::TypeDuplicateVariantValue { .. } => 39,
+            Self::TypeBitflagsVariantNotPowerOfTwo { .. } => 269,
             Self::TypeUnexpectedGenerics { .. } => 40,
             Self::TypeExpectedGenerics { .. } => 41,
             Self::TypeInvalidGenericsNumber { .. } => 42,
+            Self::TypePlaceholderForbidden { .. } => 294,
+            Self::TypePlaceholderMismatch { .. } => 295,
 
             Self::FunctionArgumentCount { .. } => 43,
             Self::FunctionDebugArgumentCount { .. } => 44,
@@ -1941,6 +2474,8 @@ impl Error {
             Self::FunctionArgumentConstantness { .. } => 46,
             Self::FunctionArgumentNotEvaluable { .. } => 47,
             Self::FunctionReturnType { .. } => 48,
+            Self::FunctionReturnStatementType { .. } => 284,
+            Self::FunctionReturnStatementForbidden { .. } => 285,
             Self::FunctionNonCallable { .. } => 49,
             Self::FunctionCallMutableFromImmutable { .. } => 50,
             Self::FunctionUnexpectedExclamationMark { .. } => 51,
@@ -1948,6 +2483,12 @@ impl Error {
             Self::FunctionStdlibArrayTruncatingToBiggerSize { .. } => 53,
             Self::FunctionStdlibArrayPaddingToLesserSize { .. } => 54,
             Self::FunctionStdlibArrayNewLengthInvalid { .. } => 55,
+            Self::FunctionStdlibArrayConcatElementType { .. } => 249,
+            Self::FunctionStdlibConvertBitlengthInvalid { .. } => 263,
+            Self::FunctionStdlibConvertBitsCountMismatch { .. } => 264,
+            Self::FunctionCfgKeyUnknown { .. } => 276,
+            Self::FunctionCfgValueInvalid { .. } => 277,
+            Self::FunctionEnsuresExpectedBoolean { .. } => 279,
 
             Self::InvalidInteger {
                 inner: zinc_math::Error::NumberParsing(_),
@@ -1965,6 +2506,8 @@ impl Error {
                 inner: zinc_math::Error::ExponentTooSmall(_),
                 ..
             } => 59,
+            Self::InvalidIntegerSuffix { .. } => 267,
+            Self::IntegerSuffixTypesMismatch { .. } => 268,
 
             Self::OperatorAssignmentFirstOperandExpectedPlace { .. } => 60,
             Self::OperatorAssignmentSecondOperandExpectedEvaluable { .. } => 61,
@@ -2022,6 +2565,9 @@ impl Error {
             Self::OperatorNotEqualsSecondOperandExpectedBoolean { .. } => 113,
             Self::OperatorNotEqualsSecondOperandExpectedInteger { .. } => 114,
             Self::OperatorNotEqualsTypesMismatch { .. } => 115,
+            Self::OperatorEqualityOperandTooLarge { .. } => 257,
+            Self::OperatorOrderingUndefined { .. } => 258,
+            Self::OperatorOrderingFieldUnbounded { .. } => 271,
             Self::OperatorGreaterEqualsFirstOperandExpectedEvaluable { .. } => 116,
             Self::OperatorGreaterEqualsFirstOperandExpectedInteger { .. } => 117,
             Self::OperatorGreaterEqualsSecondOperandExpectedEvaluable { .. } => 118,
@@ -2069,6 +2615,7 @@ impl Error {
             Self::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { .. } => 160,
             Self::OperatorBitwiseSignedOperandForbidden { .. } => 161,
             Self::OperatorBitwiseFieldOperandForbidden { .. } => 162,
+            Self::OperatorNominalOperandForbidden { .. } => 250,
             Self::OperatorAdditionFirstOperandExpectedEvaluable { .. } => 163,
             Self::OperatorAdditionFirstOperandExpectedInteger { .. } => 164,
             Self::OperatorAdditionSecondOperandExpectedEvaluable { .. } => 165,
@@ -2114,6 +2661,7 @@ impl Error {
                 ..
             } => 200,
             Self::OperatorCastingOverflow { .. } => 201,
+            Self::OperatorCastingInvalidVariant { .. } => 266,
             Self::OperatorNotExpectedEvaluable { .. } => 202,
             Self::OperatorNotExpectedBoolean { .. } => 203,
             Self::OperatorBitwiseNotExpectedEvaluable { .. } => 204,
@@ -2155,6 +2703,40 @@ impl Error {
             Self::UnitTestConstantForbidden { .. } => 236,
             Self::UnitTestCannotHaveArguments { .. } => 237,
             Self::UnitTestCannotReturnValue { .. } => 238,
+            Self::UnitTestAssertionBeyondUnitTest { .. } => 262,
+            Self::UnitTestNameDuplicate { .. } => 283,
+        }
+    }
+
+    ///
+    /// Adds the item being defined to the reference loop, if the error is the one.
+    ///
+    /// The loop is unwound from the innermost item, which detected the loop, to the item, which
+    /// has been defined first. The latter is located at the error location and closes the loop.
+    ///
+    pub fn push_reference_loop_item(self, location: Option<Location>, identifier: &str) -> Self {
+        match self {
+            Self::ScopeReferenceLoop {
+                location: loop_location,
+                mut cycle,
+                is_closed: false,
+            } => {
+                cycle.insert(0, identifier.to_owned());
+
+                let is_closed = location.map_or(false, |location| {
+                    location == loop_location && location.file == loop_location.file
+                });
+                if is_closed {
+                    cycle.push(identifier.to_owned());
+                }
+
+                Self::ScopeReferenceLoop {
+                    location: loop_location,
+                    cycle,
+                    is_closed,
+                }
+            }
+            error => error,
         }
     }
 }
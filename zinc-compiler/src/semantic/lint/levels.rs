@@ -0,0 +1,74 @@
+//!
+//! The semantic analyzer lint levels.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use zinc_lexical::Location;
+
+use crate::semantic::lint::Lint;
+
+///
+/// The lint level index, where the lints allowed in each source code file are recorded.
+///
+/// The file unique ID is the key, since a module consists of a single file.
+///
+pub struct Levels {
+    /// The inner lint storage with the file unique ID as the key.
+    pub inner: RwLock<HashMap<usize, HashSet<Lint>>>,
+}
+
+lazy_static! {
+    ///
+    /// The global lint level index.
+    ///
+    pub static ref LEVELS: Levels = Levels::new();
+}
+
+impl Default for Levels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Levels {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(HashMap::new()),
+        }
+    }
+
+    ///
+    /// Silences the `lint` warnings in the `file`.
+    ///
+    pub fn allow(&self, file: usize, lint: Lint) {
+        log::debug!("Lint `{}` is allowed in file ID {:06}", lint, file);
+
+        self.inner
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .entry(file)
+            .or_default()
+            .insert(lint);
+    }
+
+    ///
+    /// Checks if the `lint` warning at `location` must be reported.
+    ///
+    pub fn is_enabled(&self, lint: Lint, location: Location) -> bool {
+        !self
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .get(&location.file)
+            .map(|lints| lints.contains(&lint))
+            .unwrap_or_default()
+    }
+}
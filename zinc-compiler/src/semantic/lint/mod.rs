@@ -0,0 +1,55 @@
+//!
+//! The semantic analyzer lint.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod levels;
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The compiler warning, which can be silenced for a module with the `#![allow(...)]` attribute.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A deprecated standard library function is called.
+    Deprecated,
+    /// The `found` flag of `MTreeMap::get` is discarded.
+    DiscardedFoundFlag,
+    /// The `std::array` search function falls back to the linear scan over a runtime array.
+    LinearSearch,
+    /// A variable shadows a constant or an imported item.
+    ShadowedItem,
+    /// The entry input argument never constrains the application.
+    UnconstrainedInput,
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "deprecated" => Ok(Self::Deprecated),
+            "discarded_found_flag" => Ok(Self::DiscardedFoundFlag),
+            "linear_search" => Ok(Self::LinearSearch),
+            "shadowed_item" => Ok(Self::ShadowedItem),
+            "unconstrained_input" => Ok(Self::UnconstrainedInput),
+            value => Err(value.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Deprecated => write!(f, "deprecated"),
+            Self::DiscardedFoundFlag => write!(f, "discarded_found_flag"),
+            Self::LinearSearch => write!(f, "linear_search"),
+            Self::ShadowedItem => write!(f, "shadowed_item"),
+            Self::UnconstrainedInput => write!(f, "unconstrained_input"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer lint tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::semantic::lint::levels::Levels;
+use crate::semantic::lint::Lint;
+
+#[test]
+fn ok_from_str() {
+    for lint in [
+        Lint::Deprecated,
+        Lint::DiscardedFoundFlag,
+        Lint::LinearSearch,
+        Lint::ShadowedItem,
+        Lint::UnconstrainedInput,
+    ]
+    .iter()
+    {
+        assert_eq!(lint.to_string().parse::<Lint>(), Ok(*lint));
+    }
+}
+
+#[test]
+fn ok_allowed_in_file() {
+    let levels = Levels::new();
+    levels.allow(1, Lint::Deprecated);
+
+    let location = Location {
+        line: 1,
+        column: 1,
+        file: 1,
+    };
+    assert!(!levels.is_enabled(Lint::Deprecated, location));
+    assert!(levels.is_enabled(Lint::LinearSearch, location));
+    assert!(levels.is_enabled(
+        Lint::Deprecated,
+        Location {
+            file: 2,
+            ..location
+        }
+    ));
+}
+
+#[test]
+fn error_unknown() {
+    assert_eq!("dead_code".parse::<Lint>(), Err("dead_code".to_owned()));
+}
@@ -10,4 +10,5 @@ pub mod binding;
 pub mod casting;
 pub mod element;
 pub mod error;
+pub mod lint;
 pub mod scope;
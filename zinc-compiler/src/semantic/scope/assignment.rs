@@ -0,0 +1,100 @@
+//!
+//! The semantic analyzer definite assignment tracker.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::semantic::scope::item::Item;
+use crate::semantic::scope::Scope;
+
+///
+/// The definite assignment tracker.
+///
+/// Takes a snapshot of the variables, which are not assigned before a branching construction,
+/// that is, a conditional, a `match`, or a loop. Each branch is analyzed with the same initial
+/// state, and a variable becomes assigned only if it is assigned in all the branches.
+///
+pub struct Tracker {
+    /// The tracked variables with their unassignment locations before the branching.
+    variables: Vec<(Rc<RefCell<Item>>, Location)>,
+    /// The assigned variables flags and the location of each analyzed branch.
+    branches: Vec<(Vec<bool>, Location)>,
+}
+
+impl Tracker {
+    ///
+    /// Takes the snapshot of the variables, which may be unassigned in the `scope`.
+    ///
+    pub fn new(scope: Rc<RefCell<Scope>>) -> Self {
+        let variables = Scope::get_uninitialized_variables(scope)
+            .into_iter()
+            .filter_map(|item| {
+                let location = match *RefCell::borrow(&item) {
+                    Item::Variable(ref variable) => variable.uninitialized,
+                    _ => None,
+                };
+                location.map(|location| (item, location))
+            })
+            .collect();
+
+        Self {
+            variables,
+            branches: Vec::new(),
+        }
+    }
+
+    ///
+    /// Records the variables assigned in the branch at `location`, which has been just analyzed,
+    /// and restores the initial state for the next branch.
+    ///
+    pub fn branch(&mut self, location: Location) {
+        let mut assigned = Vec::with_capacity(self.variables.len());
+        for (item, initial) in self.variables.iter() {
+            if let Item::Variable(ref mut variable) = *item.borrow_mut() {
+                assigned.push(variable.uninitialized.is_none());
+                variable.uninitialized = Some(*initial);
+            }
+        }
+
+        self.branches.push((assigned, location));
+    }
+
+    ///
+    /// Records the branch at `location`, which may be taken without analyzing any code, e.g. the
+    /// implicit `else` branch or a loop with no iterations.
+    ///
+    pub fn skip(&mut self, location: Location) {
+        self.branches
+            .push((vec![false; self.variables.len()], location));
+    }
+
+    ///
+    /// Merges the recorded branches.
+    ///
+    /// A variable assigned in all the branches becomes definitely assigned. A variable assigned
+    /// only in some of them is marked as unassigned by the first branch, which skips the assignment.
+    ///
+    pub fn finish(self) {
+        for (index, (item, initial)) in self.variables.into_iter().enumerate() {
+            let skipping: Vec<Location> = self
+                .branches
+                .iter()
+                .filter(|(assigned, _location)| !assigned[index])
+                .map(|(_assigned, location)| *location)
+                .collect();
+
+            let uninitialized = if skipping.len() == self.branches.len() {
+                Some(initial)
+            } else {
+                skipping.first().copied()
+            };
+
+            if let Item::Variable(ref mut variable) = *item.borrow_mut() {
+                variable.uninitialized = uninitialized;
+            }
+        }
+    }
+}
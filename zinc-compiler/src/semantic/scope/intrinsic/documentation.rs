@@ -0,0 +1,79 @@
+//!
+//! The semantic analyzer scope intrinsic function documentation.
+//!
+
+use crate::semantic::element::r#type::function::intrinsic::stdlib::deprecation::Deprecation;
+
+///
+/// The intrinsic function documentation, which is provided along with its scope registration.
+///
+/// The argument and return types are written in the documentation notation, where `T`, `N`,
+/// and other uppercase letters are the generic parameters validated by the function itself.
+///
+#[derive(Debug, Clone)]
+pub struct Documentation {
+    /// The one-line function description.
+    pub description: &'static str,
+    /// The argument names and types.
+    pub arguments: Vec<(&'static str, &'static str)>,
+    /// The function return type.
+    pub return_type: &'static str,
+}
+
+impl Documentation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        description: &'static str,
+        arguments: &[(&'static str, &'static str)],
+        return_type: &'static str,
+    ) -> Self {
+        Self {
+            description,
+            arguments: arguments.to_vec(),
+            return_type,
+        }
+    }
+
+    ///
+    /// Converts the documentation into the JSON target information entry.
+    ///
+    /// The `deprecation` is set for the deprecated aliases.
+    ///
+    pub fn to_json(
+        &self,
+        path: String,
+        is_constant: bool,
+        is_mutable: bool,
+        deprecation: Option<Deprecation>,
+    ) -> serde_json::Value {
+        let arguments: Vec<serde_json::Value> = self
+            .arguments
+            .iter()
+            .map(|(name, r#type)| {
+                serde_json::json!({
+                    "name": name,
+                    "type": r#type,
+                })
+            })
+            .collect();
+
+        let deprecated = deprecation.map(|deprecation| {
+            serde_json::json!({
+                "since": deprecation.since,
+                "replacement": deprecation.replacement,
+            })
+        });
+
+        serde_json::json!({
+            "path": path,
+            "arguments": arguments,
+            "return_type": self.return_type,
+            "is_constant": is_constant,
+            "is_mutable": is_mutable,
+            "description": self.description,
+            "deprecated": deprecated,
+        })
+    }
+}
@@ -0,0 +1,801 @@
+//!
+//! The semantic analyzer scope intrinsic items.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod documentation;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::r#type::function::intrinsic::stdlib::deprecation::Deprecation;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::nominal::Nominal;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::item::module::Module as ScopeModuleItem;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::variable::Variable as ScopeVariableItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+use self::documentation::Documentation;
+
+///
+/// An intrinsic items set instance creator.
+///
+/// The intrinsic items are functions `dbg!`, `cfg!` and `require` and the `std` and `zksync`
+/// libraries.
+///
+#[derive(Debug)]
+pub struct IntrinsicScope {}
+
+///
+/// The intrinsic structures and nominal types IDs.
+///
+pub enum IntrinsicTypeId {
+    /// The `std::crypto::ecc::Point` structure type ID.
+    StdCryptoEccPoint = 0,
+    /// The `std::crypto::schnorr::Signature` structure type ID.
+    StdCryptoSchnorrSignature = 1,
+    /// The `zksync::Transaction` structure type ID.
+    ZkSyncTransaction = 2,
+    /// The `std::collections::MTreeMap` structure type ID.
+    StdCollectionsMTreeMap = 3,
+    /// The `zksync::Address` nominal type ID.
+    ZkSyncAddress = 4,
+    /// The `zksync::Balance` nominal type ID.
+    ZkSyncBalance = 5,
+    /// The `#[bitflags]` enumeration flag set nominal type ID.
+    EnumerationFlags = 6,
+}
+
+impl IntrinsicScope {
+    ///
+    /// Initializes the intrinsic module scope.
+    ///
+    pub fn initialize() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("intrinsic").wrap();
+
+        let function_dbg = FunctionType::dbg();
+        Self::insert_function(
+            scope.clone(),
+            function_dbg,
+            Documentation::new("Prints the formatted values, substituting the `{}` placeholders in the format string.", &[("format", "str"), ("values", "...")], "()"),
+        );
+
+        let function_cfg = FunctionType::cfg();
+        Self::insert_function(
+            scope.clone(),
+            function_cfg,
+            Documentation::new(
+                "Returns the `--cfg key=value` compiler configuration value or the optional default one at compile time.",
+                &[("key", "str"), ("default", "T")],
+                "T",
+            ),
+        );
+
+        let function_require = FunctionType::require();
+        Self::insert_function(
+            scope.clone(),
+            function_require,
+            Documentation::new(
+                "Fails the execution with the optional message if the condition is false.",
+                &[("condition", "bool"), ("message", "str")],
+                "()",
+            ),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            "std".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "std".to_owned(),
+                Self::module_std(),
+            ))
+            .wrap(),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            "zksync".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "zksync".to_owned(),
+                Self::module_zksync(),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Returns the JSON description of the registered intrinsic and standard library functions.
+    ///
+    /// The functions are sorted by their module paths.
+    ///
+    pub fn target_info() -> serde_json::Value {
+        let mut functions = Vec::new();
+        Self::describe_scope(&Self::initialize().borrow(), None, &mut functions);
+        functions.sort_by(|(path_1, _), (path_2, _)| path_1.cmp(path_2));
+
+        serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "functions": functions
+                .into_iter()
+                .map(|(_path, function)| function)
+                .collect::<Vec<serde_json::Value>>(),
+        })
+    }
+
+    ///
+    /// Registers the intrinsic `function` in the `scope` along with its `documentation`.
+    ///
+    fn insert_function(
+        scope: Rc<RefCell<Scope>>,
+        function: FunctionType,
+        documentation: Documentation,
+    ) {
+        Scope::insert_item(
+            scope,
+            function.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in_documented(
+                Type::Function(function),
+                documentation,
+            ))
+            .wrap(),
+        );
+    }
+
+    ///
+    /// Collects the documented functions of the `scope` and its modules into `functions`.
+    ///
+    /// The structure scopes are only visited if they belong to the structure, e.g. the
+    /// `MTreeMap` methods scope, since some intrinsic structures share the module scope.
+    ///
+    fn describe_scope(
+        scope: &Scope,
+        path: Option<String>,
+        functions: &mut Vec<(String, serde_json::Value)>,
+    ) {
+        for (name, item) in scope.items.borrow().iter() {
+            let path = match path {
+                Some(ref path) => format!("{}::{}", path, name),
+                None => name.to_owned(),
+            };
+
+            match *item.borrow() {
+                ScopeItem::Module(ref module) => {
+                    let scope = module
+                        .scope()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                    Self::describe_scope(&scope.borrow(), Some(path), functions);
+                }
+                ScopeItem::Type(ref r#type) => match r#type.define() {
+                    Ok(Type::Function(FunctionType::Intrinsic(function))) => {
+                        if let Some(ref documentation) = r#type.documentation {
+                            let json = documentation.to_json(
+                                path.clone(),
+                                function.is_constant_evaluable(),
+                                function.is_mutable(),
+                                function.deprecation(),
+                            );
+                            functions.push((path, json));
+                        }
+                    }
+                    Ok(Type::Structure(structure))
+                        if structure.scope.borrow().name() == structure.identifier =>
+                    {
+                        Self::describe_scope(&structure.scope.borrow(), Some(path), functions);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Initializes the `std` module scope.
+    ///
+    fn module_std() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("std").wrap();
+
+        Scope::insert_item(
+            scope.clone(),
+            "crypto".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "crypto".to_owned(),
+                Self::module_crypto(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "convert".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "convert".to_owned(),
+                Self::module_convert(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "array".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "array".to_owned(),
+                Self::module_array(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "ff".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "ff".to_owned(),
+                Self::module_ff(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "collections".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "collections".to_owned(),
+                Self::module_collections(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "test".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "test".to_owned(),
+                Self::module_test(),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::crypto` module scope.
+    ///
+    fn module_crypto() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("crypto").wrap();
+
+        let sha256 = FunctionType::library(LibraryFunctionIdentifier::CryptoSha256);
+        let pedersen = FunctionType::library(LibraryFunctionIdentifier::CryptoPedersen);
+        let verify_proof = FunctionType::library(LibraryFunctionIdentifier::CryptoVerifyProof);
+
+        let schnorr_scope = Scope::new_intrinsic("schnorr").wrap();
+        let schnorr_signature_scope = Scope::new_intrinsic("Signature").wrap();
+        let schnorr_verify =
+            FunctionType::library(LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify);
+        Self::insert_function(
+            schnorr_signature_scope.clone(),
+            schnorr_verify,
+            Documentation::new(
+                "Verifies the Schnorr signature of the message bits.",
+                &[
+                    ("signature", "std::crypto::schnorr::Signature"),
+                    ("message", "[bool; N]"),
+                ],
+                "bool",
+            ),
+        );
+        let ecc_point = StructureType::new(
+            None,
+            "Point".to_owned(),
+            IntrinsicTypeId::StdCryptoEccPoint as usize,
+            vec![
+                ("x".to_owned(), Type::field(None)),
+                ("y".to_owned(), Type::field(None)),
+            ],
+            None,
+            None,
+            schnorr_scope.clone(),
+        );
+        let schnorr_signature = StructureType::new(
+            None,
+            schnorr_signature_scope.borrow().name(),
+            IntrinsicTypeId::StdCryptoSchnorrSignature as usize,
+            vec![
+                ("r".to_owned(), Type::Structure(ecc_point.clone())),
+                ("s".to_owned(), Type::field(None)),
+                ("pk".to_owned(), Type::Structure(ecc_point.clone())),
+            ],
+            None,
+            None,
+            schnorr_signature_scope.clone(),
+        );
+        Scope::insert_item(
+            schnorr_scope.clone(),
+            schnorr_signature_scope.borrow().name(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(
+                schnorr_signature,
+            )))
+            .wrap(),
+        );
+
+        let ecc_scope = Scope::new_intrinsic("ecc").wrap();
+        Scope::insert_item(
+            ecc_scope.clone(),
+            ecc_point.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(ecc_point))).wrap(),
+        );
+
+        Self::insert_function(
+            scope.clone(),
+            sha256,
+            Documentation::new(
+                "Computes the SHA-256 hash of the preimage bits.",
+                &[("preimage", "[bool; N]")],
+                "[bool; 256]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            pedersen,
+            Documentation::new(
+                "Computes the Pedersen hash of the preimage bits as an elliptic curve point.",
+                &[("preimage", "[bool; N]")],
+                "(field, field)",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            verify_proof,
+            Documentation::new(
+                "Verifies a recursive proof against the verifying key digest and public inputs.",
+                &[
+                    ("vk_digest", "[bool; 256]"),
+                    ("public_inputs", "[field; N]"),
+                    ("proof", "[field; 8]"),
+                ],
+                "bool",
+            ),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            ecc_scope.borrow().name(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                ecc_scope.borrow().name(),
+                ecc_scope.clone(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            schnorr_scope.borrow().name(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                schnorr_scope.borrow().name(),
+                schnorr_scope.clone(),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::convert` module scope.
+    ///
+    fn module_convert() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("convert").wrap();
+
+        let to_bits = FunctionType::library(LibraryFunctionIdentifier::ConvertToBits);
+        let from_bits_unsigned =
+            FunctionType::library(LibraryFunctionIdentifier::ConvertFromBitsUnsigned);
+        let from_bits_signed =
+            FunctionType::library(LibraryFunctionIdentifier::ConvertFromBitsSigned);
+        let from_bits_field =
+            FunctionType::library(LibraryFunctionIdentifier::ConvertFromBitsField);
+        let try_into_unsigned =
+            FunctionType::library(LibraryFunctionIdentifier::ConvertTryIntoUnsigned);
+        let try_cast = FunctionType::library(LibraryFunctionIdentifier::ConvertTryCast);
+        let pack_bits = FunctionType::library(LibraryFunctionIdentifier::ConvertPackBits);
+        let unpack_bits = FunctionType::library(LibraryFunctionIdentifier::ConvertUnpackBits);
+
+        Self::insert_function(
+            scope.clone(),
+            to_bits,
+            Documentation::new(
+                "Converts the value into its little-endian bit representation.",
+                &[("value", "T")],
+                "[bool; bitlength(T)]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            from_bits_unsigned,
+            Documentation::new(
+                "Converts the bits into an unsigned integer of the same bitlength.",
+                &[("bits", "[bool; N]")],
+                "u{N}",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            from_bits_signed,
+            Documentation::new(
+                "Converts the bits into a signed integer of the same bitlength.",
+                &[("bits", "[bool; N]")],
+                "i{N}",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            from_bits_field,
+            Documentation::new(
+                "Converts the bits into a field element.",
+                &[("bits", "[bool; 254]")],
+                "field",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            try_into_unsigned,
+            Documentation::new(
+                "Converts the field element into an unsigned integer, returning whether it fits.",
+                &[("value", "field"), ("bitlength", "N")],
+                "(bool, u{N})",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            try_cast,
+            Documentation::new(
+                "Converts the integer into another integer type, returning whether it fits.",
+                &[("value", "T"), ("is_signed", "bool"), ("bitlength", "N")],
+                "(bool, {i|u}{N})",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            pack_bits,
+            Documentation::new(
+                "Packs the bits into the smallest unsigned integer which can hold them.",
+                &[("bits", "[bool; N]")],
+                "u{M}",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            unpack_bits,
+            Documentation::new(
+                "Unpacks the given number of bits from the unsigned integer.",
+                &[("value", "u{M}"), ("bits", "N")],
+                "[bool; N]",
+            ),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::array` module scope.
+    ///
+    fn module_array() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("array").wrap();
+
+        let reverse = FunctionType::library(LibraryFunctionIdentifier::ArrayReverse);
+        let truncate = FunctionType::library(LibraryFunctionIdentifier::ArrayTruncate);
+        let pad = FunctionType::library(LibraryFunctionIdentifier::ArrayPad);
+        let concat = FunctionType::library(LibraryFunctionIdentifier::ArrayConcat);
+        let binary_search = FunctionType::library(LibraryFunctionIdentifier::ArrayBinarySearch);
+        let is_sorted = FunctionType::library(LibraryFunctionIdentifier::ArrayIsSorted);
+
+        Self::insert_function(
+            scope.clone(),
+            reverse,
+            Documentation::new(
+                "Returns the array with its elements in the reverse order.",
+                &[("array", "[T; N]")],
+                "[T; N]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            truncate,
+            Documentation::new(
+                "Truncates the array to the new constant length.",
+                &[("array", "[T; N]"), ("new_length", "M")],
+                "[T; M]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            pad,
+            Documentation::new(
+                "Pads the array to the new constant length with the fill value.",
+                &[
+                    ("array", "[T; N]"),
+                    ("new_length", "M"),
+                    ("fill_value", "T"),
+                ],
+                "[T; M]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            concat,
+            Documentation::new(
+                "Returns the concatenation of two arrays.",
+                &[("first", "[T; N]"), ("second", "[T; M]")],
+                "[T; N + M]",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            binary_search,
+            Documentation::new(
+                "Searches the sorted array, returning whether the needle is found and its index.",
+                &[("sorted", "[T; N]"), ("needle", "T")],
+                "(bool, u64)",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            is_sorted,
+            Documentation::new(
+                "Checks whether the array is sorted in the ascending order.",
+                &[("array", "[T; N]")],
+                "bool",
+            ),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::ff` module scope.
+    ///
+    fn module_ff() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("ff").wrap();
+
+        let invert = FunctionType::library(LibraryFunctionIdentifier::FfInvert);
+        let inverse = FunctionType::library_deprecated(Deprecation::FF_INVERSE);
+
+        Self::insert_function(
+            scope.clone(),
+            invert,
+            Documentation::new(
+                "Returns the multiplicative inverse of the field element.",
+                &[("value", "field")],
+                "field",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            inverse,
+            Documentation::new(
+                "Returns the multiplicative inverse of the field element. Deprecated, use `std::ff::invert` instead.",
+                &[("value", "field")],
+                "field",
+            ),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::test` module scope.
+    ///
+    fn module_test() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("test").wrap();
+
+        let assert_eq = FunctionType::assert_eq();
+        let assert_storage_unchanged = FunctionType::assert_storage_unchanged();
+        let rand_u = FunctionType::library(LibraryFunctionIdentifier::TestRandU);
+
+        Self::insert_function(
+            scope.clone(),
+            assert_eq,
+            Documentation::new(
+                "Fails the unit test with the optional message if the values are not equal.",
+                &[("actual", "T"), ("expected", "T"), ("message", "str")],
+                "()",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            assert_storage_unchanged,
+            Documentation::new(
+                "Fails the unit test if the contract storage has been changed.",
+                &[],
+                "()",
+            ),
+        );
+        Self::insert_function(
+            scope.clone(),
+            rand_u,
+            Documentation::new(
+                "Returns a pseudo-random integer, which is seeded by the unit test name and the offset.",
+                &[("bits", "N"), ("seed_offset", "u{M}")],
+                "u{N}",
+            ),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::collections` module scope.
+    ///
+    fn module_collections() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("collections").wrap();
+
+        let merkle_tree_map_scope = Scope::new_intrinsic("MTreeMap").wrap();
+        let merkle_tree_map = StructureType::new(
+            None,
+            "MTreeMap".to_owned(),
+            IntrinsicTypeId::StdCollectionsMTreeMap as usize,
+            vec![],
+            Some(vec!["K".to_owned(), "V".to_owned()]),
+            None,
+            merkle_tree_map_scope.clone(),
+        );
+        let merkle_tree_map_get =
+            FunctionType::library(LibraryFunctionIdentifier::CollectionsMTreeMapGet);
+        Self::insert_function(
+            merkle_tree_map_scope.clone(),
+            merkle_tree_map_get,
+            Documentation::new(
+                "Returns the value at the key and whether the key exists.",
+                &[("self", "MTreeMap<K, V>"), ("key", "K")],
+                "(V, bool)",
+            ),
+        );
+        let merkle_tree_map_get_or = FunctionType::mtreemap_get_or();
+        Self::insert_function(
+            merkle_tree_map_scope.clone(),
+            merkle_tree_map_get_or,
+            Documentation::new(
+                "Returns the value at the key, or the default value if the key does not exist.",
+                &[("self", "MTreeMap<K, V>"), ("key", "K"), ("default", "V")],
+                "V",
+            ),
+        );
+        let merkle_tree_map_contains =
+            FunctionType::library(LibraryFunctionIdentifier::CollectionsMTreeMapContains);
+        Self::insert_function(
+            merkle_tree_map_scope.clone(),
+            merkle_tree_map_contains,
+            Documentation::new(
+                "Checks whether the key exists.",
+                &[("self", "MTreeMap<K, V>"), ("key", "K")],
+                "bool",
+            ),
+        );
+        let merkle_tree_map_insert =
+            FunctionType::library(LibraryFunctionIdentifier::CollectionsMTreeMapInsert);
+        Self::insert_function(
+            merkle_tree_map_scope.clone(),
+            merkle_tree_map_insert,
+            Documentation::new("Inserts the value at the key, returning the previous value and whether it existed.", &[("self", "MTreeMap<K, V>"), ("key", "K"), ("value", "V")], "(V, bool)"),
+        );
+        let merkle_tree_map_insert_if_absent = FunctionType::mtreemap_insert_if_absent();
+        Self::insert_function(
+            merkle_tree_map_scope.clone(),
+            merkle_tree_map_insert_if_absent,
+            Documentation::new(
+                "Inserts the value at the key if the key does not exist, returning whether it has been inserted.",
+                &[("self", "MTreeMap<K, V>"), ("key", "K"), ("value", "V")],
+                "bool",
+            ),
+        );
+        let merkle_tree_map_remove =
+            FunctionType::library(LibraryFunctionIdentifier::CollectionsMTreeMapRemove);
+        Self::insert_function(
+            merkle_tree_map_scope,
+            merkle_tree_map_remove,
+            Documentation::new(
+                "Removes the key, returning the removed value and whether it existed.",
+                &[("self", "MTreeMap<K, V>"), ("key", "K")],
+                "(V, bool)",
+            ),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            merkle_tree_map.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(
+                merkle_tree_map,
+            )))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `zksync` module scope.
+    ///
+    fn module_zksync() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("zksync").wrap();
+
+        let transfer = FunctionType::library(LibraryFunctionIdentifier::ContractTransfer);
+
+        Self::insert_function(
+            scope.clone(),
+            transfer,
+            Documentation::new(
+                "Transfers the amount of the token from the sender to the recipient.",
+                &[
+                    ("sender", "u160"),
+                    ("recipient", "u160"),
+                    ("token_address", "u160"),
+                    ("amount", "u248"),
+                ],
+                "()",
+            ),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            Nominal::IDENTIFIER_ADDRESS.to_owned(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::address(None))).wrap(),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            Nominal::IDENTIFIER_BALANCE.to_owned(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::balance(None))).wrap(),
+        );
+
+        let transaction_type = StructureType::new(
+            None,
+            "Transaction".to_owned(),
+            IntrinsicTypeId::ZkSyncTransaction as usize,
+            vec![
+                (
+                    "sender".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS),
+                ),
+                (
+                    "recipient".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS),
+                ),
+                (
+                    "token_address".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS),
+                ),
+                (
+                    "amount".to_owned(),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BALANCE),
+                ),
+            ],
+            None,
+            None,
+            scope.clone(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            transaction_type.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(
+                transaction_type.clone(),
+            )))
+            .wrap(),
+        );
+
+        Scope::insert_item(
+            scope.clone(),
+            zinc_const::contract::TRANSACTION_VARIABLE_NAME.to_owned(),
+            ScopeItem::Variable(ScopeVariableItem::new(
+                None,
+                false,
+                zinc_const::contract::TRANSACTION_VARIABLE_NAME.to_owned(),
+                Type::Structure(transaction_type),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+}
@@ -0,0 +1,134 @@
+//!
+//! The scope intrinsic items tests.
+//!
+
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_contains::Function as MTreeMapContainsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get::Function as MTreeMapGetFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get_or::Function as MTreeMapGetOrFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert::Function as MTreeMapInsertFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert_if_absent::Function as MTreeMapInsertIfAbsentFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_remove::Function as MTreeMapRemoveFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ToBitsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as PedersenFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as Sha256Function;
+use crate::semantic::scope::intrinsic::IntrinsicScope;
+
+fn function(target_info: &serde_json::Value, path: &str) -> serde_json::Value {
+    target_info["functions"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .iter()
+        .find(|function| function["path"] == path)
+        .cloned()
+        .unwrap_or_else(|| panic!("the function `{}` is missing", path))
+}
+
+fn arity(function: &serde_json::Value) -> usize {
+    function["arguments"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .len()
+}
+
+#[test]
+fn ok_target_info_functions() {
+    let target_info = IntrinsicScope::target_info();
+
+    for (path, expected) in vec![
+        ("std::crypto::sha256", Sha256Function::ARGUMENT_COUNT),
+        ("std::crypto::pedersen", PedersenFunction::ARGUMENT_COUNT),
+        ("std::convert::to_bits", ToBitsFunction::ARGUMENT_COUNT),
+        (
+            "std::collections::MTreeMap::get",
+            MTreeMapGetFunction::ARGUMENT_COUNT,
+        ),
+        (
+            "std::collections::MTreeMap::contains",
+            MTreeMapContainsFunction::ARGUMENT_COUNT,
+        ),
+        (
+            "std::collections::MTreeMap::insert",
+            MTreeMapInsertFunction::ARGUMENT_COUNT,
+        ),
+        (
+            "std::collections::MTreeMap::remove",
+            MTreeMapRemoveFunction::ARGUMENT_COUNT,
+        ),
+        (
+            "std::collections::MTreeMap::get_or",
+            MTreeMapGetOrFunction::ARGUMENT_COUNT,
+        ),
+        (
+            "std::collections::MTreeMap::insert_if_absent",
+            MTreeMapInsertIfAbsentFunction::ARGUMENT_COUNT,
+        ),
+    ]
+    .into_iter()
+    {
+        let function = function(&target_info, path);
+        assert_eq!(arity(&function), expected, "`{}` arity mismatch", path);
+        assert!(
+            !function["description"]
+                .as_str()
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .is_empty(),
+            "`{}` has no description",
+            path
+        );
+    }
+}
+
+#[test]
+fn ok_target_info_properties() {
+    let target_info = IntrinsicScope::target_info();
+
+    let sha256 = function(&target_info, "std::crypto::sha256");
+    assert_eq!(sha256["arguments"][0]["name"], "preimage");
+    assert_eq!(sha256["return_type"], "[bool; 256]");
+    assert_eq!(sha256["is_constant"], false);
+
+    let concat = function(&target_info, "std::array::concat");
+    assert_eq!(concat["is_constant"], true);
+
+    let insert = function(&target_info, "std::collections::MTreeMap::insert");
+    assert_eq!(insert["is_mutable"], true);
+
+    let get_or = function(&target_info, "std::collections::MTreeMap::get_or");
+    assert_eq!(get_or["arguments"][2]["name"], "default");
+    assert_eq!(get_or["return_type"], "V");
+    assert_eq!(get_or["is_mutable"], false);
+
+    let insert_if_absent = function(&target_info, "std::collections::MTreeMap::insert_if_absent");
+    assert_eq!(insert_if_absent["return_type"], "bool");
+    assert_eq!(insert_if_absent["is_mutable"], true);
+
+    let cfg = function(&target_info, "cfg");
+    assert_eq!(cfg["arguments"][0]["name"], "key");
+    assert_eq!(cfg["is_constant"], true);
+
+    function(&target_info, "dbg");
+    function(&target_info, "zksync::transfer");
+
+    let invert = function(&target_info, "std::ff::invert");
+    assert!(invert["deprecated"].is_null());
+
+    let inverse = function(&target_info, "std::ff::inverse");
+    assert_eq!(inverse["deprecated"]["since"], 2);
+    assert_eq!(inverse["deprecated"]["replacement"], "std::ff::invert");
+}
+
+#[test]
+fn ok_target_info_sorted() {
+    let target_info = IntrinsicScope::target_info();
+
+    let paths: Vec<String> = target_info["functions"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .iter()
+        .map(|function| function["path"].to_string())
+        .collect();
+    let mut sorted = paths.clone();
+    sorted.sort();
+
+    assert_eq!(paths, sorted);
+}
@@ -78,7 +78,10 @@ impl Constant {
 
         match variant {
             Some(State::Declared { inner, scope }) => {
-                let defined = ConstStatementAnalyzer::define(scope, inner)?;
+                let identifier = inner.identifier.name.clone();
+                let defined = ConstStatementAnalyzer::define(scope, inner).map_err(|error| {
+                    error.push_reference_loop_item(Some(self.location), identifier.as_str())
+                })?;
                 self.state.replace(Some(State::Defined {
                     inner: defined.clone(),
                 }));
@@ -94,6 +97,8 @@ impl Constant {
             }
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location,
+                cycle: vec![],
+                is_closed: false,
             }),
         }
     }
@@ -132,6 +132,21 @@ impl Item {
         }
     }
 
+    ///
+    /// The item kind, which is used in the redeclaration and shadowing diagnostics.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Variable(_) => "variable",
+            Self::Field(_) => "field",
+            Self::Constant(_) => "constant",
+            Self::Variant(_) => "variant",
+            Self::Type(inner) if inner.is_function() => "function",
+            Self::Type(_) => "type",
+            Self::Module(_) => "module",
+        }
+    }
+
     ///
     /// Extracts the intermediate representation from the element.
     ///
@@ -19,6 +19,8 @@ use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
 use crate::source::Source;
+use crate::timings::phase::Phase as TimingsPhase;
+use crate::timings::TIMINGS;
 
 use self::state::State;
 
@@ -33,6 +35,8 @@ pub struct Module {
     pub item_id: usize,
     /// The module identifier.
     pub identifier: String,
+    /// The index of the module source file. `None` for intrinsic, dependency, and alias modules.
+    pub file: Option<usize>,
     /// The definition state, which is either `declared` or `defined`.
     pub state: RefCell<Option<State>>,
 }
@@ -81,6 +85,45 @@ impl Module {
         Ok(item)
     }
 
+    ///
+    /// Declares a test root module, which is a file from the project `tests/` directory, and
+    /// returns its scope.
+    ///
+    /// The module has its own scope, where the project entry `project_scope` is available by
+    /// the project name, along with the project `dependencies`.
+    ///
+    pub fn declare_test_root(
+        module: Source,
+        project: zinc_project::ManifestProject,
+        project_scope: Rc<RefCell<Scope>>,
+        mut dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        dependencies.insert(project.name, project_scope);
+
+        let scope =
+            Scope::new_module(module.name().to_owned(), dependencies.clone(), None, false).wrap();
+
+        let module = Self::new_declared(
+            None,
+            scope.clone(),
+            module.name().to_owned(),
+            module,
+            scope.clone(),
+            None,
+            dependencies,
+            false,
+        )?;
+        let item = ScopeItem::Module(module).wrap();
+
+        scope
+            .borrow()
+            .items
+            .borrow_mut()
+            .insert(Keyword::SelfLowercase.to_string(), item);
+
+        Ok(scope)
+    }
+
     ///
     /// Creates an declared module, which must be defined during the second pass or when
     /// the item is referenced for the first time.
@@ -100,10 +143,12 @@ impl Module {
     ) -> Result<Self, Error> {
         let item_id = ITEM_INDEX.next(format!("module {}", identifier));
 
+        let _timer = TIMINGS.start(TimingsPhase::Semantic, identifier.clone());
         let (module, modules) = match module {
             Source::File(file) => (file.tree, HashMap::new()),
             Source::Directory(directory) => (directory.entry.tree, directory.modules),
         };
+        let file = Some(module.file);
 
         let (module, implementation_scopes) = ModuleAnalyzer::declare(
             scope.clone(),
@@ -118,6 +163,7 @@ impl Module {
             location,
             item_id,
             identifier,
+            file,
             state: RefCell::new(Some(State::Declared {
                 scope,
                 module,
@@ -146,6 +192,7 @@ impl Module {
             location,
             item_id,
             identifier,
+            file: None,
             state: RefCell::new(Some(State::Defined { scope })),
         }
     }
@@ -160,6 +207,7 @@ impl Module {
             location: None,
             item_id,
             identifier,
+            file: None,
             state: RefCell::new(Some(State::Defined { scope })),
         }
     }
@@ -186,6 +234,7 @@ impl Module {
                     scope: scope.clone(),
                 }));
 
+                let _timer = TIMINGS.start(TimingsPhase::Semantic, self.identifier.clone());
                 let crate_item = Scope::get_module_self_alias(scope_crate);
                 let super_item = scope_super.map(Scope::get_module_self_alias);
 
@@ -208,6 +257,8 @@ impl Module {
             }
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                cycle: vec![self.identifier.to_owned()],
+                is_closed: true,
             }),
         }
     }
@@ -220,6 +271,8 @@ impl Module {
             Some(state) => Ok(state.scope()),
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                cycle: vec![self.identifier.to_owned()],
+                is_closed: true,
             }),
         }
     }
@@ -52,6 +52,14 @@ impl Index {
             "structure std::collections::MTreeMap".to_owned(),
             IntrinsicTypeId::StdCollectionsMTreeMap as usize,
         );
+        index.next_with_id(
+            "nominal zksync::Address".to_owned(),
+            IntrinsicTypeId::ZkSyncAddress as usize,
+        );
+        index.next_with_id(
+            "nominal zksync::Balance".to_owned(),
+            IntrinsicTypeId::ZkSyncBalance as usize,
+        );
         index
     }
 
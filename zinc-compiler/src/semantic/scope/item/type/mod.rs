@@ -15,12 +15,14 @@ use zinc_lexical::Location;
 
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::analyzer::statement::contract::Analyzer as ContractStatementAnalyzer;
+use crate::semantic::analyzer::statement::interface::Analyzer as InterfaceStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#enum::Analyzer as EnumStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#fn::Analyzer as FnStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#struct::Analyzer as StructStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#type::Analyzer as TypeStatementAnalyzer;
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::error::Error;
+use crate::semantic::scope::intrinsic::documentation::Documentation;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
@@ -39,6 +41,8 @@ pub struct Type {
     pub item_id: usize,
     /// The definition state, which is either `declared` or `defined`.
     pub state: RefCell<Option<State>>,
+    /// The intrinsic function documentation. `None` for non-intrinsic types.
+    pub documentation: Option<Documentation>,
 }
 
 impl Type {
@@ -90,6 +94,7 @@ impl Type {
             location,
             item_id,
             state: RefCell::new(Some(State::Declared { inner, scope })),
+            documentation: None,
         })
     }
 
@@ -122,6 +127,7 @@ impl Type {
                 inner,
                 intermediate,
             })),
+            documentation: None,
         }
     }
 
@@ -138,9 +144,19 @@ impl Type {
                 inner,
                 intermediate: None,
             })),
+            documentation: None,
         }
     }
 
+    ///
+    /// Useful method to declare an intrinsic function along with its `documentation`.
+    ///
+    pub fn new_built_in_documented(inner: TypeElement, documentation: Documentation) -> Self {
+        let mut item = Self::new_built_in(inner);
+        item.documentation = Some(documentation);
+        item
+    }
+
     ///
     /// Defines the declared type.
     ///
@@ -153,27 +169,36 @@ impl Type {
 
         match variant {
             Some(State::Declared { inner, scope }) => {
+                let identifier = inner.identifier().name.clone();
+
                 let (r#type, intermediate) = match inner {
                     TypeStatementVariant::Type(inner) => {
-                        (TypeStatementAnalyzer::define(scope, inner)?, None)
+                        TypeStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Struct(inner) => {
-                        (StructStatementAnalyzer::define(scope, inner)?, None)
+                        StructStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Enum(inner) => {
-                        (EnumStatementAnalyzer::define(scope, inner)?, None)
+                        EnumStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Fn(inner) => FnStatementAnalyzer::define(scope, inner)
                         .map(|(r#type, intermediate)| {
                             (r#type, intermediate.map(GeneratorStatement::Fn))
-                        })?,
+                        }),
                     TypeStatementVariant::Contract(inner) => ContractStatementAnalyzer::define(
                         scope, inner,
                     )
                     .map(|(r#type, intermediate)| {
                         (r#type, Some(GeneratorStatement::Contract(intermediate)))
-                    })?,
-                };
+                    }),
+                    TypeStatementVariant::Interface(inner) => {
+                        InterfaceStatementAnalyzer::define(scope, inner)
+                            .map(|r#type| (r#type, None))
+                    }
+                }
+                .map_err(|error| {
+                    error.push_reference_loop_item(self.location, identifier.as_str())
+                })?;
 
                 self.state.replace(Some(State::Defined {
                     inner: r#type.clone(),
@@ -195,6 +220,8 @@ impl Type {
             }
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                cycle: vec![],
+                is_closed: false,
             }),
         }
     }
@@ -215,6 +242,22 @@ impl Type {
         )
     }
 
+    ///
+    /// Checks whether the type is a function.
+    ///
+    pub fn is_function(&self) -> bool {
+        matches!(self.state.borrow().as_ref(),
+            Some(State::Declared {
+                inner: TypeStatementVariant::Fn(_),
+                ..
+            }) |
+            Some(State::Defined {
+                inner: TypeElement::Function(_),
+                ..
+            })
+        )
+    }
+
     ///
     /// Extracts the intermediate representation from the element.
     ///
@@ -24,6 +24,12 @@ pub struct Variable {
     pub identifier: String,
     /// The variable type.
     pub r#type: Type,
+    /// The location of the declaration or the branch, which leave the variable unassigned.
+    /// `None` if the variable is definitely assigned.
+    pub uninitialized: Option<Location>,
+    /// The location of the `unsafe_witness` expression, whose value is bound to the variable,
+    /// but is not checked by any `require` yet.
+    pub unconstrained: Option<Location>,
 }
 
 impl Variable {
@@ -44,8 +50,18 @@ impl Variable {
             is_mutable,
             identifier,
             r#type,
+            uninitialized: None,
+            unconstrained: None,
         }
     }
+
+    ///
+    /// Checks if the variable is the implicit `zksync::msg` transaction variable.
+    ///
+    pub fn is_transaction(&self) -> bool {
+        self.location.is_none()
+            && self.identifier.as_str() == zinc_const::contract::TRANSACTION_VARIABLE_NAME
+    }
 }
 
 impl fmt::Display for Variable {
@@ -5,6 +5,7 @@
 #[cfg(test)]
 mod tests;
 
+pub mod assignment;
 pub mod intrinsic;
 pub mod item;
 pub mod stack;
@@ -12,6 +13,7 @@ pub mod r#type;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::str;
 
@@ -24,8 +26,11 @@ use zinc_syntax::Identifier;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::function::constant::cache::Cache as ConstantFunctionCache;
 use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::error::Error;
+use crate::semantic::lint::levels::LEVELS as LINT_LEVELS;
+use crate::semantic::lint::Lint;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::source::Source;
 
@@ -57,6 +62,11 @@ pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     /// The hashmap with items declared at the current scope level, with item names as keys.
     items: RefCell<HashMap<String, Rc<RefCell<Item>>>>,
+    /// The names of the items imported by the wildcard `use` statements, which are not imported
+    /// by the wildcards from the current scope in turn.
+    glob_imports: RefCell<HashSet<String>>,
+    /// The names of the items imported by the `use` statements with their path locations.
+    imports: RefCell<HashMap<String, Location>>,
 }
 
 impl Scope {
@@ -75,6 +85,8 @@ impl Scope {
             r#type,
             parent,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            glob_imports: RefCell::new(HashSet::new()),
+            imports: RefCell::new(HashMap::new()),
         }
     }
 
@@ -102,6 +114,7 @@ impl Scope {
         } else {
             ScopeType::Module {
                 is_dependency: is_dependency_entry,
+                path: Vec::new(),
             }
         };
 
@@ -110,6 +123,8 @@ impl Scope {
             r#type,
             parent: Some(IntrinsicScope::initialize()),
             items: RefCell::new(items),
+            glob_imports: RefCell::new(HashSet::new()),
+            imports: RefCell::new(HashMap::new()),
         }
     }
 
@@ -122,6 +137,8 @@ impl Scope {
             r#type: ScopeType::Intrinsic,
             parent: None,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            glob_imports: RefCell::new(HashSet::new()),
+            imports: RefCell::new(HashMap::new()),
         }
     }
 
@@ -150,6 +167,18 @@ impl Scope {
         self.r#type.clone()
     }
 
+    ///
+    /// Returns the path of the module scope relative to the application entry.
+    ///
+    /// Is empty for the entry and test root modules, and for non-module scopes.
+    ///
+    pub fn module_path(&self) -> Vec<String> {
+        match self.r#type {
+            ScopeType::Module { ref path, .. } => path.to_owned(),
+            _ => Vec::new(),
+        }
+    }
+
     ///
     /// Returns the scope parent.
     ///
@@ -157,6 +186,73 @@ impl Scope {
         self.parent.to_owned()
     }
 
+    ///
+    /// Checks if the scope is the contract constructor body or a block nested into it.
+    ///
+    pub fn is_within_constructor(&self) -> bool {
+        match self.r#type {
+            ScopeType::Constructor => true,
+            ScopeType::Block
+            | ScopeType::Conditional
+            | ScopeType::Loop
+            | ScopeType::UnsafeWitness => match self.parent {
+                Some(ref parent) => parent.borrow().is_within_constructor(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the scope is a unit test function body or a block nested into it.
+    ///
+    pub fn is_within_unit_test(&self) -> bool {
+        match self.r#type {
+            ScopeType::UnitTest => true,
+            ScopeType::Block
+            | ScopeType::Conditional
+            | ScopeType::Loop
+            | ScopeType::UnsafeWitness => match self.parent {
+                Some(ref parent) => parent.borrow().is_within_unit_test(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the scope is an `unsafe_witness` expression block or a block nested into it.
+    ///
+    pub fn is_within_unsafe_witness(&self) -> bool {
+        match self.r#type {
+            ScopeType::UnsafeWitness => true,
+            ScopeType::Block | ScopeType::Conditional | ScopeType::Loop => match self.parent {
+                Some(ref parent) => parent.borrow().is_within_unsafe_witness(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the item with `identifier` is declared outside the enclosing `unsafe_witness`
+    /// expression block.
+    ///
+    pub fn is_beyond_unsafe_witness(&self, identifier: &Identifier) -> bool {
+        if self.items.borrow().contains_key(identifier.name.as_str()) {
+            return false;
+        }
+
+        match self.r#type {
+            ScopeType::UnsafeWitness => true,
+            ScopeType::Block | ScopeType::Conditional | ScopeType::Loop => match self.parent {
+                Some(ref parent) => parent.borrow().is_beyond_unsafe_witness(identifier),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
     ///
     /// Wraps the scope into `Rc<RefCell<_>>` simplifying most of initializations.
     ///
@@ -228,13 +324,13 @@ impl Scope {
         identifier: Identifier,
         item: Rc<RefCell<Item>>,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        let kind = RefCell::borrow(&item).kind();
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            true,
+            identifier.location,
+            kind,
+        )?;
 
         RefCell::borrow(&scope)
             .items
@@ -244,26 +340,171 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Defines an item imported by the `use` statement with the path at `location`, checks if
+    /// the item has been already declared.
+    ///
+    /// The path location is reported as the redeclaration one, and is referenced by the items
+    /// declared later with the same name.
+    ///
+    pub fn define_import(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        location: Location,
+        item: Rc<RefCell<Item>>,
+    ) -> Result<(), Error> {
+        let kind = format!("imported {}", RefCell::borrow(&item).kind());
+        RefCell::borrow(&scope).check_redeclaration(&identifier, true, location, kind.as_str())?;
+
+        RefCell::borrow(&scope)
+            .imports
+            .borrow_mut()
+            .insert(identifier.name.clone(), location);
+        RefCell::borrow(&scope)
+            .items
+            .borrow_mut()
+            .insert(identifier.name, item);
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the items imported from the scope by a wildcard `use` statement in the order they
+    /// have been declared.
+    ///
+    /// The aliases, the dependencies available in every module anyway, and the items imported
+    /// by other wildcards are skipped, so the wildcard imports are never transitive.
+    ///
+    pub fn glob_items(&self) -> Vec<(String, Rc<RefCell<Item>>)> {
+        let glob_imports = self.glob_imports.borrow();
+
+        let mut items: Vec<(String, Rc<RefCell<Item>>)> = self
+            .items
+            .borrow()
+            .iter()
+            .filter(|(name, item)| {
+                !Keyword::is_alias(name.as_str())
+                    && !glob_imports.contains(name.as_str())
+                    && RefCell::borrow(&item).location().is_some()
+            })
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+            .collect();
+        items.sort_by_key(|(_name, item)| RefCell::borrow(&item).item_id());
+
+        items
+    }
+
+    ///
+    /// Returns the items declared in the scope itself in the order they have been declared.
+    ///
+    /// The aliases, the imported items, and the items without location, that is, intrinsic
+    /// items and dependencies, are skipped.
+    ///
+    pub fn declared_items(&self) -> Vec<(String, Rc<RefCell<Item>>)> {
+        let imports = self.imports.borrow();
+
+        let mut items: Vec<(String, Rc<RefCell<Item>>)> = self
+            .items
+            .borrow()
+            .iter()
+            .filter(|(name, item)| {
+                !Keyword::is_alias(name.as_str())
+                    && !imports.contains_key(name.as_str())
+                    && RefCell::borrow(&item).location().is_some()
+            })
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+            .collect();
+        items.sort_by_key(|(_name, item)| RefCell::borrow(&item).item_id());
+
+        items
+    }
+
+    ///
+    /// Defines the `items` imported by the wildcard `use` statement with the path at `location`.
+    ///
+    /// An item already declared with the same name is an error, unless it is the very same
+    /// item, e.g. imported explicitly by another `use` statement.
+    ///
+    pub fn define_glob_items(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        items: Vec<(String, Rc<RefCell<Item>>)>,
+    ) -> Result<(), Error> {
+        for (name, item) in items.into_iter() {
+            let identifier = Identifier::new(location, name);
+
+            if let Some((existing, import)) =
+                RefCell::borrow(&scope).resolve_declaration(identifier.name.as_str(), true)
+            {
+                if RefCell::borrow(&existing).item_id() == RefCell::borrow(&item).item_id() {
+                    continue;
+                }
+
+                return Err(Error::UseStatementGlobConflict {
+                    location,
+                    name: identifier.name,
+                    reference: import.or_else(|| RefCell::borrow(&existing).location()),
+                    imported: RefCell::borrow(&item).location(),
+                });
+            }
+
+            RefCell::borrow(&scope)
+                .glob_imports
+                .borrow_mut()
+                .insert(identifier.name.clone());
+            RefCell::borrow(&scope)
+                .imports
+                .borrow_mut()
+                .insert(identifier.name.clone(), location);
+            RefCell::borrow(&scope)
+                .items
+                .borrow_mut()
+                .insert(identifier.name, item);
+        }
+
+        Ok(())
+    }
+
     ///
     /// Defines a variable, which is usually a `let` binding or a function actual parameter.
     ///
     /// If the variable is the object instance `self` alias, it is not checked for being redeclared
     /// recursively to avoid collision with the module `self` alias.
     ///
+    /// A variable may shadow a constant or an imported item, which is warned about unless the
+    /// `shadowed_item` lint is allowed. Other items may not be redeclared by variables.
+    ///
     pub fn define_variable(
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         is_mutable: bool,
         r#type: SemanticType,
     ) -> Result<(), Error> {
-        if let Ok(item) =
-            RefCell::borrow(&scope).resolve_item(&identifier, !identifier.is_self_lowercase())
+        if let Some((item, import)) = RefCell::borrow(&scope)
+            .resolve_declaration(identifier.name.as_str(), !identifier.is_self_lowercase())
         {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
+            let is_shadowable =
+                import.is_some() || matches!(*RefCell::borrow(&item), Item::Constant(_));
+            if !is_shadowable {
+                return Err(Self::redeclared(
+                    identifier.location,
+                    identifier.name,
+                    "variable",
+                    &item,
+                    import,
+                ));
+            }
+
+            if LINT_LEVELS.is_enabled(Lint::ShadowedItem, identifier.location) {
+                let (reference, reference_kind) = Self::reference(&item, import);
+                log::warn!(
+                    "{}: variable `{}` shadows the {} declared at {}",
+                    identifier.location,
+                    identifier.name,
+                    reference_kind,
+                    reference.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                );
+            }
         }
 
         let name = identifier.name.clone();
@@ -282,6 +523,122 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Defines a mutable variable declared without an initializer, which is assigned later.
+    ///
+    pub fn define_variable_uninitialized(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        r#type: SemanticType,
+    ) -> Result<(), Error> {
+        let location = identifier.location;
+        Self::define_variable(scope.clone(), identifier.clone(), true, r#type)?;
+
+        if let Item::Variable(ref mut variable) = *RefCell::borrow(&scope)
+            .resolve_item(&identifier, false)?
+            .borrow_mut()
+        {
+            variable.uninitialized = Some(location);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Marks the variable declared without an initializer as definitely assigned.
+    ///
+    pub fn assign_variable(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &Identifier,
+    ) -> Result<(), Error> {
+        if let Item::Variable(ref mut variable) = *RefCell::borrow(&scope)
+            .resolve_item(identifier, true)?
+            .borrow_mut()
+        {
+            variable.uninitialized = None;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Marks the variable bound to an `unsafe_witness` expression at `location`, which must be
+    /// checked by some `require` afterwards.
+    ///
+    pub fn define_variable_unconstrained(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &Identifier,
+        location: Location,
+    ) -> Result<(), Error> {
+        if let Item::Variable(ref mut variable) = *RefCell::borrow(&scope)
+            .resolve_item(identifier, false)?
+            .borrow_mut()
+        {
+            variable.unconstrained = Some(location);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Marks the variable as checked by some `require`.
+    ///
+    /// Undeclared items are ignored here, since they are reported by the `require` call analysis.
+    ///
+    pub fn constrain_variable(scope: Rc<RefCell<Scope>>, identifier: &Identifier) {
+        if let Ok(item) = RefCell::borrow(&scope).resolve_item(identifier, true) {
+            if let Item::Variable(ref mut variable) = *item.borrow_mut() {
+                variable.unconstrained = None;
+            }
+        }
+    }
+
+    ///
+    /// Returns the earliest location of the `unsafe_witness` expressions, whose values are bound
+    /// to the variables of the current scope, but have not been checked by any `require`.
+    ///
+    pub fn get_unconstrained_location(&self) -> Option<Location> {
+        self.items
+            .borrow()
+            .values()
+            .filter_map(|item| match item.try_borrow().as_deref() {
+                Ok(Item::Variable(variable)) => variable.unconstrained,
+                _ => None,
+            })
+            .min_by_key(|location| (location.line, location.column))
+    }
+
+    ///
+    /// Returns the variables visible from the current function block, which may be unassigned yet.
+    ///
+    pub fn get_uninitialized_variables(scope: Rc<RefCell<Scope>>) -> Vec<Rc<RefCell<Item>>> {
+        let mut variables = Vec::new();
+
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            let scope = RefCell::borrow(&scope);
+
+            for item in scope.items.borrow().values() {
+                match item.try_borrow().as_deref() {
+                    Ok(Item::Variable(variable)) if variable.uninitialized.is_some() => {
+                        variables.push(item.to_owned())
+                    }
+                    _ => {}
+                }
+            }
+
+            if let ScopeType::Block
+            | ScopeType::Conditional
+            | ScopeType::Loop
+            | ScopeType::UnsafeWitness = scope.r#type
+            {
+                current = scope.parent.to_owned();
+            }
+        }
+
+        variables
+    }
+
     ///
     /// Defines a contract field.
     ///
@@ -294,13 +651,12 @@ impl Scope {
         is_implicit: bool,
         is_immutable: bool,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, false) {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            false,
+            identifier.location,
+            "field",
+        )?;
 
         let name = identifier.name.clone();
         let item = Item::Field(FieldItem::new(
@@ -329,13 +685,12 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         statement: ConstStatement,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&statement.identifier, true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: statement.location,
-                name: statement.identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        RefCell::borrow(&scope).check_redeclaration(
+            &statement.identifier,
+            true,
+            statement.location,
+            "constant",
+        )?;
 
         let name = statement.identifier.name.clone();
         let item = Item::Constant(ConstantItem::new_declared(
@@ -360,13 +715,12 @@ impl Scope {
         identifier: Identifier,
         constant: Constant,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            true,
+            identifier.location,
+            "constant",
+        )?;
 
         let name = identifier.name;
         let item = Item::Constant(ConstantItem::new_defined(identifier.location, constant));
@@ -387,13 +741,12 @@ impl Scope {
         identifier: Identifier,
         constant: Constant,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, false) {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            false,
+            identifier.location,
+            "variant",
+        )?;
 
         let name = identifier.name;
         let item = Item::Variant(VariantItem::new(
@@ -418,13 +771,16 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         statement: TypeStatementVariant,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&statement.identifier(), true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: statement.location(),
-                name: statement.identifier().name.to_owned(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        let kind = match statement {
+            TypeStatementVariant::Fn(_) => "function",
+            _ => "type",
+        };
+        RefCell::borrow(&scope).check_redeclaration(
+            statement.identifier(),
+            true,
+            statement.location(),
+            kind,
+        )?;
 
         let name = statement.identifier().name.clone();
         let item = Item::Type(TypeItem::new_declared(
@@ -450,13 +806,16 @@ impl Scope {
         r#type: SemanticType,
         intermediate: Option<GeneratorStatement>,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: r#type.location().unwrap_or(identifier.location),
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        let kind = match r#type {
+            SemanticType::Function(_) => "function",
+            _ => "type",
+        };
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            true,
+            r#type.location().unwrap_or(identifier.location),
+            kind,
+        )?;
 
         let name = identifier.name;
         let item = Item::Type(TypeItem::new_defined(
@@ -502,17 +861,23 @@ impl Scope {
         scope_crate: Rc<RefCell<Scope>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> Result<(), Error> {
-        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&identifier, true) {
-            return Err(Error::ScopeItemRedeclared {
-                location: identifier.location,
-                name: identifier.name.clone(),
-                reference: RefCell::borrow(&item).location(),
-            });
-        }
+        RefCell::borrow(&scope).check_redeclaration(
+            &identifier,
+            true,
+            identifier.location,
+            "module",
+        )?;
 
         let name = identifier.name.clone();
-        let module_scope =
-            Self::new_module(identifier.name.clone(), dependencies.clone(), None, false).wrap();
+        let mut path = RefCell::borrow(&scope).module_path();
+        path.push(identifier.name.clone());
+        let mut module_scope =
+            Self::new_module(identifier.name.clone(), dependencies.clone(), None, false);
+        module_scope.r#type = ScopeType::Module {
+            is_dependency: false,
+            path,
+        };
+        let module_scope = module_scope.wrap();
         let module = ModuleItem::new_declared(
             Some(identifier.location),
             module_scope.clone(),
@@ -621,6 +986,7 @@ impl Scope {
             Some(item) => Ok(item.to_owned()),
             None => match self.parent {
                 Some(ref parent) if recursive => {
+                    ConstantFunctionCache::record_escape(self, identifier.name.as_str());
                     RefCell::borrow(&parent).resolve_item(identifier, recursive)
                 }
                 Some(_) | None => Err(Error::ScopeItemUndeclared {
@@ -631,6 +997,86 @@ impl Scope {
         }
     }
 
+    ///
+    /// Resolves the item declared with `name` like `resolve_item`, also returning the location
+    /// of the `use` statement path, if the item has been imported into the scope, where it is found.
+    ///
+    fn resolve_declaration(
+        &self,
+        name: &str,
+        recursive: bool,
+    ) -> Option<(Rc<RefCell<Item>>, Option<Location>)> {
+        match self.items.borrow().get(name) {
+            Some(item) => Some((item.to_owned(), self.imports.borrow().get(name).copied())),
+            None => match self.parent {
+                Some(ref parent) if recursive => {
+                    ConstantFunctionCache::record_escape(self, name);
+                    RefCell::borrow(&parent).resolve_declaration(name, recursive)
+                }
+                Some(_) | None => None,
+            },
+        }
+    }
+
+    ///
+    /// Checks that the `identifier` of the item of `kind` declared at `location` does not
+    /// collide with any item visible from the scope.
+    ///
+    fn check_redeclaration(
+        &self,
+        identifier: &Identifier,
+        recursive: bool,
+        location: Location,
+        kind: &str,
+    ) -> Result<(), Error> {
+        match self.resolve_declaration(identifier.name.as_str(), recursive) {
+            Some((item, import)) => Err(Self::redeclared(
+                location,
+                identifier.name.to_owned(),
+                kind,
+                &item,
+                import,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Creates the redeclaration error of the item `name` of `kind` declared at `location`,
+    /// which collides with the already declared `item`.
+    ///
+    fn redeclared(
+        location: Location,
+        name: String,
+        kind: &str,
+        item: &Rc<RefCell<Item>>,
+        import: Option<Location>,
+    ) -> Error {
+        let (reference, reference_kind) = Self::reference(item, import);
+
+        Error::ItemRedeclared {
+            location,
+            name,
+            kind: kind.to_owned(),
+            reference,
+            reference_kind,
+        }
+    }
+
+    ///
+    /// Returns the location and kind of the already declared `item`, which are referenced by the
+    /// redeclaration and shadowing diagnostics.
+    ///
+    /// The imported items are referenced by their `use` statement path `import` location.
+    ///
+    fn reference(item: &Rc<RefCell<Item>>, import: Option<Location>) -> (Option<Location>, String) {
+        let item = RefCell::borrow(item);
+        match import {
+            Some(import) => (Some(import), format!("imported {}", item.kind())),
+            None => (item.location(), item.kind().to_owned()),
+        }
+    }
+
     ///
     /// Resolves the `std::collections::MTreeMap` type.
     ///
@@ -726,6 +1172,27 @@ impl Scope {
             .collect()
     }
 
+    ///
+    /// Extracts the intermediate representation of the scope items, except the `excluded` one.
+    ///
+    /// Is used for the test root modules, which must not extract the project scope they
+    /// reference by the project name.
+    ///
+    pub fn get_intermediate_except(&self, excluded: &str) -> Vec<GeneratorStatement> {
+        self.items
+            .borrow()
+            .iter()
+            .filter_map(|(name, item)| {
+                if Keyword::is_alias(name.as_str()) || name.as_str() == excluded {
+                    return None;
+                }
+
+                Some(RefCell::borrow(item).get_intermediate())
+            })
+            .flatten()
+            .collect()
+    }
+
     ///
     /// Displays the scope hierarchy.
     ///
@@ -2,15 +2,11 @@
 //! The scope tests.
 //!
 
-use std::collections::HashMap;
-use std::path::PathBuf;
-
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
 use crate::error::Error;
 use crate::semantic::error::Error as SemanticError;
-use crate::source::Source;
 
 #[test]
 fn ok_current_scope() {
@@ -268,6 +264,88 @@ contract Test {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_variable_shadows_constant() {
+    let input = r#"
+const LIMIT: u8 = 42;
+
+fn main() -> u8 {
+    let LIMIT: u8 = 25;
+    LIMIT
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_variable_shadows_constant_inner_block() {
+    let input = r#"
+fn main() -> u8 {
+    const LIMIT: u8 = 42;
+    {
+        let LIMIT: u8 = 25;
+    };
+    LIMIT
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_variable_shadows_import() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    const C: u8 = 42;
+}
+
+use Data::C;
+
+fn main() -> u8 {
+    let C: u8 = 25;
+    C
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_variable_shadows_constant_contract_method() {
+    let input = r#"
+const LIMIT: u8 = 42;
+
+contract Test {
+    pub fn default(self) {
+        let LIMIT: u8 = 25;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_variable_shadows_constant_lint_allowed() {
+    let input = r#"
+#![allow(shadowed_item)]
+
+const LIMIT: u8 = 42;
+
+fn main() -> u8 {
+    let LIMIT: u8 = 25;
+    LIMIT
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_item_redeclared() {
     let input = r#"
@@ -277,10 +355,12 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
         location: Location::test(4, 9),
         name: "result".to_owned(),
+        kind: "variable".to_owned(),
         reference: Some(Location::test(3, 9)),
+        reference_kind: "variable".to_owned(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -300,10 +380,12 @@ fn main() -> X {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
         location: Location::test(4, 5),
         name: "X".to_owned(),
+        kind: "imported type".to_owned(),
         reference: Some(Location::test(2, 1)),
+        reference_kind: "type".to_owned(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -324,10 +406,141 @@ fn main() -> Y {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
-        location: Location::test(5, 10),
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(5, 5),
         name: "Y".to_owned(),
+        kind: "imported type".to_owned(),
         reference: Some(Location::test(3, 1)),
+        reference_kind: "type".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_constant_function() {
+    let input = r#"
+const A: u8 = 42;
+
+fn A() {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(4, 1),
+        name: "A".to_owned(),
+        kind: "function".to_owned(),
+        reference: Some(Location::test(2, 7)),
+        reference_kind: "constant".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_type_constant() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+const Data: u8 = 42;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(6, 1),
+        name: "Data".to_owned(),
+        kind: "constant".to_owned(),
+        reference: Some(Location::test(2, 1)),
+        reference_kind: "type".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_variable_function() {
+    let input = r#"
+fn value() -> u8 { 42 }
+
+fn main() {
+    let value = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(5, 9),
+        name: "value".to_owned(),
+        kind: "variable".to_owned(),
+        reference: Some(Location::test(2, 1)),
+        reference_kind: "function".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_variable_function_contract_method() {
+    let input = r#"
+fn helper() -> u8 { 42 }
+
+contract Test {
+    pub fn default(self) {
+        let helper = 25;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(6, 13),
+        name: "helper".to_owned(),
+        kind: "variable".to_owned(),
+        reference: Some(Location::test(2, 1)),
+        reference_kind: "function".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_redeclared_use_constant() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    const C: u8 = 42;
+}
+
+const C: u8 = 25;
+
+use Data::C;
+
+fn main() -> u8 {
+    C
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ItemRedeclared {
+        location: Location::test(12, 5),
+        name: "C".to_owned(),
+        kind: "imported constant".to_owned(),
+        reference: Some(Location::test(10, 7)),
+        reference_kind: "constant".to_owned(),
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -700,6 +913,11 @@ fn main() -> u8 { B }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 7),
+        cycle: vec!["A", "B", "A"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -720,6 +938,11 @@ fn main() -> u8 { D }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 7),
+        cycle: vec!["A", "B", "C", "D", "A"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -738,6 +961,11 @@ fn main() -> A {}
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["A", "B", "A"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -767,6 +995,11 @@ fn main() -> bool {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["Outer", "Inner", "InnerMost", "Outer"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -786,6 +1019,11 @@ fn main() {}
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["Array", "SIZE", "Array"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -812,6 +1050,75 @@ fn main() -> Array {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["Array", "SIZE", "Size", "Array"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_recursion_max_depth() {
+    let input = r#"
+#[max_depth(10)]
+fn fibonacci(n: u8) -> u8 {
+    if n < 2 { n } else { fibonacci(n - 1) + fibonacci(n - 2) }
+}
+
+fn main() -> u8 { fibonacci(10) }
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_reference_loop_function_recursive() {
+    let input = r#"
+fn fibonacci(n: u8) -> u8 {
+    if n < 2 { n } else { fibonacci(n - 1) + fibonacci(n - 2) }
+}
+
+fn main() -> u8 { fibonacci(10) }
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
+        location: Location::test(2, 1),
+        cycle: vec!["fibonacci", "fibonacci"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_loop_function_mutual_max_depth() {
+    let input = r#"
+#[max_depth(10)]
+fn first() -> u8 { second() }
+
+#[max_depth(10)]
+fn second() -> u8 { first() }
+
+fn main() -> u8 { first() }
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
+        location: Location::test(3, 1),
+        cycle: vec!["first", "second", "first"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -831,6 +1138,11 @@ fn main() -> u8 { first() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["first", "second", "first"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -854,6 +1166,11 @@ fn main() -> u8 { first() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["fourth", "first", "second", "third", "fourth"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -885,6 +1202,11 @@ fn main() -> Data {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(7, 5),
+        cycle: vec!["method", "another", "method"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -924,6 +1246,11 @@ fn main() -> Data {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(7, 5),
+        cycle: vec!["method", "another", "yet_another", "and_another", "method"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -949,6 +1276,11 @@ contract Data {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(5, 5),
+        cycle: vec!["method", "another", "method"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -982,6 +1314,11 @@ contract Data {
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(5, 5),
+        cycle: vec!["method", "another", "yet_another", "and_another", "method"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -1005,18 +1342,17 @@ fn main() -> u8 { call() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["call", "call", "call"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
-    let result = crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>(),
-    );
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ]);
 
     assert_eq!(result, expected);
 }
@@ -1049,42 +1385,19 @@ fn main() -> u8 { call() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(2, 1),
+        cycle: vec!["call", "call", "call", "call", "call"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
-    let result = crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "first".to_owned(),
-            Source::test(
-                first,
-                PathBuf::from("first.zn"),
-                vec![(
-                    "second".to_owned(),
-                    Source::test(
-                        second,
-                        PathBuf::from("first/second.zn"),
-                        vec![(
-                            "third".to_owned(),
-                            Source::test(
-                                third,
-                                PathBuf::from("first/second/third.zn"),
-                                HashMap::new(),
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID),
-                        )]
-                        .into_iter()
-                        .collect::<HashMap<String, Source>>(),
-                    )
-                    .expect(zinc_const::panic::TEST_DATA_VALID),
-                )]
-                .into_iter()
-                .collect::<HashMap<String, Source>>(),
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID),
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>(),
-    );
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("first/mod.zn", first),
+        ("first/second/mod.zn", second),
+        ("first/second/third.zn", third),
+    ]);
 
     assert_eq!(result, expected);
 }
@@ -1115,18 +1428,17 @@ fn main() -> u8 { Call { value: 42 }.call() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(5, 5),
+        cycle: vec!["call", "call", "call"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
-    let result = crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "other".to_owned(),
-            Source::test(other, PathBuf::from("other.zn"), HashMap::new())
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>(),
-    );
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("other.zn", other),
+    ]);
 
     assert_eq!(result, expected);
 }
@@ -1177,42 +1489,19 @@ fn main() -> u8 { Call { value: 42 }.call() }
 
     let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
         location: Location::test(7, 5),
+        cycle: vec!["call", "call", "call", "call", "call"]
+            .into_iter()
+            .map(|identifier| identifier.to_owned())
+            .collect(),
+        is_closed: true,
     }));
 
-    let result = crate::semantic::tests::compile_entry_with_modules(
-        entry,
-        vec![(
-            "first".to_owned(),
-            Source::test(
-                first,
-                PathBuf::from("first.zn"),
-                vec![(
-                    "second".to_owned(),
-                    Source::test(
-                        second,
-                        PathBuf::from("first/second.zn"),
-                        vec![(
-                            "third".to_owned(),
-                            Source::test(
-                                third,
-                                PathBuf::from("first/second/third.zn"),
-                                HashMap::new(),
-                            )
-                            .expect(zinc_const::panic::TEST_DATA_VALID),
-                        )]
-                        .into_iter()
-                        .collect::<HashMap<String, Source>>(),
-                    )
-                    .expect(zinc_const::panic::TEST_DATA_VALID),
-                )]
-                .into_iter()
-                .collect::<HashMap<String, Source>>(),
-            )
-            .expect(zinc_const::panic::TEST_DATA_VALID),
-        )]
-        .into_iter()
-        .collect::<HashMap<String, Source>>(),
-    );
+    let result = crate::semantic::tests::compile_entry_with_files(&[
+        ("main.zn", entry),
+        ("first/mod.zn", first),
+        ("first/second/mod.zn", second),
+        ("first/second/third.zn", third),
+    ]);
 
     assert_eq!(result, expected);
 }
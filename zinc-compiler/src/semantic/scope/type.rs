@@ -18,6 +18,9 @@ pub enum Type {
     Module {
         /// Whether the module is of an application dependency.
         is_dependency: bool,
+        /// The module path relative to the application entry, e.g. `["exchange", "math"]`.
+        /// Is empty for the test root modules.
+        path: Vec<String>,
     },
     /// The module with intrinsic items like the standard library functions.
     Intrinsic,
@@ -29,12 +32,18 @@ pub enum Type {
     Enumeration,
     /// The function block.
     Function,
+    /// The contract constructor function block.
+    Constructor,
+    /// The unit test function block.
+    UnitTest,
     /// The conditional block.
     Conditional,
     /// The for-loop block.
     Loop,
     /// The ordinar expression block.
     Block,
+    /// The `unsafe_witness` expression block.
+    UnsafeWitness,
 }
 
 impl Type {
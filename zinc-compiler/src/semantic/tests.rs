@@ -4,6 +4,7 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -15,6 +16,7 @@ use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
+use crate::source::provider::memory::Memory as MemoryProvider;
 use crate::source::Source;
 
 pub(crate) fn compile_entry(code: &str) -> Result<(), Error> {
@@ -38,6 +40,30 @@ pub(crate) fn compile_entry_with_modules(
     Ok(())
 }
 
+///
+/// Compiles the project consisting of the in-memory `files`, given as the paths relative to
+/// the source code directory and their code. The entry file must be called `main.zn`.
+///
+pub(crate) fn compile_entry_with_files(files: &[(&str, &str)]) -> Result<(), Error> {
+    let root = Path::new(zinc_const::directory::SOURCE);
+    let provider = files
+        .iter()
+        .fold(MemoryProvider::new(), |provider, (path, code)| {
+            provider.with_file(root.join(path), code)
+        });
+    let source =
+        Source::try_from_provider(&provider, root, true).expect(zinc_const::panic::TEST_DATA_VALID);
+    let project = zinc_project::ManifestProject::new(
+        "test".to_owned(),
+        zinc_project::ProjectType::Contract,
+        semver::Version::new(1, 0, 0),
+    );
+
+    EntryAnalyzer::define(source, project, HashMap::new(), false).map_err(Error::Semantic)?;
+
+    Ok(())
+}
+
 pub(crate) fn compile_module(
     code: &str,
     file: usize,
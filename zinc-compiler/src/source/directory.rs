@@ -4,7 +4,7 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
@@ -12,13 +12,19 @@ use anyhow::Context;
 
 use crate::error::Error as CompilerError;
 use crate::generator::module::Module;
+use crate::generator::statement::r#fn::role::Role as GeneratorFunctionRole;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::scope::Scope;
 use crate::source::error::Error;
 use crate::source::file::File;
+use crate::source::provider::disk::Disk as DiskProvider;
+use crate::source::provider::ISourceProvider;
 use crate::source::Source;
+use crate::timings::phase::Phase as TimingsPhase;
+use crate::timings::TIMINGS;
 
 ///
 /// The Zinc source code directory, which consists of its path, root module (usually `mod.zn`),
@@ -34,6 +40,9 @@ pub struct Directory {
     pub entry: File,
     /// The module dependencies.
     pub modules: HashMap<String, Source>,
+    /// The test root files from the project `tests/` directory, which are compiled as separate
+    /// modules using the project as a dependency.
+    pub test_roots: Vec<File>,
 }
 
 impl Directory {
@@ -87,6 +96,7 @@ impl Directory {
                 name,
                 entry,
                 modules,
+                test_roots: Vec::new(),
             }),
             None if is_entry => {
                 Err(Error::ProjectEntryNotFound).with_context(|| path.to_string_lossy().to_string())
@@ -101,7 +111,18 @@ impl Directory {
     /// Initializes an application module from a hard disk directory.
     ///
     pub fn try_from_path(path: &PathBuf, is_entry: bool) -> anyhow::Result<Self> {
-        let directory = fs::read_dir(path).with_context(|| path.to_string_lossy().to_string())?;
+        Self::try_from_provider(&DiskProvider::default(), path, is_entry)
+    }
+
+    ///
+    /// Initializes an application module from the `provider` directory.
+    ///
+    pub fn try_from_provider(
+        provider: &dyn ISourceProvider,
+        path: &Path,
+        is_entry: bool,
+    ) -> anyhow::Result<Self> {
+        let directory = provider.read_directory(path)?;
 
         let name = path
             .file_stem()
@@ -113,11 +134,8 @@ impl Directory {
         let mut entry = None;
         let mut modules = HashMap::new();
 
-        for directory_entry in directory.into_iter() {
-            let directory_entry =
-                directory_entry.with_context(|| path.to_string_lossy().to_string())?;
-            let path = directory_entry.path();
-            let module = Source::try_from_path(&path)?;
+        for path in directory.into_iter() {
+            let module = Source::try_from_provider(provider, &path, false)?;
             let name = module.name().to_owned();
 
             match module {
@@ -150,6 +168,7 @@ impl Directory {
                 name,
                 entry,
                 modules,
+                test_roots: Vec::new(),
             }),
             None if is_entry => {
                 Err(Error::ProjectEntryNotFound).with_context(|| path.to_string_lossy().to_string())
@@ -160,6 +179,18 @@ impl Directory {
         }
     }
 
+    ///
+    /// Loads the test root files at `paths`, whose unit tests are compiled along with the
+    /// directory ones.
+    ///
+    pub fn with_test_roots(mut self, paths: &[PathBuf]) -> anyhow::Result<Self> {
+        for path in paths.iter() {
+            self.test_roots.push(File::try_from_path(path)?);
+        }
+
+        Ok(self)
+    }
+
     ///
     /// Runs the semantic analyzer on the syntax tree and returns the module scope.
     ///
@@ -182,23 +213,65 @@ impl Directory {
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
+    /// The test root unit tests are named after their files, e.g. `tests/exchange.zn::overflow`.
+    /// The unit test names must be unique across the application and its test roots.
+    ///
     pub fn compile(
-        self,
+        mut self,
         manifest: zinc_project::Manifest,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> anyhow::Result<Rc<RefCell<ZincVMState>>> {
+        let test_roots = std::mem::take(&mut self.test_roots);
+
         let scope = EntryAnalyzer::define(
             Source::Directory(self),
             manifest.project.clone(),
-            dependencies,
+            dependencies.clone(),
             false,
         )
         .map_err(CompilerError::Semantic)
         .map_err(|error| error.format())
         .map_err(Error::Compiling)?;
 
+        let mut intermediate = scope.borrow().get_intermediate();
+        for test_root in test_roots.into_iter() {
+            let prefix = format!(
+                "{}{}.{}",
+                zinc_const::directory::TESTS,
+                test_root.name,
+                zinc_const::extension::SOURCE
+            );
+
+            let test_scope = EntryAnalyzer::define_test_root(
+                Source::File(test_root),
+                manifest.project.clone(),
+                scope.clone(),
+                dependencies.clone(),
+            )
+            .map_err(CompilerError::Semantic)
+            .map_err(|error| error.format())
+            .map_err(Error::Compiling)?;
+
+            let statements = test_scope
+                .borrow()
+                .get_intermediate_except(manifest.project.name.as_str());
+            for mut statement in statements.into_iter() {
+                if let GeneratorStatement::Fn(ref mut statement) = statement {
+                    if let GeneratorFunctionRole::UnitTest = statement.role {
+                        statement.identifier = format!("{}::{}", prefix, statement.identifier);
+                    }
+                }
+                intermediate.push(statement);
+            }
+        }
+        EntryAnalyzer::check_unit_test_names(intermediate.as_slice())
+            .map_err(CompilerError::Semantic)
+            .map_err(|error| error.format())
+            .map_err(Error::Compiling)?;
+
+        let _timer = TIMINGS.start(TimingsPhase::Generation, "bytecode".to_owned());
         let state = ZincVMState::new(manifest).wrap();
-        Module::new(scope.borrow().get_intermediate()).write_to_zinc_vm(state.clone());
+        Module::new(intermediate).write_to_zinc_vm(state.clone());
 
         Ok(state)
     }
@@ -216,6 +289,7 @@ impl Directory {
             name: "test".to_owned(),
             entry: File::test(code, path)?,
             modules,
+            test_roots: Vec::new(),
         })
     }
 }
@@ -12,6 +12,9 @@ pub enum Error {
     /// Failed to get the file type, that is, file or directory.
     #[error("file type is unknown")]
     FileTypeUnknown,
+    /// The file or directory does not exist in the source code provider.
+    #[error("file or directory not found")]
+    NotFound,
     /// The file has no extension.
     #[error("file extension not found")]
     ExtensionNotFound,
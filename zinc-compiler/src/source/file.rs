@@ -4,15 +4,18 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs;
-use std::io::Read;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 use anyhow::Context;
 
+use zinc_lexical::Location;
 use zinc_lexical::FILE_INDEX;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTreeNode;
 use zinc_syntax::Module as SyntaxModule;
+use zinc_syntax::ModuleLocalStatement;
 use zinc_syntax::Parser;
 
 use crate::error::Error as CompilerError;
@@ -22,7 +25,11 @@ use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::scope::Scope;
 use crate::source::error::Error;
+use crate::source::provider::disk::Disk as DiskProvider;
+use crate::source::provider::ISourceProvider;
 use crate::source::Source;
+use crate::timings::phase::Phase as TimingsPhase;
+use crate::timings::TIMINGS;
 
 ///
 /// The Zinc source code file, which consists of its path and parsed syntax tree.
@@ -46,6 +53,7 @@ impl File {
     pub fn try_from_string(file: zinc_project::File) -> anyhow::Result<Self> {
         let path = PathBuf::from(file.path);
 
+        let _timer = TIMINGS.start(TimingsPhase::Parsing, path.to_string_lossy().to_string());
         let next_file_id = FILE_INDEX.next(&path, file.code);
         let tree = Parser::default()
             .parse(
@@ -74,16 +82,17 @@ impl File {
     /// Initializes an application module from a hard disk file.
     ///
     pub fn try_from_path(path: &PathBuf) -> anyhow::Result<Self> {
-        let mut file = fs::File::open(&path).with_context(|| path.to_string_lossy().to_string())?;
-
-        let size = file
-            .metadata()
-            .with_context(|| path.to_string_lossy().to_string())?
-            .len() as usize;
+        Self::try_from_provider(&DiskProvider::default(), path)
+    }
 
-        let mut code = String::with_capacity(size);
-        file.read_to_string(&mut code)
-            .with_context(|| path.to_string_lossy().to_string())?;
+    ///
+    /// Initializes an application module from the `provider` file.
+    ///
+    /// The `path` is written to the file index, so it is printed in the error messages.
+    ///
+    pub fn try_from_provider(provider: &dyn ISourceProvider, path: &Path) -> anyhow::Result<Self> {
+        let path = path.to_owned();
+        let code = provider.read_file(&path)?;
 
         let source_file_extension = path
             .extension()
@@ -101,7 +110,8 @@ impl File {
             .to_string_lossy()
             .to_string();
 
-        let next_file_id = FILE_INDEX.next(path, code);
+        let _timer = TIMINGS.start(TimingsPhase::Parsing, path.to_string_lossy().to_string());
+        let next_file_id = FILE_INDEX.next(&path, code);
         let tree = Parser::default()
             .parse(
                 FILE_INDEX
@@ -118,11 +128,7 @@ impl File {
             .map_err(|error| error.format())
             .map_err(Error::Compiling)?;
 
-        Ok(Self {
-            path: path.to_owned(),
-            name,
-            tree,
-        })
+        Ok(Self { path, name, tree })
     }
 
     ///
@@ -162,12 +168,41 @@ impl File {
         .map_err(|error| error.format())
         .map_err(Error::Compiling)?;
 
+        let _timer = TIMINGS.start(TimingsPhase::Generation, "bytecode".to_owned());
         let state = ZincVMState::new(manifest).wrap();
         Module::new(scope.borrow().get_intermediate()).write_to_zinc_vm(state.clone());
 
         Ok(state)
     }
 
+    ///
+    /// Finds the first `use` statement importing an item from the `dependency`, and returns
+    /// the imported path with the statement location.
+    ///
+    pub fn find_use(&self, dependency: &str) -> Option<(String, Location)> {
+        self.tree
+            .statements
+            .iter()
+            .find_map(|statement| match statement {
+                ModuleLocalStatement::Use(statement) => {
+                    let mut first = &statement.path;
+                    while let Some(ref left) = first.left {
+                        first = left.as_ref();
+                    }
+
+                    match first.value.as_ref() {
+                        ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier))
+                            if identifier.name == dependency =>
+                        {
+                            Some((statement.path.to_string(), statement.location))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+    }
+
     ///
     /// Checks whether the file is the entry point.
     ///
@@ -5,21 +5,24 @@
 pub mod directory;
 pub mod error;
 pub mod file;
+pub mod provider;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 
-use anyhow::Context;
+use zinc_lexical::Location;
 
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::semantic::scope::Scope;
-use crate::source::error::Error;
 
 use self::directory::Directory;
 use self::file::File;
+use self::provider::disk::Disk as DiskProvider;
+use self::provider::EntryType;
+use self::provider::ISourceProvider;
 
 ///
 /// The file system source code representation.
@@ -49,38 +52,32 @@ impl Source {
     /// Initializes the entry application module representation from the file system.
     ///
     pub fn try_from_entry(path: &PathBuf) -> anyhow::Result<Self> {
-        let file_type = fs::metadata(path)
-            .with_context(|| path.to_string_lossy().to_string())?
-            .file_type();
-
-        if file_type.is_dir() {
-            return Directory::try_from_path(path, true).map(Self::Directory);
-        }
-
-        if file_type.is_file() {
-            return File::try_from_path(path).map(Self::File);
-        }
-
-        Err(Error::FileTypeUnknown).with_context(|| path.to_string_lossy().to_string())
+        Self::try_from_provider(&DiskProvider::default(), path, true)
     }
 
     ///
     /// Initializes an application module representation from the file system.
     ///
     pub fn try_from_path(path: &PathBuf) -> anyhow::Result<Self> {
-        let file_type = fs::metadata(path)
-            .with_context(|| path.to_string_lossy().to_string())?
-            .file_type();
-
-        if file_type.is_dir() {
-            return Directory::try_from_path(path, false).map(Self::Directory);
-        }
+        Self::try_from_provider(&DiskProvider::default(), path, false)
+    }
 
-        if file_type.is_file() {
-            return File::try_from_path(path).map(Self::File);
+    ///
+    /// Initializes an application module representation from the `provider`.
+    ///
+    /// If `is_entry` is set, the `path` is the application entry module.
+    ///
+    pub fn try_from_provider(
+        provider: &dyn ISourceProvider,
+        path: &Path,
+        is_entry: bool,
+    ) -> anyhow::Result<Self> {
+        match provider.entry_type(path)? {
+            EntryType::Directory => {
+                Directory::try_from_provider(provider, path, is_entry).map(Self::Directory)
+            }
+            EntryType::File => File::try_from_provider(provider, path).map(Self::File),
         }
-
-        Err(Error::FileTypeUnknown).with_context(|| path.to_string_lossy().to_string())
     }
 
     ///
@@ -112,6 +109,26 @@ impl Source {
         }
     }
 
+    ///
+    /// Finds the first `use` statement importing an item from the `dependency`, and returns
+    /// the imported path with the statement location.
+    ///
+    /// The directory entry is searched first, and then its modules in the alphabetical order.
+    ///
+    pub fn find_use(&self, dependency: &str) -> Option<(String, Location)> {
+        match self {
+            Self::File(inner) => inner.find_use(dependency),
+            Self::Directory(inner) => inner.entry.find_use(dependency).or_else(|| {
+                let mut names: Vec<&String> = inner.modules.keys().collect();
+                names.sort();
+                names
+                    .into_iter()
+                    .filter_map(|name| inner.modules.get(name))
+                    .find_map(|module| module.find_use(dependency))
+            }),
+        }
+    }
+
     ///
     /// Gets the file or directory name.
     ///
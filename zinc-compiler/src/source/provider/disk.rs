@@ -0,0 +1,66 @@
+//!
+//! The file system source code provider.
+//!
+
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::source::error::Error;
+use crate::source::provider::EntryType;
+use crate::source::provider::ISourceProvider;
+
+///
+/// The file system source code provider, whose virtual paths are the file system ones.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Disk {}
+
+impl ISourceProvider for Disk {
+    fn entry_type(&self, path: &Path) -> anyhow::Result<EntryType> {
+        let file_type = fs::metadata(path)
+            .with_context(|| path.to_string_lossy().to_string())?
+            .file_type();
+
+        if file_type.is_dir() {
+            return Ok(EntryType::Directory);
+        }
+
+        if file_type.is_file() {
+            return Ok(EntryType::File);
+        }
+
+        Err(Error::FileTypeUnknown).with_context(|| path.to_string_lossy().to_string())
+    }
+
+    fn read_directory(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let directory = fs::read_dir(path).with_context(|| path.to_string_lossy().to_string())?;
+
+        let mut paths = Vec::new();
+        for directory_entry in directory.into_iter() {
+            let directory_entry =
+                directory_entry.with_context(|| path.to_string_lossy().to_string())?;
+            paths.push(directory_entry.path());
+        }
+
+        Ok(paths)
+    }
+
+    fn read_file(&self, path: &Path) -> anyhow::Result<String> {
+        let mut file = fs::File::open(path).with_context(|| path.to_string_lossy().to_string())?;
+
+        let size = file
+            .metadata()
+            .with_context(|| path.to_string_lossy().to_string())?
+            .len() as usize;
+
+        let mut code = String::with_capacity(size);
+        file.read_to_string(&mut code)
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(code)
+    }
+}
@@ -0,0 +1,93 @@
+//!
+//! The in-memory source code provider.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::source::error::Error;
+use crate::source::provider::EntryType;
+use crate::source::provider::ISourceProvider;
+
+///
+/// The in-memory source code provider, which is used by the tooling and tests to compile the
+/// multi-file projects without touching the file system.
+///
+/// The directories are implied by the file paths, e.g. the `src/foo/mod.zn` file makes the
+/// `src` and `src/foo` directories exist.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Memory {
+    /// The file contents by their virtual paths.
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl Memory {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Adds the file with `code` at the virtual `path`, replacing the existing one.
+    ///
+    pub fn insert<P: Into<PathBuf>>(&mut self, path: P, code: String) {
+        self.files.insert(path.into(), code);
+    }
+
+    ///
+    /// Adds the file with `code` at the virtual `path`, returning the provider.
+    ///
+    pub fn with_file<P: Into<PathBuf>>(mut self, path: P, code: &str) -> Self {
+        self.insert(path, code.to_owned());
+        self
+    }
+}
+
+impl ISourceProvider for Memory {
+    fn entry_type(&self, path: &Path) -> anyhow::Result<EntryType> {
+        if self.files.contains_key(path) {
+            return Ok(EntryType::File);
+        }
+
+        if self
+            .files
+            .keys()
+            .any(|file| file.starts_with(path) && file != path)
+        {
+            return Ok(EntryType::Directory);
+        }
+
+        Err(Error::NotFound).with_context(|| path.to_string_lossy().to_string())
+    }
+
+    fn read_directory(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let entries: BTreeSet<PathBuf> = self
+            .files
+            .keys()
+            .filter_map(|file| file.strip_prefix(path).ok())
+            .filter_map(|relative| relative.components().next())
+            .map(|component| path.join(component))
+            .collect();
+
+        if entries.is_empty() {
+            return Err(Error::NotFound).with_context(|| path.to_string_lossy().to_string());
+        }
+
+        Ok(entries.into_iter().collect())
+    }
+
+    fn read_file(&self, path: &Path) -> anyhow::Result<String> {
+        self.files
+            .get(path)
+            .cloned()
+            .ok_or(Error::NotFound)
+            .with_context(|| path.to_string_lossy().to_string())
+    }
+}
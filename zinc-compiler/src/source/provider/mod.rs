@@ -0,0 +1,46 @@
+//!
+//! The source code provider.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod disk;
+pub mod memory;
+
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The source code provider, which the module loader reads the project files from.
+///
+/// The paths are virtual, that is, they are only used to locate the files within the provider
+/// and are printed in the error messages as they are.
+///
+pub trait ISourceProvider {
+    ///
+    /// Returns the type of the entry at `path`.
+    ///
+    fn entry_type(&self, path: &Path) -> anyhow::Result<EntryType>;
+
+    ///
+    /// Returns the paths of the entries of the directory at `path`.
+    ///
+    fn read_directory(&self, path: &Path) -> anyhow::Result<Vec<PathBuf>>;
+
+    ///
+    /// Returns the content of the file at `path`.
+    ///
+    fn read_file(&self, path: &Path) -> anyhow::Result<String>;
+}
+
+///
+/// The source code provider entry type.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntryType {
+    /// The source code file.
+    File,
+    /// The directory with the source code files.
+    Directory,
+}
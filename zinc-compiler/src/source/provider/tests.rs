@@ -0,0 +1,110 @@
+//!
+//! The source code provider tests.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::source::error::Error;
+use crate::source::provider::memory::Memory as MemoryProvider;
+use crate::source::provider::EntryType;
+use crate::source::provider::ISourceProvider;
+use crate::source::Source;
+
+fn provider() -> MemoryProvider {
+    MemoryProvider::new()
+        .with_file("src/main.zn", "mod one;\nmod two;\n\nfn main() {}\n")
+        .with_file("src/one.zn", "const VALUE: u8 = 1;\n")
+        .with_file("src/two/mod.zn", "mod three;\n")
+        .with_file("src/two/three.zn", "const VALUE: u8 = 3;\n")
+}
+
+#[test]
+fn ok_memory_entry_type() {
+    let provider = provider();
+
+    assert_eq!(
+        provider
+            .entry_type(Path::new("src"))
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        EntryType::Directory
+    );
+    assert_eq!(
+        provider
+            .entry_type(Path::new("src/two"))
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        EntryType::Directory
+    );
+    assert_eq!(
+        provider
+            .entry_type(Path::new("src/one.zn"))
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        EntryType::File
+    );
+}
+
+#[test]
+fn ok_memory_read_directory() {
+    let entries = provider()
+        .read_directory(Path::new("src"))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        entries,
+        vec![
+            PathBuf::from("src/main.zn"),
+            PathBuf::from("src/one.zn"),
+            PathBuf::from("src/two"),
+        ]
+    );
+}
+
+#[test]
+fn ok_memory_source_tree() {
+    let source = Source::try_from_provider(&provider(), Path::new("src"), true)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    match source {
+        Source::Directory(directory) => {
+            assert_eq!(directory.entry.path, PathBuf::from("src/main.zn"));
+            assert!(directory.modules.contains_key("one"));
+            match directory.modules.get("two") {
+                Some(Source::Directory(two)) => {
+                    assert_eq!(two.entry.path, PathBuf::from("src/two/mod.zn"));
+                    assert!(two.modules.contains_key("three"));
+                }
+                _ => panic!("the `two` module must be a directory"),
+            }
+        }
+        Source::File(_) => panic!("the entry must be a directory"),
+    }
+}
+
+#[test]
+fn error_memory_not_found() {
+    let error = provider()
+        .read_file(Path::new("src/unknown.zn"))
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(error.to_string(), "src/unknown.zn");
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::NotFound)
+    ));
+}
+
+#[test]
+fn error_memory_module_entry_not_found() {
+    let provider = MemoryProvider::new()
+        .with_file("src/main.zn", "mod two;\n\nfn main() {}\n")
+        .with_file("src/two/three.zn", "const VALUE: u8 = 3;\n");
+
+    let error = Source::try_from_provider(&provider, Path::new("src"), true)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(error.to_string(), "src/two");
+    assert!(matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error::ModuleEntryNotFound)
+    ));
+}
@@ -0,0 +1,305 @@
+//!
+//! The compiler timings.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod phase;
+pub mod record;
+
+use std::cell::RefCell;
+use std::fs;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use lazy_static::lazy_static;
+
+use self::phase::Phase;
+use self::record::Record;
+
+///
+/// The compiler timings, where the wall time of the pipeline phases is recorded.
+///
+/// The records are only collected if the timings have been enabled, so the instrumentation
+/// is almost free during ordinary builds.
+///
+pub struct Timings {
+    /// Whether the timings are collected.
+    is_enabled: AtomicBool,
+    /// The records, merged by the phase and item name.
+    inner: RwLock<Vec<Record>>,
+    /// The constant function calls whose results have been taken from the cache.
+    constant_cache_hits: AtomicUsize,
+    /// The constant function calls which have been evaluated.
+    constant_cache_misses: AtomicUsize,
+}
+
+lazy_static! {
+    pub static ref TIMINGS: Timings = Timings::new();
+}
+
+thread_local! {
+    ///
+    /// The wall time of the nested records of each running timer.
+    ///
+    /// Is used to exclude the time of nested modules from their parent modules.
+    ///
+    static NESTED: RefCell<Vec<Duration>> = RefCell::new(Vec::new());
+}
+
+impl Timings {
+    /// The records default capacity.
+    const INITIAL_CAPACITY: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            is_enabled: AtomicBool::new(false),
+            inner: RwLock::new(Vec::with_capacity(Self::INITIAL_CAPACITY)),
+            constant_cache_hits: AtomicUsize::new(0),
+            constant_cache_misses: AtomicUsize::new(0),
+        }
+    }
+
+    ///
+    /// Enables the timings collection.
+    ///
+    pub fn enable(&self) {
+        self.is_enabled.store(true, Ordering::SeqCst);
+    }
+
+    ///
+    /// Checks whether the timings are collected.
+    ///
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Starts a timer, which records the wall time of the `name` item during the `phase`
+    /// when dropped.
+    ///
+    pub fn start(&self, phase: Phase, name: String) -> Timer {
+        if !self.is_enabled() {
+            return Timer { inner: None };
+        }
+
+        NESTED.with(|nested| nested.borrow_mut().push(Duration::default()));
+
+        Timer {
+            inner: Some((phase, name, Instant::now())),
+        }
+    }
+
+    ///
+    /// Adds the `duration` to the record with the same phase and item name, if it exists.
+    ///
+    pub fn record(&self, phase: Phase, name: String, duration: Duration) {
+        let mut inner = self
+            .inner
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        match inner
+            .iter_mut()
+            .find(|record| record.phase == phase && record.name == name)
+        {
+            Some(record) => record.duration += duration,
+            None => inner.push(Record::new(phase, name, duration)),
+        }
+    }
+
+    ///
+    /// Counts a constant function call, which is a cache hit if `is_hit` is set.
+    ///
+    pub fn record_constant_cache(&self, is_hit: bool) {
+        let counter = if is_hit {
+            &self.constant_cache_hits
+        } else {
+            &self.constant_cache_misses
+        };
+        counter.fetch_add(1, Ordering::SeqCst);
+    }
+
+    ///
+    /// Returns the constant function cache hits and misses.
+    ///
+    pub fn constant_cache(&self) -> (usize, usize) {
+        (
+            self.constant_cache_hits.load(Ordering::SeqCst),
+            self.constant_cache_misses.load(Ordering::SeqCst),
+        )
+    }
+
+    ///
+    /// Returns the constant function cache hit rate, or `None` if there have been no calls.
+    ///
+    pub fn constant_cache_hit_rate(&self) -> Option<f64> {
+        let (hits, misses) = self.constant_cache();
+        if hits + misses == 0 {
+            return None;
+        }
+
+        Some(hits as f64 / (hits + misses) as f64)
+    }
+
+    ///
+    /// Returns the total wall time of the `phase`.
+    ///
+    pub fn total(&self, phase: Phase) -> Duration {
+        self.inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .iter()
+            .filter(|record| record.phase == phase)
+            .map(|record| record.duration)
+            .sum()
+    }
+
+    ///
+    /// Converts the timings into their JSON representation.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        let records: Vec<serde_json::Value> = self
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .iter()
+            .map(Record::to_json)
+            .collect();
+
+        let mut totals = serde_json::Map::with_capacity(Phase::all().len());
+        for phase in Phase::all().iter() {
+            totals.insert(
+                phase.to_string(),
+                serde_json::json!(self.total(*phase).as_secs_f64() * 1000.0),
+            );
+        }
+
+        let (hits, misses) = self.constant_cache();
+
+        serde_json::json!({
+            "records": records,
+            "totals_ms": totals,
+            "constant_cache": {
+                "hits": hits,
+                "misses": misses,
+                "hit_rate": self.constant_cache_hit_rate(),
+            },
+            "peak_rss_bytes": Self::peak_rss(),
+        })
+    }
+
+    ///
+    /// Converts the timings into a human-readable table.
+    ///
+    pub fn to_table(&self) -> String {
+        let mut table = format!("{:<12}{:<48}{:>12}\n", "Phase", "Item", "Time, ms");
+
+        for record in self
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .iter()
+        {
+            table.push_str(
+                format!(
+                    "{:<12}{:<48}{:>12.3}\n",
+                    record.phase.to_string(),
+                    record.name,
+                    record.duration.as_secs_f64() * 1000.0,
+                )
+                .as_str(),
+            );
+        }
+
+        for phase in Phase::all().iter() {
+            table.push_str(
+                format!(
+                    "{:<12}{:<48}{:>12.3}\n",
+                    phase.to_string(),
+                    "total",
+                    self.total(*phase).as_secs_f64() * 1000.0,
+                )
+                .as_str(),
+            );
+        }
+
+        let (hits, misses) = self.constant_cache();
+        match self.constant_cache_hit_rate() {
+            Some(hit_rate) => table.push_str(
+                format!(
+                    "Constant cache: {} hits, {} misses ({:.1}%)\n",
+                    hits,
+                    misses,
+                    hit_rate * 100.0
+                )
+                .as_str(),
+            ),
+            None => table.push_str("Constant cache: no calls\n"),
+        }
+
+        match Self::peak_rss() {
+            Some(peak_rss) => {
+                table.push_str(format!("Peak RSS: {} KiB\n", peak_rss / 1024).as_str())
+            }
+            None => table.push_str("Peak RSS: unknown\n"),
+        }
+
+        table
+    }
+
+    ///
+    /// Returns the peak resident set size of the process in bytes.
+    ///
+    /// The value is only available on systems with the `procfs` file system.
+    ///
+    pub fn peak_rss() -> Option<u64> {
+        let status = fs::read_to_string("/proc/self/status").ok()?;
+
+        status
+            .lines()
+            .find(|line| line.starts_with("VmHWM:"))?
+            .split_whitespace()
+            .nth(1)?
+            .parse::<u64>()
+            .ok()
+            .map(|kilobytes| kilobytes * 1024)
+    }
+}
+
+///
+/// The running phase timer, which writes its record when dropped.
+///
+pub struct Timer {
+    /// The phase, item name, and start time. `None` if the timings are disabled.
+    inner: Option<(Phase, String, Instant)>,
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let (phase, name, started_at) = match self.inner.take() {
+            Some(inner) => inner,
+            None => return,
+        };
+
+        let elapsed = started_at.elapsed();
+        let nested = NESTED.with(|nested| {
+            let mut nested = nested.borrow_mut();
+            let own_nested = nested.pop().unwrap_or_default();
+            if let Some(parent_nested) = nested.last_mut() {
+                *parent_nested += elapsed;
+            }
+            own_nested
+        });
+
+        TIMINGS.record(phase, name, elapsed.checked_sub(nested).unwrap_or_default());
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! The compiler timings phase.
+//!
+
+use std::fmt;
+
+///
+/// The compiler pipeline phase, whose wall time is measured.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// The lexical and syntax analysis of a source code file.
+    Parsing,
+    /// The semantic analysis of a module.
+    Semantic,
+    /// The bytecode generation.
+    Generation,
+    /// The build artifact writing.
+    Writing,
+}
+
+impl Phase {
+    ///
+    /// Returns all the phases in the pipeline order.
+    ///
+    pub fn all() -> [Self; 4] {
+        [
+            Self::Parsing,
+            Self::Semantic,
+            Self::Generation,
+            Self::Writing,
+        ]
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parsing => write!(f, "parsing"),
+            Self::Semantic => write!(f, "semantic"),
+            Self::Generation => write!(f, "generation"),
+            Self::Writing => write!(f, "writing"),
+        }
+    }
+}
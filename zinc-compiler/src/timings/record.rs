@@ -0,0 +1,44 @@
+//!
+//! The compiler timings record.
+//!
+
+use std::time::Duration;
+
+use crate::timings::phase::Phase;
+
+///
+/// The wall time spent on an item during a pipeline phase.
+///
+#[derive(Debug, Clone)]
+pub struct Record {
+    /// The pipeline phase.
+    pub phase: Phase,
+    /// The item name, e.g. a file path or module identifier.
+    pub name: String,
+    /// The wall time, excluding the time of the nested records.
+    pub duration: Duration,
+}
+
+impl Record {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(phase: Phase, name: String, duration: Duration) -> Self {
+        Self {
+            phase,
+            name,
+            duration,
+        }
+    }
+
+    ///
+    /// Converts the record into its JSON representation.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "phase": self.phase.to_string(),
+            "name": self.name,
+            "duration_ms": self.duration.as_secs_f64() * 1000.0,
+        })
+    }
+}
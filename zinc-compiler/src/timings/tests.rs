@@ -0,0 +1,61 @@
+//!
+//! The compiler timings tests.
+//!
+
+use crate::generator::zinc_vm::tests::compile_with_files;
+use crate::timings::phase::Phase;
+use crate::timings::TIMINGS;
+
+#[test]
+fn ok_multi_file() {
+    TIMINGS.enable();
+
+    let main = r#"
+mod timings_math;
+
+fn main(value: u8) -> u8 {
+    timings_math::double(value)
+}
+"#;
+    let timings_math = r#"
+pub fn double(value: u8) -> u8 {
+    value * 2
+}
+"#;
+
+    let manifest = zinc_project::Manifest::new("timings", zinc_project::ProjectType::Circuit);
+    let version = manifest.project.version.clone();
+    let state = compile_with_files(manifest, &[("main", main), ("timings_math", timings_math)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    {
+        let _timer = TIMINGS.start(Phase::Writing, "timings/main.znb".to_owned());
+        state.into_application(false, None).into_build(&version);
+    }
+
+    let json = TIMINGS.to_json();
+    let records = json["records"]
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let has_record = |phase: Phase, name: &str| {
+        records.iter().any(|record| {
+            record["phase"] == phase.to_string()
+                && record["name"] == name
+                && record["duration_ms"].is_f64()
+        })
+    };
+
+    assert!(has_record(Phase::Parsing, "timings/main.zn"));
+    assert!(has_record(Phase::Parsing, "timings/timings_math.zn"));
+    assert!(has_record(Phase::Semantic, "src"));
+    assert!(has_record(Phase::Semantic, "timings_math"));
+    assert!(has_record(Phase::Generation, "bytecode"));
+    assert!(has_record(Phase::Writing, "timings/main.znb"));
+
+    for phase in Phase::all().iter() {
+        assert!(json["totals_ms"][phase.to_string()].is_f64());
+    }
+    assert!(json["constant_cache"]["hits"].is_u64());
+    assert!(json["constant_cache"]["misses"].is_u64());
+    assert!(json["peak_rss_bytes"].is_u64() || json["peak_rss_bytes"].is_null());
+}
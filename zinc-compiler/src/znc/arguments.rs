@@ -36,9 +36,63 @@ pub struct Arguments {
     #[structopt(long = "test-only")]
     pub test_only: bool,
 
+    /// The paths to the test root files, which are compiled as separate modules using
+    /// the project as a dependency. Their unit tests are run along with the project ones.
+    #[structopt(long = "test-root", parse(from_os_str))]
+    pub test_roots: Vec<PathBuf>,
+
     /// Enables the dead function code elimination optimization.
     #[structopt(long = "opt-dfe")]
     pub optimize_dead_function_elimination: bool,
+
+    /// Enables the redundant range check elimination optimization, which skips the arithmetic
+    /// and cast range checks proven redundant by the operand bounds.
+    #[structopt(long = "opt-rce")]
+    pub optimize_range_check_elimination: bool,
+
+    /// Keeps the range checks proven redundant, but reports their violations as the bound
+    /// analysis errors. Used to cross-check the redundant range check elimination.
+    #[structopt(long = "opt-rce-assert")]
+    pub assert_range_check_elimination: bool,
+
+    /// The path to the previous contract version binary. If the storage layout has changed
+    /// beyond appending fields, the `#[upgrade]` method argument is checked against the
+    /// previous storage fields.
+    #[structopt(long = "previous-build", parse(from_os_str))]
+    pub previous_build_path: Option<PathBuf>,
+
+    /// The configuration values in the `key=value` format, which are returned by the `cfg!`
+    /// function. Zargo passes the build `profile` and the manifest `cfg` section values.
+    #[structopt(long = "cfg", parse(try_from_str = parse_cfg))]
+    pub cfg: Vec<(String, String)>,
+
+    /// The maximal number of the bytecode instructions. Overrides the manifest `build` section.
+    #[structopt(long = "max-instructions")]
+    pub max_instructions: Option<usize>,
+
+    /// Prints the wall time of each compilation phase and the peak memory usage.
+    /// The format is either `table` (default) or `json`.
+    #[structopt(long = "timings", possible_values = &["table", "json"])]
+    pub timings: Option<Option<String>>,
+
+    /// The compilation error format, which is either `human` (default) or `json`.
+    /// The JSON diagnostic carries the dependency error notes as an array.
+    #[structopt(
+        long = "error-format",
+        default_value = "human",
+        possible_values = &["human", "json"]
+    )]
+    pub error_format: String,
+
+    /// Prints the compiler information as JSON instead of building the project.
+    /// The `target-info` value prints the intrinsic and standard library functions.
+    /// The `docs` value prints the project documentation extracted from the analyzed source code.
+    #[structopt(long = "print", possible_values = &["target-info", "docs"])]
+    pub print: Option<String>,
+
+    /// Documents the private functions, methods, and storage fields with `--print docs`.
+    #[structopt(long = "document-private-items")]
+    pub document_private_items: bool,
 }
 
 impl Arguments {
@@ -49,3 +103,19 @@ impl Arguments {
         Self::from_args()
     }
 }
+
+///
+/// Parses the `key=value` configuration value.
+///
+fn parse_cfg(value: &str) -> Result<(String, String), String> {
+    match value.find('=') {
+        Some(position) if position > 0 => Ok((
+            value[..position].to_owned(),
+            value[position + 1..].to_owned(),
+        )),
+        _ => Err(format!(
+            "invalid configuration value `{}`, expected `key=value`",
+            value
+        )),
+    }
+}
@@ -13,6 +13,10 @@ use std::thread;
 use anyhow::Context;
 
 use zinc_compiler::Bundler;
+use zinc_compiler::BundlerError;
+use zinc_compiler::IntrinsicScope;
+use zinc_compiler::TimingsPhase;
+use zinc_compiler::TIMINGS;
 
 use self::arguments::Arguments;
 
@@ -37,7 +41,36 @@ fn main_inner() -> anyhow::Result<()> {
 
     zinc_logger::initialize(zinc_const::app_name::COMPILER, args.verbosity, args.quiet);
 
+    if let Some("target-info") = args.print.as_deref() {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&IntrinsicScope::target_info())
+                .expect(zinc_const::panic::DATA_CONVERSION)
+        );
+        return Ok(());
+    }
+
     let optimize_dead_function_elimination = args.optimize_dead_function_elimination;
+    let range_check_elision = if args.assert_range_check_elimination {
+        Some(zinc_types::RangeCheck::Asserted)
+    } else if args.optimize_range_check_elimination {
+        Some(zinc_types::RangeCheck::Elided)
+    } else {
+        None
+    };
+    let previous_build_path = args.previous_build_path;
+    let max_instructions = args.max_instructions;
+    let cfg = args.cfg.into_iter().collect();
+    let test_roots = args.test_roots;
+    let is_error_format_json = args.error_format.as_str() == "json";
+
+    if args.timings.is_some() {
+        TIMINGS.enable();
+    }
+
+    if is_error_format_json {
+        colored::control::set_override(false);
+    }
 
     let mut manifest_path = args.manifest_path;
     if !manifest_path.is_dir()
@@ -69,6 +102,46 @@ fn main_inner() -> anyhow::Result<()> {
     fs::create_dir_all(&dependencies_directory_path)
         .with_context(|| dependencies_directory_path.to_string_lossy().to_string())?;
 
+    if let Some("docs") = args.print.as_deref() {
+        let is_private = args.document_private_items;
+        let documentation = thread::Builder::new()
+            .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+            .spawn(move || {
+                Bundler::new(
+                    manifest_path,
+                    dependencies_directory_path,
+                    false,
+                    None,
+                    None,
+                    None,
+                    Vec::new(),
+                    cfg,
+                )
+                .document(is_private)
+            })
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .join()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let documentation = match documentation {
+            Ok(documentation) => documentation,
+            Err(error) if is_error_format_json => {
+                eprintln!(
+                    "{}",
+                    serde_json::to_string_pretty(&BundlerError::to_json(&error))
+                        .expect(zinc_const::panic::DATA_CONVERSION)
+                );
+                process::exit(zinc_const::exit_code::FAILURE);
+            }
+            Err(error) => return Err(error),
+        };
+
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&documentation).expect(zinc_const::panic::DATA_CONVERSION)
+        );
+        return Ok(());
+    }
+
     let build = thread::Builder::new()
         .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
         .spawn(move || {
@@ -76,12 +149,29 @@ fn main_inner() -> anyhow::Result<()> {
                 manifest_path,
                 dependencies_directory_path,
                 optimize_dead_function_elimination,
+                range_check_elision,
+                previous_build_path,
+                max_instructions,
+                test_roots,
+                cfg,
             )
             .bundle()
         })
         .expect(zinc_const::panic::SYNCHRONIZATION)
         .join()
-        .expect(zinc_const::panic::SYNCHRONIZATION)?;
+        .expect(zinc_const::panic::SYNCHRONIZATION);
+    let build = match build {
+        Ok(build) => build,
+        Err(error) if is_error_format_json => {
+            eprintln!(
+                "{}",
+                serde_json::to_string_pretty(&BundlerError::to_json(&error))
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+            );
+            process::exit(zinc_const::exit_code::FAILURE);
+        }
+        Err(error) => return Err(error),
+    };
 
     let mut input_template_path = data_directory_path;
     input_template_path.push(format!(
@@ -89,6 +179,10 @@ fn main_inner() -> anyhow::Result<()> {
         zinc_const::file_name::INPUT,
         zinc_const::extension::JSON
     ));
+    let timer = TIMINGS.start(
+        TimingsPhase::Writing,
+        input_template_path.to_string_lossy().to_string(),
+    );
     let input_template_data =
         serde_json::to_vec_pretty(&build.input).expect(zinc_const::panic::DATA_CONVERSION);
     if !input_template_path.exists() {
@@ -104,12 +198,18 @@ fn main_inner() -> anyhow::Result<()> {
         );
     }
 
+    drop(timer);
+
     let mut binary_path = target_directory_path;
     binary_path.push(format!(
         "{}.{}",
         zinc_const::file_name::BINARY,
         zinc_const::extension::BINARY,
     ));
+    let timer = TIMINGS.start(
+        TimingsPhase::Writing,
+        binary_path.to_string_lossy().to_string(),
+    );
     if binary_path.exists() {
         fs::remove_file(&binary_path).with_context(|| binary_path.to_string_lossy().to_string())?;
     }
@@ -117,7 +217,18 @@ fn main_inner() -> anyhow::Result<()> {
         .with_context(|| binary_path.to_string_lossy().to_string())?
         .write_all(build.bytecode.as_slice())
         .with_context(|| binary_path.to_string_lossy().to_string())?;
+    drop(timer);
     log::info!("Compiled to {:?}", binary_path);
 
+    match args.timings {
+        Some(Some(format)) if format.as_str() == "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&TIMINGS.to_json())
+                .expect(zinc_const::panic::DATA_CONVERSION)
+        ),
+        Some(_) => print!("{}", TIMINGS.to_table()),
+        None => {}
+    }
+
     Ok(())
 }
@@ -17,6 +17,10 @@ pub const INTEGER_MAX: usize = 248;
 /// The `field` type bitlength.
 pub const FIELD: usize = 254;
 
+/// The maximal bitlength of the `field` values, which can be compared with the ordering operators,
+/// since the difference of such values fits the field capacity without wrapping around the modulus.
+pub const FIELD_ORDERING_MAX: usize = FIELD - 2;
+
 /// The `field` type padded to a multiple of 8 bitlength.
 pub const FIELD_PADDED: usize = FIELD + (BYTE - FIELD % BYTE);
 
@@ -0,0 +1,12 @@
+//!
+//! The Zinc conditional compilation constants.
+//!
+
+/// The configuration key of the build profile, which is passed by Zargo automatically.
+pub static PROFILE: &str = "profile";
+
+/// The debug build profile.
+pub static PROFILE_DEBUG: &str = "debug";
+
+/// The release build profile.
+pub static PROFILE_RELEASE: &str = "release";
@@ -5,6 +5,9 @@
 /// The default source code directory subpath.
 pub static SOURCE: &str = "src/";
 
+/// The integration unit tests directory subpath.
+pub static TESTS: &str = "tests/";
+
 /// The default template, keys, and other auxiliary data directory subpath.
 pub static DATA: &str = "data/";
 
@@ -20,5 +23,8 @@ pub static TARGET_RELEASE: &str = "target/release/";
 /// The target dependencies directory subpath.
 pub static TARGET_DEPS: &str = "target/deps/";
 
+/// The target documentation directory subpath.
+pub static TARGET_DOC: &str = "target/doc/";
+
 /// The integration tests scenarios directory subpath.
 pub static SCENARIOS: &str = "scenarios/";
@@ -3,6 +3,7 @@
 //!
 
 pub mod unit_test;
+pub mod verify;
 
 /// The common application success exit code.
 pub const SUCCESS: i32 = 0;
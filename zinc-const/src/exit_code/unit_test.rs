@@ -8,6 +8,7 @@ use std::process::ExitStatus;
 ///
 /// The Zinc unit test exit code constants.
 ///
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ExitCode {
     /// The test passed without an error or with an error if it is marked with the `should_panic` attribute.
     Passed = 0,
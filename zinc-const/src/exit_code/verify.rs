@@ -0,0 +1,32 @@
+//!
+//! The Zinc proof verification exit code constants.
+//!
+
+use std::convert::TryFrom;
+use std::process::ExitStatus;
+
+///
+/// The Zinc proof verification exit code constants.
+///
+pub enum ExitCode {
+    /// The proof is valid.
+    Passed = 0,
+    /// The verifying key or public data cannot be read or decoded.
+    Invalid = 1,
+    /// The proof is invalid or cannot be decoded.
+    Failed = 2,
+}
+
+impl TryFrom<ExitStatus> for ExitCode {
+    type Error = Option<i32>;
+
+    fn try_from(status: ExitStatus) -> Result<Self, Self::Error> {
+        Ok(match status.code() {
+            Some(0) => Self::Passed,
+            Some(1) => Self::Invalid,
+            Some(2) => Self::Failed,
+
+            code => return Err(code),
+        })
+    }
+}
@@ -13,3 +13,12 @@ pub static BINARY: &str = "znb";
 
 /// The JSON data file extension.
 pub static JSON: &str = "json";
+
+/// The `lcov` coverage report file extension.
+pub static COVERAGE: &str = "info";
+
+/// The contract ABI file extension.
+pub static ABI: &str = "abi.json";
+
+/// The temporary file extension, which is used until the file is completely written.
+pub static TEMPORARY: &str = "tmp";
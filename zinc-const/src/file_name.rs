@@ -17,6 +17,9 @@ pub static MODULE_ENTRY: &str = "mod";
 /// The binary file default name.
 pub static BINARY: &str = "main";
 
+/// The previous bytecode binary file name, which is kept for comparing with the current one.
+pub static BINARY_PREVIOUS: &str = "main.previous";
+
 /// The input template file default name.
 pub static INPUT: &str = "input";
 
@@ -34,3 +37,6 @@ pub static PRIVATE_KEY: &str = "private_key";
 
 /// The integration test scenario file default name.
 pub static SCENARIO: &str = "scenario";
+
+/// The unit test coverage report file default name.
+pub static COVERAGE: &str = "lcov";
@@ -5,6 +5,7 @@
 pub mod app_name;
 pub mod base;
 pub mod bitlength;
+pub mod cfg;
 pub mod contract;
 pub mod directory;
 pub mod exit_code;
@@ -14,6 +15,8 @@ pub mod limit;
 pub mod panic;
 pub mod size;
 pub mod source;
+pub mod version;
 pub mod zandbox;
 
 pub use self::exit_code::unit_test::ExitCode as UnitTestExitCode;
+pub use self::exit_code::verify::ExitCode as VerifyExitCode;
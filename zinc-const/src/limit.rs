@@ -14,8 +14,48 @@ pub const SCHNORR_MESSAGE_BYTES: usize = 31;
 /// The `schnorr` message maximal size in bits.
 pub const SCHNORR_MESSAGE_BITS: usize = SCHNORR_MESSAGE_BYTES * crate::bitlength::BYTE;
 
+/// The `verify_proof` maximal number of public inputs.
+pub const VERIFY_PROOF_PUBLIC_INPUTS: usize = 8;
+
+/// The `verify_proof` Groth16 proof size in field elements: two for the `A` and `C` points each,
+/// and four for the `B` point.
+pub const VERIFY_PROOF_FIELDS: usize = 8;
+
+/// The maximal number of the element-wise comparisons, which an array, tuple, or structure
+/// `==` or `!=` comparison is lowered to.
+pub const STRUCTURAL_EQUALITY_SIZE: usize = 1024;
+
+/// The default maximal number of the bytecode instructions, which is enforced by the compiler
+/// to prevent the circuits too large to be proven.
+pub const BYTECODE_INSTRUCTIONS: usize = 1_000_000;
+
+/// The maximal number of the virtual machine data stack cells, which bounds the addresses and
+/// sizes in the bytecode instructions and types.
+pub const DATA_STACK_SIZE: usize = 1 << 20;
+
+/// The default maximal number of scalars in the contract method arguments JSON.
+pub const INPUT_FLAT_VALUES: usize = 65536;
+
+/// The default maximal nesting depth of the contract method arguments JSON.
+pub const INPUT_DEPTH: usize = 32;
+
+/// The contract storage Merkle tree depth.
+pub const CONTRACT_STORAGE_DEPTH: usize = 16;
+
+/// The maximal number of the contract storage leaves, which is the storage Merkle tree capacity.
+pub const CONTRACT_STORAGE_LEAVES: usize = 1 << CONTRACT_STORAGE_DEPTH;
+
 /// The Zinc compiler inner thread stack size.
 pub const COMPILER_STACK_SIZE: usize = 64 * 1024 * 1024;
 
 /// The JSON payload limit to fit large contract source code.
 pub static JSON_PAYLOAD: usize = 16 * 1024 * 1024;
+
+/// The uploaded verifying key maximal size in bytes.
+pub const VERIFYING_KEY_SIZE: usize = 1024 * 1024;
+
+/// The uploaded proving key maximal size in bytes.
+pub const PROVING_KEY_SIZE: usize = 32 * 1024 * 1024;
+
+/// The JSON payload limit to fit the keys, whose bytes take up to four characters each.
+pub static KEYS_JSON_PAYLOAD: usize = 4 * (VERIFYING_KEY_SIZE + PROVING_KEY_SIZE) + 1024;
@@ -11,6 +11,9 @@ pub static THREAD_POOL: &str = "Thread pool initialization is always successful"
 /// The asynchronous runtime initialization is always successful.
 pub static ASYNC_RUNTIME: &str = "Asynchronous runtime initialization is always successful";
 
+/// The logger initialization is always successful, as it is done once at the application start.
+pub static LOGGER: &str = "Logger initialization is always successful";
+
 /// The data conversion is always valid.
 pub static DATA_CONVERSION: &str = "Data conversion is always successful: ";
 
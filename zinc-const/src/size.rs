@@ -13,3 +13,6 @@ pub const ETH_PUBLIC_KEY: usize = 64;
 
 /// The ETH private key size.
 pub const ETH_PRIVATE_KEY: usize = 32;
+
+/// The proving and verifying key file footer size, which is the magic bytes and the key length.
+pub const KEY_FOOTER: usize = 16;
@@ -4,3 +4,12 @@
 
 /// The circuit entry function name.
 pub static FUNCTION_MAIN_IDENTIFIER: &str = crate::file_name::APPLICATION_ENTRY;
+
+/// The function postcondition attribute name, whose argument is parsed as an expression.
+pub static ATTRIBUTE_ENSURES_IDENTIFIER: &str = "ensures";
+
+/// The `while` loop iterations bound attribute name, whose argument is parsed as an expression.
+pub static ATTRIBUTE_MAX_ITERATIONS_IDENTIFIER: &str = "max_iterations";
+
+/// The contract invariant attribute name, whose argument is parsed as an expression.
+pub static ATTRIBUTE_INVARIANT_IDENTIFIER: &str = "invariant";
@@ -0,0 +1,9 @@
+//!
+//! The Zinc version constants.
+//!
+
+/// The standard library API version, which is recorded in the bytecode artifact header.
+///
+/// The version is incremented each time a standard library function is added, changed, or
+/// deprecated, so the virtual machine can reject the bytecode relying on a newer library.
+pub const STD: u16 = 2;
@@ -8,12 +8,39 @@ pub static HOST: &str = "0.0.0.0";
 /// The default server binding port.
 pub const PORT: u16 = 4001;
 
+/// The server configuration file default name.
+pub static CONFIG_FILE: &str = "zandbox.toml";
+
+/// The prefix of the environment variables overriding the server configuration fields.
+pub static CONFIG_ENV_PREFIX: &str = "ZANDBOX_";
+
+/// The CORS origin wildcard, which allows the requests from any origin.
+pub static CORS_ANY_ORIGIN: &str = "*";
+
 /// The project default URL.
 pub static PROJECT_URL: &str = "/api/v1/project";
 
 /// The project source URL.
 pub static PROJECT_SOURCE_URL: &str = "/api/v1/project/source";
 
+/// The project keys URL.
+pub static PROJECT_KEYS_URL: &str = "/api/v1/project/keys";
+
+/// The projects URL prefix, which is followed by the project name and version.
+pub static PROJECTS_URL: &str = "/api/v1/projects";
+
+/// The project instances URL suffix, which follows the project name and version.
+pub static PROJECT_INSTANCES_URL_SUFFIX: &str = "instances";
+
+/// The project bytecode URL suffix, which follows the project name and version.
+pub static PROJECT_BYTECODE_URL_SUFFIX: &str = "bytecode";
+
+/// The default number of the list items returned per page.
+pub const PAGE_LIMIT_DEFAULT: usize = 50;
+
+/// The maximal number of the list items returned per page.
+pub const PAGE_LIMIT_MAX: usize = 100;
+
 /// The contract default URL.
 pub static CONTRACT_URL: &str = "/api/v1/contract";
 
@@ -28,3 +55,63 @@ pub static CONTRACT_FEE_URL: &str = "/api/v1/contract/fee";
 
 /// The contract call URL.
 pub static CONTRACT_CALL_URL: &str = "/api/v1/contract/call";
+
+/// The contract dry run URL.
+pub static CONTRACT_DRY_RUN_URL: &str = "/api/v1/contract/dry-run";
+
+/// The default number of the contract dry runs allowed per client IP address in a window.
+pub const DRY_RUN_RATE_LIMIT: usize = 60;
+
+/// The contract dry run rate limit window in seconds.
+pub const DRY_RUN_RATE_LIMIT_WINDOW_SECONDS: u64 = 60;
+
+/// The default number of seconds a contract method may run on the virtual machine.
+pub const METHOD_TIMEOUT_SECONDS: u64 = 30;
+
+/// The contract storage URL.
+pub static CONTRACT_STORAGE_URL: &str = "/api/v1/contract/storage";
+
+/// The contracts URL prefix, which is followed by the contract account ID.
+pub static CONTRACTS_URL: &str = "/api/v1/contracts";
+
+/// The contract removal challenge URL suffix, which follows the contract account ID.
+pub static CONTRACT_DELETE_CHALLENGE_URL_SUFFIX: &str = "delete-challenge";
+
+/// The number of the seconds the contract removal challenge nonce is valid for.
+pub const DELETE_CHALLENGE_LIFETIME_SECONDS: u64 = 300;
+
+/// The request header carrying the client-generated key, which the server may use to deduplicate
+/// retried non-idempotent requests.
+pub static IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// The response header echoing the server-generated request ID, which is also included into the
+/// error response envelope and every server log record of the request.
+pub static REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// The default client connection timeout in seconds.
+pub const CLIENT_CONNECT_TIMEOUT_SECONDS: u64 = 10;
+
+/// The default client request timeout in seconds, which is large enough to wait for a contract
+/// call proof to be verified.
+pub const CLIENT_REQUEST_TIMEOUT_SECONDS: u64 = 300;
+
+/// The default number of the client request retries.
+pub const CLIENT_RETRY_LIMIT: usize = 3;
+
+/// The client retry backoff for the first retry in milliseconds, which is doubled for each next one.
+pub const CLIENT_RETRY_BACKOFF_MILLISECONDS: u64 = 500;
+
+/// The client retry backoff cap in milliseconds.
+pub const CLIENT_RETRY_BACKOFF_LIMIT_MILLISECONDS: u64 = 8_000;
+
+/// The client idle pooled connection timeout in seconds.
+pub const CLIENT_POOL_IDLE_TIMEOUT_SECONDS: u64 = 90;
+
+/// The environment variable overriding the client connection timeout in seconds.
+pub static CLIENT_CONNECT_TIMEOUT_ENV: &str = "ZARGO_CONNECT_TIMEOUT";
+
+/// The environment variable overriding the client request timeout in seconds.
+pub static CLIENT_REQUEST_TIMEOUT_ENV: &str = "ZARGO_REQUEST_TIMEOUT";
+
+/// The environment variable overriding the number of the client request retries.
+pub static CLIENT_RETRY_LIMIT_ENV: &str = "ZARGO_RETRY_LIMIT";
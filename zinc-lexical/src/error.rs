@@ -60,6 +60,13 @@ pub enum Error {
         /// The invalid character.
         found: char,
     },
+    /// An integer literal is followed by a word, which is not a valid type suffix.
+    InvalidIntegerSuffix {
+        /// The location of the invalid suffix.
+        location: Location,
+        /// The invalid suffix.
+        found: String,
+    },
     /// An unexpected character forbidden in the current state.
     InvalidCharacter {
         /// The location of the invalid character.
@@ -133,6 +140,13 @@ impl Error {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn invalid_integer_suffix(location: Location, found: String) -> Self {
+        Self::InvalidIntegerSuffix { location, found }
+    }
+
     ///
     /// A shortcut constructor.
     ///
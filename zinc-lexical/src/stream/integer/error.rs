@@ -57,6 +57,13 @@ pub enum Error {
         /// The position of the invalid character.
         offset: usize,
     },
+    /// The literal is followed by a word, which is not a valid integer type suffix.
+    InvalidSuffix {
+        /// The invalid suffix.
+        found: String,
+        /// The position of the invalid suffix.
+        offset: usize,
+    },
     /// Unable to finish a literal.
     UnexpectedEnd,
 }
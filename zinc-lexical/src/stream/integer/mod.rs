@@ -8,8 +8,10 @@ mod tests;
 pub mod error;
 pub mod output;
 
+use std::convert::TryFrom;
 use std::str;
 
+use crate::token::lexeme::keyword::Keyword;
 use crate::token::lexeme::literal::integer::Integer;
 
 use self::error::Error;
@@ -58,6 +60,8 @@ pub enum State {
 /// 4. Hexadecimal
 /// '2a'
 ///
+/// Any of them may be followed by a type suffix, e.g. '42u64', '0xffi16', or '1field'.
+///
 pub fn parse(input: &str) -> Result<Output, Error> {
     let mut state = State::Start;
     let mut size = 0;
@@ -100,6 +104,11 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 } else if character == Integer::CHARACTER_DECIMAL_POINT {
                     size += 1;
                     state = State::DecimalAfterPoint;
+                } else if let Some((suffix, suffix_size)) = parse_suffix(input, size)? {
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_decimal(integer).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -115,6 +124,14 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((suffix, suffix_size)) = match size {
+                    0..=2 => None,
+                    _ => parse_suffix(input, size)?,
+                } {
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_binary(integer).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() || size <= 2 {
                     return Err(Error::ExpectedOneOfBinary {
                         found: character,
@@ -130,6 +147,14 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
                     size += 1;
+                } else if let Some((suffix, suffix_size)) = match size {
+                    0..=2 => None,
+                    _ => parse_suffix(input, size)?,
+                } {
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_octal(integer).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() || size <= 2 {
                     return Err(Error::ExpectedOneOfOctal {
                         found: character,
@@ -151,6 +176,11 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 } else if character == Integer::CHARACTER_EXPONENT {
                     size += 1;
                     state = State::DecimalAfterExponent;
+                } else if let Some((suffix, suffix_size)) = parse_suffix(input, size)? {
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_decimal(integer).with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -172,6 +202,18 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 } else if character == Integer::CHARACTER_EXPONENT {
                     size += 1;
                     state = State::DecimalAfterExponent;
+                } else if let Some((suffix, suffix_size)) = parse_suffix(input, size)? {
+                    let fractional = if fractional.is_empty() {
+                        None
+                    } else {
+                        Some(fractional)
+                    };
+
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_decimal_with_exponent(integer, fractional, None)
+                            .with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -194,6 +236,21 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 if Integer::CHARACTERS_DECIMAL.contains(&character) {
                     exponent.push(character);
                     size += 1;
+                } else if let Some((suffix, suffix_size)) = match exponent.len() {
+                    0 => None,
+                    _ => parse_suffix(input, size)?,
+                } {
+                    let fractional = if fractional.is_empty() {
+                        None
+                    } else {
+                        Some(fractional)
+                    };
+
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_decimal_with_exponent(integer, fractional, Some(exponent))
+                            .with_suffix(suffix),
+                    ));
                 } else if character.is_ascii_alphanumeric() {
                     return Err(Error::ExpectedOneOfDecimal {
                         found: character,
@@ -219,7 +276,16 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 }
             }
             State::Hexadecimal => {
-                if Integer::CHARACTERS_HEXADECIMAL.contains(&character) {
+                // the suffix is checked first, since `field` starts with a hexadecimal character
+                if let Some((suffix, suffix_size)) = match integer.len() {
+                    0 => None,
+                    _ => parse_suffix(input, size)?,
+                } {
+                    return Ok(Output::new(
+                        size + suffix_size,
+                        Integer::new_hexadecimal(integer).with_suffix(suffix),
+                    ));
+                } else if Integer::CHARACTERS_HEXADECIMAL.contains(&character) {
                     integer.push(character.to_ascii_lowercase());
                     size += 1;
                 } else if character == Integer::CHARACTER_DELIMITER {
@@ -293,3 +359,28 @@ pub fn parse(input: &str) -> Result<Output, Error> {
         }
     }
 }
+
+///
+/// Parses the integer literal type suffix at the `offset` of `input`, if there is one.
+///
+/// Returns the suffix keyword, that is, an integer or `field` type, and its size. The words
+/// starting with `u` or `i` are invalid suffixes, whereas the other ones are left to the caller.
+///
+fn parse_suffix(input: &str, offset: usize) -> Result<Option<(Keyword, usize)>, Error> {
+    let word: String = input
+        .chars()
+        .skip(offset)
+        .take_while(|character| {
+            character.is_ascii_alphanumeric() || *character == Integer::CHARACTER_DELIMITER
+        })
+        .collect();
+
+    match Keyword::try_from(word.as_str()) {
+        Ok(keyword) if Integer::is_suffix(&keyword) => Ok(Some((keyword, word.len()))),
+        _ if word.starts_with('u') || word.starts_with('i') => Err(Error::InvalidSuffix {
+            found: word,
+            offset,
+        }),
+        _ => Ok(None),
+    }
+}
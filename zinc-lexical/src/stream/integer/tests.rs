@@ -5,6 +5,7 @@
 use super::parse;
 use super::Error;
 use super::Output;
+use crate::token::lexeme::keyword::Keyword;
 use crate::token::lexeme::literal::integer::Integer;
 
 #[test]
@@ -141,6 +142,77 @@ fn ok_hexadecimal_mixed_case() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_binary_with_suffix() {
+    let input = "0b1010_i16";
+    let expected = Ok(Output::new(
+        input.len(),
+        Integer::new_binary("1010".to_owned()).with_suffix(Keyword::new_integer_signed(16)),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_octal_with_suffix() {
+    let input = "0o17u8";
+    let expected = Ok(Output::new(
+        input.len(),
+        Integer::new_octal("17".to_owned()).with_suffix(Keyword::new_integer_unsigned(8)),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_decimal_with_suffix() {
+    let input = "1_000_u64";
+    let expected = Ok(Output::new(
+        input.len(),
+        Integer::new_decimal("1000".to_owned()).with_suffix(Keyword::new_integer_unsigned(64)),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_decimal_with_exponent_and_suffix() {
+    let input = "1.5E3u16;";
+    let expected = Ok(Output::new(
+        input.len() - 1,
+        Integer::new_decimal_with_exponent(
+            "1".to_owned(),
+            Some("5".to_owned()),
+            Some("3".to_owned()),
+        )
+        .with_suffix(Keyword::new_integer_unsigned(16)),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_hexadecimal_with_suffix_field() {
+    let input = "0xff_field";
+    let expected = Ok(Output::new(
+        input.len(),
+        Integer::new_hexadecimal("ff".to_owned()).with_suffix(Keyword::Field),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_hexadecimal_with_suffix_field_without_delimiter() {
+    let input = "0xffield";
+    let expected = Ok(Output::new(
+        input.len(),
+        Integer::new_hexadecimal("f".to_owned()).with_suffix(Keyword::Field),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_not_an_integer() {
     let input = "xyz";
@@ -252,6 +324,28 @@ fn error_expected_one_of_hexadecimal() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_invalid_suffix() {
+    let input = "42u7";
+    let expected = Err(Error::InvalidSuffix {
+        found: "u7".to_owned(),
+        offset: input.len() - 2,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invalid_suffix_hexadecimal() {
+    let input = "0xffu256";
+    let expected = Err(Error::InvalidSuffix {
+        found: "u256".to_owned(),
+        offset: input.len() - 4,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_unexpected_end() {
     let input = "";
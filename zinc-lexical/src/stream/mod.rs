@@ -45,17 +45,22 @@ impl<'a> TokenStream<'a> {
     /// The initial capacity of the look-ahead buffer queue.
     const LOOK_AHEAD_INITIAL_CAPACITY: usize = 16;
 
+    /// The prefix of the optional script interpreter line at the beginning of the input.
+    const SHEBANG: &'static str = "#!";
+
     ///
     /// Initializes a stream with a file identifier.
     /// The file identifier can be used to get its path from the global type index.
     ///
     pub fn new(input: &'a str, file: usize) -> Self {
-        Self {
+        let mut stream = Self {
             input,
             offset: 0,
             location: Location::new(file),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
-        }
+        };
+        stream.skip_shebang();
+        stream
     }
 
     ///
@@ -98,18 +103,72 @@ impl<'a> TokenStream<'a> {
             .ok_or_else(|| Error::unexpected_end(self.location))
     }
 
+    ///
+    /// Returns a reference to the next token without consuming it.
+    ///
+    pub fn peek(&mut self) -> Result<&Token, Error> {
+        self.peek_nth(0)
+    }
+
+    ///
+    /// Returns a reference to the `n`-th next token without consuming it, where `peek_nth(0)` is
+    /// equivalent to `peek()`.
+    ///
+    pub fn peek_nth(&mut self, n: usize) -> Result<&Token, Error> {
+        self.look_ahead(n + 1)
+    }
+
+    ///
+    /// Puts the `token` back to the front of the look-ahead queue, so it is returned by the next
+    /// call to `next` or `peek`.
+    ///
+    /// Is used to give back the token a subparser has taken beyond its grammar rule.
+    ///
+    pub fn put_back(&mut self, token: Token) {
+        self.look_ahead.push_front(token);
+    }
+
     ///
     /// Initializes a stream with an auto-generated file identifier.
     /// The file identifier can be used to get its path from the global type index.
     /// Used for testing purposes.
     ///
     pub fn test(input: &'a str) -> Self {
-        Self {
+        let mut stream = Self {
             input,
             offset: 0,
             location: Location::new(0),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+        };
+        stream.skip_shebang();
+        stream
+    }
+
+    ///
+    /// Skips the optional `#!...` line at the beginning of the input, which allows running the
+    /// source code files as scripts.
+    ///
+    /// The line is not skipped if it starts with an inner attribute like `#![...]`.
+    ///
+    fn skip_shebang(&mut self) {
+        if !self.input.starts_with(Self::SHEBANG) {
+            return;
         }
+
+        if self.input[Self::SHEBANG.len()..]
+            .trim_start()
+            .starts_with('[')
+        {
+            return;
+        }
+
+        let length = self
+            .input
+            .chars()
+            .take_while(|character| *character != '\n')
+            .count();
+        self.offset += length;
+        self.location.column += length;
     }
 
     ///
@@ -221,6 +280,12 @@ impl<'a> TokenStream<'a> {
                             found,
                         ))
                     }
+                    Err(IntegerParserError::InvalidSuffix { found, offset }) => {
+                        return Err(Error::invalid_integer_suffix(
+                            self.location.shifted_right(offset),
+                            found,
+                        ))
+                    }
                     Err(IntegerParserError::UnexpectedEnd) => {
                         return Err(Error::unexpected_end(self.location))
                     }
@@ -82,6 +82,74 @@ let mut c: u8 = 2 + 2;
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_peek() {
+    let input = "contract Test;";
+
+    let mut stream = TokenStream::test(input);
+
+    let expected = Token::new(Lexeme::Keyword(Keyword::Contract), Location::test(1, 1));
+    assert_eq!(stream.peek().map(Token::clone), Ok(expected.clone()));
+    assert_eq!(stream.peek().map(Token::clone), Ok(expected.clone()));
+    assert_eq!(stream.next(), Ok(expected));
+}
+
+#[test]
+fn ok_peek_nth() {
+    let input = "contract Test;";
+
+    let mut stream = TokenStream::test(input);
+
+    assert_eq!(
+        stream.peek_nth(2).map(Token::clone),
+        Ok(Token::new(
+            Lexeme::Symbol(Symbol::Semicolon),
+            Location::test(1, 14)
+        ))
+    );
+    assert_eq!(
+        stream.peek_nth(3).map(Token::clone),
+        Ok(Token::new(Lexeme::Eof, Location::test(1, 15)))
+    );
+    assert_eq!(
+        stream.next(),
+        Ok(Token::new(
+            Lexeme::Keyword(Keyword::Contract),
+            Location::test(1, 1)
+        ))
+    );
+    assert_eq!(
+        stream.peek().map(Token::clone),
+        Ok(Token::new(
+            Lexeme::Identifier(Identifier::new("Test".to_owned())),
+            Location::test(1, 10)
+        ))
+    );
+}
+
+#[test]
+fn ok_put_back() {
+    let input = "contract Test;";
+
+    let mut stream = TokenStream::test(input);
+
+    let first = stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    let second = stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    stream.put_back(second.clone());
+    stream.put_back(first.clone());
+
+    assert_eq!(stream.peek().map(Token::clone), Ok(first.clone()));
+    assert_eq!(stream.next(), Ok(first));
+    assert_eq!(stream.next(), Ok(second));
+    assert_eq!(
+        stream.next(),
+        Ok(Token::new(
+            Lexeme::Symbol(Symbol::Semicolon),
+            Location::test(1, 14)
+        ))
+    );
+}
+
 #[test]
 fn error_unterminated_block_comment() {
     let input = "/*block comment";
@@ -122,6 +190,49 @@ fn error_expected_one_of_binary() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_shebang() {
+    let input = r#"#!/usr/bin/env zargo run
+#![allow(deprecated)]
+"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Number),
+            location: Location::test(2, 1),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::ExclamationMark),
+            location: Location::test(2, 2),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
+            location: Location::test(2, 3),
+        },
+    ];
+
+    let mut stream = TokenStream::test(input);
+    let result = (0..expected.len())
+        .map(|_| stream.next().expect(zinc_const::panic::TEST_DATA_VALID))
+        .collect::<Vec<Token>>();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_shebang_inner_attribute() {
+    let input = r#"#![allow(deprecated)]"#;
+
+    let expected: Result<Token, Error> = Ok(Token {
+        lexeme: Lexeme::Symbol(Symbol::Number),
+        location: Location::test(1, 1),
+    });
+
+    let result = TokenStream::test(input).next();
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_expected_one_of_octal() {
     let input = "0o378";
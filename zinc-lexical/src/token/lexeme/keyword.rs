@@ -34,6 +34,10 @@ pub enum Keyword {
     Impl,
     /// The `contract` declaration keyword.
     Contract,
+    /// The `interface` declaration keyword.
+    Interface,
+    /// The `implements` declaration keyword.
+    Implements,
     /// The `pub` declaration keyword.
     Pub,
 
@@ -49,6 +53,10 @@ pub enum Keyword {
     Else,
     /// The `match` control keyword.
     Match,
+    /// The `return` control keyword.
+    Return,
+    /// The `unsafe_witness` control keyword.
+    UnsafeWitness,
 
     /// The `bool` type keyword.
     Bool,
@@ -88,8 +96,6 @@ pub enum Keyword {
     Ref,
     /// The `extern` reserved keyword.
     Extern,
-    /// The `return` reserved keyword.
-    Return,
     /// The `loop` reserved keyword.
     Loop,
     /// The `break` reserved keyword.
@@ -171,6 +177,8 @@ impl TryFrom<&str> for Keyword {
             "use" => return Ok(Self::Use),
             "impl" => return Ok(Self::Impl),
             "contract" => return Ok(Self::Contract),
+            "interface" => return Ok(Self::Interface),
+            "implements" => return Ok(Self::Implements),
             "pub" => return Ok(Self::Pub),
 
             "for" => return Ok(Self::For),
@@ -179,6 +187,8 @@ impl TryFrom<&str> for Keyword {
             "if" => return Ok(Self::If),
             "else" => return Ok(Self::Else),
             "match" => return Ok(Self::Match),
+            "return" => return Ok(Self::Return),
+            "unsafe_witness" => return Ok(Self::UnsafeWitness),
 
             "bool" => return Ok(Self::Bool),
             "field" => return Ok(Self::Field),
@@ -196,7 +206,6 @@ impl TryFrom<&str> for Keyword {
             "static" => return Ok(Self::Static),
             "ref" => return Ok(Self::Ref),
             "extern" => return Ok(Self::Extern),
-            "return" => return Ok(Self::Return),
             "loop" => return Ok(Self::Loop),
             "break" => return Ok(Self::Break),
             "continue" => return Ok(Self::Continue),
@@ -269,6 +278,8 @@ impl fmt::Display for Keyword {
             Self::Use => write!(f, "use"),
             Self::Impl => write!(f, "impl"),
             Self::Contract => write!(f, "contract"),
+            Self::Interface => write!(f, "interface"),
+            Self::Implements => write!(f, "implements"),
             Self::Pub => write!(f, "pub"),
 
             Self::For => write!(f, "for"),
@@ -277,6 +288,8 @@ impl fmt::Display for Keyword {
             Self::If => write!(f, "if"),
             Self::Else => write!(f, "else"),
             Self::Match => write!(f, "match"),
+            Self::Return => write!(f, "return"),
+            Self::UnsafeWitness => write!(f, "unsafe_witness"),
 
             Self::Bool => write!(f, "bool"),
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
@@ -296,7 +309,6 @@ impl fmt::Display for Keyword {
             Self::Static => write!(f, "static"),
             Self::Ref => write!(f, "ref"),
             Self::Extern => write!(f, "extern"),
-            Self::Return => write!(f, "return"),
             Self::Loop => write!(f, "loop"),
             Self::Break => write!(f, "break"),
             Self::Continue => write!(f, "continue"),
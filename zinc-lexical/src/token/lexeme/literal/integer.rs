@@ -4,6 +4,8 @@
 
 use std::fmt;
 
+use crate::token::lexeme::keyword::Keyword;
+
 ///
 /// The lexical integer literal.
 ///
@@ -13,11 +15,15 @@ pub enum Integer {
     Binary {
         /// The inner literal contents.
         inner: String,
+        /// The optional type suffix, like `u8` in `0xffu8`.
+        suffix: Option<Keyword>,
     },
     /// An octal literal, like `0o52`.
     Octal {
         /// The inner literal contents.
         inner: String,
+        /// The optional type suffix, like `u8` in `0xffu8`.
+        suffix: Option<Keyword>,
     },
     /// An integer literal, like `42`.
     Decimal {
@@ -27,11 +33,15 @@ pub enum Integer {
         fractional: Option<String>,
         /// The optional pseudo-exponent part.
         exponent: Option<String>,
+        /// The optional type suffix, like `u64` in `42u64`.
+        suffix: Option<Keyword>,
     },
     /// A hexadecimal literal, like `0xffff`.
     Hexadecimal {
         /// The inner literal contents.
         inner: String,
+        /// The optional type suffix, like `u8` in `0xffu8`.
+        suffix: Option<Keyword>,
     },
 }
 
@@ -67,14 +77,20 @@ impl Integer {
     /// Creates a binary value.
     ///
     pub fn new_binary(inner: String) -> Self {
-        Self::Binary { inner }
+        Self::Binary {
+            inner,
+            suffix: None,
+        }
     }
 
     ///
     /// Creates an octal value.
     ///
     pub fn new_octal(inner: String) -> Self {
-        Self::Octal { inner }
+        Self::Octal {
+            inner,
+            suffix: None,
+        }
     }
 
     ///
@@ -85,6 +101,7 @@ impl Integer {
             integer,
             fractional: None,
             exponent: None,
+            suffix: None,
         }
     }
 
@@ -100,6 +117,7 @@ impl Integer {
             integer,
             fractional,
             exponent,
+            suffix: None,
         }
     }
 
@@ -107,27 +125,73 @@ impl Integer {
     /// Creates a hexadecimal value.
     ///
     pub fn new_hexadecimal(inner: String) -> Self {
-        Self::Hexadecimal { inner }
+        Self::Hexadecimal {
+            inner,
+            suffix: None,
+        }
+    }
+
+    ///
+    /// Sets the type suffix, which must be an integer or field type keyword.
+    ///
+    pub fn with_suffix(mut self, keyword: Keyword) -> Self {
+        match self {
+            Self::Binary { ref mut suffix, .. }
+            | Self::Octal { ref mut suffix, .. }
+            | Self::Decimal { ref mut suffix, .. }
+            | Self::Hexadecimal { ref mut suffix, .. } => *suffix = Some(keyword),
+        }
+        self
+    }
+
+    ///
+    /// Returns the type suffix, if it has been specified.
+    ///
+    pub fn suffix(&self) -> Option<&Keyword> {
+        match self {
+            Self::Binary { suffix, .. }
+            | Self::Octal { suffix, .. }
+            | Self::Decimal { suffix, .. }
+            | Self::Hexadecimal { suffix, .. } => suffix.as_ref(),
+        }
+    }
+
+    ///
+    /// Checks whether the `keyword` can be used as an integer literal type suffix.
+    ///
+    pub fn is_suffix(keyword: &Keyword) -> bool {
+        matches!(
+            keyword,
+            Keyword::IntegerUnsigned { .. } | Keyword::IntegerSigned { .. } | Keyword::Field
+        )
     }
 }
 
 impl Into<String> for Integer {
     fn into(self) -> String {
-        match self {
-            Self::Binary { inner } => inner,
-            Self::Octal { inner } => inner,
+        let suffix = self
+            .suffix()
+            .map(|suffix| suffix.to_string())
+            .unwrap_or_default();
+
+        let value = match self {
+            Self::Binary { inner, .. } => inner,
+            Self::Octal { inner, .. } => inner,
             Self::Decimal {
                 integer,
                 fractional,
                 exponent,
+                ..
             } => format!(
                 "{}.{}E{}",
                 integer,
                 fractional.unwrap_or_default(),
                 exponent.unwrap_or_default()
             ),
-            Self::Hexadecimal { inner } => inner,
-        }
+            Self::Hexadecimal { inner, .. } => inner,
+        };
+
+        format!("{}{}", value, suffix)
     }
 }
 
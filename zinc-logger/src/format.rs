@@ -0,0 +1,47 @@
+//!
+//! The Zinc logger output format.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The structured logger output format.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// The human-readable lines with the span fields, e.g. the request ID, in front.
+    Pretty,
+    /// The JSON object per line, with the event and current span fields flattened.
+    Json,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Pretty
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            value => Err(format!(
+                "unknown log format `{}`, expected `pretty` or `json`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pretty => write!(f, "pretty"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
@@ -2,8 +2,10 @@
 //! The Zinc logger.
 //!
 
+pub mod format;
 pub mod level;
 
+pub use self::format::Format;
 pub use self::level::Level;
 
 use std::io::Write;
@@ -16,6 +18,9 @@ use colored::Colorize;
 /// The space for the logging level name.
 const LEVEL_NAME_LENGTH: usize = 10;
 
+/// The modules whose logs are printed with the structured logger.
+const STRUCTURED_MODULES: [&str; 3] = ["actix_server", "zandbox", "zinc_vm"];
+
 ///
 /// Initialize logger with custom format and verbosity.
 ///
@@ -73,6 +78,52 @@ pub fn initialize(app_name: &'static str, verbosity: usize, quiet: bool) {
         .init();
 }
 
+///
+/// Initialize the structured logger, which prints the fields of the current spans, e.g. the
+/// server request ID, along with every record.
+///
+/// The `log` records are converted into the `tracing` events, so the dependencies using the
+/// former are printed within the spans as well.
+///
+/// # Arguments
+///
+/// Verbosity is the same as in `initialize`.
+///
+pub fn initialize_structured(verbosity: usize, quiet: bool, format: Format) {
+    let level: LevelFilter = if quiet {
+        LevelFilter::Off
+    } else {
+        Level::from(verbosity).into()
+    };
+    let level = level.to_string().to_ascii_lowercase();
+
+    let filter = STRUCTURED_MODULES.iter().fold(
+        tracing_subscriber::EnvFilter::new(LevelFilter::Off.to_string().to_ascii_lowercase()),
+        |filter, module| {
+            filter.add_directive(
+                format!("{}={}", module, level)
+                    .parse()
+                    .expect(zinc_const::panic::DATA_CONVERSION),
+            )
+        },
+    );
+
+    tracing_log::LogTracer::init().expect(zinc_const::panic::LOGGER);
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr);
+    match format {
+        Format::Pretty => builder.init(),
+        Format::Json => builder
+            .json()
+            .flatten_event(true)
+            .with_current_span(true)
+            .with_span_list(false)
+            .init(),
+    }
+}
+
 ///
 /// The log level string printed to the terminal.
 ///
@@ -8,8 +8,11 @@ pub(crate) mod project;
 pub(crate) mod source;
 
 pub use self::error::Error;
+pub use self::manifest::Build as ManifestBuild;
+pub use self::manifest::CfgValue as ManifestCfgValue;
 pub use self::manifest::Manifest;
 pub use self::manifest::Project as ManifestProject;
+pub use self::manifest::Timings as ManifestTimings;
 pub use self::project::r#type::Type as ProjectType;
 pub use self::project::Project;
 pub use self::source::directory::Directory;
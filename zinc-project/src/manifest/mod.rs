@@ -0,0 +1,314 @@
+//!
+//! The Zinc project manifest file.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::project::r#type::Type as ProjectType;
+
+///
+/// The Zinc project manifest file representation.
+///
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Manifest {
+    /// The `project` section.
+    pub project: Project,
+    /// The `dependencies` section.
+    pub dependencies: Option<HashMap<String, semver::Version>>,
+    /// The `build` section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<Build>,
+    /// The `cfg` section, which is passed to the compiler as the `--cfg key=value` flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<BTreeMap<String, CfgValue>>,
+}
+
+///
+/// The `project` section representation.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Project {
+    /// The project name.
+    pub name: String,
+    /// The project type. See the inner element description.
+    pub r#type: ProjectType,
+    /// The project version in the string format.
+    pub version: semver::Version,
+    /// The project description, which is shown in the project search results.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Project {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, r#type: ProjectType, version: semver::Version) -> Self {
+        Self {
+            name,
+            r#type,
+            version,
+            description: None,
+        }
+    }
+}
+
+///
+/// The `build` section representation.
+///
+/// The section values are passed to each compiler invocation, unless they are overridden
+/// with the command line options.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Build {
+    /// The maximal number of the bytecode instructions, which is checked by the compiler.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_instructions: Option<usize>,
+    /// The compilation timings format, which enables printing the timings if set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<Timings>,
+    /// Whether the release builds skip the range checks proven redundant. Enabled by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_check_elimination: Option<bool>,
+    /// The unknown keys, which are kept, but ignored with a warning.
+    #[serde(flatten)]
+    pub unknown: BTreeMap<String, toml::Value>,
+}
+
+impl Build {
+    ///
+    /// Merges the `overrides`, e.g. the command line options, into the section values.
+    ///
+    /// The `overrides` values take precedence over the section ones.
+    ///
+    pub fn merge(mut self, overrides: Self) -> Self {
+        self.max_instructions = overrides.max_instructions.or(self.max_instructions);
+        self.timings = overrides.timings.or(self.timings);
+        self.range_check_elimination = overrides
+            .range_check_elimination
+            .or(self.range_check_elimination);
+        self
+    }
+
+    ///
+    /// Returns the set values as the `key = value` pairs in the declaration order.
+    ///
+    pub fn values(&self) -> Vec<(&'static str, String)> {
+        let mut values = Vec::with_capacity(3);
+        if let Some(max_instructions) = self.max_instructions {
+            values.push(("max_instructions", max_instructions.to_string()));
+        }
+        if let Some(timings) = self.timings {
+            values.push(("timings", timings.to_string()));
+        }
+        if let Some(range_check_elimination) = self.range_check_elimination {
+            values.push((
+                "range_check_elimination",
+                range_check_elimination.to_string(),
+            ));
+        }
+        values
+    }
+
+    ///
+    /// Returns the unknown key names in the alphabetical order.
+    ///
+    pub fn unknown_keys(&self) -> Vec<&str> {
+        self.unknown.keys().map(String::as_str).collect()
+    }
+}
+
+///
+/// The compilation timings format.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Timings {
+    /// The human-readable table.
+    #[serde(rename = "table")]
+    Table,
+    /// The JSON object.
+    #[serde(rename = "json")]
+    Json,
+}
+
+impl FromStr for Timings {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            value => Err(format!(
+                "invalid timings format `{}`, expected `table` or `json`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Table => write!(f, "table"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+///
+/// The `cfg` section value representation.
+///
+/// A value may be specified for each build profile separately, e.g.
+/// `bound = { debug = 4, release = 1024 }`.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum CfgValue {
+    /// The boolean value.
+    Boolean(bool),
+    /// The integer value.
+    Integer(i64),
+    /// The string value, which is also used for integers too big for TOML.
+    String(String),
+    /// The values of the build profiles.
+    Profiles(BTreeMap<String, CfgValue>),
+}
+
+impl CfgValue {
+    ///
+    /// Returns the stringified value for the build `profile`.
+    ///
+    /// Returns `None` if the value is not specified for the `profile`.
+    ///
+    pub fn resolve(&self, profile: &str) -> Option<String> {
+        match self {
+            Self::Boolean(value) => Some(value.to_string()),
+            Self::Integer(value) => Some(value.to_string()),
+            Self::String(value) => Some(value.to_owned()),
+            Self::Profiles(profiles) => profiles.get(profile).and_then(|value| match value {
+                Self::Profiles(_) => None,
+                value => value.resolve(profile),
+            }),
+        }
+    }
+}
+
+impl Manifest {
+    ///
+    /// Creates a new manifest instance.
+    ///
+    pub fn new(project_name: &str, project_type: ProjectType) -> Self {
+        Self {
+            project: Project {
+                name: project_name.to_owned(),
+                r#type: project_type,
+                version: semver::Version::new(0, 1, 0),
+                description: None,
+            },
+            dependencies: Some(HashMap::new()),
+            build: None,
+            cfg: None,
+        }
+    }
+
+    ///
+    /// Returns the configuration values of the build `profile`, which are passed to the compiler.
+    ///
+    /// The `profile` value itself is always set and cannot be overridden in the `cfg` section.
+    ///
+    pub fn cfg_values(&self, profile: &str) -> BTreeMap<String, String> {
+        let mut values: BTreeMap<String, String> = self
+            .cfg
+            .as_ref()
+            .map(|cfg| {
+                cfg.iter()
+                    .filter_map(|(key, value)| {
+                        value.resolve(profile).map(|value| (key.to_owned(), value))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        values.insert(zinc_const::cfg::PROFILE.to_owned(), profile.to_owned());
+        values
+    }
+
+    ///
+    /// Checks if the manifest exists in the project at the given `path`.
+    ///
+    pub fn exists_at(path: &PathBuf) -> bool {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+        path.exists()
+    }
+
+    ///
+    /// Writes the manifest to a file in the project at the given `path`.
+    ///
+    pub fn write_to(&self, path: &PathBuf) -> anyhow::Result<()> {
+        let mut path = path.to_owned();
+        if path.is_dir() || !path.ends_with(Self::file_name()) {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::create(&path).with_context(|| path.to_string_lossy().to_string())?;
+        file.write_all(
+            toml::to_string_pretty(self)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .as_bytes(),
+        )
+        .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        )
+    }
+}
+
+impl TryFrom<&PathBuf> for Manifest {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::open(&path).with_context(|| path.to_string_lossy().to_string())?;
+        let size = file
+            .metadata()
+            .with_context(|| path.to_string_lossy().to_string())?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(toml::from_str(buffer.as_str()).with_context(|| path.to_string_lossy().to_string())?)
+    }
+}
@@ -0,0 +1,116 @@
+//!
+//! The Zinc project manifest file tests.
+//!
+
+use crate::manifest::Build;
+use crate::manifest::Manifest;
+use crate::manifest::Timings;
+
+const MANIFEST: &str = r#"
+[project]
+name = "test"
+type = "contract"
+version = "0.1.0"
+
+[build]
+max_instructions = 1000
+timings = "json"
+range_check_elimination = false
+"#;
+
+fn build(manifest: &str) -> Build {
+    toml::from_str::<Manifest>(manifest)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .build
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+#[test]
+fn ok_manifest_only() {
+    let build = build(MANIFEST).merge(Build::default());
+
+    assert_eq!(build.max_instructions, Some(1000));
+    assert_eq!(build.timings, Some(Timings::Json));
+    assert_eq!(build.range_check_elimination, Some(false));
+    assert!(build.unknown_keys().is_empty());
+}
+
+#[test]
+fn ok_command_line_overrides() {
+    let overrides = Build {
+        max_instructions: Some(2000),
+        timings: Some(Timings::Table),
+        ..Build::default()
+    };
+
+    let build = build(MANIFEST).merge(overrides);
+
+    assert_eq!(build.max_instructions, Some(2000));
+    assert_eq!(build.timings, Some(Timings::Table));
+    assert_eq!(build.range_check_elimination, Some(false));
+}
+
+#[test]
+fn ok_values() {
+    assert_eq!(
+        build(MANIFEST).values(),
+        vec![
+            ("max_instructions", "1000".to_owned()),
+            ("timings", "json".to_owned()),
+            ("range_check_elimination", "false".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn ok_unknown_keys() {
+    let build = build(
+        r#"
+[project]
+name = "test"
+type = "circuit"
+version = "0.1.0"
+
+[build]
+max_instructions = 1000
+loop_limit = 64
+emit = ["assembly"]
+"#,
+    );
+
+    assert_eq!(build.max_instructions, Some(1000));
+    assert_eq!(build.unknown_keys(), vec!["emit", "loop_limit"]);
+}
+
+#[test]
+fn ok_unknown_keys_written() {
+    let mut manifest =
+        toml::from_str::<Manifest>(MANIFEST).expect(zinc_const::panic::TEST_DATA_VALID);
+    manifest
+        .build
+        .as_mut()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .unknown
+        .insert("loop_limit".to_owned(), toml::Value::Integer(64));
+
+    let written = toml::to_string_pretty(&manifest).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(written.contains("loop_limit = 64"));
+}
+
+#[test]
+fn error_timings_invalid() {
+    assert!(toml::from_str::<Manifest>(
+        r#"
+[project]
+name = "test"
+type = "circuit"
+version = "0.1.0"
+
+[build]
+timings = "xml"
+"#,
+    )
+    .is_err());
+    assert!("xml".parse::<Timings>().is_err());
+}
@@ -17,6 +17,7 @@ pub use self::tree::expression::array::variant::Variant as ArrayExpressionVarian
 pub use self::tree::expression::array::Expression as ArrayExpression;
 pub use self::tree::expression::block::Expression as BlockExpression;
 pub use self::tree::expression::conditional::Expression as ConditionalExpression;
+pub use self::tree::expression::if_let::Expression as IfLetExpression;
 pub use self::tree::expression::list::Expression as ListExpression;
 pub use self::tree::expression::r#match::Expression as MatchExpression;
 pub use self::tree::expression::structure::Expression as StructureExpression;
@@ -25,6 +26,7 @@ pub use self::tree::expression::tree::node::operator::Operator as ExpressionOper
 pub use self::tree::expression::tree::node::Node as ExpressionTreeNode;
 pub use self::tree::expression::tree::Tree as ExpressionTree;
 pub use self::tree::expression::tuple::Expression as TupleExpression;
+pub use self::tree::expression::unsafe_witness::Expression as UnsafeWitnessExpression;
 pub use self::tree::identifier::Identifier;
 pub use self::tree::literal::boolean::Literal as BooleanLiteral;
 pub use self::tree::literal::integer::Literal as IntegerLiteral;
@@ -39,6 +41,8 @@ pub use self::tree::r#type::variant::Variant as TypeVariant;
 pub use self::tree::r#type::Type;
 pub use self::tree::statement::contract::Statement as ContractStatement;
 pub use self::tree::statement::field::Statement as FieldStatement;
+pub use self::tree::statement::interface::method::Method as InterfaceMethod;
+pub use self::tree::statement::interface::Statement as InterfaceStatement;
 pub use self::tree::statement::local_contract::Statement as ContractLocalStatement;
 pub use self::tree::statement::local_fn::Statement as FunctionLocalStatement;
 pub use self::tree::statement::local_impl::Statement as ImplementationLocalStatement;
@@ -50,8 +54,10 @@ pub use self::tree::statement::r#fn::Statement as FnStatement;
 pub use self::tree::statement::r#for::Statement as ForStatement;
 pub use self::tree::statement::r#impl::Statement as ImplStatement;
 pub use self::tree::statement::r#let::Statement as LetStatement;
+pub use self::tree::statement::r#return::Statement as ReturnStatement;
 pub use self::tree::statement::r#struct::Statement as StructStatement;
 pub use self::tree::statement::r#type::Statement as TypeStatement;
 pub use self::tree::statement::r#use::Statement as UseStatement;
+pub use self::tree::statement::r#while::Statement as WhileStatement;
 pub use self::tree::tuple_index::TupleIndex;
 pub use self::tree::variant::Variant;
@@ -6,6 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
 use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
@@ -13,6 +14,7 @@ use zinc_lexical::TokenStream;
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::attribute::list::Parser as AttributeListParser;
+use crate::parser::expression::Parser as ExpressionParser;
 use crate::parser::identifier_path::Parser as IdentifierPathParser;
 use crate::tree::attribute::element::builder::Builder as AttributeElementBuilder;
 use crate::tree::attribute::element::Element as AttributeElement;
@@ -34,7 +36,10 @@ pub enum State {
     Value,
     /// The `#[{identifier}(` has been parsed so far.
     Nested,
-    /// The `#[{identifier}({nested}` has been parsed so far.
+    /// The `#[ensures(`, `#[max_iterations(` or `#[invariant(` has been parsed so far.
+    Expression,
+    /// The `#[{identifier}({nested}`, `#[{identifier}({value}` or `#[ensures({expression}` has
+    /// been parsed so far.
     ParenthesisRight,
 }
 
@@ -55,6 +60,9 @@ pub struct Parser {
     builder: AttributeElementBuilder,
     /// The token returned from a subparser.
     next: Option<Token>,
+    /// If the attribute argument is an expression, e.g. `#[ensures(result > 0)]` or
+    /// `#[max_iterations(SIZE * 2)]`.
+    is_expression: bool,
 }
 
 impl Parser {
@@ -62,6 +70,11 @@ impl Parser {
     /// Parses an attribute.
     ///
     /// 'test(default)'
+    /// 'max_depth(10)'
+    /// 'amount = -1'
+    /// 'ensures(result <= limit)'
+    /// 'max_iterations(SIZE * 2)'
+    /// 'invariant(self.fee <= 10000)'
     ///
     pub fn parse(
         mut self,
@@ -74,15 +87,20 @@ impl Parser {
             match self.state {
                 State::Path => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-                        token
-                        @
-                        Token {
+                        token @ Token {
                             lexeme: Lexeme::Identifier(_),
                             ..
                         } => {
                             self.builder.set_location(token.location);
                             let (path, next) = IdentifierPathParser::default()
                                 .parse(stream.clone(), Some(token))?;
+                            let name = path.to_string();
+                            self.is_expression = name.as_str()
+                                == zinc_const::source::ATTRIBUTE_ENSURES_IDENTIFIER
+                                || name.as_str()
+                                    == zinc_const::source::ATTRIBUTE_MAX_ITERATIONS_IDENTIFIER
+                                || name.as_str()
+                                    == zinc_const::source::ATTRIBUTE_INVARIANT_IDENTIFIER;
                             self.builder.set_path(path);
                             self.next = next;
                             self.state = State::VariantOrBracketSquareRight;
@@ -102,6 +120,12 @@ impl Parser {
                         } => {
                             self.state = State::Value;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } if self.is_expression => {
+                            self.state = State::Expression;
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
                             ..
@@ -112,34 +136,50 @@ impl Parser {
                     }
                 }
                 State::Nested => {
-                    let (nested, next) =
-                        AttributeListParser::default().parse(stream.clone(), self.next.take())?;
-                    self.builder.set_nested(nested);
-                    self.next = next;
-                    self.state = State::ParenthesisRight;
-                }
-                State::Value => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
-                            lexeme: Lexeme::Literal(zinc_lexical::Literal::Boolean(inner)),
+                            lexeme: Lexeme::Literal(literal),
                             location,
                         } => {
-                            self.builder
-                                .set_value(Literal::Boolean(BooleanLiteral::new(location, inner)));
+                            self.builder.set_value(Self::literal(location, literal));
                         }
                         Token {
-                            lexeme: Lexeme::Literal(zinc_lexical::Literal::Integer(inner)),
-                            location,
+                            lexeme: Lexeme::Symbol(Symbol::Minus),
+                            ..
                         } => {
-                            self.builder
-                                .set_value(Literal::Integer(IntegerLiteral::new(location, inner)));
+                            let literal = Self::negative_integer(stream.clone())?;
+                            self.builder.set_negative_value(literal);
                         }
+                        token => {
+                            let (nested, next) = AttributeListParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_nested(nested);
+                            self.next = next;
+                        }
+                    }
+                    self.state = State::ParenthesisRight;
+                }
+                State::Expression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_expression(expression);
+                    self.next = next;
+                    self.state = State::ParenthesisRight;
+                }
+                State::Value => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
-                            lexeme: Lexeme::Literal(zinc_lexical::Literal::String(inner)),
+                            lexeme: Lexeme::Literal(literal),
                             location,
                         } => {
-                            self.builder
-                                .set_value(Literal::String(StringLiteral::new(location, inner)));
+                            self.builder.set_value(Self::literal(location, literal));
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Minus),
+                            ..
+                        } => {
+                            let literal = Self::negative_integer(stream.clone())?;
+                            self.builder.set_negative_value(literal);
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_literal(
@@ -164,6 +204,38 @@ impl Parser {
             }
         }
     }
+
+    ///
+    /// Parses the integer literal following the unary minus.
+    ///
+    fn negative_integer(stream: Rc<RefCell<TokenStream>>) -> Result<IntegerLiteral, ParsingError> {
+        match crate::parser::take_or_next(None, stream)? {
+            Token {
+                lexeme: Lexeme::Literal(zinc_lexical::Literal::Integer(inner)),
+                location,
+            } => Ok(IntegerLiteral::new(location, inner)),
+            Token { lexeme, location } => Err(ParsingError::Syntax(
+                SyntaxError::expected_integer_literal(location, lexeme),
+            )),
+        }
+    }
+
+    ///
+    /// Converts a lexical literal into the syntax one.
+    ///
+    fn literal(location: Location, literal: zinc_lexical::Literal) -> Literal {
+        match literal {
+            zinc_lexical::Literal::Boolean(inner) => {
+                Literal::Boolean(BooleanLiteral::new(location, inner))
+            }
+            zinc_lexical::Literal::Integer(inner) => {
+                Literal::Integer(IntegerLiteral::new(location, inner))
+            }
+            zinc_lexical::Literal::String(inner) => {
+                Literal::String(StringLiteral::new(location, inner))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -180,6 +252,7 @@ mod tests {
     use crate::tree::attribute::element::variant::Variant as AttributeElementVariant;
     use crate::tree::attribute::element::Element as AttributeElement;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
@@ -267,6 +340,33 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_variant_value_integer_negative() {
+        let input = r#"test = -42"#;
+
+        let expected = Ok((
+            AttributeElement::new(
+                Location::test(1, 1),
+                ExpressionTree::new(
+                    Location::test(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 1),
+                        "test".to_owned(),
+                    ))),
+                ),
+                Some(AttributeElementVariant::NegativeValue(IntegerLiteral::new(
+                    Location::test(1, 9),
+                    zinc_lexical::IntegerLiteral::new_decimal("42".to_owned()),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_variant_value_string() {
         let input = r#"test = "default""#;
@@ -296,6 +396,177 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_variant_value_integer_parenthesized() {
+        let input = r#"max_depth(10)"#;
+
+        let expected = Ok((
+            AttributeElement::new(
+                Location::test(1, 1),
+                ExpressionTree::new(
+                    Location::test(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 1),
+                        "max_depth".to_owned(),
+                    ))),
+                ),
+                Some(AttributeElementVariant::Value(Literal::Integer(
+                    IntegerLiteral::new(
+                        Location::test(1, 11),
+                        zinc_lexical::IntegerLiteral::new_decimal("10".to_owned()),
+                    ),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_variant_value_integer_negative_parenthesized() {
+        let input = r#"max_depth(-10)"#;
+
+        let expected = Ok((
+            AttributeElement::new(
+                Location::test(1, 1),
+                ExpressionTree::new(
+                    Location::test(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 1),
+                        "max_depth".to_owned(),
+                    ))),
+                ),
+                Some(AttributeElementVariant::NegativeValue(IntegerLiteral::new(
+                    Location::test(1, 12),
+                    zinc_lexical::IntegerLiteral::new_decimal("10".to_owned()),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_variant_expression() {
+        let input = r#"ensures(result <= limit)"#;
+
+        let expected = Ok((
+            AttributeElement::new(
+                Location::test(1, 1),
+                ExpressionTree::new(
+                    Location::test(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 1),
+                        "ensures".to_owned(),
+                    ))),
+                ),
+                Some(AttributeElementVariant::Expression(
+                    ExpressionTree::new_with_leaves(
+                        Location::test(1, 16),
+                        ExpressionTreeNode::operator(ExpressionOperator::LesserEquals),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 9),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 9), "result".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 19),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 19), "limit".to_owned()),
+                            )),
+                        )),
+                    ),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_variant_expression_max_iterations() {
+        let input = r#"max_iterations(SIZE)"#;
+
+        let expected =
+            Ok((
+                AttributeElement::new(
+                    Location::test(1, 1),
+                    ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "max_iterations".to_owned()),
+                        )),
+                    ),
+                    Some(AttributeElementVariant::Expression(ExpressionTree::new(
+                        Location::test(1, 16),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 16), "SIZE".to_owned()),
+                        )),
+                    ))),
+                ),
+                None,
+            ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_variant_expression_invariant() {
+        let input = r#"invariant(balanced)"#;
+
+        let expected =
+            Ok((
+                AttributeElement::new(
+                    Location::test(1, 1),
+                    ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "invariant".to_owned()),
+                        )),
+                    ),
+                    Some(AttributeElementVariant::Expression(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 11), "balanced".to_owned()),
+                        )),
+                    ))),
+                ),
+                None,
+            ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_integer_literal_negative() {
+        let input = r#"test = -true"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_integer_literal(
+            Location::test(1, 9),
+            Lexeme::Literal(zinc_lexical::Literal::Boolean(
+                zinc_lexical::BooleanLiteral::True,
+            )),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_parenthesis_right() {
         let input = r#"test(default]"#;
@@ -20,6 +20,10 @@ use crate::tree::attribute::Attribute;
 
 use self::list::Parser as ElementListParser;
 
+/// The misplaced inner attribute error hint.
+pub static HINT_INNER_MISPLACED: &str =
+    "inner attributes `#![...]` are only allowed at the beginning of the file";
+
 ///
 /// The parser state.
 ///
@@ -50,6 +54,8 @@ impl Default for State {
 pub struct Parser {
     /// The parser state.
     state: State,
+    /// Whether the inner form `#![...]` is allowed, which is only true at the beginning of a file.
+    is_inner_allowed: bool,
     /// The builder of the parsed value.
     builder: AttributeBuilder,
     /// The token returned from a subparser.
@@ -57,6 +63,16 @@ pub struct Parser {
 }
 
 impl Parser {
+    ///
+    /// Initializes a parser, which also accepts the inner form `#![...]`.
+    ///
+    pub fn new_inner() -> Self {
+        Self {
+            is_inner_allowed: true,
+            ..Self::default()
+        }
+    }
+
     ///
     /// Parses an attribute.
     ///
@@ -95,9 +111,20 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::ExclamationMark),
                             ..
-                        } => {
+                        } if self.is_inner_allowed => {
                             self.builder.set_inner();
                         }
+                        Token {
+                            lexeme: lexeme @ Lexeme::Symbol(Symbol::ExclamationMark),
+                            location,
+                        } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["["],
+                                lexeme,
+                                Some(HINT_INNER_MISPLACED),
+                            )));
+                        }
                         token => {
                             self.next = Some(token);
                         }
@@ -371,6 +398,22 @@ mod tests {
             None,
         ));
 
+        let result = Parser::new_inner().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_inner_misplaced() {
+        let input = r#"#![test]"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 2),
+            vec!["["],
+            Lexeme::Symbol(Symbol::ExclamationMark),
+            Some(super::HINT_INNER_MISPLACED),
+        )));
+
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
 
         assert_eq!(result, expected);
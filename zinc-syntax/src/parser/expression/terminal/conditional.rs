@@ -15,12 +15,14 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::parser::expression::Parser as ExpressionParser;
+use crate::parser::pattern_match::Parser as MatchPatternParser;
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::conditional::builder::Builder as ConditionalExpressionBuilder;
-use crate::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::tree::expression::if_let::Expression as IfLetExpression;
 use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
 /// The parser state.
@@ -30,6 +32,12 @@ pub enum State {
     /// The initial state.
     KeywordIf,
     /// The `if` has been parsed so far.
+    KeywordLetOrCondition,
+    /// The `if let` has been parsed so far.
+    Pattern,
+    /// The `if let {pattern}` has been parsed so far.
+    Equals,
+    /// The `if` or `if let {pattern} =` has been parsed so far.
     Condition,
     /// The `if {expression}` has been parsed so far.
     MainBlock,
@@ -56,11 +64,13 @@ pub struct Parser {
     next: Option<Token>,
     /// The builder of the parsed value.
     builder: ConditionalExpressionBuilder,
+    /// The `if let` pattern, which makes the parsed value a pattern conditional expression.
+    pattern: Option<MatchPattern>,
 }
 
 impl Parser {
     ///
-    /// Parses a conditional expression.
+    /// Parses a conditional or pattern conditional expression.
     ///
     /// '
     /// if a > b {
@@ -70,11 +80,19 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// if let State::Idle = state {
+    ///     a
+    /// } else {
+    ///     b
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
-    ) -> Result<(ConditionalExpression, Option<Token>), ParsingError> {
+    ) -> Result<(ExpressionOperand, Option<Token>), ParsingError> {
         self.next = initial;
 
         loop {
@@ -86,7 +104,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
-                            self.state = State::Condition;
+                            self.state = State::KeywordLetOrCondition;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
@@ -98,6 +116,41 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordLetOrCondition => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Let),
+                            ..
+                        } => self.state = State::Pattern,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::Condition;
+                        }
+                    }
+                }
+                State::Pattern => {
+                    let (pattern, next) =
+                        MatchPatternParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.pattern = Some(pattern);
+                    self.state = State::Equals;
+                }
+                State::Equals => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Condition,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["="],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::Condition => {
                     let (expression, next) =
                         ExpressionParser::default().parse(stream.clone(), self.next.take())?;
@@ -118,7 +171,7 @@ impl Parser {
                             lexeme: Lexeme::Keyword(Keyword::Else),
                             ..
                         } => self.state = State::KeywordIfOrElseBlock,
-                        token => return Ok((self.builder.finish(), Some(token))),
+                        token => return Ok((self.finish(), Some(token))),
                     }
                 }
                 State::KeywordIfOrElseBlock => {
@@ -129,19 +182,18 @@ impl Parser {
                             lexeme: Lexeme::Keyword(Keyword::If),
                             ..
                         } => {
-                            let (expression, next) = Self::default().parse(stream, Some(token))?;
+                            let location = token.location;
+                            let (operand, next) = Self::default().parse(stream, Some(token))?;
                             let block = BlockExpression::new(
-                                expression.location,
+                                location,
                                 vec![],
                                 Some(ExpressionTree::new(
-                                    expression.location,
-                                    ExpressionTreeNode::operand(ExpressionOperand::Conditional(
-                                        expression,
-                                    )),
+                                    location,
+                                    ExpressionTreeNode::operand(operand),
                                 )),
                             );
                             self.builder.set_else_block(block);
-                            Ok((self.builder.finish(), next))
+                            Ok((self.finish(), next))
                         }
                         token
                         @
@@ -153,7 +205,7 @@ impl Parser {
                                 BlockExpressionParser::default().parse(stream, Some(token))?;
                             self.next = next;
                             self.builder.set_else_block(block);
-                            Ok((self.builder.finish(), None))
+                            Ok((self.finish(), None))
                         }
                         Token { lexeme, location } => Err(ParsingError::Syntax(
                             SyntaxError::expected_one_of(location, vec!["if", "{"], lexeme, None),
@@ -163,6 +215,25 @@ impl Parser {
             }
         }
     }
+
+    ///
+    /// Finalizes the builder and returns the conditional expression, which is a pattern one,
+    /// if the `if let` pattern has been parsed.
+    ///
+    fn finish(self) -> ExpressionOperand {
+        let conditional = self.builder.finish();
+
+        match self.pattern {
+            Some(pattern) => ExpressionOperand::IfLet(IfLetExpression::new(
+                conditional.location,
+                pattern,
+                *conditional.condition,
+                conditional.main_block,
+                conditional.else_block,
+            )),
+            None => ExpressionOperand::Conditional(conditional),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -179,18 +250,22 @@ mod tests {
     use crate::error::ParsingError;
     use crate::tree::expression::block::Expression as BlockExpression;
     use crate::tree::expression::conditional::Expression as ConditionalExpression;
+    use crate::tree::expression::if_let::Expression as IfLetExpression;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
     use crate::tree::literal::boolean::Literal as BooleanLiteral;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
+    use crate::tree::pattern_match::Pattern as MatchPattern;
 
     #[test]
     fn ok_nested() {
         let input = r#"if true { 1 } else if false { 2 } else { 3 }"#;
 
         let expected = Ok((
-            ConditionalExpression::new(
+            ExpressionOperand::Conditional(ConditionalExpression::new(
                 Location::test(1, 1),
                 ExpressionTree::new(
                     Location::test(1, 4),
@@ -258,7 +333,60 @@ mod tests {
                         )),
                     )),
                 )),
-            ),
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_if_let() {
+        let input = r#"if let a = b { a } else { 0 }"#;
+
+        let expected = Ok((
+            ExpressionOperand::IfLet(IfLetExpression::new(
+                Location::test(1, 1),
+                MatchPattern::new(
+                    Location::test(1, 8),
+                    MatchPatternVariant::new_binding(Identifier::new(
+                        Location::test(1, 8),
+                        "a".to_owned(),
+                    )),
+                ),
+                ExpressionTree::new(
+                    Location::test(1, 12),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 12),
+                        "b".to_owned(),
+                    ))),
+                ),
+                BlockExpression::new(
+                    Location::test(1, 14),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::test(1, 16),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 16), "a".to_owned()),
+                        )),
+                    )),
+                ),
+                Some(BlockExpression::new(
+                    Location::test(1, 25),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::test(1, 27),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 27),
+                                LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                            ),
+                        )),
+                    )),
+                )),
+            )),
             None,
         ));
 
@@ -267,6 +395,23 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_equals() {
+        let input = r#"if let a == b { a }"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 10),
+                vec!["="],
+                Lexeme::Symbol(Symbol::DoubleEquals),
+                None,
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"if true { 42 } else ("#;
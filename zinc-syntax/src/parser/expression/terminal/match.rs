@@ -32,7 +32,9 @@ pub enum State {
     /// The `match {expression} {` has been parsed so far.
     BracketCurlyRightOrBranchPattern,
     /// The `match {expression} { {pattern}` has been parsed so far.
-    Select,
+    VerticalBarOrSelect,
+    /// The `match {expression} { {pattern} |` has been parsed so far.
+    BranchPatternAlternative,
     /// The `match {expression} { {pattern} =>` has been parsed so far.
     BranchExpression,
     /// The `match {expression} { {pattern} => {expression}` has been parsed so far.
@@ -65,7 +67,7 @@ impl Parser {
     /// '
     /// match value {
     ///     1 => value * 5,
-    ///     2 => value * 10,
+    ///     2 | 3 => value * 10,
     ///     another => another - 1,
     /// }
     /// '
@@ -134,12 +136,16 @@ impl Parser {
                                 MatchPatternParser::default().parse(stream.clone(), Some(token))?;
                             self.next = next;
                             self.builder.push_branch_pattern(pattern);
-                            self.state = State::Select;
+                            self.state = State::VerticalBarOrSelect;
                         }
                     }
                 }
-                State::Select => {
+                State::VerticalBarOrSelect => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            ..
+                        } => self.state = State::BranchPatternAlternative,
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::EqualsGreater),
                             ..
@@ -147,13 +153,20 @@ impl Parser {
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
                                 location,
-                                vec!["=>"],
+                                vec!["|", "=>"],
                                 lexeme,
                                 None,
                             )));
                         }
                     }
                 }
+                State::BranchPatternAlternative => {
+                    let (pattern, next) =
+                        MatchPatternParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.push_branch_pattern_alternative(pattern);
+                    self.state = State::VerticalBarOrSelect;
+                }
                 State::BranchExpression => {
                     let (expression, next) =
                         ExpressionParser::default().parse(stream.clone(), self.next.take())?;
@@ -362,6 +375,80 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_or_pattern() {
+        let input = r#"
+    match test {
+        1 | 2 => 1,
+        _ => 2,
+    }
+"#;
+        let expected = Ok((
+            MatchExpression::new(
+                Location::test(2, 5),
+                ExpressionTree::new(
+                    Location::test(2, 11),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(2, 11),
+                        "test".to_owned(),
+                    ))),
+                ),
+                vec![
+                    (
+                        MatchPattern::new(
+                            Location::test(3, 9),
+                            MatchPatternVariant::new_or(vec![
+                                MatchPattern::new(
+                                    Location::test(3, 9),
+                                    MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                                        Location::test(3, 9),
+                                        LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                    )),
+                                ),
+                                MatchPattern::new(
+                                    Location::test(3, 13),
+                                    MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                                        Location::test(3, 13),
+                                        LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                                    )),
+                                ),
+                            ]),
+                        ),
+                        ExpressionTree::new(
+                            Location::test(3, 18),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(3, 18),
+                                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                    (
+                        MatchPattern::new(
+                            Location::test(4, 9),
+                            MatchPatternVariant::new_wildcard(),
+                        ),
+                        ExpressionTree::new(
+                            Location::test(4, 14),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(4, 14),
+                                    LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_curly_left() {
         let input = r#"match 42 * 2 )"#;
@@ -386,7 +473,7 @@ mod tests {
         let expected: Result<_, ParsingError> =
             Err(ParsingError::Syntax(SyntaxError::expected_one_of(
                 Location::test(1, 22),
-                vec!["=>"],
+                vec!["|", "=>"],
                 Lexeme::Symbol(Symbol::MinusGreater),
                 None,
             )));
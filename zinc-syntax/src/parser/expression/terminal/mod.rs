@@ -8,6 +8,7 @@ pub mod conditional;
 pub mod list;
 pub mod r#match;
 pub mod tuple;
+pub mod unsafe_witness;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -34,6 +35,7 @@ use self::block::Parser as BlockExpressionParser;
 use self::conditional::Parser as ConditionalExpressionParser;
 use self::r#match::Parser as MatchExpressionParser;
 use self::tuple::Parser as TupleExpressionParser;
+use self::unsafe_witness::Parser as UnsafeWitnessExpressionParser;
 
 ///
 /// The terminal operand parser.
@@ -52,6 +54,7 @@ impl Parser {
     /// - array
     /// - conditional
     /// - match
+    /// - unsafe witness
     /// - alias (`crate`, `super`, `Self`, `self`)
     /// - identifier
     /// - literal (boolean, integer, string)
@@ -110,9 +113,7 @@ impl Parser {
                     let location = token.location;
                     ConditionalExpressionParser::default()
                         .parse(stream, Some(token))
-                        .map(|(operand, token)| {
-                            (ExpressionOperand::Conditional(operand), location, token)
-                        })
+                        .map(|(operand, token)| (operand, location, token))
                 }
                 token
                 @
@@ -127,6 +128,19 @@ impl Parser {
                             (ExpressionOperand::Match(operand), location, token)
                         })
                 }
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::UnsafeWitness),
+                    ..
+                } => {
+                    let location = token.location;
+                    UnsafeWitnessExpressionParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(operand, token)| {
+                            (ExpressionOperand::UnsafeWitness(operand), location, token)
+                        })
+                }
                 Token {
                     lexeme: Lexeme::Keyword(keyword @ Keyword::Crate),
                     location,
@@ -0,0 +1,156 @@
+//!
+//! The `unsafe_witness` expression parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::tree::expression::unsafe_witness::builder::Builder as UnsafeWitnessExpressionBuilder;
+use crate::tree::expression::unsafe_witness::Expression as UnsafeWitnessExpression;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordUnsafeWitness,
+    /// The `unsafe_witness` has been parsed so far.
+    BlockExpression,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordUnsafeWitness
+    }
+}
+
+///
+/// The `unsafe_witness` expression parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+    /// The builder of the parsed value.
+    builder: UnsafeWitnessExpressionBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses an `unsafe_witness` expression.
+    ///
+    /// '
+    /// unsafe_witness {
+    ///     std::math::sqrt(value)
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(UnsafeWitnessExpression, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordUnsafeWitness => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::UnsafeWitness),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::BlockExpression;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["unsafe_witness"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BlockExpression => {
+                    let (block, next) =
+                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_block(block);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Identifier as LexicalIdentifier;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::expression::unsafe_witness::Expression as UnsafeWitnessExpression;
+    use crate::tree::identifier::Identifier;
+
+    #[test]
+    fn ok() {
+        let input = r#"unsafe_witness { value }"#;
+
+        let expected = Ok((
+            UnsafeWitnessExpression::new(
+                Location::test(1, 1),
+                BlockExpression::new(
+                    Location::test(1, 16),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::test(1, 18),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 18), "value".to_owned()),
+                        )),
+                    )),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"unsafe_witness value"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 16),
+            vec!["{"],
+            Lexeme::Identifier(LexicalIdentifier::new("value".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -20,10 +20,12 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
 use crate::error::ParsingError;
+use crate::parser::attribute::Parser as AttributeParser;
 use crate::parser::statement::local_mod::Parser as ModuleLocalStatementParser;
 use crate::tree::module::Module;
 
@@ -38,11 +40,44 @@ pub struct Parser {
 
 impl Parser {
     ///
-    /// Parses a list of module level statements.
+    /// Parses a list of module inner attributes and module level statements.
+    ///
+    /// The inner attributes `#![...]` are only allowed before the first statement.
     ///
     pub fn parse(mut self, input: &str, file: usize) -> Result<Module, ParsingError> {
         let stream = TokenStream::new(input, file).wrap();
 
+        let mut attributes = Vec::new();
+        loop {
+            match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Number),
+                    ..
+                } => {
+                    let look_ahead = stream.borrow_mut().look_ahead(1)?.to_owned();
+                    if let Token {
+                        lexeme: Lexeme::Symbol(Symbol::ExclamationMark),
+                        ..
+                    } = look_ahead
+                    {
+                        let (attribute, next) =
+                            AttributeParser::new_inner().parse(stream.clone(), Some(token))?;
+                        self.next = next;
+                        attributes.push(attribute);
+                    } else {
+                        self.next = Some(token);
+                        break;
+                    }
+                }
+                token => {
+                    self.next = Some(token);
+                    break;
+                }
+            }
+        }
+
         let mut statements = Vec::new();
         loop {
             match crate::parser::take_or_next(self.next.take(), stream.clone())? {
@@ -59,7 +94,7 @@ impl Parser {
             }
         }
 
-        Ok(Module::new(statements))
+        Ok(Module::new(file, attributes, statements))
     }
 }
 
@@ -75,3 +110,70 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+///
+/// Puts the `token` returned from a subparser back to the `stream`, if it is `Some(_)`.
+///
+/// Is used by the parsers, which decide on their next step by peeking the stream.
+///
+pub fn put_back(token: Option<Token>, stream: Rc<RefCell<TokenStream>>) {
+    if let Some(token) = token {
+        stream.borrow_mut().put_back(token);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+
+    #[test]
+    fn ok_shebang_inner_attributes() {
+        let input = r#"#!/usr/bin/env zargo run
+#![allow(deprecated)]
+#![zinc(version = "0.2")]
+
+#[test]
+fn main() {}
+"#;
+
+        let result = Parser::default()
+            .parse(input, 0)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(
+            result
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.location, attribute.is_inner))
+                .collect::<Vec<(Location, bool)>>(),
+            vec![(Location::test(2, 1), true), (Location::test(3, 1), true)]
+        );
+        assert_eq!(result.statements.len(), 1);
+    }
+
+    #[test]
+    fn error_inner_attribute_misplaced() {
+        let input = r#"
+fn main() {}
+
+#![allow(deprecated)]
+"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(4, 2),
+            vec!["["],
+            Lexeme::Symbol(Symbol::ExclamationMark),
+            Some(crate::parser::attribute::HINT_INNER_MISPLACED),
+        )));
+
+        let result = Parser::default().parse(input, 0);
+
+        assert_eq!(result, expected);
+    }
+}
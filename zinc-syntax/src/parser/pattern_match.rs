@@ -33,6 +33,10 @@ pub enum State {
     PathOperatorOrEnd,
     /// The first path operand and a `::` path operator have been parsed so far.
     PathOperand,
+    /// The `(` or `(` with some tuple elements and `,` have been parsed so far.
+    TupleElementOrParenthesisRight,
+    /// The `(` with some tuple elements have been parsed so far.
+    TupleCommaOrParenthesisRight,
 }
 
 impl Default for State {
@@ -62,6 +66,7 @@ impl Parser {
     /// '42'
     /// 'variable'
     /// 'Path::To::Item'
+    /// '(State::Idle, true, value)'
     /// '_'
     ///
     pub fn parse(
@@ -131,6 +136,14 @@ impl Parser {
                             self.builder.set_wildcard();
                             return Ok((self.builder.finish(), None));
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_tuple();
+                            self.state = State::TupleElementOrParenthesisRight;
+                        }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_match_pattern(
                                 location, lexeme,
@@ -158,6 +171,41 @@ impl Parser {
                     self.builder.push_path_element(expression);
                     self.state = State::PathOperatorOrEnd;
                 }
+                State::TupleElementOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (element, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_tuple_element(element);
+                            self.state = State::TupleCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::TupleCommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TupleElementOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
             }
         }
     }
@@ -170,10 +218,13 @@ mod tests {
     use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
     use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -321,6 +372,67 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_tuple() {
+        let input = r#"(true, (42, value), _)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::BooleanLiteral(BooleanLiteral::new(
+                            Location::test(1, 2),
+                            LexicalBooleanLiteral::r#true(),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 8),
+                        MatchPatternVariant::Tuple(vec![
+                            MatchPattern::new(
+                                Location::test(1, 9),
+                                MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                    Location::test(1, 9),
+                                    LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                                )),
+                            ),
+                            MatchPattern::new(
+                                Location::test(1, 13),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 13),
+                                    "value".to_owned(),
+                                )),
+                            ),
+                        ]),
+                    ),
+                    MatchPattern::new(Location::test(1, 21), MatchPatternVariant::Wildcard),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_tuple_expected_comma_or_parenthesis_right() {
+        let input = r#"(true; false)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec![",", ")"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = r#"_"#;
@@ -13,14 +13,17 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::identifier_path::Parser as IdentifierPathParser;
 use crate::parser::statement::local_contract::Parser as ContractLocalStatementParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::contract::builder::Builder as ContractStatementBuilder;
-use crate::tree::statement::contract::Statement as ContractStatement;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "contract must have an identifier, e.g. `contract Uniswap { ... }`";
+/// The missing interface list error hint.
+pub static HINT_EXPECTED_INTERFACE: &str =
+    "contract must list the implemented interfaces, e.g. `contract Uniswap implements Exchange { ... }`";
 
 ///
 /// The parser state.
@@ -32,7 +35,12 @@ pub enum State {
     /// The `contract` has been parsed so far.
     Identifier,
     /// The `contract {identifier}` has been parsed so far.
-    BracketCurlyLeftOrEnd,
+    KeywordImplementsOrBracketCurlyLeftOrEnd,
+    /// The `contract {identifier} implements` or `contract {identifier} implements {path},`
+    /// has been parsed so far.
+    InterfacePath,
+    /// The `contract {identifier} implements {path}` has been parsed so far.
+    CommaOrBracketCurlyLeft,
     /// The `contract {identifier} {` has been parsed so far.
     StatementOrBracketCurlyRight,
 }
@@ -52,8 +60,6 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: ContractStatementBuilder,
-    /// The token returned from a subparser.
-    next: Option<Token>,
 }
 
 impl Parser {
@@ -61,7 +67,7 @@ impl Parser {
     /// Parses a 'contract' statement.
     ///
     /// '
-    /// contract Uniswap {
+    /// contract Uniswap implements Exchange {
     ///     ...
     /// }
     /// '
@@ -70,13 +76,14 @@ impl Parser {
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
-    ) -> Result<(ContractStatement, Option<Token>), ParsingError> {
-        self.next = initial;
+    ) -> Result<(ContractStatementBuilder, Option<Token>), ParsingError> {
+        crate::parser::put_back(initial, stream.clone());
 
         loop {
             match self.state {
                 State::KeywordContract => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Contract),
                             location,
@@ -95,14 +102,15 @@ impl Parser {
                     }
                 }
                 State::Identifier => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeftOrEnd;
+                            self.state = State::KeywordImplementsOrBracketCurlyLeftOrEnd;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -113,27 +121,82 @@ impl Parser {
                         }
                     }
                 }
-                State::BracketCurlyLeftOrEnd => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                State::KeywordImplementsOrBracketCurlyLeftOrEnd => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Implements),
+                            ..
+                        } => {
+                            self.state = State::InterfacePath;
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
                         } => {
                             self.state = State::StatementOrBracketCurlyRight;
                         }
-                        token => return Ok((self.builder.finish(), Some(token))),
+                        token => return Ok((self.builder, Some(token))),
                     }
                 }
-                State::StatementOrBracketCurlyRight => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                State::InterfacePath => {
+                    let token = stream.borrow_mut().peek()?.to_owned();
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Identifier(_),
+                            ..
+                        } => {
+                            let (path, next) =
+                                IdentifierPathParser::default().parse(stream.clone(), None)?;
+                            crate::parser::put_back(next, stream.clone());
+                            self.builder.push_implements(path);
+                            self.state = State::CommaOrBracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_INTERFACE),
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrBracketCurlyLeft => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.state = State::InterfacePath;
+                        }
                         Token {
-                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
-                        } => return Ok((self.builder.finish(), None)),
-                        token => {
+                        } => {
+                            self.state = State::StatementOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StatementOrBracketCurlyRight => {
+                    let lexeme = stream.borrow_mut().peek()?.lexeme.clone();
+                    match lexeme {
+                        Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                            stream.borrow_mut().next()?;
+                            return Ok((self.builder, None));
+                        }
+                        _ => {
                             let (statement, next) = ContractLocalStatementParser::default()
-                                .parse(stream.clone(), Some(token))?;
-                            self.next = next;
+                                .parse(stream.clone(), None)?;
+                            crate::parser::put_back(next, stream.clone());
                             self.builder.push_statement(statement);
                         }
                     }
@@ -146,6 +209,7 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -183,11 +247,15 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
                 vec![],
+                vec![],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -203,6 +271,8 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
                 vec![],
+                vec![],
+                vec![],
             ),
             Some(Token::new(
                 Lexeme::Symbol(Symbol::Semicolon),
@@ -210,7 +280,46 @@ mod tests {
             )),
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_implements() {
+        let input = r#"
+    contract Test implements First, Second {}
+"#;
+
+        let expected = Ok((
+            ContractStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![
+                    ExpressionTree::new(
+                        Location::test(2, 30),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(2, 30), "First".to_owned()),
+                        )),
+                    ),
+                    ExpressionTree::new(
+                        Location::test(2, 37),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(2, 37), "Second".to_owned()),
+                        )),
+                    ),
+                ],
+                vec![],
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -227,17 +336,21 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![ContractLocalStatement::Field(FieldStatement::new(
                     Location::test(3, 9),
                     false,
                     Identifier::new(Location::test(3, 9), "a".to_owned()),
                     Type::new(Location::test(3, 12), TypeVariant::integer_unsigned(232)),
                 ))],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -256,6 +369,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(3, 9),
@@ -276,11 +390,14 @@ mod tests {
                         Type::new(Location::test(5, 16), TypeVariant::integer_unsigned(232)),
                     )),
                 ],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -297,6 +414,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![ContractLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
@@ -311,11 +429,14 @@ mod tests {
                         )),
                     ),
                 ))],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -334,6 +455,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![
                     ContractLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -378,11 +500,14 @@ mod tests {
                         ),
                     )),
                 ],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -399,6 +524,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![ContractLocalStatement::Fn(FnStatement::new(
                     Location::test(3, 9),
                     false,
@@ -419,11 +545,14 @@ mod tests {
                     BlockExpression::new(Location::test(3, 33), vec![], None),
                     vec![],
                 ))],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -444,6 +573,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![
                     ContractLocalStatement::Fn(FnStatement::new(
                         Location::test(3, 9),
@@ -506,11 +636,14 @@ mod tests {
                         vec![],
                     )),
                 ],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -531,6 +664,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(3, 9),
@@ -573,11 +707,14 @@ mod tests {
                         vec![],
                     )),
                 ],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -606,6 +743,7 @@ mod tests {
             ContractStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 14), "Test".to_owned()),
+                vec![],
                 vec![
                     ContractLocalStatement::Field(FieldStatement::new(
                         Location::test(3, 9),
@@ -728,13 +866,92 @@ mod tests {
                         vec![],
                     )),
                 ],
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_empty_with_semicolon_stream_position() {
+        let input = r#"contract Test; contract Other {}"#;
+
+        let stream = TokenStream::test(input).wrap();
+        let result = Parser::default()
+            .parse(stream.clone(), None)
+            .map(|(_statement, next)| next);
+
+        assert_eq!(
+            result,
+            Ok(Some(Token::new(
+                Lexeme::Symbol(Symbol::Semicolon),
+                Location::test(1, 14),
+            )))
+        );
+        assert_eq!(
+            stream.borrow_mut().next(),
+            Ok(Token::new(
+                Lexeme::Keyword(Keyword::Contract),
+                Location::test(1, 16),
+            ))
+        );
+    }
+
+    #[test]
+    fn ok_empty_with_brackets_stream_position() {
+        let input = r#"contract Test {} contract Other;"#;
+
+        let expected = Ok((
+            ContractStatement::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 10), "Test".to_owned()),
+                vec![],
+                vec![],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let stream = TokenStream::test(input).wrap();
+        let result = Parser::default()
+            .parse(stream.clone(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            stream.borrow_mut().next(),
+            Ok(Token::new(
+                Lexeme::Keyword(Keyword::Contract),
+                Location::test(1, 18),
+            ))
+        );
+    }
+
+    #[test]
+    fn ok_initial_token() {
+        let input = r#"contract Test implements First {}"#;
+
+        let stream = TokenStream::test(input).wrap();
+        let initial = stream.borrow_mut().next().ok();
+        let result = Parser::default()
+            .parse(stream.clone(), initial)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        let expected = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
+        assert_eq!(
+            stream.borrow_mut().next(),
+            Ok(Token::new(Lexeme::Eof, Location::test(1, 34)))
+        );
     }
 
     #[test]
@@ -747,7 +964,9 @@ mod tests {
             Some(super::HINT_EXPECTED_IDENTIFIER),
         )));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -762,7 +981,26 @@ mod tests {
             Some(crate::parser::statement::field::HINT_EXPECTED_IDENTIFIER),
         )));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_interface() {
+        let input = r#"contract Test implements { a: u8 };"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 26),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_INTERFACE),
+        )));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -16,7 +16,6 @@ use crate::error::ParsingError;
 use crate::parser::variant_list::Parser as VariantListParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#enum::builder::Builder as EnumStatementBuilder;
-use crate::tree::statement::r#enum::Statement as EnumStatement;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
@@ -74,7 +73,7 @@ impl Parser {
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
-    ) -> Result<(EnumStatement, Option<Token>), ParsingError> {
+    ) -> Result<(EnumStatementBuilder, Option<Token>), ParsingError> {
         self.next = initial;
 
         loop {
@@ -123,7 +122,7 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
                         } => self.state = State::VariantList,
-                        token => return Ok((self.builder.finish(), Some(token))),
+                        token => return Ok((self.builder, Some(token))),
                     }
                 }
                 State::VariantList => {
@@ -138,7 +137,7 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
                             ..
-                        } => Ok((self.builder.finish(), None)),
+                        } => Ok((self.builder, None)),
                         Token { lexeme, location } => Err(ParsingError::Syntax(
                             SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
                         )),
@@ -177,11 +176,14 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
                 vec![],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -197,6 +199,7 @@ mod tests {
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
                 vec![],
+                vec![],
             ),
             Some(Token::new(
                 Lexeme::Symbol(Symbol::Semicolon),
@@ -204,7 +207,9 @@ mod tests {
             )),
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -229,11 +234,14 @@ mod tests {
                         LexicalIntegerLiteral::new_decimal("1".to_owned()),
                     ),
                 )],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -278,11 +286,14 @@ mod tests {
                         ),
                     ),
                 ],
+                vec![],
             ),
             None,
         ));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -297,7 +308,9 @@ mod tests {
             Some(super::HINT_EXPECTED_IDENTIFIER),
         )));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
@@ -313,7 +326,9 @@ mod tests {
             None,
         )));
 
-        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
 
         assert_eq!(result, expected);
     }
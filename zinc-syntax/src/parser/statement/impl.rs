@@ -52,8 +52,6 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: ImplStatementBuilder,
-    /// The token returned from a subparser.
-    next: Option<Token>,
 }
 
 impl Parser {
@@ -73,12 +71,13 @@ impl Parser {
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
     ) -> Result<(ImplStatement, Option<Token>), ParsingError> {
-        self.next = initial;
+        crate::parser::put_back(initial, stream.clone());
 
         loop {
             match self.state {
                 State::KeywordImpl => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Impl),
                             location,
@@ -97,7 +96,8 @@ impl Parser {
                     }
                 }
                 State::Identifier => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
@@ -116,7 +116,8 @@ impl Parser {
                     }
                 }
                 State::BracketCurlyLeft => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
@@ -127,15 +128,16 @@ impl Parser {
                     }
                 }
                 State::StatementOrBracketCurlyRight => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
-                            ..
-                        } => return Ok((self.builder.finish(), None)),
-                        token => {
+                    let lexeme = stream.borrow_mut().peek()?.lexeme.clone();
+                    match lexeme {
+                        Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                            stream.borrow_mut().next()?;
+                            return Ok((self.builder.finish(), None));
+                        }
+                        _ => {
                             let (statement, next) = ImplementationLocalStatementParser::default()
-                                .parse(stream.clone(), Some(token))?;
-                            self.next = next;
+                                .parse(stream.clone(), None)?;
+                            crate::parser::put_back(next, stream.clone());
                             self.builder.push_statement(statement);
                         }
                     }
@@ -148,9 +150,11 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
@@ -618,6 +622,32 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_empty_stream_position() {
+        let input = r#"impl Test {} impl Other {}"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 6), "Test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let stream = TokenStream::test(input).wrap();
+        let result = Parser::default().parse(stream.clone(), None);
+
+        assert_eq!(result, expected);
+        assert_eq!(
+            stream.borrow_mut().next(),
+            Ok(Token::new(
+                Lexeme::Keyword(Keyword::Impl),
+                Location::test(1, 14),
+            ))
+        );
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"impl { const VALUE: u64 = 42; }"#;
@@ -0,0 +1,264 @@
+//!
+//! The `interface` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::statement::interface_method::Parser as InterfaceMethodParser;
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::interface::builder::Builder as InterfaceStatementBuilder;
+use crate::tree::statement::interface::Statement as InterfaceStatement;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "interface must have an identifier, e.g. `interface Token { ... }`";
+/// The invalid statement error hint.
+pub static HINT_ONLY_METHODS: &str =
+    "only method signatures may be declared in interfaces, e.g. `fn balance(self) -> u248;`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordInterface,
+    /// The `interface` has been parsed so far.
+    Identifier,
+    /// The `interface {identifier}` has been parsed so far.
+    BracketCurlyLeft,
+    /// The `interface {identifier} {` has been parsed so far.
+    MethodOrBracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordInterface
+    }
+}
+
+///
+/// The `interface` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: InterfaceStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses an 'interface' statement.
+    ///
+    /// '
+    /// interface Token {
+    ///     fn transfer(mut self, to: zksync::Address, amount: u248) -> bool;
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(InterfaceStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordInterface => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Interface),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["interface"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.state = State::MethodOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::MethodOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            ..
+                        } => {
+                            let (method, next) = InterfaceMethodParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_method(method);
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["fn", "}"],
+                                lexeme,
+                                Some(HINT_ONLY_METHODS),
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::statement::interface::method::Method as InterfaceMethod;
+    use crate::tree::statement::interface::Statement as InterfaceStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"
+    interface Test {}
+"#;
+
+        let expected = Ok((
+            InterfaceStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 15), "Test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_single_method() {
+        let input = r#"
+    interface Test {
+        fn f(mut self) -> bool;
+    }
+"#;
+
+        let expected = Ok((
+            InterfaceStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 15), "Test".to_owned()),
+                vec![InterfaceMethod::new(
+                    Location::test(3, 9),
+                    Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![Binding::new(
+                        Location::test(3, 14),
+                        BindingPattern::new(
+                            Location::test(3, 14),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(3, 18), "self".to_owned()),
+                                true,
+                            ),
+                        ),
+                        None,
+                    )],
+                    Some(Type::new(Location::test(3, 27), TypeVariant::boolean())),
+                )],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_method() {
+        let input = r#"
+    interface Test {
+        const A: u8 = 42;
+    }
+"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(3, 9),
+            vec!["fn", "}"],
+            Lexeme::Keyword(Keyword::Const),
+            Some(super::HINT_ONLY_METHODS),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -19,7 +19,7 @@ use crate::tree::statement::r#let::builder::Builder as LetStatementBuilder;
 use crate::tree::statement::r#let::Statement as LetStatement;
 
 /// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "variable must be initialized, e.g. `let value: u8 = 42;`";
+pub static HINT_EXPECTED_VALUE: &str = "variable must be initialized, e.g. `let value: u8 = 42;`, or be mutable with a type, e.g. `let mut value: u8;`";
 
 ///
 /// The parser state.
@@ -31,6 +31,7 @@ pub enum State {
     /// The `let` has been parsed so far.
     Binding,
     /// The `let {binding}` has been parsed so far.
+    /// The `;` is also allowed here for mutable variables with an explicit type.
     Equals,
     /// The `let {binding} =` has been parsed so far.
     Expression,
@@ -104,6 +105,12 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::Equals),
                             ..
                         } => self.state = State::Expression,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } if self.builder.is_uninitialized_allowed() => {
+                            return Ok((self.builder.finish(), None));
+                        }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_value(
                                 location,
@@ -832,6 +839,51 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_uninitialized() {
+        let input = r#"let mut a: u8;"#;
+
+        let expected = Ok((
+            LetStatement::new_uninitialized(
+                Location::test(1, 1),
+                Binding::new(
+                    Location::test(1, 5),
+                    BindingPattern::new(
+                        Location::test(1, 5),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 9), "a".to_owned()),
+                            true,
+                        ),
+                    ),
+                    Some(Type::new(
+                        Location::test(1, 12),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                    )),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_value_mutable_without_type() {
+        let input = r#"let mut a;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
+            Location::test(1, 10),
+            Lexeme::Symbol(Symbol::Semicolon),
+            Some(super::HINT_EXPECTED_VALUE),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_value_without_type() {
         let input = r#"let a;"#;
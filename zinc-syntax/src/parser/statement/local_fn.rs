@@ -16,6 +16,8 @@ use crate::parser::expression::Parser as ExpressionParser;
 use crate::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::parser::statement::r#for::Parser as ForStatementParser;
 use crate::parser::statement::r#let::Parser as LetStatementParser;
+use crate::parser::statement::r#return::Parser as ReturnStatementParser;
+use crate::parser::statement::r#while::Parser as WhileStatementParser;
 use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 ///
@@ -72,6 +74,34 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::While),
+                ..
+            }
+            | token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Number),
+                ..
+            } => {
+                let (statement, next) =
+                    WhileStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::While(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Return(statement)
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -120,6 +150,7 @@ mod tests {
     use crate::tree::r#type::Type;
     use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
     use crate::tree::statement::r#let::Statement as LetStatement;
+    use crate::tree::statement::r#return::Statement as ReturnStatement;
 
     #[test]
     fn ok_semicolon_terminated() {
@@ -190,4 +221,30 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_return() {
+        let input = r#"return 42;"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Return(ReturnStatement::new(
+                Location::test(1, 1),
+                Some(ExpressionTree::new(
+                    Location::test(1, 8),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 8),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+            )),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -15,6 +15,7 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::attribute::Parser as AttributeParser;
 use crate::parser::statement::contract::Parser as ContractStatementParser;
+use crate::parser::statement::interface::Parser as InterfaceStatementParser;
 use crate::parser::statement::module::Parser as ModStatementParser;
 use crate::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::parser::statement::r#enum::Parser as EnumStatementParser;
@@ -167,9 +168,14 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Enum),
                             ..
-                        } => EnumStatementParser::default()
-                            .parse(stream.clone(), Some(token))
-                            .map(|(statement, next)| (ModuleLocalStatement::Enum(statement), next)),
+                        } => {
+                            let (mut builder, next) =
+                                EnumStatementParser::default().parse(stream.clone(), Some(token))?;
+
+                            builder.set_attributes(self.attributes);
+
+                            return Ok((ModuleLocalStatement::Enum(builder.finish()), next));
+                        }
                         token
                         @
                         Token {
@@ -221,10 +227,23 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Contract),
                             ..
-                        } => ContractStatementParser::default()
+                        } => {
+                            let (mut builder, next) = ContractStatementParser::default()
+                                .parse(stream.clone(), Some(token))?;
+
+                            builder.set_attributes(self.attributes);
+
+                            return Ok((ModuleLocalStatement::Contract(builder.finish()), next));
+                        }
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Interface),
+                            ..
+                        } => InterfaceStatementParser::default()
                             .parse(stream.clone(), Some(token))
                             .map(|(statement, next)| {
-                                (ModuleLocalStatement::Contract(statement), next)
+                                (ModuleLocalStatement::Interface(statement), next)
                             }),
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Semicolon),
@@ -253,6 +272,7 @@ mod tests {
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::tree::attribute::element::variant::Variant as AttributeElementVariant;
     use crate::tree::attribute::element::Element as AttributeElement;
     use crate::tree::attribute::Attribute;
     use crate::tree::binding::Binding;
@@ -265,7 +285,9 @@ mod tests {
     use crate::tree::pattern_binding::Pattern as BindingPattern;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
+    use crate::tree::statement::contract::Statement as ContractStatement;
     use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
+    use crate::tree::statement::r#enum::Statement as EnumStatement;
     use crate::tree::statement::r#fn::Statement as FnStatement;
 
     #[test]
@@ -479,4 +501,80 @@ fn test() {}
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_enum_attribute() {
+        let input = r#"
+#[bitflags]
+enum Test {}
+"#;
+
+        let expected = Ok((
+            ModuleLocalStatement::Enum(EnumStatement::new(
+                Location::test(3, 1),
+                Identifier::new(Location::test(3, 6), "Test".to_owned()),
+                vec![],
+                vec![Attribute::new(
+                    Location::test(2, 1),
+                    false,
+                    vec![AttributeElement::new(
+                        Location::test(2, 3),
+                        ExpressionTree::new(
+                            Location::test(2, 3),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(2, 3), "bitflags".to_owned()),
+                            )),
+                        ),
+                        None,
+                    )],
+                )],
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_contract_attribute() {
+        let input = r#"
+#[invariant(balanced)]
+contract Test {}
+"#;
+
+        let expected = Ok((
+            ModuleLocalStatement::Contract(ContractStatement::new(
+                Location::test(3, 1),
+                Identifier::new(Location::test(3, 10), "Test".to_owned()),
+                vec![],
+                vec![],
+                vec![Attribute::new(
+                    Location::test(2, 1),
+                    false,
+                    vec![AttributeElement::new(
+                        Location::test(2, 3),
+                        ExpressionTree::new(
+                            Location::test(2, 3),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(2, 3), "invariant".to_owned()),
+                            )),
+                        ),
+                        Some(AttributeElementVariant::Expression(ExpressionTree::new(
+                            Location::test(2, 13),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(2, 13), "balanced".to_owned()),
+                            )),
+                        ))),
+                    )],
+                )],
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -9,12 +9,16 @@ pub mod field;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#impl;
+pub mod interface;
+pub mod interface_method;
 pub mod r#let;
 pub mod local_contract;
 pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
 pub mod r#type;
 pub mod r#use;
+pub mod r#while;
@@ -0,0 +1,243 @@
+//!
+//! The `return` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::statement::r#return::builder::Builder as ReturnStatementBuilder;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordReturn,
+    /// The `return` has been parsed so far.
+    ExpressionOrSemicolon,
+    /// The `return {expression}` has been parsed so far.
+    Semicolon,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordReturn
+    }
+}
+
+///
+/// The `return` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: ReturnStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'return' statement.
+    ///
+    /// 'return a + b;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ReturnStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordReturn => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Return),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ExpressionOrSemicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["return"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ExpressionOrSemicolon => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (expression, next) =
+                                ExpressionParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_expression(expression);
+                            self.next = next;
+                            self.state = State::Semicolon;
+                        }
+                    }
+                }
+                State::Semicolon => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of_or_operator(
+                                location,
+                                vec![";"],
+                                lexeme,
+                                None,
+                            ),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::statement::r#return::Statement as ReturnStatement;
+
+    #[test]
+    fn ok_value() {
+        let input = r#"return 42;"#;
+
+        let expected = Ok((
+            ReturnStatement::new(
+                Location::test(1, 1),
+                Some(ExpressionTree::new(
+                    Location::test(1, 8),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 8),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_expression() {
+        let input = r#"return a + 1;"#;
+
+        let expected = Ok((
+            ReturnStatement::new(
+                Location::test(1, 1),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::test(1, 10),
+                    ExpressionTreeNode::operator(ExpressionOperator::Addition),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 8),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 8), "a".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 12),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 12),
+                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                            ),
+                        )),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_unit() {
+        let input = r#"return;"#;
+
+        let expected = Ok((ReturnStatement::new(Location::test(1, 1), None), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"return 42"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 10),
+            vec![";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_expression() {
+        let input = r#"return }"#;
+
+        let expected = Err(ParsingError::Syntax(
+            SyntaxError::expected_expression_or_operand(
+                Location::test(1, 8),
+                Lexeme::Symbol(Symbol::BracketCurlyRight),
+            ),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
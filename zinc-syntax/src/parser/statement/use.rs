@@ -7,21 +7,33 @@ use std::rc::Rc;
 
 use zinc_lexical::Keyword;
 use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
 use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
-use crate::parser::expression::path::Parser as PathOperandParser;
+use crate::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#use::builder::Builder as UseStatementBuilder;
+use crate::tree::statement::r#use::item::Item as UseStatementItem;
 use crate::tree::statement::r#use::Statement as UseStatement;
 
 /// The missing alias identifier error hint.
 pub static HINT_EXPECTED_ALIAS_IDENTIFIER: &str =
     "specify the alias identifier after the `as` keyword, e.g. `use crate::Data as GlobalData;`";
 
+/// The empty group error hint.
+pub static HINT_EMPTY_GROUP: &str =
+    "specify at least one item in the group, e.g. `use crate::{Data, Error as DataError};`";
+
+/// The nested group error hint.
+pub static HINT_NESTED_GROUP: &str =
+    "nested groups are not supported, split them into several statements, e.g. `use a::b::{c, d}; use a::e;`";
+
 ///
 /// The parser state.
 ///
@@ -29,14 +41,27 @@ pub static HINT_EXPECTED_ALIAS_IDENTIFIER: &str =
 pub enum State {
     /// The initial state.
     KeywordUse,
-    /// The `use` has been parsed so far.
+    /// The `use` or `use {path}::` has been parsed so far.
     Path,
+    /// The `use {path element}` has been parsed so far.
+    DoubleColonOrNext,
     /// The `use {path}` has been parsed so far.
     AsOrNext,
     /// The `use {path} as` has been parsed so far.
     AliasIdentifier,
-    /// The `use {path} as {identifier}` has been parsed so far.
+    /// The `use {path} as {identifier}`, `use {path}::{ ... }`, or `use {path}::*` has been
+    /// parsed so far.
     Semicolon,
+    /// The `use {path}::{` or `use {path}::{ ..., ` has been parsed so far.
+    GroupItemOrBracketCurlyRight,
+    /// The `use {path}::{ ..., {identifier}::` has been parsed so far.
+    GroupItemIdentifier,
+    /// The `use {path}::{ ..., {identifier}` has been parsed so far.
+    GroupItemDoubleColonOrAsOrNext,
+    /// The `use {path}::{ ..., {identifier} as` has been parsed so far.
+    GroupItemAliasIdentifier,
+    /// The `use {path}::{ ..., {identifier} as {identifier}` has been parsed so far.
+    GroupCommaOrBracketCurlyRight,
 }
 
 impl Default for State {
@@ -54,8 +79,16 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: UseStatementBuilder,
-    /// The token returned from a subparser.
-    next: Option<Token>,
+    /// The builder of the statement path.
+    path: ExpressionTreeBuilder,
+    /// The parsed group items.
+    group: Vec<UseStatementItem>,
+    /// The location of the group item being parsed.
+    item_location: Option<Location>,
+    /// The path of the group item being parsed.
+    item_path: Vec<Identifier>,
+    /// The alias of the group item being parsed.
+    item_alias_identifier: Option<Identifier>,
 }
 
 impl Parser {
@@ -63,18 +96,21 @@ impl Parser {
     /// Parses a 'use' statement.
     ///
     /// 'use jabberwocky::gone;'
+    /// 'use jabberwocky::{gone, went as gone_too};'
+    /// 'use jabberwocky::*;'
     ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
     ) -> Result<(UseStatement, Option<Token>), ParsingError> {
-        self.next = initial;
+        crate::parser::put_back(initial, stream.clone());
 
         loop {
             match self.state {
                 State::KeywordUse => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Use),
                             location,
@@ -94,27 +130,60 @@ impl Parser {
                 }
                 State::Path => {
                     let (expression, next) =
-                        PathOperandParser::default().parse(stream.clone(), self.next.take())?;
-                    self.builder.set_path(expression);
-                    self.next = next;
-                    self.state = State::AsOrNext;
+                        TerminalOperandParser::default().parse(stream.clone(), None)?;
+                    crate::parser::put_back(next, stream.clone());
+                    self.path.eat(expression);
+                    self.state = State::DoubleColonOrNext;
+                }
+                State::DoubleColonOrNext => {
+                    let lexeme = stream.borrow_mut().peek()?.lexeme.clone();
+                    match lexeme {
+                        Lexeme::Symbol(Symbol::DoubleColon) => {
+                            let location = stream.borrow_mut().next()?.location;
+
+                            let lexeme = stream.borrow_mut().peek()?.lexeme.clone();
+                            match lexeme {
+                                Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
+                                    stream.borrow_mut().next()?;
+                                    self.builder
+                                        .set_path(std::mem::take(&mut self.path).finish());
+                                    self.state = State::GroupItemOrBracketCurlyRight;
+                                }
+                                Lexeme::Symbol(Symbol::Asterisk) => {
+                                    stream.borrow_mut().next()?;
+                                    self.builder
+                                        .set_path(std::mem::take(&mut self.path).finish());
+                                    self.builder.set_glob();
+                                    self.state = State::Semicolon;
+                                }
+                                _ => {
+                                    self.path.eat_operator(ExpressionOperator::Path, location);
+                                    self.state = State::Path;
+                                }
+                            }
+                        }
+                        _ => {
+                            self.builder
+                                .set_path(std::mem::take(&mut self.path).finish());
+                            self.state = State::AsOrNext;
+                        }
+                    }
                 }
                 State::AsOrNext => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Keyword(Keyword::As),
-                            ..
-                        } => {
+                    let lexeme = stream.borrow_mut().peek()?.lexeme.clone();
+                    match lexeme {
+                        Lexeme::Keyword(Keyword::As) => {
+                            stream.borrow_mut().next()?;
                             self.state = State::AliasIdentifier;
                         }
-                        token => {
-                            self.next = Some(token);
+                        _ => {
                             self.state = State::Semicolon;
                         }
                     }
                 }
                 State::AliasIdentifier => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
                             location,
@@ -132,8 +201,171 @@ impl Parser {
                         }
                     }
                 }
+                State::GroupItemOrBracketCurlyRight => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.item_location = Some(location);
+                            self.item_path
+                                .push(Identifier::new(location, identifier.inner));
+                            self.state = State::GroupItemDoubleColonOrAsOrNext;
+                        }
+                        Token {
+                            lexeme: lexeme @ Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            location,
+                        } if self.group.is_empty() => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EMPTY_GROUP),
+                            )));
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.builder.set_group(std::mem::take(&mut self.group));
+                            self.state = State::Semicolon;
+                        }
+                        Token {
+                            lexeme: lexeme @ Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            location,
+                        } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_NESTED_GROUP),
+                            )));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::GroupItemIdentifier => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.item_path
+                                .push(Identifier::new(location, identifier.inner));
+                            self.state = State::GroupItemDoubleColonOrAsOrNext;
+                        }
+                        Token {
+                            lexeme: lexeme @ Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            location,
+                        } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_NESTED_GROUP),
+                            )));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::GroupItemDoubleColonOrAsOrNext => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleColon),
+                            ..
+                        } => {
+                            self.state = State::GroupItemIdentifier;
+                        }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::As),
+                            ..
+                        } => {
+                            self.state = State::GroupItemAliasIdentifier;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.finish_item();
+                            self.state = State::GroupItemOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.finish_item();
+                            self.builder.set_group(std::mem::take(&mut self.group));
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["::", "as", ",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::GroupItemAliasIdentifier => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.item_alias_identifier =
+                                Some(Identifier::new(location, identifier.inner));
+                            self.state = State::GroupCommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_ALIAS_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::GroupCommaOrBracketCurlyRight => {
+                    let token = stream.borrow_mut().next()?;
+                    match token {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.finish_item();
+                            self.state = State::GroupItemOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => {
+                            self.finish_item();
+                            self.builder.set_group(std::mem::take(&mut self.group));
+                            self.state = State::Semicolon;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::Semicolon => {
-                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                    let token = stream.borrow_mut().next()?;
+                    return match token {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Semicolon),
                             ..
@@ -146,12 +378,31 @@ impl Parser {
             }
         }
     }
+
+    ///
+    /// Moves the parsed group item to the group.
+    ///
+    fn finish_item(&mut self) {
+        let location = self
+            .item_location
+            .take()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        self.group.push(UseStatementItem::new(
+            location,
+            std::mem::take(&mut self.item_path),
+            self.item_alias_identifier.take(),
+        ));
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
@@ -162,6 +413,7 @@ mod tests {
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::statement::r#use::item::Item as UseStatementItem;
     use crate::tree::statement::r#use::Statement as UseStatement;
 
     #[test]
@@ -198,6 +450,8 @@ mod tests {
                     )),
                 ),
                 None,
+                None,
+                false,
             ),
             None,
         ));
@@ -244,6 +498,8 @@ mod tests {
                     Location::test(1, 31),
                     "MegaUltraNamespace".to_owned(),
                 )),
+                None,
+                false,
             ),
             None,
         ));
@@ -253,6 +509,204 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_stream_position() {
+        let input = r#"use mega::ultra; use namespace;"#;
+
+        let stream = TokenStream::test(input).wrap();
+        let result = Parser::default()
+            .parse(stream.clone(), None)
+            .map(|(statement, next)| (statement.alias_identifier, next));
+
+        assert_eq!(result, Ok((None, None)));
+        assert_eq!(
+            stream.borrow_mut().next(),
+            Ok(Token::new(
+                Lexeme::Keyword(Keyword::Use),
+                Location::test(1, 18),
+            ))
+        );
+    }
+
+    #[test]
+    fn ok_group_single() {
+        let input = r#"use mega::{Ultra};"#;
+
+        let expected = Ok((
+            UseStatement::new(
+                Location::test(1, 1),
+                ExpressionTree::new(
+                    Location::test(1, 5),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 5),
+                        "mega".to_owned(),
+                    ))),
+                ),
+                None,
+                Some(vec![UseStatementItem::new(
+                    Location::test(1, 12),
+                    vec![Identifier::new(Location::test(1, 12), "Ultra".to_owned())],
+                    None,
+                )]),
+                false,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_group_with_aliases() {
+        let input = r#"use mega::ultra::{Namespace as Alias, inner::Item,};"#;
+
+        let expected = Ok((
+            UseStatement::new(
+                Location::test(1, 1),
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Path),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 5), "mega".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 11), "ultra".to_owned()),
+                        )),
+                    )),
+                ),
+                None,
+                Some(vec![
+                    UseStatementItem::new(
+                        Location::test(1, 19),
+                        vec![Identifier::new(
+                            Location::test(1, 19),
+                            "Namespace".to_owned(),
+                        )],
+                        Some(Identifier::new(Location::test(1, 32), "Alias".to_owned())),
+                    ),
+                    UseStatementItem::new(
+                        Location::test(1, 39),
+                        vec![
+                            Identifier::new(Location::test(1, 39), "inner".to_owned()),
+                            Identifier::new(Location::test(1, 46), "Item".to_owned()),
+                        ],
+                        None,
+                    ),
+                ]),
+                false,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_glob() {
+        let input = r#"use mega::ultra::*;"#;
+
+        let expected = Ok((
+            UseStatement::new(
+                Location::test(1, 1),
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Path),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 5), "mega".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 11), "ultra".to_owned()),
+                        )),
+                    )),
+                ),
+                None,
+                None,
+                true,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_glob_with_alias() {
+        let input = r#"use mega::* as Mega;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 13),
+            vec![";"],
+            Lexeme::Keyword(Keyword::As),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_group_empty() {
+        let input = r#"use mega::{};"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 12),
+            Lexeme::Symbol(Symbol::BracketCurlyRight),
+            Some(super::HINT_EMPTY_GROUP),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_group_nested() {
+        let input = r#"use a::{b::{c, d}, e};"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 12),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_NESTED_GROUP),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_group_expected_comma_or_bracket_curly_right() {
+        let input = r#"use a::{b;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 10),
+            vec!["::", "as", ",", "}"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_semicolon() {
         let input = r#"use jabberwocky"#;
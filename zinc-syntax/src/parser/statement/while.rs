@@ -0,0 +1,264 @@
+//!
+//! The `while` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::attribute::Parser as AttributeParser;
+use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::statement::r#while::builder::Builder as WhileStatementBuilder;
+use crate::tree::statement::r#while::Statement as WhileStatement;
+
+/// The misplaced attribute error hint.
+pub static HINT_ATTRIBUTES_ONLY_BEFORE_WHILE: &str =
+    "only `while` loops may have attributes inside functions, e.g. `#[max_iterations(10)] while i < n { ... }`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    AttributeOrKeywordWhile,
+    /// The `while` with optional attributes has been parsed so far.
+    ConditionExpression,
+    /// The `while {expression}` has been parsed so far.
+    BlockExpression,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::AttributeOrKeywordWhile
+    }
+}
+
+///
+/// The `while` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: WhileStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a while-loop statement.
+    ///
+    /// '
+    /// #[max_iterations(100)]
+    /// while x < limit {
+    ///     x *= 2;
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(WhileStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::AttributeOrKeywordWhile => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Number),
+                            ..
+                        } => {
+                            let (attribute, next) =
+                                AttributeParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.push_attribute(attribute);
+                            self.next = next;
+                        }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::While),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ConditionExpression;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["while"],
+                                lexeme,
+                                Some(HINT_ATTRIBUTES_ONLY_BEFORE_WHILE),
+                            )));
+                        }
+                    }
+                }
+                State::ConditionExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_condition(expression);
+                    self.state = State::BlockExpression;
+                }
+                State::BlockExpression => {
+                    let (expression, next) =
+                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_block(expression);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::attribute::element::variant::Variant as AttributeElementVariant;
+    use crate::tree::attribute::element::Element as AttributeElement;
+    use crate::tree::attribute::Attribute;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::statement::r#while::Statement as WhileStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"while i < 4 {}"#;
+
+        let expected = Ok((
+            WhileStatement::new(
+                Location::test(1, 1),
+                vec![],
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Lesser),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 7), "i".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 11),
+                                LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                            ),
+                        )),
+                    )),
+                ),
+                BlockExpression::new(Location::test(1, 13), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_with_attribute() {
+        let input = r#"#[max_iterations(8)] while flag {}"#;
+
+        let expected = Ok((
+            WhileStatement::new(
+                Location::test(1, 22),
+                vec![Attribute::new(
+                    Location::test(1, 1),
+                    false,
+                    vec![AttributeElement::new(
+                        Location::test(1, 3),
+                        ExpressionTree::new(
+                            Location::test(1, 3),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 3), "max_iterations".to_owned()),
+                            )),
+                        ),
+                        Some(AttributeElementVariant::Expression(ExpressionTree::new(
+                            Location::test(1, 18),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(1, 18),
+                                    LexicalIntegerLiteral::new_decimal("8".to_owned()),
+                                ),
+                            )),
+                        ))),
+                    )],
+                )],
+                ExpressionTree::new(
+                    Location::test(1, 28),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 28),
+                        "flag".to_owned(),
+                    ))),
+                ),
+                BlockExpression::new(Location::test(1, 33), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_keyword_while() {
+        let input = r#"#[max_iterations(8)] for i in 0..8 {}"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 22),
+            vec!["while"],
+            Lexeme::Keyword(Keyword::For),
+            Some(super::HINT_ATTRIBUTES_ONLY_BEFORE_WHILE),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"while flag;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 11),
+            vec!["{"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -58,6 +58,7 @@ impl Parser {
     /// Parses an array type literal.
     ///
     /// '[u8; 16]'
+    /// '[u8; _]'
     ///
     pub fn parse(
         mut self,
@@ -113,10 +114,20 @@ impl Parser {
                     }
                 }
                 State::SizeExpression => {
-                    let (expression, next) =
-                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
-                    self.next = next;
-                    self.builder.set_array_size_expression(expression);
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Underscore),
+                            ..
+                        } => {
+                            self.builder.set_array_size_inferred();
+                        }
+                        token => {
+                            let (expression, next) =
+                                ExpressionParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.set_array_size_expression(expression);
+                        }
+                    }
                     self.state = State::BracketSquareRight;
                 }
                 State::BracketSquareRight => {
@@ -270,6 +281,54 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_size_inferred() {
+        let input = r#"[u8; _]"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::array_size_inferred(Type::new(
+                    Location::test(1, 2),
+                    TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_type_inferred() {
+        let input = r#"[_; 4]"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::array(
+                    Type::new(Location::test(1, 2), TypeVariant::inferred()),
+                    ExpressionTree::new(
+                        Location::test(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 5),
+                                LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                            ),
+                        )),
+                    ),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_semicolon() {
         let input = r#"[field, 8]"#;
@@ -44,6 +44,7 @@ impl Parser {
     /// '[u8; 16]'
     /// '(u8, field, bool)'
     /// 'Path::To::Type`
+    /// '(u8, _)'
     ///
     pub fn parse(
         mut self,
@@ -134,6 +135,14 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
                 ..
             } => TupleParser::default().parse(stream.clone(), Some(token)),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Underscore),
+                location,
+            } => {
+                self.builder.set_location(location);
+                self.builder.set_inferred();
+                Ok((self.builder.finish(), None))
+            }
             Token { lexeme, location } => Err(ParsingError::Syntax(SyntaxError::expected_type(
                 location, lexeme, None,
             ))),
@@ -378,6 +387,17 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_inferred() {
+        let input = r#"_"#;
+
+        let expected = Ok((Type::new(Location::test(1, 1), TypeVariant::inferred()), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_type_keyword() {
         let input = r#"while"#;
@@ -249,6 +249,33 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_nested_inferred() {
+        let input = r#"(u64, (_, bool), _)"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::tuple(vec![
+                    Type::new(Location::test(1, 2), TypeVariant::integer_unsigned(64)),
+                    Type::new(
+                        Location::test(1, 7),
+                        TypeVariant::tuple(vec![
+                            Type::new(Location::test(1, 8), TypeVariant::inferred()),
+                            Type::new(Location::test(1, 11), TypeVariant::boolean()),
+                        ]),
+                    ),
+                    Type::new(Location::test(1, 18), TypeVariant::inferred()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_comma_or_parenthesis_right() {
         let input = r#"(field;)"#;
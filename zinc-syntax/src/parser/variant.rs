@@ -40,6 +40,7 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'B = -1'
     ///
     pub fn parse(
         mut self,
@@ -80,7 +81,17 @@ impl Parser {
             }
         }
 
-        match crate::parser::take_or_next(self.next.take(), stream)? {
+        let mut next = crate::parser::take_or_next(self.next.take(), stream.clone())?;
+        if let Token {
+            lexeme: Lexeme::Symbol(Symbol::Minus),
+            ..
+        } = next
+        {
+            self.builder.set_negative();
+            next = crate::parser::take_or_next(None, stream)?;
+        }
+
+        match next {
             Token {
                 lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
                 location,
@@ -132,6 +143,27 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_negative() {
+        let input = r#"A = -1"#;
+
+        let expected = Ok((
+            Variant::new_negative(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                IntegerLiteral::new(
+                    Location::test(1, 6),
+                    LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_value() {
         let input = r#"A"#;
@@ -160,4 +192,18 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_integer_literal_negative() {
+        let input = r#"A = -id"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_integer_literal(
+            Location::test(1, 6),
+            Lexeme::Identifier(LexicalIdentifier::new("id".to_owned())),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
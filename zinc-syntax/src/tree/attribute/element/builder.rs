@@ -7,6 +7,7 @@ use zinc_lexical::Location;
 use crate::tree::attribute::element::variant::Variant as AttributeElementVariant;
 use crate::tree::attribute::element::Element as AttributeElement;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::literal::Literal;
 
 ///
@@ -20,8 +21,12 @@ pub struct Builder {
     path: Option<ExpressionTree>,
     /// The attribute value literal.
     value: Option<Literal>,
+    /// The attribute negative integer value literal.
+    negative_value: Option<IntegerLiteral>,
     /// The nested attribute.
     nested: Option<Vec<AttributeElement>>,
+    /// The attribute expression.
+    expression: Option<ExpressionTree>,
 }
 
 impl Builder {
@@ -46,6 +51,13 @@ impl Builder {
         self.value = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_negative_value(&mut self, value: IntegerLiteral) {
+        self.negative_value = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -53,6 +65,13 @@ impl Builder {
         self.nested = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_expression(&mut self, value: ExpressionTree) {
+        self.expression = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -78,8 +97,12 @@ impl Builder {
 
         let variant = if let Some(value) = self.value.take() {
             Some(AttributeElementVariant::Value(value))
+        } else if let Some(value) = self.negative_value.take() {
+            Some(AttributeElementVariant::NegativeValue(value))
         } else if let Some(nested) = self.nested.take() {
             Some(AttributeElementVariant::Nested(nested))
+        } else if let Some(expression) = self.expression.take() {
+            Some(AttributeElementVariant::Expression(expression))
         } else {
             None
         };
@@ -3,6 +3,8 @@
 //!
 
 use crate::tree::attribute::element::Element;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::literal::Literal;
 
 ///
@@ -12,6 +14,10 @@ use crate::tree::literal::Literal;
 pub enum Variant {
     /// The value assigned with the `=` operator, e.g. `#[value = 0x42]`.
     Value(Literal),
+    /// The negative integer value assigned with the `=` operator, e.g. `#[value = -1]`.
+    NegativeValue(IntegerLiteral),
     /// The nested attribute, e.g. `#[msg(sender = 0x0)]`.
     Nested(Vec<Element>),
+    /// The parenthesized expression, e.g. `#[ensures(result <= limit)]`.
+    Expression(ExpressionTree),
 }
@@ -37,4 +37,18 @@ impl Expression {
             expression: expression.map(Box::new),
         }
     }
+
+    ///
+    /// Checks if the block always returns from the function, that is, its result expression
+    /// diverges, or it has no result expression and one of its statements diverges.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        match self.expression {
+            Some(ref expression) => expression.is_diverging(),
+            None => self
+                .statements
+                .iter()
+                .any(FunctionLocalStatement::is_diverging),
+        }
+    }
 }
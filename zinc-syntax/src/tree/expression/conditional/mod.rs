@@ -41,4 +41,16 @@ impl Expression {
             else_block,
         }
     }
+
+    ///
+    /// Checks if both branches always return from the function.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        self.main_block.is_diverging()
+            && self
+                .else_block
+                .as_ref()
+                .map(BlockExpression::is_diverging)
+                .unwrap_or_default()
+    }
 }
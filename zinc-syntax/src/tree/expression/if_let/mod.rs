@@ -0,0 +1,47 @@
+//!
+//! The pattern conditional expression.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_match::Pattern as MatchPattern;
+
+///
+/// The pattern conditional expression `if let pattern = scrutinee { ... } else { ... }`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The pattern the scrutinee is matched against.
+    pub pattern: MatchPattern,
+    /// The scrutinee expression, which is the matched expression.
+    pub scrutinee: Box<ExpressionTree>,
+    /// The main conditional block expression, which is executed if the pattern matches.
+    pub main_block: BlockExpression,
+    /// The `else` conditional block expression.
+    pub else_block: Option<BlockExpression>,
+}
+
+impl Expression {
+    ///
+    /// Creates a pattern conditional expression.
+    ///
+    pub fn new(
+        location: Location,
+        pattern: MatchPattern,
+        scrutinee: ExpressionTree,
+        main_block: BlockExpression,
+        else_block: Option<BlockExpression>,
+    ) -> Self {
+        Self {
+            location,
+            pattern,
+            scrutinee: Box::new(scrutinee),
+            main_block,
+            else_block,
+        }
+    }
+}
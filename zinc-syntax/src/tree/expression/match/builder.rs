@@ -6,6 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::r#match::Expression as MatchExpression;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
@@ -43,6 +44,30 @@ impl Builder {
         self.branches.push((value, None));
     }
 
+    ///
+    /// Pushes an alternative to the last branch pattern, turning it into an or-pattern.
+    ///
+    pub fn push_branch_pattern_alternative(&mut self, value: MatchPattern) {
+        let (pattern, _expression) = self.branches.last_mut().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "branch pattern"
+            )
+        });
+
+        match pattern.variant {
+            MatchPatternVariant::Or(ref mut alternatives) => alternatives.push(value),
+            _ => {
+                let first = pattern.to_owned();
+                *pattern = MatchPattern::new(
+                    first.location,
+                    MatchPatternVariant::new_or(vec![first, value]),
+                );
+            }
+        }
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
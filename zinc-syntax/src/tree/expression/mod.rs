@@ -5,8 +5,10 @@
 pub mod array;
 pub mod block;
 pub mod conditional;
+pub mod if_let;
 pub mod list;
 pub mod r#match;
 pub mod structure;
 pub mod tree;
 pub mod tuple;
+pub mod unsafe_witness;
@@ -70,6 +70,17 @@ impl Tree {
             _ => false,
         }
     }
+
+    ///
+    /// Checks if the tree is a single block or conditional expression, which always returns
+    /// from the function.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        match *self.value {
+            Node::Operand(ref operand) => operand.is_diverging(),
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Tree {
@@ -9,10 +9,12 @@ use zinc_lexical::Location;
 use crate::tree::expression::array::Expression as ArrayExpression;
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::tree::expression::if_let::Expression as IfLetExpression;
 use crate::tree::expression::list::Expression as ListExpression;
 use crate::tree::expression::r#match::Expression as MatchExpression;
 use crate::tree::expression::structure::Expression as StructureExpression;
 use crate::tree::expression::tuple::Expression as TupleExpression;
+use crate::tree::expression::unsafe_witness::Expression as UnsafeWitnessExpression;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
@@ -51,8 +53,12 @@ pub enum Operand {
     Block(BlockExpression),
     /// A conditional expression `if x { ... } else { ... }`.
     Conditional(ConditionalExpression),
+    /// A pattern conditional expression `if let State::Idle = x { ... } else { ... }`.
+    IfLet(IfLetExpression),
     /// A match expression `match value { 1 => 10, _ => 42 }`.
     Match(MatchExpression),
+    /// An unconstrained witness expression `unsafe_witness { ... }`.
+    UnsafeWitness(UnsafeWitnessExpression),
 }
 
 impl Operand {
@@ -65,9 +71,22 @@ impl Operand {
         matches!(self,
             Self::Block(_) |
             Self::Conditional(_) |
+            Self::IfLet(_) |
             Self::Match(_)
         )
     }
+
+    ///
+    /// Checks if the operand is a block or conditional expression, which always returns from
+    /// the function.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        match self {
+            Self::Block(inner) => inner.is_diverging(),
+            Self::Conditional(inner) => inner.is_diverging(),
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Operand {
@@ -0,0 +1,56 @@
+//!
+//! The `unsafe_witness` expression builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::unsafe_witness::Expression as UnsafeWitnessExpression;
+
+///
+/// The `unsafe_witness` expression builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The block expression, which computes the witness value.
+    block: Option<BlockExpression>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_block(&mut self, value: BlockExpression) {
+        self.block = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> UnsafeWitnessExpression {
+        UnsafeWitnessExpression::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.block.take().unwrap_or_else(|| {
+                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "block")
+            }),
+        )
+    }
+}
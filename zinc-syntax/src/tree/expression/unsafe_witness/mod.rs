@@ -0,0 +1,31 @@
+//!
+//! The `unsafe_witness` expression.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::block::Expression as BlockExpression;
+
+///
+/// The `unsafe_witness` expression.
+///
+/// The block is evaluated only during the witness generation and never contributes constraints.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The block expression, which computes the witness value.
+    pub block: BlockExpression,
+}
+
+impl Expression {
+    ///
+    /// Creates an `unsafe_witness` expression.
+    ///
+    pub fn new(location: Location, block: BlockExpression) -> Self {
+        Self { location, block }
+    }
+}
@@ -2,6 +2,7 @@
 //! The module.
 //!
 
+use crate::tree::attribute::Attribute;
 use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
 
 ///
@@ -9,15 +10,27 @@ use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Module {
+    /// The index of the source file, which the module has been parsed from.
+    pub file: usize,
+    /// The module inner attributes `#![...]`, which are placed at the beginning of the file.
+    pub attributes: Vec<Attribute>,
     /// The module statements.
     pub statements: Vec<ModuleLocalStatement>,
 }
 
 impl Module {
     ///
-    /// Creates a module with statements.
+    /// Creates a module with inner attributes and statements.
     ///
-    pub fn new(statements: Vec<ModuleLocalStatement>) -> Self {
-        Self { statements }
+    pub fn new(
+        file: usize,
+        attributes: Vec<Attribute>,
+        statements: Vec<ModuleLocalStatement>,
+    ) -> Self {
+        Self {
+            file,
+            attributes,
+            statements,
+        }
     }
 }
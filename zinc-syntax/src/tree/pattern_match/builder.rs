@@ -29,6 +29,8 @@ pub struct Builder {
     binding: Option<Identifier>,
     /// The path builder variant, which means that the pattern is a path expression.
     path_builder: ExpressionTreeBuilder,
+    /// The tuple variant, which means that the pattern is a tuple of patterns.
+    tuple: Option<Vec<MatchPattern>>,
     /// If the pattern variant is a wildcard.
     is_wildcard: bool,
 }
@@ -78,6 +80,20 @@ impl Builder {
         self.path_builder.eat(tree);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_tuple(&mut self) {
+        self.tuple = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_tuple_element(&mut self, value: MatchPattern) {
+        self.tuple.get_or_insert_with(Vec::new).push(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -110,11 +126,13 @@ impl Builder {
             MatchPatternVariant::Binding(identifier)
         } else if !self.path_builder.is_empty() {
             MatchPatternVariant::Path(self.path_builder.finish())
+        } else if let Some(elements) = self.tuple.take() {
+            MatchPatternVariant::Tuple(elements)
         } else {
             panic!(
                 "{}{}",
                 zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | binding | path | tuple | wildcard"
             );
         };
 
@@ -6,6 +6,7 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
 /// The match pattern variant.
@@ -20,8 +21,12 @@ pub enum Variant {
     Binding(Identifier),
     /// An expression path refutable pattern, usually points to a constant or enumeration variant.
     Path(ExpressionTree),
+    /// A tuple pattern, which is refutable if any of its elements is refutable.
+    Tuple(Vec<MatchPattern>),
     /// A wildcard irrefutable pattern.
     Wildcard,
+    /// An or-pattern, which is matched if any of its alternatives is matched.
+    Or(Vec<MatchPattern>),
 }
 
 impl Variant {
@@ -53,10 +58,24 @@ impl Variant {
         Self::Path(expression)
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple(elements: Vec<MatchPattern>) -> Self {
+        Self::Tuple(elements)
+    }
+
     ///
     /// A shortcut constructor.
     ///
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_or(alternatives: Vec<MatchPattern>) -> Self {
+        Self::Or(alternatives)
+    }
 }
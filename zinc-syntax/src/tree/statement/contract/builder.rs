@@ -4,6 +4,8 @@
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::contract::Statement as ContractStatement;
 use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
@@ -17,8 +19,12 @@ pub struct Builder {
     location: Option<Location>,
     /// The contract type identifier.
     identifier: Option<Identifier>,
+    /// The paths to the interfaces, which the contract implements.
+    implements: Vec<ExpressionTree>,
     /// The contract statements.
     statements: Vec<ContractLocalStatement>,
+    /// The contract outer attributes.
+    attributes: Vec<Attribute>,
 }
 
 impl Builder {
@@ -36,6 +42,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_implements(&mut self, path: ExpressionTree) {
+        self.implements.push(path);
+    }
+
     ///
     /// Pushes the corresponding builder value.
     ///
@@ -43,6 +56,13 @@ impl Builder {
         self.statements.push(statement);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_attributes(&mut self, value: Vec<Attribute>) {
+        self.attributes = value;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -65,7 +85,9 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.implements,
             self.statements,
+            self.attributes,
         )
     }
 }
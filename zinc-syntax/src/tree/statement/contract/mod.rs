@@ -6,6 +6,8 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
 
@@ -18,8 +20,12 @@ pub struct Statement {
     pub location: Location,
     /// The contract type identifier.
     pub identifier: Identifier,
+    /// The paths to the interfaces, which the contract implements.
+    pub implements: Vec<ExpressionTree>,
     /// The contract statements.
     pub statements: Vec<ContractLocalStatement>,
+    /// The contract outer attributes.
+    pub attributes: Vec<Attribute>,
 }
 
 impl Statement {
@@ -29,12 +35,16 @@ impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        implements: Vec<ExpressionTree>,
         statements: Vec<ContractLocalStatement>,
+        attributes: Vec<Attribute>,
     ) -> Self {
         Self {
             location,
             identifier,
+            implements,
             statements,
+            attributes,
         }
     }
 }
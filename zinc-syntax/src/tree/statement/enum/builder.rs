@@ -4,6 +4,7 @@
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#enum::Statement as EnumStatement;
 use crate::tree::variant::Variant;
@@ -19,6 +20,8 @@ pub struct Builder {
     identifier: Option<Identifier>,
     /// The enumeration type variants.
     variants: Vec<Variant>,
+    /// The enumeration outer attributes.
+    attributes: Vec<Attribute>,
 }
 
 impl Builder {
@@ -43,6 +46,13 @@ impl Builder {
         self.variants = value;
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_attributes(&mut self, value: Vec<Attribute>) {
+        self.attributes = value;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -66,6 +76,7 @@ impl Builder {
                 )
             }),
             self.variants,
+            self.attributes,
         )
     }
 }
@@ -6,6 +6,7 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::attribute::Attribute;
 use crate::tree::identifier::Identifier;
 use crate::tree::variant::Variant;
 
@@ -20,17 +21,25 @@ pub struct Statement {
     pub identifier: Identifier,
     /// The enumeration type variants.
     pub variants: Vec<Variant>,
+    /// The enumeration outer attributes.
+    pub attributes: Vec<Attribute>,
 }
 
 impl Statement {
     ///
     /// Creates an `enum` statement.
     ///
-    pub fn new(location: Location, identifier: Identifier, variants: Vec<Variant>) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        variants: Vec<Variant>,
+        attributes: Vec<Attribute>,
+    ) -> Self {
         Self {
             location,
             identifier,
             variants,
+            attributes,
         }
     }
 }
@@ -0,0 +1,71 @@
+//!
+//! The `interface` statement builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::interface::method::Method as InterfaceMethod;
+use crate::tree::statement::interface::Statement as InterfaceStatement;
+
+///
+/// The `interface` statement builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The interface identifier.
+    identifier: Option<Identifier>,
+    /// The interface method signatures.
+    methods: Vec<InterfaceMethod>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_method(&mut self, method: InterfaceMethod) {
+        self.methods.push(method);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> InterfaceStatement {
+        InterfaceStatement::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "identifier"
+                )
+            }),
+            self.methods,
+        )
+    }
+}
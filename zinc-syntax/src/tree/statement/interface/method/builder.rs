@@ -0,0 +1,82 @@
+//!
+//! The `interface` method signature builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+use crate::tree::statement::interface::method::Method as InterfaceMethod;
+
+///
+/// The `interface` method signature builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The method identifier.
+    identifier: Option<Identifier>,
+    /// The method argument bindings.
+    argument_bindings: Vec<Binding>,
+    /// The optional method return type, which is `()` if not specified.
+    return_type: Option<Type>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_argument_bindings(&mut self, value: Vec<Binding>) {
+        self.argument_bindings = value;
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_return_type(&mut self, value: Type) {
+        self.return_type = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> InterfaceMethod {
+        InterfaceMethod::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "identifier"
+                )
+            }),
+            self.argument_bindings,
+            self.return_type.take(),
+        )
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! The `interface` method signature.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The `interface` method signature, that is, an `fn` statement without the body.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Method {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The method identifier.
+    pub identifier: Identifier,
+    /// The method argument bindings list.
+    pub argument_bindings: Vec<Binding>,
+    /// The optional method return type, which is `()` if not specified.
+    pub return_type: Option<Type>,
+}
+
+impl Method {
+    ///
+    /// Creates an `interface` method signature.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        argument_bindings: Vec<Binding>,
+        return_type: Option<Type>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            argument_bindings,
+            return_type,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The `interface` statement.
+//!
+
+pub mod builder;
+pub mod method;
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+
+use self::method::Method;
+
+///
+/// The `interface` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The interface identifier.
+    pub identifier: Identifier,
+    /// The interface method signatures.
+    pub methods: Vec<Method>,
+}
+
+impl Statement {
+    ///
+    /// Creates an `interface` statement.
+    ///
+    pub fn new(location: Location, identifier: Identifier, methods: Vec<Method>) -> Self {
+        Self {
+            location,
+            identifier,
+            methods,
+        }
+    }
+}
@@ -6,6 +6,8 @@ use zinc_lexical::Location;
 
 use crate::tree::binding::Binding;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
 use crate::tree::statement::r#let::Statement as LetStatement;
 
 ///
@@ -43,6 +45,26 @@ impl Builder {
         self.expression = Some(value);
     }
 
+    ///
+    /// Checks if the binding can be declared without an initializer.
+    ///
+    /// Only a single mutable variable with an explicit type can be assigned later.
+    ///
+    pub fn is_uninitialized_allowed(&self) -> bool {
+        match self.binding {
+            Some(Binding {
+                pattern:
+                    BindingPattern {
+                        variant: BindingPatternVariant::Binding { is_mutable, .. },
+                        ..
+                    },
+                r#type: Some(_),
+                ..
+            }) => is_mutable,
+            _ => false,
+        }
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -50,24 +72,20 @@ impl Builder {
     /// If some of the required items has not been set.
     ///
     pub fn finish(mut self) -> LetStatement {
-        LetStatement::new(
-            self.location.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "location"
-                )
-            }),
-            self.binding.take().unwrap_or_else(|| {
-                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "binding")
-            }),
-            self.expression.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "expression"
-                )
-            }),
-        )
+        let location = self.location.take().unwrap_or_else(|| {
+            panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "location"
+            )
+        });
+        let binding = self.binding.take().unwrap_or_else(|| {
+            panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "binding")
+        });
+
+        match self.expression.take() {
+            Some(expression) => LetStatement::new(location, binding, expression),
+            None => LetStatement::new_uninitialized(location, binding),
+        }
     }
 }
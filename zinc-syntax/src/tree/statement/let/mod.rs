@@ -18,8 +18,8 @@ pub struct Statement {
     pub location: Location,
     /// The binding pattern.
     pub binding: Binding,
-    /// The expression assigned to the variable.
-    pub expression: ExpressionTree,
+    /// The expression assigned to the variable. `None` if the variable is assigned later.
+    pub expression: Option<ExpressionTree>,
 }
 
 impl Statement {
@@ -30,7 +30,18 @@ impl Statement {
         Self {
             location,
             binding,
-            expression,
+            expression: Some(expression),
+        }
+    }
+
+    ///
+    /// Creates a `let` statement without an initializer.
+    ///
+    pub fn new_uninitialized(location: Location, binding: Binding) -> Self {
+        Self {
+            location,
+            binding,
+            expression: None,
         }
     }
 }
@@ -8,6 +8,8 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::statement::r#const::Statement as ConstStatement;
 use crate::tree::statement::r#for::Statement as ForStatement;
 use crate::tree::statement::r#let::Statement as LetStatement;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
+use crate::tree::statement::r#while::Statement as WhileStatement;
 
 ///
 /// The function-or-block-level statement.
@@ -20,6 +22,10 @@ pub enum Statement {
     Const(ConstStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The `while` statement.
+    While(WhileStatement),
+    /// The `return` statement.
+    Return(ReturnStatement),
     /// The empty `;` statement.
     Empty(Location),
     /// The expression statement.
@@ -35,8 +41,21 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::While(inner) => inner.location,
+            Self::Return(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
     }
+
+    ///
+    /// Checks if the statement always returns from the function.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        match self {
+            Self::Return(_) => true,
+            Self::Expression(inner) => inner.is_diverging(),
+            _ => false,
+        }
+    }
 }
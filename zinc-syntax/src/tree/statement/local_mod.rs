@@ -5,6 +5,7 @@
 use zinc_lexical::Location;
 
 use crate::tree::statement::contract::Statement as ContractStatement;
+use crate::tree::statement::interface::Statement as InterfaceStatement;
 use crate::tree::statement::module::Statement as ModStatement;
 use crate::tree::statement::r#const::Statement as ConstStatement;
 use crate::tree::statement::r#enum::Statement as EnumStatement;
@@ -37,6 +38,8 @@ pub enum Statement {
     Impl(ImplStatement),
     /// The `contract` statement.
     Contract(ContractStatement),
+    /// The `interface` statement.
+    Interface(InterfaceStatement),
     /// The empty `;` statement.
     Empty(Location),
 }
@@ -56,6 +59,7 @@ impl Statement {
             Self::Use(inner) => inner.location,
             Self::Impl(inner) => inner.location,
             Self::Contract(inner) => inner.location,
+            Self::Interface(inner) => inner.location,
             Self::Empty(location) => *location,
         }
     }
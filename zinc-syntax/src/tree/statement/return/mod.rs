@@ -0,0 +1,32 @@
+//!
+//! The `return` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The `return` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The returned expression. `None` for the `return;` statement returning `()`.
+    pub expression: Option<ExpressionTree>,
+}
+
+impl Statement {
+    ///
+    /// Creates a `return` statement.
+    ///
+    pub fn new(location: Location, expression: Option<ExpressionTree>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+}
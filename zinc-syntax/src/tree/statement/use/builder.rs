@@ -6,6 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#use::item::Item;
 use crate::tree::statement::r#use::Statement as UseStatement;
 
 ///
@@ -19,6 +20,10 @@ pub struct Builder {
     path: Option<ExpressionTree>,
     /// The imported item optional alias.
     alias_identifier: Option<Identifier>,
+    /// The grouped items imported from the path.
+    group: Option<Vec<Item>>,
+    /// Whether all the items of the path module are imported.
+    is_glob: bool,
 }
 
 impl Builder {
@@ -43,6 +48,20 @@ impl Builder {
         self.alias_identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_group(&mut self, value: Vec<Item>) {
+        self.group = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_glob(&mut self) {
+        self.is_glob = true;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -62,6 +81,8 @@ impl Builder {
                 panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "path")
             }),
             self.alias_identifier.take(),
+            self.group.take(),
+            self.is_glob,
         )
     }
 }
@@ -0,0 +1,37 @@
+//!
+//! The `use` statement group item.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+
+///
+/// The `use` statement group item, e.g. `Baz as Qux` in `use foo::{Bar, Baz as Qux};`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The item path relative to the statement path, e.g. `bar::Baz` in `use foo::{bar::Baz};`.
+    pub path: Vec<Identifier>,
+    /// The imported item optional alias.
+    pub alias_identifier: Option<Identifier>,
+}
+
+impl Item {
+    ///
+    /// Creates a `use` statement group item.
+    ///
+    pub fn new(
+        location: Location,
+        path: Vec<Identifier>,
+        alias_identifier: Option<Identifier>,
+    ) -> Self {
+        Self {
+            location,
+            path,
+            alias_identifier,
+        }
+    }
+}
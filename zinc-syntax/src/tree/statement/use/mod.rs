@@ -3,12 +3,15 @@
 //!
 
 pub mod builder;
+pub mod item;
 
 use zinc_lexical::Location;
 
 use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 
+use self::item::Item;
+
 ///
 /// The `use` statement.
 ///
@@ -16,10 +19,14 @@ use crate::tree::identifier::Identifier;
 pub struct Statement {
     /// The location of the syntax construction.
     pub location: Location,
-    /// The imported item path expression.
+    /// The imported item path expression, or the common path of the grouped items.
     pub path: ExpressionTree,
     /// The imported item optional alias.
     pub alias_identifier: Option<Identifier>,
+    /// The items imported from the `path`, e.g. `{Bar, Baz as Qux}` in `use foo::{Bar, Baz as Qux};`.
+    pub group: Option<Vec<Item>>,
+    /// Whether all the items of the `path` module are imported, e.g. `use foo::*;`.
+    pub is_glob: bool,
 }
 
 impl Statement {
@@ -30,11 +37,15 @@ impl Statement {
         location: Location,
         path: ExpressionTree,
         alias_identifier: Option<Identifier>,
+        group: Option<Vec<Item>>,
+        is_glob: bool,
     ) -> Self {
         Self {
             location,
             path,
             alias_identifier,
+            group,
+            is_glob,
         }
     }
 }
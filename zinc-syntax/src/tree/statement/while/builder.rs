@@ -0,0 +1,84 @@
+//!
+//! The `while` statement builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::statement::r#while::Statement as WhileStatement;
+
+///
+/// The `while` statement builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The loop outer attributes.
+    attributes: Vec<Attribute>,
+    /// The loop condition expression.
+    condition: Option<ExpressionTree>,
+    /// The loop block.
+    block: Option<BlockExpression>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Pushes an outer attribute to the attribute list.
+    ///
+    pub fn push_attribute(&mut self, value: Attribute) {
+        self.attributes.push(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_condition(&mut self, value: ExpressionTree) {
+        self.condition = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_block(&mut self, value: BlockExpression) {
+        self.block = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> WhileStatement {
+        WhileStatement::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.attributes,
+            self.condition.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "condition"
+                )
+            }),
+            self.block.take().unwrap_or_else(|| {
+                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "block")
+            }),
+        )
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! The `while` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The `while` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The loop outer attributes, which must specify the iterations bound.
+    pub attributes: Vec<Attribute>,
+    /// The loop condition expression, which is checked before each iteration.
+    pub condition: ExpressionTree,
+    /// The loop block.
+    pub block: BlockExpression,
+}
+
+impl Statement {
+    ///
+    /// Creates a `while` statement.
+    ///
+    pub fn new(
+        location: Location,
+        attributes: Vec<Attribute>,
+        condition: ExpressionTree,
+        block: BlockExpression,
+    ) -> Self {
+        Self {
+            location,
+            attributes,
+            condition,
+            block,
+        }
+    }
+}
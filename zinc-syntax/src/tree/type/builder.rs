@@ -24,12 +24,16 @@ pub struct Builder {
     array_type: Option<Type>,
     /// The array size expression, which means that the type is an array.
     array_size: Option<ExpressionTree>,
+    /// The array size `_` placeholder, which means that the size is inferred.
+    is_array_size_inferred: bool,
     /// The tuple elements, which means that the type is a tuple.
     tuple_element_types: Vec<Type>,
     /// The path expression, which means that the type is an alias.
     path_expression: Option<ExpressionTree>,
     /// The optional generic type arguments.
     generics: Option<Vec<Type>>,
+    /// The `_` placeholder, which means that the type is inferred.
+    is_inferred: bool,
 }
 
 /// The invalid type keyword panic, which is prevented by the type parser.
@@ -77,6 +81,13 @@ impl Builder {
         self.array_size = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_array_size_inferred(&mut self) {
+        self.is_array_size_inferred = true;
+    }
+
     ///
     /// Pushes the corresponding builder value.
     ///
@@ -98,6 +109,13 @@ impl Builder {
         self.generics = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_inferred(&mut self) {
+        self.is_inferred = true;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -124,20 +142,26 @@ impl Builder {
                 keyword => panic!("{}{}", self::BUILDER_TYPE_INVALID_KEYWORD, keyword),
             }
         } else if let Some(array_type) = self.array_type.take() {
-            TypeVariant::array(
-                array_type,
-                self.array_size.take().unwrap_or_else(|| {
-                    panic!(
-                        "{}{}",
-                        zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                        "array size"
-                    )
-                }),
-            )
+            if self.is_array_size_inferred {
+                TypeVariant::array_size_inferred(array_type)
+            } else {
+                TypeVariant::array(
+                    array_type,
+                    self.array_size.take().unwrap_or_else(|| {
+                        panic!(
+                            "{}{}",
+                            zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                            "array size"
+                        )
+                    }),
+                )
+            }
         } else if !self.tuple_element_types.is_empty() {
             TypeVariant::tuple(self.tuple_element_types)
         } else if self.is_unit {
             TypeVariant::unit()
+        } else if self.is_inferred {
+            TypeVariant::inferred()
         } else {
             panic!(self::VALIDATED_BY_THE_TYPE_PARSER);
         };
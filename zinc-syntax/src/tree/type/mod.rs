@@ -27,4 +27,23 @@ impl Type {
     pub fn new(location: Location, variant: Variant) -> Self {
         Self { location, variant }
     }
+
+    ///
+    /// Checks whether the type or any of its components is the `_` placeholder.
+    ///
+    pub fn has_placeholders(&self) -> bool {
+        match self.variant {
+            Variant::Inferred => true,
+            Variant::Array {
+                ref inner,
+                ref size,
+            } => size.is_none() || inner.has_placeholders(),
+            Variant::Tuple { ref inners } => inners.iter().any(Self::has_placeholders),
+            Variant::Alias {
+                generics: Some(ref generics),
+                ..
+            } => generics.iter().any(Self::has_placeholders),
+            _ => false,
+        }
+    }
 }
@@ -34,8 +34,8 @@ pub enum Variant {
     Array {
         /// The array element type.
         inner: Box<Type>,
-        /// The array size expression.
-        size: ExpressionTree,
+        /// The array size expression, which is `None` for the `_` placeholder.
+        size: Option<ExpressionTree>,
     },
     /// `({type1}, {type2}, ...)` in the source code.
     Tuple {
@@ -49,6 +49,8 @@ pub enum Variant {
         /// The optional generic type arguments.
         generics: Option<Vec<Type>>,
     },
+    /// `_` in the source code, which is inferred from the `let` statement initializer.
+    Inferred,
 }
 
 impl Variant {
@@ -104,7 +106,17 @@ impl Variant {
     pub fn array(inner: Type, size: ExpressionTree) -> Self {
         Self::Array {
             inner: Box::new(inner),
-            size,
+            size: Some(size),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn array_size_inferred(inner: Type) -> Self {
+        Self::Array {
+            inner: Box::new(inner),
+            size: None,
         }
     }
 
@@ -121,4 +133,11 @@ impl Variant {
     pub fn alias(path: ExpressionTree, generics: Option<Vec<Type>>) -> Self {
         Self::Alias { path, generics }
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn inferred() -> Self {
+        Self::Inferred
+    }
 }
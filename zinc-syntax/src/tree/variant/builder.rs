@@ -19,6 +19,8 @@ pub struct Builder {
     identifier: Option<Identifier>,
     /// The enumeration variant integer value.
     literal: Option<IntegerLiteral>,
+    /// If the integer value is preceded by the unary minus.
+    is_negative: bool,
 }
 
 impl Builder {
@@ -43,6 +45,13 @@ impl Builder {
         self.literal = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_negative(&mut self) {
+        self.is_negative = true;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -50,7 +59,13 @@ impl Builder {
     /// If some of the required items has not been set.
     ///
     pub fn finish(&mut self) -> Variant {
-        Variant::new(
+        let constructor = if self.is_negative {
+            Variant::new_negative
+        } else {
+            Variant::new
+        };
+
+        constructor(
             self.location.take().unwrap_or_else(|| {
                 panic!(
                     "{}{}",
@@ -20,6 +20,8 @@ pub struct Variant {
     pub identifier: Identifier,
     /// The enumeration variant integer value.
     pub literal: IntegerLiteral,
+    /// If the integer value is preceded by the unary minus, e.g. `Down = -1`.
+    pub is_negative: bool,
 }
 
 impl Variant {
@@ -31,6 +33,23 @@ impl Variant {
             location,
             identifier,
             literal,
+            is_negative: false,
+        }
+    }
+
+    ///
+    /// Creates an enumeration variant with a negative value.
+    ///
+    pub fn new_negative(
+        location: Location,
+        identifier: Identifier,
+        literal: IntegerLiteral,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            literal,
+            is_negative: true,
         }
     }
 }
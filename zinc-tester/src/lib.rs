@@ -9,6 +9,7 @@ pub(crate) mod summary;
 
 pub use self::error::Error;
 pub use self::one_file::directory::Directory as OneFileTestsDirectory;
+pub use self::one_file::runners::differential::Runner as DifferentialRunner;
 pub use self::one_file::runners::evaluation::Runner as EvaluationRunner;
 pub use self::one_file::runners::IRunnable;
 pub use self::ordinar::directory::Directory as OrdinarTestsDirectory;
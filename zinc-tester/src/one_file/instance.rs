@@ -66,7 +66,9 @@ impl Instance {
                 zinc_compiler::Module::new(scope.borrow().get_intermediate())
                     .write_to_zinc_vm(state.clone());
 
-                Ok(ZincVMState::unwrap_rc(state).into_application(true))
+                // the redundant range checks are kept, so every test cross-checks the bound analysis
+                Ok(ZincVMState::unwrap_rc(state)
+                    .into_application(true, Some(zinc_types::RangeCheck::Asserted)))
             })
             .expect(zinc_const::panic::SYNCHRONIZATION)
             .join()
@@ -0,0 +1,311 @@
+//!
+//! The differential test runner.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+use std::mem;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use colored::Colorize;
+use num::BigInt;
+use num::Zero;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
+use zinc_vm::ContractOutput;
+
+use crate::error::Error;
+use crate::one_file::file::File;
+use crate::one_file::instance::Instance;
+use crate::one_file::metadata::Metadata;
+use crate::one_file::runners::IRunnable;
+use crate::summary::Summary;
+
+///
+/// The differential runner.
+///
+/// Executes each test case with the constraint synthesis and with the pure evaluation, and
+/// checks that both backends return the same outputs, storages, and error kinds.
+///
+#[derive(Clone)]
+pub struct Runner {
+    /// If zero, does not print the successful tests.
+    pub verbosity: usize,
+    /// If set, runs only the tests whose full names contain the string.
+    pub filter: Option<String>,
+    /// The total time spent by each backend.
+    pub timing: Arc<Mutex<Timing>>,
+}
+
+///
+/// The total time spent by each backend.
+///
+#[derive(Debug, Default)]
+pub struct Timing {
+    /// The time spent with the constraint synthesis.
+    pub synthesis: Duration,
+    /// The time spent with the pure evaluation.
+    pub evaluation: Duration,
+}
+
+///
+/// The backend-independent part of the application output, which must be equal for both backends.
+///
+#[derive(Debug, PartialEq)]
+struct Comparable {
+    /// The output result.
+    result: serde_json::Value,
+    /// The contract storages after the execution.
+    storages: HashMap<BigInt, serde_json::Value>,
+    /// The contract storage Merkle tree root hashes after the execution.
+    storage_roots: HashMap<BigInt, BigInt>,
+}
+
+impl From<ContractOutput> for Comparable {
+    fn from(output: ContractOutput) -> Self {
+        Self {
+            result: output.result.into_json(),
+            storages: output
+                .storages
+                .into_iter()
+                .map(|(address, storage)| (address, storage.into_json()))
+                .collect(),
+            storage_roots: output.storage_roots,
+        }
+    }
+}
+
+impl Runner {
+    ///
+    /// Creates a runner instance.
+    ///
+    pub fn new(verbosity: usize, filter: Option<String>) -> Self {
+        Self {
+            verbosity,
+            filter,
+            timing: Arc::new(Mutex::new(Timing::default())),
+        }
+    }
+
+    ///
+    /// Compares the results of both backends.
+    ///
+    /// Returns the mismatch description, if the results differ.
+    ///
+    fn compare(
+        synthesis: Result<Comparable, zinc_vm::Error>,
+        evaluation: Result<Comparable, zinc_vm::Error>,
+    ) -> Option<String> {
+        match (synthesis, evaluation) {
+            (Ok(synthesis), Ok(evaluation)) if synthesis == evaluation => None,
+            (Ok(synthesis), Ok(evaluation)) => Some(format!(
+                "synthesis returned {:?}, but evaluation returned {:?}",
+                synthesis, evaluation
+            )),
+            (Err(synthesis), Err(evaluation))
+                if mem::discriminant(&synthesis) == mem::discriminant(&evaluation) =>
+            {
+                None
+            }
+            (Err(synthesis), Err(evaluation)) => Some(format!(
+                "synthesis failed with `{}`, but evaluation failed with `{}`",
+                synthesis, evaluation
+            )),
+            (Err(synthesis), Ok(_)) => Some(format!(
+                "synthesis failed with `{}`, but evaluation succeeded",
+                synthesis
+            )),
+            (Ok(_), Err(evaluation)) => Some(format!(
+                "evaluation failed with `{}`, but synthesis succeeded",
+                evaluation
+            )),
+        }
+    }
+}
+
+impl IRunnable for Runner {
+    fn run(
+        self,
+        path: PathBuf,
+        file: File,
+        metadata: Metadata,
+        summary: Arc<Mutex<Summary>>,
+    ) -> anyhow::Result<()> {
+        let path = match path.strip_prefix(crate::ONE_FILE_TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => &path,
+        };
+
+        for case in metadata.cases.into_iter() {
+            let case_name = format!("{}::{}", path.to_string_lossy(), case.case);
+            if let Some(filter) = self.filter.as_ref() {
+                if !case_name.contains(filter) {
+                    continue;
+                }
+            }
+
+            if metadata.ignore || case.ignore {
+                summary
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION)
+                    .ignored += 1;
+                println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+                continue;
+            }
+
+            let mut instance = match Instance::new(
+                case_name.clone(),
+                file.code.as_str(),
+                path.to_owned(),
+                case.method.clone(),
+                case.input,
+            ) {
+                Ok(application) => application,
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .invalid += 1;
+                    println!(
+                        "[INTEGRATION] {} {}: {:?}",
+                        "INVALID".red(),
+                        case_name,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            let (synthesis, synthesis_time, evaluation, evaluation_time) =
+                match instance.application {
+                    zinc_types::Application::Circuit(circuit) => {
+                        let start = Instant::now();
+                        let synthesis = CircuitFacade::new(circuit.clone())
+                            .run::<Bn256>(instance.input.clone())
+                            .map(|output| Comparable {
+                                result: output.result.into_json(),
+                                storages: HashMap::new(),
+                                storage_roots: HashMap::new(),
+                            });
+                        let synthesis_time = start.elapsed();
+
+                        let start = Instant::now();
+                        let evaluation = CircuitFacade::new(circuit)
+                            .evaluate::<Bn256>(instance.input)
+                            .map(|output| Comparable {
+                                result: output.result.into_json(),
+                                storages: HashMap::new(),
+                                storage_roots: HashMap::new(),
+                            });
+                        let evaluation_time = start.elapsed();
+
+                        (synthesis, synthesis_time, evaluation, evaluation_time)
+                    }
+                    zinc_types::Application::Contract(contract) => {
+                        let storage: Vec<zinc_types::ContractFieldValue> = contract
+                            .storage
+                            .clone()
+                            .into_iter()
+                            .map(zinc_types::ContractFieldValue::new_from_type)
+                            .collect();
+
+                        let method_name = case.method.unwrap_or_else(|| {
+                            zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned()
+                        });
+                        if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                            instance.input.insert_contract_instance(BigInt::zero());
+                        }
+
+                        let mut storages = HashMap::with_capacity(1);
+                        storages.insert(
+                            zksync_types::Address::default(),
+                            zinc_types::Value::Contract(storage),
+                        );
+                        let input = ContractInput::new(
+                            instance.input,
+                            storages,
+                            method_name,
+                            zinc_types::TransactionMsg::default(),
+                        );
+
+                        let start = Instant::now();
+                        let synthesis = ContractFacade::new(contract.clone())
+                            .synthesize::<Bn256>(input.clone())
+                            .map(Comparable::from);
+                        let synthesis_time = start.elapsed();
+
+                        let start = Instant::now();
+                        let evaluation = ContractFacade::new(contract)
+                            .run::<Bn256>(input)
+                            .map(Comparable::from);
+                        let evaluation_time = start.elapsed();
+
+                        (synthesis, synthesis_time, evaluation, evaluation_time)
+                    }
+                    zinc_types::Application::Library(_library) => {
+                        anyhow::bail!(Error::CannotRunLibrary);
+                    }
+                };
+
+            {
+                let mut timing = self
+                    .timing
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION);
+                timing.synthesis += synthesis_time;
+                timing.evaluation += evaluation_time;
+            }
+
+            match Self::compare(synthesis, evaluation) {
+                None => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .passed += 1;
+                    if self.verbosity > 0 {
+                        println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
+                    }
+                }
+                Some(mismatch) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} ({})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        mismatch
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let speedup = if self.evaluation.as_nanos() == 0 {
+            0.0
+        } else {
+            self.synthesis.as_secs_f64() / self.evaluation.as_secs_f64()
+        };
+
+        write!(
+            f,
+            "synthesis {:.3}s, evaluation {:.3}s, speedup {:.1}x",
+            self.synthesis.as_secs_f64(),
+            self.evaluation.as_secs_f64(),
+            speedup,
+        )
+    }
+}
@@ -2,6 +2,7 @@
 //! The test runners.
 //!
 
+pub mod differential;
 pub mod evaluation;
 
 use std::path::PathBuf;
@@ -17,4 +17,7 @@ pub struct Publish {
     pub input_path: PathBuf,
     /// The change-pubkey fee token.
     pub change_pubkey_fee_token: String,
+    /// The expected error message, if the publishing must fail.
+    #[serde(default)]
+    pub expect_error: Option<String>,
 }
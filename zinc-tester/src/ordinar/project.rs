@@ -140,6 +140,9 @@ impl Project {
                 self.path.clone(),
                 false,
                 Some(zksync::Network::Localhost.to_string()),
+                None,
+                None,
+                false,
             )
             .execute(),
         ) {
@@ -184,20 +187,56 @@ impl Project {
                 self.verbosity <= 1,
                 self.path.clone(),
                 Some(zksync::Network::Localhost.to_string()),
+                false,
+                None,
+                false,
+                None,
+                false,
+                None,
+                None,
+                None,
             )
             .execute(),
         ) {
+            if self.path.to_string_lossy().contains("error") {
+                if self.verbosity > 0 {
+                    println!(
+                        "[INTEGRATION] {} {} (unit test failure)",
+                        "PASSED".green(),
+                        self.path.to_string_lossy(),
+                    );
+                }
+                summary
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION)
+                    .passed += 1;
+            } else {
+                println!(
+                    "[INTEGRATION] {} {} (unit test failure): {:?}",
+                    "FAILED".bright_red(),
+                    self.path.to_string_lossy(),
+                    error,
+                );
+                summary
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION)
+                    .failed += 1;
+            }
+
+            return Err(error);
+        }
+
+        if self.path.to_string_lossy().contains("error") {
             println!(
-                "[INTEGRATION] {} {} (unit test failure): {:?}",
+                "[INTEGRATION] {} {} (should have failed)",
                 "FAILED".bright_red(),
                 self.path.to_string_lossy(),
-                error,
             );
             summary
                 .lock()
                 .expect(zinc_const::panic::SYNCHRONIZATION)
                 .failed += 1;
-            return Err(error);
+            anyhow::bail!("the project was expected to fail");
         }
 
         Ok(())
@@ -254,27 +293,48 @@ impl Project {
                 action.instance.clone(),
                 Some(zksync::Network::Localhost.to_string()),
                 None,
+                false,
+                None,
+                true,
+                false,
             )
             .execute(),
         ) {
             Ok(data) => {
+                if let Some(expected) = action.expect_error {
+                    println!(
+                        "[INTEGRATION] {} {} (publish failure): (expected error `{}`, found success)",
+                        "FAILED".bright_red(),
+                        self.path.to_string_lossy(),
+                        expected,
+                    );
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    anyhow::bail!("Publish has succeeded, but must have failed");
+                }
+
                 self.instance_addresses
                     .insert(action.instance, data.address);
                 Ok(())
             }
-            Err(error) => {
-                println!(
-                    "[INTEGRATION] {} {} (publish failure): {:?}",
-                    "FAILED".bright_red(),
-                    self.path.to_string_lossy(),
-                    error,
-                );
-                summary
-                    .lock()
-                    .expect(zinc_const::panic::SYNCHRONIZATION)
-                    .failed += 1;
-                Err(error)
-            }
+            Err(error) => match action.expect_error {
+                Some(expected) if format!("{:#}", error).contains(expected.as_str()) => Ok(()),
+                _ => {
+                    println!(
+                        "[INTEGRATION] {} {} (publish failure): {:?}",
+                        "FAILED".bright_red(),
+                        self.path.to_string_lossy(),
+                        error,
+                    );
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    Err(error)
+                }
+            },
         }
     }
 
@@ -24,6 +24,11 @@ pub struct Arguments {
     /// Runs only tests whose name contains the specified string.
     #[structopt(short = "f", long = "filter")]
     pub filter: Option<String>,
+
+    /// Runs the one-file tests with both the constraint synthesis and the pure evaluation,
+    /// and compares their results and timing.
+    #[structopt(long = "differential")]
+    pub differential: bool,
 }
 
 impl Arguments {
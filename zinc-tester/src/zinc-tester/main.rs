@@ -25,11 +25,23 @@ fn main() -> anyhow::Result<()> {
     let summary = zinc_tester::Summary::default().wrap();
 
     println!("[INTEGRATION] Running one-file tests");
-    zinc_tester::OneFileTestsDirectory::new(&PathBuf::from(zinc_tester::ONE_FILE_TESTS_DIRECTORY))?
-        .run(
+    let one_file_tests = zinc_tester::OneFileTestsDirectory::new(&PathBuf::from(
+        zinc_tester::ONE_FILE_TESTS_DIRECTORY,
+    ))?;
+    if args.differential {
+        let runner = zinc_tester::DifferentialRunner::new(args.verbosity, args.filter);
+        let timing = runner.timing.clone();
+        one_file_tests.run(runner, summary.clone());
+        println!(
+            "[INTEGRATION] Differential timing: {}",
+            timing.lock().expect(zinc_const::panic::SYNCHRONIZATION)
+        );
+    } else {
+        one_file_tests.run(
             zinc_tester::EvaluationRunner::new(args.verbosity, args.filter),
             summary.clone(),
         );
+    }
 
     println!("[INTEGRATION] Running project tests");
     zinc_tester::OrdinarTestsDirectory::new(&PathBuf::from(
@@ -2,10 +2,11 @@
 //! The bytecode circuit application.
 //!
 
-use std::collections::HashMap;
-
+use indexmap::IndexMap;
+use rustc_hex::ToHex;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
 
 use crate::application::unit_test::UnitTest;
 use crate::data::r#type::Type;
@@ -24,8 +25,12 @@ pub struct Circuit {
     pub input: Type,
     /// The circuit entry output type.
     pub output: Type,
+    /// The input template digest, which depends on the input arguments types.
+    #[serde(default)]
+    pub input_template: String,
     /// The circuit unit tests.
-    pub unit_tests: HashMap<String, UnitTest>,
+    #[serde(deserialize_with = "crate::bounded::deserialize_index_map")]
+    pub unit_tests: IndexMap<String, UnitTest>,
     /// The circuit bytecode instructions.
     pub instructions: Vec<Instruction>,
 }
@@ -39,16 +44,29 @@ impl Circuit {
         address: usize,
         input: Type,
         output: Type,
-        unit_tests: HashMap<String, UnitTest>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
+        let input_template = Self::input_template_hash(&input);
+
         Self {
             name,
             address,
             input,
             output,
+            input_template,
             unit_tests,
             instructions,
         }
     }
+
+    ///
+    /// Computes the input template digest, which is the hexadecimal SHA-256 digest of the
+    /// `input` arguments type.
+    ///
+    pub fn input_template_hash(input: &Type) -> String {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(format!("arguments: {};", input).as_bytes());
+        hasher.finalize()[..].to_hex()
+    }
 }
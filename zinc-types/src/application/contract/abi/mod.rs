@@ -0,0 +1,133 @@
+//!
+//! The contract ABI.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod parameter;
+
+use rustc_hex::ToHex;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+
+use crate::application::contract::method::Method;
+use crate::data::r#type::Type;
+
+use self::parameter::Parameter;
+
+///
+/// The contract ABI entry kind.
+///
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum EntryType {
+    /// The contract constructor `new`.
+    Constructor,
+    /// An ordinary contract method.
+    Function,
+}
+
+///
+/// The contract ABI method state mutability.
+///
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum StateMutability {
+    /// The immutable method, which cannot change the contract storage.
+    View,
+    /// The mutable method, which ignores the `zksync::msg` transaction.
+    Nonpayable,
+    /// The mutable method, which reads the `zksync::msg` transaction, e.g. to accept a transfer.
+    Payable,
+}
+
+///
+/// The contract ABI entry, which describes a single method.
+///
+/// Zinc contracts have no events yet, so all the entries are methods.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// The entry kind.
+    pub r#type: EntryType,
+    /// The method name.
+    pub name: String,
+    /// The first 4 bytes of the `sha256` digest of the method signature, e.g. `transfer(address,uint248)`.
+    pub selector: String,
+    /// The method input arguments, excluding the contract instance `self`.
+    pub inputs: Vec<Parameter>,
+    /// The method output, which is empty for the `()` type and constructors.
+    pub outputs: Vec<Parameter>,
+    /// The method state mutability.
+    pub state_mutability: StateMutability,
+}
+
+impl Entry {
+    /// The size of the selector in bytes.
+    pub const SELECTOR_SIZE: usize = 4;
+
+    /// The contract instance argument, which is passed implicitly and omitted from the ABI.
+    pub const INSTANCE_ARGUMENT: &'static str = "self";
+
+    ///
+    /// Describes the contract `method`.
+    ///
+    pub fn new(method: &Method) -> Self {
+        let is_constructor = method.name == zinc_const::contract::CONSTRUCTOR_IDENTIFIER;
+
+        let inputs: Vec<Parameter> = match method.input {
+            Type::Structure(ref fields) => fields
+                .iter()
+                .filter(|(name, _type)| name != Self::INSTANCE_ARGUMENT)
+                .map(|(name, r#type)| Parameter::new(name.to_owned(), r#type))
+                .collect(),
+            ref r#type => vec![Parameter::new(String::new(), r#type)],
+        };
+
+        let outputs = match method.output {
+            _ if is_constructor => vec![],
+            Type::Unit => vec![],
+            ref r#type => vec![Parameter::new(String::new(), r#type)],
+        };
+
+        let state_mutability = match (method.is_mutable, method.reads_msg) {
+            (false, _) => StateMutability::View,
+            (true, false) => StateMutability::Nonpayable,
+            (true, true) => StateMutability::Payable,
+        };
+
+        Self {
+            r#type: if is_constructor {
+                EntryType::Constructor
+            } else {
+                EntryType::Function
+            },
+            name: method.name.to_owned(),
+            selector: Self::selector(method.name.as_str(), inputs.as_slice()),
+            inputs,
+            outputs,
+            state_mutability,
+        }
+    }
+
+    ///
+    /// Computes the selector of the method `name` with the `inputs`.
+    ///
+    pub fn selector(name: &str, inputs: &[Parameter]) -> String {
+        let signature = format!(
+            "{}({})",
+            name,
+            inputs
+                .iter()
+                .map(Parameter::canonical_type)
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+
+        let digest = sha2::Sha256::digest(signature.as_bytes());
+        format!("0x{}", digest[..Self::SELECTOR_SIZE].to_hex::<String>())
+    }
+}
@@ -0,0 +1,153 @@
+//!
+//! The contract ABI parameter.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+///
+/// The contract ABI method input or output parameter.
+///
+/// The Zinc types are mapped to the ABI type vocabulary as follows:
+///
+/// | Zinc type                      | ABI `type`                 |
+/// |--------------------------------|----------------------------|
+/// | `bool`                         | `bool`                     |
+/// | `u{N}`                         | `uint{N}`                  |
+/// | `i{N}`                         | `int{N}`                   |
+/// | `field`                        | `field`                    |
+/// | enumeration, `#[bitflags]` set | `uint{N}` or `int{N}`      |
+/// | `zksync::Address`              | `address`                  |
+/// | `zksync::Balance`              | `uint248`                  |
+/// | contract instance              | `address`                  |
+/// | `[T; N]`                       | `{T}[N]`                   |
+/// | tuple                          | `tuple` with `components`  |
+/// | structure                      | `tuple` with `components`  |
+/// | `MTreeMap<K, V>`               | `mapping({K} => {V})`      |
+///
+/// The arrays of tuples and structures keep the element `components`. The tuple components are
+/// named by their indexes. The original Zinc type is always kept in `internalType`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+    /// The parameter name, which is empty for the method output.
+    pub name: String,
+    /// The parameter type in the ABI type vocabulary.
+    pub r#type: String,
+    /// The original Zinc type.
+    pub internal_type: String,
+    /// The tuple or structure fields, including the ones of the array elements.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Parameter>,
+}
+
+impl Parameter {
+    ///
+    /// Maps the Zinc `type` to the ABI parameter called `name`.
+    ///
+    pub fn new(name: String, r#type: &Type) -> Self {
+        let (abi_type, components) = Self::map(r#type);
+
+        Self {
+            name,
+            r#type: abi_type,
+            internal_type: r#type.to_string(),
+            components,
+        }
+    }
+
+    ///
+    /// Returns the canonical type, where the tuples are expanded into their component types,
+    /// which is used in the method signature.
+    ///
+    pub fn canonical_type(&self) -> String {
+        match self.r#type.strip_prefix("tuple") {
+            Some(dimensions) => format!(
+                "({}){}",
+                self.components
+                    .iter()
+                    .map(Self::canonical_type)
+                    .collect::<Vec<String>>()
+                    .join(","),
+                dimensions
+            ),
+            None => self.r#type.to_owned(),
+        }
+    }
+
+    ///
+    /// Maps the Zinc `type` to the ABI type and its components.
+    ///
+    fn map(r#type: &Type) -> (String, Vec<Self>) {
+        match r#type {
+            Type::Unit => ("tuple".to_owned(), vec![]),
+            Type::Scalar(ScalarType::Boolean) => ("bool".to_owned(), vec![]),
+            Type::Scalar(ScalarType::Integer(inner)) => (Self::integer(inner), vec![]),
+            Type::Scalar(ScalarType::Field) => ("field".to_owned(), vec![]),
+            Type::Enumeration {
+                bitlength,
+                variants,
+            } => (
+                Self::integer(&Type::enumeration_integer_type(
+                    *bitlength,
+                    variants.as_slice(),
+                )),
+                vec![],
+            ),
+            Type::Flags { bitlength, .. } => {
+                (Self::integer(&IntegerType::new(false, *bitlength)), vec![])
+            }
+            Type::Address => ("address".to_owned(), vec![]),
+            Type::Balance => (Self::integer(&IntegerType::BALANCE), vec![]),
+
+            Type::Array(r#type, size) => {
+                let (element, components) = Self::map(r#type);
+                (format!("{}[{}]", element, size), components)
+            }
+            Type::Tuple(types) => (
+                "tuple".to_owned(),
+                types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, r#type)| Self::new(index.to_string(), r#type))
+                    .collect(),
+            ),
+            Type::Structure(fields) => (
+                "tuple".to_owned(),
+                fields
+                    .iter()
+                    .map(|(name, r#type)| Self::new(name.to_owned(), r#type))
+                    .collect(),
+            ),
+            Type::Contract(_) => ("address".to_owned(), vec![]),
+
+            Type::Map {
+                key_type,
+                value_type,
+            } => (
+                format!(
+                    "mapping({} => {})",
+                    Self::map(key_type).0,
+                    Self::map(value_type).0
+                ),
+                vec![],
+            ),
+        }
+    }
+
+    ///
+    /// Maps the integer type to `uint{N}` or `int{N}`.
+    ///
+    fn integer(r#type: &IntegerType) -> String {
+        format!(
+            "{}int{}",
+            if r#type.is_signed { "" } else { "u" },
+            r#type.bitlength
+        )
+    }
+}
@@ -0,0 +1,326 @@
+//!
+//! The contract ABI tests.
+//!
+
+use indexmap::IndexMap;
+use num::BigInt;
+
+use crate::application::contract::abi::parameter::Parameter;
+use crate::application::contract::abi::EntryType;
+use crate::application::contract::abi::StateMutability;
+use crate::application::contract::method::Method;
+use crate::application::contract::Contract;
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+fn instance() -> (String, Type) {
+    (
+        "self".to_owned(),
+        Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+    )
+}
+
+fn transfer() -> Type {
+    Type::Structure(vec![
+        ("to".to_owned(), Type::Address),
+        ("amount".to_owned(), Type::Balance),
+    ])
+}
+
+fn method(
+    name: &str,
+    is_mutable: bool,
+    reads_msg: bool,
+    input: Vec<(String, Type)>,
+    output: Type,
+) -> Method {
+    Method::new(
+        0,
+        name.to_owned(),
+        0,
+        is_mutable,
+        reads_msg,
+        None,
+        Type::Structure(input),
+        output,
+    )
+}
+
+///
+/// The fixture contract:
+///
+/// ```zinc
+/// contract Wallet {
+///     pub owner: zksync::Address;
+///
+///     pub fn new(initial: zksync::Balance) -> Self;
+///     pub fn transfer(mut self, recipient: zksync::Address, amount: zksync::Balance, history: [Transfer; 2]);
+///     pub fn balance_of(self, owner: zksync::Address) -> zksync::Balance;
+///     pub fn deposit(mut self) -> bool;
+///     pub fn pair(self, kind: Kind, flag: (bool, field), offset: i64) -> (u8, [u8; 4]);
+/// }
+/// ```
+///
+fn contract() -> Contract {
+    let methods = vec![
+        method(
+            "new",
+            true,
+            false,
+            vec![("initial".to_owned(), Type::Balance)],
+            Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+        ),
+        method(
+            "transfer",
+            true,
+            false,
+            vec![
+                instance(),
+                ("recipient".to_owned(), Type::Address),
+                ("amount".to_owned(), Type::Balance),
+                ("history".to_owned(), Type::Array(Box::new(transfer()), 2)),
+            ],
+            Type::Unit,
+        ),
+        method(
+            "balance_of",
+            false,
+            false,
+            vec![instance(), ("owner".to_owned(), Type::Address)],
+            Type::Balance,
+        ),
+        method(
+            "deposit",
+            true,
+            true,
+            vec![instance()],
+            Type::Scalar(ScalarType::Boolean),
+        ),
+        method(
+            "pair",
+            false,
+            false,
+            vec![
+                instance(),
+                (
+                    "kind".to_owned(),
+                    Type::Enumeration {
+                        bitlength: 8,
+                        variants: vec![
+                            ("A".to_owned(), BigInt::from(1)),
+                            ("B".to_owned(), BigInt::from(2)),
+                        ],
+                    },
+                ),
+                (
+                    "flag".to_owned(),
+                    Type::Tuple(vec![
+                        Type::Scalar(ScalarType::Boolean),
+                        Type::Scalar(ScalarType::Field),
+                    ]),
+                ),
+                (
+                    "offset".to_owned(),
+                    Type::Scalar(ScalarType::Integer(IntegerType::new(true, 64))),
+                ),
+            ],
+            Type::Tuple(vec![
+                Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+                Type::Array(
+                    Box::new(Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+                    4,
+                ),
+            ]),
+        ),
+    ];
+
+    Contract::new(
+        "Wallet".to_owned(),
+        vec![ContractFieldType::new(
+            "owner".to_owned(),
+            Type::Address,
+            true,
+            false,
+        )],
+        methods
+            .into_iter()
+            .map(|method| (method.name.clone(), method))
+            .collect(),
+        IndexMap::new(),
+        vec![],
+        None,
+        vec![],
+    )
+}
+
+#[test]
+fn ok_methods_order() {
+    let names: Vec<String> = contract()
+        .abi()
+        .into_iter()
+        .map(|entry| entry.name)
+        .collect();
+
+    assert_eq!(
+        names,
+        vec!["new", "transfer", "balance_of", "deposit", "pair"]
+    );
+}
+
+#[test]
+fn ok_constructor() {
+    let abi = contract().abi();
+    let entry = &abi[0];
+
+    assert_eq!(entry.r#type, EntryType::Constructor);
+    assert_eq!(entry.selector, "0xb02274aa");
+    assert_eq!(entry.inputs.len(), 1);
+    assert_eq!(entry.inputs[0].r#type, "uint248");
+    assert!(entry.outputs.is_empty());
+}
+
+#[test]
+fn ok_state_mutability() {
+    let mutabilities: Vec<StateMutability> = contract()
+        .abi()
+        .into_iter()
+        .map(|entry| entry.state_mutability)
+        .collect();
+
+    assert_eq!(
+        mutabilities,
+        vec![
+            StateMutability::Nonpayable,
+            StateMutability::Nonpayable,
+            StateMutability::View,
+            StateMutability::Payable,
+            StateMutability::View,
+        ]
+    );
+}
+
+#[test]
+fn ok_instance_omitted() {
+    let abi = contract().abi();
+
+    assert!(abi[3].inputs.is_empty());
+    assert_eq!(abi[3].selector, "0x2df2cef7");
+    assert!(abi
+        .iter()
+        .all(|entry| entry.inputs.iter().all(|input| input.name != "self")));
+}
+
+#[test]
+fn ok_address_and_balance() {
+    let abi = contract().abi();
+    let entry = &abi[2];
+
+    assert_eq!(entry.r#type, EntryType::Function);
+    assert_eq!(entry.selector, "0xc0bb20c3");
+    assert_eq!(entry.inputs[0].r#type, "address");
+    assert_eq!(entry.inputs[0].internal_type, "zksync::Address");
+    assert_eq!(entry.outputs[0].r#type, "uint248");
+    assert_eq!(entry.outputs[0].internal_type, "zksync::Balance");
+}
+
+#[test]
+fn ok_array_of_structures() {
+    let abi = contract().abi();
+    let entry = &abi[1];
+
+    assert!(entry.outputs.is_empty());
+    assert_eq!(
+        entry.inputs[2],
+        Parameter {
+            name: "history".to_owned(),
+            r#type: "tuple[2]".to_owned(),
+            internal_type: "[to: zksync::Address, amount: zksync::Balance; 2]".to_owned(),
+            components: vec![
+                Parameter {
+                    name: "to".to_owned(),
+                    r#type: "address".to_owned(),
+                    internal_type: "zksync::Address".to_owned(),
+                    components: vec![],
+                },
+                Parameter {
+                    name: "amount".to_owned(),
+                    r#type: "uint248".to_owned(),
+                    internal_type: "zksync::Balance".to_owned(),
+                    components: vec![],
+                },
+            ],
+        }
+    );
+    assert_eq!(
+        entry.inputs[2].canonical_type(),
+        "(address,uint248)[2]".to_owned()
+    );
+    assert_eq!(entry.selector, "0x65713f2d");
+}
+
+#[test]
+fn ok_tuple_and_enumeration() {
+    let abi = contract().abi();
+    let entry = &abi[4];
+
+    assert_eq!(entry.inputs[0].r#type, "uint8");
+    assert_eq!(entry.inputs[1].r#type, "tuple");
+    assert_eq!(
+        entry.inputs[1]
+            .components
+            .iter()
+            .map(|component| (component.name.as_str(), component.r#type.as_str()))
+            .collect::<Vec<(&str, &str)>>(),
+        vec![("0", "bool"), ("1", "field")]
+    );
+    assert_eq!(entry.inputs[2].r#type, "int64");
+    assert_eq!(entry.selector, "0xa4e142ec");
+
+    assert_eq!(entry.outputs.len(), 1);
+    assert_eq!(entry.outputs[0].name, "");
+    assert_eq!(entry.outputs[0].components[1].r#type, "uint8[4]");
+}
+
+#[test]
+fn ok_map() {
+    let parameter = Parameter::new(
+        "balances".to_owned(),
+        &Type::Map {
+            key_type: Box::new(Type::Address),
+            value_type: Box::new(Type::Balance),
+        },
+    );
+
+    assert_eq!(parameter.r#type, "mapping(address => uint248)");
+}
+
+#[test]
+fn ok_json() {
+    let abi = contract().abi();
+
+    assert_eq!(
+        serde_json::to_value(&abi[2]).expect(zinc_const::panic::DATA_CONVERSION),
+        serde_json::json!({
+            "type": "function",
+            "name": "balance_of",
+            "selector": "0xc0bb20c3",
+            "inputs": [
+                {
+                    "name": "owner",
+                    "type": "address",
+                    "internalType": "zksync::Address",
+                }
+            ],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "uint248",
+                    "internalType": "zksync::Balance",
+                }
+            ],
+            "stateMutability": "view",
+        })
+    );
+}
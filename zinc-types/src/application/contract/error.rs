@@ -0,0 +1,39 @@
+//!
+//! The bytecode contract application upgrade error.
+//!
+
+use thiserror::Error;
+
+///
+/// The contract upgrade error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The storage layout has changed beyond appending fields, but the new version has no
+    /// migration method.
+    #[error("the storage layout `{current}` is not an extension of the previous one `{previous}`, and the contract has no `#[upgrade]` method:\n{diff}")]
+    StorageLayoutMismatch {
+        /// The previous version storage layout hash.
+        previous: String,
+        /// The new version storage layout hash.
+        current: String,
+        /// The mismatched fields, where the previous ones are prefixed with `-` and the new
+        /// ones with `+`.
+        diff: String,
+    },
+
+    /// The migration method is declared, but missing from the contract methods.
+    #[error("the `#[upgrade]` method `{0}` is not found")]
+    MigrationNotFound(String),
+
+    /// The migration method argument does not match the previous version storage.
+    #[error("the `#[upgrade]` method `{method}` argument type `{found}` does not match the previous storage `{expected}`")]
+    MigrationArgumentType {
+        /// The migration method name.
+        method: String,
+        /// The previous version storage fields as a structure.
+        expected: String,
+        /// The migration method argument type.
+        found: String,
+    },
+}
@@ -20,6 +20,13 @@ pub struct Method {
     pub address: usize,
     /// Whether the method can mutate the contract storage state.
     pub is_mutable: bool,
+    /// Whether the method or any function it calls reads the `zksync::msg` transaction variable,
+    /// which must be supplied by the server from the submitted transaction.
+    pub reads_msg: bool,
+    /// The contract storage field holding the only address allowed to call the method, which is
+    /// set by the `#[require_sender(field)]` and `#[only_owner]` attributes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_sender: Option<String>,
     /// The contract method input arguments as a structure.
     pub input: Type,
     /// The contract method output type.
@@ -35,6 +42,8 @@ impl Method {
         name: String,
         address: usize,
         is_mutable: bool,
+        reads_msg: bool,
+        required_sender: Option<String>,
         input: Type,
         output: Type,
     ) -> Self {
@@ -43,6 +52,8 @@ impl Method {
             name,
             address,
             is_mutable,
+            reads_msg,
+            required_sender,
             input,
             output,
         }
@@ -0,0 +1,43 @@
+//!
+//! The bytecode contract application storage migration.
+//!
+
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+
+use super::method::Method;
+
+///
+/// The way the storage of the previous contract version is carried over to the new one.
+///
+#[derive(Debug, Clone)]
+pub enum Migration<'a> {
+    /// The storage layouts match, so the storage is reused as is.
+    Reuse,
+    /// The new version only appends the fields to the previous layout, so the previous storage
+    /// is reused, and the appended fields are initialized with the default values.
+    Append(&'a [ContractFieldType]),
+    /// The storage is transformed by the `#[upgrade]` method of the new version.
+    Method(&'a Method),
+}
+
+impl<'a> Migration<'a> {
+    ///
+    /// Returns the `#[upgrade]` method, if the storage must be transformed by it.
+    ///
+    pub fn method(&self) -> Option<&'a Method> {
+        match *self {
+            Self::Method(method) => Some(method),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the fields appended to the previous storage layout.
+    ///
+    pub fn appended_fields(&self) -> &'a [ContractFieldType] {
+        match *self {
+            Self::Append(fields) => fields,
+            _ => &[],
+        }
+    }
+}
@@ -2,18 +2,31 @@
 //! The bytecode contract application.
 //!
 
-pub mod method;
+#[cfg(test)]
+mod tests;
 
-use std::collections::HashMap;
+pub mod abi;
+pub mod error;
+pub mod method;
+pub mod migration;
+pub mod pruning;
 
+use indexmap::IndexMap;
+use rustc_hex::ToHex;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
 
 use crate::application::unit_test::UnitTest;
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::Type;
 use crate::instructions::Instruction;
 
+use self::abi::Entry as AbiEntry;
+use self::error::Error;
 use self::method::Method;
+use self::migration::Migration;
+use self::pruning::error::Error as PruningError;
 
 ///
 /// The bytecode contract application.
@@ -24,10 +37,31 @@ pub struct Contract {
     pub name: String,
     /// The contract storage structure.
     pub storage: Vec<ContractFieldType>,
+    /// The contract storage layout hash, which depends on the field names, types, and order.
+    pub storage_layout: String,
+    /// The number of the contract storage leaves, which the server checks against the storage
+    /// Merkle tree capacity.
+    #[serde(default)]
+    pub storage_leaves: usize,
+    /// The input template digest, which depends on the storage and method arguments types.
+    #[serde(default)]
+    pub input_template: String,
+    /// The name of the method marked with the `#[upgrade]` attribute, if any.
+    #[serde(default)]
+    pub upgrade: Option<String>,
+    /// The names of the interfaces, which the contract implements.
+    #[serde(default)]
+    pub interfaces: Vec<String>,
+    /// The hexadecimal circuit hash of the complete artifact, if the contract has been pruned to
+    /// a subset of its methods. The pruned artifacts are never published.
+    #[serde(default)]
+    pub pruned_from: Option<String>,
     /// The contract methods.
-    pub methods: HashMap<String, Method>,
+    #[serde(deserialize_with = "crate::bounded::deserialize_index_map")]
+    pub methods: IndexMap<String, Method>,
     /// The contract unit tests.
-    pub unit_tests: HashMap<String, UnitTest>,
+    #[serde(deserialize_with = "crate::bounded::deserialize_index_map")]
+    pub unit_tests: IndexMap<String, UnitTest>,
     /// The contract bytecode instructions.
     pub instructions: Vec<Instruction>,
 }
@@ -39,16 +73,265 @@ impl Contract {
     pub fn new(
         name: String,
         storage: Vec<ContractFieldType>,
-        methods: HashMap<String, Method>,
-        unit_tests: HashMap<String, UnitTest>,
+        methods: IndexMap<String, Method>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
+        upgrade: Option<String>,
+        interfaces: Vec<String>,
     ) -> Self {
+        let storage_layout = Self::storage_layout_hash(storage.as_slice());
+        let storage_leaves = Self::storage_leaf_count(storage.as_slice());
+        let input_template = Self::input_template_hash(storage.as_slice(), &methods);
+
         Self {
             name,
             storage,
+            storage_layout,
+            storage_leaves,
+            input_template,
+            upgrade,
+            interfaces,
+            pruned_from: None,
             methods,
             unit_tests,
             instructions,
         }
     }
+
+    ///
+    /// Prunes the contract to the `methods` and the functions they call transitively.
+    ///
+    /// The other methods, the unit tests, and the interfaces, which may be implemented only
+    /// partially now, are removed. The `#[upgrade]` method is only kept if it is requested. The
+    /// constants are inlined into the instructions, so the kept functions never refer to the
+    /// removed ones. The storage and input template are unchanged, so the input file of the
+    /// complete contract is still valid.
+    ///
+    /// The pruned contract records the `circuit_hash` of the complete bytecode file, which is
+    /// preserved if the contract is pruned again.
+    ///
+    pub fn prune(&self, methods: &[String], circuit_hash: &[u8]) -> Result<Self, PruningError> {
+        if methods.is_empty() {
+            return Err(PruningError::MethodsEmpty);
+        }
+        if let Some(name) = methods
+            .iter()
+            .find(|name| !self.methods.contains_key(name.as_str()))
+        {
+            return Err(PruningError::MethodNotFound(name.to_owned()));
+        }
+
+        let mut kept: IndexMap<String, Method> = self
+            .methods
+            .iter()
+            .filter(|(name, _method)| methods.contains(name))
+            .map(|(name, method)| (name.to_owned(), method.to_owned()))
+            .collect();
+        let entries: Vec<usize> = kept.values().map(|method| method.address).collect();
+        let (instructions, addresses) =
+            pruning::prune(self.instructions.as_slice(), entries.as_slice())?;
+        for method in kept.values_mut() {
+            method.address = addresses
+                .get(&method.address)
+                .copied()
+                .ok_or(PruningError::FunctionAddressInvalid(method.address))?;
+        }
+
+        Ok(Self {
+            name: self.name.to_owned(),
+            storage: self.storage.to_owned(),
+            storage_layout: self.storage_layout.to_owned(),
+            storage_leaves: self.storage_leaves,
+            input_template: self.input_template.to_owned(),
+            upgrade: self
+                .upgrade
+                .to_owned()
+                .filter(|name| kept.contains_key(name.as_str())),
+            interfaces: vec![],
+            pruned_from: Some(
+                self.pruned_from
+                    .to_owned()
+                    .unwrap_or_else(|| circuit_hash.to_hex()),
+            ),
+            methods: kept,
+            unit_tests: IndexMap::new(),
+            instructions,
+        })
+    }
+
+    ///
+    /// Describes the contract methods in the Ethereum-style ABI format.
+    ///
+    pub fn abi(&self) -> Vec<AbiEntry> {
+        self.methods.values().map(AbiEntry::new).collect()
+    }
+
+    ///
+    /// Computes the storage layout hash, which is the hexadecimal SHA-256 digest of the storage
+    /// field names and types in their declaration order.
+    ///
+    pub fn storage_layout_hash(storage: &[ContractFieldType]) -> String {
+        let mut hasher = sha2::Sha256::new();
+        for field in storage.iter() {
+            hasher.update(format!("{}: {};", field.name, field.r#type).as_bytes());
+        }
+        hasher.finalize()[..].to_hex()
+    }
+
+    ///
+    /// Computes the input template digest, which is the hexadecimal SHA-256 digest of the
+    /// `storage` field types and the `methods` arguments types.
+    ///
+    pub fn input_template_hash(
+        storage: &[ContractFieldType],
+        methods: &IndexMap<String, Method>,
+    ) -> String {
+        let mut hasher = sha2::Sha256::new();
+        for field in storage.iter() {
+            hasher.update(format!("{}: {};", field.name, field.r#type).as_bytes());
+        }
+        for (name, method) in methods.iter() {
+            hasher.update(format!("{}({});", name, method.input).as_bytes());
+        }
+        hasher.finalize()[..].to_hex()
+    }
+
+    ///
+    /// Computes the number of the storage leaves occupied by all the `storage` fields.
+    ///
+    pub fn storage_leaf_count(storage: &[ContractFieldType]) -> usize {
+        storage.iter().map(|field| field.leaves.len()).sum()
+    }
+
+    ///
+    /// The storage fields with the numbers of leaves they occupy, the largest first.
+    ///
+    /// The fields with equal leaf counts keep their declaration order.
+    ///
+    pub fn storage_sizing(&self) -> Vec<(String, usize)> {
+        let mut fields: Vec<(String, usize)> = self
+            .storage
+            .iter()
+            .map(|field| (field.name.to_owned(), field.leaves.len()))
+            .collect();
+        fields.sort_by(|(_, a), (_, b)| b.cmp(a));
+        fields
+    }
+
+    ///
+    /// The explicit storage fields as a structure, which is the argument of the migration method
+    /// of the next contract version.
+    ///
+    pub fn explicit_storage_type(&self) -> Type {
+        Type::Structure(
+            self.storage
+                .iter()
+                .filter(|field| !field.is_implicit)
+                .map(|field| (field.name.to_owned(), field.r#type.to_owned()))
+                .collect(),
+        )
+    }
+
+    ///
+    /// Checks whether the contract can replace the `previous` version.
+    ///
+    /// The storage is reused as is if the storage layouts match. If the contract has the
+    /// `#[upgrade]` method, it transforms the previous storage, and its argument is checked
+    /// against the previous storage layout. Otherwise, the new layout may only append fields
+    /// to the previous one, keeping the names, types, and order of the existing fields.
+    ///
+    pub fn migration(&self, previous: &Self) -> Result<Migration, Error> {
+        if self.storage_layout == previous.storage_layout {
+            return Ok(Migration::Reuse);
+        }
+
+        let name = match self.upgrade {
+            Some(ref name) => name,
+            None => {
+                return match self.appended_fields(previous) {
+                    Some(fields) => Ok(Migration::Append(fields)),
+                    None => Err(Error::StorageLayoutMismatch {
+                        previous: previous.storage_layout.to_owned(),
+                        current: self.storage_layout.to_owned(),
+                        diff: Self::storage_diff(
+                            previous.storage.as_slice(),
+                            self.storage.as_slice(),
+                        ),
+                    }),
+                };
+            }
+        };
+        let method = self
+            .methods
+            .get(name)
+            .ok_or_else(|| Error::MigrationNotFound(name.to_owned()))?;
+
+        let expected = previous.explicit_storage_type();
+        let found = match method.input {
+            Type::Structure(ref fields) if fields.len() == 1 => fields[0].1.to_owned(),
+            ref r#type => r#type.to_owned(),
+        };
+        if found != expected {
+            return Err(Error::MigrationArgumentType {
+                method: name.to_owned(),
+                expected: expected.to_string(),
+                found: found.to_string(),
+            });
+        }
+
+        Ok(Migration::Method(method))
+    }
+
+    ///
+    /// Returns the fields appended to the `previous` version storage, if the previous fields are
+    /// the unchanged prefix of the storage.
+    ///
+    pub fn appended_fields(&self, previous: &Self) -> Option<&[ContractFieldType]> {
+        if self.storage.len() <= previous.storage.len() {
+            return None;
+        }
+
+        let is_prefix =
+            previous
+                .storage
+                .iter()
+                .zip(self.storage.iter())
+                .all(|(previous, current)| {
+                    previous.name == current.name && previous.r#type == current.r#type
+                });
+        if !is_prefix {
+            return None;
+        }
+
+        Some(&self.storage[previous.storage.len()..])
+    }
+
+    ///
+    /// Lists the mismatched fields at the same positions, prefixing the `previous` ones with `-`
+    /// and the `current` ones with `+`.
+    ///
+    pub fn storage_diff(previous: &[ContractFieldType], current: &[ContractFieldType]) -> String {
+        let mut lines = Vec::new();
+
+        for index in 0..std::cmp::max(previous.len(), current.len()) {
+            let previous = previous
+                .get(index)
+                .map(|field| format!("{}: {}", field.name, field.r#type));
+            let current = current
+                .get(index)
+                .map(|field| format!("{}: {}", field.name, field.r#type));
+            if previous == current {
+                continue;
+            }
+
+            if let Some(previous) = previous {
+                lines.push(format!("- {}", previous));
+            }
+            if let Some(current) = current {
+                lines.push(format!("+ {}", current));
+            }
+        }
+
+        lines.join("\n")
+    }
 }
@@ -0,0 +1,23 @@
+//!
+//! The bytecode contract application pruning error.
+//!
+
+use thiserror::Error;
+
+///
+/// The contract pruning error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// No methods to keep are specified.
+    #[error("at least one method to keep must be specified")]
+    MethodsEmpty,
+
+    /// The method to keep is missing from the contract methods.
+    #[error("the method `{0}` is not found")]
+    MethodNotFound(String),
+
+    /// The method or call address does not point to the start of a function.
+    #[error("the address {0} is not the start of a function")]
+    FunctionAddressInvalid(usize),
+}
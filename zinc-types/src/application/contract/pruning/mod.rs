@@ -0,0 +1,89 @@
+//!
+//! The bytecode contract application pruning.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::instructions::Instruction;
+
+use self::error::Error;
+
+///
+/// Removes the functions, which are not reachable from the `entries` addresses.
+///
+/// Every function starts at its address and ends with its only `Return` instruction, so the
+/// bytecode is split into functions at the `Return` instructions. The functions called from the
+/// entries are kept transitively, and the `Call` addresses are shifted by the number of the
+/// removed instructions before them.
+///
+/// Returns the pruned instructions and the mapping of the kept function addresses.
+///
+pub fn prune(
+    instructions: &[Instruction],
+    entries: &[usize],
+) -> Result<(Vec<Instruction>, HashMap<usize, usize>), Error> {
+    let reachable = reachable(instructions, entries);
+
+    let mut pruned = Vec::with_capacity(instructions.len());
+    let mut addresses = HashMap::with_capacity(reachable.len());
+    let mut function_start = 0;
+    let mut is_kept = false;
+    for (address, instruction) in instructions.iter().enumerate() {
+        if address == function_start {
+            is_kept = reachable.contains(&address);
+            if is_kept {
+                addresses.insert(address, pruned.len());
+            }
+        }
+
+        if is_kept {
+            pruned.push(instruction.to_owned());
+        }
+
+        if let Instruction::Return(_) = instruction {
+            function_start = address + 1;
+        }
+    }
+
+    for instruction in pruned.iter_mut() {
+        if let Instruction::Call(ref mut call) = instruction {
+            call.address = addresses
+                .get(&call.address)
+                .copied()
+                .ok_or(Error::FunctionAddressInvalid(call.address))?;
+        }
+    }
+
+    Ok((pruned, addresses))
+}
+
+///
+/// Collects the addresses of the functions called from the `entries` transitively, including
+/// the entries themselves.
+///
+pub fn reachable(instructions: &[Instruction], entries: &[usize]) -> BTreeSet<usize> {
+    let mut reachable = BTreeSet::new();
+
+    let mut stack = entries.to_vec();
+    while let Some(start_address) = stack.pop() {
+        if !reachable.insert(start_address) {
+            continue;
+        }
+
+        for instruction in instructions.iter().skip(start_address) {
+            match instruction {
+                Instruction::Call(call) => stack.push(call.address),
+                Instruction::Return(_) => break,
+                _ => {}
+            }
+        }
+    }
+
+    reachable
+}
@@ -0,0 +1,199 @@
+//!
+//! The bytecode contract application pruning tests.
+//!
+
+use indexmap::IndexMap;
+use num::BigInt;
+
+use crate::application::contract::method::Method;
+use crate::application::contract::pruning::error::Error;
+use crate::application::contract::Contract;
+use crate::application::unit_test::UnitTest;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::Instruction;
+
+/// The circuit hash of the complete contract bytecode file.
+const CIRCUIT_HASH: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+fn method(name: &str, address: usize) -> Method {
+    Method::new(
+        0,
+        name.to_owned(),
+        address,
+        false,
+        false,
+        None,
+        Type::Structure(vec![]),
+        Type::Scalar(ScalarType::Field),
+    )
+}
+
+fn push(value: usize) -> Instruction {
+    Instruction::Push(Push::new_field(BigInt::from(value)))
+}
+
+fn call(address: usize) -> Instruction {
+    Instruction::Call(Call::new(address, 0))
+}
+
+fn r#return(output_size: usize) -> Instruction {
+    Instruction::Return(Return::new(output_size))
+}
+
+///
+/// The contract bytecode:
+///
+/// ```text
+/// 0:  fn removed() -> field   { 1 }
+/// 2:  fn query() -> field     { shared() }
+/// 4:  fn mutate() -> field    { removed() }
+/// 6:  fn shared() -> field    { leaf() }
+/// 8:  fn leaf() -> field      { 2 }
+/// 10: #[test] fn test()       { mutate(); }
+/// ```
+///
+fn contract() -> Contract {
+    let mut methods = IndexMap::new();
+    methods.insert("query".to_owned(), method("query", 2));
+    methods.insert("mutate".to_owned(), method("mutate", 4));
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "test".to_owned(),
+        UnitTest::new(10, false, None, false, false, None, None),
+    );
+
+    Contract::new(
+        "test".to_owned(),
+        vec![],
+        methods,
+        unit_tests,
+        vec![
+            push(1),
+            r#return(1),
+            call(6),
+            r#return(1),
+            call(0),
+            r#return(1),
+            call(8),
+            r#return(1),
+            push(2),
+            r#return(1),
+            call(4),
+            r#return(0),
+        ],
+        Some("mutate".to_owned()),
+        vec!["Queryable".to_owned()],
+    )
+}
+
+#[test]
+fn ok_transitive_callees() {
+    let pruned = contract()
+        .prune(&["query".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        pruned.instructions,
+        vec![
+            call(2),
+            r#return(1),
+            call(4),
+            r#return(1),
+            push(2),
+            r#return(1)
+        ]
+    );
+    assert_eq!(pruned.methods.len(), 1);
+    assert_eq!(pruned.methods["query"].address, 0);
+}
+
+#[test]
+fn ok_addresses_shifted() {
+    let pruned = contract()
+        .prune(&["mutate".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        pruned.instructions,
+        vec![push(1), r#return(1), call(0), r#return(1)]
+    );
+    assert_eq!(pruned.methods["mutate"].address, 2);
+    assert_eq!(pruned.upgrade.as_deref(), Some("mutate"));
+}
+
+#[test]
+fn ok_all_methods() {
+    let contract = contract();
+
+    let pruned = contract
+        .prune(&["mutate".to_owned(), "query".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(pruned.instructions, contract.instructions[..10].to_vec());
+    assert_eq!(
+        pruned.methods.keys().collect::<Vec<&String>>(),
+        vec!["query", "mutate"]
+    );
+    assert!(pruned.unit_tests.is_empty());
+}
+
+#[test]
+fn ok_metadata() {
+    let contract = contract();
+
+    let pruned = contract
+        .prune(&["query".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(contract.pruned_from, None);
+    assert_eq!(pruned.pruned_from.as_deref(), Some("deadbeef"));
+    assert_eq!(pruned.upgrade, None);
+    assert!(pruned.interfaces.is_empty());
+    assert!(pruned.unit_tests.is_empty());
+    assert_eq!(pruned.storage_layout, contract.storage_layout);
+    assert_eq!(pruned.input_template, contract.input_template);
+}
+
+#[test]
+fn ok_pruned_again() {
+    let pruned = contract()
+        .prune(&["query".to_owned(), "mutate".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .prune(&["query".to_owned()], &[0x00])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(pruned.pruned_from.as_deref(), Some("deadbeef"));
+    assert_eq!(pruned.instructions.len(), 6);
+}
+
+#[test]
+fn error_methods_empty() {
+    let result = contract().prune(&[], &CIRCUIT_HASH);
+
+    assert_eq!(result.err(), Some(Error::MethodsEmpty));
+}
+
+#[test]
+fn error_method_not_found() {
+    let result = contract().prune(&["query".to_owned(), "missing".to_owned()], &CIRCUIT_HASH);
+
+    assert_eq!(
+        result.err(),
+        Some(Error::MethodNotFound("missing".to_owned()))
+    );
+}
+
+#[test]
+fn error_function_address_invalid() {
+    let mut contract = contract();
+    contract.instructions[2] = call(7);
+
+    let result = contract.prune(&["query".to_owned()], &CIRCUIT_HASH);
+
+    assert_eq!(result.err(), Some(Error::FunctionAddressInvalid(7)));
+}
@@ -0,0 +1,187 @@
+//!
+//! The bytecode contract application tests.
+//!
+
+use indexmap::IndexMap;
+
+use crate::application::contract::error::Error;
+use crate::application::contract::method::Method;
+use crate::application::contract::migration::Migration;
+use crate::application::contract::Contract;
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+fn field(name: &str, r#type: Type) -> ContractFieldType {
+    ContractFieldType::new(name.to_owned(), r#type, true, false)
+}
+
+fn contract(storage: Vec<ContractFieldType>, upgrade: Option<(&str, Type)>) -> Contract {
+    let mut methods = IndexMap::new();
+    if let Some((name, argument)) = upgrade.as_ref() {
+        methods.insert(
+            name.to_string(),
+            Method::new(
+                0,
+                name.to_string(),
+                0,
+                false,
+                false,
+                None,
+                Type::Structure(vec![("old".to_owned(), argument.to_owned())]),
+                Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+            ),
+        );
+    }
+
+    Contract::new(
+        "test".to_owned(),
+        storage,
+        methods,
+        IndexMap::new(),
+        vec![],
+        upgrade.map(|(name, _argument)| name.to_owned()),
+        vec![],
+    )
+}
+
+fn storage_v1() -> Vec<ContractFieldType> {
+    vec![
+        field("value", Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+        field("total", Type::Scalar(ScalarType::Field)),
+    ]
+}
+
+fn storage_v2() -> Vec<ContractFieldType> {
+    vec![
+        field("value", Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+        field("total", Type::Scalar(ScalarType::Field)),
+        field("is_active", Type::Scalar(ScalarType::Boolean)),
+    ]
+}
+
+#[test]
+fn ok_storage_layout_hash_deterministic() {
+    assert_eq!(
+        Contract::storage_layout_hash(storage_v1().as_slice()),
+        Contract::storage_layout_hash(storage_v1().as_slice()),
+    );
+}
+
+#[test]
+fn ok_storage_layout_hash_changes() {
+    let original = Contract::storage_layout_hash(storage_v1().as_slice());
+
+    let mut reordered = storage_v1();
+    reordered.reverse();
+    assert_ne!(
+        Contract::storage_layout_hash(reordered.as_slice()),
+        original
+    );
+
+    let mut renamed = storage_v1();
+    renamed[0].name = "amount".to_owned();
+    assert_ne!(Contract::storage_layout_hash(renamed.as_slice()), original);
+
+    let mut retyped = storage_v1();
+    retyped[0].r#type = Type::Scalar(ScalarType::Integer(IntegerType::BALANCE));
+    assert_ne!(Contract::storage_layout_hash(retyped.as_slice()), original);
+}
+
+#[test]
+fn ok_migration_same_layout() {
+    let previous = contract(storage_v1(), None);
+    let current = contract(storage_v1(), None);
+
+    assert!(matches!(current.migration(&previous), Ok(Migration::Reuse)));
+}
+
+#[test]
+fn ok_migration_method() {
+    let previous = contract(storage_v1(), None);
+    let current = contract(
+        storage_v2(),
+        Some(("migrate", previous.explicit_storage_type())),
+    );
+
+    let method = current
+        .migration(&previous)
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .method()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(method.name, "migrate");
+}
+
+#[test]
+fn ok_migration_append() {
+    let previous = contract(storage_v1(), None);
+    let current = contract(storage_v2(), None);
+
+    let migration = current
+        .migration(&previous)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        migration.appended_fields(),
+        &[field("is_active", Type::Scalar(ScalarType::Boolean))]
+    );
+}
+
+#[test]
+fn error_storage_layout_reordered() {
+    let previous = contract(storage_v1(), None);
+    let mut storage = storage_v2();
+    storage.swap(0, 1);
+    let current = contract(storage, None);
+
+    let expected = Err(Error::StorageLayoutMismatch {
+        previous: previous.storage_layout.clone(),
+        current: current.storage_layout.clone(),
+        diff: "- value: u8\n+ total: field\n- total: field\n+ value: u8\n+ is_active: bool"
+            .to_owned(),
+    });
+
+    let result = current.migration(&previous).map(|_migration| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_storage_layout_retyped() {
+    let previous = contract(storage_v1(), None);
+    let mut storage = storage_v2();
+    storage[1] = field("total", Type::Scalar(ScalarType::Integer(IntegerType::U8)));
+    let current = contract(storage, None);
+
+    let expected = Err(Error::StorageLayoutMismatch {
+        previous: previous.storage_layout.clone(),
+        current: current.storage_layout.clone(),
+        diff: "- total: field\n+ total: u8\n+ is_active: bool".to_owned(),
+    });
+
+    let result = current.migration(&previous).map(|_migration| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_migration_argument_type() {
+    let previous = contract(storage_v1(), None);
+    let argument = Type::Structure(vec![(
+        "value".to_owned(),
+        Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+    )]);
+    let current = contract(storage_v2(), Some(("migrate", argument.clone())));
+
+    let expected = Err(Error::MigrationArgumentType {
+        method: "migrate".to_owned(),
+        expected: previous.explicit_storage_type().to_string(),
+        found: argument.to_string(),
+    });
+
+    let result = current.migration(&previous).map(|_migration| ());
+
+    assert_eq!(result, expected);
+}
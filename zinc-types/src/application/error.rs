@@ -0,0 +1,146 @@
+//!
+//! The bytecode application decoding error.
+//!
+
+use thiserror::Error;
+
+///
+/// The bytecode application decoding error.
+///
+/// Each variant carries the artifact byte offset where the decoding has failed.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The artifact ends before the expected data.
+    #[error("unexpected end of the artifact at offset {offset}: expected {expected} more bytes")]
+    UnexpectedEnd {
+        /// The artifact byte offset.
+        offset: usize,
+        /// The number of the missing bytes.
+        expected: usize,
+    },
+
+    /// The artifact format version is not the supported one.
+    #[error("unsupported artifact format version {found} at offset {offset}: the supported version is {supported}")]
+    UnsupportedVersion {
+        /// The artifact byte offset.
+        offset: usize,
+        /// The artifact format version.
+        found: u16,
+        /// The supported format version.
+        supported: u16,
+    },
+
+    /// The header string is not a valid UTF-8 one.
+    #[error("invalid UTF-8 string at offset {offset}")]
+    InvalidString {
+        /// The artifact byte offset.
+        offset: usize,
+    },
+
+    /// The serialized application is malformed.
+    #[error("invalid application data at offset {offset}: {message}")]
+    InvalidApplication {
+        /// The artifact byte offset.
+        offset: usize,
+        /// The deserializer error description.
+        message: String,
+    },
+
+    /// The application data ends before the expected data, e.g. in an instruction operand or
+    /// a string, whose length prefix exceeds the remaining data.
+    #[error("unexpected end of the application data at offset {offset}")]
+    Truncated {
+        /// The artifact byte offset.
+        offset: usize,
+    },
+
+    /// The enumeration variant tag, e.g. an instruction opcode, is unknown.
+    #[error("unknown variant tag at offset {offset}: {message}")]
+    UnknownVariant {
+        /// The artifact byte offset of the tag.
+        offset: usize,
+        /// The deserializer error description.
+        message: String,
+    },
+
+    /// The entry point of the application, a contract method, or a unit test is out of the
+    /// instructions range.
+    #[error("the entry `{name}` address {address} is out of the {length} instructions at offset {offset}")]
+    EntryOutOfRange {
+        /// The artifact byte offset of the application data.
+        offset: usize,
+        /// The entry name.
+        name: String,
+        /// The entry instruction address.
+        address: usize,
+        /// The number of instructions.
+        length: usize,
+    },
+
+    /// The call target is out of the instructions range.
+    #[error("instruction #{index} at offset {offset} calls the address {target} out of the {length} instructions")]
+    TargetOutOfRange {
+        /// The artifact byte offset of the instruction.
+        offset: usize,
+        /// The instruction index.
+        index: usize,
+        /// The call target instruction address.
+        target: usize,
+        /// The number of instructions.
+        length: usize,
+    },
+
+    /// The instruction address or size operand exceeds the virtual machine limits.
+    #[error("instruction #{index} at offset {offset} has the operand `{operand}` equal to {value}, which exceeds {limit}")]
+    OperandOutOfRange {
+        /// The artifact byte offset of the instruction.
+        offset: usize,
+        /// The instruction index.
+        index: usize,
+        /// The operand name.
+        operand: &'static str,
+        /// The operand value.
+        value: usize,
+        /// The operand maximal value.
+        limit: usize,
+    },
+
+    /// The type cannot be represented by the virtual machine, e.g. an integer of invalid
+    /// bitlength or a type exceeding the data stack size.
+    #[error("invalid type at offset {offset}: {reason}")]
+    InvalidType {
+        /// The artifact byte offset of the instruction or the application data.
+        offset: usize,
+        /// The type error description.
+        reason: String,
+    },
+
+    /// There is unexpected data after the serialized application.
+    #[error("unexpected trailing data at offset {offset}")]
+    TrailingData {
+        /// The artifact byte offset.
+        offset: usize,
+    },
+}
+
+impl Error {
+    ///
+    /// The artifact byte offset where the decoding has failed.
+    ///
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::UnexpectedEnd { offset, .. } => *offset,
+            Self::UnsupportedVersion { offset, .. } => *offset,
+            Self::InvalidString { offset } => *offset,
+            Self::InvalidApplication { offset, .. } => *offset,
+            Self::Truncated { offset } => *offset,
+            Self::UnknownVariant { offset, .. } => *offset,
+            Self::EntryOutOfRange { offset, .. } => *offset,
+            Self::TargetOutOfRange { offset, .. } => *offset,
+            Self::OperandOutOfRange { offset, .. } => *offset,
+            Self::InvalidType { offset, .. } => *offset,
+            Self::TrailingData { offset } => *offset,
+        }
+    }
+}
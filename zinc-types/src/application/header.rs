@@ -0,0 +1,152 @@
+//!
+//! The bytecode application artifact header.
+//!
+
+use std::convert::TryInto;
+
+use serde::Serialize;
+
+use crate::application::error::Error;
+
+///
+/// The bytecode application artifact header, which precedes the serialized application.
+///
+/// The header consists of the magic bytes, the little-endian format and standard library
+/// versions, and the length-prefixed toolchain and project version strings.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Header {
+    /// The artifact format version. `0` for the legacy artifacts written without the header.
+    pub format_version: u16,
+    /// The standard library API version the artifact has been built against. `1` for the
+    /// artifacts written before the standard library has been versioned.
+    pub std_version: u16,
+    /// The version of the Zinc toolchain which has built the artifact.
+    pub compiler_version: Option<String>,
+    /// The version of the project the artifact has been built from.
+    pub project_version: Option<String>,
+}
+
+impl Header {
+    /// The magic bytes the artifact starts with.
+    pub const MAGIC: &'static [u8] = b"ZNB\0";
+    /// The current artifact format version.
+    pub const FORMAT_VERSION: u16 = 3;
+    /// The oldest supported artifact format version, which has no standard library version.
+    pub const FORMAT_VERSION_MIN: u16 = 2;
+    /// The standard library version of the artifacts built before it has been versioned.
+    pub const STD_VERSION_INITIAL: u16 = 1;
+
+    ///
+    /// Creates the current format header for the project with `project_version`.
+    ///
+    pub fn new(project_version: &semver::Version) -> Self {
+        Self {
+            format_version: Self::FORMAT_VERSION,
+            std_version: zinc_const::version::STD,
+            compiler_version: Some(env!("CARGO_PKG_VERSION").to_owned()),
+            project_version: Some(project_version.to_string()),
+        }
+    }
+
+    ///
+    /// Creates the header of a legacy artifact, which has no version information.
+    ///
+    pub fn legacy() -> Self {
+        Self {
+            format_version: 0,
+            std_version: Self::STD_VERSION_INITIAL,
+            compiler_version: None,
+            project_version: None,
+        }
+    }
+
+    ///
+    /// Reads the header from the beginning of the artifact `bytes`.
+    ///
+    /// Returns the header and its size, which is `0` for the legacy artifacts.
+    ///
+    pub fn read(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        if !bytes.starts_with(Self::MAGIC) {
+            return Ok((Self::legacy(), 0));
+        }
+        let mut offset = Self::MAGIC.len();
+
+        let format_version = Self::read_u16(bytes, &mut offset)?;
+        if format_version < Self::FORMAT_VERSION_MIN || format_version > Self::FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion {
+                offset: offset - 2,
+                found: format_version,
+                supported: Self::FORMAT_VERSION,
+            });
+        }
+
+        let std_version = if format_version >= 3 {
+            Self::read_u16(bytes, &mut offset)?
+        } else {
+            Self::STD_VERSION_INITIAL
+        };
+
+        let compiler_version = Self::read_string(bytes, &mut offset)?;
+        let project_version = Self::read_string(bytes, &mut offset)?;
+
+        Ok((
+            Self {
+                format_version,
+                std_version,
+                compiler_version: Some(compiler_version),
+                project_version: Some(project_version),
+            },
+            offset,
+        ))
+    }
+
+    ///
+    /// Writes the header to the beginning of the artifact `bytes`.
+    ///
+    pub fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(Self::MAGIC);
+        bytes.extend_from_slice(&self.format_version.to_le_bytes());
+        bytes.extend_from_slice(&self.std_version.to_le_bytes());
+        for string in [&self.compiler_version, &self.project_version].iter() {
+            let string = string.as_deref().unwrap_or_default().as_bytes();
+            bytes.extend_from_slice(&(string.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(string);
+        }
+    }
+
+    ///
+    /// Reads `size` bytes at `offset`, moving the `offset` past them.
+    ///
+    fn read_bytes<'a>(bytes: &'a [u8], offset: &mut usize, size: usize) -> Result<&'a [u8], Error> {
+        let slice = bytes
+            .get(*offset..*offset + size)
+            .ok_or(Error::UnexpectedEnd {
+                offset: *offset,
+                expected: size,
+            })?;
+        *offset += size;
+        Ok(slice)
+    }
+
+    ///
+    /// Reads the little-endian `u16` at `offset`, moving the `offset` past it.
+    ///
+    fn read_u16(bytes: &[u8], offset: &mut usize) -> Result<u16, Error> {
+        let value = Self::read_bytes(bytes, offset, 2)?;
+        Ok(u16::from_le_bytes(
+            value.try_into().expect(zinc_const::panic::DATA_CONVERSION),
+        ))
+    }
+
+    ///
+    /// Reads the length-prefixed UTF-8 string at `offset`, moving the `offset` past it.
+    ///
+    fn read_string(bytes: &[u8], offset: &mut usize) -> Result<String, Error> {
+        let length = Self::read_u16(bytes, offset)?;
+
+        let start = *offset;
+        let string = Self::read_bytes(bytes, offset, length as usize)?;
+        String::from_utf8(string.to_vec()).map_err(|_| Error::InvalidString { offset: start })
+    }
+}
@@ -2,8 +2,7 @@
 //! The bytecode library.
 //!
 
-use std::collections::HashMap;
-
+use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -18,7 +17,8 @@ pub struct Library {
     /// The library name.
     pub name: String,
     /// The library unit tests.
-    pub unit_tests: HashMap<String, UnitTest>,
+    #[serde(deserialize_with = "crate::bounded::deserialize_index_map")]
+    pub unit_tests: IndexMap<String, UnitTest>,
     /// The library bytecode instructions.
     pub instructions: Vec<Instruction>,
 }
@@ -29,7 +29,7 @@ impl Library {
     ///
     pub fn new(
         name: String,
-        unit_tests: HashMap<String, UnitTest>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
         Self {
@@ -2,28 +2,41 @@
 //! The bytecode application.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod circuit;
 pub mod contract;
+pub mod error;
+pub mod header;
 pub mod library;
 pub mod unit_test;
+pub mod verifier;
 
-use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io::Cursor;
 
+use bincode::Options;
+use indexmap::IndexMap;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::application::unit_test::UnitTest;
+use crate::build::input::merge as input_merge;
 use crate::build::input::Input as InputBuild;
 use crate::build::Build;
 use crate::data::r#type::contract_field::ContractField as ContractFieldType;
 use crate::data::r#type::Type;
-use crate::data::value::Value;
 use crate::instructions::Instruction;
+use crate::transaction::msg::Msg as TransactionMsg;
 
 use self::circuit::Circuit;
 use self::contract::method::Method as ContractMethod;
 use self::contract::Contract;
+use self::error::Error;
+use self::header::Header;
 use self::library::Library;
+use self::verifier::Verifier;
 
 ///
 /// The bytecode application.
@@ -47,7 +60,7 @@ impl Application {
         address: usize,
         input: Type,
         output: Type,
-        unit_tests: HashMap<String, UnitTest>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
         Self::Circuit(Circuit::new(
@@ -66,9 +79,11 @@ impl Application {
     pub fn new_contract(
         name: String,
         storage: Vec<ContractFieldType>,
-        methods: HashMap<String, ContractMethod>,
-        unit_tests: HashMap<String, UnitTest>,
+        methods: IndexMap<String, ContractMethod>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
+        upgrade: Option<String>,
+        interfaces: Vec<String>,
     ) -> Self {
         Self::Contract(Contract::new(
             name,
@@ -76,6 +91,8 @@ impl Application {
             methods,
             unit_tests,
             instructions,
+            upgrade,
+            interfaces,
         ))
     }
 
@@ -84,7 +101,7 @@ impl Application {
     ///
     pub fn new_library(
         name: String,
-        unit_tests: HashMap<String, UnitTest>,
+        unit_tests: IndexMap<String, UnitTest>,
         instructions: Vec<Instruction>,
     ) -> Self {
         Self::Library(Library::new(name, unit_tests, instructions))
@@ -101,58 +118,123 @@ impl Application {
         }
     }
 
+    ///
+    /// Removes the unit tests, whose names do not match the `filter`.
+    ///
+    pub fn retain_unit_tests(&mut self, filter: &str) {
+        let unit_tests = match self {
+            Self::Circuit(ref mut inner) => &mut inner.unit_tests,
+            Self::Contract(ref mut inner) => &mut inner.unit_tests,
+            Self::Library(ref mut inner) => &mut inner.unit_tests,
+        };
+
+        unit_tests.retain(|name, _unit_test| UnitTest::matches(name.as_str(), filter));
+    }
+
     ///
     /// Converts the compiled application state into a set of byte arrays, which are ready to be
     /// written to the Zinc project build files.
     ///
-    pub fn into_build(self) -> Build {
+    /// The `project_version` is written to the bytecode artifact header.
+    ///
+    pub fn into_build(self, project_version: &semver::Version) -> Build {
+        let input = self.regenerate_input(None);
+        let bytecode = self.into_vec(project_version);
+
+        Build::new(bytecode, input)
+    }
+
+    ///
+    /// The input template digest, which the input file must be generated for.
+    ///
+    /// `None` for libraries, which have no input.
+    ///
+    pub fn input_template(&self) -> Option<&str> {
+        match self {
+            Self::Circuit(ref inner) => Some(inner.input_template.as_str()),
+            Self::Contract(ref inner) => Some(inner.input_template.as_str()),
+            Self::Library(_) => None,
+        }
+    }
+
+    ///
+    /// Generates the input file for the application.
+    ///
+    /// If the `previous` input file JSON is specified, its values are kept at the paths, which
+    /// are still valid for the application input types. Otherwise, the default template is generated.
+    ///
+    pub fn regenerate_input(&self, previous: Option<serde_json::Value>) -> InputBuild {
+        let mut previous = match previous {
+            Some(serde_json::Value::Object(previous)) => previous,
+            _ => serde_json::Map::new(),
+        };
+
         match self {
             Application::Circuit(circuit) => {
-                let arguments = Value::new(circuit.input.clone()).into_json();
-                let bytecode = Application::Circuit(circuit).into_vec();
+                let arguments =
+                    input_merge::value(previous.remove("arguments"), circuit.input.clone());
 
-                Build::new(bytecode, InputBuild::new_circuit(arguments))
+                InputBuild::new_circuit(Some(circuit.input_template.clone()), arguments)
             }
             Application::Contract(contract) => {
-                let mut arguments = HashMap::with_capacity(contract.methods.len());
+                let mut previous_arguments = match previous.remove("arguments") {
+                    Some(serde_json::Value::Object(arguments)) => arguments,
+                    _ => serde_json::Map::new(),
+                };
+                let mut arguments = IndexMap::with_capacity(contract.methods.len());
                 for (name, method) in contract.methods.iter() {
                     arguments.insert(
                         name.to_owned(),
-                        Value::new(method.input.to_owned()).into_json(),
+                        input_merge::value(
+                            previous_arguments.remove(name.as_str()),
+                            method.input.to_owned(),
+                        ),
                     );
                 }
 
-                let fields: Vec<serde_json::Value> = contract
+                let types: Vec<Type> = contract
                     .storage
-                    .clone()
-                    .into_iter()
-                    .map(|field| Value::new(field.r#type).into_json())
+                    .iter()
+                    .map(|field| field.r#type.to_owned())
                     .collect();
-                let mut storages = HashMap::with_capacity(1);
-                storages.insert(
-                    "0x0000000000000000000000000000000000000000".to_owned(),
-                    serde_json::Value::Array(fields),
-                );
-
-                let transaction = serde_json::json!({
-                    "sender": "0x0000000000000000000000000000000000000000",
-                    "recipient": "0x0000000000000000000000000000000000000000",
-                    "token_address": "0x0000000000000000000000000000000000000000",
-                    "amount": "0",
-                });
-
-                let bytecode = Application::Contract(contract).into_vec();
-
-                Build::new(
-                    bytecode,
-                    InputBuild::new_contract(storages, transaction, arguments),
-                )
-            }
-            Application::Library(library) => {
-                let bytecode = Application::Library(library).into_vec();
+                let mut storages = IndexMap::with_capacity(1);
+                match previous.remove("storages") {
+                    Some(serde_json::Value::Object(previous_storages))
+                        if !previous_storages.is_empty() =>
+                    {
+                        for (address, storage) in previous_storages.into_iter() {
+                            storages.insert(
+                                address,
+                                input_merge::storage(Some(storage), types.clone()),
+                            );
+                        }
+                    }
+                    _ => {
+                        storages.insert(
+                            "0x0000000000000000000000000000000000000000".to_owned(),
+                            input_merge::storage(None, types),
+                        );
+                    }
+                }
+
+                let transaction = match previous.remove("msg") {
+                    Some(msg) if TransactionMsg::try_from(&msg).is_ok() => msg,
+                    _ => serde_json::json!({
+                        "sender": "0x0000000000000000000000000000000000000000",
+                        "recipient": "0x0000000000000000000000000000000000000000",
+                        "token_address": "0x0000000000000000000000000000000000000000",
+                        "amount": "0",
+                    }),
+                };
 
-                Build::new(bytecode, InputBuild::new_library())
+                InputBuild::new_contract(
+                    Some(contract.input_template.clone()),
+                    storages,
+                    transaction,
+                    arguments,
+                )
             }
+            Application::Library(_library) => InputBuild::new_library(),
         }
     }
 
@@ -160,13 +242,72 @@ impl Application {
     /// Deserializes an application from the byte `slice`.
     ///
     pub fn try_from_slice(slice: &[u8]) -> Result<Self, String> {
-        bincode::deserialize(slice).map_err(|error| format!("{:?}", error))
+        Self::try_decode(slice)
+            .map(|(_header, application)| application)
+            .map_err(|error| error.to_string())
+    }
+
+    ///
+    /// Deserializes an application along with its artifact header from the byte `slice`.
+    ///
+    /// The legacy artifacts without the header are decoded as well.
+    ///
+    /// The application is verified against the virtual machine limits, so the malformed or
+    /// truncated bytecode is rejected with an error instead of crashing the virtual machine.
+    ///
+    pub fn try_decode(slice: &[u8]) -> Result<(Header, Self), Error> {
+        let (header, offset) = Header::read(slice)?;
+
+        let mut cursor = Cursor::new(&slice[offset..]);
+        let application: Self = bincode::options()
+            .with_fixint_encoding()
+            .allow_trailing_bytes()
+            .with_limit((slice.len() - offset) as u64)
+            .deserialize_from(&mut cursor)
+            .map_err(|error| Self::decoding_error(*error, offset + cursor.position() as usize))?;
+
+        let end = offset + cursor.position() as usize;
+        if end != slice.len() {
+            return Err(Error::TrailingData { offset: end });
+        }
+
+        Verifier::verify(&application, offset, end)?;
+
+        Ok((header, application))
     }
 
     ///
-    /// Serializes the application to a byte array.
+    /// Serializes the application to a byte array, preceded by the artifact header with the
+    /// `project_version`.
+    ///
+    pub fn into_vec(self, project_version: &semver::Version) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        Header::new(project_version).write(&mut bytes);
+        bincode::serialize_into(&mut bytes, &self).expect(zinc_const::panic::DATA_CONVERSION);
+        bytes
+    }
+
     ///
-    pub fn into_vec(self) -> Vec<u8> {
-        bincode::serialize(&self).expect(zinc_const::panic::DATA_CONVERSION)
+    /// Converts the deserializer `error`, which has occurred at `offset`, into the decoding error.
+    ///
+    fn decoding_error(error: bincode::ErrorKind, offset: usize) -> Error {
+        match error {
+            bincode::ErrorKind::Io(ref inner)
+                if inner.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                Error::Truncated { offset }
+            }
+            bincode::ErrorKind::SizeLimit => Error::Truncated { offset },
+            bincode::ErrorKind::Custom(message) if message.contains("variant index") => {
+                Error::UnknownVariant {
+                    offset: offset.saturating_sub(std::mem::size_of::<u32>()),
+                    message,
+                }
+            }
+            error => Error::InvalidApplication {
+                offset,
+                message: error.to_string(),
+            },
+        }
     }
 }
@@ -0,0 +1,523 @@
+//!
+//! The bytecode application tests.
+//!
+
+use indexmap::IndexMap;
+use num::BigInt;
+
+use crate::application::error::Error;
+use crate::application::header::Header;
+use crate::application::unit_test::UnitTest;
+use crate::application::Application;
+use crate::build::input::Input as InputBuild;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+///
+/// Creates the minimal circuit application.
+///
+fn application() -> Application {
+    Application::new_circuit(
+        "test".to_owned(),
+        0,
+        Type::Unit,
+        Type::Unit,
+        IndexMap::new(),
+        vec![Instruction::Return(crate::Return::new(0))],
+    )
+}
+
+///
+/// Creates the circuit application with the `instructions`.
+///
+fn circuit(instructions: Vec<Instruction>) -> Application {
+    Application::new_circuit(
+        "test".to_owned(),
+        0,
+        Type::Structure(vec![(
+            "value".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+        )]),
+        Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+        IndexMap::new(),
+        instructions,
+    )
+}
+
+///
+/// Creates the circuit application with the most instruction kinds, which is mutated by the
+/// fuzzing tests.
+///
+fn fuzzing_seed() -> Application {
+    circuit(vec![
+        Instruction::Load(crate::Load::new(0, 1)),
+        Instruction::Call(crate::Call::new(5, 1)),
+        Instruction::Push(crate::Push::new(BigInt::from(1), IntegerType::U8.into())),
+        Instruction::Add(crate::Add::new(crate::RangeCheck::Enforced)),
+        Instruction::Return(crate::Return::new(1)),
+        Instruction::Store(crate::Store::new(1, 1)),
+        Instruction::LoopBegin(crate::LoopBegin::new(2)),
+        Instruction::Load(crate::Load::new(1, 1)),
+        Instruction::Cast(crate::Cast::new(ScalarType::Field)),
+        Instruction::LoopEnd(crate::LoopEnd),
+        Instruction::Push(crate::Push::new_field(BigInt::from(0))),
+        Instruction::LoadByIndex(crate::LoadByIndex::new(0, 1, 2)),
+        Instruction::Return(crate::Return::new(1)),
+    ])
+}
+
+///
+/// The deterministic pseudo-random generator, which makes the fuzzing tests reproducible.
+///
+struct Random(u64);
+
+impl Random {
+    ///
+    /// Returns the next pseudo-random number using the `xorshift64` algorithm.
+    ///
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    ///
+    /// Returns the next pseudo-random number less than `bound`.
+    ///
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % (bound as u64)) as usize
+    }
+}
+
+///
+/// Applies several random byte mutations to the artifact `bytes`.
+///
+fn mutate(random: &mut Random, bytes: &mut Vec<u8>) {
+    for _ in 0..=random.below(4) {
+        let position = random.below(bytes.len());
+        match random.below(5) {
+            0 => bytes[position] ^= 1 << random.below(8),
+            1 => bytes[position] = random.next() as u8,
+            2 => {
+                for byte in bytes.iter_mut().skip(position).take(8) {
+                    *byte = 0xff;
+                }
+            }
+            3 => bytes.truncate(position),
+            _ => bytes.insert(position, random.next() as u8),
+        }
+
+        if bytes.is_empty() {
+            break;
+        }
+    }
+}
+
+///
+/// The project version written into the header.
+///
+fn version() -> semver::Version {
+    semver::Version::new(1, 2, 3)
+}
+
+#[test]
+fn ok_header_round_trip() {
+    let bytes = application().into_vec(&version());
+
+    let (header, application) =
+        Application::try_decode(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(header, Header::new(&version()));
+    assert_eq!(header.project_version.as_deref(), Some("1.2.3"));
+    assert_eq!(header.std_version, zinc_const::version::STD);
+    assert_eq!(application.instructions().len(), 1);
+}
+
+#[test]
+fn ok_legacy_without_header() {
+    let bytes = bincode::serialize(&application()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let (header, _application) =
+        Application::try_decode(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(header, Header::legacy());
+}
+
+#[test]
+fn ok_format_without_std_version() {
+    let mut bytes = application().into_vec(&version());
+    let offset = Header::MAGIC.len();
+    bytes[offset..offset + 2].copy_from_slice(&Header::FORMAT_VERSION_MIN.to_le_bytes());
+    bytes.drain(offset + 2..offset + 4);
+
+    let (header, _application) =
+        Application::try_decode(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(header.format_version, Header::FORMAT_VERSION_MIN);
+    assert_eq!(header.std_version, Header::STD_VERSION_INITIAL);
+    assert_eq!(header.project_version.as_deref(), Some("1.2.3"));
+}
+
+#[test]
+fn error_truncated_header() {
+    let bytes = application().into_vec(&version());
+
+    let result = Application::try_decode(&bytes[..Header::MAGIC.len() + 3]);
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::UnexpectedEnd {
+            offset: Header::MAGIC.len() + 2,
+            expected: 2,
+        })
+    );
+}
+
+#[test]
+fn error_unsupported_version() {
+    let mut bytes = application().into_vec(&version());
+    bytes[Header::MAGIC.len()] = 0xff;
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::UnsupportedVersion {
+            offset: Header::MAGIC.len(),
+            found: 0xff,
+            supported: Header::FORMAT_VERSION,
+        })
+    );
+}
+
+#[test]
+fn error_truncated_application() {
+    let bytes = application().into_vec(&version());
+    let (_header, header_size) =
+        Header::read(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let error = Application::try_decode(&bytes[..bytes.len() - 1])
+        .map(|_| ())
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(error, Error::Truncated { .. }));
+    assert!(error.offset() >= header_size && error.offset() < bytes.len());
+}
+
+#[test]
+fn error_trailing_data() {
+    let mut bytes = application().into_vec(&version());
+    let length = bytes.len();
+    bytes.push(0);
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::TrailingData { offset: length })
+    );
+}
+
+#[test]
+fn ok_contract_round_trip() {
+    let application = Application::new_contract(
+        "test".to_owned(),
+        vec![],
+        IndexMap::new(),
+        IndexMap::new(),
+        vec![Instruction::Return(crate::Return::new(0))],
+        None,
+        vec![],
+    );
+    let bytes = application.into_vec(&version());
+
+    let (_header, application) =
+        Application::try_decode(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(
+        matches!(application, Application::Contract(ref contract) if contract.upgrade.is_none())
+    );
+}
+
+#[test]
+fn error_unknown_opcode() {
+    let mut bytes = circuit(vec![Instruction::Return(crate::Return::new(1))]).into_vec(&version());
+    let offset = bytes.len() - 12;
+    bytes[offset] = 0xff;
+
+    let error = Application::try_decode(bytes.as_slice())
+        .map(|_| ())
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(error, Error::UnknownVariant { .. }));
+    assert_eq!(error.offset(), offset);
+}
+
+#[test]
+fn error_length_exceeds_data() {
+    let mut bytes = application().into_vec(&version());
+    let (_header, header_size) =
+        Header::read(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+    let name_length = header_size + std::mem::size_of::<u32>();
+    for byte in bytes[name_length..name_length + 8].iter_mut() {
+        *byte = 0xff;
+    }
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::Truncated {
+            offset: name_length + 8,
+        })
+    );
+}
+
+#[test]
+fn error_entry_out_of_range() {
+    let mut application = application();
+    if let Application::Circuit(ref mut circuit) = application {
+        circuit.address = 1;
+    }
+    let bytes = application.into_vec(&version());
+    let (_header, header_size) =
+        Header::read(bytes.as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::EntryOutOfRange {
+            offset: header_size,
+            name: "test".to_owned(),
+            address: 1,
+            length: 1,
+        })
+    );
+}
+
+#[test]
+fn error_call_target_out_of_range() {
+    let bytes = circuit(vec![
+        Instruction::Call(crate::Call::new(42, 0)),
+        Instruction::Return(crate::Return::new(1)),
+    ])
+    .into_vec(&version());
+    let return_size = bincode::serialized_size(&Instruction::Return(crate::Return::new(1)))
+        .expect(zinc_const::panic::TEST_DATA_VALID) as usize;
+    let call_size = bincode::serialized_size(&Instruction::Call(crate::Call::new(42, 0)))
+        .expect(zinc_const::panic::TEST_DATA_VALID) as usize;
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert_eq!(
+        result.map(|_| ()),
+        Err(Error::TargetOutOfRange {
+            offset: bytes.len() - return_size - call_size,
+            index: 0,
+            target: 42,
+            length: 2,
+        })
+    );
+}
+
+#[test]
+fn error_operand_out_of_range() {
+    let bytes = circuit(vec![
+        Instruction::Store(crate::Store::new(std::usize::MAX, 1)),
+        Instruction::Return(crate::Return::new(1)),
+    ])
+    .into_vec(&version());
+
+    let error = Application::try_decode(bytes.as_slice())
+        .map(|_| ())
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(
+        error,
+        Error::OperandOutOfRange {
+            index: 0,
+            operand: "address",
+            ..
+        }
+    ));
+}
+
+#[test]
+fn error_invalid_integer_bitlength() {
+    let bytes = circuit(vec![
+        Instruction::Cast(crate::Cast::new(ScalarType::Integer(IntegerType {
+            is_signed: true,
+            bitlength: 0,
+        }))),
+        Instruction::Return(crate::Return::new(1)),
+    ])
+    .into_vec(&version());
+
+    let error = Application::try_decode(bytes.as_slice())
+        .map(|_| ())
+        .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(matches!(error, Error::InvalidType { .. }));
+}
+
+#[test]
+fn ok_fuzzing_seed() {
+    let bytes = fuzzing_seed().into_vec(&version());
+
+    let result = Application::try_decode(bytes.as_slice());
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_fuzzing_mutated_artifacts() {
+    let seed = fuzzing_seed().into_vec(&version());
+    let mut random = Random(0x5eed_0f_2111);
+
+    for _ in 0..10_000 {
+        let mut bytes = seed.clone();
+        mutate(&mut random, &mut bytes);
+
+        if let Ok((_header, application)) = Application::try_decode(bytes.as_slice()) {
+            let length = application.instructions().len();
+            for instruction in application.instructions().iter() {
+                if let Instruction::Call(ref call) = instruction {
+                    assert!(call.address < length);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ok_fuzzing_arbitrary_bytes() {
+    let mut random = Random(0xa4b1_7a4e);
+
+    for _ in 0..10_000 {
+        let mut bytes: Vec<u8> = (0..random.below(256))
+            .map(|_| random.next() as u8)
+            .collect();
+        if random.below(2) == 0 {
+            bytes.splice(0..0, Header::MAGIC.iter().copied());
+        }
+
+        let _ = Application::try_decode(bytes.as_slice());
+    }
+}
+
+#[test]
+fn error_regression_corpus() {
+    let corpus = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("corpus");
+
+    for entry in std::fs::read_dir(corpus).expect(zinc_const::panic::TEST_DATA_VALID) {
+        let path = entry.expect(zinc_const::panic::TEST_DATA_VALID).path();
+        let bytes = std::fs::read(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let result = Application::try_decode(bytes.as_slice());
+
+        assert!(result.is_err(), "{:?} must be rejected", path);
+    }
+}
+
+#[test]
+fn ok_regenerate_input() {
+    let application = circuit(vec![Instruction::Return(crate::Return::new(1))]);
+
+    let input = application.regenerate_input(Some(serde_json::json!({
+        "type": "circuit",
+        "_template": "stale",
+        "arguments": { "value": "42", "removed": true },
+    })));
+
+    assert_eq!(input.template(), application.input_template());
+    assert!(matches!(
+        input,
+        InputBuild::Circuit { ref arguments, .. } if arguments == &serde_json::json!({ "value": "42" })
+    ));
+}
+
+#[test]
+fn ok_input_template_changes_with_input_type() {
+    let application = circuit(vec![Instruction::Return(crate::Return::new(1))]);
+    let retyped = Application::new_circuit(
+        "test".to_owned(),
+        0,
+        Type::Structure(vec![(
+            "value".to_owned(),
+            Type::Scalar(ScalarType::Boolean),
+        )]),
+        Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+        IndexMap::new(),
+        vec![Instruction::Return(crate::Return::new(1))],
+    );
+
+    assert_ne!(application.input_template(), retyped.input_template());
+}
+
+#[test]
+fn ok_unit_test_split_name() {
+    assert_eq!(UnitTest::split_name("overflow"), ("src/", "overflow"));
+    assert_eq!(
+        UnitTest::split_name("exchange::math::overflow"),
+        ("src/", "exchange::math::overflow")
+    );
+    assert_eq!(
+        UnitTest::split_name("tests/exchange.zn::overflow"),
+        ("tests/exchange.zn", "overflow")
+    );
+}
+
+#[test]
+fn ok_retain_unit_tests() {
+    let mut unit_tests = IndexMap::new();
+    for name in vec![
+        "overflow",
+        "exchange::overflow",
+        "exchange::math::overflow",
+        "exchange::underflow",
+        "tests/exchange.zn::overflow",
+        "exchange::math::no_overflow",
+    ]
+    .into_iter()
+    {
+        unit_tests.insert(
+            name.to_owned(),
+            UnitTest::new(0, false, None, false, false, None, None),
+        );
+    }
+
+    for (filter, expected) in vec![
+        (
+            "overflow",
+            vec![
+                "overflow",
+                "exchange::overflow",
+                "exchange::math::overflow",
+                "tests/exchange.zn::overflow",
+            ],
+        ),
+        ("math::overflow", vec!["exchange::math::overflow"]),
+        ("exchange::overflow", vec!["exchange::overflow"]),
+        ("flow", vec![]),
+    ]
+    .into_iter()
+    {
+        let mut application =
+            Application::new_library("test".to_owned(), unit_tests.clone(), vec![]);
+        application.retain_unit_tests(filter);
+
+        let names: Vec<String> = match application {
+            Application::Library(library) => library
+                .unit_tests
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect(),
+            _ => panic!(zinc_const::panic::TEST_DATA_VALID),
+        };
+        assert_eq!(names, expected, "filter `{}`", filter);
+    }
+}
@@ -14,10 +14,16 @@ pub struct UnitTest {
     pub address: usize,
     /// If an error means success, is set by the `#[should_panic]` macro
     pub should_panic: bool,
+    /// The substring the error message must contain, is set by `#[should_panic(expected = "...")]`
+    pub expected_panic: Option<String>,
     /// If the test must be ignored, is set by the `#[ignore]` macro
     pub is_ignored: bool,
+    /// If the test must synthesize and check the constraints, is set by the `#[proof]` macro
+    pub is_proof: bool,
     /// The optional transaction variable.
     pub zksync_msg: Option<crate::transaction::msg::Msg>,
+    /// The `zksync::msg` attribute source location, which is reported by the failing transfers.
+    pub zksync_msg_location: Option<String>,
 }
 
 impl UnitTest {
@@ -27,14 +33,46 @@ impl UnitTest {
     pub fn new(
         address: usize,
         should_panic: bool,
+        expected_panic: Option<String>,
         is_ignored: bool,
+        is_proof: bool,
         zksync_msg: Option<crate::transaction::msg::Msg>,
+        zksync_msg_location: Option<String>,
     ) -> Self {
         Self {
             address,
             should_panic,
+            expected_panic,
             is_ignored,
+            is_proof,
             zksync_msg,
+            zksync_msg_location,
         }
     }
+
+    ///
+    /// Splits the unit test `name` into the file it is reported under and the test path.
+    ///
+    /// The test root unit tests are named like `tests/<file>.zn::<function>`, whereas
+    /// the in-source ones are named with their module path like `<module>::<function>` and
+    /// reported under the source code directory.
+    ///
+    pub fn split_name(name: &str) -> (&str, &str) {
+        if name.starts_with(zinc_const::directory::TESTS) {
+            if let Some(index) = name.find("::") {
+                return (&name[..index], &name[index + 2..]);
+            }
+        }
+
+        (zinc_const::directory::SOURCE, name)
+    }
+
+    ///
+    /// Checks if the fully qualified unit test `name` matches the `filter`, which is either
+    /// the whole name or its suffix consisting of whole path segments, e.g. `overflow` or
+    /// `math::overflow` for `exchange::math::overflow`.
+    ///
+    pub fn matches(name: &str, filter: &str) -> bool {
+        name == filter || name.ends_with(format!("::{}", filter).as_str())
+    }
 }
@@ -0,0 +1,335 @@
+//!
+//! The bytecode application verifier.
+//!
+
+use crate::application::error::Error;
+use crate::application::Application;
+use crate::data::r#type::contract_field::leaf::Leaf;
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+///
+/// The bytecode application verifier.
+///
+/// Checks the decoded application against the virtual machine limits, so the malformed bytecode
+/// is rejected with an error before it is run instead of crashing the virtual machine.
+///
+pub struct Verifier<'a> {
+    /// The verified application instructions.
+    instructions: &'a [Instruction],
+    /// The artifact byte offset of the application data.
+    offset: usize,
+    /// The artifact byte offset of the application data end.
+    end: usize,
+}
+
+impl<'a> Verifier<'a> {
+    ///
+    /// Verifies the `application` decoded from the artifact data between `offset` and `end`.
+    ///
+    pub fn verify(application: &'a Application, offset: usize, end: usize) -> Result<(), Error> {
+        let verifier = Self {
+            instructions: application.instructions(),
+            offset,
+            end,
+        };
+
+        match application {
+            Application::Circuit(circuit) => {
+                verifier.entry(circuit.name.as_str(), circuit.address)?;
+                verifier.r#type(&circuit.input, None)?;
+                verifier.r#type(&circuit.output, None)?;
+                for (name, unit_test) in circuit.unit_tests.iter() {
+                    verifier.entry(name.as_str(), unit_test.address)?;
+                }
+            }
+            Application::Contract(contract) => {
+                verifier.fields(contract.storage.as_slice(), None)?;
+                for (name, method) in contract.methods.iter() {
+                    verifier.entry(name.as_str(), method.address)?;
+                    verifier.r#type(&method.input, None)?;
+                    verifier.r#type(&method.output, None)?;
+                }
+                for (name, unit_test) in contract.unit_tests.iter() {
+                    verifier.entry(name.as_str(), unit_test.address)?;
+                }
+            }
+            Application::Library(library) => {
+                for (name, unit_test) in library.unit_tests.iter() {
+                    verifier.entry(name.as_str(), unit_test.address)?;
+                }
+            }
+        }
+
+        for (index, instruction) in verifier.instructions.iter().enumerate() {
+            verifier.instruction(index, instruction)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the instruction operands at `index`.
+    ///
+    fn instruction(&self, index: usize, instruction: &Instruction) -> Result<(), Error> {
+        match instruction {
+            Instruction::Call(inner) => {
+                if inner.address >= self.instructions.len() {
+                    return Err(Error::TargetOutOfRange {
+                        offset: self.instruction_offset(index),
+                        index,
+                        target: inner.address,
+                        length: self.instructions.len(),
+                    });
+                }
+                self.size(index, "input_size", inner.input_size)
+            }
+            Instruction::Return(inner) => self.size(index, "output_size", inner.output_size),
+            Instruction::Load(inner) => self.range(index, inner.address, inner.size),
+            Instruction::Store(inner) => self.range(index, inner.address, inner.size),
+            Instruction::LoadByIndex(inner) => {
+                self.range(index, inner.address, inner.total_size)?;
+                self.slice(index, inner.value_size, inner.total_size)
+            }
+            Instruction::StoreByIndex(inner) => {
+                self.range(index, inner.address, inner.total_size)?;
+                self.slice(index, inner.value_size, inner.total_size)
+            }
+            Instruction::Slice(inner) => {
+                self.size(index, "total_size", inner.total_size)?;
+                self.slice(index, inner.slice_length, inner.total_size)
+            }
+            Instruction::StorageInit(inner) => {
+                self.fields(inner.field_types.as_slice(), Some(index))
+            }
+            Instruction::StorageFetch(inner) => {
+                self.fields(inner.field_types.as_slice(), Some(index))
+            }
+            Instruction::StorageLoad(inner) => self.size(index, "size", inner.size),
+            Instruction::StorageStore(inner) => self.size(index, "size", inner.size),
+            Instruction::CallLibrary(inner) => {
+                self.size(index, "input_size", inner.input_size)?;
+                self.size(index, "output_size", inner.output_size)
+            }
+            Instruction::Push(inner) => self.scalar(&inner.scalar_type, Some(index)),
+            Instruction::Cast(inner) => self.scalar(&inner.r#type, Some(index)),
+            Instruction::AssertEq(inner) => self.r#type(&inner.r#type, Some(index)),
+            Instruction::Dbg(inner) => {
+                for r#type in inner.argument_types.iter() {
+                    self.r#type(r#type, Some(index))?;
+                }
+                Ok(())
+            }
+            Instruction::UnsafeWitnessEnd(inner) => {
+                self.size(index, "output_size", inner.output_types.len())?;
+                for scalar_type in inner.output_types.iter() {
+                    self.scalar(scalar_type, Some(index))?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks that the entry `address` points to an instruction.
+    ///
+    fn entry(&self, name: &str, address: usize) -> Result<(), Error> {
+        if address >= self.instructions.len() {
+            return Err(Error::EntryOutOfRange {
+                offset: self.offset,
+                name: name.to_owned(),
+                address,
+                length: self.instructions.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks that the data stack range starting at `address` fits into the data stack.
+    ///
+    fn range(&self, index: usize, address: usize, size: usize) -> Result<(), Error> {
+        let end = address.checked_add(size).unwrap_or(usize::MAX);
+        self.size(index, "address", end)
+    }
+
+    ///
+    /// Checks that the `length` of the slice does not exceed the `total_size` of its array.
+    ///
+    fn slice(&self, index: usize, length: usize, total_size: usize) -> Result<(), Error> {
+        if length > total_size {
+            return Err(Error::OperandOutOfRange {
+                offset: self.instruction_offset(index),
+                index,
+                operand: "length",
+                value: length,
+                limit: total_size,
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks that the size `operand` fits into the data stack.
+    ///
+    fn size(&self, index: usize, operand: &'static str, value: usize) -> Result<(), Error> {
+        if value > zinc_const::limit::DATA_STACK_SIZE {
+            return Err(Error::OperandOutOfRange {
+                offset: self.instruction_offset(index),
+                index,
+                operand,
+                value,
+                limit: zinc_const::limit::DATA_STACK_SIZE,
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the contract storage field types found in the instruction at `index`, or in the
+    /// application metadata if it is `None`.
+    ///
+    fn fields(&self, fields: &[ContractField], index: Option<usize>) -> Result<(), Error> {
+        for field in fields.iter() {
+            self.r#type(&field.r#type, index)?;
+
+            if field.leaves != Leaf::flatten(field.name.to_owned(), field.r#type.to_owned()) {
+                return Err(Error::InvalidType {
+                    offset: self.type_offset(index),
+                    reason: format!(
+                        "the storage leaves of the field `{}` do not match its type",
+                        field.name
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the scalars and size of the `type` found in the instruction at `index`.
+    ///
+    fn r#type(&self, r#type: &Type, index: Option<usize>) -> Result<(), Error> {
+        let size = self.type_size(r#type, index)?;
+        if size > zinc_const::limit::DATA_STACK_SIZE {
+            return Err(Error::InvalidType {
+                offset: self.type_offset(index),
+                reason: format!(
+                    "the size {} exceeds {}",
+                    size,
+                    zinc_const::limit::DATA_STACK_SIZE
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the scalars of the `type` found in the instruction at `index` and calculates
+    /// its size.
+    ///
+    /// Unlike `Type::size`, the size overflow is reported as an error.
+    ///
+    fn type_size(&self, r#type: &Type, index: Option<usize>) -> Result<usize, Error> {
+        let overflow = || Error::InvalidType {
+            offset: self.type_offset(index),
+            reason: "the size overflows".to_owned(),
+        };
+
+        match r#type {
+            Type::Scalar(scalar) => self.scalar(scalar, index).map(|_| 1),
+            Type::Enumeration { bitlength, .. } | Type::Flags { bitlength, .. } => {
+                self.bitlength(*bitlength, index).map(|_| 1)
+            }
+            Type::Array(r#type, size) => self
+                .type_size(r#type, index)?
+                .checked_mul(*size)
+                .ok_or_else(overflow),
+            Type::Tuple(types) => types.iter().try_fold(0usize, |sum, r#type| {
+                sum.checked_add(self.type_size(r#type, index)?)
+                    .ok_or_else(overflow)
+            }),
+            Type::Structure(fields) => fields.iter().try_fold(0usize, |sum, (_name, r#type)| {
+                sum.checked_add(self.type_size(r#type, index)?)
+                    .ok_or_else(overflow)
+            }),
+            Type::Contract(fields) => {
+                self.fields(fields.as_slice(), index)?;
+                Ok(r#type.size())
+            }
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                self.r#type(key_type, index)?;
+                self.r#type(value_type, index)?;
+                Ok(0)
+            }
+            Type::Unit | Type::Address | Type::Balance => Ok(r#type.size()),
+        }
+    }
+
+    ///
+    /// Checks the integer bitlength of the `scalar` type found in the instruction at `index`.
+    ///
+    fn scalar(&self, scalar: &ScalarType, index: Option<usize>) -> Result<(), Error> {
+        match scalar {
+            ScalarType::Integer(inner) => self.bitlength(inner.bitlength, index),
+            ScalarType::Boolean | ScalarType::Field => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks that the integer `bitlength` found in the instruction at `index` is supported.
+    ///
+    fn bitlength(&self, bitlength: usize, index: Option<usize>) -> Result<(), Error> {
+        if bitlength == 0 || bitlength > zinc_const::bitlength::INTEGER_MAX {
+            return Err(Error::InvalidType {
+                offset: self.type_offset(index),
+                reason: format!(
+                    "the integer bitlength {} is not in range 1..={}",
+                    bitlength,
+                    zinc_const::bitlength::INTEGER_MAX
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the artifact byte offset of the instruction at `index`, or of the application
+    /// data if the type is found in its metadata.
+    ///
+    fn type_offset(&self, index: Option<usize>) -> usize {
+        match index {
+            Some(index) => self.instruction_offset(index),
+            None => self.offset,
+        }
+    }
+
+    ///
+    /// Calculates the artifact byte offset of the instruction at `index`.
+    ///
+    /// The instructions are serialized last, so the offset is counted back from the data end.
+    /// It is only calculated on errors, since it requires serializing the subsequent instructions.
+    ///
+    fn instruction_offset(&self, index: usize) -> usize {
+        let tail: u64 = self.instructions[index..]
+            .iter()
+            .map(|instruction| {
+                bincode::serialized_size(instruction).expect(zinc_const::panic::DATA_CONVERSION)
+            })
+            .sum();
+
+        self.end - tail as usize
+    }
+}
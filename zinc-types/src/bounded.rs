@@ -0,0 +1,83 @@
+//!
+//! The bounded deserialization tools.
+//!
+//! The `num` and `indexmap` implementations preallocate the capacity from the untrusted length
+//! prefix, which aborts the process on malformed bytecode. These ones keep the data format, but
+//! let the collections grow with the data actually read, so a bad length is reported as an error.
+//!
+
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use indexmap::IndexMap;
+use num::bigint::Sign;
+use num::BigInt;
+use serde::de::MapAccess;
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+
+///
+/// Deserializes the `BigInt` in the `num` format.
+///
+pub fn deserialize_bigint<'de, D>(deserializer: D) -> Result<BigInt, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (sign, digits): (i8, Vec<u32>) = Deserialize::deserialize(deserializer)?;
+    let sign = match sign {
+        -1 => Sign::Minus,
+        0 => Sign::NoSign,
+        1 => Sign::Plus,
+        sign => {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Signed(sign.into()),
+                &"a sign of -1, 0, or 1",
+            ))
+        }
+    };
+
+    Ok(BigInt::new(sign, digits))
+}
+
+///
+/// Deserializes the `IndexMap` in the `indexmap` format.
+///
+pub fn deserialize_index_map<'de, D, K, V>(deserializer: D) -> Result<IndexMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    deserializer.deserialize_map(IndexMapVisitor(PhantomData))
+}
+
+///
+/// The `IndexMap` visitor, which does not preallocate the capacity.
+///
+struct IndexMapVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K, V> Visitor<'de> for IndexMapVisitor<K, V>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    type Value = IndexMap<K, V>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut map = IndexMap::new();
+        while let Some((key, value)) = access.next_entry()? {
+            map.insert(key, value);
+        }
+
+        Ok(map)
+    }
+}
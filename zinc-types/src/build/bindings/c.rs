@@ -0,0 +1,105 @@
+//!
+//! The Zinc build C bindings backend.
+//!
+
+use crate::build::bindings::entry::Entry;
+use crate::build::bindings::language::Language;
+use crate::build::bindings::Bindings;
+
+/// The number of the circuit hash bytes on a line.
+const HASH_BYTES_PER_LINE: usize = 16;
+
+///
+/// Generates the `bindings.h` header.
+///
+/// All the constants are prefixed with the application name, and the contract method constants
+/// are also prefixed with the method name.
+///
+pub fn generate(bindings: &Bindings) -> String {
+    let prefix = Bindings::constant_name(&[bindings.name.as_str()]);
+    let guard = format!("ZINC_{}_BINDINGS_H", prefix);
+
+    let mut lines = vec![
+        "/*".to_owned(),
+        format!(
+            " * The `{}` {} public input layout.",
+            bindings.name,
+            if bindings.is_contract {
+                "contract"
+            } else {
+                "circuit"
+            }
+        ),
+        " *".to_owned(),
+        format!(
+            " * Generated by `zargo build --bindings {}` from the build metadata. Do not edit.",
+            Language::C
+        ),
+        " */".to_owned(),
+        String::new(),
+        format!("#ifndef {}", guard),
+        format!("#define {}", guard),
+        String::new(),
+        "#include <stdint.h>".to_owned(),
+        String::new(),
+        "/* The SHA-256 digest of the bytecode file, which the keys are generated for. */"
+            .to_owned(),
+        format!(
+            "static const uint8_t {}_CIRCUIT_HASH[{}] = {{",
+            prefix,
+            bindings.circuit_hash.len()
+        ),
+    ];
+    for chunk in bindings.circuit_hash.chunks(HASH_BYTES_PER_LINE) {
+        let bytes: Vec<String> = chunk.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+        lines.push(format!("    {},", bytes.join(", ")));
+    }
+    lines.push("};".to_owned());
+
+    for entry in bindings.entries.iter() {
+        lines.push(String::new());
+
+        let prefix = match entry.method {
+            Some(ref method) => {
+                lines.push(format!(
+                    "/* The `{}` method public input layout. */",
+                    method
+                ));
+                lines.push(String::new());
+                Bindings::constant_name(&[bindings.name.as_str(), method.as_str()])
+            }
+            None => prefix.clone(),
+        };
+        lines.extend(entry_lines(prefix.as_str(), entry));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("#endif /* {} */", guard));
+
+    lines.join("\n") + "\n"
+}
+
+///
+/// Renders the `entry` constants prefixed with `prefix`, separating the groups with empty lines.
+///
+fn entry_lines(prefix: &str, entry: &Entry) -> Vec<String> {
+    let mut groups = Vec::new();
+
+    if let Some(selector) = entry.selector {
+        groups.push(vec![
+            "/* The ABI selector of the method signature. */".to_owned(),
+            format!("#define {}_SELECTOR UINT32_C(0x{:08x})", prefix, selector),
+        ]);
+    }
+
+    for constants in entry.constants().into_iter() {
+        let mut group = Vec::with_capacity(constants.len() * 2);
+        for (description, name, value) in constants.into_iter() {
+            group.push(format!("/* {} */", description));
+            group.push(format!("#define {}_{} {}", prefix, name, value));
+        }
+        groups.push(group);
+    }
+
+    groups.join(&String::new())
+}
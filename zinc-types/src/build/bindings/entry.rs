@@ -0,0 +1,197 @@
+//!
+//! The Zinc build bindings entry.
+//!
+
+use crate::application::circuit::Circuit;
+use crate::application::contract::abi::Entry as AbiEntry;
+use crate::application::contract::method::Method;
+use crate::build::bindings::Bindings;
+use crate::data::r#type::Type;
+
+///
+/// The public input layout of the circuit or a contract method.
+///
+/// The public input vector consists of the output scalars, followed by the storage root hash
+/// for contract methods.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// The contract method name. `None` for circuits.
+    pub method: Option<String>,
+    /// The contract method ABI selector. `None` for circuits.
+    pub selector: Option<u32>,
+    /// The number of the input scalars, including the contract instance `self`.
+    pub input_size: usize,
+    /// The number of the output scalars.
+    pub output_size: usize,
+    /// The offset of the contract storage root hash. `None` for circuits.
+    pub storage_root_offset: Option<usize>,
+    /// The output structure and tuple fields in the layout order.
+    pub fields: Vec<Field>,
+}
+
+///
+/// The output field, which occupies a contiguous range of the public input vector.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// The field path below the output, e.g. `["balances"]` or `["pair", "0"]`.
+    pub path: Vec<String>,
+    /// The offset of the first scalar in the public input vector.
+    pub offset: usize,
+    /// The number of the scalars.
+    pub size: usize,
+    /// The number of the elements, if the field is an array.
+    pub length: Option<usize>,
+}
+
+impl Entry {
+    ///
+    /// Describes the `circuit` entry layout.
+    ///
+    pub fn new_circuit(circuit: &Circuit) -> Self {
+        let mut fields = Vec::new();
+        Self::collect_fields(&[], &circuit.output, 0, &mut fields);
+
+        Self {
+            method: None,
+            selector: None,
+            input_size: circuit.input.size(),
+            output_size: circuit.output.size(),
+            storage_root_offset: None,
+            fields,
+        }
+    }
+
+    ///
+    /// Describes the contract `method` layout.
+    ///
+    pub fn new_method(method: &Method) -> Self {
+        let selector = AbiEntry::new(method).selector;
+        let selector = u32::from_str_radix(&selector["0x".len()..], zinc_const::base::HEXADECIMAL)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        let mut fields = Vec::new();
+        Self::collect_fields(&[], &method.output, 0, &mut fields);
+
+        Self {
+            method: Some(method.name.to_owned()),
+            selector: Some(selector),
+            input_size: method.input.size(),
+            output_size: method.output.size(),
+            storage_root_offset: Some(method.output.size()),
+            fields,
+        }
+    }
+
+    ///
+    /// The number of the public input scalars.
+    ///
+    pub fn public_input_size(&self) -> usize {
+        match self.storage_root_offset {
+            Some(offset) => offset + 1,
+            None => self.output_size,
+        }
+    }
+
+    ///
+    /// Returns the size and offset constants as groups of the description, name, and value,
+    /// which are rendered by the language backends.
+    ///
+    /// The constant names are not prefixed with the application or method names.
+    ///
+    pub fn constants(&self) -> Vec<Vec<(String, String, usize)>> {
+        let mut groups = vec![
+            vec![(
+                "The number of the input scalars.".to_owned(),
+                "INPUT_SIZE".to_owned(),
+                self.input_size,
+            )],
+            vec![(
+                "The number of the output scalars.".to_owned(),
+                "OUTPUT_SIZE".to_owned(),
+                self.output_size,
+            )],
+            vec![(
+                "The number of the public input scalars.".to_owned(),
+                "PUBLIC_INPUT_SIZE".to_owned(),
+                self.public_input_size(),
+            )],
+        ];
+
+        if let Some(offset) = self.storage_root_offset {
+            groups.push(vec![(
+                "The offset of the storage root hash in the public input vector.".to_owned(),
+                "STORAGE_ROOT_OFFSET".to_owned(),
+                offset,
+            )]);
+        }
+
+        for field in self.fields.iter() {
+            let path = format!("output.{}", field.path.join("."));
+            let mut segments = vec!["output"];
+            segments.extend(field.path.iter().map(String::as_str));
+            let name = Bindings::constant_name(segments.as_slice());
+
+            let mut group = vec![
+                (
+                    format!("The offset of `{}` in the public input vector.", path),
+                    format!("{}_OFFSET", name),
+                    field.offset,
+                ),
+                (
+                    format!("The number of the `{}` scalars.", path),
+                    format!("{}_SIZE", name),
+                    field.size,
+                ),
+            ];
+            if let Some(length) = field.length {
+                group.push((
+                    format!("The number of the `{}` array elements.", path),
+                    format!("{}_LENGTH", name),
+                    length,
+                ));
+            }
+            groups.push(group);
+        }
+
+        groups
+    }
+
+    ///
+    /// Pushes the structure and tuple fields of `r#type` at `offset` to `fields`, descending into
+    /// the nested structures and tuples. The array elements are described by the array field.
+    ///
+    fn collect_fields(path: &[String], r#type: &Type, mut offset: usize, fields: &mut Vec<Field>) {
+        let children: Vec<(String, &Type)> = match r#type {
+            Type::Tuple(types) => types
+                .iter()
+                .enumerate()
+                .map(|(index, r#type)| (index.to_string(), r#type))
+                .collect(),
+            Type::Structure(types) => types
+                .iter()
+                .map(|(name, r#type)| (name.to_owned(), r#type))
+                .collect(),
+            _ => return,
+        };
+
+        for (name, r#type) in children.into_iter() {
+            let mut path = path.to_vec();
+            path.push(name);
+
+            fields.push(Field {
+                path: path.clone(),
+                offset,
+                size: r#type.size(),
+                length: match r#type {
+                    Type::Array(_, length) => Some(*length),
+                    _ => None,
+                },
+            });
+            Self::collect_fields(path.as_slice(), r#type, offset, fields);
+
+            offset += r#type.size();
+        }
+    }
+}
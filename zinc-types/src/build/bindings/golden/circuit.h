@@ -0,0 +1,54 @@
+/*
+ * The `sum` circuit public input layout.
+ *
+ * Generated by `zargo build --bindings c` from the build metadata. Do not edit.
+ */
+
+#ifndef ZINC_SUM_BINDINGS_H
+#define ZINC_SUM_BINDINGS_H
+
+#include <stdint.h>
+
+/* The SHA-256 digest of the bytecode file, which the keys are generated for. */
+static const uint8_t SUM_CIRCUIT_HASH[32] = {
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+};
+
+/* The number of the input scalars. */
+#define SUM_INPUT_SIZE 5
+
+/* The number of the output scalars. */
+#define SUM_OUTPUT_SIZE 7
+
+/* The number of the public input scalars. */
+#define SUM_PUBLIC_INPUT_SIZE 7
+
+/* The offset of `output.sum` in the public input vector. */
+#define SUM_OUTPUT_SUM_OFFSET 0
+/* The number of the `output.sum` scalars. */
+#define SUM_OUTPUT_SUM_SIZE 1
+
+/* The offset of `output.flags` in the public input vector. */
+#define SUM_OUTPUT_FLAGS_OFFSET 1
+/* The number of the `output.flags` scalars. */
+#define SUM_OUTPUT_FLAGS_SIZE 2
+
+/* The offset of `output.flags.0` in the public input vector. */
+#define SUM_OUTPUT_FLAGS_0_OFFSET 1
+/* The number of the `output.flags.0` scalars. */
+#define SUM_OUTPUT_FLAGS_0_SIZE 1
+
+/* The offset of `output.flags.1` in the public input vector. */
+#define SUM_OUTPUT_FLAGS_1_OFFSET 2
+/* The number of the `output.flags.1` scalars. */
+#define SUM_OUTPUT_FLAGS_1_SIZE 1
+
+/* The offset of `output.digest` in the public input vector. */
+#define SUM_OUTPUT_DIGEST_OFFSET 3
+/* The number of the `output.digest` scalars. */
+#define SUM_OUTPUT_DIGEST_SIZE 4
+/* The number of the `output.digest` array elements. */
+#define SUM_OUTPUT_DIGEST_LENGTH 4
+
+#endif /* ZINC_SUM_BINDINGS_H */
@@ -0,0 +1,47 @@
+//!
+//! The `sum` circuit public input layout.
+//!
+//! Generated by `zargo build --bindings rust` from the build metadata. Do not edit.
+//!
+
+/// The SHA-256 digest of the bytecode file, which the keys are generated for.
+pub const CIRCUIT_HASH: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// The number of the input scalars.
+pub const INPUT_SIZE: usize = 5;
+
+/// The number of the output scalars.
+pub const OUTPUT_SIZE: usize = 7;
+
+/// The number of the public input scalars.
+pub const PUBLIC_INPUT_SIZE: usize = 7;
+
+/// The offset of `output.sum` in the public input vector.
+pub const OUTPUT_SUM_OFFSET: usize = 0;
+/// The number of the `output.sum` scalars.
+pub const OUTPUT_SUM_SIZE: usize = 1;
+
+/// The offset of `output.flags` in the public input vector.
+pub const OUTPUT_FLAGS_OFFSET: usize = 1;
+/// The number of the `output.flags` scalars.
+pub const OUTPUT_FLAGS_SIZE: usize = 2;
+
+/// The offset of `output.flags.0` in the public input vector.
+pub const OUTPUT_FLAGS_0_OFFSET: usize = 1;
+/// The number of the `output.flags.0` scalars.
+pub const OUTPUT_FLAGS_0_SIZE: usize = 1;
+
+/// The offset of `output.flags.1` in the public input vector.
+pub const OUTPUT_FLAGS_1_OFFSET: usize = 2;
+/// The number of the `output.flags.1` scalars.
+pub const OUTPUT_FLAGS_1_SIZE: usize = 1;
+
+/// The offset of `output.digest` in the public input vector.
+pub const OUTPUT_DIGEST_OFFSET: usize = 3;
+/// The number of the `output.digest` scalars.
+pub const OUTPUT_DIGEST_SIZE: usize = 4;
+/// The number of the `output.digest` array elements.
+pub const OUTPUT_DIGEST_LENGTH: usize = 4;
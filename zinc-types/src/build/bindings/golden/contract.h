@@ -0,0 +1,79 @@
+/*
+ * The `Wallet` contract public input layout.
+ *
+ * Generated by `zargo build --bindings c` from the build metadata. Do not edit.
+ */
+
+#ifndef ZINC_WALLET_BINDINGS_H
+#define ZINC_WALLET_BINDINGS_H
+
+#include <stdint.h>
+
+/* The SHA-256 digest of the bytecode file, which the keys are generated for. */
+static const uint8_t WALLET_CIRCUIT_HASH[32] = {
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+};
+
+/* The `balance_of` method public input layout. */
+
+/* The ABI selector of the method signature. */
+#define WALLET_BALANCE_OF_SELECTOR UINT32_C(0xc0bb20c3)
+
+/* The number of the input scalars. */
+#define WALLET_BALANCE_OF_INPUT_SIZE 2
+
+/* The number of the output scalars. */
+#define WALLET_BALANCE_OF_OUTPUT_SIZE 1
+
+/* The number of the public input scalars. */
+#define WALLET_BALANCE_OF_PUBLIC_INPUT_SIZE 2
+
+/* The offset of the storage root hash in the public input vector. */
+#define WALLET_BALANCE_OF_STORAGE_ROOT_OFFSET 1
+
+/* The `deposit` method public input layout. */
+
+/* The ABI selector of the method signature. */
+#define WALLET_DEPOSIT_SELECTOR UINT32_C(0x2df2cef7)
+
+/* The number of the input scalars. */
+#define WALLET_DEPOSIT_INPUT_SIZE 1
+
+/* The number of the output scalars. */
+#define WALLET_DEPOSIT_OUTPUT_SIZE 1
+
+/* The number of the public input scalars. */
+#define WALLET_DEPOSIT_PUBLIC_INPUT_SIZE 2
+
+/* The offset of the storage root hash in the public input vector. */
+#define WALLET_DEPOSIT_STORAGE_ROOT_OFFSET 1
+
+/* The `info` method public input layout. */
+
+/* The ABI selector of the method signature. */
+#define WALLET_INFO_SELECTOR UINT32_C(0xed241335)
+
+/* The number of the input scalars. */
+#define WALLET_INFO_INPUT_SIZE 1
+
+/* The number of the output scalars. */
+#define WALLET_INFO_OUTPUT_SIZE 2
+
+/* The number of the public input scalars. */
+#define WALLET_INFO_PUBLIC_INPUT_SIZE 3
+
+/* The offset of the storage root hash in the public input vector. */
+#define WALLET_INFO_STORAGE_ROOT_OFFSET 2
+
+/* The offset of `output.owner` in the public input vector. */
+#define WALLET_INFO_OUTPUT_OWNER_OFFSET 0
+/* The number of the `output.owner` scalars. */
+#define WALLET_INFO_OUTPUT_OWNER_SIZE 1
+
+/* The offset of `output.balance` in the public input vector. */
+#define WALLET_INFO_OUTPUT_BALANCE_OFFSET 1
+/* The number of the `output.balance` scalars. */
+#define WALLET_INFO_OUTPUT_BALANCE_SIZE 1
+
+#endif /* ZINC_WALLET_BINDINGS_H */
@@ -0,0 +1,75 @@
+//!
+//! The `Wallet` contract public input layout.
+//!
+//! Generated by `zargo build --bindings rust` from the build metadata. Do not edit.
+//!
+
+/// The SHA-256 digest of the bytecode file, which the keys are generated for.
+pub const CIRCUIT_HASH: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// The `balance_of` method public input layout.
+pub mod balance_of {
+    /// The ABI selector of the method signature.
+    pub const SELECTOR: u32 = 0xc0bb20c3;
+
+    /// The number of the input scalars.
+    pub const INPUT_SIZE: usize = 2;
+
+    /// The number of the output scalars.
+    pub const OUTPUT_SIZE: usize = 1;
+
+    /// The number of the public input scalars.
+    pub const PUBLIC_INPUT_SIZE: usize = 2;
+
+    /// The offset of the storage root hash in the public input vector.
+    pub const STORAGE_ROOT_OFFSET: usize = 1;
+}
+
+/// The `deposit` method public input layout.
+pub mod deposit {
+    /// The ABI selector of the method signature.
+    pub const SELECTOR: u32 = 0x2df2cef7;
+
+    /// The number of the input scalars.
+    pub const INPUT_SIZE: usize = 1;
+
+    /// The number of the output scalars.
+    pub const OUTPUT_SIZE: usize = 1;
+
+    /// The number of the public input scalars.
+    pub const PUBLIC_INPUT_SIZE: usize = 2;
+
+    /// The offset of the storage root hash in the public input vector.
+    pub const STORAGE_ROOT_OFFSET: usize = 1;
+}
+
+/// The `info` method public input layout.
+pub mod info {
+    /// The ABI selector of the method signature.
+    pub const SELECTOR: u32 = 0xed241335;
+
+    /// The number of the input scalars.
+    pub const INPUT_SIZE: usize = 1;
+
+    /// The number of the output scalars.
+    pub const OUTPUT_SIZE: usize = 2;
+
+    /// The number of the public input scalars.
+    pub const PUBLIC_INPUT_SIZE: usize = 3;
+
+    /// The offset of the storage root hash in the public input vector.
+    pub const STORAGE_ROOT_OFFSET: usize = 2;
+
+    /// The offset of `output.owner` in the public input vector.
+    pub const OUTPUT_OWNER_OFFSET: usize = 0;
+    /// The number of the `output.owner` scalars.
+    pub const OUTPUT_OWNER_SIZE: usize = 1;
+
+    /// The offset of `output.balance` in the public input vector.
+    pub const OUTPUT_BALANCE_OFFSET: usize = 1;
+    /// The number of the `output.balance` scalars.
+    pub const OUTPUT_BALANCE_SIZE: usize = 1;
+}
@@ -0,0 +1,50 @@
+//!
+//! The Zinc build bindings language.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The bindings language.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    /// The `bindings.rs` file, which only consists of constants, so it is `no_std`-friendly.
+    Rust,
+    /// The `bindings.h` header.
+    C,
+}
+
+impl Language {
+    ///
+    /// The bindings file name.
+    ///
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Rust => "bindings.rs",
+            Self::C => "bindings.h",
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "rust" => Ok(Self::Rust),
+            "c" => Ok(Self::C),
+            value => Err(format!("expected `rust` or `c`, but found `{}`", value)),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rust => write!(f, "rust"),
+            Self::C => write!(f, "c"),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! The Zinc build public input layout bindings.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod c;
+pub mod entry;
+pub mod language;
+pub mod rust;
+
+use crate::application::Application;
+
+use self::entry::Entry;
+use self::language::Language;
+
+///
+/// The public input layout bindings.
+///
+/// Describes the input and output sizes, the offsets of the output fields in the flat public
+/// input vector, the contract method selectors, and the circuit hash, so the verifiers written
+/// in other languages do not have to reverse-engineer the layout.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bindings {
+    /// The application name.
+    pub name: String,
+    /// The SHA-256 digest of the bytecode file, which the keys are generated for.
+    pub circuit_hash: Vec<u8>,
+    /// Whether the application is a contract.
+    pub is_contract: bool,
+    /// The circuit entry, or an entry for each contract method.
+    pub entries: Vec<Entry>,
+}
+
+impl Bindings {
+    ///
+    /// Describes the `application` layout.
+    ///
+    /// Returns `None` for libraries, since they have no public inputs.
+    ///
+    pub fn new(application: &Application, circuit_hash: Vec<u8>) -> Option<Self> {
+        match application {
+            Application::Circuit(circuit) => Some(Self {
+                name: circuit.name.to_owned(),
+                circuit_hash,
+                is_contract: false,
+                entries: vec![Entry::new_circuit(circuit)],
+            }),
+            Application::Contract(contract) => Some(Self {
+                name: contract.name.to_owned(),
+                circuit_hash,
+                is_contract: true,
+                entries: contract.methods.values().map(Entry::new_method).collect(),
+            }),
+            Application::Library(_) => None,
+        }
+    }
+
+    ///
+    /// Generates the bindings source code in the `language`.
+    ///
+    /// The output only depends on the layout, so it does not change between the builds of the
+    /// same application.
+    ///
+    pub fn generate(&self, language: Language) -> String {
+        match language {
+            Language::Rust => rust::generate(self),
+            Language::C => c::generate(self),
+        }
+    }
+
+    ///
+    /// Converts the path `segments` into an uppercase constant name, e.g. `OUTPUT_BALANCES`.
+    ///
+    pub fn constant_name(segments: &[&str]) -> String {
+        segments
+            .iter()
+            .map(|segment| Self::identifier(segment).to_uppercase())
+            .collect::<Vec<String>>()
+            .join("_")
+    }
+
+    ///
+    /// Replaces the characters, which are not allowed in the Rust and C identifiers, with `_`.
+    ///
+    pub fn identifier(name: &str) -> String {
+        name.chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() {
+                    character
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
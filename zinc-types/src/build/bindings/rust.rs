@@ -0,0 +1,98 @@
+//!
+//! The Zinc build Rust bindings backend.
+//!
+
+use crate::build::bindings::entry::Entry;
+use crate::build::bindings::language::Language;
+use crate::build::bindings::Bindings;
+
+/// The number of the circuit hash bytes on a line.
+const HASH_BYTES_PER_LINE: usize = 16;
+
+/// The nested module indentation.
+const INDENT: &str = "    ";
+
+///
+/// Generates the `bindings.rs` file, which only consists of constants, so it can be included
+/// into `no_std` crates.
+///
+/// The contract method constants are placed into a module named after the method.
+///
+pub fn generate(bindings: &Bindings) -> String {
+    let mut lines = vec![
+        "//!".to_owned(),
+        format!(
+            "//! The `{}` {} public input layout.",
+            bindings.name,
+            if bindings.is_contract {
+                "contract"
+            } else {
+                "circuit"
+            }
+        ),
+        "//!".to_owned(),
+        format!(
+            "//! Generated by `zargo build --bindings {}` from the build metadata. Do not edit.",
+            Language::Rust
+        ),
+        "//!".to_owned(),
+        String::new(),
+        "/// The SHA-256 digest of the bytecode file, which the keys are generated for.".to_owned(),
+        format!(
+            "pub const CIRCUIT_HASH: [u8; {}] = [",
+            bindings.circuit_hash.len()
+        ),
+    ];
+    for chunk in bindings.circuit_hash.chunks(HASH_BYTES_PER_LINE) {
+        let bytes: Vec<String> = chunk.iter().map(|byte| format!("0x{:02x}", byte)).collect();
+        lines.push(format!("{}{},", INDENT, bytes.join(", ")));
+    }
+    lines.push("];".to_owned());
+
+    for entry in bindings.entries.iter() {
+        lines.push(String::new());
+
+        match entry.method {
+            Some(ref method) => {
+                lines.push(format!("/// The `{}` method public input layout.", method));
+                lines.push(format!("pub mod {} {{", Bindings::identifier(method)));
+                for line in entry_lines(entry).into_iter() {
+                    if line.is_empty() {
+                        lines.push(line);
+                    } else {
+                        lines.push(format!("{}{}", INDENT, line));
+                    }
+                }
+                lines.push("}".to_owned());
+            }
+            None => lines.extend(entry_lines(entry)),
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+///
+/// Renders the `entry` constants, separating the groups with empty lines.
+///
+fn entry_lines(entry: &Entry) -> Vec<String> {
+    let mut groups = Vec::new();
+
+    if let Some(selector) = entry.selector {
+        groups.push(vec![
+            "/// The ABI selector of the method signature.".to_owned(),
+            format!("pub const SELECTOR: u32 = 0x{:08x};", selector),
+        ]);
+    }
+
+    for constants in entry.constants().into_iter() {
+        let mut group = Vec::with_capacity(constants.len() * 2);
+        for (description, name, value) in constants.into_iter() {
+            group.push(format!("/// {}", description));
+            group.push(format!("pub const {}: usize = {};", name, value));
+        }
+        groups.push(group);
+    }
+
+    groups.join(&String::new())
+}
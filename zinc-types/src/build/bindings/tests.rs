@@ -0,0 +1,227 @@
+//!
+//! The Zinc build bindings tests.
+//!
+
+use indexmap::IndexMap;
+
+use crate::application::circuit::Circuit;
+use crate::application::contract::method::Method;
+use crate::application::contract::Contract;
+use crate::application::library::Library;
+use crate::application::Application;
+use crate::build::bindings::entry::Field;
+use crate::build::bindings::language::Language;
+use crate::build::bindings::Bindings;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+///
+/// The emitted Rust bindings are compiled with the tests, so a malformed output breaks the build.
+///
+#[allow(dead_code)]
+#[path = "golden/circuit.rs"]
+mod circuit_bindings;
+
+#[allow(dead_code)]
+#[path = "golden/contract.rs"]
+mod contract_bindings;
+
+fn circuit_hash() -> Vec<u8> {
+    (0..zinc_const::size::SHA256_HASH as u8).collect()
+}
+
+fn bytes(size: usize) -> Type {
+    Type::Array(
+        Box::new(Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+        size,
+    )
+}
+
+///
+/// The fixture circuit:
+///
+/// ```zinc
+/// struct Output { sum: field, flags: (bool, bool), digest: [u8; 4] }
+///
+/// fn main(a: field, b: [u8; 4]) -> Output;
+/// ```
+///
+fn circuit() -> Application {
+    Application::Circuit(Circuit::new(
+        "sum".to_owned(),
+        0,
+        Type::Structure(vec![
+            ("a".to_owned(), Type::Scalar(ScalarType::Field)),
+            ("b".to_owned(), bytes(4)),
+        ]),
+        Type::Structure(vec![
+            ("sum".to_owned(), Type::Scalar(ScalarType::Field)),
+            (
+                "flags".to_owned(),
+                Type::Tuple(vec![
+                    Type::Scalar(ScalarType::Boolean),
+                    Type::Scalar(ScalarType::Boolean),
+                ]),
+            ),
+            ("digest".to_owned(), bytes(4)),
+        ]),
+        IndexMap::new(),
+        vec![],
+    ))
+}
+
+fn method(name: &str, is_mutable: bool, input: Vec<(String, Type)>, output: Type) -> Method {
+    let mut arguments = vec![(
+        "self".to_owned(),
+        Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+    )];
+    arguments.extend(input);
+
+    Method::new(
+        0,
+        name.to_owned(),
+        0,
+        is_mutable,
+        false,
+        None,
+        Type::Structure(arguments),
+        output,
+    )
+}
+
+///
+/// The fixture contract:
+///
+/// ```zinc
+/// contract Wallet {
+///     pub fn balance_of(self, owner: zksync::Address) -> zksync::Balance;
+///     pub fn deposit(mut self) -> bool;
+///     pub fn info(self) -> Info { owner: zksync::Address, balance: zksync::Balance };
+/// }
+/// ```
+///
+fn contract() -> Application {
+    let methods = vec![
+        method(
+            "balance_of",
+            false,
+            vec![("owner".to_owned(), Type::Address)],
+            Type::Balance,
+        ),
+        method("deposit", true, vec![], Type::Scalar(ScalarType::Boolean)),
+        method(
+            "info",
+            false,
+            vec![],
+            Type::Structure(vec![
+                ("owner".to_owned(), Type::Address),
+                ("balance".to_owned(), Type::Balance),
+            ]),
+        ),
+    ];
+
+    Application::Contract(Contract::new(
+        "Wallet".to_owned(),
+        vec![],
+        methods
+            .into_iter()
+            .map(|method| (method.name.clone(), method))
+            .collect(),
+        IndexMap::new(),
+        vec![],
+        None,
+        vec![],
+    ))
+}
+
+fn generate(application: Application, language: Language) -> String {
+    Bindings::new(&application, circuit_hash())
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .generate(language)
+}
+
+#[test]
+fn ok_circuit_rust() {
+    assert_eq!(
+        generate(circuit(), Language::Rust),
+        include_str!("golden/circuit.rs")
+    );
+}
+
+#[test]
+fn ok_circuit_c() {
+    assert_eq!(
+        generate(circuit(), Language::C),
+        include_str!("golden/circuit.h")
+    );
+}
+
+#[test]
+fn ok_contract_rust() {
+    assert_eq!(
+        generate(contract(), Language::Rust),
+        include_str!("golden/contract.rs")
+    );
+}
+
+#[test]
+fn ok_contract_c() {
+    assert_eq!(
+        generate(contract(), Language::C),
+        include_str!("golden/contract.h")
+    );
+}
+
+#[test]
+fn ok_deterministic() {
+    assert_eq!(
+        generate(contract(), Language::Rust),
+        generate(contract(), Language::Rust)
+    );
+}
+
+#[test]
+fn ok_compiled_constants() {
+    assert_eq!(circuit_bindings::CIRCUIT_HASH.to_vec(), circuit_hash());
+    assert_eq!(
+        circuit_bindings::PUBLIC_INPUT_SIZE,
+        circuit_bindings::OUTPUT_DIGEST_OFFSET + circuit_bindings::OUTPUT_DIGEST_SIZE
+    );
+
+    assert_eq!(contract_bindings::balance_of::SELECTOR, 0xc0bb_20c3);
+    assert_eq!(
+        contract_bindings::info::STORAGE_ROOT_OFFSET,
+        contract_bindings::info::OUTPUT_SIZE
+    );
+}
+
+#[test]
+fn ok_nested_fields() {
+    let bindings =
+        Bindings::new(&circuit(), circuit_hash()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        bindings.entries[0].fields[3],
+        Field {
+            path: vec!["flags".to_owned(), "1".to_owned()],
+            offset: 2,
+            size: 1,
+            length: None,
+        }
+    );
+}
+
+#[test]
+fn ok_language_from_str() {
+    assert_eq!("rust".parse::<Language>(), Ok(Language::Rust));
+    assert_eq!("c".parse::<Language>(), Ok(Language::C));
+    assert!("go".parse::<Language>().is_err());
+}
+
+#[test]
+fn error_library() {
+    let library = Application::Library(Library::new("lib".to_owned(), IndexMap::new(), vec![]));
+
+    assert_eq!(Bindings::new(&library, circuit_hash()), None);
+}
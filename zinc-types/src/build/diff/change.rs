@@ -0,0 +1,160 @@
+//!
+//! The Zinc build artifacts diff change.
+//!
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::data::r#type::Type;
+
+///
+/// The change of a single item, e.g. a metadata field, a type field, or a storage field.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change {
+    /// The changed item path, e.g. `input.amount` or `balances`.
+    pub path: String,
+    /// The previous value, which is `None` if the item has been added.
+    pub previous: Option<String>,
+    /// The current value, which is `None` if the item has been removed.
+    pub current: Option<String>,
+}
+
+impl Change {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, previous: Option<String>, current: Option<String>) -> Self {
+        Self {
+            path,
+            previous,
+            current,
+        }
+    }
+
+    ///
+    /// Returns the change of the item at `path`, or `None` if its value has not changed.
+    ///
+    pub fn compare(path: &str, previous: Option<String>, current: Option<String>) -> Option<Self> {
+        if previous == current {
+            return None;
+        }
+
+        Some(Self::new(path.to_owned(), previous, current))
+    }
+
+    ///
+    /// Compares the `previous` and `current` types structurally, pushing the changes to `changes`.
+    ///
+    /// The structure fields are matched by their names, and the tuple elements by their indexes,
+    /// so only the innermost changed types are reported. The array elements are compared if the
+    /// array size has not changed.
+    ///
+    pub fn types(path: &str, previous: &Type, current: &Type, changes: &mut Vec<Self>) {
+        if previous == current {
+            return;
+        }
+
+        match (previous, current) {
+            (Type::Structure(previous), Type::Structure(current)) => {
+                Self::fields(path, previous.as_slice(), current.as_slice(), changes)
+            }
+            (Type::Tuple(previous), Type::Tuple(current)) => {
+                for index in 0..std::cmp::max(previous.len(), current.len()) {
+                    let path = Self::join(path, index.to_string().as_str());
+                    match (previous.get(index), current.get(index)) {
+                        (Some(previous), Some(current)) => {
+                            Self::types(path.as_str(), previous, current, changes)
+                        }
+                        (previous, current) => changes.push(Self::new(
+                            path,
+                            previous.map(Type::to_string),
+                            current.map(Type::to_string),
+                        )),
+                    }
+                }
+            }
+            (Type::Array(previous, previous_size), Type::Array(current, current_size))
+                if previous_size == current_size =>
+            {
+                Self::types(
+                    format!("{}[]", path).as_str(),
+                    previous.as_ref(),
+                    current.as_ref(),
+                    changes,
+                )
+            }
+            (previous, current) => changes.push(Self::new(
+                path.to_owned(),
+                Some(previous.to_string()),
+                Some(current.to_string()),
+            )),
+        }
+    }
+
+    ///
+    /// Compares the `previous` and `current` named fields, pushing the changes to `changes`.
+    ///
+    /// The removed and changed fields are reported in the previous order, followed by the added
+    /// fields in the current order.
+    ///
+    pub fn fields(
+        path: &str,
+        previous: &[(String, Type)],
+        current: &[(String, Type)],
+        changes: &mut Vec<Self>,
+    ) {
+        for (name, previous_type) in previous.iter() {
+            let path = Self::join(path, name.as_str());
+            match current
+                .iter()
+                .find(|(current_name, _current_type)| current_name == name)
+            {
+                Some((_current_name, current_type)) => {
+                    Self::types(path.as_str(), previous_type, current_type, changes)
+                }
+                None => changes.push(Self::new(path, Some(previous_type.to_string()), None)),
+            }
+        }
+
+        for (name, current_type) in current.iter() {
+            if previous
+                .iter()
+                .any(|(previous_name, _previous_type)| previous_name == name)
+            {
+                continue;
+            }
+
+            changes.push(Self::new(
+                Self::join(path, name.as_str()),
+                None,
+                Some(current_type.to_string()),
+            ));
+        }
+    }
+
+    ///
+    /// Appends the `child` segment to the `parent` path.
+    ///
+    fn join(parent: &str, child: &str) -> String {
+        if parent.is_empty() {
+            child.to_owned()
+        } else {
+            format!("{}.{}", parent, child)
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.previous.as_ref(), self.current.as_ref()) {
+            (Some(previous), Some(current)) => {
+                write!(f, "~ {}: {} -> {}", self.path, previous, current)
+            }
+            (None, Some(current)) => write!(f, "+ {}: {}", self.path, current),
+            (Some(previous), None) => write!(f, "- {}: {}", self.path, previous),
+            (None, None) => write!(f, "~ {}", self.path),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//!
+//! The Zinc build artifacts diff function instruction count.
+//!
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The changed instruction count of a function.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Function {
+    /// The function name.
+    pub name: String,
+    /// The previous instruction count, which is `None` if the function has been added.
+    pub previous: Option<usize>,
+    /// The current instruction count, which is `None` if the function has been removed.
+    pub current: Option<usize>,
+}
+
+impl Function {
+    /// The name, which the instructions written before the first function marker are counted as.
+    pub const PROLOGUE_NAME: &'static str = "<prologue>";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, previous: Option<usize>, current: Option<usize>) -> Self {
+        Self {
+            name,
+            previous,
+            current,
+        }
+    }
+
+    ///
+    /// Compares the instruction counts of the `previous` and `current` functions.
+    ///
+    /// The functions are reported in the order of their first appearance in the bytecode.
+    ///
+    pub fn compare(previous: &[Instruction], current: &[Instruction]) -> Vec<Self> {
+        let previous = Self::count(previous);
+        let current = Self::count(current);
+
+        let mut functions = Vec::new();
+        for name in previous.keys().chain(
+            current
+                .keys()
+                .filter(|name| !previous.contains_key(name.as_str())),
+        ) {
+            let previous = previous.get(name).copied();
+            let current = current.get(name).copied();
+            if previous != current {
+                functions.push(Self::new(name.to_owned(), previous, current));
+            }
+        }
+        functions
+    }
+
+    ///
+    /// Counts the `instructions` of each function, that is, the ones between its marker and the
+    /// next function marker.
+    ///
+    /// The debug information markers and no-operations are not counted. The unrolled copies of
+    /// a recursive function share its name, so they are counted together.
+    ///
+    pub fn count(instructions: &[Instruction]) -> IndexMap<String, usize> {
+        let mut counts = IndexMap::new();
+
+        let mut function = Self::PROLOGUE_NAME;
+        for instruction in instructions.iter() {
+            match instruction {
+                Instruction::FunctionMarker(marker) => {
+                    function = marker.function.as_str();
+                }
+                Instruction::NoOperation(_) => {}
+                instruction if instruction.is_debug() => {}
+                _ => *counts.entry(function.to_owned()).or_default() += 1,
+            }
+        }
+
+        counts
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.previous, self.current) {
+            (Some(previous), Some(current)) => {
+                write!(f, "~ {}: {} -> {}", self.name, previous, current)
+            }
+            (None, Some(current)) => write!(f, "+ {}: {}", self.name, current),
+            (Some(previous), None) => write!(f, "- {}: {}", self.name, previous),
+            (None, None) => write!(f, "~ {}", self.name),
+        }
+    }
+}
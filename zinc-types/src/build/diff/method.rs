@@ -0,0 +1,244 @@
+//!
+//! The Zinc build artifacts diff circuit entry and contract methods.
+//!
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::application::contract::abi::Entry as AbiEntry;
+use crate::application::Application;
+use crate::build::diff::change::Change;
+use crate::data::r#type::Type;
+
+///
+/// The circuit entry or contract method identity.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Method {
+    /// The method name.
+    pub name: String,
+    /// The method ABI selector, which is `None` for the circuit entry.
+    pub selector: Option<String>,
+}
+
+impl Method {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, selector: Option<String>) -> Self {
+        Self { name, selector }
+    }
+}
+
+impl From<&Signature<'_>> for Method {
+    fn from(signature: &Signature<'_>) -> Self {
+        Self::new(signature.name.to_owned(), signature.selector.clone())
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.selector {
+            Some(ref selector) => write!(f, "{} ({})", self.name, selector),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+///
+/// The method, which has been renamed without changing its signature.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Rename {
+    /// The previous method identity.
+    pub previous: Method,
+    /// The current method identity.
+    pub current: Method,
+}
+
+impl Rename {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(previous: Method, current: Method) -> Self {
+        Self { previous, current }
+    }
+}
+
+impl fmt::Display for Rename {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "~ {} -> {}", self.previous, self.current)
+    }
+}
+
+///
+/// The method, whose selector, mutability, or input and output types have changed.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Modification {
+    /// The method name.
+    pub name: String,
+    /// The changes with paths like `selector`, `mutable`, `input.amount`, or `output`.
+    pub changes: Vec<Change>,
+}
+
+impl Modification {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, changes: Vec<Change>) -> Self {
+        Self { name, changes }
+    }
+}
+
+///
+/// The circuit entry or contract methods changes.
+///
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct Methods {
+    /// The methods, which exist only in the current artifact.
+    pub added: Vec<Method>,
+    /// The methods, which exist only in the previous artifact.
+    pub removed: Vec<Method>,
+    /// The methods, which have been renamed without changing their signatures.
+    pub renamed: Vec<Rename>,
+    /// The methods, which exist in both artifacts, but have changed.
+    pub changed: Vec<Modification>,
+}
+
+impl Methods {
+    ///
+    /// Compares the `previous` and `current` application methods.
+    ///
+    /// A removed method is considered renamed if an added method has the same mutability, input,
+    /// and output types. The circuit entry is compared as a method without a selector.
+    ///
+    pub fn compare(previous: &Application, current: &Application) -> Self {
+        let previous = Signature::list(previous);
+        let current = Signature::list(current);
+
+        let mut methods = Self::default();
+        let mut added: Vec<&Signature> = current
+            .iter()
+            .filter(|current| {
+                previous
+                    .iter()
+                    .all(|previous| previous.name != current.name)
+            })
+            .collect();
+
+        for previous in previous.iter() {
+            if let Some(current) = current.iter().find(|current| current.name == previous.name) {
+                let changes = previous.compare(current);
+                if !changes.is_empty() {
+                    methods
+                        .changed
+                        .push(Modification::new(previous.name.to_owned(), changes));
+                }
+                continue;
+            }
+
+            match added
+                .iter()
+                .position(|current| previous.is_same_signature(current))
+            {
+                Some(index) => {
+                    let current = added.remove(index);
+                    methods
+                        .renamed
+                        .push(Rename::new(Method::from(previous), Method::from(current)));
+                }
+                None => methods.removed.push(Method::from(previous)),
+            }
+        }
+
+        methods.added = added.into_iter().map(Method::from).collect();
+        methods
+    }
+
+    ///
+    /// Checks if there are no method changes.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.renamed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+///
+/// The method data, which is compared between the artifacts.
+///
+#[derive(Debug)]
+pub struct Signature<'a> {
+    /// The method name.
+    pub name: &'a str,
+    /// The method ABI selector, which is `None` for the circuit entry.
+    pub selector: Option<String>,
+    /// Whether the method can mutate the contract storage.
+    pub is_mutable: bool,
+    /// The method input arguments type.
+    pub input: &'a Type,
+    /// The method output type.
+    pub output: &'a Type,
+}
+
+impl<'a> Signature<'a> {
+    ///
+    /// Lists the circuit entry or the contract methods of the `application`.
+    ///
+    pub fn list(application: &'a Application) -> Vec<Self> {
+        match application {
+            Application::Circuit(circuit) => vec![Self {
+                name: zinc_const::source::FUNCTION_MAIN_IDENTIFIER,
+                selector: None,
+                is_mutable: false,
+                input: &circuit.input,
+                output: &circuit.output,
+            }],
+            Application::Contract(contract) => contract
+                .methods
+                .values()
+                .map(|method| Self {
+                    name: method.name.as_str(),
+                    selector: Some(AbiEntry::new(method).selector),
+                    is_mutable: method.is_mutable,
+                    input: &method.input,
+                    output: &method.output,
+                })
+                .collect(),
+            Application::Library(_) => vec![],
+        }
+    }
+
+    ///
+    /// Checks if the `other` method has the same mutability, input, and output types.
+    ///
+    pub fn is_same_signature(&self, other: &Self) -> bool {
+        self.is_mutable == other.is_mutable
+            && self.input == other.input
+            && self.output == other.output
+    }
+
+    ///
+    /// Compares the method with its `current` version.
+    ///
+    pub fn compare(&self, current: &Self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        changes.extend(Change::compare(
+            "selector",
+            self.selector.clone(),
+            current.selector.clone(),
+        ));
+        changes.extend(Change::compare(
+            "mutable",
+            Some(self.is_mutable.to_string()),
+            Some(current.is_mutable.to_string()),
+        ));
+        Change::types("input", self.input, current.input, &mut changes);
+        Change::types("output", self.output, current.output, &mut changes);
+        changes
+    }
+}
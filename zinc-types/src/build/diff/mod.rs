@@ -0,0 +1,305 @@
+//!
+//! The Zinc build artifacts comparison.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod change;
+pub mod function;
+pub mod method;
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::application::header::Header;
+use crate::application::Application;
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+use self::change::Change;
+use self::function::Function;
+use self::method::Methods;
+
+///
+/// The structural comparison of two build artifacts.
+///
+/// Describes the metadata, per-function instruction count, method, storage, and source file
+/// changes between the previous and current builds, e.g. to preview a contract upgrade.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diff {
+    /// The changed metadata fields, e.g. the versions, the application name, or the total
+    /// instruction count.
+    pub metadata: Vec<Change>,
+    /// The functions, whose instruction counts have changed.
+    pub functions: Vec<Function>,
+    /// The circuit entry or contract methods changes.
+    pub methods: Methods,
+    /// The contract storage fields changes.
+    pub storage: Vec<Change>,
+    /// The source files, whose generated code has changed. `None` if any of the artifacts has
+    /// been built without the debug information markers.
+    pub files: Option<Vec<String>>,
+}
+
+impl Diff {
+    ///
+    /// Compares the `previous` and `current` artifacts along with their headers.
+    ///
+    pub fn new(
+        previous_header: &Header,
+        previous: &Application,
+        current_header: &Header,
+        current: &Application,
+    ) -> Self {
+        let mut metadata = Vec::new();
+        metadata.extend(Change::compare(
+            "name",
+            Some(Self::name(previous).to_owned()),
+            Some(Self::name(current).to_owned()),
+        ));
+        metadata.extend(Change::compare(
+            "type",
+            Some(Self::kind(previous).to_owned()),
+            Some(Self::kind(current).to_owned()),
+        ));
+        metadata.extend(Change::compare(
+            "format_version",
+            Some(previous_header.format_version.to_string()),
+            Some(current_header.format_version.to_string()),
+        ));
+        metadata.extend(Change::compare(
+            "std_version",
+            Some(previous_header.std_version.to_string()),
+            Some(current_header.std_version.to_string()),
+        ));
+        metadata.extend(Change::compare(
+            "compiler_version",
+            previous_header.compiler_version.clone(),
+            current_header.compiler_version.clone(),
+        ));
+        metadata.extend(Change::compare(
+            "project_version",
+            previous_header.project_version.clone(),
+            current_header.project_version.clone(),
+        ));
+        metadata.extend(Change::compare(
+            "instructions",
+            Some(Self::instruction_count(previous.instructions()).to_string()),
+            Some(Self::instruction_count(current.instructions()).to_string()),
+        ));
+
+        let mut storage = Vec::new();
+        Change::fields(
+            "",
+            Self::storage(previous).as_slice(),
+            Self::storage(current).as_slice(),
+            &mut storage,
+        );
+
+        let files = match (
+            Self::files(previous.instructions()),
+            Self::files(current.instructions()),
+        ) {
+            (Some(previous), Some(current)) => {
+                let mut files: Vec<String> = previous
+                    .keys()
+                    .chain(current.keys())
+                    .filter(|file| previous.get(file.as_str()) != current.get(file.as_str()))
+                    .cloned()
+                    .collect();
+                files.sort();
+                files.dedup();
+                Some(files)
+            }
+            _ => None,
+        };
+
+        Self {
+            metadata,
+            functions: Function::compare(previous.instructions(), current.instructions()),
+            methods: Methods::compare(previous, current),
+            storage,
+            files,
+        }
+    }
+
+    ///
+    /// Checks if the artifacts are equivalent.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.metadata.is_empty()
+            && self.functions.is_empty()
+            && self.methods.is_empty()
+            && self.storage.is_empty()
+            && self.files.as_ref().map_or(true, Vec::is_empty)
+    }
+
+    ///
+    /// Returns the application name.
+    ///
+    fn name(application: &Application) -> &str {
+        match application {
+            Application::Circuit(inner) => inner.name.as_str(),
+            Application::Contract(inner) => inner.name.as_str(),
+            Application::Library(inner) => inner.name.as_str(),
+        }
+    }
+
+    ///
+    /// Returns the application type name.
+    ///
+    fn kind(application: &Application) -> &'static str {
+        match application {
+            Application::Circuit(_) => "circuit",
+            Application::Contract(_) => "contract",
+            Application::Library(_) => "library",
+        }
+    }
+
+    ///
+    /// Counts the `instructions`, excluding the debug information markers and no-operations.
+    ///
+    fn instruction_count(instructions: &[Instruction]) -> usize {
+        Function::count(instructions).values().sum()
+    }
+
+    ///
+    /// Returns the contract storage fields, or nothing for the other applications.
+    ///
+    fn storage(application: &Application) -> Vec<(String, Type)> {
+        match application {
+            Application::Contract(contract) => contract
+                .storage
+                .iter()
+                .map(|field| (field.name.to_owned(), field.r#type.to_owned()))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Splits the `instructions` into the source files using the debug information markers.
+    ///
+    /// The instructions are rendered as text without the debug markers, and the call addresses
+    /// are replaced with the callee names, so the code shifted by the changes in other files
+    /// is not reported. Returns `None` if there are no debug information markers.
+    ///
+    fn files(instructions: &[Instruction]) -> Option<IndexMap<String, Vec<String>>> {
+        let mut files: IndexMap<String, Vec<String>> = IndexMap::new();
+
+        let mut file = None;
+        for instruction in instructions.iter() {
+            match instruction {
+                Instruction::FileMarker(marker) => {
+                    file = Some(marker.file.as_str());
+                    files.entry(marker.file.to_owned()).or_default();
+                    continue;
+                }
+                Instruction::NoOperation(_) => continue,
+                instruction if instruction.is_debug() => continue,
+                _ => {}
+            }
+
+            let file = match file {
+                Some(file) => file,
+                None => continue,
+            };
+            let instruction = match instruction {
+                Instruction::Call(call) => {
+                    format!("call {}", Self::callee(instructions, call.address))
+                }
+                instruction => instruction.to_string(),
+            };
+            files
+                .get_mut(file)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+                .push(instruction);
+        }
+
+        if files.is_empty() {
+            None
+        } else {
+            Some(files)
+        }
+    }
+
+    ///
+    /// Finds the name of the function at `address` in its leading debug markers.
+    ///
+    /// Falls back to the address if the function has no marker.
+    ///
+    fn callee(instructions: &[Instruction], address: usize) -> String {
+        instructions
+            .iter()
+            .skip(address)
+            .take_while(|instruction| instruction.is_debug())
+            .find_map(|instruction| match instruction {
+                Instruction::FunctionMarker(marker) => Some(marker.function.to_owned()),
+                _ => None,
+            })
+            .unwrap_or_else(|| address.to_string())
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+
+        if !self.metadata.is_empty() {
+            writeln!(f, "Metadata:")?;
+            for change in self.metadata.iter() {
+                writeln!(f, "    {}", change)?;
+            }
+        }
+
+        if !self.functions.is_empty() {
+            writeln!(f, "Instructions:")?;
+            for function in self.functions.iter() {
+                writeln!(f, "    {}", function)?;
+            }
+        }
+
+        if !self.methods.is_empty() {
+            writeln!(f, "Methods:")?;
+            for method in self.methods.added.iter() {
+                writeln!(f, "    + {}", method)?;
+            }
+            for method in self.methods.removed.iter() {
+                writeln!(f, "    - {}", method)?;
+            }
+            for rename in self.methods.renamed.iter() {
+                writeln!(f, "    {}", rename)?;
+            }
+            for method in self.methods.changed.iter() {
+                writeln!(f, "    ~ {}", method.name)?;
+                for change in method.changes.iter() {
+                    writeln!(f, "        {}", change)?;
+                }
+            }
+        }
+
+        if !self.storage.is_empty() {
+            writeln!(f, "Storage:")?;
+            for change in self.storage.iter() {
+                writeln!(f, "    {}", change)?;
+            }
+        }
+
+        if let Some(ref files) = self.files {
+            if !files.is_empty() {
+                writeln!(f, "Files:")?;
+                for file in files.iter() {
+                    writeln!(f, "    ~ {}", file)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
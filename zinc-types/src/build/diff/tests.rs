@@ -0,0 +1,362 @@
+//!
+//! The Zinc build artifacts comparison tests.
+//!
+
+use indexmap::IndexMap;
+
+use crate::application::contract::abi::Entry as AbiEntry;
+use crate::application::contract::method::Method as ContractMethod;
+use crate::application::contract::Contract;
+use crate::application::header::Header;
+use crate::application::Application;
+use crate::build::diff::change::Change;
+use crate::build::diff::method::Method;
+use crate::build::diff::method::Modification;
+use crate::build::diff::method::Rename;
+use crate::build::diff::Diff;
+use crate::data::r#type::contract_field::ContractField as ContractFieldType;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::data_stack::load::Load;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::marker::file::FileMarker;
+use crate::instructions::marker::function::FunctionMarker;
+use crate::instructions::marker::line::LineMarker;
+use crate::instructions::operator::arithmetic::add::Add;
+use crate::instructions::Instruction;
+
+fn integer(bitlength: usize) -> Type {
+    Type::Scalar(ScalarType::Integer(IntegerType {
+        is_signed: false,
+        bitlength,
+    }))
+}
+
+fn method(
+    name: &str,
+    is_mutable: bool,
+    input: Vec<(String, Type)>,
+    output: Type,
+) -> ContractMethod {
+    let mut arguments = vec![(
+        "self".to_owned(),
+        Type::Scalar(ScalarType::Integer(IntegerType::ETH_ADDRESS)),
+    )];
+    arguments.extend(input);
+
+    ContractMethod::new(
+        0,
+        name.to_owned(),
+        0,
+        is_mutable,
+        false,
+        None,
+        Type::Structure(arguments),
+        output,
+    )
+}
+
+fn contract(
+    methods: Vec<ContractMethod>,
+    storage: Vec<(&str, Type)>,
+    instructions: Vec<Instruction>,
+) -> Application {
+    Application::Contract(Contract::new(
+        "Wallet".to_owned(),
+        storage
+            .into_iter()
+            .map(|(name, r#type)| ContractFieldType::new(name.to_owned(), r#type, false, false))
+            .collect(),
+        methods
+            .into_iter()
+            .map(|method| (method.name.clone(), method))
+            .collect(),
+        IndexMap::new(),
+        instructions,
+        None,
+        vec![],
+    ))
+}
+
+///
+/// The bytecode of the following source code, where `deposit` adds `fee` if `with_fee` is set:
+///
+/// ```text
+/// src/main.zn:
+///  5 pub fn deposit(mut self, amount: u64) { self.balance += amount [+ fee]; }
+///  9 pub fn get(self) -> u64 { math::double(self.balance) }
+///
+/// src/math.zn:
+///  1 pub fn double(value: u64) -> u64 { value + value }
+/// ```
+///
+fn instructions(with_fee: bool) -> Vec<Instruction> {
+    let mut instructions = vec![
+        FileMarker::new("src/main.zn".to_owned()).into(),
+        FunctionMarker::new("deposit".to_owned()).into(),
+        LineMarker::new(5).into(),
+        Load::new(0, 1).into(),
+        Load::new(1, 1).into(),
+        Add::default().into(),
+    ];
+    if with_fee {
+        instructions.push(Load::new(2, 1).into());
+        instructions.push(Add::default().into());
+    }
+    instructions.push(Return::new(0).into());
+
+    let double_address = instructions.len();
+    instructions.extend(vec![
+        FileMarker::new("src/math.zn".to_owned()).into(),
+        FunctionMarker::new("double".to_owned()).into(),
+        LineMarker::new(1).into(),
+        Load::new(0, 1).into(),
+        Load::new(0, 1).into(),
+        Add::default().into(),
+        Return::new(1).into(),
+        FileMarker::new("src/main.zn".to_owned()).into(),
+        FunctionMarker::new("get".to_owned()).into(),
+        LineMarker::new(9).into(),
+        Load::new(0, 1).into(),
+        Call::new(double_address, 1).into(),
+        Return::new(1).into(),
+    ]);
+    instructions
+}
+
+fn methods() -> Vec<ContractMethod> {
+    vec![
+        method(
+            "deposit",
+            true,
+            vec![("amount".to_owned(), integer(64))],
+            Type::Unit,
+        ),
+        method("get", false, vec![], integer(64)),
+    ]
+}
+
+///
+/// The fixture contracts, which differ in the `deposit` function and the `balance` field type.
+///
+fn fixture() -> Diff {
+    let header = Header::new(&semver::Version::new(0, 1, 0));
+    let previous = contract(
+        methods(),
+        vec![("balance", integer(64))],
+        instructions(false),
+    );
+    let current = contract(
+        methods(),
+        vec![("balance", integer(128))],
+        instructions(true),
+    );
+
+    Diff::new(&header, &previous, &header, &current)
+}
+
+fn selector(method: &ContractMethod) -> Option<String> {
+    Some(AbiEntry::new(method).selector)
+}
+
+#[test]
+fn ok_text() {
+    assert_eq!(fixture().to_string(), include_str!("golden/contract.txt"));
+}
+
+#[test]
+fn ok_json() {
+    assert_eq!(
+        serde_json::to_string_pretty(&fixture()).expect(zinc_const::panic::DATA_CONVERSION) + "\n",
+        include_str!("golden/contract.json")
+    );
+}
+
+#[test]
+fn ok_equal() {
+    let header = Header::new(&semver::Version::new(0, 1, 0));
+    let application = contract(
+        methods(),
+        vec![("balance", integer(64))],
+        instructions(false),
+    );
+
+    let diff = Diff::new(&header, &application, &header, &application);
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.files, Some(vec![]));
+    assert_eq!(diff.to_string(), "No differences\n");
+}
+
+#[test]
+fn ok_metadata() {
+    let previous = contract(methods(), vec![], vec![]);
+    let current = Application::new_library("Wallet".to_owned(), IndexMap::new(), vec![]);
+
+    let diff = Diff::new(
+        &Header::legacy(),
+        &previous,
+        &Header::new(&semver::Version::new(0, 2, 0)),
+        &current,
+    );
+
+    let paths: Vec<&str> = diff
+        .metadata
+        .iter()
+        .map(|change| change.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "type",
+            "format_version",
+            "std_version",
+            "compiler_version",
+            "project_version"
+        ]
+    );
+    assert_eq!(diff.methods.removed.len(), 2);
+}
+
+#[test]
+fn ok_methods() {
+    let deposit = method(
+        "deposit",
+        true,
+        vec![("amount".to_owned(), integer(64))],
+        Type::Unit,
+    );
+    let get = method("get", false, vec![], integer(64));
+    let withdraw = method(
+        "withdraw",
+        true,
+        vec![("amount".to_owned(), integer(64))],
+        Type::Scalar(ScalarType::Boolean),
+    );
+    let deposit_current = method(
+        "deposit",
+        true,
+        vec![("amount".to_owned(), integer(128))],
+        Type::Unit,
+    );
+    let balance = method("balance", false, vec![], integer(64));
+    let transfer = method(
+        "transfer",
+        true,
+        vec![("amount".to_owned(), integer(64))],
+        Type::Unit,
+    );
+
+    let header = Header::new(&semver::Version::new(0, 1, 0));
+    let previous = contract(
+        vec![deposit.clone(), get.clone(), withdraw.clone()],
+        vec![],
+        vec![],
+    );
+    let current = contract(
+        vec![deposit_current.clone(), balance.clone(), transfer.clone()],
+        vec![],
+        vec![],
+    );
+
+    let diff = Diff::new(&header, &previous, &header, &current);
+
+    assert_eq!(
+        diff.methods.added,
+        vec![Method::new("transfer".to_owned(), selector(&transfer))]
+    );
+    assert_eq!(
+        diff.methods.removed,
+        vec![Method::new("withdraw".to_owned(), selector(&withdraw))]
+    );
+    assert_eq!(
+        diff.methods.renamed,
+        vec![Rename::new(
+            Method::new("get".to_owned(), selector(&get)),
+            Method::new("balance".to_owned(), selector(&balance)),
+        )]
+    );
+    assert_eq!(
+        diff.methods.changed,
+        vec![Modification::new(
+            "deposit".to_owned(),
+            vec![
+                Change::new(
+                    "selector".to_owned(),
+                    selector(&deposit),
+                    selector(&deposit_current)
+                ),
+                Change::new(
+                    "input.amount".to_owned(),
+                    Some("u64".to_owned()),
+                    Some("u128".to_owned())
+                ),
+            ]
+        )]
+    );
+}
+
+#[test]
+fn ok_types_structural() {
+    let previous = Type::Structure(vec![
+        ("owner".to_owned(), Type::Address),
+        (
+            "limits".to_owned(),
+            Type::Tuple(vec![integer(8), integer(8)]),
+        ),
+        ("history".to_owned(), Type::Array(Box::new(integer(64)), 4)),
+        ("flag".to_owned(), Type::Scalar(ScalarType::Boolean)),
+    ]);
+    let current = Type::Structure(vec![
+        ("owner".to_owned(), Type::Address),
+        (
+            "limits".to_owned(),
+            Type::Tuple(vec![integer(8), integer(16), integer(8)]),
+        ),
+        ("history".to_owned(), Type::Array(Box::new(integer(128)), 4)),
+        ("memo".to_owned(), Type::Scalar(ScalarType::Field)),
+    ]);
+
+    let mut changes = Vec::new();
+    Change::types("output", &previous, &current, &mut changes);
+
+    assert_eq!(
+        changes,
+        vec![
+            Change::new(
+                "output.limits.1".to_owned(),
+                Some("u8".to_owned()),
+                Some("u16".to_owned())
+            ),
+            Change::new("output.limits.2".to_owned(), None, Some("u8".to_owned())),
+            Change::new(
+                "output.history[]".to_owned(),
+                Some("u64".to_owned()),
+                Some("u128".to_owned())
+            ),
+            Change::new("output.flag".to_owned(), Some("bool".to_owned()), None),
+            Change::new("output.memo".to_owned(), None, Some("field".to_owned())),
+        ]
+    );
+}
+
+#[test]
+fn ok_without_debug_markers() {
+    let header = Header::new(&semver::Version::new(0, 1, 0));
+    let previous = contract(
+        methods(),
+        vec![],
+        instructions(false)
+            .into_iter()
+            .filter(|instruction| !instruction.is_debug())
+            .collect(),
+    );
+    let current = contract(methods(), vec![], instructions(true));
+
+    let diff = Diff::new(&header, &previous, &header, &current);
+
+    assert_eq!(diff.files, None);
+    assert_eq!(diff.functions.len(), 4);
+}
@@ -0,0 +1,481 @@
+//!
+//! The Zinc project documentation HTML backend.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use crate::build::documentation::item::Item;
+use crate::build::documentation::item::Kind;
+use crate::build::documentation::item::MemberKind;
+use crate::build::documentation::Documentation;
+use crate::build::documentation::Module;
+
+/// The stylesheet file name.
+pub const STYLESHEET: &str = "style.css";
+
+/// The search index file name.
+pub const SEARCH_INDEX: &str = "search-index.json";
+
+/// The module page file name.
+const MODULE_PAGE: &str = "index.html";
+
+/// The stylesheet, which is written along with the pages, so they do not need network access.
+const STYLE: &str = r#"body { margin: 0; display: flex; font-family: sans-serif; line-height: 1.5; color: #222; }
+nav.sidebar { min-width: 14em; padding: 1em; background: #f5f5f5; border-right: 1px solid #ddd; }
+nav.sidebar ul { list-style: none; padding-left: 0; }
+main { max-width: 60em; padding: 1em 2em; }
+a { color: #2a6db0; text-decoration: none; }
+a:hover { text-decoration: underline; }
+pre, code { font-family: monospace; background: #f5f5f5; }
+pre { padding: 0.5em; overflow-x: auto; }
+dt { margin-top: 0.5em; }
+dd { margin-left: 2em; }
+.version { color: #777; }
+.private { color: #a33; font-size: 0.8em; }
+"#;
+
+///
+/// Renders the `documentation` pages, the stylesheet, and the search index, with the file paths
+/// relative to the documentation directory as keys.
+///
+pub fn render(documentation: &Documentation) -> BTreeMap<String, String> {
+    let links = Links::new(documentation);
+
+    let mut pages = BTreeMap::new();
+    for module in documentation.modules.iter() {
+        let url = module_url(module.path.as_str());
+        pages.insert(
+            url.clone(),
+            module_page(documentation, module, url.as_str()),
+        );
+
+        for item in module.items.iter() {
+            let url = item_url(item);
+            pages.insert(
+                url.clone(),
+                item_page(documentation, module, item, &links, url.as_str()),
+            );
+        }
+    }
+
+    pages.insert(STYLESHEET.to_owned(), STYLE.to_owned());
+    pages.insert(
+        SEARCH_INDEX.to_owned(),
+        serde_json::to_string_pretty(&documentation.search_index())
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            + "\n",
+    );
+
+    pages
+}
+
+///
+/// Returns the module page URL relative to the documentation directory, e.g.
+/// `exchange/math/index.html` for the `exchange::math` module.
+///
+pub fn module_url(path: &str) -> String {
+    format!("{}/{}", directory(path), MODULE_PAGE)
+}
+
+///
+/// Returns the item page URL relative to the documentation directory, e.g.
+/// `exchange/math/struct.Fraction.html` for the `exchange::math::Fraction` structure.
+///
+pub fn item_url(item: &Item) -> String {
+    let module = match item.path.rfind(Documentation::PATH_SEPARATOR) {
+        Some(position) => &item.path[..position],
+        None => item.path.as_str(),
+    };
+
+    format!(
+        "{}/{}.{}.html",
+        directory(module),
+        item.kind.prefix(),
+        item.name
+    )
+}
+
+///
+/// The type item URLs by their names, which the signatures are cross-linked with.
+///
+struct Links {
+    /// The module paths and page URLs of the type items with the same name.
+    types: HashMap<String, Vec<(String, String)>>,
+}
+
+impl Links {
+    ///
+    /// Collects the type items of the `documentation`.
+    ///
+    fn new(documentation: &Documentation) -> Self {
+        let mut types: HashMap<String, Vec<(String, String)>> = HashMap::new();
+        for module in documentation.modules.iter() {
+            for item in module.items.iter() {
+                if let Kind::Function | Kind::Constant = item.kind {
+                    continue;
+                }
+
+                types
+                    .entry(item.name.clone())
+                    .or_default()
+                    .push((module.path.clone(), item_url(item)));
+            }
+        }
+
+        Self { types }
+    }
+
+    ///
+    /// Returns the URL of the type item `name` as seen from the `module`.
+    ///
+    /// If several modules declare types with the same name, only the one from the `module`
+    /// itself is linked, since the signatures do not contain the type paths.
+    ///
+    fn resolve(&self, name: &str, module: &str) -> Option<&str> {
+        match self.types.get(name)?.as_slice() {
+            [(_path, url)] => Some(url.as_str()),
+            candidates => candidates
+                .iter()
+                .find(|(path, _url)| path == module)
+                .map(|(_path, url)| url.as_str()),
+        }
+    }
+
+    ///
+    /// Escapes the `signature` and links the type names in it, with the URLs relative to
+    /// the page at `base`.
+    ///
+    fn signature(&self, signature: &str, module: &str, base: &str) -> String {
+        let mut result = String::with_capacity(signature.len());
+        let mut identifier = String::new();
+
+        for character in signature.chars().chain(std::iter::once(' ')) {
+            if character.is_ascii_alphanumeric() || character == '_' {
+                identifier.push(character);
+                continue;
+            }
+
+            if !identifier.is_empty() {
+                match self.resolve(identifier.as_str(), module) {
+                    Some(url) => result.push_str(
+                        format!("<a href=\"{}\">{}</a>", relative(base, url), identifier).as_str(),
+                    ),
+                    None => result.push_str(identifier.as_str()),
+                }
+                identifier.clear();
+            }
+            result.push_str(escape(character.to_string().as_str()).as_str());
+        }
+        result.pop();
+
+        result
+    }
+}
+
+///
+/// Renders the `module` page at `url`.
+///
+fn module_page(documentation: &Documentation, module: &Module, url: &str) -> String {
+    let mut body = vec![
+        format!("<h1>Module {}</h1>", breadcrumbs(module.path.as_str(), url)),
+        description(module.description.as_deref()),
+    ];
+
+    let submodules: Vec<&Module> = documentation
+        .modules
+        .iter()
+        .filter(|submodule| {
+            submodule.depth() == module.depth() + 1
+                && submodule.path.starts_with(
+                    format!("{}{}", module.path, Documentation::PATH_SEPARATOR).as_str(),
+                )
+        })
+        .collect();
+    if !submodules.is_empty() {
+        body.push("<h2 id=\"modules\">Modules</h2>".to_owned());
+        body.push("<dl>".to_owned());
+        for submodule in submodules.into_iter() {
+            body.push(format!(
+                "<dt id=\"mod.{}\"><a href=\"{}\">{}</a></dt>",
+                submodule.name(),
+                relative(url, module_url(submodule.path.as_str()).as_str()),
+                submodule.name()
+            ));
+            body.push(format!(
+                "<dd>{}</dd>",
+                escape(Documentation::summary(submodule.description.as_deref()).as_str())
+            ));
+        }
+        body.push("</dl>".to_owned());
+    }
+
+    let mut kinds: Vec<Kind> = module.items.iter().map(|item| item.kind).collect();
+    kinds.sort();
+    kinds.dedup();
+    for kind in kinds.into_iter() {
+        body.push(format!("<h2 id=\"{}s\">{}</h2>", kind, kind.title()));
+        body.push("<dl>".to_owned());
+        for item in module.items.iter().filter(|item| item.kind == kind) {
+            body.push(format!(
+                "<dt id=\"{}.{}\"><a href=\"{}\">{}</a>{}</dt>",
+                kind.prefix(),
+                item.name,
+                relative(url, item_url(item).as_str()),
+                item.name,
+                private(item.is_public)
+            ));
+            body.push(format!(
+                "<dd>{}</dd>",
+                escape(Documentation::summary(item.description.as_deref()).as_str())
+            ));
+        }
+        body.push("</dl>".to_owned());
+    }
+
+    page(documentation, module.path.as_str(), url, body)
+}
+
+///
+/// Renders the `item` page at `url`.
+///
+fn item_page(
+    documentation: &Documentation,
+    module: &Module,
+    item: &Item,
+    links: &Links,
+    url: &str,
+) -> String {
+    let mut body = vec![
+        format!(
+            "<h1>{} {}{}</h1>",
+            capitalize(item.kind.to_string().as_str()),
+            breadcrumbs(item.path.as_str(), url),
+            private(item.is_public)
+        ),
+        format!(
+            "<pre class=\"signature\">{}</pre>",
+            links.signature(item.signature.as_str(), module.path.as_str(), url)
+        ),
+        description(item.description.as_deref()),
+    ];
+
+    let mut kinds: Vec<MemberKind> = item.members.iter().map(|member| member.kind).collect();
+    kinds.sort();
+    kinds.dedup();
+    for kind in kinds.into_iter() {
+        body.push(format!("<h2 id=\"{}s\">{}</h2>", kind, kind.title()));
+        body.push("<dl>".to_owned());
+        for member in item.members.iter().filter(|member| member.kind == kind) {
+            let anchor = member.anchor();
+            body.push(format!(
+                "<dt id=\"{}\"><a href=\"#{}\">&sect;</a> <code>{}</code>{}</dt>",
+                anchor,
+                anchor,
+                links.signature(member.signature.as_str(), module.path.as_str(), url),
+                private(member.is_public)
+            ));
+            body.push(format!(
+                "<dd>{}</dd>",
+                description(member.description.as_deref())
+            ));
+        }
+        body.push("</dl>".to_owned());
+    }
+
+    page(documentation, item.path.as_str(), url, body)
+}
+
+///
+/// Wraps the page `body` lines into the HTML document with the module navigation sidebar.
+///
+fn page(documentation: &Documentation, title: &str, url: &str, body: Vec<String>) -> String {
+    let mut lines = vec![
+        "<!DOCTYPE html>".to_owned(),
+        "<html lang=\"en\">".to_owned(),
+        "<head>".to_owned(),
+        "<meta charset=\"utf-8\">".to_owned(),
+        format!(
+            "<title>{} - {} {}</title>",
+            escape(title),
+            escape(documentation.name.as_str()),
+            escape(documentation.version.as_str())
+        ),
+        format!(
+            "<link rel=\"stylesheet\" href=\"{}\">",
+            relative(url, STYLESHEET)
+        ),
+        "</head>".to_owned(),
+        "<body>".to_owned(),
+        "<nav class=\"sidebar\">".to_owned(),
+        format!(
+            "<a class=\"project\" href=\"{}\">{}</a> <span class=\"version\">{}</span>",
+            relative(url, module_url(documentation.name.as_str()).as_str()),
+            escape(documentation.name.as_str()),
+            escape(documentation.version.as_str())
+        ),
+        "<ul>".to_owned(),
+    ];
+    for module in documentation.modules.iter() {
+        lines.push(format!(
+            "<li><a href=\"{}\">{}</a></li>",
+            relative(url, module_url(module.path.as_str()).as_str()),
+            escape(module.path.as_str())
+        ));
+    }
+    lines.push("</ul>".to_owned());
+    lines.push(format!(
+        "<a href=\"{}\">Search index</a>",
+        relative(url, SEARCH_INDEX)
+    ));
+    lines.push("</nav>".to_owned());
+    lines.push("<main>".to_owned());
+    lines.extend(body.into_iter().filter(|line| !line.is_empty()));
+    lines.push("</main>".to_owned());
+    lines.push("</body>".to_owned());
+    lines.push("</html>".to_owned());
+
+    lines.join("\n") + "\n"
+}
+
+///
+/// Renders the `path` with each of its module segments linked to the module page.
+///
+fn breadcrumbs(path: &str, base: &str) -> String {
+    let segments: Vec<&str> = path.split(Documentation::PATH_SEPARATOR).collect();
+    let mut result = Vec::with_capacity(segments.len());
+    for index in 0..segments.len() {
+        let is_module_page = base.ends_with(MODULE_PAGE);
+        if index + 1 == segments.len() && !is_module_page {
+            result.push(escape(segments[index]));
+            continue;
+        }
+
+        let module = segments[..=index].join(Documentation::PATH_SEPARATOR);
+        result.push(format!(
+            "<a href=\"{}\">{}</a>",
+            relative(base, module_url(module.as_str()).as_str()),
+            escape(segments[index])
+        ));
+    }
+
+    result.join(Documentation::PATH_SEPARATOR)
+}
+
+///
+/// Renders the doc comment `text` as paragraphs and fenced code blocks.
+///
+fn description(text: Option<&str>) -> String {
+    let text = match text {
+        Some(text) if !text.trim().is_empty() => text,
+        _ => return String::new(),
+    };
+
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            match code.take() {
+                Some(code) => blocks.push(format!(
+                    "<pre><code>{}</code></pre>",
+                    escape(code.join("\n").as_str())
+                )),
+                None => {
+                    blocks.extend(self::paragraph(paragraph.as_slice()));
+                    paragraph.clear();
+                    code = Some(Vec::new());
+                }
+            }
+            continue;
+        }
+
+        match code {
+            Some(ref mut code) => code.push(line),
+            None if line.trim().is_empty() => {
+                blocks.extend(self::paragraph(paragraph.as_slice()));
+                paragraph.clear();
+            }
+            None => paragraph.push(line.trim()),
+        }
+    }
+    if let Some(code) = code {
+        blocks.push(format!(
+            "<pre><code>{}</code></pre>",
+            escape(code.join("\n").as_str())
+        ));
+    }
+    blocks.extend(self::paragraph(paragraph.as_slice()));
+
+    blocks.join("\n")
+}
+
+///
+/// Renders the `lines` as a paragraph with the inline `code` spans, if there are any lines.
+///
+fn paragraph(lines: &[&str]) -> Option<String> {
+    if lines.is_empty() {
+        return None;
+    }
+
+    let text = escape(lines.join(" ").as_str());
+    let mut result = String::with_capacity(text.len());
+    for (index, part) in text.split('`').enumerate() {
+        if index % 2 == 1 {
+            result.push_str(format!("<code>{}</code>", part).as_str());
+        } else {
+            result.push_str(part);
+        }
+    }
+
+    Some(format!("<p>{}</p>", result))
+}
+
+///
+/// Returns the marker of the private items, which are only documented on demand.
+///
+fn private(is_public: bool) -> &'static str {
+    if is_public {
+        ""
+    } else {
+        " <span class=\"private\">private</span>"
+    }
+}
+
+///
+/// Returns the module directory relative to the documentation directory.
+///
+fn directory(path: &str) -> String {
+    path.split(Documentation::PATH_SEPARATOR)
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+///
+/// Returns the `target` URL relative to the page at `base`, both relative to the
+/// documentation directory.
+///
+fn relative(base: &str, target: &str) -> String {
+    format!("{}{}", "../".repeat(base.matches('/').count()), target)
+}
+
+///
+/// Uppercases the first character of the `text`.
+///
+fn capitalize(text: &str) -> String {
+    let mut characters = text.chars();
+    match characters.next() {
+        Some(first) => first.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+///
+/// Escapes the HTML special characters.
+///
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
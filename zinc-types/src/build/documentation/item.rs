@@ -0,0 +1,226 @@
+//!
+//! The Zinc project documentation item.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The documented module item.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    /// The item kind.
+    pub kind: Kind,
+    /// The item name.
+    pub name: String,
+    /// The item path, starting with the project name, e.g. `exchange::math::Fraction`.
+    pub path: String,
+    /// The item signature, e.g. `fn add(a: u8, b: u8) -> u8` or `struct Fraction`.
+    pub signature: String,
+    /// The outer `///` doc comment text.
+    pub description: Option<String>,
+    /// Whether the item is declared with the `pub` keyword. Only functions may be private.
+    pub is_public: bool,
+    /// The structure fields, enumeration variants, contract storage fields, methods, and
+    /// associated constants.
+    pub members: Vec<Member>,
+}
+
+///
+/// The documented item kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    /// The `contract` item.
+    Contract,
+    /// The `interface` item.
+    Interface,
+    /// The `struct` item.
+    Structure,
+    /// The `enum` item.
+    Enumeration,
+    /// The `type` alias item.
+    Type,
+    /// The `fn` item.
+    Function,
+    /// The `const` item.
+    Constant,
+}
+
+///
+/// The documented item member, e.g. a structure field or a contract method.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Member {
+    /// The member kind.
+    pub kind: MemberKind,
+    /// The member name.
+    pub name: String,
+    /// The member signature, e.g. `amount: u64` or `fn deposit(mut self) -> bool`.
+    pub signature: String,
+    /// The outer `///` doc comment text.
+    pub description: Option<String>,
+    /// Whether the member is declared with the `pub` keyword.
+    pub is_public: bool,
+}
+
+///
+/// The documented item member kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberKind {
+    /// The structure field.
+    Field,
+    /// The enumeration variant.
+    Variant,
+    /// The contract storage field.
+    StorageField,
+    /// The associated constant.
+    Constant,
+    /// The method or associated function.
+    Method,
+}
+
+impl Item {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        kind: Kind,
+        name: String,
+        path: String,
+        signature: String,
+        description: Option<String>,
+        is_public: bool,
+        members: Vec<Member>,
+    ) -> Self {
+        Self {
+            kind,
+            name,
+            path,
+            signature,
+            description,
+            is_public,
+            members,
+        }
+    }
+}
+
+impl Kind {
+    ///
+    /// The page file name prefix, e.g. `struct` for `struct.Fraction.html`.
+    ///
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Contract => "contract",
+            Self::Interface => "interface",
+            Self::Structure => "struct",
+            Self::Enumeration => "enum",
+            Self::Type => "type",
+            Self::Function => "fn",
+            Self::Constant => "constant",
+        }
+    }
+
+    ///
+    /// The module page section title.
+    ///
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Contract => "Contracts",
+            Self::Interface => "Interfaces",
+            Self::Structure => "Structures",
+            Self::Enumeration => "Enumerations",
+            Self::Type => "Type aliases",
+            Self::Function => "Functions",
+            Self::Constant => "Constants",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Contract => write!(f, "contract"),
+            Self::Interface => write!(f, "interface"),
+            Self::Structure => write!(f, "structure"),
+            Self::Enumeration => write!(f, "enumeration"),
+            Self::Type => write!(f, "type"),
+            Self::Function => write!(f, "function"),
+            Self::Constant => write!(f, "constant"),
+        }
+    }
+}
+
+impl Member {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        kind: MemberKind,
+        name: String,
+        signature: String,
+        description: Option<String>,
+        is_public: bool,
+    ) -> Self {
+        Self {
+            kind,
+            name,
+            signature,
+            description,
+            is_public,
+        }
+    }
+
+    ///
+    /// The member anchor on the item page, e.g. `method.deposit`.
+    ///
+    pub fn anchor(&self) -> String {
+        format!("{}.{}", self.kind.prefix(), self.name)
+    }
+}
+
+impl MemberKind {
+    ///
+    /// The member anchor prefix.
+    ///
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Field => "field",
+            Self::Variant => "variant",
+            Self::StorageField => "storage",
+            Self::Constant => "constant",
+            Self::Method => "method",
+        }
+    }
+
+    ///
+    /// The item page section title.
+    ///
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Field => "Fields",
+            Self::Variant => "Variants",
+            Self::StorageField => "Storage",
+            Self::Constant => "Associated constants",
+            Self::Method => "Methods",
+        }
+    }
+}
+
+impl fmt::Display for MemberKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field => write!(f, "field"),
+            Self::Variant => write!(f, "variant"),
+            Self::StorageField => write!(f, "storage_field"),
+            Self::Constant => write!(f, "constant"),
+            Self::Method => write!(f, "method"),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//!
+//! The Zinc project documentation.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod html;
+pub mod item;
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use self::item::Item;
+
+///
+/// The project documentation, which is extracted by the compiler from the analyzed project and
+/// rendered into HTML pages by `zargo doc`.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Documentation {
+    /// The project name, which is also the root module name.
+    pub name: String,
+    /// The project version.
+    pub version: String,
+    /// The project modules, where the root one goes first, and the others are ordered by path.
+    pub modules: Vec<Module>,
+}
+
+///
+/// The documented module.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    /// The module path, starting with the project name, e.g. `exchange::math`.
+    pub path: String,
+    /// The module inner `//!` doc comment text.
+    pub description: Option<String>,
+    /// The module items ordered by name.
+    pub items: Vec<Item>,
+}
+
+impl Documentation {
+    /// The path segment separator.
+    pub const PATH_SEPARATOR: &'static str = "::";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: String, mut modules: Vec<Module>) -> Self {
+        modules.sort_by(|a, b| {
+            (a.path != name, a.path.as_str()).cmp(&(b.path != name, b.path.as_str()))
+        });
+
+        Self {
+            name,
+            version,
+            modules,
+        }
+    }
+
+    ///
+    /// Renders the HTML pages, the stylesheet, and the search index, with the file paths
+    /// relative to the documentation directory as keys.
+    ///
+    /// The output only depends on the documentation, so it does not change between the runs.
+    ///
+    pub fn pages(&self) -> BTreeMap<String, String> {
+        html::render(self)
+    }
+
+    ///
+    /// Returns the root module page path relative to the documentation directory.
+    ///
+    pub fn index(&self) -> String {
+        html::module_url(self.name.as_str())
+    }
+
+    ///
+    /// Returns the search index, which is an array of the item and member paths with their
+    /// kinds, page URLs relative to the documentation directory, and description summaries.
+    ///
+    pub fn search_index(&self) -> serde_json::Value {
+        let mut entries = Vec::new();
+        for module in self.modules.iter() {
+            entries.push(serde_json::json!({
+                "path": module.path,
+                "kind": "module",
+                "url": html::module_url(module.path.as_str()),
+                "summary": Self::summary(module.description.as_deref()),
+            }));
+
+            for item in module.items.iter() {
+                let url = html::item_url(item);
+                entries.push(serde_json::json!({
+                    "path": item.path,
+                    "kind": item.kind.to_string(),
+                    "url": url,
+                    "summary": Self::summary(item.description.as_deref()),
+                }));
+
+                for member in item.members.iter() {
+                    entries.push(serde_json::json!({
+                        "path": format!("{}{}{}", item.path, Self::PATH_SEPARATOR, member.name),
+                        "kind": member.kind.to_string(),
+                        "url": format!("{}#{}", url, member.anchor()),
+                        "summary": Self::summary(member.description.as_deref()),
+                    }));
+                }
+            }
+        }
+
+        serde_json::Value::Array(entries)
+    }
+
+    ///
+    /// Returns the first paragraph of the `description`, or an empty string.
+    ///
+    pub fn summary(description: Option<&str>) -> String {
+        description
+            .and_then(|description| description.split("\n\n").next())
+            .map(|paragraph| {
+                paragraph
+                    .split_whitespace()
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Module {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, description: Option<String>, mut items: Vec<Item>) -> Self {
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            path,
+            description,
+            items,
+        }
+    }
+
+    ///
+    /// The module name, which is the last segment of its path.
+    ///
+    pub fn name(&self) -> &str {
+        self.path
+            .rsplit(Documentation::PATH_SEPARATOR)
+            .next()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// The number of the path segments after the project name.
+    ///
+    pub fn depth(&self) -> usize {
+        self.path.matches(Documentation::PATH_SEPARATOR).count()
+    }
+}
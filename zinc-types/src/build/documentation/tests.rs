@@ -0,0 +1,139 @@
+//!
+//! The Zinc project documentation tests.
+//!
+
+use crate::build::documentation::html;
+use crate::build::documentation::Documentation;
+
+///
+/// The fixture library, whose root module declares the `Side` enumeration, the `Market`
+/// interface, the `fill` function, and the `FEE_PERCENT` constant, and whose `math` module
+/// declares the `Fraction` structure and the private `gcd` function.
+///
+fn library() -> Documentation {
+    serde_json::from_str(include_str!("golden/library.json"))
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+fn rendered(path: &str) -> String {
+    library()
+        .pages()
+        .remove(path)
+        .unwrap_or_else(|| panic!("the page `{}` is missing", path))
+}
+
+#[test]
+fn ok_pages() {
+    let pages = library().pages();
+
+    let paths: Vec<&str> = pages.keys().map(String::as_str).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "exchange/constant.FEE_PERCENT.html",
+            "exchange/enum.Side.html",
+            "exchange/fn.fill.html",
+            "exchange/index.html",
+            "exchange/interface.Market.html",
+            "exchange/math/fn.gcd.html",
+            "exchange/math/index.html",
+            "exchange/math/struct.Fraction.html",
+            html::SEARCH_INDEX,
+            html::STYLESHEET,
+        ]
+    );
+}
+
+#[test]
+fn ok_root_module_page() {
+    let page = rendered("exchange/index.html");
+
+    assert!(page.contains("<link rel=\"stylesheet\" href=\"../style.css\">"));
+    assert!(page.contains("<h2 id=\"modules\">Modules</h2>"));
+    assert!(
+        page.contains("<dt id=\"mod.math\"><a href=\"../exchange/math/index.html\">math</a></dt>")
+    );
+    assert!(page.contains("<dd>The fixed-point arithmetic.</dd>"));
+    assert!(page.contains("<h2 id=\"enumerations\">Enumerations</h2>"));
+    assert!(
+        page.contains("<dt id=\"enum.Side\"><a href=\"../exchange/enum.Side.html\">Side</a></dt>")
+    );
+    assert!(page.contains("<dt id=\"fn.fill\"><a href=\"../exchange/fn.fill.html\">fill</a></dt>"));
+    assert!(page.contains("<p>Provides the order book primitives.</p>"));
+}
+
+#[test]
+fn ok_nested_module_page() {
+    let page = rendered("exchange/math/index.html");
+
+    assert!(page.contains("<link rel=\"stylesheet\" href=\"../../style.css\">"));
+    assert!(page.contains(
+        "<h1>Module <a href=\"../../exchange/index.html\">exchange</a>::<a href=\"../../exchange/math/index.html\">math</a></h1>"
+    ));
+    assert!(page.contains(
+        "<dt id=\"struct.Fraction\"><a href=\"../../exchange/math/struct.Fraction.html\">Fraction</a></dt>"
+    ));
+    assert!(page.contains(
+        "<dt id=\"fn.gcd\"><a href=\"../../exchange/math/fn.gcd.html\">gcd</a> <span class=\"private\">private</span></dt>"
+    ));
+}
+
+#[test]
+fn ok_item_page_members() {
+    let page = rendered("exchange/math/struct.Fraction.html");
+
+    assert!(page.contains("<h2 id=\"fields\">Fields</h2>"));
+    assert!(page.contains("<dt id=\"field.numerator\"><a href=\"#field.numerator\">"));
+    assert!(page.contains("<h2 id=\"methods\">Methods</h2>"));
+    assert!(page.contains("<dt id=\"method.add\"><a href=\"#method.add\">"));
+    assert!(page.contains("<p>A fraction with the <code>u64</code> numerator and denominator.</p>"));
+}
+
+#[test]
+fn ok_signature_links() {
+    let page = rendered("exchange/fn.fill.html");
+
+    assert!(page.contains(
+        "<pre class=\"signature\">fn fill(side: <a href=\"../exchange/enum.Side.html\">Side</a>, amount: <a href=\"../exchange/math/struct.Fraction.html\">Fraction</a>) -&gt; u64</pre>"
+    ));
+    assert!(page.contains("<pre><code>let filled = fill(Side::Buy, fraction);</code></pre>"));
+
+    let page = rendered("exchange/interface.Market.html");
+    assert!(page.contains("<code>fn price(self, side: <a href=\"../exchange/enum.Side.html\">Side</a>) -&gt; u64</code>"));
+}
+
+#[test]
+fn ok_search_index() {
+    let index = library().search_index();
+
+    let fraction = index
+        .as_array()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .iter()
+        .find(|entry| entry["path"] == "exchange::math::Fraction::add")
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(fraction["kind"], "method");
+    assert_eq!(
+        fraction["url"],
+        "exchange/math/struct.Fraction.html#method.add"
+    );
+    assert_eq!(fraction["summary"], "Adds two fractions.");
+
+    assert_eq!(index[0]["path"], "exchange");
+    assert_eq!(index[0]["url"], "exchange/index.html");
+    assert_eq!(index[0]["summary"], "The exchange library.");
+}
+
+#[test]
+fn ok_index() {
+    let documentation = library();
+
+    assert!(documentation
+        .pages()
+        .contains_key(documentation.index().as_str()));
+}
+
+#[test]
+fn ok_deterministic() {
+    assert_eq!(library().pages(), library().pages());
+}
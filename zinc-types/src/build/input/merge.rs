@@ -0,0 +1,83 @@
+//!
+//! The Zinc build input file merging.
+//!
+
+use crate::data::r#type::Type;
+use crate::data::value::Value;
+
+///
+/// Merges the `previous` input JSON value into the template of the data `type`.
+///
+/// The previous value is kept at each path where it is still valid for the new type, so the
+/// renamed, retyped, and added fields get the template default values, and the removed fields
+/// are dropped. The structure fields are matched by name, and the array and tuple elements
+/// are matched by position.
+///
+pub fn value(previous: Option<serde_json::Value>, r#type: Type) -> serde_json::Value {
+    let previous = match previous {
+        Some(previous) => previous,
+        None => return Value::new(r#type).into_json(),
+    };
+
+    match r#type {
+        Type::Structure(fields) => {
+            let mut previous = match previous {
+                serde_json::Value::Object(previous) => previous,
+                _ => serde_json::Map::new(),
+            };
+
+            let mut object = serde_json::Map::with_capacity(fields.len());
+            for (name, r#type) in fields.into_iter() {
+                if name == zinc_lexical::Keyword::SelfLowercase.to_string() {
+                    continue;
+                }
+
+                let field = self::value(previous.remove(name.as_str()), r#type);
+                object.insert(name, field);
+            }
+            serde_json::Value::Object(object)
+        }
+        Type::Tuple(types) => {
+            let mut previous = self::elements(previous);
+
+            serde_json::Value::Array(
+                types
+                    .into_iter()
+                    .map(|r#type| self::value(previous.next(), r#type))
+                    .collect(),
+            )
+        }
+        Type::Array(r#type, size) => {
+            let mut previous = self::elements(previous);
+
+            serde_json::Value::Array(
+                (0..size)
+                    .map(|_| self::value(previous.next(), r#type.as_ref().to_owned()))
+                    .collect(),
+            )
+        }
+        r#type => match Value::try_from_typed_json(previous.clone(), r#type.clone()) {
+            Ok(_value) => previous,
+            Err(_error) => Value::new(r#type).into_json(),
+        },
+    }
+}
+
+///
+/// Merges the `previous` contract storage fields into the template of the storage `types`.
+///
+/// The storage fields are matched by position, as they are stored in the input file as an array.
+///
+pub fn storage(previous: Option<serde_json::Value>, types: Vec<Type>) -> serde_json::Value {
+    self::value(previous, Type::Tuple(types))
+}
+
+///
+/// Returns the iterator over the `previous` array elements, which is empty for other values.
+///
+fn elements(previous: serde_json::Value) -> impl Iterator<Item = serde_json::Value> {
+    match previous {
+        serde_json::Value::Array(elements) => elements.into_iter(),
+        _ => vec![].into_iter(),
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! The Zinc build input file representation.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod merge;
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The Zinc build input file representation.
+///
+/// The maps preserve the insertion order, so the template keys follow the declaration order
+/// and the file is the same for every build of the same project.
+///
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Input {
+    /// The circuit byte representation.
+    Circuit {
+        /// The input template digest of the build, which the file was generated for.
+        #[serde(rename = "_template", default, skip_serializing_if = "Option::is_none")]
+        template: Option<String>,
+        /// The circuit arguments JSON data.
+        arguments: serde_json::Value,
+    },
+    /// The contract byte representation.
+    Contract {
+        /// The input template digest of the build, which the file was generated for.
+        #[serde(rename = "_template", default, skip_serializing_if = "Option::is_none")]
+        template: Option<String>,
+        /// The storages JSON data.
+        storages: IndexMap<String, serde_json::Value>,
+        /// The contract input transaction, represented by the `zksync::msg` variable.
+        msg: serde_json::Value,
+        /// The contract methods arguments JSON data.
+        arguments: IndexMap<String, serde_json::Value>,
+    },
+    /// The library byte representation.
+    Library,
+}
+
+impl Input {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_circuit(template: Option<String>, arguments: serde_json::Value) -> Self {
+        Self::Circuit {
+            template,
+            arguments,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_contract(
+        template: Option<String>,
+        storages: IndexMap<String, serde_json::Value>,
+        msg: serde_json::Value,
+        arguments: IndexMap<String, serde_json::Value>,
+    ) -> Self {
+        Self::Contract {
+            template,
+            storages,
+            msg,
+            arguments,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_library() -> Self {
+        Self::Library
+    }
+
+    ///
+    /// The input template digest of the build, which the file was generated for.
+    ///
+    /// `None` for the files generated before the digest was introduced.
+    ///
+    pub fn template(&self) -> Option<&str> {
+        match self {
+            Self::Circuit { template, .. } => template.as_deref(),
+            Self::Contract { template, .. } => template.as_deref(),
+            Self::Library => None,
+        }
+    }
+}
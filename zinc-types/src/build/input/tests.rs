@@ -0,0 +1,156 @@
+//!
+//! The Zinc build input file tests.
+//!
+
+use crate::build::input::merge;
+use crate::build::input::Input;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+fn u8() -> Type {
+    Type::Scalar(ScalarType::Integer(IntegerType::U8))
+}
+
+fn boolean() -> Type {
+    Type::Scalar(ScalarType::Boolean)
+}
+
+fn structure(fields: Vec<(&str, Type)>) -> Type {
+    Type::Structure(
+        fields
+            .into_iter()
+            .map(|(name, r#type)| (name.to_owned(), r#type))
+            .collect(),
+    )
+}
+
+#[test]
+fn ok_unchanged() {
+    let previous = serde_json::json!({ "a": "42", "b": true });
+
+    let result = merge::value(
+        Some(previous.clone()),
+        structure(vec![("a", u8()), ("b", boolean())]),
+    );
+
+    assert_eq!(result, previous);
+}
+
+#[test]
+fn ok_renamed() {
+    let previous = serde_json::json!({ "a": "42", "b": true });
+
+    let result = merge::value(
+        Some(previous),
+        structure(vec![("c", u8()), ("b", boolean())]),
+    );
+
+    assert_eq!(result, serde_json::json!({ "c": "0", "b": true }));
+}
+
+#[test]
+fn ok_retyped() {
+    let previous = serde_json::json!({ "a": "42", "b": true });
+
+    let result = merge::value(
+        Some(previous),
+        structure(vec![("a", boolean()), ("b", boolean())]),
+    );
+
+    assert_eq!(result, serde_json::json!({ "a": false, "b": true }));
+}
+
+#[test]
+fn ok_added() {
+    let previous = serde_json::json!({ "a": "42" });
+
+    let result = merge::value(
+        Some(previous),
+        structure(vec![("a", u8()), ("b", structure(vec![("c", boolean())]))]),
+    );
+
+    assert_eq!(
+        result,
+        serde_json::json!({ "a": "42", "b": { "c": false } })
+    );
+}
+
+#[test]
+fn ok_removed() {
+    let previous = serde_json::json!({ "a": "42", "b": true });
+
+    let result = merge::value(Some(previous), structure(vec![("a", u8())]));
+
+    assert_eq!(result, serde_json::json!({ "a": "42" }));
+}
+
+#[test]
+fn ok_nested() {
+    let previous = serde_json::json!({
+        "orders": [{ "amount": "1" }, { "amount": "2" }],
+    });
+
+    let result = merge::value(
+        Some(previous),
+        structure(vec![(
+            "orders",
+            Type::Array(
+                Box::new(structure(vec![("amount", u8()), ("paid", boolean())])),
+                3,
+            ),
+        )]),
+    );
+
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "orders": [
+                { "amount": "1", "paid": false },
+                { "amount": "2", "paid": false },
+                { "amount": "0", "paid": false },
+            ],
+        })
+    );
+}
+
+#[test]
+fn ok_shrunk_array() {
+    let previous = serde_json::json!(["1", "2", "3"]);
+
+    let result = merge::value(Some(previous), Type::Array(Box::new(u8()), 2));
+
+    assert_eq!(result, serde_json::json!(["1", "2"]));
+}
+
+#[test]
+fn ok_invalid_previous() {
+    let result = merge::value(
+        Some(serde_json::json!("garbage")),
+        structure(vec![("a", u8())]),
+    );
+
+    assert_eq!(result, serde_json::json!({ "a": "0" }));
+}
+
+#[test]
+fn ok_template_field() {
+    let input = Input::new_circuit(Some("digest".to_owned()), serde_json::json!({ "a": "42" }));
+
+    let json = serde_json::to_value(&input).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(json["_template"], serde_json::json!("digest"));
+
+    let input: Input = serde_json::from_value(json).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(input.template(), Some("digest"));
+}
+
+#[test]
+fn ok_template_field_missing() {
+    let input: Input = serde_json::from_value(serde_json::json!({
+        "type": "circuit",
+        "arguments": { "a": "42" },
+    }))
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(input.template(), None);
+}
@@ -2,6 +2,9 @@
 //! The Zinc build representation.
 //!
 
+pub mod bindings;
+pub mod diff;
+pub mod documentation;
 pub mod input;
 
 use self::input::Input;
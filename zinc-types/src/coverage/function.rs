@@ -0,0 +1,82 @@
+//!
+//! The unit test coverage function.
+//!
+
+/// The number of bits in a bitset word.
+const WORD_BITLENGTH: usize = 64;
+
+///
+/// The unit test coverage function.
+///
+/// Spans the instructions from its `FunctionMarker` up to the next one.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The function name from its marker.
+    pub name: String,
+    /// The address of the function marker.
+    pub address: usize,
+    /// The `(file index, line)` location of each function instruction, or `None` for the
+    /// instructions, which are not coverable, like markers.
+    pub locations: Vec<Option<(usize, usize)>>,
+    /// The bitset of the executed instructions, indexed by the offset from `address`.
+    executed: Vec<u64>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, address: usize) -> Self {
+        Self {
+            name,
+            address,
+            locations: vec![],
+            executed: vec![],
+        }
+    }
+
+    ///
+    /// Appends the next instruction `location` to the function.
+    ///
+    pub fn push(&mut self, location: Option<(usize, usize)>) {
+        if self.locations.len() % WORD_BITLENGTH == 0 {
+            self.executed.push(0);
+        }
+        self.locations.push(location);
+    }
+
+    ///
+    /// Marks the instruction at `offset` from the function address as executed.
+    ///
+    pub fn hit(&mut self, offset: usize) {
+        if offset < self.locations.len() {
+            self.executed[offset / WORD_BITLENGTH] |= 1 << (offset % WORD_BITLENGTH);
+        }
+    }
+
+    ///
+    /// Checks whether the instruction at `offset` from the function address has been executed.
+    ///
+    pub fn is_hit(&self, offset: usize) -> bool {
+        offset < self.locations.len()
+            && self.executed[offset / WORD_BITLENGTH] & (1 << (offset % WORD_BITLENGTH)) != 0
+    }
+
+    ///
+    /// The location of the first coverable instruction, which is the first line of the function.
+    ///
+    pub fn first_location(&self) -> Option<(usize, usize)> {
+        self.locations.iter().find_map(|location| *location)
+    }
+
+    ///
+    /// Checks whether any coverable instruction of the function has been executed.
+    ///
+    pub fn is_called(&self) -> bool {
+        self.locations
+            .iter()
+            .enumerate()
+            .any(|(offset, location)| location.is_some() && self.is_hit(offset))
+    }
+}
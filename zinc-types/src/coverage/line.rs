@@ -0,0 +1,41 @@
+//!
+//! The unit test coverage source code line.
+//!
+
+///
+/// The unit test coverage source code line.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Line {
+    /// The number of coverable instructions generated from the line.
+    pub instructions: usize,
+    /// The number of the line instructions executed by at least one unit test.
+    pub executed: usize,
+}
+
+impl Line {
+    ///
+    /// Appends an instruction of the line, which is `is_executed` or not.
+    ///
+    pub fn push(&mut self, is_executed: bool) {
+        self.instructions += 1;
+        if is_executed {
+            self.executed += 1;
+        }
+    }
+
+    ///
+    /// Checks whether any instruction of the line has been executed.
+    ///
+    pub fn is_hit(&self) -> bool {
+        self.executed > 0
+    }
+
+    ///
+    /// Checks whether the line has been executed only partially, e.g. due to an untaken
+    /// one-line branch.
+    ///
+    pub fn is_partial(&self) -> bool {
+        self.executed > 0 && self.executed < self.instructions
+    }
+}
@@ -0,0 +1,256 @@
+//!
+//! The unit test coverage.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod function;
+pub mod line;
+
+use std::collections::BTreeMap;
+
+use crate::instructions::Instruction;
+
+use self::function::Function;
+use self::line::Line;
+
+///
+/// The unit test instruction coverage.
+///
+/// Collects the addresses of the instructions executed by the unit tests into a bitset per
+/// function, and maps them to the source code lines using the location markers.
+///
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    /// The source code file paths from the file markers.
+    files: Vec<String>,
+    /// The functions sorted by their addresses.
+    functions: Vec<Function>,
+}
+
+impl Coverage {
+    ///
+    /// Builds the instruction to source code line map from the location markers of `instructions`.
+    ///
+    /// The markers, branch terminators and no-operations are not coverable, since they do not
+    /// belong to any statement.
+    ///
+    pub fn new(instructions: &[Instruction]) -> Self {
+        let mut files: Vec<String> = Vec::new();
+        let mut functions: Vec<Function> = Vec::new();
+
+        let mut file = None;
+        let mut line = None;
+        for (address, instruction) in instructions.iter().enumerate() {
+            let location = match instruction {
+                Instruction::FileMarker(marker) => {
+                    file = Some(
+                        match files.iter().position(|file| file == &marker.file) {
+                            Some(index) => index,
+                            None => {
+                                files.push(marker.file.clone());
+                                files.len() - 1
+                            }
+                        },
+                    );
+                    None
+                }
+                Instruction::FunctionMarker(marker) => {
+                    functions.push(Function::new(marker.function.clone(), address));
+                    None
+                }
+                Instruction::LineMarker(marker) => {
+                    line = Some(marker.line);
+                    None
+                }
+                Instruction::ColumnMarker(_)
+                | Instruction::NoOperation(_)
+                | Instruction::Else(_)
+                | Instruction::EndIf(_) => None,
+                _ => match (file, line) {
+                    (Some(file), Some(line)) => Some((file, line)),
+                    _ => None,
+                },
+            };
+
+            if let Some(function) = functions.last_mut() {
+                function.push(location);
+            }
+        }
+
+        Self { files, functions }
+    }
+
+    ///
+    /// Marks the instruction at `address` as executed.
+    ///
+    pub fn hit(&mut self, address: usize) {
+        let index = match self
+            .functions
+            .binary_search_by_key(&address, |function| function.address)
+        {
+            Ok(index) => index,
+            Err(0) => return,
+            Err(index) => index - 1,
+        };
+
+        let function = &mut self.functions[index];
+        function.hit(address - function.address);
+    }
+
+    ///
+    /// Renders the coverage as an `lcov` tracefile.
+    ///
+    /// The partially executed lines, e.g. with an untaken one-line branch, are written as a
+    /// pair of branch records, where only the first one is taken.
+    ///
+    pub fn to_lcov(&self) -> String {
+        let lines = self.lines();
+
+        let mut result = String::new();
+        for (file_index, file) in self.files.iter().enumerate() {
+            let lines = match lines.get(&file_index) {
+                Some(lines) => lines,
+                None => continue,
+            };
+
+            result.push_str("TN:\n");
+            result.push_str(format!("SF:{}\n", file).as_str());
+
+            let functions: Vec<(usize, &Function)> = self
+                .functions
+                .iter()
+                .filter_map(|function| match function.first_location() {
+                    Some((file, line)) if file == file_index => Some((line, function)),
+                    _ => None,
+                })
+                .collect();
+            for (line, function) in functions.iter() {
+                result.push_str(format!("FN:{},{}\n", line, function.name).as_str());
+            }
+            for (_line, function) in functions.iter() {
+                result.push_str(
+                    format!("FNDA:{},{}\n", function.is_called() as usize, function.name).as_str(),
+                );
+            }
+            result.push_str(format!("FNF:{}\n", functions.len()).as_str());
+            result.push_str(
+                format!(
+                    "FNH:{}\n",
+                    functions
+                        .iter()
+                        .filter(|(_line, function)| function.is_called())
+                        .count()
+                )
+                .as_str(),
+            );
+
+            let partial_count = lines.values().filter(|line| line.is_partial()).count();
+            for (number, _line) in lines.iter().filter(|(_number, line)| line.is_partial()) {
+                result.push_str(format!("BRDA:{},0,0,1\n", number).as_str());
+                result.push_str(format!("BRDA:{},0,1,0\n", number).as_str());
+            }
+            result.push_str(format!("BRF:{}\n", partial_count * 2).as_str());
+            result.push_str(format!("BRH:{}\n", partial_count).as_str());
+
+            for (number, line) in lines.iter() {
+                result.push_str(format!("DA:{},{}\n", number, line.is_hit() as usize).as_str());
+            }
+            result.push_str(format!("LF:{}\n", lines.len()).as_str());
+            result.push_str(
+                format!("LH:{}\n", lines.values().filter(|line| line.is_hit()).count()).as_str(),
+            );
+
+            result.push_str("end_of_record\n");
+        }
+        result
+    }
+
+    ///
+    /// Renders the per-file line coverage summary, one file per line, followed by the total.
+    ///
+    /// The uncovered and partially covered line numbers are listed separately.
+    ///
+    pub fn summary(&self) -> String {
+        let lines = self.lines();
+
+        let mut rows = Vec::with_capacity(lines.len() + 1);
+        let mut total_hit = 0;
+        let mut total_count = 0;
+        for (file_index, lines) in lines.iter() {
+            let hit = lines.values().filter(|line| line.is_hit()).count();
+            total_hit += hit;
+            total_count += lines.len();
+
+            let mut row = Self::summary_percentage(hit, lines.len());
+            let uncovered = Self::summary_line_list(lines, |line| !line.is_hit());
+            if !uncovered.is_empty() {
+                row.push_str(format!("  uncovered: {}", uncovered).as_str());
+            }
+            let partial = Self::summary_line_list(lines, Line::is_partial);
+            if !partial.is_empty() {
+                row.push_str(format!("  partial: {}", partial).as_str());
+            }
+            rows.push((self.files[*file_index].as_str(), row));
+        }
+        rows.push(("total", Self::summary_percentage(total_hit, total_count)));
+
+        let width = rows
+            .iter()
+            .map(|(file, _row)| file.len())
+            .max()
+            .unwrap_or_default();
+        rows.into_iter()
+            .map(|(file, row)| format!("{:<width$}  {}", file, row, width = width))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    ///
+    /// Groups the coverable instructions by file index and line number.
+    ///
+    fn lines(&self) -> BTreeMap<usize, BTreeMap<usize, Line>> {
+        let mut lines: BTreeMap<usize, BTreeMap<usize, Line>> = BTreeMap::new();
+        for function in self.functions.iter() {
+            for (offset, location) in function.locations.iter().enumerate() {
+                if let Some((file, line)) = location {
+                    lines
+                        .entry(*file)
+                        .or_default()
+                        .entry(*line)
+                        .or_default()
+                        .push(function.is_hit(offset));
+                }
+            }
+        }
+        lines
+    }
+
+    ///
+    /// Formats the `hit` out of `count` lines as a percentage.
+    ///
+    fn summary_percentage(hit: usize, count: usize) -> String {
+        let percentage = if count == 0 {
+            100.0
+        } else {
+            (hit as f64) * 100.0 / (count as f64)
+        };
+        format!("{:>6.2}%  {}/{} lines", percentage, hit, count)
+    }
+
+    ///
+    /// Joins the numbers of `lines` matching the `filter`.
+    ///
+    fn summary_line_list<F>(lines: &BTreeMap<usize, Line>, filter: F) -> String
+    where
+        F: Fn(&Line) -> bool,
+    {
+        lines
+            .iter()
+            .filter(|(_number, line)| filter(line))
+            .map(|(number, _line)| number.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,200 @@
+//!
+//! The unit test coverage tests.
+//!
+
+use num::BigInt;
+
+use crate::coverage::Coverage;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::instructions::data_stack::load::Load;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::r#else::Else;
+use crate::instructions::flow::r#endif::EndIf;
+use crate::instructions::flow::r#if::If;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::marker::file::FileMarker;
+use crate::instructions::marker::function::FunctionMarker;
+use crate::instructions::marker::line::LineMarker;
+use crate::instructions::operator::comparison::gt::Gt;
+use crate::instructions::Instruction;
+
+///
+/// The bytecode of the following source code, where the `else` branch is never taken:
+///
+/// ```text
+///  1 fn sign(value: u8) -> u8 {
+///  2     if value > 0 {
+///  3         1
+///  4     } else {
+///  5         0
+///  6     }
+///  7 }
+///  8
+///  9 #[test]
+/// 10 fn positive() { sign(42); }
+/// ```
+///
+fn untested_branch() -> Vec<Instruction> {
+    vec![
+        FileMarker::new("src/main.zn".to_owned()).into(),
+        FunctionMarker::new("sign".to_owned()).into(),
+        LineMarker::new(2).into(),
+        Load::new(0, 1).into(),
+        Push::new(BigInt::from(0), ScalarType::Integer(IntegerType::U8)).into(),
+        Gt.into(),
+        If.into(),
+        LineMarker::new(3).into(),
+        Push::new(BigInt::from(1), ScalarType::Integer(IntegerType::U8)).into(),
+        Else.into(),
+        LineMarker::new(5).into(),
+        Push::new(BigInt::from(0), ScalarType::Integer(IntegerType::U8)).into(),
+        EndIf.into(),
+        LineMarker::new(1).into(),
+        Return::new(1).into(),
+        FileMarker::new("src/main.zn".to_owned()).into(),
+        FunctionMarker::new("positive".to_owned()).into(),
+        LineMarker::new(10).into(),
+        Push::new(BigInt::from(42), ScalarType::Integer(IntegerType::U8)).into(),
+        Call::new(1, 1).into(),
+        Return::new(0).into(),
+    ]
+}
+
+///
+/// The bytecode of the one-line `if a > b { a } else { b }` function body, where the `else`
+/// branch is never taken.
+///
+fn untested_one_line_branch() -> Vec<Instruction> {
+    vec![
+        FileMarker::new("src/main.zn".to_owned()).into(),
+        FunctionMarker::new("max".to_owned()).into(),
+        LineMarker::new(2).into(),
+        Load::new(0, 1).into(),
+        Load::new(1, 1).into(),
+        Gt.into(),
+        If.into(),
+        Load::new(0, 1).into(),
+        Else.into(),
+        Load::new(1, 1).into(),
+        EndIf.into(),
+        Return::new(1).into(),
+    ]
+}
+
+#[test]
+fn ok_lcov_untested_branch() {
+    let mut coverage = Coverage::new(untested_branch().as_slice());
+    for address in [2, 3, 4, 5, 6, 7, 8, 9, 12, 13, 14].iter() {
+        coverage.hit(*address);
+    }
+    for address in [17, 18, 19, 20].iter() {
+        coverage.hit(*address);
+    }
+
+    assert_eq!(
+        coverage.to_lcov(),
+        r#"TN:
+SF:src/main.zn
+FN:2,sign
+FN:10,positive
+FNDA:1,sign
+FNDA:1,positive
+FNF:2
+FNH:2
+BRF:0
+BRH:0
+DA:1,1
+DA:2,1
+DA:3,1
+DA:5,0
+DA:10,1
+LF:5
+LH:4
+end_of_record
+"#
+    );
+}
+
+#[test]
+fn ok_lcov_untested_one_line_branch() {
+    let mut coverage = Coverage::new(untested_one_line_branch().as_slice());
+    for address in [2, 3, 4, 5, 6, 7, 8, 10, 11].iter() {
+        coverage.hit(*address);
+    }
+
+    assert_eq!(
+        coverage.to_lcov(),
+        r#"TN:
+SF:src/main.zn
+FN:2,max
+FNDA:1,max
+FNF:1
+FNH:1
+BRDA:2,0,0,1
+BRDA:2,0,1,0
+BRF:2
+BRH:1
+DA:2,1
+LF:1
+LH:1
+end_of_record
+"#
+    );
+}
+
+#[test]
+fn ok_lcov_uncalled_function() {
+    let mut coverage = Coverage::new(untested_branch().as_slice());
+    coverage.hit(1_000);
+
+    assert_eq!(
+        coverage.to_lcov(),
+        r#"TN:
+SF:src/main.zn
+FN:2,sign
+FN:10,positive
+FNDA:0,sign
+FNDA:0,positive
+FNF:2
+FNH:0
+BRF:0
+BRH:0
+DA:1,0
+DA:2,0
+DA:3,0
+DA:5,0
+DA:10,0
+LF:5
+LH:0
+end_of_record
+"#
+    );
+}
+
+#[test]
+fn ok_summary() {
+    let mut coverage = Coverage::new(untested_branch().as_slice());
+    for address in [3, 4, 5, 6, 8, 14, 18, 19, 20].iter() {
+        coverage.hit(*address);
+    }
+
+    assert_eq!(
+        coverage.summary(),
+        "src/main.zn   80.00%  4/5 lines  uncovered: 5\ntotal         80.00%  4/5 lines",
+    );
+}
+
+#[test]
+fn ok_summary_partial() {
+    let mut coverage = Coverage::new(untested_one_line_branch().as_slice());
+    for address in [3, 4, 5, 6, 7, 11].iter() {
+        coverage.hit(*address);
+    }
+
+    assert_eq!(
+        coverage.summary(),
+        "src/main.zn  100.00%  1/1 lines  partial: 2\ntotal        100.00%  1/1 lines",
+    );
+}
@@ -0,0 +1,124 @@
+//!
+//! The contract type storage leaf.
+//!
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::Type;
+
+///
+/// The contract storage leaf, which is a single Merkle tree leaf of a storage field.
+///
+/// The structures and arrays of structures are flattened into leaves in the declaration order:
+/// the structure fields go one by one, and the array elements go in the index order, so the
+/// concatenated leaf values are exactly the flat field value.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Leaf {
+    /// The leaf path, starting from the field name, e.g. `orders[2].amount`.
+    pub path: String,
+    /// The leaf type.
+    pub r#type: Type,
+    /// The number of boolean flags, if the leaf is a boolean array packed into unsigned
+    /// integers in the storage encoding.
+    #[serde(default)]
+    pub packed_bits: Option<usize>,
+}
+
+impl Leaf {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Boolean arrays are always packed, so the packing metadata is derived from the type.
+    ///
+    pub fn new(path: String, r#type: Type) -> Self {
+        let packed_bits = ContractField::packing(&r#type);
+
+        Self {
+            path,
+            r#type,
+            packed_bits,
+        }
+    }
+
+    ///
+    /// Flattens the value of `type` located at `path` into the storage leaves.
+    ///
+    pub fn flatten(path: String, r#type: Type) -> Vec<Self> {
+        match r#type {
+            Type::Structure(fields) => fields
+                .into_iter()
+                .map(|(name, r#type)| Self::flatten(format!("{}.{}", path, name), r#type))
+                .flatten()
+                .collect(),
+            Type::Array(r#type, size) if r#type.is_storage_flattened() => {
+                if r#type.storage_leaf_count() == 0 {
+                    return vec![];
+                }
+
+                (0..size)
+                    .map(|index| Self::flatten(format!("{}[{}]", path, index), *r#type.clone()))
+                    .flatten()
+                    .collect()
+            }
+            r#type => vec![Self::new(path, r#type)],
+        }
+    }
+
+    ///
+    /// The type of the leaf in the storage encoding, which differs from the leaf type
+    /// only if the leaf is packed.
+    ///
+    pub fn storage_type(&self) -> Type {
+        match self.packed_bits {
+            Some(bits) => ContractField::packed_type(bits),
+            None => self.r#type.to_owned(),
+        }
+    }
+
+    ///
+    /// Packs the flat leaf `values` into the storage encoding.
+    ///
+    /// The flag at index `i` is the bit `i % 248` of the scalar `i / 248`.
+    ///
+    pub fn pack_values(&self, values: Vec<BigInt>) -> Vec<BigInt> {
+        let bits = match self.packed_bits {
+            Some(bits) => bits,
+            None => return values,
+        };
+
+        let mut result = vec![BigInt::zero(); ContractField::packed_size(bits)];
+        for (index, value) in values.into_iter().enumerate().take(bits) {
+            if !value.is_zero() {
+                result[index / ContractField::PACKED_BITS_PER_SCALAR] |=
+                    BigInt::one() << (index % ContractField::PACKED_BITS_PER_SCALAR);
+            }
+        }
+        result
+    }
+
+    ///
+    /// Unpacks the storage encoding `values` into the flat leaf values.
+    ///
+    pub fn unpack_values(&self, values: &[BigInt]) -> Vec<BigInt> {
+        let bits = match self.packed_bits {
+            Some(bits) => bits,
+            None => return values.to_owned(),
+        };
+
+        (0..bits)
+            .map(|index| {
+                let scalar = values
+                    .get(index / ContractField::PACKED_BITS_PER_SCALAR)
+                    .cloned()
+                    .unwrap_or_default();
+                (scalar >> (index % ContractField::PACKED_BITS_PER_SCALAR)) & BigInt::one()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,191 @@
+//!
+//! The contract type storage field.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod leaf;
+
+use std::fmt;
+
+use num::BigInt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+
+use self::leaf::Leaf;
+
+///
+/// The contract type storage field representation.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContractField {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    pub r#type: Type,
+    /// Whether the field is public.
+    pub is_public: bool,
+    /// Whether the field is implicit.
+    pub is_implicit: bool,
+    /// The number of boolean flags, if the field is a boolean array packed into unsigned
+    /// integers in the storage encoding.
+    #[serde(default)]
+    pub packed_bits: Option<usize>,
+    /// The field storage leaves in the declaration order, which are the field itself, unless
+    /// it is a structure or an array of structures.
+    pub leaves: Vec<Leaf>,
+}
+
+impl ContractField {
+    /// The number of boolean flags packed into a single storage scalar.
+    pub const PACKED_BITS_PER_SCALAR: usize = zinc_const::bitlength::INTEGER_MAX;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Boolean arrays are always packed, so the packing metadata is derived from the type,
+    /// as well as the storage leaves.
+    ///
+    pub fn new(name: String, r#type: Type, is_public: bool, is_implicit: bool) -> Self {
+        let packed_bits = Self::packing(&r#type);
+        let leaves = Leaf::flatten(name.clone(), r#type.clone());
+
+        Self {
+            name,
+            r#type,
+            is_public,
+            is_implicit,
+            packed_bits,
+            leaves,
+        }
+    }
+
+    ///
+    /// Returns the number of boolean flags, if the `type` is a non-empty boolean array.
+    ///
+    pub fn packing(r#type: &Type) -> Option<usize> {
+        match r#type {
+            Type::Array(inner, size)
+                if *size > 0 && inner.as_ref() == &Type::Scalar(ScalarType::Boolean) =>
+            {
+                Some(*size)
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// The number of storage scalars occupied by `bits` packed boolean flags.
+    ///
+    pub fn packed_size(bits: usize) -> usize {
+        bits.div_ceil(Self::PACKED_BITS_PER_SCALAR)
+    }
+
+    ///
+    /// The storage type of `bits` packed boolean flags.
+    ///
+    /// A single scalar is the smallest unsigned integer holding the flags, whereas several
+    /// scalars are an array of the largest unsigned integers.
+    ///
+    pub fn packed_type(bits: usize) -> Type {
+        match Self::packed_size(bits) {
+            1 => Type::Scalar(ScalarType::Integer(IntegerType::new(
+                false,
+                bits.div_ceil(zinc_const::bitlength::BYTE) * zinc_const::bitlength::BYTE,
+            ))),
+            size => Type::Array(
+                Box::new(Type::Scalar(ScalarType::Integer(IntegerType::new(
+                    false,
+                    Self::PACKED_BITS_PER_SCALAR,
+                )))),
+                size,
+            ),
+        }
+    }
+
+    ///
+    /// The type of the field in the storage encoding, which differs from the field type
+    /// only if the field has packed leaves.
+    ///
+    /// The encoding of a flattened field is the tuple of its leaf encodings.
+    ///
+    pub fn storage_type(&self) -> Type {
+        if !self.r#type.is_storage_flattened() {
+            return match self.packed_bits {
+                Some(bits) => Self::packed_type(bits),
+                None => self.r#type.to_owned(),
+            };
+        }
+
+        Type::Tuple(self.leaves.iter().map(Leaf::storage_type).collect())
+    }
+
+    ///
+    /// Splits the flat field `values` into the leaves and packs each of them into the storage
+    /// encoding.
+    ///
+    pub fn pack_leaves(&self, mut values: Vec<BigInt>) -> Vec<Vec<BigInt>> {
+        let mut result = Vec::with_capacity(self.leaves.len());
+        for leaf in self.leaves.iter() {
+            let size = std::cmp::min(leaf.r#type.size(), values.len());
+            let rest = values.split_off(size);
+            result.push(leaf.pack_values(values));
+            values = rest;
+        }
+        result
+    }
+
+    ///
+    /// Unpacks the storage encoding `leaves` into the flat field values.
+    ///
+    pub fn unpack_leaves(&self, leaves: &[Vec<BigInt>]) -> Vec<BigInt> {
+        self.leaves
+            .iter()
+            .zip(leaves.iter())
+            .map(|(leaf, values)| leaf.unpack_values(values.as_slice()))
+            .flatten()
+            .collect()
+    }
+
+    ///
+    /// Packs the flat field `values` into the flat storage encoding, which is the concatenation
+    /// of the leaf encodings.
+    ///
+    /// The flag at index `i` of a packed leaf is the bit `i % 248` of the scalar `i / 248`.
+    ///
+    pub fn pack_values(&self, values: Vec<BigInt>) -> Vec<BigInt> {
+        self.pack_leaves(values).into_iter().flatten().collect()
+    }
+
+    ///
+    /// Unpacks the flat storage encoding `values` into the flat field values.
+    ///
+    pub fn unpack_values(&self, values: &[BigInt]) -> Vec<BigInt> {
+        let mut leaves = Vec::with_capacity(self.leaves.len());
+        let mut offset = 0;
+        for leaf in self.leaves.iter() {
+            let size = leaf.storage_type().size();
+            let end = std::cmp::min(offset + size, values.len());
+            leaves.push(values[std::cmp::min(offset, end)..end].to_owned());
+            offset += size;
+        }
+        self.unpack_leaves(leaves.as_slice())
+    }
+}
+
+impl fmt::Display for ContractField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}: {}",
+            if self.is_public { "pub " } else { "" },
+            self.name,
+            self.r#type,
+        )
+    }
+}
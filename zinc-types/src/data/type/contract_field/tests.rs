@@ -0,0 +1,163 @@
+//!
+//! The contract type storage field tests.
+//!
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::Value;
+
+fn flags(size: usize) -> ContractField {
+    ContractField::new(
+        "flags".to_owned(),
+        Type::Array(Box::new(Type::Scalar(ScalarType::Boolean)), size),
+        true,
+        false,
+    )
+}
+
+fn pattern(size: usize) -> Vec<BigInt> {
+    (0..size)
+        .map(|index| {
+            if index % 3 == 0 || index + 1 == size {
+                BigInt::one()
+            } else {
+                BigInt::zero()
+            }
+        })
+        .collect()
+}
+
+fn round_trip(size: usize) {
+    let field = flags(size);
+    let values = pattern(size);
+
+    let packed = field.pack_values(values.clone());
+    assert_eq!(packed.len(), field.storage_type().size());
+    assert_eq!(field.unpack_values(packed.as_slice()), values);
+}
+
+#[test]
+fn ok_round_trip_1() {
+    round_trip(1);
+
+    assert_eq!(
+        flags(1).storage_type(),
+        Type::Scalar(ScalarType::Integer(IntegerType::U8))
+    );
+}
+
+#[test]
+fn ok_round_trip_248() {
+    round_trip(248);
+
+    assert_eq!(
+        flags(248).storage_type(),
+        Type::Scalar(ScalarType::Integer(IntegerType::new(false, 248)))
+    );
+}
+
+#[test]
+fn ok_round_trip_249() {
+    round_trip(249);
+
+    let field = flags(249);
+    assert_eq!(field.storage_type().size(), 2);
+
+    let mut values = vec![BigInt::zero(); 249];
+    values[248] = BigInt::one();
+    assert_eq!(
+        field.pack_values(values),
+        vec![BigInt::zero(), BigInt::one()]
+    );
+}
+
+#[test]
+fn ok_not_packed() {
+    let field = ContractField::new(
+        "values".to_owned(),
+        Type::Array(
+            Box::new(Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+            2,
+        ),
+        true,
+        false,
+    );
+    let values = vec![BigInt::from(42), BigInt::from(25)];
+
+    assert_eq!(field.packed_bits, None);
+    assert_eq!(field.storage_type(), field.r#type);
+    assert_eq!(field.pack_values(values.clone()), values);
+}
+
+#[test]
+fn ok_storage_query_decoded() {
+    let metadata = serde_json::to_value(flags(3)).expect("Serialization");
+    assert_eq!(metadata["packed_bits"], serde_json::json!(3));
+
+    let field: ContractField = serde_json::from_value(metadata).expect("Deserialization");
+    let leaf = vec![BigInt::from(0b101)];
+    let value = Value::from_flat_values(
+        field.r#type.to_owned(),
+        field.unpack_values(leaf.as_slice()).as_slice(),
+    );
+
+    assert_eq!(value.into_json(), serde_json::json!([true, false, true]));
+}
+
+#[test]
+fn ok_storage_leaves_decoded() {
+    let order = Type::Structure(vec![
+        (
+            "id".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+        ),
+        (
+            "amount".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::new(false, 64))),
+        ),
+        (
+            "flags".to_owned(),
+            Type::Array(Box::new(Type::Scalar(ScalarType::Boolean)), 2),
+        ),
+    ]);
+    let field = ContractField::new(
+        "orders".to_owned(),
+        Type::Array(Box::new(order), 4),
+        true,
+        false,
+    );
+
+    let metadata = serde_json::to_value(field).expect("Serialization");
+    let field: ContractField = serde_json::from_value(metadata).expect("Deserialization");
+    assert_eq!(field.leaves.len(), 12);
+    assert_eq!(field.leaves[0].path, "orders[0].id");
+    assert_eq!(field.leaves[7].path, "orders[2].amount");
+    assert_eq!(field.leaves[11].path, "orders[3].flags");
+    assert_eq!(field.leaves[11].packed_bits, Some(2));
+
+    let leaves: Vec<Vec<BigInt>> = (0..4)
+        .map(|index| {
+            vec![
+                vec![BigInt::from(index)],
+                vec![BigInt::from(index * 100)],
+                vec![BigInt::from(index % 4)],
+            ]
+        })
+        .flatten()
+        .collect();
+    let value = Value::from_flat_values(
+        field.r#type.to_owned(),
+        field.unpack_leaves(leaves.as_slice()).as_slice(),
+    );
+
+    assert_eq!(
+        value.into_json()[2],
+        serde_json::json!({ "id": "2", "amount": "200", "flags": [false, true] })
+    );
+}
@@ -8,6 +8,7 @@ pub mod scalar;
 use std::fmt;
 
 use num::BigInt;
+use num::Signed;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -34,6 +35,18 @@ pub enum Type {
         /// The variant list.
         variants: Vec<(String, BigInt)>,
     },
+    /// The `#[bitflags]` enumeration flag set type, which is specified in the input JSON file
+    /// using an array of variant name strings.
+    Flags {
+        /// The enumeration type bitlength.
+        bitlength: usize,
+        /// The variant list.
+        variants: Vec<(String, BigInt)>,
+    },
+    /// The `zksync::Address` type, which is specified in the input JSON file using a hex string.
+    Address,
+    /// The `zksync::Balance` type, which is specified in the input JSON file using a decimal string.
+    Balance,
 
     /// The array type.
     Array(Box<Type>, usize),
@@ -68,6 +81,19 @@ impl Type {
         Self::Scalar(ScalarType::eth_address())
     }
 
+    ///
+    /// Returns the integer type of the enumeration values, which is signed if any of the
+    /// `variants` is negative.
+    ///
+    pub fn enumeration_integer_type(
+        bitlength: usize,
+        variants: &[(String, BigInt)],
+    ) -> IntegerType {
+        let is_signed = variants.iter().any(|(_name, value)| value.is_negative());
+
+        IntegerType::new(is_signed, bitlength)
+    }
+
     ///
     /// Converts a complex type into an array of primitive scalar types, which is useful for
     /// reading an application input values.
@@ -76,9 +102,18 @@ impl Type {
         match self {
             Self::Unit => vec![],
             Self::Scalar(scalar_type) => vec![scalar_type],
-            Self::Enumeration { bitlength, .. } => {
+            Self::Enumeration {
+                bitlength,
+                variants,
+            } => vec![ScalarType::Integer(Self::enumeration_integer_type(
+                bitlength,
+                variants.as_slice(),
+            ))],
+            Self::Flags { bitlength, .. } => {
                 vec![ScalarType::Integer(IntegerType::new(false, bitlength))]
             }
+            Self::Address => vec![ScalarType::Integer(IntegerType::ETH_ADDRESS)],
+            Self::Balance => vec![ScalarType::Integer(IntegerType::BALANCE)],
 
             Self::Array(r#type, size) => vec![Self::into_flat_scalar_types(*r#type); size]
                 .into_iter()
@@ -125,6 +160,9 @@ impl Type {
             Self::Unit => 0,
             Self::Scalar(_) => 1,
             Self::Enumeration { .. } => 1,
+            Self::Flags { .. } => 1,
+            Self::Address => 1,
+            Self::Balance => 1,
 
             Self::Array(r#type, size) => r#type.size() * *size,
             Self::Tuple(fields) => fields.iter().map(Self::size).sum(),
@@ -135,6 +173,37 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the type is flattened into several leaves in the contract storage, that is,
+    /// if it is a structure or an array of such.
+    ///
+    pub fn is_storage_flattened(&self) -> bool {
+        match self {
+            Self::Structure(_) => true,
+            Self::Array(r#type, _) => r#type.is_storage_flattened(),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns the number of leaves the type occupies in the contract storage.
+    ///
+    /// The flattened types occupy as many leaves as their elements together, whereas the other
+    /// types occupy a single leaf.
+    ///
+    pub fn storage_leaf_count(&self) -> usize {
+        match self {
+            Self::Structure(fields) => fields
+                .iter()
+                .map(|(_name, r#type)| r#type.storage_leaf_count())
+                .sum(),
+            Self::Array(r#type, size) if r#type.is_storage_flattened() => {
+                r#type.storage_leaf_count() * *size
+            }
+            _ => 1,
+        }
+    }
+
     ///
     /// Changes the first argument from the contract instance to a contract address.
     ///
@@ -166,6 +235,17 @@ impl fmt::Display for Type {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            Self::Flags { variants, .. } => write!(
+                f,
+                "flags {}",
+                variants
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            ),
+            Self::Address => write!(f, "zksync::Address"),
+            Self::Balance => write!(f, "zksync::Balance"),
 
             Self::Array(inner, size) => write!(f, "[{}; {}]", inner, size),
             Self::Tuple(types) => write!(
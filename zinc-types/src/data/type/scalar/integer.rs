@@ -5,6 +5,7 @@
 use std::fmt;
 
 use num::BigInt;
+use num::Signed;
 use num::Zero;
 use serde::Deserialize;
 use serde::Serialize;
@@ -94,6 +95,36 @@ impl Type {
             (BigInt::from(1) << self.bitlength) - 1u8
         }
     }
+
+    ///
+    /// Checks if casting a value of type `from` to the type reinterprets its two's complement
+    /// representation instead of preserving the value.
+    ///
+    /// It is the case if the signedness differs, and the bitlength is either the same, or the
+    /// signed value is extended to a wider unsigned type. The `field` values are never
+    /// reinterpreted.
+    ///
+    pub fn is_reinterpreted_from(&self, from: &Self) -> bool {
+        self.bitlength < zinc_const::bitlength::FIELD
+            && self.is_signed != from.is_signed
+            && (self.bitlength == from.bitlength
+                || (from.is_signed && self.bitlength > from.bitlength))
+    }
+
+    ///
+    /// Reinterprets the two's complement representation of `value` as the type.
+    ///
+    /// The `value` must fit into the type bitlength.
+    ///
+    pub fn reinterpret(&self, value: BigInt) -> BigInt {
+        if self.is_signed && value > self.max() {
+            value - (BigInt::from(1) << self.bitlength)
+        } else if !self.is_signed && value.is_negative() {
+            value + (BigInt::from(1) << self.bitlength)
+        } else {
+            value
+        }
+    }
 }
 
 impl fmt::Display for Type {
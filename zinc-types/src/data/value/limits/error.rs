@@ -0,0 +1,29 @@
+//!
+//! The template value limits error.
+//!
+
+use thiserror::Error;
+
+///
+/// The template value limits error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The JSON value has more scalars than allowed.
+    #[error("`{path}` exceeds the limit of {limit} values")]
+    TooManyValues {
+        /// The path of the aggregate, where the limit has been exceeded.
+        path: String,
+        /// The maximal number of scalars.
+        limit: usize,
+    },
+
+    /// The JSON value is nested deeper than allowed.
+    #[error("`{path}` exceeds the nesting depth limit of {limit}")]
+    TooDeep {
+        /// The path of the aggregate, where the limit has been exceeded.
+        path: String,
+        /// The maximal nesting depth.
+        limit: usize,
+    },
+}
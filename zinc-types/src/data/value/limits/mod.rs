@@ -0,0 +1,225 @@
+//!
+//! The template value limits.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+
+use std::cmp;
+
+use crate::data::r#type::Type;
+
+use self::error::Error;
+
+///
+/// The template value limits.
+///
+/// Is used to reject the oversized JSON input before it is decoded, so a client cannot make the
+/// decoder allocate millions of values.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// The maximal number of scalars, that is, the JSON strings, numbers, and booleans.
+    pub flat_values: usize,
+    /// The maximal nesting depth of the JSON arrays and objects.
+    pub depth: usize,
+}
+
+///
+/// The JSON value path segment.
+///
+#[derive(Debug, Clone, Copy)]
+enum Segment<'a> {
+    /// The array element index.
+    Index(usize),
+    /// The object field name.
+    Field(&'a str),
+}
+
+impl Limits {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(flat_values: usize, depth: usize) -> Self {
+        Self { flat_values, depth }
+    }
+
+    ///
+    /// Narrows the limits down to the bounds of the template `r#type`.
+    ///
+    /// The `std::collections::MTreeMap` values have no size bound, so only `self` limits their
+    /// number of scalars.
+    ///
+    pub fn narrowed(self, r#type: &Type) -> Self {
+        let flat_values = match Self::flat_values_bound(r#type) {
+            Some(bound) => cmp::min(self.flat_values, bound),
+            None => self.flat_values,
+        };
+        let depth = cmp::min(self.depth, Self::depth_bound(r#type));
+
+        Self::new(flat_values, depth)
+    }
+
+    ///
+    /// Checks the JSON `value` against the limits without allocating.
+    ///
+    /// Bails out as soon as either limit is exceeded, returning the path of the offending array
+    /// or object.
+    ///
+    pub fn check(&self, value: &serde_json::Value) -> Result<(), Error> {
+        let mut path = Vec::new();
+        let mut flat_values = 0;
+        self.check_inner(value, &mut path, &mut flat_values)
+    }
+
+    ///
+    /// Descends into the JSON `value` at `path`, counting the scalars in `flat_values`.
+    ///
+    fn check_inner<'a>(
+        &self,
+        value: &'a serde_json::Value,
+        path: &mut Vec<Segment<'a>>,
+        flat_values: &mut usize,
+    ) -> Result<(), Error> {
+        match value {
+            serde_json::Value::Null => return Ok(()),
+            serde_json::Value::Array(elements) => {
+                if path.len() >= self.depth {
+                    return Err(Error::TooDeep {
+                        path: Self::path_to_string(path.as_slice()),
+                        limit: self.depth,
+                    });
+                }
+
+                for (index, element) in elements.iter().enumerate() {
+                    path.push(Segment::Index(index));
+                    self.check_inner(element, path, flat_values)?;
+                    path.pop();
+                }
+            }
+            serde_json::Value::Object(fields) => {
+                if path.len() >= self.depth {
+                    return Err(Error::TooDeep {
+                        path: Self::path_to_string(path.as_slice()),
+                        limit: self.depth,
+                    });
+                }
+
+                for (name, field) in fields.iter() {
+                    path.push(Segment::Field(name.as_str()));
+                    self.check_inner(field, path, flat_values)?;
+                    path.pop();
+                }
+            }
+            _ => {
+                *flat_values += 1;
+                if *flat_values > self.flat_values {
+                    path.pop();
+                    return Err(Error::TooManyValues {
+                        path: Self::path_to_string(path.as_slice()),
+                        limit: self.flat_values,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the number of scalars in the JSON representation of `r#type`, or `None` if the
+    /// type contains a map.
+    ///
+    fn flat_values_bound(r#type: &Type) -> Option<usize> {
+        match r#type {
+            Type::Unit => Some(0),
+            Type::Scalar(_) | Type::Enumeration { .. } | Type::Address | Type::Balance => Some(1),
+            Type::Flags { variants, .. } => Some(variants.len()),
+
+            Type::Array(r#type, size) => {
+                Self::flat_values_bound(r#type.as_ref()).and_then(|bound| bound.checked_mul(*size))
+            }
+            Type::Tuple(types) => types.iter().map(Self::flat_values_bound).sum(),
+            Type::Structure(fields) => fields
+                .iter()
+                .map(|(_name, r#type)| Self::flat_values_bound(r#type))
+                .sum(),
+            Type::Contract(fields) => fields
+                .iter()
+                .map(|field| Self::flat_values_bound(&field.r#type))
+                .sum(),
+
+            Type::Map { .. } => None,
+        }
+    }
+
+    ///
+    /// Returns the nesting depth of the JSON representation of `r#type`.
+    ///
+    /// A map is an array of objects with the `key` and `value` fields, so it takes two levels.
+    ///
+    fn depth_bound(r#type: &Type) -> usize {
+        match r#type {
+            Type::Unit
+            | Type::Scalar(_)
+            | Type::Enumeration { .. }
+            | Type::Address
+            | Type::Balance => 0,
+            Type::Flags { .. } => 1,
+
+            Type::Array(r#type, _size) => 1 + Self::depth_bound(r#type.as_ref()),
+            Type::Tuple(types) => 1 + types.iter().map(Self::depth_bound).max().unwrap_or(0),
+            Type::Structure(fields) => {
+                1 + fields
+                    .iter()
+                    .map(|(_name, r#type)| Self::depth_bound(r#type))
+                    .max()
+                    .unwrap_or(0)
+            }
+            Type::Contract(fields) => {
+                1 + fields
+                    .iter()
+                    .map(|field| Self::depth_bound(&field.r#type))
+                    .max()
+                    .unwrap_or(0)
+            }
+
+            Type::Map {
+                key_type,
+                value_type,
+            } => {
+                2 + cmp::max(
+                    Self::depth_bound(key_type.as_ref()),
+                    Self::depth_bound(value_type.as_ref()),
+                )
+            }
+        }
+    }
+
+    ///
+    /// Renders the `path` in the `.field[index]` notation, where the root value is `.`.
+    ///
+    fn path_to_string(path: &[Segment]) -> String {
+        if path.is_empty() {
+            return ".".to_owned();
+        }
+
+        path.iter()
+            .map(|segment| match segment {
+                Segment::Index(index) => format!("[{}]", index),
+                Segment::Field(name) => format!(".{}", name),
+            })
+            .collect()
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new(
+            zinc_const::limit::INPUT_FLAT_VALUES,
+            zinc_const::limit::INPUT_DEPTH,
+        )
+    }
+}
@@ -0,0 +1,96 @@
+//!
+//! The template value limits tests.
+//!
+
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::limits::error::Error;
+use crate::data::value::limits::Limits;
+
+#[test]
+fn ok_within_limits() {
+    let value = serde_json::json!({
+        "a": ["0x1", "0x2"],
+        "b": { "c": true },
+    });
+
+    assert_eq!(Limits::new(3, 2).check(&value), Ok(()));
+}
+
+#[test]
+fn ok_narrowed() {
+    let r#type = Type::Structure(vec![
+        (
+            "values".to_owned(),
+            Type::Array(Box::new(Type::Scalar(ScalarType::Field)), 4),
+        ),
+        (
+            "pair".to_owned(),
+            Type::Tuple(vec![Type::Scalar(ScalarType::Boolean), Type::Unit]),
+        ),
+    ]);
+
+    assert_eq!(Limits::new(1000, 1000).narrowed(&r#type), Limits::new(5, 2));
+    assert_eq!(Limits::new(3, 1).narrowed(&r#type), Limits::new(3, 1));
+}
+
+#[test]
+fn ok_narrowed_map() {
+    let r#type = Type::Map {
+        key_type: Box::new(Type::Address),
+        value_type: Box::new(Type::Scalar(ScalarType::Field)),
+    };
+
+    assert_eq!(
+        Limits::new(1000, 1000).narrowed(&r#type),
+        Limits::new(1000, 2)
+    );
+}
+
+#[test]
+fn error_too_many_values() {
+    let r#type = Type::Structure(vec![(
+        "values".to_owned(),
+        Type::Array(Box::new(Type::Scalar(ScalarType::Field)), 4),
+    )]);
+    let value = serde_json::json!({ "values": vec!["0x1"; 1_000_000] });
+
+    assert_eq!(
+        Limits::default().narrowed(&r#type).check(&value),
+        Err(Error::TooManyValues {
+            path: ".values".to_owned(),
+            limit: 4,
+        })
+    );
+}
+
+#[test]
+fn error_too_deep() {
+    let mut r#type = Type::Scalar(ScalarType::Field);
+    let mut value = serde_json::json!("0x1");
+    for _ in 0..64 {
+        r#type = Type::Array(Box::new(r#type), 1);
+        value = serde_json::json!([value]);
+    }
+
+    assert_eq!(
+        Limits::default().narrowed(&r#type).check(&value),
+        Err(Error::TooDeep {
+            path: "[0]".repeat(zinc_const::limit::INPUT_DEPTH),
+            limit: zinc_const::limit::INPUT_DEPTH,
+        })
+    );
+}
+
+#[test]
+fn error_too_deep_root() {
+    let value = serde_json::json!({ "a": "0x1" });
+
+    assert_eq!(
+        Limits::new(1, 0).check(&value),
+        Err(Error::TooDeep {
+            path: ".".to_owned(),
+            limit: 0,
+        })
+    );
+}
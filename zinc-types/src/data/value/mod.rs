@@ -2,8 +2,14 @@
 //! The template value.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod contract_field;
+pub mod limits;
+pub mod pretty;
 pub mod scalar;
+pub mod template;
 
 use std::collections::HashSet;
 
@@ -19,6 +25,8 @@ use crate::data::r#type::scalar::integer::Type as IntegerType;
 use crate::data::r#type::scalar::Type as ScalarType;
 use crate::data::r#type::Type;
 use crate::error::Error;
+use crate::scalar::format as scalar_format;
+use crate::scalar::parse as scalar_parse;
 
 use self::contract_field::ContractField;
 use self::scalar::Value as ScalarValue;
@@ -42,6 +50,13 @@ pub enum Value {
         /// The enumeration variant value.
         value: ScalarValue,
     },
+    /// A `#[bitflags]` enumeration flag set, represented with a JSON array of variant name strings.
+    Flags {
+        /// The names of the variants set in the flag set.
+        names: Vec<String>,
+        /// The flag set value.
+        value: ScalarValue,
+    },
 
     /// Represented with JSON array.
     Array(Vec<Value>),
@@ -72,6 +87,7 @@ impl Value {
                 bitlength,
                 mut variants,
             } => {
+                let integer_type = Type::enumeration_integer_type(bitlength, variants.as_slice());
                 let (name, value) = variants.remove(0);
 
                 match bitlength {
@@ -79,12 +95,18 @@ impl Value {
                         name,
                         value: ScalarValue::Field(value),
                     },
-                    bitlength => Self::Enumeration {
+                    _ => Self::Enumeration {
                         name,
-                        value: ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
+                        value: ScalarValue::Integer(value, integer_type),
                     },
                 }
             }
+            Type::Flags { bitlength, .. } => Self::Flags {
+                names: vec![],
+                value: ScalarValue::Integer(BigInt::zero(), IntegerType::new(false, bitlength)),
+            },
+            Type::Address => Self::Scalar(ScalarValue::Address(BigInt::zero())),
+            Type::Balance => Self::Scalar(ScalarValue::Balance(BigInt::zero())),
 
             Type::Array(r#type, size) => Self::Array(vec![Self::new(*r#type); size]),
             Type::Tuple(fields) => Self::Array(fields.into_iter().map(Self::new).collect()),
@@ -116,6 +138,12 @@ impl Value {
                 bitlength,
                 variants,
             } => Self::enumeration_from_json(value, bitlength, variants),
+            Type::Flags {
+                bitlength,
+                variants,
+            } => Self::flags_from_json(value, bitlength, variants),
+            Type::Address => Self::address_from_json(value),
+            Type::Balance => Self::balance_from_json(value),
 
             Type::Array(inner, size) => Self::array_from_json(value, *inner, size),
             Type::Tuple(inner) => Self::tuple_from_json(value, inner),
@@ -154,13 +182,46 @@ impl Value {
                     .map(Self::Scalar),
             }
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-            Type::Enumeration { bitlength, .. } => flat_values
+            Type::Enumeration {
+                bitlength,
+                variants,
+            } => flat_values
                 .first()
                 .cloned()
                 .map(|value| match bitlength {
                     zinc_const::bitlength::FIELD => ScalarValue::Field(value),
-                    bitlength => ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
+                    bitlength => ScalarValue::Integer(
+                        value,
+                        Type::enumeration_integer_type(bitlength, variants.as_slice()),
+                    ),
+                })
+                .map(Self::Scalar)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            Type::Flags {
+                bitlength,
+                variants,
+            } => flat_values
+                .first()
+                .cloned()
+                .map(|value| Self::Flags {
+                    names: variants
+                        .into_iter()
+                        .filter(|(_name, variant)| !(&value & variant).is_zero())
+                        .map(|(name, _variant)| name)
+                        .collect(),
+                    value: ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
                 })
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            Type::Address => flat_values
+                .first()
+                .cloned()
+                .map(ScalarValue::Address)
+                .map(Self::Scalar)
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            Type::Balance => flat_values
+                .first()
+                .cloned()
+                .map(ScalarValue::Balance)
                 .map(Self::Scalar)
                 .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
             Type::Array(r#type, size) => {
@@ -224,6 +285,7 @@ impl Value {
             Self::Unit => vec![],
             Self::Scalar(value) => vec![value.to_bigint()],
             Self::Enumeration { name: _, value } => vec![value.to_bigint()],
+            Self::Flags { names: _, value } => vec![value.to_bigint()],
 
             Self::Array(values) => values
                 .into_iter()
@@ -254,20 +316,28 @@ impl Value {
         match self {
             Self::Unit => serde_json::Value::Null,
             Self::Scalar(scalar) => match scalar {
-                ScalarValue::Field(value) => serde_json::Value::String(format!(
-                    "0x{}",
-                    value.to_str_radix(zinc_const::base::HEXADECIMAL)
-                )),
+                ScalarValue::Field(value) => {
+                    serde_json::Value::String(scalar_format::hexadecimal(&value))
+                }
                 ScalarValue::Integer(value, r#type) => serde_json::Value::String(
                     if r#type.bitlength == zinc_const::bitlength::ETH_ADDRESS {
-                        format!("0x{}", value.to_str_radix(zinc_const::base::HEXADECIMAL))
+                        scalar_format::hexadecimal(&value)
                     } else {
-                        value.to_string()
+                        scalar_format::decimal(&value)
                     },
                 ),
                 ScalarValue::Boolean(value) => serde_json::Value::Bool(value),
+                ScalarValue::Address(value) => {
+                    serde_json::Value::String(scalar_format::address(&value))
+                }
+                ScalarValue::Balance(value) => {
+                    serde_json::Value::String(scalar_format::decimal(&value))
+                }
             },
             Self::Enumeration { name, value: _ } => serde_json::Value::String(name),
+            Self::Flags { names, value: _ } => {
+                serde_json::Value::Array(names.into_iter().map(serde_json::Value::String).collect())
+            }
 
             Self::Array(values) => {
                 serde_json::Value::Array(values.into_iter().map(Self::into_json).collect())
@@ -306,6 +376,64 @@ impl Value {
         }
     }
 
+    ///
+    /// Renders the difference between the `actual` and `expected` values of the same type.
+    ///
+    /// Each element, which differs, is rendered with its path as a pair of lines prefixed with
+    /// `-` for the actual value and `+` for the expected one. Returns `None` if the values are equal.
+    ///
+    pub fn diff(actual: &Self, expected: &Self) -> Option<String> {
+        let mut lines = Vec::new();
+        Self::diff_inner(String::new(), actual, expected, &mut lines);
+
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join("\n"))
+        }
+    }
+
+    ///
+    /// Descends into the aggregate values, pushing the differing elements to `lines`.
+    ///
+    fn diff_inner(path: String, actual: &Self, expected: &Self, lines: &mut Vec<String>) {
+        match (actual, expected) {
+            (Self::Array(actual), Self::Array(expected)) if actual.len() == expected.len() => {
+                for (index, (actual, expected)) in actual.iter().zip(expected.iter()).enumerate() {
+                    Self::diff_inner(format!("{}[{}]", path, index), actual, expected, lines);
+                }
+            }
+            (Self::Structure(actual), Self::Structure(expected))
+                if actual.len() == expected.len() =>
+            {
+                for ((name, actual), (_name, expected)) in actual.iter().zip(expected.iter()) {
+                    Self::diff_inner(format!("{}.{}", path, name), actual, expected, lines);
+                }
+            }
+            (Self::Contract(actual), Self::Contract(expected))
+                if actual.len() == expected.len() =>
+            {
+                for (actual, expected) in actual.iter().zip(expected.iter()) {
+                    Self::diff_inner(
+                        format!("{}.{}", path, actual.name),
+                        &actual.value,
+                        &expected.value,
+                        lines,
+                    );
+                }
+            }
+            (actual, expected) => {
+                let actual = actual.to_owned().into_json();
+                let expected = expected.to_owned().into_json();
+
+                if actual != expected {
+                    lines.push(format!("-   actual{} = {}", path, actual));
+                    lines.push(format!("+ expected{} = {}", path, expected));
+                }
+            }
+        }
+    }
+
     ///
     /// Inserts a contract address `self` argument into the function arguments structure.
     ///
@@ -351,12 +479,28 @@ impl Value {
     /// Creates an integer value from the JSON `value`.
     ///
     fn integer_from_json(value: serde_json::Value, r#type: IntegerType) -> anyhow::Result<Self> {
+        let bigint = Self::bigint_from_json(value, &r#type)?;
+
+        Ok(Self::Scalar(ScalarValue::Integer(bigint, r#type)))
+    }
+
+    ///
+    /// Parses the JSON `value` numeric string, checking if it fits into the integer `r#type`.
+    ///
+    fn bigint_from_json(value: serde_json::Value, r#type: &IntegerType) -> anyhow::Result<BigInt> {
         let value_string = value.as_str().ok_or_else(|| Error::TypeError {
             expected: "numeric string: 0b[0-1]+ | 0o[0-7]+ | [0-9]+ | 0x[0-9A-Fa-f]+".into(),
             found: value.to_string(),
         })?;
 
         let bigint = zinc_math::bigint_from_str(value_string).map_err(Error::from)?;
+        Self::bigint_checked(bigint, r#type)
+    }
+
+    ///
+    /// Checks if the parsed `bigint` fits into the integer `r#type`.
+    ///
+    fn bigint_checked(bigint: BigInt, r#type: &IntegerType) -> anyhow::Result<BigInt> {
         if bigint.is_negative() && !r#type.is_signed {
             anyhow::bail!(Error::from(zinc_math::Error::Overflow {
                 value: bigint,
@@ -375,7 +519,7 @@ impl Value {
             }));
         }
 
-        Ok(Self::Scalar(ScalarValue::Integer(bigint, r#type)))
+        Ok(bigint)
     }
 
     ///
@@ -396,6 +540,7 @@ impl Value {
             found: value.to_string(),
         })?;
 
+        let integer_type = Type::enumeration_integer_type(bitlength, variants.as_slice());
         let bigint = match variants.into_iter().find(|(name, value)| {
             name == value_string
                 || zinc_math::bigint_from_str(value.to_string().as_str())
@@ -409,13 +554,96 @@ impl Value {
 
         match bitlength {
             zinc_const::bitlength::FIELD => Ok(Self::Scalar(ScalarValue::Field(bigint))),
-            bitlength => Ok(Self::Scalar(ScalarValue::Integer(
-                bigint,
-                IntegerType::new(false, bitlength),
-            ))),
+            _ => Ok(Self::Scalar(ScalarValue::Integer(bigint, integer_type))),
         }
     }
 
+    ///
+    /// Creates a `#[bitflags]` enumeration flag set value from the JSON `value`.
+    ///
+    /// The value is an array of the variant names, which are combined into the flag set.
+    ///
+    fn flags_from_json(
+        value: serde_json::Value,
+        bitlength: usize,
+        variants: Vec<(String, BigInt)>,
+    ) -> anyhow::Result<Self> {
+        let array = match value {
+            serde_json::Value::Array(array) => array,
+            value => anyhow::bail!(Error::type_error(
+                format!(
+                    "JSON array of: {}",
+                    variants
+                        .iter()
+                        .map(|(name, _value)| name.to_owned())
+                        .collect::<Vec<String>>()
+                        .join(" | ")
+                ),
+                value
+            )),
+        };
+
+        let mut names = Vec::with_capacity(array.len());
+        let mut bigint = BigInt::zero();
+        for (index, value) in array.into_iter().enumerate() {
+            let value_string = value
+                .as_str()
+                .ok_or_else(|| Error::type_error("variant name string".to_owned(), value.clone()))
+                .with_context(|| format!("[{}]", index))?;
+
+            let (name, value) = variants
+                .iter()
+                .find(|(name, _value)| name == value_string)
+                .cloned()
+                .ok_or_else(|| Error::UnexpectedVariant(value_string.to_owned()))
+                .with_context(|| format!("[{}]", index))?;
+
+            if !names.contains(&name) {
+                bigint |= value;
+                names.push(name);
+            }
+        }
+
+        Ok(Self::Flags {
+            names,
+            value: ScalarValue::Integer(bigint, IntegerType::new(false, bitlength)),
+        })
+    }
+
+    ///
+    /// Creates a `zksync::Address` value from the JSON `value`.
+    ///
+    /// Only the `0x`-prefixed hexadecimal strings are accepted.
+    ///
+    fn address_from_json(value: serde_json::Value) -> anyhow::Result<Self> {
+        let value_string = value.as_str().ok_or_else(|| {
+            Error::type_error(
+                "hexadecimal string: 0x[0-9A-Fa-f]+".to_owned(),
+                value.clone(),
+            )
+        })?;
+
+        let bigint = scalar_parse::address(value_string).map_err(Error::from)?;
+
+        Ok(Self::Scalar(ScalarValue::Address(bigint)))
+    }
+
+    ///
+    /// Creates a `zksync::Balance` value from the JSON `value`.
+    ///
+    /// Only the decimal strings are accepted.
+    ///
+    fn balance_from_json(value: serde_json::Value) -> anyhow::Result<Self> {
+        let value_string = value
+            .as_str()
+            .ok_or_else(|| Error::type_error("decimal string: [0-9]+".to_owned(), value.clone()))?;
+
+        let bigint = scalar_parse::decimal(value_string).map_err(Error::from)?;
+        let bigint = Self::bigint_checked(bigint, &IntegerType::BALANCE)?;
+
+        Ok(Self::Scalar(ScalarValue::Balance(bigint)))
+    }
+
     ///
     /// Creates a field value from the JSON `value`.
     ///
@@ -460,10 +688,10 @@ impl Value {
         r#type: Type,
         size: usize,
     ) -> anyhow::Result<Self> {
-        let array = value
-            .as_array()
-            .cloned()
-            .ok_or_else(|| Error::type_error("JSON array".to_owned(), value))?;
+        let array = match value {
+            serde_json::Value::Array(array) => array,
+            value => anyhow::bail!(Error::type_error("JSON array".to_owned(), value)),
+        };
 
         if array.len() != size {
             anyhow::bail!(Error::UnexpectedSize {
@@ -487,10 +715,10 @@ impl Value {
     /// Creates a tuple value from the JSON `value`.
     ///
     fn tuple_from_json(value: serde_json::Value, types: Vec<Type>) -> anyhow::Result<Self> {
-        let array = value
-            .as_array()
-            .cloned()
-            .ok_or_else(|| Error::type_error("JSON array".to_owned(), value))?;
+        let array = match value {
+            serde_json::Value::Array(array) => array,
+            value => anyhow::bail!(Error::type_error("JSON array".to_owned(), value)),
+        };
 
         if array.len() != types.len() {
             anyhow::bail!(Error::UnexpectedSize {
@@ -516,10 +744,10 @@ impl Value {
         value: serde_json::Value,
         field_types: Vec<(String, Type)>,
     ) -> anyhow::Result<Self> {
-        let mut object = value
-            .as_object()
-            .cloned()
-            .ok_or_else(|| Error::type_error("JSON object".to_owned(), value))?;
+        let mut object = match value {
+            serde_json::Value::Object(object) => object,
+            value => anyhow::bail!(Error::type_error("JSON object".to_owned(), value)),
+        };
 
         let mut used_fields = HashSet::with_capacity(field_types.len());
         let mut field_values = Vec::with_capacity(field_types.len());
@@ -556,10 +784,10 @@ impl Value {
         value: serde_json::Value,
         field_types: Vec<ContractFieldType>,
     ) -> anyhow::Result<Self> {
-        let mut object = value
-            .as_object()
-            .cloned()
-            .ok_or_else(|| Error::type_error("JSON object".to_owned(), value))?;
+        let mut object = match value {
+            serde_json::Value::Object(object) => object,
+            value => anyhow::bail!(Error::type_error("JSON object".to_owned(), value)),
+        };
 
         let mut used_fields = HashSet::with_capacity(field_types.len());
         let mut field_values = Vec::with_capacity(field_types.len());
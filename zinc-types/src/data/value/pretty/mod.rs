@@ -0,0 +1,228 @@
+//!
+//! The template value pretty printer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use num::ToPrimitive;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::scalar::Value as ScalarValue;
+use crate::data::value::Value;
+use crate::scalar::format as scalar_format;
+
+///
+/// The template value pretty printer.
+///
+/// Renders the typed application output for humans, unlike the JSON representation, which is
+/// intended to be read by the tools.
+///
+pub struct Printer {}
+
+impl Printer {
+    /// The nested aggregate value indentation.
+    const INDENT: &'static str = "    ";
+
+    ///
+    /// Renders the `value` using its `r#type` metadata.
+    ///
+    /// The addresses and byte arrays are rendered as `0x`-prefixed hexadecimal strings, the
+    /// enumerations as their variant names, and the structure fields are aligned by their names.
+    ///
+    /// The value, which does not match the type, is rendered as its JSON representation.
+    ///
+    pub fn print(value: &Value, r#type: &Type) -> String {
+        match (value, r#type) {
+            (Value::Unit, Type::Unit) => "()".to_owned(),
+            (Value::Scalar(ScalarValue::Boolean(value)), _) => value.to_string(),
+            (Value::Scalar(ScalarValue::Integer(value, r#type)), _)
+                if r#type.bitlength == zinc_const::bitlength::ETH_ADDRESS =>
+            {
+                scalar_format::address(value)
+            }
+            (Value::Scalar(ScalarValue::Integer(value, _)), Type::Enumeration { variants, .. })
+            | (Value::Scalar(ScalarValue::Field(value)), Type::Enumeration { variants, .. }) => {
+                variants
+                    .iter()
+                    .find(|(_name, variant)| variant == value)
+                    .map(|(name, _variant)| name.to_owned())
+                    .unwrap_or_else(|| value.to_string())
+            }
+            (Value::Scalar(ScalarValue::Integer(value, _)), _) => scalar_format::decimal(value),
+            (Value::Scalar(ScalarValue::Field(value)), _) => scalar_format::hexadecimal(value),
+            (Value::Scalar(ScalarValue::Address(value)), _) => scalar_format::address(value),
+            (Value::Scalar(ScalarValue::Balance(value)), _) => scalar_format::decimal(value),
+            (Value::Enumeration { name, .. }, _) => name.to_owned(),
+            (Value::Flags { names, .. }, _) => {
+                if names.is_empty() {
+                    "(none)".to_owned()
+                } else {
+                    names.join(" | ")
+                }
+            }
+
+            (Value::Array(values), Type::Array(r#type, _size)) => {
+                Self::array(values.as_slice(), r#type.as_ref())
+            }
+            (Value::Array(values), Type::Tuple(types)) if values.len() == types.len() => {
+                let elements = values
+                    .iter()
+                    .zip(types.iter())
+                    .map(|(value, r#type)| Self::print(value, r#type))
+                    .collect();
+                if types.iter().all(Self::is_inline) {
+                    Self::line("(", ")", elements)
+                } else {
+                    Self::block("(", ")", elements)
+                }
+            }
+            (Value::Structure(fields), Type::Structure(types)) if fields.len() == types.len() => {
+                Self::fields(
+                    fields
+                        .iter()
+                        .zip(types.iter())
+                        .filter(|((name, _value), _type)| {
+                            *name != zinc_lexical::Keyword::SelfLowercase.to_string()
+                        })
+                        .map(|((name, value), (_name, r#type))| {
+                            (name.to_owned(), Self::print(value, r#type))
+                        })
+                        .collect(),
+                )
+            }
+            (Value::Contract(fields), Type::Contract(types)) if fields.len() == types.len() => {
+                Self::fields(
+                    fields
+                        .iter()
+                        .zip(types.iter())
+                        .map(|(field, r#type)| {
+                            (
+                                field.name.to_owned(),
+                                Self::print(&field.value, &r#type.r#type),
+                            )
+                        })
+                        .collect(),
+                )
+            }
+
+            (
+                Value::Map(entries),
+                Type::Map {
+                    key_type,
+                    value_type,
+                },
+            ) => Self::block(
+                "{",
+                "}",
+                entries
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{} => {}",
+                            Self::print(key, key_type),
+                            Self::print(value, value_type)
+                        )
+                    })
+                    .collect(),
+            ),
+
+            (value, _type) => value.to_owned().into_json().to_string(),
+        }
+    }
+
+    ///
+    /// Renders the array `values` of the element `r#type`.
+    ///
+    /// The byte arrays are rendered as a single hexadecimal string, the scalar arrays are kept
+    /// on a single line, and the aggregate arrays are rendered with an element per line.
+    ///
+    fn array(values: &[Value], r#type: &Type) -> String {
+        if r#type == &Type::Scalar(ScalarType::Integer(IntegerType::U8)) {
+            let bytes: Option<Vec<u8>> = values
+                .iter()
+                .map(|value| match value {
+                    Value::Scalar(scalar) => scalar.to_bigint().to_u8(),
+                    _ => None,
+                })
+                .collect();
+            if let Some(bytes) = bytes {
+                return scalar_format::bytes(bytes.as_slice());
+            }
+        }
+
+        let elements = values
+            .iter()
+            .map(|value| Self::print(value, r#type))
+            .collect();
+        if Self::is_inline(r#type) {
+            Self::line("[", "]", elements)
+        } else {
+            Self::block("[", "]", elements)
+        }
+    }
+
+    ///
+    /// Whether the values of the `r#type` are rendered on a single line inside the aggregates.
+    ///
+    fn is_inline(r#type: &Type) -> bool {
+        matches!(
+            r#type,
+            Type::Unit
+                | Type::Scalar(_)
+                | Type::Enumeration { .. }
+                | Type::Flags { .. }
+                | Type::Address
+                | Type::Balance
+        )
+    }
+
+    ///
+    /// Renders the structure or contract `fields` with the values aligned by the longest name.
+    ///
+    fn fields(fields: Vec<(String, String)>) -> String {
+        let width = fields
+            .iter()
+            .map(|(name, _value)| name.len())
+            .max()
+            .unwrap_or_default()
+            + 1;
+
+        Self::block(
+            "{",
+            "}",
+            fields
+                .into_iter()
+                .map(|(name, value)| format!("{:width$} {}", name + ":", value, width = width))
+                .collect(),
+        )
+    }
+
+    ///
+    /// Renders the `elements` between the `open` and `close` brackets on a single line.
+    ///
+    fn line(open: &str, close: &str, elements: Vec<String>) -> String {
+        format!("{}{}{}", open, elements.join(", "), close)
+    }
+
+    ///
+    /// Renders the `lines` between the `open` and `close` brackets, indenting each line and
+    /// the nested multi-line values.
+    ///
+    fn block(open: &str, close: &str, lines: Vec<String>) -> String {
+        if lines.is_empty() {
+            return format!("{}{}", open, close);
+        }
+
+        let mut result = format!("{}\n", open);
+        for line in lines.into_iter() {
+            result.push_str(Self::INDENT);
+            result.push_str(line.replace('\n', &format!("\n{}", Self::INDENT)).as_str());
+            result.push_str(",\n");
+        }
+        result.push_str(close);
+        result
+    }
+}
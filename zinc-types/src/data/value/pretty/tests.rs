@@ -0,0 +1,200 @@
+//!
+//! The template value pretty printer tests.
+//!
+
+use num::BigInt;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::pretty::Printer;
+use crate::data::value::Value;
+
+fn print(json: serde_json::Value, r#type: Type) -> String {
+    let value =
+        Value::try_from_typed_json(json, r#type.clone()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    Printer::print(&value, &r#type)
+}
+
+fn enumeration_type() -> Type {
+    Type::Enumeration {
+        bitlength: zinc_const::bitlength::BYTE,
+        variants: vec![
+            ("Pending".to_owned(), BigInt::from(0)),
+            ("Filled".to_owned(), BigInt::from(1)),
+        ],
+    }
+}
+
+#[test]
+fn ok_unit() {
+    assert_eq!(print(serde_json::Value::Null, Type::Unit), "()");
+}
+
+#[test]
+fn ok_boolean() {
+    assert_eq!(
+        print(serde_json::json!(true), Type::Scalar(ScalarType::Boolean)),
+        "true"
+    );
+    assert_eq!(
+        print(serde_json::json!(false), Type::Scalar(ScalarType::Boolean)),
+        "false"
+    );
+}
+
+#[test]
+fn ok_integer() {
+    assert_eq!(
+        print(
+            serde_json::json!("-42"),
+            Type::Scalar(ScalarType::Integer(IntegerType::I8))
+        ),
+        "-42"
+    );
+}
+
+#[test]
+fn ok_field() {
+    assert_eq!(
+        print(serde_json::json!("255"), Type::Scalar(ScalarType::Field)),
+        "0xff"
+    );
+}
+
+#[test]
+fn ok_address() {
+    assert_eq!(
+        print(serde_json::json!("0xabc"), Type::Address),
+        "0x0000000000000000000000000000000000000abc"
+    );
+}
+
+#[test]
+fn ok_balance() {
+    assert_eq!(
+        print(serde_json::json!("1000000000000000000"), Type::Balance),
+        "1000000000000000000"
+    );
+}
+
+#[test]
+fn ok_enumeration() {
+    assert_eq!(
+        print(serde_json::json!("Filled"), enumeration_type()),
+        "Filled"
+    );
+}
+
+#[test]
+fn ok_byte_array() {
+    assert_eq!(
+        print(
+            serde_json::json!(["1", "171", "0"]),
+            Type::Array(
+                Box::new(Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+                3
+            )
+        ),
+        "0x01ab00"
+    );
+}
+
+#[test]
+fn ok_scalar_array() {
+    assert_eq!(
+        print(
+            serde_json::json!([true, false]),
+            Type::Array(Box::new(Type::Scalar(ScalarType::Boolean)), 2)
+        ),
+        "[true, false]"
+    );
+}
+
+#[test]
+fn ok_tuple() {
+    assert_eq!(
+        print(
+            serde_json::json!(["42", "Filled"]),
+            Type::Tuple(vec![Type::Balance, enumeration_type()])
+        ),
+        "(42, Filled)"
+    );
+}
+
+#[test]
+fn ok_nested_structure() {
+    let r#type = Type::Structure(vec![
+        (
+            "id".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::U16)),
+        ),
+        ("owner".to_owned(), Type::Address),
+        ("status".to_owned(), enumeration_type()),
+        (
+            "payment".to_owned(),
+            Type::Structure(vec![
+                ("amount".to_owned(), Type::Balance),
+                ("is_paid".to_owned(), Type::Scalar(ScalarType::Boolean)),
+            ]),
+        ),
+        (
+            "hash".to_owned(),
+            Type::Array(
+                Box::new(Type::Scalar(ScalarType::Integer(IntegerType::U8))),
+                2,
+            ),
+        ),
+    ]);
+    let json = serde_json::json!({
+        "id": "7",
+        "owner": "0x1",
+        "status": "Pending",
+        "payment": {
+            "amount": "100",
+            "is_paid": true,
+        },
+        "hash": ["222", "173"],
+    });
+
+    assert_eq!(
+        print(json, r#type),
+        r#"{
+    id:      7,
+    owner:   0x0000000000000000000000000000000000000001,
+    status:  Pending,
+    payment: {
+        amount:  100,
+        is_paid: true,
+    },
+    hash:    0xdead,
+}"#
+    );
+}
+
+#[test]
+fn ok_structure_array() {
+    let r#type = Type::Array(
+        Box::new(Type::Structure(vec![(
+            "value".to_owned(),
+            Type::Scalar(ScalarType::Boolean),
+        )])),
+        2,
+    );
+
+    assert_eq!(
+        print(
+            serde_json::json!([{ "value": true }, { "value": false }]),
+            r#type
+        ),
+        r#"[
+    {
+        value: true,
+    },
+    {
+        value: false,
+    },
+]"#
+    );
+}
@@ -23,6 +23,10 @@ pub enum Value {
     /// Represented with numeric string. We cannot use the JSON native numeric type, because
     /// it cannot store large values like `2^253`.
     Field(BigInt),
+    /// The `zksync::Address` value. Represented with a `0x`-prefixed hexadecimal string.
+    Address(BigInt),
+    /// The `zksync::Balance` value. Represented with a decimal string.
+    Balance(BigInt),
 }
 
 impl Value {
@@ -38,7 +42,10 @@ impl Value {
                     BigInt::zero()
                 }
             }
-            Self::Field(value) | Self::Integer(value, _) => value.clone(),
+            Self::Field(value)
+            | Self::Integer(value, _)
+            | Self::Address(value)
+            | Self::Balance(value) => value.clone(),
         }
     }
 }
@@ -0,0 +1,199 @@
+//!
+//! The template value validator.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod violation;
+
+use crate::data::r#type::Type;
+use crate::data::value::Value;
+use crate::error::Error;
+
+use self::violation::Violation;
+
+///
+/// The template value validator.
+///
+/// Checks the JSON value against the template type before it is parsed, collecting all the
+/// mismatches with their JSON pointers instead of stopping at the first one.
+///
+#[derive(Debug, Default)]
+pub struct Validator {
+    /// The violations found so far.
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    ///
+    /// Checks the JSON `value` against the template `r#type`, returning all the violations.
+    ///
+    /// The scalar values are checked by the template value parser, so a value without violations
+    /// is always parsed successfully.
+    ///
+    pub fn validate(value: &serde_json::Value, r#type: &Type) -> Vec<Violation> {
+        let mut validator = Self::default();
+        validator.value(String::new(), value, r#type);
+        validator.violations
+    }
+
+    ///
+    /// Descends into the JSON `value` at `pointer`, checking it against `r#type`.
+    ///
+    fn value(&mut self, pointer: String, value: &serde_json::Value, r#type: &Type) {
+        match (r#type, value) {
+            (Type::Array(element_type, size), serde_json::Value::Array(elements)) => {
+                if elements.len() != *size {
+                    self.push_size(pointer.as_str(), r#type, elements.len());
+                }
+
+                for (index, element) in elements.iter().take(*size).enumerate() {
+                    self.value(Self::index(pointer.as_str(), index), element, element_type);
+                }
+            }
+            (Type::Tuple(types), serde_json::Value::Array(elements)) => {
+                if elements.len() != types.len() {
+                    self.push_size(pointer.as_str(), r#type, elements.len());
+                }
+
+                for (index, (element, r#type)) in elements.iter().zip(types.iter()).enumerate() {
+                    self.value(Self::index(pointer.as_str(), index), element, r#type);
+                }
+            }
+            (Type::Structure(fields), serde_json::Value::Object(object)) => self.fields(
+                pointer.as_str(),
+                object,
+                fields
+                    .iter()
+                    .filter(|(name, _type)| {
+                        name.as_str() != zinc_lexical::Keyword::SelfLowercase.to_string()
+                    })
+                    .map(|(name, r#type)| (name.as_str(), r#type))
+                    .collect(),
+            ),
+            (Type::Contract(fields), serde_json::Value::Object(object)) => self.fields(
+                pointer.as_str(),
+                object,
+                fields
+                    .iter()
+                    .map(|field| (field.name.as_str(), &field.r#type))
+                    .collect(),
+            ),
+            (
+                Type::Map {
+                    key_type,
+                    value_type,
+                },
+                serde_json::Value::Array(entries),
+            ) => {
+                for (index, entry) in entries.iter().enumerate() {
+                    let pointer = Self::index(pointer.as_str(), index);
+                    match entry {
+                        serde_json::Value::Object(object) => {
+                            self.entry(pointer.as_str(), object, "key", key_type);
+                            self.entry(pointer.as_str(), object, "value", value_type);
+                        }
+                        entry => self.push(
+                            pointer,
+                            "{ key, value }".to_owned(),
+                            Error::describe(entry),
+                        ),
+                    }
+                }
+            }
+            (Type::Array(..), value)
+            | (Type::Tuple(..), value)
+            | (Type::Structure(..), value)
+            | (Type::Contract(..), value)
+            | (Type::Map { .. }, value) => {
+                self.push(pointer, r#type.to_string(), Error::describe(value))
+            }
+            (r#type, value) => {
+                if Value::try_from_typed_json(value.to_owned(), r#type.to_owned()).is_err() {
+                    self.push(pointer, r#type.to_string(), Error::describe(value));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Checks the JSON `object` at `pointer` against the structure or contract `fields`.
+    ///
+    /// Both the missing and unknown fields are reported.
+    ///
+    fn fields(
+        &mut self,
+        pointer: &str,
+        object: &serde_json::Map<String, serde_json::Value>,
+        fields: Vec<(&str, &Type)>,
+    ) {
+        for (name, r#type) in fields.iter() {
+            let pointer = Self::field(pointer, name);
+            match object.get(*name) {
+                Some(value) => self.value(pointer, value, r#type),
+                None => self.push(pointer, r#type.to_string(), "nothing".to_owned()),
+            }
+        }
+
+        for (name, value) in object.iter() {
+            if !fields.iter().any(|(field, _type)| field == name) {
+                self.push(
+                    Self::field(pointer, name.as_str()),
+                    "no field".to_owned(),
+                    Error::describe(value),
+                );
+            }
+        }
+    }
+
+    ///
+    /// Checks the `name` field of the map entry `object` at `pointer` against `r#type`.
+    ///
+    fn entry(
+        &mut self,
+        pointer: &str,
+        object: &serde_json::Map<String, serde_json::Value>,
+        name: &str,
+        r#type: &Type,
+    ) {
+        let pointer = Self::field(pointer, name);
+        match object.get(name) {
+            Some(value) => self.value(pointer, value, r#type),
+            None => self.push(pointer, r#type.to_string(), "nothing".to_owned()),
+        }
+    }
+
+    ///
+    /// Reports the array or tuple at `pointer`, whose number of elements does not match `r#type`.
+    ///
+    fn push_size(&mut self, pointer: &str, r#type: &Type, size: usize) {
+        self.push(
+            pointer.to_owned(),
+            r#type.to_string(),
+            format!("array of {} elements", size),
+        );
+    }
+
+    ///
+    /// Reports the violation at `pointer`.
+    ///
+    fn push(&mut self, pointer: String, expected: String, found: String) {
+        self.violations
+            .push(Violation::new(pointer, expected, found));
+    }
+
+    ///
+    /// Appends the array `index` to the JSON `pointer`.
+    ///
+    fn index(pointer: &str, index: usize) -> String {
+        format!("{}/{}", pointer, index)
+    }
+
+    ///
+    /// Appends the object field `name` to the JSON `pointer`, escaping it as RFC 6901 requires.
+    ///
+    fn field(pointer: &str, name: &str) -> String {
+        format!("{}/{}", pointer, name.replace('~', "~0").replace('/', "~1"))
+    }
+}
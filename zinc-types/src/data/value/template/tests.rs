@@ -0,0 +1,168 @@
+//!
+//! The template value validator tests.
+//!
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::template::violation::Violation;
+use crate::data::value::template::Validator;
+
+fn r#type() -> Type {
+    Type::Structure(vec![
+        (
+            "amount".to_owned(),
+            Type::Scalar(ScalarType::Integer(IntegerType::new(false, 64))),
+        ),
+        (
+            "recipients".to_owned(),
+            Type::Array(Box::new(Type::Address), 3),
+        ),
+        (
+            "memo".to_owned(),
+            Type::Structure(vec![(
+                "is_urgent".to_owned(),
+                Type::Scalar(ScalarType::Boolean),
+            )]),
+        ),
+    ])
+}
+
+#[test]
+fn ok_valid() {
+    let value = serde_json::json!({
+        "amount": "1000",
+        "recipients": [
+            "0x0000000000000000000000000000000000000001",
+            "0x0000000000000000000000000000000000000002",
+            "0x0000000000000000000000000000000000000003",
+        ],
+        "memo": { "is_urgent": false },
+    });
+
+    assert_eq!(Validator::validate(&value, &r#type()), vec![]);
+}
+
+#[test]
+fn error_scalar_type() {
+    let value = serde_json::json!({
+        "amount": true,
+        "recipients": [
+            "0x0000000000000000000000000000000000000001",
+            "0x0000000000000000000000000000000000000002",
+            "0x0000000000000000000000000000000000000003",
+        ],
+        "memo": { "is_urgent": "yes" },
+    });
+
+    assert_eq!(
+        Validator::validate(&value, &r#type()),
+        vec![
+            Violation::new(
+                "/amount".to_owned(),
+                "u64".to_owned(),
+                "boolean (true)".to_owned(),
+            ),
+            Violation::new(
+                "/memo/is_urgent".to_owned(),
+                "bool".to_owned(),
+                "string (\"yes\")".to_owned(),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn error_short_array() {
+    let value = serde_json::json!({
+        "amount": "1000",
+        "recipients": [
+            "0x0000000000000000000000000000000000000001",
+            "255",
+        ],
+        "memo": { "is_urgent": false },
+    });
+
+    assert_eq!(
+        Validator::validate(&value, &r#type()),
+        vec![
+            Violation::new(
+                "/recipients".to_owned(),
+                "[zksync::Address; 3]".to_owned(),
+                "array of 2 elements".to_owned(),
+            ),
+            Violation::new(
+                "/recipients/1".to_owned(),
+                "zksync::Address".to_owned(),
+                "string (\"255\")".to_owned(),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn error_unknown_structure_field() {
+    let value = serde_json::json!({
+        "amount": "1000",
+        "recipients": [
+            "0x0000000000000000000000000000000000000001",
+            "0x0000000000000000000000000000000000000002",
+            "0x0000000000000000000000000000000000000003",
+        ],
+        "memo": { "is_urgent": false, "note/text": "hello" },
+    });
+
+    assert_eq!(
+        Validator::validate(&value, &r#type()),
+        vec![Violation::new(
+            "/memo/note~1text".to_owned(),
+            "no field".to_owned(),
+            "string (\"hello\")".to_owned(),
+        )]
+    );
+}
+
+#[test]
+fn error_missing_structure_field() {
+    let value = serde_json::json!({
+        "amount": "1000",
+        "memo": 42,
+    });
+
+    assert_eq!(
+        Validator::validate(&value, &r#type()),
+        vec![
+            Violation::new(
+                "/recipients".to_owned(),
+                "[zksync::Address; 3]".to_owned(),
+                "nothing".to_owned(),
+            ),
+            Violation::new(
+                "/memo".to_owned(),
+                "is_urgent: bool".to_owned(),
+                "number (42)".to_owned(),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn ok_list_aligned() {
+    let violations = vec![
+        Violation::new(
+            "/amount".to_owned(),
+            "u64".to_owned(),
+            "boolean (true)".to_owned(),
+        ),
+        Violation::new(
+            "/memo/is_urgent".to_owned(),
+            "bool".to_owned(),
+            "number (1)".to_owned(),
+        ),
+    ];
+
+    assert_eq!(
+        Violation::list(violations.as_slice()),
+        "    /amount          expected `u64`, found boolean (true)\n    /memo/is_urgent  expected `bool`, found number (1)",
+    );
+}
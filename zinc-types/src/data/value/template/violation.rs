@@ -0,0 +1,82 @@
+//!
+//! The template value violation.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The template value violation, that is, a JSON value, which does not match the template type.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    /// The JSON pointer of the mismatched value, e.g. `/transfers/2/amount`.
+    pub pointer: String,
+    /// The type expected by the template.
+    pub expected: String,
+    /// The received JSON token.
+    pub found: String,
+}
+
+impl Violation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(pointer: String, expected: String, found: String) -> Self {
+        Self {
+            pointer,
+            expected,
+            found,
+        }
+    }
+
+    ///
+    /// Renders the `violations` as a list, one per line, with the pointers aligned.
+    ///
+    pub fn list(violations: &[Self]) -> String {
+        let width = violations
+            .iter()
+            .map(|violation| violation.pointer_or_root().len())
+            .max()
+            .unwrap_or_default();
+
+        violations
+            .iter()
+            .map(|violation| {
+                format!(
+                    "    {:<width$}  expected `{}`, found {}",
+                    violation.pointer_or_root(),
+                    violation.expected,
+                    violation.found,
+                    width = width,
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    ///
+    /// Returns the pointer, or `/` for the whole value, whose pointer is empty.
+    ///
+    fn pointer_or_root(&self) -> &str {
+        if self.pointer.is_empty() {
+            "/"
+        } else {
+            self.pointer.as_str()
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}`: expected `{}`, found {}",
+            self.pointer_or_root(),
+            self.expected,
+            self.found
+        )
+    }
+}
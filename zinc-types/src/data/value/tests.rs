@@ -0,0 +1,211 @@
+//!
+//! The template value tests.
+//!
+
+use num::BigInt;
+use num::One;
+
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::data::value::scalar::Value as ScalarValue;
+use crate::data::value::Value;
+
+#[test]
+fn ok_address_into_json() {
+    let value = Value::Scalar(ScalarValue::Address(BigInt::from(0x42)));
+
+    assert_eq!(
+        value.into_json(),
+        serde_json::json!("0x0000000000000000000000000000000000000042"),
+    );
+}
+
+#[test]
+fn ok_balance_into_json() {
+    let value = Value::Scalar(ScalarValue::Balance(
+        BigInt::from(10).pow(18) * BigInt::from(5),
+    ));
+
+    assert_eq!(value.into_json(), serde_json::json!("5000000000000000000"));
+}
+
+#[test]
+fn ok_address_from_json() {
+    let value = Value::try_from_typed_json(serde_json::json!("0xff"), Type::Address)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(value.into_flat_values(), vec![BigInt::from(0xff)]);
+}
+
+#[test]
+fn ok_balance_from_json() {
+    let value = Value::try_from_typed_json(serde_json::json!("1000"), Type::Balance)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(value.into_flat_values(), vec![BigInt::from(1000)]);
+}
+
+#[test]
+fn ok_address_json_round_trip() {
+    let json = serde_json::json!("0x00000000000000000000000000000000deadbeef");
+
+    let value = Value::try_from_typed_json(json.clone(), Type::Address)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let value = Value::from_flat_values(Type::Address, value.into_flat_values().as_slice());
+
+    assert_eq!(value.into_json(), json);
+}
+
+#[test]
+fn ok_default_values() {
+    assert_eq!(
+        Value::new(Type::Address).into_json(),
+        serde_json::json!("0x0000000000000000000000000000000000000000"),
+    );
+    assert_eq!(
+        Value::new(Type::Balance).into_json(),
+        serde_json::json!("0")
+    );
+}
+
+#[test]
+fn error_address_from_decimal_json() {
+    let result = Value::try_from_typed_json(serde_json::json!("255"), Type::Address);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn error_address_overflow() {
+    let value = format!("0x{}", "f".repeat(zinc_const::size::ETH_ADDRESS * 2 + 1));
+
+    let result = Value::try_from_typed_json(serde_json::json!(value), Type::Address);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn error_balance_from_hexadecimal_json() {
+    let result = Value::try_from_typed_json(serde_json::json!("0xff"), Type::Balance);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn error_balance_overflow() {
+    let value = (BigInt::one() << zinc_const::bitlength::BALANCE).to_string();
+
+    let result = Value::try_from_typed_json(serde_json::json!(value), Type::Balance);
+
+    assert!(result.is_err());
+}
+
+fn flags_type() -> Type {
+    Type::Flags {
+        bitlength: zinc_const::bitlength::BYTE,
+        variants: vec![
+            ("Read".to_owned(), BigInt::from(1)),
+            ("Write".to_owned(), BigInt::from(2)),
+            ("Execute".to_owned(), BigInt::from(4)),
+        ],
+    }
+}
+
+#[test]
+fn ok_flags_from_json() {
+    let value = Value::try_from_typed_json(serde_json::json!(["Read", "Execute"]), flags_type())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(value.into_flat_values(), vec![BigInt::from(5)]);
+}
+
+#[test]
+fn ok_flags_json_round_trip() {
+    let json = serde_json::json!(["Write", "Execute"]);
+
+    let value = Value::try_from_typed_json(json.clone(), flags_type())
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let value = Value::from_flat_values(flags_type(), value.into_flat_values().as_slice());
+
+    assert_eq!(value.into_json(), json);
+}
+
+#[test]
+fn ok_flags_default_empty() {
+    assert_eq!(Value::new(flags_type()).into_json(), serde_json::json!([]));
+}
+
+#[test]
+fn error_flags_unknown_variant() {
+    let result = Value::try_from_typed_json(serde_json::json!(["Read", "Delete"]), flags_type());
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn ok_structure_into_json_declaration_order() {
+    let value = Value::new(Type::Structure(vec![
+        ("zeta".to_owned(), Type::Scalar(ScalarType::Boolean)),
+        ("alpha".to_owned(), Type::Address),
+        ("middle".to_owned(), Type::Balance),
+    ]));
+
+    let json = serde_json::to_string(&value.into_json()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(
+        json,
+        r#"{"zeta":false,"alpha":"0x0000000000000000000000000000000000000000","middle":"0"}"#,
+    );
+}
+
+fn nested_structure(value: u8, flags: [bool; 2]) -> Value {
+    let r#type = Type::Structure(vec![
+        (
+            "inner".to_owned(),
+            Type::Structure(vec![(
+                "value".to_owned(),
+                Type::Scalar(ScalarType::Integer(IntegerType::U8)),
+            )]),
+        ),
+        (
+            "flags".to_owned(),
+            Type::Array(Box::new(Type::Scalar(ScalarType::Boolean)), 2),
+        ),
+    ]);
+
+    let flat_values = vec![
+        BigInt::from(value),
+        BigInt::from(flags[0] as u8),
+        BigInt::from(flags[1] as u8),
+    ];
+
+    Value::from_flat_values(r#type, flat_values.as_slice())
+}
+
+#[test]
+fn ok_diff_equal() {
+    let actual = nested_structure(42, [true, false]);
+    let expected = nested_structure(42, [true, false]);
+
+    assert_eq!(Value::diff(&actual, &expected), None);
+}
+
+#[test]
+fn ok_diff_structure() {
+    let actual = nested_structure(42, [true, false]);
+    let expected = nested_structure(25, [true, true]);
+
+    assert_eq!(
+        Value::diff(&actual, &expected),
+        Some(
+            [
+                "-   actual.inner.value = \"42\"",
+                "+ expected.inner.value = \"25\"",
+                "-   actual.flags[1] = false",
+                "+ expected.flags[1] = true",
+            ]
+            .join("\n")
+        ),
+    );
+}
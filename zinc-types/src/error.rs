@@ -13,6 +13,10 @@ pub enum Error {
     #[error("{0}")]
     TypeMath(#[from] zinc_math::Error),
 
+    /// The scalar value parsing error.
+    #[error("{0}")]
+    ScalarParsing(#[from] crate::scalar::error::Error),
+
     /// The primitive value does not match the expected primitive type.
     #[error("type mismatch: expected `{expected}`, found `{found}`")]
     TypeError {
@@ -57,15 +61,23 @@ impl Error {
     /// A shortcut constructor.
     ///
     pub fn type_error(expected: String, found: serde_json::Value) -> Self {
-        let found: String = match found {
+        Self::TypeError {
+            expected,
+            found: Self::describe(&found),
+        }
+    }
+
+    ///
+    /// Describes the JSON `value` token, e.g. `string ("42")` or `array`.
+    ///
+    pub fn describe(value: &serde_json::Value) -> String {
+        match value {
             serde_json::Value::Null => "null".into(),
             serde_json::Value::Bool(value) => format!("boolean ({})", value),
             serde_json::Value::Number(value) => format!("number ({})", value),
             serde_json::Value::String(value) => format!("string (\"{}\")", value),
             serde_json::Value::Array(_) => "array".into(),
             serde_json::Value::Object(_) => "structure".into(),
-        };
-
-        Self::TypeError { expected, found }
+        }
     }
 }
@@ -0,0 +1,56 @@
+//!
+//! The `assert_eq` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+///
+/// The `assert_eq` instruction.
+///
+/// Pops two values of `r#type` from the evaluation stack and fails with a rendered difference
+/// if they are not equal. Is only available in unit tests.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssertEq {
+    /// The type of the compared values.
+    pub r#type: Type,
+    /// The optional error message.
+    pub message: Option<String>,
+}
+
+impl AssertEq {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(r#type: Type, message: Option<String>) -> Self {
+        Self { r#type, message }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for AssertEq {
+    fn into(self) -> Instruction {
+        Instruction::AssertEq(self)
+    }
+}
+
+impl fmt::Display for AssertEq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            None => write!(f, "assert_eq {}", self.r#type),
+            Some(text) => write!(f, "assert_eq {} \"{}\"", self.r#type, text),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//!
+//! The `assert_storage_unchanged` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `assert_storage_unchanged` instruction.
+///
+/// Fails if the storage of any contract instance differs from its snapshot taken when the
+/// instance was created or fetched. Is only available in unit tests.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AssertStorageUnchanged;
+
+impl AssertStorageUnchanged {
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for AssertStorageUnchanged {
+    fn into(self) -> Instruction {
+        Instruction::AssertStorageUnchanged(self)
+    }
+}
+
+impl fmt::Display for AssertStorageUnchanged {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assert_storage_unchanged")
+    }
+}
@@ -16,6 +16,8 @@ pub enum LibraryFunctionIdentifier {
     CryptoPedersen,
     /// The `std::crypto::schnorr::Signature::verify` function identifier.
     CryptoSchnorrSignatureVerify,
+    /// The `std::crypto::verify_proof` function identifier.
+    CryptoVerifyProof,
 
     /// The `std::convert::to_bits` function identifier.
     ConvertToBits,
@@ -25,6 +27,14 @@ pub enum LibraryFunctionIdentifier {
     ConvertFromBitsSigned,
     /// The `std::convert::from_bits_field` function identifier.
     ConvertFromBitsField,
+    /// The `std::convert::try_into_unsigned` function identifier.
+    ConvertTryIntoUnsigned,
+    /// The `std::convert::try_cast` function identifier.
+    ConvertTryCast,
+    /// The `std::convert::pack_bits` function identifier.
+    ConvertPackBits,
+    /// The `std::convert::unpack_bits` function identifier.
+    ConvertUnpackBits,
 
     /// The `std::array::reverse` function identifier.
     ArrayReverse,
@@ -32,13 +42,27 @@ pub enum LibraryFunctionIdentifier {
     ArrayTruncate,
     /// The `std::array::pad` function identifier.
     ArrayPad,
+    /// The `std::array::concat` function identifier.
+    ArrayConcat,
+    /// The `std::array::binary_search` function identifier.
+    ArrayBinarySearch,
+    /// The `std::array::is_sorted` function identifier.
+    ArrayIsSorted,
 
     /// The `std::ff::invert` function identifier.
     FfInvert,
 
+    /// The `std::test::rand_u` function identifier.
+    TestRandU,
+
     /// The `<Contract>::transfer` function identifier.
     ContractTransfer,
 
+    /// The `<Enumeration>::from` function identifier.
+    EnumerationFrom,
+    /// The `as <Enumeration>` casting identifier.
+    EnumerationCast,
+
     /// The `std::collections::MTreeMap::get` function identifier.
     CollectionsMTreeMapGet,
     /// The `std::collections::MTreeMap::contains` function identifier.
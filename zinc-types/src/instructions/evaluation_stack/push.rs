@@ -17,6 +17,7 @@ use crate::instructions::Instruction;
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Push {
     /// The constant value.
+    #[serde(deserialize_with = "crate::bounded::deserialize_bigint")]
     pub value: BigInt,
     /// The constant type.
     pub scalar_type: ScalarType,
@@ -2,6 +2,7 @@
 //! The bytecode instruction.
 //!
 
+pub mod assertion;
 pub mod call_library;
 pub mod contract;
 pub mod data_stack;
@@ -12,12 +13,15 @@ pub mod marker;
 pub mod noop;
 pub mod operator;
 pub mod require;
+pub mod unsafe_witness;
 
 use std::fmt;
 
 use serde::Deserialize;
 use serde::Serialize;
 
+use self::assertion::eq::AssertEq;
+use self::assertion::storage_unchanged::AssertStorageUnchanged;
 use self::call_library::CallLibrary;
 use self::contract::fetch::StorageFetch;
 use self::contract::init::StorageInit;
@@ -67,6 +71,8 @@ use self::operator::logical::not::Not;
 use self::operator::logical::or::Or;
 use self::operator::logical::xor::Xor;
 use self::require::Require;
+use self::unsafe_witness::begin::UnsafeWitnessBegin;
+use self::unsafe_witness::end::UnsafeWitnessEnd;
 
 ///
 /// The bytecode instruction.
@@ -174,6 +180,16 @@ pub enum Instruction {
     /// The standard library function call instruction.
     CallLibrary(CallLibrary),
 
+    /// An unconstrained witness computation instruction.
+    UnsafeWitnessBegin(UnsafeWitnessBegin),
+    /// An unconstrained witness computation instruction.
+    UnsafeWitnessEnd(UnsafeWitnessEnd),
+
+    /// A unit test assertion instruction.
+    AssertEq(AssertEq),
+    /// A unit test assertion instruction.
+    AssertStorageUnchanged(AssertStorageUnchanged),
+
     /// A debug location marker instruction.
     FileMarker(FileMarker),
     /// A debug location marker instruction.
@@ -246,6 +262,12 @@ impl Instruction {
             Self::Require(inner) => inner.is_debug(),
             Self::CallLibrary(inner) => inner.is_debug(),
 
+            Self::UnsafeWitnessBegin(inner) => inner.is_debug(),
+            Self::UnsafeWitnessEnd(inner) => inner.is_debug(),
+
+            Self::AssertEq(inner) => inner.is_debug(),
+            Self::AssertStorageUnchanged(inner) => inner.is_debug(),
+
             Self::FileMarker(inner) => inner.is_debug(),
             Self::FunctionMarker(inner) => inner.is_debug(),
             Self::LineMarker(inner) => inner.is_debug(),
@@ -313,6 +335,12 @@ impl fmt::Display for Instruction {
             Self::Require(inner) => write!(f, "{}", inner),
             Self::CallLibrary(inner) => write!(f, "{}", inner),
 
+            Self::UnsafeWitnessBegin(inner) => write!(f, "{}", inner),
+            Self::UnsafeWitnessEnd(inner) => write!(f, "{}", inner),
+
+            Self::AssertEq(inner) => write!(f, "{}", inner),
+            Self::AssertStorageUnchanged(inner) => write!(f, "{}", inner),
+
             Self::FileMarker(inner) => write!(f, "{}", inner),
             Self::FunctionMarker(inner) => write!(f, "{}", inner),
             Self::LineMarker(inner) => write!(f, "{}", inner),
@@ -7,15 +7,26 @@ use std::fmt;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::instructions::operator::range_check::RangeCheck;
 use crate::instructions::Instruction;
 
 ///
 /// The `arithmetic subtraction` instruction.
 ///
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Sub;
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Sub {
+    /// The result range check.
+    pub range_check: RangeCheck,
+}
 
 impl Sub {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(range_check: RangeCheck) -> Self {
+        Self { range_check }
+    }
+
     ///
     /// If the instruction is for the debug mode only.
     ///
@@ -32,6 +43,6 @@ impl Into<Instruction> for Sub {
 
 impl fmt::Display for Sub {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "sub")
+        write!(f, "sub{}", self.range_check.suffix())
     }
 }
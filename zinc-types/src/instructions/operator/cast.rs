@@ -8,6 +8,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::data::r#type::scalar::Type as ScalarType;
+use crate::instructions::operator::range_check::RangeCheck;
 use crate::instructions::Instruction;
 
 ///
@@ -17,6 +18,8 @@ use crate::instructions::Instruction;
 pub struct Cast {
     /// The type casted to.
     pub r#type: ScalarType,
+    /// The result range check.
+    pub range_check: RangeCheck,
 }
 
 impl Cast {
@@ -24,7 +27,10 @@ impl Cast {
     /// A shortcut constructor.
     ///
     pub fn new(r#type: ScalarType) -> Self {
-        Self { r#type }
+        Self {
+            r#type,
+            range_check: RangeCheck::Enforced,
+        }
     }
 
     ///
@@ -43,6 +49,6 @@ impl Into<Instruction> for Cast {
 
 impl fmt::Display for Cast {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "cast {}", self.r#type)
+        write!(f, "cast {}{}", self.r#type, self.range_check.suffix())
     }
 }
@@ -7,3 +7,4 @@ pub mod bitwise;
 pub mod cast;
 pub mod comparison;
 pub mod logical;
+pub mod range_check;
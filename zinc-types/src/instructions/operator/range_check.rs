@@ -0,0 +1,42 @@
+//!
+//! The operator result range check.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The way the operator result is checked to fit its type.
+///
+/// The compiler marks the check as redundant if the operand bounds prove that the result always
+/// fits its type, e.g. after `require(x < 100)` the `x + 1` of type `u8` cannot overflow.
+///
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RangeCheck {
+    /// The range check constraints are created.
+    Enforced,
+    /// The range check is proven redundant, so its constraints are not created.
+    Elided,
+    /// The range check is proven redundant, but its constraints are still created, and the VM
+    /// reports the violation as a bound analysis bug. Used to cross-check the analysis.
+    Asserted,
+}
+
+impl RangeCheck {
+    ///
+    /// Returns the instruction suffix describing the check, if it is not enforced.
+    ///
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Self::Enforced => "",
+            Self::Elided => " unchecked",
+            Self::Asserted => " asserted",
+        }
+    }
+}
+
+impl Default for RangeCheck {
+    fn default() -> Self {
+        Self::Enforced
+    }
+}
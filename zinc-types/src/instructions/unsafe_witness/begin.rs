@@ -0,0 +1,40 @@
+//!
+//! The `unsafe witness begin` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `unsafe witness begin` instruction.
+///
+/// The instructions until the matching `UnsafeWitnessEnd` are evaluated only to compute the
+/// witness values, and do not contribute any constraints.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnsafeWitnessBegin;
+
+impl UnsafeWitnessBegin {
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for UnsafeWitnessBegin {
+    fn into(self) -> Instruction {
+        Instruction::UnsafeWitnessBegin(self)
+    }
+}
+
+impl fmt::Display for UnsafeWitnessBegin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsafe_witness_begin")
+    }
+}
@@ -0,0 +1,51 @@
+//!
+//! The `unsafe witness end` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::instructions::Instruction;
+
+///
+/// The `unsafe witness end` instruction.
+///
+/// Takes the values computed since the matching `UnsafeWitnessBegin` from the evaluation stack
+/// and allocates them as new witnesses, checked only against their types.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct UnsafeWitnessEnd {
+    /// The types of the witness values on the top of the evaluation stack.
+    pub output_types: Vec<ScalarType>,
+}
+
+impl UnsafeWitnessEnd {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(output_types: Vec<ScalarType>) -> Self {
+        Self { output_types }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl Into<Instruction> for UnsafeWitnessEnd {
+    fn into(self) -> Instruction {
+        Instruction::UnsafeWitnessEnd(self)
+    }
+}
+
+impl fmt::Display for UnsafeWitnessEnd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsafe_witness_end {}", self.output_types.len())
+    }
+}
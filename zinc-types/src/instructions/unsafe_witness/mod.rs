@@ -0,0 +1,6 @@
+//!
+//! The unconstrained witness instructions.
+//!
+
+pub mod begin;
+pub mod end;
@@ -0,0 +1,136 @@
+//!
+//! The proving and verifying keys tools.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::convert::TryInto;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use sha2::Digest;
+
+/// The magic bytes opening the key file footer.
+static KEY_FOOTER_MAGIC: &[u8] = b"ZINC_KEY";
+
+///
+/// Computes the circuit hash, which is the SHA-256 digest of the application `bytecode`.
+///
+/// The trusted setup writes the circuit hash at the beginning of both keys, so the keys can be
+/// checked against the bytecode they have been generated for.
+///
+pub fn circuit_hash(bytecode: &[u8]) -> Vec<u8> {
+    sha2::Sha256::digest(bytecode).to_vec()
+}
+
+///
+/// Extracts the circuit hash from the proving or verifying `key`.
+///
+/// Returns `None` if the key is too short to contain the hash.
+///
+pub fn key_circuit_hash(key: &[u8]) -> Option<&[u8]> {
+    key.get(..zinc_const::size::SHA256_HASH)
+}
+
+///
+/// Appends the footer to the proving or verifying `key`, which marks the key file as complete.
+///
+/// The footer consists of the magic bytes and the little-endian length of the key, and is
+/// written last, so a file truncated at any offset does not have a valid footer.
+///
+pub fn key_with_footer(mut key: Vec<u8>) -> Vec<u8> {
+    let length = key.len() as u64;
+    key.extend_from_slice(KEY_FOOTER_MAGIC);
+    key.extend_from_slice(length.to_le_bytes().as_ref());
+    key
+}
+
+///
+/// Strips the footer from the proving or verifying key `file` contents.
+///
+/// Returns `None` if the footer is missing or does not match the key length, that is, if the
+/// file has been truncated, e.g. by an interrupted trusted setup.
+///
+pub fn key_without_footer(file: &[u8]) -> Option<&[u8]> {
+    let length = file.len().checked_sub(zinc_const::size::KEY_FOOTER)?;
+    let (key, footer) = file.split_at(length);
+
+    if is_key_footer_valid(footer, file.len() as u64) {
+        Some(key)
+    } else {
+        None
+    }
+}
+
+///
+/// Checks whether the last bytes of the key file are a valid `footer` for the file of
+/// `file_length` bytes.
+///
+/// Allows to check a large proving key file without reading it completely.
+///
+pub fn is_key_footer_valid(footer: &[u8], file_length: u64) -> bool {
+    if footer.len() != zinc_const::size::KEY_FOOTER {
+        return false;
+    }
+
+    let (magic, length) = footer.split_at(KEY_FOOTER_MAGIC.len());
+    let length = match length.try_into() {
+        Ok(length) => u64::from_le_bytes(length),
+        Err(_) => return false,
+    };
+
+    magic == KEY_FOOTER_MAGIC
+        && file_length.checked_sub(zinc_const::size::KEY_FOOTER as u64) == Some(length)
+}
+
+///
+/// Reads the footer of the proving or verifying key file `reader` and returns the length of the
+/// key without the footer.
+///
+/// Only the footer is read, so the check is cheap even for large proving keys.
+/// Returns `None` if the footer is missing or does not match the key length.
+///
+pub fn read_key_length<R: Read + Seek>(reader: &mut R) -> io::Result<Option<u64>> {
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    if file_length < zinc_const::size::KEY_FOOTER as u64 {
+        return Ok(None);
+    }
+
+    let mut footer = vec![0; zinc_const::size::KEY_FOOTER];
+    reader.seek(SeekFrom::End(-(zinc_const::size::KEY_FOOTER as i64)))?;
+    reader.read_exact(footer.as_mut_slice())?;
+
+    if is_key_footer_valid(footer.as_slice(), file_length) {
+        Ok(Some(file_length - zinc_const::size::KEY_FOOTER as u64))
+    } else {
+        Ok(None)
+    }
+}
+
+///
+/// Reads the proving or verifying key without the footer from the key file `reader`.
+///
+/// The footer is checked before the key is read, and the key is read directly into a buffer of
+/// its exact size, so a large proving key is neither read when the file is incomplete nor held
+/// in memory twice.
+/// Returns `None` if the footer is missing or does not match the key length.
+///
+pub fn read_key<R: Read + Seek>(mut reader: R) -> io::Result<Option<Vec<u8>>> {
+    let length = match read_key_length(&mut reader)? {
+        Some(length) => length,
+        None => return Ok(None),
+    };
+
+    let mut key = Vec::with_capacity(length as usize);
+    reader.seek(SeekFrom::Start(0))?;
+    reader.by_ref().take(length).read_to_end(&mut key)?;
+
+    if key.len() as u64 == length {
+        Ok(Some(key))
+    } else {
+        Ok(None)
+    }
+}
@@ -0,0 +1,138 @@
+//!
+//! The proving and verifying keys tools tests.
+//!
+
+use std::io::Cursor;
+
+use crate::keys::circuit_hash;
+use crate::keys::is_key_footer_valid;
+use crate::keys::key_circuit_hash;
+use crate::keys::key_with_footer;
+use crate::keys::key_without_footer;
+use crate::keys::read_key;
+use crate::keys::read_key_length;
+
+#[test]
+fn ok_key_circuit_hash() {
+    let bytecode = vec![1, 2, 3];
+
+    let mut key = circuit_hash(bytecode.as_slice());
+    key.extend(vec![0; 1024]);
+
+    assert_eq!(
+        key_circuit_hash(key.as_slice()),
+        Some(circuit_hash(bytecode.as_slice()).as_slice())
+    );
+}
+
+#[test]
+fn ok_circuit_hash_changes() {
+    assert_ne!(circuit_hash(&[1, 2, 3]), circuit_hash(&[1, 2, 4]));
+}
+
+#[test]
+fn error_key_too_short() {
+    assert_eq!(key_circuit_hash(&[0; 16]), None);
+}
+
+#[test]
+fn ok_key_footer_round_trip() {
+    let mut key = circuit_hash(&[1, 2, 3]);
+    key.extend(vec![42; 1024]);
+
+    let file = key_with_footer(key.clone());
+
+    assert_eq!(key_without_footer(file.as_slice()), Some(key.as_slice()));
+}
+
+#[test]
+fn ok_key_footer_valid() {
+    let file = key_with_footer(vec![42; 1024]);
+    let footer = &file[file.len() - zinc_const::size::KEY_FOOTER..];
+
+    assert!(is_key_footer_valid(footer, file.len() as u64));
+}
+
+#[test]
+fn error_key_truncated() {
+    let mut key = circuit_hash(&[1, 2, 3]);
+    key.extend((0..1024).map(|index| index as u8));
+    let file = key_with_footer(key);
+
+    for offset in vec![
+        0,
+        1,
+        zinc_const::size::SHA256_HASH,
+        file.len() / 2,
+        file.len() - zinc_const::size::KEY_FOOTER,
+        file.len() - zinc_const::size::KEY_FOOTER / 2,
+        file.len() - 1,
+    ]
+    .into_iter()
+    {
+        assert_eq!(
+            key_without_footer(&file[..offset]),
+            None,
+            "the key truncated at {} must be rejected",
+            offset
+        );
+    }
+}
+
+#[test]
+fn error_key_footer_missing() {
+    let mut key = circuit_hash(&[1, 2, 3]);
+    key.extend(vec![0; 1024]);
+
+    assert_eq!(key_without_footer(key.as_slice()), None);
+}
+
+#[test]
+fn ok_read_key() {
+    let mut key = circuit_hash(&[1, 2, 3]);
+    key.extend((0..8192).map(|index| index as u8));
+    let file = key_with_footer(key.clone());
+
+    assert_eq!(
+        read_key_length(&mut Cursor::new(file.as_slice()))
+            .expect(zinc_const::panic::TEST_DATA_VALID),
+        Some(key.len() as u64)
+    );
+    assert_eq!(
+        read_key(Cursor::new(file.as_slice())).expect(zinc_const::panic::TEST_DATA_VALID),
+        key_without_footer(file.as_slice()).map(|key| key.to_vec())
+    );
+}
+
+#[test]
+fn error_read_key_truncated() {
+    let mut key = circuit_hash(&[1, 2, 3]);
+    key.extend((0..1024).map(|index| index as u8));
+    let file = key_with_footer(key);
+
+    for offset in vec![
+        0,
+        1,
+        zinc_const::size::SHA256_HASH,
+        file.len() / 2,
+        file.len() - zinc_const::size::KEY_FOOTER,
+        file.len() - zinc_const::size::KEY_FOOTER / 2,
+        file.len() - 1,
+    ]
+    .into_iter()
+    {
+        assert_eq!(
+            read_key_length(&mut Cursor::new(&file[..offset]))
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            None,
+            "the key truncated at {} must be rejected",
+            offset
+        );
+        assert_eq!(
+            read_key(Cursor::new(&file[..offset])).expect(zinc_const::panic::TEST_DATA_VALID),
+            None,
+            "the key truncated at {} must be rejected",
+            offset
+        );
+    }
+}
@@ -3,31 +3,63 @@
 //!
 
 pub(crate) mod application;
+pub(crate) mod bounded;
 pub(crate) mod build;
+pub(crate) mod coverage;
 pub(crate) mod data;
 pub(crate) mod error;
 pub(crate) mod instructions;
+pub(crate) mod keys;
+pub(crate) mod ownership;
 pub(crate) mod request;
 pub(crate) mod response;
+pub(crate) mod scalar;
 pub(crate) mod transaction;
 pub(crate) mod utils;
 
 pub use self::application::circuit::Circuit;
+pub use self::application::contract::abi::parameter::Parameter as AbiParameter;
+pub use self::application::contract::abi::Entry as AbiEntry;
+pub use self::application::contract::abi::EntryType as AbiEntryType;
+pub use self::application::contract::abi::StateMutability as AbiStateMutability;
+pub use self::application::contract::error::Error as ContractUpgradeError;
 pub use self::application::contract::method::Method as ContractMethod;
+pub use self::application::contract::migration::Migration as ContractMigration;
+pub use self::application::contract::pruning::error::Error as ContractPruningError;
 pub use self::application::contract::Contract;
+pub use self::application::error::Error as ApplicationDecodingError;
+pub use self::application::header::Header as ApplicationHeader;
 pub use self::application::library::Library;
 pub use self::application::unit_test::UnitTest;
 pub use self::application::Application;
+pub use self::build::bindings::language::Language as BindingsLanguage;
+pub use self::build::bindings::Bindings;
+pub use self::build::diff::Diff as BuildDiff;
+pub use self::build::documentation::item::Item as DocumentationItem;
+pub use self::build::documentation::item::Kind as DocumentationItemKind;
+pub use self::build::documentation::item::Member as DocumentationMember;
+pub use self::build::documentation::item::MemberKind as DocumentationMemberKind;
+pub use self::build::documentation::Documentation;
+pub use self::build::documentation::Module as DocumentationModule;
 pub use self::build::input::Input as InputBuild;
 pub use self::build::Build;
+pub use self::coverage::Coverage;
+pub use self::data::r#type::contract_field::leaf::Leaf as ContractStorageLeaf;
 pub use self::data::r#type::contract_field::ContractField as ContractFieldType;
 pub use self::data::r#type::scalar::integer::Type as IntegerType;
 pub use self::data::r#type::scalar::Type as ScalarType;
 pub use self::data::r#type::Type;
 pub use self::data::value::contract_field::ContractField as ContractFieldValue;
+pub use self::data::value::limits::error::Error as ValueLimitError;
+pub use self::data::value::limits::Limits as ValueLimits;
+pub use self::data::value::pretty::Printer as ValuePrinter;
 pub use self::data::value::scalar::Value as ScalarValue;
+pub use self::data::value::template::violation::Violation as ValueViolation;
+pub use self::data::value::template::Validator as ValueValidator;
 pub use self::data::value::Value;
 pub use self::error::Error;
+pub use self::instructions::assertion::eq::AssertEq;
+pub use self::instructions::assertion::storage_unchanged::AssertStorageUnchanged;
 pub use self::instructions::call_library::function_identifier::LibraryFunctionIdentifier;
 pub use self::instructions::call_library::CallLibrary;
 pub use self::instructions::contract::fetch::StorageFetch;
@@ -77,26 +109,76 @@ pub use self::instructions::operator::logical::and::And;
 pub use self::instructions::operator::logical::not::Not;
 pub use self::instructions::operator::logical::or::Or;
 pub use self::instructions::operator::logical::xor::Xor;
+pub use self::instructions::operator::range_check::RangeCheck;
 pub use self::instructions::require::Require;
+pub use self::instructions::unsafe_witness::begin::UnsafeWitnessBegin;
+pub use self::instructions::unsafe_witness::end::UnsafeWitnessEnd;
 pub use self::instructions::Instruction;
+pub use self::keys::circuit_hash;
+pub use self::keys::is_key_footer_valid;
+pub use self::keys::key_circuit_hash;
+pub use self::keys::key_with_footer;
+pub use self::keys::key_without_footer;
+pub use self::keys::read_key;
+pub use self::keys::read_key_length;
+pub use self::ownership::source_digest;
+pub use self::ownership::ContractRemoval;
+pub use self::ownership::OwnerRotation;
+pub use self::ownership::PublishClaim;
+pub use self::request::abi::Path as AbiRequestPath;
+pub use self::request::bytecode::Path as BytecodeRequestPath;
 pub use self::request::call::Body as CallRequestBody;
 pub use self::request::call::Query as CallRequestQuery;
+pub use self::request::dry_run::Body as DryRunRequestBody;
+pub use self::request::dry_run::Query as DryRunRequestQuery;
 pub use self::request::fee::Body as FeeRequestBody;
 pub use self::request::fee::Query as FeeRequestQuery;
 pub use self::request::initialize::Body as InitializeRequestBody;
 pub use self::request::initialize::Query as InitializeRequestQuery;
+pub use self::request::instances::Path as InstancesRequestPath;
+pub use self::request::instances::Query as InstancesRequestQuery;
+pub use self::request::keys::Body as KeysRequestBody;
+pub use self::request::keys::Query as KeysRequestQuery;
+pub use self::request::owner::Body as OwnerRequestBody;
+pub use self::request::owner::Query as OwnerRequestQuery;
 pub use self::request::publish::Body as PublishRequestBody;
 pub use self::request::publish::Query as PublishRequestQuery;
 pub use self::request::query::Body as QueryRequestBody;
 pub use self::request::query::Query as QueryRequestQuery;
+pub use self::request::remove::Body as RemoveRequestBody;
+pub use self::request::remove::Path as RemoveRequestPath;
+pub use self::request::remove::Query as RemoveRequestQuery;
+pub use self::request::search::Query as SearchRequestQuery;
 pub use self::request::source::Query as SourceRequestQuery;
+pub use self::request::storage::Query as StorageRequestQuery;
+pub use self::request::upgrade::Body as UpgradeRequestBody;
+pub use self::request::upgrade::Query as UpgradeRequestQuery;
 pub use self::request::upload::Body as UploadRequestBody;
 pub use self::request::upload::Query as UploadRequestQuery;
+pub use self::response::bytecode::Body as BytecodeResponseBody;
+pub use self::response::data::Body as DataResponseBody;
+pub use self::response::delete_challenge::Body as DeleteChallengeResponseBody;
+pub use self::response::dry_run::Body as DryRunResponseBody;
+pub use self::response::dry_run::Change as DryRunResponseChange;
+pub use self::response::error::Body as ErrorResponseBody;
+pub use self::response::error::Error as ErrorResponse;
 pub use self::response::fee::Body as FeeResponseBody;
 pub use self::response::initialize::Body as InitializeResponseBody;
+pub use self::response::instances::Body as InstancesResponseBody;
+pub use self::response::instances::Instance as InstancesResponseInstance;
 pub use self::response::metadata::Body as MetadataResponseBody;
+pub use self::response::owner::Body as OwnerResponseBody;
 pub use self::response::publish::Body as PublishResponseBody;
+pub use self::response::remove::Body as RemoveResponseBody;
+pub use self::response::search::Body as SearchResponseBody;
+pub use self::response::search::Project as SearchResponseProject;
 pub use self::response::source::Body as SourceResponseBody;
+pub use self::response::storage::Body as StorageResponseBody;
+pub use self::response::storage::Field as StorageResponseField;
+pub use self::response::upgrade::Body as UpgradeResponseBody;
+pub use self::scalar::error::Error as ScalarParsingError;
+pub use self::scalar::format as scalar_format;
+pub use self::scalar::parse as scalar_parse;
 pub use self::transaction::error::Error as TransactionError;
 pub use self::transaction::msg::Msg as TransactionMsg;
 pub use self::transaction::Transaction;
@@ -0,0 +1,232 @@
+//!
+//! The project ownership proofs.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use rustc_hex::ToHex;
+use sha2::Digest;
+
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::Address;
+use zksync_types::H256;
+
+///
+/// The project publishing claim, which is signed with the contract owner private key.
+///
+/// The server checks the claim against the owner address recorded at the first publish of
+/// the project, so the project versions cannot be published by anyone else.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishClaim {
+    /// The project name.
+    pub name: String,
+    /// The project version.
+    pub version: semver::Version,
+    /// The SHA-256 digest of the project source code and manifest.
+    pub source_digest: Vec<u8>,
+    /// The SHA-256 digest of the contract bytecode.
+    pub bytecode_digest: Vec<u8>,
+}
+
+impl PublishClaim {
+    ///
+    /// Describes the `project` with its `bytecode`, which is published as `name` and `version`.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+        project: &zinc_project::Project,
+        bytecode: &[u8],
+    ) -> Self {
+        Self {
+            name,
+            version,
+            source_digest: source_digest(project),
+            bytecode_digest: sha2::Sha256::digest(bytecode).to_vec(),
+        }
+    }
+
+    ///
+    /// The message, which is signed as an Ethereum personal message.
+    ///
+    pub fn message(&self) -> String {
+        format!(
+            "Zinc project publish\nname: {}\nversion: {}\nsource: 0x{}\nbytecode: 0x{}",
+            self.name,
+            self.version,
+            self.source_digest.to_hex::<String>(),
+            self.bytecode_digest.to_hex::<String>(),
+        )
+    }
+
+    ///
+    /// Signs the claim with the owner `private_key`.
+    ///
+    pub fn sign(&self, private_key: &H256) -> anyhow::Result<PackedEthSignature> {
+        PackedEthSignature::sign(private_key, self.message().as_bytes())
+            .map_err(|error| anyhow::anyhow!("{}", error))
+    }
+
+    ///
+    /// Checks whether the `signature` has been made by the `owner` over this claim.
+    ///
+    pub fn is_signed_by(&self, signature: &PackedEthSignature, owner: Address) -> bool {
+        signature
+            .signature_recover_signer(self.message().as_bytes())
+            .map_or(false, |signer| signer == owner)
+    }
+}
+
+///
+/// The project owner rotation, which is signed with the current owner private key.
+///
+/// The rotation includes the number of the rotations made before, which the server increments
+/// with each rotation, so a rotation signature is only valid once.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnerRotation {
+    /// The project name.
+    pub name: String,
+    /// The number of the owner rotations made before this one.
+    pub sequence: u64,
+    /// The current owner address.
+    pub owner: Address,
+    /// The new owner address.
+    pub new_owner: Address,
+}
+
+impl OwnerRotation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, sequence: u64, owner: Address, new_owner: Address) -> Self {
+        Self {
+            name,
+            sequence,
+            owner,
+            new_owner,
+        }
+    }
+
+    ///
+    /// The message, which is signed as an Ethereum personal message.
+    ///
+    pub fn message(&self) -> String {
+        format!(
+            "Zinc project owner rotation\nname: {}\nsequence: {}\nowner: 0x{}\nnew owner: 0x{}",
+            self.name,
+            self.sequence,
+            self.owner.as_bytes().to_hex::<String>(),
+            self.new_owner.as_bytes().to_hex::<String>(),
+        )
+    }
+
+    ///
+    /// Signs the rotation with the current owner `private_key`.
+    ///
+    pub fn sign(&self, private_key: &H256) -> anyhow::Result<PackedEthSignature> {
+        PackedEthSignature::sign(private_key, self.message().as_bytes())
+            .map_err(|error| anyhow::anyhow!("{}", error))
+    }
+
+    ///
+    /// Checks whether the `signature` has been made by the current owner over this rotation.
+    ///
+    pub fn is_signed_by_owner(&self, signature: &PackedEthSignature) -> bool {
+        signature
+            .signature_recover_signer(self.message().as_bytes())
+            .map_or(false, |signer| signer == self.owner)
+    }
+}
+
+///
+/// The contract instance removal, which is signed with the contract owner private key.
+///
+/// The nonce is issued by the server for a single removal, so the signature cannot be replayed.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractRemoval {
+    /// The contract zkSync account ID.
+    pub account_id: u64,
+    /// The server-provided challenge nonce.
+    pub nonce: String,
+    /// Whether the contract is purged instead of being archived.
+    pub purge: bool,
+}
+
+impl ContractRemoval {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: u64, nonce: String, purge: bool) -> Self {
+        Self {
+            account_id,
+            nonce,
+            purge,
+        }
+    }
+
+    ///
+    /// The message, which is signed as an Ethereum personal message.
+    ///
+    pub fn message(&self) -> String {
+        format!(
+            "Zinc contract removal\naccount ID: {}\nnonce: {}\npurge: {}",
+            self.account_id, self.nonce, self.purge,
+        )
+    }
+
+    ///
+    /// Signs the removal with the owner `private_key`.
+    ///
+    pub fn sign(&self, private_key: &H256) -> anyhow::Result<PackedEthSignature> {
+        PackedEthSignature::sign(private_key, self.message().as_bytes())
+            .map_err(|error| anyhow::anyhow!("{}", error))
+    }
+
+    ///
+    /// Recovers the address, which has made the `signature` over this removal.
+    ///
+    pub fn signer(&self, signature: &PackedEthSignature) -> Option<Address> {
+        signature
+            .signature_recover_signer(self.message().as_bytes())
+            .ok()
+    }
+}
+
+///
+/// Computes the SHA-256 digest of the `project` manifest and source code.
+///
+/// The project JSON objects are hashed with their keys sorted, since the source modules are
+/// kept in a hash map and may be serialized in any order.
+///
+pub fn source_digest(project: &zinc_project::Project) -> Vec<u8> {
+    let value = serde_json::to_value(project).expect(zinc_const::panic::DATA_CONVERSION);
+    let value = canonicalize(value);
+    let bytes = serde_json::to_vec(&value).expect(zinc_const::panic::DATA_CONVERSION);
+    sha2::Sha256::digest(bytes.as_slice()).to_vec()
+}
+
+///
+/// Sorts the JSON object keys recursively.
+///
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(object) => {
+            let mut entries: Vec<(String, serde_json::Value)> = object.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(array) => {
+            serde_json::Value::Array(array.into_iter().map(canonicalize).collect())
+        }
+        value => value,
+    }
+}
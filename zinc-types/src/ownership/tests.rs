@@ -0,0 +1,232 @@
+//!
+//! The project ownership proofs tests.
+//!
+
+use std::collections::HashMap;
+
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::H256;
+
+use crate::ownership::source_digest;
+use crate::ownership::ContractRemoval;
+use crate::ownership::OwnerRotation;
+use crate::ownership::PublishClaim;
+
+fn project(code: &str) -> zinc_project::Project {
+    let mut modules = HashMap::new();
+    for name in ["first", "second", "third"].iter() {
+        modules.insert(
+            (*name).to_owned(),
+            zinc_project::Source::File(zinc_project::File {
+                name: (*name).to_owned(),
+                path: format!("src/{}.zn", name),
+                code: format!("const {}: u8 = 42;", name.to_uppercase()),
+            }),
+        );
+    }
+    modules.insert(
+        zinc_const::file_name::APPLICATION_ENTRY.to_owned(),
+        zinc_project::Source::File(zinc_project::File {
+            name: zinc_const::file_name::APPLICATION_ENTRY.to_owned(),
+            path: "src/main.zn".to_owned(),
+            code: code.to_owned(),
+        }),
+    );
+
+    zinc_project::Project::new(
+        zinc_project::Manifest::new("test", zinc_project::ProjectType::Contract),
+        zinc_project::Source::Directory(zinc_project::Directory {
+            name: "src".to_owned(),
+            path: "src".to_owned(),
+            modules,
+        }),
+    )
+}
+
+fn private_key(byte: u8) -> H256 {
+    H256::from([byte; zinc_const::size::ETH_PRIVATE_KEY])
+}
+
+#[test]
+fn ok_signature_accepted() {
+    let claim = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Test {}"),
+        &[1, 2, 3],
+    );
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let signature = claim
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(claim.is_signed_by(&signature, owner));
+}
+
+#[test]
+fn ok_source_digest_deterministic() {
+    let digests: Vec<Vec<u8>> = (0..8)
+        .map(|_| source_digest(&project("contract Test {}")))
+        .collect();
+
+    assert!(digests.windows(2).all(|pair| pair[0] == pair[1]));
+}
+
+#[test]
+fn ok_owner_rotation_accepted() {
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let new_owner = PackedEthSignature::address_from_private_key(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let rotation = OwnerRotation::new("test".to_owned(), 0, owner, new_owner);
+
+    let signature = rotation
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(rotation.is_signed_by_owner(&signature));
+}
+
+#[test]
+fn ok_contract_removal_accepted() {
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let removal = ContractRemoval::new(42, "0123456789abcdef".to_owned(), false);
+
+    let signature = removal
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(removal.signer(&signature), Some(owner));
+}
+
+#[test]
+fn error_source_digest_tampered() {
+    let claim = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Test {}"),
+        &[1, 2, 3],
+    );
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let signature = claim
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let tampered = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Tampered {}"),
+        &[1, 2, 3],
+    );
+
+    assert!(!tampered.is_signed_by(&signature, owner));
+}
+
+#[test]
+fn error_bytecode_digest_tampered() {
+    let claim = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Test {}"),
+        &[1, 2, 3],
+    );
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let signature = claim
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let tampered = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Test {}"),
+        &[1, 2, 4],
+    );
+
+    assert!(!tampered.is_signed_by(&signature, owner));
+}
+
+#[test]
+fn error_different_key() {
+    let claim = PublishClaim::new(
+        "test".to_owned(),
+        semver::Version::new(0, 1, 0),
+        &project("contract Test {}"),
+        &[1, 2, 3],
+    );
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let signature = claim
+        .sign(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(!claim.is_signed_by(&signature, owner));
+}
+
+#[test]
+fn error_owner_rotation_different_key() {
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let new_owner = PackedEthSignature::address_from_private_key(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let rotation = OwnerRotation::new("test".to_owned(), 0, owner, new_owner);
+
+    let signature = rotation
+        .sign(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(!rotation.is_signed_by_owner(&signature));
+}
+
+#[test]
+fn error_owner_rotation_replayed() {
+    let first = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let second = PackedEthSignature::address_from_private_key(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let captured = OwnerRotation::new("test".to_owned(), 0, first, second);
+    let signature = captured
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let back = OwnerRotation::new("test".to_owned(), 1, second, first);
+    let back_signature = back
+        .sign(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(back.is_signed_by_owner(&back_signature));
+
+    let replayed = OwnerRotation::new("test".to_owned(), 2, first, second);
+
+    assert!(!replayed.is_signed_by_owner(&signature));
+}
+
+#[test]
+fn error_contract_removal_different_key() {
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let removal = ContractRemoval::new(42, "0123456789abcdef".to_owned(), false);
+
+    let signature = removal
+        .sign(&private_key(2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_ne!(removal.signer(&signature), Some(owner));
+}
+
+#[test]
+fn error_contract_removal_purge_tampered() {
+    let owner = PackedEthSignature::address_from_private_key(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let signature = ContractRemoval::new(42, "0123456789abcdef".to_owned(), false)
+        .sign(&private_key(1))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let tampered = ContractRemoval::new(42, "0123456789abcdef".to_owned(), true);
+
+    assert_ne!(tampered.signer(&signature), Some(owner));
+}
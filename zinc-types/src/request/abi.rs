@@ -0,0 +1,25 @@
+//!
+//! The project ABI resource GET request.
+//!
+
+use serde::Deserialize;
+
+///
+/// The project ABI resource GET request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+}
+
+impl Path {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version) -> Self {
+        Self { name, version }
+    }
+}
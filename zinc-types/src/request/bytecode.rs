@@ -0,0 +1,25 @@
+//!
+//! The project bytecode resource GET request.
+//!
+
+use serde::Deserialize;
+
+///
+/// The project bytecode resource GET request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The project name.
+    pub name: String,
+    /// The project version.
+    pub version: semver::Version,
+}
+
+impl Path {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version) -> Self {
+        Self { name, version }
+    }
+}
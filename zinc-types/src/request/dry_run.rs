@@ -0,0 +1,74 @@
+//!
+//! The contract resource `dry-run` POST request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::Address;
+
+use crate::transaction::msg::Msg as TransactionMsg;
+
+///
+/// The contract resource `dry-run` POST request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The name of the evaluated method.
+    pub method: String,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: Address, method: String) -> Self {
+        Self { address, method }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![
+            (
+                "address",
+                serde_json::to_string(&self.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+                    .replace("\"", ""),
+            ),
+            ("method", self.method),
+        ]
+        .into_iter()
+    }
+}
+
+///
+/// The contract resource `dry-run` POST request body.
+///
+/// Unlike the `call` request, the transaction is not signed, since it is never sent to zkSync.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The JSON method input.
+    pub arguments: serde_json::Value,
+    /// The `zksync::msg` variable value. Defaults to the zero transaction, if unset.
+    #[serde(default)]
+    pub msg: TransactionMsg,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(arguments: serde_json::Value, msg: TransactionMsg) -> Self {
+        Self { arguments, msg }
+    }
+}
@@ -0,0 +1,74 @@
+//!
+//! The project instances resource GET request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+
+///
+/// The project instances resource GET request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+}
+
+impl Path {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version) -> Self {
+        Self { name, version }
+    }
+}
+
+///
+/// The project instances resource GET request query.
+///
+#[derive(Debug, Default, Deserialize)]
+pub struct Query {
+    /// The number of the instances to skip.
+    #[serde(default)]
+    pub offset: usize,
+    /// The maximal number of the instances to return.
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(offset: usize, limit: Option<usize>) -> Self {
+        Self { offset, limit }
+    }
+
+    ///
+    /// Returns the page size, which is the requested limit capped with the maximal one.
+    ///
+    pub fn limit(&self) -> usize {
+        std::cmp::min(
+            self.limit
+                .unwrap_or(zinc_const::zandbox::PAGE_LIMIT_DEFAULT),
+            zinc_const::zandbox::PAGE_LIMIT_MAX,
+        )
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut result = Vec::with_capacity(2);
+        result.push(("offset", self.offset.to_string()));
+        if let Some(limit) = self.limit {
+            result.push(("limit", limit.to_string()));
+        }
+        result.into_iter()
+    }
+}
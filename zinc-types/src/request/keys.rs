@@ -0,0 +1,62 @@
+//!
+//! The project resource `keys` PUT request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The project resource `keys` PUT request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The name of the uploaded project.
+    pub name: String,
+    /// The version of the uploaded project.
+    pub version: semver::Version,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, version: semver::Version) -> Self {
+        Self { name, version }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![("name", self.name), ("version", self.version.to_string())].into_iter()
+    }
+}
+
+///
+/// The project resource `keys` PUT request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The verifying key.
+    pub verifying_key: Vec<u8>,
+    /// The proving key, which is only required for server-side proving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proving_key: Option<Vec<u8>>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verifying_key: Vec<u8>, proving_key: Option<Vec<u8>>) -> Self {
+        Self {
+            verifying_key,
+            proving_key,
+        }
+    }
+}
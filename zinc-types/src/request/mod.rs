@@ -2,10 +2,20 @@
 //! The contract resource requests.
 //!
 
+pub mod abi;
+pub mod bytecode;
 pub mod call;
+pub mod dry_run;
 pub mod fee;
 pub mod initialize;
+pub mod instances;
+pub mod keys;
+pub mod owner;
 pub mod publish;
 pub mod query;
+pub mod remove;
+pub mod search;
 pub mod source;
+pub mod storage;
+pub mod upgrade;
 pub mod upload;
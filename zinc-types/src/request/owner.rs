@@ -0,0 +1,62 @@
+//!
+//! The project resource `owner` GET and PUT requests.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::Address;
+
+///
+/// The project resource `owner` GET and PUT request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The name of the project.
+    pub name: String,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![("name", self.name)].into_iter()
+    }
+}
+
+///
+/// The project resource `owner` PUT request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The new owner address.
+    pub new_owner: Address,
+    /// The current owner signature of the rotation, see `zinc_types::OwnerRotation`.
+    pub signature: PackedEthSignature,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(new_owner: Address, signature: PackedEthSignature) -> Self {
+        Self {
+            new_owner,
+            signature,
+        }
+    }
+}
@@ -7,6 +7,9 @@ use std::iter::IntoIterator;
 use serde::Deserialize;
 use serde::Serialize;
 
+use zksync_types::tx::PackedEthSignature;
+use zksync_types::Address;
+
 ///
 /// The contract resource POST request query.
 ///
@@ -66,10 +69,18 @@ pub struct Body {
     pub project: zinc_project::Project,
     /// The contract bytecode.
     pub bytecode: Vec<u8>,
-    /// The JSON constructor input.
-    pub arguments: serde_json::Value,
+    /// The JSON constructor input. Required if the contract has the `new` constructor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<serde_json::Value>,
+    /// The JSON initial storage. Allowed only if the contract has no constructor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<serde_json::Value>,
     /// The verifying key.
     pub verifying_key: Vec<u8>,
+    /// The contract owner address.
+    pub owner: Address,
+    /// The owner signature of the publishing claim, see `zinc_types::PublishClaim`.
+    pub signature: PackedEthSignature,
 }
 
 impl Body {
@@ -79,14 +90,20 @@ impl Body {
     pub fn new(
         project: zinc_project::Project,
         bytecode: Vec<u8>,
-        arguments: serde_json::Value,
+        arguments: Option<serde_json::Value>,
+        storage: Option<serde_json::Value>,
         verifying_key: Vec<u8>,
+        owner: Address,
+        signature: PackedEthSignature,
     ) -> Self {
         Self {
             project,
             bytecode,
             arguments,
+            storage,
             verifying_key,
+            owner,
+            signature,
         }
     }
 }
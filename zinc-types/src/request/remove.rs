@@ -0,0 +1,77 @@
+//!
+//! The contracts resource DELETE request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::tx::PackedEthSignature;
+
+///
+/// The contracts resource DELETE and `delete-challenge` GET request path.
+///
+#[derive(Debug, Deserialize)]
+pub struct Path {
+    /// The contract zkSync account ID.
+    pub account_id: u64,
+}
+
+impl Path {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: u64) -> Self {
+        Self { account_id }
+    }
+}
+
+///
+/// The contracts resource DELETE request query.
+///
+#[derive(Debug, Default, Deserialize)]
+pub struct Query {
+    /// Whether the contract is deleted instead of being archived. Requires the admin token.
+    #[serde(default)]
+    pub purge: bool,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(purge: bool) -> Self {
+        Self { purge }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![("purge", self.purge.to_string())].into_iter()
+    }
+}
+
+///
+/// The contracts resource DELETE request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The nonce issued by the `delete-challenge` endpoint.
+    pub nonce: String,
+    /// The contract owner signature of the removal, see `zinc_types::ContractRemoval`.
+    pub signature: PackedEthSignature,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(nonce: String, signature: PackedEthSignature) -> Self {
+        Self { nonce, signature }
+    }
+}
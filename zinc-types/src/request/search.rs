@@ -0,0 +1,61 @@
+//!
+//! The projects resource GET `search` request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+
+///
+/// The projects resource GET `search` request query.
+///
+#[derive(Debug, Default, Deserialize)]
+pub struct Query {
+    /// The text searched for in the project names and descriptions.
+    pub query: String,
+    /// The number of the projects to skip.
+    #[serde(default)]
+    pub offset: usize,
+    /// The maximal number of the projects to return.
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(query: String, offset: usize, limit: Option<usize>) -> Self {
+        Self {
+            query,
+            offset,
+            limit,
+        }
+    }
+
+    ///
+    /// Returns the page size, which is the requested limit capped with the maximal one.
+    ///
+    pub fn limit(&self) -> usize {
+        std::cmp::min(
+            self.limit
+                .unwrap_or(zinc_const::zandbox::PAGE_LIMIT_DEFAULT),
+            zinc_const::zandbox::PAGE_LIMIT_MAX,
+        )
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut result = Vec::with_capacity(3);
+        result.push(("query", self.query));
+        result.push(("offset", self.offset.to_string()));
+        if let Some(limit) = self.limit {
+            result.push(("limit", limit.to_string()));
+        }
+        result.into_iter()
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! The contract resource GET method `storage` request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+
+///
+/// The contract resource GET method `storage` request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract zkSync account ID.
+    pub account_id: u64,
+    /// The number of the map entries to skip in each map field.
+    #[serde(default)]
+    pub offset: usize,
+    /// The maximal number of the map entries to return for each map field.
+    pub limit: Option<usize>,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: u64, offset: usize, limit: Option<usize>) -> Self {
+        Self {
+            account_id,
+            offset,
+            limit,
+        }
+    }
+
+    ///
+    /// Returns the page size, which is the requested limit capped with the maximal one.
+    ///
+    pub fn limit(&self) -> usize {
+        std::cmp::min(
+            self.limit
+                .unwrap_or(zinc_const::zandbox::PAGE_LIMIT_DEFAULT),
+            zinc_const::zandbox::PAGE_LIMIT_MAX,
+        )
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut result = Vec::with_capacity(3);
+        result.push(("account_id", self.account_id.to_string()));
+        result.push(("offset", self.offset.to_string()));
+        if let Some(limit) = self.limit {
+            result.push(("limit", limit.to_string()));
+        }
+        result.into_iter()
+    }
+}
@@ -0,0 +1,75 @@
+//!
+//! The contract resource `upgrade` POST request.
+//!
+
+use std::iter::IntoIterator;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::Address;
+
+///
+/// The contract resource `upgrade` POST request query.
+///
+#[derive(Debug, Deserialize)]
+pub struct Query {
+    /// The contract ETH address.
+    pub address: Address,
+    /// The new version of the contract.
+    pub version: semver::Version,
+}
+
+impl Query {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: Address, version: semver::Version) -> Self {
+        Self { address, version }
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = (&'static str, String);
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        vec![
+            (
+                "address",
+                serde_json::to_string(&self.address)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+                    .replace("\"", ""),
+            ),
+            ("version", self.version.to_string()),
+        ]
+        .into_iter()
+    }
+}
+
+///
+/// The contract resource `upgrade` POST request body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The new version project data.
+    pub project: zinc_project::Project,
+    /// The new version contract bytecode.
+    pub bytecode: Vec<u8>,
+    /// The new version verifying key.
+    pub verifying_key: Vec<u8>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(project: zinc_project::Project, bytecode: Vec<u8>, verifying_key: Vec<u8>) -> Self {
+        Self {
+            project,
+            bytecode,
+            verifying_key,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! The project bytecode resource GET response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The project bytecode resource GET response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The project compiler version.
+    pub zinc_version: String,
+    /// The bytecode uploaded with the project.
+    pub bytecode: Vec<u8>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(zinc_version: String, bytecode: Vec<u8>) -> Self {
+        Self {
+            zinc_version,
+            bytecode,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//!
+//! The successful response envelope.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The successful response body envelope.
+///
+/// Every successful Zandbox response is wrapped into `{ "data": ... }`.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body<T> {
+    /// The response payload.
+    pub data: T,
+}
+
+impl<T> Body<T> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(data: T) -> Self {
+        Self { data }
+    }
+}
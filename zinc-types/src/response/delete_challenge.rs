@@ -0,0 +1,26 @@
+//!
+//! The contracts resource `delete-challenge` GET response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contracts resource `delete-challenge` GET response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The nonce, which must be signed along with the contract account ID.
+    pub nonce: String,
+    /// The number of the seconds the nonce is valid for.
+    pub expires_in: u64,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(nonce: String, expires_in: u64) -> Self {
+        Self { nonce, expires_in }
+    }
+}
@@ -0,0 +1,82 @@
+//!
+//! The contract resource POST method `dry-run` response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::transaction::msg::Msg as TransactionMsg;
+
+///
+/// The contract resource POST method `dry-run` response body.
+///
+/// The method is evaluated against a copy of the contract storage, so neither the storage nor
+/// zkSync are affected.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Body {
+    /// The method output in the typed JSON format, as it is returned by the `call` endpoint.
+    pub output: serde_json::Value,
+    /// The transfers, which the contract would send.
+    pub transfers: Vec<TransactionMsg>,
+    /// The contract storage fields changed by the method.
+    pub storage: Vec<Change>,
+    /// The number of the executed virtual machine instructions.
+    pub cost: usize,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        output: serde_json::Value,
+        transfers: Vec<TransactionMsg>,
+        storage: Vec<Change>,
+        cost: usize,
+    ) -> Self {
+        Self {
+            output,
+            transfers,
+            storage,
+            cost,
+        }
+    }
+}
+
+///
+/// The contract storage field changed by the method.
+///
+/// Only the changed entries are listed for the map fields, so an entry missing in `before` is
+/// inserted by the method, and an entry missing in `after` is removed.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    pub r#type: String,
+    /// The field value before the method execution in the typed JSON format.
+    pub before: serde_json::Value,
+    /// The field value after the method execution in the typed JSON format.
+    pub after: serde_json::Value,
+}
+
+impl Change {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        r#type: String,
+        before: serde_json::Value,
+        after: serde_json::Value,
+    ) -> Self {
+        Self {
+            name,
+            r#type,
+            before,
+            after,
+        }
+    }
+}
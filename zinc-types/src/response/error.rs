@@ -0,0 +1,67 @@
+//!
+//! The failure response envelope.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The failure response body envelope.
+///
+/// Every failed Zandbox response is wrapped into `{ "error": { "code": ..., "message": ..., "details": ... } }`.
+/// The request ID is added by the server middleware.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The error description.
+    pub error: Error,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code: String, message: String, details: Option<serde_json::Value>) -> Self {
+        Self {
+            error: Error {
+                code,
+                message,
+                details,
+                request_id: None,
+            },
+        }
+    }
+
+    ///
+    /// Sets the server-generated ID of the failed request.
+    ///
+    pub fn with_request_id(mut self, request_id: String) -> Self {
+        self.error.request_id = Some(request_id);
+        self
+    }
+}
+
+///
+/// The failure response error description.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Error {
+    /// The machine-readable error code, e.g. `METHOD_NOT_FOUND`.
+    pub code: String,
+    /// The human-readable error message.
+    pub message: String,
+    /// The optional structured error details.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+    /// The server-generated request ID, which is also echoed in the `X-Request-Id` header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
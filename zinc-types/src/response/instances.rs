@@ -0,0 +1,92 @@
+//!
+//! The project instances resource GET response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::Address;
+
+///
+/// The project instances resource GET response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The contract instances on the page.
+    pub instances: Vec<Instance>,
+    /// The number of the skipped instances.
+    pub offset: usize,
+    /// The maximal number of the instances on the page.
+    pub limit: usize,
+    /// The total number of the project instances.
+    pub total: usize,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(instances: Vec<Instance>, offset: usize, limit: usize, total: usize) -> Self {
+        Self {
+            instances,
+            offset,
+            limit,
+            total,
+        }
+    }
+
+    ///
+    /// Whether there are instances beyond the page.
+    ///
+    pub fn has_next_page(&self) -> bool {
+        self.offset + self.instances.len() < self.total
+    }
+}
+
+///
+/// The project instances resource GET response instance.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Instance {
+    /// The contract zkSync account ID.
+    pub account_id: u64,
+    /// The contract instance name.
+    pub instance: String,
+    /// The contract ETH address.
+    pub address: Address,
+    /// The ETH address of the account, which has made the initial deposit to the contract.
+    /// Unknown for the instances created by other contracts.
+    pub owner: Option<Address>,
+    /// The publishing timestamp in the RFC 3339 format, in UTC.
+    pub published_at: String,
+    /// The names of the interfaces, which the contract implements.
+    pub interfaces: Vec<String>,
+    /// The hexadecimal storage root hash, which is unknown until the storage is written.
+    pub storage_root: Option<String>,
+}
+
+impl Instance {
+    ///
+    /// A shortcut constructor.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_id: u64,
+        instance: String,
+        address: Address,
+        owner: Option<Address>,
+        published_at: String,
+        interfaces: Vec<String>,
+        storage_root: Option<String>,
+    ) -> Self {
+        Self {
+            account_id,
+            instance,
+            address,
+            owner,
+            published_at,
+            interfaces,
+            storage_root,
+        }
+    }
+}
@@ -2,8 +2,19 @@
 //! The contract resource responses.
 //!
 
+pub mod bytecode;
+pub mod data;
+pub mod delete_challenge;
+pub mod dry_run;
+pub mod error;
 pub mod fee;
 pub mod initialize;
+pub mod instances;
 pub mod metadata;
+pub mod owner;
 pub mod publish;
+pub mod remove;
+pub mod search;
 pub mod source;
+pub mod storage;
+pub mod upgrade;
@@ -0,0 +1,28 @@
+//!
+//! The project resource `owner` GET response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::Address;
+
+///
+/// The project resource `owner` GET response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The current owner address.
+    pub owner: Address,
+    /// The number of the owner rotations made so far, which the next rotation must be signed with.
+    pub rotations: u64,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(owner: Address, rotations: u64) -> Self {
+        Self { owner, rotations }
+    }
+}
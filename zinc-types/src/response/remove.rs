@@ -0,0 +1,30 @@
+//!
+//! The contracts resource DELETE response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contracts resource DELETE response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The contract zkSync account ID.
+    pub account_id: u64,
+    /// The archival timestamp in the RFC 3339 format. `None` if the contract has been purged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(account_id: u64, archived_at: Option<String>) -> Self {
+        Self {
+            account_id,
+            archived_at,
+        }
+    }
+}
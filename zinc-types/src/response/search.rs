@@ -0,0 +1,80 @@
+//!
+//! The projects resource GET `search` response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The projects resource GET `search` response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The found projects on the page, the best matches first.
+    pub projects: Vec<Project>,
+    /// The number of the skipped projects.
+    pub offset: usize,
+    /// The maximal number of the projects on the page.
+    pub limit: usize,
+    /// The total number of the found projects.
+    pub total: usize,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(projects: Vec<Project>, offset: usize, limit: usize, total: usize) -> Self {
+        Self {
+            projects,
+            offset,
+            limit,
+            total,
+        }
+    }
+
+    ///
+    /// Whether there are projects beyond the page.
+    ///
+    pub fn has_next_page(&self) -> bool {
+        self.offset + self.projects.len() < self.total
+    }
+}
+
+///
+/// The projects resource GET `search` response project.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    /// The project name.
+    pub name: String,
+    /// The latest published project version.
+    pub version: semver::Version,
+    /// The project type.
+    pub r#type: zinc_project::ProjectType,
+    /// The project description from the manifest of the latest version.
+    pub description: Option<String>,
+    /// The publishing timestamp of the latest version in the RFC 3339 format, in UTC.
+    pub published_at: String,
+}
+
+impl Project {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+        r#type: zinc_project::ProjectType,
+        description: Option<String>,
+        published_at: String,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            r#type,
+            description,
+            published_at,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//!
+//! The contract resource GET method `storage` response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use zksync_types::Address;
+
+///
+/// The contract resource GET method `storage` response body.
+///
+/// The map fields are split into pages, whereas the other fields are returned in full.
+///
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Body {
+    /// The contract project name.
+    pub name: String,
+    /// The contract project version.
+    pub version: semver::Version,
+    /// The contract ETH address.
+    pub address: Address,
+    /// The contract storage layout hash.
+    pub storage_layout: String,
+    /// The contract storage fields, including the private and implicit ones.
+    pub fields: Vec<Field>,
+    /// The number of the skipped entries in each map field.
+    pub offset: usize,
+    /// The maximal number of the entries in each map field on the page.
+    pub limit: usize,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+        address: Address,
+        storage_layout: String,
+        fields: Vec<Field>,
+        offset: usize,
+        limit: usize,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            address,
+            storage_layout,
+            fields,
+            offset,
+            limit,
+        }
+    }
+
+    ///
+    /// Whether there are map entries beyond the page.
+    ///
+    pub fn has_next_page(&self) -> bool {
+        self.fields.iter().any(|field| match field.total {
+            Some(total) => self.offset + self.limit < total,
+            None => false,
+        })
+    }
+}
+
+///
+/// The contract resource GET method `storage` response field.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    /// The field name.
+    pub name: String,
+    /// The field type.
+    pub r#type: String,
+    /// The field value in the typed JSON format. Only the page entries for the map fields.
+    pub value: serde_json::Value,
+    /// The total number of the entries, if the field is a map.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub total: Option<usize>,
+}
+
+impl Field {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        r#type: String,
+        value: serde_json::Value,
+        total: Option<usize>,
+    ) -> Self {
+        Self {
+            name,
+            r#type,
+            value,
+            total,
+        }
+    }
+}
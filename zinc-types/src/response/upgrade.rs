@@ -0,0 +1,43 @@
+//!
+//! The contract resource `upgrade` POST response.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The contract resource `upgrade` POST response body.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Body {
+    /// The storage layout hash of the previous contract version.
+    pub previous_storage_layout: String,
+    /// The storage layout hash of the new contract version.
+    pub storage_layout: String,
+    /// The migration method name, if the storage has been migrated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migration: Option<String>,
+    /// The storage fields appended to the previous layout, which are initialized with the
+    /// default values.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub appended_fields: Vec<String>,
+}
+
+impl Body {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        previous_storage_layout: String,
+        storage_layout: String,
+        migration: Option<String>,
+        appended_fields: Vec<String>,
+    ) -> Self {
+        Self {
+            previous_storage_layout,
+            storage_layout,
+            migration,
+            appended_fields,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//!
+//! The scalar value parsing error.
+//!
+
+use thiserror::Error;
+
+///
+/// The scalar value parsing error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The string is empty or consists of the sign or prefix only.
+    #[error("expected a number, found `{found}`")]
+    Empty {
+        /// The invalid string.
+        found: String,
+    },
+
+    /// The hexadecimal string does not start with the `0x` prefix.
+    #[error("expected a `0x`-prefixed hexadecimal string, found `{found}`")]
+    PrefixMissing {
+        /// The invalid string.
+        found: String,
+    },
+
+    /// The string contains a character, which is not a digit of the expected base.
+    #[error("invalid digit `{digit}` at position {position} of `{found}`")]
+    InvalidDigit {
+        /// The invalid string.
+        found: String,
+        /// The invalid character.
+        digit: char,
+        /// The invalid character position, starting from zero.
+        position: usize,
+    },
+
+    /// The value is negative, but only the non-negative ones are allowed.
+    #[error("expected a non-negative number, found `{found}`")]
+    Negative {
+        /// The invalid string.
+        found: String,
+    },
+
+    /// The value does not fit into the expected number of bits.
+    #[error("value `{found}` does not fit into {bitlength} bits")]
+    Overflow {
+        /// The invalid string.
+        found: String,
+        /// The maximal allowed value bitlength.
+        bitlength: usize,
+    },
+}
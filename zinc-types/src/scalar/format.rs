@@ -0,0 +1,57 @@
+//!
+//! The scalar value rendering.
+//!
+
+use num::BigInt;
+use num::Signed;
+use rustc_hex::ToHex;
+
+///
+/// Renders the `value` as a decimal string, e.g. `-42`.
+///
+pub fn decimal(value: &BigInt) -> String {
+    value.to_str_radix(zinc_const::base::DECIMAL)
+}
+
+///
+/// Renders the `value` as a `0x`-prefixed lowercase hexadecimal string without the leading
+/// zeros, e.g. `0x2a` or `-0x2a`.
+///
+pub fn hexadecimal(value: &BigInt) -> String {
+    hexadecimal_padded(value, 0)
+}
+
+///
+/// Renders the `value` as a `0x`-prefixed lowercase hexadecimal string, which is zero-padded
+/// to `size` bytes. The longer values are rendered as is.
+///
+pub fn hexadecimal_padded(value: &BigInt, size: usize) -> String {
+    format!(
+        "{}0x{:0>width$}",
+        if value.is_negative() { "-" } else { "" },
+        value.abs().to_str_radix(zinc_const::base::HEXADECIMAL),
+        width = size * 2,
+    )
+}
+
+///
+/// Renders the ETH address `value`, zero-padded to the address size.
+///
+pub fn address(value: &BigInt) -> String {
+    hexadecimal_padded(value, zinc_const::size::ETH_ADDRESS)
+}
+
+///
+/// Renders the hash `value`, e.g. the storage root hash, zero-padded to the `sha256` hash size.
+///
+pub fn hash(value: &BigInt) -> String {
+    hexadecimal_padded(value, zinc_const::size::SHA256_HASH)
+}
+
+///
+/// Renders the big-endian `bytes` as a `0x`-prefixed lowercase hexadecimal string, keeping
+/// the leading zero bytes.
+///
+pub fn bytes(bytes: &[u8]) -> String {
+    format!("0x{}", bytes.to_hex::<String>())
+}
@@ -0,0 +1,14 @@
+//!
+//! The scalar value textual representation.
+//!
+//! The values are rendered and parsed in the same canonical formats across the toolchain:
+//! decimal strings, `0x`-prefixed lowercase hexadecimal strings, and the hexadecimal strings
+//! zero-padded to the ETH address or hash size.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod format;
+pub mod parse;
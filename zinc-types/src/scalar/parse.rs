@@ -0,0 +1,120 @@
+//!
+//! The scalar value parsing.
+//!
+
+use num::BigInt;
+use num::Num;
+use num::Signed;
+
+use crate::scalar::error::Error;
+
+///
+/// Parses the decimal `string`, e.g. `42` or `-42`.
+///
+/// Unlike the source code literals, the underscores, exponents, and other bases are rejected.
+///
+pub fn decimal(string: &str) -> Result<BigInt, Error> {
+    let (is_negative, digits) = split_sign(string);
+
+    parse_digits(string, digits, zinc_const::base::DECIMAL, is_negative)
+}
+
+///
+/// Parses the `0x`-prefixed hexadecimal `string`, e.g. `0x2a` or `-0x2A`.
+///
+pub fn hexadecimal(string: &str) -> Result<BigInt, Error> {
+    let (is_negative, digits) = split_sign(string);
+    let digits = digits
+        .strip_prefix("0x")
+        .ok_or_else(|| Error::PrefixMissing {
+            found: string.to_owned(),
+        })?;
+
+    parse_digits(string, digits, zinc_const::base::HEXADECIMAL, is_negative)
+}
+
+///
+/// Parses the `string` as hexadecimal if it is `0x`-prefixed, and as decimal otherwise.
+///
+pub fn numeric(string: &str) -> Result<BigInt, Error> {
+    let (_is_negative, digits) = split_sign(string);
+
+    if digits.starts_with("0x") {
+        hexadecimal(string)
+    } else {
+        decimal(string)
+    }
+}
+
+///
+/// Parses the hexadecimal ETH address `string`, which may be shorter than the address size.
+///
+pub fn address(string: &str) -> Result<BigInt, Error> {
+    hexadecimal(string)
+        .and_then(|value| unsigned(string, value, zinc_const::bitlength::ETH_ADDRESS))
+}
+
+///
+/// Parses the hexadecimal hash `string`, which may be shorter than the `sha256` hash size.
+///
+pub fn hash(string: &str) -> Result<BigInt, Error> {
+    hexadecimal(string)
+        .and_then(|value| unsigned(string, value, zinc_const::bitlength::SHA256_HASH))
+}
+
+///
+/// Checks whether the `value` parsed from `string` is non-negative and fits into `bitlength`.
+///
+pub fn unsigned(string: &str, value: BigInt, bitlength: usize) -> Result<BigInt, Error> {
+    if value.is_negative() {
+        return Err(Error::Negative {
+            found: string.to_owned(),
+        });
+    }
+
+    if value.bits() > bitlength as u64 {
+        return Err(Error::Overflow {
+            found: string.to_owned(),
+            bitlength,
+        });
+    }
+
+    Ok(value)
+}
+
+///
+/// Splits the optional minus sign from the `string`.
+///
+fn split_sign(string: &str) -> (bool, &str) {
+    match string.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, string),
+    }
+}
+
+///
+/// Parses the `digits` of the `base`, reporting the invalid digit position in the whole `string`.
+///
+fn parse_digits(string: &str, digits: &str, base: u32, is_negative: bool) -> Result<BigInt, Error> {
+    if digits.is_empty() {
+        return Err(Error::Empty {
+            found: string.to_owned(),
+        });
+    }
+
+    let offset = string.len() - digits.len();
+    if let Some((position, digit)) = digits
+        .char_indices()
+        .find(|(_position, digit)| !digit.is_digit(base))
+    {
+        return Err(Error::InvalidDigit {
+            found: string.to_owned(),
+            digit,
+            position: offset + position,
+        });
+    }
+
+    let value = BigInt::from_str_radix(digits, base).expect(zinc_const::panic::DATA_CONVERSION);
+
+    Ok(if is_negative { -value } else { value })
+}
@@ -0,0 +1,269 @@
+//!
+//! The scalar value textual representation tests.
+//!
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::scalar::error::Error;
+use crate::scalar::format;
+use crate::scalar::parse;
+
+/// The number of random values checked for every bitlength.
+const VALUES_PER_BITLENGTH: usize = 64;
+
+///
+/// Returns all the supported integer bitlengths and the field one.
+///
+fn bitlengths() -> Vec<usize> {
+    let mut bitlengths: Vec<usize> = (zinc_const::bitlength::BYTE
+        ..=zinc_const::bitlength::INTEGER_MAX)
+        .step_by(zinc_const::bitlength::BYTE)
+        .collect();
+    bitlengths.push(zinc_const::bitlength::FIELD);
+    bitlengths
+}
+
+///
+/// Generates a random value, which fits into `bitlength` bits, including the sign bit if the
+/// value is `is_signed`.
+///
+fn random_value(rng: &mut rand::rngs::StdRng, bitlength: usize, is_signed: bool) -> BigInt {
+    let bytes: Vec<u8> = (0..(bitlength + 7) / 8).map(|_| rng.gen()).collect();
+    let value = BigInt::from_bytes_be(num::bigint::Sign::Plus, bytes.as_slice());
+
+    if is_signed {
+        let value = value % (BigInt::one() << (bitlength - 1));
+        if rng.gen() {
+            -value
+        } else {
+            value
+        }
+    } else {
+        value % (BigInt::one() << bitlength)
+    }
+}
+
+#[test]
+fn ok_round_trip_decimal() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(zinc_const::bitlength::FIELD as u64);
+
+    for bitlength in bitlengths().into_iter() {
+        for is_signed in [false, true].iter().copied() {
+            for _ in 0..VALUES_PER_BITLENGTH {
+                let value = random_value(&mut rng, bitlength, is_signed);
+
+                assert_eq!(
+                    parse::decimal(format::decimal(&value).as_str()),
+                    Ok(value.clone())
+                );
+                assert_eq!(parse::numeric(format::decimal(&value).as_str()), Ok(value));
+            }
+        }
+    }
+}
+
+#[test]
+fn ok_round_trip_hexadecimal() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(zinc_const::bitlength::FIELD as u64);
+
+    for bitlength in bitlengths().into_iter() {
+        for is_signed in [false, true].iter().copied() {
+            for _ in 0..VALUES_PER_BITLENGTH {
+                let value = random_value(&mut rng, bitlength, is_signed);
+
+                assert_eq!(
+                    parse::hexadecimal(format::hexadecimal(&value).as_str()),
+                    Ok(value.clone())
+                );
+                assert_eq!(
+                    parse::numeric(format::hexadecimal(&value).as_str()),
+                    Ok(value.clone())
+                );
+                assert_eq!(
+                    parse::hexadecimal(
+                        format::hexadecimal_padded(&value, zinc_const::size::SHA256_HASH).as_str()
+                    ),
+                    Ok(value)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn ok_round_trip_unsigned() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(zinc_const::bitlength::FIELD as u64);
+
+    for bitlength in bitlengths().into_iter() {
+        for _ in 0..VALUES_PER_BITLENGTH {
+            let value = random_value(&mut rng, bitlength, false);
+            let string = format::hexadecimal(&value);
+
+            assert_eq!(
+                parse::unsigned(string.as_str(), value.clone(), bitlength),
+                Ok(value)
+            );
+        }
+    }
+}
+
+#[test]
+fn ok_round_trip_address() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(zinc_const::bitlength::FIELD as u64);
+
+    for _ in 0..VALUES_PER_BITLENGTH {
+        let value = random_value(&mut rng, zinc_const::bitlength::ETH_ADDRESS, false);
+        let string = format::address(&value);
+
+        assert_eq!(string.len(), "0x".len() + zinc_const::size::ETH_ADDRESS * 2);
+        assert_eq!(parse::address(string.as_str()), Ok(value));
+    }
+}
+
+#[test]
+fn ok_round_trip_hash() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(zinc_const::bitlength::FIELD as u64);
+
+    for _ in 0..VALUES_PER_BITLENGTH {
+        let value = random_value(&mut rng, zinc_const::bitlength::SHA256_HASH, false);
+        let string = format::hash(&value);
+
+        assert_eq!(string.len(), "0x".len() + zinc_const::size::SHA256_HASH * 2);
+        assert_eq!(parse::hash(string.as_str()), Ok(value));
+    }
+}
+
+#[test]
+fn ok_golden_address() {
+    assert_eq!(
+        format::address(&BigInt::zero()),
+        "0x0000000000000000000000000000000000000000"
+    );
+    assert_eq!(
+        format::address(&BigInt::from(0x42)),
+        "0x0000000000000000000000000000000000000042"
+    );
+    assert_eq!(
+        format::address(&((BigInt::one() << zinc_const::bitlength::ETH_ADDRESS) - BigInt::one())),
+        "0xffffffffffffffffffffffffffffffffffffffff"
+    );
+}
+
+#[test]
+fn ok_golden_hash() {
+    assert_eq!(
+        format::hash(&BigInt::one()),
+        "0x0000000000000000000000000000000000000000000000000000000000000001"
+    );
+    assert_eq!(
+        format::hash(&BigInt::from(0xdead_beef_u64)),
+        "0x00000000000000000000000000000000000000000000000000000000deadbeef"
+    );
+}
+
+#[test]
+fn ok_golden_hexadecimal() {
+    assert_eq!(format::hexadecimal(&BigInt::zero()), "0x0");
+    assert_eq!(format::hexadecimal(&BigInt::from(42)), "0x2a");
+    assert_eq!(format::hexadecimal(&BigInt::from(-42)), "-0x2a");
+    assert_eq!(format::hexadecimal_padded(&BigInt::from(-42), 2), "-0x002a");
+    assert_eq!(
+        format::hexadecimal_padded(&BigInt::from(0x1234), 1),
+        "0x1234"
+    );
+}
+
+#[test]
+fn ok_golden_bytes() {
+    assert_eq!(format::bytes(&[]), "0x");
+    assert_eq!(format::bytes(&[0x00, 0x01, 0xab]), "0x0001ab");
+}
+
+#[test]
+fn ok_parse_hexadecimal_uppercase() {
+    assert_eq!(
+        parse::hexadecimal("0xDEADbeef"),
+        Ok(BigInt::from(0xdead_beef_u64))
+    );
+}
+
+#[test]
+fn error_parse_empty() {
+    assert_eq!(
+        parse::decimal(""),
+        Err(Error::Empty {
+            found: "".to_owned()
+        })
+    );
+    assert_eq!(
+        parse::hexadecimal("-0x"),
+        Err(Error::Empty {
+            found: "-0x".to_owned()
+        })
+    );
+}
+
+#[test]
+fn error_parse_prefix_missing() {
+    assert_eq!(
+        parse::hexadecimal("ff"),
+        Err(Error::PrefixMissing {
+            found: "ff".to_owned()
+        })
+    );
+}
+
+#[test]
+fn error_parse_invalid_digit() {
+    assert_eq!(
+        parse::decimal("1_000"),
+        Err(Error::InvalidDigit {
+            found: "1_000".to_owned(),
+            digit: '_',
+            position: 1,
+        })
+    );
+    assert_eq!(
+        parse::decimal("0xff"),
+        Err(Error::InvalidDigit {
+            found: "0xff".to_owned(),
+            digit: 'x',
+            position: 1,
+        })
+    );
+    assert_eq!(
+        parse::hexadecimal("-0xfg"),
+        Err(Error::InvalidDigit {
+            found: "-0xfg".to_owned(),
+            digit: 'g',
+            position: 4,
+        })
+    );
+}
+
+#[test]
+fn error_parse_address_negative() {
+    assert_eq!(
+        parse::address("-0x1"),
+        Err(Error::Negative {
+            found: "-0x1".to_owned()
+        })
+    );
+}
+
+#[test]
+fn error_parse_address_overflow() {
+    let string = format!("0x1{}", "0".repeat(zinc_const::size::ETH_ADDRESS * 2));
+
+    assert_eq!(
+        parse::address(string.as_str()),
+        Err(Error::Overflow {
+            found: string,
+            bitlength: zinc_const::bitlength::ETH_ADDRESS,
+        })
+    );
+}
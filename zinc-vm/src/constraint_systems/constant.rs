@@ -9,8 +9,17 @@ use franklin_crypto::bellman::Variable;
 
 use crate::IEngine;
 
+///
+/// The constraint system, which only computes the witness values.
+///
+/// The allocations and constraints are not kept, but counted, which is the host-side cost of
+/// the witness generation measured by the constant-time audit.
+///
 #[derive(Default)]
-pub struct Constant {}
+pub struct Constant {
+    /// The number of the allocations and constraints.
+    operations: usize,
+}
 
 impl Constant {
     pub fn is_satisfied(&self) -> bool {
@@ -20,6 +29,10 @@ impl Constant {
     pub fn num_constraints(&self) -> usize {
         0
     }
+
+    pub fn operations(&self) -> usize {
+        self.operations
+    }
 }
 
 impl<E: IEngine> ConstraintSystem<E> for Constant {
@@ -32,6 +45,7 @@ impl<E: IEngine> ConstraintSystem<E> for Constant {
         AR: Into<String>,
     {
         f()?;
+        self.operations += 1;
         Ok(<Self as ConstraintSystem<E>>::one())
     }
 
@@ -42,6 +56,7 @@ impl<E: IEngine> ConstraintSystem<E> for Constant {
         AR: Into<String>,
     {
         f()?;
+        self.operations += 1;
         Ok(<Self as ConstraintSystem<E>>::one())
     }
 
@@ -53,6 +68,7 @@ impl<E: IEngine> ConstraintSystem<E> for Constant {
         LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
         LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
     {
+        self.operations += 1;
     }
 
     fn push_namespace<NR, N>(&mut self, _name_fn: N)
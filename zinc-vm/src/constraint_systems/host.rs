@@ -0,0 +1,158 @@
+//!
+//! The host-switching constraint system.
+//!
+
+use std::marker::PhantomData;
+
+use franklin_crypto::bellman::pairing::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::bellman::Variable;
+
+///
+/// The constraint system wrapper, which can be switched to the host mode.
+///
+/// In the host mode, the values are still computed, but the variables are not allocated and
+/// the constraints are not enforced. It is used to evaluate the `unsafe_witness` blocks.
+///
+pub struct Host<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    /// The inner constraint system.
+    inner: CS,
+    /// The number of the nested host mode sections.
+    depth: usize,
+
+    _pd: PhantomData<E>,
+}
+
+impl<E, CS> Host<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    pub fn new(inner: CS) -> Self {
+        Self {
+            inner,
+            depth: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    ///
+    /// The inner constraint system.
+    ///
+    pub fn inner(&self) -> &CS {
+        &self.inner
+    }
+
+    ///
+    /// Enters the host mode section.
+    ///
+    pub fn begin(&mut self) {
+        self.depth += 1;
+    }
+
+    ///
+    /// Leaves the host mode section.
+    ///
+    /// Returns `false` if there is no section to leave.
+    ///
+    pub fn end(&mut self) -> bool {
+        match self.depth.checked_sub(1) {
+            Some(depth) => {
+                self.depth = depth;
+                true
+            }
+            None => false,
+        }
+    }
+
+    ///
+    /// Whether the constraint system is in the host mode.
+    ///
+    pub fn is_host(&self) -> bool {
+        self.depth > 0
+    }
+
+    ///
+    /// Computes the host mode value, which may be missing during the key generation.
+    ///
+    fn evaluate<F>(f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+    {
+        match f() {
+            Ok(_) | Err(SynthesisError::AssignmentMissing) => Ok(CS::one()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl<E, CS> ConstraintSystem<E> for Host<E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        if self.is_host() {
+            return Self::evaluate(f);
+        }
+
+        self.inner.alloc(annotation, f)
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        if self.is_host() {
+            return Self::evaluate(f);
+        }
+
+        self.inner.alloc_input(annotation, f)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        if self.is_host() {
+            return;
+        }
+
+        self.inner.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.inner.get_root().push_namespace(name_fn);
+    }
+
+    fn pop_namespace(&mut self) {
+        self.inner.get_root().pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
@@ -55,9 +55,9 @@ where
             },
         )?;
         log::trace!(
-            "r1cs: witness: name = \"{}\", value = {:?}, index = {:?}",
+            "r1cs: witness: name = \"{}\", value = {}, index = {:?}",
             annotation,
-            value,
+            value_to_string::<E>(value),
             variable.get_unchecked()
         );
         Ok(variable)
@@ -80,9 +80,9 @@ where
             },
         )?;
         log::trace!(
-            "r1cs: input: name = \"{}\", value = {:?}, index = {:?}",
+            "r1cs: input: name = \"{}\", value = {}, index = {:?}",
             annotation,
-            value,
+            value_to_string::<E>(value),
             variable.get_unchecked()
         );
         Ok(variable)
@@ -128,6 +128,16 @@ where
     }
 }
 
+fn value_to_string<E: Engine>(value: Option<E::Fr>) -> String {
+    value
+        .map(|value| {
+            zinc_types::scalar_format::decimal(&gadgets::scalar::fr_bigint::fr_to_bigint::<E>(
+                &value, true,
+            ))
+        })
+        .unwrap_or_else(|| "none".into())
+}
+
 fn lc_to_string<E: Engine>(lc: &LinearCombination<E>) -> String {
     let mut string = String::new();
 
@@ -140,9 +150,9 @@ fn lc_to_string<E: Engine>(lc: &LinearCombination<E>) -> String {
         } else if c_value == BigInt::from(-1) {
             " - ".into()
         } else if c_value.is_negative() {
-            String::from(" - ") + &(-c_value).to_string() + " * "
+            String::from(" - ") + &zinc_types::scalar_format::decimal(&(-c_value)) + " * "
         } else {
-            String::from(" + ") + &c_value.to_string() + " * "
+            String::from(" + ") + &zinc_types::scalar_format::decimal(&c_value) + " * "
         };
 
         if c_str == " + " && is_first {
@@ -2,6 +2,9 @@
 //! The debug constraint system.
 //!
 
+use std::time::Duration;
+use std::time::Instant;
+
 use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::bellman::Index;
@@ -17,6 +20,8 @@ pub struct Main<E: IEngine> {
 
     satisfied: bool,
     constraints_num: usize,
+
+    synthesis_time: Option<Duration>,
 }
 
 impl<E: IEngine> Main<E> {
@@ -26,12 +31,35 @@ impl<E: IEngine> Main<E> {
             witness: Vec::new(),
             satisfied: true,
             constraints_num: 0,
+
+            synthesis_time: None,
         };
 
         cs.inputs.push(E::Fr::one());
         cs
     }
 
+    ///
+    /// Enables measuring the time spent on allocating the variables and enforcing the constraints.
+    ///
+    pub fn with_timing(mut self) -> Self {
+        self.synthesis_time = Some(Duration::default());
+        self
+    }
+
+    fn measure<T, F: FnOnce(&mut Self) -> T>(&mut self, f: F) -> T {
+        if self.synthesis_time.is_none() {
+            return f(self);
+        }
+
+        let start = Instant::now();
+        let result = f(self);
+        if let Some(synthesis_time) = self.synthesis_time.as_mut() {
+            *synthesis_time += start.elapsed();
+        }
+        result
+    }
+
     fn eval_lc(terms: &[(Variable, E::Fr)], inputs: &[E::Fr], witness: &[E::Fr]) -> E::Fr {
         let mut acc = E::Fr::zero();
 
@@ -57,6 +85,13 @@ impl<E: IEngine> Main<E> {
     pub fn num_constraints(&self) -> usize {
         self.constraints_num
     }
+
+    ///
+    /// The total synthesis time, if the timing is enabled.
+    ///
+    pub fn synthesis_time(&self) -> Option<Duration> {
+        self.synthesis_time
+    }
 }
 
 impl<E: IEngine> ConstraintSystem<E> for Main<E> {
@@ -68,9 +103,11 @@ impl<E: IEngine> ConstraintSystem<E> for Main<E> {
         A: FnOnce() -> AR,
         AR: Into<String>,
     {
-        let value = f()?;
-        self.witness.push(value);
-        Ok(Variable::new_unchecked(Index::Aux(self.witness.len() - 1)))
+        self.measure(|cs| {
+            let value = f()?;
+            cs.witness.push(value);
+            Ok(Variable::new_unchecked(Index::Aux(cs.witness.len() - 1)))
+        })
     }
 
     fn alloc_input<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
@@ -79,9 +116,11 @@ impl<E: IEngine> ConstraintSystem<E> for Main<E> {
         A: FnOnce() -> AR,
         AR: Into<String>,
     {
-        let value = f()?;
-        self.inputs.push(value);
-        Ok(Variable::new_unchecked(Index::Input(self.inputs.len() - 1)))
+        self.measure(|cs| {
+            let value = f()?;
+            cs.inputs.push(value);
+            Ok(Variable::new_unchecked(Index::Input(cs.inputs.len() - 1)))
+        })
     }
 
     fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, a: LA, b: LB, c: LC)
@@ -92,22 +131,24 @@ impl<E: IEngine> ConstraintSystem<E> for Main<E> {
         LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
         LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
     {
-        let zero = LinearCombination::zero();
-        let value_a = Self::eval_lc(a(zero.clone()).as_ref(), &self.inputs, &self.witness);
-        let value_b = Self::eval_lc(b(zero.clone()).as_ref(), &self.inputs, &self.witness);
-        let value_c = Self::eval_lc(c(zero).as_ref(), &self.inputs, &self.witness);
-
-        let value_ab = {
-            let mut tmp: E::Fr = value_a;
-            tmp.mul_assign(&value_b);
-            tmp
-        };
+        self.measure(|cs| {
+            let zero = LinearCombination::zero();
+            let value_a = Self::eval_lc(a(zero.clone()).as_ref(), &cs.inputs, &cs.witness);
+            let value_b = Self::eval_lc(b(zero.clone()).as_ref(), &cs.inputs, &cs.witness);
+            let value_c = Self::eval_lc(c(zero).as_ref(), &cs.inputs, &cs.witness);
+
+            let value_ab = {
+                let mut tmp: E::Fr = value_a;
+                tmp.mul_assign(&value_b);
+                tmp
+            };
 
-        if value_ab != value_c {
-            self.satisfied = false;
-        }
+            if value_ab != value_c {
+                cs.satisfied = false;
+            }
 
-        self.constraints_num += 1;
+            cs.constraints_num += 1;
+        })
     }
 
     fn push_namespace<NR, N>(&mut self, _name_fn: N)
@@ -4,5 +4,90 @@
 
 pub mod constant;
 pub mod dedup;
+pub mod host;
 pub mod logging;
 pub mod main;
+pub mod recording;
+
+use std::time::Duration;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::IEngine;
+
+use self::constant::Constant;
+use self::host::Host;
+use self::main::Main;
+
+///
+/// The constraint system, whose satisfiability is checked after the execution.
+///
+/// Implemented by the synthesizing `Main` constraint system and the evaluating `Constant` one,
+/// so the same instruction dispatch can be executed with or without the constraints.
+///
+pub trait IConstraintSystem<E: IEngine>: ConstraintSystem<E> {
+    ///
+    /// Whether all the enforced constraints are satisfied.
+    ///
+    fn is_satisfied(&self) -> bool;
+
+    ///
+    /// The number of the enforced constraints.
+    ///
+    fn num_constraints(&self) -> usize;
+
+    ///
+    /// The total synthesis time, if the timing is enabled.
+    ///
+    fn synthesis_time(&self) -> Option<Duration> {
+        None
+    }
+    ///
+    /// The number of the executed allocations and constraints, if they are counted.
+    ///
+    fn operations(&self) -> usize {
+        0
+    }
+}
+
+impl<E: IEngine> IConstraintSystem<E> for Main<E> {
+    fn is_satisfied(&self) -> bool {
+        Main::is_satisfied(self)
+    }
+
+    fn num_constraints(&self) -> usize {
+        Main::num_constraints(self)
+    }
+
+    fn synthesis_time(&self) -> Option<Duration> {
+        Main::synthesis_time(self)
+    }
+}
+
+impl<E: IEngine> IConstraintSystem<E> for Constant {
+    fn is_satisfied(&self) -> bool {
+        Constant::is_satisfied(self)
+    }
+
+    fn num_constraints(&self) -> usize {
+        Constant::num_constraints(self)
+    }
+
+    fn operations(&self) -> usize {
+        Constant::operations(self)
+    }
+}
+
+impl<E: IEngine, CS: IConstraintSystem<E>> IConstraintSystem<E> for Host<E, CS> {
+    fn is_satisfied(&self) -> bool {
+        self.inner().is_satisfied()
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.inner().num_constraints()
+    }
+
+    fn synthesis_time(&self) -> Option<Duration> {
+        self.inner().synthesis_time()
+    }
+}
@@ -0,0 +1,115 @@
+//!
+//! The recording constraint system.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::Index;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::bellman::Variable;
+
+use crate::constraint_systems::main::Main;
+use crate::IEngine;
+
+///
+/// The debug constraint system, which also records the values of the allocated variables in the
+/// order of allocation.
+///
+pub struct Recording<E: IEngine> {
+    inner: Main<E>,
+    variables: Vec<(bool, E::Fr)>,
+}
+
+impl<E: IEngine> Recording<E> {
+    pub fn new() -> Self {
+        Self {
+            inner: Main::new(),
+            variables: Vec::new(),
+        }
+    }
+
+    pub fn is_satisfied(&self) -> bool {
+        self.inner.is_satisfied()
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.inner.num_constraints()
+    }
+
+    ///
+    /// The allocated variables, where the flag is set for the public inputs.
+    ///
+    pub fn variables(&self) -> &[(bool, E::Fr)] {
+        self.variables.as_slice()
+    }
+
+    fn record(&mut self, variable: Variable, value: Option<E::Fr>) {
+        if let Some(value) = value {
+            let is_input = matches!(variable.get_unchecked(), Index::Input(_));
+            self.variables.push((is_input, value));
+        }
+    }
+}
+
+impl<E: IEngine> ConstraintSystem<E> for Recording<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let mut value = None;
+        let variable = self.inner.alloc(annotation, || {
+            let tmp = f()?;
+            value = Some(tmp);
+            Ok(tmp)
+        })?;
+        self.record(variable, value);
+        Ok(variable)
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let mut value = None;
+        let variable = self.inner.alloc_input(annotation, || {
+            let tmp = f()?;
+            value = Some(tmp);
+            Ok(tmp)
+        })?;
+        self.record(variable, value);
+        Ok(variable)
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.inner.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.inner.push_namespace(name_fn)
+    }
+
+    fn pop_namespace(&mut self) {
+        self.inner.pop_namespace()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
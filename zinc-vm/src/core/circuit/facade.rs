@@ -2,42 +2,114 @@
 //! The virtual machine circuit facade.
 //!
 
-use colored::Colorize;
+use std::time::Duration;
+use std::time::Instant;
+
 use num::BigInt;
 
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
-use zinc_const::UnitTestExitCode;
-
+use crate::constraint_systems::constant::Constant as ConstantCS;
 use crate::constraint_systems::main::Main as MainCS;
+use crate::constraint_systems::recording::Recording as RecordingCS;
+use crate::constraint_systems::IConstraintSystem;
 use crate::core::circuit::output::Output as CircuitOutput;
 use crate::core::circuit::State as CircuitState;
+use crate::core::execution_state::cancellation::Cancellation;
+use crate::core::execution_state::test_seed::TestSeed;
+use crate::core::unit_test::backend::Backend as UnitTestBackend;
+use crate::core::unit_test::outcome::Outcome as UnitTestOutcome;
+use crate::core::unit_test::outcome::Status as UnitTestStatus;
+use crate::core::unit_test::Report as UnitTestReport;
 use crate::core::virtual_machine::IVirtualMachine;
+use crate::core::witness::Variable as WitnessVariable;
+use crate::core::witness::VariableKind as WitnessVariableKind;
+use crate::core::witness::Witness;
 use crate::error::Error;
+use crate::gadgets::scalar::fr_bigint;
 use crate::IEngine;
 
 pub struct Facade {
     inner: zinc_types::Circuit,
+    verifying_keys: Vec<Vec<u8>>,
+    cancellation: Cancellation,
 }
 
 impl Facade {
     pub fn new(inner: zinc_types::Circuit) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            verifying_keys: vec![],
+            cancellation: Cancellation::new(),
+        }
+    }
+
+    ///
+    /// Sets the serialized verifying keys available to `std::crypto::verify_proof`.
+    ///
+    pub fn with_verifying_keys(mut self, verifying_keys: Vec<Vec<u8>>) -> Self {
+        self.verifying_keys = verifying_keys;
+        self
+    }
+
+    ///
+    /// Sets the token, which aborts the execution when it is cancelled or times out.
+    ///
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
     }
 
     pub fn run<E: IEngine>(self, input: zinc_types::Value) -> Result<CircuitOutput, Error> {
-        let cs = MainCS::<Bn256>::new();
+        self.run_with(MainCS::<Bn256>::new(), input)
+    }
 
+    ///
+    /// Runs the circuit like `run`, but aborts the execution with `Error::TimedOut` if it takes
+    /// longer than `timeout`.
+    ///
+    pub fn run_with_timeout<E: IEngine>(
+        self,
+        input: zinc_types::Value,
+        timeout: Duration,
+    ) -> Result<CircuitOutput, Error> {
+        let cancellation = self.cancellation.clone().with_timeout(timeout);
+        self.with_cancellation(cancellation).run::<E>(input)
+    }
+
+    ///
+    /// Runs the circuit like `run`, but only evaluates the instructions without synthesizing
+    /// the constraints, so their satisfiability is not checked.
+    ///
+    pub fn evaluate<E: IEngine>(self, input: zinc_types::Value) -> Result<CircuitOutput, Error> {
+        self.run_with(ConstantCS::default(), input)
+    }
+
+    ///
+    /// Runs the circuit with the constraint system `cs`.
+    ///
+    fn run_with<CS: IConstraintSystem<Bn256>>(
+        self,
+        cs: CS,
+        input: zinc_types::Value,
+    ) -> Result<CircuitOutput, Error> {
         let inputs_flat = input.into_flat_values();
         let output_type = self.inner.output.clone();
 
         let mut state = CircuitState::new(cs);
+        for verifying_key in self.verifying_keys.iter() {
+            state
+                .execution_state
+                .verifying_keys
+                .insert(verifying_key.as_slice())?;
+        }
+        state.execution_state.cancellation = self.cancellation;
 
         let mut num_constraints = 0;
         let result = state.run(
             self.inner,
             Some(&inputs_flat),
-            |cs| {
+            |cs, _address, _location| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -62,42 +134,219 @@ impl Facade {
         Ok(CircuitOutput::new(output_value))
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
+    ///
+    /// Runs the circuit like `run`, but returns the values of all the allocated variables
+    /// instead of the output.
+    ///
+    /// Each variable is labeled with the address of the instruction, which has allocated it,
+    /// and the source code location, if the bytecode has the debug information.
+    ///
+    pub fn witness<E: IEngine>(self, input: zinc_types::Value) -> Result<Witness, Error> {
+        let cs = RecordingCS::<Bn256>::new();
 
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
-            if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
-            }
+        let inputs_flat = input.into_flat_values();
 
-            let cs = MainCS::<Bn256>::new();
+        let mut state = CircuitState::new(cs);
+        for verifying_key in self.verifying_keys.iter() {
+            state
+                .execution_state
+                .verifying_keys
+                .insert(verifying_key.as_slice())?;
+        }
+        state.execution_state.cancellation = self.cancellation;
+
+        // The constraint system is first checked right after the entry call, so the variables
+        // allocated before that are the arguments.
+        let mut arguments_count = None;
+        let mut steps = Vec::new();
+        state.run(
+            self.inner,
+            Some(&inputs_flat),
+            |cs, address, location| {
+                let location = location.file.as_ref().map(|_| location.to_string());
+                steps.push((cs.variables().len(), address, location));
+            },
+            |cs| {
+                if arguments_count.is_none() {
+                    arguments_count = Some(cs.variables().len());
+                }
+
+                if !cs.is_satisfied() {
+                    return Err(Error::UnsatisfiedConstraint);
+                }
+
+                Ok(())
+            },
+        )?;
 
-            let mut state = CircuitState::new(cs);
+        let cs = state.constraint_system().inner();
+        if !cs.is_satisfied() {
+            return Err(Error::UnsatisfiedConstraint);
+        }
 
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
+        let arguments_count = arguments_count.unwrap_or_default();
+        let mut steps = steps.into_iter().peekable();
+        let mut variables = Vec::with_capacity(cs.variables().len());
+        for (index, (is_input, value)) in cs.variables().iter().enumerate() {
+            while let Some((variables_count, _, _)) = steps.peek() {
+                if *variables_count > index {
+                    break;
                 }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
+                steps.next();
+            }
+
+            let (instruction, location) = match steps.peek() {
+                Some((_, address, location)) if index >= arguments_count => {
+                    (Some(*address), location.to_owned())
                 }
+                _ => (None, None),
+            };
+            let kind = if *is_input {
+                WitnessVariableKind::Input
+            } else {
+                WitnessVariableKind::Auxiliary
+            };
+
+            variables.push(WitnessVariable::new(
+                kind,
+                fr_bigint::fr_to_bigint::<Bn256>(value, false),
+                instruction,
+                location,
+            ));
+        }
 
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
+        Ok(Witness::new(variables))
+    }
+
+    ///
+    /// Computes the witness like `witness`, but aborts the execution with `Error::TimedOut` if
+    /// it takes longer than `timeout`.
+    ///
+    pub fn witness_with_timeout<E: IEngine>(
+        self,
+        input: zinc_types::Value,
+        timeout: Duration,
+    ) -> Result<Witness, Error> {
+        let cancellation = self.cancellation.clone().with_timeout(timeout);
+        self.with_cancellation(cancellation).witness::<E>(input)
+    }
+
+    pub fn test<E: IEngine>(
+        self,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+        min_time: Option<Duration>,
+        is_fast: bool,
+        timeout: Option<Duration>,
+    ) -> Result<UnitTestReport, Error> {
+        let mut report = UnitTestReport::default();
+
+        let mut unit_tests = self.inner.unit_tests.clone();
+        UnitTestReport::sort(&mut unit_tests);
+        for (name, unit_test) in unit_tests.into_iter() {
+            let (test_file, name) = zinc_types::UnitTest::split_name(name.as_str());
+            report.start(test_file, name);
+
+            if unit_test.is_ignored {
+                report.push(
+                    UnitTestOutcome::ignored(test_file.to_owned(), name.to_owned()),
+                    min_time,
+                );
+                return Ok(report);
+            }
+
+            if self.cancellation.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
+
+            let start = Instant::now();
+            let cancellation = match timeout {
+                Some(timeout) => self.cancellation.clone().with_timeout(timeout),
+                None => self.cancellation.clone(),
+            };
+
+            let backend = UnitTestBackend::select(&unit_test, is_fast);
+            let test_seed = TestSeed::new(test_file, name);
+            let (result, synthesis_time, test_seed) = match backend {
+                UnitTestBackend::Synthesis => self.test_with(
+                    MainCS::<Bn256>::new().with_timing(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                ),
+                UnitTestBackend::Evaluation => self.test_with(
+                    ConstantCS::default(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                ),
+            };
+            let (status, detail) = match result {
+                Err(error) if matches!(error, Error::Cancelled | Error::TimedOut { .. }) => {
+                    (UnitTestStatus::Failed, Some(error.to_string()))
                 }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
+                Err(error) if unit_test.should_panic => {
+                    UnitTestStatus::panicked(unit_test.expected_panic.as_deref(), error.to_string())
                 }
+                Ok(_) if unit_test.should_panic => (
+                    UnitTestStatus::Failed,
+                    Some("should have failed".to_owned()),
+                ),
+                Ok(_) => (UnitTestStatus::Passed, None),
+                Err(error) => (UnitTestStatus::Failed, Some(error.to_string())),
             };
+            let total_time = start.elapsed();
+
+            report.push(
+                UnitTestOutcome::new(
+                    test_file.to_owned(),
+                    name.to_owned(),
+                    status,
+                    detail,
+                    backend,
+                    synthesis_time,
+                    total_time,
+                ),
+                min_time,
+            );
+            if status == UnitTestStatus::Failed {
+                if let Some(seed) = test_seed.as_ref() {
+                    seed.log_failure(name);
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
+    ///
+    /// Executes the `unit_test` with the constraint system `cs`.
+    ///
+    /// Returns the test result, the synthesis time, and the test seed.
+    ///
+    fn test_with<CS: IConstraintSystem<Bn256>>(
+        &self,
+        cs: CS,
+        unit_test: &zinc_types::UnitTest,
+        test_seed: TestSeed,
+        cancellation: Cancellation,
+        coverage: Option<&mut zinc_types::Coverage>,
+    ) -> (Result<(), Error>, Duration, Option<TestSeed>) {
+        let mut state = CircuitState::new(cs);
+        state.execution_state.cancellation = cancellation;
+
+        let mut result = state.test(self.inner.clone(), unit_test.address, test_seed, coverage);
+        let cs = state.constraint_system();
+        if result.is_ok() && !cs.is_satisfied() {
+            result = Err(Error::UnsatisfiedConstraint);
         }
+        let synthesis_time = cs.synthesis_time().unwrap_or_default();
 
-        Ok(exit_code)
+        (
+            result,
+            synthesis_time,
+            state.execution_state.test_seed.take(),
+        )
     }
 }
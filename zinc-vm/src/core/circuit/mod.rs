@@ -2,6 +2,9 @@
 //! The virtual machine circuit.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod facade;
 pub mod output;
 pub mod synthesizer;
@@ -10,16 +13,20 @@ use colored::Colorize;
 use num::bigint::ToBigInt;
 use num::BigInt;
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
+use crate::constraint_systems::host::Host as HostCS;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::counter::NamespaceCounter;
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::function_frame::Frame;
+use crate::core::execution_state::test_seed::TestSeed;
 use crate::core::execution_state::ExecutionState;
 use crate::core::location::Location;
 use crate::core::virtual_machine::IVirtualMachine;
@@ -36,7 +43,7 @@ where
     E: IEngine,
     CS: ConstraintSystem<E>,
 {
-    counter: NamespaceCounter<E, CS>,
+    counter: NamespaceCounter<E, HostCS<E, CS>>,
     execution_state: ExecutionState<E>,
     outputs: Vec<Scalar<E>>,
 
@@ -50,7 +57,7 @@ where
 {
     pub fn new(cs: CS) -> Self {
         Self {
-            counter: NamespaceCounter::new(cs),
+            counter: NamespaceCounter::new(HostCS::new(cs)),
             execution_state: ExecutionState::new(),
             outputs: vec![],
 
@@ -66,7 +73,7 @@ where
         mut check_cs: F,
     ) -> Result<Vec<Option<BigInt>>, Error>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, usize, &Location),
         F: FnMut(&CS) -> Result<(), Error>,
     {
         self.counter.cs.enforce(
@@ -83,7 +90,7 @@ where
 
         if let Err(error) = zinc_types::Call::new(circuit.address, input_size)
             .execute(self)
-            .and(check_cs(&self.counter.cs))
+            .and(check_cs(self.counter.cs.inner()))
         {
             log::error!("{}\nat {}", error, self.location.to_string().blue());
             return Err(error);
@@ -91,29 +98,26 @@ where
 
         let mut step = 0;
         while self.execution_state.instruction_counter < circuit.instructions.len() {
-            let namespace = format!(
-                "step={}, addr={}",
-                step, self.execution_state.instruction_counter
-            );
+            self.execution_state.cancellation.check_periodically(step)?;
+
+            let address = self.execution_state.instruction_counter;
+            let namespace = format!("step={}, addr={}", step, address);
             self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                circuit.instructions[self.execution_state.instruction_counter].clone();
+            let instruction = circuit.instructions[address].clone();
 
-            log::trace!(
-                "{}:{} > {}",
-                step,
-                self.execution_state.instruction_counter,
-                instruction,
-            );
+            log::trace!("{}:{} > {}", step, address, instruction);
 
             self.execution_state.instruction_counter += 1;
-            if let Err(error) = instruction.execute(self).and(check_cs(&self.counter.cs)) {
+            if let Err(error) = instruction
+                .execute(self)
+                .and(check_cs(self.counter.cs.inner()))
+            {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
                 return Err(error);
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(self.counter.cs.inner(), address, &self.location);
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -121,7 +125,15 @@ where
         self.get_outputs()
     }
 
-    pub fn test(&mut self, circuit: zinc_types::Circuit, address: usize) -> Result<(), Error> {
+    pub fn test(
+        &mut self,
+        circuit: zinc_types::Circuit,
+        address: usize,
+        test_seed: TestSeed,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+    ) -> Result<(), Error> {
+        self.execution_state.test_seed = Some(test_seed);
+
         self.counter.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -140,20 +152,25 @@ where
 
         let mut step = 0;
         while self.execution_state.instruction_counter < circuit.instructions.len() {
-            let namespace = format!(
-                "step={}, addr={}",
-                step, self.execution_state.instruction_counter
-            );
-            self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                circuit.instructions[self.execution_state.instruction_counter].clone();
+            self.execution_state.cancellation.check_periodically(step)?;
 
-            log::trace!(
-                "{}:{} > {}",
-                step,
-                self.execution_state.instruction_counter,
-                instruction,
-            );
+            let address = self.execution_state.instruction_counter;
+            let namespace = format!("step={}, addr={}", step, address);
+            self.counter.cs.push_namespace(|| namespace);
+            let instruction = circuit.instructions[address].clone();
+
+            log::trace!("{}:{} > {}", step, address, instruction);
+
+            // Only the instructions of the taken branches are covered.
+            if let Some(coverage) = coverage.as_mut() {
+                if self
+                    .condition_top()?
+                    .get_value()
+                    .map_or(false, |value| !value.is_zero())
+                {
+                    coverage.hit(address);
+                }
+            }
 
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
@@ -231,7 +248,7 @@ where
     CS: ConstraintSystem<E>,
 {
     type E = E;
-    type CS = CS;
+    type CS = HostCS<E, CS>;
     type S = SetupStorage<E>;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
@@ -298,6 +315,10 @@ where
         0
     }
 
+    fn storages_changed(&mut self) -> Result<Vec<BigInt>, Error> {
+        Ok(vec![])
+    }
+
     fn loop_begin(&mut self, iterations: usize) -> Result<(), Error> {
         let frame = self
             .execution_state
@@ -307,7 +328,9 @@ where
 
         frame.blocks.push(Block::Loop(Loop {
             first_instruction_index: self.execution_state.instruction_counter,
-            iterations_left: iterations - 1,
+            iterations_left: iterations
+                .checked_sub(1)
+                .ok_or(MalformedBytecode::LoopWithoutIterations)?,
         }));
 
         Ok(())
@@ -318,7 +341,7 @@ where
             .execution_state
             .frames_stack
             .last_mut()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or_else(|| Error::InternalError("Root frame is missing".into()))?;
 
         match frame.blocks.pop() {
             Some(Block::Loop(mut loop_block)) => {
@@ -359,7 +382,7 @@ where
             .execution_state
             .frames_stack
             .pop()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or(MalformedBytecode::UnexpectedReturn)?;
 
         if self.execution_state.frames_stack.len() == 1 {
             for cell in outputs.into_iter().rev() {
@@ -468,6 +491,18 @@ where
         Ok(())
     }
 
+    fn unsafe_witness_begin(&mut self) {
+        self.counter.cs.begin();
+    }
+
+    fn unsafe_witness_end(&mut self) -> Result<(), Error> {
+        if !self.counter.cs.end() {
+            return Err(MalformedBytecode::UnexpectedUnsafeWitnessEnd.into());
+        }
+
+        Ok(())
+    }
+
     fn call_native<F: INativeCallable<E, SetupStorage<E>>>(
         &mut self,
         function: F,
@@ -486,7 +521,7 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
-    fn constraint_system(&mut self) -> &mut CS {
+    fn constraint_system(&mut self) -> &mut HostCS<E, CS> {
         &mut self.counter.cs
     }
 
@@ -497,4 +532,12 @@ where
     fn set_location(&mut self, location: Location) {
         self.location = location;
     }
+
+    fn is_auditing(&self) -> bool {
+        self.execution_state.audit.is_some()
+    }
+
+    fn audit(&mut self, reason: AuditReason) {
+        self.execution_state.audit(reason);
+    }
 }
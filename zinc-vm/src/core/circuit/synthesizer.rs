@@ -30,7 +30,13 @@ where
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
         let mut circuit = State::new(DedupCS::new(LoggingCS::new(cs)));
-        *self.output = Some(circuit.run(self.bytecode, self.inputs.as_deref(), |_| {}, |_| Ok(())));
+        circuit.execution_state.is_proving = true;
+        *self.output = Some(circuit.run(
+            self.bytecode,
+            self.inputs.as_deref(),
+            |_, _, _| {},
+            |_| Ok(()),
+        ));
 
         Ok(())
     }
@@ -0,0 +1,161 @@
+//!
+//! The virtual machine circuit fuzzing tests.
+//!
+
+use std::panic;
+use std::path::PathBuf;
+
+use indexmap::IndexMap;
+use num::BigInt;
+use rand::Rng;
+use rand::SeedableRng;
+use rand::XorShiftRng;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::circuit::test::TestConstraintSystem;
+
+use zinc_types::Instruction;
+
+use crate::core::circuit::State;
+use crate::error::Error;
+
+/// The maximal number of the executed instructions, which stops the infinite loops.
+const STEPS_LIMIT: usize = 10_000;
+
+/// The maximal input size, which keeps the runs with mutated input types fast.
+const INPUT_SIZE_LIMIT: usize = 64;
+
+///
+/// Decodes the circuit from `bytes` and runs it with zero inputs and the bounded number of steps.
+///
+/// Returns `None` if the artifact is not a circuit or its input is too large to run, and the
+/// decoding or execution error message otherwise.
+///
+/// # Panics
+/// If the virtual machine panics, with the crashing artifact in the message.
+///
+fn run_bounded(bytes: &[u8]) -> Option<Result<(), String>> {
+    let circuit = match zinc_types::Application::try_from_slice(bytes) {
+        Ok(zinc_types::Application::Circuit(circuit)) => circuit,
+        Ok(_) => return None,
+        Err(error) => return Some(Err(error)),
+    };
+    let input_size = circuit.input.size();
+    if input_size > INPUT_SIZE_LIMIT {
+        return None;
+    }
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        let mut state =
+            State::<Bn256, TestConstraintSystem<Bn256>>::new(TestConstraintSystem::<Bn256>::new());
+        let inputs = vec![BigInt::from(0); input_size];
+
+        let mut steps = 0;
+        state
+            .run(
+                circuit,
+                Some(inputs.as_slice()),
+                |_, _, _| {},
+                |_| {
+                    steps += 1;
+                    if steps > STEPS_LIMIT {
+                        return Err(Error::InternalError("The step limit is exceeded".into()));
+                    }
+                    Ok(())
+                },
+            )
+            .map(|_| ())
+            .map_err(|error| error.to_string())
+    }));
+
+    match result {
+        Ok(result) => Some(result),
+        Err(_) => panic!(
+            "The virtual machine has panicked on `{}`",
+            hex::encode(bytes)
+        ),
+    }
+}
+
+///
+/// Creates the circuit with the most instruction kinds, which is mutated by the fuzzing tests.
+///
+fn seed() -> Vec<u8> {
+    zinc_types::Application::new_circuit(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Structure(vec![(
+            "value".to_owned(),
+            zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+        )]),
+        zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+        IndexMap::new(),
+        vec![
+            Instruction::Load(zinc_types::Load::new(0, 1)),
+            Instruction::Call(zinc_types::Call::new(3, 1)),
+            Instruction::Return(zinc_types::Return::new(1)),
+            Instruction::Push(zinc_types::Push::new_field(BigInt::from(0))),
+            Instruction::Push(zinc_types::Push::new_field(BigInt::from(0))),
+            Instruction::Store(zinc_types::Store::new(1, 2)),
+            Instruction::LoopBegin(zinc_types::LoopBegin::new(2)),
+            Instruction::Push(zinc_types::Push::new_field(BigInt::from(1))),
+            Instruction::LoadByIndex(zinc_types::LoadByIndex::new(1, 1, 2)),
+            Instruction::Cast(zinc_types::Cast::new(zinc_types::IntegerType::U8.into())),
+            Instruction::Load(zinc_types::Load::new(0, 1)),
+            Instruction::Add(zinc_types::Add::new(zinc_types::RangeCheck::Enforced)),
+            Instruction::Store(zinc_types::Store::new(0, 1)),
+            Instruction::LoopEnd(zinc_types::LoopEnd),
+            Instruction::Load(zinc_types::Load::new(0, 1)),
+            Instruction::Return(zinc_types::Return::new(1)),
+        ],
+    )
+    .into_vec(&semver::Version::new(0, 1, 0))
+}
+
+#[test]
+fn ok_fuzzing_seed() {
+    let result = run_bounded(seed().as_slice()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_fuzzing_mutated_artifacts() {
+    let seed = seed();
+    let mut random = XorShiftRng::from_seed([0x5eed, 0x0f, 0x2111, 0x7a4e]);
+
+    for _ in 0..2_000 {
+        let mut bytes = seed.clone();
+        for _ in 0..random.gen_range(1, 4) {
+            let index = random.gen_range(0, bytes.len());
+            match random.gen_range(0, 3) {
+                0 => bytes[index] = random.gen(),
+                1 => bytes[index] ^= 1 << random.gen_range(0u8, 8),
+                _ => bytes.truncate(index),
+            }
+            if bytes.is_empty() {
+                break;
+            }
+        }
+
+        let _ = run_bounded(bytes.as_slice());
+    }
+}
+
+#[test]
+fn error_regression_corpus() {
+    let corpus = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("corpus");
+
+    for entry in std::fs::read_dir(corpus).expect(zinc_const::panic::TEST_DATA_VALID) {
+        let path = entry.expect(zinc_const::panic::TEST_DATA_VALID).path();
+        let bytes = std::fs::read(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let result = run_bounded(bytes.as_slice());
+
+        assert!(
+            matches!(result, Some(Err(_))),
+            "{:?} must fail with an error",
+            path
+        );
+    }
+}
@@ -3,33 +3,46 @@
 //!
 
 use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
 
-use colored::Colorize;
 use num::BigInt;
 
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 use franklin_crypto::bellman::ConstraintSystem;
 
-use zinc_const::UnitTestExitCode;
-
 use crate::constraint_systems::constant::Constant as ConstantCS;
 use crate::constraint_systems::main::Main as MainCS;
+use crate::constraint_systems::IConstraintSystem;
 use crate::core::contract::input::Input as ContractInput;
 use crate::core::contract::output::Output as ContractOutput;
 use crate::core::contract::storage::database::Storage as DatabaseStorage;
 use crate::core::contract::storage::keeper::DummyKeeper;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::State as ContractState;
+use crate::core::execution_state::audit::Audit;
+use crate::core::execution_state::cancellation::Cancellation;
+use crate::core::execution_state::test_msg::TestMsg;
+use crate::core::execution_state::test_seed::TestSeed;
+use crate::core::unit_test::backend::Backend as UnitTestBackend;
+use crate::core::unit_test::outcome::Outcome as UnitTestOutcome;
+use crate::core::unit_test::outcome::Status as UnitTestStatus;
+use crate::core::unit_test::Report as UnitTestReport;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::fr_bigint;
 use crate::IEngine;
 
 pub struct Facade {
     inner: zinc_types::Contract,
     keeper: Box<dyn IKeeper>,
+    verifying_keys: Vec<Vec<u8>>,
+    storages: HashMap<zksync_types::Address, zinc_types::Value>,
+    is_audit: bool,
+    cancellation: Cancellation,
 }
 
 impl Facade {
@@ -40,6 +53,10 @@ impl Facade {
         Self {
             inner,
             keeper: Box::new(DummyKeeper::default()),
+            verifying_keys: vec![],
+            storages: HashMap::new(),
+            is_audit: false,
+            cancellation: Cancellation::new(),
         }
     }
 
@@ -47,31 +64,126 @@ impl Facade {
     /// A shortcut constructor.
     ///
     pub fn new_with_keeper(inner: zinc_types::Contract, keeper: Box<dyn IKeeper>) -> Self {
-        Self { inner, keeper }
+        Self {
+            inner,
+            keeper,
+            verifying_keys: vec![],
+            storages: HashMap::new(),
+            is_audit: false,
+            cancellation: Cancellation::new(),
+        }
+    }
+
+    ///
+    /// Sets the serialized verifying keys available to `std::crypto::verify_proof`.
+    ///
+    pub fn with_verifying_keys(mut self, verifying_keys: Vec<Vec<u8>>) -> Self {
+        self.verifying_keys = verifying_keys;
+        self
+    }
+
+    ///
+    /// Sets the contract storages available to the unit tests, e.g. the ones loaded from a dump.
+    ///
+    pub fn with_storages(
+        mut self,
+        storages: HashMap<zksync_types::Address, zinc_types::Value>,
+    ) -> Self {
+        self.storages = storages;
+        self
+    }
+
+    ///
+    /// Enables the constant-time audit mode, whose report is returned with the `run` output.
+    ///
+    pub fn with_audit(mut self) -> Self {
+        self.is_audit = true;
+        self
+    }
+
+    ///
+    /// Sets the token, which aborts the execution when it is cancelled or times out.
+    ///
+    /// The storage writes of an aborted method are discarded.
+    ///
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
     }
 
     pub fn run<E: IEngine>(self, input: ContractInput) -> Result<ContractOutput, Error> {
-        let mut cs = ConstantCS {};
+        self.run_with(ConstantCS::default(), input)
+    }
+
+    ///
+    /// Runs the contract method like `run`, but aborts the execution with `Error::TimedOut` if
+    /// it takes longer than `timeout`.
+    ///
+    pub fn run_with_timeout<E: IEngine>(
+        self,
+        input: ContractInput,
+        timeout: Duration,
+    ) -> Result<ContractOutput, Error> {
+        let cancellation = self.cancellation.clone().with_timeout(timeout);
+        self.with_cancellation(cancellation).run::<E>(input)
+    }
+
+    ///
+    /// Runs the contract method like `run`, but also synthesizes the constraints and checks
+    /// their satisfiability.
+    ///
+    pub fn synthesize<E: IEngine>(self, input: ContractInput) -> Result<ContractOutput, Error> {
+        self.run_with(MainCS::<Bn256>::new(), input)
+    }
 
+    ///
+    /// Synthesizes the contract method like `synthesize`, but aborts the execution with
+    /// `Error::TimedOut` if it takes longer than `timeout`.
+    ///
+    pub fn synthesize_with_timeout<E: IEngine>(
+        self,
+        input: ContractInput,
+        timeout: Duration,
+    ) -> Result<ContractOutput, Error> {
+        let cancellation = self.cancellation.clone().with_timeout(timeout);
+        self.with_cancellation(cancellation).synthesize::<E>(input)
+    }
+
+    ///
+    /// Runs the contract method with the constraint system `cs`.
+    ///
+    fn run_with<CS: IConstraintSystem<Bn256>>(
+        self,
+        mut cs: CS,
+        input: ContractInput,
+    ) -> Result<ContractOutput, Error> {
         let method = self
             .inner
             .methods
             .get(input.method_name.as_str())
             .cloned()
-            .ok_or(Error::MethodNotFound {
-                found: input.method_name.clone(),
+            .ok_or_else(|| match self.inner.pruned_from {
+                Some(ref original) => Error::MethodPruned {
+                    found: input.method_name.clone(),
+                    original: original.to_owned(),
+                },
+                None => Error::MethodNotFound {
+                    found: input.method_name.clone(),
+                },
             })?;
+        let is_constructor = method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER
+            || self.inner.upgrade.as_deref() == Some(method.name.as_str());
         let arguments_flat = input.arguments.into_flat_values();
         let output_type = if method.is_mutable {
             method.output.into_mutable_method_output()
-        } else if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+        } else if is_constructor {
             zinc_types::Type::eth_address()
         } else {
             method.output
         };
 
         let mut storages = HashMap::with_capacity(1);
-        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+        if !is_constructor {
             for (address, storage) in input.storages.into_iter() {
                 let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
                 let storage =
@@ -83,13 +195,26 @@ impl Facade {
         }
 
         let mut state = ContractState::new(cs, storages, self.keeper, input.transaction);
+        for verifying_key in self.verifying_keys.iter() {
+            state
+                .execution_state
+                .verifying_keys
+                .insert(verifying_key.as_slice())?;
+        }
+        if self.is_audit {
+            state.execution_state.audit = Some(Audit::new());
+        }
+        state.execution_state.cancellation = self.cancellation;
 
         let mut num_constraints = 0;
+        let mut instructions = 0;
         let result = state.run(
             self.inner,
             method.input,
             Some(&arguments_flat),
             |cs| {
+                instructions += 1;
+
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -109,14 +234,36 @@ impl Facade {
             return Err(Error::UnsatisfiedConstraint);
         }
 
+        let operations = cs.operations()
+            + state
+                .storages
+                .values()
+                .map(|storage| storage.operations())
+                .sum::<usize>();
+        let audit = state
+            .execution_state
+            .audit
+            .take()
+            .map(|audit| audit.into_report(operations));
+
         let output_value: Vec<BigInt> = result.into_iter().filter_map(|value| value).collect();
         let output_value = zinc_types::Value::from_flat_values(output_type, &output_value);
 
+        let storage_roots = state
+            .storages
+            .iter()
+            .map(|(address, storage)| {
+                (
+                    address.to_owned(),
+                    fr_bigint::fr_to_bigint::<Bn256>(&storage.current_root_hash(), false),
+                )
+            })
+            .collect();
         let storages = state
             .storages
             .into_iter()
-            .map(|(address, storage)| (address, storage.into_build()))
-            .collect();
+            .map(|(address, storage)| storage.into_build().map(|build| (address, build)))
+            .collect::<Result<_, Error>>()?;
 
         let transfers = state.execution_state.transfers;
         let initializers = state.execution_state.initializers;
@@ -124,52 +271,169 @@ impl Facade {
         Ok(ContractOutput::new(
             output_value,
             storages,
+            storage_roots,
             transfers,
             initializers,
+            instructions,
+        )
+        .with_audit(audit))
+    }
+
+    ///
+    /// Computes the storage Merkle tree root hash, which the contract has with the `storage` data.
+    ///
+    pub fn storage_root_hash(&self, storage: zinc_types::Value) -> Result<BigInt, Error> {
+        let storage = DatabaseStorage::<Bn256>::from_build(self.inner.storage.clone(), storage)?;
+
+        Ok(fr_bigint::fr_to_bigint::<Bn256>(
+            &storage.root_hash(),
+            false,
         ))
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
+    pub fn test<E: IEngine>(
+        self,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+        min_time: Option<Duration>,
+        is_fast: bool,
+        timeout: Option<Duration>,
+    ) -> Result<UnitTestReport, Error> {
+        let mut report = UnitTestReport::default();
+
+        let mut unit_tests = self.inner.unit_tests.clone();
+        UnitTestReport::sort(&mut unit_tests);
+        for (name, unit_test) in unit_tests.into_iter() {
+            let (test_file, name) = zinc_types::UnitTest::split_name(name.as_str());
+            report.start(test_file, name);
 
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
             if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
+                report.push(
+                    UnitTestOutcome::ignored(test_file.to_owned(), name.to_owned()),
+                    min_time,
+                );
+                return Ok(report);
             }
 
-            let cs = MainCS::<Bn256>::new();
+            if self.cancellation.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
 
-            let mut state = ContractState::<_, _, DatabaseStorage<_>, Sha256Hasher>::new(
-                cs,
-                HashMap::with_capacity(1),
-                Box::new(DummyKeeper::default()),
-                unit_test.zksync_msg.unwrap_or_default(),
-            );
+            let start = Instant::now();
+            let cancellation = match timeout {
+                Some(timeout) => self.cancellation.clone().with_timeout(timeout),
+                None => self.cancellation.clone(),
+            };
 
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
+            let backend = UnitTestBackend::select(&unit_test, is_fast);
+            let test_seed = TestSeed::new(test_file, name);
+            let (result, synthesis_time, test_seed) = match backend {
+                UnitTestBackend::Synthesis => self.test_with(
+                    MainCS::<Bn256>::new().with_timing(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                )?,
+                UnitTestBackend::Evaluation => self.test_with(
+                    ConstantCS::default(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                )?,
+            };
+            let (status, detail) = match result {
+                Err(error) if matches!(error, Error::Cancelled | Error::TimedOut { .. }) => {
+                    (UnitTestStatus::Failed, Some(error.to_string()))
                 }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
+                Err(error) if unit_test.should_panic => {
+                    UnitTestStatus::panicked(unit_test.expected_panic.as_deref(), error.to_string())
                 }
+                Ok(_) if unit_test.should_panic => (
+                    UnitTestStatus::Failed,
+                    Some("should have failed".to_owned()),
+                ),
+                Ok(_) => (UnitTestStatus::Passed, None),
+                Err(error) => (UnitTestStatus::Failed, Some(error.to_string())),
+            };
+            let total_time = start.elapsed();
 
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
-                }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
+            report.push(
+                UnitTestOutcome::new(
+                    test_file.to_owned(),
+                    name.to_owned(),
+                    status,
+                    detail,
+                    backend,
+                    synthesis_time,
+                    total_time,
+                ),
+                min_time,
+            );
+            if status == UnitTestStatus::Failed {
+                if let Some(seed) = test_seed.as_ref() {
+                    seed.log_failure(name);
                 }
-            };
+            }
         }
 
-        Ok(exit_code)
+        Ok(report)
+    }
+
+    ///
+    /// Executes the `unit_test` with the constraint system `cs`.
+    ///
+    /// Returns the test result, the synthesis time, and the test seed.
+    ///
+    fn test_with<CS: IConstraintSystem<Bn256>>(
+        &self,
+        mut cs: CS,
+        unit_test: &zinc_types::UnitTest,
+        test_seed: TestSeed,
+        cancellation: Cancellation,
+        coverage: Option<&mut zinc_types::Coverage>,
+    ) -> Result<(Result<(), Error>, Duration, Option<TestSeed>), Error> {
+        let mut storages = HashMap::with_capacity(self.storages.len());
+        for (address, storage) in self.storages.iter() {
+            let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
+            let storage = DatabaseStorage::<Bn256>::from_build(
+                self.inner.storage.clone(),
+                storage.to_owned(),
+            )?;
+            let storage_gadget =
+                StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
+            storages.insert(address, storage_gadget);
+        }
+
+        let mut state = ContractState::<_, _, DatabaseStorage<_>, Sha256Hasher>::new(
+            cs,
+            storages,
+            Box::new(DummyKeeper::default()),
+            unit_test.zksync_msg.clone().unwrap_or_default(),
+        );
+        state.execution_state.cancellation = cancellation;
+
+        let test_msg = unit_test
+            .zksync_msg
+            .clone()
+            .map(|msg| TestMsg::new(msg, unit_test.zksync_msg_location.clone()));
+        let mut result = state.test(
+            self.inner.clone(),
+            unit_test.address,
+            test_msg,
+            test_seed,
+            coverage,
+        );
+        let cs = state.constraint_system();
+        if result.is_ok() && !cs.is_satisfied() {
+            result = Err(Error::UnsatisfiedConstraint);
+        }
+        let synthesis_time = cs.synthesis_time().unwrap_or_default();
+
+        Ok((
+            result,
+            synthesis_time,
+            state.execution_state.test_seed.take(),
+        ))
     }
 }
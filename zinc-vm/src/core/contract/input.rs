@@ -10,6 +10,7 @@ use zksync_types::Address;
 ///
 /// The virtual machine contract input.
 ///
+#[derive(Clone)]
 pub struct Input {
     /// The contract method arguments, which is witness for now.
     pub arguments: zinc_types::Value,
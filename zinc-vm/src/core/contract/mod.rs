@@ -2,6 +2,9 @@
 //! The virtual machine contract.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod facade;
 pub mod input;
 pub mod output;
@@ -16,17 +19,22 @@ use num::bigint::ToBigInt;
 use num::BigInt;
 use num::Zero;
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
+use crate::constraint_systems::host::Host as HostCS;
 use crate::core::contract::output::initializer::Initializer;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::counter::NamespaceCounter;
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::function_frame::Frame;
+use crate::core::execution_state::test_msg::TestMsg;
+use crate::core::execution_state::test_seed::TestSeed;
 use crate::core::execution_state::ExecutionState;
 use crate::core::location::Location;
 use crate::core::virtual_machine::IVirtualMachine;
@@ -48,11 +56,12 @@ where
     S: IMerkleTree<E>,
     H: IMerkleTreeHasher<E>,
 {
-    counter: NamespaceCounter<E, CS>,
+    counter: NamespaceCounter<E, HostCS<E, CS>>,
     execution_state: ExecutionState<E>,
     outputs: Vec<Scalar<E>>,
 
     storages: HashMap<BigInt, StorageGadget<E, S, H>>,
+    storage_snapshots: HashMap<BigInt, E::Fr>,
     keeper: Box<dyn IKeeper>,
     transaction: zinc_types::TransactionMsg,
 
@@ -72,12 +81,18 @@ where
         keeper: Box<dyn IKeeper>,
         transaction: zinc_types::TransactionMsg,
     ) -> Self {
+        let storage_snapshots = storages
+            .iter()
+            .map(|(address, storage)| (address.to_owned(), storage.current_root_hash()))
+            .collect();
+
         Self {
-            counter: NamespaceCounter::new(cs),
+            counter: NamespaceCounter::new(HostCS::new(cs)),
             execution_state: ExecutionState::new(),
             outputs: vec![],
 
             storages,
+            storage_snapshots,
             keeper,
             transaction,
 
@@ -112,7 +127,7 @@ where
 
         if let Err(error) = zinc_types::Call::new(address, input_size)
             .execute(self)
-            .and(check_cs(&self.counter.cs))
+            .and(check_cs(self.counter.cs.inner()))
         {
             log::error!("{}\nat {}", error, self.location.to_string().blue());
             return Err(error);
@@ -121,6 +136,8 @@ where
         let mut step = 0;
         let execution_time = std::time::Instant::now();
         while self.execution_state.instruction_counter < contract.instructions.len() {
+            self.execution_state.cancellation.check_periodically(step)?;
+
             let namespace = format!(
                 "step={}, addr={}",
                 step, self.execution_state.instruction_counter
@@ -136,14 +153,24 @@ where
                 instruction,
             );
 
+            if let Some(audit) = self.execution_state.audit.as_mut() {
+                audit.step(
+                    self.execution_state.instruction_counter,
+                    self.location.clone(),
+                );
+            }
+
             self.execution_state.instruction_counter += 1;
-            if let Err(error) = instruction.execute(self).and(check_cs(&self.counter.cs)) {
+            if let Err(error) = instruction
+                .execute(self)
+                .and(check_cs(self.counter.cs.inner()))
+            {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
                 return Err(error);
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(self.counter.cs.inner());
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -153,10 +180,21 @@ where
             execution_time.elapsed().as_micros()
         );
 
+        self.flush_storages()?;
         self.get_outputs()
     }
 
-    pub fn test(&mut self, contract: zinc_types::Contract, address: usize) -> Result<(), Error> {
+    pub fn test(
+        &mut self,
+        contract: zinc_types::Contract,
+        address: usize,
+        test_msg: Option<TestMsg>,
+        test_seed: TestSeed,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+    ) -> Result<(), Error> {
+        self.execution_state.test_msg = test_msg;
+        self.execution_state.test_seed = Some(test_seed);
+
         self.counter.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -175,20 +213,25 @@ where
 
         let mut step = 0;
         while self.execution_state.instruction_counter < contract.instructions.len() {
-            let namespace = format!(
-                "step={}, addr={}",
-                step, self.execution_state.instruction_counter
-            );
-            self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                contract.instructions[self.execution_state.instruction_counter].clone();
+            self.execution_state.cancellation.check_periodically(step)?;
 
-            log::trace!(
-                "{}:{} > {}",
-                step,
-                self.execution_state.instruction_counter,
-                instruction,
-            );
+            let address = self.execution_state.instruction_counter;
+            let namespace = format!("step={}, addr={}", step, address);
+            self.counter.cs.push_namespace(|| namespace);
+            let instruction = contract.instructions[address].clone();
+
+            log::trace!("{}:{} > {}", step, address, instruction);
+
+            // Only the instructions of the taken branches are covered.
+            if let Some(coverage) = coverage.as_mut() {
+                if self
+                    .condition_top()?
+                    .get_value()
+                    .map_or(false, |value| !value.is_zero())
+                {
+                    coverage.hit(address);
+                }
+            }
 
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
@@ -201,6 +244,17 @@ where
             step += 1;
         }
 
+        self.flush_storages()
+    }
+
+    ///
+    /// Applies the buffered storage writes, which are made during the method execution.
+    ///
+    fn flush_storages(&mut self) -> Result<(), Error> {
+        for storage in self.storages.values_mut() {
+            storage.flush()?;
+        }
+
         Ok(())
     }
 
@@ -278,7 +332,7 @@ where
     H: IMerkleTreeHasher<E>,
 {
     type E = E;
-    type CS = CS;
+    type CS = HostCS<E, CS>;
     type S = S;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
@@ -312,6 +366,7 @@ where
         mut values: Vec<Scalar<Self::E>>,
         field_types: Vec<zinc_types::ContractFieldType>,
     ) -> Result<Scalar<Self::E>, Error> {
+        self.execution_state.audit(AuditReason::StorageInit);
         if self
             .condition_top()?
             .to_bigint()
@@ -324,10 +379,24 @@ where
             ));
         }
 
-        let eth_private_key = self.keeper.generate();
-        let eth_address: zksync_types::Address =
-            zksync_types::tx::PackedEthSignature::address_from_private_key(&eth_private_key)
-                .expect(zinc_const::panic::DATA_CONVERSION);
+        // the unit test instances are never published, so the bound address needs no key
+        let (eth_private_key, eth_address) = match self
+            .execution_state
+            .test_msg
+            .as_mut()
+            .and_then(TestMsg::bind_recipient)
+        {
+            Some(eth_address) => (zksync_types::H256::default(), eth_address),
+            None => {
+                let eth_private_key = self.keeper.generate();
+                let eth_address: zksync_types::Address =
+                    zksync_types::tx::PackedEthSignature::address_from_private_key(
+                        &eth_private_key,
+                    )
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+                (eth_private_key, eth_address)
+            }
+        };
         let eth_address_bigint =
             BigInt::from_bytes_be(num::bigint::Sign::Plus, eth_address.as_bytes());
 
@@ -339,6 +408,10 @@ where
 
         let storage = Self::S::from_evaluation_stack(field_types.clone(), values)?;
         let storage_gadget = StorageGadget::new(self.counter.next(), storage)?;
+        self.storage_snapshots.insert(
+            eth_address_bigint.clone(),
+            storage_gadget.current_root_hash(),
+        );
         self.storages.insert(eth_address_bigint, storage_gadget);
 
         self.execution_state.initializers.push(Initializer::new(
@@ -363,7 +436,7 @@ where
 
         if self.storages.contains_key(&eth_address) {
             return Err(Error::ContractAlreadyFetched {
-                address: eth_address.to_str_radix(zinc_const::base::HEXADECIMAL),
+                address: zinc_types::scalar_format::address(&eth_address),
             });
         }
 
@@ -372,6 +445,8 @@ where
             .fetch(eth_address.clone(), field_types.clone())?;
         let storage = Self::S::from_build(field_types, storage)?;
         let storage_gadget = StorageGadget::new(self.counter.next(), storage)?;
+        self.storage_snapshots
+            .insert(eth_address.clone(), storage_gadget.current_root_hash());
         self.storages.insert(eth_address, storage_gadget);
 
         Ok(())
@@ -383,14 +458,24 @@ where
         index: Scalar<Self::E>,
         size: usize,
     ) -> Result<Vec<Scalar<Self::E>>, Error> {
-        self.storages
+        let is_auditing = self.is_auditing();
+        let storage = self
+            .storages
             .get_mut(
                 &eth_address
                     .to_bigint()
                     .expect(zinc_const::panic::DATA_CONVERSION),
             )
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .load(self.counter.next(), index, size)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        // the cache and write buffer hits depend on the witness, e.g. on the stores
+        // in the branches not taken
+        if is_auditing {
+            storage.flush()?;
+            storage.clear_cache();
+        }
+
+        storage.load(self.counter.next(), index, size)
     }
 
     fn storage_store(
@@ -399,20 +484,46 @@ where
         index: Scalar<Self::E>,
         values: LeafVariant<Self::E>,
     ) -> Result<(), Error> {
-        self.storages
+        let is_auditing = self.is_auditing();
+        let storage = self
+            .storages
             .get_mut(
                 &eth_address
                     .to_bigint()
                     .expect(zinc_const::panic::DATA_CONVERSION),
             )
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .store(self.counter.next(), index, values)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        storage.store(self.counter.next(), index, values)?;
+
+        // the writes are not buffered, so the write buffer hits do not depend on the witness
+        if is_auditing {
+            storage.flush()?;
+        }
+
+        Ok(())
     }
 
     fn storages_count(&self) -> usize {
         self.storages.len()
     }
 
+    fn storages_changed(&mut self) -> Result<Vec<BigInt>, Error> {
+        // the buffered writes are not reflected in the root hashes until they are applied
+        self.flush_storages()?;
+
+        let mut addresses: Vec<BigInt> = self
+            .storages
+            .iter()
+            .filter(|(address, storage)| {
+                self.storage_snapshots.get(*address) != Some(&storage.current_root_hash())
+            })
+            .map(|(address, _storage)| address.to_owned())
+            .collect();
+        addresses.sort();
+        Ok(addresses)
+    }
+
     fn loop_begin(&mut self, iterations: usize) -> Result<(), Error> {
         let frame = self
             .execution_state
@@ -422,7 +533,9 @@ where
 
         frame.blocks.push(Block::Loop(Loop {
             first_instruction_index: self.execution_state.instruction_counter,
-            iterations_left: iterations - 1,
+            iterations_left: iterations
+                .checked_sub(1)
+                .ok_or(MalformedBytecode::LoopWithoutIterations)?,
         }));
 
         Ok(())
@@ -433,7 +546,7 @@ where
             .execution_state
             .frames_stack
             .last_mut()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or_else(|| Error::InternalError("Root frame is missing".into()))?;
 
         match frame.blocks.pop() {
             Some(Block::Loop(mut loop_block)) => {
@@ -544,7 +657,7 @@ where
             .execution_state
             .frames_stack
             .pop()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or(MalformedBytecode::UnexpectedReturn)?;
 
         if self.execution_state.frames_stack.len() == 1 {
             for cell in outputs.into_iter().rev() {
@@ -652,7 +765,21 @@ where
         Ok(())
     }
 
+    fn unsafe_witness_begin(&mut self) {
+        self.counter.cs.begin();
+    }
+
+    fn unsafe_witness_end(&mut self) -> Result<(), Error> {
+        if !self.counter.cs.end() {
+            return Err(MalformedBytecode::UnexpectedUnsafeWitnessEnd.into());
+        }
+
+        Ok(())
+    }
+
     fn call_native<F: INativeCallable<E, S>>(&mut self, function: F) -> Result<(), Error> {
+        self.flush_storages()?;
+
         let state = &mut self.execution_state;
         let cs = &mut self.counter.cs;
 
@@ -676,7 +803,7 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
-    fn constraint_system(&mut self) -> &mut CS {
+    fn constraint_system(&mut self) -> &mut HostCS<E, CS> {
         &mut self.counter.cs
     }
 
@@ -687,4 +814,12 @@ where
     fn set_location(&mut self, location: Location) {
         self.location = location;
     }
+
+    fn is_auditing(&self) -> bool {
+        self.execution_state.audit.is_some()
+    }
+
+    fn audit(&mut self, reason: AuditReason) {
+        self.execution_state.audit(reason);
+    }
 }
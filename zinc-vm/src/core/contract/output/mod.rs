@@ -8,6 +8,8 @@ use std::collections::HashMap;
 
 use num::BigInt;
 
+use crate::core::execution_state::audit::Report as AuditReport;
+
 use self::initializer::Initializer;
 
 ///
@@ -19,10 +21,16 @@ pub struct Output {
     pub result: zinc_types::Value,
     /// The contract storage after executing a method.
     pub storages: HashMap<BigInt, zinc_types::Value>,
+    /// The contract storage Merkle tree root hashes after executing a method.
+    pub storage_roots: HashMap<BigInt, BigInt>,
     /// The transfers executed during the method execution.
     pub transfers: Vec<zinc_types::TransactionMsg>,
     /// The contract initializers created during the method execution.
     pub initializers: Vec<Initializer>,
+    /// The constant-time audit report, if the audit mode is enabled.
+    pub audit: Option<AuditReport>,
+    /// The number of the executed instructions, which is the method execution cost.
+    pub instructions: usize,
 }
 
 impl Output {
@@ -32,14 +40,27 @@ impl Output {
     pub fn new(
         result: zinc_types::Value,
         storages: HashMap<BigInt, zinc_types::Value>,
+        storage_roots: HashMap<BigInt, BigInt>,
         transfers: Vec<zinc_types::TransactionMsg>,
         initializers: Vec<Initializer>,
+        instructions: usize,
     ) -> Self {
         Self {
             result,
             storages,
+            storage_roots,
             transfers,
             initializers,
+            audit: None,
+            instructions,
         }
     }
+
+    ///
+    /// Sets the constant-time audit report.
+    ///
+    pub fn with_audit(mut self, audit: Option<AuditReport>) -> Self {
+        self.audit = audit;
+        self
+    }
 }
@@ -26,30 +26,33 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         field_types: Vec<zinc_types::ContractFieldType>,
         mut values: Vec<Scalar<E>>,
     ) -> Result<Self, Error> {
-        let mut storage_leaves = Vec::with_capacity(field_types.len());
+        let leaves_count = field_types.iter().map(|field| field.leaves.len()).sum();
+        let mut storage_leaves = Vec::with_capacity(leaves_count);
         for field_type in field_types.iter() {
-            let leaf = match field_type.r#type {
+            match field_type.r#type {
                 zinc_types::Type::Map {
                     ref key_type,
                     ref value_type,
-                } => LeafInput::Map {
+                } => storage_leaves.push(LeafInput::Map {
                     key_type: *key_type.to_owned(),
                     value_type: *value_type.to_owned(),
                     entries: vec![],
-                },
-                ref r#type => {
-                    let values: Vec<BigInt> = values
-                        .drain(..r#type.size())
-                        .map(|value| value.to_bigint().expect(zinc_const::panic::DATA_CONVERSION))
-                        .collect();
-                    LeafInput::Array {
-                        r#type: r#type.to_owned(),
-                        values,
+                }),
+                _ => {
+                    for leaf in field_type.leaves.iter() {
+                        let values: Vec<BigInt> = values
+                            .drain(..leaf.r#type.size())
+                            .map(|value| {
+                                value.to_bigint().expect(zinc_const::panic::DATA_CONVERSION)
+                            })
+                            .collect();
+                        storage_leaves.push(LeafInput::Array {
+                            r#type: leaf.storage_type(),
+                            values: leaf.pack_values(values),
+                        });
                     }
                 }
-            };
-
-            storage_leaves.push(leaf);
+            }
         }
 
         let depth = (storage_leaves.len() as f64).log2().ceil() as usize;
@@ -72,46 +75,47 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         field_types: Vec<zinc_types::ContractFieldType>,
         value: zinc_types::Value,
     ) -> Result<Self, Error> {
-        let storage_leaves = match value {
-            zinc_types::Value::Contract(fields) => fields
-                .into_iter()
-                .enumerate()
-                .map(|(index, field)| {
-                    let r#type = field_types[index].r#type.to_owned();
-
-                    match field.value {
-                        zinc_types::Value::Map(map) => {
-                            let (key_type, value_type) = match r#type {
-                                zinc_types::Type::Map {
-                                    key_type,
-                                    value_type,
-                                } => (*key_type, *value_type),
-                                _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                            };
-
-                            let entries = map
-                                .into_iter()
-                                .map(|(key, value)| {
-                                    (key.into_flat_values(), value.into_flat_values())
-                                })
-                                .collect();
-                            LeafInput::Map {
-                                key_type,
-                                value_type,
-                                entries,
-                            }
-                        }
-                        value => {
-                            let mut values = value.into_flat_values();
-                            values.reverse();
-                            LeafInput::Array { r#type, values }
-                        }
-                    }
-                })
-                .collect::<Vec<LeafInput>>(),
+        let fields = match value {
+            zinc_types::Value::Contract(fields) => fields,
             _ => return Err(Error::InvalidStorageValue),
         };
 
+        let leaves_count = field_types.iter().map(|field| field.leaves.len()).sum();
+        let mut storage_leaves = Vec::with_capacity(leaves_count);
+        for (index, field) in fields.into_iter().enumerate() {
+            match field.value {
+                zinc_types::Value::Map(map) => {
+                    let (key_type, value_type) = match field_types[index].r#type.to_owned() {
+                        zinc_types::Type::Map {
+                            key_type,
+                            value_type,
+                        } => (*key_type, *value_type),
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    let entries = map
+                        .into_iter()
+                        .map(|(key, value)| (key.into_flat_values(), value.into_flat_values()))
+                        .collect();
+                    storage_leaves.push(LeafInput::Map {
+                        key_type,
+                        value_type,
+                        entries,
+                    });
+                }
+                value => {
+                    let leaves = field_types[index].pack_leaves(value.into_flat_values());
+                    for (leaf, mut values) in field_types[index].leaves.iter().zip(leaves) {
+                        values.reverse();
+                        storage_leaves.push(LeafInput::Array {
+                            r#type: leaf.storage_type(),
+                            values,
+                        });
+                    }
+                }
+            }
+        }
+
         let depth = (storage_leaves.len() as f64).log2().ceil() as usize;
         let hash_tree_size = 1 << (depth + 1);
 
@@ -130,6 +134,13 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
 
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
+        if index >= self.leaf_values.len() {
+            return Err(Error::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: self.leaf_values.len(),
+                found: index,
+            });
+        }
 
         Ok(Leaf::new(
             self.leaf_values[index].to_owned(),
@@ -140,6 +151,13 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
 
     fn store(&mut self, index: BigInt, value: LeafVariant<E>) -> Result<(), Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
+        if index >= self.leaf_values.len() {
+            return Err(Error::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: self.leaf_values.len(),
+                found: index,
+            });
+        }
 
         self.leaf_values[index] = value;
 
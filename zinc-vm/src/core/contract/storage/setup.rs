@@ -18,61 +18,62 @@ pub struct Storage<E: IEngine> {
     depth: usize,
 }
 
-impl<E: IEngine> IMerkleTree<E> for Storage<E> {
-    fn from_evaluation_stack(
-        field_types: Vec<zinc_types::ContractFieldType>,
-        _values: Vec<Scalar<E>>,
-    ) -> Result<Self, Error> {
-        let depth = (field_types.len() as f64).log2().ceil() as usize;
+impl<E: IEngine> Storage<E> {
+    ///
+    /// Creates the storage with zero leaves of the `field_types` layout.
+    ///
+    fn new(field_types: Vec<zinc_types::ContractFieldType>) -> Self {
+        let leaves: Vec<zinc_types::ContractStorageLeaf> = field_types
+            .iter()
+            .map(|field| field.leaves.to_owned())
+            .flatten()
+            .collect();
+
+        let depth = (leaves.len() as f64).log2().ceil() as usize;
         let leaf_values_count = 1 << depth;
 
-        let mut result = Self {
-            field_types: field_types.clone(),
-            leaf_values: vec![vec![]; leaf_values_count],
-            depth,
-        };
-
-        for (index, field) in field_types.into_iter().enumerate() {
-            let values = field
-                .r#type
+        let mut leaf_values = vec![vec![]; leaf_values_count];
+        for (index, leaf) in leaves.into_iter().enumerate() {
+            leaf_values[index] = leaf
+                .storage_type()
                 .into_flat_scalar_types()
                 .into_iter()
                 .map(|r#type| Scalar::<E>::new_constant_usize(0, r#type))
                 .collect();
-            result.leaf_values[index] = values;
         }
 
-        Ok(result)
+        Self {
+            field_types,
+            leaf_values,
+            depth,
+        }
+    }
+}
+
+impl<E: IEngine> IMerkleTree<E> for Storage<E> {
+    fn from_evaluation_stack(
+        field_types: Vec<zinc_types::ContractFieldType>,
+        _values: Vec<Scalar<E>>,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(field_types))
     }
 
     fn from_build(
         field_types: Vec<zinc_types::ContractFieldType>,
         _value: zinc_types::Value,
     ) -> Result<Self, Error> {
-        let depth = (field_types.len() as f64).log2().ceil() as usize;
-        let leaf_values_count = 1 << depth;
-
-        let mut result = Self {
-            field_types: field_types.clone(),
-            leaf_values: vec![vec![]; leaf_values_count],
-            depth,
-        };
-
-        for (index, field) in field_types.into_iter().enumerate() {
-            let values = field
-                .r#type
-                .into_flat_scalar_types()
-                .into_iter()
-                .map(|r#type| Scalar::<E>::new_constant_usize(0, r#type))
-                .collect();
-            result.leaf_values[index] = values;
-        }
-
-        Ok(result)
+        Ok(Self::new(field_types))
     }
 
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
+        if index >= self.leaf_values.len() {
+            return Err(Error::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: self.leaf_values.len(),
+                found: index,
+            });
+        }
 
         Ok(Leaf::new(
             LeafVariant::Array(self.leaf_values[index].to_owned()),
@@ -83,6 +84,13 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
 
     fn store(&mut self, index: BigInt, value: LeafVariant<E>) -> Result<(), Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
+        if index >= self.leaf_values.len() {
+            return Err(Error::IndexOutOfBounds {
+                lower_bound: 0,
+                upper_bound: self.leaf_values.len(),
+                found: index,
+            });
+        }
 
         self.leaf_values[index] = match value {
             LeafVariant::Array(array) => array,
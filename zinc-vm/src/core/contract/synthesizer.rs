@@ -45,6 +45,7 @@ where
             self.keeper,
             self.transaction,
         );
+        contract.execution_state.is_proving = true;
 
         *self.output = Some(contract.run(
             self.bytecode,
@@ -0,0 +1,205 @@
+//!
+//! The virtual machine contract tests.
+//!
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use num::BigInt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use crate::core::contract::facade::Facade;
+use crate::core::contract::input::Input;
+use crate::core::unit_test::outcome::Status as UnitTestStatus;
+use crate::error::Error;
+
+/// The circuit hash of the complete contract bytecode file.
+const CIRCUIT_HASH: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+fn method(name: &str, address: usize) -> zinc_types::ContractMethod {
+    zinc_types::ContractMethod::new(
+        0,
+        name.to_owned(),
+        address,
+        false,
+        false,
+        None,
+        zinc_types::Type::Structure(vec![]),
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+    )
+}
+
+///
+/// Creates the contract, whose `query` method calls the function shared with the `other` one,
+/// and the `other` method also calls a function of its own.
+///
+fn contract() -> zinc_types::Contract {
+    let mut methods = IndexMap::new();
+    methods.insert("other".to_owned(), method("other", 0));
+    methods.insert("query".to_owned(), method("query", 6));
+
+    zinc_types::Contract::new(
+        "test".to_owned(),
+        vec![],
+        methods,
+        IndexMap::new(),
+        vec![
+            zinc_types::Instruction::Call(zinc_types::Call::new(4, 0)),
+            zinc_types::Instruction::Call(zinc_types::Call::new(10, 0)),
+            zinc_types::Instruction::Add(zinc_types::Add::default()),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+            zinc_types::Instruction::Push(zinc_types::Push::new_field(BigInt::from(5))),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+            zinc_types::Instruction::Call(zinc_types::Call::new(10, 0)),
+            zinc_types::Instruction::Push(zinc_types::Push::new_field(BigInt::from(3))),
+            zinc_types::Instruction::Mul(zinc_types::Mul::default()),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+            zinc_types::Instruction::Push(zinc_types::Push::new_field(BigInt::from(7))),
+            zinc_types::Instruction::Return(zinc_types::Return::new(1)),
+        ],
+        None,
+        vec![],
+    )
+}
+
+fn run(contract: zinc_types::Contract, method: &str) -> Result<serde_json::Value, Error> {
+    let input = Input::new(
+        zinc_types::Value::Structure(vec![]),
+        HashMap::new(),
+        method.to_owned(),
+        zinc_types::TransactionMsg::default(),
+    );
+
+    Facade::new(contract)
+        .run::<Bn256>(input)
+        .map(|output| output.result.into_json())
+}
+
+#[test]
+fn ok_pruned_identical() {
+    let contract = contract();
+    let pruned = contract
+        .prune(&["query".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert!(pruned.instructions.len() < contract.instructions.len());
+
+    let expected = run(contract, "query").expect(zinc_const::panic::TEST_DATA_VALID);
+    let result = run(pruned, "query").expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(result, expected);
+    assert_eq!(result, serde_json::json!("0x15"));
+}
+
+#[test]
+fn error_method_pruned() {
+    let pruned = contract()
+        .prune(&["query".to_owned()], &CIRCUIT_HASH)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = run(pruned, "other");
+
+    assert!(matches!(
+        result,
+        Err(Error::MethodPruned { ref found, ref original })
+            if found == "other" && original == "deadbeef"
+    ));
+}
+
+#[test]
+fn error_method_not_found() {
+    let result = run(contract(), "missing");
+
+    assert!(matches!(
+        result,
+        Err(Error::MethodNotFound { ref found }) if found == "missing"
+    ));
+}
+
+///
+/// Runs the contract unit test, which writes the `value` to the first storage field, if any,
+/// and then asserts that the storage is unchanged.
+///
+/// Returns the test status and its failure message.
+///
+fn test_storage_unchanged(value: Option<u64>) -> (UnitTestStatus, Option<String>) {
+    let storage = vec![
+        zinc_types::ContractFieldType::new(
+            "first".to_owned(),
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            true,
+            false,
+        ),
+        zinc_types::ContractFieldType::new(
+            "second".to_owned(),
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            true,
+            false,
+        ),
+    ];
+    let eth_address = zksync_types::Address::from_low_u64_be(0x42);
+
+    let mut instructions = vec![];
+    if let Some(value) = value {
+        instructions.extend(vec![
+            zinc_types::Instruction::Push(zinc_types::Push::new_field(BigInt::from(value))),
+            zinc_types::Instruction::Push(zinc_types::Push::new(
+                BigInt::from(0x42),
+                zinc_types::ScalarType::eth_address(),
+            )),
+            zinc_types::Instruction::Push(zinc_types::Push::new_field(BigInt::from(0))),
+            zinc_types::Instruction::StorageStore(zinc_types::StorageStore::new(1)),
+        ]);
+    }
+    instructions.extend(vec![
+        zinc_types::Instruction::AssertStorageUnchanged(zinc_types::AssertStorageUnchanged),
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+    ]);
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "tests/main.zn::unchanged".to_owned(),
+        zinc_types::UnitTest::new(0, false, None, false, false, None, None),
+    );
+
+    let mut storages = HashMap::new();
+    storages.insert(
+        eth_address,
+        zinc_types::Value::new(zinc_types::Type::Contract(storage.clone())),
+    );
+
+    let contract = zinc_types::Contract::new(
+        "test".to_owned(),
+        storage,
+        IndexMap::new(),
+        unit_tests,
+        instructions,
+        None,
+        vec![],
+    );
+
+    let report = Facade::new(contract)
+        .with_storages(storages)
+        .test::<Bn256>(None, None, false, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let outcome = report.outcomes[0].to_owned();
+
+    (outcome.status, outcome.detail)
+}
+
+#[test]
+fn ok_storage_unchanged() {
+    assert_eq!(test_storage_unchanged(None), (UnitTestStatus::Passed, None));
+}
+
+#[test]
+fn error_storage_changed() {
+    let expected = Error::StorageChanged {
+        addresses: zinc_types::scalar_format::address(&BigInt::from(0x42)),
+    };
+
+    assert_eq!(
+        test_storage_unchanged(Some(42)),
+        (UnitTestStatus::Failed, Some(expected.to_string()))
+    );
+}
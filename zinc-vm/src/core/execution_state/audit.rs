@@ -0,0 +1,259 @@
+//!
+//! The constant-time audit.
+//!
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::core::location::Location;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The constant-time audit of a contract method execution.
+///
+/// In the audit mode, the virtual machine refuses the optimizations which make the number of
+/// the executed host operations depend on the witness values, e.g. the storage load cache and
+/// the storage writes skipped in the branches not taken. Both branches of `if` are always
+/// synthesized regardless of the mode. The instructions whose host-side cost still depends on
+/// the witness are recorded as the audit findings.
+///
+#[derive(Debug)]
+pub struct Audit {
+    /// The address of the instruction being executed.
+    address: usize,
+    /// The source code location of the instruction being executed.
+    location: Location,
+    /// The findings keyed by the instruction address.
+    findings: BTreeMap<usize, Finding>,
+}
+
+impl Default for Audit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Audit {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            address: 0,
+            location: Location::new(),
+            findings: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Sets the address and source code location of the instruction being executed.
+    ///
+    pub fn step(&mut self, address: usize, location: Location) {
+        self.address = address;
+        self.location = location;
+    }
+
+    ///
+    /// Records the instruction being executed, if its host-side work guarded by the branch
+    /// `conditions` is skipped depending on the witness.
+    ///
+    /// The work guarded by the constant conditions only is not reported, since it is the same
+    /// for every witness.
+    ///
+    pub fn check<E: IEngine>(&mut self, conditions: &[Scalar<E>], reason: Reason) {
+        if conditions.iter().all(Scalar::is_constant) {
+            return;
+        }
+
+        let address = self.address;
+        let location = self.location.clone();
+        self.findings.entry(address).or_insert(Finding {
+            address,
+            location,
+            reason,
+        });
+    }
+
+    ///
+    /// Creates the audit report with the total number of the executed host `operations`.
+    ///
+    pub fn into_report(self, operations: usize) -> Report {
+        Report {
+            operations,
+            findings: self
+                .findings
+                .into_iter()
+                .map(|(_, finding)| finding)
+                .collect(),
+        }
+    }
+}
+
+///
+/// The instruction, whose host-side cost depends on the witness.
+///
+#[derive(Debug, Clone)]
+pub struct Finding {
+    /// The instruction address in the bytecode.
+    pub address: usize,
+    /// The instruction source code location.
+    pub location: Location,
+    /// The host-side work skipped depending on the witness.
+    pub reason: Reason,
+}
+
+///
+/// The host-side work, which the virtual machine skips in the branches not taken.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reason {
+    /// The contract instance is only created in the taken branch.
+    StorageInit,
+    /// The map entry is only written in the taken branch.
+    MapInsert,
+    /// The transfer is only queued in the taken branch.
+    Transfer,
+    /// The debug message is only printed in the taken branch.
+    Debug,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StorageInit => write!(
+                f,
+                "the contract instance is only created if the branch is taken"
+            ),
+            Self::MapInsert => write!(f, "the map entry is only written if the branch is taken"),
+            Self::Transfer => write!(f, "the transfer is only queued if the branch is taken"),
+            Self::Debug => write!(
+                f,
+                "the debug message is only printed if the branch is taken"
+            ),
+        }
+    }
+}
+
+///
+/// The constant-time audit report.
+///
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// The number of the executed host operations, that is, the allocations, constraints, and
+    /// storage Merkle tree reads and writes.
+    pub operations: usize,
+    /// The instructions, whose host-side cost depends on the witness, ordered by address.
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    ///
+    /// Whether the host-side cost of the execution does not depend on the witness.
+    ///
+    pub fn is_constant_time(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "host operations: {}", self.operations)?;
+        if self.findings.is_empty() {
+            return writeln!(f, "no witness-dependent instructions found");
+        }
+
+        writeln!(f, "witness-dependent instructions:")?;
+        for finding in self.findings.iter() {
+            writeln!(
+                f,
+                "{:>8}  {}  {}",
+                finding.address, finding.location, finding.reason
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::core::location::Location;
+    use crate::gadgets;
+    use crate::gadgets::scalar::Scalar;
+
+    use super::Audit;
+    use super::Reason;
+
+    fn condition<CS: ConstraintSystem<Bn256>>(mut cs: CS) -> Scalar<Bn256> {
+        let fr = gadgets::scalar::fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(1))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let num = AllocatedNum::alloc(cs.namespace(|| "condition"), || Ok(fr))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        Scalar::from(num)
+    }
+
+    fn location(line: usize) -> Location {
+        let mut location = Location::new();
+        location.file = Some("main.zn".to_owned());
+        location.line = Some(line);
+        location
+    }
+
+    #[test]
+    fn ok_constant_conditions_skipped() {
+        let mut audit = Audit::new();
+
+        audit.step(4, location(10));
+        audit.check::<Bn256>(&[], Reason::Debug);
+        audit.check(
+            &[Scalar::<Bn256>::new_constant_bool(true)],
+            Reason::Transfer,
+        );
+
+        let report = audit.into_report(42);
+        assert_eq!(report.operations, 42);
+        assert!(report.is_constant_time());
+    }
+
+    #[test]
+    fn ok_witness_conditions_reported() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let conditions = vec![
+            Scalar::<Bn256>::new_constant_bool(true),
+            condition(cs.namespace(|| "witness")),
+        ];
+
+        let mut audit = Audit::new();
+        audit.step(12, location(20));
+        audit.check(conditions.as_slice(), Reason::MapInsert);
+        audit.step(4, location(10));
+        audit.check(conditions.as_slice(), Reason::Debug);
+        audit.step(12, location(20));
+        audit.check(conditions.as_slice(), Reason::MapInsert);
+
+        let report = audit.into_report(0);
+        assert!(!report.is_constant_time());
+
+        let findings: Vec<(usize, Option<usize>, Reason)> = report
+            .findings
+            .iter()
+            .map(|finding| (finding.address, finding.location.line, finding.reason))
+            .collect();
+        assert_eq!(
+            findings,
+            vec![
+                (4, Some(10), Reason::Debug),
+                (12, Some(20), Reason::MapInsert),
+            ]
+        );
+    }
+}
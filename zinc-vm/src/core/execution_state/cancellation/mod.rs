@@ -0,0 +1,88 @@
+//!
+//! The VM execution cancellation token.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::error::Error;
+
+///
+/// The token, which aborts the execution it is passed to, when it is cancelled from another
+/// thread or when its deadline is reached.
+///
+/// The clones share the cancellation flag, so the embedder keeps a clone to cancel the execution.
+/// The token is checked periodically in the instruction dispatch loop and inside the long
+/// gadget loops.
+///
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    /// The flag shared by all the clones.
+    is_cancelled: Arc<AtomicBool>,
+    /// The time the deadline is counted from, and the deadline itself.
+    timeout: Option<(Instant, Duration)>,
+}
+
+impl Cancellation {
+    /// The number of the executed instructions or gadget loop iterations between the token checks.
+    pub const CHECK_INTERVAL: usize = 1024;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Sets the deadline of `timeout`, which is counted from now.
+    ///
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some((Instant::now(), timeout));
+        self
+    }
+
+    ///
+    /// Cancels the executions the token or any of its clones are passed to.
+    ///
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Returns an error if the token is cancelled or its deadline is reached.
+    ///
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+
+        if let Some((start, timeout)) = self.timeout {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                return Err(Error::TimedOut { elapsed });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the token at every `CHECK_INTERVAL`-th `iteration` of the instruction dispatch loop
+    /// or a long gadget loop.
+    ///
+    pub fn check_periodically(&self, iteration: usize) -> Result<(), Error> {
+        if iteration % Self::CHECK_INTERVAL == 0 {
+            self.check()
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//!
+//! The VM execution cancellation tests.
+//!
+
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use indexmap::IndexMap;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use crate::core::circuit::facade::Facade as CircuitFacade;
+use crate::core::execution_state::cancellation::Cancellation;
+use crate::error::Error;
+
+/// The delay before the execution is cancelled or timed out.
+const DELAY: Duration = Duration::from_millis(200);
+
+/// The generous upper bound of the time the execution takes to notice the cancellation.
+const MARGIN: Duration = Duration::from_secs(10);
+
+///
+/// Creates the circuit with the loop, which is too long to finish during the test run.
+///
+fn looping_circuit() -> zinc_types::Circuit {
+    zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        IndexMap::new(),
+        vec![
+            zinc_types::Instruction::LoopBegin(zinc_types::LoopBegin::new(usize::MAX)),
+            zinc_types::Instruction::LoopEnd(zinc_types::LoopEnd),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        ],
+    )
+}
+
+#[test]
+fn ok_not_cancelled() {
+    let cancellation = Cancellation::new().with_timeout(MARGIN);
+
+    assert!(cancellation.check().is_ok());
+    assert!(cancellation.check_periodically(0).is_ok());
+    assert!(!cancellation.is_cancelled());
+}
+
+#[test]
+fn ok_cancelled_clone() {
+    let cancellation = Cancellation::new();
+    cancellation.clone().cancel();
+
+    assert!(cancellation.is_cancelled());
+    assert!(matches!(cancellation.check(), Err(Error::Cancelled)));
+    assert!(cancellation.check_periodically(1).is_ok());
+    assert!(matches!(
+        cancellation.check_periodically(Cancellation::CHECK_INTERVAL),
+        Err(Error::Cancelled)
+    ));
+}
+
+#[test]
+fn error_cancelled_from_another_thread() {
+    let cancellation = Cancellation::new();
+
+    let canceller = cancellation.clone();
+    let handle = thread::spawn(move || {
+        thread::sleep(DELAY);
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let result = CircuitFacade::new(looping_circuit())
+        .with_cancellation(cancellation)
+        .evaluate::<Bn256>(zinc_types::Value::Unit);
+    let elapsed = start.elapsed();
+    handle.join().expect(zinc_const::panic::SYNCHRONIZATION);
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+    assert!(elapsed >= DELAY);
+    assert!(elapsed < DELAY + MARGIN);
+}
+
+#[test]
+fn error_timed_out() {
+    let start = Instant::now();
+    let result = CircuitFacade::new(looping_circuit())
+        .run_with_timeout::<Bn256>(zinc_types::Value::Unit, DELAY);
+    let elapsed = start.elapsed();
+
+    match result {
+        Err(Error::TimedOut { elapsed: reported }) => {
+            assert!(reported >= DELAY);
+            assert!(reported <= elapsed);
+        }
+        _ => panic!("The execution must time out"),
+    }
+    assert!(elapsed < DELAY + MARGIN);
+}
+
+#[test]
+fn error_witness_cancelled_from_another_thread() {
+    let cancellation = Cancellation::new();
+
+    let canceller = cancellation.clone();
+    let handle = thread::spawn(move || {
+        thread::sleep(DELAY);
+        canceller.cancel();
+    });
+
+    let start = Instant::now();
+    let result = CircuitFacade::new(looping_circuit())
+        .with_cancellation(cancellation)
+        .witness::<Bn256>(zinc_types::Value::Unit);
+    let elapsed = start.elapsed();
+    handle.join().expect(zinc_const::panic::SYNCHRONIZATION);
+
+    assert!(matches!(result, Err(Error::Cancelled)));
+    assert!(elapsed >= DELAY);
+    assert!(elapsed < DELAY + MARGIN);
+}
+
+#[test]
+fn error_witness_timed_out() {
+    let start = Instant::now();
+    let result = CircuitFacade::new(looping_circuit())
+        .witness_with_timeout::<Bn256>(zinc_types::Value::Unit, DELAY);
+    let elapsed = start.elapsed();
+
+    match result {
+        Err(Error::TimedOut { elapsed: reported }) => {
+            assert!(reported >= DELAY);
+            assert!(reported <= elapsed);
+        }
+        _ => panic!("The witness computation must time out"),
+    }
+    assert!(elapsed < DELAY + MARGIN);
+}
@@ -50,6 +50,14 @@ impl<E: IEngine> DataStack<E> {
     }
 
     pub fn set(&mut self, address: usize, value: Cell<E>) -> Result<(), Error> {
+        if address >= zinc_const::limit::DATA_STACK_SIZE {
+            return Err(MalformedBytecode::DataStackOverflow {
+                address,
+                limit: zinc_const::limit::DATA_STACK_SIZE,
+            }
+            .into());
+        }
+
         if self.memory.len() <= address {
             let mut extra = vec![None; address + 1 - self.memory.len()];
             self.memory.append(&mut extra);
@@ -10,6 +10,8 @@ use franklin_crypto::circuit::test::TestConstraintSystem;
 
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::data_stack::DataStack;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
@@ -34,6 +36,21 @@ fn test_get_set() {
     );
 }
 
+#[test]
+fn test_set_overflow() {
+    let mut ds = DataStack::<Bn256>::new();
+    let value = Scalar::new_constant_usize(42, zinc_types::ScalarType::Field);
+
+    let result = ds.set(std::usize::MAX, Cell::Value(value));
+
+    assert!(matches!(
+        result,
+        Err(Error::MalformedBytecode(
+            MalformedBytecode::DataStackOverflow { .. }
+        ))
+    ));
+}
+
 #[test]
 fn test_fork_merge_true() {
     let mut ds = DataStack::new();
@@ -2,11 +2,16 @@
 //! The VM execution state.
 //!
 
+pub mod audit;
 pub mod block;
+pub mod cancellation;
 pub mod cell;
 pub mod data_stack;
 pub mod evaluation_stack;
 pub mod function_frame;
+pub mod test_msg;
+pub mod test_seed;
+pub mod verifying_keys;
 
 use std::fmt;
 
@@ -14,9 +19,15 @@ use crate::core::contract::output::initializer::Initializer;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+use self::audit::Audit;
+use self::audit::Reason as AuditReason;
+use self::cancellation::Cancellation;
 use self::data_stack::DataStack;
 use self::evaluation_stack::EvaluationStack;
 use self::function_frame::Frame;
+use self::test_msg::TestMsg;
+use self::test_seed::TestSeed;
+use self::verifying_keys::VerifyingKeys;
 
 #[derive(Debug)]
 pub struct ExecutionState<E: IEngine> {
@@ -27,6 +38,12 @@ pub struct ExecutionState<E: IEngine> {
     pub frames_stack: Vec<Frame<E>>,
     pub transfers: Vec<zinc_types::TransactionMsg>,
     pub initializers: Vec<Initializer>,
+    pub verifying_keys: VerifyingKeys<E>,
+    pub test_msg: Option<TestMsg>,
+    pub test_seed: Option<TestSeed>,
+    pub cancellation: Cancellation,
+    pub is_proving: bool,
+    pub audit: Option<Audit>,
 }
 
 impl<E: IEngine> ExecutionState<E> {
@@ -44,6 +61,22 @@ impl<E: IEngine> ExecutionState<E> {
             frames_stack: Vec::with_capacity(Self::FRAMES_INITIAL_CAPACITY),
             transfers: Vec::with_capacity(Self::TRANSFERS_INITIAL_CAPACITY),
             initializers: Vec::with_capacity(Self::INITIALIZERS_INITIAL_CAPACITY),
+            verifying_keys: VerifyingKeys::new(),
+            test_msg: None,
+            test_seed: None,
+            cancellation: Cancellation::new(),
+            is_proving: false,
+            audit: None,
+        }
+    }
+
+    ///
+    /// Records the instruction being executed to the constant-time audit, if it is enabled and
+    /// the host-side work for `reason` is only done if the witness-dependent branch is taken.
+    ///
+    pub fn audit(&mut self, reason: AuditReason) {
+        if let Some(audit) = self.audit.as_mut() {
+            audit.check(self.conditions_stack.as_slice(), reason);
         }
     }
 }
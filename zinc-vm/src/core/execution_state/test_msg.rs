@@ -0,0 +1,171 @@
+//!
+//! The unit test `zksync::msg` attribute.
+//!
+
+use num::BigUint;
+use num::Zero;
+
+use crate::error::Error;
+
+///
+/// The `zksync::msg` attribute of the unit test being executed.
+///
+/// The attribute transaction is the only source of funds in a unit test, so the transfers must
+/// be sent from its recipient in its token, and must not exceed its amount in total.
+///
+#[derive(Debug)]
+pub struct TestMsg {
+    /// The transaction variable set by the attribute.
+    pub msg: zinc_types::TransactionMsg,
+    /// The attribute source location, or `<unavailable>` for the bytecode built without it.
+    pub location: String,
+    /// The total amount transferred so far.
+    transferred: BigUint,
+    /// Whether the recipient address has already been taken by a contract instance.
+    is_recipient_bound: bool,
+}
+
+impl TestMsg {
+    pub fn new(msg: zinc_types::TransactionMsg, location: Option<String>) -> Self {
+        Self {
+            msg,
+            location: location.unwrap_or_else(|| "<unavailable>".to_owned()),
+            transferred: BigUint::zero(),
+            is_recipient_bound: false,
+        }
+    }
+
+    ///
+    /// Returns the attribute recipient for the first contract instance created by the test,
+    /// so the transaction is sent to that instance.
+    ///
+    pub fn bind_recipient(&mut self) -> Option<zksync_types::Address> {
+        if self.is_recipient_bound {
+            return None;
+        }
+
+        self.is_recipient_bound = true;
+        Some(self.msg.recipient)
+    }
+
+    ///
+    /// Checks the `transfer` against the attribute fields and adds its amount to the total.
+    ///
+    pub fn check_transfer(&mut self, transfer: &zinc_types::TransactionMsg) -> Result<(), Error> {
+        if transfer.sender != self.msg.recipient {
+            return Err(Error::TransferRecipientMismatch {
+                expected: Self::address_to_string(self.msg.recipient),
+                found: Self::address_to_string(transfer.sender),
+                location: self.location.clone(),
+            });
+        }
+
+        if transfer.token_address != self.msg.token_address {
+            return Err(Error::TransferTokenMismatch {
+                expected: Self::address_to_string(self.msg.token_address),
+                found: Self::address_to_string(transfer.token_address),
+                location: self.location.clone(),
+            });
+        }
+
+        let amount = zinc_types::num_compat_forward(self.msg.amount.clone());
+        let transferred =
+            &self.transferred + zinc_types::num_compat_forward(transfer.amount.clone());
+        if transferred > amount {
+            return Err(Error::TransferInsufficientAmount {
+                expected: amount.to_string(),
+                found: transferred.to_string(),
+                location: self.location.clone(),
+            });
+        }
+        self.transferred = transferred;
+
+        Ok(())
+    }
+
+    ///
+    /// Formats the `address` as a `0x`-prefixed hexadecimal string.
+    ///
+    fn address_to_string(address: zksync_types::Address) -> String {
+        format!("0x{}", hex::encode(address.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::error::Error;
+
+    use super::TestMsg;
+
+    const LOCATION: &str = "src/main.zn:10:1";
+
+    fn msg() -> TestMsg {
+        TestMsg::new(
+            zinc_types::TransactionMsg::new_from_bigints(
+                BigInt::from(1),
+                BigInt::from(2),
+                BigInt::from(3),
+                BigInt::from(100),
+            ),
+            Some(LOCATION.to_owned()),
+        )
+    }
+
+    fn transfer(sender: u32, token_address: u32, amount: u32) -> zinc_types::TransactionMsg {
+        zinc_types::TransactionMsg::new_from_bigints(
+            BigInt::from(sender),
+            BigInt::from(42),
+            BigInt::from(token_address),
+            BigInt::from(amount),
+        )
+    }
+
+    #[test]
+    fn ok_within_amount() {
+        let mut msg = msg();
+
+        msg.check_transfer(&transfer(2, 3, 60))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        msg.check_transfer(&transfer(2, 3, 40))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    #[test]
+    fn error_recipient_mismatch() {
+        let error = msg()
+            .check_transfer(&transfer(5, 3, 60))
+            .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(matches!(error, Error::TransferRecipientMismatch { .. }));
+        assert!(error.to_string().contains("`recipient`"));
+        assert!(error.to_string().contains(LOCATION));
+    }
+
+    #[test]
+    fn error_token_mismatch() {
+        let error = msg()
+            .check_transfer(&transfer(2, 4, 60))
+            .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(matches!(error, Error::TransferTokenMismatch { .. }));
+        assert!(error.to_string().contains("`token_address`"));
+        assert!(error.to_string().contains(LOCATION));
+    }
+
+    #[test]
+    fn error_insufficient_amount() {
+        let mut msg = msg();
+
+        msg.check_transfer(&transfer(2, 3, 60))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let error = msg
+            .check_transfer(&transfer(2, 3, 41))
+            .expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(matches!(error, Error::TransferInsufficientAmount { .. }));
+        assert!(error.to_string().contains("`amount` is 100"));
+        assert!(error.to_string().contains(LOCATION));
+    }
+}
@@ -0,0 +1,141 @@
+//!
+//! The unit test pseudo-random generator seed.
+//!
+
+use std::fmt;
+
+use num::bigint::Sign;
+use num::BigInt;
+use sha2::Digest;
+use sha2::Sha256;
+
+///
+/// The seed of the `std::test::rand_u` generator of the unit test being executed.
+///
+/// The seed is the `sha256` digest of the fully qualified unit test name `<file>::<function>`,
+/// so every rerun of the test produces the same sequence of values.
+///
+#[derive(Debug)]
+pub struct TestSeed {
+    /// The seed bytes.
+    seed: Vec<u8>,
+    /// Whether the generator has been used by the unit test.
+    is_used: bool,
+}
+
+impl TestSeed {
+    /// The size of the `seed_offset` argument encoding, which is enough for any integer type.
+    const OFFSET_SIZE: usize = 32;
+
+    pub fn new(file: &str, name: &str) -> Self {
+        let name = format!("{}::{}", file, name);
+
+        Self {
+            seed: Sha256::digest(name.as_bytes()).to_vec(),
+            is_used: false,
+        }
+    }
+
+    ///
+    /// Generates an unsigned integer of `bitlength` bits, which is the prefix of the `sha256`
+    /// digest of the seed followed by the big-endian `offset`.
+    ///
+    /// The `bitlength` must be a multiple of 8, which is checked by the compiler.
+    ///
+    pub fn generate(&mut self, offset: &BigInt, bitlength: usize) -> BigInt {
+        self.is_used = true;
+
+        let (_sign, offset) = offset.to_bytes_be();
+        let mut preimage = self.seed.clone();
+        preimage.extend(vec![0; Self::OFFSET_SIZE.saturating_sub(offset.len())]);
+        preimage.extend(offset);
+
+        let digest = Sha256::digest(preimage.as_slice());
+        BigInt::from_bytes_be(
+            Sign::Plus,
+            &digest[..bitlength / zinc_const::bitlength::BYTE],
+        )
+    }
+
+    ///
+    /// Whether the generator has been used by the unit test, that is, whether the seed is worth
+    /// printing on failure.
+    ///
+    pub fn is_used(&self) -> bool {
+        self.is_used
+    }
+
+    ///
+    /// Prints the seed of the failed unit test `name`, so the failure can be replayed.
+    ///
+    pub fn log_failure(&self, name: &str) {
+        if self.is_used {
+            log::error!("test {} ... seed {}", name, self);
+        }
+    }
+}
+
+impl fmt::Display for TestSeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.seed.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::One;
+
+    use super::TestSeed;
+
+    const FILE: &str = "src";
+    const NAME: &str = "addition_is_commutative";
+
+    #[test]
+    fn ok_reproducible() {
+        let mut first = TestSeed::new(FILE, NAME);
+        let mut second = TestSeed::new(FILE, NAME);
+
+        for offset in 0..16 {
+            assert_eq!(
+                first.generate(&BigInt::from(offset), 64),
+                second.generate(&BigInt::from(offset), 64)
+            );
+        }
+        assert_eq!(first.to_string(), second.to_string());
+    }
+
+    #[test]
+    fn ok_offset_changes_value() {
+        let mut seed = TestSeed::new(FILE, NAME);
+
+        assert_ne!(
+            seed.generate(&BigInt::from(0), 64),
+            seed.generate(&BigInt::from(1), 64)
+        );
+    }
+
+    #[test]
+    fn ok_name_changes_seed() {
+        let mut first = TestSeed::new(FILE, NAME);
+        let mut second = TestSeed::new(FILE, "another");
+
+        assert_ne!(first.to_string(), second.to_string());
+        assert_ne!(
+            first.generate(&BigInt::from(0), 64),
+            second.generate(&BigInt::from(0), 64)
+        );
+    }
+
+    #[test]
+    fn ok_bitlength() {
+        let mut seed = TestSeed::new(FILE, NAME);
+
+        for bitlength in (8..=248).step_by(8) {
+            assert!(
+                seed.generate(&BigInt::from(bitlength), bitlength) < BigInt::one() << bitlength
+            );
+        }
+        assert!(seed.is_used());
+    }
+}
@@ -0,0 +1,54 @@
+//!
+//! The verifying keys available to `std::crypto::verify_proof`.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use franklin_crypto::bellman::groth16::VerifyingKey;
+
+use crate::error::Error;
+use crate::IEngine;
+
+///
+/// The verifying keys indexed by the `sha256` digest of their serialized form.
+///
+pub struct VerifyingKeys<E: IEngine> {
+    inner: HashMap<Vec<u8>, VerifyingKey<E>>,
+}
+
+impl<E: IEngine> VerifyingKeys<E> {
+    pub fn new() -> Self {
+        Self {
+            inner: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Parses the verifying key `bytes` and registers the key by their `sha256` digest.
+    ///
+    pub fn insert(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let key = VerifyingKey::<E>::read(bytes).map_err(Error::InvalidVerifyingKey)?;
+        self.inner.insert(Sha256::digest(bytes).to_vec(), key);
+        Ok(())
+    }
+
+    pub fn get(&self, digest: &[u8]) -> Option<&VerifyingKey<E>> {
+        self.inner.get(digest)
+    }
+}
+
+impl<E: IEngine> Default for VerifyingKeys<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: IEngine> fmt::Debug for VerifyingKeys<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} verifying key(s)", self.inner.len())
+    }
+}
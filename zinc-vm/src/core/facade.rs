@@ -6,35 +6,277 @@ use franklin_crypto::bellman::groth16;
 use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::groth16::VerifyingKey;
 
+use crate::core::proof_encoding::ProofEncoding;
+use crate::core::public_inputs::PublicInputs;
+use crate::error::Error;
 use crate::error::VerificationError;
-use crate::gadgets;
 use crate::IEngine;
 
 pub struct Facade;
 
 impl Facade {
+    ///
+    /// Verifies the `proof` of the `public_inputs`, which must be laid out as described in
+    /// `PublicInputs`.
+    ///
     pub fn verify<E: IEngine>(
         verifying_key: VerifyingKey<E>,
         proof: Proof<E>,
-        public_input: zinc_types::Value,
+        public_inputs: PublicInputs,
     ) -> Result<bool, VerificationError> {
-        let public_input_flat = public_input
-            .into_flat_values()
-            .into_iter()
-            .map(|value| {
-                gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&value)
-                    .ok_or(VerificationError::ValueOverflow(value))
-            })
-            .collect::<Result<Vec<E::Fr>, VerificationError>>()?;
+        let public_inputs = public_inputs.into_field_vec::<E>()?;
 
         let prepared_verifying_key = groth16::prepare_verifying_key(&verifying_key);
-        let success = groth16::verify_proof(
-            &prepared_verifying_key,
-            &proof,
-            public_input_flat.as_slice(),
-        )
-        .map_err(VerificationError::SynthesisError)?;
+        let success =
+            groth16::verify_proof(&prepared_verifying_key, &proof, public_inputs.as_slice())
+                .map_err(VerificationError::SynthesisError)?;
 
         Ok(success)
     }
+
+    ///
+    /// Verifies the proof given as the serialized `verifying_key`, the `proof` in any of the
+    /// `ProofEncoding` forms, and the `public_data` JSON written by `PublicInputs::into_json`.
+    ///
+    pub fn verify_bytes<E: IEngine>(
+        verifying_key: &[u8],
+        proof: &[u8],
+        public_data: &[u8],
+    ) -> Result<bool, VerificationError> {
+        let verifying_key = VerifyingKey::<E>::read(verifying_key)
+            .map_err(VerificationError::InvalidVerifyingKey)?;
+
+        let proof = ProofEncoding::decode(proof)?;
+        let proof = Proof::<E>::read(proof.as_slice())
+            .map_err(|error| VerificationError::InvalidProof(error.to_string()))?;
+
+        let public_data = serde_json::from_slice(public_data)
+            .map_err(|error| VerificationError::InvalidPublicInputs(error.to_string()))?;
+        let public_inputs = PublicInputs::try_from_json(public_data)?;
+
+        Self::verify(verifying_key, proof, public_inputs)
+    }
+
+    ///
+    /// Checks whether the bytecode artifact with `header` can be executed by the virtual machine.
+    ///
+    /// The bytecode built against a newer standard library is rejected, since it may call the
+    /// library functions the virtual machine does not know about.
+    ///
+    pub fn check_header(header: &zinc_types::ApplicationHeader) -> Result<(), Error> {
+        if header.std_version > zinc_const::version::STD {
+            return Err(Error::StdVersionUnsupported {
+                found: header.std_version,
+                supported: zinc_const::version::STD,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::marker::PhantomData;
+
+    use num::BigInt;
+
+    use franklin_crypto::bellman::groth16;
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::core::circuit::synthesizer::Synthesizer;
+    use crate::core::public_inputs::PublicInputs;
+    use crate::error::Error;
+    use crate::error::VerificationError;
+
+    use super::Facade;
+
+    ///
+    /// The circuit, which returns its field input squared.
+    ///
+    fn circuit() -> zinc_types::Circuit {
+        zinc_types::Circuit::new(
+            "test".to_owned(),
+            0,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            indexmap::IndexMap::new(),
+            vec![
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Mul::default().into(),
+                zinc_types::Return::new(1).into(),
+            ],
+        )
+    }
+
+    ///
+    /// Proves the circuit with `input` and returns the serialized verifying key, the raw proof,
+    /// and the public data JSON.
+    ///
+    fn prove(input: BigInt) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let rng = &mut rand::thread_rng();
+
+        let params = groth16::generate_random_parameters::<Bn256, _, _>(
+            Synthesizer {
+                inputs: None,
+                output: &mut None,
+                bytecode: circuit(),
+                _pd: PhantomData,
+            },
+            rng,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let proof = groth16::create_random_proof(
+            Synthesizer {
+                inputs: Some(vec![input.clone()]),
+                output: &mut None,
+                bytecode: circuit(),
+                _pd: PhantomData,
+            },
+            &params,
+            rng,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut verifying_key = Vec::new();
+        params
+            .vk
+            .write(&mut verifying_key)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut proof_bytes = Vec::new();
+        proof
+            .write(&mut proof_bytes)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let output = zinc_types::Value::Scalar(zinc_types::ScalarValue::Field(&input * &input));
+        let public_data = serde_json::to_vec(
+            &PublicInputs::try_from(output, None)
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .into_json(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        (verifying_key, proof_bytes, public_data)
+    }
+
+    #[test]
+    fn ok_header_std_version() {
+        let mut header = zinc_types::ApplicationHeader::new(&semver::Version::new(0, 1, 0));
+        assert!(Facade::check_header(&header).is_ok());
+
+        header.std_version = zinc_types::ApplicationHeader::STD_VERSION_INITIAL;
+        assert!(Facade::check_header(&header).is_ok());
+
+        assert!(Facade::check_header(&zinc_types::ApplicationHeader::legacy()).is_ok());
+    }
+
+    #[test]
+    fn error_header_std_version_newer() {
+        let mut header = zinc_types::ApplicationHeader::new(&semver::Version::new(0, 1, 0));
+        header.std_version = zinc_const::version::STD + 1;
+
+        let error = Facade::check_header(&header).expect_err(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(matches!(
+            error,
+            Error::StdVersionUnsupported { found, supported }
+                if found == zinc_const::version::STD + 1 && supported == zinc_const::version::STD
+        ));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "the bytecode requires the standard library version {}, but the virtual machine supports up to version {}: update the Zinc toolchain",
+                zinc_const::version::STD + 1,
+                zinc_const::version::STD,
+            )
+        );
+    }
+
+    #[test]
+    fn ok_arbitrary_paths() {
+        let (verifying_key, proof, public_data) = prove(BigInt::from(12));
+
+        let directory = std::env::temp_dir().join(format!("zinc-verify-{}", std::process::id()));
+        fs::create_dir_all(directory.join("keys")).expect(zinc_const::panic::TEST_DATA_VALID);
+        let verifying_key_path = directory.join("keys").join("vk.bin");
+        let proof_path = directory.join("proof.b64");
+        let public_data_path = directory.join("public.json");
+        fs::write(&verifying_key_path, verifying_key).expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::write(&proof_path, base64::encode(proof) + "\n")
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::write(&public_data_path, public_data).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let is_valid = Facade::verify_bytes::<Bn256>(
+            fs::read(&verifying_key_path)
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .as_slice(),
+            fs::read(&proof_path)
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .as_slice(),
+            fs::read(&public_data_path)
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        fs::remove_dir_all(directory).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn ok_encodings() {
+        let (verifying_key, proof, public_data) = prove(BigInt::from(12));
+
+        for encoded in vec![
+            proof.clone(),
+            hex::encode(proof.as_slice()).into_bytes(),
+            format!("0x{}\n", hex::encode(proof.as_slice())).into_bytes(),
+            base64::encode(proof.as_slice()).into_bytes(),
+        ]
+        .into_iter()
+        {
+            let is_valid = Facade::verify_bytes::<Bn256>(
+                verifying_key.as_slice(),
+                encoded.as_slice(),
+                public_data.as_slice(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+            assert!(is_valid);
+        }
+    }
+
+    #[test]
+    fn error_corrupted() {
+        let (verifying_key, mut proof, public_data) = prove(BigInt::from(12));
+
+        // The `a` and `c` points are both compressed into 32 bytes, so swapping them keeps the
+        // proof decodable, but invalid.
+        let (a, rest) = proof.split_at_mut(32);
+        a.swap_with_slice(&mut rest[64..96]);
+
+        let is_valid = Facade::verify_bytes::<Bn256>(
+            verifying_key.as_slice(),
+            proof.as_slice(),
+            public_data.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn error_truncated() {
+        let (verifying_key, proof, public_data) = prove(BigInt::from(12));
+
+        let result = Facade::verify_bytes::<Bn256>(
+            verifying_key.as_slice(),
+            &proof[..proof.len() / 2],
+            public_data.as_slice(),
+        );
+        assert!(matches!(result, Err(VerificationError::InvalidProof(_))));
+    }
 }
@@ -2,62 +2,162 @@
 //! The virtual machine library facade.
 //!
 
-use colored::Colorize;
+use std::time::Duration;
+use std::time::Instant;
 
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
-use zinc_const::UnitTestExitCode;
-
+use crate::constraint_systems::constant::Constant as ConstantCS;
 use crate::constraint_systems::main::Main as MainCS;
+use crate::constraint_systems::IConstraintSystem;
+use crate::core::execution_state::cancellation::Cancellation;
+use crate::core::execution_state::test_seed::TestSeed;
 use crate::core::library::State as LibraryState;
+use crate::core::unit_test::backend::Backend as UnitTestBackend;
+use crate::core::unit_test::outcome::Outcome as UnitTestOutcome;
+use crate::core::unit_test::outcome::Status as UnitTestStatus;
+use crate::core::unit_test::Report as UnitTestReport;
+use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::IEngine;
 
 pub struct Facade {
     inner: zinc_types::Library,
+    cancellation: Cancellation,
 }
 
 impl Facade {
     pub fn new(inner: zinc_types::Library) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            cancellation: Cancellation::new(),
+        }
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
+    ///
+    /// Sets the token, which aborts the execution when it is cancelled or times out.
+    ///
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    pub fn test<E: IEngine>(
+        self,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+        min_time: Option<Duration>,
+        is_fast: bool,
+        timeout: Option<Duration>,
+    ) -> Result<UnitTestReport, Error> {
+        let mut report = UnitTestReport::default();
+
+        let mut unit_tests = self.inner.unit_tests.clone();
+        UnitTestReport::sort(&mut unit_tests);
+        for (name, unit_test) in unit_tests.into_iter() {
+            let (test_file, name) = zinc_types::UnitTest::split_name(name.as_str());
+            report.start(test_file, name);
 
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
             if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
+                report.push(
+                    UnitTestOutcome::ignored(test_file.to_owned(), name.to_owned()),
+                    min_time,
+                );
+                return Ok(report);
             }
 
-            let cs = MainCS::<Bn256>::new();
+            if self.cancellation.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
 
-            let mut state = LibraryState::new(cs);
+            let start = Instant::now();
+            let cancellation = match timeout {
+                Some(timeout) => self.cancellation.clone().with_timeout(timeout),
+                None => self.cancellation.clone(),
+            };
 
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
+            let backend = UnitTestBackend::select(&unit_test, is_fast);
+            let test_seed = TestSeed::new(test_file, name);
+            let (result, synthesis_time, test_seed) = match backend {
+                UnitTestBackend::Synthesis => self.test_with(
+                    MainCS::<Bn256>::new().with_timing(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                ),
+                UnitTestBackend::Evaluation => self.test_with(
+                    ConstantCS::default(),
+                    &unit_test,
+                    test_seed,
+                    cancellation,
+                    coverage.as_deref_mut(),
+                ),
+            };
+            let (status, detail) = match result {
+                Err(error) if matches!(error, Error::Cancelled | Error::TimedOut { .. }) => {
+                    (UnitTestStatus::Failed, Some(error.to_string()))
                 }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
+                Err(error) if unit_test.should_panic => {
+                    UnitTestStatus::panicked(unit_test.expected_panic.as_deref(), error.to_string())
                 }
+                Ok(_) if unit_test.should_panic => (
+                    UnitTestStatus::Failed,
+                    Some("should have failed".to_owned()),
+                ),
+                Ok(_) => (UnitTestStatus::Passed, None),
+                Err(error) => (UnitTestStatus::Failed, Some(error.to_string())),
+            };
+            let total_time = start.elapsed();
 
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
+            report.push(
+                UnitTestOutcome::new(
+                    test_file.to_owned(),
+                    name.to_owned(),
+                    status,
+                    detail,
+                    backend,
+                    synthesis_time,
+                    total_time,
+                ),
+                min_time,
+            );
+            if status == UnitTestStatus::Failed {
+                if let Some(seed) = test_seed.as_ref() {
+                    seed.log_failure(name);
                 }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
-                }
-            };
+            }
+        }
+
+        Ok(report)
+    }
+
+    ///
+    /// Executes the `unit_test` with the constraint system `cs`.
+    ///
+    /// Returns the test result, the synthesis time, and the test seed.
+    ///
+    fn test_with<CS: IConstraintSystem<Bn256>>(
+        &self,
+        cs: CS,
+        unit_test: &zinc_types::UnitTest,
+        test_seed: TestSeed,
+        cancellation: Cancellation,
+        coverage: Option<&mut zinc_types::Coverage>,
+    ) -> (Result<(), Error>, Duration, Option<TestSeed>) {
+        let mut state = LibraryState::new(cs);
+        state.execution_state.cancellation = cancellation;
+
+        let mut result = state.test(self.inner.clone(), unit_test.address, test_seed, coverage);
+        let cs = state.constraint_system();
+        if result.is_ok() && !cs.is_satisfied() {
+            result = Err(Error::UnsatisfiedConstraint);
         }
+        let synthesis_time = cs.synthesis_time().unwrap_or_default();
 
-        Ok(exit_code)
+        (
+            result,
+            synthesis_time,
+            state.execution_state.test_seed.take(),
+        )
     }
 }
@@ -7,16 +7,20 @@ pub mod facade;
 use colored::Colorize;
 use num::BigInt;
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
+use crate::constraint_systems::host::Host as HostCS;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::counter::NamespaceCounter;
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::function_frame::Frame;
+use crate::core::execution_state::test_seed::TestSeed;
 use crate::core::execution_state::ExecutionState;
 use crate::core::location::Location;
 use crate::core::virtual_machine::IVirtualMachine;
@@ -33,7 +37,7 @@ where
     E: IEngine,
     CS: ConstraintSystem<E>,
 {
-    counter: NamespaceCounter<E, CS>,
+    counter: NamespaceCounter<E, HostCS<E, CS>>,
     execution_state: ExecutionState<E>,
 
     pub(crate) location: Location,
@@ -46,14 +50,22 @@ where
 {
     pub fn new(cs: CS) -> Self {
         Self {
-            counter: NamespaceCounter::new(cs),
+            counter: NamespaceCounter::new(HostCS::new(cs)),
             execution_state: ExecutionState::new(),
 
             location: Location::new(),
         }
     }
 
-    pub fn test(&mut self, library: zinc_types::Library, address: usize) -> Result<(), Error> {
+    pub fn test(
+        &mut self,
+        library: zinc_types::Library,
+        address: usize,
+        test_seed: TestSeed,
+        mut coverage: Option<&mut zinc_types::Coverage>,
+    ) -> Result<(), Error> {
+        self.execution_state.test_seed = Some(test_seed);
+
         self.counter.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -72,20 +84,25 @@ where
 
         let mut step = 0;
         while self.execution_state.instruction_counter < library.instructions.len() {
-            let namespace = format!(
-                "step={}, addr={}",
-                step, self.execution_state.instruction_counter
-            );
-            self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                library.instructions[self.execution_state.instruction_counter].clone();
+            self.execution_state.cancellation.check_periodically(step)?;
 
-            log::trace!(
-                "{}:{} > {}",
-                step,
-                self.execution_state.instruction_counter,
-                instruction,
-            );
+            let address = self.execution_state.instruction_counter;
+            let namespace = format!("step={}, addr={}", step, address);
+            self.counter.cs.push_namespace(|| namespace);
+            let instruction = library.instructions[address].clone();
+
+            log::trace!("{}:{} > {}", step, address, instruction);
+
+            // Only the instructions of the taken branches are covered.
+            if let Some(coverage) = coverage.as_mut() {
+                if self
+                    .condition_top()?
+                    .get_value()
+                    .map_or(false, |value| !value.is_zero())
+                {
+                    coverage.hit(address);
+                }
+            }
 
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
@@ -151,7 +168,7 @@ where
     CS: ConstraintSystem<E>,
 {
     type E = E;
-    type CS = CS;
+    type CS = HostCS<E, CS>;
     type S = SetupStorage<E>;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
@@ -218,6 +235,10 @@ where
         0
     }
 
+    fn storages_changed(&mut self) -> Result<Vec<BigInt>, Error> {
+        Ok(vec![])
+    }
+
     fn loop_begin(&mut self, iterations: usize) -> Result<(), Error> {
         let frame = self
             .execution_state
@@ -227,7 +248,9 @@ where
 
         frame.blocks.push(Block::Loop(Loop {
             first_instruction_index: self.execution_state.instruction_counter,
-            iterations_left: iterations - 1,
+            iterations_left: iterations
+                .checked_sub(1)
+                .ok_or(MalformedBytecode::LoopWithoutIterations)?,
         }));
 
         Ok(())
@@ -238,7 +261,7 @@ where
             .execution_state
             .frames_stack
             .last_mut()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or_else(|| Error::InternalError("Root frame is missing".into()))?;
 
         match frame.blocks.pop() {
             Some(Block::Loop(mut loop_block)) => {
@@ -279,7 +302,7 @@ where
             .execution_state
             .frames_stack
             .pop()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            .ok_or(MalformedBytecode::UnexpectedReturn)?;
 
         if self.execution_state.frames_stack.len() == 1 {
             self.execution_state.instruction_counter = std::usize::MAX;
@@ -384,6 +407,18 @@ where
         Ok(())
     }
 
+    fn unsafe_witness_begin(&mut self) {
+        self.counter.cs.begin();
+    }
+
+    fn unsafe_witness_end(&mut self) -> Result<(), Error> {
+        if !self.counter.cs.end() {
+            return Err(MalformedBytecode::UnexpectedUnsafeWitnessEnd.into());
+        }
+
+        Ok(())
+    }
+
     fn call_native<F: INativeCallable<E, SetupStorage<E>>>(
         &mut self,
         function: F,
@@ -402,7 +437,7 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
-    fn constraint_system(&mut self) -> &mut CS {
+    fn constraint_system(&mut self) -> &mut HostCS<E, CS> {
         &mut self.counter.cs
     }
 
@@ -413,4 +448,12 @@ where
     fn set_location(&mut self, location: Location) {
         self.location = location;
     }
+
+    fn is_auditing(&self) -> bool {
+        self.execution_state.audit.is_some()
+    }
+
+    fn audit(&mut self, reason: AuditReason) {
+        self.execution_state.audit(reason);
+    }
 }
@@ -4,7 +4,7 @@
 
 use std::fmt;
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Location {
     pub file: Option<String>,
     pub function: Option<String>,
@@ -9,4 +9,8 @@ pub mod execution_state;
 pub mod facade;
 pub mod library;
 pub mod location;
+pub mod proof_encoding;
+pub mod public_inputs;
+pub mod unit_test;
 pub mod virtual_machine;
+pub mod witness;
@@ -0,0 +1,125 @@
+//!
+//! The proof encoding.
+//!
+
+use std::fmt;
+
+use crate::error::VerificationError;
+
+///
+/// The proof file encoding, which is detected from the file contents.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProofEncoding {
+    /// The binary form written by the proof `write` method.
+    Raw,
+    /// The hexadecimal text, optionally prefixed with `0x`.
+    Hexadecimal,
+    /// The standard base64 text.
+    Base64,
+}
+
+impl ProofEncoding {
+    ///
+    /// Detects the encoding of `data`.
+    ///
+    /// The surrounding whitespace is ignored for the text encodings. Hexadecimal is checked
+    /// first, since its alphabet is a subset of the base64 one.
+    ///
+    pub fn detect(data: &[u8]) -> Self {
+        let text = Self::trim(data);
+        if text.is_empty() {
+            return Self::Raw;
+        }
+
+        let hexadecimal = text.strip_prefix(b"0x").unwrap_or(text);
+        if !hexadecimal.is_empty()
+            && hexadecimal.len() % 2 == 0
+            && hexadecimal.iter().all(u8::is_ascii_hexdigit)
+        {
+            return Self::Hexadecimal;
+        }
+
+        if text.len() % 4 == 0
+            && text
+                .iter()
+                .all(|byte| byte.is_ascii_alphanumeric() || b"+/=".contains(byte))
+        {
+            return Self::Base64;
+        }
+
+        Self::Raw
+    }
+
+    ///
+    /// Detects the encoding of `data` and decodes it into the raw proof bytes.
+    ///
+    pub fn decode(data: &[u8]) -> Result<Vec<u8>, VerificationError> {
+        match Self::detect(data) {
+            Self::Raw => Ok(data.to_vec()),
+            Self::Hexadecimal => {
+                let text = Self::trim(data);
+                hex::decode(text.strip_prefix(b"0x").unwrap_or(text))
+                    .map_err(|error| VerificationError::InvalidProof(error.to_string()))
+            }
+            Self::Base64 => base64::decode(Self::trim(data))
+                .map_err(|error| VerificationError::InvalidProof(error.to_string())),
+        }
+    }
+
+    ///
+    /// Strips the leading and trailing ASCII whitespace.
+    ///
+    fn trim(data: &[u8]) -> &[u8] {
+        let start = data
+            .iter()
+            .position(|byte| !byte.is_ascii_whitespace())
+            .unwrap_or(data.len());
+        let end = data
+            .iter()
+            .rposition(|byte| !byte.is_ascii_whitespace())
+            .map(|position| position + 1)
+            .unwrap_or(start);
+        &data[start..end]
+    }
+}
+
+impl fmt::Display for ProofEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Raw => write!(f, "raw"),
+            Self::Hexadecimal => write!(f, "hex"),
+            Self::Base64 => write!(f, "base64"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProofEncoding;
+
+    #[test]
+    fn ok_detect() {
+        assert_eq!(
+            ProofEncoding::detect(&[0x00, 0xff, 0x7f]),
+            ProofEncoding::Raw
+        );
+        assert_eq!(ProofEncoding::detect(b"0a1B\n"), ProofEncoding::Hexadecimal);
+        assert_eq!(ProofEncoding::detect(b"0x0a1b"), ProofEncoding::Hexadecimal);
+        assert_eq!(ProofEncoding::detect(b"CgsM+w=="), ProofEncoding::Base64);
+    }
+
+    #[test]
+    fn ok_decode() {
+        let expected = vec![0x0a, 0x0b, 0x0c, 0xfb];
+
+        assert_eq!(
+            ProofEncoding::decode(b" 0x0a0b0cfb\n").expect(zinc_const::panic::TEST_DATA_VALID),
+            expected
+        );
+        assert_eq!(
+            ProofEncoding::decode(b"CgsM+w==").expect(zinc_const::panic::TEST_DATA_VALID),
+            expected
+        );
+    }
+}
@@ -0,0 +1,370 @@
+//!
+//! The application public inputs.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+
+use crate::error::VerificationError;
+use crate::gadgets;
+use crate::IEngine;
+
+/// The name of the contract storage root hash slot.
+pub static STORAGE_ROOT_SLOT_NAME: &str = "storage_root";
+
+/// The name prefix of the output value slots.
+pub static OUTPUT_SLOT_NAME: &str = "output";
+
+///
+/// The public input slot.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    /// The slot meaning, e.g. `output.balances[1]` or `storage_root`.
+    pub name: String,
+    /// The slot value. Negative values are taken modulo the field characteristic.
+    pub value: BigInt,
+}
+
+impl Slot {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, value: BigInt) -> Self {
+        Self { name, value }
+    }
+}
+
+///
+/// The application public inputs.
+///
+/// This is the canonical layout of the public input vector, which the proofs are created and
+/// verified with:
+///
+/// 1. The output value scalars, flattened in the order of the array elements, the tuple items,
+/// and the structure and contract fields declaration. A unit output takes no slots. The
+/// `std::collections::MTreeMap` values cannot be a part of the output.
+///
+/// 2. The contract storage root hash, for contracts only. For mutable contract methods, the
+/// output is the `result` field of the method output structure, since its `root_hash` field is
+/// already the last slot.
+///
+/// There are no method selector slots, since each contract method has its own verifying key.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicInputs {
+    /// The slots in the layout order.
+    slots: Vec<Slot>,
+}
+
+impl PublicInputs {
+    ///
+    /// Lays out the application `output` and the contract `storage_root`, if the application is
+    /// a contract.
+    ///
+    pub fn try_from(
+        output: zinc_types::Value,
+        storage_root: Option<BigInt>,
+    ) -> Result<Self, VerificationError> {
+        let mut slots = Vec::new();
+        Self::flatten(OUTPUT_SLOT_NAME.to_owned(), output, &mut slots)?;
+
+        if let Some(storage_root) = storage_root {
+            slots.push(Slot::new(STORAGE_ROOT_SLOT_NAME.to_owned(), storage_root));
+        }
+
+        Ok(Self { slots })
+    }
+
+    ///
+    /// Parses the public inputs written by `into_json`.
+    ///
+    pub fn try_from_json(json: serde_json::Value) -> Result<Self, VerificationError> {
+        let entries = match json {
+            serde_json::Value::Array(entries) => entries,
+            json => {
+                return Err(VerificationError::InvalidPublicInputs(format!(
+                    "expected an array of slots, found `{}`",
+                    json
+                )))
+            }
+        };
+
+        let mut slots = Vec::with_capacity(entries.len());
+        for entry in entries.into_iter() {
+            let name = entry.get("name").and_then(serde_json::Value::as_str);
+            let value = entry
+                .get("value")
+                .and_then(serde_json::Value::as_str)
+                .and_then(|value| value.parse::<BigInt>().ok());
+
+            match (name, value) {
+                (Some(name), Some(value)) => slots.push(Slot::new(name.to_owned(), value)),
+                _ => {
+                    return Err(VerificationError::InvalidPublicInputs(format!(
+                        "expected a slot with the `name` and decimal `value` strings, found `{}`",
+                        entry
+                    )))
+                }
+            }
+        }
+
+        Ok(Self { slots })
+    }
+
+    ///
+    /// Converts the public inputs to the JSON array of slots.
+    ///
+    pub fn into_json(self) -> serde_json::Value {
+        serde_json::Value::Array(
+            self.slots
+                .into_iter()
+                .map(|slot| {
+                    serde_json::json!({
+                        "name": slot.name,
+                        "value": slot.value.to_string(),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// Converts the public inputs to the field elements vector passed to the verifier.
+    ///
+    pub fn into_field_vec<E: IEngine>(self) -> Result<Vec<E::Fr>, VerificationError> {
+        self.slots
+            .into_iter()
+            .map(|slot| {
+                gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&slot.value)
+                    .ok_or(VerificationError::ValueOverflow(slot.value))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the human-readable layout description, one slot per line.
+    ///
+    pub fn describe(&self) -> String {
+        self.slots
+            .iter()
+            .enumerate()
+            .map(|(index, slot)| format!("{:>4}: {} = {}", index, slot.name, slot.value))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    ///
+    /// Returns the slots in the layout order.
+    ///
+    pub fn slots(&self) -> &[Slot] {
+        self.slots.as_slice()
+    }
+
+    ///
+    /// Returns the mutable slots in the layout order.
+    ///
+    pub fn slots_mut(&mut self) -> &mut [Slot] {
+        self.slots.as_mut_slice()
+    }
+
+    ///
+    /// Flattens the `value` at `path` into `slots` in the order of `Value::into_flat_values`.
+    ///
+    fn flatten(
+        path: String,
+        value: zinc_types::Value,
+        slots: &mut Vec<Slot>,
+    ) -> Result<(), VerificationError> {
+        match value {
+            zinc_types::Value::Unit => {}
+            zinc_types::Value::Scalar(value) => slots.push(Slot::new(path, value.to_bigint())),
+            zinc_types::Value::Enumeration { value, .. } => {
+                slots.push(Slot::new(path, value.to_bigint()))
+            }
+            zinc_types::Value::Flags { value, .. } => {
+                slots.push(Slot::new(path, value.to_bigint()))
+            }
+            zinc_types::Value::Array(values) => {
+                for (index, value) in values.into_iter().enumerate() {
+                    Self::flatten(format!("{}[{}]", path, index), value, slots)?;
+                }
+            }
+            zinc_types::Value::Structure(fields) => {
+                for (name, value) in fields.into_iter() {
+                    Self::flatten(format!("{}.{}", path, name), value, slots)?;
+                }
+            }
+            zinc_types::Value::Contract(fields) => {
+                for field in fields.into_iter() {
+                    Self::flatten(format!("{}.{}", path, field.name), field.value, slots)?;
+                }
+            }
+            zinc_types::Value::Map(_) => {
+                return Err(VerificationError::InvalidPublicInputs(format!(
+                    "the `{}` map value cannot be public",
+                    path
+                )))
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for PublicInputs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use num::BigInt;
+    use num::One;
+
+    use franklin_crypto::bellman::groth16;
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::core::circuit::synthesizer::Synthesizer;
+    use crate::core::facade::Facade;
+
+    use super::PublicInputs;
+
+    ///
+    /// The circuit, which multiplies its field input by three.
+    ///
+    fn circuit() -> zinc_types::Circuit {
+        zinc_types::Circuit::new(
+            "test".to_owned(),
+            0,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            indexmap::IndexMap::new(),
+            vec![
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Push::new_field(BigInt::from(3)).into(),
+                zinc_types::Mul::default().into(),
+                zinc_types::Return::new(1).into(),
+            ],
+        )
+    }
+
+    ///
+    /// Proves the circuit with `input` and returns the verifying key, the proof, and the public
+    /// inputs reloaded from their JSON representation.
+    ///
+    fn prove(
+        input: BigInt,
+    ) -> (
+        groth16::VerifyingKey<Bn256>,
+        groth16::Proof<Bn256>,
+        PublicInputs,
+    ) {
+        let rng = &mut rand::thread_rng();
+
+        let mut output = None;
+        let params = groth16::generate_random_parameters::<Bn256, _, _>(
+            Synthesizer {
+                inputs: None,
+                output: &mut output,
+                bytecode: circuit(),
+                _pd: PhantomData,
+            },
+            rng,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut output = None;
+        let proof = groth16::create_random_proof(
+            Synthesizer {
+                inputs: Some(vec![input]),
+                output: &mut output,
+                bytecode: circuit(),
+                _pd: PhantomData,
+            },
+            &params,
+            rng,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let output_flat: Vec<BigInt> = output
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+            .into_iter()
+            .filter_map(|value| value)
+            .collect();
+        let output_value = zinc_types::Value::from_flat_values(
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            output_flat.as_slice(),
+        );
+
+        let public_inputs =
+            PublicInputs::try_from(output_value, None).expect(zinc_const::panic::TEST_DATA_VALID);
+        let serialized = serde_json::to_string(&public_inputs.into_json())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let reloaded = PublicInputs::try_from_json(
+            serde_json::from_str(serialized.as_str()).expect(zinc_const::panic::TEST_DATA_VALID),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        (params.vk, proof, reloaded)
+    }
+
+    #[test]
+    fn ok_reloaded() {
+        let (verifying_key, proof, public_inputs) = prove(BigInt::from(14));
+
+        assert_eq!(public_inputs.slots().len(), 1);
+        assert_eq!(public_inputs.slots()[0].value, BigInt::from(42));
+
+        let is_valid = Facade::verify::<Bn256>(verifying_key, proof, public_inputs)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn error_perturbed() {
+        let (verifying_key, proof, mut public_inputs) = prove(BigInt::from(14));
+
+        public_inputs.slots_mut()[0].value += BigInt::one();
+
+        let is_valid = Facade::verify::<Bn256>(verifying_key, proof, public_inputs)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(!is_valid);
+    }
+
+    #[test]
+    fn ok_describe() {
+        let output = zinc_types::Value::Structure(vec![
+            (
+                "balances".to_owned(),
+                zinc_types::Value::Array(vec![
+                    zinc_types::Value::Scalar(zinc_types::ScalarValue::Field(BigInt::from(1))),
+                    zinc_types::Value::Scalar(zinc_types::ScalarValue::Field(BigInt::from(2))),
+                ]),
+            ),
+            (
+                "flag".to_owned(),
+                zinc_types::Value::Scalar(zinc_types::ScalarValue::Boolean(true)),
+            ),
+        ]);
+
+        let public_inputs = PublicInputs::try_from(output, Some(BigInt::from(42)))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(
+            public_inputs.describe(),
+            vec![
+                "   0: output.balances[0] = 1",
+                "   1: output.balances[1] = 2",
+                "   2: output.flag = 1",
+                "   3: storage_root = 42",
+            ]
+            .join("\n")
+        );
+    }
+}
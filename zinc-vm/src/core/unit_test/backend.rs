@@ -0,0 +1,32 @@
+//!
+//! The unit test execution backend.
+//!
+
+///
+/// The unit test execution backend.
+///
+/// Both backends execute the same instructions, and only differ in the constraint system.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    /// The constraints are synthesized and checked for satisfiability.
+    Synthesis,
+    /// The instructions are evaluated without the constraints, which is much faster, but
+    /// does not check whether the constraints are satisfiable.
+    Evaluation,
+}
+
+impl Backend {
+    ///
+    /// Selects the backend for the `unit_test`.
+    ///
+    /// The tests marked with the `#[proof]` attribute are synthesized, unless `is_fast` is set.
+    ///
+    pub fn select(unit_test: &zinc_types::UnitTest, is_fast: bool) -> Self {
+        if unit_test.is_proof && !is_fast {
+            Self::Synthesis
+        } else {
+            Self::Evaluation
+        }
+    }
+}
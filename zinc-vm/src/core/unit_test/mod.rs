@@ -0,0 +1,187 @@
+//!
+//! The unit test report.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod backend;
+pub mod outcome;
+
+use std::time::Duration;
+
+use indexmap::IndexMap;
+
+use zinc_const::UnitTestExitCode;
+
+use self::outcome::format_duration;
+use self::outcome::Outcome;
+use self::outcome::Status;
+
+///
+/// The unit test report, which is returned by the application facades.
+///
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// The test outcomes in the execution order.
+    pub outcomes: Vec<Outcome>,
+    /// The test run exit code.
+    pub exit_code: UnitTestExitCode,
+    /// The file and module path of the last started test, which the headers are printed for.
+    current: Option<(String, Vec<String>)>,
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self {
+            outcomes: Vec::new(),
+            exit_code: UnitTestExitCode::Passed,
+            current: None,
+        }
+    }
+}
+
+impl Report {
+    /// The number of the slowest tests shown in the summary.
+    pub const SLOWEST_COUNT: usize = 5;
+
+    /// The module tree indentation.
+    pub const INDENT: &'static str = "    ";
+
+    ///
+    /// Sorts the `unit_tests` by their files and module paths, so the tests of each module
+    /// are run and reported together. The order of the tests within a module is kept.
+    ///
+    pub fn sort(unit_tests: &mut IndexMap<String, zinc_types::UnitTest>) {
+        unit_tests.sort_by(|name_1, _, name_2, _| {
+            let (file_1, path_1) = zinc_types::UnitTest::split_name(name_1.as_str());
+            let (file_2, path_2) = zinc_types::UnitTest::split_name(name_2.as_str());
+
+            let mut modules_1: Vec<&str> = path_1.split("::").collect();
+            modules_1.pop();
+            let mut modules_2: Vec<&str> = path_2.split("::").collect();
+            modules_2.pop();
+
+            (file_1, modules_1).cmp(&(file_2, modules_2))
+        });
+    }
+
+    ///
+    /// Prints the file and module headers of the test `name` from the `file`, which are not
+    /// shared with the previous test.
+    ///
+    pub fn start(&mut self, file: &str, name: &str) {
+        for header in self.headers(file, name).into_iter() {
+            log::info!("{}", header);
+        }
+    }
+
+    ///
+    /// Returns the file and module headers of the test `name` from the `file`, which are not
+    /// shared with the previous test, with the modules indented by their depth.
+    ///
+    pub fn headers(&mut self, file: &str, name: &str) -> Vec<String> {
+        let mut modules: Vec<String> = name.split("::").map(str::to_owned).collect();
+        modules.pop();
+
+        let mut headers = Vec::with_capacity(modules.len() + 1);
+        let common = match self.current {
+            Some((ref current_file, ref current_modules)) if current_file == file => {
+                current_modules
+                    .iter()
+                    .zip(modules.iter())
+                    .take_while(|(current, module)| current == module)
+                    .count()
+            }
+            _ => {
+                headers.push(format!("running tests in {}", file));
+                0
+            }
+        };
+        for (depth, module) in modules.iter().enumerate().skip(common) {
+            headers.push(format!("{}mod {}", Self::INDENT.repeat(depth), module));
+        }
+
+        self.current = Some((file.to_owned(), modules));
+        headers
+    }
+
+    ///
+    /// Prints the test `outcome` line and appends it to the report.
+    ///
+    pub fn push(&mut self, outcome: Outcome, min_time: Option<Duration>) {
+        outcome.log(min_time);
+
+        match outcome.status {
+            Status::Failed => self.exit_code = UnitTestExitCode::Failed,
+            Status::Ignored => self.exit_code = UnitTestExitCode::Ignored,
+            Status::Passed => {}
+        }
+
+        self.outcomes.push(outcome);
+    }
+
+    ///
+    /// Returns at most `count` slowest tests, the slowest first.
+    ///
+    /// The tests with equal wall time keep their execution order.
+    ///
+    pub fn slowest(&self, count: usize) -> Vec<&Outcome> {
+        let mut outcomes: Vec<&Outcome> = self
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.status != Status::Ignored)
+            .collect();
+        outcomes.sort_by(|a, b| b.total_time().cmp(&a.total_time()));
+        outcomes.truncate(count);
+        outcomes
+    }
+
+    ///
+    /// The total time spent on the constraint synthesis.
+    ///
+    pub fn synthesis_time(&self) -> Duration {
+        self.outcomes
+            .iter()
+            .map(|outcome| outcome.synthesis_time)
+            .sum()
+    }
+
+    ///
+    /// The total time spent on the instruction execution.
+    ///
+    pub fn execution_time(&self) -> Duration {
+        self.outcomes
+            .iter()
+            .map(|outcome| outcome.execution_time)
+            .sum()
+    }
+
+    ///
+    /// The summary lines with the slowest tests and the total timing.
+    ///
+    pub fn summary(&self) -> String {
+        let mut lines = Vec::with_capacity(Self::SLOWEST_COUNT + 2);
+
+        let slowest = self.slowest(Self::SLOWEST_COUNT);
+        if !slowest.is_empty() {
+            lines.push(format!("slowest {} tests:", slowest.len()));
+            for outcome in slowest.into_iter() {
+                lines.push(format!(
+                    "    {}::{} {}",
+                    outcome.file,
+                    outcome.name,
+                    outcome.timing()
+                ));
+            }
+        }
+
+        lines.push(format!(
+            "total time: {} synthesis, {} execution",
+            format_duration(self.synthesis_time()),
+            format_duration(self.execution_time()),
+        ));
+
+        lines.join("\n")
+    }
+}
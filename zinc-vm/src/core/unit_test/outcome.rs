@@ -0,0 +1,199 @@
+//!
+//! The unit test outcome.
+//!
+
+use std::time::Duration;
+
+use colored::Colorize;
+
+use crate::core::unit_test::backend::Backend;
+use crate::core::unit_test::Report;
+
+///
+/// The unit test status.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    /// The test has passed, or has failed as it is marked with the `should_panic` attribute.
+    Passed,
+    /// The test has failed, or has passed despite the `should_panic` attribute.
+    Failed,
+    /// The test is marked with the `ignore` attribute.
+    Ignored,
+}
+
+impl Status {
+    ///
+    /// Returns the status and detail of the `#[should_panic]` test, which has failed with
+    /// the `error` message.
+    ///
+    /// If the test expects a message substring, the test only passes if the `error` contains it.
+    ///
+    pub fn panicked(expected: Option<&str>, error: String) -> (Self, Option<String>) {
+        match expected {
+            Some(expected) if !error.contains(expected) => (
+                Self::Failed,
+                Some(format!(
+                    "panicked with `{}`, expected a message containing `{}`",
+                    error, expected
+                )),
+            ),
+            _ => (Self::Passed, Some("failed".to_owned())),
+        }
+    }
+}
+
+///
+/// The unit test outcome with its timing.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    /// The source file, where the test is declared.
+    pub file: String,
+    /// The test name with its module path, e.g. `exchange::math::overflow`.
+    pub name: String,
+    /// The test status.
+    pub status: Status,
+    /// The status detail, e.g. `failed` or the runtime error message.
+    pub detail: Option<String>,
+    /// The backend the test has been executed with.
+    pub backend: Backend,
+    /// The time spent on allocating the variables and enforcing the constraints.
+    pub synthesis_time: Duration,
+    /// The rest of the test wall time, spent on executing the instructions.
+    pub execution_time: Duration,
+}
+
+impl Outcome {
+    ///
+    /// Creates an outcome, splitting the test wall `total_time` into the synthesis and execution.
+    ///
+    pub fn new(
+        file: String,
+        name: String,
+        status: Status,
+        detail: Option<String>,
+        backend: Backend,
+        synthesis_time: Duration,
+        total_time: Duration,
+    ) -> Self {
+        Self {
+            file,
+            name,
+            status,
+            detail,
+            backend,
+            synthesis_time,
+            execution_time: total_time.checked_sub(synthesis_time).unwrap_or_default(),
+        }
+    }
+
+    ///
+    /// Creates an outcome of the ignored test.
+    ///
+    pub fn ignored(file: String, name: String) -> Self {
+        Self::new(
+            file,
+            name,
+            Status::Ignored,
+            None,
+            Backend::Evaluation,
+            Duration::default(),
+            Duration::default(),
+        )
+    }
+
+    ///
+    /// The test wall time.
+    ///
+    pub fn total_time(&self) -> Duration {
+        self.synthesis_time + self.execution_time
+    }
+
+    ///
+    /// The test function name without its module path.
+    ///
+    pub fn function(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or_default()
+    }
+
+    ///
+    /// The outcome line indentation, which depends on the module depth.
+    ///
+    pub fn indent(&self) -> String {
+        Report::INDENT.repeat(self.name.matches("::").count())
+    }
+
+    ///
+    /// Prints the outcome line with the timing.
+    ///
+    /// The test is printed under its module header, so only the function name is shown.
+    /// The passed and ignored tests faster than `min_time` are hidden. The failed ones are
+    /// always printed.
+    ///
+    pub fn log(&self, min_time: Option<Duration>) {
+        let is_hidden = match min_time {
+            Some(min_time) => self.status != Status::Failed && self.total_time() < min_time,
+            None => false,
+        };
+        if is_hidden {
+            return;
+        }
+
+        let detail = match self.detail {
+            Some(ref detail) => format!(" ({})", detail),
+            None => String::new(),
+        };
+        let indent = self.indent();
+        match self.status {
+            Status::Passed => log::info!(
+                "{}test {} ... {}{} {}",
+                indent,
+                self.function(),
+                "ok".green(),
+                detail,
+                self.timing(),
+            ),
+            Status::Failed => log::error!(
+                "{}test {} ... {}{} {}",
+                indent,
+                self.function(),
+                "error".bright_red(),
+                detail,
+                self.timing(),
+            ),
+            Status::Ignored => log::info!(
+                "{}test {} ... {}",
+                indent,
+                self.function(),
+                "ignore".yellow()
+            ),
+        }
+    }
+
+    ///
+    /// The timing suffix of the outcome line.
+    ///
+    pub fn timing(&self) -> String {
+        match self.backend {
+            Backend::Synthesis => format!(
+                "[{} = {} synthesis + {} execution]",
+                format_duration(self.total_time()),
+                format_duration(self.synthesis_time),
+                format_duration(self.execution_time),
+            ),
+            Backend::Evaluation => format!("[{} evaluation]", format_duration(self.total_time())),
+        }
+    }
+}
+
+///
+/// Formats the `duration` in milliseconds, or in seconds if it takes longer than a second.
+///
+pub fn format_duration(duration: Duration) -> String {
+    if duration >= Duration::from_secs(1) {
+        format!("{:.3}s", duration.as_secs_f64())
+    } else {
+        format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+    }
+}
@@ -0,0 +1,445 @@
+//!
+//! The unit test report tests.
+//!
+
+use std::time::Duration;
+
+use indexmap::IndexMap;
+use num::BigInt;
+use num::Zero;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_const::UnitTestExitCode;
+
+use crate::core::circuit::facade::Facade as CircuitFacade;
+use crate::core::unit_test::backend::Backend;
+use crate::core::unit_test::outcome::Outcome;
+use crate::core::unit_test::outcome::Status;
+use crate::core::unit_test::Report;
+
+fn outcome(name: &str, status: Status, synthesis_ms: u64, total_ms: u64) -> Outcome {
+    Outcome::new(
+        "main".to_owned(),
+        name.to_owned(),
+        status,
+        None,
+        Backend::Synthesis,
+        Duration::from_millis(synthesis_ms),
+        Duration::from_millis(total_ms),
+    )
+}
+
+fn report() -> Report {
+    let mut report = Report::default();
+    for outcome in vec![
+        outcome("first", Status::Passed, 1, 3),
+        outcome("slow", Status::Passed, 30, 400),
+        outcome("second", Status::Passed, 2, 3),
+        outcome("failing", Status::Failed, 5, 10),
+        outcome("fast", Status::Passed, 0, 1),
+        outcome("third", Status::Passed, 1, 2),
+        Outcome::ignored("main".to_owned(), "ignored".to_owned()),
+    ]
+    .into_iter()
+    {
+        report.push(outcome, None);
+    }
+    report
+}
+
+#[test]
+fn ok_outcome_timing() {
+    let outcome = outcome("test", Status::Passed, 30, 100);
+
+    assert_eq!(outcome.synthesis_time, Duration::from_millis(30));
+    assert_eq!(outcome.execution_time, Duration::from_millis(70));
+    assert_eq!(outcome.total_time(), Duration::from_millis(100));
+}
+
+#[test]
+fn ok_outcome_timing_clamped() {
+    let outcome = outcome("test", Status::Passed, 30, 20);
+
+    assert_eq!(outcome.execution_time, Duration::default());
+    assert_eq!(outcome.total_time(), Duration::from_millis(30));
+}
+
+#[test]
+fn ok_slowest_sorted() {
+    let report = report();
+
+    let slowest: Vec<&str> = report
+        .slowest(Report::SLOWEST_COUNT)
+        .into_iter()
+        .map(|outcome| outcome.name.as_str())
+        .collect();
+
+    assert_eq!(slowest, vec!["slow", "failing", "first", "second", "third"]);
+}
+
+#[test]
+fn ok_slowest_fewer_than_count() {
+    let mut report = Report::default();
+    report.push(outcome("first", Status::Passed, 1, 2), None);
+    report.push(outcome("second", Status::Passed, 1, 5), None);
+
+    let slowest: Vec<&str> = report
+        .slowest(Report::SLOWEST_COUNT)
+        .into_iter()
+        .map(|outcome| outcome.name.as_str())
+        .collect();
+
+    assert_eq!(slowest, vec!["second", "first"]);
+}
+
+#[test]
+fn ok_summary() {
+    let report = report();
+
+    let summary = report.summary();
+    let lines: Vec<&str> = summary.lines().collect();
+
+    assert_eq!(lines.len(), Report::SLOWEST_COUNT + 2);
+    assert_eq!(lines[0], "slowest 5 tests:");
+    assert!(lines[1].starts_with("    main::slow [400.000ms = "));
+    assert_eq!(
+        lines[6],
+        "total time: 39.000ms synthesis, 380.000ms execution"
+    );
+}
+
+#[test]
+fn ok_exit_code() {
+    let report = report();
+
+    assert_eq!(report.exit_code, UnitTestExitCode::Ignored);
+    assert_eq!(report.outcomes.len(), 7);
+}
+
+#[test]
+fn ok_exit_code_failed() {
+    let mut report = Report::default();
+    report.push(outcome("first", Status::Passed, 1, 2), None);
+    report.push(outcome("second", Status::Failed, 1, 2), None);
+    report.push(outcome("third", Status::Passed, 1, 2), None);
+
+    assert_eq!(report.exit_code, UnitTestExitCode::Failed);
+}
+
+#[test]
+fn ok_circuit_facade() {
+    let instructions = vec![
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        zinc_types::Instruction::LoopBegin(zinc_types::LoopBegin::new(20_000)),
+        zinc_types::Instruction::NoOperation(zinc_types::NoOperation),
+        zinc_types::Instruction::LoopEnd(zinc_types::LoopEnd),
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+    ];
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "tests/main.zn::fast".to_owned(),
+        zinc_types::UnitTest::new(0, false, None, false, false, None, None),
+    );
+    unit_tests.insert(
+        "tests/main.zn::slow".to_owned(),
+        zinc_types::UnitTest::new(1, false, None, false, false, None, None),
+    );
+    unit_tests.insert(
+        "tests/main.zn::should_panic".to_owned(),
+        zinc_types::UnitTest::new(0, true, None, false, false, None, None),
+    );
+
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        unit_tests,
+        instructions,
+    );
+
+    let report = CircuitFacade::new(circuit)
+        .test::<Bn256>(None, None, false, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let outcomes: Vec<(&str, &str, Status)> = report
+        .outcomes
+        .iter()
+        .map(|outcome| (outcome.file.as_str(), outcome.name.as_str(), outcome.status))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("tests/main.zn", "fast", Status::Passed),
+            ("tests/main.zn", "slow", Status::Passed),
+            ("tests/main.zn", "should_panic", Status::Failed),
+        ]
+    );
+    assert_eq!(report.exit_code, UnitTestExitCode::Failed);
+    assert_eq!(report.slowest(1)[0].name, "slow");
+}
+
+#[test]
+fn ok_circuit_facade_expected_panic() {
+    let instructions = vec![
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        zinc_types::Instruction::Push(zinc_types::Push::new(
+            BigInt::zero(),
+            zinc_types::ScalarType::Boolean,
+        )),
+        zinc_types::Instruction::Require(zinc_types::Require::new(Some(
+            "The balance is too low".to_owned(),
+        ))),
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+    ];
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "main::plain".to_owned(),
+        zinc_types::UnitTest::new(1, true, None, false, false, None, None),
+    );
+    unit_tests.insert(
+        "main::matching".to_owned(),
+        zinc_types::UnitTest::new(
+            1,
+            true,
+            Some("balance is too low".to_owned()),
+            false,
+            false,
+            None,
+            None,
+        ),
+    );
+    unit_tests.insert(
+        "main::mismatching".to_owned(),
+        zinc_types::UnitTest::new(
+            1,
+            true,
+            Some("overflow".to_owned()),
+            false,
+            false,
+            None,
+            None,
+        ),
+    );
+
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        unit_tests,
+        instructions,
+    );
+
+    let report = CircuitFacade::new(circuit)
+        .test::<Bn256>(None, None, false, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let outcomes: Vec<(&str, Status)> = report
+        .outcomes
+        .iter()
+        .map(|outcome| (outcome.name.as_str(), outcome.status))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("plain", Status::Passed),
+            ("matching", Status::Passed),
+            ("mismatching", Status::Failed),
+        ]
+    );
+    assert!(report.outcomes[2]
+        .detail
+        .as_deref()
+        .map_or(false, |detail| detail.contains("The balance is too low")
+            && detail.contains("overflow")));
+    assert_eq!(report.exit_code, UnitTestExitCode::Failed);
+}
+
+#[test]
+fn ok_circuit_facade_module_tree() {
+    let instructions = vec![zinc_types::Instruction::Return(zinc_types::Return::new(0))];
+
+    let mut unit_tests = IndexMap::new();
+    for name in vec![
+        "math::add",
+        "add",
+        "geometry::shapes::add",
+        "tests/math.zn::add",
+        "geometry::add",
+        "math::sub",
+    ]
+    .into_iter()
+    {
+        unit_tests.insert(
+            name.to_owned(),
+            zinc_types::UnitTest::new(0, false, None, false, false, None, None),
+        );
+    }
+
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        unit_tests,
+        instructions,
+    );
+
+    let report = CircuitFacade::new(circuit)
+        .test::<Bn256>(None, None, false, None)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let outcomes: Vec<(&str, &str, Status)> = report
+        .outcomes
+        .iter()
+        .map(|outcome| (outcome.file.as_str(), outcome.name.as_str(), outcome.status))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("src/", "add", Status::Passed),
+            ("src/", "geometry::add", Status::Passed),
+            ("src/", "geometry::shapes::add", Status::Passed),
+            ("src/", "math::add", Status::Passed),
+            ("src/", "math::sub", Status::Passed),
+            ("tests/math.zn", "add", Status::Passed),
+        ]
+    );
+    assert_eq!(report.outcomes[2].function(), "add");
+    assert_eq!(report.outcomes[2].indent(), "        ");
+}
+
+#[test]
+fn ok_report_headers() {
+    let mut report = Report::default();
+
+    let headers: Vec<Vec<String>> = vec![
+        ("src/", "add"),
+        ("src/", "geometry::add"),
+        ("src/", "geometry::shapes::add"),
+        ("src/", "geometry::shapes::sub"),
+        ("src/", "math::add"),
+        ("tests/math.zn", "add"),
+    ]
+    .into_iter()
+    .map(|(file, name)| report.headers(file, name))
+    .collect();
+
+    assert_eq!(
+        headers,
+        vec![
+            vec!["running tests in src/".to_owned()],
+            vec!["mod geometry".to_owned()],
+            vec!["    mod shapes".to_owned()],
+            vec![],
+            vec!["mod math".to_owned()],
+            vec!["running tests in tests/math.zn".to_owned()],
+        ]
+    );
+}
+
+#[test]
+fn ok_backend_select() {
+    let proof = zinc_types::UnitTest::new(0, false, None, false, true, None, None);
+    let evaluated = zinc_types::UnitTest::new(0, false, None, false, false, None, None);
+
+    assert_eq!(Backend::select(&proof, false), Backend::Synthesis);
+    assert_eq!(Backend::select(&proof, true), Backend::Evaluation);
+    assert_eq!(Backend::select(&evaluated, false), Backend::Evaluation);
+    assert_eq!(Backend::select(&evaluated, true), Backend::Evaluation);
+}
+
+#[test]
+fn ok_circuit_facade_backends() {
+    let instructions = vec![zinc_types::Instruction::Return(zinc_types::Return::new(0))];
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "main::evaluated".to_owned(),
+        zinc_types::UnitTest::new(0, false, None, false, false, None, None),
+    );
+    unit_tests.insert(
+        "main::proof".to_owned(),
+        zinc_types::UnitTest::new(0, false, None, false, true, None, None),
+    );
+
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        unit_tests,
+        instructions,
+    );
+
+    for (is_fast, expected) in vec![
+        (false, vec![Backend::Evaluation, Backend::Synthesis]),
+        (true, vec![Backend::Evaluation, Backend::Evaluation]),
+    ]
+    .into_iter()
+    {
+        let report = CircuitFacade::new(circuit.clone())
+            .test::<Bn256>(None, None, is_fast, None)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let backends: Vec<Backend> = report
+            .outcomes
+            .iter()
+            .map(|outcome| outcome.backend)
+            .collect();
+        assert_eq!(backends, expected);
+        assert_eq!(report.exit_code, UnitTestExitCode::Passed);
+    }
+}
+
+#[test]
+fn ok_circuit_facade_timeout() {
+    let instructions = vec![
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        zinc_types::Instruction::LoopBegin(zinc_types::LoopBegin::new(usize::MAX)),
+        zinc_types::Instruction::LoopEnd(zinc_types::LoopEnd),
+        zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+    ];
+
+    let mut unit_tests = IndexMap::new();
+    unit_tests.insert(
+        "main::fast".to_owned(),
+        zinc_types::UnitTest::new(0, false, None, false, false, None, None),
+    );
+    unit_tests.insert(
+        "main::looping".to_owned(),
+        zinc_types::UnitTest::new(1, true, None, false, false, None, None),
+    );
+
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Unit,
+        unit_tests,
+        instructions,
+    );
+
+    let report = CircuitFacade::new(circuit)
+        .test::<Bn256>(None, None, false, Some(Duration::from_millis(200)))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let outcomes: Vec<(&str, Status)> = report
+        .outcomes
+        .iter()
+        .map(|outcome| (outcome.name.as_str(), outcome.status))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![("fast", Status::Passed), ("looping", Status::Failed)]
+    );
+    assert!(report.outcomes[1]
+        .detail
+        .as_deref()
+        .map_or(false, |detail| detail.contains("timed out")));
+    assert_eq!(report.exit_code, UnitTestExitCode::Failed);
+}
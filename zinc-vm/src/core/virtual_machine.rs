@@ -2,9 +2,12 @@
 //! The virtual machine trait.
 //!
 
+use num::BigInt;
+
 use franklin_crypto::bellman::ConstraintSystem;
 
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::cell::Cell;
 use crate::core::location::Location;
 use crate::error::Error;
@@ -57,6 +60,7 @@ pub trait IVirtualMachine {
         values: LeafVariant<Self::E>,
     ) -> Result<(), Error>;
     fn storages_count(&self) -> usize;
+    fn storages_changed(&mut self) -> Result<Vec<BigInt>, Error>;
 
     // Flow control operations
 
@@ -70,6 +74,9 @@ pub trait IVirtualMachine {
     fn branch_else(&mut self) -> Result<(), Error>;
     fn branch_end(&mut self) -> Result<(), Error>;
 
+    fn unsafe_witness_begin(&mut self);
+    fn unsafe_witness_end(&mut self) -> Result<(), Error>;
+
     fn call_native<F: INativeCallable<Self::E, Self::S>>(
         &mut self,
         function: F,
@@ -82,4 +89,10 @@ pub trait IVirtualMachine {
     fn get_location(&mut self) -> Location;
 
     fn set_location(&mut self, location: Location);
+
+    // Constant-time audit
+
+    fn is_auditing(&self) -> bool;
+
+    fn audit(&mut self, reason: AuditReason);
 }
@@ -0,0 +1,242 @@
+//!
+//! The application witness.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+
+///
+/// The witness variable kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VariableKind {
+    /// The public input variable, e.g. an output value scalar.
+    Input,
+    /// The private auxiliary variable, e.g. an argument or an intermediate value.
+    Auxiliary,
+}
+
+impl fmt::Display for VariableKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Input => write!(f, "input"),
+            Self::Auxiliary => write!(f, "auxiliary"),
+        }
+    }
+}
+
+///
+/// The witness variable.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    /// The variable kind.
+    pub kind: VariableKind,
+    /// The variable value as a field element.
+    pub value: BigInt,
+    /// The address of the instruction, which has allocated the variable. Unset for the
+    /// variables allocated before and after the bytecode execution, that is, for the application
+    /// arguments and output.
+    pub instruction: Option<usize>,
+    /// The source code location of the instruction, if the bytecode has the debug information.
+    pub location: Option<String>,
+}
+
+impl Variable {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        kind: VariableKind,
+        value: BigInt,
+        instruction: Option<usize>,
+        location: Option<String>,
+    ) -> Self {
+        Self {
+            kind,
+            value,
+            instruction,
+            location,
+        }
+    }
+}
+
+///
+/// The application witness.
+///
+/// Contains every variable allocated during the application execution, in the order of
+/// allocation. The constant `one` public input is implicit and not included.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Witness {
+    /// The variables in the order of allocation.
+    variables: Vec<Variable>,
+}
+
+impl Witness {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(variables: Vec<Variable>) -> Self {
+        Self { variables }
+    }
+
+    ///
+    /// Converts the witness to the JSON array of variables.
+    ///
+    pub fn into_json(self) -> serde_json::Value {
+        serde_json::Value::Array(
+            self.variables
+                .into_iter()
+                .map(|variable| {
+                    serde_json::json!({
+                        "kind": variable.kind.to_string(),
+                        "value": variable.value.to_string(),
+                        "instruction": variable.instruction,
+                        "location": variable.location,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// Converts the witness to the compact binary form, which is the little-endian 64-bit
+    /// variable count, followed by a kind byte (`1` for inputs, `0` otherwise) and the
+    /// 32-byte little-endian value of each variable.
+    ///
+    pub fn into_bytes(self) -> Vec<u8> {
+        let value_size = zinc_const::bitlength::FIELD_PADDED / zinc_const::bitlength::BYTE;
+
+        let mut bytes = Vec::with_capacity(8 + self.variables.len() * (1 + value_size));
+        bytes.extend((self.variables.len() as u64).to_le_bytes().iter());
+        for variable in self.variables.into_iter() {
+            bytes.push((variable.kind == VariableKind::Input) as u8);
+
+            let (_sign, mut value) = variable.value.to_bytes_le();
+            value.resize(value_size, 0);
+            bytes.extend(value);
+        }
+
+        bytes
+    }
+
+    ///
+    /// Returns the variables in the order of allocation.
+    ///
+    pub fn variables(&self) -> &[Variable] {
+        self.variables.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::marker::PhantomData;
+
+    use num::BigInt;
+
+    use franklin_crypto::bellman::groth16;
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::core::circuit::facade::Facade as CircuitFacade;
+    use crate::core::circuit::synthesizer::Synthesizer;
+
+    use super::VariableKind;
+    use super::Witness;
+
+    ///
+    /// The circuit, which squares its field input at line 2 of `main.zn`.
+    ///
+    fn circuit() -> zinc_types::Circuit {
+        zinc_types::Circuit::new(
+            "test".to_owned(),
+            0,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            indexmap::IndexMap::new(),
+            vec![
+                zinc_types::FileMarker::new("main.zn".to_owned()).into(),
+                zinc_types::LineMarker::new(2).into(),
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Mul::default().into(),
+                zinc_types::Return::new(1).into(),
+            ],
+        )
+    }
+
+    fn witness(input: BigInt) -> Witness {
+        CircuitFacade::new(circuit())
+            .witness::<Bn256>(zinc_types::Value::Scalar(zinc_types::ScalarValue::Field(
+                input,
+            )))
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    #[test]
+    fn ok_variables_count() {
+        let params = groth16::generate_random_parameters::<Bn256, _, _>(
+            Synthesizer {
+                inputs: None,
+                output: &mut None,
+                bytecode: circuit(),
+                _pd: PhantomData,
+            },
+            &mut rand::thread_rng(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let witness = witness(BigInt::from(14));
+
+        // The constant `one` input is not a part of the witness.
+        assert_eq!(
+            witness.variables().len(),
+            params.vk.ic.len() - 1 + params.l.len()
+        );
+    }
+
+    #[test]
+    fn ok_named_values() {
+        let witness = witness(BigInt::from(14));
+
+        let argument = witness
+            .variables()
+            .first()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(argument.kind, VariableKind::Auxiliary);
+        assert_eq!(argument.value, BigInt::from(14));
+        assert_eq!(argument.instruction, None);
+
+        let product = witness
+            .variables()
+            .iter()
+            .find(|variable| variable.instruction == Some(4))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(product.value, BigInt::from(196));
+        assert_eq!(product.location.as_deref(), Some("main.zn:2"));
+
+        let output = witness
+            .variables()
+            .last()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(output.kind, VariableKind::Input);
+        assert_eq!(output.value, BigInt::from(196));
+        assert_eq!(output.instruction, None);
+    }
+
+    #[test]
+    fn ok_serialized() {
+        let witness = witness(BigInt::from(14));
+        let count = witness.variables().len();
+
+        let json = witness.clone().into_json();
+        assert_eq!(json.as_array().map(Vec::len), Some(count));
+        assert_eq!(json[0]["value"], serde_json::json!("14"));
+
+        let bytes = witness.into_bytes();
+        assert_eq!(bytes.len(), 8 + count * 33);
+        assert_eq!(bytes[..8], (count as u64).to_le_bytes());
+        assert_eq!(bytes[8..10], [0, 14]);
+    }
+}
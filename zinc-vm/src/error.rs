@@ -2,6 +2,8 @@
 //! The Zinc virtual machine error.
 //!
 
+use std::time::Duration;
+
 use num::BigInt;
 use thiserror::Error;
 
@@ -22,6 +24,9 @@ pub enum MalformedBytecode {
     #[error("unexpected `loop_end` instruction")]
     UnexpectedLoopEnd,
 
+    #[error("`loop_begin` instruction without iterations")]
+    LoopWithoutIterations,
+
     #[error("unexpected `return` instruction")]
     UnexpectedReturn,
 
@@ -31,12 +36,18 @@ pub enum MalformedBytecode {
     #[error("unexpected `end_if` instruction")]
     UnexpectedEndIf,
 
+    #[error("unexpected `unsafe_witness_end` instruction")]
+    UnexpectedUnsafeWitnessEnd,
+
     #[error("stack underflow")]
     StackUnderflow,
 
     #[error("reading uninitialized memory")]
     UninitializedStorageAccess,
 
+    #[error("data stack overflow: address {address} exceeds the data stack size {limit}")]
+    DataStackOverflow { address: usize, limit: usize },
+
     #[error("conditional branches produced results of different sizes")]
     BranchStacksDoNotMatch,
 }
@@ -48,6 +59,15 @@ pub enum VerificationError {
 
     #[error("failed to synthesize circuit: {0}")]
     SynthesisError(franklin_crypto::bellman::SynthesisError),
+
+    #[error("invalid public inputs: {0}")]
+    InvalidPublicInputs(String),
+
+    #[error("invalid verifying key: {0}")]
+    InvalidVerifyingKey(std::io::Error),
+
+    #[error("invalid proof: {0}")]
+    InvalidProof(String),
 }
 
 #[derive(Debug, Error)]
@@ -70,6 +90,12 @@ pub enum Error {
     #[error("require error: {0}")]
     RequireError(String),
 
+    #[error("assertion failed: {message}\n{diff}")]
+    AssertionFailed { message: String, diff: String },
+
+    #[error("assertion failed: the storage of contracts {addresses} has changed")]
+    StorageChanged { addresses: String },
+
     #[error(
         "index out of bounds: expected index in range {lower_bound}..{upper_bound}, found {found}"
     )]
@@ -94,6 +120,9 @@ pub enum Error {
     #[error("division by zero")]
     DivisionByZero,
 
+    #[error("remainder of division by zero")]
+    RemainderByZero,
+
     #[error("inverting zero")]
     ZeroInversion,
 
@@ -106,6 +135,36 @@ pub enum Error {
         scalar_type: zinc_types::ScalarType,
     },
 
+    #[error("unsound range check elision: value {value} is not in range of type {scalar_type}, though the compiler has proven it is")]
+    RangeCheckElisionUnsound {
+        value: BigInt,
+        scalar_type: zinc_types::ScalarType,
+    },
+
+    #[error("recipient mismatch: the transfer is sent from {found}, but the `zksync::msg` field `recipient` is {expected}\nat {location}")]
+    TransferRecipientMismatch {
+        expected: String,
+        found: String,
+        location: String,
+    },
+
+    #[error("token mismatch: the transfer token is {found}, but the `zksync::msg` field `token_address` is {expected}\nat {location}")]
+    TransferTokenMismatch {
+        expected: String,
+        found: String,
+        location: String,
+    },
+
+    #[error("insufficient amount: {found} is transferred in total, but the `zksync::msg` field `amount` is {expected}\nat {location}")]
+    TransferInsufficientAmount {
+        expected: String,
+        found: String,
+        location: String,
+    },
+
+    #[error("`std::test::rand_u` can only be evaluated within unit tests")]
+    TestRandBeyondUnitTest,
+
     #[error("the unit test data is missing")]
     UnitTestDataMissing,
 
@@ -126,4 +185,30 @@ pub enum Error {
 
     #[error("contract method `{found}` does not exist")]
     MethodNotFound { found: String },
+
+    #[error(
+        "contract method `{found}` has been pruned from the artifact of the circuit {original}"
+    )]
+    MethodPruned { found: String, original: String },
+
+    #[error("`std::crypto::verify_proof` requires the virtual machine built with the `recursive-verification` feature")]
+    RecursiveVerificationDisabled,
+
+    #[error("`std::crypto::verify_proof` is not supported in proving mode yet, it can only be evaluated")]
+    RecursiveVerificationProving,
+
+    #[error("the verifying key with digest 0x{digest} is not loaded")]
+    VerifyingKeyNotFound { digest: String },
+
+    #[error("invalid verifying key: {0}")]
+    InvalidVerifyingKey(std::io::Error),
+
+    #[error("the bytecode requires the standard library version {found}, but the virtual machine supports up to version {supported}: update the Zinc toolchain")]
+    StdVersionUnsupported { found: u16, supported: u16 },
+
+    #[error("the execution has been cancelled")]
+    Cancelled,
+
+    #[error("the execution has timed out after {} ms", .elapsed.as_millis())]
+    TimedOut { elapsed: Duration },
 }
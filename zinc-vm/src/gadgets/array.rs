@@ -5,20 +5,20 @@ use crate::gadgets;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
-/// This gadget only enforces 0 <= index < array.len() if condition is true
-pub fn conditional_get<E, CS>(
+/// This gadget replaces the index with zero if condition is false, so the index out of bounds
+/// error is not raised in the branches which are not taken, e.g. in the right operand of `&&`.
+/// It costs a single constraint if condition depends on the witness, and nothing otherwise.
+pub fn conditional_index<E, CS>(
     cs: CS,
-    _condition: &Scalar<E>,
-    array: &[Scalar<E>],
+    condition: &Scalar<E>,
     index: &Scalar<E>,
 ) -> Result<Scalar<E>, Error>
 where
     E: IEngine,
     CS: ConstraintSystem<E>,
 {
-    //    let zero = Scalar::new_constant_usize(0, index.get_type());
-    //    let index = gadgets::select::conditional(cs.namespace(|| "index"), condition, index, &zero)?;
-    enforcing_get(cs, array, &index)
+    let zero = Scalar::new_constant_usize(0, index.get_type());
+    gadgets::select::conditional(cs, condition, index, &zero)
 }
 
 /// This gadget enforces 0 <= index < array.len()
@@ -1,6 +1,9 @@
 use std::borrow::BorrowMut;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
+use num::BigInt;
+
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::bellman::SynthesisError;
 
@@ -17,6 +20,10 @@ use crate::IEngine;
 pub struct StorageGadget<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> {
     storage: S,
     root_hash: Scalar<E>,
+    cache: HashMap<BigInt, Vec<Scalar<E>>>,
+    operations: usize,
+    pending: HashMap<BigInt, LeafVariant<E>>,
+    writes: usize,
 
     _pd: PhantomData<H>,
 }
@@ -42,12 +49,27 @@ where
         Ok(StorageGadget {
             storage,
             root_hash,
+            cache: HashMap::new(),
+            operations: 0,
+            pending: HashMap::new(),
+            writes: 0,
             _pd: PhantomData,
         })
     }
 
+    ///
+    /// Loads the leaf at `index`.
+    ///
+    /// The allocated leaf fields are cached until the slot is written, so the repeated loads
+    /// of the same slot within a method execution do not allocate and prove the leaf again.
+    /// Only the constant indexes are cached, since a cache hit of a variable index depends on
+    /// the witness and would change the number of constraints.
+    ///
+    /// The constant slots with pending writes are read from the write buffer, whereas the loads
+    /// of the variable indexes apply the buffered writes first, so the leaf is always allocated.
+    ///
     pub fn load<CS>(
-        &self,
+        &mut self,
         mut cs: CS,
         index: Scalar<E>,
         _size: usize,
@@ -55,15 +77,31 @@ where
     where
         CS: ConstraintSystem<E>,
     {
+        let index_value = index
+            .get_value()
+            .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let is_constant = index.is_constant();
+        if is_constant {
+            if let Some(values) = self.pending.get(&index_value) {
+                return Ok(match values {
+                    LeafVariant::Array(array) => array.to_owned(),
+                    LeafVariant::Map { .. } => vec![],
+                });
+            }
+            if let Some(leaf_fields) = self.cache.get(&index_value) {
+                return Ok(leaf_fields.to_owned());
+            }
+        } else {
+            self.flush()?;
+        }
+
         let depth = self.storage.depth();
         let mut index_bits = index.get_bits_le(cs.namespace(|| "index into bits"))?;
         index_bits.truncate(depth);
 
-        let index = index
-            .get_value()
-            .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
-            .expect(zinc_const::panic::TEST_DATA_VALID);
-        let merkle_tree_leaf = self.storage.load(index)?;
+        let merkle_tree_leaf = self.storage.load(index_value.clone())?;
+        self.operations += 1;
 
         let leaf_value = match merkle_tree_leaf.leaf_values {
             LeafVariant::Array(array) => array,
@@ -72,9 +110,20 @@ where
         let leaf_fields =
             AllocatedLeaf::alloc_leaf_fields(cs.namespace(|| "alloc leaf fields"), leaf_value)?;
 
+        if is_constant {
+            self.cache.insert(index_value, leaf_fields.clone());
+        }
         Ok(leaf_fields)
     }
 
+    ///
+    /// Stores the `values` to the leaf at `index`.
+    ///
+    /// The write to a constant index is buffered until the next `flush`, and the repeated writes
+    /// to the same slot replace the buffered values, so the index of each written slot is proven
+    /// only once. The writes to the variable indexes are applied at once, since whether they hit
+    /// a buffered slot depends on the witness.
+    ///
     pub fn store<CS>(
         &mut self,
         mut cs: CS,
@@ -84,73 +133,144 @@ where
     where
         CS: ConstraintSystem<E>,
     {
-        let depth = self.storage.depth();
-        let mut index_bits = index.get_bits_le(cs.namespace(|| "index into bits"))?;
-        index_bits.truncate(depth);
+        let index_value = index
+            .get_value()
+            .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
 
-        let _merkle_tree_leaf = self.storage.store(
-            index
-                .get_value()
-                .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            values,
-        )?;
+        let is_constant = index.is_constant();
+        if !is_constant || !self.pending.contains_key(&index_value) {
+            let depth = self.storage.depth();
+            let mut index_bits = index.get_bits_le(cs.namespace(|| "index into bits"))?;
+            index_bits.truncate(depth);
+        }
+
+        if is_constant {
+            self.cache.remove(&index_value);
+            self.pending.insert(index_value, values);
+            self.writes += 1;
+        } else {
+            // a variable index may point to any of the cached slots
+            self.cache.clear();
+            self.flush()?;
+            self.storage.store(index_value, values)?;
+            self.operations += 1;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Applies the buffered writes to the storage at once.
+    ///
+    /// Called at the method exit and before the storage is passed to the library functions.
+    ///
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let mut pending: Vec<(BigInt, LeafVariant<E>)> = self.pending.drain().collect();
+        pending.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        log::trace!(
+            "Storage writes: {} buffered, {} applied",
+            self.writes,
+            pending.len()
+        );
+        self.writes = 0;
+
+        for (index, values) in pending.into_iter() {
+            self.storage.store(index, values)?;
+            self.operations += 1;
+        }
 
         Ok(())
     }
 
-    pub fn into_build(self) -> zinc_types::Value {
+    ///
+    /// Drops the cached leaves, so the next loads read the storage again.
+    ///
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    ///
+    /// The number of the Merkle tree leaf reads and writes, excluding the cached loads.
+    ///
+    pub fn operations(&self) -> usize {
+        self.operations
+    }
+
+    ///
+    /// Reassembles the storage field values from the leaves, which go in the field order.
+    ///
+    pub fn into_build(mut self) -> Result<zinc_types::Value, Error> {
+        self.flush()?;
+
         let field_types = self.storage.types().to_owned();
-        let fields = self
-            .storage
-            .into_values()
-            .into_iter()
-            .zip(field_types)
-            .map(|(leaf, field)| {
-                let value = match leaf {
-                    LeafOutput::Array(array) => {
-                        zinc_types::Value::from_flat_values(field.r#type, array.as_slice())
-                    }
-                    LeafOutput::Map(entries) => {
-                        let (key_type, value_type) = match field.r#type {
-                            zinc_types::Type::Map {
-                                key_type,
-                                value_type,
-                            } => (*key_type, *value_type),
-                            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
-                        };
-
-                        let mut values = Vec::with_capacity(entries.len());
-                        for (key, value) in entries.into_iter() {
-                            let key = zinc_types::Value::from_flat_values(
-                                key_type.clone(),
-                                key.as_slice(),
-                            );
-                            let value = zinc_types::Value::from_flat_values(
-                                value_type.clone(),
-                                value.as_slice(),
-                            );
-                            values.push((key, value));
-                        }
-                        zinc_types::Value::Map(values)
+        let mut leaves = self.storage.into_values().into_iter();
+
+        let mut fields = Vec::with_capacity(field_types.len());
+        for field in field_types.into_iter() {
+            let value = match field.r#type {
+                zinc_types::Type::Map {
+                    ref key_type,
+                    ref value_type,
+                } => {
+                    let entries = match leaves.next() {
+                        Some(LeafOutput::Map(entries)) => entries,
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    let mut values = Vec::with_capacity(entries.len());
+                    for (key, value) in entries.into_iter() {
+                        let key = zinc_types::Value::from_flat_values(
+                            *key_type.to_owned(),
+                            key.as_slice(),
+                        );
+                        let value = zinc_types::Value::from_flat_values(
+                            *value_type.to_owned(),
+                            value.as_slice(),
+                        );
+                        values.push((key, value));
                     }
-                };
+                    zinc_types::Value::Map(values)
+                }
+                _ => {
+                    let arrays: Vec<Vec<BigInt>> = leaves
+                        .by_ref()
+                        .take(field.leaves.len())
+                        .map(|leaf| match leaf {
+                            LeafOutput::Array(array) => array,
+                            LeafOutput::Map(_) => {
+                                panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                            }
+                        })
+                        .collect();
+                    let values = field.unpack_leaves(arrays.as_slice());
+                    zinc_types::Value::from_flat_values(field.r#type.to_owned(), values.as_slice())
+                }
+            };
 
-                zinc_types::ContractFieldValue::new(
-                    field.name,
-                    value,
-                    field.is_public,
-                    field.is_implicit,
-                )
-            })
-            .collect::<Vec<zinc_types::ContractFieldValue>>();
+            fields.push(zinc_types::ContractFieldValue::new(
+                field.name,
+                value,
+                field.is_public,
+                field.is_implicit,
+            ));
+        }
 
-        zinc_types::Value::Contract(fields)
+        Ok(zinc_types::Value::Contract(fields))
     }
 
     pub fn root_hash(&self) -> Result<Scalar<E>, Error> {
         Ok(self.root_hash.clone())
     }
+
+    pub fn current_root_hash(&self) -> E::Fr {
+        self.storage.root_hash()
+    }
 }
 
 impl<E, S, H> AsMut<S> for StorageGadget<E, S, H>
@@ -160,6 +280,376 @@ where
     H: IMerkleTreeHasher<E>,
 {
     fn as_mut(&mut self) -> &mut S {
+        debug_assert!(
+            self.pending.is_empty(),
+            "the storage writes must be flushed"
+        );
+        self.cache.clear();
         self.storage.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::ToBigInt;
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::core::contract::storage::database::Storage as DatabaseStorage;
+    use crate::core::contract::storage::leaf::LeafVariant;
+    use crate::gadgets;
+    use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+    use crate::gadgets::contract::merkle_tree::IMerkleTree;
+    use crate::gadgets::scalar::Scalar;
+
+    use super::StorageGadget;
+
+    const FIELDS_COUNT: usize = 3;
+
+    enum Operation {
+        Load(usize),
+        LoadVariable(usize),
+        Store(usize, usize),
+        StoreVariable(usize, usize),
+        StoreIf(usize, usize, bool),
+    }
+
+    struct Output {
+        values: Vec<BigInt>,
+        storage: serde_json::Value,
+        root_hash: BigInt,
+        num_constraints: usize,
+    }
+
+    fn constant(value: usize) -> Scalar<Bn256> {
+        Scalar::new_constant_usize(value, zinc_types::ScalarType::Field)
+    }
+
+    fn variable<CS: ConstraintSystem<Bn256>>(mut cs: CS, value: usize) -> Scalar<Bn256> {
+        let fr = gadgets::scalar::fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(value))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let num = AllocatedNum::alloc(cs.namespace(|| "variable"), || Ok(fr))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        Scalar::from(num)
+    }
+
+    ///
+    /// Runs the `operations` on a fresh storage.
+    ///
+    /// The `Load` and `Store` operations use the constant indexes, and their `Variable`
+    /// counterparts allocate the indexes as the witness variables.
+    ///
+    /// If `is_batched` is false, the writes are applied right after each store, which is the
+    /// behavior of the storage without the write buffer.
+    ///
+    fn run(operations: &[Operation], is_cached: bool, is_batched: bool) -> Output {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let field_types = (0..FIELDS_COUNT)
+            .map(|index| {
+                zinc_types::ContractFieldType::new(
+                    format!("field_{}", index),
+                    zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                    true,
+                    false,
+                )
+            })
+            .collect();
+        let values = (0..FIELDS_COUNT)
+            .map(|index| Scalar::new_constant_usize(index * 10, zinc_types::ScalarType::Field))
+            .collect();
+        let storage = DatabaseStorage::<Bn256>::from_evaluation_stack(field_types, values)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let mut gadget =
+            StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut outputs = Vec::with_capacity(operations.len());
+        for (step, operation) in operations.iter().enumerate() {
+            if !is_cached {
+                gadget.clear_cache();
+            }
+
+            let mut cs = cs.namespace(|| format!("step {}", step));
+            match operation {
+                Operation::Load(index) | Operation::LoadVariable(index) => {
+                    let index = match operation {
+                        Operation::LoadVariable(_) => variable(cs.namespace(|| "index"), *index),
+                        _ => constant(*index),
+                    };
+                    let values = gadget
+                        .load(cs.namespace(|| "load"), index, 1)
+                        .expect(zinc_const::panic::TEST_DATA_VALID);
+                    outputs.extend(
+                        values.into_iter().map(|value| {
+                            value.to_bigint().expect(zinc_const::panic::TEST_DATA_VALID)
+                        }),
+                    );
+                }
+                Operation::Store(index, value)
+                | Operation::StoreVariable(index, value)
+                | Operation::StoreIf(index, value, true) => {
+                    let index = match operation {
+                        Operation::StoreVariable(..) => variable(cs.namespace(|| "index"), *index),
+                        _ => constant(*index),
+                    };
+                    let value = variable(cs.namespace(|| "value"), *value);
+                    gadget
+                        .store(
+                            cs.namespace(|| "store"),
+                            index,
+                            LeafVariant::Array(vec![value]),
+                        )
+                        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+                    if !is_batched {
+                        gadget.flush().expect(zinc_const::panic::TEST_DATA_VALID);
+                    }
+                }
+                // the `StorageStore` instruction skips the write in a false branch
+                Operation::StoreIf(_, _, false) => {}
+            }
+        }
+
+        gadget.flush().expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+
+        let root_hash =
+            gadgets::scalar::fr_bigint::fr_to_bigint::<Bn256>(&gadget.current_root_hash(), false);
+
+        Output {
+            values: outputs,
+            storage: gadget
+                .into_build()
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .into_json(),
+            root_hash,
+            num_constraints: cs.num_constraints(),
+        }
+    }
+
+    #[test]
+    fn ok_interleaved() {
+        let operations = vec![
+            Operation::Load(0),
+            Operation::Load(1),
+            Operation::Load(0),
+            Operation::Store(0, 42),
+            Operation::Load(0),
+            Operation::Load(1),
+            Operation::Store(1, 25),
+            Operation::Store(1, 64),
+            Operation::Load(1),
+            Operation::Load(0),
+            Operation::Load(2),
+        ];
+
+        let cached = run(operations.as_slice(), true, true);
+        let uncached = run(operations.as_slice(), false, true);
+
+        assert_eq!(
+            cached.values,
+            vec![0, 10, 0, 42, 10, 64, 42, 20]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(cached.values, uncached.values);
+        assert_eq!(cached.storage, uncached.storage);
+    }
+
+    #[test]
+    fn ok_constraints() {
+        let operations = vec![
+            Operation::Load(0),
+            Operation::Load(0),
+            Operation::Load(0),
+            Operation::Load(1),
+            Operation::Load(1),
+        ];
+
+        let cached = run(operations.as_slice(), true, true).num_constraints;
+        let uncached = run(operations.as_slice(), false, true).num_constraints;
+
+        assert!(
+            cached < uncached,
+            "cached loads use {} constraints, uncached loads use {}",
+            cached,
+            uncached
+        );
+    }
+
+    #[test]
+    fn ok_batched_interleaved() {
+        let operations = vec![
+            Operation::Store(0, 1),
+            Operation::Load(0),
+            Operation::Store(2, 7),
+            Operation::Store(0, 2),
+            Operation::Load(1),
+            Operation::Load(0),
+            Operation::Store(1, 5),
+            Operation::Load(2),
+            Operation::Store(2, 9),
+            Operation::Load(2),
+            Operation::Load(1),
+        ];
+
+        let batched = run(operations.as_slice(), true, true);
+        let unbatched = run(operations.as_slice(), true, false);
+
+        assert_eq!(
+            batched.values,
+            vec![1, 10, 2, 7, 9, 5]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(batched.values, unbatched.values);
+        assert_eq!(batched.storage, unbatched.storage);
+        assert_eq!(batched.root_hash, unbatched.root_hash);
+    }
+
+    #[test]
+    fn ok_batched_conditional() {
+        let operations = vec![
+            Operation::Load(1),
+            Operation::StoreIf(1, 11, true),
+            Operation::StoreIf(1, 12, false),
+            Operation::Load(1),
+            Operation::StoreIf(0, 3, false),
+            Operation::Load(0),
+            Operation::StoreIf(2, 4, true),
+            Operation::StoreIf(2, 6, true),
+            Operation::StoreIf(2, 8, false),
+            Operation::Load(2),
+        ];
+
+        let batched = run(operations.as_slice(), true, true);
+        let unbatched = run(operations.as_slice(), false, false);
+
+        assert_eq!(
+            batched.values,
+            vec![10, 11, 0, 6]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(batched.values, unbatched.values);
+        assert_eq!(batched.storage, unbatched.storage);
+        assert_eq!(batched.root_hash, unbatched.root_hash);
+    }
+
+    #[test]
+    fn ok_batched_constraints() {
+        let operations = vec![
+            Operation::Store(0, 1),
+            Operation::Load(0),
+            Operation::Store(0, 2),
+            Operation::Load(0),
+            Operation::Store(0, 3),
+            Operation::Store(1, 4),
+            Operation::Store(1, 5),
+        ];
+
+        let batched = run(operations.as_slice(), true, true).num_constraints;
+        let unbatched = run(operations.as_slice(), true, false).num_constraints;
+
+        assert!(
+            batched < unbatched,
+            "batched writes use {} constraints, unbatched writes use {}",
+            batched,
+            unbatched
+        );
+    }
+
+    #[test]
+    fn ok_cached_variable_constraints() {
+        let constraints = |index| {
+            let operations = vec![
+                Operation::Load(1),
+                Operation::LoadVariable(0),
+                Operation::LoadVariable(index),
+                Operation::Load(0),
+            ];
+
+            run(operations.as_slice(), true, true).num_constraints
+        };
+
+        assert_eq!(constraints(0), constraints(1));
+    }
+
+    #[test]
+    fn ok_cached_variable_store() {
+        let operations = vec![
+            Operation::Load(1),
+            Operation::StoreVariable(1, 5),
+            Operation::Load(1),
+        ];
+
+        let cached = run(operations.as_slice(), true, true);
+        let uncached = run(operations.as_slice(), false, true);
+
+        assert_eq!(
+            cached.values,
+            vec![10, 5]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(cached.values, uncached.values);
+    }
+
+    #[test]
+    fn ok_batched_variable_constraints() {
+        let constraints = |index| {
+            let operations = vec![
+                Operation::Store(1, 3),
+                Operation::StoreVariable(index, 5),
+                Operation::StoreVariable(0, 7),
+                Operation::LoadVariable(index),
+                Operation::Store(1, 9),
+                Operation::Load(1),
+            ];
+
+            run(operations.as_slice(), true, true).num_constraints
+        };
+
+        assert_eq!(constraints(0), constraints(1));
+    }
+
+    #[test]
+    fn ok_batched_variable_interleaved() {
+        let operations = vec![
+            Operation::Store(1, 3),
+            Operation::LoadVariable(1),
+            Operation::StoreVariable(1, 5),
+            Operation::Load(1),
+            Operation::Store(2, 7),
+            Operation::StoreVariable(2, 8),
+            Operation::Load(2),
+            Operation::Store(2, 9),
+            Operation::LoadVariable(2),
+        ];
+
+        let batched = run(operations.as_slice(), true, true);
+        let unbatched = run(operations.as_slice(), true, false);
+
+        assert_eq!(
+            batched.values,
+            vec![3, 5, 8, 9]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(batched.values, unbatched.values);
+        assert_eq!(batched.storage, unbatched.storage);
+        assert_eq!(batched.root_hash, unbatched.root_hash);
+    }
+}
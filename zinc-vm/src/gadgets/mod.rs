@@ -14,5 +14,7 @@ pub mod logical;
 pub mod output;
 pub mod require;
 pub mod scalar;
+pub mod search;
 pub mod select;
+pub mod verify_proof;
 pub mod witness;
@@ -21,6 +21,7 @@ use franklin_crypto::circuit::num::AllocatedNum;
 use franklin_crypto::circuit::Assignment;
 
 use crate::error::Error;
+use crate::gadgets;
 use crate::IEngine;
 
 use self::expectation::ITypeExpectation;
@@ -251,6 +252,127 @@ impl<E: IEngine> Scalar<E> {
         }
     }
 
+    ///
+    /// Checks whether the operator result `scalar` fits `scalar_type` according to `range_check`.
+    ///
+    /// The elided check creates no constraints. The asserted one reports the overflow as a bug of
+    /// the compiler bound analysis, which has proven the check redundant.
+    ///
+    pub fn conditional_range_check<CS>(
+        cs: CS,
+        condition: &Self,
+        scalar: &Self,
+        scalar_type: zinc_types::ScalarType,
+        range_check: zinc_types::RangeCheck,
+    ) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        match range_check {
+            zinc_types::RangeCheck::Enforced => {
+                Self::conditional_type_check(cs, condition, scalar, scalar_type)
+            }
+            zinc_types::RangeCheck::Elided => Ok(scalar.to_type_unchecked(scalar_type)),
+            zinc_types::RangeCheck::Asserted => {
+                Self::conditional_type_check(cs, condition, scalar, scalar_type).map_err(|error| {
+                    match error {
+                        Error::ValueOverflow { value, scalar_type } => {
+                            Error::RangeCheckElisionUnsound { value, scalar_type }
+                        }
+                        error => error,
+                    }
+                })
+            }
+        }
+    }
+
+    ///
+    /// Reinterprets the two's complement representation of the integer `scalar` as `int_type`.
+    ///
+    /// The sign is taken from the top bit of the range decomposition, so the reinterpretation
+    /// also checks the `scalar` to fit its own type.
+    ///
+    pub fn conditional_reinterpret<CS>(
+        mut cs: CS,
+        condition: &Self,
+        scalar: &Self,
+        int_type: zinc_types::IntegerType,
+    ) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let from = match scalar.get_type() {
+            zinc_types::ScalarType::Integer(from) => from,
+            scalar_type => {
+                return Err(Error::TypeError {
+                    expected: "integer".to_owned(),
+                    found: scalar_type.to_string(),
+                })
+            }
+        };
+
+        if let ScalarVariant::Constant(constant) = scalar.get_variant() {
+            let value = fr_bigint::fr_to_bigint::<E>(&constant.value, from.is_signed);
+            return Self::new_constant_bigint(int_type.reinterpret(value), int_type.into());
+        }
+
+        let scalar_expr = scalar.to_expression::<CS>();
+        let offset_expr = if !from.is_signed {
+            Expression::u64::<CS>(0)
+        } else {
+            let offset = BigInt::from(1) << (from.bitlength - 1);
+            let offset_fr =
+                fr_bigint::bigint_to_fr::<E>(&offset).expect("invalid integer type length");
+            Expression::constant::<CS>(offset_fr)
+        };
+        let zero = Expression::u64::<CS>(0);
+
+        // If reinterpreting inside the false branch, use zero instead to avoid throwing an error.
+        let condition_bool = condition.to_boolean(cs.namespace(|| "to_boolean"))?;
+        let value_to_split = Expression::conditionally_select(
+            cs.namespace(|| "select value to split"),
+            scalar_expr + offset_expr,
+            zero,
+            &condition_bool,
+        )?;
+
+        let mut bits =
+            value_to_split.into_bits_le_fixed(cs.namespace(|| "into_bits"), from.bitlength)?;
+        let top_bit = bits.pop().expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let top_bit = Self::from_boolean(cs.namespace(|| "top bit"), top_bit)?;
+
+        // The top bit of the offset signed value is set if it is non-negative, and the top bit of
+        // the unsigned value is set if it is negative after the reinterpretation.
+        let (if_set, if_unset) = if from.is_signed {
+            let wrapped = Self::new_constant_bigint(
+                BigInt::from(1) << int_type.bitlength,
+                zinc_types::ScalarType::Field,
+            )?;
+            let wrapped = gadgets::arithmetic::add::add(
+                cs.namespace(|| "wrap negative"),
+                &scalar.to_field(),
+                &wrapped,
+            )?;
+            (scalar.to_field(), wrapped)
+        } else {
+            let wrapped = Self::new_constant_bigint(
+                -(BigInt::from(1) << from.bitlength),
+                zinc_types::ScalarType::Field,
+            )?;
+            let wrapped = gadgets::arithmetic::add::add(
+                cs.namespace(|| "wrap positive"),
+                &scalar.to_field(),
+                &wrapped,
+            )?;
+            (wrapped, scalar.to_field())
+        };
+
+        let result =
+            gadgets::select::conditional(cs.namespace(|| "select"), &top_bit, &if_set, &if_unset)?;
+
+        Ok(result.to_type_unchecked(int_type.into()))
+    }
+
     fn conditional_int_type_check<CS>(
         mut cs: CS,
         condition: &Self,
@@ -347,7 +469,12 @@ impl<E: IEngine> fmt::Display for Scalar<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let value_str = self
             .get_value()
-            .map(|f| fr_bigint::fr_to_bigint::<E>(&f, self.is_signed()).to_string())
+            .map(|f| {
+                zinc_types::scalar_format::decimal(&fr_bigint::fr_to_bigint::<E>(
+                    &f,
+                    self.is_signed(),
+                ))
+            })
             .unwrap_or_else(|| "none".into());
 
         let det = if self.is_constant() { "det" } else { "witness" };
@@ -0,0 +1,302 @@
+//!
+//! The sorted array search gadgets.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// Searches the sorted `array` for the `needle` with an unrolled binary search.
+///
+/// Returns whether the needle is found, and the index of its first occurrence or the position
+/// where it can be inserted to keep the array sorted.
+///
+/// The array is padded with the maximal value of its type up to the next power of two, so
+/// only `log2(N + 1)` comparisons are made. The array must be sorted, which is only guaranteed
+/// for constant arrays, so the gadget must not be used for witness data.
+///
+pub fn binary_search<E, CS>(
+    mut cs: CS,
+    array: &[Scalar<E>],
+    needle: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>), Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    if array.is_empty() {
+        return Ok(not_found());
+    }
+
+    let scalar_type = needle.get_type();
+    let max = match scalar_type {
+        zinc_types::ScalarType::Integer(ref integer_type) => {
+            Scalar::new_constant_bigint(integer_type.max(), scalar_type.clone())?
+        }
+        ref r#type => {
+            return Err(Error::TypeError {
+                expected: "integer type".into(),
+                found: r#type.to_string(),
+            })
+        }
+    };
+
+    let bitlength = zinc_math::log2ceil(array.len() + 1);
+    let mut padded = array.to_vec();
+    padded.resize(1 << bitlength, max);
+
+    let mut index_bits_be = Vec::with_capacity(bitlength);
+    for bit in (0..bitlength).rev() {
+        let step = 1 << bit;
+        let candidates: Vec<Scalar<E>> = padded
+            .iter()
+            .skip(step - 1)
+            .step_by(step * 2)
+            .cloned()
+            .collect();
+
+        let candidate = gadgets::select::recursive(
+            cs.namespace(|| format!("candidate {}", bit)),
+            index_bits_be.as_slice(),
+            candidates.as_slice(),
+        )?;
+        let is_lesser = gadgets::comparison::lesser_than(
+            cs.namespace(|| format!("lesser {}", bit)),
+            &candidate,
+            needle,
+        )?;
+
+        index_bits_be.push(is_lesser);
+    }
+
+    let index = pack_index(cs.namespace(|| "index"), index_bits_be.as_slice())?;
+
+    let value = gadgets::select::recursive(
+        cs.namespace(|| "value"),
+        index_bits_be.as_slice(),
+        padded.as_slice(),
+    )?;
+    let is_equal = gadgets::comparison::equals(cs.namespace(|| "equals"), &value, needle)?;
+    let length = Scalar::new_constant_usize(array.len(), index.get_type());
+    let is_in_bounds =
+        gadgets::comparison::lesser_than(cs.namespace(|| "in bounds"), &index, &length)?;
+    let is_found = gadgets::logical::and::and(cs.namespace(|| "found"), &is_equal, &is_in_bounds)?;
+
+    Ok((is_found, index))
+}
+
+///
+/// Searches the `array` for the `needle` with a linear scan.
+///
+/// Returns the same result as `binary_search`, but does not depend on the array being
+/// constant, so it is used for runtime arrays.
+///
+pub fn linear_search<E, CS>(
+    mut cs: CS,
+    array: &[Scalar<E>],
+    needle: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>), Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let index_type = zinc_types::ScalarType::Integer(zinc_types::IntegerType::new(
+        false,
+        zinc_const::bitlength::INDEX,
+    ));
+
+    let (mut is_found, mut index) = not_found();
+    for (position, element) in array.iter().enumerate() {
+        let is_lesser = gadgets::comparison::lesser_than(
+            cs.namespace(|| format!("lesser {}", position)),
+            element,
+            needle,
+        )?;
+        index = gadgets::arithmetic::add::add(
+            cs.namespace(|| format!("index {}", position)),
+            &index,
+            &is_lesser,
+        )?
+        .to_type_unchecked(index_type.clone());
+
+        let is_equal = gadgets::comparison::equals(
+            cs.namespace(|| format!("equals {}", position)),
+            element,
+            needle,
+        )?;
+        is_found = gadgets::logical::or::or(
+            cs.namespace(|| format!("found {}", position)),
+            &is_found,
+            &is_equal,
+        )?;
+    }
+
+    Ok((is_found, index))
+}
+
+///
+/// Checks whether the `array` is sorted in the non-descending order.
+///
+pub fn is_sorted<E, CS>(mut cs: CS, array: &[Scalar<E>]) -> Result<Scalar<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut result = Scalar::new_constant_bool(true);
+    for (position, pair) in array.windows(2).enumerate() {
+        let is_ordered = gadgets::comparison::lesser_or_equals(
+            cs.namespace(|| format!("ordered {}", position)),
+            &pair[0],
+            &pair[1],
+        )?;
+        result = gadgets::logical::and::and(
+            cs.namespace(|| format!("sorted {}", position)),
+            &result,
+            &is_ordered,
+        )?;
+    }
+
+    Ok(result)
+}
+
+///
+/// The result of the search in an empty array.
+///
+fn not_found<E: IEngine>() -> (Scalar<E>, Scalar<E>) {
+    (
+        Scalar::new_constant_bool(false),
+        Scalar::new_constant_usize(
+            0,
+            zinc_types::IntegerType::new(false, zinc_const::bitlength::INDEX).into(),
+        ),
+    )
+}
+
+///
+/// Packs the big-endian boolean `bits` into an index integer.
+///
+fn pack_index<E, CS>(mut cs: CS, bits_be: &[Scalar<E>]) -> Result<Scalar<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let index_type: zinc_types::ScalarType =
+        zinc_types::IntegerType::new(false, zinc_const::bitlength::INDEX).into();
+
+    if bits_be.iter().all(Scalar::is_constant) {
+        let mut index = 0;
+        for bit in bits_be.iter() {
+            index = (index << 1) + bit.get_constant_usize()?;
+        }
+        return Ok(Scalar::new_constant_usize(index, index_type));
+    }
+
+    let mut bits_le = Vec::with_capacity(bits_be.len());
+    for (position, bit) in bits_be.iter().rev().enumerate() {
+        bits_le.push(bit.to_boolean(cs.namespace(|| format!("bit {}", position)))?);
+    }
+
+    let index = AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack"), bits_le.as_slice())?;
+
+    Ok(Scalar::from(index).to_type_unchecked(index_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::gadgets;
+    use crate::gadgets::scalar::Scalar;
+
+    const ARRAY_SIZE: usize = 64;
+
+    fn table() -> Vec<Scalar<Bn256>> {
+        (0..ARRAY_SIZE)
+            .map(|index| Scalar::new_constant_usize(index * 2, zinc_types::IntegerType::U8.into()))
+            .collect()
+    }
+
+    fn needle<CS: ConstraintSystem<Bn256>>(mut cs: CS, value: usize) -> Scalar<Bn256> {
+        let fr = gadgets::scalar::fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(value))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let num = AllocatedNum::alloc(cs.namespace(|| "needle"), || Ok(fr))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        Scalar::from(num).to_type_unchecked(zinc_types::IntegerType::U8.into())
+    }
+
+    fn search(binary: bool, value: usize) -> (bool, usize, usize) {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let needle = needle(cs.namespace(|| "input"), value);
+        let table = table();
+        let (is_found, index) = if binary {
+            gadgets::search::binary_search(cs.namespace(|| "search"), &table, &needle)
+        } else {
+            gadgets::search::linear_search(cs.namespace(|| "search"), &table, &needle)
+        }
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(cs.is_satisfied(), "unsatisfied constraint system");
+
+        let is_found = gadgets::scalar::fr_bigint::fr_to_bigint::<Bn256>(
+            &is_found
+                .get_value()
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            false,
+        ) == BigInt::from(1);
+        let index = gadgets::scalar::fr_bigint::fr_to_bigint::<Bn256>(
+            &index.get_value().expect(zinc_const::panic::TEST_DATA_VALID),
+            false,
+        );
+        let index = index
+            .to_string()
+            .parse::<usize>()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        (is_found, index, cs.num_constraints())
+    }
+
+    #[test]
+    fn test_binary_search_boundaries() {
+        for binary in [true, false].iter().copied() {
+            assert!(search(binary, 0).0);
+            assert_eq!(search(binary, 0).1, 0);
+
+            assert!(search(binary, 126).0);
+            assert_eq!(search(binary, 126).1, ARRAY_SIZE - 1);
+
+            assert!(!search(binary, 127).0);
+            assert_eq!(search(binary, 127).1, ARRAY_SIZE);
+
+            assert!(!search(binary, 255).0);
+            assert_eq!(search(binary, 255).1, ARRAY_SIZE);
+
+            assert!(!search(binary, 41).0);
+            assert_eq!(search(binary, 41).1, 21);
+        }
+    }
+
+    #[test]
+    fn test_binary_search_constraints() {
+        let (_, _, binary) = search(true, 42);
+        let (_, _, linear) = search(false, 42);
+
+        assert!(
+            binary < linear,
+            "binary search uses {} constraints, linear scan uses {}",
+            binary,
+            linear
+        );
+    }
+}
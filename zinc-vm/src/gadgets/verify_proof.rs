@@ -0,0 +1,108 @@
+//!
+//! The `std::crypto::verify_proof` native verification.
+//!
+
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Proof;
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::CurveAffine;
+use franklin_crypto::bellman::pairing::EncodedPoint;
+
+use crate::core::execution_state::verifying_keys::VerifyingKeys;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// The size of a proof word in bytes.
+const WORD_SIZE: usize = zinc_const::bitlength::FIELD_PADDED / zinc_const::bitlength::BYTE;
+
+///
+/// Verifies the Groth16 `proof` of the `public_inputs` natively, outside of the circuit.
+///
+/// The verifying key is looked up in `keys` by the `vk_digest` bits, which are the `sha256`
+/// digest of the serialized key. The proof is the uncompressed encoding of the `A`, `B` and `C`
+/// points split into 32-byte big-endian words, so only the same curve is supported, and only
+/// the words which fit into the scalar field can be passed. A proof which does not decode into
+/// valid curve points is reported as invalid.
+///
+/// No constraints are produced, so the result is only meaningful when the application is
+/// evaluated. An in-circuit verifier requires non-native field arithmetic, which is not
+/// implemented yet.
+///
+pub fn verify_native<E: IEngine>(
+    keys: &VerifyingKeys<E>,
+    vk_digest: &[Scalar<E>],
+    public_inputs: &[Scalar<E>],
+    proof: &[Scalar<E>],
+) -> Result<bool, Error> {
+    let mut digest = vec![0u8; vk_digest.len() / zinc_const::bitlength::BYTE];
+    for (index, bit) in vk_digest.iter().enumerate() {
+        if !bit.grab_value()?.is_zero() {
+            digest[index / zinc_const::bitlength::BYTE] |=
+                0x80 >> (index % zinc_const::bitlength::BYTE);
+        }
+    }
+    let verifying_key = keys
+        .get(digest.as_slice())
+        .ok_or_else(|| Error::VerifyingKeyNotFound {
+            digest: hex::encode(digest.as_slice()),
+        })?;
+
+    let mut bytes = Vec::with_capacity(proof.len() * WORD_SIZE);
+    for word in proof.iter() {
+        let (_sign, word) =
+            gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&word.grab_value()?, false).to_bytes_be();
+        bytes.resize(bytes.len() + WORD_SIZE - word.len(), 0);
+        bytes.extend(word);
+    }
+    let proof = match decode_proof::<E>(bytes.as_slice()) {
+        Some(proof) => proof,
+        None => return Ok(false),
+    };
+
+    let mut public_input_values = Vec::with_capacity(public_inputs.len());
+    for input in public_inputs.iter() {
+        public_input_values.push(input.grab_value()?);
+    }
+
+    let prepared_verifying_key = groth16::prepare_verifying_key(verifying_key);
+    let is_valid = groth16::verify_proof(
+        &prepared_verifying_key,
+        &proof,
+        public_input_values.as_slice(),
+    )?;
+
+    Ok(is_valid)
+}
+
+///
+/// Decodes the uncompressed `A`, `B` and `C` proof points.
+///
+fn decode_proof<E: IEngine>(bytes: &[u8]) -> Option<Proof<E>> {
+    let (a, bytes) = decode_point::<E::G1Affine>(bytes)?;
+    let (b, bytes) = decode_point::<E::G2Affine>(bytes)?;
+    let (c, bytes) = decode_point::<E::G1Affine>(bytes)?;
+
+    if !bytes.is_empty() {
+        return None;
+    }
+
+    Some(Proof { a, b, c })
+}
+
+///
+/// Decodes an uncompressed curve point from the beginning of `bytes`, returning the rest.
+///
+fn decode_point<G: CurveAffine>(bytes: &[u8]) -> Option<(G, &[u8])> {
+    let mut encoded = G::Uncompressed::empty();
+    let size = encoded.as_ref().len();
+    if bytes.len() < size {
+        return None;
+    }
+
+    encoded.as_mut().copy_from_slice(&bytes[..size]);
+    let point = encoded.into_affine().ok()?;
+
+    Some((point, &bytes[size..]))
+}
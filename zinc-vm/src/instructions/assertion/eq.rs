@@ -0,0 +1,109 @@
+//!
+//! The `AssertEq` instruction.
+//!
+
+use num::BigInt;
+use num::Signed;
+
+use franklin_crypto::bellman::SynthesisError;
+
+use zinc_types::AssertEq;
+
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for AssertEq {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let size = self.r#type.size();
+
+        let mut flat = Vec::with_capacity(size * 2);
+        for _ in 0..size * 2 {
+            let value = vm
+                .pop()?
+                .try_into_value()?
+                .to_bigint()
+                .ok_or(Error::SynthesisError(SynthesisError::AssignmentMissing))?;
+            flat.push(value);
+        }
+        flat.reverse();
+        let expected: Vec<BigInt> = flat.split_off(size);
+
+        let actual = zinc_types::Value::from_flat_values(self.r#type.clone(), flat.as_slice());
+        let expected = zinc_types::Value::from_flat_values(self.r#type, expected.as_slice());
+
+        if let Some(condition) = vm.condition_top()?.to_bigint() {
+            if condition.is_positive() {
+                if let Some(diff) = zinc_types::Value::diff(&actual, &expected) {
+                    return Err(Error::AssertionFailed {
+                        message: self
+                            .message
+                            .unwrap_or_else(|| "`actual == expected`".to_owned()),
+                        diff,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::error::Error;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    #[test]
+    fn test_assert_eq_ok() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::AssertEq::new(
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                None,
+            ))
+            .test::<i32>(&[])
+    }
+
+    #[test]
+    fn test_assert_eq_fail() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::Push::new_field(BigInt::from(64)))
+            .push(zinc_types::AssertEq::new(
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                Some("values differ".to_owned()),
+            ))
+            .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::AssertionFailed { message, diff })) => {
+                assert_eq!(message, "values differ");
+                assert_eq!(diff, "-   actual = \"0x2a\"\n+ expected = \"0x40\"");
+            }
+            _ => panic!("Expected assertion error"),
+        }
+    }
+
+    #[test]
+    fn test_assert_eq_in_condition() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(0),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::If)
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::Push::new_field(BigInt::from(64)))
+            .push(zinc_types::AssertEq::new(
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                None,
+            ))
+            .push(zinc_types::EndIf)
+            .test::<i32>(&[])
+    }
+}
@@ -0,0 +1,6 @@
+//!
+//! The unit test assertion instructions.
+//!
+
+pub mod eq;
+pub mod storage_unchanged;
@@ -0,0 +1,33 @@
+//!
+//! The `AssertStorageUnchanged` instruction.
+//!
+
+use num::Signed;
+
+use zinc_types::AssertStorageUnchanged;
+
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for AssertStorageUnchanged {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        if let Some(condition) = vm.condition_top()?.to_bigint() {
+            if condition.is_positive() {
+                let addresses: Vec<String> = vm
+                    .storages_changed()?
+                    .into_iter()
+                    .map(|address| zinc_types::scalar_format::address(&address))
+                    .collect();
+
+                if !addresses.is_empty() {
+                    return Err(Error::StorageChanged {
+                        addresses: addresses.join(", "),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
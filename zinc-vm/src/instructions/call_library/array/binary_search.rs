@@ -0,0 +1,64 @@
+//!
+//! The `std::array::binary_search` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct BinarySearch {
+    array_length: usize,
+}
+
+impl BinarySearch {
+    pub fn new(inputs_count: usize) -> Result<Self, Error> {
+        inputs_count
+            .checked_sub(1)
+            .map(|array_length| Self { array_length })
+            .ok_or_else(|| {
+                MalformedBytecode::InvalidArguments(
+                    "array::binary_search expects at least 1 argument".into(),
+                )
+                .into()
+            })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for BinarySearch {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let needle = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let mut array = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            array.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        array.reverse();
+
+        let (is_found, index) = if array.iter().all(Scalar::is_constant) {
+            gadgets::search::binary_search(cs, array.as_slice(), &needle)?
+        } else {
+            gadgets::search::linear_search(cs, array.as_slice(), &needle)?
+        };
+
+        state.evaluation_stack.push(is_found.into())?;
+        state.evaluation_stack.push(index.into())?;
+
+        Ok(())
+    }
+}
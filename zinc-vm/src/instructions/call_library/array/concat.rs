@@ -0,0 +1,32 @@
+//!
+//! The `std::array::concat` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+///
+/// The arrays are already placed one after another on the evaluation stack,
+/// so the concatenated array is the function input itself.
+///
+pub struct Concat;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Concat {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        _cs: CS,
+        _state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! The `std::array::is_sorted` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct IsSorted {
+    array_length: usize,
+}
+
+impl IsSorted {
+    pub fn new(inputs_count: usize) -> Result<Self, Error> {
+        Ok(Self {
+            array_length: inputs_count,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for IsSorted {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let mut array = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            array.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        array.reverse();
+
+        let is_sorted = gadgets::search::is_sorted(cs, array.as_slice())?;
+        state.evaluation_stack.push(is_sorted.into())?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,9 @@
 //! The `std::array` module calls.
 //!
 
+pub mod binary_search;
+pub mod concat;
+pub mod is_sorted;
 pub mod pad;
 pub mod reverse;
 pub mod truncate;
@@ -61,7 +61,9 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
 
         let data = match storages
             .get(&eth_address)
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .ok_or_else(|| Error::ContractNotFound {
+                address: zinc_types::scalar_format::address(&eth_address),
+            })?
             .load(index)?
             .leaf_values
         {
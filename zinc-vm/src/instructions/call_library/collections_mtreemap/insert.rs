@@ -11,6 +11,7 @@ use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
@@ -65,7 +66,9 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Insert {
 
         let (mut data, key_size, value_size) = match storages
             .get(&eth_address)
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .ok_or_else(|| Error::ContractNotFound {
+                address: zinc_types::scalar_format::address(&eth_address),
+            })?
             .load(index.clone())?
             .leaf_values
         {
@@ -102,6 +105,7 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Insert {
             None => data.push((key, value)),
         }
 
+        state.audit(AuditReason::MapInsert);
         if state
             .conditions_stack
             .iter()
@@ -65,7 +65,9 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Remove {
 
         let (mut data, key_size, value_size) = match storages
             .get(&eth_address)
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .ok_or_else(|| Error::ContractNotFound {
+                address: zinc_types::scalar_format::address(&eth_address),
+            })?
             .load(index.clone())?
             .leaf_values
         {
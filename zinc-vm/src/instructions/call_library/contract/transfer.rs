@@ -10,6 +10,7 @@ use num::BigInt;
 use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
@@ -65,18 +66,19 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Transfer {
                 .expect(zinc_const::panic::DATA_CONVERSION),
         );
 
+        state.audit(AuditReason::Transfer);
         if state
             .conditions_stack
             .iter()
             .map(|value| value.get_value().expect(zinc_const::panic::DATA_CONVERSION))
             .all(|value| !value.is_zero())
         {
-            state.transfers.push(zinc_types::TransactionMsg::new(
-                sender,
-                recipient,
-                token_address,
-                amount,
-            ));
+            let transfer =
+                zinc_types::TransactionMsg::new(sender, recipient, token_address, amount);
+            if let Some(test_msg) = state.test_msg.as_mut() {
+                test_msg.check_transfer(&transfer)?;
+            }
+            state.transfers.push(transfer);
         }
 
         Ok(())
@@ -5,4 +5,8 @@
 pub mod from_bits_field;
 pub mod from_bits_signed;
 pub mod from_bits_unsigned;
+pub mod pack_bits;
 pub mod to_bits;
+pub mod try_cast;
+pub mod try_into_unsigned;
+pub mod unpack_bits;
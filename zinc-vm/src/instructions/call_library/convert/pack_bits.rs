@@ -0,0 +1,191 @@
+//!
+//! The `std::convert::pack_bits` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+///
+/// Packs the boolean flags into unsigned integers, so the flag at index `i` becomes the bit `i`
+/// of the result.
+///
+/// If there are several output scalars, which is the case of packed contract storage fields,
+/// each of them holds up to 248 flags.
+///
+pub struct PackBits {
+    bits: usize,
+    scalars: usize,
+}
+
+impl PackBits {
+    pub fn new(inputs_count: usize, outputs_count: usize) -> Self {
+        Self {
+            bits: inputs_count,
+            scalars: outputs_count,
+        }
+    }
+
+    ///
+    /// The number of flags in a single output scalar.
+    ///
+    pub fn chunk_size(bits: usize, scalars: usize) -> usize {
+        if scalars == 1 {
+            bits
+        } else {
+            zinc_types::ContractFieldType::PACKED_BITS_PER_SCALAR
+        }
+    }
+
+    ///
+    /// Checks whether `bits` flags can be packed into `scalars` scalars.
+    ///
+    pub fn is_valid<E: IEngine>(bits: usize, scalars: usize) -> bool {
+        match scalars {
+            0 => false,
+            1 => bits > 0 && bits <= E::Fr::CAPACITY as usize,
+            scalars => scalars == zinc_types::ContractFieldType::packed_size(bits),
+        }
+    }
+
+    ///
+    /// The type of the output scalar holding `bits` flags.
+    ///
+    pub fn scalar_type(bits: usize) -> zinc_types::ScalarType {
+        if bits > zinc_const::bitlength::INTEGER_MAX {
+            return zinc_types::ScalarType::Field;
+        }
+
+        zinc_types::IntegerType {
+            is_signed: false,
+            bitlength: bits.div_ceil(zinc_const::bitlength::BYTE) * zinc_const::bitlength::BYTE,
+        }
+        .into()
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for PackBits {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        if !Self::is_valid::<E>(self.bits, self.scalars) {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "convert::pack_bits: {} bits cannot be packed into {} scalars",
+                self.bits, self.scalars
+            ))
+            .into());
+        }
+
+        let mut bits = Vec::with_capacity(self.bits);
+        for index in 0..self.bits {
+            let bit = state.evaluation_stack.pop()?.try_into_value()?;
+            let boolean = bit.to_boolean(cs.namespace(|| format!("to_boolean {}", index)))?;
+            bits.push(boolean);
+        }
+        bits.reverse();
+
+        let chunk_size = Self::chunk_size(self.bits, self.scalars);
+        for (index, chunk) in bits.chunks(chunk_size).enumerate() {
+            let num = AllocatedNum::pack_bits_to_element(
+                cs.namespace(|| format!("pack_bits_to_element {}", index)),
+                chunk,
+            )?;
+
+            let scalar = Scalar::new_unchecked_variable(
+                num.get_value(),
+                num.get_variable(),
+                Self::scalar_type(chunk_size),
+            );
+
+            state.evaluation_stack.push(scalar.into())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    fn runner(bits: &[bool], scalars: usize) -> TestRunner {
+        let mut runner = TestRunner::new();
+        for bit in bits.iter() {
+            runner = runner.push(zinc_types::Push::new(
+                BigInt::from(*bit as u8),
+                zinc_types::ScalarType::Boolean,
+            ));
+        }
+        runner.push(zinc_types::CallLibrary::new(
+            zinc_types::LibraryFunctionIdentifier::ConvertPackBits,
+            bits.len(),
+            scalars,
+        ))
+    }
+
+    fn round_trip(size: usize) -> Result<(), TestingError> {
+        let bits: Vec<bool> = (0..size).map(|index| index % 3 == 0).collect();
+        let scalars = zinc_types::ContractFieldType::packed_size(size);
+
+        let expected: Vec<u8> = bits.iter().rev().map(|bit| *bit as u8).collect();
+
+        runner(bits.as_slice(), scalars)
+            .push(zinc_types::Push::new(
+                BigInt::from(size),
+                zinc_types::IntegerType::U16.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::ConvertUnpackBits,
+                scalars + 1,
+                size,
+            ))
+            .test(expected.as_slice())
+    }
+
+    #[test]
+    fn test_pack_bits() -> Result<(), TestingError> {
+        runner(&[true, false, true, true], 1).test(&[0b1101])
+    }
+
+    #[test]
+    fn test_pack_bits_chunks() -> Result<(), TestingError> {
+        let mut bits = vec![false; 249];
+        bits[0] = true;
+        bits[248] = true;
+
+        runner(bits.as_slice(), 2).test(&[1, 1])
+    }
+
+    #[test]
+    fn test_round_trip_1() -> Result<(), TestingError> {
+        round_trip(1)
+    }
+
+    #[test]
+    fn test_round_trip_248() -> Result<(), TestingError> {
+        round_trip(248)
+    }
+
+    #[test]
+    fn test_round_trip_249() -> Result<(), TestingError> {
+        round_trip(249)
+    }
+}
@@ -0,0 +1,180 @@
+//!
+//! The `std::convert::try_cast` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct TryCast;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for TryCast {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let bitlength = state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant_usize()?;
+
+        if bitlength == 0 || bitlength > zinc_const::bitlength::INTEGER_MAX {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "convert::try_cast: integer type with length {} is not supported",
+                bitlength
+            ))
+            .into());
+        }
+
+        let is_signed = !state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant()?
+            .is_zero();
+
+        let value = state.evaluation_stack.pop()?.try_into_value()?;
+        if !matches!(value.get_type(), zinc_types::ScalarType::Integer(_)) {
+            return Err(Error::TypeError {
+                expected: "integer".to_owned(),
+                found: value.get_type().to_string(),
+            });
+        }
+
+        let int_type = zinc_types::IntegerType {
+            is_signed,
+            bitlength,
+        };
+
+        // The value fits the type if it is in range `0..2^bitlength` after shifting by the
+        // minimal value of the type.
+        let offset = Scalar::new_constant_bigint(-int_type.min(), zinc_types::ScalarType::Field)?;
+        let shifted =
+            gadgets::arithmetic::add::add(cs.namespace(|| "shift"), &value.to_field(), &offset)?;
+        let bound =
+            Scalar::new_constant_bigint(BigInt::one() << bitlength, zinc_types::ScalarType::Field)?;
+        let is_fitting =
+            gadgets::comparison::lesser_than(cs.namespace(|| "lesser_than"), &shifted, &bound)?;
+
+        let zero = Scalar::new_constant_bigint(BigInt::zero(), zinc_types::ScalarType::Field)?;
+        let result = gadgets::select::conditional(
+            cs.namespace(|| "conditional"),
+            &is_fitting,
+            &value.to_field(),
+            &zero,
+        )?;
+
+        state.evaluation_stack.push(is_fitting.into())?;
+        state
+            .evaluation_stack
+            .push(result.to_type_unchecked(int_type.into()).into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    fn runner(
+        value: i64,
+        value_type: zinc_types::IntegerType,
+        is_signed: bool,
+        bitlength: usize,
+    ) -> TestRunner {
+        TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(value),
+                value_type.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(is_signed as u8),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(bitlength),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::ConvertTryCast,
+                3,
+                2,
+            ))
+    }
+
+    #[test]
+    fn test_try_cast_signed_to_unsigned_negative() -> Result<(), TestingError> {
+        runner(
+            -1,
+            zinc_types::IntegerType::I8,
+            false,
+            zinc_const::bitlength::BYTE,
+        )
+        .test(&[0, 0])
+    }
+
+    #[test]
+    fn test_try_cast_signed_to_unsigned_max() -> Result<(), TestingError> {
+        runner(
+            127,
+            zinc_types::IntegerType::I8,
+            false,
+            zinc_const::bitlength::BYTE,
+        )
+        .test(&[127, 1])
+    }
+
+    #[test]
+    fn test_try_cast_unsigned_to_signed_overflow() -> Result<(), TestingError> {
+        runner(
+            128,
+            zinc_types::IntegerType::U8,
+            true,
+            zinc_const::bitlength::BYTE,
+        )
+        .test(&[0, 0])
+    }
+
+    #[test]
+    fn test_try_cast_signed_narrowing_min() -> Result<(), TestingError> {
+        runner(
+            -128,
+            zinc_types::IntegerType::I16,
+            true,
+            zinc_const::bitlength::BYTE,
+        )
+        .test(&[-128, 1])
+    }
+
+    #[test]
+    fn test_try_cast_signed_narrowing_underflow() -> Result<(), TestingError> {
+        runner(
+            -129,
+            zinc_types::IntegerType::I16,
+            true,
+            zinc_const::bitlength::BYTE,
+        )
+        .test(&[0, 0])
+    }
+}
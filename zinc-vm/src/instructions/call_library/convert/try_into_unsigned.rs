@@ -0,0 +1,118 @@
+//!
+//! The `std::convert::try_into_unsigned` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct TryIntoUnsigned;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for TryIntoUnsigned {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let bitlength = state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant_usize()?;
+
+        if bitlength == 0 || bitlength > zinc_const::bitlength::INTEGER_MAX {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "convert::try_into_unsigned: integer type with length {} is not supported",
+                bitlength
+            ))
+            .into());
+        }
+
+        let value = state.evaluation_stack.pop()?.try_into_value()?;
+        value
+            .get_type()
+            .assert_type(zinc_types::ScalarType::Field)?;
+
+        let bound =
+            Scalar::new_constant_bigint(BigInt::one() << bitlength, zinc_types::ScalarType::Field)?;
+        let is_fitting =
+            gadgets::comparison::lesser_than(cs.namespace(|| "lesser_than"), &value, &bound)?;
+
+        let zero = Scalar::new_constant_bigint(BigInt::zero(), zinc_types::ScalarType::Field)?;
+        let result = gadgets::select::conditional(
+            cs.namespace(|| "conditional"),
+            &is_fitting,
+            &value,
+            &zero,
+        )?;
+
+        let int_type = zinc_types::IntegerType {
+            is_signed: false,
+            bitlength,
+        };
+
+        state.evaluation_stack.push(is_fitting.into())?;
+        state
+            .evaluation_stack
+            .push(result.to_type_unchecked(int_type.into()).into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::One;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    fn runner(value: BigInt, bitlength: usize) -> TestRunner {
+        TestRunner::new()
+            .push(zinc_types::Push::new_field(value))
+            .push(zinc_types::Push::new(
+                BigInt::from(bitlength),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::ConvertTryIntoUnsigned,
+                2,
+                2,
+            ))
+    }
+
+    #[test]
+    fn test_try_into_unsigned_max() -> Result<(), TestingError> {
+        runner(BigInt::from(255), zinc_const::bitlength::BYTE).test(&[255, 1])
+    }
+
+    #[test]
+    fn test_try_into_unsigned_overflow() -> Result<(), TestingError> {
+        runner(BigInt::from(256), zinc_const::bitlength::BYTE).test(&[0, 0])
+    }
+
+    #[test]
+    fn test_try_into_unsigned_u64_max() -> Result<(), TestingError> {
+        runner(BigInt::from(u64::MAX), 64).test(&[u64::MAX, 1])
+    }
+
+    #[test]
+    fn test_try_into_unsigned_u64_overflow() -> Result<(), TestingError> {
+        runner(BigInt::one() << 64, 64).test(&[0, 0])
+    }
+}
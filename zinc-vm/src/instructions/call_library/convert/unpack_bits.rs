@@ -0,0 +1,132 @@
+//!
+//! The `std::convert::unpack_bits` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::convert::pack_bits::PackBits;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+///
+/// Unpacks the unsigned integers into boolean flags, which is the inverse of `PackBits`.
+///
+/// The number of flags is passed as the last argument.
+///
+pub struct UnpackBits {
+    scalars: usize,
+}
+
+impl UnpackBits {
+    pub fn new(inputs_count: usize) -> Self {
+        Self {
+            scalars: inputs_count.saturating_sub(1),
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for UnpackBits {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let bits = state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant_usize()?;
+
+        if !PackBits::is_valid::<E>(bits, self.scalars) {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "convert::unpack_bits: {} scalars cannot be unpacked into {} bits",
+                self.scalars, bits
+            ))
+            .into());
+        }
+
+        let mut scalars = Vec::with_capacity(self.scalars);
+        for _ in 0..self.scalars {
+            scalars.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        scalars.reverse();
+
+        let chunk_size = PackBits::chunk_size(bits, self.scalars);
+        for (index, scalar) in scalars.into_iter().enumerate() {
+            let bitlength = std::cmp::min(chunk_size, bits - index * chunk_size);
+
+            let chunk = scalar.to_expression::<CS>().into_bits_le_fixed(
+                cs.namespace(|| format!("into_bits_le_fixed {}", index)),
+                bitlength,
+            )?;
+
+            for (bit_index, bit) in chunk.into_iter().enumerate() {
+                let scalar = Scalar::from_boolean(
+                    cs.namespace(|| format!("from_boolean {} {}", index, bit_index)),
+                    bit,
+                )?;
+                state.evaluation_stack.push(scalar.into())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    #[test]
+    fn test_unpack_bits() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(0b1101),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(4),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::ConvertUnpackBits,
+                2,
+                4,
+            ))
+            .test(&[1, 1, 0, 1])
+    }
+
+    #[test]
+    fn test_unpack_bits_overflow() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(0b10000),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(4),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::ConvertUnpackBits,
+                2,
+                4,
+            ))
+            .test::<i32>(&[]);
+
+        assert!(result.is_err());
+    }
+}
@@ -5,3 +5,4 @@
 pub mod pedersen;
 pub mod schnorr_verify;
 pub mod sha256;
+pub mod verify_proof;
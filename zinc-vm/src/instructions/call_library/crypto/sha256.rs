@@ -44,6 +44,8 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Sha256 {
     ) -> Result<(), Error> {
         let mut bits = Vec::new();
         for i in 0..self.message_length {
+            state.cancellation.check_periodically(i)?;
+
             let bit = state
                 .evaluation_stack
                 .pop()?
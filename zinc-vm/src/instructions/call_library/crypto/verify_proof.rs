@@ -0,0 +1,167 @@
+//!
+//! The `std::crypto::verify_proof` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct VerifyProof {
+    public_inputs_count: usize,
+}
+
+impl VerifyProof {
+    pub fn new(input_size: usize) -> Result<Self, Error> {
+        let fixed_size =
+            zinc_const::bitlength::SHA256_HASH + zinc_const::limit::VERIFY_PROOF_FIELDS;
+
+        match input_size.checked_sub(fixed_size) {
+            Some(public_inputs_count)
+                if public_inputs_count > 0
+                    && public_inputs_count <= zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS =>
+            {
+                Ok(Self {
+                    public_inputs_count,
+                })
+            }
+            _ => Err(MalformedBytecode::InvalidArguments(format!(
+                "verify_proof expects {} digest bits, 1 to {} public inputs, and {} proof words, got {} arguments",
+                zinc_const::bitlength::SHA256_HASH,
+                zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS,
+                zinc_const::limit::VERIFY_PROOF_FIELDS,
+                input_size
+            ))
+            .into()),
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for VerifyProof {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        _cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let proof = pop_values(state, zinc_const::limit::VERIFY_PROOF_FIELDS)?;
+        let public_inputs = pop_values(state, self.public_inputs_count)?;
+        let vk_digest = pop_values(state, zinc_const::bitlength::SHA256_HASH)?;
+
+        if !cfg!(feature = "recursive-verification") {
+            return Err(Error::RecursiveVerificationDisabled);
+        }
+
+        if state.is_proving {
+            return Err(Error::RecursiveVerificationProving);
+        }
+
+        let is_valid = gadgets::verify_proof::verify_native(
+            &state.verifying_keys,
+            vk_digest.as_slice(),
+            public_inputs.as_slice(),
+            proof.as_slice(),
+        )?;
+
+        state
+            .evaluation_stack
+            .push(Scalar::new_constant_bool(is_valid).into())?;
+
+        Ok(())
+    }
+}
+
+///
+/// Pops `count` values from the evaluation stack, restoring their original order.
+///
+fn pop_values<E: IEngine>(
+    state: &mut ExecutionState<E>,
+    count: usize,
+) -> Result<Vec<Scalar<E>>, Error> {
+    let mut values = Vec::with_capacity(count);
+    for _ in 0..count {
+        values.push(state.evaluation_stack.pop()?.try_into_value()?);
+    }
+    values.reverse();
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::Zero;
+
+    use crate::error::Error;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    use super::VerifyProof;
+
+    fn runner(public_inputs_count: usize) -> TestRunner {
+        let mut runner = TestRunner::new();
+        for _ in 0..zinc_const::bitlength::SHA256_HASH {
+            runner = runner.push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            ));
+        }
+        for _ in 0..public_inputs_count + zinc_const::limit::VERIFY_PROOF_FIELDS {
+            runner = runner.push(zinc_types::Push::new_field(BigInt::zero()));
+        }
+
+        runner.push(zinc_types::CallLibrary::new(
+            zinc_types::LibraryFunctionIdentifier::CryptoVerifyProof,
+            zinc_const::bitlength::SHA256_HASH
+                + public_inputs_count
+                + zinc_const::limit::VERIFY_PROOF_FIELDS,
+            1,
+        ))
+    }
+
+    #[test]
+    fn test_verify_proof_input_size() {
+        let fixed_size =
+            zinc_const::bitlength::SHA256_HASH + zinc_const::limit::VERIFY_PROOF_FIELDS;
+
+        assert!(VerifyProof::new(fixed_size).is_err());
+        assert!(VerifyProof::new(fixed_size + 1).is_ok());
+        assert!(
+            VerifyProof::new(fixed_size + zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS).is_ok()
+        );
+        assert!(
+            VerifyProof::new(fixed_size + zinc_const::limit::VERIFY_PROOF_PUBLIC_INPUTS + 1)
+                .is_err()
+        );
+    }
+
+    #[cfg(not(feature = "recursive-verification"))]
+    #[test]
+    fn test_verify_proof_disabled() {
+        match runner(2).test::<usize>(&[]) {
+            Err(TestingError::Error(Error::RecursiveVerificationDisabled)) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[cfg(feature = "recursive-verification")]
+    #[test]
+    fn test_verify_proof_key_not_found() {
+        match runner(2).test::<usize>(&[]) {
+            Err(TestingError::Error(Error::VerifyingKeyNotFound { digest })) => {
+                assert_eq!(digest, "00".repeat(zinc_const::size::SHA256_HASH))
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//!
+//! The `as <Enumeration>` casting call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Cast {
+    variants_count: usize,
+}
+
+impl Cast {
+    /// The error message, if the value is not an enumeration variant.
+    pub const ERROR_INVALID_VARIANT: &'static str = "the value is not a valid enumeration variant";
+
+    pub fn new(input_size: usize) -> Result<Self, Error> {
+        super::variants_count("enumeration::cast", input_size)
+            .map(|variants_count| Self { variants_count })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Cast {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let (value, variants) = super::pop_operands(state, self.variants_count)?;
+
+        let is_member = super::is_member(cs.namespace(|| "is_member"), &value, &variants)?;
+
+        let condition = state
+            .conditions_stack
+            .last()
+            .cloned()
+            .ok_or(MalformedBytecode::StackUnderflow)?;
+        let not_condition = gadgets::logical::not::not(cs.namespace(|| "not"), &condition)?;
+        let is_valid = gadgets::logical::or::or(cs.namespace(|| "or"), &is_member, &not_condition)?;
+        gadgets::require::require(
+            cs.namespace(|| "require"),
+            is_valid,
+            Some(Self::ERROR_INVALID_VARIANT),
+        )?;
+
+        let variant_type = variants
+            .first()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .get_type();
+        state
+            .evaluation_stack
+            .push(value.to_type_unchecked(variant_type).into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::Zero;
+
+    use crate::error::Error;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    fn runner(value: BigInt) -> TestRunner {
+        let mut runner = TestRunner::new().push(zinc_types::Push::new(
+            value,
+            zinc_types::IntegerType::U8.into(),
+        ));
+        for variant in [1, 2, 4].iter() {
+            runner = runner.push(zinc_types::Push::new(
+                BigInt::from(*variant),
+                zinc_types::IntegerType::new(false, 3).into(),
+            ));
+        }
+        runner.push(zinc_types::CallLibrary::new(
+            zinc_types::LibraryFunctionIdentifier::EnumerationCast,
+            4,
+            1,
+        ))
+    }
+
+    #[test]
+    fn test_enumeration_cast_variant() -> Result<(), TestingError> {
+        runner(BigInt::from(2)).test(&[2])
+    }
+
+    #[test]
+    fn test_enumeration_cast_out_of_range() {
+        let result = runner(BigInt::from(3)).test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::RequireError(_))) => {}
+            _ => panic!("Expected require error"),
+        }
+    }
+
+    #[test]
+    fn test_enumeration_cast_in_false_condition() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::If)
+            .push(zinc_types::Push::new(
+                BigInt::from(3),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(1),
+                zinc_types::IntegerType::new(false, 3).into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::EnumerationCast,
+                2,
+                1,
+            ))
+            .push(zinc_types::EndIf)
+            .test::<i32>(&[])
+    }
+}
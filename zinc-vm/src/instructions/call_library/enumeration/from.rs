@@ -0,0 +1,97 @@
+//!
+//! The `<Enumeration>::from` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct From {
+    variants_count: usize,
+}
+
+impl From {
+    pub fn new(input_size: usize) -> Result<Self, Error> {
+        super::variants_count("enumeration::from", input_size)
+            .map(|variants_count| Self { variants_count })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for From {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let (value, variants) = super::pop_operands(state, self.variants_count)?;
+
+        let is_member = super::is_member(cs.namespace(|| "is_member"), &value, &variants)?;
+
+        let fallback = variants
+            .first()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let value = value.to_type_unchecked(fallback.get_type());
+        let result = gadgets::select::conditional(
+            cs.namespace(|| "conditional"),
+            &is_member,
+            &value,
+            fallback,
+        )?;
+
+        state.evaluation_stack.push(is_member.into())?;
+        state.evaluation_stack.push(result.into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    fn runner(value: BigInt) -> TestRunner {
+        let mut runner = TestRunner::new().push(zinc_types::Push::new(
+            value,
+            zinc_types::IntegerType::U8.into(),
+        ));
+        for variant in [1, 2, 4].iter() {
+            runner = runner.push(zinc_types::Push::new(
+                BigInt::from(*variant),
+                zinc_types::IntegerType::new(false, 3).into(),
+            ));
+        }
+        runner.push(zinc_types::CallLibrary::new(
+            zinc_types::LibraryFunctionIdentifier::EnumerationFrom,
+            4,
+            2,
+        ))
+    }
+
+    #[test]
+    fn test_enumeration_from_variant() -> Result<(), TestingError> {
+        runner(BigInt::from(4)).test(&[4, 1])
+    }
+
+    #[test]
+    fn test_enumeration_from_not_variant() -> Result<(), TestingError> {
+        runner(BigInt::from(3)).test(&[1, 0])
+    }
+
+    #[test]
+    fn test_enumeration_from_out_of_range() -> Result<(), TestingError> {
+        runner(BigInt::from(255)).test(&[1, 0])
+    }
+}
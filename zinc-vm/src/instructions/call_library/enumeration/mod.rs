@@ -0,0 +1,81 @@
+//!
+//! The enumeration built-in calls.
+//!
+
+pub mod cast;
+pub mod from;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// Pops the enumeration variant values and the checked value from the evaluation stack.
+///
+/// The variants are pushed by the compiler after the checked value, so the latter is the
+/// deepest one.
+///
+fn pop_operands<E: IEngine>(
+    state: &mut ExecutionState<E>,
+    variants_count: usize,
+) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error> {
+    let mut variants = Vec::with_capacity(variants_count);
+    for _ in 0..variants_count {
+        variants.push(state.evaluation_stack.pop()?.try_into_value()?);
+    }
+    variants.reverse();
+
+    let value = state.evaluation_stack.pop()?.try_into_value()?;
+
+    Ok((value, variants))
+}
+
+///
+/// Checks whether the `value` is equal to any of the `variants`.
+///
+fn is_member<E, CS>(
+    mut cs: CS,
+    value: &Scalar<E>,
+    variants: &[Scalar<E>],
+) -> Result<Scalar<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut is_member = Scalar::new_constant_bool(false);
+    for (index, variant) in variants.iter().enumerate() {
+        let is_equal = gadgets::comparison::equals(
+            cs.namespace(|| format!("equals {}", index)),
+            value,
+            variant,
+        )?;
+        is_member = gadgets::logical::or::or(
+            cs.namespace(|| format!("or {}", index)),
+            &is_member,
+            &is_equal,
+        )?;
+    }
+
+    Ok(is_member)
+}
+
+///
+/// Returns the number of the enumeration variants passed to the function.
+///
+fn variants_count(function: &str, input_size: usize) -> Result<usize, Error> {
+    input_size
+        .checked_sub(1)
+        .filter(|count| *count > 0)
+        .ok_or_else(|| {
+            MalformedBytecode::InvalidArguments(format!(
+                "{} expects the value and at least one variant",
+                function
+            ))
+            .into()
+        })
+}
@@ -7,7 +7,9 @@ pub mod collections_mtreemap;
 pub mod contract;
 pub mod convert;
 pub mod crypto;
+pub mod enumeration;
 pub mod ff;
+pub mod test;
 
 use std::collections::HashMap;
 
@@ -25,6 +27,9 @@ use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::instructions::IExecutable;
 use crate::IEngine;
 
+use self::array::binary_search::BinarySearch as ArrayBinarySearch;
+use self::array::concat::Concat as ArrayConcat;
+use self::array::is_sorted::IsSorted as ArrayIsSorted;
 use self::array::pad::Pad as ArrayPad;
 use self::array::reverse::Reverse as ArrayReverse;
 use self::array::truncate::Truncate as ArrayTruncate;
@@ -36,11 +41,19 @@ use self::contract::transfer::Transfer as ZksyncTransfer;
 use self::convert::from_bits_field::FromBitsField as ConvertFromBitsField;
 use self::convert::from_bits_signed::FromBitsSigned as ConvertFromBitsSigned;
 use self::convert::from_bits_unsigned::FromBitsUnsigned as ConvertFromBitsUnsigned;
+use self::convert::pack_bits::PackBits as ConvertPackBits;
 use self::convert::to_bits::ToBits as ConvertToBits;
+use self::convert::try_cast::TryCast as ConvertTryCast;
+use self::convert::try_into_unsigned::TryIntoUnsigned as ConvertTryIntoUnsigned;
+use self::convert::unpack_bits::UnpackBits as ConvertUnpackBits;
 use self::crypto::pedersen::Pedersen as CryptoPedersen;
 use self::crypto::schnorr_verify::SchnorrSignatureVerify as CryptoSchnorrSignatureVerify;
 use self::crypto::sha256::Sha256 as CryptoSha256;
+use self::crypto::verify_proof::VerifyProof as CryptoVerifyProof;
+use self::enumeration::cast::Cast as EnumerationCast;
+use self::enumeration::from::From as EnumerationFrom;
 use self::ff::invert::Inverse as FfInverse;
+use self::test::rand_u::RandU as TestRandU;
 
 pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>> {
     fn call<CS: ConstraintSystem<E>>(
@@ -63,6 +76,9 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify => {
                 vm.call_native(CryptoSchnorrSignatureVerify::new(self.input_size)?)
             }
+            LibraryFunctionIdentifier::CryptoVerifyProof => {
+                vm.call_native(CryptoVerifyProof::new(self.input_size)?)
+            }
 
             LibraryFunctionIdentifier::ConvertToBits => vm.call_native(ConvertToBits),
             LibraryFunctionIdentifier::ConvertFromBitsUnsigned => {
@@ -72,6 +88,16 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
                 vm.call_native(ConvertFromBitsSigned::new(self.input_size))
             }
             LibraryFunctionIdentifier::ConvertFromBitsField => vm.call_native(ConvertFromBitsField),
+            LibraryFunctionIdentifier::ConvertTryIntoUnsigned => {
+                vm.call_native(ConvertTryIntoUnsigned)
+            }
+            LibraryFunctionIdentifier::ConvertTryCast => vm.call_native(ConvertTryCast),
+            LibraryFunctionIdentifier::ConvertPackBits => {
+                vm.call_native(ConvertPackBits::new(self.input_size, self.output_size))
+            }
+            LibraryFunctionIdentifier::ConvertUnpackBits => {
+                vm.call_native(ConvertUnpackBits::new(self.input_size))
+            }
 
             LibraryFunctionIdentifier::ArrayReverse => {
                 vm.call_native(ArrayReverse::new(self.input_size)?)
@@ -80,11 +106,27 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
                 vm.call_native(ArrayTruncate::new(self.input_size)?)
             }
             LibraryFunctionIdentifier::ArrayPad => vm.call_native(ArrayPad::new(self.input_size)?),
+            LibraryFunctionIdentifier::ArrayConcat => vm.call_native(ArrayConcat),
+            LibraryFunctionIdentifier::ArrayBinarySearch => {
+                vm.call_native(ArrayBinarySearch::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::ArrayIsSorted => {
+                vm.call_native(ArrayIsSorted::new(self.input_size)?)
+            }
 
             LibraryFunctionIdentifier::FfInvert => vm.call_native(FfInverse),
 
+            LibraryFunctionIdentifier::TestRandU => vm.call_native(TestRandU),
+
             LibraryFunctionIdentifier::ContractTransfer => vm.call_native(ZksyncTransfer),
 
+            LibraryFunctionIdentifier::EnumerationFrom => {
+                vm.call_native(EnumerationFrom::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::EnumerationCast => {
+                vm.call_native(EnumerationCast::new(self.input_size)?)
+            }
+
             LibraryFunctionIdentifier::CollectionsMTreeMapGet => vm.call_native(
                 CollectionsMTreeMapGet::new(self.input_size, self.output_size),
             ),
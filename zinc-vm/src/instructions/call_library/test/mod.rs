@@ -0,0 +1,5 @@
+//!
+//! The `std::test` module calls.
+//!
+
+pub mod rand_u;
@@ -0,0 +1,106 @@
+//!
+//! The `std::test::rand_u` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::fr_bigint;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+///
+/// The pseudo-random generator, which is only evaluated on the host within unit tests and
+/// never makes it to a proof.
+///
+pub struct RandU;
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for RandU {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        _cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let seed_offset = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let bitlength = state
+            .evaluation_stack
+            .pop()?
+            .try_into_value()?
+            .get_constant_usize()?;
+
+        if bitlength == 0
+            || bitlength > zinc_const::bitlength::INTEGER_MAX
+            || bitlength % zinc_const::bitlength::BYTE != 0
+        {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "test::rand_u: integer type with length {} is not supported",
+                bitlength
+            ))
+            .into());
+        }
+
+        if state.is_proving {
+            return Err(Error::TestRandBeyondUnitTest);
+        }
+        let seed = state
+            .test_seed
+            .as_mut()
+            .ok_or(Error::TestRandBeyondUnitTest)?;
+
+        let seed_offset = fr_bigint::fr_to_bigint::<E>(&seed_offset.grab_value()?, false);
+        let value = seed.generate(&seed_offset, bitlength);
+
+        state.evaluation_stack.push(
+            Scalar::new_constant_bigint(
+                value,
+                zinc_types::IntegerType::new(false, bitlength).into(),
+            )?
+            .into(),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::error::Error;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    #[test]
+    fn error_beyond_unit_test() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(64),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(0),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::TestRandU,
+                2,
+                1,
+            ))
+            .test::<i32>(&[]);
+
+        assert!(matches!(
+            result,
+            Err(TestingError::Error(Error::TestRandBeyondUnitTest))
+        ));
+    }
+}
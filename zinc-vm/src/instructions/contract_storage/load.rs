@@ -2,10 +2,14 @@
 //! The `StorageLoad` instruction.
 //!
 
+use num::bigint::ToBigInt;
+use num::Signed;
+
 use zinc_types::StorageLoad;
 
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
+use crate::gadgets::scalar::Scalar;
 use crate::instructions::IExecutable;
 
 impl<VM: IVirtualMachine> IExecutable<VM> for StorageLoad {
@@ -13,7 +17,27 @@ impl<VM: IVirtualMachine> IExecutable<VM> for StorageLoad {
         let index = vm.pop()?.try_into_value()?;
         let eth_address = vm.pop()?.try_into_value()?;
 
-        let values = vm.storage_load(eth_address, index, self.size)?;
+        let is_taken = vm
+            .condition_top()?
+            .to_bigint()
+            .expect(zinc_const::panic::DATA_CONVERSION)
+            .is_positive();
+
+        // the leaf index computed in a branch which is not taken may select any leaf or none,
+        // so the loaded value is only shaped to keep the evaluation stack balanced
+        let values = match vm.storage_load(eth_address, index, self.size) {
+            Ok(mut values) if !is_taken => {
+                values.resize(
+                    self.size,
+                    Scalar::new_constant_usize(0, zinc_types::ScalarType::Field),
+                );
+                values
+            }
+            Err(Error::IndexOutOfBounds { .. }) | Err(Error::ExpectedUsize(_)) if !is_taken => {
+                vec![Scalar::new_constant_usize(0, zinc_types::ScalarType::Field); self.size]
+            }
+            result => result?,
+        };
 
         for value in values.into_iter().rev() {
             vm.push(value.into())?;
@@ -22,12 +22,19 @@ impl<VM: IVirtualMachine> IExecutable<VM> for StorageStore {
             values.push(vm.pop()?.try_into_value()?);
         }
 
-        if vm
+        let is_taken = vm
             .condition_top()?
             .to_bigint()
             .expect(zinc_const::panic::DATA_CONVERSION)
-            .is_positive()
-        {
+            .is_positive();
+
+        if vm.is_auditing() {
+            // the leaf is read and written in both branches, where the branch not taken writes
+            // the current values back, so the host operations do not depend on the condition
+            let current = vm.storage_load(eth_address.clone(), index.clone(), self.size)?;
+            let values = if is_taken { values } else { current };
+            vm.storage_store(eth_address, index, LeafVariant::Array(values))?;
+        } else if is_taken {
             vm.storage_store(eth_address, index, LeafVariant::Array(values))?;
         }
 
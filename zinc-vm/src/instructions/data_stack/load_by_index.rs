@@ -5,11 +5,14 @@
 use num::bigint::ToBigInt;
 use num::ToPrimitive;
 
+use franklin_crypto::bellman::ConstraintSystem;
+
 use zinc_types::LoadByIndex;
 
 use crate::core::execution_state::cell::Cell;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
+use crate::gadgets;
 use crate::instructions::IExecutable;
 
 impl<VM: IVirtualMachine> IExecutable<VM> for LoadByIndex {
@@ -22,19 +25,25 @@ impl<VM: IVirtualMachine> IExecutable<VM> for LoadByIndex {
             array.push(value);
         }
 
-        let _condition = vm.condition_top()?;
+        let condition = vm.condition_top()?;
+        let index = gadgets::array::conditional_index(
+            vm.constraint_system().namespace(|| "index"),
+            &condition,
+            &index,
+        )?;
+        let index = index.to_bigint().expect(zinc_const::panic::DATA_CONVERSION);
+        let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
         let mut values = Vec::with_capacity(self.value_size);
         for i in 0..self.value_size {
-            let value = array
-                .get(
-                    i + index
-                        .to_bigint()
-                        .expect(zinc_const::panic::DATA_CONVERSION)
-                        .to_usize()
-                        .expect(zinc_const::panic::DATA_CONVERSION),
-                )
-                .cloned()
-                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            let value =
+                array
+                    .get(index.saturating_add(i))
+                    .cloned()
+                    .ok_or(Error::IndexOutOfBounds {
+                        lower_bound: 0,
+                        upper_bound: self.total_size,
+                        found: index.saturating_add(i),
+                    })?;
             values.push(value);
         }
 
@@ -29,6 +29,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for StoreByIndex {
         values.reverse();
 
         let index = vm.pop()?.try_into_value()?;
+        let condition = vm.condition_top()?;
+        let index = gadgets::array::conditional_index(
+            vm.constraint_system().namespace(|| "index"),
+            &condition,
+            &index,
+        )?;
 
         for (i, value) in values.into_iter().enumerate() {
             let mut cs = vm.constraint_system();
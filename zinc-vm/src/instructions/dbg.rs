@@ -10,6 +10,7 @@ use franklin_crypto::bellman::SynthesisError;
 
 use zinc_types::Dbg;
 
+use crate::core::execution_state::audit::Reason as AuditReason;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::gadgets::scalar::Scalar;
@@ -28,23 +29,31 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
                     let eth_address = vm.pop()?.try_into_value()?;
 
                     let mut flat = Vec::with_capacity(size);
-                    for (index, field) in fields.iter().enumerate() {
-                        let values: Vec<BigInt> = vm
-                            .storage_load(
-                                eth_address.clone(),
-                                Scalar::new_constant_usize(
-                                    index,
-                                    zinc_types::ScalarType::Integer(zinc_types::IntegerType::new(
-                                        false,
-                                        zinc_const::bitlength::INDEX,
-                                    )),
-                                ),
-                                field.r#type.size(),
-                            )?
-                            .into_iter()
-                            .map(|scalar| scalar.to_bigint().unwrap_or_default())
-                            .collect();
-                        flat.extend(values);
+                    let mut leaf_index = 0;
+                    for field in fields.iter() {
+                        let mut leaves = Vec::with_capacity(field.leaves.len());
+                        for leaf in field.leaves.iter() {
+                            let values: Vec<BigInt> = vm
+                                .storage_load(
+                                    eth_address.clone(),
+                                    Scalar::new_constant_usize(
+                                        leaf_index,
+                                        zinc_types::ScalarType::Integer(
+                                            zinc_types::IntegerType::new(
+                                                false,
+                                                zinc_const::bitlength::INDEX,
+                                            ),
+                                        ),
+                                    ),
+                                    leaf.storage_type().size(),
+                                )?
+                                .into_iter()
+                                .map(|scalar| scalar.to_bigint().unwrap_or_default())
+                                .collect();
+                            leaves.push(values);
+                            leaf_index += 1;
+                        }
+                        flat.extend(field.unpack_leaves(leaves.as_slice()));
                     }
                     values.push(zinc_types::Value::from_flat_values(
                         zinc_types::Type::Contract(fields),
@@ -66,6 +75,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Dbg {
             }
         }
 
+        vm.audit(AuditReason::Debug);
         if let Some(condition) = vm.condition_top()?.to_bigint() {
             if condition.is_positive() {
                 let mut buffer = self.format;
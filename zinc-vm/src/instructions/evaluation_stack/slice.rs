@@ -26,25 +26,30 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Slice {
         }
         array.reverse();
 
-        let offset_usize = offset
+        let condition = vm.condition_top()?;
+        let offset = gadgets::array::conditional_index(
+            vm.constraint_system().namespace(|| "offset"),
+            &condition,
+            &offset,
+        )?;
+        let offset_bigint = offset
             .to_bigint()
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .to_usize()
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
-        if offset_usize + self.slice_length > self.total_size {
+        let offset_usize = offset_bigint
+            .to_usize()
+            .ok_or(Error::ExpectedUsize(offset_bigint))?;
+        if offset_usize.saturating_add(self.slice_length) > self.total_size {
             return Err(Error::IndexOutOfBounds {
                 lower_bound: 0,
                 upper_bound: self.total_size,
-                found: offset_usize + self.slice_length,
+                found: offset_usize.saturating_add(self.slice_length),
             });
         }
 
         for i in 0..self.slice_length {
-            let condition = vm.condition_top()?;
-            let namespace = format!("conditional_get_{}", i);
-            let value = gadgets::array::conditional_get(
+            let namespace = format!("get_{}", i);
+            let value = gadgets::array::enforcing_get(
                 vm.constraint_system().namespace(|| namespace),
-                &condition,
                 &array[i..=array.len() - self.slice_length + i],
                 &offset,
             )?;
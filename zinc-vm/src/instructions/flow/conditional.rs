@@ -36,6 +36,7 @@ mod tests {
     use num::One;
     use num::Zero;
 
+    use crate::error::Error;
     use crate::tests::TestRunner;
     use crate::tests::TestingError;
 
@@ -112,7 +113,7 @@ mod tests {
                     zinc_types::IntegerType::I8.into(),
                 ))
                 .push(zinc_types::Load::new(0, 1))
-                .push(zinc_types::Add)
+                .push(zinc_types::Add::default())
                 .push(zinc_types::Store::new(0, 1))
                 .push(zinc_types::Else)
                 .push(zinc_types::Load::new(0, 1))
@@ -120,7 +121,7 @@ mod tests {
                     BigInt::one(),
                     zinc_types::IntegerType::I8.into(),
                 ))
-                .push(zinc_types::Sub)
+                .push(zinc_types::Sub::default())
                 .push(zinc_types::Store::new(0, 1))
                 .push(zinc_types::EndIf)
                 .push(zinc_types::Load::new(0, 1))
@@ -129,4 +130,141 @@ mod tests {
 
         Ok(())
     }
+
+    ///
+    /// Pushes the instructions of `a != 0 && b / a > 2`, where `a` and `b` are the inputs.
+    ///
+    fn guarded_division(a: u8, b: u8) -> TestRunner {
+        TestRunner::new_with_input(
+            zinc_types::Type::Tuple(vec![
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            ]),
+            vec![BigInt::from(a), BigInt::from(b)],
+        )
+        .push(zinc_types::Load::new(0, 1))
+        .push(zinc_types::Push::new(
+            BigInt::zero(),
+            zinc_types::IntegerType::U8.into(),
+        ))
+        .push(zinc_types::Ne)
+        .push(zinc_types::If)
+        .push(zinc_types::Load::new(1, 1))
+        .push(zinc_types::Load::new(0, 1))
+        .push(zinc_types::Div)
+        .push(zinc_types::Push::new(
+            BigInt::from(2),
+            zinc_types::IntegerType::U8.into(),
+        ))
+        .push(zinc_types::Gt)
+        .push(zinc_types::Else)
+        .push(zinc_types::Push::new(
+            BigInt::zero(),
+            zinc_types::ScalarType::Boolean,
+        ))
+        .push(zinc_types::EndIf)
+    }
+
+    #[test]
+    fn test_short_circuit_guarded_division() -> Result<(), TestingError> {
+        guarded_division(0, 9).test(&[0])?;
+        guarded_division(3, 9).test(&[1])?;
+        guarded_division(5, 9).test(&[0])
+    }
+
+    #[test]
+    fn test_short_circuit_unguarded_division() {
+        let result = TestRunner::new_with_input(
+            zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            vec![BigInt::zero()],
+        )
+        .push(zinc_types::Push::new(
+            BigInt::from(9),
+            zinc_types::IntegerType::U8.into(),
+        ))
+        .push(zinc_types::Load::new(0, 1))
+        .push(zinc_types::Div)
+        .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::DivisionByZero)) => {}
+            result => panic!("Expected the division by zero error, got {:?}", result),
+        }
+    }
+
+    ///
+    /// Pushes the instructions of `index < 3 && array[index] > 0`, where `array` and `index` are
+    /// the inputs.
+    ///
+    fn guarded_index(index: u8) -> TestRunner {
+        TestRunner::new_with_input(
+            zinc_types::Type::Tuple(vec![
+                zinc_types::Type::Array(
+                    Box::new(zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into())),
+                    3,
+                ),
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            ]),
+            vec![
+                BigInt::one(),
+                BigInt::zero(),
+                BigInt::one(),
+                BigInt::from(index),
+            ],
+        )
+        .push(zinc_types::Load::new(3, 1))
+        .push(zinc_types::Push::new(
+            BigInt::from(3),
+            zinc_types::IntegerType::U8.into(),
+        ))
+        .push(zinc_types::Lt)
+        .push(zinc_types::If)
+        .push(zinc_types::Load::new(3, 1))
+        .push(zinc_types::LoadByIndex::new(0, 1, 3))
+        .push(zinc_types::Push::new(
+            BigInt::zero(),
+            zinc_types::IntegerType::U8.into(),
+        ))
+        .push(zinc_types::Gt)
+        .push(zinc_types::Else)
+        .push(zinc_types::Push::new(
+            BigInt::zero(),
+            zinc_types::ScalarType::Boolean,
+        ))
+        .push(zinc_types::EndIf)
+    }
+
+    #[test]
+    fn test_short_circuit_guarded_index() -> Result<(), TestingError> {
+        guarded_index(3).test(&[0])?;
+        guarded_index(2).test(&[1])?;
+        guarded_index(1).test(&[0])
+    }
+
+    #[test]
+    fn test_short_circuit_unguarded_index() {
+        let result = TestRunner::new_with_input(
+            zinc_types::Type::Tuple(vec![
+                zinc_types::Type::Array(
+                    Box::new(zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into())),
+                    3,
+                ),
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            ]),
+            vec![
+                BigInt::one(),
+                BigInt::zero(),
+                BigInt::one(),
+                BigInt::from(3),
+            ],
+        )
+        .push(zinc_types::Load::new(3, 1))
+        .push(zinc_types::LoadByIndex::new(0, 1, 3))
+        .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::IndexOutOfBounds { .. })) => {}
+            result => panic!("Expected the index out of bounds error, got {:?}", result),
+        }
+    }
 }
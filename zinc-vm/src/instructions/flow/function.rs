@@ -50,4 +50,38 @@ mod tests {
             .push(zinc_types::Call::new(2, 2))
             .test(&[3, 42])
     }
+
+    #[test]
+    fn test_mutable_method_write_back() -> Result<(), TestingError> {
+        TestRunner::new()
+            // call main
+            .push(zinc_types::Call::new(8, 0))
+            // fn increment(mut self: (field, field)) -> field
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Push::new_field(BigInt::from(1)))
+            .push(zinc_types::Add::default())
+            .push(zinc_types::Store::new(0, 1))
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Load::new(0, 2))
+            .push(zinc_types::Return::new(3))
+            // fn main
+            .push(zinc_types::Push::new_field(BigInt::from(10)))
+            .push(zinc_types::Push::new_field(BigInt::from(11)))
+            .push(zinc_types::Push::new_field(BigInt::from(20)))
+            .push(zinc_types::Push::new_field(BigInt::from(21)))
+            .push(zinc_types::Push::new_field(BigInt::from(30)))
+            .push(zinc_types::Push::new_field(BigInt::from(31)))
+            .push(zinc_types::Store::new(0, 6))
+            // array[1].increment()
+            .push(zinc_types::Push::new_field(BigInt::from(2)))
+            .push(zinc_types::LoadByIndex::new(0, 2, 6))
+            .push(zinc_types::Call::new(1, 2))
+            // write the returned instance back to the element slots
+            .push(zinc_types::Store::new(6, 2))
+            .push(zinc_types::Push::new_field(BigInt::from(2)))
+            .push(zinc_types::Load::new(6, 2))
+            .push(zinc_types::StoreByIndex::new(0, 2, 6))
+            .push(zinc_types::Load::new(0, 6))
+            .test(&[31, 30, 21, 21, 11, 10, 21])
+    }
 }
@@ -40,11 +40,11 @@ mod test {
             .push(zinc_types::LoopBegin::new(10))
             .push(zinc_types::Load::new(0, 1))
             .push(zinc_types::Push::new_field(BigInt::one()))
-            .push(zinc_types::Add)
+            .push(zinc_types::Add::default())
             .push(zinc_types::Store::new(0, 1))
             .push(zinc_types::Load::new(0, 1))
             .push(zinc_types::Load::new(1, 1))
-            .push(zinc_types::Add)
+            .push(zinc_types::Add::default())
             .push(zinc_types::Store::new(1, 1))
             .push(zinc_types::LoopEnd)
             .push(zinc_types::Load::new(0, 1))
@@ -5,3 +5,4 @@
 pub mod conditional;
 pub mod function;
 pub mod r#loop;
+pub mod unsafe_witness;
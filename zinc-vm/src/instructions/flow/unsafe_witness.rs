@@ -0,0 +1,162 @@
+//!
+//! The unconstrained witness instructions.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use zinc_types::UnsafeWitnessBegin;
+use zinc_types::UnsafeWitnessEnd;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::scalar::fr_bigint;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for UnsafeWitnessBegin {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        vm.unsafe_witness_begin();
+
+        Ok(())
+    }
+}
+
+impl<VM: IVirtualMachine> IExecutable<VM> for UnsafeWitnessEnd {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let mut values = Vec::with_capacity(self.output_types.len());
+        for _ in 0..self.output_types.len() {
+            values.push(vm.pop()?.try_into_value()?);
+        }
+        values.reverse();
+
+        vm.unsafe_witness_end()?;
+
+        let cs = vm.constraint_system();
+        let mut witnesses = Vec::with_capacity(values.len());
+        for (index, (value, scalar_type)) in values.into_iter().zip(self.output_types).enumerate() {
+            let value = value
+                .get_value()
+                .map(|fr| fr_bigint::fr_to_bigint::<VM::E>(&fr, scalar_type.is_signed()));
+            let witness = gadgets::witness::allocate(
+                cs.namespace(|| format!("witness {}", index)),
+                value.as_ref(),
+                scalar_type,
+            )?;
+            witnesses.push(witness);
+        }
+
+        for witness in witnesses.into_iter() {
+            vm.push(Cell::Value(witness))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::error::Error;
+    use crate::error::MalformedBytecode;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    ///
+    /// Pushes the checks `quotient * 3 <= value && value - quotient * 3 < 3`, where `value` and
+    /// `quotient` are stored at the data stack addresses 0 and 1, and loads the `quotient`.
+    ///
+    fn division_checks(runner: TestRunner) -> TestRunner {
+        runner
+            .push(zinc_types::Load::new(1, 1))
+            .push(zinc_types::Push::new(
+                BigInt::from(3),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Mul::default())
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Le)
+            .push(zinc_types::Require::new(None))
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Load::new(1, 1))
+            .push(zinc_types::Push::new(
+                BigInt::from(3),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Mul::default())
+            .push(zinc_types::Sub::default())
+            .push(zinc_types::Push::new(
+                BigInt::from(3),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Lt)
+            .push(zinc_types::Require::new(None))
+            .push(zinc_types::Load::new(1, 1))
+    }
+
+    #[test]
+    fn test_unsafe_witness() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::UnsafeWitnessBegin)
+            .push(zinc_types::Push::new_field(BigInt::from(3)))
+            .push(zinc_types::Push::new_field(BigInt::from(4)))
+            .push(zinc_types::Mul::default())
+            .push(zinc_types::UnsafeWitnessEnd::new(vec![
+                zinc_types::ScalarType::Field,
+            ]))
+            .test(&[12])
+    }
+
+    #[test]
+    fn test_unsafe_witness_end_unexpected() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new_field(BigInt::from(3)))
+            .push(zinc_types::UnsafeWitnessEnd::new(vec![
+                zinc_types::ScalarType::Field,
+            ]))
+            .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::MalformedBytecode(
+                MalformedBytecode::UnexpectedUnsafeWitnessEnd,
+            ))) => {}
+            _ => panic!("Expected the unexpected `unsafe_witness_end` error"),
+        }
+    }
+
+    #[test]
+    fn test_unsafe_witness_same_constraints_as_input() -> Result<(), TestingError> {
+        let hinted = division_checks(
+            TestRunner::new_with_input(
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+                vec![BigInt::from(200)],
+            )
+            .push(zinc_types::UnsafeWitnessBegin)
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Push::new(
+                BigInt::from(3),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Div)
+            .push(zinc_types::UnsafeWitnessEnd::new(vec![
+                zinc_types::IntegerType::U8.into(),
+            ]))
+            .push(zinc_types::Store::new(1, 1)),
+        )
+        .test_constraints(&[66])?;
+
+        let hand_written = division_checks(TestRunner::new_with_input(
+            zinc_types::Type::Tuple(vec![
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+                zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            ]),
+            vec![BigInt::from(200), BigInt::from(66)],
+        ))
+        .test_constraints(&[66])?;
+
+        assert_eq!(hinted, hand_written);
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 //! The instructions.
 //!
 
+pub mod assertion;
 pub mod call_library;
 pub mod contract_storage;
 pub mod data_stack;
@@ -78,6 +79,10 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Instruction {
             Self::Return(inner) => inner.execute(vm),
 
             Self::CallLibrary(inner) => inner.execute(vm),
+            Self::UnsafeWitnessBegin(inner) => inner.execute(vm),
+            Self::UnsafeWitnessEnd(inner) => inner.execute(vm),
+            Self::AssertEq(inner) => inner.execute(vm),
+            Self::AssertStorageUnchanged(inner) => inner.execute(vm),
             Self::Require(inner) => inner.execute(vm),
             Self::Dbg(inner) => inner.execute(vm),
 
@@ -26,11 +26,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Add {
 
         let unchecked_sum = gadgets::arithmetic::add::add(cs.namespace(|| "sum"), &left, &right)?;
 
-        let sum = Scalar::conditional_type_check(
+        let sum = Scalar::conditional_range_check(
             cs.namespace(|| "type check"),
             &condition,
             &unchecked_sum,
             sum_type,
+            self.range_check,
         )?;
 
         vm.push(Cell::Value(sum))
@@ -50,7 +51,7 @@ mod tests {
         TestRunner::new()
             .push(zinc_types::Push::new_field(BigInt::one()))
             .push(zinc_types::Push::new_field(BigInt::from(2)))
-            .push(zinc_types::Add)
+            .push(zinc_types::Add::default())
             .test(&[3])
     }
 }
@@ -2,6 +2,7 @@
 //! The `Div` instruction.
 //!
 
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
 use zinc_types::Div;
@@ -33,6 +34,9 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Div {
                     &right,
                     &one,
                 )?;
+                if denom.get_value().map(|value| value.is_zero()) == Some(true) {
+                    return Err(Error::DivisionByZero);
+                }
                 let inverse =
                     gadgets::arithmetic::field::inverse(cs.namespace(|| "inverse"), &denom)?;
                 gadgets::arithmetic::mul::mul(cs.namespace(|| "div"), &left, &inverse)?
@@ -68,6 +72,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Div {
 mod test {
     use num::BigInt;
 
+    use crate::error::Error;
     use crate::tests::TestRunner;
     use crate::tests::TestingError;
 
@@ -112,4 +117,44 @@ mod test {
             .push(zinc_types::Div)
             .test(&[3, -3, -2, 2])
     }
+
+    #[test]
+    fn test_div_by_zero() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(9),
+                zinc_types::IntegerType::I8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(0),
+                zinc_types::IntegerType::I8.into(),
+            ))
+            .push(zinc_types::Div)
+            .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::DivisionByZero)) => {}
+            _ => panic!("Expected the division by zero error"),
+        }
+    }
+
+    #[test]
+    fn test_div_field_by_zero() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(9),
+                zinc_types::ScalarType::Field,
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(0),
+                zinc_types::ScalarType::Field,
+            ))
+            .push(zinc_types::Div)
+            .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::DivisionByZero)) => {}
+            _ => panic!("Expected the division by zero error"),
+        }
+    }
 }
@@ -26,11 +26,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Mul {
 
         let unchecked_mul = gadgets::arithmetic::mul::mul(cs.namespace(|| "mul"), &left, &right)?;
 
-        let mul = Scalar::conditional_type_check(
+        let mul = Scalar::conditional_range_check(
             cs.namespace(|| "type check"),
             &condition,
             &unchecked_mul,
             mul_type,
+            self.range_check,
         )?;
 
         vm.push(Cell::Value(mul))
@@ -49,7 +50,7 @@ mod test {
         TestRunner::new()
             .push(zinc_types::Push::new_field(BigInt::from(3)))
             .push(zinc_types::Push::new_field(BigInt::from(4)))
-            .push(zinc_types::Mul)
+            .push(zinc_types::Mul::default())
             .test(&[12])
     }
 }
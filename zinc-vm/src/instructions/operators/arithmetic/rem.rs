@@ -27,7 +27,11 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Rem {
             &condition,
             &left,
             &right,
-        )?;
+        )
+        .map_err(|error| match error {
+            Error::DivisionByZero => Error::RemainderByZero,
+            error => error,
+        })?;
 
         let rem = Scalar::conditional_type_check(
             cs.namespace(|| "type check"),
@@ -44,6 +48,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Rem {
 mod test {
     use num::BigInt;
 
+    use crate::error::Error;
     use crate::tests::TestRunner;
     use crate::tests::TestingError;
 
@@ -88,4 +93,24 @@ mod test {
             .push(zinc_types::Rem)
             .test(&[3, 3, 1, 1])
     }
+
+    #[test]
+    fn test_rem_by_zero() {
+        let result = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::from(9),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(0),
+                zinc_types::IntegerType::U8.into(),
+            ))
+            .push(zinc_types::Rem)
+            .test::<i32>(&[]);
+
+        match result {
+            Err(TestingError::Error(Error::RemainderByZero)) => {}
+            _ => panic!("Expected the remainder by zero error"),
+        }
+    }
 }
@@ -26,11 +26,12 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Sub {
 
         let unchecked_diff = gadgets::arithmetic::sub::sub(cs.namespace(|| "diff"), &left, &right)?;
 
-        let diff = Scalar::conditional_type_check(
+        let diff = Scalar::conditional_range_check(
             cs.namespace(|| "type check"),
             &condition,
             &unchecked_diff,
             diff_type,
+            self.range_check,
         )?;
 
         vm.push(Cell::Value(diff))
@@ -50,7 +51,7 @@ mod test {
         TestRunner::new()
             .push(zinc_types::Push::new_field(BigInt::from(2)))
             .push(zinc_types::Push::new_field(BigInt::one()))
-            .push(zinc_types::Sub)
+            .push(zinc_types::Sub::default())
             .test(&[1])
     }
 }
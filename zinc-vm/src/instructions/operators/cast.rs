@@ -18,12 +18,25 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Cast {
 
         let condition = vm.condition_top()?;
         let cs = vm.constraint_system();
-        let new_value = Scalar::conditional_type_check(
-            cs.namespace(|| "type check"),
-            &condition,
-            &old_value,
-            self.r#type,
-        )?;
+        let new_value = match (old_value.get_type(), self.r#type) {
+            (zinc_types::ScalarType::Integer(from), zinc_types::ScalarType::Integer(to))
+                if to.is_reinterpreted_from(&from) =>
+            {
+                Scalar::conditional_reinterpret(
+                    cs.namespace(|| "reinterpret"),
+                    &condition,
+                    &old_value,
+                    to,
+                )?
+            }
+            (_, r#type) => Scalar::conditional_range_check(
+                cs.namespace(|| "type check"),
+                &condition,
+                &old_value,
+                r#type,
+                self.range_check,
+            )?,
+        };
 
         vm.push(Cell::Value(new_value))
     }
@@ -6,7 +6,10 @@ use num::BigInt;
 use num::One;
 
 use zinc_types::Add;
+use zinc_types::Cast;
+use zinc_types::Load;
 use zinc_types::Push;
+use zinc_types::RangeCheck;
 use zinc_types::Sub;
 
 use crate::error::Error;
@@ -21,7 +24,7 @@ fn unsigned_positive_overflow_fail() {
             zinc_types::IntegerType::U8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::U8.into()))
-        .push(Add)
+        .push(Add::default())
         .test(&[256]);
 
     match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
@@ -41,7 +44,7 @@ fn unsigned_negative_overflow_fail() {
             BigInt::from(255),
             zinc_types::IntegerType::U8.into(),
         ))
-        .push(Sub)
+        .push(Sub::default())
         .test(&[-1]);
 
     match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
@@ -58,7 +61,7 @@ fn signed_positive_overflow_fail() {
             zinc_types::IntegerType::I8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::I8.into()))
-        .push(Add)
+        .push(Add::default())
         .test(&[128]);
 
     match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
@@ -75,7 +78,7 @@ fn signed_negative_overflow_fail() {
             zinc_types::IntegerType::I8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::I8.into()))
-        .push(Sub)
+        .push(Sub::default())
         .test(&[-129]);
 
     match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
@@ -92,7 +95,7 @@ fn unsigned_positive_overflow_ok() -> Result<(), TestingError> {
             zinc_types::IntegerType::U8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::U8.into()))
-        .push(Add)
+        .push(Add::default())
         .test(&[255])
 }
 
@@ -107,7 +110,7 @@ fn unsigned_negative_overflow_ok() -> Result<(), TestingError> {
             BigInt::from(255),
             zinc_types::IntegerType::U8.into(),
         ))
-        .push(Sub)
+        .push(Sub::default())
         .test(&[0])
 }
 
@@ -119,7 +122,7 @@ fn signed_positive_overflow_ok() -> Result<(), TestingError> {
             zinc_types::IntegerType::I8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::I8.into()))
-        .push(Add)
+        .push(Add::default())
         .test(&[127])
 }
 
@@ -131,6 +134,145 @@ fn signed_negative_overflow_ok() -> Result<(), TestingError> {
             zinc_types::IntegerType::I8.into(),
         ))
         .push(Push::new(BigInt::one(), zinc_types::IntegerType::I8.into()))
-        .push(Sub)
+        .push(Sub::default())
         .test(&[-128])
 }
+
+#[test]
+fn field_to_unsigned_overflow_fail() {
+    let res = TestRunner::new()
+        .push(Push::new_field(BigInt::from(256)))
+        .push(Cast::new(zinc_types::IntegerType::U8.into()))
+        .test(&[256]);
+
+    match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
+        TestingError::Error(Error::ValueOverflow { .. }) => {}
+        err => panic!("expected overflow error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn field_to_unsigned_overflow_ok() -> Result<(), TestingError> {
+    TestRunner::new()
+        .push(Push::new_field(BigInt::from(255)))
+        .push(Cast::new(zinc_types::IntegerType::U8.into()))
+        .test(&[255])
+}
+
+#[test]
+fn range_check_elided_same_result() -> Result<(), TestingError> {
+    let run = |range_check| {
+        TestRunner::new_with_input(
+            zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+            vec![BigInt::from(99)],
+        )
+        .push(Load::new(0, 1))
+        .push(Push::new(BigInt::one(), zinc_types::IntegerType::U8.into()))
+        .push(Add::new(range_check))
+        .test_constraints(&[100])
+    };
+
+    let enforced = run(RangeCheck::Enforced)?;
+    let elided = run(RangeCheck::Elided)?;
+    let asserted = run(RangeCheck::Asserted)?;
+
+    assert!(elided < enforced);
+    assert_eq!(asserted, enforced);
+
+    Ok(())
+}
+
+#[test]
+fn range_check_asserted_overflow_fail() {
+    let res = TestRunner::new_with_input(
+        zinc_types::Type::Scalar(zinc_types::IntegerType::U8.into()),
+        vec![BigInt::from(255)],
+    )
+    .push(Load::new(0, 1))
+    .push(Push::new(BigInt::one(), zinc_types::IntegerType::U8.into()))
+    .push(Add::new(RangeCheck::Asserted))
+    .test(&[256]);
+
+    match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
+        TestingError::Error(Error::RangeCheckElisionUnsound { .. }) => {}
+        err => panic!("expected unsound elision error, got {:?} instead", err),
+    }
+}
+
+fn reinterpret(
+    from: zinc_types::IntegerType,
+    to: zinc_types::IntegerType,
+    value: i64,
+    expected: i64,
+) -> Result<(), TestingError> {
+    TestRunner::new_with_input(
+        zinc_types::Type::Scalar(from.clone().into()),
+        vec![BigInt::from(value)],
+    )
+    .push(Load::new(0, 1))
+    .push(Cast::new(to.clone().into()))
+    .test(&[expected])?;
+
+    TestRunner::new()
+        .push(Push::new(BigInt::from(value), from.into()))
+        .push(Cast::new(to.into()))
+        .test(&[expected])
+}
+
+#[test]
+fn reinterpret_signed_to_unsigned_ok() -> Result<(), TestingError> {
+    for (value, expected) in [(-1, 255), (-128, 128), (127, 127), (0, 0)].iter() {
+        reinterpret(
+            zinc_types::IntegerType::I8,
+            zinc_types::IntegerType::U8,
+            *value,
+            *expected,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn reinterpret_unsigned_to_signed_ok() -> Result<(), TestingError> {
+    for (value, expected) in [(65535, -1), (32768, -32768), (32767, 32767), (0, 0)].iter() {
+        reinterpret(
+            zinc_types::IntegerType::U16,
+            zinc_types::IntegerType::I16,
+            *value,
+            *expected,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn reinterpret_sign_extension_ok() -> Result<(), TestingError> {
+    for (value, expected) in [(-1, 65535), (-128, 65408), (127, 127), (0, 0)].iter() {
+        reinterpret(
+            zinc_types::IntegerType::I8,
+            zinc_types::IntegerType::U16,
+            *value,
+            *expected,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn signed_to_unsigned_narrowing_overflow_fail() {
+    let res = TestRunner::new()
+        .push(Push::new(
+            BigInt::from(-1),
+            zinc_types::IntegerType::I16.into(),
+        ))
+        .push(Cast::new(zinc_types::IntegerType::U8.into()))
+        .test(&[255]);
+
+    match res.err().expect(zinc_const::panic::TEST_DATA_VALID) {
+        TestingError::Error(Error::ValueOverflow { .. }) => {}
+        err => panic!("expected overflow error, got {:?} instead", err),
+    }
+}
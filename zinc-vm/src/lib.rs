@@ -23,8 +23,22 @@ pub use self::core::contract::input::Input as ContractInput;
 pub use self::core::contract::output::initializer::Initializer as ContractOutputInitializer;
 pub use self::core::contract::output::Output as ContractOutput;
 pub use self::core::contract::storage::keeper::IKeeper as IContractStorageKeeper;
+pub use self::core::execution_state::audit::Finding as AuditFinding;
+pub use self::core::execution_state::audit::Reason as AuditReason;
+pub use self::core::execution_state::audit::Report as AuditReport;
+pub use self::core::execution_state::cancellation::Cancellation as CancellationToken;
 pub use self::core::facade::Facade;
 pub use self::core::library::facade::Facade as LibraryFacade;
+pub use self::core::proof_encoding::ProofEncoding;
+pub use self::core::public_inputs::PublicInputs;
+pub use self::core::public_inputs::Slot as PublicInputSlot;
+pub use self::core::unit_test::backend::Backend as UnitTestBackend;
+pub use self::core::unit_test::outcome::Outcome as UnitTestOutcome;
+pub use self::core::unit_test::outcome::Status as UnitTestStatus;
+pub use self::core::unit_test::Report as UnitTestReport;
+pub use self::core::witness::Variable as WitnessVariable;
+pub use self::core::witness::VariableKind as WitnessVariableKind;
+pub use self::core::witness::Witness;
 pub use self::error::Error;
 pub use self::error::VerificationError;
 
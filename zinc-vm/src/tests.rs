@@ -2,9 +2,8 @@
 //! The test tools.
 //!
 
-use std::collections::HashMap;
-
 use colored::Colorize;
+use indexmap::IndexMap;
 use num::bigint::ToBigInt;
 use num::BigInt;
 use thiserror::Error;
@@ -61,12 +60,21 @@ pub enum TestingError {
 
 pub struct TestRunner {
     instructions: Vec<Instruction>,
+    input: Option<(zinc_types::Type, Vec<BigInt>)>,
 }
 
 impl TestRunner {
     pub fn new() -> Self {
         Self {
             instructions: vec![Call::new(1, 0).into()],
+            input: None,
+        }
+    }
+
+    pub fn new_with_input(input_type: zinc_types::Type, values: Vec<BigInt>) -> Self {
+        Self {
+            instructions: vec![Call::new(1, 0).into()],
+            input: Some((input_type, values)),
         }
     }
 
@@ -76,6 +84,13 @@ impl TestRunner {
     }
 
     pub fn test<T: Into<BigInt> + Copy>(self, expected_stack: &[T]) -> Result<(), TestingError> {
+        self.test_constraints(expected_stack).map(|_| ())
+    }
+
+    pub fn test_constraints<T: Into<BigInt> + Copy>(
+        self,
+        expected_stack: &[T],
+    ) -> Result<usize, TestingError> {
         self.test_constrained(expected_stack).map_err(|error| {
             println!("{}: {}", "error".bold().red(), error);
             error
@@ -85,25 +100,37 @@ impl TestRunner {
     fn test_constrained<T: Into<BigInt> + Copy>(
         self,
         expected_stack: &[T],
-    ) -> Result<(), TestingError> {
+    ) -> Result<usize, TestingError> {
         let mut vm = new_test_constrained_vm();
 
+        // the input is passed directly to the tested instructions, skipping the initial call
+        let (address, input_type, input_values) = match self.input {
+            Some((input_type, input_values)) => (1, input_type, input_values),
+            None => (0, zinc_types::Type::Unit, vec![]),
+        };
+
         let circuit = zinc_types::Circuit::new(
             "test".to_owned(),
-            0,
-            zinc_types::Type::Unit,
+            address,
+            input_type,
             zinc_types::Type::Unit,
-            HashMap::new(),
+            IndexMap::new(),
             self.instructions,
         );
 
-        vm.run(circuit, Some(&[]), |_| {}, |_| Ok(()))
-            .map_err(TestingError::Error)?;
+        vm.run(
+            circuit,
+            Some(input_values.as_slice()),
+            |_, _, _| {},
+            |_| Ok(()),
+        )
+        .map_err(TestingError::Error)?;
 
-        let cs = vm.constraint_system();
+        let cs = vm.constraint_system().inner();
 
         let unconstrained = cs.find_unconstrained();
         let satisfied = cs.is_satisfied();
+        let constraints = cs.num_constraints();
 
         assert_stack_eq(&mut vm, expected_stack);
 
@@ -112,7 +139,7 @@ impl TestRunner {
         } else if !satisfied {
             Err(TestingError::Unsatisfied)
         } else {
-            Ok(())
+            Ok(constraints)
         }
     }
 }
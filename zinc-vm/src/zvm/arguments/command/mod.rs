@@ -4,6 +4,7 @@
 
 pub mod run;
 pub mod test;
+pub mod verify;
 
 use structopt::StructOpt;
 
@@ -11,6 +12,7 @@ use crate::error::Error;
 
 use self::run::Command as RunCommand;
 use self::test::Command as TestCommand;
+use self::verify::Command as VerifyCommand;
 
 ///
 /// The generic trait used for commands.
@@ -35,6 +37,8 @@ pub enum Command {
     Run(RunCommand),
     /// Executes a unit test.
     Test(TestCommand),
+    /// Verifies the zero-knowledge proof.
+    Verify(VerifyCommand),
 }
 
 impl IExecutable for Command {
@@ -44,6 +48,7 @@ impl IExecutable for Command {
         match self {
             Command::Run(inner) => inner.execute(),
             Command::Test(inner) => inner.execute(),
+            Command::Verify(inner) => inner.execute(),
         }
     }
 }
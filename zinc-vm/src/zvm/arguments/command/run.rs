@@ -4,9 +4,11 @@
 
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::ffi::OsStr;
 use std::fs;
 use std::path::PathBuf;
 
+use indexmap::IndexMap;
 use num::BigInt;
 use num::Zero;
 use structopt::StructOpt;
@@ -16,6 +18,7 @@ use franklin_crypto::bellman::pairing::bn256::Bn256;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
 use zinc_vm::ContractInput;
+use zinc_vm::Facade;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
@@ -42,6 +45,20 @@ pub struct Command {
     /// The method name to call, if the application is a contract.
     #[structopt(long = "method")]
     pub method: Option<String>,
+
+    /// The paths to the verifying key files available to `std::crypto::verify_proof`.
+    #[structopt(long = "verifying-key")]
+    pub verifying_key_paths: Vec<PathBuf>,
+
+    /// The path to the witness file, which is written in JSON if the extension is `json`,
+    /// and in the compact binary form otherwise. Only for circuits.
+    #[structopt(long = "output-witness")]
+    pub output_witness_path: Option<PathBuf>,
+
+    /// Runs the contract method in the constant-time audit mode and prints the instructions,
+    /// whose host-side cost depends on the witness. Only for contracts.
+    #[structopt(long = "audit")]
+    pub is_audit: bool,
 }
 
 impl IExecutable for Command {
@@ -51,8 +68,15 @@ impl IExecutable for Command {
         // Read the bytecode
         let bytecode =
             fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
-        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
-            .map_err(Error::ApplicationDecoding)?;
+        let (header, application) = zinc_types::Application::try_decode(bytecode.as_slice())
+            .map_err(|error| Error::ApplicationDecoding(error.to_string()))?;
+        Facade::check_header(&header)?;
+
+        // Read the verifying keys
+        let mut verifying_keys = Vec::with_capacity(self.verifying_key_paths.len());
+        for path in self.verifying_key_paths.iter() {
+            verifying_keys.push(fs::read(path).error_with_path(|| path.to_string_lossy())?);
+        }
 
         // Read the input file
         let input_path = self.input_path;
@@ -62,11 +86,33 @@ impl IExecutable for Command {
 
         let output = match application {
             zinc_types::Application::Circuit(circuit) => match input {
-                zinc_types::InputBuild::Circuit { arguments } => {
+                zinc_types::InputBuild::Circuit { arguments, .. } => {
+                    if self.is_audit {
+                        return Err(Error::AuditUnsupported);
+                    }
+
                     let input_type = circuit.input.clone();
                     let arguments = zinc_types::Value::try_from_typed_json(arguments, input_type)?;
 
-                    CircuitFacade::new(circuit).run::<Bn256>(arguments)?.result
+                    if let Some(path) = self.output_witness_path {
+                        let witness = CircuitFacade::new(circuit.clone())
+                            .with_verifying_keys(verifying_keys.clone())
+                            .witness::<Bn256>(arguments.clone())?;
+
+                        let is_json = path.extension().and_then(OsStr::to_str)
+                            == Some(zinc_const::extension::JSON);
+                        let data = if is_json {
+                            serde_json::to_vec_pretty(&witness.into_json())?
+                        } else {
+                            witness.into_bytes()
+                        };
+                        fs::write(&path, data).error_with_path(|| path.to_string_lossy())?;
+                    }
+
+                    CircuitFacade::new(circuit)
+                        .with_verifying_keys(verifying_keys)
+                        .run::<Bn256>(arguments)?
+                        .result
                 }
                 zinc_types::InputBuild::Contract { .. } => {
                     return Err(Error::InputDataInvalid {
@@ -89,16 +135,29 @@ impl IExecutable for Command {
                     })
                 }
                 zinc_types::InputBuild::Contract {
+                    template,
                     arguments,
                     msg: transaction,
                     storages,
                 } => {
+                    if self.output_witness_path.is_some() {
+                        return Err(Error::WitnessOutputUnsupported);
+                    }
+
                     let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
-                    let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
-                        Error::MethodNotFound {
-                            name: method_name.clone(),
-                        },
-                    )?;
+                    let method = contract
+                        .methods
+                        .get(method_name.as_str())
+                        .cloned()
+                        .ok_or_else(|| match contract.pruned_from {
+                            Some(ref original) => Error::MethodPruned {
+                                name: method_name.clone(),
+                                original: original.to_owned(),
+                            },
+                            None => Error::MethodNotFound {
+                                name: method_name.clone(),
+                            },
+                        })?;
 
                     let method_arguments = arguments.get(method_name.as_str()).cloned().ok_or(
                         Error::MethodArgumentsNotFound {
@@ -107,42 +166,21 @@ impl IExecutable for Command {
                     )?;
                     let mut method_arguments =
                         zinc_types::Value::try_from_typed_json(method_arguments, method.input)?;
-                    if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                    if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER
+                        && contract.upgrade.as_ref() != Some(&method_name)
+                    {
                         method_arguments.insert_contract_instance(BigInt::zero());
                     }
 
-                    let mut input_storages = HashMap::with_capacity(storages.len());
-                    for (address, value) in storages.into_iter() {
-                        let address: zksync_types::Address = address["0x".len()..]
-                            .parse()
-                            .expect(zinc_const::panic::DATA_CONVERSION);
-
-                        let value = match value {
-                            serde_json::Value::Array(array) => {
-                                let mut storage_values = Vec::with_capacity(contract.storage.len());
-                                for (field, value) in
-                                    contract.storage.clone().into_iter().zip(array)
-                                {
-                                    storage_values.push(zinc_types::ContractFieldValue::new(
-                                        field.name,
-                                        zinc_types::Value::try_from_typed_json(
-                                            value,
-                                            field.r#type,
-                                        )?,
-                                        field.is_public,
-                                        field.is_implicit,
-                                    ));
-                                }
-                                zinc_types::Value::Contract(storage_values)
-                            }
-                            value => {
-                                return Err(Error::InvalidContractStorageFormat { found: value })
-                            }
-                        };
-                        input_storages.insert(address, value);
+                    let input_storages = Self::storages(&contract, storages)?;
+
+                    let mut facade =
+                        ContractFacade::new(contract).with_verifying_keys(verifying_keys);
+                    if self.is_audit {
+                        facade = facade.with_audit();
                     }
 
-                    let output = ContractFacade::new(contract).run::<Bn256>(ContractInput::new(
+                    let output = facade.run::<Bn256>(ContractInput::new(
                         method_arguments,
                         input_storages,
                         method_name,
@@ -154,7 +192,11 @@ impl IExecutable for Command {
                         })?,
                     ))?;
 
-                    let mut storages = HashMap::with_capacity(output.storages.len());
+                    if let Some(audit) = output.audit.as_ref() {
+                        eprint!("{}", audit);
+                    }
+
+                    let mut storages = IndexMap::with_capacity(output.storages.len());
                     for (eth_address, value) in output.storages.into_iter() {
                         match value {
                             zinc_types::Value::Contract(fields) => {
@@ -162,16 +204,8 @@ impl IExecutable for Command {
                                 for field in fields.into_iter() {
                                     storage_values.push(field.value.into_json());
                                 }
-                                let eth_address =
-                                    eth_address.to_str_radix(zinc_const::base::HEXADECIMAL);
                                 storages.insert(
-                                    format!(
-                                        "0x{}{}",
-                                        "0".repeat(
-                                            zinc_const::size::ETH_ADDRESS * 2 - eth_address.len()
-                                        ),
-                                        eth_address,
-                                    ),
+                                    zinc_types::scalar_format::address(&eth_address),
                                     serde_json::Value::Array(storage_values),
                                 );
                             }
@@ -183,10 +217,16 @@ impl IExecutable for Command {
                         }
                     }
 
-                    let input_str = serde_json::to_string_pretty(
-                        &zinc_types::InputBuild::new_contract(storages, transaction, arguments),
-                    )
-                    .expect(zinc_const::panic::DATA_CONVERSION);
+                    storages.sort_keys();
+
+                    let input_str =
+                        serde_json::to_string_pretty(&zinc_types::InputBuild::new_contract(
+                            template,
+                            storages,
+                            transaction,
+                            arguments,
+                        ))
+                        .expect(zinc_const::panic::DATA_CONVERSION);
                     fs::write(&input_path, input_str)
                         .error_with_path(|| input_path.to_string_lossy())?;
 
@@ -211,3 +251,39 @@ impl IExecutable for Command {
         Ok(zinc_const::exit_code::SUCCESS as i32)
     }
 }
+
+impl Command {
+    ///
+    /// Parses the input file `storages` of the `contract`, which are keyed by the ETH addresses.
+    ///
+    pub fn storages(
+        contract: &zinc_types::Contract,
+        storages: IndexMap<String, serde_json::Value>,
+    ) -> Result<HashMap<zksync_types::Address, zinc_types::Value>, Error> {
+        let mut input_storages = HashMap::with_capacity(storages.len());
+        for (address, value) in storages.into_iter() {
+            let address: zksync_types::Address = address["0x".len()..]
+                .parse()
+                .expect(zinc_const::panic::DATA_CONVERSION);
+
+            let value = match value {
+                serde_json::Value::Array(array) => {
+                    let mut storage_values = Vec::with_capacity(contract.storage.len());
+                    for (field, value) in contract.storage.clone().into_iter().zip(array) {
+                        storage_values.push(zinc_types::ContractFieldValue::new(
+                            field.name,
+                            zinc_types::Value::try_from_typed_json(value, field.r#type)?,
+                            field.is_public,
+                            field.is_implicit,
+                        ));
+                    }
+                    zinc_types::Value::Contract(storage_values)
+                }
+                value => return Err(Error::InvalidContractStorageFormat { found: value }),
+            };
+            input_storages.insert(address, value);
+        }
+
+        Ok(input_storages)
+    }
+}
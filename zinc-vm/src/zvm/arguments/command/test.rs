@@ -2,8 +2,10 @@
 //! The Zinc virtual machine `test` subcommand.
 //!
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use structopt::StructOpt;
 
@@ -11,8 +13,10 @@ use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
+use zinc_vm::Facade;
 use zinc_vm::LibraryFacade;
 
+use crate::arguments::command::run::Command as RunCommand;
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
 use crate::error::IErrorPath;
@@ -26,6 +30,32 @@ pub struct Command {
     /// The path to the binary bytecode file.
     #[structopt(long = "binary")]
     pub binary_path: PathBuf,
+
+    /// The path to the input JSON file, whose contract storages are available to the unit tests.
+    #[structopt(long = "input")]
+    pub input_path: Option<PathBuf>,
+
+    /// The path to the `lcov` instruction coverage report, which is collected if set.
+    #[structopt(long = "coverage")]
+    pub coverage_path: Option<PathBuf>,
+
+    /// Hides the passed tests faster than the specified number of milliseconds.
+    #[structopt(long = "min-time")]
+    pub min_time: Option<u64>,
+
+    /// Evaluates all the tests without synthesizing the constraints, including the `#[proof]` ones.
+    /// The constraint satisfiability is not checked in this mode.
+    #[structopt(long = "fast")]
+    pub is_fast: bool,
+
+    /// Runs only the tests whose fully qualified names match the filter, which is either the whole
+    /// name or its suffix, e.g. `overflow` or `math::overflow` for `exchange::math::overflow`.
+    #[structopt(long = "filter")]
+    pub filter: Option<String>,
+
+    /// Fails the tests running longer than the specified number of seconds.
+    #[structopt(long = "timeout")]
+    pub timeout: Option<u64>,
 }
 
 impl IExecutable for Command {
@@ -35,21 +65,78 @@ impl IExecutable for Command {
         // Read the bytecode
         let bytecode =
             fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
-        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
-            .map_err(Error::ApplicationDecoding)?;
+        let (header, mut application) = zinc_types::Application::try_decode(bytecode.as_slice())
+            .map_err(|error| Error::ApplicationDecoding(error.to_string()))?;
+        Facade::check_header(&header)?;
 
-        let status = match application {
-            zinc_types::Application::Circuit(circuit) => {
-                CircuitFacade::new(circuit).test::<Bn256>()?
-            }
+        if let Some(ref filter) = self.filter {
+            application.retain_unit_tests(filter.as_str());
+        }
+
+        let mut coverage = self
+            .coverage_path
+            .as_ref()
+            .map(|_| zinc_types::Coverage::new(application.instructions()));
+
+        let min_time = self.min_time.map(Duration::from_millis);
+        let timeout = self.timeout.map(Duration::from_secs);
+
+        let report = match application {
+            zinc_types::Application::Circuit(circuit) => CircuitFacade::new(circuit)
+                .test::<Bn256>(coverage.as_mut(), min_time, self.is_fast, timeout)?,
             zinc_types::Application::Contract(contract) => {
-                ContractFacade::new(contract).test::<Bn256>()?
-            }
-            zinc_types::Application::Library(library) => {
-                LibraryFacade::new(library).test::<Bn256>()?
+                let storages = match self.input_path {
+                    Some(ref input_path) => {
+                        let input_template = fs::read_to_string(input_path)
+                            .error_with_path(|| input_path.to_string_lossy())?;
+                        let input: zinc_types::InputBuild =
+                            serde_json::from_str(input_template.as_str())?;
+                        match input {
+                            zinc_types::InputBuild::Contract { storages, .. } => {
+                                RunCommand::storages(&contract, storages)?
+                            }
+                            zinc_types::InputBuild::Circuit { .. } => {
+                                return Err(Error::InputDataInvalid {
+                                    expected: "contract".to_owned(),
+                                    found: "circuit".to_owned(),
+                                })
+                            }
+                            zinc_types::InputBuild::Library { .. } => {
+                                return Err(Error::InputDataInvalid {
+                                    expected: "contract".to_owned(),
+                                    found: "library".to_owned(),
+                                })
+                            }
+                        }
+                    }
+                    None => HashMap::new(),
+                };
+
+                ContractFacade::new(contract)
+                    .with_storages(storages)
+                    .test::<Bn256>(coverage.as_mut(), min_time, self.is_fast, timeout)?
             }
+            zinc_types::Application::Library(library) => LibraryFacade::new(library)
+                .test::<Bn256>(coverage.as_mut(), min_time, self.is_fast, timeout)?,
         };
 
-        Ok(status as i32)
+        for line in report.summary().lines() {
+            log::info!("{}", line);
+        }
+
+        if let (Some(coverage), Some(coverage_path)) = (coverage, self.coverage_path) {
+            fs::write(&coverage_path, coverage.to_lcov())
+                .error_with_path(|| coverage_path.to_string_lossy())?;
+
+            log::info!(
+                "coverage report written to {}",
+                coverage_path.to_string_lossy()
+            );
+            for line in coverage.summary().lines() {
+                log::info!("{}", line);
+            }
+        }
+
+        Ok(report.exit_code as i32)
     }
 }
@@ -0,0 +1,70 @@
+//!
+//! The Zinc virtual machine `verify` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::Facade;
+use zinc_vm::VerificationError;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `verify` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(name = "verify", about = "Verifies the zero-knowledge proof")]
+pub struct Command {
+    /// The path to the verifying key file.
+    #[structopt(long = "verifying-key")]
+    pub verifying_key_path: PathBuf,
+
+    /// The path to the proof file, which may be raw, hexadecimal, or base64.
+    #[structopt(long = "proof")]
+    pub proof_path: PathBuf,
+
+    /// The path to the public data JSON file.
+    #[structopt(long = "public-data")]
+    pub public_data_path: PathBuf,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        let verifying_key = fs::read(&self.verifying_key_path)
+            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
+        let proof =
+            fs::read(&self.proof_path).error_with_path(|| self.proof_path.to_string_lossy())?;
+        let public_data = fs::read(&self.public_data_path)
+            .error_with_path(|| self.public_data_path.to_string_lossy())?;
+
+        let exit_code = match Facade::verify_bytes::<Bn256>(
+            verifying_key.as_slice(),
+            proof.as_slice(),
+            public_data.as_slice(),
+        ) {
+            Ok(true) => zinc_const::VerifyExitCode::Passed,
+            Ok(false) => zinc_const::VerifyExitCode::Failed,
+            Err(VerificationError::InvalidProof(error)) => {
+                log::debug!("{}: {}", self.proof_path.to_string_lossy(), error);
+                zinc_const::VerifyExitCode::Failed
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        match exit_code {
+            zinc_const::VerifyExitCode::Passed => println!("PASS"),
+            _ => println!("FAIL"),
+        }
+
+        Ok(exit_code as i32)
+    }
+}
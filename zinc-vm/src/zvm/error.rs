@@ -57,6 +57,10 @@ pub enum Error {
     #[error("method `{name}` not found")]
     MethodNotFound { name: String },
 
+    /// The method has been removed from the pruned contract artifact.
+    #[error("method `{name}` has been pruned from the artifact of the circuit {original}, rebuild without `--only-methods` to call it")]
+    MethodPruned { name: String, original: String },
+
     /// The method arguments are not present in the input data.
     #[error("method `{name}` arguments not found")]
     MethodArgumentsNotFound { name: String },
@@ -75,6 +79,13 @@ pub enum Error {
     /// The library cannot be run as a standalone application.
     #[error("libraries cannot be run as they have no entry points")]
     CannotRunLibrary,
+
+    /// The witness can only be written for circuits.
+    #[error("the witness output is only supported for circuits")]
+    WitnessOutputUnsupported,
+    /// The constant-time audit can only be run for contracts.
+    #[error("the constant-time audit is only supported for contracts")]
+    AuditUnsupported,
 }
 
 ///